@@ -34,6 +34,9 @@ npm ci
 echo "→ Building renderer and main process..."
 npm run build:electron
 
+# Bundle uv for the Docker-free server mode
+"$PROJECT_ROOT/build/fetch-uv.sh"
+
 # Package as AppImage
 echo "→ Packaging as AppImage..."
 npm run package:linux
@@ -0,0 +1,52 @@
+#!/bin/bash
+# Download the uv binary bundled with the app (build/uv/), used by the
+# Docker-free server mode to create its managed Python environment
+# (dashboard/electron/backendBootstrap.ts).
+#
+# Usage: build/fetch-uv.sh [target-triple]
+#   Default target is the build host, e.g. x86_64-unknown-linux-gnu.
+#   Windows: x86_64-pc-windows-msvc   macOS: aarch64-apple-darwin
+#
+# UV_VERSION pins the release (default below); keep it in step with the
+# uv version server/docker/Dockerfile installs.
+set -e
+
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+DEST="$SCRIPT_DIR/uv"
+UV_VERSION="${UV_VERSION:-0.10.8}"
+
+if [[ -n "${1:-}" ]]; then
+    TARGET="$1"
+else
+    case "$(uname -s)-$(uname -m)" in
+        Linux-x86_64) TARGET="x86_64-unknown-linux-gnu" ;;
+        Linux-aarch64) TARGET="aarch64-unknown-linux-gnu" ;;
+        Darwin-arm64) TARGET="aarch64-apple-darwin" ;;
+        Darwin-x86_64) TARGET="x86_64-apple-darwin" ;;
+        *) echo "ERROR: unsupported host $(uname -s)-$(uname -m); pass a target triple"; exit 1 ;;
+    esac
+fi
+
+BASE="https://github.com/astral-sh/uv/releases/download/$UV_VERSION"
+rm -rf "$DEST"
+mkdir -p "$DEST"
+TMP="$(mktemp -d)"
+trap 'rm -rf "$TMP"' EXIT
+
+echo "→ Fetching uv $UV_VERSION ($TARGET)"
+if [[ "$TARGET" == *windows* ]]; then
+    ARCHIVE="uv-$TARGET.zip"
+else
+    ARCHIVE="uv-$TARGET.tar.gz"
+fi
+curl -fsSL -o "$TMP/$ARCHIVE" "$BASE/$ARCHIVE"
+curl -fsSL -o "$TMP/$ARCHIVE.sha256" "$BASE/$ARCHIVE.sha256"
+(cd "$TMP" && sha256sum -c "$ARCHIVE.sha256")
+
+if [[ "$ARCHIVE" == *.zip ]]; then
+    unzip -q -j "$TMP/$ARCHIVE" 'uv.exe' -d "$DEST"
+else
+    tar -xzf "$TMP/$ARCHIVE" -C "$TMP"
+    install -m 755 "$TMP/uv-$TARGET/uv" "$DEST/uv"
+fi
+echo "✓ uv bundled in $DEST"
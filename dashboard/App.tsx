@@ -8,9 +8,12 @@ import { ServerView } from './components/views/ServerView';
 import { LogsView } from './components/views/LogsView';
 import { NotificationsView } from './components/views/NotificationsView';
 import { SettingsModal } from './components/views/SettingsModal';
+import { CommandPalette } from './components/views/CommandPalette';
 import { AboutModal } from './components/views/AboutModal';
 import { BugReportModal } from './components/views/BugReportModal';
 import { StarPopupModal } from './components/views/StarPopupModal';
+import { SampleJobModal } from './components/views/SampleJobModal';
+import { StartupProgress } from './components/views/StartupProgress';
 import { DedupChoiceContainer } from './components/import/DedupChoiceContainer';
 import { Button } from './components/ui/Button';
 import { CustomSelect } from './components/ui/CustomSelect';
@@ -23,22 +26,32 @@ import { queryClient } from './src/queryClient';
 import { useServerStatus } from './src/hooks/useServerStatus';
 import { useAdminStatus } from './src/hooks/useAdminStatus';
 import { initApiClient } from './src/api/client';
+import { setNetworkTraceEnabled } from './src/services/networkTrace';
 import { DockerProvider, useDockerContext } from './src/hooks/DockerContext';
 import { getConfig, setConfig } from './src/config/store';
 import { useLiveMode } from './src/hooks/useLiveMode';
 import { useImportQueueStore, selectIsUploading } from './src/stores/importQueueStore';
+import { LibraryReadOnlyBanner } from './components/ui/LibraryReadOnlyBanner';
 import { QueuePausedBanner } from './components/ui/QueuePausedBanner';
 import { UpdateBanner } from './components/ui/UpdateBanner';
 import { NotificationToasts } from './components/ui/NotificationToasts';
 import { HfTokenExplainer } from './components/ui/HfTokenExplainer';
 import { useStarPopup } from './src/hooks/useStarPopup';
+import { useSampleJobOffer } from './src/hooks/useSampleJobOffer';
 import { useNotificationBridge } from './src/hooks/useNotificationBridge';
 import { useServerEventReactor } from './src/hooks/useServerEventReactor';
 import { useNotificationsStore } from './src/stores/notificationsStore';
 import { SERVER_START_ID } from './src/utils/startupEventMapping';
 import { useAuthTokenSync } from './src/hooks/useAuthTokenSync';
 import { useWatcherFilesBridge } from './src/hooks/useWatcherFilesBridge';
+import { usePhoneIngestBridge } from './src/hooks/usePhoneIngestBridge';
+import { useConnectionFailover } from './src/hooks/useConnectionQuality';
+import { useHousekeepingScheduler } from './src/hooks/useHousekeepingScheduler';
 import { useUpdateToast } from './src/hooks/useUpdateToast';
+import { useLiveConfigReload } from './src/hooks/useLiveConfigReload';
+import { useKeymap } from './src/hooks/useKeymap';
+import { useLocaleSync } from './src/hooks/useLocale';
+import { matchesAccelerator } from './src/utils/keymap';
 import {
   MAIN_RECOMMENDED_MODEL,
   LIVE_RECOMMENDED_MODEL,
@@ -77,6 +90,7 @@ const AppInner: React.FC = () => {
   const [notebookTab, setNotebookTab] = useState<NotebookTab>(NotebookTab.CALENDAR);
   const [sessionTab, setSessionTab] = useState<SessionTab>(SessionTab.MAIN);
   const [isSettingsOpen, setIsSettingsOpen] = useState(false);
+  const [isPaletteOpen, setIsPaletteOpen] = useState(false);
   const [isAboutOpen, setIsAboutOpen] = useState(false);
   const [isBugReportOpen, setIsBugReportOpen] = useState(false);
   const serverConnection = useServerStatus();
@@ -99,6 +113,44 @@ const AppInner: React.FC = () => {
     }
   }, []);
 
+  // UI language: load the active catalog and follow runtime switches.
+  useLocaleSync();
+
+  // Open-settings and command-palette shortcuts from the shortcut registry
+  // (Ctrl+, / ⌘, and Ctrl+K / ⌘K by default).
+  const keymap = useKeymap();
+  useEffect(() => {
+    const isMac = window.electronAPI?.app.getPlatform() === 'darwin';
+    const handler = (event: KeyboardEvent) => {
+      if (matchesAccelerator(event, keymap['settings.open'], isMac)) {
+        event.preventDefault();
+        setIsSettingsOpen(true);
+      } else if (matchesAccelerator(event, keymap['palette.open'], isMac)) {
+        event.preventDefault();
+        setIsPaletteOpen((open) => !open);
+      }
+    };
+    window.addEventListener('keydown', handler);
+    return () => window.removeEventListener('keydown', handler);
+  }, [keymap]);
+
+  // Renderer-side palette actions handed over by the main-process registry.
+  useEffect(() => {
+    const onRun = window.electronAPI?.actions?.onRun;
+    if (!onRun) return;
+    const views: Record<string, View> = {
+      'navigate.session': View.SESSION,
+      'navigate.notebook': View.NOTEBOOK,
+      'navigate.server': View.SERVER,
+      'navigate.logs': View.LOGS,
+      'navigate.notifications': View.NOTIFICATIONS,
+    };
+    return onRun((id) => {
+      if (id === 'settings.open') setIsSettingsOpen(true);
+      else if (views[id]) setCurrentView(views[id]);
+    });
+  }, []);
+
   // Track remote mode so useAuthTokenSync re-evaluates on mode switch
   const [useRemote, setUseRemote] = useState(false);
   useEffect(() => {
@@ -117,8 +169,16 @@ const AppInner: React.FC = () => {
   // mounted at app root so it does not double-register when a per-tab hook
   // (e.g. useSessionWatcher) survives a tab switch (Issue #94).
   useWatcherFilesBridge();
+  // Same singleton rule for uploads from a paired phone.
+  usePhoneIngestBridge();
+  // ...and for acting on the connection monitor's profile-switch suggestions.
+  useConnectionFailover();
+  // ...and for the nightly server housekeeping schedule.
+  useHousekeepingScheduler();
   // Raises the Update/Dismiss toast when main pushes updates:updateAvailable.
   useUpdateToast();
+  // Re-syncs the API client when connection settings change from anywhere.
+  useLiveConfigReload();
 
   // Track clientRunning at app level so Sidebar can derive Session status
   const [clientRunning, setClientRunning] = useState(false);
@@ -129,6 +189,9 @@ const AppInner: React.FC = () => {
   // Star popup (one-time after 2+ hours cumulative use)
   const { showStarPopup, dismissStarPopup } = useStarPopup();
 
+  // Onboarding sample transcription, offered once when the server first comes up
+  const { showSampleJob, dismissSampleJob } = useSampleJobOffer(serverConnection.ready);
+
   // Derive upload/import status from unified Zustand queue store (GH #41/#42)
   const isUploading = useImportQueueStore(selectIsUploading);
 
@@ -248,6 +311,9 @@ const AppInner: React.FC = () => {
 
   useEffect(() => {
     void initApiClient();
+    getConfig<boolean>('app.networkTrace')
+      .then((v) => setNetworkTraceEnabled(v === true))
+      .catch(() => {});
   }, []);
 
   const resolveHfPrompt = useCallback(
@@ -816,6 +882,12 @@ const AppInner: React.FC = () => {
         {/* Queue paused banner — visible across all views */}
         <QueuePausedBanner />
 
+        {/* Library owned by another machine (synced drive) — visible across all views */}
+        <LibraryReadOnlyBanner
+          readOnly={Boolean(serverConnection.details?.library_read_only)}
+          onOwnershipChanged={serverConnection.refresh}
+        />
+
         {/* Scrollable View Content - Removed p-6 to allow full-width scrolling in Server View */}
         {/* @container: makes the content area a container-query context so SessionView/
             NotebookView grids reflow based on available width (sidebar-collapse aware),
@@ -850,15 +922,19 @@ const AppInner: React.FC = () => {
 
       {/* Modals */}
       <SettingsModal isOpen={isSettingsOpen} onClose={() => setIsSettingsOpen(false)} />
+      <CommandPalette isOpen={isPaletteOpen} onClose={() => setIsPaletteOpen(false)} />
       <AboutModal isOpen={isAboutOpen} onClose={() => setIsAboutOpen(false)} />
       <BugReportModal isOpen={isBugReportOpen} onClose={() => setIsBugReportOpen(false)} />
       <StarPopupModal isOpen={showStarPopup} onDismiss={() => void dismissStarPopup()} />
+      <SampleJobModal isOpen={showSampleJob && !showStarPopup} onDismiss={dismissSampleJob} />
 
       {/* Issue #104, Sprint 2 Item 4 — full dedup choice flow.
           The container subscribes to useDedupChoiceStore; the import queue
           calls requestChoice() and awaits the user's pick before continuing. */}
       <DedupChoiceContainer />
 
+      <StartupProgress />
+
       {modelOnboardingOpen && (
         <div className="fixed inset-0 z-60 flex items-center justify-center p-4">
           <div
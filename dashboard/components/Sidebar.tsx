@@ -16,6 +16,8 @@ import {
 } from 'lucide-react';
 import logoUrl from '../../docs/assets/logo.png';
 import { StatusLight } from './ui/StatusLight';
+import { useT } from '../src/hooks/useLocale';
+import { useUnreadNotificationCount } from '../src/hooks/useNotificationHistory';
 import type { RuntimeProfile } from '../src/types/runtime';
 import { ProfileSelector } from './profiles/ProfileSelector';
 import { ModelProfileSelector } from './profiles/ModelProfileSelector';
@@ -163,41 +165,46 @@ export const Sidebar: React.FC<SidebarProps> = ({
             ? 'warning'
             : 'inactive';
 
+  const t = useT();
+  const unreadNotifications = useUnreadNotificationCount();
+
   // Top navigation items that get the sliding animation
   const navItems = [
     {
       id: View.SESSION,
-      label: 'Session',
+      label: t('nav-session'),
       icon: <Mic2 size={20} />,
       status: sessionStatus as 'active' | 'warning' | 'inactive' | 'error',
     },
     {
       id: View.NOTEBOOK,
-      label: 'Notebook',
+      label: t('nav-notebook'),
       icon: <Book size={20} />,
       status: serverSidebarStatus as 'active' | 'warning' | 'inactive' | 'error',
     },
     {
       id: View.SERVER,
-      label: 'Server',
+      label: t('nav-server'),
       icon: <Server size={20} />,
       status: serverSidebarStatus as 'active' | 'warning' | 'inactive' | 'error',
     },
     {
       id: View.LOGS,
-      label: 'Logs',
+      label: t('nav-logs'),
       icon: <Terminal size={20} />,
     },
   ];
 
   // Sub-items shown indented below the Notebook nav item
   const notebookSubItems = [
-    { id: NotebookTab.SEARCH, icon: <Search size={14} />, label: 'Search' },
-    { id: NotebookTab.IMPORT, icon: <Upload size={14} />, label: 'Import' },
+    { id: NotebookTab.SEARCH, icon: <Search size={14} />, label: t('nav-search') },
+    { id: NotebookTab.IMPORT, icon: <Upload size={14} />, label: t('nav-import') },
   ];
 
   // Sub-items shown indented below the Session nav item
-  const sessionSubItems = [{ id: SessionTab.IMPORT, icon: <Upload size={14} />, label: 'Import' }];
+  const sessionSubItems = [
+    { id: SessionTab.IMPORT, icon: <Upload size={14} />, label: t('nav-import') },
+  ];
 
   const activeIndex = navItems.findIndex((item) => item.id === currentView);
 
@@ -436,10 +443,20 @@ export const Sidebar: React.FC<SidebarProps> = ({
               : 'text-slate-400 hover:bg-white/5 hover:text-white'
           }`}
         >
-          <Bell
-            size={20}
-            className={currentView === View.NOTIFICATIONS ? 'text-accent-cyan' : ''}
-          />
+          <span className="relative">
+            <Bell
+              size={20}
+              className={currentView === View.NOTIFICATIONS ? 'text-accent-cyan' : ''}
+            />
+            {unreadNotifications > 0 && currentView !== View.NOTIFICATIONS && (
+              <span
+                className="bg-accent-cyan absolute -top-1.5 -right-2 min-w-4 rounded-full px-1 text-center text-[9px] leading-4 font-bold text-black"
+                title={`${unreadNotifications} unread`}
+              >
+                {unreadNotifications > 99 ? '99+' : unreadNotifications}
+              </span>
+            )}
+          </span>
           <span
             className={`text-sm font-medium whitespace-nowrap transition-all duration-200 ${collapsed ? 'hidden w-0 opacity-0' : 'opacity-100'}`}
           >
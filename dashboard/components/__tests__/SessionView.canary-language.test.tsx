@@ -175,7 +175,10 @@ vi.mock('../../src/services/modelSelection', () => ({
   isModelDisabled: () => false,
 }));
 
-vi.mock('../../src/hooks/useClipboard', () => ({ writeToClipboard: vi.fn() }));
+vi.mock('../../src/hooks/useClipboard', () => ({
+  writeToClipboard: vi.fn(),
+  writeTranscriptToClipboard: vi.fn(),
+}));
 vi.mock('../../src/services/clientDebugLog', () => ({ logClientEvent: vi.fn() }));
 
 vi.mock('sonner', () => ({
@@ -215,10 +218,12 @@ const baseLiveState = {
   error: null,
   analyser: null,
   muted: false,
+  streamingMode: 'accurate' as const,
   start: vi.fn(),
   stop: vi.fn(),
   toggleMute: vi.fn(),
   setGain: vi.fn(),
+  setStreamingMode: vi.fn(),
   clearHistory: vi.fn(),
   getText: vi.fn().mockReturnValue(''),
 };
@@ -165,6 +165,7 @@ vi.mock('../../src/services/modelSelection', () => ({
 
 vi.mock('../../src/hooks/useClipboard', () => ({
   writeToClipboard: vi.fn(),
+  writeTranscriptToClipboard: vi.fn(),
 }));
 
 vi.mock('../../src/services/clientDebugLog', () => ({
@@ -209,14 +210,17 @@ const baseLiveState = {
   status: 'idle' as const,
   sentences: [],
   partial: '',
+  alerts: [],
   statusMessage: null,
   error: null,
   analyser: null,
   muted: false,
+  streamingMode: 'accurate' as const,
   start: vi.fn(),
   stop: vi.fn(),
   toggleMute: vi.fn(),
   setGain: vi.fn(),
+  setStreamingMode: vi.fn(),
   clearHistory: vi.fn(),
   getText: vi.fn().mockReturnValue(''),
 };
@@ -484,14 +488,15 @@ describe('Start Recording disabled-reason surface', () => {
   // machines — see the `canStartRecording` derivation in SessionView.tsx).
 
   // `LiveStatus` positive set (`isLive === true`): 'connecting' | 'starting' |
-  // 'listening' | 'processing' (defined in dashboard/src/hooks/useLiveMode.ts).
+  // 'listening' | 'processing' | 'reconnecting' (defined in
+  // dashboard/src/hooks/useLiveMode.ts).
   // Parameterize across the full set so a future addition or exclusion is
   // caught — covers the most-common WS-handshake transients ('connecting',
   // 'starting') AND the steady-state ('listening') AND the post-utterance
   // pause ('processing'). Each case also asserts the warning↔disablement
   // coupling so a regression that surfaced the warning while leaving the
   // button enabled (or vice versa) does NOT pass silently.
-  it.each(['connecting', 'starting', 'listening', 'processing'] as const)(
+  it.each(['connecting', 'starting', 'listening', 'processing', 'reconnecting'] as const)(
     'shows "Live Mode is active" warning AND disables Start button when live.status is "%s"',
     (status) => {
       const props = {
@@ -0,0 +1,344 @@
+/**
+ * CloudImportCard — browse an S3 bucket, Google Drive or Dropbox folder and
+ * queue media from it like a local import.
+ *
+ * Listing, sign-in and downloads all run in the Electron main process
+ * (electron/cloudImport.ts): Drive and Dropbox sign in through the system
+ * browser, credentials stay sealed in the OS keychain, and files stream into
+ * the local cache before their paths are handed to `onImport`.
+ */
+
+import { useCallback, useEffect, useState } from 'react';
+
+import { ChevronLeft, Cloud, FileAudio, Folder, LogIn, Plus, Trash2 } from 'lucide-react';
+import { toast } from 'sonner';
+
+import { Button } from '../ui/Button';
+import { CustomSelect } from '../ui/CustomSelect';
+import { GlassCard } from '../ui/GlassCard';
+
+export interface CloudImportCardProps {
+  /** Local paths of the downloaded files, ready for the import queue. */
+  onImport: (paths: string[]) => void;
+}
+
+const PROVIDER_LABELS: Record<CloudProvider, string> = {
+  s3: 'Amazon S3 / compatible',
+  gdrive: 'Google Drive',
+  dropbox: 'Dropbox',
+};
+
+const EMPTY_FORM = {
+  provider: 's3' as CloudProvider,
+  label: '',
+  bucket: '',
+  region: '',
+  endpoint: '',
+  prefix: '',
+  accessKeyId: '',
+  secretAccessKey: '',
+  clientId: '',
+  clientSecret: '',
+};
+
+function formatSize(bytes?: number | null): string {
+  if (bytes == null) return '';
+  if (bytes < 1024 * 1024) return `${Math.max(1, Math.round(bytes / 1024))} KB`;
+  if (bytes < 1024 ** 3) return `${(bytes / 1024 ** 2).toFixed(1)} MB`;
+  return `${(bytes / 1024 ** 3).toFixed(2)} GB`;
+}
+
+function errorText(err: unknown): string {
+  const message = err instanceof Error ? err.message : String(err);
+  // ipcRenderer.invoke wraps main-process errors in a generic prefix.
+  return message.replace(/^Error invoking remote method '[^']+': (Error: )?/, '');
+}
+
+export function CloudImportCard({ onImport }: CloudImportCardProps) {
+  const api = window.electronAPI?.cloud;
+  const [sources, setSources] = useState<CloudSourceState[]>([]);
+  const [sourceId, setSourceId] = useState('');
+  const [trail, setTrail] = useState<{ id?: string; name: string }[]>([]);
+  const [entries, setEntries] = useState<CloudEntry[]>([]);
+  const [selected, setSelected] = useState<Set<string>>(new Set());
+  const [loading, setLoading] = useState(false);
+  const [downloading, setDownloading] = useState(false);
+  const [progress, setProgress] = useState<Record<string, CloudDownloadProgress>>({});
+  const [form, setForm] = useState<typeof EMPTY_FORM | null>(null);
+
+  const source = sources.find((s) => s.id === sourceId);
+
+  const refreshSources = useCallback(async () => {
+    if (!api) return;
+    const list = await api.listSources();
+    setSources(list);
+    setSourceId((current) =>
+      list.some((s) => s.id === current) ? current : (list[0]?.id ?? ''),
+    );
+  }, [api]);
+
+  useEffect(() => {
+    void refreshSources();
+  }, [refreshSources]);
+
+  useEffect(() => {
+    if (!api) return;
+    return api.onDownloadProgress((p) => setProgress((prev) => ({ ...prev, [p.entryId]: p })));
+  }, [api]);
+
+  const folderId = trail.at(-1)?.id;
+
+  useEffect(() => {
+    setSelected(new Set());
+    if (!api || !source?.connected) {
+      setEntries([]);
+      return;
+    }
+    let cancelled = false;
+    setLoading(true);
+    api
+      .browse(source.id, folderId)
+      .then((list) => !cancelled && setEntries(list))
+      .catch((err) => {
+        if (cancelled) return;
+        setEntries([]);
+        toast.error(`Could not list ${source.label}`, { description: errorText(err) });
+      })
+      .finally(() => !cancelled && setLoading(false));
+    return () => {
+      cancelled = true;
+    };
+  }, [api, source?.id, source?.connected, source?.label, folderId]);
+
+  if (!api) return null;
+
+  const handleSelectSource = (id: string) => {
+    setSourceId(id);
+    setTrail([]);
+  };
+
+  const handleSave = async () => {
+    if (!form) return;
+    const { secretAccessKey, clientSecret, ...fields } = form;
+    try {
+      const saved = await api.saveSource(fields, { secretAccessKey, clientSecret });
+      setForm(null);
+      await refreshSources();
+      handleSelectSource(saved.id);
+    } catch (err) {
+      toast.error('Could not save the cloud source', { description: errorText(err) });
+    }
+  };
+
+  const handleConnect = async () => {
+    if (!source) return;
+    try {
+      await api.connect(source.id);
+      await refreshSources();
+      toast.success(`Signed in to ${source.label}`);
+    } catch (err) {
+      toast.error('Sign-in failed', { description: errorText(err) });
+    }
+  };
+
+  const handleRemove = async () => {
+    if (!source) return;
+    await api.removeSource(source.id);
+    setTrail([]);
+    await refreshSources();
+  };
+
+  const toggle = (id: string) =>
+    setSelected((prev) => {
+      const next = new Set(prev);
+      if (next.has(id)) next.delete(id);
+      else next.add(id);
+      return next;
+    });
+
+  const handleImport = async () => {
+    if (!source || selected.size === 0) return;
+    const picked = entries.filter((e) => selected.has(e.id));
+    setDownloading(true);
+    setProgress({});
+    try {
+      const paths = await api.download(source.id, picked);
+      onImport(paths);
+      setSelected(new Set());
+    } catch (err) {
+      toast.error('Cloud download failed', { description: errorText(err) });
+    } finally {
+      setDownloading(false);
+    }
+  };
+
+  const inputClass =
+    'w-full rounded-lg border border-white/10 bg-white/5 px-3 py-2 text-sm text-slate-300 outline-none focus:border-accent-cyan/50';
+  const field = (key: keyof typeof EMPTY_FORM, placeholder: string, type = 'text') => (
+    <input
+      type={type}
+      value={form?.[key] ?? ''}
+      placeholder={placeholder}
+      aria-label={placeholder}
+      className={inputClass}
+      onChange={(e) => setForm((f) => (f ? { ...f, [key]: e.target.value } : f))}
+    />
+  );
+
+  const active = Object.values(progress).filter((p) => !p.done);
+
+  return (
+    <GlassCard title="Import from Cloud">
+      <div className="space-y-4">
+        <div className="flex items-center gap-2">
+          {sources.length > 0 ? (
+            <CustomSelect
+              value={sourceId}
+              onChange={handleSelectSource}
+              options={sources.map((s) => s.id)}
+              optionLabel={Object.fromEntries(sources.map((s) => [s.id, s.label]))}
+              optionDescription={Object.fromEntries(
+                sources.map((s) => [s.id, PROVIDER_LABELS[s.provider]]),
+              )}
+              className="flex-1"
+              aria-label="Cloud source"
+            />
+          ) : (
+            <p className="flex-1 text-xs text-slate-400">
+              Add an S3 bucket, Google Drive or Dropbox to import media straight from it.
+            </p>
+          )}
+          {source && !source.connected && source.provider !== 's3' && (
+            <Button variant="secondary" size="sm" onClick={() => void handleConnect()}>
+              <LogIn size={14} className="mr-1.5" />
+              Sign in
+            </Button>
+          )}
+          {source && (
+            <Button variant="ghost" size="sm" title="Remove source" onClick={handleRemove}>
+              <Trash2 size={14} />
+            </Button>
+          )}
+          <Button variant="secondary" size="sm" onClick={() => setForm({ ...EMPTY_FORM })}>
+            <Plus size={14} className="mr-1.5" />
+            Add
+          </Button>
+        </div>
+
+        {form && (
+          <div className="space-y-2 rounded-xl border border-white/10 bg-white/5 p-3">
+            <CustomSelect
+              value={form.provider}
+              onChange={(provider) => setForm({ ...form, provider: provider as CloudProvider })}
+              options={Object.keys(PROVIDER_LABELS)}
+              optionLabel={PROVIDER_LABELS}
+              aria-label="Cloud provider"
+            />
+            {field('label', 'Name (optional)')}
+            {form.provider === 's3' ? (
+              <>
+                <div className="flex gap-2">
+                  {field('bucket', 'Bucket')}
+                  {field('region', 'Region (us-east-1)')}
+                </div>
+                {field('endpoint', 'Endpoint for MinIO / R2 / B2 (optional)')}
+                {field('prefix', 'Folder prefix (optional)')}
+                {field('accessKeyId', 'Access key ID')}
+                {field('secretAccessKey', 'Secret access key', 'password')}
+              </>
+            ) : (
+              <>
+                {field('clientId', 'OAuth client ID')}
+                {field('clientSecret', 'OAuth client secret (if issued)', 'password')}
+                <p className="text-xs text-slate-500">
+                  Register a desktop OAuth app with the provider; sign-in opens your browser.
+                </p>
+              </>
+            )}
+            <div className="flex justify-end gap-2">
+              <Button variant="ghost" size="sm" onClick={() => setForm(null)}>
+                Cancel
+              </Button>
+              <Button variant="primary" size="sm" onClick={() => void handleSave()}>
+                Save
+              </Button>
+            </div>
+          </div>
+        )}
+
+        {source?.connected && (
+          <div className="space-y-2">
+            <div className="flex items-center gap-2 text-xs text-slate-400">
+              <button
+                disabled={trail.length === 0}
+                onClick={() => setTrail((t) => t.slice(0, -1))}
+                className="rounded p-1 transition-colors hover:bg-white/10 disabled:opacity-30"
+                title="Up one folder"
+              >
+                <ChevronLeft size={14} />
+              </button>
+              <Cloud size={12} />
+              <span className="truncate">
+                {[source.label, ...trail.map((t) => t.name)].join(' / ')}
+              </span>
+            </div>
+            <div className="max-h-64 overflow-y-auto rounded-lg border border-white/10">
+              {loading ? (
+                <p className="p-3 text-xs text-slate-500">Loading…</p>
+              ) : entries.length === 0 ? (
+                <p className="p-3 text-xs text-slate-500">No folders or media files here.</p>
+              ) : (
+                entries.map((entry) => {
+                  const p = progress[entry.id];
+                  return entry.isFolder ? (
+                    <button
+                      key={entry.id}
+                      onClick={() => setTrail((t) => [...t, { id: entry.id, name: entry.name }])}
+                      className="flex w-full items-center gap-2 px-3 py-1.5 text-left text-sm text-slate-300 hover:bg-white/5"
+                    >
+                      <Folder size={14} className="text-accent-cyan shrink-0" />
+                      <span className="truncate">{entry.name}</span>
+                    </button>
+                  ) : (
+                    <label
+                      key={entry.id}
+                      className="flex cursor-pointer items-center gap-2 px-3 py-1.5 text-sm text-slate-300 hover:bg-white/5"
+                    >
+                      <input
+                        type="checkbox"
+                        checked={selected.has(entry.id)}
+                        onChange={() => toggle(entry.id)}
+                        disabled={downloading}
+                      />
+                      <FileAudio size={14} className="shrink-0 text-slate-400" />
+                      <span className="min-w-0 flex-1 truncate">{entry.name}</span>
+                      <span className="shrink-0 text-xs text-slate-500">
+                        {p && !p.done && p.total
+                          ? `${Math.round((p.received / p.total) * 100)}%`
+                          : formatSize(entry.size)}
+                      </span>
+                    </label>
+                  );
+                })
+              )}
+            </div>
+            <div className="flex items-center justify-between gap-3">
+              <span className="truncate text-xs text-slate-500">
+                {active.length > 0
+                  ? `Downloading ${active[0].name} — ${formatSize(active[0].received)}`
+                  : `${selected.size} selected`}
+              </span>
+              <Button
+                variant="primary"
+                disabled={selected.size === 0 || downloading}
+                onClick={() => void handleImport()}
+              >
+                {downloading ? 'Downloading…' : 'Import'}
+              </Button>
+            </div>
+          </div>
+        )}
+      </div>
+    </GlassCard>
+  );
+}
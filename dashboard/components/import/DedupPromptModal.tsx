@@ -22,6 +22,24 @@ import type { DedupMatch } from '../../src/api/types';
 
 export type DedupChoice = 'use_existing' | 'create_new' | 'cancel';
 
+function formatOffset(seconds: number): string {
+  const total = Math.round(Math.abs(seconds));
+  const m = Math.floor(total / 60);
+  const s = String(total % 60).padStart(2, '0');
+  return `${m}:${s}`;
+}
+
+/** How a fingerprint match differs from the recording it matched, if at all. */
+function describeCopy(match: DedupMatch): string | null {
+  if (match.match !== 'fingerprint') return null;
+  const offset = match.offset_seconds ?? 0;
+  if (Math.abs(offset) < 1) return 'It is the same audio in a different encoding.';
+  const shifted = 'the transcript will be shifted to match';
+  return offset > 0
+    ? `It is a trimmed copy starting ${formatOffset(offset)} into it; ${shifted}.`
+    : `It is the same audio with ${formatOffset(offset)} of extra lead-in; ${shifted}.`;
+}
+
 export interface DedupPromptModalProps {
   open: boolean;
   match: DedupMatch | null;
@@ -35,6 +53,7 @@ export function DedupPromptModal({ open, match, onChoice }: DedupPromptModalProp
   const useExistingRef = useRef<HTMLButtonElement | null>(null);
 
   if (!match) return null;
+  const copyNote = describeCopy(match);
 
   const formattedDate = (() => {
     if (!match.created_at) return '';
@@ -74,6 +93,7 @@ export function DedupPromptModal({ open, match, onChoice }: DedupPromptModalProp
               ) : null}
               .
             </p>
+            {copyNote ? <p className="mt-2 text-slate-400">{copyNote}</p> : null}
             <p className="mt-2">Use the existing transcript, or create a new entry?</p>
           </div>
           <div className="flex justify-end gap-3 border-t border-white/10 bg-white/5 px-6 py-4 select-none">
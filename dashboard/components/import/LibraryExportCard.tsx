@@ -0,0 +1,211 @@
+/**
+ * LibraryExportCard — dump the whole notebook (or a date / title slice of
+ * it) to SRT, TXT and friends in one go, for moving to another tool.
+ *
+ * The server plans every file with the output manager's filename template
+ * and collision policy, then streams a progress event per file. Cancel asks
+ * the server to stop after the current file; files already written stay.
+ */
+
+import { useRef, useState } from 'react';
+
+import { FolderOpen, Square } from 'lucide-react';
+import { toast } from 'sonner';
+
+import { apiClient } from '../../src/api/client';
+import type { LibraryExportEvent } from '../../src/api/types';
+import { Button } from '../ui/Button';
+import { GlassCard } from '../ui/GlassCard';
+
+// `plaintext` rather than `txt`: the verbose TXT export refuses diarized recordings.
+const FORMATS: [format: string, label: string][] = [
+  ['plaintext', 'TXT'],
+  ['srt', 'SRT'],
+  ['ass', 'ASS'],
+  ['html', 'HTML'],
+  ['summary', 'Summary'],
+];
+
+type Progress = Extract<LibraryExportEvent, { type: 'progress' }>;
+type Summary = Extract<LibraryExportEvent, { type: 'done' }>;
+
+export function summarizeLibraryExport(summary: Summary): string {
+  const parts = [`${summary.written} written`];
+  if (summary.skipped) parts.push(`${summary.skipped} skipped`);
+  if (summary.unsupported) parts.push(`${summary.unsupported} not available in that format`);
+  if (summary.failed) parts.push(`${summary.failed} failed`);
+  return parts.join(', ');
+}
+
+const inputClass =
+  'focus:border-accent-cyan/50 rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white focus:outline-none';
+
+export function LibraryExportCard() {
+  const [formats, setFormats] = useState<string[]>(['plaintext', 'srt']);
+  const [startDate, setStartDate] = useState('');
+  const [endDate, setEndDate] = useState('');
+  const [title, setTitle] = useState('');
+  const [dest, setDest] = useState('');
+  const [running, setRunning] = useState(false);
+  const [total, setTotal] = useState(0);
+  const [last, setLast] = useState<Progress | null>(null);
+  const abortRef = useRef<AbortController | null>(null);
+
+  const toggleFormat = (format: string) =>
+    setFormats((prev) =>
+      prev.includes(format) ? prev.filter((f) => f !== format) : [...prev, format],
+    );
+
+  const handleBrowse = async () => {
+    const folder = await window.electronAPI?.fileIO?.selectFolder();
+    if (folder) setDest(folder);
+  };
+
+  const handleExport = async () => {
+    const controller = new AbortController();
+    abortRef.current = controller;
+    setRunning(true);
+    setTotal(0);
+    setLast(null);
+    try {
+      const events = apiClient.exportLibrary(
+        {
+          formats,
+          start_date: startDate || undefined,
+          end_date: endDate || undefined,
+          title: title.trim() || undefined,
+          dest: dest.trim() || undefined,
+        },
+        controller.signal,
+      );
+      for await (const event of events) {
+        if (event.type === 'start') setTotal(event.total);
+        else if (event.type === 'progress') setLast(event);
+        else {
+          const message = summarizeLibraryExport(event);
+          if (event.cancelled) toast.info(`Library export cancelled — ${message}`);
+          else if (event.failed) toast.warning(`Library export finished — ${message}`);
+          else toast.success(`Library export finished — ${message}`);
+        }
+      }
+    } catch (err) {
+      if (!controller.signal.aborted) {
+        toast.error('Library export failed', {
+          description: err instanceof Error ? err.message : String(err),
+        });
+      }
+    } finally {
+      abortRef.current = null;
+      setRunning(false);
+    }
+  };
+
+  const handleCancel = () => {
+    // Graceful stop first; dropping the stream stops the server as well.
+    apiClient.cancelLibraryExport().catch(() => abortRef.current?.abort());
+  };
+
+  const done = last?.done ?? 0;
+
+  return (
+    <GlassCard title="Export Library">
+      <div className="space-y-4">
+        <p className="text-xs text-slate-400">
+          Write every recording — or those matching the filters — to files named by the output
+          template. The folder is on the server machine.
+        </p>
+        <div className="flex flex-wrap gap-2">
+          {FORMATS.map(([format, label]) => (
+            <button
+              key={format}
+              type="button"
+              onClick={() => toggleFormat(format)}
+              aria-pressed={formats.includes(format)}
+              disabled={running}
+              className={`rounded-lg border px-3 py-1 text-xs transition-colors ${formats.includes(format) ? 'border-accent-cyan/50 bg-accent-cyan/10 text-accent-cyan' : 'border-white/10 text-slate-400 hover:text-white'}`}
+            >
+              {label}
+            </button>
+          ))}
+        </div>
+        <div className="flex flex-wrap items-center gap-2">
+          <input
+            type="date"
+            value={startDate}
+            onChange={(e) => setStartDate(e.target.value)}
+            disabled={running}
+            aria-label="From date"
+            className="rounded border border-white/10 bg-black/20 px-2 py-1 text-xs text-slate-300"
+          />
+          <span className="text-sm text-slate-500">-</span>
+          <input
+            type="date"
+            value={endDate}
+            onChange={(e) => setEndDate(e.target.value)}
+            disabled={running}
+            aria-label="To date"
+            className="rounded border border-white/10 bg-black/20 px-2 py-1 text-xs text-slate-300"
+          />
+          <input
+            type="text"
+            value={title}
+            onChange={(e) => setTitle(e.target.value)}
+            disabled={running}
+            placeholder="Title contains…"
+            className={`${inputClass} min-w-0 flex-1 py-1 text-xs`}
+          />
+        </div>
+        <div className="flex gap-2">
+          <input
+            type="text"
+            value={dest}
+            onChange={(e) => setDest(e.target.value)}
+            disabled={running}
+            placeholder="Destination folder (default: output manager folders)"
+            className={`${inputClass} min-w-0 flex-1 font-mono`}
+          />
+          {window.electronAPI?.fileIO?.selectFolder && (
+            <Button
+              variant="ghost"
+              size="sm"
+              icon={<FolderOpen size={14} />}
+              disabled={running}
+              onClick={() => void handleBrowse()}
+            >
+              Browse
+            </Button>
+          )}
+        </div>
+        {running && (
+          <div className="space-y-1.5">
+            <div className="h-1.5 overflow-hidden rounded-full bg-white/10">
+              <div
+                className="bg-accent-cyan h-full rounded-full transition-all"
+                style={{ width: `${total ? Math.round((done / total) * 100) : 0}%` }}
+              />
+            </div>
+            <p className="truncate text-xs text-slate-500">
+              {done} / {total}
+              {last ? ` — ${last.filename} (${last.status})` : ''}
+            </p>
+          </div>
+        )}
+        <div className="flex justify-end">
+          {running ? (
+            <Button variant="ghost" icon={<Square size={14} />} onClick={handleCancel}>
+              Cancel
+            </Button>
+          ) : (
+            <Button
+              variant="primary"
+              disabled={formats.length === 0}
+              onClick={() => void handleExport()}
+            >
+              Export
+            </Button>
+          )}
+        </div>
+      </div>
+    </GlassCard>
+  );
+}
@@ -0,0 +1,86 @@
+/**
+ * PhoneIngestCard — pair a phone by QR code so its browser can send voice
+ * memos straight into the Notebook import queue over the local network.
+ *
+ * The upload server runs in the Electron main process (electron/phoneIngest.ts);
+ * received files reach the queue through usePhoneIngestBridge, so this card
+ * only controls the server and shows the pairing link.
+ */
+
+import { useEffect, useState } from 'react';
+
+import { RefreshCw, Smartphone } from 'lucide-react';
+import { toast } from 'sonner';
+
+import { AppleSwitch } from '../ui/AppleSwitch';
+import { Button } from '../ui/Button';
+import { GlassCard } from '../ui/GlassCard';
+
+export function PhoneIngestCard() {
+  const api = window.electronAPI?.phoneIngest;
+  const [status, setStatus] = useState<PhoneIngestStatus | null>(null);
+  const [busy, setBusy] = useState(false);
+
+  useEffect(() => {
+    void api?.getStatus().then(setStatus);
+  }, [api]);
+
+  if (!api) return null;
+
+  const handleToggle = async (enabled: boolean) => {
+    setBusy(true);
+    try {
+      setStatus(await api.setEnabled(enabled));
+    } catch (err) {
+      toast.error('Could not start the phone upload server', {
+        description: err instanceof Error ? err.message : String(err),
+      });
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleReset = async () => {
+    setStatus(await api.resetPairing());
+    toast.success('Pairing reset — scan the new code on your phone');
+  };
+
+  return (
+    <GlassCard title="Send from Phone">
+      <div className="space-y-4">
+        <div className="flex items-center justify-between gap-3">
+          <p className="text-xs text-slate-400">
+            Upload voice memos from a phone on the same network — no app or cable needed.
+          </p>
+          <AppleSwitch
+            checked={status?.running ?? false}
+            onChange={(enabled) => void handleToggle(enabled)}
+            disabled={busy}
+          />
+        </div>
+
+        {status?.running && status.url && status.qrSvg && (
+          <div className="flex items-center gap-4">
+            <img
+              src={`data:image/svg+xml;utf8,${encodeURIComponent(status.qrSvg)}`}
+              alt="Pairing QR code"
+              className="h-36 w-36 shrink-0 rounded-lg"
+            />
+            <div className="min-w-0 space-y-2 text-xs text-slate-400">
+              <p className="flex items-center gap-1.5 text-slate-300">
+                <Smartphone size={14} />
+                Scan with your phone camera
+              </p>
+              <p className="break-all text-slate-500 select-all">{status.url}</p>
+              <p>Anyone with this link can send files. Reset it to unpair every phone.</p>
+              <Button variant="ghost" size="sm" onClick={() => void handleReset()}>
+                <RefreshCw size={14} className="mr-1.5" />
+                Reset pairing
+              </Button>
+            </div>
+          </div>
+        )}
+      </div>
+    </GlassCard>
+  );
+}
@@ -0,0 +1,123 @@
+/**
+ * SubtitleImportCard — bring existing captions (SRT / VTT / ASS) into the
+ * notebook alongside their media file.
+ *
+ * The server stores the pair as a normal recording (segments + words), so
+ * the editor, search and all exporters work on it. "Align to audio" asks the
+ * server to re-time the cue text with its forced aligner; without it, word
+ * timings are spread inside each cue.
+ */
+
+import { useRef, useState } from 'react';
+
+import { FileAudio, FileText } from 'lucide-react';
+import { toast } from 'sonner';
+
+import { apiClient } from '../../src/api/client';
+import { AppleSwitch } from '../ui/AppleSwitch';
+import { Button } from '../ui/Button';
+import { GlassCard } from '../ui/GlassCard';
+
+export interface SubtitleImportCardProps {
+  language?: string;
+  onImported?: (recordingId: number) => void;
+}
+
+const MEDIA_ACCEPT = '.mp3,.wav,.m4a,.flac,.ogg,.webm,.opus,.mp4,.mkv,.mov';
+const SUBTITLE_ACCEPT = '.srt,.vtt,.ass,.ssa';
+
+export function SubtitleImportCard({ language, onImported }: SubtitleImportCardProps) {
+  const mediaInputRef = useRef<HTMLInputElement>(null);
+  const subtitleInputRef = useRef<HTMLInputElement>(null);
+  const [media, setMedia] = useState<File | null>(null);
+  const [subtitles, setSubtitles] = useState<File | null>(null);
+  const [align, setAlign] = useState(false);
+  const [busy, setBusy] = useState(false);
+
+  const handleImport = async () => {
+    if (!media || !subtitles) return;
+    setBusy(true);
+    try {
+      const result = await apiClient.importSubtitles(media, subtitles, {
+        align,
+        language,
+        file_created_at: new Date(media.lastModified).toISOString(),
+      });
+      toast.success(`Imported ${result.cues} cues — ID ${result.recording_id}`, {
+        description:
+          align && result.alignment === 'proportional'
+            ? 'No aligner available on the server — cue timings were kept as-is.'
+            : undefined,
+      });
+      setMedia(null);
+      setSubtitles(null);
+      onImported?.(result.recording_id);
+    } catch (err) {
+      toast.error('Subtitle import failed', {
+        description: err instanceof Error ? err.message : String(err),
+      });
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const pickerClass =
+    'hover:bg-accent-cyan/10 hover:text-accent-cyan flex min-w-0 flex-1 items-center gap-2 rounded-lg border border-white/10 px-3 py-2 text-sm text-slate-400 transition-colors';
+
+  return (
+    <GlassCard title="Import Subtitles">
+      <input
+        ref={mediaInputRef}
+        type="file"
+        accept={MEDIA_ACCEPT}
+        className="hidden"
+        onChange={(e) => {
+          setMedia(e.target.files?.[0] ?? null);
+          e.target.value = '';
+        }}
+      />
+      <input
+        ref={subtitleInputRef}
+        type="file"
+        accept={SUBTITLE_ACCEPT}
+        className="hidden"
+        onChange={(e) => {
+          setSubtitles(e.target.files?.[0] ?? null);
+          e.target.value = '';
+        }}
+      />
+      <div className="space-y-4">
+        <p className="text-xs text-slate-400">
+          Add an existing SRT, VTT or ASS file with its media to the notebook without
+          re-transcribing.
+        </p>
+        <div className="flex gap-3">
+          <button className={pickerClass} onClick={() => mediaInputRef.current?.click()}>
+            <FileAudio size={14} className="shrink-0" />
+            <span className="truncate">{media?.name ?? 'Choose media…'}</span>
+          </button>
+          <button className={pickerClass} onClick={() => subtitleInputRef.current?.click()}>
+            <FileText size={14} className="shrink-0" />
+            <span className="truncate">{subtitles?.name ?? 'Choose subtitles…'}</span>
+          </button>
+        </div>
+        <div className="flex items-center justify-between gap-3">
+          <AppleSwitch
+            checked={align}
+            onChange={setAlign}
+            label="Align to audio"
+            description="Re-time the captions against the speech (slower)"
+            size="sm"
+          />
+          <Button
+            variant="primary"
+            disabled={!media || !subtitles || busy}
+            onClick={() => void handleImport()}
+          >
+            {busy ? 'Importing…' : 'Import'}
+          </Button>
+        </div>
+      </div>
+    </GlassCard>
+  );
+}
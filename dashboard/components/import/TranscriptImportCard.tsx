@@ -0,0 +1,186 @@
+/**
+ * TranscriptImportCard — bring transcripts made with other tools (Otter,
+ * Whisper, YouTube, Descript, plain subtitles) into the notebook.
+ *
+ * The server detects the format and stores the result as a normal
+ * recording. Media is optional: without it the recording is text-only but
+ * still searchable and exportable. Imported files keep their file dates so
+ * the calendar shows them where they happened. A `.tsproj` project bundle
+ * (exported from any notebook) goes in whole instead — audio, edits, notes
+ * and redactions included.
+ */
+
+import { useRef, useState } from 'react';
+
+import { FileAudio, FileText, X } from 'lucide-react';
+import { toast } from 'sonner';
+
+import { apiClient } from '../../src/api/client';
+import type { TranscriptImportFormat } from '../../src/api/types';
+import { AppleSwitch } from '../ui/AppleSwitch';
+import { Button } from '../ui/Button';
+import { GlassCard } from '../ui/GlassCard';
+
+export interface TranscriptImportCardProps {
+  language?: string;
+  onImported?: (recordingId: number) => void;
+}
+
+const MEDIA_ACCEPT = '.mp3,.wav,.m4a,.flac,.ogg,.webm,.opus,.mp4,.mkv,.mov';
+const TRANSCRIPT_ACCEPT = '.json,.txt,.sbv,.srt,.vtt,.ass,.ssa,.tsproj';
+
+const FORMATS: [format: TranscriptImportFormat | '', label: string][] = [
+  ['', 'Detect automatically'],
+  ['otter', 'Otter.ai (TXT)'],
+  ['whisper_json', 'Whisper JSON'],
+  ['sbv', 'YouTube (SBV)'],
+  ['descript', 'Descript (TXT)'],
+  ['srt', 'SRT'],
+  ['vtt', 'WebVTT'],
+  ['ass', 'ASS'],
+];
+
+export function TranscriptImportCard({ language, onImported }: TranscriptImportCardProps) {
+  const transcriptInputRef = useRef<HTMLInputElement>(null);
+  const mediaInputRef = useRef<HTMLInputElement>(null);
+  const [transcript, setTranscript] = useState<File | null>(null);
+  const [media, setMedia] = useState<File | null>(null);
+  const [format, setFormat] = useState<TranscriptImportFormat | ''>('');
+  const [align, setAlign] = useState(false);
+  const [busy, setBusy] = useState(false);
+  const isProject = transcript?.name.toLowerCase().endsWith('.tsproj') ?? false;
+
+  const importProject = async (bundle: File): Promise<number> => {
+    const result = await apiClient.importProjectBundle(bundle);
+    const description = result.has_audio
+      ? undefined
+      : result.media === 'reference'
+        ? 'Text only — the referenced audio was not found here.'
+        : 'Text only — the project has no audio.';
+    toast.success(`Imported project (${result.segments} segments) — ID ${result.recording_id}`, {
+      description,
+    });
+    return result.recording_id;
+  };
+
+  const handleImport = async () => {
+    if (!transcript) return;
+    setBusy(true);
+    try {
+      if (isProject) {
+        const recordingId = await importProject(transcript);
+        setTranscript(null);
+        onImported?.(recordingId);
+        return;
+      }
+      const result = await apiClient.importTranscript(transcript, {
+        media,
+        format: format || undefined,
+        align: align && media !== null,
+        language,
+        recorded_at: new Date((media ?? transcript).lastModified).toISOString(),
+      });
+      const label = FORMATS.find(([f]) => f === result.format)?.[1] ?? result.format;
+      toast.success(
+        `Imported ${result.segments} segments from ${label} — ID ${result.recording_id}`,
+        { description: result.has_audio ? undefined : 'Text only — no audio was attached.' },
+      );
+      setTranscript(null);
+      setMedia(null);
+      onImported?.(result.recording_id);
+    } catch (err) {
+      toast.error('Transcript import failed', {
+        description: err instanceof Error ? err.message : String(err),
+      });
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const pickerClass =
+    'hover:bg-accent-cyan/10 hover:text-accent-cyan flex min-w-0 flex-1 items-center gap-2 rounded-lg border border-white/10 px-3 py-2 text-sm text-slate-400 transition-colors';
+
+  return (
+    <GlassCard title="Import Transcripts">
+      <input
+        ref={transcriptInputRef}
+        type="file"
+        accept={TRANSCRIPT_ACCEPT}
+        className="hidden"
+        onChange={(e) => {
+          setTranscript(e.target.files?.[0] ?? null);
+          e.target.value = '';
+        }}
+      />
+      <input
+        ref={mediaInputRef}
+        type="file"
+        accept={MEDIA_ACCEPT}
+        className="hidden"
+        onChange={(e) => {
+          setMedia(e.target.files?.[0] ?? null);
+          e.target.value = '';
+        }}
+      />
+      <div className="space-y-4">
+        <p className="text-xs text-slate-400">
+          Bring your history from Otter.ai, Whisper, YouTube or Descript. Attaching the audio is
+          optional. Project bundles (.tsproj) import with their audio, edits and notes.
+        </p>
+        <div className="flex gap-3">
+          <button className={pickerClass} onClick={() => transcriptInputRef.current?.click()}>
+            <FileText size={14} className="shrink-0" />
+            <span className="truncate">{transcript?.name ?? 'Choose transcript…'}</span>
+          </button>
+          {!isProject && (
+            <button className={pickerClass} onClick={() => mediaInputRef.current?.click()}>
+              <FileAudio size={14} className="shrink-0" />
+              <span className="truncate">{media?.name ?? 'Add media (optional)…'}</span>
+            </button>
+          )}
+          {media && !isProject && (
+            <button
+              onClick={() => setMedia(null)}
+              title="Import without media"
+              aria-label="Remove media"
+              className="text-slate-500 transition-colors hover:text-white"
+            >
+              <X size={14} />
+            </button>
+          )}
+        </div>
+        <div className="flex flex-wrap items-center justify-between gap-3">
+          <select
+            value={format}
+            disabled={isProject}
+            onChange={(e) => setFormat(e.target.value as TranscriptImportFormat | '')}
+            aria-label="Transcript format"
+            className="rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-slate-300 focus:outline-none"
+          >
+            {FORMATS.map(([value, label]) => (
+              <option key={value || 'auto'} value={value}>
+                {label}
+              </option>
+            ))}
+          </select>
+          {media && !isProject && (
+            <AppleSwitch
+              checked={align}
+              onChange={setAlign}
+              label="Align to audio"
+              description="Re-time the text against the speech (slower)"
+              size="sm"
+            />
+          )}
+          <Button
+            variant="primary"
+            disabled={!transcript || busy}
+            onClick={() => void handleImport()}
+          >
+            {busy ? 'Importing…' : 'Import'}
+          </Button>
+        </div>
+      </div>
+    </GlassCard>
+  );
+}
@@ -65,4 +65,15 @@ describe('DedupPromptModal — content', () => {
     render(<DedupPromptModal open match={sampleMatch} onChoice={vi.fn()} />);
     expect(screen.getByText('Possible duplicate detected')).toBeInTheDocument();
   });
+
+  it('explains where a trimmed copy starts', () => {
+    render(
+      <DedupPromptModal
+        open
+        match={{ ...sampleMatch, source: 'recording', match: 'fingerprint', offset_seconds: 75.4 }}
+        onChoice={vi.fn()}
+      />,
+    );
+    expect(screen.getByText(/trimmed copy starting 1:15 into it/)).toBeInTheDocument();
+  });
 });
@@ -0,0 +1,70 @@
+/**
+ * LibraryExportCard — sends the chosen formats and filters, then reports the
+ * server's `done` counts in a toast.
+ */
+
+import { act, fireEvent, render, screen } from '@testing-library/react';
+import { beforeEach, describe, expect, it, vi } from 'vitest';
+
+import type { LibraryExportEvent, LibraryExportRequest } from '../../../src/api/types';
+
+const exportLibrary = vi.fn();
+const toastSuccess = vi.fn();
+
+vi.mock('../../../src/api/client', () => ({
+  apiClient: {
+    exportLibrary: (...args: unknown[]) => exportLibrary(...args),
+    cancelLibraryExport: vi.fn().mockResolvedValue({ cancelled: true }),
+  },
+}));
+vi.mock('sonner', () => ({
+  toast: { success: (...a: unknown[]) => toastSuccess(...a), info: vi.fn(), warning: vi.fn() },
+}));
+
+import { LibraryExportCard, summarizeLibraryExport } from '../LibraryExportCard';
+
+const DONE: Extract<LibraryExportEvent, { type: 'done' }> = {
+  type: 'done',
+  done: 4,
+  total: 4,
+  cancelled: false,
+  written: 3,
+  skipped: 0,
+  unsupported: 1,
+  failed: 0,
+};
+
+describe('LibraryExportCard', () => {
+  beforeEach(() => {
+    exportLibrary.mockReset();
+    toastSuccess.mockReset();
+  });
+
+  it('summarises only the non-zero counts', () => {
+    expect(summarizeLibraryExport(DONE)).toBe('3 written, 1 not available in that format');
+  });
+
+  it('exports the selected formats with the filters and toasts the result', async () => {
+    exportLibrary.mockImplementation(async function* () {
+      yield { type: 'start', total: 4, recordings: 2 };
+      yield DONE;
+    });
+    render(<LibraryExportCard />);
+
+    fireEvent.click(screen.getByRole('button', { name: 'ASS' }));
+    fireEvent.change(screen.getByPlaceholderText('Title contains…'), {
+      target: { value: ' standup ' },
+    });
+    await act(async () => {
+      fireEvent.click(screen.getByRole('button', { name: 'Export' }));
+    });
+
+    const request = exportLibrary.mock.calls[0][0] as LibraryExportRequest;
+    expect(request.formats).toEqual(['plaintext', 'srt', 'ass']);
+    expect(request.title).toBe('standup');
+    expect(request.dest).toBeUndefined();
+    expect(toastSuccess).toHaveBeenCalledWith(
+      'Library export finished — 3 written, 1 not available in that format',
+    );
+  });
+});
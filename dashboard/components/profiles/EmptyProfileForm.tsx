@@ -8,7 +8,7 @@
  * Tab order is enforced by DOM source order (no tabIndex overrides):
  *   banner-dismiss → name → description → filename template
  *   → destination folder + Choose folder button
- *   → auto-summary toggle → auto-export toggle → Save → Cancel
+ *   → auto-summary toggle → auto-export toggle → PII-scan toggle → Save → Cancel
  */
 
 import React, { useEffect, useId, useState } from 'react';
@@ -42,6 +42,7 @@ export const EmptyProfileForm: React.FC<EmptyProfileFormProps> = ({
   const destId = useId();
   const summaryId = useId();
   const exportId = useId();
+  const piiId = useId();
 
   const pickFolder = useFolderPicker();
 
@@ -51,6 +52,7 @@ export const EmptyProfileForm: React.FC<EmptyProfileFormProps> = ({
   const [destinationFolder, setDestinationFolder] = useState<string>(documentsPathOverride ?? '');
   const [autoSummary, setAutoSummary] = useState<boolean>(false);
   const [autoExport, setAutoExport] = useState<boolean>(false);
+  const [piiScan, setPiiScan] = useState<boolean>(false);
 
   const [bannerVisible, setBannerVisible] = useState<boolean>(true);
   const [submitting, setSubmitting] = useState<boolean>(false);
@@ -114,6 +116,7 @@ export const EmptyProfileForm: React.FC<EmptyProfileFormProps> = ({
           destination_folder: destinationFolder,
           auto_summary_enabled: autoSummary,
           auto_export_enabled: autoExport,
+          pii_scan_enabled: piiScan,
         },
       });
       onCreated(created);
@@ -219,6 +222,17 @@ export const EmptyProfileForm: React.FC<EmptyProfileFormProps> = ({
         />
       </label>
 
+      <label className="flex items-center justify-between gap-3 text-sm">
+        <span>Suggest redactions for personal info on completion</span>
+        <input
+          id={piiId}
+          type="checkbox"
+          checked={piiScan}
+          onChange={(e) => setPiiScan(e.target.checked)}
+          aria-label="Suggest PII redactions"
+        />
+      </label>
+
       {error !== null && (
         <div role="alert" className="text-sm text-red-300">
           {error}
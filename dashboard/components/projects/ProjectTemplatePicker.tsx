@@ -0,0 +1,66 @@
+/**
+ * ProjectTemplatePicker — chooses the project template for the next
+ * notebook imports and edits its fields (pre-filled from the template's
+ * defaults). The parent applies both when it queues files; templates are
+ * managed in Settings → Notebook.
+ */
+
+import React from 'react';
+
+import type { ProjectTemplate } from '../../src/api/types';
+import { templateMetadata } from '../../src/hooks/useProjectTemplates';
+
+interface ProjectTemplatePickerProps {
+  templates: ProjectTemplate[];
+  template: ProjectTemplate | null;
+  metadata: Record<string, string>;
+  onChange: (template: ProjectTemplate | null, metadata: Record<string, string>) => void;
+}
+
+export const ProjectTemplatePicker: React.FC<ProjectTemplatePickerProps> = ({
+  templates,
+  template,
+  metadata,
+  onChange,
+}) => {
+  if (templates.length === 0) return null;
+
+  return (
+    <div className="space-y-2">
+      <label className="flex items-center gap-2 text-xs text-slate-300">
+        <span>Project</span>
+        <select
+          value={template?.id ?? ''}
+          onChange={(e) => {
+            const next = templates.find((t) => t.id === Number(e.target.value)) ?? null;
+            onChange(next, templateMetadata(next, metadata));
+          }}
+          aria-label="Project template"
+          className="rounded bg-white/5 px-2 py-1 text-xs"
+        >
+          <option value="">— none —</option>
+          {templates.map((t) => (
+            <option key={t.id} value={t.id}>
+              {t.name}
+            </option>
+          ))}
+        </select>
+      </label>
+      {template && template.fields.length > 0 && (
+        <div className="grid grid-cols-2 gap-2">
+          {template.fields.map(({ key, label }) => (
+            <input
+              key={key}
+              value={metadata[key] ?? ''}
+              onChange={(e) => onChange(template, { ...metadata, [key]: e.target.value })}
+              placeholder={label}
+              aria-label={label}
+              title={label}
+              className="focus:border-accent-cyan/50 rounded-lg border border-white/10 bg-black/20 px-3 py-1.5 text-sm text-white focus:outline-none"
+            />
+          ))}
+        </div>
+      )}
+    </div>
+  );
+};
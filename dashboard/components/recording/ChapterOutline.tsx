@@ -0,0 +1,64 @@
+/**
+ * Collapsible table of contents for a long recording.
+ *
+ * One row per chapter with its start time; clicking a row seeks there. The
+ * chapter under the playhead is highlighted so the outline doubles as a
+ * "you are here" marker while listening.
+ */
+
+import { useState } from 'react';
+import { ChevronDown, ListTree } from 'lucide-react';
+import type { Chapter } from '../../src/api/types';
+
+interface Props {
+  chapters: Chapter[];
+  activeIndex: number;
+  formatTime: (seconds: number) => string;
+  onSelect: (chapter: Chapter) => void;
+}
+
+export function ChapterOutline({ chapters, activeIndex, formatTime, onSelect }: Props) {
+  const [expanded, setExpanded] = useState(true);
+
+  return (
+    <nav
+      aria-label="Chapters"
+      className="bg-glass-100 overflow-hidden rounded-2xl border border-white/10 select-none"
+    >
+      <button
+        type="button"
+        onClick={() => setExpanded((v) => !v)}
+        aria-expanded={expanded}
+        className="flex w-full items-center gap-2 px-5 py-3 text-slate-400 transition-colors hover:text-white"
+      >
+        <ListTree size={16} className="text-accent-cyan" />
+        <span className="text-xs font-bold tracking-widest uppercase">Outline</span>
+        <span className="text-xs text-slate-500">{chapters.length} chapters</span>
+        <ChevronDown
+          size={14}
+          className={`ml-auto transition-transform ${expanded ? 'rotate-180' : ''}`}
+        />
+      </button>
+      {expanded && (
+        <ol className="custom-scrollbar max-h-64 overflow-y-auto px-2 pb-2">
+          {chapters.map((chapter, i) => (
+            <li key={chapter.id}>
+              <button
+                type="button"
+                onClick={() => onSelect(chapter)}
+                aria-current={i === activeIndex ? 'true' : undefined}
+                title={chapter.source === 'llm' ? 'Titled by the AI model' : undefined}
+                className={`flex w-full items-baseline gap-3 rounded-lg px-3 py-1.5 text-left text-sm transition-colors ${i === activeIndex ? 'bg-accent-cyan/10 text-white' : 'text-slate-300 hover:bg-white/5 hover:text-white'}`}
+              >
+                <span className="w-16 flex-none font-mono text-xs text-slate-500">
+                  {formatTime(chapter.start_time)}
+                </span>
+                <span className="min-w-0 truncate">{chapter.title}</span>
+              </button>
+            </li>
+          ))}
+        </ol>
+      )}
+    </nav>
+  );
+}
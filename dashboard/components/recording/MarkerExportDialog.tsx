@@ -0,0 +1,82 @@
+/**
+ * MarkerExportDialog — pick an NLE marker format (CMX3600 EDL, Premiere XML,
+ * Resolve CSV) and, optionally, a keyword. Without a keyword the export has
+ * one marker per speaker turn; with one, a marker for every mention.
+ */
+
+import { useEffect, useState } from 'react';
+
+import { Dialog, DialogPanel, DialogTitle } from '@headlessui/react';
+
+import { Button } from '../ui/Button';
+import type { MarkerExportFormat } from '../../src/api/types';
+
+const FORMAT_OPTIONS: { value: MarkerExportFormat; label: string }[] = [
+  { value: 'edl', label: 'CMX3600 EDL (Resolve / Avid)' },
+  { value: 'premiere_xml', label: 'Premiere XML' },
+  { value: 'resolve_csv', label: 'Resolve marker CSV' },
+];
+
+export interface MarkerExportDialogProps {
+  open: boolean;
+  onCancel: () => void;
+  onExport: (format: MarkerExportFormat, keyword: string) => void;
+}
+
+export function MarkerExportDialog({ open, onCancel, onExport }: MarkerExportDialogProps) {
+  const [format, setFormat] = useState<MarkerExportFormat>('edl');
+  const [keyword, setKeyword] = useState('');
+
+  useEffect(() => {
+    if (open) setKeyword('');
+  }, [open]);
+
+  return (
+    <Dialog open={open} onClose={onCancel} className="relative z-10000">
+      <div className="fixed inset-0 bg-black/60 backdrop-blur-sm" aria-hidden="true" />
+      <div className="fixed inset-0 flex items-center justify-center p-4">
+        <DialogPanel className="blur-panel flex w-full max-w-md flex-col overflow-hidden rounded-3xl border border-white/10 bg-black/60 shadow-2xl backdrop-blur-xl">
+          <div className="flex items-center border-b border-white/10 bg-white/5 px-6 py-4 select-none">
+            <DialogTitle className="text-base font-semibold text-white">
+              Export timeline markers
+            </DialogTitle>
+          </div>
+          <div className="space-y-3 bg-black/20 px-6 py-5 text-sm text-slate-300">
+            <label className="flex flex-col gap-1 text-xs">
+              Format
+              <select
+                value={format}
+                onChange={(e) => setFormat(e.target.value as MarkerExportFormat)}
+                className="rounded-lg border border-white/10 bg-black/30 px-3 py-2 text-sm text-white"
+              >
+                {FORMAT_OPTIONS.map((option) => (
+                  <option key={option.value} value={option.value}>
+                    {option.label}
+                  </option>
+                ))}
+              </select>
+            </label>
+            <label className="flex flex-col gap-1 text-xs">
+              Keyword (optional)
+              <input
+                type="text"
+                value={keyword}
+                onChange={(e) => setKeyword(e.target.value)}
+                placeholder="Leave empty for one marker per speaker turn"
+                className="rounded-lg border border-white/10 bg-black/30 px-3 py-2 text-sm text-white"
+              />
+            </label>
+          </div>
+          <div className="flex justify-end gap-3 border-t border-white/10 bg-white/5 px-6 py-4 select-none">
+            <Button variant="ghost" onClick={onCancel}>
+              Cancel
+            </Button>
+            <Button variant="primary" onClick={() => onExport(format, keyword)}>
+              Export
+            </Button>
+          </div>
+        </DialogPanel>
+      </div>
+    </Dialog>
+  );
+}
@@ -0,0 +1,157 @@
+/**
+ * Custom fields of a recording (case number, client, interviewee…), usually
+ * pre-filled by the project template it was imported under. Shown as chips;
+ * Edit turns them into field/value rows saved as a full replacement — a
+ * blank value removes the field.
+ */
+
+import { useState } from 'react';
+import { Pencil, Plus, Tags, X } from 'lucide-react';
+import { apiClient } from '../../src/api/client';
+
+interface Props {
+  recordingId: number;
+  metadata: Record<string, string>;
+  onSaved: () => void;
+}
+
+interface Row {
+  field: string;
+  value: string;
+}
+
+const INPUT =
+  'focus:border-accent-cyan/50 min-w-0 flex-1 rounded-lg border border-white/10 bg-black/20 px-3 py-1.5 text-sm text-white focus:outline-none';
+
+export function RecordingMetadataPanel({ recordingId, metadata, onSaved }: Props) {
+  const [rows, setRows] = useState<Row[] | null>(null);
+  const [saving, setSaving] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const entries = Object.entries(metadata);
+
+  const startEditing = () => {
+    setError(null);
+    setRows(
+      entries.length > 0
+        ? entries.map(([field, value]) => ({ field, value }))
+        : [{ field: '', value: '' }],
+    );
+  };
+
+  const save = async () => {
+    if (!rows) return;
+    setSaving(true);
+    setError(null);
+    try {
+      await apiClient.updateRecordingMetadata(
+        recordingId,
+        Object.fromEntries(rows.map((r) => [r.field, r.value])),
+      );
+      setRows(null);
+      onSaved();
+    } catch (err) {
+      setError(err instanceof Error ? err.message : 'Could not save fields');
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const updateRow = (i: number, patch: Partial<Row>) =>
+    setRows((rs) => rs && rs.map((r, j) => (j === i ? { ...r, ...patch } : r)));
+
+  return (
+    <section
+      aria-label="Recording fields"
+      className="bg-glass-100 rounded-2xl border border-white/10 px-5 py-3"
+    >
+      <div className="flex items-center gap-2 text-slate-400 select-none">
+        <Tags size={16} className="text-accent-cyan" />
+        <span className="text-xs font-bold tracking-widest uppercase">Fields</span>
+        {rows === null && (
+          <button
+            type="button"
+            onClick={startEditing}
+            className="ml-auto inline-flex items-center gap-1 text-xs transition-colors hover:text-white"
+          >
+            <Pencil size={12} />
+            Edit
+          </button>
+        )}
+      </div>
+
+      {rows === null ? (
+        entries.length > 0 ? (
+          <div className="mt-2 flex flex-wrap gap-2">
+            {entries.map(([field, value]) => (
+              <span
+                key={field}
+                className="selectable-text rounded-full bg-white/5 px-3 py-1 text-xs text-slate-300"
+              >
+                <span className="text-slate-500">{field}:</span> {value}
+              </span>
+            ))}
+          </div>
+        ) : (
+          <p className="mt-2 text-xs text-slate-500">
+            No fields yet — add a case number, client or location to filter by later.
+          </p>
+        )
+      ) : (
+        <div className="mt-3 space-y-2">
+          {rows.map((row, i) => (
+            <div key={i} className="flex gap-2">
+              <input
+                value={row.field}
+                onChange={(e) => updateRow(i, { field: e.target.value })}
+                placeholder="Field"
+                aria-label={`Field ${i + 1} name`}
+                className={INPUT}
+              />
+              <input
+                value={row.value}
+                onChange={(e) => updateRow(i, { value: e.target.value })}
+                placeholder="Value"
+                aria-label={`Field ${i + 1} value`}
+                className={INPUT}
+              />
+              <button
+                type="button"
+                onClick={() => setRows(rows.filter((_, j) => j !== i))}
+                aria-label={`Remove field ${i + 1}`}
+                className="text-slate-500 transition-colors hover:text-white"
+              >
+                <X size={14} />
+              </button>
+            </div>
+          ))}
+          {error && <p className="text-xs text-red-400">{error}</p>}
+          <div className="flex items-center gap-2 text-xs">
+            <button
+              type="button"
+              onClick={() => setRows([...rows, { field: '', value: '' }])}
+              className="inline-flex items-center gap-1 text-slate-400 transition-colors hover:text-white"
+            >
+              <Plus size={12} />
+              Add field
+            </button>
+            <button
+              type="button"
+              onClick={() => setRows(null)}
+              className="ml-auto text-slate-400 transition-colors hover:text-white"
+            >
+              Cancel
+            </button>
+            <button
+              type="button"
+              disabled={saving}
+              onClick={() => void save()}
+              className="bg-accent-cyan/20 text-accent-cyan hover:bg-accent-cyan/30 rounded-full px-3 py-1 font-semibold transition disabled:opacity-50"
+            >
+              {saving ? 'Saving…' : 'Save'}
+            </button>
+          </div>
+        </div>
+      )}
+    </section>
+  );
+}
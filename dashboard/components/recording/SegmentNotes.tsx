@@ -0,0 +1,138 @@
+/**
+ * Inline review notes under a transcript segment.
+ *
+ * Renders each thread (root + replies) with author and date, a reply box
+ * per thread, and — when `composing` is set — a box for a new note on the
+ * segment. Enter submits, Shift+Enter adds a line, Esc cancels. Submit
+ * callbacks reject on failure so the draft is kept for a retry.
+ */
+
+import { useState } from 'react';
+import { MessageSquare, Trash2 } from 'lucide-react';
+import type { Annotation, AnnotationThread } from '../../src/api/types';
+
+interface Props {
+  threads: AnnotationThread[];
+  composing: boolean;
+  onCompose: (body: string) => Promise<void>;
+  onCancelCompose: () => void;
+  onReply: (threadId: number, body: string) => Promise<void>;
+  onDelete: (annotationId: number) => Promise<void>;
+}
+
+function NoteBox({
+  placeholder,
+  onSubmit,
+  onCancel,
+}: {
+  placeholder: string;
+  onSubmit: (body: string) => Promise<void>;
+  onCancel: () => void;
+}) {
+  const [value, setValue] = useState('');
+  const [busy, setBusy] = useState(false);
+  const submit = async () => {
+    const body = value.trim();
+    if (!body || busy) return;
+    setBusy(true);
+    try {
+      await onSubmit(body);
+      setValue('');
+    } catch {
+      // Keep the draft; the caller surfaces the error.
+    } finally {
+      setBusy(false);
+    }
+  };
+  return (
+    <textarea
+      autoFocus
+      rows={1}
+      value={value}
+      disabled={busy}
+      placeholder={placeholder}
+      aria-label={placeholder}
+      onChange={(e) => setValue(e.target.value)}
+      onKeyDown={(e) => {
+        if (e.key === 'Enter' && !e.shiftKey) {
+          e.preventDefault();
+          void submit();
+        }
+        if (e.key === 'Escape') onCancel();
+      }}
+      className="w-full resize-y rounded-lg border border-white/10 bg-black/30 px-2 py-1 text-xs text-slate-200 placeholder:text-slate-500 focus:border-amber-400/40 focus:outline-none"
+    />
+  );
+}
+
+function NoteLine({ note, onDelete }: { note: Annotation; onDelete: (id: number) => void }) {
+  return (
+    <div className="group/note flex items-start gap-2 text-xs">
+      <div className="min-w-0 flex-1">
+        <span className="font-semibold text-amber-200">{note.author}</span>
+        <span className="ml-1.5 text-[10px] text-slate-500">
+          {new Date(note.created_at).toLocaleString()}
+        </span>
+        <p className="whitespace-pre-wrap text-slate-300">{note.body}</p>
+      </div>
+      <button
+        type="button"
+        onClick={() => onDelete(note.id)}
+        className="text-slate-500 opacity-0 transition group-hover/note:opacity-100 hover:text-rose-300"
+        aria-label="Delete note"
+        title="Delete note"
+      >
+        <Trash2 size={12} />
+      </button>
+    </div>
+  );
+}
+
+export function SegmentNotes({
+  threads,
+  composing,
+  onCompose,
+  onCancelCompose,
+  onReply,
+  onDelete,
+}: Props) {
+  const [replyTo, setReplyTo] = useState<number | null>(null);
+  if (threads.length === 0 && !composing) return null;
+  return (
+    <div className="mt-2 space-y-2 border-l-2 border-amber-400/30 pl-3 select-text">
+      {threads.map((thread) => (
+        <div key={thread.id} className="space-y-1.5">
+          <NoteLine note={thread} onDelete={(id) => void onDelete(id)} />
+          {thread.replies.map((reply) => (
+            <div key={reply.id} className="pl-3">
+              <NoteLine note={reply} onDelete={(id) => void onDelete(id)} />
+            </div>
+          ))}
+          {replyTo === thread.id ? (
+            <div className="pl-3">
+              <NoteBox
+                placeholder="Reply…"
+                onSubmit={async (body) => {
+                  await onReply(thread.id, body);
+                  setReplyTo(null);
+                }}
+                onCancel={() => setReplyTo(null)}
+              />
+            </div>
+          ) : (
+            <button
+              type="button"
+              onClick={() => setReplyTo(thread.id)}
+              className="inline-flex items-center gap-1 pl-3 text-[11px] text-slate-500 transition hover:text-amber-200"
+            >
+              <MessageSquare size={11} /> Reply
+            </button>
+          )}
+        </div>
+      ))}
+      {composing && (
+        <NoteBox placeholder="Add a note…" onSubmit={onCompose} onCancel={onCancelCompose} />
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,125 @@
+/**
+ * ShareDialog — send the recording's export to a configured share target
+ * (`share.targets` in the server config). Credentials the target still lacks
+ * are asked for here and stored in the server keychain before sending.
+ */
+
+import { useEffect, useState } from 'react';
+
+import { Dialog, DialogPanel, DialogTitle } from '@headlessui/react';
+
+import { Button } from '../ui/Button';
+import type { ShareFormat, ShareTarget } from '../../src/api/types';
+
+const FORMAT_OPTIONS: { value: ShareFormat; label: string }[] = [
+  { value: 'plaintext', label: 'Plain text' },
+  { value: 'srt', label: 'SRT subtitles' },
+  { value: 'ass', label: 'ASS subtitles' },
+];
+
+const CREDENTIAL_LABELS: Record<string, string> = {
+  smtp_password: 'SMTP password',
+  webhook_url: 'Webhook URL',
+  password: 'Password',
+};
+
+export interface ShareDialogProps {
+  open: boolean;
+  targets: ShareTarget[];
+  busy?: boolean;
+  onCancel: () => void;
+  onShare: (target: string, format: ShareFormat, credentials: Record<string, string>) => void;
+}
+
+export function ShareDialog({ open, targets, busy, onCancel, onShare }: ShareDialogProps) {
+  const [targetName, setTargetName] = useState('');
+  const [format, setFormat] = useState<ShareFormat>('plaintext');
+  const [credentials, setCredentials] = useState<Record<string, string>>({});
+
+  useEffect(() => {
+    if (open) {
+      setTargetName('');
+      setCredentials({});
+    }
+  }, [open]);
+
+  const target = targets.find((t) => t.name === targetName) ?? targets[0];
+  const missing = target?.missing_credentials ?? [];
+  const ready = Boolean(target) && missing.every((field) => credentials[field]?.trim());
+
+  return (
+    <Dialog open={open} onClose={onCancel} className="relative z-10000">
+      <div className="fixed inset-0 bg-black/60 backdrop-blur-sm" aria-hidden="true" />
+      <div className="fixed inset-0 flex items-center justify-center p-4">
+        <DialogPanel className="blur-panel flex w-full max-w-md flex-col overflow-hidden rounded-3xl border border-white/10 bg-black/60 shadow-2xl backdrop-blur-xl">
+          <div className="flex items-center border-b border-white/10 bg-white/5 px-6 py-4 select-none">
+            <DialogTitle className="text-base font-semibold text-white">Share</DialogTitle>
+          </div>
+          <div className="space-y-3 bg-black/20 px-6 py-5 text-sm text-slate-300">
+            <label className="flex flex-col gap-1 text-xs">
+              Target
+              <select
+                value={target?.name ?? ''}
+                onChange={(e) => {
+                  setTargetName(e.target.value);
+                  setCredentials({});
+                }}
+                className="rounded-lg border border-white/10 bg-black/30 px-3 py-2 text-sm text-white"
+              >
+                {targets.map((t) => (
+                  <option key={t.name} value={t.name}>
+                    {t.name} ({t.type})
+                  </option>
+                ))}
+              </select>
+            </label>
+            <label className="flex flex-col gap-1 text-xs">
+              Format
+              <select
+                value={format}
+                onChange={(e) => setFormat(e.target.value as ShareFormat)}
+                className="rounded-lg border border-white/10 bg-black/30 px-3 py-2 text-sm text-white"
+              >
+                {FORMAT_OPTIONS.map((option) => (
+                  <option key={option.value} value={option.value}>
+                    {option.label}
+                  </option>
+                ))}
+              </select>
+            </label>
+            {missing.map((field) => (
+              <label key={field} className="flex flex-col gap-1 text-xs">
+                {CREDENTIAL_LABELS[field] ?? field}
+                <input
+                  type="password"
+                  autoComplete="off"
+                  value={credentials[field] ?? ''}
+                  onChange={(e) => setCredentials((prev) => ({ ...prev, [field]: e.target.value }))}
+                  placeholder="Saved in the server keychain"
+                  className="rounded-lg border border-white/10 bg-black/30 px-3 py-2 text-sm text-white"
+                />
+              </label>
+            ))}
+          </div>
+          <div className="flex justify-end gap-3 border-t border-white/10 bg-white/5 px-6 py-4 select-none">
+            <Button variant="ghost" onClick={onCancel}>
+              Cancel
+            </Button>
+            <Button
+              variant="primary"
+              disabled={!ready || busy}
+              onClick={() => {
+                const entered = Object.fromEntries(
+                  missing.map((field) => [field, credentials[field].trim()]),
+                );
+                if (target) onShare(target.name, format, entered);
+              }}
+            >
+              {busy ? 'Sharing…' : 'Share'}
+            </Button>
+          </div>
+        </DialogPanel>
+      </div>
+    </Dialog>
+  );
+}
@@ -0,0 +1,120 @@
+/**
+ * Per-speaker conversation statistics for a diarized recording.
+ *
+ * One row per speaker (most talk time first): a share-of-talk bar, words per
+ * minute, turns, the longest monologue (click to jump there) and how often
+ * they interrupted or were interrupted. Collapsed by default; the footer
+ * toggle adds the same numbers to PDF and plain-text exports.
+ */
+
+import { useState } from 'react';
+import { BarChart3, ChevronDown } from 'lucide-react';
+import type { SpeakerStatsResponse } from '../../src/api/types';
+import { formatDuration } from '../../src/hooks/useSpeakerStats';
+
+interface Props {
+  stats: SpeakerStatsResponse;
+  includeInExports: boolean;
+  onIncludeInExportsChange: (next: boolean) => void;
+  onSeek: (seconds: number) => void;
+}
+
+export function SpeakerStatsPanel({
+  stats,
+  includeInExports,
+  onIncludeInExportsChange,
+  onSeek,
+}: Props) {
+  const [expanded, setExpanded] = useState(false);
+
+  return (
+    <section
+      aria-label="Speaker statistics"
+      className="bg-glass-100 overflow-hidden rounded-2xl border border-white/10 select-none"
+    >
+      <button
+        type="button"
+        onClick={() => setExpanded((v) => !v)}
+        aria-expanded={expanded}
+        className="flex w-full items-center gap-2 px-5 py-3 text-slate-400 transition-colors hover:text-white"
+      >
+        <BarChart3 size={16} className="text-accent-cyan" />
+        <span className="text-xs font-bold tracking-widest uppercase">Speakers</span>
+        <span className="text-xs text-slate-500">
+          {stats.speakers.length} speakers · {formatDuration(stats.total_talk_seconds)} of speech
+        </span>
+        <ChevronDown
+          size={14}
+          className={`ml-auto transition-transform ${expanded ? 'rotate-180' : ''}`}
+        />
+      </button>
+      {expanded && (
+        <div className="px-5 pb-4">
+          <table className="w-full text-left text-sm">
+            <thead className="text-xs text-slate-500">
+              <tr>
+                <th className="py-1 pr-3 font-medium">Speaker</th>
+                <th className="py-1 pr-3 font-medium">Talk time</th>
+                <th className="py-1 pr-3 text-right font-medium">WPM</th>
+                <th className="py-1 pr-3 text-right font-medium">Turns</th>
+                <th className="py-1 pr-3 text-right font-medium">Longest</th>
+                <th
+                  className="py-1 text-right font-medium"
+                  title="Interrupted others / was interrupted"
+                >
+                  Interruptions
+                </th>
+              </tr>
+            </thead>
+            <tbody className="text-slate-300">
+              {stats.speakers.map((s) => (
+                <tr key={s.speaker} className="border-t border-white/5">
+                  <td className="max-w-40 truncate py-1.5 pr-3 text-white">{s.label}</td>
+                  <td className="py-1.5 pr-3">
+                    <div className="flex items-center gap-2">
+                      <div className="h-1.5 w-24 overflow-hidden rounded-full bg-white/10">
+                        <div
+                          className="bg-accent-cyan h-full rounded-full"
+                          style={{ width: `${Math.round(s.share * 100)}%` }}
+                        />
+                      </div>
+                      <span className="font-mono text-xs">
+                        {formatDuration(s.talk_seconds)} · {Math.round(s.share * 100)}%
+                      </span>
+                    </div>
+                  </td>
+                  <td className="py-1.5 pr-3 text-right font-mono text-xs">
+                    {Math.round(s.words_per_minute)}
+                  </td>
+                  <td className="py-1.5 pr-3 text-right font-mono text-xs">{s.turns}</td>
+                  <td className="py-1.5 pr-3 text-right">
+                    <button
+                      type="button"
+                      onClick={() => onSeek(s.longest_monologue_start)}
+                      title="Jump to this monologue"
+                      className="hover:text-accent-cyan font-mono text-xs transition-colors"
+                    >
+                      {formatDuration(s.longest_monologue_seconds)}
+                    </button>
+                  </td>
+                  <td className="py-1.5 text-right font-mono text-xs">
+                    {s.interruptions} / {s.interrupted}
+                  </td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+          <label className="mt-3 flex items-center gap-2 text-xs text-slate-400">
+            <input
+              type="checkbox"
+              checked={includeInExports}
+              onChange={(e) => onIncludeInExportsChange(e.target.checked)}
+              className="accent-accent-cyan"
+            />
+            Include in PDF and plain-text exports
+          </label>
+        </div>
+      )}
+    </section>
+  );
+}
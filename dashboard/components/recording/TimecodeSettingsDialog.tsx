@@ -0,0 +1,119 @@
+/**
+ * TimecodeSettingsDialog — edit a recording's start timecode, frame rate and
+ * drop-frame flag. Saved settings are applied to SRT/ASS exports and to the
+ * transcript viewer's timestamps (see src/utils/timecode.ts).
+ */
+
+import { useEffect, useState } from 'react';
+
+import { Dialog, DialogPanel, DialogTitle } from '@headlessui/react';
+
+import { Button } from '../ui/Button';
+import type { TimecodeFps, TimecodeSettings } from '../../src/api/types';
+
+const FPS_OPTIONS: TimecodeFps[] = [23.976, 24, 25, 29.97, 30];
+
+export interface TimecodeSettingsDialogProps {
+  open: boolean;
+  value: TimecodeSettings | null;
+  onCancel: () => void;
+  /** `null` clears the settings. */
+  onSave: (next: TimecodeSettings | null) => Promise<unknown>;
+}
+
+export function TimecodeSettingsDialog({
+  open,
+  value,
+  onCancel,
+  onSave,
+}: TimecodeSettingsDialogProps) {
+  const [startTimecode, setStartTimecode] = useState('01:00:00:00');
+  const [fps, setFps] = useState<TimecodeFps>(25);
+  const [dropFrame, setDropFrame] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!open) return;
+    setStartTimecode(value?.start_timecode ?? '01:00:00:00');
+    setFps(value?.fps ?? 25);
+    setDropFrame(value?.drop_frame ?? false);
+    setError(null);
+  }, [open, value]);
+
+  const save = async (next: TimecodeSettings | null) => {
+    try {
+      await onSave(next);
+    } catch (err) {
+      setError(err instanceof Error ? err.message : 'Could not save timecode');
+    }
+  };
+
+  return (
+    <Dialog open={open} onClose={onCancel} className="relative z-10000">
+      <div className="fixed inset-0 bg-black/60 backdrop-blur-sm" aria-hidden="true" />
+      <div className="fixed inset-0 flex items-center justify-center p-4">
+        <DialogPanel className="blur-panel flex w-full max-w-md flex-col overflow-hidden rounded-3xl border border-white/10 bg-black/60 shadow-2xl backdrop-blur-xl">
+          <div className="flex items-center border-b border-white/10 bg-white/5 px-6 py-4 select-none">
+            <DialogTitle className="text-base font-semibold text-white">Timecode</DialogTitle>
+          </div>
+          <div className="space-y-3 bg-black/20 px-6 py-5 text-sm text-slate-300">
+            <label className="flex flex-col gap-1 text-xs">
+              Start timecode
+              <input
+                type="text"
+                value={startTimecode}
+                onChange={(e) => setStartTimecode(e.target.value)}
+                placeholder="01:00:00:00"
+                className="rounded-lg border border-white/10 bg-black/30 px-3 py-2 font-mono text-sm text-white"
+              />
+            </label>
+            <label className="flex flex-col gap-1 text-xs">
+              Frame rate
+              <select
+                value={fps}
+                onChange={(e) => {
+                  const next = Number(e.target.value) as TimecodeFps;
+                  setFps(next);
+                  if (next !== 29.97) setDropFrame(false);
+                }}
+                className="rounded-lg border border-white/10 bg-black/30 px-3 py-2 text-sm text-white"
+              >
+                {FPS_OPTIONS.map((option) => (
+                  <option key={option} value={option}>
+                    {option} fps
+                  </option>
+                ))}
+              </select>
+            </label>
+            <label className="flex items-center gap-2 text-xs">
+              <input
+                type="checkbox"
+                checked={dropFrame}
+                disabled={fps !== 29.97}
+                onChange={(e) => setDropFrame(e.target.checked)}
+              />
+              Drop-frame (29.97 only)
+            </label>
+            {error && <p className="text-xs text-red-300">{error}</p>}
+          </div>
+          <div className="flex justify-end gap-3 border-t border-white/10 bg-white/5 px-6 py-4 select-none">
+            {value && (
+              <Button variant="ghost" onClick={() => save(null)}>
+                Clear
+              </Button>
+            )}
+            <Button variant="ghost" onClick={onCancel}>
+              Cancel
+            </Button>
+            <Button
+              variant="primary"
+              onClick={() => save({ start_timecode: startTimecode, fps, drop_frame: dropFrame })}
+            >
+              Save
+            </Button>
+          </div>
+        </DialogPanel>
+      </div>
+    </Dialog>
+  );
+}
@@ -0,0 +1,40 @@
+import { fireEvent, render, screen } from '@testing-library/react';
+import { describe, expect, it, vi } from 'vitest';
+
+import { ShareDialog } from '../ShareDialog';
+import type { ShareTarget } from '../../../src/api/types';
+
+const targets: ShareTarget[] = [
+  { name: 'team', type: 'slack', missing_credentials: ['webhook_url'] },
+  { name: 'me', type: 'email', missing_credentials: [] },
+];
+
+describe('ShareDialog', () => {
+  it('asks for missing credentials before sharing and passes them on', () => {
+    const onShare = vi.fn();
+    render(<ShareDialog open targets={targets} onCancel={vi.fn()} onShare={onShare} />);
+
+    const share = screen.getByRole('button', { name: 'Share' });
+    expect(share).toBeDisabled();
+
+    fireEvent.change(screen.getByLabelText('Webhook URL'), {
+      target: { value: ' https://hooks.slack.com/x ' },
+    });
+    fireEvent.click(share);
+
+    expect(onShare).toHaveBeenCalledWith('team', 'plaintext', {
+      webhook_url: 'https://hooks.slack.com/x',
+    });
+  });
+
+  it('shares straight away when the target has its credentials', () => {
+    const onShare = vi.fn();
+    render(<ShareDialog open targets={targets} onCancel={vi.fn()} onShare={onShare} />);
+
+    fireEvent.change(screen.getByLabelText(/^Target/), { target: { value: 'me' } });
+    fireEvent.change(screen.getByLabelText(/^Format/), { target: { value: 'srt' } });
+    fireEvent.click(screen.getByRole('button', { name: 'Share' }));
+
+    expect(onShare).toHaveBeenCalledWith('me', 'srt', {});
+  });
+});
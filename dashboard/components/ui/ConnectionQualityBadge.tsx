@@ -0,0 +1,40 @@
+/**
+ * ConnectionQualityBadge — small good / degraded / offline pill for the
+ * Session view's Client Link row, fed by useConnectionQuality. Renders
+ * nothing until the first probe round has finished.
+ */
+
+import { Signal, SignalLow, SignalZero } from 'lucide-react';
+
+import { useConnectionQuality } from '../../src/hooks/useConnectionQuality';
+
+const STYLES = {
+  good: { className: 'bg-green-500/15 text-green-400', Icon: Signal },
+  degraded: { className: 'bg-orange-500/15 text-orange-300', Icon: SignalLow },
+  offline: { className: 'bg-red-500/15 text-red-400', Icon: SignalZero },
+} as const;
+
+function describe(report: ConnectionQualityReport): string {
+  const latency = report.latencyMs === null ? 'no answer' : `${report.latencyMs} ms`;
+  const loss = `${Math.round(report.loss * 100)}% loss`;
+  const lines = [`${report.profile}: ${latency}, ${loss}`];
+  if (report.alternative) {
+    lines.push(`${report.alternative.profile}: ${report.alternative.quality}`);
+  }
+  return lines.join('\n');
+}
+
+export function ConnectionQualityBadge() {
+  const report = useConnectionQuality();
+  if (!report) return null;
+  const { className, Icon } = STYLES[report.quality];
+  return (
+    <span
+      title={describe(report)}
+      className={`flex items-center gap-1 rounded px-1.5 py-0.5 text-[10px] font-bold tracking-wide uppercase ${className}`}
+    >
+      <Icon size={10} />
+      {report.quality}
+    </span>
+  );
+}
@@ -0,0 +1,98 @@
+/**
+ * LibraryReadOnlyBanner — persistent banner visible across all views while
+ * the server has the library open read-only because another machine owns it
+ * (a library on a synced drive). Names the other machine and offers "Take
+ * ownership" for when that machine is gone for good — to tokens that may.
+ */
+
+import { useEffect, useState } from 'react';
+
+import { toast } from 'sonner';
+
+import { apiClient } from '../../src/api/client';
+import type { LibraryLockStatus } from '../../src/api/types';
+import { useConfirm } from '../../src/hooks/useConfirm';
+import { usePermissions } from '../../src/hooks/usePermissions';
+
+interface Props {
+  readOnly: boolean;
+  /** Re-poll the server status after ownership changed. */
+  onOwnershipChanged?: () => void;
+}
+
+function lastSeen(seconds: number): string {
+  if (seconds < 90) return 'just now';
+  const minutes = Math.round(seconds / 60);
+  return minutes < 90 ? `${minutes} min ago` : `${Math.round(minutes / 60)} h ago`;
+}
+
+export function LibraryReadOnlyBanner({ readOnly, onOwnershipChanged }: Props) {
+  const [lock, setLock] = useState<LibraryLockStatus | null>(null);
+  const [busy, setBusy] = useState(false);
+  const { confirm, dialog } = useConfirm();
+  const canTakeOwnership = usePermissions().can('can_manage_library');
+
+  useEffect(() => {
+    if (!readOnly) {
+      setLock(null);
+      return;
+    }
+    let cancelled = false;
+    apiClient
+      .getLibraryLock()
+      .then((status) => {
+        if (!cancelled) setLock(status);
+      })
+      .catch(() => {
+        /* the banner still shows without the holder's name */
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [readOnly]);
+
+  if (!readOnly) return null;
+
+  const holder = lock?.holder;
+  const handleTakeOwnership = async () => {
+    const ok = await confirm(
+      `Only continue if ${holder?.machine ?? 'the other machine'} has stopped TranscriptionSuite ` +
+        'or will not sync again. Two machines writing to the same library can corrupt it.',
+      { title: 'Take ownership of the library?', confirmLabel: 'Take ownership', danger: true },
+    );
+    if (!ok) return;
+    setBusy(true);
+    try {
+      await apiClient.takeLibraryOwnership();
+      toast.success('This machine now owns the library');
+      onOwnershipChanged?.();
+    } catch (err) {
+      toast.error('Could not take ownership', {
+        description: err instanceof Error ? err.message : String(err),
+      });
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  return (
+    <div className="flex items-center justify-between gap-3 border border-amber-400/30 bg-amber-400/10 px-4 py-2 text-sm text-amber-400">
+      <span>
+        Read-only — the library is open on {holder?.machine ?? 'another machine'}
+        {holder ? ` (last seen ${lastSeen(holder.last_seen_seconds)})` : ''}. Changes are disabled
+        until {canTakeOwnership ? 'this machine takes' : 'an admin takes'} ownership.
+      </span>
+      {canTakeOwnership && (
+        <button
+          type="button"
+          onClick={() => void handleTakeOwnership()}
+          disabled={busy}
+          className="shrink-0 rounded bg-amber-400/20 px-3 py-1 text-xs font-medium text-amber-300 transition-colors hover:bg-amber-400/30 disabled:opacity-50"
+        >
+          Take ownership
+        </button>
+      )}
+      {dialog}
+    </div>
+  );
+}
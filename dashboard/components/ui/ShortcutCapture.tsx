@@ -5,6 +5,8 @@ interface ShortcutCaptureProps {
   onChange: (accelerator: string) => void;
   placeholder?: string;
   disabled?: boolean;
+  /** In-app shortcuts may be a single key (e.g. `K`); global ones need a modifier. */
+  allowBare?: boolean;
   portalTrigger?: string;
   isWaylandPortal?: boolean;
   onPortalRebind?: () => void;
@@ -12,9 +14,9 @@ interface ShortcutCaptureProps {
 
 /**
  * Map a KeyboardEvent to an Electron accelerator string.
- * Requires at least one modifier + a non-modifier key.
+ * Requires at least one modifier + a non-modifier key, unless `allowBare`.
  */
-function keyEventToAccelerator(e: React.KeyboardEvent): string | null {
+function keyEventToAccelerator(e: React.KeyboardEvent, allowBare = false): string | null {
   const modifiers: string[] = [];
   if (e.ctrlKey) modifiers.push('Ctrl');
   if (e.altKey) modifiers.push('Alt');
@@ -34,7 +36,7 @@ function keyEventToAccelerator(e: React.KeyboardEvent): string | null {
   if (ignoredKeys.has(e.key)) return null;
 
   // Must have at least one modifier
-  if (modifiers.length === 0) return null;
+  if (modifiers.length === 0 && !allowBare) return null;
 
   // Normalize key name to Electron accelerator format
   let key = e.key;
@@ -84,6 +86,7 @@ export const ShortcutCapture: React.FC<ShortcutCaptureProps> = ({
   onChange,
   placeholder = 'Click to set shortcut',
   disabled = false,
+  allowBare = false,
   portalTrigger,
   isWaylandPortal = false,
   onPortalRebind,
@@ -118,7 +121,7 @@ export const ShortcutCapture: React.FC<ShortcutCaptureProps> = ({
         return;
       }
 
-      const accelerator = keyEventToAccelerator(e);
+      const accelerator = keyEventToAccelerator(e, allowBare);
       if (accelerator) {
         setCapturing(false);
         setPendingDisplay('');
@@ -133,7 +136,7 @@ export const ShortcutCapture: React.FC<ShortcutCaptureProps> = ({
         setPendingDisplay(mods.length > 0 ? mods.join('+') + '+...' : '');
       }
     },
-    [capturing, cancelCapture, onChange],
+    [capturing, cancelCapture, onChange, allowBare],
   );
 
   const handleBlur = useCallback(() => {
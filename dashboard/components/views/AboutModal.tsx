@@ -13,6 +13,8 @@ export const AboutModal: React.FC<AboutModalProps> = ({ isOpen, onClose }) => {
   const [isVisible, setIsVisible] = useState(false);
   const [appVersion, setAppVersion] = useState<string>(import.meta.env.VITE_APP_VERSION ?? '0.0.0');
   const [platform, setPlatform] = useState<string>('');
+  const [portableRoot, setPortableRoot] = useState<string | null>(null);
+  const [dataDir, setDataDir] = useState<string | null>(null);
   const copyrightYears = '2025-2026';
 
   const openExternal = async (url: string): Promise<void> => {
@@ -49,6 +51,14 @@ export const AboutModal: React.FC<AboutModalProps> = ({ isOpen, onClose }) => {
           })
           .catch(() => {});
         setPlatform(api.app.getPlatform?.() ?? '');
+        api.app
+          .getPortableRoot?.()
+          .then(setPortableRoot)
+          .catch(() => {});
+        api.app
+          .getDataPaths?.()
+          .then((paths) => setDataDir(paths.data))
+          .catch(() => {});
       }
 
       // Double RAF to ensure DOM paint before transition
@@ -119,7 +129,18 @@ export const AboutModal: React.FC<AboutModalProps> = ({ isOpen, onClose }) => {
           <p className="text-accent-cyan mb-4 text-xs font-medium tracking-widest uppercase">
             v{appVersion}
             {platform ? ` • ${platform}` : ''}
+            {portableRoot ? ' • portable' : ''}
           </p>
+          {(portableRoot || dataDir) && (
+            <button
+              type="button"
+              onClick={() => void window.electronAPI?.app?.openDataDir?.('data')}
+              title="Open data folder"
+              className="hover:text-accent-cyan -mt-3 mb-4 px-2 text-center text-xs break-all text-slate-500 transition-colors"
+            >
+              Data stored in {portableRoot ?? dataDir}
+            </button>
+          )}
 
           {/* Description */}
           <p className="mb-6 px-2 text-center text-sm leading-relaxed text-slate-300">
@@ -1,6 +1,6 @@
 import React, { useState, useEffect, useRef, useCallback } from 'react';
 import { createPortal } from 'react-dom';
-import { X, Upload, FileAudio, Calendar, Trash2, Info } from 'lucide-react';
+import { X, Upload, FileAudio, Calendar, Trash2, Info, Languages } from 'lucide-react';
 import { Button } from '../ui/Button';
 import { AppleSwitch } from '../ui/AppleSwitch';
 import { GlassCard } from '../ui/GlassCard';
@@ -9,6 +9,8 @@ import { useNotificationsStore } from '../../src/stores/notificationsStore';
 import { apiClient } from '../../src/api/client';
 import { useAdminStatus } from '../../src/hooks/useAdminStatus';
 import { useLanguages } from '../../src/hooks/useLanguages';
+import { useLanguageDetection } from '../../src/hooks/useLanguageDetection';
+import { describeDetection } from '../../src/utils/languagePreview';
 import { getConfig } from '../../src/config/store';
 import {
   isCanaryModel,
@@ -77,6 +79,24 @@ export const AddNoteModal: React.FC<AddNoteModalProps> = ({
   const isCanaryMainBidi = isCanaryModel(activeModel) && mainLanguage === 'English';
   const canTranslate = supportsTranslation(activeModel);
 
+  // Language preview: sample the first queued file while the picker is on
+  // Auto Detect, so the import can be pinned to the detected language.
+  const previewFile = selectedFiles[0] ?? null;
+  const detection = useLanguageDetection(
+    previewFile,
+    mainLanguage === 'Auto Detect' && supportsAutoDetect(activeModel),
+  );
+  const preview = describeDetection(detection.result, languages);
+  const [useDetectedLanguage, setUseDetectedLanguage] = useState(false);
+  useEffect(() => {
+    setUseDetectedLanguage(false);
+  }, [previewFile]);
+  // Only a confident preview pre-selects the language; a shaky one is a hint.
+  useEffect(() => {
+    if (preview?.confident && !preview.multilingual) setUseDetectedLanguage(true);
+  }, [preview?.confident, preview?.multilingual]);
+  const effectiveLanguage = useDetectedLanguage && preview ? preview.name : mainLanguage;
+
   const selectedDateKey =
     initialDate && DATE_KEY_RE.test(initialDate) ? initialDate : formatDateKey(new Date());
   const selectedDateLabel = new Date(`${selectedDateKey}T00:00:00`).toLocaleDateString([], {
@@ -185,7 +205,7 @@ export const AddNoteModal: React.FC<AddNoteModalProps> = ({
     // (Auto Detect, languages still loading, stale display name) round-trips
     // to the backend fail-loud path. Wording matches the live-recording /
     // session-import guard verbatim so future copy changes propagate via grep.
-    const resolvedLang = resolveLanguage(mainLanguage);
+    const resolvedLang = resolveLanguage(effectiveLanguage);
     if (resolvedLang === undefined && !supportsAutoDetect(activeModel)) {
       toast.error('Source language required', {
         description: languagesLoading
@@ -259,6 +279,7 @@ export const AddNoteModal: React.FC<AddNoteModalProps> = ({
     title,
     activeModel,
     mainLanguage,
+    effectiveLanguage,
     mainTranslate,
     mainBidiTarget,
     isCanaryMainBidi,
@@ -452,6 +473,32 @@ export const AddNoteModal: React.FC<AddNoteModalProps> = ({
           {/* 3. Configuration Options */}
           <GlassCard title="Import Options">
             <div className="space-y-4">
+              {detection.loading && (
+                <p className="text-xs text-slate-500">Detecting spoken language…</p>
+              )}
+              {preview && (
+                <>
+                  <AppleSwitch
+                    checked={useDetectedLanguage}
+                    onChange={setUseDetectedLanguage}
+                    label={`Use detected language: ${preview.name}`}
+                    description={`${preview.percent}% likely, from the first ${Math.round(
+                      detection.result?.sampled_seconds ?? 0,
+                    )} s of ${previewFile?.name ?? 'the file'}`}
+                  />
+                  {preview.multilingual && (
+                    <div className="flex items-start gap-2 rounded-lg bg-amber-400/10 px-3 py-2">
+                      <Languages size={14} className="mt-0.5 shrink-0 text-amber-300" />
+                      <p className="text-xs leading-relaxed text-amber-200">
+                        More than one language was heard
+                        {preview.others.length ? ` (also ${preview.others.join(', ')})` : ''}.
+                        Leaving Auto Detect on may transcribe parts in the wrong language.
+                      </p>
+                    </div>
+                  )}
+                  <div className="h-px bg-white/5"></div>
+                </>
+              )}
               <AppleSwitch
                 checked={isDiarizationEnabled}
                 onChange={handleDiarizationChange}
@@ -29,19 +29,38 @@ import {
   Copy,
   MoreHorizontal,
   Download,
+  AudioLines,
+  ChevronLeft,
+  ChevronRight,
+  ClipboardCheck,
+  Flag,
+  EyeOff,
+  Merge,
+  UserCheck,
+  Archive,
+  ListTree,
 } from 'lucide-react';
 import { Button } from '../ui/Button';
 import { StatusLight } from '../ui/StatusLight';
 import { AudioVisualizer } from '../AudioVisualizer';
 import { useRecording } from '../../src/hooks/useRecording';
 import { apiClient } from '../../src/api/client';
+import { formatClock } from '../../src/services/jobProgress';
 import { FindReplaceTextEditor } from '../editor/FindReplaceTextEditor';
 import { flattenSegmentsToText } from '../../src/services/transcriptFlatten';
+import { filterText, loadOutputFilter } from '../../src/services/outputFilter';
 import { toast } from 'sonner';
 import { useConfirm } from '../../src/hooks/useConfirm';
 import { ConfidenceChip } from '../recording/ConfidenceChip';
 import { DeleteRecordingDialog } from '../recording/DeleteRecordingDialog';
+import { TimecodeSettingsDialog } from '../recording/TimecodeSettingsDialog';
+import { MarkerExportDialog } from '../recording/MarkerExportDialog';
+import { ShareDialog } from '../recording/ShareDialog';
 import { SpeakerRenameInput } from '../recording/SpeakerRenameInput';
+import { SegmentNotes } from '../recording/SegmentNotes';
+import { ChapterOutline } from '../recording/ChapterOutline';
+import { SpeakerStatsPanel } from '../recording/SpeakerStatsPanel';
+import { RecordingMetadataPanel } from '../recording/RecordingMetadataPanel';
 import { AutoActionStatusBadge, statusToBadgeProps } from '../recording/AutoActionStatusBadge';
 import { useAutoActionRetry } from '../../src/hooks/useAutoActionRetry';
 import { PersistentInfoBanner } from '../ui/PersistentInfoBanner';
@@ -50,10 +69,36 @@ import { useDiarizationConfidence } from '../../src/hooks/useDiarizationConfiden
 import { useDiarizationReview } from '../../src/hooks/useDiarizationReview';
 import { useWordHighlighter } from '../../src/hooks/useWordHighlighter';
 import { useRecordingAliases } from '../../src/hooks/useRecordingAliases';
+import { useRecordingTimecode } from '../../src/hooks/useRecordingTimecode';
+import { useLowConfidenceRegions } from '../../src/hooks/useLowConfidenceRegions';
+import { useSegmentReview } from '../../src/hooks/useSegmentReview';
+import { useAnnotations } from '../../src/hooks/useAnnotations';
+import { useRedactions } from '../../src/hooks/useRedactions';
+import { chapterIndexAt, useOutline } from '../../src/hooks/useOutline';
+import { useSpeakerStats } from '../../src/hooks/useSpeakerStats';
+import { useMediaSession } from '../../src/hooks/useMediaSession';
+import { useHidPedal } from '../../src/hooks/useHidPedal';
+import { useReviewTimeTracking } from '../../src/hooks/useReviewTimeTracking';
+import { useKeymap } from '../../src/hooks/useKeymap';
+import { matchesAccelerator } from '../../src/utils/keymap';
 import { buildSpeakerLabelMap, labelFor } from '../../src/utils/aliasSubstitution';
 import { LOW_CONFIDENCE_THRESHOLD } from '../../src/utils/confidenceBuckets';
-import { getConfig } from '../../src/config/store';
-import type { ChatMessage, Conversation, LLMModel } from '../../src/api/types';
+import { formatTimecode } from '../../src/utils/timecode';
+import { REVIEW_SHORTCUTS, findReviewTarget } from '../../src/utils/segmentReview';
+import type { ReviewTarget } from '../../src/utils/segmentReview';
+import { isRedacted, overlappingRedactions } from '../../src/utils/redaction';
+import { getConfig, setConfig } from '../../src/config/store';
+import type {
+  AnnotationThread,
+  Chapter,
+  ChatMessage,
+  Conversation,
+  ExportFormat,
+  LLMModel,
+  RedactionAudioMode,
+  ShareFormat,
+  ShareTarget,
+} from '../../src/api/types';
 
 /** Local type for chat message display (simpler than API's ChatMessage) */
 interface DisplayMessage {
@@ -91,6 +136,9 @@ interface ParsedLlmSegment {
   streaming?: boolean;
 }
 
+/** Review-mode key map, one shortcut per line (button tooltip). */
+const REVIEW_SHORTCUT_HELP = REVIEW_SHORTCUTS.map(([key, label]) => `${key}  ${label}`).join('\n');
+
 /** Format seconds to MM:SS display */
 function formatRecSecs(s: number): string {
   const m = Math.floor(s / 60);
@@ -435,6 +483,11 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
   // Issue #104, Story 3.7 — DeleteRecordingDialog state for the
   // recording-delete affordance in the options menu.
   const [deleteDialogOpen, setDeleteDialogOpen] = useState(false);
+  const [timecodeDialogOpen, setTimecodeDialogOpen] = useState(false);
+  const [markerDialogOpen, setMarkerDialogOpen] = useState(false);
+  const [shareDialogOpen, setShareDialogOpen] = useState(false);
+  const [shareTargets, setShareTargets] = useState<ShareTarget[]>([]);
+  const [shareBusy, setShareBusy] = useState(false);
 
   // Portal Container State
   const [portalContainer, setPortalContainer] = useState<HTMLElement | null>(null);
@@ -588,6 +641,7 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
   const [editingContent, setEditingContent] = useState('');
   // Ref for the chat text input — used to auto-focus it
   const chatInputRef = useRef<HTMLInputElement>(null);
+  const labelFileInputRef = useRef<HTMLInputElement>(null);
   // Focus the chat input whenever the sidebar opens or a new session is created
   useEffect(() => {
     if (!isSidebarOpen) return;
@@ -624,6 +678,7 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
     transcription,
     loading: recordingLoading,
     audioUrl,
+    refresh: refreshRecording,
   } = useRecording(note?.recordingId ?? null);
   const segments = transcription?.segments ?? [];
   const hasDiarizationTranscript =
@@ -635,6 +690,14 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
   // (FR22 / "applies to all turns of the same speaker_id").
   const recordingId = note?.recordingId ?? null;
   const aliasState = useRecordingAliases(recordingId);
+  // Per-recording SMPTE timecode — when set, transcript timestamps show
+  // HH:MM:SS:FF labels matching the SRT/ASS export.
+  const timecodeState = useRecordingTimecode(recordingId);
+  const formatSegTime = useCallback(
+    (s: number) =>
+      timecodeState.timecode ? formatTimecode(s, timecodeState.timecode) : formatRecSecs(s),
+    [timecodeState.timecode],
+  );
   const speakerLabelMap = useMemo(
     () => buildSpeakerLabelMap(segments, aliasState.aliasMap),
     [segments, aliasState.aliasMap],
@@ -656,6 +719,18 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
   // renders no chips at all (graceful fallback).
   const confidenceState = useDiarizationConfidence(recordingId);
 
+  // Word-level review: runs of words the STT backend was unsure about.
+  // Re-fetched whenever the loaded segments change (realign, re-segment).
+  const lowConfidence = useLowConfidenceRegions(
+    recordingId,
+    LOW_CONFIDENCE_THRESHOLD,
+    transcription?.segments,
+  );
+  const [regionCursor, setRegionCursor] = useState(-1);
+  useEffect(() => {
+    setRegionCursor(-1);
+  }, [lowConfidence.regions]);
+
   // Issue #104 Story 5.7 — review state drives the persistent banner.
   const reviewState = useDiarizationReview(recordingId);
 
@@ -725,6 +800,24 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
     containerRef: transcriptContainerRef,
     enabled: allowWordPlaybackHighlight,
   });
+  const handleJumpToRegion = useCallback(
+    (step: 1 | -1) => {
+      const regions = lowConfidence.regions;
+      if (regions.length === 0) return;
+      const next = (regionCursor + step + regions.length) % regions.length;
+      setRegionCursor(next);
+      const region = regions[next];
+      if (audioRef.current) audioRef.current.currentTime = region.start;
+      const flat = segments.flatMap((seg) => seg.words ?? []);
+      const wordIdx = flat.findIndex((w) => Math.abs(w.start - region.start) < 0.01);
+      if (wordIdx >= 0) {
+        transcriptContainerRef.current
+          ?.querySelector(`[data-word-idx="${wordIdx}"]`)
+          ?.scrollIntoView({ block: 'center', behavior: 'smooth' });
+      }
+    },
+    [lowConfidence.regions, regionCursor, segments],
+  );
 
   // Initialize Portal Target on Mount
   useEffect(() => {
@@ -992,6 +1085,274 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
 
   const hasCorrected = !!correctedTranscript?.trim();
 
+  // Review mode — keyboard-driven QA pass over the timed segments. Each
+  // segment is approved / flagged / unreviewed; state persists per segment.
+  const segmentReview = useSegmentReview(recordingId, transcription?.segments);
+  const [isReviewMode, setIsReviewMode] = useState(false);
+  const [reviewIndex, setReviewIndex] = useState(-1);
+  // Threaded review notes, shown inline under the segment they belong to.
+  // Notes whose segment was replaced (re-segmentation) fall back to the
+  // segment containing their start time.
+  const annotationState = useAnnotations(recordingId);
+  const [noteComposeIndex, setNoteComposeIndex] = useState<number | null>(null);
+  const notesBySegment = useMemo(() => {
+    const byIndex = new Map<number, AnnotationThread[]>();
+    for (const thread of annotationState.threads) {
+      let index = segments.findIndex((seg) => seg.id != null && seg.id === thread.segment_id);
+      if (index < 0) {
+        index = segments.findIndex((seg) => thread.start_time < seg.end);
+      }
+      if (index < 0) index = segments.length - 1;
+      if (index < 0) continue;
+      byIndex.set(index, [...(byIndex.get(index) ?? []), thread]);
+    }
+    return byIndex;
+  }, [annotationState.threads, segments]);
+  const handleAddNote = useCallback(
+    async (index: number, body: string) => {
+      const seg = segments[index];
+      if (!seg) return;
+      try {
+        await annotationState.add(
+          seg.id != null
+            ? { body, segment_id: seg.id }
+            : { body, start_time: seg.start, end_time: seg.end },
+        );
+        setNoteComposeIndex(null);
+      } catch (err) {
+        toast.error('Failed to save note');
+        throw err;
+      }
+    },
+    [segments, annotationState],
+  );
+  const handleReplyNote = useCallback(
+    async (threadId: number, body: string) => {
+      try {
+        await annotationState.add({ body, parent_id: threadId });
+      } catch (err) {
+        toast.error('Failed to save reply');
+        throw err;
+      }
+    },
+    [annotationState],
+  );
+  const handleDeleteNote = useCallback(
+    async (annotationId: number) => {
+      if (!(await confirm('Delete this note and its replies?', { danger: true }))) return;
+      try {
+        await annotationState.remove(annotationId);
+      } catch {
+        toast.error('Failed to delete note');
+      }
+    },
+    [annotationState, confirm],
+  );
+  // Redactions — ranges masked in every export. Alt+click a word or use the
+  // segment's hover button; acting on an already-redacted span un-redacts it.
+  const redactionState = useRedactions(recordingId);
+  const handleToggleRedaction = useCallback(
+    async (start: number, end: number) => {
+      const existing = overlappingRedactions(start, end, redactionState.ranges);
+      try {
+        if (existing.length > 0) {
+          await redactionState.remove(existing.map((r) => r.id));
+        } else {
+          await redactionState.add(start, end);
+        }
+      } catch {
+        toast.error('Failed to update redaction');
+      }
+    },
+    [redactionState],
+  );
+  const handleScanPii = useCallback(async () => {
+    setOptionsMenuOpen(false);
+    try {
+      const found = await redactionState.scanPii();
+      if (found === 0) toast.success('No personal info found');
+      else toast.success(`Suggested ${found} redaction${found === 1 ? '' : 's'}`);
+    } catch {
+      toast.error('PII scan failed');
+    }
+  }, [redactionState]);
+
+  // Outline — chapters at long pauses and topic shifts. Picking one seeks
+  // there and scrolls the transcript to the segment it starts in.
+  const outline = useOutline(recordingId);
+  const activeChapter = useMemo(
+    () => chapterIndexAt(outline.chapters, currentTime),
+    [outline.chapters, currentTime],
+  );
+  const seekAndReveal = useCallback(
+    (seconds: number) => {
+      if (audioRef.current) audioRef.current.currentTime = seconds;
+      setCurrentTime(seconds);
+      const index = segments.findIndex((seg) => seg.end > seconds);
+      if (index < 0) return;
+      transcriptContainerRef.current
+        ?.querySelector(`[data-segment-idx="${index}"]`)
+        ?.scrollIntoView({ block: 'center', behavior: 'smooth' });
+    },
+    [segments],
+  );
+  const handleChapterSelect = useCallback(
+    (chapter: Chapter) => seekAndReveal(chapter.start_time),
+    [seekAndReveal],
+  );
+  const handleRebuildOutline = useCallback(async () => {
+    setOptionsMenuOpen(false);
+    try {
+      const count = await outline.rebuild();
+      if (count === 0) toast.success('Too short or too uniform for chapters');
+      else toast.success(`Outline rebuilt — ${count} chapters`);
+    } catch {
+      toast.error('Failed to rebuild outline');
+    }
+  }, [outline]);
+
+  const hasSpeakers = useMemo(() => segments.some((s) => !!s.speaker), [segments]);
+
+  // Speaker statistics — refetched when the transcript or speaker names change.
+  const speakerStatsKey = useMemo(
+    () => [transcription?.segments, aliasState.aliases],
+    [transcription?.segments, aliasState.aliases],
+  );
+  const speakerStats = useSpeakerStats(hasSpeakers ? recordingId : null, speakerStatsKey);
+  const [exportSpeakerStats, setExportSpeakerStats] = useState(false);
+  useEffect(() => {
+    void getConfig<boolean>('output.speakerStats').then((v) => setExportSpeakerStats(v ?? false));
+  }, []);
+  const handleExportSpeakerStatsChange = useCallback((next: boolean) => {
+    setExportSpeakerStats(next);
+    void setConfig('output.speakerStats', next);
+  }, []);
+  const handleIdentifySpeakers = useCallback(async () => {
+    setOptionsMenuOpen(false);
+    if (!recordingId) return;
+    try {
+      const result = await apiClient.identifySpeakers(recordingId);
+      aliasState.refresh();
+      const names = Object.values(result.matched);
+      if (names.length === 0) toast.success('No enrolled voices recognised');
+      else toast.success(`Recognised ${names.join(', ')}`);
+    } catch (err) {
+      const message = err instanceof Error ? err.message : 'Unknown error';
+      toast.error(`Speaker identification failed: ${message}`);
+    }
+  }, [recordingId, aliasState]);
+
+  const canReview =
+    hasSegmentDetail && !hasCorrected && segments.length > 0 && segments.every((s) => s.id != null);
+  const reviewActive = isReviewMode && canReview && !isTranscriptEditing;
+  const reviewedCount = segmentReview.progress.approved + segmentReview.progress.flagged;
+  const reviewStates = useMemo(
+    () =>
+      segments.map((seg) => (seg.id != null ? (segmentReview.states.get(seg.id) ?? null) : null)),
+    [segments, segmentReview.states],
+  );
+  const focusReviewSegment = useCallback(
+    (index: number | null) => {
+      if (index === null) return;
+      setReviewIndex(index);
+      const seg = segments[index];
+      if (seg && audioRef.current) audioRef.current.currentTime = seg.start;
+      transcriptContainerRef.current
+        ?.querySelector(`[data-segment-idx="${index}"]`)
+        ?.scrollIntoView({ block: 'center', behavior: 'smooth' });
+    },
+    [segments],
+  );
+  const jumpToReviewTarget = useCallback(
+    (target: ReviewTarget, direction: 1 | -1, states = reviewStates) => {
+      const next = findReviewTarget(states, reviewIndex, target, direction);
+      if (next === null) {
+        toast.info(target === 'flagged' ? 'No flagged segments' : 'Every segment is reviewed');
+        return;
+      }
+      focusReviewSegment(next);
+    },
+    [reviewStates, reviewIndex, focusReviewSegment],
+  );
+  const markReviewSegment = useCallback(
+    (state: 'approved' | 'flagged' | null) => {
+      const id = segments[reviewIndex]?.id;
+      if (id == null) return;
+      segmentReview.setState([id], state).catch(() => {
+        toast.error('Failed to save review state');
+      });
+      if (state === null) return;
+      // Advance against the post-update states so a just-approved segment
+      // is not picked again.
+      const after = reviewStates.map((st, i) => (i === reviewIndex ? state : st));
+      const next = findReviewTarget(after, reviewIndex, 'unreviewed', 1);
+      if (next !== null) focusReviewSegment(next);
+    },
+    [segments, reviewIndex, reviewStates, segmentReview, focusReviewSegment],
+  );
+  const handleToggleReviewMode = useCallback(() => {
+    if (isReviewMode) {
+      setIsReviewMode(false);
+      return;
+    }
+    setIsReviewMode(true);
+    // Start on the first unreviewed segment.
+    focusReviewSegment(findReviewTarget(reviewStates, -1, 'unreviewed', 1) ?? 0);
+  }, [isReviewMode, reviewStates, focusReviewSegment]);
+
+  useEffect(() => {
+    if (!isOpen || !reviewActive) return;
+    const handler = (event: KeyboardEvent) => {
+      const target = event.target as HTMLElement | null;
+      if (target) {
+        const tag = target.tagName;
+        if (tag === 'INPUT' || tag === 'TEXTAREA' || target.isContentEditable) return;
+      }
+      if (event.ctrlKey || event.metaKey || event.altKey) return;
+      const back = event.shiftKey ? -1 : 1;
+      switch (event.key.toLowerCase()) {
+        case 'a':
+          markReviewSegment('approved');
+          break;
+        case 'f':
+          markReviewSegment('flagged');
+          break;
+        case 'u':
+          markReviewSegment(null);
+          break;
+        case 'n':
+          jumpToReviewTarget('unreviewed', back);
+          break;
+        case 'm':
+          jumpToReviewTarget('flagged', back);
+          break;
+        case 'arrowdown':
+          focusReviewSegment(Math.min(segments.length - 1, reviewIndex + 1));
+          break;
+        case 'arrowup':
+          focusReviewSegment(Math.max(0, reviewIndex - 1));
+          break;
+        case 'escape':
+          setIsReviewMode(false);
+          break;
+        default:
+          return;
+      }
+      event.preventDefault();
+      event.stopPropagation();
+    };
+    document.addEventListener('keydown', handler);
+    return () => document.removeEventListener('keydown', handler);
+  }, [
+    isOpen,
+    reviewActive,
+    markReviewSegment,
+    jumpToReviewTarget,
+    focusReviewSegment,
+    segments.length,
+    reviewIndex,
+  ]);
+
   const handleSaveCorrectedTranscript = useCallback(
     async (text: string) => {
       if (!recordingId) return;
@@ -1060,6 +1421,128 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
     }
   }, [recordingId]);
 
+  /**
+   * Apply the corrected transcript to the timed view: the server keeps the
+   * timings of unchanged words and re-aligns the edited ones, then clears the
+   * overlay so click-to-seek works on the corrected text.
+   */
+  const handleRealignTranscript = useCallback(async () => {
+    if (!recordingId) return;
+    const ok = await confirm(
+      'Apply your edits to the timed transcript? Word timings are re-aligned to the audio ' +
+        'and the original segment text is replaced.',
+      { confirmLabel: 'Realign' },
+    );
+    if (!ok) return;
+    try {
+      const result = await apiClient.realignTranscript(recordingId);
+      setCorrectedTranscript(null);
+      setIsTranscriptEditing(false);
+      refreshRecording();
+      onRecordingMutated?.();
+      toast.success(
+        result.method === 'wav2vec2'
+          ? `Transcript realigned (${result.word_count} words)`
+          : `Edits applied — timings interpolated (${result.word_count} words)`,
+      );
+    } catch (err) {
+      const message = err instanceof Error ? err.message : 'Unknown error';
+      toast.error(`Could not realign transcript: ${message}`);
+    }
+  }, [recordingId, confirm, refreshRecording, onRecordingMutated]);
+
+  /**
+   * Redo one segment with the server's bigger re-transcription model and
+   * splice the result in — for the odd garbled line, without re-running
+   * the whole recording.
+   */
+  const [retranscribingSegmentId, setRetranscribingSegmentId] = useState<number | null>(null);
+  const handleRetranscribeSegment = useCallback(
+    async (segmentId: number) => {
+      if (!recordingId || retranscribingSegmentId !== null) return;
+      const ok = await confirm(
+        'Re-transcribe this segment with a larger model? Its text and word timings are ' +
+          'replaced; the first run may take a while as the model loads.',
+        { confirmLabel: 'Re-transcribe' },
+      );
+      if (!ok) return;
+      setRetranscribingSegmentId(segmentId);
+      try {
+        const result = await apiClient.retranscribeSegment(recordingId, segmentId);
+        refreshRecording();
+        onRecordingMutated?.();
+        toast.success('Segment re-transcribed', {
+          description: `Was: “${result.previous_text}”`,
+        });
+      } catch (err) {
+        const message = err instanceof Error ? err.message : 'Unknown error';
+        toast.error(`Could not re-transcribe segment: ${message}`);
+      } finally {
+        setRetranscribingSegmentId(null);
+      }
+    },
+    [recordingId, retranscribingSegmentId, confirm, refreshRecording, onRecordingMutated],
+  );
+
+  /**
+   * A transcription that stopped partway kept what it finished; transcribe
+   * the rest of the audio from where it stopped and append it.
+   */
+  const [resumingTranscript, setResumingTranscript] = useState(false);
+  const handleResumeTranscript = useCallback(async () => {
+    if (!recordingId || resumingTranscript) return;
+    setResumingTranscript(true);
+    try {
+      const result = await apiClient.resumeRecording(recordingId);
+      refreshRecording();
+      onRecordingMutated?.();
+      if (result.partial) {
+        toast.warning(
+          `Transcription stopped again at ${formatClock(result.partial_until ?? result.offset)}`,
+          { description: result.partial_reason ?? undefined },
+        );
+      } else {
+        toast.success(`Transcript completed from ${formatClock(result.offset)}`);
+      }
+    } catch (err) {
+      const message = err instanceof Error ? err.message : 'Unknown error';
+      toast.error(`Could not resume transcription: ${message}`);
+    } finally {
+      setResumingTranscript(false);
+    }
+  }, [recordingId, resumingTranscript, refreshRecording, onRecordingMutated]);
+
+  // Caption fixes: Shift+click a word to split its segment there, or merge a
+  // segment into the next. The server only re-parents words, never re-times.
+  const handleSplitSegment = useCallback(
+    async (segmentId: number, atSeconds: number) => {
+      if (!recordingId) return;
+      try {
+        await apiClient.splitSegment(recordingId, segmentId, atSeconds * 1000);
+        refreshRecording();
+        onRecordingMutated?.();
+      } catch (err) {
+        const message = err instanceof Error ? err.message : 'Unknown error';
+        toast.error(`Could not split segment: ${message}`);
+      }
+    },
+    [recordingId, refreshRecording, onRecordingMutated],
+  );
+  const handleMergeSegments = useCallback(
+    async (segmentIds: number[]) => {
+      if (!recordingId) return;
+      try {
+        await apiClient.mergeSegments(recordingId, segmentIds);
+        refreshRecording();
+        onRecordingMutated?.();
+      } catch (err) {
+        const message = err instanceof Error ? err.message : 'Unknown error';
+        toast.error(`Could not merge segments: ${message}`);
+      }
+    },
+    [recordingId, refreshRecording, onRecordingMutated],
+  );
+
   const handleClearSummary = useCallback(async () => {
     if (!note?.recordingId) return;
     if (
@@ -1232,6 +1715,22 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
     );
   };
 
+  // OS media keys + MPRIS / SMTC overlay drive the same <audio> element.
+  useMediaSession(audioRef, {
+    enabled: isOpen && !!audioUrl,
+    title: recording?.title ?? note?.title ?? '',
+    subtitle: note?.date,
+  });
+  useHidPedal(audioRef, isOpen && !!audioUrl);
+  useReviewTimeTracking(audioRef, {
+    enabled: isOpen,
+    recordingId: recording?.id ?? null,
+    title: recording?.title ?? note?.title ?? '',
+    metadata: recording?.metadata,
+    audioSeconds: recording?.duration_seconds ?? 0,
+  });
+  const keymap = useKeymap();
+
   // GH #97: Keyboard shortcuts for playback while the modal is open.
   // Reads audio.paused directly (not React's isPlaying) so the closure can
   // never go stale between renders, which lets us register the listener once
@@ -1245,15 +1744,15 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
         const tag = target.tagName;
         if (tag === 'INPUT' || tag === 'TEXTAREA' || target.isContentEditable) return;
       }
-      if (event.ctrlKey || event.metaKey || event.altKey) return;
-
       const audio = audioRef.current;
       if (!audio) return;
 
-      const key = event.key.toLowerCase();
-      const isPlayPause = event.code === 'Space' || key === 'k';
-      const isBack = key === 'j';
-      const isForward = key === 'l';
+      // Bindings come from the shortcut registry; bare Space always toggles too.
+      const isMac = window.electronAPI?.app.getPlatform() === 'darwin';
+      const bareSpace = event.code === 'Space' && !event.ctrlKey && !event.metaKey && !event.altKey;
+      const isPlayPause = bareSpace || matchesAccelerator(event, keymap['playback.toggle'], isMac);
+      const isBack = matchesAccelerator(event, keymap['playback.back'], isMac);
+      const isForward = matchesAccelerator(event, keymap['playback.forward'], isMac);
       if (!isPlayPause && !isBack && !isForward) return;
 
       event.preventDefault();
@@ -1277,7 +1776,7 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
 
     document.addEventListener('keydown', handler);
     return () => document.removeEventListener('keydown', handler);
-  }, [isOpen, note?.recordingId]);
+  }, [isOpen, note?.recordingId, keymap]);
 
   // LLM Chat handler — sends user message and streams assistant response
   const handleSendMessage = useCallback(
@@ -1668,6 +2167,20 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
     setOptionsMenuOpen(false);
   }, [recording?.title, note?.title]);
 
+  /** Toggle the "keep audio forever" exemption from the source-audio retention policy. */
+  const handleToggleKeepAudio = useCallback(async () => {
+    setOptionsMenuOpen(false);
+    if (!note?.recordingId) return;
+    const keep = !recording?.keep_audio;
+    try {
+      await apiClient.updateRecordingKeepAudio(note.recordingId, keep);
+      refreshRecording();
+      toast.success(keep ? 'Audio will be kept forever' : 'Audio follows the retention policy');
+    } catch {
+      toast.error('Failed to update audio retention');
+    }
+  }, [note?.recordingId, recording?.keep_audio, refreshRecording]);
+
   /** Commit a recording rename. No-op when the title is unchanged or empty. */
   const handleRecordingRenameCommit = useCallback(async () => {
     if (!recordingRenameDialog || !note?.recordingId) return;
@@ -1697,10 +2210,14 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
 
   /** Open the recording export download for the requested format. */
   const handleRecordingExport = useCallback(
-    (format: 'txt' | 'srt' | 'ass') => {
+    (
+      format: ExportFormat,
+      query?: string,
+      options?: { annotations?: boolean; captionProfile?: string },
+    ) => {
       setOptionsMenuOpen(false);
       if (!note?.recordingId) return;
-      const url = apiClient.getExportUrl(note.recordingId, format);
+      const url = apiClient.getExportUrl(note.recordingId, format, query, options);
       if (url === null) {
         toast.error('Remote host not configured. Open Settings → Connection.');
         return;
@@ -1712,43 +2229,324 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
     [note?.recordingId],
   );
 
+  /** Lint the subtitle cues; offer the re-wrapped SRT when that fixes something. */
+  const handleCaptionLint = useCallback(async () => {
+    setOptionsMenuOpen(false);
+    if (!note?.recordingId) return;
+    try {
+      const result = await apiClient.lintCaptions(note.recordingId);
+      const total = result.violations.length;
+      if (total === 0) {
+        toast.success(`Captions meet the ${result.profile.name} profile`);
+        return;
+      }
+      const first = result.violations[0];
+      const fixable = total - result.remaining_after_fix;
+      toast.warning(`${total} caption issue${total === 1 ? '' : 's'} (${result.profile.name})`, {
+        description: `${formatSegTime(first.start)} — ${first.message}${
+          fixable > 0 ? `. Re-wrapping fixes ${fixable}.` : ''
+        }`,
+        action:
+          fixable > 0
+            ? {
+                label: 'Export fixed SRT',
+                onClick: () =>
+                  handleRecordingExport('srt', undefined, { captionProfile: result.profile.name }),
+              }
+            : {
+                label: 'Jump',
+                onClick: () => {
+                  if (audioRef.current) audioRef.current.currentTime = first.start;
+                },
+              },
+      });
+    } catch (error) {
+      toast.error(error instanceof Error ? error.message : 'Caption check failed');
+    }
+  }, [note?.recordingId, handleRecordingExport, formatSegTime]);
+
+  /** Preview the routed output paths, then write them on confirm. */
+  const handleRoutedExport = useCallback(async () => {
+    setOptionsMenuOpen(false);
+    if (!note?.recordingId) return;
+    const request = { recording_ids: [note.recordingId] };
+    try {
+      const { outputs } = await apiClient.previewOutputs(request);
+      const routed = outputs.filter((o) => o.action === 'write' || o.action === 'overwrite');
+      if (routed.length === 0) {
+        toast.error(
+          outputs.some((o) => o.action === 'skip')
+            ? 'Every output file already exists'
+            : 'No output folder configured (output_manager in config.yaml)',
+        );
+        return;
+      }
+      toast.info(`Save ${routed.length} file${routed.length === 1 ? '' : 's'}?`, {
+        description: routed
+          .map((o) => `${o.path}${o.action === 'overwrite' ? ' (replace)' : ''}`)
+          .join('\n'),
+        action: {
+          label: 'Save',
+          onClick: async () => {
+            try {
+              const result = await apiClient.exportOutputs(request);
+              const failed = result.outputs.filter((o) => o.status === 'failed');
+              if (failed.length > 0) {
+                toast.error(`${failed.length} output${failed.length === 1 ? '' : 's'} failed`, {
+                  description: failed.map((o) => `${o.filename}: ${o.error}`).join('\n'),
+                });
+              } else {
+                toast.success(`Saved ${result.written} file${result.written === 1 ? '' : 's'}`);
+              }
+            } catch (error) {
+              toast.error(error instanceof Error ? error.message : 'Export failed');
+            }
+          },
+        },
+      });
+    } catch (error) {
+      toast.error(error instanceof Error ? error.message : 'Could not plan outputs');
+    }
+  }, [note?.recordingId]);
+
+  /** Download the media with every redacted range bleeped or silenced. */
+  const handleRedactedMediaExport = useCallback(
+    (mode: RedactionAudioMode) => {
+      setOptionsMenuOpen(false);
+      if (!note?.recordingId) return;
+      const url = apiClient.getRedactedMediaUrl(note.recordingId, mode);
+      if (url === null) {
+        toast.error('Remote host not configured. Open Settings → Connection.');
+        return;
+      }
+      window.open(url, '_blank', 'noopener,noreferrer');
+    },
+    [note?.recordingId],
+  );
+
+  // Share targets come from the server config; the menu item only shows when
+  // at least one is configured.
+  useEffect(() => {
+    if (!isOpen) return;
+    let cancelled = false;
+    apiClient
+      .listShareTargets()
+      .then(({ targets }) => {
+        if (!cancelled) setShareTargets(targets);
+      })
+      .catch(() => {
+        if (!cancelled) setShareTargets([]);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [isOpen]);
+
+  /**
+   * Send the export to a share target, storing any credentials entered in the
+   * dialog first. Email drafts (no SMTP relay) are saved as `.eml` and opened
+   * in the desktop mail client.
+   */
+  const handleShare = useCallback(
+    async (target: string, format: ShareFormat, credentials: Record<string, string>) => {
+      if (!note?.recordingId) return;
+      setShareBusy(true);
+      try {
+        if (Object.keys(credentials).length > 0) {
+          const stored = await apiClient.setShareCredentials(target, credentials);
+          setShareTargets((prev) =>
+            prev.map((t) =>
+              t.name === target ? { ...t, missing_credentials: stored.missing_credentials } : t,
+            ),
+          );
+        }
+        const result = await apiClient.shareRecording(note.recordingId, target, format);
+        setShareDialogOpen(false);
+        if (result.status !== 'draft' || !result.eml) {
+          toast.success(
+            result.status === 'uploaded' ? `Uploaded to ${target}` : `Sent to ${target}`,
+          );
+          return;
+        }
+        const filename = result.filename ?? 'transcript.eml';
+        const fileIO = window.electronAPI?.fileIO;
+        if (!fileIO?.saveFile || !fileIO.writeText) {
+          const url = URL.createObjectURL(new Blob([result.eml], { type: 'message/rfc822' }));
+          const link = document.createElement('a');
+          link.href = url;
+          link.download = filename;
+          document.body.appendChild(link);
+          link.click();
+          document.body.removeChild(link);
+          URL.revokeObjectURL(url);
+          return;
+        }
+        const path = await fileIO.saveFile({
+          defaultPath: filename,
+          filters: [{ name: 'Email', extensions: ['eml'] }],
+        });
+        if (!path) return;
+        await fileIO.writeText(path, result.eml);
+        await window.electronAPI?.app?.openPath(path);
+      } catch (error) {
+        toast.error(error instanceof Error ? error.message : 'Share failed');
+      } finally {
+        setShareBusy(false);
+      }
+    },
+    [note?.recordingId],
+  );
+
+  /** Download the whole recording as a `.tsproj` project bundle. */
+  const handleProjectExport = useCallback(
+    (embedMedia: boolean) => {
+      setOptionsMenuOpen(false);
+      if (!note?.recordingId) return;
+      const url = apiClient.getProjectBundleUrl(note.recordingId, embedMedia);
+      if (url === null) {
+        toast.error('Remote host not configured. Open Settings → Connection.');
+        return;
+      }
+      window.open(url, '_blank', 'noopener,noreferrer');
+    },
+    [note?.recordingId],
+  );
+
   /**
    * Issue #104, Story 3.5 — download the FR9-format plain-text transcript
    * via the native OS file-save dialog. Uses the new `format=plaintext`
    * branch on the export route (StreamingResponse, paragraph per speaker
-   * turn, no subtitle timestamps).
+   * turn, no subtitle timestamps). `accessible` is the screen-reader variant
+   * (speaker named on every turn, no markdown).
    */
-  const handleDownloadPlaintextTranscript = useCallback(async () => {
+  const handleDownloadTextTranscript = useCallback(
+    async (format: 'plaintext' | 'accessible') => {
+      setOptionsMenuOpen(false);
+      if (!note?.recordingId) return;
+      const url = apiClient.getExportUrl(note.recordingId, format, undefined, {
+        speakerStats: format === 'plaintext' && exportSpeakerStats,
+      });
+      if (url === null) {
+        toast.error('Remote host not configured. Open Settings → Connection.');
+        return;
+      }
+      const fileIO = window.electronAPI?.fileIO;
+      if (!fileIO?.saveFile || !fileIO.writeText) {
+        // Fallback: just open the URL — browser will save via its own dialog.
+        window.open(url, '_blank', 'noopener,noreferrer');
+        return;
+      }
+      try {
+        const suffix = format === 'accessible' ? '_screen_reader' : '';
+        const defaultName = `${(note.title || 'recording').replace(/\s+/g, '_')}${suffix}.txt`;
+        const target = await fileIO.saveFile({
+          defaultPath: defaultName,
+          filters: [{ name: 'Text', extensions: ['txt'] }],
+        });
+        if (!target) return;
+        const response = await fetch(url);
+        if (!response.ok) throw new Error(`Server returned ${response.status}`);
+        const content = filterText(await response.text(), await loadOutputFilter());
+        await fileIO.writeText(target, content);
+        toast.success(`Transcript saved to ${target}`);
+      } catch (err) {
+        const message = err instanceof Error ? err.message : 'Unknown error';
+        toast.error(`Could not save transcript: ${message}`);
+      }
+    },
+    [note?.recordingId, note?.title, exportSpeakerStats],
+  );
+
+  /**
+   * Printable PDF export — fetch the server's print-ready HTML
+   * (`format=html`) and hand it to the main process, which renders it via
+   * Chromium `printToPDF` with running header/footer and page numbers.
+   * Styling comes from the `pdf.*` client config keys.
+   */
+  const handleDownloadPdf = useCallback(async () => {
     setOptionsMenuOpen(false);
     if (!note?.recordingId) return;
-    const url = apiClient.getExportUrl(note.recordingId, 'plaintext' as never);
-    if (url === null) {
-      toast.error('Remote host not configured. Open Settings → Connection.');
+    const fileIO = window.electronAPI?.fileIO;
+    if (!fileIO?.saveFile || !fileIO.exportPdf) {
+      toast.error('PDF export is only available in the desktop app.');
       return;
     }
-    const fileIO = window.electronAPI?.fileIO;
-    if (!fileIO?.saveFile || !fileIO.writeText) {
-      // Fallback: just open the URL — browser will save via its own dialog.
-      window.open(url, '_blank', 'noopener,noreferrer');
+    const [header, footer, speakerStyle, timestamps, pageNumbers, pageSize, fontSize] =
+      await Promise.all([
+        getConfig<string>('pdf.header'),
+        getConfig<string>('pdf.footer'),
+        getConfig<'bold' | 'caps' | 'plain'>('pdf.speakerStyle'),
+        getConfig<boolean>('pdf.timestamps'),
+        getConfig<'none' | 'page' | 'page-of-total'>('pdf.pageNumbers'),
+        getConfig<'A4' | 'Letter' | 'Legal'>('pdf.pageSize'),
+        getConfig<number>('pdf.fontSize'),
+      ]);
+    const url = apiClient.getPrintExportUrl(note.recordingId, {
+      speakerStyle,
+      timestamps,
+      fontSize,
+      pageSize,
+      speakerStats: exportSpeakerStats,
+    });
+    if (url === null) {
+      toast.error('Remote host not configured. Open Settings → Connection.');
       return;
     }
+    const title = note.title || 'Recording';
+    const expand = (template: string | undefined) =>
+      (template ?? '')
+        .replace(/\{title\}/g, title)
+        .replace(/\{date\}/g, note.date ?? '');
     try {
-      const defaultName = `${(note.title || 'recording').replace(/\s+/g, '_')}.txt`;
+      const defaultName = `${title.replace(/\s+/g, '_')}.pdf`;
       const target = await fileIO.saveFile({
         defaultPath: defaultName,
-        filters: [{ name: 'Text', extensions: ['txt'] }],
+        filters: [{ name: 'PDF', extensions: ['pdf'] }],
       });
       if (!target) return;
       const response = await fetch(url);
       if (!response.ok) throw new Error(`Server returned ${response.status}`);
-      const content = await response.text();
-      await fileIO.writeText(target, content);
-      toast.success(`Transcript saved to ${target}`);
+      const html = await response.text();
+      await fileIO.exportPdf(html, target, {
+        header: expand(header),
+        footer: expand(footer),
+        pageNumbers,
+        pageSize,
+      });
+      toast.success(`PDF saved to ${target}`);
     } catch (err) {
       const message = err instanceof Error ? err.message : 'Unknown error';
-      toast.error(`Could not save transcript: ${message}`);
+      toast.error(`Could not export PDF: ${message}`);
     }
-  }, [note?.recordingId, note?.title]);
+  }, [note?.recordingId, note?.title, note?.date, exportSpeakerStats]);
+
+  /**
+   * Import an Audacity label track as new segment boundaries. Previews the
+   * segment count (dry run) and asks for confirmation before rewriting.
+   */
+  const handleImportAudacityLabels = useCallback(
+    async (file: File) => {
+      if (!note?.recordingId) return;
+      try {
+        const content = await file.text();
+        const preview = await apiClient.importAudacityLabels(note.recordingId, content, true);
+        const ok = await confirm(
+          `Re-segment this transcript into ${preview.segment_count} segments from ` +
+            `${preview.label_count} Audacity labels? Words are kept; segment boundaries change.`,
+          { confirmLabel: 'Re-segment' },
+        );
+        if (!ok) return;
+        await apiClient.importAudacityLabels(note.recordingId, content);
+        refreshRecording();
+        onRecordingMutated?.();
+        toast.success(`Transcript re-segmented (${preview.segment_count} segments)`);
+      } catch (err) {
+        const message = err instanceof Error ? err.message : 'Unknown error';
+        toast.error(`Could not import labels: ${message}`);
+      }
+    },
+    [note?.recordingId, confirm, refreshRecording, onRecordingMutated],
+  );
 
   /** Story 3.5 — download the AI summary as plain text. */
   const handleDownloadPlaintextSummary = useCallback(async () => {
@@ -1766,7 +2564,7 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
         filters: [{ name: 'Text', extensions: ['txt'] }],
       });
       if (!target) return;
-      await fileIO.writeText(target, summaryText);
+      await fileIO.writeText(target, filterText(summaryText, await loadOutputFilter()));
       toast.success(`Summary saved to ${target}`);
     } catch (err) {
       const message = err instanceof Error ? err.message : 'Unknown error';
@@ -1822,6 +2620,42 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
         onCancel={() => setDeleteDialogOpen(false)}
         onConfirm={handleConfirmRecordingDelete}
       />
+      <input
+        ref={labelFileInputRef}
+        type="file"
+        accept=".txt,text/plain"
+        className="hidden"
+        onChange={(e) => {
+          const file = e.target.files?.[0];
+          e.target.value = '';
+          if (file) void handleImportAudacityLabels(file);
+        }}
+      />
+      <MarkerExportDialog
+        open={markerDialogOpen}
+        onCancel={() => setMarkerDialogOpen(false)}
+        onExport={(format, keyword) => {
+          setMarkerDialogOpen(false);
+          handleRecordingExport(format, keyword);
+        }}
+      />
+      <ShareDialog
+        open={shareDialogOpen}
+        targets={shareTargets}
+        busy={shareBusy}
+        onCancel={() => setShareDialogOpen(false)}
+        onShare={(target, format, credentials) => void handleShare(target, format, credentials)}
+      />
+      <TimecodeSettingsDialog
+        open={timecodeDialogOpen}
+        value={timecodeState.timecode}
+        onCancel={() => setTimecodeDialogOpen(false)}
+        onSave={async (next) => {
+          await timecodeState.setTimecode(next);
+          setTimecodeDialogOpen(false);
+          toast.success(next ? 'Timecode saved' : 'Timecode cleared');
+        }}
+      />
       {renameDialog &&
         createPortal(
           <div className="fixed inset-0 z-10000 flex items-center justify-center p-4">
@@ -2025,17 +2859,54 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
                           >
                             <Edit2 size={14} /> Rename
                           </button>
+                          <button
+                            onClick={() => {
+                              setOptionsMenuOpen(false);
+                              setTimecodeDialogOpen(true);
+                            }}
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                          >
+                            <Clock size={14} /> Timecode…
+                          </button>
+                          <button
+                            onClick={() => void handleToggleKeepAudio()}
+                            disabled={Boolean(recording?.audio_retired_at)}
+                            aria-pressed={Boolean(recording?.keep_audio)}
+                            title={
+                              recording?.audio_retired_at
+                                ? 'The audio was already removed by the retention policy'
+                                : "Never delete or archive this recording's audio"
+                            }
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white disabled:cursor-not-allowed disabled:opacity-50 disabled:hover:bg-transparent disabled:hover:text-slate-300"
+                          >
+                            <Archive size={14} /> Keep audio forever
+                            {recording?.keep_audio && <Check size={14} className="ml-auto" />}
+                          </button>
                           {/* Issue #104, Story 3.5 — Download transcript /
                               Download summary use the new plain-text streaming
                               format + native save dialog. The verbose Export
                               TXT/SRT/ASS items below stay for power users. */}
                           <button
-                            onClick={handleDownloadPlaintextTranscript}
+                            onClick={() => void handleDownloadTextTranscript('plaintext')}
                             aria-label="Download transcript as plain text"
                             className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
                           >
                             <Download size={14} /> Download transcript
                           </button>
+                          <button
+                            onClick={() => void handleDownloadTextTranscript('accessible')}
+                            aria-label="Download transcript formatted for screen readers"
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                          >
+                            <Download size={14} /> Download for screen readers
+                          </button>
+                          <button
+                            onClick={handleDownloadPdf}
+                            aria-label="Download transcript as PDF"
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                          >
+                            <FileText size={14} /> Download PDF
+                          </button>
                           <button
                             onClick={handleDownloadPlaintextSummary}
                             aria-label="Download summary as plain text"
@@ -2068,6 +2939,119 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
                           >
                             <Download size={14} /> Export ASS
                           </button>
+                          {shareTargets.length > 0 && (
+                            <button
+                              onClick={() => {
+                                setOptionsMenuOpen(false);
+                                setShareDialogOpen(true);
+                              }}
+                              className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                            >
+                              <Share size={14} /> Share…
+                            </button>
+                          )}
+                          <button
+                            onClick={() => void handleCaptionLint()}
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                          >
+                            <ClipboardCheck size={14} /> Check captions
+                          </button>
+                          <button
+                            onClick={() => void handleRoutedExport()}
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                          >
+                            <Download size={14} /> Save to output folders
+                          </button>
+                          {annotationState.threads.length > 0 && (
+                            <>
+                              <button
+                                onClick={() =>
+                                  handleRecordingExport('txt', undefined, { annotations: true })
+                                }
+                                className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                              >
+                                <Download size={14} /> Export TXT with notes
+                              </button>
+                              <button
+                                onClick={() =>
+                                  handleRecordingExport('ass', undefined, { annotations: true })
+                                }
+                                className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                              >
+                                <Download size={14} /> Export ASS with notes
+                              </button>
+                            </>
+                          )}
+                          <button
+                            onClick={() => void handleScanPii()}
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                          >
+                            <EyeOff size={14} /> Scan for personal info
+                          </button>
+                          {segments.length > 0 && (
+                            <button
+                              onClick={() => void handleRebuildOutline()}
+                              disabled={outline.loading}
+                              className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white disabled:cursor-not-allowed disabled:opacity-50 disabled:hover:bg-transparent disabled:hover:text-slate-300"
+                            >
+                              <ListTree size={14} /> Rebuild outline
+                            </button>
+                          )}
+                          {hasSpeakers && (
+                            <button
+                              onClick={() => void handleIdentifySpeakers()}
+                              className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                            >
+                              <UserCheck size={14} /> Identify speakers
+                            </button>
+                          )}
+                          {redactionState.ranges.length > 0 &&
+                            (['bleep', 'mute'] as const).map((mode) => (
+                              <button
+                                key={mode}
+                                onClick={() => handleRedactedMediaExport(mode)}
+                                className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                              >
+                                <EyeOff size={14} />{' '}
+                                {mode === 'bleep' ? 'Export bleeped audio' : 'Export muted audio'}
+                              </button>
+                            ))}
+                          <button
+                            onClick={() => handleProjectExport(true)}
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                          >
+                            <Download size={14} /> Export project (.tsproj)
+                          </button>
+                          <button
+                            onClick={() => handleProjectExport(false)}
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                          >
+                            <Download size={14} /> Export project, audio by reference
+                          </button>
+                          <button
+                            onClick={() => {
+                              setOptionsMenuOpen(false);
+                              setMarkerDialogOpen(true);
+                            }}
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                          >
+                            <Download size={14} /> Export markers…
+                          </button>
+                          <button
+                            onClick={() => handleRecordingExport('audacity')}
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                          >
+                            <Download size={14} /> Export Audacity labels
+                          </button>
+                          <button
+                            onClick={() => {
+                              setOptionsMenuOpen(false);
+                              labelFileInputRef.current?.click();
+                            }}
+                            className="flex w-full items-center gap-2 px-4 py-2 text-left text-sm text-slate-300 hover:bg-white/10 hover:text-white"
+                          >
+                            <Edit2 size={14} /> Import Audacity labels…
+                          </button>
                           <div className="my-1 h-px bg-white/10"></div>
                           <button
                             onClick={handleRecordingDelete}
@@ -2325,6 +3309,48 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
                   />
                 )}
 
+                {recording?.partial_until != null && (
+                  <PersistentInfoBanner
+                    severity="warning"
+                    message={
+                      resumingTranscript
+                        ? `Transcribing the rest from ${formatClock(recording.partial_until)}…`
+                        : `Partial transcript — stopped at ${formatClock(recording.partial_until)}` +
+                          (recording.partial_reason ? ` (${recording.partial_reason})` : '')
+                    }
+                    ctaLabel={
+                      resumingTranscript || recording.audio_retired_at ? undefined : 'Resume'
+                    }
+                    onCta={() => void handleResumeTranscript()}
+                  />
+                )}
+
+                {outline.chapters.length > 1 && !isTranscriptEditing && (
+                  <ChapterOutline
+                    chapters={outline.chapters}
+                    activeIndex={activeChapter}
+                    formatTime={formatSegTime}
+                    onSelect={handleChapterSelect}
+                  />
+                )}
+
+                {speakerStats && speakerStats.speakers.length > 1 && !isTranscriptEditing && (
+                  <SpeakerStatsPanel
+                    stats={speakerStats}
+                    includeInExports={exportSpeakerStats}
+                    onIncludeInExportsChange={handleExportSpeakerStatsChange}
+                    onSeek={seekAndReveal}
+                  />
+                )}
+
+                {recording && !isTranscriptEditing && (
+                  <RecordingMetadataPanel
+                    recordingId={recording.id}
+                    metadata={recording.metadata ?? {}}
+                    onSaved={refreshRecording}
+                  />
+                )}
+
                 {/* 3. Transcript - Added selectable-text to paragraphs */}
                 <div className="space-y-6">
                   <div
@@ -2366,6 +3392,56 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
                             >
                               <Pencil size={13} />
                             </button>
+                            {canReview && (
+                              <button
+                                type="button"
+                                onClick={handleToggleReviewMode}
+                                className={`pointer-events-auto inline-flex items-center gap-1.5 rounded-full border px-3 py-1 text-[11px] font-semibold tracking-wide transition ${reviewActive ? 'border-emerald-400/30 bg-emerald-500/20 text-emerald-200 hover:bg-emerald-500/30' : 'border-white/10 bg-[rgba(22,31,50,0.95)] text-slate-400 hover:bg-white/10 hover:text-white'}`}
+                                title={
+                                  reviewActive
+                                    ? REVIEW_SHORTCUT_HELP
+                                    : 'Review mode — approve or flag each segment'
+                                }
+                                aria-pressed={reviewActive}
+                              >
+                                <ClipboardCheck size={12} />
+                                {reviewActive
+                                  ? `${reviewedCount} / ${segmentReview.progress.total} reviewed`
+                                  : 'Review'}
+                                {reviewActive && segmentReview.progress.flagged > 0 && (
+                                  <span className="inline-flex items-center gap-0.5 text-rose-300">
+                                    <Flag size={10} />
+                                    {segmentReview.progress.flagged}
+                                  </span>
+                                )}
+                              </button>
+                            )}
+                            {!hasCorrected && lowConfidence.regions.length > 0 && (
+                              <span
+                                className="pointer-events-auto inline-flex items-center gap-1 rounded-full border border-amber-400/25 bg-amber-500/10 px-2 py-1 text-[11px] font-semibold tracking-wide text-amber-200"
+                                title={`Words below ${Math.round(LOW_CONFIDENCE_THRESHOLD * 100)}% confidence`}
+                              >
+                                <button
+                                  type="button"
+                                  onClick={() => handleJumpToRegion(-1)}
+                                  className="rounded text-amber-300 transition hover:text-amber-100"
+                                  aria-label="Previous uncertain passage"
+                                >
+                                  <ChevronLeft size={12} />
+                                </button>
+                                {regionCursor >= 0
+                                  ? `${regionCursor + 1} / ${lowConfidence.regions.length} uncertain`
+                                  : `${lowConfidence.regions.length} uncertain`}
+                                <button
+                                  type="button"
+                                  onClick={() => handleJumpToRegion(1)}
+                                  className="rounded text-amber-300 transition hover:text-amber-100"
+                                  aria-label="Next uncertain passage"
+                                >
+                                  <ChevronRight size={12} />
+                                </button>
+                              </span>
+                            )}
                             {hasCorrected && (
                               <span className="pointer-events-auto inline-flex items-center gap-1.5 rounded-full border border-amber-400/25 bg-amber-500/15 px-3 py-1 text-[11px] font-semibold tracking-wide text-amber-200 uppercase">
                                 Edited
@@ -2379,6 +3455,16 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
                                   <RotateCw size={12} />
                                   Revert
                                 </button>
+                                <button
+                                  type="button"
+                                  onClick={() => void handleRealignTranscript()}
+                                  className="inline-flex items-center gap-1 rounded text-amber-300 transition hover:text-amber-100"
+                                  title="Apply edits to the timed transcript and re-align word timings"
+                                  aria-label="Realign transcript"
+                                >
+                                  <AudioLines size={12} />
+                                  Realign
+                                </button>
                               </span>
                             )}
                           </>
@@ -2405,7 +3491,19 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
                       segments.map((seg, i) => (
                         <div
                           key={i}
-                          className={`group flex ${hasDiarizationTranscript ? 'gap-6' : ''}`}
+                          data-segment-idx={i}
+                          onClick={reviewActive ? () => setReviewIndex(i) : undefined}
+                          className={`group flex ${hasDiarizationTranscript ? 'gap-6' : ''} ${
+                            reviewActive
+                              ? `-ml-3 border-l-2 pl-3 ${
+                                  reviewStates[i] === 'approved'
+                                    ? 'border-emerald-400/60'
+                                    : reviewStates[i] === 'flagged'
+                                      ? 'border-rose-400/70'
+                                      : 'border-white/10'
+                                } ${i === reviewIndex ? 'rounded-r bg-white/5' : ''}`
+                              : ''
+                          }`}
                         >
                           {hasDiarizationTranscript && (
                             <div className="w-16 flex-none pt-1 text-right select-none">
@@ -2438,7 +3536,7 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
                               )}
                               {!hideTimestamps && (
                                 <div className="font-mono text-[10px] text-slate-500">
-                                  {formatRecSecs(seg.start)}
+                                  {formatSegTime(seg.start)}
                                 </div>
                               )}
                             </div>
@@ -2450,17 +3548,25 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
                                   <span
                                     key={wi}
                                     data-word-idx={segmentWordOffsets[i] + wi}
-                                    onClick={() => {
+                                    onClick={(e) => {
+                                      if (e.altKey) {
+                                        void handleToggleRedaction(w.start, w.end);
+                                        return;
+                                      }
+                                      if (e.shiftKey && wi > 0 && seg.id != null) {
+                                        void handleSplitSegment(seg.id, w.start);
+                                        return;
+                                      }
                                       if (audioRef.current) {
                                         audioRef.current.currentTime = w.start;
                                         audioRef.current.play().catch(() => {});
                                       }
                                     }}
-                                    className="hover:bg-accent-cyan/20 hover:text-accent-cyan cursor-pointer rounded px-px transition-colors duration-150"
+                                    className={`hover:bg-accent-cyan/20 hover:text-accent-cyan cursor-pointer rounded px-px transition-colors duration-150 ${typeof w.confidence === 'number' && w.confidence < LOW_CONFIDENCE_THRESHOLD ? 'underline decoration-amber-400/70 decoration-dotted underline-offset-4' : ''} ${isRedacted(w.start, w.end, redactionState.ranges) ? 'bg-slate-950 text-slate-500 line-through' : ''}`}
                                     title={
                                       hideTimestamps
                                         ? undefined
-                                        : `${formatRecSecs(w.start)} → ${formatRecSecs(w.end)}`
+                                        : `${formatSegTime(w.start)} → ${formatSegTime(w.end)}`
                                     }
                                   >
                                     {w.word}
@@ -2477,13 +3583,90 @@ export const AudioNoteModal: React.FC<AudioNoteModalProps> = ({
                                   }
                                 }}
                                 title={
-                                  hideTimestamps ? undefined : `Seek to ${formatRecSecs(seg.start)}`
+                                  hideTimestamps ? undefined : `Seek to ${formatSegTime(seg.start)}`
                                 }
                               >
                                 {seg.text}
                               </p>
                             )}
+                            <SegmentNotes
+                              threads={notesBySegment.get(i) ?? []}
+                              composing={noteComposeIndex === i}
+                              onCompose={(body) => handleAddNote(i, body)}
+                              onCancelCompose={() => setNoteComposeIndex(null)}
+                              onReply={handleReplyNote}
+                              onDelete={handleDeleteNote}
+                            />
                           </div>
+                          <button
+                            type="button"
+                            onClick={(e) => {
+                              e.stopPropagation();
+                              setNoteComposeIndex(i);
+                            }}
+                            className="ml-2 h-6 flex-none self-start rounded p-1 text-slate-500 opacity-0 transition group-hover:opacity-100 hover:bg-white/10 hover:text-amber-200"
+                            title="Add a note to this segment"
+                            aria-label="Add note"
+                          >
+                            <MessageSquare size={13} />
+                          </button>
+                          <button
+                            type="button"
+                            onClick={(e) => {
+                              e.stopPropagation();
+                              void handleToggleRedaction(seg.start, seg.end);
+                            }}
+                            className={`h-6 flex-none self-start rounded p-1 transition hover:bg-white/10 ${
+                              overlappingRedactions(seg.start, seg.end, redactionState.ranges)
+                                .length > 0
+                                ? 'text-rose-300'
+                                : 'text-slate-500 opacity-0 group-hover:opacity-100 hover:text-rose-300'
+                            }`}
+                            title="Redact this segment (Alt+click a word to redact one word)"
+                            aria-label="Toggle redaction"
+                          >
+                            <EyeOff size={13} />
+                          </button>
+                          {seg.id != null && !hasCorrected && (
+                            <button
+                              type="button"
+                              onClick={(e) => {
+                                e.stopPropagation();
+                                if (seg.id != null) void handleRetranscribeSegment(seg.id);
+                              }}
+                              disabled={retranscribingSegmentId !== null}
+                              className={`h-6 flex-none self-start rounded p-1 transition hover:bg-white/10 ${
+                                retranscribingSegmentId === seg.id
+                                  ? 'text-accent-cyan'
+                                  : 'text-slate-500 opacity-0 group-hover:opacity-100 hover:text-accent-cyan'
+                              }`}
+                              title="Re-transcribe this segment with a larger model"
+                              aria-label="Re-transcribe segment"
+                            >
+                              {retranscribingSegmentId === seg.id ? (
+                                <Loader2 size={13} className="animate-spin" />
+                              ) : (
+                                <RotateCw size={13} />
+                              )}
+                            </button>
+                          )}
+                          {seg.id != null && segments[i + 1]?.id != null && !hasCorrected && (
+                            <button
+                              type="button"
+                              onClick={(e) => {
+                                e.stopPropagation();
+                                const nextId = segments[i + 1]?.id;
+                                if (seg.id != null && nextId != null) {
+                                  void handleMergeSegments([seg.id, nextId]);
+                                }
+                              }}
+                              className="h-6 flex-none self-start rounded p-1 text-slate-500 opacity-0 transition group-hover:opacity-100 hover:bg-white/10 hover:text-accent-cyan"
+                              title="Merge with the next segment (Shift+click a word to split a segment there)"
+                              aria-label="Merge with next segment"
+                            >
+                              <Merge size={13} />
+                            </button>
+                          )}
                         </div>
                       ))
                     ) : (
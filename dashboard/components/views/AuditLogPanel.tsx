@@ -0,0 +1,115 @@
+import React, { useCallback, useEffect, useState } from 'react';
+import { RefreshCw } from 'lucide-react';
+import { apiClient } from '../../src/api/client';
+import type { AuditEvent } from '../../src/api/types';
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+/** `YYYY-MM-DD` of the local calendar day, as `<input type="date">` expects. */
+const isoDate = (date: Date) =>
+  new Date(date.getTime() - date.getTimezoneOffset() * 60_000).toISOString().slice(0, 10);
+
+/** A local calendar day's bound as a UTC timestamp — the server keeps UTC. */
+const dayBound = (day: string, end: boolean) =>
+  new Date(`${day}T${end ? '23:59:59.999' : '00:00:00'}`).toISOString();
+
+const INPUT = 'rounded bg-black/20 px-2 py-1 text-xs text-white focus:outline-none';
+
+/**
+ * The server's append-only audit log — deletions, redactions, bulk edits and
+ * settings changes — inside Settings → Manage Tokens. Filters by local day
+ * range (both ends inclusive) and action prefix; the initiating request is
+ * shown on hover.
+ */
+export const AuditLogPanel: React.FC = () => {
+  const [start, setStart] = useState(() => isoDate(new Date(Date.now() - 7 * DAY_MS)));
+  const [end, setEnd] = useState(() => isoDate(new Date()));
+  const [action, setAction] = useState('');
+  const [events, setEvents] = useState<AuditEvent[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const refresh = useCallback(() => {
+    apiClient
+      .getAuditLog({
+        start: start && dayBound(start, false),
+        end: end && dayBound(end, true),
+        action: action.trim(),
+      })
+      .then((res) => {
+        setEvents(res.events);
+        setError(null);
+      })
+      .catch((err) => {
+        setEvents(null);
+        setError(err instanceof Error ? err.message : String(err));
+      });
+  }, [start, end, action]);
+
+  useEffect(refresh, [refresh]);
+
+  return (
+    <div className="space-y-2 border-t border-white/10 pt-3">
+      <div className="flex items-center gap-2">
+        <p className="flex-1 text-[10px] font-semibold tracking-wider text-slate-500 uppercase">
+          Audit log
+        </p>
+        <input
+          type="date"
+          value={start}
+          onChange={(e) => setStart(e.target.value)}
+          aria-label="Audit log from"
+          className={INPUT}
+        />
+        <input
+          type="date"
+          value={end}
+          onChange={(e) => setEnd(e.target.value)}
+          aria-label="Audit log to"
+          className={INPUT}
+        />
+        <input
+          value={action}
+          onChange={(e) => setAction(e.target.value)}
+          placeholder="Action, e.g. recording"
+          aria-label="Audit log action"
+          className={`${INPUT} w-36`}
+        />
+        <button
+          onClick={refresh}
+          className="p-0.5 text-slate-500 transition-colors hover:text-white"
+          title="Refresh audit log"
+        >
+          <RefreshCw size={12} />
+        </button>
+      </div>
+      {error && <p className="text-[10px] text-red-400">{error}</p>}
+      {events && events.length === 0 && (
+        <p className="text-[10px] text-slate-500">Nothing recorded in this range.</p>
+      )}
+      {events && events.length > 0 && (
+        <div className="max-h-60 space-y-1 overflow-y-auto">
+          {events.map((event) => (
+            <div
+              key={event.id}
+              title={event.command}
+              className="flex items-center gap-2 rounded bg-black/20 px-2 py-1.5 text-xs"
+            >
+              <span className="text-[10px] whitespace-nowrap text-slate-500">
+                {new Date(event.occurred_at).toLocaleString()}
+              </span>
+              <span className="font-mono text-white">{event.action}</span>
+              {event.target_id && (
+                <span className="truncate text-slate-400">
+                  {event.target_type} {event.target_id}
+                </span>
+              )}
+              <span className="ml-auto truncate text-[10px] text-slate-500">
+                {event.actor ?? 'unknown'}
+              </span>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+};
@@ -0,0 +1,185 @@
+import React, { useEffect, useRef, useState } from 'react';
+import { FileAudio, Gauge } from 'lucide-react';
+import { Button } from '../ui/Button';
+import { getConfig, type ConnectionProfile } from '../../src/config/store';
+import {
+  PROFILE_LABELS,
+  apiBenchmarkDeps,
+  runBenchmark,
+  saveBenchmarkReport,
+  type BenchmarkReport,
+} from '../../src/services/benchmark';
+
+const PROFILES: ConnectionProfile[] = ['local', 'tailscale', 'lan'];
+
+const INPUT =
+  'focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-1.5 text-sm text-white placeholder-slate-600 focus:outline-none';
+
+const percent = (value: number) => `${(value * 100).toFixed(1)}%`;
+
+interface BenchmarkPanelProps {
+  /** Pre-selected server: the one the app connects to now. */
+  activeProfile: ConnectionProfile;
+}
+
+/**
+ * Settings → Server → Benchmark: compares servers and models on a reference
+ * clip the user supplies with its correct transcript (services/benchmark.ts).
+ * The recommended server and model become the default for new jobs.
+ */
+export const BenchmarkPanel: React.FC<BenchmarkPanelProps> = ({ activeProfile }) => {
+  const [profiles, setProfiles] = useState<ConnectionProfile[]>([activeProfile]);
+  const [models, setModels] = useState('');
+  const [file, setFile] = useState<File | null>(null);
+  const [reference, setReference] = useState('');
+  const [running, setRunning] = useState<string | null>(null);
+  const [report, setReport] = useState<BenchmarkReport | null>(null);
+  const fileInputRef = useRef<HTMLInputElement>(null);
+
+  useEffect(() => {
+    getConfig<BenchmarkReport | null>('dispatch.lastBenchmark')
+      .then((last) => setReport(last ?? null))
+      .catch(() => {});
+  }, []);
+
+  const toggleProfile = (profile: ConnectionProfile) =>
+    setProfiles((current) =>
+      current.includes(profile) ? current.filter((p) => p !== profile) : [...current, profile],
+    );
+
+  const run = async () => {
+    if (!file) return;
+    const modelList = models
+      .split(',')
+      .map((m) => m.trim())
+      .filter(Boolean);
+    const targets = PROFILES.filter((p) => profiles.includes(p)).map((profile) => ({
+      profile,
+      models: modelList,
+    }));
+    setRunning('Starting…');
+    try {
+      const next = await runBenchmark(
+        { file, reference },
+        targets,
+        apiBenchmarkDeps((target, index, total) =>
+          setRunning(`${PROFILE_LABELS[target.profile]} (${index + 1}/${total})…`),
+        ),
+      );
+      await saveBenchmarkReport(next);
+      setReport(next);
+    } finally {
+      setRunning(null);
+    }
+  };
+
+  const canRun = file !== null && reference.trim() !== '' && profiles.length > 0 && !running;
+  const recommended = report?.recommended;
+
+  return (
+    <div className="space-y-3">
+      <p className="text-xs text-slate-400">
+        Transcribe a clip whose correct transcript you know on each server, and compare speed
+        (realtime factor — lower is faster) and accuracy (word error rate). Each model loads and
+        warms up first, and running jobs must finish before a server is benchmarked.
+      </p>
+      <div className="flex flex-wrap items-center gap-3 text-xs text-slate-300">
+        {PROFILES.map((profile) => (
+          <label key={profile} className="flex items-center gap-1.5">
+            <input
+              type="checkbox"
+              checked={profiles.includes(profile)}
+              onChange={() => toggleProfile(profile)}
+            />
+            {PROFILE_LABELS[profile]}
+          </label>
+        ))}
+      </div>
+      <input
+        value={models}
+        onChange={(e) => setModels(e.target.value)}
+        placeholder="Models, comma-separated — blank uses each server's main model"
+        aria-label="Models to benchmark"
+        className={INPUT}
+      />
+      <div className="flex items-center gap-2">
+        <Button
+          variant="secondary"
+          size="sm"
+          icon={<FileAudio size={14} />}
+          onClick={() => fileInputRef.current?.click()}
+        >
+          {file ? 'Change clip' : 'Choose clip'}
+        </Button>
+        <span className="truncate text-xs text-slate-400">{file?.name ?? 'No clip chosen'}</span>
+        <input
+          ref={fileInputRef}
+          type="file"
+          accept=".mp3,.wav,.m4a,.flac,.ogg,.webm,.opus"
+          className="hidden"
+          onChange={(e) => {
+            setFile(e.target.files?.[0] ?? null);
+            e.target.value = '';
+          }}
+        />
+      </div>
+      <textarea
+        rows={3}
+        value={reference}
+        onChange={(e) => setReference(e.target.value)}
+        placeholder="The clip's correct transcript"
+        aria-label="Reference transcript"
+        className="focus:border-accent-cyan/50 w-full resize-y rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white placeholder-slate-600 focus:outline-none"
+      />
+      <Button
+        variant="secondary"
+        size="sm"
+        icon={<Gauge size={14} />}
+        onClick={() => void run()}
+        disabled={!canRun}
+      >
+        {running ? `Benchmarking ${running}` : 'Run benchmark'}
+      </Button>
+
+      {report && (
+        <div className="space-y-1">
+          <p className="text-xs text-slate-500">
+            {new Date(report.ranAt).toLocaleString()} · {report.sampleName}
+            {report.audioSeconds ? ` (${report.audioSeconds.toFixed(1)} s)` : ''}
+          </p>
+          <ul className="space-y-1">
+            {report.results.map((r, i) => {
+              const best = recommended?.profile === r.profile && recommended.model === r.model;
+              return (
+                <li
+                  key={i}
+                  className={`flex justify-between gap-3 rounded bg-black/20 px-2 py-1 text-xs ${
+                    best ? 'text-accent-cyan' : 'text-slate-400'
+                  }`}
+                >
+                  <span className="truncate">
+                    {PROFILE_LABELS[r.profile]} · {r.model || 'main model'}
+                  </span>
+                  {r.error ? (
+                    <span className="truncate text-red-400" title={r.error}>
+                      {r.error}
+                    </span>
+                  ) : (
+                    <span className="shrink-0">
+                      RTF {r.realtimeFactor?.toFixed(3)} · WER {percent(r.wer ?? 0)}
+                    </span>
+                  )}
+                </li>
+              );
+            })}
+          </ul>
+          <p className="text-xs text-slate-400">
+            {recommended
+              ? `New jobs default to ${recommended.model} on ${PROFILE_LABELS[recommended.profile]}.`
+              : 'No server finished the benchmark, so the job defaults are unchanged.'}
+          </p>
+        </div>
+      )}
+    </div>
+  );
+};
@@ -1,5 +1,13 @@
 import React, { useState, useEffect, useRef } from 'react';
-import { X, Bug, ExternalLink, CheckCircle } from 'lucide-react';
+import { X, Bug, ExternalLink, CheckCircle, XCircle, MinusCircle, Loader2 } from 'lucide-react';
+import {
+  STAGE_LABELS,
+  apiSelfTestDeps,
+  formatSelfTestReport,
+  runSelfTest,
+  type SelfTestReport,
+} from '../../src/services/selfTest';
+import { getNetworkTrace } from '../../src/services/networkTrace';
 
 interface BugReportModalProps {
   isOpen: boolean;
@@ -17,6 +25,8 @@ export const BugReportModal: React.FC<BugReportModalProps> = ({ isOpen, onClose
   const [clientLogPath, setClientLogPath] = useState<string>('');
   const [serverLogPath, setServerLogPath] = useState<string>('');
   const [submitted, setSubmitted] = useState(false);
+  const [selfTest, setSelfTest] = useState<SelfTestReport | null>(null);
+  const [selfTestRunning, setSelfTestRunning] = useState(false);
   const textareaRef = useRef<HTMLTextAreaElement>(null);
 
   const openExternal = async (url: string): Promise<void> => {
@@ -42,6 +52,7 @@ export const BugReportModal: React.FC<BugReportModalProps> = ({ isOpen, onClose
       setIsVisible(false);
       setSubmitted(false);
       setDescription('');
+      setSelfTest(null);
 
       const api = window.electronAPI;
       if (api?.app) {
@@ -85,10 +96,26 @@ export const BugReportModal: React.FC<BugReportModalProps> = ({ isOpen, onClose
     }
   }, [isVisible]);
 
+  const handleSelfTest = async () => {
+    setSelfTestRunning(true);
+    try {
+      setSelfTest(await runSelfTest(apiSelfTestDeps()));
+    } finally {
+      setSelfTestRunning(false);
+    }
+  };
+
   const handleSubmit = () => {
     const title = encodeURIComponent('Bug Report');
     const sysInfo = `**Version:** ${appVersion}\n**Platform:** ${platform}`;
-    const body = `## Description\n\n${description || '_No description provided._'}\n\n## System Info\n\n${sysInfo}\n\n---\n\n_Please attach your log files to this issue before submitting (paths shown in the app)._`;
+    const selfTestInfo = selfTest
+      ? `\n\n## Self-test\n\n\`\`\`\n${formatSelfTestReport(selfTest)}\n\`\`\``
+      : '';
+    const traceInfo =
+      getNetworkTrace().length > 0
+        ? ' A network trace was recorded: use Copy in Settings → App → Network Trace and paste it here.'
+        : '';
+    const body = `## Description\n\n${description || '_No description provided._'}\n\n## System Info\n\n${sysInfo}${selfTestInfo}\n\n---\n\n_Please attach your log files to this issue before submitting (paths shown in the app).${traceInfo}_`;
 
     const url = `${REPO_ISSUES_URL}?title=${title}&body=${encodeURIComponent(body)}`;
     void openExternal(url);
@@ -158,6 +185,52 @@ export const BugReportModal: React.FC<BugReportModalProps> = ({ isOpen, onClose
                 </p>
               </div>
 
+              {/* End-to-end self-test */}
+              <div className="rounded-xl border border-white/10 bg-white/5 px-4 py-3">
+                <div className="flex items-center justify-between gap-3">
+                  <p className="text-xs font-medium text-slate-400">
+                    Self-test
+                    {selfTest && (
+                      <span className={selfTest.passed ? 'text-green-400' : 'text-red-400'}>
+                        {selfTest.passed ? ' · passed' : ' · failed'}
+                      </span>
+                    )}
+                  </p>
+                  <button
+                    onClick={() => void handleSelfTest()}
+                    disabled={selfTestRunning}
+                    className="flex items-center gap-1.5 rounded-lg border border-white/10 bg-white/5 px-2.5 py-1 text-xs text-white transition-colors hover:bg-white/10 disabled:opacity-50"
+                  >
+                    {selfTestRunning && <Loader2 size={12} className="animate-spin" />}
+                    {selfTestRunning ? 'Running…' : selfTest ? 'Run again' : 'Run self-test'}
+                  </button>
+                </div>
+                {selfTest ? (
+                  <ul className="mt-2 space-y-1">
+                    {selfTest.stages.map((s) => (
+                      <li key={s.stage} className="flex items-start gap-2 text-xs">
+                        {s.status === 'pass' ? (
+                          <CheckCircle size={14} className="mt-px shrink-0 text-green-400" />
+                        ) : s.status === 'fail' ? (
+                          <XCircle size={14} className="mt-px shrink-0 text-red-400" />
+                        ) : (
+                          <MinusCircle size={14} className="mt-px shrink-0 text-slate-500" />
+                        )}
+                        <span className="text-slate-300">
+                          {STAGE_LABELS[s.stage]}
+                          <span className="text-slate-500"> — {s.detail}</span>
+                        </span>
+                      </li>
+                    ))}
+                  </ul>
+                ) : (
+                  <p className="mt-1 text-xs text-slate-500">
+                    Transcribes a 10-second sample end to end and shows which step fails. The
+                    result is added to the issue.
+                  </p>
+                )}
+              </div>
+
               {/* Submit */}
               <button
                 onClick={handleSubmit}
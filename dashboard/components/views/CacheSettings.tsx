@@ -0,0 +1,129 @@
+import React, { useEffect, useState } from 'react';
+import { Button } from '../ui/Button';
+import { useConfirm } from '../../src/hooks/useConfirm';
+
+const MB = 1024 * 1024;
+
+function formatSize(bytes: number): string {
+  if (bytes < MB) return `${Math.round(bytes / 1024)} KB`;
+  if (bytes < 1024 ** 3) return `${(bytes / MB).toFixed(1)} MB`;
+  return `${(bytes / 1024 ** 3).toFixed(2)} GB`;
+}
+
+function errorText(err: unknown): string {
+  const message = err instanceof Error ? err.message : String(err);
+  // ipcRenderer.invoke wraps main-process errors in a generic prefix.
+  return message.replace(/^Error invoking remote method '[^']+': (Error: )?/, '');
+}
+
+/**
+ * Cache location and per-category quotas for Settings → App. Changes apply
+ * immediately — they are not part of the modal's Save flow. Over-quota
+ * categories lose their least recently used files (electron/cacheManager.ts).
+ */
+export const CacheSettings: React.FC = () => {
+  const cache = window.electronAPI?.cache;
+  const [usage, setUsage] = useState<CacheUsage | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const { confirm, dialog } = useConfirm();
+
+  useEffect(() => {
+    cache
+      ?.getUsage()
+      .then(setUsage)
+      .catch(() => {});
+  }, [cache]);
+
+  if (!cache) {
+    return <p className="text-xs text-slate-500">Cache settings are only available in the app.</p>;
+  }
+
+  const apply = async (action: () => Promise<CacheUsage>) => {
+    setError(null);
+    try {
+      setUsage(await action());
+    } catch (err) {
+      setError(errorText(err));
+    }
+  };
+
+  const moveTo = async (dir: string | null) => {
+    if (
+      !(await confirm('Move the cache? Everything cached at the current location is deleted.', {
+        confirmLabel: 'Move',
+      }))
+    )
+      return;
+    await apply(() => cache.setDirectory(dir));
+  };
+
+  const change = async () => {
+    const dir = await window.electronAPI?.fileIO.selectFolder();
+    if (dir) await moveTo(dir);
+  };
+
+  const total = usage?.categories.reduce((sum, c) => sum + c.bytes, 0) ?? 0;
+
+  return (
+    <div className="space-y-3">
+      <div className="flex items-center justify-between gap-2">
+        <span className="truncate font-mono text-xs text-slate-400" title={usage?.root}>
+          {usage?.root ?? '…'}
+        </span>
+        <div className="flex shrink-0 gap-2">
+          {usage?.custom && (
+            <Button variant="ghost" size="sm" onClick={() => void moveTo(null)}>
+              Use default
+            </Button>
+          )}
+          <Button variant="secondary" size="sm" onClick={() => void change()}>
+            Change…
+          </Button>
+        </div>
+      </div>
+      {usage?.categories.map((c) => (
+        <div key={c.category} className="flex items-center gap-2 text-xs text-slate-400">
+          <span className="flex-1 text-sm text-white/90">{c.label}</span>
+          <span>{formatSize(c.bytes)} of</span>
+          <input
+            type="number"
+            min={1}
+            defaultValue={Math.round(c.quotaBytes / MB)}
+            onBlur={(e) => {
+              const mb = Number(e.target.value);
+              if (mb > 0 && mb !== Math.round(c.quotaBytes / MB)) {
+                void apply(() => cache.setQuota(c.category, mb));
+              }
+            }}
+            className="focus:border-accent-cyan/50 w-20 rounded border border-white/10 bg-black/20 px-2 py-1 text-white focus:outline-none"
+          />
+          <span>MB</span>
+          <Button
+            variant="ghost"
+            size="sm"
+            onClick={() => void apply(() => cache.clear(c.category))}
+            disabled={c.files === 0}
+          >
+            Clear
+          </Button>
+        </div>
+      ))}
+      <div className="flex items-center justify-between">
+        <span className="text-xs text-slate-500">
+          {formatSize(total)} in use. Least recently used files are removed when a category is
+          over its limit.
+        </span>
+        <Button
+          variant="secondary"
+          size="sm"
+          onClick={() => void apply(() => cache.clear())}
+          disabled={total === 0}
+        >
+          Clear all
+        </Button>
+      </div>
+      {error && <p className="text-xs text-red-400">{error}</p>}
+      {dialog}
+    </div>
+  );
+};
@@ -0,0 +1,119 @@
+import React, { useEffect, useMemo, useRef, useState } from 'react';
+import { Search } from 'lucide-react';
+import { toast } from 'sonner';
+import { searchActions, type PaletteAction } from '../../src/utils/commandPalette';
+
+interface CommandPaletteProps {
+  isOpen: boolean;
+  onClose: () => void;
+}
+
+/**
+ * Ctrl+K command palette over the main-process action registry
+ * (electron/actionRegistry.ts). Actions that can't run now stay listed,
+ * dimmed, with the reason.
+ */
+export const CommandPalette: React.FC<CommandPaletteProps> = ({ isOpen, onClose }) => {
+  const [actions, setActions] = useState<PaletteAction[]>([]);
+  const [query, setQuery] = useState('');
+  const [selected, setSelected] = useState(0);
+  const listRef = useRef<HTMLUListElement>(null);
+
+  // Enablement changes with app state, so list afresh on every open.
+  useEffect(() => {
+    if (!isOpen) return;
+    setQuery('');
+    setSelected(0);
+    window.electronAPI?.actions
+      ?.list()
+      .then(setActions)
+      .catch(() => setActions([]));
+  }, [isOpen]);
+
+  const matches = useMemo(() => searchActions(actions, query), [actions, query]);
+
+  useEffect(() => setSelected(0), [query]);
+
+  useEffect(() => {
+    listRef.current?.children[selected]?.scrollIntoView({ block: 'nearest' });
+  }, [selected]);
+
+  if (!isOpen) return null;
+
+  const run = async (action: PaletteAction | undefined) => {
+    if (!action?.enabled) return;
+    onClose();
+    const result = await window.electronAPI?.actions?.invoke(action.id);
+    if (result && !result.ok) toast.error(`${action.title}: ${result.error}`);
+  };
+
+  const onKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'ArrowDown') {
+      e.preventDefault();
+      setSelected((i) => Math.min(i + 1, matches.length - 1));
+    } else if (e.key === 'ArrowUp') {
+      e.preventDefault();
+      setSelected((i) => Math.max(i - 1, 0));
+    } else if (e.key === 'Enter') {
+      e.preventDefault();
+      void run(matches[selected]);
+    } else if (e.key === 'Escape') {
+      e.preventDefault();
+      onClose();
+    }
+  };
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-start justify-center p-4 pt-[15vh]">
+      <div className="absolute inset-0 bg-black/40 backdrop-blur-sm" onClick={onClose} />
+      <div
+        role="dialog"
+        aria-label="Command palette"
+        className="blur-panel relative flex w-full max-w-lg flex-col overflow-hidden rounded-2xl border border-white/20 bg-black/60 shadow-2xl backdrop-blur-xl"
+      >
+        <div className="flex items-center gap-2 border-b border-white/10 px-4 py-3">
+          <Search size={16} className="text-slate-400" />
+          <input
+            autoFocus
+            value={query}
+            onChange={(e) => setQuery(e.target.value)}
+            onKeyDown={onKeyDown}
+            placeholder="Type a command…"
+            aria-label="Search commands"
+            className="w-full bg-transparent text-sm text-white placeholder-slate-500 focus:outline-none"
+          />
+        </div>
+        <ul ref={listRef} role="listbox" className="max-h-80 overflow-y-auto py-1">
+          {matches.length === 0 && (
+            <li className="px-4 py-3 text-sm text-slate-500">No matching commands</li>
+          )}
+          {matches.map((action, i) => (
+            <li
+              key={action.id}
+              role="option"
+              aria-selected={i === selected}
+              aria-disabled={!action.enabled}
+              onMouseEnter={() => setSelected(i)}
+              onClick={() => void run(action)}
+              className={`flex cursor-default items-center justify-between gap-3 px-4 py-2 text-sm ${
+                i === selected ? 'bg-white/10' : ''
+              } ${action.enabled ? 'text-white' : 'text-slate-500'}`}
+            >
+              <span className="min-w-0">
+                <span className="block truncate">{action.title}</span>
+                <span className="block truncate text-xs text-slate-500">
+                  {action.disabledReason ?? action.category}
+                </span>
+              </span>
+              {action.accelerator && (
+                <kbd className="shrink-0 rounded border border-white/10 bg-white/5 px-1.5 py-0.5 font-mono text-xs text-slate-400">
+                  {action.accelerator}
+                </kbd>
+              )}
+            </li>
+          ))}
+        </ul>
+      </div>
+    </div>
+  );
+};
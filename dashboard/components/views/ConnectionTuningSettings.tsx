@@ -0,0 +1,79 @@
+import React from 'react';
+import {
+  CONNECTION_TUNING_LIMITS,
+  validateConnectionTuning,
+  type ConnectionProfile,
+  type ConnectionTuning,
+} from '../../src/config/store';
+import { AppleSwitch } from '../ui/AppleSwitch';
+
+const PROFILE_LABELS: Record<ConnectionProfile, string> = {
+  local: 'local server',
+  tailscale: 'Tailscale',
+  lan: 'LAN',
+};
+
+interface ConnectionTuningSettingsProps {
+  profile: ConnectionProfile;
+  value: ConnectionTuning;
+  onChange: (value: ConnectionTuning) => void;
+}
+
+/**
+ * Timeouts, keep-alive and HTTP/2 for one connection profile in Settings →
+ * Client. Each profile keeps its own values; they are saved with the modal.
+ */
+export const ConnectionTuningSettings: React.FC<ConnectionTuningSettingsProps> = ({
+  profile,
+  value,
+  onChange,
+}) => {
+  const errors = validateConnectionTuning(value);
+  const numberField = (key: keyof typeof CONNECTION_TUNING_LIMITS, label: string) => (
+    <div>
+      <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+        {label}
+      </label>
+      <input
+        type="number"
+        min={CONNECTION_TUNING_LIMITS[key].min}
+        max={CONNECTION_TUNING_LIMITS[key].max}
+        value={Number.isFinite(value[key]) ? value[key] : ''}
+        onChange={(e) => onChange({ ...value, [key]: parseFloat(e.target.value) })}
+        className="focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white focus:outline-none"
+      />
+    </div>
+  );
+
+  return (
+    <div className="space-y-3">
+      <p className="text-xs text-slate-400">
+        How patient the app is with the {PROFILE_LABELS[profile]} connection. Raise the timeouts
+        for remote servers on slow links.
+      </p>
+      <div className="grid grid-cols-2 gap-4">
+        {numberField('connectTimeoutSeconds', 'Connect Timeout (s)')}
+        {numberField('readTimeoutSeconds', 'Read Timeout (s)')}
+      </div>
+      {errors.map((error) => (
+        <p key={error} className="text-xs text-amber-300/80">
+          {error}
+        </p>
+      ))}
+      <AppleSwitch
+        checked={value.keepAlive}
+        onChange={(keepAlive) => onChange({ ...value, keepAlive })}
+        label="Keep connections alive"
+        description="Reuse connections between requests"
+        size="sm"
+      />
+      <AppleSwitch
+        checked={value.http2}
+        onChange={(http2) => onChange({ ...value, http2 })}
+        label="Allow HTTP/2"
+        description="Turn off if a proxy in front of the server mishandles HTTP/2 (restart needed)"
+        size="sm"
+      />
+    </div>
+  );
+};
@@ -0,0 +1,139 @@
+import React, { useEffect, useRef, useState } from 'react';
+import { Loader2, ShieldCheck, Wrench } from 'lucide-react';
+import { apiClient } from '../../src/api/client';
+import type { MaintenanceIssue, MaintenanceReport, MaintenanceStep } from '../../src/api/types';
+import { AppleSwitch } from '../ui/AppleSwitch';
+import { Button } from '../ui/Button';
+
+const STEP_LABELS: Record<MaintenanceStep, string> = {
+  integrity: 'Checking file integrity',
+  foreign_keys: 'Looking for orphaned rows',
+  consistency: 'Checking segments and words',
+  fts: 'Checking the search index',
+  vacuum: 'Compacting the database',
+};
+
+const megabytes = (bytes: number) => `${(bytes / 1024 / 1024).toFixed(1)} MB`;
+
+/**
+ * Database maintenance for Settings → Notebook. "Check" only reports;
+ * "Check & Repair" also fixes what it can and, optionally, vacuums. The
+ * server runs the same repair on a schedule (`storage.maintenance_*`).
+ */
+export const DatabaseMaintenanceSettings: React.FC = () => {
+  const [running, setRunning] = useState(false);
+  const [vacuum, setVacuum] = useState(true);
+  const [progress, setProgress] = useState<string | null>(null);
+  const [issues, setIssues] = useState<MaintenanceIssue[]>([]);
+  const [report, setReport] = useState<MaintenanceReport | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const abortRef = useRef<AbortController | null>(null);
+
+  useEffect(() => {
+    apiClient
+      .getMaintenanceStatus()
+      .then((status) => {
+        setReport(status.last_report);
+        setIssues(status.last_report?.details ?? []);
+        setRunning(status.running);
+      })
+      .catch(() => {
+        /* the last report is informational only */
+      });
+    return () => abortRef.current?.abort();
+  }, []);
+
+  const run = async (repair: boolean) => {
+    const controller = new AbortController();
+    abortRef.current = controller;
+    setRunning(true);
+    setIssues([]);
+    setReport(null);
+    setError(null);
+    try {
+      for await (const event of apiClient.runMaintenance({ repair, vacuum }, controller.signal)) {
+        if (event.type === 'step') {
+          setProgress(`${STEP_LABELS[event.step]}… (${event.index + 1}/${event.total})`);
+        } else if (event.type === 'issue') {
+          setIssues((prev) => [...prev, event]);
+        } else if (event.type === 'done') {
+          setReport(event);
+        }
+      }
+    } catch (err) {
+      if (!controller.signal.aborted) setError(err instanceof Error ? err.message : String(err));
+    } finally {
+      setRunning(false);
+      setProgress(null);
+    }
+  };
+
+  return (
+    <div className="space-y-4">
+      <p className="text-xs text-slate-400">
+        Check the database for corruption and inconsistencies, rebuild the search index and repair
+        what can be repaired. Page-level corruption cannot be fixed in place — restore a backup.
+      </p>
+      <AppleSwitch
+        checked={vacuum}
+        onChange={setVacuum}
+        label="Compact after repair"
+        description="VACUUM the database to give free space back to the disk"
+        size="sm"
+      />
+      {running && (
+        <div className="flex items-center gap-2 text-xs text-slate-400">
+          <Loader2 size={14} className="animate-spin" />
+          {progress ?? 'Maintenance is running…'}
+        </div>
+      )}
+      {error && <div className="rounded bg-red-500/10 p-2 text-xs text-red-400">{error}</div>}
+      {report && (
+        <div
+          className={`rounded p-2 text-xs ${
+            report.healthy ? 'bg-green-500/10 text-green-400' : 'bg-orange-500/10 text-orange-300'
+          }`}
+        >
+          {report.issues === 0
+            ? 'No problems found.'
+            : `${report.issues} problem(s) found, ${report.repaired} repaired.`}{' '}
+          <span className="text-slate-500">
+            {new Date(report.finished_at * 1000).toLocaleString()} ·{' '}
+            {megabytes(report.size_before)}
+            {report.size_after !== report.size_before && ` → ${megabytes(report.size_after)}`}
+          </span>
+        </div>
+      )}
+      {issues.length > 0 && (
+        <ul className="max-h-40 space-y-1 overflow-y-auto rounded-lg border border-white/10 bg-black/30 p-3 text-xs">
+          {issues.map((issue, i) => (
+            <li key={i} className={issue.repaired ? 'text-slate-400' : 'text-orange-300'}>
+              {issue.repaired ? 'Repaired: ' : ''}
+              {issue.message}
+            </li>
+          ))}
+        </ul>
+      )}
+      <div className="flex gap-3">
+        <Button
+          variant="secondary"
+          size="sm"
+          icon={<ShieldCheck size={14} />}
+          onClick={() => void run(false)}
+          disabled={running}
+        >
+          Check
+        </Button>
+        <Button
+          variant="primary"
+          size="sm"
+          icon={<Wrench size={14} />}
+          onClick={() => void run(true)}
+          disabled={running}
+        >
+          Check &amp; Repair
+        </Button>
+      </div>
+    </div>
+  );
+};
@@ -0,0 +1,78 @@
+/**
+ * FollowAlongButton — Live Mode header control for sharing the transcript
+ * with meeting participants on the local network.
+ *
+ * The first click starts a share session and opens a panel with the QR code
+ * and link; while sharing, the button toggles that panel, which also carries
+ * the viewer count and the Stop control that ends the session for everyone.
+ */
+
+import { useState } from 'react';
+
+import { Radio, Users } from 'lucide-react';
+import { toast } from 'sonner';
+
+import type { FollowAlongState } from '../../src/hooks/useFollowAlong';
+import { Button } from '../ui/Button';
+
+export function FollowAlongButton({ followAlong }: { followAlong: FollowAlongState }) {
+  const [open, setOpen] = useState(false);
+  const { status } = followAlong;
+  const running = status?.running ?? false;
+
+  if (!followAlong.available) return null;
+
+  const handleClick = async () => {
+    if (running) {
+      if (!open) followAlong.refresh();
+      setOpen(!open);
+      return;
+    }
+    try {
+      await followAlong.start();
+      setOpen(true);
+    } catch (err) {
+      toast.error('Could not start sharing', {
+        description: err instanceof Error ? err.message : String(err),
+      });
+    }
+  };
+
+  const handleStop = async () => {
+    await followAlong.stop();
+    setOpen(false);
+    toast.success('Sharing stopped — the link no longer works');
+  };
+
+  return (
+    <div className="relative">
+      <button
+        onClick={() => void handleClick()}
+        aria-pressed={running}
+        className={`flex h-7 w-7 items-center justify-center rounded-lg border transition-colors ${running ? 'border-accent-cyan/30 bg-accent-cyan/15 text-accent-cyan' : 'border-white/10 bg-white/5 text-slate-400 hover:bg-white/10 hover:text-white'}`}
+        title={running ? 'Sharing live transcript' : 'Let others follow along on the network'}
+      >
+        <Radio size={14} />
+      </button>
+      {open && running && status?.url && status.qrSvg && (
+        <div className="absolute top-9 right-0 z-50 w-64 space-y-3 rounded-xl border border-white/10 bg-slate-900/95 p-3 text-xs text-slate-400 shadow-xl backdrop-blur">
+          <img
+            src={`data:image/svg+xml;utf8,${encodeURIComponent(status.qrSvg)}`}
+            alt="Follow-along QR code"
+            className="mx-auto h-40 w-40 rounded-lg"
+          />
+          <p className="break-all text-slate-300 select-all">{status.url}</p>
+          <div className="flex items-center justify-between">
+            <span className="flex items-center gap-1.5">
+              <Users size={12} />
+              {status.viewers} watching
+            </span>
+            <Button variant="danger" size="sm" onClick={() => void handleStop()}>
+              Stop sharing
+            </Button>
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,118 @@
+import React, { useCallback, useEffect, useState } from 'react';
+import { Button } from '../ui/Button';
+import { CustomSelect } from '../ui/CustomSelect';
+import {
+  HID_PEDAL_ACTIONS,
+  bindHidAction,
+  getHid,
+  listHidDevices,
+  loadHidBindings,
+  waitForPedalPress,
+  type HidBinding,
+  type HidDeviceInfo,
+  type HidPedalAction,
+} from '../../src/services/hidPedal';
+
+const ACTION_ORDER = Object.keys(HID_PEDAL_ACTIONS) as HidPedalAction[];
+
+/**
+ * Foot-pedal mapping for Settings → App. Bindings are saved as soon as a
+ * pedal is learned or removed — they are not part of the modal's Save flow.
+ */
+export const FootPedalSettings: React.FC = () => {
+  const [devices, setDevices] = useState<HidDeviceInfo[]>([]);
+  const [bindings, setBindings] = useState<HidBinding[]>([]);
+  const [action, setAction] = useState<HidPedalAction>('play_while_held');
+  const [listening, setListening] = useState(false);
+  const [message, setMessage] = useState<string | null>(null);
+  const supported = getHid() !== null;
+
+  const refresh = useCallback(async () => {
+    const [found, saved] = await Promise.all([listHidDevices(), loadHidBindings()]);
+    setDevices(found);
+    setBindings(saved);
+  }, []);
+
+  useEffect(() => {
+    void refresh();
+  }, [refresh]);
+
+  const deviceName = (key: string) => devices.find((d) => d.key === key)?.name ?? key;
+
+  const learn = async () => {
+    setListening(true);
+    setMessage(null);
+    const press = await waitForPedalPress();
+    setListening(false);
+    if (!press) {
+      setMessage('No pedal press detected. Check the pedal is connected and try again.');
+      return;
+    }
+    setBindings(await bindHidAction(press.deviceKey, press.button, action));
+    setMessage(
+      `Pedal ${press.button + 1} on ${deviceName(press.deviceKey)} → ${HID_PEDAL_ACTIONS[action]}`,
+    );
+  };
+
+  const unbind = async (binding: HidBinding) => {
+    setBindings(await bindHidAction(binding.deviceKey, binding.button, null));
+  };
+
+  if (!supported) {
+    return <p className="text-xs text-slate-500">HID devices are not available in this build.</p>;
+  }
+
+  return (
+    <div className="space-y-3">
+      <div className="flex items-center justify-between text-xs text-slate-400">
+        <span>
+          {devices.length === 0
+            ? 'No HID devices detected'
+            : `Detected: ${devices.map((d) => d.name).join(', ')}`}
+        </span>
+        <Button variant="ghost" size="sm" onClick={() => void refresh()}>
+          Refresh
+        </Button>
+      </div>
+      <div className="flex items-end gap-2">
+        <div className="flex-1">
+          <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+            Action
+          </label>
+          <CustomSelect
+            value={HID_PEDAL_ACTIONS[action]}
+            onChange={(v) =>
+              setAction(ACTION_ORDER.find((a) => HID_PEDAL_ACTIONS[a] === v) ?? 'play_pause')
+            }
+            options={ACTION_ORDER.map((a) => HID_PEDAL_ACTIONS[a])}
+          />
+        </div>
+        <Button variant="secondary" onClick={() => void learn()} disabled={listening}>
+          {listening ? 'Press a pedal…' : 'Bind pedal'}
+        </Button>
+      </div>
+      {message && <p className="text-xs text-slate-400">{message}</p>}
+      {bindings.length > 0 && (
+        <ul className="space-y-1">
+          {bindings.map((b) => (
+            <li
+              key={`${b.deviceKey}:${b.button}`}
+              className="flex items-center justify-between rounded-lg bg-white/5 px-3 py-1.5 text-sm text-slate-300"
+            >
+              <span>
+                {deviceName(b.deviceKey)} · pedal {b.button + 1} → {HID_PEDAL_ACTIONS[b.action]}
+              </span>
+              <Button variant="ghost" size="sm" onClick={() => void unbind(b)}>
+                Remove
+              </Button>
+            </li>
+          ))}
+        </ul>
+      )}
+      <p className="text-xs text-slate-500">
+        Pedals control playback in the recording view. Releasing a play-while-held pedal pauses
+        and steps back slightly so the last words replay on the next press.
+      </p>
+    </div>
+  );
+};
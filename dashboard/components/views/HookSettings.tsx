@@ -0,0 +1,174 @@
+import React, { useEffect, useState } from 'react';
+import { Play, Trash2 } from 'lucide-react';
+import { Button } from '../ui/Button';
+import { AppleSwitch } from '../ui/AppleSwitch';
+import { CustomSelect } from '../ui/CustomSelect';
+import { getConfig, setConfig } from '../../src/config/store';
+
+const EVENT_LABELS: Record<HookEvent, string> = {
+  on_job_complete: 'Import job complete',
+  on_recording_saved: 'Recording saved to notebook',
+  on_export: 'Transcript exported',
+};
+const EVENTS = Object.keys(EVENT_LABELS) as HookEvent[];
+const DEFAULT_TIMEOUT_SECONDS = 30;
+
+function describeRun(run: HookRun): string {
+  if (run.timedOut) return `timed out after ${Math.round(run.durationMs / 1000)} s`;
+  if (run.error) return `could not start: ${run.error}`;
+  return `exit ${run.exitCode} in ${run.durationMs} ms`;
+}
+
+/**
+ * Settings → App → Script Hooks: shell commands run on lifecycle events
+ * (electron/scriptHooks.ts). Saved as they are edited — not part of the
+ * modal's Save flow — and used from the next event.
+ */
+export const HookSettings: React.FC = () => {
+  const hooks = window.electronAPI?.hooks;
+  const [scripts, setScripts] = useState<HookScript[]>([]);
+  const [timeout, setTimeoutSeconds] = useState(DEFAULT_TIMEOUT_SECONDS);
+  const [lastRuns, setLastRuns] = useState<Record<string, HookRun>>({});
+  const [event, setEvent] = useState<HookEvent>('on_job_complete');
+  const [command, setCommand] = useState('');
+
+  useEffect(() => {
+    if (!hooks) return;
+    getConfig<HookScript[]>('hooks.scripts')
+      .then((v) => Array.isArray(v) && setScripts(v))
+      .catch(() => {});
+    getConfig<number>('hooks.timeoutSeconds')
+      .then((v) => typeof v === 'number' && setTimeoutSeconds(v))
+      .catch(() => {});
+    // History is newest first; keep each hook's most recent run.
+    hooks
+      .history()
+      .then((runs) =>
+        setLastRuns(Object.fromEntries([...runs].reverse().map((r) => [r.scriptId, r]))),
+      )
+      .catch(() => {});
+    return hooks.onRan((run) => setLastRuns((prev) => ({ ...prev, [run.scriptId]: run })));
+  }, [hooks]);
+
+  if (!hooks) {
+    return <p className="text-xs text-slate-500">Script hooks are only available in the app.</p>;
+  }
+
+  const save = (next: HookScript[]) => {
+    setScripts(next);
+    void setConfig('hooks.scripts', next);
+  };
+
+  const add = () => {
+    if (!command.trim()) return;
+    save([...scripts, { id: crypto.randomUUID(), event, command: command.trim(), enabled: true }]);
+    setCommand('');
+  };
+
+  const saveTimeout = (value: number) => {
+    const next = Math.min(600, Math.max(1, Math.round(value) || DEFAULT_TIMEOUT_SECONDS));
+    setTimeoutSeconds(next);
+    void setConfig('hooks.timeoutSeconds', next);
+  };
+
+  const test = async (script: HookScript) => {
+    try {
+      await hooks.test(script.id);
+    } catch {
+      // The run itself arrives through onRan; a hook removed meanwhile has nothing to show.
+    }
+  };
+
+  const inputClass =
+    'focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white focus:outline-none';
+
+  return (
+    <div className="space-y-3">
+      <p className="text-xs text-slate-400">
+        Run your own commands when something happens. Each command runs in the system shell and
+        gets the event as JSON on stdin — paths, job and recording ids — plus the event name in{' '}
+        <code className="text-slate-300">TRANSCRIPTIONSUITE_HOOK_EVENT</code>. Runs are logged to{' '}
+        <code className="text-slate-300">hooks.log</code> in the logs folder.
+      </p>
+      {scripts.map((script) => {
+        const run = lastRuns[script.id];
+        return (
+          <div key={script.id} className="flex items-start gap-3">
+            <div className="min-w-0 flex-1">
+              <p className="truncate font-mono text-sm text-white/90" title={script.command}>
+                {script.command}
+              </p>
+              <p className="truncate text-xs text-slate-500" title={run?.output || undefined}>
+                {EVENT_LABELS[script.event]}
+                {run ? ` · last run ${describeRun(run)}` : ''}
+              </p>
+            </div>
+            <AppleSwitch
+              size="sm"
+              checked={script.enabled}
+              onChange={(enabled) =>
+                save(scripts.map((s) => (s.id === script.id ? { ...s, enabled } : s)))
+              }
+            />
+            <Button
+              variant="ghost"
+              size="sm"
+              icon={<Play size={14} />}
+              onClick={() => void test(script)}
+              aria-label={`Test ${script.command}`}
+            />
+            <Button
+              variant="ghost"
+              size="sm"
+              icon={<Trash2 size={14} />}
+              onClick={() => save(scripts.filter((s) => s.id !== script.id))}
+              aria-label={`Remove ${script.command}`}
+            />
+          </div>
+        );
+      })}
+      <div className="flex items-end gap-2">
+        <div className="w-56 shrink-0">
+          <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+            When
+          </label>
+          <CustomSelect
+            value={EVENT_LABELS[event]}
+            onChange={(v) => setEvent(EVENTS.find((e) => EVENT_LABELS[e] === v) ?? event)}
+            options={EVENTS.map((e) => EVENT_LABELS[e])}
+          />
+        </div>
+        <div className="min-w-0 flex-1">
+          <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+            Run
+          </label>
+          <input
+            value={command}
+            onChange={(e) => setCommand(e.target.value)}
+            onKeyDown={(e) => e.key === 'Enter' && add()}
+            placeholder="~/bin/on-transcript.sh"
+            aria-label="Hook command"
+            className={`${inputClass} font-mono`}
+          />
+        </div>
+        <Button variant="secondary" onClick={add} disabled={!command.trim()}>
+          Add
+        </Button>
+      </div>
+      <div className="w-40">
+        <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+          Timeout (s)
+        </label>
+        <input
+          type="number"
+          min={1}
+          max={600}
+          value={timeout}
+          onChange={(e) => setTimeoutSeconds(parseInt(e.target.value, 10))}
+          onBlur={(e) => saveTimeout(parseInt(e.target.value, 10))}
+          className={inputClass}
+        />
+      </div>
+    </div>
+  );
+};
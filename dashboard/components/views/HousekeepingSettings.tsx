@@ -0,0 +1,201 @@
+import React, { useEffect, useState } from 'react';
+import { X } from 'lucide-react';
+import { apiClient } from '../../src/api/client';
+import type { GoldenSetReport } from '../../src/api/types';
+import { AppleSwitch } from '../ui/AppleSwitch';
+import { Button } from '../ui/Button';
+import { DEFAULT_CONFIG, getHousekeeping, setConfig } from '../../src/config/store';
+import { runHousekeepingNow } from '../../src/hooks/useHousekeepingScheduler';
+import { useConfirm } from '../../src/hooks/useConfirm';
+import {
+  enabledTasks,
+  parseClock,
+  type HousekeepingSettings,
+} from '../../src/services/housekeeping';
+
+const percent = (wer: number | null) => (wer === null ? '—' : `${(wer * 100).toFixed(1)}%`);
+
+const inputClass =
+  'focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white focus:outline-none';
+
+/**
+ * Nightly server housekeeping for Settings → Server. Changes are saved as
+ * they are made — they are not part of the modal's Save flow. The schedule
+ * runs in this app (useHousekeepingScheduler), so it only fires while the
+ * app is open; results land in the notification center.
+ */
+export const HousekeepingSettings: React.FC = () => {
+  const [settings, setSettings] = useState<HousekeepingSettings>(DEFAULT_CONFIG.housekeeping);
+  const [running, setRunning] = useState(false);
+  const [golden, setGolden] = useState<GoldenSetReport | null>(null);
+  const { confirm, dialog } = useConfirm();
+
+  const loadGolden = () => {
+    apiClient
+      .getGoldenSet()
+      .then(setGolden)
+      .catch(() => setGolden(null));
+  };
+
+  useEffect(() => {
+    getHousekeeping()
+      .then(setSettings)
+      .catch(() => {});
+    loadGolden();
+  }, []);
+
+  const removeReference = async (recordingId: number) => {
+    await apiClient.setGoldenReference(recordingId, false).catch(() => {});
+    loadGolden();
+  };
+
+  const update = <K extends keyof HousekeepingSettings>(
+    key: K,
+    value: HousekeepingSettings[K],
+  ) => {
+    setSettings((prev) => ({ ...prev, [key]: value }));
+    void setConfig(`housekeeping.${key}`, value);
+  };
+
+  const updateClock = (key: 'windowStart' | 'windowEnd', value: string) => {
+    setSettings((prev) => ({ ...prev, [key]: value }));
+    if (parseClock(value) !== null) void setConfig(`housekeeping.${key}`, value);
+  };
+
+  const runNow = async () => {
+    if (
+      settings.restartContainer &&
+      !(await confirm('Run housekeeping now? The local server container restarts at the end.', {
+        confirmLabel: 'Run now',
+      }))
+    )
+      return;
+    setRunning(true);
+    try {
+      await runHousekeepingNow(settings);
+    } finally {
+      setRunning(false);
+      loadGolden();
+    }
+  };
+
+  return (
+    <div className="space-y-3">
+      <AppleSwitch
+        checked={settings.enabled}
+        onChange={(v) => update('enabled', v)}
+        label="Run nightly"
+        description="Once per night inside the window, as soon as the server is idle"
+      />
+      <div className="grid grid-cols-2 gap-4">
+        <div>
+          <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+            Window start
+          </label>
+          <input
+            type="time"
+            value={settings.windowStart}
+            onChange={(e) => updateClock('windowStart', e.target.value)}
+            className={inputClass}
+          />
+        </div>
+        <div>
+          <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+            Window end
+          </label>
+          <input
+            type="time"
+            value={settings.windowEnd}
+            onChange={(e) => updateClock('windowEnd', e.target.value)}
+            className={inputClass}
+          />
+        </div>
+      </div>
+      <AppleSwitch
+        checked={settings.rotateLogs}
+        onChange={(v) => update('rotateLogs', v)}
+        label="Rotate server logs"
+      />
+      <AppleSwitch
+        checked={settings.pruneModelCache}
+        onChange={(v) => update('pruneModelCache', v)}
+        label="Prune model cache"
+        description="Removes partial downloads and models no config uses"
+      />
+      {settings.pruneModelCache && (
+        <div className="flex items-center gap-2 text-xs text-slate-400">
+          <span>Keep models used in the last</span>
+          <input
+            type="number"
+            min={1}
+            max={3650}
+            value={settings.pruneUnusedDays}
+            onChange={(e) => update('pruneUnusedDays', Math.max(1, Number(e.target.value) || 1))}
+            className="focus:border-accent-cyan/50 w-16 rounded border border-white/10 bg-black/20 px-2 py-1 text-white focus:outline-none"
+          />
+          <span>days</span>
+        </div>
+      )}
+      <AppleSwitch
+        checked={settings.goldenCheck}
+        onChange={(v) => update('goldenCheck', v)}
+        label="Check transcription accuracy"
+        description="Re-transcribes recordings marked “Use as Reference” in the notebook and flags a rise in word error rate"
+      />
+      {settings.goldenCheck && golden && (
+        <div className="space-y-1 text-xs text-slate-400">
+          {golden.recordings.length === 0 ? (
+            <p className="text-slate-500">No reference transcripts yet.</p>
+          ) : (
+            golden.recordings.map((ref) => (
+              <div key={ref.recording_id} className="flex items-center gap-2">
+                <span
+                  className="flex-1 truncate text-slate-300"
+                  title={ref.last_error ?? undefined}
+                >
+                  {ref.title || `Recording ${ref.recording_id}`}
+                </span>
+                <span className="font-mono">WER {percent(ref.latest_wer)}</span>
+                {ref.drift !== null && (
+                  <span
+                    className={`font-mono ${ref.regressed ? 'text-red-400' : 'text-slate-500'}`}
+                  >
+                    {ref.drift > 0 ? '+' : ''}
+                    {(ref.drift * 100).toFixed(1)}
+                  </span>
+                )}
+                <button
+                  onClick={() => void removeReference(ref.recording_id)}
+                  aria-label="Stop using as reference"
+                  className="rounded p-0.5 text-slate-500 hover:bg-white/10 hover:text-white"
+                >
+                  <X size={12} />
+                </button>
+              </div>
+            ))
+          )}
+        </div>
+      )}
+      <AppleSwitch
+        checked={settings.restartContainer}
+        onChange={(v) => update('restartContainer', v)}
+        label="Restart the server container"
+        description="Local Docker server only; skipped when connected to a remote server"
+      />
+      <div className="flex items-center justify-between">
+        <span className="text-xs text-slate-500">
+          {settings.lastRunDate ? `Last scheduled run: ${settings.lastRunDate}` : 'No scheduled run yet'}
+        </span>
+        <Button
+          variant="secondary"
+          size="sm"
+          onClick={() => void runNow()}
+          disabled={running || enabledTasks(settings).length === 0}
+        >
+          {running ? 'Running…' : 'Run now'}
+        </Button>
+      </div>
+      {dialog}
+    </div>
+  );
+};
@@ -0,0 +1,90 @@
+import React, { useEffect, useState } from 'react';
+import { toast } from 'sonner';
+import { Button } from '../ui/Button';
+import { ShortcutCapture } from '../ui/ShortcutCapture';
+
+/**
+ * In-app shortcuts from the main-process registry (electron/shortcutRegistry.ts).
+ * Each change is validated and saved immediately — a binding that collides
+ * with another action is refused with the conflicting action named.
+ */
+export const InAppShortcutSettings: React.FC = () => {
+  const [keymap, setKeymap] = useState<KeymapEntry[]>([]);
+  const api = window.electronAPI?.shortcuts;
+
+  useEffect(() => {
+    if (!api?.getKeymap) return;
+    api
+      .getKeymap()
+      .then(setKeymap)
+      .catch(() => {});
+    return api.onKeymapChanged(setKeymap);
+  }, [api]);
+
+  if (!api?.getKeymap) return null;
+
+  const apply = (result: KeymapResult | null) => {
+    if (!result) return;
+    if (result.ok) setKeymap(result.keymap);
+    else toast.error(result.error);
+  };
+
+  const appEntries = keymap.filter((entry) => entry.scope === 'app');
+
+  return (
+    <div className="space-y-3">
+      <label className="block text-xs font-medium tracking-wider text-slate-500 uppercase">
+        In-app shortcuts
+      </label>
+      <div className="grid grid-cols-1 gap-3 md:grid-cols-2">
+        {appEntries.map((entry) => (
+          <div key={entry.id}>
+            <div className="mb-1 flex items-center justify-between text-xs text-slate-400">
+              <span>{entry.label}</span>
+              {entry.accelerator !== entry.defaultAccelerator && (
+                <button
+                  type="button"
+                  className="text-slate-500 hover:text-white"
+                  onClick={() => void api.set(entry.id, null).then(apply)}
+                >
+                  Reset
+                </button>
+              )}
+            </div>
+            <ShortcutCapture
+              value={entry.accelerator}
+              placeholder={entry.defaultAccelerator}
+              allowBare
+              onChange={(acc) => void api.set(entry.id, acc).then(apply)}
+            />
+          </div>
+        ))}
+      </div>
+      <div className="flex justify-end gap-2">
+        <Button
+          variant="secondary"
+          size="sm"
+          onClick={() =>
+            void api.importKeymap().then((result) => {
+              apply(result);
+              if (result?.ok) toast.success('Keymap imported');
+            })
+          }
+        >
+          Import Keymap
+        </Button>
+        <Button
+          variant="secondary"
+          size="sm"
+          onClick={() =>
+            void api.exportKeymap().then((file) => {
+              if (file) toast.success(`Keymap saved to ${file}`);
+            })
+          }
+        >
+          Export Keymap
+        </Button>
+      </div>
+    </div>
+  );
+};
@@ -0,0 +1,195 @@
+import React, { useCallback, useEffect, useRef, useState } from 'react';
+import { Mic, Square, Users } from 'lucide-react';
+import { toast } from 'sonner';
+import { GlassCard } from '../ui/GlassCard';
+import { Button } from '../ui/Button';
+import { formatClock } from '../../src/services/jobProgress';
+import { getConfig, setConfig } from '../../src/config/store';
+import { useImportQueueStore } from '../../src/stores/importQueueStore';
+import { InterviewRecorder, interviewChannelLabels } from '../../src/services/interviewRecorder';
+
+interface InterviewRecordingCardProps {
+  /** Microphone device ID chosen in Audio Configuration. */
+  micDeviceId?: string;
+  /** Linux sink for the far end; unset on Windows/macOS (getDisplayMedia). */
+  monitorSinkName?: string;
+  isLinux: boolean;
+  /** Another recording or Live Mode owns the inputs. */
+  disabled: boolean;
+  /** Language code for the upload; undefined auto-detects. */
+  language?: string;
+  onAnalyserChange: (analyser: AnalyserNode | null) => void;
+  onActiveChange: (active: boolean) => void;
+}
+
+/**
+ * Interview mode: records the microphone and system audio as two channels of
+ * one file and sends it to the Audio Notebook as a multitrack job, so each
+ * side of a call is attributed by channel instead of by diarization.
+ */
+export const InterviewRecordingCard: React.FC<InterviewRecordingCardProps> = ({
+  micDeviceId,
+  monitorSinkName,
+  isLinux,
+  disabled,
+  language,
+  onAnalyserChange,
+  onActiveChange,
+}) => {
+  const [localName, setLocalName] = useState('Me');
+  const [remoteName, setRemoteName] = useState('Guest');
+  const [status, setStatus] = useState<'idle' | 'starting' | 'recording'>('idle');
+  const [startedAt, setStartedAt] = useState<number | null>(null);
+  const [elapsed, setElapsed] = useState(0);
+  const recorderRef = useRef<InterviewRecorder | null>(null);
+
+  useEffect(() => {
+    void Promise.all([
+      getConfig<string>('session.interviewLocalName'),
+      getConfig<string>('session.interviewRemoteName'),
+    ]).then(([local, remote]) => {
+      if (typeof local === 'string') setLocalName(local);
+      if (typeof remote === 'string') setRemoteName(remote);
+    });
+    return () => {
+      // Leaving the view mid-call discards the recording.
+      recorderRef.current?.cancel();
+    };
+  }, []);
+
+  useEffect(() => {
+    if (startedAt === null) return;
+    const timer = window.setInterval(() => setElapsed((Date.now() - startedAt) / 1000), 500);
+    return () => window.clearInterval(timer);
+  }, [startedAt]);
+
+  const finish = useCallback(() => {
+    setStatus('idle');
+    setStartedAt(null);
+    setElapsed(0);
+    onAnalyserChange(null);
+    onActiveChange(false);
+    if (!isLinux) window.electronAPI?.audio?.disableSystemAudioLoopback?.();
+  }, [isLinux, onAnalyserChange, onActiveChange]);
+
+  const start = useCallback(async () => {
+    setStatus('starting');
+    onActiveChange(true);
+    const recorder = new InterviewRecorder();
+    recorderRef.current = recorder;
+    try {
+      if (!isLinux) await window.electronAPI?.audio?.enableSystemAudioLoopback?.();
+      await recorder.start({ micDeviceId, monitorSinkName: isLinux ? monitorSinkName : undefined });
+      setStatus('recording');
+      setStartedAt(Date.now());
+      onAnalyserChange(recorder.analyser);
+    } catch (err) {
+      recorderRef.current = null;
+      finish();
+      if (err instanceof Error && err.name === 'AbortError') return;
+      toast.error('Could not start interview recording', {
+        description: err instanceof Error ? err.message : undefined,
+      });
+    }
+  }, [isLinux, micDeviceId, monitorSinkName, onActiveChange, onAnalyserChange, finish]);
+
+  const stop = useCallback(async () => {
+    const recorder = recorderRef.current;
+    recorderRef.current = null;
+    if (!recorder) return;
+    const recordedAt = new Date(startedAt ?? Date.now());
+    try {
+      const blob = await recorder.stop();
+      const stamp = recordedAt.toISOString().slice(0, 19).replace(/[:T]/g, '-');
+      const labels = interviewChannelLabels(localName, remoteName);
+      const file = new File([blob], `interview-${stamp}.webm`, { type: blob.type });
+      useImportQueueStore.getState().addPriorityFiles([file], 'notebook-normal', {
+        language,
+        multitrack: true,
+        channel_labels: labels,
+        enable_word_timestamps: true,
+        title: `Interview: ${labels.join(' & ')}`,
+        file_created_at: recordedAt.toISOString(),
+      });
+      toast.success('Interview queued for transcription', {
+        description: 'It will appear in the Audio Notebook when it is done.',
+      });
+    } catch (err) {
+      toast.error('Interview recording failed', {
+        description: err instanceof Error ? err.message : undefined,
+      });
+    } finally {
+      finish();
+    }
+  }, [startedAt, localName, remoteName, language, finish]);
+
+  const saveName = (key: 'interviewLocalName' | 'interviewRemoteName', value: string) => {
+    void setConfig(`session.${key}`, value).catch(() => {});
+  };
+
+  const busy = status !== 'idle';
+  const inputClass =
+    'focus:border-accent-cyan/50 min-w-0 flex-1 rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white focus:outline-none disabled:opacity-50';
+
+  return (
+    <GlassCard
+      title={
+        <span className="flex items-center gap-2">
+          <Users size={16} className="text-accent-cyan" /> Interview Recording
+        </span>
+      }
+      className="flex-none"
+    >
+      <div className="space-y-3">
+        <div className="flex gap-2">
+          <input
+            type="text"
+            value={localName}
+            disabled={busy}
+            onChange={(e) => setLocalName(e.target.value)}
+            onBlur={(e) => saveName('interviewLocalName', e.target.value)}
+            placeholder="Me"
+            aria-label="Name for the microphone channel"
+            className={inputClass}
+          />
+          <input
+            type="text"
+            value={remoteName}
+            disabled={busy}
+            onChange={(e) => setRemoteName(e.target.value)}
+            onBlur={(e) => saveName('interviewRemoteName', e.target.value)}
+            placeholder="Guest"
+            aria-label="Name for the system audio channel"
+            className={inputClass}
+          />
+        </div>
+        <div className="flex items-center justify-between gap-3">
+          {status === 'recording' ? (
+            <Button variant="danger" icon={<Square size={14} />} onClick={() => void stop()}>
+              Stop & Transcribe
+            </Button>
+          ) : (
+            <Button
+              variant="secondary"
+              icon={<Mic size={14} />}
+              disabled={disabled || busy}
+              onClick={() => void start()}
+            >
+              {status === 'starting' ? 'Starting…' : 'Record Interview'}
+            </Button>
+          )}
+          {status === 'recording' && (
+            <span className="font-mono text-sm text-slate-300 tabular-nums">
+              {formatClock(elapsed)}
+            </span>
+          )}
+        </div>
+        <p className="text-xs text-slate-500">
+          Records your microphone and the system audio (the call) on separate channels. Each
+          side&apos;s words are attributed to its name — no diarization needed. Use headphones so
+          the other side does not leak into your microphone.
+        </p>
+      </div>
+    </GlassCard>
+  );
+};
@@ -0,0 +1,58 @@
+import React, { useEffect, useState } from 'react';
+import { toast } from 'sonner';
+import { CustomSelect } from '../ui/CustomSelect';
+import { useT } from '../../src/hooks/useLocale';
+import { useLocaleStore } from '../../src/stores/localeStore';
+
+interface LocaleOption {
+  code: string;
+  name: string;
+}
+
+/**
+ * UI language picker. Switching is applied at once by the main process
+ * (electron/localeCatalog.ts), which pushes the new catalog to every window.
+ */
+export const LanguageSettings: React.FC = () => {
+  const t = useT();
+  const code = useLocaleStore((s) => s.code);
+  const [locales, setLocales] = useState<LocaleOption[]>([]);
+  const [userDir, setUserDir] = useState('');
+  const api = window.electronAPI?.locale;
+
+  useEffect(() => {
+    api
+      ?.list()
+      .then(({ locales: found, userDir: dir }) => {
+        setLocales(found);
+        setUserDir(dir);
+      })
+      .catch(() => {});
+  }, [api]);
+
+  if (!api || locales.length === 0) return null;
+
+  const current = locales.find((l) => l.code === code) ?? locales[0];
+
+  return (
+    <div>
+      <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+        {t('settings-language')}
+      </label>
+      <CustomSelect
+        value={current.name}
+        onChange={(name) => {
+          const next = locales.find((l) => l.name === name);
+          if (!next || next.code === code) return;
+          api.set(next.code).catch((err: unknown) => {
+            toast.error(err instanceof Error ? err.message : 'Could not switch language');
+          });
+        }}
+        options={locales.map((l) => l.name)}
+      />
+      <p className="mt-2 text-xs text-slate-500">
+        {t('settings-language-hint', { folder: userDir })}
+      </p>
+    </div>
+  );
+};
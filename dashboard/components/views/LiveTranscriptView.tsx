@@ -8,11 +8,15 @@
  *   - stopped/off with no content        → idle placeholder
  *
  * Editing is client-only (drives the Copy button); nothing is persisted.
+ * Sentences that tripped a keyword alert carry a bell; each row has a
+ * `data-live-sentence` index so a clicked notification can scroll to it.
+ * Rows are memoized: partial updates arrive several times a second and must
+ * not re-render an hour of finished sentences.
  */
 
-import { type RefObject } from 'react';
-import { Loader2, Activity, Radio } from 'lucide-react';
-import type { LiveModeState } from '../../src/hooks/useLiveMode';
+import { memo, type RefObject } from 'react';
+import { Loader2, Activity, Radio, Bell } from 'lucide-react';
+import type { LiveModeState, LiveSentence } from '../../src/hooks/useLiveMode';
 import { FindReplaceTextEditor } from '../editor/FindReplaceTextEditor';
 
 interface LiveTranscriptViewProps {
@@ -27,11 +31,50 @@ interface LiveTranscriptViewProps {
   liveModeUnsupportedMessage: string;
   /** Auto-scroll target for the streaming view. */
   transcriptRef: RefObject<HTMLDivElement | null>;
+  /** Sentence indexes that matched a watch keyword. */
+  alertedSentences?: ReadonlySet<number>;
+  /** Sentence briefly flashed after jumping to it from a notification. */
+  highlightedSentence?: number | null;
   /** Client-only edited text (seeded from live.getText() once stopped). */
   editedLiveText: string;
   onEditedLiveChange: (next: string) => void;
 }
 
+const LiveSentenceRow = memo(function LiveSentenceRow({
+  sentence,
+  index,
+  alerted,
+  highlighted,
+  hideTimestamps,
+}: {
+  sentence: LiveSentence;
+  index: number;
+  alerted: boolean;
+  highlighted: boolean;
+  hideTimestamps: boolean;
+}) {
+  return (
+    <div
+      data-live-sentence={index}
+      className={`mb-2 rounded transition-colors ${highlighted ? 'bg-amber-400/15' : ''}`}
+    >
+      {alerted && (
+        <Bell
+          size={12}
+          aria-label="Keyword alert"
+          className="mr-1.5 inline-block align-baseline text-amber-300"
+        />
+      )}
+      {!hideTimestamps && (
+        <span className="mr-2 text-slate-500 select-none">
+          {new Date(sentence.timestamp).toLocaleTimeString('en-US', { hour12: false })}
+        </span>
+      )}
+      <span>{sentence.text}</span>
+    </div>
+  );
+});
+
 const AREA_CLASS =
   'custom-scrollbar selectable-text relative min-h-0 flex-1 overflow-y-auto rounded-xl border border-white/5 bg-black/20 p-4 font-mono text-sm leading-relaxed text-slate-300 shadow-inner';
 
@@ -46,6 +89,8 @@ export function LiveTranscriptView({
   liveModeWhisperOnlyCompatible,
   liveModeUnsupportedMessage,
   transcriptRef,
+  alertedSentences,
+  highlightedSentence = null,
   editedLiveText,
   onEditedLiveChange,
 }: LiveTranscriptViewProps) {
@@ -81,14 +126,14 @@ export function LiveTranscriptView({
             </div>
           )}
           {live.sentences.map((s, i) => (
-            <div key={i} className="mb-2">
-              {!hideTimestamps && (
-                <span className="mr-2 text-slate-500 select-none">
-                  {new Date(s.timestamp).toLocaleTimeString('en-US', { hour12: false })}
-                </span>
-              )}
-              <span>{s.text}</span>
-            </div>
+            <LiveSentenceRow
+              key={i}
+              sentence={s}
+              index={i}
+              alerted={alertedSentences?.has(i) ?? false}
+              highlighted={i === highlightedSentence}
+              hideTimestamps={hideTimestamps}
+            />
           ))}
           {live.partial && (
             <div className="mb-2 opacity-60">
@@ -0,0 +1,173 @@
+import React, { useState } from 'react';
+import { FolderOpen, Loader2, Scissors } from 'lucide-react';
+import { toast } from 'sonner';
+import {
+  audibleChannels,
+  channelFilename,
+  meterPercent,
+} from '../../src/services/channelSelection';
+import { parseRangeTime, rangeFilename } from '../../src/services/timeRange';
+import type { LocalEdit } from '../../src/stores/importQueueStore';
+import { GlassCard } from '../ui/GlassCard';
+
+interface LocalEditPanelProps {
+  /** Queue the edit; false when the import options were not ready. */
+  onTranscribe: (path: string, edit: LocalEdit) => boolean;
+}
+
+function basename(filePath: string): string {
+  return filePath.split(/[\\/]/).pop() || filePath;
+}
+
+/**
+ * Transcribe only part of a local recording — a time range, some of its
+ * channels, or both. The part is cut out locally with ffmpeg before the
+ * upload, and the transcript keeps the recording's timestamps
+ * (src/services/timeRange.ts, src/services/channelSelection.ts).
+ */
+export const LocalEditPanel: React.FC<LocalEditPanelProps> = ({ onTranscribe }) => {
+  const [source, setSource] = useState<string | null>(null);
+  const [start, setStart] = useState('');
+  const [end, setEnd] = useState('');
+  const [probe, setProbe] = useState<ChannelProbe | null>(null);
+  const [probing, setProbing] = useState(false);
+  const [selected, setSelected] = useState<number[]>([]);
+
+  const startMs = parseRangeTime(start);
+  const endMs = parseRangeTime(end);
+  const hasRange = start.trim() !== '' || end.trim() !== '';
+  const rangeValid = startMs !== null && endMs !== null && endMs > startMs;
+  const channelCount = probe?.channels ?? 1;
+  const channelSubset = selected.length > 0 && selected.length < channelCount;
+  const ready =
+    !!source &&
+    !probing &&
+    (hasRange ? rangeValid : channelSubset) &&
+    (channelCount === 1 || selected.length > 0);
+
+  const chooseSource = async () => {
+    const mediaClip = window.electronAPI?.mediaClip;
+    const picked = await mediaClip?.selectSource();
+    if (!picked || !mediaClip) return;
+    setSource(picked);
+    setProbe(null);
+    setSelected([]);
+    setProbing(true);
+    try {
+      const result = await mediaClip.probeChannels(picked);
+      setProbe(result);
+      setSelected(audibleChannels(result.levels));
+    } catch (err) {
+      toast.error(err instanceof Error ? err.message : 'Could not read the channels');
+    } finally {
+      setProbing(false);
+    }
+  };
+
+  const toggleChannel = (index: number) => {
+    setSelected((prev) =>
+      prev.includes(index)
+        ? prev.filter((c) => c !== index)
+        : [...prev, index].sort((a, b) => a - b),
+    );
+  };
+
+  const submit = () => {
+    if (!source || !ready) return;
+    const edit: LocalEdit = {
+      ...(hasRange ? { range: { startMs: startMs!, endMs: endMs! } } : {}),
+      ...(channelSubset ? { channels: selected } : {}),
+    };
+    if (!onTranscribe(source, edit)) return;
+    const ranged = edit.range ? rangeFilename(basename(source), edit.range) : basename(source);
+    toast.success(`Queued ${edit.channels ? channelFilename(ranged, edit.channels) : ranged}`);
+  };
+
+  const inputClass =
+    'w-24 rounded-lg border border-white/10 bg-white/5 px-3 py-2 text-sm text-slate-300 outline-none placeholder:text-slate-600';
+
+  return (
+    <GlassCard title="Transcribe Part of a Recording">
+      <div className="space-y-3 text-sm">
+        <div className="flex items-center gap-3">
+          <span className="flex-1 truncate text-slate-300" title={source ?? undefined}>
+            {source ? basename(source) : 'No recording selected'}
+          </span>
+          {probing && <Loader2 size={14} className="animate-spin text-slate-500" />}
+          <button
+            onClick={() => void chooseSource()}
+            className="hover:bg-accent-cyan/10 hover:text-accent-cyan flex items-center gap-2 rounded-lg border border-white/10 px-3 py-2 text-sm text-slate-400 transition-colors"
+          >
+            <FolderOpen size={14} />
+            Choose
+          </button>
+        </div>
+        {probe && probe.channels > 1 && (
+          <div className="space-y-1.5">
+            <p className="text-xs text-slate-500">
+              Channels — levels from the first minute; several are mixed to mono.
+            </p>
+            {probe.levels.map((level) => (
+              <label key={level.index} className="flex items-center gap-3 text-slate-300">
+                <input
+                  type="checkbox"
+                  checked={selected.includes(level.index)}
+                  onChange={() => toggleChannel(level.index)}
+                  className="accent-accent-cyan"
+                />
+                <span className="w-24 truncate">{level.label}</span>
+                <div className="h-1.5 flex-1 overflow-hidden rounded-full bg-white/10">
+                  <div
+                    className="bg-accent-cyan h-full rounded-full"
+                    style={{ width: `${meterPercent(level.rmsDb)}%` }}
+                  />
+                </div>
+                <span className="w-16 text-right font-mono text-xs text-slate-500">
+                  {level.rmsDb} dB
+                </span>
+              </label>
+            ))}
+          </div>
+        )}
+        <div className="flex items-center gap-2">
+          <input
+            type="text"
+            value={start}
+            onChange={(e) => setStart(e.target.value)}
+            placeholder="0:00"
+            aria-label="Range start"
+            className={inputClass}
+          />
+          <span className="text-slate-500">to</span>
+          <input
+            type="text"
+            value={end}
+            onChange={(e) => setEnd(e.target.value)}
+            placeholder="10:00"
+            aria-label="Range end"
+            className={inputClass}
+          />
+          <button
+            onClick={submit}
+            disabled={!ready}
+            className="hover:bg-accent-cyan/10 hover:text-accent-cyan ml-auto flex items-center gap-2 rounded-lg border border-white/10 px-3 py-2 text-sm text-slate-400 transition-colors disabled:opacity-40"
+          >
+            <Scissors size={14} />
+            Transcribe
+          </button>
+        </div>
+        {hasRange && !rangeValid && (
+          <p className="text-xs text-amber-400">
+            Use h:mm:ss or m:ss, with the end after the start.
+          </p>
+        )}
+        {source && !probing && !hasRange && !channelSubset && (
+          <p className="text-xs text-slate-500">
+            Enter a range{channelCount > 1 ? ' or untick channels' : ''} — for the whole
+            recording, import it as usual.
+          </p>
+        )}
+      </div>
+    </GlassCard>
+  );
+};
@@ -0,0 +1,220 @@
+import React, { useCallback, useEffect, useRef, useState } from 'react';
+import { Square, Video } from 'lucide-react';
+import { toast } from 'sonner';
+import { GlassCard } from '../ui/GlassCard';
+import { Button } from '../ui/Button';
+import { useImportQueueStore } from '../../src/stores/importQueueStore';
+import {
+  MeetingSession,
+  parseMeetingUrl,
+  type MeetingInfo,
+  type MeetingRecording,
+  type MeetingSessionState,
+} from '../../src/services/meetings';
+
+interface MeetingCaptureCardProps {
+  /** Capture system audio instead of an input device (Audio Configuration). */
+  systemAudio: boolean;
+  /** Input device ID — a microphone or a virtual cable. */
+  deviceId?: string;
+  /** Linux sink for system audio; unset on Windows/macOS (getDisplayMedia). */
+  monitorSinkName?: string;
+  isLinux: boolean;
+  /** Another recording or Live Mode owns the inputs. */
+  disabled: boolean;
+  /** Language code for the upload; undefined auto-detects. */
+  language?: string;
+  onAnalyserChange: (analyser: AnalyserNode | null) => void;
+  onActiveChange: (active: boolean) => void;
+}
+
+const STATE_LABELS: Record<MeetingSessionState, string> = {
+  starting: 'Joining…',
+  waiting: 'Waiting for audio',
+  recording: 'Recording',
+  ended: 'Ended',
+};
+
+/**
+ * Meeting capture: paste a Zoom / Teams / Meet / Webex / Jitsi link, and the
+ * meeting opens while the selected input is watched for activity. Recording
+ * starts when the call becomes audible and stops after a long silence; each
+ * part lands in the Audio Notebook titled after the meeting.
+ */
+export const MeetingCaptureCard: React.FC<MeetingCaptureCardProps> = ({
+  systemAudio,
+  deviceId,
+  monitorSinkName,
+  isLinux,
+  disabled,
+  language,
+  onAnalyserChange,
+  onActiveChange,
+}) => {
+  const [url, setUrl] = useState('');
+  const [title, setTitle] = useState('');
+  const [state, setState] = useState<MeetingSessionState | null>(null);
+  const sessionRef = useRef<MeetingSession | null>(null);
+
+  useEffect(
+    () => () => {
+      // Leaving the view ends the session; a part in progress is still filed.
+      void sessionRef.current?.end();
+    },
+    [],
+  );
+
+  let preview: string | null = null;
+  try {
+    preview = url.trim() ? parseMeetingUrl(url).title : null;
+  } catch {
+    preview = null;
+  }
+
+  const finish = useCallback(() => {
+    sessionRef.current = null;
+    setState(null);
+    onAnalyserChange(null);
+    onActiveChange(false);
+    if (systemAudio && !isLinux) window.electronAPI?.audio?.disableSystemAudioLoopback?.();
+  }, [systemAudio, isLinux, onAnalyserChange, onActiveChange]);
+
+  const file = useCallback(
+    ({ meeting, blob, startedAt, part }: MeetingRecording) => {
+      const name = title.trim() || meeting.title;
+      const stamp = startedAt.toISOString().slice(0, 19).replace(/[:T]/g, '-');
+      const recording = new File([blob], `meeting-${stamp}.webm`, { type: blob.type });
+      useImportQueueStore.getState().addPriorityFiles([recording], 'notebook-normal', {
+        language,
+        enable_word_timestamps: true,
+        title: part > 1 ? `${name} (part ${part})` : name,
+        file_created_at: startedAt.toISOString(),
+        // Post-transcription rules can match on the meeting link.
+        source: meeting.url,
+      });
+      toast.success('Meeting recording queued for transcription', { description: name });
+    },
+    [title, language],
+  );
+
+  const join = useCallback(async () => {
+    let meeting: MeetingInfo;
+    try {
+      meeting = parseMeetingUrl(url);
+    } catch (err) {
+      toast.error(err instanceof Error ? err.message : 'Not a valid meeting link');
+      return;
+    }
+    onActiveChange(true);
+    setState('starting');
+    const session = new MeetingSession(
+      meeting,
+      {
+        deviceId,
+        systemAudio,
+        monitorSinkName: systemAudio && isLinux ? monitorSinkName : undefined,
+        openUrl: (link) => window.electronAPI?.app?.openExternal(link),
+      },
+      {
+        onStateChange: setState,
+        onRecording: file,
+        onError: (err) =>
+          toast.error('Meeting recording failed', {
+            description: err instanceof Error ? err.message : undefined,
+          }),
+      },
+    );
+    sessionRef.current = session;
+    try {
+      if (systemAudio && !isLinux) await window.electronAPI?.audio?.enableSystemAudioLoopback?.();
+      await session.start();
+      onAnalyserChange(session.analyser);
+    } catch (err) {
+      finish();
+      if (err instanceof Error && err.name === 'AbortError') return;
+      toast.error('Could not start meeting capture', {
+        description: err instanceof Error ? err.message : undefined,
+      });
+    }
+  }, [
+    url,
+    deviceId,
+    systemAudio,
+    isLinux,
+    monitorSinkName,
+    file,
+    finish,
+    onActiveChange,
+    onAnalyserChange,
+  ]);
+
+  const leave = useCallback(async () => {
+    await sessionRef.current?.end();
+    finish();
+  }, [finish]);
+
+  const busy = state !== null;
+  const inputClass =
+    'focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white focus:outline-none disabled:opacity-50';
+
+  return (
+    <GlassCard
+      title={
+        <span className="flex items-center gap-2">
+          <Video size={16} className="text-accent-cyan" /> Meeting Capture
+        </span>
+      }
+      className="flex-none"
+    >
+      <div className="space-y-3">
+        <input
+          type="url"
+          value={url}
+          disabled={busy}
+          onChange={(e) => setUrl(e.target.value)}
+          placeholder="https://zoom.us/j/… or a Teams / Meet / Webex link"
+          aria-label="Meeting link"
+          className={inputClass}
+        />
+        <input
+          type="text"
+          value={title}
+          disabled={busy}
+          onChange={(e) => setTitle(e.target.value)}
+          placeholder={preview ?? 'Title (optional)'}
+          aria-label="Meeting title"
+          className={inputClass}
+        />
+        <div className="flex items-center justify-between gap-3">
+          {busy && state !== 'starting' ? (
+            <Button variant="danger" icon={<Square size={14} />} onClick={() => void leave()}>
+              Stop
+            </Button>
+          ) : (
+            <Button
+              variant="secondary"
+              icon={<Video size={14} />}
+              disabled={disabled || busy || !url.trim()}
+              onClick={() => void join()}
+            >
+              {state === 'starting' ? 'Joining…' : 'Join & Capture'}
+            </Button>
+          )}
+          {state && (
+            <span
+              className={`text-xs ${state === 'recording' ? 'text-red-400' : 'text-slate-400'}`}
+            >
+              {STATE_LABELS[state]}
+            </span>
+          )}
+        </div>
+        <p className="text-xs text-slate-500">
+          {systemAudio
+            ? 'Captures system audio.'
+            : 'Captures the selected input — pick a virtual cable to record only the call.'}{' '}
+          Recording starts when the call becomes audible; two minutes of silence ends a part.
+        </p>
+      </div>
+    </GlassCard>
+  );
+};
@@ -0,0 +1,144 @@
+import React, { useCallback, useEffect, useState } from 'react';
+import { Cpu } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '../ui/Button';
+
+export interface NativeBackendCardProps {
+  /** CUDA wheels for the GPU runtime, CPU wheels for everything else. */
+  useGpu: boolean;
+}
+
+const STATUS_LABEL: Record<NativeBackendStatus, string> = {
+  stopped: 'Stopped',
+  installing: 'Setting up…',
+  starting: 'Starting…',
+  running: 'Running',
+  stopping: 'Stopping…',
+  error: 'Error',
+};
+
+/**
+ * Run the server from a managed Python environment when no container
+ * runtime is installed. The first start downloads Python and the pinned
+ * backend dependencies (several GB with CUDA); later starts reuse them.
+ */
+export function NativeBackendCard({ useGpu }: NativeBackendCardProps): React.ReactElement | null {
+  const api = window.electronAPI?.nativeBackend;
+  const [status, setStatus] = useState<NativeBackendStatus>('stopped');
+  const [progress, setProgress] = useState<NativeBackendProgress | null>(null);
+  const [installed, setInstalled] = useState(false);
+
+  useEffect(() => {
+    if (!api) return;
+    api
+      .getState()
+      .then((state) => {
+        setStatus(state.status);
+        setProgress(state.progress);
+        setInstalled(state.installed);
+      })
+      .catch(() => {});
+    const offStatus = api.onStatusChanged((next) => {
+      setStatus(next);
+      if (next === 'running') setInstalled(true);
+    });
+    const offProgress = api.onProgress(setProgress);
+    return () => {
+      offStatus();
+      offProgress();
+    };
+  }, [api]);
+
+  const options = useCallback(
+    (force = false): NativeBackendBootstrapOptions => ({
+      extras: ['whisper'],
+      pytorchVariant: useGpu ? 'cu129' : 'cpu',
+      force,
+    }),
+    [useGpu],
+  );
+
+  const handleStart = useCallback(async () => {
+    if (!api) return;
+    const config = window.electronAPI?.config;
+    try {
+      const port = Number((await config?.get('server.port').catch(() => 9786)) ?? 9786);
+      const hfToken = ((await config?.get('server.hfToken').catch(() => '')) ?? '') as string;
+      await api.start({ ...options(), port, hfToken: hfToken || undefined });
+    } catch (err) {
+      toast.error(`Failed to start the server: ${err instanceof Error ? err.message : err}`);
+    }
+  }, [api, options]);
+
+  const handleRepair = useCallback(async () => {
+    if (!api) return;
+    try {
+      await api.install(options(true));
+      toast.success('Python environment reinstalled');
+    } catch (err) {
+      toast.error(`Reinstall failed: ${err instanceof Error ? err.message : err}`);
+    }
+  }, [api, options]);
+
+  if (!api) return null;
+
+  const busy = status === 'installing' || status === 'starting' || status === 'stopping';
+  const active = busy || status === 'running';
+  const download = useGpu ? "the server's dependencies (several GB with CUDA)" : 'its dependencies';
+  const setupNote = installed
+    ? 'its Python environment is already set up.'
+    : `the first start downloads Python and ${download}.`;
+
+  return (
+    <section
+      aria-labelledby="native-backend-title"
+      className="overflow-hidden rounded-xl border border-white/10 bg-white/5"
+    >
+      <div className="flex items-center gap-3 px-5 py-3.5">
+        <Cpu size={18} className="text-accent-cyan" />
+        <h3 id="native-backend-title" className="m-0 text-sm font-semibold text-white">
+          Run without Docker
+        </h3>
+        <span className="font-mono text-xs text-slate-500">{STATUS_LABEL[status]}</span>
+      </div>
+      <div className="space-y-2.5 px-5 pb-4">
+        <p className="m-0 text-xs text-slate-400">
+          No container runtime is installed. The server can run directly on this machine
+          instead — {setupNote}
+        </p>
+        {status === 'installing' && progress && (
+          <div>
+            <div className="h-1.5 overflow-hidden rounded-full bg-white/10">
+              <div
+                className={`bg-accent-cyan h-full transition-all duration-300 ${progress.percent === null ? 'animate-pulse' : ''}`}
+                style={{ width: `${progress.percent ?? 100}%` }}
+              />
+            </div>
+            <p className="mt-1 mb-0 truncate text-xs text-slate-500">{progress.message}</p>
+          </div>
+        )}
+        {status === 'error' && progress?.stage === 'error' && (
+          <p className="m-0 rounded bg-red-500/10 p-2 text-xs whitespace-pre-wrap text-red-200">
+            {progress.message}
+          </p>
+        )}
+        <div className="flex gap-2">
+          {active ? (
+            <Button variant="secondary" size="sm" onClick={() => void api.stop()} disabled={busy}>
+              Stop
+            </Button>
+          ) : (
+            <Button size="sm" onClick={() => void handleStart()}>
+              {installed ? 'Start server' : 'Set up & start'}
+            </Button>
+          )}
+          {installed && !active && (
+            <Button variant="ghost" size="sm" onClick={() => void handleRepair()}>
+              Reinstall environment
+            </Button>
+          )}
+        </div>
+      </div>
+    </section>
+  );
+}
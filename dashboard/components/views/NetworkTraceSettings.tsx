@@ -0,0 +1,104 @@
+import React, { useEffect, useState } from 'react';
+import { toast } from 'sonner';
+import { AppleSwitch } from '../ui/AppleSwitch';
+import { Button } from '../ui/Button';
+import { getConfig, setConfig } from '../../src/config/store';
+import { writeToClipboard } from '../../src/hooks/useClipboard';
+import {
+  NETWORK_TRACE_CAPACITY,
+  clearNetworkTrace,
+  formatNetworkTrace,
+  getNetworkTrace,
+  setNetworkTraceEnabled,
+  subscribeNetworkTrace,
+} from '../../src/services/networkTrace';
+
+const VISIBLE_ENTRIES = 50;
+
+/**
+ * Network trace for Settings → App. The switch is saved immediately — it is
+ * not part of the modal's Save flow — and the trace itself lives in memory
+ * only, so it is empty again after a restart.
+ */
+export const NetworkTraceSettings: React.FC = () => {
+  const [enabled, setEnabled] = useState(false);
+  const [trace, setTrace] = useState(getNetworkTrace);
+  const [expanded, setExpanded] = useState(false);
+
+  useEffect(() => {
+    getConfig<boolean>('app.networkTrace')
+      .then((v) => setEnabled(v === true))
+      .catch(() => {});
+    return subscribeNetworkTrace(() => setTrace(getNetworkTrace()));
+  }, []);
+
+  const toggle = (on: boolean) => {
+    setEnabled(on);
+    setNetworkTraceEnabled(on);
+    void setConfig('app.networkTrace', on);
+  };
+
+  const copy = async () => {
+    await writeToClipboard(formatNetworkTrace(trace));
+    toast.success('Network trace copied');
+  };
+
+  return (
+    <div className="space-y-3">
+      <AppleSwitch
+        checked={enabled}
+        onChange={toggle}
+        label="Record server traffic"
+        description="Keeps the last requests and socket messages in memory for bug reports. Tokens and passwords are redacted."
+      />
+      <div className="flex items-center justify-between">
+        <span className="text-xs text-slate-500">
+          {trace.length} of {NETWORK_TRACE_CAPACITY} entries
+        </span>
+        <div className="flex gap-2">
+          <Button
+            variant="ghost"
+            size="sm"
+            onClick={() => setExpanded((v) => !v)}
+            disabled={trace.length === 0}
+          >
+            {expanded ? 'Hide' : 'View'}
+          </Button>
+          <Button
+            variant="secondary"
+            size="sm"
+            onClick={() => void copy()}
+            disabled={trace.length === 0}
+          >
+            Copy
+          </Button>
+          <Button
+            variant="ghost"
+            size="sm"
+            onClick={clearNetworkTrace}
+            disabled={trace.length === 0}
+          >
+            Clear
+          </Button>
+        </div>
+      </div>
+      {expanded && trace.length > 0 && (
+        <ul className="max-h-64 space-y-1 overflow-y-auto rounded-lg border border-white/10 bg-black/20 p-2 font-mono text-[11px]">
+          {trace
+            .slice(-VISIBLE_ENTRIES)
+            .reverse()
+            .map((e) => (
+              <li key={e.id} className="truncate text-slate-400" title={e.responseBody ?? e.error}>
+                <span className={e.error || (e.status ?? 0) >= 400 ? 'text-red-400' : ''}>
+                  {e.kind === 'ws' ? `WS ${e.method}` : e.method} {e.status ?? ''}
+                </span>{' '}
+                {e.url}
+                {e.durationMs !== undefined && ` · ${e.durationMs} ms`}
+                {e.error && ` · ${e.error}`}
+              </li>
+            ))}
+        </ul>
+      )}
+    </div>
+  );
+};
@@ -27,14 +27,17 @@ import {
   Pause,
   FolderOpen,
   WifiOff,
+  Target,
 } from 'lucide-react';
 import { GlassCard } from '../ui/GlassCard';
 import { Button } from '../ui/Button';
 import { AppleSwitch } from '../ui/AppleSwitch';
 import { AudioNoteModal } from './AudioNoteModal';
 import { AddNoteModal } from './AddNoteModal';
+import { ServerQueuePanel } from './ServerQueuePanel';
 import { useCalendar } from '../../src/hooks/useCalendar';
 import { useSearch } from '../../src/hooks/useSearch';
+import { useMetadataFields } from '../../src/hooks/useMetadataFields';
 import { useLanguages } from '../../src/hooks/useLanguages';
 import { useShallow } from 'zustand/react/shallow';
 import {
@@ -48,10 +51,20 @@ import {
 import type { UnifiedImportJob } from '../../src/stores/importQueueStore';
 import { useAdminStatus } from '../../src/hooks/useAdminStatus';
 import { useNotebookWatcher } from '../../src/hooks/useNotebookWatcher';
+import { describeMedia, useMediaInfo } from '../../src/hooks/useMediaInfo';
 import { apiClient } from '../../src/api/client';
-import type { AdminStatus, Recording } from '../../src/api/types';
+import type { AdminStatus, ProjectTemplate, Recording } from '../../src/api/types';
 import { jobTrackerFromAdminStatus } from '../../src/api/types';
-import { describeJobProgress } from '../../src/services/jobProgress';
+import {
+  describeDowngrade,
+  describeJobProgress,
+  describeServerQueueWait,
+  formatClock,
+} from '../../src/services/jobProgress';
+import { describeUploadProgress } from '../../src/services/uploadPipeline';
+import { runPlugins } from '../../src/services/plugins';
+import { transcriptionFromSegments } from '../../src/services/transcriptionFormatters';
+import { filterTranscription, loadOutputFilter } from '../../src/services/outputFilter';
 import { supportsExplicitWordTimestampToggle as supportsExplicitWordTimestampToggleForModel } from '../../src/utils/transcriptionBackend';
 import {
   isCanaryModel,
@@ -61,7 +74,18 @@ import {
 import { toast } from 'sonner';
 import { useConfirm } from '../../src/hooks/useConfirm';
 import { DeleteRecordingDialog } from '../recording/DeleteRecordingDialog';
+import { SubtitleImportCard } from '../import/SubtitleImportCard';
+import { TranscriptImportCard } from '../import/TranscriptImportCard';
+import { CloudImportCard } from '../import/CloudImportCard';
+import { PhoneIngestCard } from '../import/PhoneIngestCard';
+import { LibraryExportCard } from '../import/LibraryExportCard';
 import { useActiveProfileStore } from '../../src/stores/activeProfileStore';
+import {
+  applyTemplateOptions,
+  filledMetadata,
+  useProjectTemplates,
+} from '../../src/hooks/useProjectTemplates';
+import { ProjectTemplatePicker } from '../projects/ProjectTemplatePicker';
 import { useNotificationsStore } from '../../src/stores/notificationsStore';
 import { getConfig, setConfig } from '../../src/config/store';
 import { useScrollFade } from '../../src/hooks/useScrollFade';
@@ -278,6 +302,14 @@ const NoteActionMenu: React.FC<MenuProps> = ({
   const renameInputRef = useRef<HTMLInputElement>(null);
   const getValidRecordingId = (): number | null =>
     typeof recordingId === 'number' && Number.isFinite(recordingId) ? recordingId : null;
+  // Enabled exporter plugins add "Export <format>" entries (services/plugins.ts).
+  const [pluginExporters, setPluginExporters] = useState<PluginInfo[]>([]);
+  useEffect(() => {
+    window.electronAPI?.plugins
+      ?.list()
+      .then((plugins) => setPluginExporters(plugins.filter((p) => p.enabled && p.exporter)))
+      .catch(() => {});
+  }, []);
 
   const handlePlay = () => {
     onPlay(noteEventId);
@@ -329,6 +361,51 @@ const NoteActionMenu: React.FC<MenuProps> = ({
     onClose();
   };
 
+  const handleMarkReference = async () => {
+    onClose();
+    const targetId = getValidRecordingId();
+    if (targetId === null) return;
+    try {
+      await apiClient.setGoldenReference(targetId, true);
+      toast.success('Added to the accuracy golden set');
+    } catch (err) {
+      toast.error(err instanceof Error ? err.message : 'Could not use it as a reference');
+    }
+  };
+
+  const handlePluginExport = async (plugin: PluginInfo) => {
+    onClose();
+    const targetId = getValidRecordingId();
+    const fileIO = window.electronAPI?.fileIO;
+    if (targetId === null || !fileIO) return;
+    try {
+      const detail = await apiClient.getRecording(targetId);
+      const stem = noteTitle.replace(/[\\/:*?"<>|]+/g, '_').trim() || `recording-${targetId}`;
+      const transcription = filterTranscription(
+        transcriptionFromSegments(detail.segments),
+        await loadOutputFilter(),
+      );
+      const result = await runPlugins(stem, transcription, {
+        exporterIds: [plugin.id],
+      });
+      const output = result.outputs[0];
+      if (!output) {
+        toast.error(result.errors.join('; ') || `${plugin.name} produced no output.`);
+        return;
+      }
+      const extension = plugin.exporter?.extension ?? 'txt';
+      const filePath = await fileIO.saveFile({
+        defaultPath: output.outputFilename,
+        filters: [{ name: plugin.exporter?.label ?? plugin.name, extensions: [extension] }],
+      });
+      if (!filePath) return;
+      await fileIO.writeText(filePath, output.content);
+      toast.success(`Exported ${output.outputFilename}.`);
+    } catch (err) {
+      toast.error(err instanceof Error ? err.message : 'Export failed.');
+    }
+  };
+
   const handleDelete = () => {
     const targetId = getValidRecordingId();
     if (targetId === null) {
@@ -521,6 +598,24 @@ const NoteActionMenu: React.FC<MenuProps> = ({
               <Download size={14} />
               Export ASS
             </button>
+            {pluginExporters.map((plugin) => (
+              <button
+                key={plugin.id}
+                onClick={() => void handlePluginExport(plugin)}
+                className="flex w-full items-center gap-2.5 px-3 py-2 text-left text-xs text-slate-300 transition-colors hover:bg-white/10 hover:text-white"
+              >
+                <Download size={14} />
+                Export {plugin.exporter?.label}
+              </button>
+            ))}
+            <button
+              onClick={() => void handleMarkReference()}
+              title="Corrected transcripts only: re-checked against new models for accuracy drift"
+              className="flex w-full items-center gap-2.5 px-3 py-2 text-left text-xs text-slate-300 transition-colors hover:bg-white/10 hover:text-white"
+            >
+              <Target size={14} />
+              Use as Reference
+            </button>
             <div className="mx-2 my-1 h-px bg-white/5"></div>
             <button
               onClick={handleDelete}
@@ -673,6 +768,8 @@ interface EventData {
   title: string;
   duration?: string;
   tag?: string;
+  /** The transcript covers only part of the audio (the job stopped early). */
+  partial?: boolean;
   startTime: number;
   recordingId?: number;
 }
@@ -714,6 +811,9 @@ const TimeSection: React.FC<{
     trigger: MenuTrigger;
   } | null>(null);
   const isCompact = visibleSlots >= 4;
+  const mediaInfo = useMediaInfo(
+    events.flatMap((evt) => (evt.recordingId !== undefined ? [evt.recordingId] : [])),
+  );
   // GH #92: highlight the hour row currently under the user's drag cursor.
   // dragOver fires repeatedly so setting on dragOver (mirroring the existing
   // import-tab pattern at line ~1670) keeps the state accurate; clear on
@@ -886,6 +986,13 @@ const TimeSection: React.FC<{
                       .toString()
                       .padStart(2, '0');
                     const timeStr = `${Math.floor(evt.startTime).toString().padStart(2, '0')}:${minutes}`;
+                    const media =
+                      evt.recordingId !== undefined ? mediaInfo.get(evt.recordingId) : undefined;
+                    const mediaLabel = describeMedia(media);
+                    const previewUrl =
+                      media?.status === 'ready' && media.preview && evt.recordingId !== undefined
+                        ? apiClient.getPreviewUrl(evt.recordingId)
+                        : null;
                     return (
                       <div
                         key={evt.id}
@@ -893,15 +1000,32 @@ const TimeSection: React.FC<{
                         onContextMenu={(e) => handleContextMenu(e, evt)}
                         className="bg-glass-200 hover:bg-glass-300 group/card relative h-[85%] w-35 flex-none cursor-pointer snap-start overflow-hidden rounded-xl border border-white/10 p-3 shadow-sm transition-all duration-300 hover:-translate-y-1 hover:border-white/20 hover:shadow-lg active:scale-[0.98]"
                       >
+                        {previewUrl && (
+                          <img
+                            src={previewUrl}
+                            alt=""
+                            loading="lazy"
+                            className="pointer-events-none absolute inset-0 h-full w-full object-cover opacity-20 transition-opacity group-hover/card:opacity-30"
+                          />
+                        )}
                         <div className="bg-accent-cyan absolute top-0 bottom-0 left-0 w-1 opacity-80 transition-opacity group-hover/card:opacity-100"></div>
-                        <div className="flex h-full flex-col justify-between gap-2">
+                        <div className="relative flex h-full flex-col justify-between gap-2">
                           <div className="flex items-start justify-between">
                             <div className="rounded bg-black/30 px-1.5 py-0.5 font-mono text-[9px] text-slate-400">
                               {timeStr}
                             </div>
-                            {!isCompact && evt.tag === 'Diarized' && (
-                              <div className="bg-accent-cyan/10 text-accent-cyan border-accent-cyan/20 rounded border px-1 py-0.5 text-[8px] font-bold tracking-wider uppercase">
-                                DIARIZED
+                            {!isCompact && (evt.tag === 'Diarized' || evt.partial) && (
+                              <div className="flex gap-1">
+                                {evt.partial && (
+                                  <div className="rounded border border-amber-400/20 bg-amber-400/10 px-1 py-0.5 text-[8px] font-bold tracking-wider text-amber-300 uppercase">
+                                    PARTIAL
+                                  </div>
+                                )}
+                                {evt.tag === 'Diarized' && (
+                                  <div className="bg-accent-cyan/10 text-accent-cyan border-accent-cyan/20 rounded border px-1 py-0.5 text-[8px] font-bold tracking-wider uppercase">
+                                    DIARIZED
+                                  </div>
+                                )}
                               </div>
                             )}
                           </div>
@@ -917,6 +1041,11 @@ const TimeSection: React.FC<{
                                 {evt.duration}
                               </div>
                             )}
+                            {!isCompact && mediaLabel && (
+                              <div className="mt-0.5 truncate font-mono text-[8px] text-slate-500">
+                                {mediaLabel}
+                              </div>
+                            )}
                           </div>
                           {!isCompact && (
                             <div className="mt-auto flex items-center justify-end gap-2 border-t border-white/5 pt-1.5 opacity-0 transition-opacity group-hover/card:opacity-100">
@@ -1014,6 +1143,7 @@ const recordingToEvent = (rec: Recording): EventData => {
     startTime,
     duration: formatDuration(rec.duration_seconds),
     tag: rec.has_diarization ? 'Diarized' : undefined,
+    partial: rec.partial_until != null,
     recordingId: rec.id,
   };
 };
@@ -1359,6 +1489,10 @@ const SearchTab: React.FC<{ onNoteClick: (note: any) => void }> = ({ onNoteClick
   const [fuzzy, setFuzzy] = useState(true);
   const [startDate, setStartDate] = useState('');
   const [endDate, setEndDate] = useState('');
+  const [filterField, setFilterField] = useState('');
+  const [filterValue, setFilterValue] = useState('');
+  const metadataFields = useMetadataFields();
+  const filterValues = metadataFields.find((f) => f.field === filterField)?.values ?? [];
   const { results, count, loading, error, search } = useSearch();
 
   // Trigger search whenever inputs change
@@ -1367,8 +1501,9 @@ const SearchTab: React.FC<{ onNoteClick: (note: any) => void }> = ({ onNoteClick
       fuzzy,
       startDate: startDate || undefined,
       endDate: endDate || undefined,
+      metadata: filterField && filterValue ? { [filterField]: filterValue } : undefined,
     });
-  }, [query, fuzzy, startDate, endDate, search]);
+  }, [query, fuzzy, startDate, endDate, filterField, filterValue, search]);
 
   return (
     <div className="mx-auto max-w-3xl space-y-6">
@@ -1409,6 +1544,44 @@ const SearchTab: React.FC<{ onNoteClick: (note: any) => void }> = ({ onNoteClick
               className="rounded border border-white/10 bg-black/20 px-2 py-1 text-xs text-slate-300"
             />
           </div>
+          {metadataFields.length > 0 && (
+            <>
+              <div className="h-6 w-px bg-white/10"></div>
+              <div className="flex gap-2">
+                <select
+                  value={filterField}
+                  onChange={(e) => {
+                    setFilterField(e.target.value);
+                    setFilterValue('');
+                  }}
+                  aria-label="Filter by field"
+                  className="rounded border border-white/10 bg-black/20 px-2 py-1 text-xs text-slate-300"
+                >
+                  <option value="">Any field</option>
+                  {metadataFields.map((f) => (
+                    <option key={f.field} value={f.field}>
+                      {f.field}
+                    </option>
+                  ))}
+                </select>
+                {filterField && (
+                  <select
+                    value={filterValue}
+                    onChange={(e) => setFilterValue(e.target.value)}
+                    aria-label={`${filterField} value`}
+                    className="rounded border border-white/10 bg-black/20 px-2 py-1 text-xs text-slate-300"
+                  >
+                    <option value="">Any value</option>
+                    {filterValues.map((v) => (
+                      <option key={v} value={v}>
+                        {v}
+                      </option>
+                    ))}
+                  </select>
+                )}
+              </div>
+            </>
+          )}
         </div>
 
         <div className="selectable-text mt-4 space-y-2">
@@ -1512,6 +1685,12 @@ const ImportTab = ({
   // sees profile_snapshot=None and short-circuits as a no-op.
   const activeProfileId = useActiveProfileStore((s) => s.activeProfileId);
 
+  // Project template for the next manual imports: its options override the
+  // toggles below and its fields are stored on each recording.
+  const { templates: projectTemplates } = useProjectTemplates();
+  const [projectTemplate, setProjectTemplate] = useState<ProjectTemplate | null>(null);
+  const [projectMetadata, setProjectMetadata] = useState<Record<string, string>>({});
+
   const {
     notebookWatchPath,
     notebookWatchActive,
@@ -1719,7 +1898,7 @@ const ImportTab = ({
   );
 
   const handleFiles = useCallback(
-    (files: FileList | null) => {
+    (files: FileList | (File | string)[] | null) => {
       if (!files || files.length === 0) return;
 
       // gh-102 followup #2: mirror SessionImportTab.handleFiles guard
@@ -1729,7 +1908,7 @@ const ImportTab = ({
       // picker round-trips to the backend fail-loud path. Wording matches the
       // live-recording / session-import guard verbatim so future copy changes
       // propagate via grep.
-      const resolvedLang = resolveLanguage(mainLanguage);
+      const resolvedLang = projectTemplate?.options.language ?? resolveLanguage(mainLanguage);
       if (resolvedLang === undefined && !supportsAutoDetect(activeModel)) {
         toast.error('Source language required', {
           description: languagesLoading
@@ -1749,18 +1928,29 @@ const ImportTab = ({
         ? (resolveLanguage(mainBidiTarget) ?? 'en')
         : 'en';
 
-      addFiles(Array.from(files), 'notebook-normal', {
-        language: resolvedLang,
-        translation_enabled: mainTranslateActive ? true : undefined,
-        translation_target_language: mainTranslateActive ? mainTranslateTarget : undefined,
-        enable_diarization: effectiveDiarization,
-        enable_word_timestamps: supportsExplicitWordTimestampToggle ? wordTimestamps : true,
-        parallel_diarization: effectiveDiarization ? parallelDiarization : undefined,
-        // Sprint 4 deferred-work no. 2 — pass undefined (not null) when no
-        // active profile is set so apiClient.uploadAndTranscribe's `!= null`
-        // guard correctly omits the FormData field.
-        profile_id: activeProfileId ?? undefined,
-      });
+      // Cloud imports arrive as local cache paths rather than File objects.
+      const list = Array.from(files);
+      const firstName = typeof list[0] === 'string' ? list[0].split(/[\\/]/).pop() : list[0].name;
+      addFiles(
+        list,
+        'notebook-normal',
+        applyTemplateOptions(
+          {
+            language: resolvedLang,
+            translation_enabled: mainTranslateActive ? true : undefined,
+            translation_target_language: mainTranslateActive ? mainTranslateTarget : undefined,
+            enable_diarization: effectiveDiarization,
+            enable_word_timestamps: supportsExplicitWordTimestampToggle ? wordTimestamps : true,
+            parallel_diarization: effectiveDiarization ? parallelDiarization : undefined,
+            // Sprint 4 deferred-work no. 2 — pass undefined (not null) when no
+            // active profile is set so apiClient.uploadAndTranscribe's `!= null`
+            // guard correctly omits the FormData field.
+            profile_id: activeProfileId ?? undefined,
+            metadata: filledMetadata(projectMetadata),
+          },
+          projectTemplate,
+        ),
+      );
 
       // Enqueue feedback for the Notebook import tab (parity with the Session
       // import tab and AddNoteModal, which both surface a queued record).
@@ -1769,7 +1959,7 @@ const ImportTab = ({
         category: 'import',
         title:
           files.length === 1
-            ? `Added "${files[0].name}" to the Import Queue`
+            ? `Added "${firstName}" to the Import Queue`
             : `${files.length} files added to the Import Queue`,
         status: 'complete',
       });
@@ -1787,6 +1977,8 @@ const ImportTab = ({
     [
       addFiles,
       activeProfileId,
+      projectTemplate,
+      projectMetadata,
       effectiveDiarization,
       parallelDiarization,
       supportsExplicitWordTimestampToggle,
@@ -1831,8 +2023,18 @@ const ImportTab = ({
   const statusLabel = (job: UnifiedImportJob) => {
     switch (job.status) {
       case 'pending':
+        if (job.uploadProgress && job.uploadProgress.sent < job.uploadProgress.total) {
+          return describeUploadProgress(job.uploadProgress);
+        }
         return 'Queued';
       case 'processing': {
+        if (job.serverQueue) {
+          const { position, estimatedStartAt } = job.serverQueue;
+          return describeServerQueueWait(position, estimatedStartAt, Date.now() / 1000);
+        }
+        if (job.uploadProgress && job.uploadProgress.sent < job.uploadProgress.total) {
+          return describeUploadProgress(job.uploadProgress);
+        }
         // GH-211: phase/position label computed from the parent's polled prop.
         // Do NOT call useJobProgress here — it would open a second admin poll
         // that bypasses the parent's 403 circuit breaker.
@@ -1848,7 +2050,12 @@ const ImportTab = ({
       case 'success': {
         const diar = job.diarizationOutcome;
         const skipped = !!diar?.requested && !diar?.performed;
-        const base = `Done — ID ${job.result?.recording_id}`;
+        const done = `Done — ID ${job.result?.recording_id}`;
+        const partial =
+          job.partialUntil !== undefined
+            ? `${done} (partial — stopped at ${formatClock(job.partialUntil)})`
+            : done;
+        const base = job.downgrade ? `${partial} (${describeDowngrade(job.downgrade)})` : partial;
         if (!skipped) return base;
         const why =
           diar?.reason === 'token_missing' ? 'no HF token' : (diar?.reason ?? 'unavailable');
@@ -1898,6 +2105,16 @@ const ImportTab = ({
         <Button variant="primary">Browse Files</Button>
       </div>
 
+      <ProjectTemplatePicker
+        templates={projectTemplates}
+        template={projectTemplate}
+        metadata={projectMetadata}
+        onChange={(template, metadata) => {
+          setProjectTemplate(template);
+          setProjectMetadata(metadata);
+        }}
+      />
+
       {/* 4.6 — First-run hint: suggest watch folder after 3+ manual imports */}
       {showWatchHint && hasElectronApi && (
         <div className="flex items-start gap-3 rounded-xl border border-amber-400/20 bg-amber-400/5 px-3 py-2.5">
@@ -1916,6 +2133,16 @@ const ImportTab = ({
         </div>
       )}
 
+      <SubtitleImportCard language={resolveLanguage(mainLanguage)} />
+
+      <TranscriptImportCard language={resolveLanguage(mainLanguage)} />
+
+      {hasElectronApi && <CloudImportCard onImport={handleFiles} />}
+
+      {hasElectronApi && <PhoneIngestCard />}
+
+      <LibraryExportCard />
+
       {/* Folder Watch */}
       {hasElectronApi && (
         <GlassCard title="Folder Watch">
@@ -2116,6 +2343,9 @@ const ImportTab = ({
         </GlassCard>
       )}
 
+      {/* Shown while our next job waits behind other clients on a shared server */}
+      {jobs.some((j) => j.serverQueue) && <ServerQueuePanel />}
+
       {/* Import Info Note */}
       <div className="flex items-start gap-2 rounded-lg bg-white/5 px-3 py-2.5">
         <Info size={14} className="mt-0.5 shrink-0 text-slate-500" />
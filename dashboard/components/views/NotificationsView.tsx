@@ -1,14 +1,18 @@
 /**
- * NotificationsView - the session notification log (View.NOTIFICATIONS).
+ * NotificationsView - the session notification log (View.NOTIFICATIONS),
+ * followed by the history of earlier sessions.
  *
- * Read-only by design: records cannot be dismissed or deleted here; the
- * whole log clears when the app quits. Transcription records embed the
- * transcript behind a collapsible block (transcripts can be megabytes).
+ * The session log is read-only by design: records cannot be dismissed or
+ * deleted here; it clears when the app quits. History entries can be
+ * dismissed one by one; unread ones (never seen in this view) are marked
+ * with a dot and become read when the view is left. Transcription records
+ * embed the transcript behind a collapsible block (transcripts can be
+ * megabytes).
  */
 
-import { useMemo, useState } from 'react';
+import { useEffect, useMemo, useState } from 'react';
 import { useShallow } from 'zustand/react/shallow';
-import { Bell, ChevronDown } from 'lucide-react';
+import { Bell, ChevronDown, X } from 'lucide-react';
 import { GlassCard } from '../ui/GlassCard';
 import {
   CATEGORY_COLOR,
@@ -16,10 +20,12 @@ import {
   NotificationStatusIcon,
   severityBorderClass,
 } from '../ui/notificationVisuals';
+import { useNotificationHistory } from '../../src/hooks/useNotificationHistory';
 import {
   useNotificationsStore,
   selectAllNotifications,
   type AppNotification,
+  type NotificationCategory,
 } from '../../src/stores/notificationsStore';
 
 function formatTime(ts: number): string {
@@ -30,6 +36,23 @@ function formatTime(ts: number): string {
   });
 }
 
+function formatDateTime(ts: number): string {
+  return new Date(ts).toLocaleString([], {
+    month: 'short',
+    day: 'numeric',
+    hour: '2-digit',
+    minute: '2-digit',
+  });
+}
+
+function fromHistory(record: NotificationHistoryRecord): AppNotification {
+  return {
+    ...record,
+    category: record.category as NotificationCategory,
+    toastDismissed: true,
+  };
+}
+
 function TranscriptBlock({ transcript }: { transcript: string }) {
   const [expanded, setExpanded] = useState(false);
   return (
@@ -52,7 +75,13 @@ function TranscriptBlock({ transcript }: { transcript: string }) {
   );
 }
 
-function NotificationRow({ item }: { item: AppNotification }) {
+interface NotificationRowProps {
+  item: AppNotification;
+  /** History rows: show the date, an unread dot and a dismiss button. */
+  history?: { unread: boolean; onDismiss: () => void };
+}
+
+function NotificationRow({ item, history }: NotificationRowProps) {
   const isActive = item.status === 'active';
   return (
     <div
@@ -64,14 +93,27 @@ function NotificationRow({ item }: { item: AppNotification }) {
         </span>
         <div className="min-w-0 flex-1">
           <div className="flex items-center gap-2">
+            {history?.unread && (
+              <span className="bg-accent-cyan h-1.5 w-1.5 shrink-0 rounded-full" title="Unread" />
+            )}
             <span className="truncate text-sm font-medium text-slate-200">{item.title}</span>
             <NotificationStatusIcon status={item.status} />
           </div>
           {item.detail && <p className="mt-0.5 text-xs text-slate-500">{item.detail}</p>}
         </div>
         <span className="shrink-0 font-mono text-[10px] text-slate-500">
-          {formatTime(item.createdAt)}
+          {history ? formatDateTime(item.createdAt) : formatTime(item.createdAt)}
         </span>
+        {history && (
+          <button
+            type="button"
+            onClick={history.onDismiss}
+            className="shrink-0 p-0.5 text-slate-500 transition-colors hover:text-white"
+            title="Dismiss"
+          >
+            <X size={12} />
+          </button>
+        )}
       </div>
 
       {isActive && (
@@ -105,6 +147,14 @@ export function NotificationsView() {
     () => [...notifications].sort((a, b) => b.createdAt - a.createdAt),
     [notifications],
   );
+  const { history, dismiss, markRead } = useNotificationHistory();
+  const earlier = useMemo(() => {
+    const inSession = new Set(notifications.map((n) => n.entryId));
+    return history.filter((record) => !inSession.has(record.entryId));
+  }, [history, notifications]);
+
+  // Everything listed here has been seen once the view is left.
+  useEffect(() => () => void markRead(), [markRead]);
 
   return (
     <div className="custom-scrollbar h-full overflow-y-auto p-6">
@@ -126,6 +176,37 @@ export function NotificationsView() {
           </div>
         )}
       </GlassCard>
+      {earlier.length > 0 && (
+        <GlassCard
+          title="Earlier"
+          className="mt-6"
+          action={
+            earlier.some((record) => !record.read) && (
+              <button
+                type="button"
+                onClick={() => void markRead()}
+                className="text-xs text-slate-400 transition-colors hover:text-white"
+              >
+                Mark all read
+              </button>
+            )
+          }
+        >
+          <p className="mb-4 text-xs text-slate-500">
+            Kept across restarts, so popups you missed are not lost. Dismissed entries are removed
+            from this list.
+          </p>
+          <div className="space-y-2">
+            {earlier.map((record) => (
+              <NotificationRow
+                key={record.entryId}
+                item={fromHistory(record)}
+                history={{ unread: !record.read, onDismiss: () => void dismiss(record.entryId) }}
+              />
+            ))}
+          </div>
+        </GlassCard>
+      )}
     </div>
   );
 }
@@ -0,0 +1,101 @@
+import React, { useEffect, useState } from 'react';
+import { Puzzle, Trash2 } from 'lucide-react';
+import { Button } from '../ui/Button';
+import { AppleSwitch } from '../ui/AppleSwitch';
+import { useConfirm } from '../../src/hooks/useConfirm';
+
+function errorText(err: unknown): string {
+  const message = err instanceof Error ? err.message : String(err);
+  // ipcRenderer.invoke wraps main-process errors in a generic prefix.
+  return message.replace(/^Error invoking remote method '[^']+': (Error: )?/, '');
+}
+
+const KIND_LABELS: Record<PluginInfo['kind'], string> = {
+  postprocessor: 'Post-processor',
+  exporter: 'Exporter',
+};
+
+/**
+ * Settings → App → Plugins: install, enable and remove WebAssembly plugins
+ * (electron/pluginHost.ts). Enabled post-processors rewrite every written
+ * transcript; enabled exporters add their format next to the built-in ones.
+ */
+export const PluginSettings: React.FC = () => {
+  const plugins = window.electronAPI?.plugins;
+  const [installed, setInstalled] = useState<PluginInfo[]>([]);
+  const [error, setError] = useState<string | null>(null);
+  const { confirm, dialog } = useConfirm();
+
+  useEffect(() => {
+    if (!plugins) return;
+    plugins
+      .list()
+      .then(setInstalled)
+      .catch(() => {});
+    return plugins.onChanged(setInstalled);
+  }, [plugins]);
+
+  if (!plugins) {
+    return <p className="text-xs text-slate-500">Plugins are only available in the app.</p>;
+  }
+
+  const attempt = async (action: () => Promise<unknown>) => {
+    setError(null);
+    try {
+      await action();
+    } catch (err) {
+      setError(errorText(err));
+    }
+  };
+
+  const remove = async (plugin: PluginInfo) => {
+    if (!(await confirm(`Remove the ${plugin.name} plugin?`, { confirmLabel: 'Remove' }))) return;
+    await attempt(() => plugins.remove(plugin.id));
+  };
+
+  return (
+    <div className="space-y-3">
+      <p className="text-xs text-slate-400">
+        Plugins are WebAssembly modules that rewrite transcripts or export them in formats the app
+        doesn&apos;t have. They run sandboxed: no file, network or system access, limited memory and
+        time.
+      </p>
+      {installed.map((plugin) => (
+        <div key={plugin.id} className="flex items-start gap-3">
+          <div className="min-w-0 flex-1">
+            <p className="truncate text-sm text-white/90">
+              {plugin.name} <span className="text-xs text-slate-500">{plugin.version}</span>
+            </p>
+            <p className="truncate text-xs text-slate-500">
+              {KIND_LABELS[plugin.kind]}
+              {plugin.exporter ? ` · .${plugin.exporter.extension}` : ''}
+              {plugin.description ? ` · ${plugin.description}` : ''}
+            </p>
+          </div>
+          <AppleSwitch
+            size="sm"
+            checked={plugin.enabled}
+            onChange={(enabled) => void attempt(() => plugins.setEnabled(plugin.id, enabled))}
+          />
+          <Button
+            variant="ghost"
+            size="sm"
+            icon={<Trash2 size={14} />}
+            onClick={() => void remove(plugin)}
+            aria-label={`Remove ${plugin.name}`}
+          />
+        </div>
+      ))}
+      <Button
+        variant="secondary"
+        size="sm"
+        icon={<Puzzle size={14} />}
+        onClick={() => void attempt(() => plugins.install())}
+      >
+        Install plugin…
+      </Button>
+      {error && <p className="text-xs text-red-400">{error}</p>}
+      {dialog}
+    </div>
+  );
+};
@@ -0,0 +1,254 @@
+import React, { useState } from 'react';
+import { Plus, Save, Trash2, X } from 'lucide-react';
+import { Button } from '../ui/Button';
+import { useProjectTemplates } from '../../src/hooks/useProjectTemplates';
+import type { ProjectTemplate, ProjectTemplatePayload } from '../../src/api/types';
+
+interface FieldRow {
+  key: string;
+  value: string;
+}
+
+interface Draft {
+  id: number | null;
+  name: string;
+  description: string;
+  rows: FieldRow[];
+  language: string;
+  diarization: '' | 'on' | 'off';
+  speakers: string;
+}
+
+const EMPTY_DRAFT: Draft = {
+  id: null,
+  name: '',
+  description: '',
+  rows: [{ key: '', value: '' }],
+  language: '',
+  diarization: '',
+  speakers: '',
+};
+
+function toDraft(t: ProjectTemplate): Draft {
+  const { language, enable_diarization, expected_speakers } = t.options;
+  return {
+    id: t.id,
+    name: t.name,
+    description: t.description ?? '',
+    rows: t.fields.map(({ key }) => ({ key, value: t.defaults[key] ?? '' })),
+    language: language ?? '',
+    diarization: enable_diarization === undefined ? '' : enable_diarization ? 'on' : 'off',
+    speakers: expected_speakers ? String(expected_speakers) : '',
+  };
+}
+
+function toPayload(d: Draft): ProjectTemplatePayload {
+  const rows = d.rows.filter((r) => r.key.trim());
+  const speakers = Number(d.speakers);
+  return {
+    name: d.name.trim(),
+    description: d.description.trim() || null,
+    fields: rows.map((r) => ({ key: r.key.trim(), label: r.key.trim() })),
+    defaults: Object.fromEntries(rows.map((r) => [r.key.trim(), r.value.trim()])),
+    options: {
+      language: d.language.trim() || undefined,
+      enable_diarization: d.diarization === '' ? undefined : d.diarization === 'on',
+      expected_speakers: speakers >= 1 && speakers <= 10 ? speakers : undefined,
+    },
+  };
+}
+
+const INPUT =
+  'focus:border-accent-cyan/50 min-w-0 rounded-lg border border-white/10 bg-black/20 px-3 py-1.5 text-sm text-white focus:outline-none';
+
+/**
+ * Project templates for Settings → Notebook. Like voice profiles, edits are
+ * saved to the server directly rather than through the modal's Save flow.
+ * Field names double as their labels; a value pre-fills the field on import.
+ */
+export const ProjectTemplateSettings: React.FC = () => {
+  const { templates, loading, error, save, remove } = useProjectTemplates();
+  const [draft, setDraft] = useState<Draft | null>(null);
+  const [busy, setBusy] = useState(false);
+  const [message, setMessage] = useState<string | null>(null);
+
+  const update = (patch: Partial<Draft>) => setDraft((d) => (d ? { ...d, ...patch } : d));
+  const updateRow = (i: number, patch: Partial<FieldRow>) =>
+    setDraft((d) =>
+      d ? { ...d, rows: d.rows.map((r, j) => (j === i ? { ...r, ...patch } : r)) } : d,
+    );
+
+  const submit = async () => {
+    if (!draft) return;
+    setBusy(true);
+    setMessage(null);
+    try {
+      const saved = await save(draft.id, toPayload(draft));
+      setDraft(null);
+      setMessage(`Saved "${saved.name}"`);
+    } catch (err) {
+      setMessage(`Could not save: ${err instanceof Error ? err.message : 'unknown error'}`);
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const destroy = async (t: ProjectTemplate) => {
+    try {
+      await remove(t.id);
+      if (draft?.id === t.id) setDraft(null);
+    } catch {
+      setMessage(`Could not delete ${t.name}`);
+    }
+  };
+
+  return (
+    <div className="space-y-3">
+      {loading && <p className="text-xs text-slate-500">Loading templates…</p>}
+      {error && <p className="text-xs text-red-400">{error}</p>}
+      {templates.length > 0 && (
+        <ul className="space-y-1">
+          {templates.map((t) => (
+            <li
+              key={t.id}
+              className="flex items-center justify-between rounded-lg bg-white/5 px-3 py-1.5 text-sm text-slate-300"
+            >
+              <button
+                type="button"
+                className="min-w-0 truncate text-left hover:text-white"
+                onClick={() => setDraft(toDraft(t))}
+              >
+                {t.name}{' '}
+                <span className="text-xs text-slate-500">
+                  · {t.fields.length} field{t.fields.length === 1 ? '' : 's'}
+                </span>
+              </button>
+              <Button
+                variant="ghost"
+                size="sm"
+                icon={<Trash2 size={12} />}
+                onClick={() => void destroy(t)}
+                aria-label={`Delete template "${t.name}"`}
+              >
+                Delete
+              </Button>
+            </li>
+          ))}
+        </ul>
+      )}
+
+      {draft ? (
+        <div className="space-y-2 rounded-lg border border-white/10 p-3">
+          <div className="flex gap-2">
+            <input
+              value={draft.name}
+              onChange={(e) => update({ name: e.target.value })}
+              placeholder="Template name, e.g. Client interviews"
+              aria-label="Template name"
+              className={`${INPUT} flex-1`}
+            />
+            <input
+              value={draft.description}
+              onChange={(e) => update({ description: e.target.value })}
+              placeholder="Description (optional)"
+              aria-label="Template description"
+              className={`${INPUT} flex-1`}
+            />
+          </div>
+          <p className="text-xs text-slate-500">Fields, with an optional pre-filled value</p>
+          {draft.rows.map((row, i) => (
+            <div key={i} className="flex gap-2">
+              <input
+                value={row.key}
+                onChange={(e) => updateRow(i, { key: e.target.value })}
+                placeholder="Field, e.g. Case number"
+                aria-label={`Field ${i + 1} name`}
+                className={`${INPUT} flex-1`}
+              />
+              <input
+                value={row.value}
+                onChange={(e) => updateRow(i, { value: e.target.value })}
+                placeholder="Default value"
+                aria-label={`Field ${i + 1} default`}
+                className={`${INPUT} flex-1`}
+              />
+              <Button
+                variant="ghost"
+                size="sm"
+                icon={<X size={12} />}
+                aria-label={`Remove field ${i + 1}`}
+                onClick={() => update({ rows: draft.rows.filter((_, j) => j !== i) })}
+              />
+            </div>
+          ))}
+          <Button
+            variant="ghost"
+            size="sm"
+            icon={<Plus size={12} />}
+            onClick={() => update({ rows: [...draft.rows, { key: '', value: '' }] })}
+          >
+            Add field
+          </Button>
+          <p className="text-xs text-slate-500">Transcription options (blank = leave as set)</p>
+          <div className="flex gap-2">
+            <input
+              value={draft.language}
+              onChange={(e) => update({ language: e.target.value })}
+              placeholder="Language code, e.g. en"
+              aria-label="Template language"
+              className={`${INPUT} w-40`}
+            />
+            <select
+              value={draft.diarization}
+              onChange={(e) => update({ diarization: e.target.value as Draft['diarization'] })}
+              aria-label="Template diarization"
+              className={INPUT}
+            >
+              <option value="">Diarization: as set</option>
+              <option value="on">Diarization: on</option>
+              <option value="off">Diarization: off</option>
+            </select>
+            <input
+              type="number"
+              min={1}
+              max={10}
+              value={draft.speakers}
+              onChange={(e) => update({ speakers: e.target.value })}
+              placeholder="Speakers"
+              aria-label="Template expected speakers"
+              className={`${INPUT} w-28`}
+            />
+          </div>
+          <div className="flex justify-end gap-2">
+            <Button variant="ghost" size="sm" onClick={() => setDraft(null)}>
+              Cancel
+            </Button>
+            <Button
+              variant="secondary"
+              size="sm"
+              icon={<Save size={12} />}
+              disabled={busy || !draft.name.trim()}
+              onClick={() => void submit()}
+            >
+              Save template
+            </Button>
+          </div>
+        </div>
+      ) : (
+        <Button
+          variant="secondary"
+          size="sm"
+          icon={<Plus size={12} />}
+          onClick={() => setDraft(EMPTY_DRAFT)}
+        >
+          New template
+        </Button>
+      )}
+      {message && <p className="text-xs text-slate-400">{message}</p>}
+      <p className="text-xs text-slate-500">
+        Pick a template in the Notebook&apos;s Import tab to pre-fill these fields and options for
+        each file you import. Fields can be edited per recording afterwards.
+      </p>
+    </div>
+  );
+};
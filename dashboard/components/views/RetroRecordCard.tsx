@@ -0,0 +1,179 @@
+import React, { useCallback, useEffect, useRef, useState } from 'react';
+import { History, Save } from 'lucide-react';
+import { toast } from 'sonner';
+import { GlassCard } from '../ui/GlassCard';
+import { Button } from '../ui/Button';
+import { CustomSelect } from '../ui/CustomSelect';
+import { formatClock } from '../../src/services/jobProgress';
+import { getConfig, setConfig } from '../../src/config/store';
+import { useImportQueueStore } from '../../src/stores/importQueueStore';
+import { RETRO_MINUTE_OPTIONS, RetroRecorder } from '../../src/services/retroRecorder';
+
+interface RetroRecordCardProps {
+  /** Buffer system audio instead of an input device (Audio Configuration). */
+  systemAudio: boolean;
+  /** Input device ID chosen in Audio Configuration. */
+  deviceId?: string;
+  /** Linux sink for system audio; unset on Windows/macOS (getDisplayMedia). */
+  monitorSinkName?: string;
+  isLinux: boolean;
+  /** Language code for the upload; undefined auto-detects. */
+  language?: string;
+}
+
+const minutesLabel = (minutes: number) => `${minutes} min`;
+const MINUTE_LABELS = RETRO_MINUTE_OPTIONS.map(minutesLabel);
+
+/**
+ * Retro-record: while switched on, the last few minutes of the selected
+ * input stay in memory. "Save" files them in the Audio Notebook as a normal
+ * recording, for the moment something worth keeping was said before anyone
+ * pressed record.
+ */
+export const RetroRecordCard: React.FC<RetroRecordCardProps> = ({
+  systemAudio,
+  deviceId,
+  monitorSinkName,
+  isLinux,
+  language,
+}) => {
+  const [enabled, setEnabled] = useState(false);
+  const [minutes, setMinutes] = useState(5);
+  const [armed, setArmed] = useState(false);
+  const [buffered, setBuffered] = useState(0);
+  const recorderRef = useRef<RetroRecorder | null>(null);
+
+  useEffect(() => {
+    void Promise.all([
+      getConfig<boolean>('session.retroRecord'),
+      getConfig<number>('session.retroMinutes'),
+    ]).then(([on, mins]) => {
+      if (typeof mins === 'number' && mins > 0) setMinutes(mins);
+      if (on === true) setEnabled(true);
+    });
+  }, []);
+
+  // (Re)arm whenever the input or the buffer length changes; switching off
+  // or leaving the view drops the buffer.
+  useEffect(() => {
+    if (!enabled) return;
+    const recorder = new RetroRecorder();
+    recorderRef.current = recorder;
+    let cancelled = false;
+    const loopback = systemAudio && !isLinux;
+    void (async () => {
+      try {
+        if (loopback) await window.electronAPI?.audio?.enableSystemAudioLoopback?.();
+        await recorder.start({
+          deviceId,
+          systemAudio,
+          monitorSinkName: systemAudio && isLinux ? monitorSinkName : undefined,
+          minutes,
+        });
+        if (!cancelled) setArmed(true);
+      } catch (err) {
+        if (cancelled || (err instanceof Error && err.name === 'AbortError')) return;
+        setEnabled(false);
+        toast.error('Could not start retro-record', {
+          description: err instanceof Error ? err.message : undefined,
+        });
+      }
+    })();
+    return () => {
+      cancelled = true;
+      recorder.stop();
+      if (recorderRef.current === recorder) recorderRef.current = null;
+      setArmed(false);
+      setBuffered(0);
+      if (loopback) window.electronAPI?.audio?.disableSystemAudioLoopback?.();
+    };
+  }, [enabled, minutes, deviceId, systemAudio, monitorSinkName, isLinux]);
+
+  useEffect(() => {
+    if (!armed) return;
+    const timer = window.setInterval(
+      () => setBuffered(recorderRef.current?.bufferedSeconds ?? 0),
+      1000,
+    );
+    return () => window.clearInterval(timer);
+  }, [armed]);
+
+  const toggle = useCallback(() => {
+    const next = !enabled;
+    setEnabled(next);
+    void setConfig('session.retroRecord', next).catch(() => {});
+  }, [enabled]);
+
+  const changeMinutes = useCallback((label: string) => {
+    const next = RETRO_MINUTE_OPTIONS[MINUTE_LABELS.indexOf(label)];
+    if (!next) return;
+    setMinutes(next);
+    void setConfig('session.retroMinutes', next).catch(() => {});
+  }, []);
+
+  const save = useCallback(() => {
+    const saved = recorderRef.current?.save();
+    setBuffered(0);
+    if (!saved) {
+      toast.info('Nothing buffered yet');
+      return;
+    }
+    const title = `Retro recording (${formatClock(saved.seconds)})`;
+    useImportQueueStore.getState().addPriorityFiles([saved.file], 'notebook-normal', {
+      language,
+      enable_word_timestamps: true,
+      title,
+      file_created_at: saved.startedAt.toISOString(),
+    });
+    toast.success('Retro recording queued for transcription', {
+      description: 'It will appear in the Audio Notebook when it is done.',
+    });
+  }, [language]);
+
+  return (
+    <GlassCard
+      title={
+        <span className="flex items-center gap-2">
+          <History size={16} className="text-accent-cyan" /> Retro-Record
+        </span>
+      }
+      className="flex-none"
+    >
+      <div className="space-y-3">
+        <div className="flex items-center justify-between gap-3">
+          <label className="flex cursor-pointer items-center gap-2 text-sm text-slate-300">
+            <input
+              type="checkbox"
+              checked={enabled}
+              onChange={toggle}
+              aria-label="Keep a rolling buffer"
+              className="accent-cyan-400"
+            />
+            Keep the last
+          </label>
+          <CustomSelect
+            value={minutesLabel(minutes)}
+            onChange={changeMinutes}
+            options={MINUTE_LABELS}
+            aria-label="Buffer length"
+            className="focus:ring-accent-cyan h-8 min-w-22 rounded-lg border border-white/10 bg-white/5 px-2 py-1 text-sm text-slate-300 outline-none focus:ring-1"
+          />
+        </div>
+        <div className="flex items-center justify-between gap-3">
+          <Button variant="secondary" icon={<Save size={14} />} disabled={!armed} onClick={save}>
+            Save what was just said
+          </Button>
+          {armed && (
+            <span className="font-mono text-xs text-slate-400">
+              {formatClock(buffered)} / {formatClock(minutes * 60)}
+            </span>
+          )}
+        </div>
+        <p className="text-xs text-slate-500">
+          {systemAudio ? 'Buffers system audio' : 'Buffers the selected input'} in memory only —
+          nothing is kept or uploaded until you save.
+        </p>
+      </div>
+    </GlassCard>
+  );
+};
@@ -0,0 +1,183 @@
+import React, { useEffect, useState } from 'react';
+import { X, Sparkles, Loader2, CheckCircle2, AlertCircle } from 'lucide-react';
+import {
+  apiSampleJobDeps,
+  runSampleTranscription,
+  type SampleJobResult,
+  type SampleJobStage,
+} from '../../src/services/sampleJob';
+
+interface SampleJobModalProps {
+  isOpen: boolean;
+  onDismiss: () => void;
+}
+
+type SampleJobState =
+  | { phase: 'offer' }
+  | { phase: 'running'; stage: SampleJobStage }
+  | { phase: 'done'; result: SampleJobResult }
+  | { phase: 'failed'; error: string };
+
+const STAGE_TEXT: Record<SampleJobStage, string> = {
+  upload: 'Uploading the sample…',
+  transcribe: 'Transcribing — the first job also loads the model…',
+  cleanup: 'Cleaning up…',
+};
+
+export const SampleJobModal: React.FC<SampleJobModalProps> = ({ isOpen, onDismiss }) => {
+  const [isRendered, setIsRendered] = useState(false);
+  const [isVisible, setIsVisible] = useState(false);
+  const [state, setState] = useState<SampleJobState>({ phase: 'offer' });
+
+  useEffect(() => {
+    let timer: ReturnType<typeof setTimeout>;
+    let rafId: number;
+
+    if (isOpen) {
+      setIsRendered(true);
+      setIsVisible(false);
+      setState({ phase: 'offer' });
+
+      rafId = requestAnimationFrame(() => {
+        rafId = requestAnimationFrame(() => {
+          setIsVisible(true);
+        });
+      });
+    } else {
+      setIsVisible(false);
+      timer = setTimeout(() => setIsRendered(false), 500);
+    }
+
+    return () => {
+      clearTimeout(timer);
+      cancelAnimationFrame(rafId);
+    };
+  }, [isOpen]);
+
+  const run = async () => {
+    setState({ phase: 'running', stage: 'upload' });
+    try {
+      const result = await runSampleTranscription(apiSampleJobDeps(), {
+        onStage: (stage) => setState({ phase: 'running', stage }),
+      });
+      setState({ phase: 'done', result });
+    } catch (err) {
+      setState({ phase: 'failed', error: err instanceof Error ? err.message : String(err) });
+    }
+  };
+
+  // Closing waits for the job, so the sample recording is always cleaned up.
+  const dismiss = () => {
+    if (state.phase !== 'running') onDismiss();
+  };
+
+  if (!isRendered) return null;
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-center justify-center p-4">
+      {/* Backdrop */}
+      <div
+        className={`absolute inset-0 bg-black/40 backdrop-blur-sm transition-opacity duration-500 ease-in-out ${isVisible ? 'opacity-100' : 'opacity-0'}`}
+        onClick={dismiss}
+      />
+
+      {/* Modal Content */}
+      <div
+        className={`blur-panel relative flex w-full max-w-md flex-col overflow-hidden rounded-3xl border border-white/20 bg-black/40 bg-linear-to-b from-white/5 to-black/20 shadow-2xl backdrop-blur-xl transition-all duration-500 ease-[cubic-bezier(0.32,0.72,0,1)] ${isVisible ? 'translate-y-0 opacity-100' : 'translate-y-[100vh] opacity-0'} `}
+      >
+        <div className="bg-accent-cyan/20 pointer-events-none absolute top-0 left-0 h-32 w-32 rounded-full blur-2xl" />
+        <div className="pointer-events-none absolute inset-0 bg-black/20" />
+
+        {/* Close button */}
+        {state.phase !== 'running' && (
+          <div className="absolute top-4 right-4 z-10">
+            <button
+              onClick={dismiss}
+              className="rounded-full border border-white/10 bg-black/10 p-2 text-white backdrop-blur-md transition-colors hover:bg-black/40"
+            >
+              <X size={16} />
+            </button>
+          </div>
+        )}
+
+        {/* Body */}
+        <div className="relative z-0 flex flex-col items-center gap-4 px-6 py-8">
+          <div className="border-accent-cyan/20 bg-accent-cyan/10 flex h-16 w-16 items-center justify-center rounded-full border">
+            {state.phase === 'running' ? (
+              <Loader2 size={32} className="text-accent-cyan animate-spin" />
+            ) : state.phase === 'done' ? (
+              <CheckCircle2 size={32} className="text-green-400" />
+            ) : state.phase === 'failed' ? (
+              <AlertCircle size={32} className="text-red-400" />
+            ) : (
+              <Sparkles size={32} className="text-accent-cyan" />
+            )}
+          </div>
+
+          {state.phase === 'offer' && (
+            <>
+              <h2 className="text-center text-lg font-semibold text-white">Your server is ready</h2>
+              <p className="text-center text-sm leading-relaxed text-slate-300">
+                Try it on a 30-second sample clip. It checks that everything works, and the sample
+                is removed again afterwards.
+              </p>
+            </>
+          )}
+          {state.phase === 'running' && (
+            <p className="text-center text-sm text-slate-300">{STAGE_TEXT[state.stage]}</p>
+          )}
+          {state.phase === 'done' && (
+            <>
+              <h2 className="text-center text-lg font-semibold text-white">It works!</h2>
+              <p className="max-h-40 w-full overflow-y-auto rounded-xl border border-white/10 bg-black/20 px-4 py-3 text-sm leading-relaxed text-slate-200">
+                {state.result.text || '(The sample produced no text.)'}
+              </p>
+              <p className="text-center text-xs text-slate-400">
+                {Math.round(state.result.audioSeconds)} s of audio transcribed in{' '}
+                {(state.result.elapsedMs / 1000).toFixed(1)} s
+              </p>
+            </>
+          )}
+          {state.phase === 'failed' && (
+            <>
+              <h2 className="text-center text-lg font-semibold text-white">
+                The sample did not go through
+              </h2>
+              <p className="text-center text-sm leading-relaxed text-slate-300">{state.error}</p>
+              <p className="text-center text-xs text-slate-400">
+                The self-test under Bug Report in the sidebar shows the step where it stops.
+              </p>
+            </>
+          )}
+
+          <div className="flex w-full flex-col gap-2 pt-2">
+            {state.phase === 'offer' && (
+              <button
+                onClick={() => void run()}
+                className="border-accent-cyan/30 bg-accent-cyan/20 hover:bg-accent-cyan/30 flex items-center justify-center gap-2 rounded-xl border px-4 py-2.5 text-sm font-medium text-white transition-colors"
+              >
+                Transcribe the Sample
+              </button>
+            )}
+            {state.phase === 'failed' && (
+              <button
+                onClick={() => void run()}
+                className="rounded-xl border border-white/10 bg-white/5 px-4 py-2.5 text-sm text-white transition-colors hover:bg-white/10"
+              >
+                Try Again
+              </button>
+            )}
+            {state.phase !== 'running' && (
+              <button
+                onClick={dismiss}
+                className="rounded-xl border border-white/10 bg-white/5 px-4 py-2.5 text-sm text-slate-400 transition-colors hover:bg-white/10 hover:text-white"
+              >
+                {state.phase === 'offer' ? 'Skip' : 'Get Started'}
+              </button>
+            )}
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+};
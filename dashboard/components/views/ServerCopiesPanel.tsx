@@ -0,0 +1,128 @@
+import React, { useCallback, useEffect, useState } from 'react';
+import { Download, Loader2, Server, Trash2 } from 'lucide-react';
+import { toast } from 'sonner';
+import { useConfirm } from '../../src/hooks/useConfirm';
+import {
+  deleteServerCopy,
+  downloadServerCopy,
+  listServerCopies,
+  type ServerCopy,
+} from '../../src/services/serverCopies';
+import { GlassCard } from '../ui/GlassCard';
+
+const MB = 1024 * 1024;
+
+function formatSize(bytes: number): string {
+  if (bytes < MB) return `${Math.round(bytes / 1024)} KB`;
+  if (bytes < 1024 ** 3) return `${(bytes / MB).toFixed(1)} MB`;
+  return `${(bytes / 1024 ** 3).toFixed(2)} GB`;
+}
+
+function errorText(err: unknown): string {
+  const message = err instanceof Error ? err.message : String(err);
+  // ipcRenderer.invoke wraps main-process errors in a generic prefix.
+  return message.replace(/^Error invoking remote method '[^']+': (\w*Error: )?/, '');
+}
+
+interface ServerCopiesPanelProps {
+  /** Changes when an import finishes, so a newly kept copy shows up. */
+  refreshKey?: number;
+}
+
+/**
+ * Uploads the server kept for session imports ("Keep server copy"). Each can
+ * be downloaded again — verified against the uploaded file's SHA-256 before
+ * it is saved — or deleted from the server. Hidden while there are none.
+ */
+export const ServerCopiesPanel: React.FC<ServerCopiesPanelProps> = ({ refreshKey }) => {
+  const [copies, setCopies] = useState<ServerCopy[]>([]);
+  const [busy, setBusy] = useState<string | null>(null);
+  const { confirm, dialog } = useConfirm();
+
+  const refresh = useCallback(() => {
+    listServerCopies()
+      .then(setCopies)
+      .catch(() => setCopies([]));
+  }, []);
+
+  useEffect(() => refresh(), [refresh, refreshKey]);
+
+  const download = async (copy: ServerCopy) => {
+    setBusy(copy.job_id);
+    try {
+      const saved = await downloadServerCopy(copy);
+      if (saved) toast.success(`Saved ${copy.filename} (checksum verified)`);
+    } catch (err) {
+      toast.error(`Could not download ${copy.filename}: ${errorText(err)}`);
+    } finally {
+      setBusy(null);
+    }
+  };
+
+  const remove = async (copy: ServerCopy) => {
+    if (
+      !(await confirm(`Delete the server copy of ${copy.filename}?`, {
+        title: 'Delete server copy',
+        confirmLabel: 'Delete',
+        danger: true,
+      }))
+    ) {
+      return;
+    }
+    setBusy(copy.job_id);
+    try {
+      setCopies(await deleteServerCopy(copy.job_id));
+    } catch (err) {
+      toast.error(`Could not delete ${copy.filename}: ${errorText(err)}`);
+    } finally {
+      setBusy(null);
+    }
+  };
+
+  if (copies.length === 0) return null;
+  return (
+    <GlassCard title="Server Copies">
+      <div className="space-y-2 text-sm">
+        {copies.map((copy) => (
+          <div
+            key={copy.job_id}
+            className="flex items-center gap-3 rounded-lg bg-white/5 px-3 py-2"
+          >
+            <Server size={14} className="shrink-0 text-slate-500" />
+            <span className="flex-1 truncate text-white" title={copy.filename}>
+              {copy.filename}
+              <span className="ml-2 text-xs text-slate-400">
+                {formatSize(copy.size)} · {new Date(copy.keptAt).toLocaleDateString()}
+              </span>
+            </span>
+            {busy === copy.job_id ? (
+              <Loader2 size={14} className="animate-spin text-slate-400" />
+            ) : (
+              <>
+                <button
+                  onClick={() => void download(copy)}
+                  disabled={busy !== null}
+                  className="hover:text-accent-cyan text-slate-400 transition-colors disabled:opacity-40"
+                  aria-label={`Download ${copy.filename}`}
+                  title="Download"
+                >
+                  <Download size={14} />
+                </button>
+                <button
+                  onClick={() => void remove(copy)}
+                  disabled={busy !== null}
+                  className="text-slate-400 transition-colors hover:text-red-400 disabled:opacity-40"
+                  aria-label={`Delete the server copy of ${copy.filename}`}
+                  title="Delete from server"
+                >
+                  <Trash2 size={14} />
+                </button>
+              </>
+            )}
+          </div>
+        ))}
+      </div>
+      {dialog}
+    </GlassCard>
+  );
+};
@@ -0,0 +1,117 @@
+import React, { useCallback, useEffect, useState } from 'react';
+import { ChevronDown, ChevronUp, Users } from 'lucide-react';
+import { toast } from 'sonner';
+import { apiClient } from '../../src/api/client';
+import type { ServerQueue } from '../../src/api/types';
+import { describeJobProgress } from '../../src/services/jobProgress';
+import { GlassCard } from '../ui/GlassCard';
+
+const POLL_INTERVAL_MS = 5_000;
+
+const clockTime = (epochSeconds: number) =>
+  new Date(epochSeconds * 1000).toLocaleTimeString([], { hour: '2-digit', minute: '2-digit' });
+
+/**
+ * Who is using a shared server's transcription slot and who is waiting for
+ * it, with estimated start times. Entries from this client can be moved up
+ * or down among themselves; other clients' entries are shown without their
+ * file names. Hidden while nobody else is running or waiting.
+ */
+export const ServerQueuePanel: React.FC = () => {
+  const [queue, setQueue] = useState<ServerQueue | null>(null);
+
+  const refresh = useCallback(() => {
+    apiClient
+      .getServerQueue()
+      .then(setQueue)
+      .catch(() => setQueue(null)); // older servers have no queue
+  }, []);
+
+  useEffect(() => {
+    refresh();
+    const timer = setInterval(refresh, POLL_INTERVAL_MS);
+    return () => clearInterval(timer);
+  }, [refresh]);
+
+  if (!queue) return null;
+  const { active, entries } = queue;
+  if (entries.length === 0 && (!active || active.mine)) return null;
+
+  const ownIds = entries.filter((e) => e.mine && e.id).map((e) => e.id!);
+  const move = async (entryId: string, by: -1 | 1) => {
+    const order = [...ownIds];
+    const from = order.indexOf(entryId);
+    const to = from + by;
+    if (from < 0 || to < 0 || to >= order.length) return;
+    [order[from], order[to]] = [order[to], order[from]];
+    try {
+      setQueue(await apiClient.reorderServerQueue(order));
+    } catch (err) {
+      toast.error(`Could not reorder: ${err instanceof Error ? err.message : String(err)}`);
+      refresh();
+    }
+  };
+
+  const now = Date.now() / 1000;
+  return (
+    <GlassCard title="Server Queue">
+      <div className="space-y-2 text-sm">
+        {active && (
+          <div className="flex items-center gap-3 rounded-lg bg-white/5 px-3 py-2">
+            <Users size={14} className="shrink-0 text-slate-500" />
+            <span className="flex-1 truncate text-white">
+              {active.mine ? 'You' : active.user}
+              <span className="ml-2 text-xs text-slate-400">
+                {describeJobProgress(active.progress, active.started_at, now)}
+              </span>
+            </span>
+            {active.estimated_end_at !== null && (
+              <span className="text-xs whitespace-nowrap text-slate-400">
+                done ~{clockTime(active.estimated_end_at)}
+              </span>
+            )}
+          </div>
+        )}
+        {entries.map((entry) => {
+          const ownIndex = entry.id ? ownIds.indexOf(entry.id) : -1;
+          return (
+            <div
+              key={entry.id ?? `${entry.user}-${entry.position}`}
+              className="flex items-center gap-3 rounded-lg bg-white/5 px-3 py-2"
+            >
+              <span className="w-6 shrink-0 text-xs text-slate-500">#{entry.position + 1}</span>
+              <span className={`flex-1 truncate ${entry.mine ? 'text-white' : 'text-slate-400'}`}>
+                {entry.mine ? (entry.label ?? 'Your job') : entry.user}
+              </span>
+              <span className="text-xs whitespace-nowrap text-slate-400">
+                {entry.estimated_start_at !== null
+                  ? `starts ~${clockTime(entry.estimated_start_at)}`
+                  : 'start unknown'}
+              </span>
+              {ownIndex >= 0 && ownIds.length > 1 && (
+                <>
+                  <button
+                    onClick={() => void move(entry.id!, -1)}
+                    disabled={ownIndex === 0}
+                    className="hover:text-accent-cyan p-1 text-slate-400 transition-colors disabled:opacity-30"
+                    title="Run earlier"
+                  >
+                    <ChevronUp size={16} />
+                  </button>
+                  <button
+                    onClick={() => void move(entry.id!, 1)}
+                    disabled={ownIndex === ownIds.length - 1}
+                    className="hover:text-accent-cyan p-1 text-slate-400 transition-colors disabled:opacity-30"
+                    title="Run later"
+                  >
+                    <ChevronDown size={16} />
+                  </button>
+                </>
+              )}
+            </div>
+          );
+        })}
+      </div>
+    </GlassCard>
+  );
+};
@@ -0,0 +1,110 @@
+import React, { useCallback, useEffect, useState } from 'react';
+import { UserX } from 'lucide-react';
+import { toast } from 'sonner';
+import { apiClient } from '../../src/api/client';
+import type { ServerUser, UsageReport } from '../../src/api/types';
+
+const USAGE_DAYS = 30;
+
+const hours = (seconds: number) => `${(seconds / 3600).toFixed(1)} h`;
+
+interface ServerUsersPanelProps {
+  /** The owning view's useConfirm() — its dialog is already rendered there. */
+  confirm: (
+    message: string,
+    options?: { title?: string; confirmLabel?: string; danger?: boolean },
+  ) => Promise<boolean>;
+  /** Called after a user's tokens were revoked, so the token list can refresh. */
+  onChanged: () => void | Promise<void>;
+}
+
+/**
+ * Users of a shared server — the client names tokens were issued to — with
+ * their last-30-days usage, inside Settings → Manage Tokens. "Remove"
+ * revokes every token of the user; the server refuses to remove the last
+ * admin. Recordings are counted by the self-declared user name, which need
+ * not match a token's client name, so they are listed separately.
+ */
+export const ServerUsersPanel: React.FC<ServerUsersPanelProps> = ({ confirm, onChanged }) => {
+  const [users, setUsers] = useState<ServerUser[]>([]);
+  const [usage, setUsage] = useState<UsageReport | null>(null);
+
+  const refresh = useCallback(() => {
+    apiClient
+      .listServerUsers()
+      .then((res) => setUsers(res.users))
+      .catch(() => setUsers([])); // older servers have no user list
+    apiClient
+      .getUsage(USAGE_DAYS)
+      .then(setUsage)
+      .catch(() => setUsage(null));
+  }, []);
+
+  useEffect(refresh, [refresh]);
+
+  if (users.length === 0) return null;
+
+  const jobsByClient = new Map(usage?.clients.map((row) => [row.client_name, row] as const));
+
+  const remove = async (user: ServerUser) => {
+    const ok = await confirm(
+      `Remove "${user.name}"? All ${user.active_tokens} of their active tokens are revoked and their apps lose access.`,
+      { title: 'Remove user', danger: true, confirmLabel: 'Remove' },
+    );
+    if (!ok) return;
+    try {
+      const res = await apiClient.removeServerUser(user.name);
+      toast.success(`Removed ${user.name} (${res.revoked} token(s) revoked)`);
+      refresh();
+      await onChanged();
+    } catch (err) {
+      toast.error(`Could not remove user: ${err instanceof Error ? err.message : String(err)}`);
+    }
+  };
+
+  return (
+    <div className="space-y-2 border-t border-white/10 pt-3">
+      <p className="text-[10px] font-semibold tracking-wider text-slate-500 uppercase">
+        Users · last {USAGE_DAYS} days
+      </p>
+      {users.map((user) => {
+        const jobs = jobsByClient.get(user.name);
+        return (
+          <div
+            key={user.name}
+            className="flex items-center gap-2 rounded bg-black/20 px-2 py-1.5 text-xs"
+          >
+            <span
+              className={`flex-1 truncate ${user.active_tokens > 0 ? 'text-white' : 'text-slate-500'}`}
+            >
+              {user.name}
+            </span>
+            {user.is_admin && (
+              <span className="text-[9px] font-bold text-amber-400 uppercase">Admin</span>
+            )}
+            <span className="text-[10px] whitespace-nowrap text-slate-500">
+              {jobs ? `${jobs.jobs} jobs · ${hours(jobs.audio_seconds)}` : 'no jobs'}
+            </span>
+            {user.active_tokens > 0 && (
+              <button
+                onClick={() => void remove(user)}
+                className="p-0.5 text-slate-500 transition-colors hover:text-red-400"
+                title="Remove user"
+              >
+                <UserX size={12} />
+              </button>
+            )}
+          </div>
+        );
+      })}
+      {usage && usage.owners.length > 0 && (
+        <p className="text-[10px] text-slate-500">
+          Recordings:{' '}
+          {usage.owners
+            .map((row) => `${row.owner} ${row.recordings} (${hours(row.recording_seconds)})`)
+            .join(' · ')}
+        </p>
+      )}
+    </div>
+  );
+};
@@ -35,6 +35,7 @@ import { AmdIcon } from '../ui/icons/AmdIcon';
 import { IntelIcon } from '../ui/icons/IntelIcon';
 import { AppleIcon } from '../ui/icons/AppleIcon';
 import { GpuHealthCard } from './GpuHealthCard';
+import { NativeBackendCard } from './NativeBackendCard';
 import { GpuDiagnosticModal, type GpuDiagnosticResultProp } from './GpuDiagnosticModal';
 import { InstanceSettingsSelectors } from './server/InstanceSettingsSelectors';
 import { RemoteConnectionCard } from './server/RemoteConnectionCard';
@@ -43,6 +44,7 @@ import { StartupActivityInline } from './server/StartupActivityInline';
 import { useNotificationsStore } from '../../src/stores/notificationsStore';
 import { SERVER_START_ID } from '../../src/utils/startupEventMapping';
 import { useAdminStatus } from '../../src/hooks/useAdminStatus';
+import { usePermissions } from '../../src/hooks/usePermissions';
 import { useServerStatus } from '../../src/hooks/useServerStatus';
 import { useDockerContext } from '../../src/hooks/DockerContext';
 import { useModelCache } from '../../src/hooks/useModelCache';
@@ -168,6 +170,8 @@ let cachedGpuInfo:
       gpu: boolean;
       toolkit: boolean;
       vulkan: boolean;
+      rocm?: boolean;
+      xpu?: boolean;
       wslSupport?: { available: boolean; gpuPassthroughDetected: boolean; reason?: string };
     }
   | null
@@ -236,6 +240,7 @@ function mapDiarizationModelToSelection(modelName: string): string {
 
 export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFlowPending }) => {
   const { status: adminStatus, refresh: refreshAdminStatus } = useAdminStatus();
+  const canManageModels = usePermissions().can('can_manage_models');
   const docker = useDockerContext();
 
   // Model selection state
@@ -351,8 +356,11 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
             // Falling back to 'cpu' is the safe universal default; the
             // auto-detect block below will pick a better profile if eligible
             // (only runs once per machine, gated by `gpuAutoDetectDone`).
+            // The Linux-only rocm/xpu profiles get the same treatment off Linux.
+            const platform = (window as any).electronAPI?.app?.getPlatform?.();
             const normalized: RuntimeProfile =
-              val === 'vulkan-wsl2' && (window as any).electronAPI?.app?.getPlatform?.() !== 'win32'
+              (val === 'vulkan-wsl2' && platform !== 'win32') ||
+              ((val === 'rocm' || val === 'xpu') && platform !== undefined && platform !== 'linux')
                 ? 'cpu'
                 : val;
             setRuntimeProfile(normalized);
@@ -1239,11 +1247,13 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
       ? 'vulkan-wsl2'
       : runtimeProfile === 'vulkan'
         ? 'vulkan-linux'
-        : useLegacyGpu
-          ? 'cuda-legacy'
-          : 'cuda';
+        : runtimeProfile === 'rocm' || runtimeProfile === 'xpu'
+          ? runtimeProfile
+          : useLegacyGpu
+            ? 'cuda-legacy'
+            : 'cuda';
 
-  // Per-version availability from the four GHCR tag lists. Fail open: the
+  // Per-version availability from the per-variant GHCR tag lists. Fail open: the
   // default repo has been published since v0.4.4, so an empty `cuda` list
   // means the probe itself failed (offline, GHCR down) — treat availability
   // as unknown instead of disabling every tile.
@@ -1293,6 +1303,8 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
     gpu: boolean;
     toolkit: boolean;
     vulkan: boolean;
+    rocm?: boolean;
+    xpu?: boolean;
     wslSupport?: { available: boolean; gpuPassthroughDetected: boolean; reason?: string };
   } | null>(cachedGpuInfo ?? null);
 
@@ -1422,13 +1434,17 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
             gpu: boolean;
             toolkit: boolean;
             vulkan: boolean;
+            rocm?: boolean;
+            xpu?: boolean;
             wslSupport?: { available: boolean; gpuPassthroughDetected: boolean; reason?: string };
           }) => {
             cachedGpuInfo = info;
             setGpuInfo(info);
             // Auto-set runtime profile based on hardware detection.
             // Runs exactly once: on fresh install or upgrade from a version without the flag.
-            // Priority: Metal (Apple Silicon) > NVIDIA GPU > Vulkan (AMD/Intel) > CPU
+            // Priority: Metal (Apple Silicon) > NVIDIA GPU > ROCm (AMD) > Vulkan (AMD/Intel) > CPU.
+            // Intel XPU is offered but never auto-picked: older Intel iGPUs
+            // report the same vendor yet are not supported by the oneAPI wheels.
             api.config
               ?.get('server.gpuAutoDetectDone')
               .then((done: unknown) => {
@@ -1439,6 +1455,8 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
                   detected = 'metal';
                 } else if (info.gpu && info.toolkit) {
                   detected = 'gpu';
+                } else if (info.rocm) {
+                  detected = 'rocm';
                 } else if (info.vulkan) {
                   detected = 'vulkan';
                 }
@@ -1495,6 +1513,8 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
           gpu: boolean;
           toolkit: boolean;
           vulkan: boolean;
+          rocm?: boolean;
+          xpu?: boolean;
           wslSupport?: { available: boolean; gpuPassthroughDetected: boolean; reason?: string };
         }) => {
           cachedGpuInfo = info;
@@ -1804,6 +1824,11 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
             </div>
           )}
 
+          {/* Docker-free fallback: managed Python env (electron/backendBootstrap.ts). */}
+          {needsDocker && !docker.available && hostPlatform !== 'darwin' && (
+            <NativeBackendCard useGpu={runtimeProfile === 'gpu'} />
+          )}
+
           {/*
             GPU Health card (NVIDIA Linux only). Sits adjacent to the setup
             checklist so all hardware/runtime status is colocated at the top
@@ -1937,6 +1962,48 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
                     }
                     onSelect={() => handleRuntimeProfileChange('vulkan')}
                   />
+                  <SelectorTile
+                    icon={<AmdIcon size={30} />}
+                    label="ROCm"
+                    sublabel="AMD · Linux"
+                    accent="red"
+                    selected={runtimeProfile === 'rocm'}
+                    disabled={
+                      isRunning ||
+                      hostPlatform === 'win32' ||
+                      hostPlatform === 'darwin' ||
+                      (hostPlatform === 'linux' && !gpuInfo?.rocm)
+                    }
+                    badge={
+                      hostPlatform === 'win32' || hostPlatform === 'darwin'
+                        ? 'Linux only'
+                        : hostPlatform === 'linux' && !gpuInfo?.rocm
+                          ? 'Requires AMD GPU'
+                          : undefined
+                    }
+                    onSelect={() => handleRuntimeProfileChange('rocm')}
+                  />
+                  <SelectorTile
+                    icon={<IntelIcon size={30} />}
+                    label="Intel XPU"
+                    sublabel="oneAPI · Linux"
+                    accent="red"
+                    selected={runtimeProfile === 'xpu'}
+                    disabled={
+                      isRunning ||
+                      hostPlatform === 'win32' ||
+                      hostPlatform === 'darwin' ||
+                      (hostPlatform === 'linux' && !gpuInfo?.xpu)
+                    }
+                    badge={
+                      hostPlatform === 'win32' || hostPlatform === 'darwin'
+                        ? 'Linux only'
+                        : hostPlatform === 'linux' && !gpuInfo?.xpu
+                          ? 'Requires Intel GPU'
+                          : undefined
+                    }
+                    onSelect={() => handleRuntimeProfileChange('xpu')}
+                  />
                   <SelectorTile
                     icon={<AppleIcon size={16} />}
                     label="Metal"
@@ -1977,6 +2044,12 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
                     Experimental: AMD/Intel GPU via WSL2 + Mesa dzn — see README §2.5.2
                   </p>
                 )}
+                {(runtimeProfile === 'rocm' || runtimeProfile === 'xpu') && !isRunning && (
+                  <p className="mt-2 text-xs text-slate-500 italic">
+                    {runtimeProfile === 'rocm' ? 'AMD' : 'Intel'} GPU via PyTorch — pulls the{' '}
+                    {runtimeProfile === 'rocm' ? 'ROCm' : 'XPU'} image variant
+                  </p>
+                )}
                 {runtimeProfile === 'cpu' && !isRunning && (
                   <p className="mt-2 text-xs text-slate-500 italic">
                     Slower transcription, no NVIDIA GPU required
@@ -2192,8 +2265,8 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
                   Image variant selector — same visual language as the
                   Instance Settings matrix (SelectorGroup + SelectorTile).
                   Only cuda ↔ cuda-legacy is user-switchable here (this
-                  replaces the retired Legacy GPU toggle); the vulkan
-                  variants are implied by the Runtime selector and render
+                  replaces the retired Legacy GPU toggle); the vulkan, ROCm
+                  and XPU variants are implied by the Runtime selector and render
                   locked (active) or disabled (inactive) so the whole
                   variant matrix stays readable at a glance. Availability
                   is per selected version tag: a variant whose GHCR package
@@ -2204,7 +2277,7 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
                     icon={<Layers size={16} className="text-accent-cyan" />}
                     title="Image Variant"
                     hint="Which server image build this version uses"
-                    columnsClass="grid-cols-2 lg:grid-cols-4"
+                    columnsClass="grid-cols-2 lg:grid-cols-3"
                   >
                     {(
                       [
@@ -2254,6 +2327,24 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
                           runtimeOk: runtimeProfile === 'vulkan',
                           runtimeBadge: 'Requires Vulkan Linux runtime',
                         },
+                        {
+                          variant: 'rocm',
+                          label: 'ROCm',
+                          sublabel: 'AMD · Linux',
+                          accent: 'red',
+                          icon: <AmdIcon size={30} />,
+                          runtimeOk: runtimeProfile === 'rocm',
+                          runtimeBadge: 'Requires ROCm runtime',
+                        },
+                        {
+                          variant: 'xpu',
+                          label: 'Intel XPU',
+                          sublabel: 'oneAPI · Linux',
+                          accent: 'red',
+                          icon: <IntelIcon size={30} />,
+                          runtimeOk: runtimeProfile === 'xpu',
+                          runtimeBadge: 'Requires Intel XPU runtime',
+                        },
                       ] satisfies Array<{
                         variant: ImageVariant;
                         label: string;
@@ -2266,11 +2357,11 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
                     ).map((t) => {
                       const isActiveTile = activeImageVariant === t.variant;
                       const published = isVariantPublished(t.variant);
-                      const vulkanVariant =
-                        t.variant === 'vulkan-wsl2' || t.variant === 'vulkan-linux';
+                      // Every non-CUDA variant is implied by the Runtime selector.
+                      const impliedVariant = t.variant !== 'cuda' && t.variant !== 'cuda-legacy';
                       const disabled =
                         !isActiveTile &&
-                        (vulkanVariant ||
+                        (impliedVariant ||
                           !t.runtimeOk ||
                           !published ||
                           containerBlocksVariantSwitch);
@@ -2280,7 +2371,7 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
                       const badge = disabled
                         ? !published
                           ? 'Not published'
-                          : vulkanVariant || !t.runtimeOk
+                          : impliedVariant || !t.runtimeOk
                             ? t.runtimeBadge
                             : 'Remove container to switch'
                         : undefined;
@@ -2293,7 +2384,7 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
                           accent={t.accent}
                           selected={isActiveTile}
                           disabled={disabled}
-                          locked={isActiveTile && vulkanVariant}
+                          locked={isActiveTile && impliedVariant}
                           badge={badge}
                           onSelect={() => handleImageVariantSelect(t.variant)}
                         />
@@ -2472,22 +2563,24 @@ export const ServerView: React.FC<ServerViewProps> = ({ onStartServer, startupFl
                             {modelsLoaded ? 'Models Loaded' : 'Models Not Loaded'}
                           </span>
                         )}
-                        <Button
-                          variant={modelsLoaded === false ? 'secondary' : 'danger'}
-                          className="h-9 px-4 whitespace-nowrap"
-                          onClick={modelsLoaded === false ? handleLoadModels : handleUnloadModels}
-                          disabled={modelsLoading || !isRunning}
-                        >
-                          {modelsLoading ? (
-                            <>
-                              <Loader2 size={14} className="mr-2 animate-spin" /> Loading...
-                            </>
-                          ) : modelsLoaded === false ? (
-                            'Load Models'
-                          ) : (
-                            'Unload Models'
-                          )}
-                        </Button>
+                        {canManageModels && (
+                          <Button
+                            variant={modelsLoaded === false ? 'secondary' : 'danger'}
+                            className="h-9 px-4 whitespace-nowrap"
+                            onClick={modelsLoaded === false ? handleLoadModels : handleUnloadModels}
+                            disabled={modelsLoading || !isRunning}
+                          >
+                            {modelsLoading ? (
+                              <>
+                                <Loader2 size={14} className="mr-2 animate-spin" /> Loading...
+                              </>
+                            ) : modelsLoaded === false ? (
+                              'Load Models'
+                            ) : (
+                              'Unload Models'
+                            )}
+                          </Button>
+                        )}
                         {!isMetal && (
                           <Button
                             variant="danger"
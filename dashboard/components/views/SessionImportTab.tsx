@@ -18,6 +18,9 @@ import {
   WifiOff,
 } from 'lucide-react';
 import { GlassCard } from '../ui/GlassCard';
+import { ServerQueuePanel } from './ServerQueuePanel';
+import { ServerCopiesPanel } from './ServerCopiesPanel';
+import { LocalEditPanel } from './LocalEditPanel';
 import { Button } from '../ui/Button';
 import { AppleSwitch } from '../ui/AppleSwitch';
 import { CustomSelect } from '../ui/CustomSelect';
@@ -30,10 +33,17 @@ import {
   selectErrorCount,
   selectIsProcessing,
 } from '../../src/stores/importQueueStore';
-import type { UnifiedImportJob } from '../../src/stores/importQueueStore';
+import type { LocalEdit, UnifiedImportJob } from '../../src/stores/importQueueStore';
+import type { TranscriptionUploadOptions } from '../../src/api/types';
 import type { SessionOutputFormat } from '../../src/services/transcriptionFormatters';
 import { useAdminStatus } from '../../src/hooks/useAdminStatus';
 import { useJobProgress } from '../../src/hooks/useJobProgress';
+import {
+  describeDowngrade,
+  describeServerQueueWait,
+  formatClock,
+} from '../../src/services/jobProgress';
+import { describeUploadProgress } from '../../src/services/uploadPipeline';
 import { useLanguages } from '../../src/hooks/useLanguages';
 import { apiClient } from '../../src/api/client';
 import { supportsExplicitWordTimestampToggle as supportsExplicitWordTimestampToggleForModel } from '../../src/utils/transcriptionBackend';
@@ -67,6 +77,7 @@ export const SessionImportTab: React.FC = () => {
   const completedCount = useImportQueueStore(selectCompletedCount);
   const errorCount = useImportQueueStore(selectErrorCount);
   const addFiles = useImportQueueStore((s) => s.addFiles);
+  const transcribeLocalEdit = useImportQueueStore((s) => s.transcribeLocalEdit);
   const removeJob = useImportQueueStore((s) => s.removeJob);
   const retryJob = useImportQueueStore((s) => s.retryJob);
   const clearFinished = useImportQueueStore((s) => s.clearFinished);
@@ -99,6 +110,7 @@ export const SessionImportTab: React.FC = () => {
   const [parallelDiarization, setParallelDiarization] = useState<boolean>(false);
   const [parallelDefault, setParallelDefault] = useState<boolean>(false);
   const [multitrack, setMultitrack] = useState(false);
+  const [keepServerCopy, setKeepServerCopy] = useState(false);
   const [isDragOver, setIsDragOver] = useState(false);
   const [isDragOverWatch, setIsDragOverWatch] = useState(false);
   const [logExpanded, setLogExpanded] = useState(false);
@@ -174,6 +186,7 @@ export const SessionImportTab: React.FC = () => {
       const electronAPI = (window as any).electronAPI;
 
       getConfig<boolean>('output.hideTimestamps').then((v) => setHideTimestamps(v ?? false));
+      getConfig<boolean>('sessionImport.keepServerCopy').then((v) => setKeepServerCopy(v ?? false));
 
       // GH-212: explicit output format. Default preserves pre-selector
       // behavior: txt when the global hideTimestamps was on, else subtitles.
@@ -356,52 +369,73 @@ export const SessionImportTab: React.FC = () => {
     [languages],
   );
 
+  /** Upload options from the toggles above; null (after a toast) when no language resolves. */
+  const buildUploadOptions = useCallback((): TranscriptionUploadOptions | null => {
+    // gh-102 followup: mirror SessionView.handleStartRecording (SessionView.tsx:705–715).
+    // The Canary backend (canary_backend.py:79) raises ValueError when
+    // `language` is missing. Without this guard, dropping a file on Canary
+    // with an unresolvable picker (Auto Detect, languages still loading,
+    // stale display name) round-trips to the backend fail-loud path and
+    // surfaces the cryptic toast the issue 102 reporter screenshotted.
+    // Wording matches the live-recording guard verbatim so future copy
+    // changes propagate via grep.
+    const resolvedLang = resolveLanguage(mainLanguage);
+    if (resolvedLang === undefined && !supportsAutoDetect(activeModel)) {
+      toast.error('Source language required', {
+        description: languagesLoading
+          ? 'Loading languages — please try again in a moment.'
+          : mainLanguage
+            ? `"${mainLanguage}" is not a valid source language for the active model. Pick a language from the Source Language dropdown.`
+            : 'No source language is selected. Pick a language from the Source Language dropdown.',
+      });
+      return null;
+    }
+
+    // Translation parity: mirror SessionView.tsx:718–719 so the import
+    // surface produces the same envelope handleStartRecording produces for
+    // live recording. Canary bidi (English source + non-Off target) drives
+    // translate=true; the regular Whisper translate-to-English toggle is
+    // only honored when the active model supports translation.
+    const mainTranslateActive = isCanaryMainBidi
+      ? mainBidiTarget !== 'Off'
+      : mainTranslate && canTranslate;
+    const mainTranslateTarget = isCanaryMainBidi
+      ? (resolveLanguage(mainBidiTarget) ?? 'en')
+      : 'en';
+
+    return {
+      language: resolvedLang,
+      translation_enabled: mainTranslateActive ? true : undefined,
+      translation_target_language: mainTranslateActive ? mainTranslateTarget : undefined,
+      enable_diarization: multitrack ? false : effectiveDiarization,
+      enable_word_timestamps: supportsExplicitWordTimestampToggle ? wordTimestamps : true,
+      parallel_diarization: effectiveDiarization && !multitrack ? parallelDiarization : undefined,
+      multitrack: multitrack || undefined,
+    };
+  }, [
+    effectiveDiarization,
+    multitrack,
+    parallelDiarization,
+    supportsExplicitWordTimestampToggle,
+    wordTimestamps,
+    activeModel,
+    mainLanguage,
+    mainTranslate,
+    mainBidiTarget,
+    isCanaryMainBidi,
+    canTranslate,
+    languagesLoading,
+    resolveLanguage,
+  ]);
+
   const handleFiles = useCallback(
     (files: FileList | null) => {
       if (!files || files.length === 0) return;
-
-      // gh-102 followup: mirror SessionView.handleStartRecording (SessionView.tsx:705–715).
-      // The Canary backend (canary_backend.py:79) raises ValueError when
-      // `language` is missing. Without this guard, dropping a file on Canary
-      // with an unresolvable picker (Auto Detect, languages still loading,
-      // stale display name) round-trips to the backend fail-loud path and
-      // surfaces the cryptic toast the issue 102 reporter screenshotted.
-      // Wording matches the live-recording guard verbatim so future copy
-      // changes propagate via grep.
-      const resolvedLang = resolveLanguage(mainLanguage);
-      if (resolvedLang === undefined && !supportsAutoDetect(activeModel)) {
-        toast.error('Source language required', {
-          description: languagesLoading
-            ? 'Loading languages — please try again in a moment.'
-            : mainLanguage
-              ? `"${mainLanguage}" is not a valid source language for the active model. Pick a language from the Source Language dropdown.`
-              : 'No source language is selected. Pick a language from the Source Language dropdown.',
-        });
-        return;
-      }
-
-      // Translation parity: mirror SessionView.tsx:718–719 so the import
-      // surface produces the same envelope handleStartRecording produces for
-      // live recording. Canary bidi (English source + non-Off target) drives
-      // translate=true; the regular Whisper translate-to-English toggle is
-      // only honored when the active model supports translation.
-      const mainTranslateActive = isCanaryMainBidi
-        ? mainBidiTarget !== 'Off'
-        : mainTranslate && canTranslate;
-      const mainTranslateTarget = isCanaryMainBidi
-        ? (resolveLanguage(mainBidiTarget) ?? 'en')
-        : 'en';
+      const options = buildUploadOptions();
+      if (!options) return;
 
       const fileArray = Array.from(files);
-      addFiles(fileArray, 'session-normal', {
-        language: resolvedLang,
-        translation_enabled: mainTranslateActive ? true : undefined,
-        translation_target_language: mainTranslateActive ? mainTranslateTarget : undefined,
-        enable_diarization: multitrack ? false : effectiveDiarization,
-        enable_word_timestamps: supportsExplicitWordTimestampToggle ? wordTimestamps : true,
-        parallel_diarization: effectiveDiarization && !multitrack ? parallelDiarization : undefined,
-        multitrack: multitrack || undefined,
-      });
+      addFiles(fileArray, 'session-normal', options);
 
       // GH-210: immediate feedback on manual add. The Folder-Watch path
       // already surfaces detection in handleFilesDetected; this mirrors it for
@@ -438,24 +472,17 @@ export const SessionImportTab: React.FC = () => {
         });
       }
     },
-    [
-      addFiles,
-      effectiveDiarization,
-      multitrack,
-      parallelDiarization,
-      supportsExplicitWordTimestampToggle,
-      wordTimestamps,
-      sessionWatchPath,
-      showWatchHint,
-      activeModel,
-      mainLanguage,
-      mainTranslate,
-      mainBidiTarget,
-      isCanaryMainBidi,
-      canTranslate,
-      languagesLoading,
-      resolveLanguage,
-    ],
+    [addFiles, buildUploadOptions, sessionWatchPath, showWatchHint],
+  );
+
+  const handleTranscribeLocalEdit = useCallback(
+    (path: string, edit: LocalEdit): boolean => {
+      const options = buildUploadOptions();
+      if (!options) return false;
+      transcribeLocalEdit(path, edit, options);
+      return true;
+    },
+    [buildUploadOptions, transcribeLocalEdit],
   );
 
   const handleDrop = useCallback(
@@ -535,8 +562,18 @@ export const SessionImportTab: React.FC = () => {
   const statusLabel = (job: UnifiedImportJob) => {
     switch (job.status) {
       case 'pending':
+        if (job.uploadProgress && job.uploadProgress.sent < job.uploadProgress.total) {
+          return describeUploadProgress(job.uploadProgress);
+        }
         return job.plannedFormat ? `Queued (${job.plannedFormat})` : 'Queued';
       case 'processing':
+        if (job.serverQueue) {
+          const { position, estimatedStartAt } = job.serverQueue;
+          return describeServerQueueWait(position, estimatedStartAt, Date.now() / 1000);
+        }
+        if (job.uploadProgress && job.uploadProgress.sent < job.uploadProgress.total) {
+          return describeUploadProgress(job.uploadProgress);
+        }
         return stalled
           ? `${progressLabel} — no recent progress, the job may be stalled`
           : progressLabel;
@@ -545,7 +582,12 @@ export const SessionImportTab: React.FC = () => {
       case 'success': {
         const diar = job.diarizationOutcome;
         const skipped = !!diar?.requested && !diar?.performed;
-        const base = job.outputFilename ? `Done — ${job.outputFilename}` : 'Done';
+        const done = job.outputFilename ? `Done — ${job.outputFilename}` : 'Done';
+        const partial =
+          job.partialUntil !== undefined
+            ? `${done} (partial — stopped at ${formatClock(job.partialUntil)})`
+            : done;
+        const base = job.downgrade ? `${partial} (${describeDowngrade(job.downgrade)})` : partial;
         if (!skipped) return base;
         const why =
           diar?.reason === 'token_missing' ? 'no HF token' : (diar?.reason ?? 'unavailable');
@@ -713,6 +755,14 @@ export const SessionImportTab: React.FC = () => {
         </div>
       )}
 
+      {/* Shown while our next job waits behind other clients on a shared server */}
+      {jobs.some((j) => j.serverQueue) && <ServerQueuePanel />}
+
+      {/* Uploads the server kept ("Keep server copy"), for download later */}
+      {hasElectronApi && <ServerCopiesPanel refreshKey={completedCount + errorCount} />}
+
+      {hasElectronApi && <LocalEditPanel onTranscribe={handleTranscribeLocalEdit} />}
+
       {/* Output Location */}
       {hasElectronApi && (
         <GlassCard title="Output Location">
@@ -946,6 +996,16 @@ export const SessionImportTab: React.FC = () => {
             description="Separate channels into individual speaker tracks — ideal for podcast, film, or panel recordings with isolated mics"
           />
           <div className="h-px bg-white/5"></div>
+          <AppleSwitch
+            checked={keepServerCopy}
+            onChange={(enabled) => {
+              setKeepServerCopy(enabled);
+              void setConfig('sessionImport.keepServerCopy', enabled);
+            }}
+            label="Keep Server Copy"
+            description="Leave each uploaded file on the server, to download it again later — handy when the original was recorded on another device"
+          />
+          <div className="h-px bg-white/5"></div>
           <AppleSwitch
             checked={wordTimestamps}
             onChange={handleTimestampsChange}
@@ -23,11 +23,13 @@ import {
   Minimize2,
   AlertTriangle,
   Zap,
+  Captions,
 } from 'lucide-react';
 import { GlassCard } from '../ui/GlassCard';
 import { Button } from '../ui/Button';
 import { AppleSwitch } from '../ui/AppleSwitch';
 import { StatusLight } from '../ui/StatusLight';
+import { ConnectionQualityBadge } from '../ui/ConnectionQualityBadge';
 import { AudioVisualizer } from '../AudioVisualizer';
 import { CustomSelect } from '../ui/CustomSelect';
 import { ScrollFadeOverlay } from '../ui/ScrollFadeOverlay';
@@ -35,16 +37,22 @@ import { FullscreenVisualizer } from './FullscreenVisualizer';
 import { PopOutWindow } from '../PopOutWindow';
 import { FindReplaceTextEditor } from '../editor/FindReplaceTextEditor';
 import { LiveTranscriptView } from './LiveTranscriptView';
+import { FollowAlongButton } from './FollowAlongButton';
 import { useQueryClient } from '@tanstack/react-query';
 import { useLanguages } from '../../src/hooks/useLanguages';
 import { formatClock } from '../../src/services/jobProgress';
-import { writeToClipboard } from '../../src/hooks/useClipboard';
+import { writeToClipboard, writeTranscriptToClipboard } from '../../src/hooks/useClipboard';
+import { filterText, loadOutputFilter } from '../../src/services/outputFilter';
 import { useTranscription } from '../../src/hooks/useTranscription';
-import type { LiveModeState } from '../../src/hooks/useLiveMode';
+import type { LiveModeState, LiveStreamingMode } from '../../src/hooks/useLiveMode';
+import { useCaptionOverlay } from '../../src/hooks/useCaptionOverlay';
+import { useFollowAlong } from '../../src/hooks/useFollowAlong';
+import { useKeywordAlerts } from '../../src/hooks/useKeywordAlerts';
 import { useDockerContext } from '../../src/hooks/DockerContext';
 import { useTraySync } from '../../src/hooks/useTraySync';
 import type { ServerConnectionInfo } from '../../src/hooks/useServerStatus';
 import { useAdminStatus } from '../../src/hooks/useAdminStatus';
+import { usePermissions } from '../../src/hooks/usePermissions';
 import { useScrollFade } from '../../src/hooks/useScrollFade';
 import { apiClient } from '../../src/api/client';
 import { getAuthToken, getConfig, setConfig } from '../../src/config/store';
@@ -64,11 +72,20 @@ import {
 import { isModelDisabled } from '../../src/services/modelSelection';
 import { SessionTab } from '../../types';
 import { SessionImportTab } from './SessionImportTab';
+import { InterviewRecordingCard } from './InterviewRecordingCard';
+import { MeetingCaptureCard } from './MeetingCaptureCard';
+import { RetroRecordCard } from './RetroRecordCard';
 import { useImportQueueStore } from '../../src/stores/importQueueStore';
 import { useNotificationsStore } from '../../src/stores/notificationsStore';
 import { toast } from 'sonner';
+import { ensureServerAwake } from '../../src/services/serverIdle';
 import { isRuntimeProfile, type RuntimeProfile } from '../../src/types/runtime';
 
+const STREAMING_MODE_LABELS: Record<LiveStreamingMode, string> = {
+  fast: 'Captions',
+  accurate: 'Notes',
+};
+
 interface SessionViewProps {
   serverConnection: ServerConnectionInfo;
   clientRunning: boolean;
@@ -151,6 +168,7 @@ export const SessionView: React.FC<SessionViewProps> = ({
 
   // Admin status (needed early for model-aware language list)
   const admin = useAdminStatus();
+  const canManageModels = usePermissions().can('can_manage_models');
   // Prefer admin status (auth-gated) for model name; fall back to the
   // unauthenticated /api/status payload so remote clients without an
   // auth token can still derive the active model.
@@ -176,8 +194,17 @@ export const SessionView: React.FC<SessionViewProps> = ({
   // Transcription hooks
   const transcription = useTranscription();
 
-  // Active analyser: live mode takes priority when active, then one-shot
-  const activeAnalyser = live.analyser ?? transcription.analyser;
+  // Interview mode records outside the /ws session (InterviewRecordingCard).
+  const [interviewAnalyser, setInterviewAnalyser] = useState<AnalyserNode | null>(null);
+  const [interviewActive, setInterviewActive] = useState(false);
+  // So does meeting capture (MeetingCaptureCard).
+  const [meetingAnalyser, setMeetingAnalyser] = useState<AnalyserNode | null>(null);
+  const [meetingActive, setMeetingActive] = useState(false);
+
+  // Active analyser: live mode takes priority when active, then one-shot,
+  // then interview, then meeting capture.
+  const activeAnalyser =
+    live.analyser ?? transcription.analyser ?? interviewAnalyser ?? meetingAnalyser;
 
   // Session main-result editing (client-only): hand-corrections flow into
   // Copy/Download. Reset whenever a new transcription replaces the text.
@@ -356,6 +383,8 @@ export const SessionView: React.FC<SessionViewProps> = ({
   // predicate definition feeds both, instead of duplicating the live-status
   // check inline. Originally declared in the "Live Mode State" block below.
   const isLive = live.status !== 'idle' && live.status !== 'error';
+  const captionOverlay = useCaptionOverlay(live.sentences, live.partial, live.status);
+  const followAlong = useFollowAlong(live.sentences, live.partial);
   // Issue #86 #1 (+ follow-up) — surface the reason the Start Recording button
   // is gated. The disabled-prop covers four conditions; this IIFE surfaces an
   // inline amber warning for three of them (`mainModelDisabled` keeps its own
@@ -391,7 +420,7 @@ export const SessionView: React.FC<SessionViewProps> = ({
     setEditedLiveText(next);
   }, []);
   const handleLiveCopyAndClear = useCallback(() => {
-    writeToClipboard(editedLiveText || live.getText()).catch(() => {});
+    writeTranscriptToClipboard(editedLiveText || live.getText()).catch(() => {});
     live.clearHistory();
     liveEditDirtyRef.current = false;
     setEditedLiveText('');
@@ -500,6 +529,25 @@ export const SessionView: React.FC<SessionViewProps> = ({
     };
   }, []);
 
+  // Captions want text now; notes want it right. Switching applies mid-session.
+  const { setStreamingMode: setLiveStreamingMode } = live;
+  useEffect(() => {
+    getConfig<LiveStreamingMode>('live.streamingMode')
+      .then((saved) => {
+        if (saved === 'fast' || saved === 'accurate') setLiveStreamingMode(saved);
+      })
+      .catch(() => {});
+  }, [setLiveStreamingMode]);
+
+  const handleStreamingModeChange = useCallback(
+    (label: string) => {
+      const mode = label === STREAMING_MODE_LABELS.fast ? 'fast' : 'accurate';
+      setLiveStreamingMode(mode);
+      void setConfig('live.streamingMode', mode).catch(() => {});
+    },
+    [setLiveStreamingMode],
+  );
+
   const handleAudioSourceChange = useCallback((source: 'mic' | 'system') => {
     setAudioSource(source);
     persistedSelectionsRef.current.audioSource = source;
@@ -768,9 +816,11 @@ export const SessionView: React.FC<SessionViewProps> = ({
   const isProcessing = transcription.status === 'processing';
   const isConnecting = transcription.status === 'connecting';
   const canStartRecording =
-    transcription.status === 'idle' ||
-    transcription.status === 'complete' ||
-    transcription.status === 'error';
+    !interviewActive &&
+    !meetingActive &&
+    (transcription.status === 'idle' ||
+      transcription.status === 'complete' ||
+      transcription.status === 'error');
 
   const handleStartRecording = useCallback(() => {
     if (!canStartRecording || mainModelDisabled) return;
@@ -796,6 +846,11 @@ export const SessionView: React.FC<SessionViewProps> = ({
     const mainTranslateTarget = isCanaryMainBidi ? (resolveLanguage(mainBidiTarget) ?? 'en') : 'en';
 
     void (async () => {
+      try {
+        await ensureServerAwake();
+      } catch {
+        return;
+      }
       if (isSystemAudio && !isLinux) {
         // Windows / macOS: register getDisplayMedia loopback handler
         await window.electronAPI?.audio?.enableSystemAudioLoopback?.();
@@ -911,7 +966,7 @@ export const SessionView: React.FC<SessionViewProps> = ({
   const handleCopyTranscription = useCallback(() => {
     const text = editedResultText ?? transcription.result?.text;
     if (!text) return;
-    writeToClipboard(text).catch(() => {});
+    writeTranscriptToClipboard(text).catch(() => {});
   }, [editedResultText, transcription.result?.text]);
 
   // Download transcription as TXT file (prefers the edited text)
@@ -932,6 +987,7 @@ export const SessionView: React.FC<SessionViewProps> = ({
     (checked: boolean) => {
       if (checked) {
         if (liveModelDisabled || !liveModeWhisperOnlyCompatible) return;
+        if (interviewActive || meetingActive) return;
         // gh-102: same guard as handleStartRecording — refuse to start live
         // mode when the active live model needs an explicit source language
         // and the dashboard cannot resolve one. The current UI gates live
@@ -955,11 +1011,17 @@ export const SessionView: React.FC<SessionViewProps> = ({
           ? (resolveLanguage(liveBidiTarget) ?? 'en')
           : 'en';
         void (async () => {
+          try {
+            await ensureServerAwake();
+          } catch {
+            return;
+          }
           const rawGracePeriod = await getConfig<number>('audio.gracePeriod');
           const gracePeriodSeconds =
             typeof rawGracePeriod === 'number' && Number.isFinite(rawGracePeriod)
               ? rawGracePeriod
               : 1.0;
+          const watchKeywords = await getConfig<string[]>('live.watchKeywords');
 
           if (isSystemAudio && !isLinux) {
             await window.electronAPI?.audio?.enableSystemAudioLoopback?.();
@@ -978,6 +1040,8 @@ export const SessionView: React.FC<SessionViewProps> = ({
             // Linux system audio: AudioCapture owns the loopback module
             // lifecycle via loopbackOwner (GH-230).
             monitorSinkName: isSystemAudio && isLinux ? sinkNameMap[sysDevice] : undefined,
+            watchKeywords: Array.isArray(watchKeywords) ? watchKeywords : [],
+            streamingMode: live.streamingMode,
           });
           // Apply persisted capture gain after capture starts
           if (isSystemAudio) {
@@ -1010,6 +1074,8 @@ export const SessionView: React.FC<SessionViewProps> = ({
       captureGain,
       activeLiveModel,
       languagesLoading,
+      interviewActive,
+      meetingActive,
     ],
   );
 
@@ -1122,17 +1188,18 @@ export const SessionView: React.FC<SessionViewProps> = ({
           window.electronAPI?.clipboard?.pasteAtCursor &&
           ((await getConfig<boolean>('app.pasteAtCursor')) ?? false);
         const autoCopy = (await getConfig<boolean>('app.autoCopy')) ?? true;
+        const copied = filterText(text, await loadOutputFilter());
 
         if (pasteEnabled) {
           // pasteAtCursor writes text to clipboard + simulates Ctrl+V.
           // preserveClipboard: false keeps the text in clipboard (autoCopy is implicit).
           await window
-            .electronAPI!.clipboard.pasteAtCursor(text, { preserveClipboard: false })
+            .electronAPI!.clipboard.pasteAtCursor(copied, { preserveClipboard: false })
             .catch((err) => {
               console.warn('Paste at cursor failed:', err);
             });
         } else if (autoCopy) {
-          await writeToClipboard(text).catch(() => {});
+          await writeToClipboard(copied).catch(() => {});
         }
       })();
 
@@ -1314,6 +1381,33 @@ export const SessionView: React.FC<SessionViewProps> = ({
     }
   }, [live.sentences, live.partial]);
 
+  // Keyword alerts: clicking a notification pauses auto-scroll and brings the
+  // matching sentence into view, flashing it briefly.
+  const [highlightedSentence, setHighlightedSentence] = useState<number | null>(null);
+  const highlightTimerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
+  const jumpToLiveSentence = useCallback((index: number) => {
+    const row = liveTranscriptRef.current?.querySelector<HTMLElement>(
+      `[data-live-sentence="${index}"]`,
+    );
+    if (!row) return;
+    liveAutoScrollRef.current = false;
+    row.scrollIntoView({ block: 'center', behavior: 'smooth' });
+    setHighlightedSentence(index);
+    if (highlightTimerRef.current) clearTimeout(highlightTimerRef.current);
+    highlightTimerRef.current = setTimeout(() => setHighlightedSentence(null), 2000);
+  }, []);
+  useEffect(
+    () => () => {
+      if (highlightTimerRef.current) clearTimeout(highlightTimerRef.current);
+    },
+    [],
+  );
+  useKeywordAlerts(live.alerts, jumpToLiveSentence);
+  const alertedSentences = useMemo(
+    () => new Set(live.alerts.map((a) => a.sentenceIndex)),
+    [live.alerts],
+  );
+
   const maskStyle: React.CSSProperties = {
     backgroundColor: '#0f172a',
     backgroundImage:
@@ -1601,26 +1695,32 @@ export const SessionView: React.FC<SessionViewProps> = ({
                             </Button>
                           </div>
                         )}
-                        <div className="ml-auto shrink-0">
-                          <Button
-                            variant={showUnloadModelsState ? 'danger' : 'secondary'}
-                            size="sm"
-                            onClick={isAsrModelsLoaded ? handleUnloadAllModels : handleReloadModels}
-                            disabled={!serverConnection.reachable || modelsOperationPending}
-                            className="px-3 text-xs"
-                          >
-                            {modelsOperationPending ? (
-                              <>
-                                <Loader2 size={14} className="mr-1 animate-spin" />
-                                {modelsOperationType === 'loading' ? 'Loading...' : 'Unloading...'}
-                              </>
-                            ) : showUnloadModelsState ? (
-                              'Unload Models'
-                            ) : (
-                              'Reload Models'
-                            )}
-                          </Button>
-                        </div>
+                        {canManageModels && (
+                          <div className="ml-auto shrink-0">
+                            <Button
+                              variant={showUnloadModelsState ? 'danger' : 'secondary'}
+                              size="sm"
+                              onClick={
+                                isAsrModelsLoaded ? handleUnloadAllModels : handleReloadModels
+                              }
+                              disabled={!serverConnection.reachable || modelsOperationPending}
+                              className="px-3 text-xs"
+                            >
+                              {modelsOperationPending ? (
+                                <>
+                                  <Loader2 size={14} className="mr-1 animate-spin" />
+                                  {modelsOperationType === 'loading'
+                                    ? 'Loading...'
+                                    : 'Unloading...'}
+                                </>
+                              ) : showUnloadModelsState ? (
+                                'Unload Models'
+                              ) : (
+                                'Reload Models'
+                              )}
+                            </Button>
+                          </div>
+                        )}
                       </div>
                     </div>
 
@@ -1647,6 +1747,7 @@ export const SessionView: React.FC<SessionViewProps> = ({
                               {clientMode}
                             </span>
                           )}
+                          {clientRunning && <ConnectionQualityBadge />}
                           <StatusLight
                             status={
                               clientRunning && !serverConnection.reachable
@@ -2179,6 +2280,35 @@ export const SessionView: React.FC<SessionViewProps> = ({
                     </div>
                   </div>
                 </GlassCard>
+
+                <InterviewRecordingCard
+                  micDeviceId={micDeviceIds[micDevice]}
+                  monitorSinkName={sinkNameMap[sysDevice]}
+                  isLinux={isLinux}
+                  disabled={!canStartRecording || isLive || mainModelDisabled}
+                  language={resolveLanguage(mainLanguage)}
+                  onAnalyserChange={setInterviewAnalyser}
+                  onActiveChange={setInterviewActive}
+                />
+
+                <MeetingCaptureCard
+                  systemAudio={audioSource === 'system'}
+                  deviceId={micDeviceIds[micDevice]}
+                  monitorSinkName={sinkNameMap[sysDevice]}
+                  isLinux={isLinux}
+                  disabled={!canStartRecording || isLive || mainModelDisabled}
+                  language={resolveLanguage(mainLanguage)}
+                  onAnalyserChange={setMeetingAnalyser}
+                  onActiveChange={setMeetingActive}
+                />
+
+                <RetroRecordCard
+                  systemAudio={audioSource === 'system'}
+                  deviceId={micDeviceIds[micDevice]}
+                  monitorSinkName={sinkNameMap[sysDevice]}
+                  isLinux={isLinux}
+                  language={resolveLanguage(mainLanguage)}
+                />
               </div>
             </div>
 
@@ -2352,6 +2482,21 @@ export const SessionView: React.FC<SessionViewProps> = ({
                             Live Mode
                           </h2>
                           <div className="flex items-center gap-2">
+                            {captionOverlay.available && (
+                              <button
+                                onClick={captionOverlay.toggle}
+                                aria-pressed={captionOverlay.visible}
+                                className={`flex h-7 w-7 items-center justify-center rounded-lg border transition-colors ${captionOverlay.visible ? 'border-accent-cyan/30 bg-accent-cyan/15 text-accent-cyan' : 'border-white/10 bg-white/5 text-slate-400 hover:bg-white/10 hover:text-white'}`}
+                                title={
+                                  captionOverlay.visible
+                                    ? 'Hide caption overlay'
+                                    : 'Show captions on top of other apps'
+                                }
+                              >
+                                <Captions size={14} />
+                              </button>
+                            )}
+                            <FollowAlongButton followAlong={followAlong} />
                             <button
                               onClick={() => live.toggleMute()}
                               className={`flex h-7 w-7 items-center justify-center rounded-lg border transition-colors ${live.muted ? 'border-red-500/30 bg-red-500/20 text-red-400 hover:bg-red-500/30' : 'border-white/10 bg-white/5 text-slate-400 hover:bg-white/10 hover:text-white'}`}
@@ -2376,9 +2521,11 @@ export const SessionView: React.FC<SessionViewProps> = ({
                             >
                               {live.status === 'starting'
                                 ? 'Loading...'
-                                : isLive
-                                  ? 'Active'
-                                  : 'Offline'}
+                                : live.status === 'reconnecting'
+                                  ? 'Reconnecting...'
+                                  : isLive
+                                    ? 'Active'
+                                    : 'Offline'}
                             </span>
                             <span
                               title={
@@ -2444,6 +2591,22 @@ export const SessionView: React.FC<SessionViewProps> = ({
                             )}
                           </div>
                           <div className="mx-0.5 h-5 w-px shrink-0 bg-white/10"></div>
+                          <div
+                            className="flex h-8 shrink-0 items-center gap-2"
+                            title="Captions: fast partial text. Notes: slower, more accurate sentences."
+                          >
+                            <span className="text-[9px] font-bold tracking-widest whitespace-nowrap text-slate-500 uppercase">
+                              Mode
+                            </span>
+                            <CustomSelect
+                              value={STREAMING_MODE_LABELS[live.streamingMode]}
+                              onChange={handleStreamingModeChange}
+                              options={[STREAMING_MODE_LABELS.fast, STREAMING_MODE_LABELS.accurate]}
+                              accentColor="magenta"
+                              className="focus:ring-accent-magenta h-full min-w-25 rounded-lg border border-white/10 bg-white/5 px-2 py-1 text-sm text-slate-300 outline-none focus:ring-1"
+                            />
+                          </div>
+                          <div className="mx-0.5 h-5 w-px shrink-0 bg-white/10"></div>
                           <Button
                             variant="ghost"
                             size="sm"
@@ -2466,6 +2629,8 @@ export const SessionView: React.FC<SessionViewProps> = ({
                           liveModeWhisperOnlyCompatible={liveModeWhisperOnlyCompatible}
                           liveModeUnsupportedMessage={liveModeUnsupportedMessage}
                           transcriptRef={liveTranscriptRef}
+                          alertedSentences={alertedSentences}
+                          highlightedSentence={highlightedSentence}
                           editedLiveText={editedLiveText}
                           onEditedLiveChange={handleEditedLiveChange}
                         />
@@ -2485,6 +2650,21 @@ export const SessionView: React.FC<SessionViewProps> = ({
                         >
                           <ExternalLink size={14} />
                         </button>
+                        {captionOverlay.available && (
+                          <button
+                            onClick={captionOverlay.toggle}
+                            aria-pressed={captionOverlay.visible}
+                            className={`flex h-7 w-7 items-center justify-center rounded-lg border transition-colors ${captionOverlay.visible ? 'border-accent-cyan/30 bg-accent-cyan/15 text-accent-cyan' : 'border-white/10 bg-white/5 text-slate-400 hover:bg-white/10 hover:text-white'}`}
+                            title={
+                              captionOverlay.visible
+                                ? 'Hide caption overlay'
+                                : 'Show captions on top of other apps'
+                            }
+                          >
+                            <Captions size={14} />
+                          </button>
+                        )}
+                        <FollowAlongButton followAlong={followAlong} />
                         <button
                           onClick={() => live.toggleMute()}
                           className={`flex h-7 w-7 items-center justify-center rounded-lg border transition-colors ${live.muted ? 'border-red-500/30 bg-red-500/20 text-red-400 hover:bg-red-500/30' : 'border-white/10 bg-white/5 text-slate-400 hover:bg-white/10 hover:text-white'}`}
@@ -2503,9 +2683,11 @@ export const SessionView: React.FC<SessionViewProps> = ({
                         >
                           {live.status === 'starting'
                             ? 'Loading...'
-                            : isLive
-                              ? 'Active'
-                              : 'Offline'}
+                            : live.status === 'reconnecting'
+                              ? 'Reconnecting...'
+                              : isLive
+                                ? 'Active'
+                                : 'Offline'}
                         </span>
                         <span
                           title={
@@ -2567,6 +2749,22 @@ export const SessionView: React.FC<SessionViewProps> = ({
                         )}
                       </div>
                       <div className="mx-0.5 h-5 w-px shrink-0 bg-white/10"></div>
+                      <div
+                        className="flex h-8 shrink-0 items-center gap-2"
+                        title="Captions: fast partial text. Notes: slower, more accurate sentences."
+                      >
+                        <span className="text-[9px] font-bold tracking-widest whitespace-nowrap text-slate-500 uppercase">
+                          Mode
+                        </span>
+                        <CustomSelect
+                          value={STREAMING_MODE_LABELS[live.streamingMode]}
+                          onChange={handleStreamingModeChange}
+                          options={[STREAMING_MODE_LABELS.fast, STREAMING_MODE_LABELS.accurate]}
+                          accentColor="magenta"
+                          className="focus:ring-accent-magenta h-full min-w-25 rounded-lg border border-white/10 bg-white/5 px-2 py-1 text-sm text-slate-300 outline-none focus:ring-1"
+                        />
+                      </div>
+                      <div className="mx-0.5 h-5 w-px shrink-0 bg-white/10"></div>
                       <Button
                         variant="ghost"
                         size="sm"
@@ -2590,6 +2788,8 @@ export const SessionView: React.FC<SessionViewProps> = ({
                       liveModeWhisperOnlyCompatible={liveModeWhisperOnlyCompatible}
                       liveModeUnsupportedMessage={liveModeUnsupportedMessage}
                       transcriptRef={liveTranscriptRef}
+                      alertedSentences={alertedSentences}
+                      highlightedSentence={highlightedSentence}
                       editedLiveText={editedLiveText}
                       onEditedLiveChange={handleEditedLiveChange}
                     />
@@ -37,12 +37,38 @@ import { toast } from 'sonner';
 import { useConfirm } from '../../src/hooks/useConfirm';
 import { isMLXModel, isVibeVoiceASRModel } from '../../src/services/modelCapabilities';
 import { mergeConfigUpdates } from '../../src/utils/configTree';
-import { DEFAULT_SERVER_PORT } from '../../src/config/store';
+import {
+  DEFAULT_CONFIG,
+  DEFAULT_SERVER_PORT,
+  normalizeConnectionTuning,
+  validateConnectionTuning,
+  type ConnectionProfile,
+} from '../../src/config/store';
 import { readPersistedBlurEffects } from '../../src/utils/blurEffectsBoot';
 import { readPersistedIdleAnimations } from '../../src/utils/idleAnimationsBoot';
-import type { AuthToken, LLMModel } from '../../src/api/types';
+import type { AuthToken, LLMModel, PostRuleReport } from '../../src/api/types';
 import { useAdminStatus } from '../../src/hooks/useAdminStatus';
 import { ServerConfigEditor } from './ServerConfigEditor';
+import { ConnectionTuningSettings } from './ConnectionTuningSettings';
+import { DatabaseMaintenanceSettings } from './DatabaseMaintenanceSettings';
+import { ServerUsersPanel } from './ServerUsersPanel';
+import { AuditLogPanel } from './AuditLogPanel';
+import { FootPedalSettings } from './FootPedalSettings';
+import { HousekeepingSettings } from './HousekeepingSettings';
+import { WhispercppEngineSettings } from './WhispercppEngineSettings';
+import { BenchmarkPanel } from './BenchmarkPanel';
+import { NetworkTraceSettings } from './NetworkTraceSettings';
+import { TimeTrackingSettings } from './TimeTrackingSettings';
+import { SettingsTransferSettings } from './SettingsTransferSettings';
+import { UploadSettings } from './UploadSettings';
+import { CacheSettings } from './CacheSettings';
+import { PluginSettings } from './PluginSettings';
+import { HookSettings } from './HookSettings';
+import { VoiceProfileSettings } from './VoiceProfileSettings';
+import { ProjectTemplateSettings } from './ProjectTemplateSettings';
+import { InAppShortcutSettings } from './InAppShortcutSettings';
+import { LanguageSettings } from './LanguageSettings';
+import { useT } from '../../src/hooks/useLocale';
 import { NvidiaIcon } from '../ui/icons/NvidiaIcon';
 import { AmdIcon } from '../ui/icons/AmdIcon';
 import { IntelIcon } from '../ui/icons/IntelIcon';
@@ -52,9 +78,16 @@ import type { Profile } from '../../src/api/client';
 import { EmptyProfileForm } from '../profiles/EmptyProfileForm';
 import { ModelProfilesPanel } from '../profiles/ModelProfilesPanel';
 import { useLanguages } from '../../src/hooks/useLanguages';
+import { usePermissions } from '../../src/hooks/usePermissions';
 import { MAIN_MODEL_PRESETS } from '../../src/services/modelSelection';
 import { CANARY_TRANSLATION_TARGETS } from '../../src/services/modelCapabilities';
 import type { DuplicatePolicy } from '../../src/stores/importQueueStore';
+import {
+  formatReplacements,
+  parseReplacements,
+  type MaskStyle,
+  type WordReplacement,
+} from '../../src/services/outputFilter';
 
 interface SettingsModalProps {
   isOpen: boolean;
@@ -86,6 +119,13 @@ const DUPLICATE_POLICY_LABELS: Record<DuplicatePolicy, string> = {
 function normalizeDuplicatePolicy(raw: unknown): DuplicatePolicy {
   return raw === 'ask' ? 'ask' : 'create_new';
 }
+const MASK_STYLE_ORDER: MaskStyle[] = ['asterisks', 'first-letter', 'bleep', 'remove'];
+const MASK_STYLE_LABELS: Record<MaskStyle, string> = {
+  asterisks: 'Asterisks (****)',
+  'first-letter': 'Keep first letter (f***)',
+  bleep: '[bleep]',
+  remove: 'Remove the word',
+};
 
 function normalizeConfigString(value: unknown): string {
   return typeof value === 'string' ? value.trim() : '';
@@ -110,6 +150,7 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
     | { available: boolean; reason: string }
     | undefined;
   const [activeTab, setActiveTab] = useState('App');
+  const t = useT();
   const { confirm, dialog: confirmDialog } = useConfirm();
   const [showAuthToken, setShowAuthToken] = useState(false);
   const [tokenCopied, setTokenCopied] = useState(false);
@@ -117,6 +158,9 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
   const [serverAdminTokenCopied, setServerAdminTokenCopied] = useState(false);
   const [showHfToken, setShowHfToken] = useState(false);
   const [webhookTesting, setWebhookTesting] = useState(false);
+  const [ruleTest, setRuleTest] = useState({ source: '', filename: '' });
+  const [ruleTesting, setRuleTesting] = useState(false);
+  const [ruleReport, setRuleReport] = useState<PostRuleReport[] | null>(null);
   const [showWebhookSecret, setShowWebhookSecret] = useState(false);
 
   // Animation State
@@ -153,6 +197,8 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
         gpu: boolean;
         toolkit: boolean;
         vulkan: boolean;
+        rocm?: boolean;
+        xpu?: boolean;
         wslSupport?: { available: boolean; gpuPassthroughDetected: boolean; reason?: string };
       }
     | undefined
@@ -208,7 +254,16 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
     blurEffectsEnabled: true,
     idleAnimationsEnabled: false, // GH #87 — default OFF (see idleAnimationsBoot)
     duplicatePolicy: 'create_new' as DuplicatePolicy, // GH-120 — Folder Watch
+    containerRuntime: 'auto' as 'auto' | 'wsl',
+    wslDistro: '',
+    idleStopEnabled: false,
+    idleStopMinutes: 15,
+    replicas: 1,
   });
+  // Windows: distros for the "Docker in WSL" engine and whether the selected
+  // one has a working Docker Engine.
+  const [wslDistros, setWslDistros] = useState<WslDistro[]>([]);
+  const [wslProbe, setWslProbe] = useState<WslDockerProbe | null>(null);
   // Issue #87 — track the LAST SAVED Blur effects value so we can revert any
   // unsaved live-preview DOM changes if the modal closes via X without Save.
   // Lazy-initialised from the same localStorage source the boot probe in
@@ -258,14 +313,45 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
     authToken: '',
     port: DEFAULT_SERVER_PORT,
     useHttps: false,
+    autoFailover: false,
+    powerAutoWake: false,
+    powerMacAddress: '',
+    powerBroadcastAddress: '255.255.255.255',
+    powerSleepAfterJobs: false,
+    powerSshTarget: '',
+    powerSshCommand: 'sudo systemctl suspend',
+    userName: '',
+    showOnlyMine: false,
     hfToken: '',
     hideTimestamps: false,
+    normalizeNumbers: false,
+    outputFilterEnabled: false,
+    outputFilterBuiltInList: true,
+    outputFilterWords: '',
+    outputFilterMaskStyle: 'asterisks' as MaskStyle,
+    outputFilterReplacements: '',
+    captionFileEnabled: false,
+    captionFilePath: '',
+    obsCaptionsEnabled: false,
+    obsUrl: 'ws://127.0.0.1:4455',
+    obsPassword: '',
+    obsInputName: 'TranscriptionSuite Captions',
+    watchKeywords: '',
   });
+  const [connectionTuning, setConnectionTuning] = useState(DEFAULT_CONFIG.connection.tuning);
+  const tuningProfile: ConnectionProfile = clientSettings.useRemote
+    ? clientSettings.remoteProfile === 'lan'
+      ? 'lan'
+      : 'tailscale'
+    : 'local';
 
   // Sync auth token from the centralized useAuthTokenSync hook's cache.
   // Handles both new tokens (from Docker log detection) and token clearing
   // (stale-token guard sets cache to '' when the server rejects it).
   const queryClient = useQueryClient();
+  const { can } = usePermissions();
+  const canManageTokens = can('can_manage_tokens');
+  const canChangeServerSettings = can('can_change_server_settings');
   useEffect(() => {
     const syncToken = (token: string | undefined) => {
       const value = token ?? '';
@@ -284,6 +370,25 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
     return unsubscribe;
   }, [queryClient]);
 
+  // Check the selected WSL distro for a usable Docker Engine.
+  useEffect(() => {
+    const wsl = window.electronAPI?.wsl;
+    if (!isOpen || !wsl || appSettings.containerRuntime !== 'wsl' || !appSettings.wslDistro) {
+      setWslProbe(null);
+      return;
+    }
+    let cancelled = false;
+    wsl
+      .probe(appSettings.wslDistro)
+      .then((result) => {
+        if (!cancelled) setWslProbe(result);
+      })
+      .catch(() => {});
+    return () => {
+      cancelled = true;
+    };
+  }, [isOpen, appSettings.containerRuntime, appSettings.wslDistro]);
+
   // Load AI tab data when active
   useEffect(() => {
     if (!isOpen || activeTab !== 'AI') return;
@@ -376,6 +481,10 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
           .catch(() => {
             setGpuInfo(undefined);
           });
+        api.wsl
+          ?.listDistros?.()
+          .then((distros: WslDistro[]) => setWslDistros(distros))
+          .catch(() => setWslDistros([]));
 
         // Load the server config directory path (dedicated server-config subdir
         // that actually holds config.yaml — not the userData root).
@@ -402,6 +511,17 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
                 authToken: (cfg['connection.authToken'] as string) ?? prev.authToken,
                 port: (cfg['connection.port'] as number) ?? prev.port,
                 useHttps: useRemote ? true : useHttps,
+                autoFailover: (cfg['connection.autoFailover'] as boolean) ?? prev.autoFailover,
+                powerAutoWake: (cfg['power.autoWake'] as boolean) ?? prev.powerAutoWake,
+                powerMacAddress: (cfg['power.macAddress'] as string) ?? prev.powerMacAddress,
+                powerBroadcastAddress:
+                  (cfg['power.broadcastAddress'] as string) ?? prev.powerBroadcastAddress,
+                powerSleepAfterJobs:
+                  (cfg['power.sleepAfterJobs'] as boolean) ?? prev.powerSleepAfterJobs,
+                powerSshTarget: (cfg['power.sshTarget'] as string) ?? prev.powerSshTarget,
+                powerSshCommand: (cfg['power.sshCommand'] as string) ?? prev.powerSshCommand,
+                userName: (cfg['identity.userName'] as string) ?? prev.userName,
+                showOnlyMine: (cfg['identity.showOnlyMine'] as boolean) ?? prev.showOnlyMine,
                 gracePeriod: (cfg['audio.gracePeriod'] as number) ?? prev.gracePeriod,
                 previewDurationSeconds:
                   (cfg['audio.previewDurationSeconds'] as number) ?? prev.previewDurationSeconds,
@@ -411,7 +531,43 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
                 autoAddNotebook: (cfg['notebook.autoAdd'] as boolean) ?? prev.autoAddNotebook,
                 hfToken: (cfg['server.hfToken'] as string) ?? prev.hfToken,
                 hideTimestamps: (cfg['output.hideTimestamps'] as boolean) ?? prev.hideTimestamps,
+                normalizeNumbers:
+                  (cfg['output.normalizeNumbers'] as boolean) ?? prev.normalizeNumbers,
+                outputFilterEnabled:
+                  (cfg['output.filter.enabled'] as boolean) ?? prev.outputFilterEnabled,
+                outputFilterBuiltInList:
+                  (cfg['output.filter.builtInList'] as boolean) ?? prev.outputFilterBuiltInList,
+                outputFilterWords: Array.isArray(cfg['output.filter.words'])
+                  ? (cfg['output.filter.words'] as string[]).join('\n')
+                  : prev.outputFilterWords,
+                outputFilterMaskStyle: MASK_STYLE_ORDER.includes(
+                  cfg['output.filter.maskStyle'] as MaskStyle,
+                )
+                  ? (cfg['output.filter.maskStyle'] as MaskStyle)
+                  : prev.outputFilterMaskStyle,
+                outputFilterReplacements: Array.isArray(cfg['output.filter.replacements'])
+                  ? formatReplacements(cfg['output.filter.replacements'] as WordReplacement[])
+                  : prev.outputFilterReplacements,
+                captionFileEnabled:
+                  (cfg['captions.textFile.enabled'] as boolean) ?? prev.captionFileEnabled,
+                captionFilePath: (cfg['captions.textFile.path'] as string) ?? prev.captionFilePath,
+                obsCaptionsEnabled:
+                  (cfg['captions.obs.enabled'] as boolean) ?? prev.obsCaptionsEnabled,
+                obsUrl: (cfg['captions.obs.url'] as string) ?? prev.obsUrl,
+                obsPassword: (cfg['captions.obs.password'] as string) ?? prev.obsPassword,
+                obsInputName: (cfg['captions.obs.inputName'] as string) ?? prev.obsInputName,
+                watchKeywords: Array.isArray(cfg['live.watchKeywords'])
+                  ? (cfg['live.watchKeywords'] as string[]).join('\n')
+                  : prev.watchKeywords,
               }));
+              setConnectionTuning({
+                local: normalizeConnectionTuning(cfg['connection.tuning.local'], 'local'),
+                tailscale: normalizeConnectionTuning(
+                  cfg['connection.tuning.tailscale'],
+                  'tailscale',
+                ),
+                lan: normalizeConnectionTuning(cfg['connection.tuning.lan'], 'lan'),
+              });
               const loadedBlurEffectsEnabled = (cfg['ui.blurEffectsEnabled'] as boolean) ?? true;
               savedBlurEffectsRef.current = loadedBlurEffectsEnabled;
               const loadedIdleAnimationsEnabled =
@@ -436,6 +592,13 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
                 blurEffectsEnabled: loadedBlurEffectsEnabled,
                 idleAnimationsEnabled: loadedIdleAnimationsEnabled,
                 duplicatePolicy: normalizeDuplicatePolicy(cfg['folderWatch.duplicatePolicy']),
+                containerRuntime: cfg['server.containerRuntime'] === 'wsl' ? 'wsl' : 'auto',
+                wslDistro: (cfg['server.wslDistro'] as string) ?? prev.wslDistro,
+                idleStopEnabled:
+                  (cfg['server.idleStop.enabled'] as boolean) ?? prev.idleStopEnabled,
+                idleStopMinutes:
+                  (cfg['server.idleStop.minutes'] as number) ?? prev.idleStopMinutes,
+                replicas: (cfg['server.replicas'] as number) ?? prev.replicas,
               }));
               setShortcutSettings((prev) => ({
                 ...prev,
@@ -544,6 +707,12 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
       return;
     }
 
+    const tuningErrors = validateConnectionTuning(connectionTuning[tuningProfile]);
+    if (tuningErrors.length > 0) {
+      toast.error(tuningErrors[0]);
+      return;
+    }
+
     if (api?.config) {
       const entries: [string, unknown][] = [
         ['connection.localHost', normalizedLocalHost || clientSettings.localHost],
@@ -554,6 +723,21 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
         ['connection.authToken', clientSettings.authToken],
         ['connection.port', clientSettings.port],
         ['connection.useHttps', normalizedUseHttps],
+        ['connection.autoFailover', clientSettings.autoFailover],
+        ['power.autoWake', clientSettings.powerAutoWake],
+        ['power.macAddress', clientSettings.powerMacAddress.trim()],
+        ['power.broadcastAddress', clientSettings.powerBroadcastAddress.trim()],
+        ['power.sleepAfterJobs', clientSettings.powerSleepAfterJobs],
+        ['power.sshTarget', clientSettings.powerSshTarget.trim()],
+        ['power.sshCommand', clientSettings.powerSshCommand.trim()],
+        ['identity.userName', clientSettings.userName.trim()],
+        ['identity.showOnlyMine', clientSettings.showOnlyMine],
+        ...(Object.keys(connectionTuning) as ConnectionProfile[]).map(
+          (profile): [string, unknown] => [
+            `connection.tuning.${profile}`,
+            normalizeConnectionTuning(connectionTuning[profile], profile),
+          ],
+        ),
         ['audio.gracePeriod', clientSettings.gracePeriod],
         ['audio.previewDurationSeconds', clientSettings.previewDurationSeconds],
         ['diarization.constrainSpeakers', clientSettings.constrainSpeakers],
@@ -561,6 +745,31 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
         ['notebook.autoAdd', clientSettings.autoAddNotebook],
         ['server.hfToken', clientSettings.hfToken],
         ['output.hideTimestamps', clientSettings.hideTimestamps],
+        ['output.normalizeNumbers', clientSettings.normalizeNumbers],
+        ['output.filter.enabled', clientSettings.outputFilterEnabled],
+        ['output.filter.builtInList', clientSettings.outputFilterBuiltInList],
+        [
+          'output.filter.words',
+          clientSettings.outputFilterWords
+            .split('\n')
+            .map((line) => line.trim())
+            .filter(Boolean),
+        ],
+        ['output.filter.maskStyle', clientSettings.outputFilterMaskStyle],
+        ['output.filter.replacements', parseReplacements(clientSettings.outputFilterReplacements)],
+        ['captions.textFile.enabled', clientSettings.captionFileEnabled],
+        ['captions.textFile.path', clientSettings.captionFilePath.trim()],
+        ['captions.obs.enabled', clientSettings.obsCaptionsEnabled],
+        ['captions.obs.url', clientSettings.obsUrl.trim()],
+        ['captions.obs.password', clientSettings.obsPassword],
+        ['captions.obs.inputName', clientSettings.obsInputName.trim()],
+        [
+          'live.watchKeywords',
+          clientSettings.watchKeywords
+            .split('\n')
+            .map((line) => line.trim())
+            .filter(Boolean),
+        ],
         ['app.autoCopy', appSettings.autoCopy],
         ['app.showNotifications', appSettings.showNotifications],
         ['app.stopServerOnQuit', appSettings.stopServerOnQuit],
@@ -573,6 +782,11 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
         ['ui.blurEffectsEnabled', appSettings.blurEffectsEnabled],
         ['ui.idleAnimationsEnabled', appSettings.idleAnimationsEnabled],
         ['folderWatch.duplicatePolicy', appSettings.duplicatePolicy],
+        ['server.containerRuntime', appSettings.containerRuntime],
+        ['server.wslDistro', appSettings.wslDistro],
+        ['server.idleStop.enabled', appSettings.idleStopEnabled],
+        ['server.idleStop.minutes', appSettings.idleStopMinutes],
+        ['server.replicas', appSettings.replicas],
         ['shortcuts.startRecording', shortcutSettings.startRecording.trim()],
         ['shortcuts.stopTranscribe', shortcutSettings.stopTranscribe.trim()],
       ];
@@ -616,6 +830,7 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
     // and force admin status re-fetch to give immediate token validity feedback
     queryClient.setQueryData(['authToken'], clientSettings.authToken);
     queryClient.invalidateQueries({ queryKey: ['adminStatus'] });
+    queryClient.invalidateQueries({ queryKey: ['permissions'] });
 
     // Save server config.yaml changes (if any).
     // Read the existing local config first so we merge new changes on top —
@@ -640,7 +855,15 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
 
     setIsDirty(false);
     onClose();
-  }, [clientSettings, appSettings, shortcutSettings, serverConfigUpdates, onClose]);
+  }, [
+    clientSettings,
+    connectionTuning,
+    tuningProfile,
+    appSettings,
+    shortcutSettings,
+    serverConfigUpdates,
+    onClose,
+  ]);
 
   const handleServerConfigFieldChange = useCallback((path: string, value: unknown) => {
     setServerConfigUpdates((prev) => ({ ...prev, [path]: value }));
@@ -791,6 +1014,147 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
           }}
           label="Stop server when quitting dashboard"
         />
+        <div className="mt-4 space-y-2">
+          <AppleSwitch
+            checked={appSettings.idleStopEnabled}
+            onChange={(v) => {
+              setAppSettings((prev) => ({ ...prev, idleStopEnabled: v }));
+              setIsDirty(true);
+            }}
+            label="Stop server when idle"
+          />
+          {appSettings.idleStopEnabled && (
+            <div>
+              <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+                Idle time (minutes)
+              </label>
+              <div className="flex items-center rounded-lg border border-white/10 bg-black/20">
+                <button
+                  type="button"
+                  onClick={() => {
+                    setAppSettings((prev) => ({
+                      ...prev,
+                      idleStopMinutes: Math.max(1, prev.idleStopMinutes - 5),
+                    }));
+                    setIsDirty(true);
+                  }}
+                  className="px-3 py-2 text-slate-400 transition-colors select-none hover:text-white"
+                >
+                  −
+                </button>
+                <input
+                  type="number"
+                  min="1"
+                  value={appSettings.idleStopMinutes}
+                  onChange={(e) => {
+                    setAppSettings((prev) => ({
+                      ...prev,
+                      idleStopMinutes: Math.max(1, parseInt(e.target.value) || 1),
+                    }));
+                    setIsDirty(true);
+                  }}
+                  className="min-w-0 flex-1 [appearance:textfield] bg-transparent py-2 text-center text-sm text-white focus:outline-none [&::-webkit-inner-spin-button]:appearance-none [&::-webkit-outer-spin-button]:appearance-none"
+                />
+                <button
+                  type="button"
+                  onClick={() => {
+                    setAppSettings((prev) => ({
+                      ...prev,
+                      idleStopMinutes: prev.idleStopMinutes + 5,
+                    }));
+                    setIsDirty(true);
+                  }}
+                  className="px-3 py-2 text-slate-400 transition-colors select-none hover:text-white"
+                >
+                  +
+                </button>
+              </div>
+            </div>
+          )}
+          <p className="text-xs text-slate-500">
+            Frees GPU memory when nothing has run for a while. The local container starts again
+            for the next recording or import. Not used with a remote server or Docker inside WSL.
+          </p>
+        </div>
+        {appSettings.runtimeProfile === 'gpu' && (
+          <div className="mt-4 space-y-2">
+            <label className="block text-xs font-medium tracking-wider text-slate-500 uppercase">
+              Server replicas
+            </label>
+            <CustomSelect
+              value={String(appSettings.replicas)}
+              onChange={(v) => {
+                setAppSettings((prev) => ({ ...prev, replicas: Number(v) }));
+                setIsDirty(true);
+              }}
+              options={['1', '2', '3', '4']}
+              optionLabel={{ 1: 'One server', 2: '2 GPUs', 3: '3 GPUs', 4: '4 GPUs' }}
+              aria-label="Server replicas"
+            />
+            <p className="text-xs text-slate-500">
+              Runs one server per GPU on consecutive ports from the server port. Import jobs are
+              spread across them. Takes effect the next time the server starts.
+            </p>
+          </div>
+        )}
+        {platform === 'win32' && (
+          <div className="mt-4 space-y-2">
+            <label className="block text-xs font-medium tracking-wider text-slate-500 uppercase">
+              Container engine
+            </label>
+            <CustomSelect
+              value={appSettings.containerRuntime}
+              onChange={(v) => {
+                const containerRuntime = v === 'wsl' ? 'wsl' : 'auto';
+                const fallback = wslDistros.find((d) => d.isDefault)?.name ?? '';
+                setAppSettings((prev) => ({
+                  ...prev,
+                  containerRuntime,
+                  wslDistro: prev.wslDistro || fallback,
+                }));
+                setIsDirty(true);
+              }}
+              options={['auto', 'wsl']}
+              optionLabel={{ auto: 'Docker Desktop / Podman', wsl: 'Docker inside WSL' }}
+              optionMeta={{ wsl: { disabled: wslDistros.length === 0 } }}
+              aria-label="Container engine"
+            />
+            {appSettings.containerRuntime === 'wsl' && (
+              <>
+                <CustomSelect
+                  value={appSettings.wslDistro}
+                  onChange={(v) => {
+                    setAppSettings((prev) => ({ ...prev, wslDistro: v }));
+                    setIsDirty(true);
+                  }}
+                  options={wslDistros.map((d) => d.name)}
+                  optionMeta={Object.fromEntries(
+                    wslDistros.map((d) => [
+                      d.name,
+                      { badge: d.version === 2 ? d.state : 'WSL 1', disabled: d.version !== 2 },
+                    ]),
+                  )}
+                  placeholder="Select a distro"
+                  aria-label="WSL distro"
+                />
+                {wslProbe && (
+                  <p
+                    className={`text-xs ${wslProbe.available ? 'text-slate-500' : 'text-red-400'}`}
+                  >
+                    {wslProbe.available
+                      ? `Docker ${wslProbe.dockerVersion} found in ${appSettings.wslDistro}.`
+                      : wslProbe.reason}
+                  </p>
+                )}
+                <p className="text-xs text-slate-500">
+                  The server container runs with the Docker Engine installed in this distro. CUDA
+                  needs the NVIDIA Container Toolkit inside WSL; Vulkan and Metal profiles are not
+                  available here.
+                </p>
+              </>
+            )}
+          </div>
+        )}
       </Section>
       <Section title="Runtime Mode">
         <div className="space-y-3">
@@ -884,16 +1248,52 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
               </span>
             </button>
           )}
+          {/* AMD ROCm / Intel XPU — Linux only, shown when checkGpu() found
+              the vendor (or the profile is already selected). */}
+          {platform === 'linux' &&
+            (gpuInfo?.rocm ||
+              gpuInfo?.xpu ||
+              appSettings.runtimeProfile === 'rocm' ||
+              appSettings.runtimeProfile === 'xpu') && (
+              <div className="flex gap-3">
+                {(
+                  [
+                    ['rocm', 'ROCm', <AmdIcon key="amd" size={30} />],
+                    ['xpu', 'Intel XPU', <IntelIcon key="intel" size={30} />],
+                  ] as const
+                ).map(([profile, label, icon]) => (
+                  <button
+                    key={profile}
+                    onClick={() => {
+                      setAppSettings((prev) => ({ ...prev, runtimeProfile: profile }));
+                      setIsDirty(true);
+                    }}
+                    className={`flex flex-1 items-center justify-center gap-2 rounded-lg border px-4 py-3 text-sm font-medium transition-all ${
+                      appSettings.runtimeProfile === profile
+                        ? 'bg-accent-rose/15 border-accent-rose/40 text-accent-rose'
+                        : 'border-white/10 bg-white/5 text-slate-400 hover:bg-white/10'
+                    }`}
+                  >
+                    {icon}
+                    {label}
+                  </button>
+                ))}
+              </div>
+            )}
           <p className="text-xs text-slate-500 italic">
-            {appSettings.runtimeProfile === 'vulkan'
-              ? 'Vulkan mode: Uses whisper.cpp for AMD/Intel GPU acceleration. Requires a GGML model and /dev/dri access. No diarization or live mode.'
-              : appSettings.runtimeProfile === 'vulkan-wsl2'
-                ? 'Vulkan WSL2 (experimental): AMD/Intel GPU acceleration on Windows + Docker Desktop with WSL2 backend, via Mesa dzn (Vulkan-on-D3D12). Requires the locally-built sidecar image — see README §2.5 for build steps. May silently fall back to CPU rasterizer if dzn cannot enumerate /dev/dxg.'
-                : appSettings.runtimeProfile === 'cpu'
-                  ? 'CPU mode: No GPU required. Works on macOS, Linux, and Windows. Expect slower transcription speeds.'
-                  : appSettings.runtimeProfile === 'metal'
-                    ? 'Metal mode: Apple Silicon MLX acceleration. Recommended for M-series Macs running bare-metal.'
-                    : 'GPU mode: Requires NVIDIA GPU with CUDA. Recommended for Linux and Windows with supported hardware.'}
+            {appSettings.runtimeProfile === 'rocm'
+              ? 'ROCm mode: PyTorch on an AMD GPU using the ROCm image variant. Linux only; mounts /dev/kfd and /dev/dri into the container.'
+              : appSettings.runtimeProfile === 'xpu'
+                ? 'Intel XPU mode: PyTorch on an Intel Arc or Core Ultra GPU using the oneAPI image variant. Linux only; mounts /dev/dri into the container.'
+                : appSettings.runtimeProfile === 'vulkan'
+                  ? 'Vulkan mode: Uses whisper.cpp for AMD/Intel GPU acceleration. Requires a GGML model and /dev/dri access. No diarization or live mode.'
+                  : appSettings.runtimeProfile === 'vulkan-wsl2'
+                    ? 'Vulkan WSL2 (experimental): AMD/Intel GPU acceleration on Windows + Docker Desktop with WSL2 backend, via Mesa dzn (Vulkan-on-D3D12). Requires the locally-built sidecar image — see README §2.5 for build steps. May silently fall back to CPU rasterizer if dzn cannot enumerate /dev/dxg.'
+                    : appSettings.runtimeProfile === 'cpu'
+                      ? 'CPU mode: No GPU required. Works on macOS, Linux, and Windows. Expect slower transcription speeds.'
+                      : appSettings.runtimeProfile === 'metal'
+                        ? 'Metal mode: Apple Silicon MLX acceleration. Recommended for M-series Macs running bare-metal.'
+                        : 'GPU mode: Requires NVIDIA GPU with CUDA. Recommended for Linux and Windows with supported hardware.'}
           </p>
           {appSettings.runtimeProfile === 'metal' && adminStatus !== null && !metalSupported && (
             <p className="text-xs text-red-400">
@@ -966,7 +1366,34 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
           imports always ask.
         </p>
       </Section>
+      <Section title="Foot Pedal">
+        <FootPedalSettings />
+      </Section>
+      <Section title="Cache">
+        <CacheSettings />
+      </Section>
+      <Section title="Plugins">
+        <PluginSettings />
+      </Section>
+      <Section title="Script Hooks">
+        <HookSettings />
+      </Section>
+      <Section title="Uploads">
+        <UploadSettings />
+      </Section>
+      <Section title="Network Trace">
+        <NetworkTraceSettings />
+      </Section>
+      <Section title="Settings Transfer">
+        <SettingsTransferSettings
+          onImported={() => {
+            setIsDirty(false);
+            onClose();
+          }}
+        />
+      </Section>
       <Section title="Appearance">
+        <LanguageSettings />
         <AppleSwitch
           checked={appSettings.blurEffectsEnabled}
           onChange={(v) => {
@@ -1090,6 +1517,7 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
               Reset Defaults
             </Button>
           </div>
+          <InAppShortcutSettings />
         </div>
       </Section>
       <Section title="Update Checks">
@@ -1403,6 +1831,198 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
           label="Hide timestamps"
           description="Remove timestamps from transcript display and file output. Useful when feeding transcripts to LLMs."
         />
+        <AppleSwitch
+          checked={clientSettings.normalizeNumbers}
+          onChange={(v) => {
+            setClientSettings((prev) => ({ ...prev, normalizeNumbers: v }));
+            setIsDirty(true);
+          }}
+          label="Write numbers as digits"
+          description="Turn spelled-out numbers, dates, percentages and amounts into digits in saved session transcripts (English, Spanish and French)."
+        />
+      </Section>
+
+      <Section title="Output Filter">
+        <AppleSwitch
+          checked={clientSettings.outputFilterEnabled}
+          onChange={(v) => {
+            setClientSettings((prev) => ({ ...prev, outputFilterEnabled: v }));
+            setIsDirty(true);
+          }}
+          label="Filter exported and copied transcripts"
+          description="Mask profanity and apply your word replacements in exported files and clipboard copies. Stored transcripts are left unchanged."
+        />
+        {clientSettings.outputFilterEnabled && (
+          <div className="mt-4 space-y-4">
+            <AppleSwitch
+              checked={clientSettings.outputFilterBuiltInList}
+              onChange={(v) => {
+                setClientSettings((prev) => ({ ...prev, outputFilterBuiltInList: v }));
+                setIsDirty(true);
+              }}
+              label="Mask common profanity"
+              description="Built-in list of English swear words."
+            />
+            <div>
+              <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+                Masking style
+              </label>
+              <CustomSelect
+                value={MASK_STYLE_LABELS[clientSettings.outputFilterMaskStyle]}
+                onChange={(v) => {
+                  const style =
+                    MASK_STYLE_ORDER.find((m) => MASK_STYLE_LABELS[m] === v) ?? 'asterisks';
+                  setClientSettings((prev) => ({ ...prev, outputFilterMaskStyle: style }));
+                  setIsDirty(true);
+                }}
+                options={MASK_STYLE_ORDER.map((m) => MASK_STYLE_LABELS[m])}
+              />
+            </div>
+            <div>
+              <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+                More words to mask
+              </label>
+              <textarea
+                rows={3}
+                value={clientSettings.outputFilterWords}
+                onChange={(e) => {
+                  setClientSettings((prev) => ({ ...prev, outputFilterWords: e.target.value }));
+                  setIsDirty(true);
+                }}
+                placeholder={'heck\nfrick*'}
+                aria-label="Words to mask"
+                className="focus:border-accent-cyan/50 w-full resize-y rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white placeholder-slate-600 focus:outline-none"
+              />
+              <p className="mt-1 text-xs text-slate-500">
+                One word or phrase per line; end a word with * to match longer forms too.
+              </p>
+            </div>
+            <div>
+              <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+                Replacements
+              </label>
+              <textarea
+                rows={3}
+                value={clientSettings.outputFilterReplacements}
+                onChange={(e) => {
+                  setClientSettings((prev) => ({
+                    ...prev,
+                    outputFilterReplacements: e.target.value,
+                  }));
+                  setIsDirty(true);
+                }}
+                placeholder={'gonna => going to\nAcme Corp => the client'}
+                aria-label="Word replacements"
+                className="focus:border-accent-cyan/50 w-full resize-y rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white placeholder-slate-600 focus:outline-none"
+              />
+              <p className="mt-1 text-xs text-slate-500">
+                One <code className="text-slate-300">word =&gt; replacement</code> per line, applied
+                before masking.
+              </p>
+            </div>
+          </div>
+        )}
+      </Section>
+
+      <Section title="Caption Output">
+        <p className="mb-3 text-xs text-slate-500">
+          Mirror Live Mode captions to streaming software such as OBS. Both outputs show the same
+          text as the caption overlay: the last finished sentences plus the one being spoken.
+        </p>
+        <AppleSwitch
+          checked={clientSettings.captionFileEnabled}
+          onChange={(v) => {
+            setClientSettings((prev) => ({ ...prev, captionFileEnabled: v }));
+            setIsDirty(true);
+          }}
+          label="Write captions to a text file"
+          description='Point an OBS Text source with "Read from file" at this file.'
+        />
+        <div
+          className={`mt-3 mb-4 transition-opacity duration-200 ${clientSettings.captionFileEnabled ? 'opacity-100' : 'pointer-events-none opacity-50'}`}
+        >
+          <input
+            type="text"
+            value={clientSettings.captionFilePath}
+            onChange={(e) => {
+              setClientSettings((prev) => ({ ...prev, captionFilePath: e.target.value }));
+              setIsDirty(true);
+            }}
+            placeholder="/path/to/live-captions.txt"
+            className="focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white focus:outline-none"
+          />
+        </div>
+        <AppleSwitch
+          checked={clientSettings.obsCaptionsEnabled}
+          onChange={(v) => {
+            setClientSettings((prev) => ({ ...prev, obsCaptionsEnabled: v }));
+            setIsDirty(true);
+          }}
+          label="Send captions to OBS"
+          description="Sets the text of a Text source over obs-websocket (OBS 28+: Tools → WebSocket Server Settings). Reconnects automatically if OBS is restarted."
+        />
+        <div
+          className={`mt-3 space-y-3 transition-opacity duration-200 ${clientSettings.obsCaptionsEnabled ? 'opacity-100' : 'pointer-events-none opacity-50'}`}
+        >
+          <div>
+            <label className="mb-2 block text-sm font-medium text-slate-300">WebSocket URL</label>
+            <input
+              type="text"
+              value={clientSettings.obsUrl}
+              onChange={(e) => {
+                setClientSettings((prev) => ({ ...prev, obsUrl: e.target.value }));
+                setIsDirty(true);
+              }}
+              placeholder="ws://127.0.0.1:4455"
+              className="focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white focus:outline-none"
+            />
+          </div>
+          <div>
+            <label className="mb-2 block text-sm font-medium text-slate-300">Password</label>
+            <input
+              type="password"
+              value={clientSettings.obsPassword}
+              onChange={(e) => {
+                setClientSettings((prev) => ({ ...prev, obsPassword: e.target.value }));
+                setIsDirty(true);
+              }}
+              placeholder="Leave empty if authentication is off"
+              className="focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white focus:outline-none"
+            />
+          </div>
+          <div>
+            <label className="mb-2 block text-sm font-medium text-slate-300">Text source name</label>
+            <input
+              type="text"
+              value={clientSettings.obsInputName}
+              onChange={(e) => {
+                setClientSettings((prev) => ({ ...prev, obsInputName: e.target.value }));
+                setIsDirty(true);
+              }}
+              className="focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white focus:outline-none"
+            />
+          </div>
+        </div>
+      </Section>
+
+      <Section title="Keyword Alerts">
+        <p className="mb-3 text-xs text-slate-400">
+          Get a desktop notification when Live Mode hears one of these. One keyword or phrase per
+          line; case and accents are ignored. Wrap a line in slashes for a regular expression,
+          e.g. <code className="text-slate-300">/order #\d+/</code>. Clicking the notification
+          jumps to the sentence. Applies from the next Live Mode session.
+        </p>
+        <textarea
+          rows={4}
+          value={clientSettings.watchKeywords}
+          onChange={(e) => {
+            setClientSettings((prev) => ({ ...prev, watchKeywords: e.target.value }));
+            setIsDirty(true);
+          }}
+          placeholder={'action item\ndeadline\nAcme Corp'}
+          aria-label="Watch keywords"
+          className="focus:border-accent-cyan/50 w-full resize-y rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white placeholder-slate-600 focus:outline-none"
+        />
       </Section>
 
       <Section title="HuggingFace Token">
@@ -1459,6 +2079,10 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
         />
       </Section>
 
+      <Section title="Review Time">
+        <TimeTrackingSettings />
+      </Section>
+
       <Section title="Connection">
         <div className="space-y-4">
           <div className="grid grid-cols-2 gap-4">
@@ -1558,6 +2182,83 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
                   Enter a LAN host or IP before saving this profile.
                 </p>
               )}
+              <div className="mt-3">
+                <AppleSwitch
+                  checked={clientSettings.autoFailover}
+                  onChange={(v) => setClientSettings((prev) => ({ ...prev, autoFailover: v }))}
+                  label="Switch profiles when the link degrades"
+                  description="Move to the other remote profile when it answers and this one keeps failing"
+                  size="sm"
+                />
+              </div>
+              <div className="mt-3 space-y-3">
+                <AppleSwitch
+                  checked={clientSettings.powerAutoWake}
+                  onChange={(v) => setClientSettings((prev) => ({ ...prev, powerAutoWake: v }))}
+                  label="Wake the server for queued jobs"
+                  description="Send a Wake-on-LAN packet when the server does not answer"
+                  size="sm"
+                />
+                {clientSettings.powerAutoWake && (
+                  <div className="grid grid-cols-2 gap-3">
+                    <input
+                      type="text"
+                      placeholder="MAC address, e.g. 3c:7c:3f:12:34:56"
+                      value={clientSettings.powerMacAddress}
+                      onChange={(e) =>
+                        setClientSettings((prev) => ({ ...prev, powerMacAddress: e.target.value }))
+                      }
+                      aria-label="Server MAC address"
+                      className="focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white focus:outline-none"
+                    />
+                    <input
+                      type="text"
+                      placeholder="Broadcast address"
+                      value={clientSettings.powerBroadcastAddress}
+                      onChange={(e) =>
+                        setClientSettings((prev) => ({
+                          ...prev,
+                          powerBroadcastAddress: e.target.value,
+                        }))
+                      }
+                      aria-label="Wake-on-LAN broadcast address"
+                      className="focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white focus:outline-none"
+                    />
+                  </div>
+                )}
+                <AppleSwitch
+                  checked={clientSettings.powerSleepAfterJobs}
+                  onChange={(v) =>
+                    setClientSettings((prev) => ({ ...prev, powerSleepAfterJobs: v }))
+                  }
+                  label="Suspend the server when jobs finish"
+                  description="Over SSH with key authentication, and only after this app woke it"
+                  size="sm"
+                />
+                {clientSettings.powerSleepAfterJobs && (
+                  <div className="grid grid-cols-2 gap-3">
+                    <input
+                      type="text"
+                      placeholder="user@host"
+                      value={clientSettings.powerSshTarget}
+                      onChange={(e) =>
+                        setClientSettings((prev) => ({ ...prev, powerSshTarget: e.target.value }))
+                      }
+                      aria-label="SSH target"
+                      className="focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white focus:outline-none"
+                    />
+                    <input
+                      type="text"
+                      value={clientSettings.powerSshCommand}
+                      onChange={(e) =>
+                        setClientSettings((prev) => ({ ...prev, powerSshCommand: e.target.value }))
+                      }
+                      aria-label="Suspend command"
+                      className="focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white focus:outline-none"
+                    />
+                  </div>
+                )}
+              </div>
             </div>
           )}
 
@@ -1602,178 +2303,240 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
             </div>
           </div>
 
+          <div>
+            <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+              Your Name
+            </label>
+            <input
+              type="text"
+              value={clientSettings.userName}
+              maxLength={64}
+              placeholder="Optional — for servers shared by several people"
+              onChange={(e) => setClientSettings((prev) => ({ ...prev, userName: e.target.value }))}
+              className="focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white focus:outline-none"
+            />
+            <div className="mt-3">
+              <AppleSwitch
+                checked={clientSettings.showOnlyMine}
+                onChange={(v) => setClientSettings((prev) => ({ ...prev, showOnlyMine: v }))}
+                disabled={!clientSettings.userName.trim()}
+                label="Show only my items"
+                description="Hide other people's recordings and queued jobs; shared recordings stay visible"
+                size="sm"
+              />
+            </div>
+          </div>
+
           {/* Token Management Panel */}
-          <div className="mt-2">
-            <button
-              onClick={async () => {
-                setShowTokenPanel(!showTokenPanel);
-                if (!showTokenPanel && tokens.length === 0) {
-                  setTokensLoading(true);
-                  try {
-                    const res = await apiClient.listTokens();
-                    setTokens(res.tokens || []);
-                  } catch {
-                    /* server may not have TLS enabled */
+          {canManageTokens && (
+            <div className="mt-2">
+              <button
+                onClick={async () => {
+                  setShowTokenPanel(!showTokenPanel);
+                  if (!showTokenPanel && tokens.length === 0) {
+                    setTokensLoading(true);
+                    try {
+                      const res = await apiClient.listTokens();
+                      setTokens(res.tokens || []);
+                    } catch {
+                      /* server may not have TLS enabled */
+                    }
+                    setTokensLoading(false);
                   }
-                  setTokensLoading(false);
-                }
-              }}
-              className="flex items-center gap-2 text-xs text-slate-400 transition-colors hover:text-white"
-            >
-              <Shield size={12} />
-              <span>Manage Tokens</span>
-              <ChevronDown
-                size={12}
-                className={`transition-transform ${showTokenPanel ? 'rotate-180' : ''}`}
-              />
-            </button>
+                }}
+                className="flex items-center gap-2 text-xs text-slate-400 transition-colors hover:text-white"
+              >
+                <Shield size={12} />
+                <span>Manage Tokens</span>
+                <ChevronDown
+                  size={12}
+                  className={`transition-transform ${showTokenPanel ? 'rotate-180' : ''}`}
+                />
+              </button>
 
-            {showTokenPanel && (
-              <div className="mt-3 space-y-3 rounded-lg border border-white/10 bg-white/5 p-3">
-                {tokensLoading ? (
-                  <div className="flex items-center gap-2 text-sm text-slate-400">
-                    <Loader2 size={14} className="animate-spin" /> Loading tokens...
-                  </div>
-                ) : (
-                  <>
-                    {/* Existing tokens list */}
-                    {tokens.length > 0 ? (
-                      <div className="max-h-40 space-y-2 overflow-y-auto">
-                        {tokens
-                          .filter((t) => !t.is_revoked)
-                          .map((t) => (
-                            <div
-                              key={t.token_id}
-                              className="flex items-center gap-2 rounded bg-black/20 px-2 py-1.5 text-xs"
-                            >
-                              <span
-                                className={`h-2 w-2 rounded-full ${t.is_admin ? 'bg-amber-400' : 'bg-accent-cyan'}`}
-                              />
-                              <span className="flex-1 truncate text-white">{t.client_name}</span>
-                              <span className="font-mono text-slate-500">
-                                {t.token_id.slice(0, 8)}…
-                              </span>
-                              {t.is_admin && (
-                                <span className="text-[9px] font-bold text-amber-400 uppercase">
-                                  Admin
-                                </span>
-                              )}
-                              {t.expires_at && (
-                                <span className="text-[10px] text-slate-500">
-                                  {t.is_expired
-                                    ? 'Expired'
-                                    : `Expires ${new Date(t.expires_at).toLocaleDateString()}`}
-                                </span>
-                              )}
-                              <button
-                                onClick={async () => {
-                                  if (
-                                    !(await confirm(`Revoke token for "${t.client_name}"?`, {
-                                      danger: true,
-                                      confirmLabel: 'Revoke',
-                                    }))
-                                  )
-                                    return;
-                                  try {
-                                    await apiClient.revokeToken(t.token_id);
-                                    setTokens((prev) =>
-                                      prev.filter((tk) => tk.token_id !== t.token_id),
-                                    );
-                                  } catch {
-                                    toast.error('Failed to revoke token.');
-                                  }
-                                }}
-                                className="p-0.5 text-slate-500 transition-colors hover:text-red-400"
-                                title="Revoke"
+              {showTokenPanel && (
+                <div className="mt-3 space-y-3 rounded-lg border border-white/10 bg-white/5 p-3">
+                  {tokensLoading ? (
+                    <div className="flex items-center gap-2 text-sm text-slate-400">
+                      <Loader2 size={14} className="animate-spin" /> Loading tokens...
+                    </div>
+                  ) : (
+                    <>
+                      {/* Existing tokens list */}
+                      {tokens.length > 0 ? (
+                        <div className="max-h-40 space-y-2 overflow-y-auto">
+                          {tokens
+                            .filter((t) => !t.is_revoked)
+                            .map((t) => (
+                              <div
+                                key={t.token_id}
+                                className="flex items-center gap-2 rounded bg-black/20 px-2 py-1.5 text-xs"
                               >
-                                <Trash2 size={12} />
-                              </button>
-                            </div>
-                          ))}
-                      </div>
-                    ) : (
-                      <p className="text-xs text-slate-500">
-                        No active tokens. Enable TLS on the server to manage tokens.
-                      </p>
-                    )}
-
-                    {/* Created token display (shown once after creation) */}
-                    {createdTokenPlaintext && (
-                      <div className="rounded-lg border border-green-500/30 bg-green-500/10 p-3">
-                        <p className="mb-1 text-xs font-semibold text-green-400">
-                          New Token Created — Copy Now!
-                        </p>
-                        <div className="flex items-center gap-2">
-                          <code className="flex-1 rounded bg-black/30 px-2 py-1 font-mono text-xs break-all text-white select-all">
-                            {createdTokenPlaintext}
-                          </code>
-                          <button
-                            onClick={() => {
-                              writeToClipboard(createdTokenPlaintext).catch(() => {});
-                              setCopiedTokenId('new');
-                              setTimeout(() => setCopiedTokenId(null), 2000);
-                            }}
-                            className="p-1 text-green-400 transition-colors hover:text-white"
-                          >
-                            {copiedTokenId === 'new' ? <Check size={14} /> : <Copy size={14} />}
-                          </button>
+                                <span
+                                  className={`h-2 w-2 rounded-full ${t.is_admin ? 'bg-amber-400' : 'bg-accent-cyan'}`}
+                                />
+                                <span className="flex-1 truncate text-white">{t.client_name}</span>
+                                <span className="font-mono text-slate-500">
+                                  {t.token_id.slice(0, 8)}…
+                                </span>
+                                {t.is_admin && (
+                                  <span className="text-[9px] font-bold text-amber-400 uppercase">
+                                    Admin
+                                  </span>
+                                )}
+                                {t.expires_at && (
+                                  <span className="text-[10px] text-slate-500">
+                                    {t.is_expired
+                                      ? 'Expired'
+                                      : `Expires ${new Date(t.expires_at).toLocaleDateString()}`}
+                                  </span>
+                                )}
+                                <button
+                                  onClick={async () => {
+                                    if (
+                                      !(await confirm(
+                                        `Rotate token for "${t.client_name}"? The current token stops working immediately.`,
+                                        { danger: true, confirmLabel: 'Rotate' },
+                                      ))
+                                    )
+                                      return;
+                                    try {
+                                      const res = await apiClient.rotateToken(t.token_id);
+                                      setCreatedTokenPlaintext(res.token.token);
+                                      const list = await apiClient.listTokens();
+                                      setTokens(list.tokens || []);
+                                    } catch (err) {
+                                      toast.error(
+                                        `Failed to rotate token: ${err instanceof Error ? err.message : String(err)}`,
+                                      );
+                                    }
+                                  }}
+                                  className="hover:text-accent-cyan p-0.5 text-slate-500 transition-colors"
+                                  title="Rotate"
+                                >
+                                  <RotateCw size={12} />
+                                </button>
+                                <button
+                                  onClick={async () => {
+                                    if (
+                                      !(await confirm(`Revoke token for "${t.client_name}"?`, {
+                                        danger: true,
+                                        confirmLabel: 'Revoke',
+                                      }))
+                                    )
+                                      return;
+                                    try {
+                                      await apiClient.revokeToken(t.token_id);
+                                      setTokens((prev) =>
+                                        prev.filter((tk) => tk.token_id !== t.token_id),
+                                      );
+                                    } catch (err) {
+                                      toast.error(
+                                        `Failed to revoke token: ${err instanceof Error ? err.message : String(err)}`,
+                                      );
+                                    }
+                                  }}
+                                  className="p-0.5 text-slate-500 transition-colors hover:text-red-400"
+                                  title="Revoke"
+                                >
+                                  <Trash2 size={12} />
+                                </button>
+                              </div>
+                            ))}
                         </div>
-                        <p className="mt-1 text-[10px] text-slate-500">
-                          This token will not be shown again.
+                      ) : (
+                        <p className="text-xs text-slate-500">
+                          No active tokens. Enable TLS on the server to manage tokens.
                         </p>
-                      </div>
-                    )}
-
-                    {/* Create new token */}
-                    <div className="flex items-center gap-2">
-                      <input
-                        type="text"
-                        placeholder="Client name..."
-                        value={newTokenName}
-                        onChange={(e) => setNewTokenName(e.target.value)}
-                        className="focus:border-accent-cyan/50 flex-1 rounded border border-white/10 bg-black/20 px-2 py-1 text-xs text-white placeholder-slate-600 focus:outline-none"
-                      />
-                      <label className="flex items-center gap-1 text-[10px] whitespace-nowrap text-slate-400">
+                      )}
+
+                      {/* Created token display (shown once after creation) */}
+                      {createdTokenPlaintext && (
+                        <div className="rounded-lg border border-green-500/30 bg-green-500/10 p-3">
+                          <p className="mb-1 text-xs font-semibold text-green-400">
+                            New Token Created — Copy Now!
+                          </p>
+                          <div className="flex items-center gap-2">
+                            <code className="flex-1 rounded bg-black/30 px-2 py-1 font-mono text-xs break-all text-white select-all">
+                              {createdTokenPlaintext}
+                            </code>
+                            <button
+                              onClick={() => {
+                                writeToClipboard(createdTokenPlaintext).catch(() => {});
+                                setCopiedTokenId('new');
+                                setTimeout(() => setCopiedTokenId(null), 2000);
+                              }}
+                              className="p-1 text-green-400 transition-colors hover:text-white"
+                            >
+                              {copiedTokenId === 'new' ? <Check size={14} /> : <Copy size={14} />}
+                            </button>
+                          </div>
+                          <p className="mt-1 text-[10px] text-slate-500">
+                            This token will not be shown again.
+                          </p>
+                        </div>
+                      )}
+
+                      {/* Create new token */}
+                      <div className="flex items-center gap-2">
                         <input
-                          type="checkbox"
-                          checked={newTokenAdmin}
-                          onChange={(e) => setNewTokenAdmin(e.target.checked)}
-                          className="rounded"
+                          type="text"
+                          placeholder="Client name..."
+                          value={newTokenName}
+                          onChange={(e) => setNewTokenName(e.target.value)}
+                          className="focus:border-accent-cyan/50 flex-1 rounded border border-white/10 bg-black/20 px-2 py-1 text-xs text-white placeholder-slate-600 focus:outline-none"
                         />
-                        Admin
-                      </label>
-                      <button
-                        onClick={async () => {
-                          if (!newTokenName.trim()) return;
-                          try {
-                            const res = await apiClient.createToken({
-                              client_name: newTokenName.trim(),
-                              is_admin: newTokenAdmin,
-                            });
-                            if (res.token) {
-                              setCreatedTokenPlaintext(res.token.token);
-                              setNewTokenName('');
-                              setNewTokenAdmin(false);
-                              // Refresh list
-                              const list = await apiClient.listTokens();
-                              setTokens(list.tokens || []);
+                        <label className="flex items-center gap-1 text-[10px] whitespace-nowrap text-slate-400">
+                          <input
+                            type="checkbox"
+                            checked={newTokenAdmin}
+                            onChange={(e) => setNewTokenAdmin(e.target.checked)}
+                            className="rounded"
+                          />
+                          Admin
+                        </label>
+                        <button
+                          onClick={async () => {
+                            if (!newTokenName.trim()) return;
+                            try {
+                              const res = await apiClient.createToken({
+                                client_name: newTokenName.trim(),
+                                is_admin: newTokenAdmin,
+                              });
+                              if (res.token) {
+                                setCreatedTokenPlaintext(res.token.token);
+                                setNewTokenName('');
+                                setNewTokenAdmin(false);
+                                // Refresh list
+                                const list = await apiClient.listTokens();
+                                setTokens(list.tokens || []);
+                              }
+                            } catch {
+                              toast.error('Failed to create token.');
                             }
-                          } catch {
-                            toast.error('Failed to create token.');
-                          }
+                          }}
+                          disabled={!newTokenName.trim()}
+                          className="text-accent-cyan p-1 transition-colors hover:text-white disabled:text-slate-600"
+                          title="Create token"
+                        >
+                          <Plus size={14} />
+                        </button>
+                      </div>
+
+                      <ServerUsersPanel
+                        confirm={confirm}
+                        onChanged={async () => {
+                          const list = await apiClient.listTokens();
+                          setTokens(list.tokens || []);
                         }}
-                        disabled={!newTokenName.trim()}
-                        className="text-accent-cyan p-1 transition-colors hover:text-white disabled:text-slate-600"
-                        title="Create token"
-                      >
-                        <Plus size={14} />
-                      </button>
-                    </div>
-                  </>
-                )}
-              </div>
-            )}
-          </div>
+                      />
+                      <AuditLogPanel />
+                    </>
+                  )}
+                </div>
+              )}
+            </div>
+          )}
 
           <div className="grid grid-cols-2 items-end gap-4">
             <div>
@@ -1803,6 +2566,16 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
               HTTPS is required for remote profiles (Tailscale and LAN) to keep token auth enabled.
             </p>
           )}
+
+          <div className="my-2 h-px bg-white/5"></div>
+
+          <ConnectionTuningSettings
+            profile={tuningProfile}
+            value={connectionTuning[tuningProfile]}
+            onChange={(value) =>
+              setConnectionTuning((prev) => ({ ...prev, [tuningProfile]: value }))
+            }
+          />
         </div>
       </Section>
     </div>
@@ -1890,6 +2663,24 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
           </div>
         </div>
 
+        {canChangeServerSettings && (
+          <Section title="Scheduled Maintenance">
+            <HousekeepingSettings />
+          </Section>
+        )}
+
+        {window.electronAPI?.docker && (
+          <Section title="whisper.cpp Engine">
+            <WhispercppEngineSettings />
+          </Section>
+        )}
+
+        {can('can_manage_models') && (
+          <Section title="Benchmark">
+            <BenchmarkPanel activeProfile={tuningProfile} />
+          </Section>
+        )}
+
         <Section title="Outgoing Webhook">
           <p className="mb-3 text-xs text-slate-400">
             Send HTTP POST requests to an external URL when transcription events occur (live
@@ -1975,6 +2766,79 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
           </div>
         </Section>
 
+        <Section title="Post-Transcription Rules">
+          <p className="mb-3 text-xs text-slate-400">
+            Rules in <code className="text-slate-400">post_transcription_rules</code> (config.yaml)
+            export files and call webhooks when a notebook job completes. Dry-run them against a
+            Folder Watch folder and filename — nothing is written or sent.
+          </p>
+          <div className="space-y-3">
+            <input
+              type="text"
+              value={ruleTest.source}
+              onChange={(e) => setRuleTest((prev) => ({ ...prev, source: e.target.value }))}
+              placeholder="Watch folder (empty = manual upload)"
+              className="w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white placeholder:text-slate-600 focus:border-blue-500/50 focus:outline-none"
+            />
+            <input
+              type="text"
+              value={ruleTest.filename}
+              onChange={(e) => setRuleTest((prev) => ({ ...prev, filename: e.target.value }))}
+              placeholder="episode-12.mp3"
+              className="w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white placeholder:text-slate-600 focus:border-blue-500/50 focus:outline-none"
+            />
+            <Button
+              variant="secondary"
+              size="sm"
+              icon={
+                ruleTesting ? <Loader2 size={14} className="animate-spin" /> : <Send size={14} />
+              }
+              disabled={ruleTesting}
+              onClick={async () => {
+                setRuleTesting(true);
+                try {
+                  const res = await apiClient.testPostRules({
+                    source: ruleTest.source.trim() || undefined,
+                    filename: ruleTest.filename.trim() || undefined,
+                  });
+                  setRuleReport(res.rules);
+                } catch (e: unknown) {
+                  toast.error(e instanceof Error ? e.message : 'Rule test failed');
+                } finally {
+                  setRuleTesting(false);
+                }
+              }}
+            >
+              {ruleTesting ? 'Testing...' : 'Test Rules'}
+            </Button>
+            {ruleReport && ruleReport.length === 0 && (
+              <p className="text-xs text-slate-500">No rules configured.</p>
+            )}
+            {ruleReport?.map((r) => (
+              <div
+                key={r.rule}
+                className="rounded-lg border border-white/10 bg-black/20 p-2 text-xs"
+              >
+                <div className={r.matched ? 'text-green-400' : 'text-slate-400'}>
+                  {r.matched ? '✓' : '✗'} {r.rule}
+                </div>
+                {r.reasons.map((reason) => (
+                  <div key={reason} className="text-slate-500">
+                    {reason}
+                  </div>
+                ))}
+                {r.actions.map((a, i) => (
+                  <div key={i} className="font-mono text-slate-300">
+                    {a.type === 'webhook'
+                      ? `POST ${a.url}`
+                      : `${a.format} → ${a.path ?? 'unrouted'}${a.action === 'skip' ? ' (exists, skipped)' : ''}`}
+                  </div>
+                ))}
+              </div>
+            ))}
+          </div>
+        </Section>
+
         <ServerConfigEditor
           pendingUpdates={serverConfigUpdates}
           onFieldChange={handleServerConfigFieldChange}
@@ -2164,6 +3028,14 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
 
   const renderNotebookTab = () => (
     <div className="space-y-6">
+      <Section title="Voice Profiles">
+        <VoiceProfileSettings />
+      </Section>
+
+      <Section title="Project Templates">
+        <ProjectTemplateSettings />
+      </Section>
+
       <Section title="Database Backup">
         <p className="mb-4 text-xs text-slate-400">Manage local SQLite database backups.</p>
         <div className="mb-4 overflow-hidden rounded-lg border border-white/10 bg-black/30">
@@ -2232,6 +3104,10 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
         </div>
       </Section>
 
+      <Section title="Database Maintenance">
+        <DatabaseMaintenanceSettings />
+      </Section>
+
       <Section title="Database Restore">
         <div className="mb-4 flex items-start gap-3 rounded-lg border border-orange-500/20 bg-orange-500/10 p-4">
           <AlertTriangle size={20} className="shrink-0 text-orange-500" />
@@ -2384,7 +3260,7 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
         >
           {/* Header */}
           <div className="flex flex-none items-center justify-between border-b border-white/10 bg-white/5 px-6 py-4 select-none">
-            <h2 className="text-lg font-semibold text-white">Settings</h2>
+            <h2 className="text-lg font-semibold text-white">{t('settings-title')}</h2>
             <button onClick={onClose} className="text-slate-400 transition-colors hover:text-white">
               <X size={20} />
             </button>
@@ -2403,7 +3279,7 @@ export const SettingsModal: React.FC<SettingsModalProps> = ({ isOpen, onClose })
                 }`}
               >
                 {getIconForTab(tab)}
-                {tab}
+                {t(`settings-tab-${tab.toLowerCase()}`)}
               </button>
             ))}
           </div>
@@ -0,0 +1,91 @@
+import React, { useState } from 'react';
+import { toast } from 'sonner';
+import { Button } from '../ui/Button';
+import { apiClient } from '../../src/api/client';
+
+/** `old => new` per line; lines without an arrow are ignored. */
+export function parsePathRemaps(text: string): SettingsPathRemap[] {
+  return text
+    .split('\n')
+    .map((line) => line.split('=>').map((part) => part.trim()))
+    .filter((parts) => parts.length === 2 && parts[0] && parts[1])
+    .map(([from, to]) => ({ from, to }));
+}
+
+/**
+ * Settings transfer for Settings → App: export a signed bundle without
+ * secrets (electron/settingsBundle.ts) and import one from another machine.
+ * An import writes straight to the config store, so the modal closes
+ * through `onImported` instead of saving its now stale form over it.
+ */
+export const SettingsTransferSettings: React.FC<{ onImported: () => void }> = ({ onImported }) => {
+  const api = window.electronAPI?.settingsBundle;
+  const [remaps, setRemaps] = useState('');
+  const [busy, setBusy] = useState(false);
+
+  if (!api) return <p className="text-xs text-slate-500">Needs the desktop app.</p>;
+
+  const exportBundle = async () => {
+    const file = await api.exportBundle().catch((err: Error) => {
+      toast.error(`Could not export settings: ${err.message}`);
+      return null;
+    });
+    if (file) toast.success(`Settings saved to ${file}`);
+  };
+
+  const importBundle = async () => {
+    setBusy(true);
+    try {
+      const result = await api.importBundle(parsePathRemaps(remaps));
+      if (!result) return;
+      if (!result.ok) {
+        toast.error(`Could not import settings: ${result.error}`);
+        return;
+      }
+      const total = Object.values(result.counts).reduce((a, b) => a + b, 0);
+      toast.success(`Imported ${total} settings`);
+      if (result.disabledHooks > 0) {
+        toast.info(`${result.disabledHooks} script hooks were imported turned off`);
+      }
+      if (result.switchedOff.length > 0) {
+        toast.info(`Turned off until you review them: ${result.switchedOff.join(', ')}`);
+      }
+      if (result.missingPaths.length > 0) {
+        toast.warning(`Folders missing on this machine: ${result.missingPaths.join(', ')}`);
+      }
+      await apiClient.syncFromConfig().catch(() => {});
+      onImported();
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  return (
+    <div className="space-y-3">
+      <p className="text-xs text-slate-500">
+        Copies settings, connection profiles, word lists and script hooks to another machine.
+        Tokens and passwords are left out; folders in your home folder follow you to the new one.
+      </p>
+      <div>
+        <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+          Path remaps on import
+        </label>
+        <textarea
+          value={remaps}
+          onChange={(e) => setRemaps(e.target.value)}
+          rows={2}
+          placeholder={'D:\\Audio => /mnt/audio'}
+          className="w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 font-mono text-sm text-white placeholder:text-slate-600 focus:border-blue-500/50 focus:outline-none"
+        />
+      </div>
+      <div className="flex justify-end gap-2">
+        <Button variant="secondary" size="sm" disabled={busy} onClick={() => void importBundle()}>
+          Import Settings
+        </Button>
+        <Button variant="secondary" size="sm" onClick={() => void exportBundle()}>
+          Export Settings
+        </Button>
+      </div>
+    </div>
+  );
+};
@@ -0,0 +1,128 @@
+import React, { useCallback, useEffect, useState } from 'react';
+import { AlertTriangle, CheckCircle2, Circle, Loader2, X } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '../ui/Button';
+
+const STAGE_TITLE: Record<StartupStage, string> = {
+  backend: 'Server process',
+  health: 'Server responding',
+  model: 'Model loaded',
+  version: 'Version check',
+};
+
+function StageIcon({ status }: { status: StartupStageState['status'] }): React.ReactElement {
+  switch (status) {
+    case 'running':
+      return <Loader2 size={14} className="text-accent-cyan animate-spin" />;
+    case 'done':
+      return <CheckCircle2 size={14} className="text-emerald-400" />;
+    case 'warning':
+    case 'failed':
+      return (
+        <AlertTriangle
+          size={14}
+          className={status === 'failed' ? 'text-red-400' : 'text-amber-400'}
+        />
+      );
+    default:
+      return <Circle size={14} className="text-slate-600" />;
+  }
+}
+
+/**
+ * Startup progress panel, driven by the main-process startup sequencer.
+ * Shown while a local server is starting and when a start fails; a failure
+ * offers the diagnostic report for copying instead of an endless spinner.
+ */
+export function StartupProgress(): React.ReactElement | null {
+  const api = window.electronAPI?.startup;
+  const [state, setState] = useState<StartupState | null>(null);
+  const [dismissed, setDismissed] = useState(false);
+
+  useEffect(() => {
+    if (!api) return;
+    api
+      .getState()
+      .then(setState)
+      .catch(() => {});
+    return api.onStateChanged((next) => {
+      setState(next);
+      // A new run (or its outcome) brings a dismissed panel back.
+      if (next.status !== 'ready') setDismissed(false);
+      const warning = next.status === 'ready' && next.stages.find((s) => s.status === 'warning');
+      if (warning) toast.warning(warning.message);
+    });
+  }, [api]);
+
+  const handleCopy = useCallback(async () => {
+    const text = await api?.getReportText();
+    if (!text) return;
+    try {
+      await navigator.clipboard.writeText(text);
+      toast.success('Startup report copied');
+    } catch {
+      toast.error('Could not copy the report');
+    }
+  }, [api]);
+
+  if (!api || !state || dismissed) return null;
+  if (state.status !== 'running' && state.status !== 'failed') return null;
+
+  const failed = state.status === 'failed';
+  const current = state.stages.find((s) => s.status === 'running' || s.status === 'failed');
+
+  return (
+    <section
+      role="status"
+      aria-live="polite"
+      aria-labelledby="startup-progress-title"
+      className="fixed bottom-4 left-4 z-50 w-80 overflow-hidden rounded-xl border border-white/10 bg-black/70 shadow-2xl backdrop-blur-xl"
+    >
+      <div className="flex items-center gap-2 px-4 py-3">
+        <h3 id="startup-progress-title" className="m-0 flex-1 text-sm font-semibold text-white">
+          {failed ? 'Server failed to start' : 'Starting server…'}
+        </h3>
+        <span className="font-mono text-xs text-slate-500">{state.percent}%</span>
+        <button
+          type="button"
+          aria-label="Hide"
+          onClick={() => setDismissed(true)}
+          className="text-slate-500 hover:text-white"
+        >
+          <X size={14} />
+        </button>
+      </div>
+      <div className="h-1 bg-white/10">
+        <div
+          className={`h-full transition-all duration-300 ${failed ? 'bg-red-500' : 'bg-accent-cyan'}`}
+          style={{ width: `${failed ? 100 : state.percent}%` }}
+        />
+      </div>
+      <ul className="m-0 list-none space-y-1.5 px-4 py-3">
+        {state.stages.map((stage) => (
+          <li key={stage.stage} className="flex items-center gap-2 text-xs text-slate-300">
+            <StageIcon status={stage.status} />
+            <span>{STAGE_TITLE[stage.stage]}</span>
+          </li>
+        ))}
+      </ul>
+      {current?.message && (
+        <p
+          className={`m-0 px-4 pb-3 text-xs ${failed ? 'text-red-200' : 'truncate text-slate-500'}`}
+        >
+          {current.message}
+        </p>
+      )}
+      {failed && (
+        <div className="flex gap-2 px-4 pb-3">
+          <Button size="sm" variant="secondary" onClick={() => void handleCopy()}>
+            Copy report
+          </Button>
+          <Button size="sm" variant="ghost" onClick={() => void api.attach()}>
+            Check again
+          </Button>
+        </div>
+      )}
+    </section>
+  );
+}
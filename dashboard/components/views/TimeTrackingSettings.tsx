@@ -0,0 +1,178 @@
+import React, { useCallback, useEffect, useState } from 'react';
+import { Download } from 'lucide-react';
+import { toast } from 'sonner';
+import { AppleSwitch } from '../ui/AppleSwitch';
+import { Button } from '../ui/Button';
+import { CustomSelect } from '../ui/CustomSelect';
+import { getConfig, setConfig } from '../../src/config/store';
+
+const PERIODS = ['This month', 'Last month', 'All time'] as const;
+type Period = (typeof PERIODS)[number];
+
+function periodRange(period: Period, now = new Date()): TimeTrackingRange {
+  if (period === 'All time') return {};
+  const offset = period === 'Last month' ? -1 : 0;
+  return {
+    from: new Date(now.getFullYear(), now.getMonth() + offset, 1).getTime(),
+    to: new Date(now.getFullYear(), now.getMonth() + offset + 1, 1).getTime(),
+  };
+}
+
+const formatHours = (ms: number) => `${(ms / 3_600_000).toFixed(2)} h`;
+const formatMinutes = (seconds: number) => `${Math.round(seconds / 60)} min`;
+
+/**
+ * Review time tracking for Settings → Client. Values are saved immediately
+ * — they are not part of the modal's Save flow. The totals and the CSV come
+ * from the main process (electron/timeTracking.ts) and never leave this
+ * machine unless exported.
+ */
+export const TimeTrackingSettings: React.FC = () => {
+  const [enabled, setEnabled] = useState(false);
+  const [projectField, setProjectField] = useState('Client');
+  const [idleMinutes, setIdleMinutes] = useState(2);
+  const [period, setPeriod] = useState<Period>('This month');
+  const [totals, setTotals] = useState<ProjectTimeTotals[]>([]);
+  const api = window.electronAPI?.timeTracking;
+
+  useEffect(() => {
+    getConfig<boolean>('timeTracking.enabled')
+      .then((v) => setEnabled(v === true))
+      .catch(() => {});
+    getConfig<string>('timeTracking.projectField')
+      .then((v) => typeof v === 'string' && setProjectField(v))
+      .catch(() => {});
+    getConfig<number>('timeTracking.idleMinutes')
+      .then((v) => typeof v === 'number' && setIdleMinutes(v))
+      .catch(() => {});
+  }, []);
+
+  useEffect(() => {
+    if (!api) return;
+    let cancelled = false;
+    api
+      .projectTotals(periodRange(period))
+      .then((rows) => !cancelled && setTotals(rows))
+      .catch(() => {});
+    return () => {
+      cancelled = true;
+    };
+  }, [api, period]);
+
+  const toggle = (on: boolean) => {
+    setEnabled(on);
+    void setConfig('timeTracking.enabled', on);
+  };
+
+  const saveIdle = (value: number) => {
+    const next = Math.min(60, Math.max(1, Math.round(value) || 1));
+    setIdleMinutes(next);
+    void setConfig('timeTracking.idleMinutes', next);
+  };
+
+  const exportCsv = useCallback(
+    async (perRecording: boolean) => {
+      try {
+        const saved = await api?.exportCsv(periodRange(period), perRecording);
+        if (saved) toast.success('Time report exported', { description: saved });
+      } catch (err) {
+        toast.error('Could not export the time report', {
+          description: err instanceof Error ? err.message : undefined,
+        });
+      }
+    },
+    [api, period],
+  );
+
+  if (!api) return null;
+
+  const inputClass =
+    'focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white focus:outline-none';
+
+  return (
+    <div className="space-y-3">
+      <AppleSwitch
+        checked={enabled}
+        onChange={toggle}
+        label="Track review time"
+        description="Counts the time a recording is open while you play, edit or review it. Time stops after the idle limit without keyboard or mouse input."
+      />
+      <div className="grid grid-cols-2 gap-4">
+        <div>
+          <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+            Project Field
+          </label>
+          <input
+            type="text"
+            value={projectField}
+            placeholder="Client"
+            onChange={(e) => setProjectField(e.target.value)}
+            onBlur={(e) => void setConfig('timeTracking.projectField', e.target.value.trim())}
+            className={inputClass}
+          />
+        </div>
+        <div>
+          <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+            Idle Limit (minutes)
+          </label>
+          <input
+            type="number"
+            min={1}
+            max={60}
+            value={idleMinutes}
+            onChange={(e) => setIdleMinutes(parseInt(e.target.value, 10))}
+            onBlur={(e) => saveIdle(parseInt(e.target.value, 10))}
+            className={inputClass}
+          />
+        </div>
+      </div>
+      <div className="flex items-center justify-between gap-3">
+        <CustomSelect
+          value={period}
+          onChange={(v) => setPeriod(v as Period)}
+          options={[...PERIODS]}
+          aria-label="Report period"
+          className="focus:ring-accent-cyan h-8 min-w-32 rounded-lg border border-white/10 bg-white/5 px-2 py-1 text-sm text-slate-300 outline-none focus:ring-1"
+        />
+        <div className="flex gap-2">
+          <Button
+            variant="secondary"
+            size="sm"
+            icon={<Download size={14} />}
+            onClick={() => void exportCsv(false)}
+            disabled={totals.length === 0}
+          >
+            Export CSV
+          </Button>
+          <Button
+            variant="ghost"
+            size="sm"
+            onClick={() => void exportCsv(true)}
+            disabled={totals.length === 0}
+          >
+            Per recording
+          </Button>
+        </div>
+      </div>
+      {totals.length > 0 ? (
+        <ul className="space-y-1 text-xs text-slate-400">
+          {totals.map((t) => (
+            <li key={t.project} className="flex justify-between gap-3">
+              <span className="truncate">{t.project || '(No project)'}</span>
+              <span className="font-mono whitespace-nowrap">
+                {formatHours(t.activeMs)} · {formatMinutes(t.audioSeconds)} audio ·{' '}
+                {t.recordings} {t.recordings === 1 ? 'recording' : 'recordings'}
+              </span>
+            </li>
+          ))}
+        </ul>
+      ) : (
+        <p className="text-xs text-slate-500">No tracked time in this period.</p>
+      )}
+      <p className="text-xs text-slate-500">
+        The project is the recording&apos;s &ldquo;{projectField || '—'}&rdquo; field. Changing it
+        applies to recordings opened from now on.
+      </p>
+    </div>
+  );
+};
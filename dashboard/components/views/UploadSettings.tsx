@@ -0,0 +1,78 @@
+import React, { useEffect, useState } from 'react';
+import { getConfig, setConfig } from '../../src/config/store';
+import { DEFAULT_PARALLEL_STREAMS } from '../../src/services/uploadPipeline';
+
+const MAX_STREAMS = 8;
+
+/**
+ * Batch upload settings for Settings → App. Values are saved on blur — they
+ * are not part of the modal's Save flow — and apply from the next batch.
+ */
+export const UploadSettings: React.FC = () => {
+  const [streams, setStreams] = useState(DEFAULT_PARALLEL_STREAMS);
+  const [limit, setLimit] = useState(0);
+
+  useEffect(() => {
+    getConfig<number>('upload.parallelStreams')
+      .then((v) => typeof v === 'number' && setStreams(v))
+      .catch(() => {});
+    getConfig<number>('upload.bandwidthLimitMbps')
+      .then((v) => typeof v === 'number' && setLimit(v))
+      .catch(() => {});
+  }, []);
+
+  const saveStreams = (value: number) => {
+    const next = Math.min(MAX_STREAMS, Math.max(1, Math.round(value) || 1));
+    setStreams(next);
+    void setConfig('upload.parallelStreams', next);
+  };
+
+  const saveLimit = (value: number) => {
+    const next = Number.isFinite(value) && value > 0 ? value : 0;
+    setLimit(next);
+    void setConfig('upload.bandwidthLimitMbps', next);
+  };
+
+  const inputClass =
+    'focus:border-accent-cyan/50 w-full rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white focus:outline-none';
+
+  return (
+    <div className="space-y-3">
+      <div className="grid grid-cols-2 gap-4">
+        <div>
+          <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+            Parallel Uploads
+          </label>
+          <input
+            type="number"
+            min={1}
+            max={MAX_STREAMS}
+            value={streams}
+            onChange={(e) => setStreams(parseInt(e.target.value, 10))}
+            onBlur={(e) => saveStreams(parseInt(e.target.value, 10))}
+            className={inputClass}
+          />
+        </div>
+        <div>
+          <label className="mb-1.5 block text-xs font-medium tracking-wider text-slate-500 uppercase">
+            Bandwidth Limit (Mbit/s)
+          </label>
+          <input
+            type="number"
+            min={0}
+            step="any"
+            value={limit}
+            onChange={(e) => setLimit(parseFloat(e.target.value))}
+            onBlur={(e) => saveLimit(parseFloat(e.target.value))}
+            className={inputClass}
+          />
+        </div>
+      </div>
+      <p className="text-xs text-slate-500">
+        Import batches upload the next files while the current one transcribes. The limit is shared
+        by all uploads; 0 means unlimited. With 1 parallel upload, each file is sent when its turn
+        comes.
+      </p>
+    </div>
+  );
+};
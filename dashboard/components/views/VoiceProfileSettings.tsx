@@ -0,0 +1,196 @@
+import React, { useCallback, useEffect, useRef, useState } from 'react';
+import { Mic, Square, Trash2, Upload } from 'lucide-react';
+import { Button } from '../ui/Button';
+import { apiClient } from '../../src/api/client';
+import type { VoiceProfile } from '../../src/api/types';
+
+/** Long enough for a stable embedding; the server needs at least 3 s. */
+const SAMPLE_SECONDS = 10;
+
+/**
+ * Voice-profile enrollment for Settings → Notebook. Each sample is sent to
+ * the server as soon as it is recorded or picked — profiles are not part of
+ * the modal's Save flow. Enrolling an existing name adds a sample to it.
+ */
+export const VoiceProfileSettings: React.FC = () => {
+  const [profiles, setProfiles] = useState<VoiceProfile[]>([]);
+  const [enabled, setEnabled] = useState<boolean | null>(null);
+  const [name, setName] = useState('');
+  const [recording, setRecording] = useState(false);
+  const [busy, setBusy] = useState(false);
+  const [message, setMessage] = useState<string | null>(null);
+  const recorderRef = useRef<MediaRecorder | null>(null);
+  const fileInputRef = useRef<HTMLInputElement>(null);
+
+  const refresh = useCallback(async () => {
+    try {
+      const resp = await apiClient.getVoiceProfiles();
+      setEnabled(resp.enabled);
+      setProfiles(resp.profiles);
+    } catch {
+      setEnabled(null);
+      setMessage('Could not load voice profiles — is the server running?');
+    }
+  }, []);
+
+  useEffect(() => {
+    void refresh();
+    return () => {
+      // Closing Settings mid-recording discards the sample.
+      const recorder = recorderRef.current;
+      if (!recorder) return;
+      recorder.onstop = null;
+      recorder.stop();
+      recorder.stream.getTracks().forEach((t) => t.stop());
+    };
+  }, [refresh]);
+
+  const enroll = async (sample: Blob, filename: string) => {
+    const who = name.trim();
+    setBusy(true);
+    setMessage(null);
+    try {
+      const profile = await apiClient.enrollVoiceSample(who, sample, filename);
+      setMessage(
+        profile.sample_count === 1
+          ? `Enrolled ${profile.name}`
+          : `Added sample ${profile.sample_count} to ${profile.name}`,
+      );
+      await refresh();
+    } catch (err) {
+      setMessage(`Enrollment failed: ${err instanceof Error ? err.message : 'unknown error'}`);
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const record = async () => {
+    let stream: MediaStream;
+    try {
+      stream = await navigator.mediaDevices.getUserMedia({ audio: true });
+    } catch {
+      setMessage('Microphone access was denied');
+      return;
+    }
+    const recorder = new MediaRecorder(stream);
+    const chunks: Blob[] = [];
+    const timer = window.setTimeout(() => recorder.stop(), SAMPLE_SECONDS * 1000);
+    recorder.ondataavailable = (e) => chunks.push(e.data);
+    recorder.onstop = () => {
+      window.clearTimeout(timer);
+      stream.getTracks().forEach((t) => t.stop());
+      recorderRef.current = null;
+      setRecording(false);
+      void enroll(new Blob(chunks, { type: recorder.mimeType }), 'sample.webm');
+    };
+    recorderRef.current = recorder;
+    recorder.start();
+    setRecording(true);
+    setMessage(`Recording — speak naturally for up to ${SAMPLE_SECONDS} seconds…`);
+  };
+
+  const remove = async (profile: VoiceProfile) => {
+    try {
+      await apiClient.deleteVoiceProfile(profile.id);
+      await refresh();
+    } catch {
+      setMessage(`Could not delete ${profile.name}`);
+    }
+  };
+
+  if (enabled === false) {
+    return (
+      <p className="text-xs text-slate-500">
+        Voice profiles need a speaker-embedding model. Set{' '}
+        <code className="text-slate-400">speaker_identification.model_path</code> in the server
+        config (Server tab) to an ONNX speaker model and restart the server.
+      </p>
+    );
+  }
+
+  const canEnroll = enabled === true && name.trim() !== '' && !busy && !recording;
+
+  return (
+    <div className="space-y-3">
+      <div className="flex items-end gap-2">
+        <input
+          type="text"
+          value={name}
+          onChange={(e) => setName(e.target.value)}
+          placeholder="Person's name"
+          aria-label="Name to enroll"
+          className="focus:border-accent-cyan/50 min-w-0 flex-1 rounded-lg border border-white/10 bg-black/20 px-3 py-2 text-sm text-white focus:outline-none"
+        />
+        {recording ? (
+          <Button
+            variant="secondary"
+            icon={<Square size={14} />}
+            onClick={() => recorderRef.current?.stop()}
+          >
+            Stop
+          </Button>
+        ) : (
+          <Button
+            variant="secondary"
+            icon={<Mic size={14} />}
+            disabled={!canEnroll}
+            onClick={() => void record()}
+          >
+            Record sample
+          </Button>
+        )}
+        <Button
+          variant="ghost"
+          icon={<Upload size={14} />}
+          disabled={!canEnroll}
+          onClick={() => fileInputRef.current?.click()}
+        >
+          Upload
+        </Button>
+        <input
+          ref={fileInputRef}
+          type="file"
+          accept="audio/*"
+          className="hidden"
+          onChange={(e) => {
+            const file = e.target.files?.[0];
+            e.target.value = '';
+            if (file) void enroll(file, file.name);
+          }}
+        />
+      </div>
+      {message && <p className="text-xs text-slate-400">{message}</p>}
+      {profiles.length > 0 && (
+        <ul className="space-y-1">
+          {profiles.map((p) => (
+            <li
+              key={p.id}
+              className="flex items-center justify-between rounded-lg bg-white/5 px-3 py-1.5 text-sm text-slate-300"
+            >
+              <span>
+                {p.name}{' '}
+                <span className="text-xs text-slate-500">
+                  · {p.sample_count} sample{p.sample_count === 1 ? '' : 's'}
+                </span>
+              </span>
+              <Button
+                variant="ghost"
+                size="sm"
+                icon={<Trash2 size={12} />}
+                onClick={() => void remove(p)}
+                aria-label={`Delete voice profile "${p.name}"`}
+              >
+                Delete
+              </Button>
+            </li>
+          ))}
+        </ul>
+      )}
+      <p className="text-xs text-slate-500">
+        Record each person speaking alone, in a quiet room. After every diarized transcription,
+        speakers whose voice matches a profile are named automatically — names you set yourself
+        are never replaced. Adding samples from different microphones improves matching.
+      </p>
+    </div>
+  );
+};
@@ -0,0 +1,177 @@
+import React, { useEffect, useState } from 'react';
+import { Gauge } from 'lucide-react';
+import { AppleSwitch } from '../ui/AppleSwitch';
+import { Button } from '../ui/Button';
+import { useConfirm } from '../../src/hooks/useConfirm';
+
+function errorText(err: unknown): string {
+  const message = err instanceof Error ? err.message : String(err);
+  // ipcRenderer.invoke wraps main-process errors in a generic prefix.
+  return message.replace(/^Error invoking remote method '[^']+': (Error: )?/, '');
+}
+
+function describeRun(run: WhispercppTuning): string {
+  const threads = run.threads > 0 ? `${run.threads} threads` : 'default threads';
+  const cores = run.cores === 'performance' ? ' · P-cores' : '';
+  return `${threads}${cores}${run.flashAttention ? ' · flash attn' : ''}`;
+}
+
+const sameSettings = (a: WhispercppTuning, b: WhispercppTuning) =>
+  a.threads === b.threads && a.flashAttention === b.flashAttention && a.cores === b.cores;
+
+/**
+ * whisper.cpp engine tuning for Settings → Server (Vulkan profiles only).
+ * Settings are per machine and saved as they are made; they take effect the
+ * next time the server starts. The benchmark restarts the engine once per
+ * candidate and keeps the fastest (electron/whispercppTuning.ts).
+ */
+export const WhispercppEngineSettings: React.FC = () => {
+  const docker = window.electronAPI?.docker;
+  const [state, setState] = useState<WhispercppTuningState | null>(null);
+  const [progress, setProgress] = useState<{ done: number; total: number } | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const { confirm, dialog } = useConfirm();
+
+  useEffect(() => {
+    docker
+      ?.getWhispercppTuning()
+      .then(setState)
+      .catch(() => {});
+  }, [docker]);
+
+  if (!docker || !state) return null;
+  if (!state.available) {
+    return (
+      <p className="text-xs text-slate-500">
+        The whisper.cpp engine is used by the Vulkan runtime profiles. Switch to one in the Server
+        view to tune it.
+      </p>
+    );
+  }
+
+  const { tuning, topology } = state;
+  const hybrid = topology.performance.length > 0 && topology.efficiency.length > 0;
+
+  const update = (patch: Partial<WhispercppTuning>) => {
+    const next = { ...tuning, ...patch };
+    setState({ ...state, tuning: next });
+    docker
+      .setWhispercppTuning(next)
+      .then((saved) => setState((s) => (s ? { ...s, tuning: saved } : s)))
+      .catch((err) => setError(errorText(err)));
+  };
+
+  const benchmark = async () => {
+    if (
+      !(await confirm(
+        'Run the benchmark? It restarts the transcription engine several times over a few minutes — wait for running transcriptions to finish first.',
+        { confirmLabel: 'Run benchmark' },
+      ))
+    )
+      return;
+    setError(null);
+    setProgress({ done: 0, total: 0 });
+    const unsubscribe = docker.onWhispercppBenchmarkProgress(setProgress);
+    try {
+      const saved = await docker.benchmarkWhispercpp();
+      setState((s) => (s ? { ...s, tuning: saved } : s));
+    } catch (err) {
+      setError(errorText(err));
+    } finally {
+      unsubscribe();
+      setProgress(null);
+    }
+  };
+
+  const measured = tuning.benchmark;
+  const runs = measured?.runs ?? [];
+  const fastest = Math.min(...runs.map((r) => r.seconds ?? Infinity));
+
+  return (
+    <div className="space-y-3">
+      <div className="flex items-center gap-2 text-xs text-slate-400">
+        <span>Threads</span>
+        <input
+          type="number"
+          min={0}
+          max={topology.logical}
+          value={tuning.threads}
+          onChange={(e) => {
+            const threads = Math.max(0, Math.floor(Number(e.target.value)) || 0);
+            update({ threads: Math.min(topology.logical, threads) });
+          }}
+          aria-label="whisper.cpp threads"
+          className="focus:border-accent-cyan/50 w-16 rounded border border-white/10 bg-black/20 px-2 py-1 text-white focus:outline-none"
+        />
+        <span>0 = engine default · {topology.logical} logical CPUs</span>
+      </div>
+      <AppleSwitch
+        checked={tuning.flashAttention}
+        onChange={(flashAttention) => update({ flashAttention })}
+        label="Flash attention"
+        description="Faster and lighter on most GPUs; turn off if transcripts come out garbled"
+        size="sm"
+      />
+      <AppleSwitch
+        checked={tuning.cores === 'performance'}
+        onChange={(on) => update({ cores: on ? 'performance' : 'all' })}
+        disabled={!hybrid}
+        label="Performance cores only"
+        description={
+          hybrid
+            ? `Keep the engine on the ${topology.performance.length} performance threads, off the ${topology.efficiency.length} efficiency ones`
+            : 'Needs a hybrid CPU (big/little cores) on Linux'
+        }
+        size="sm"
+      />
+      <p className="text-xs text-slate-500">
+        Applies the next time the server starts. Quantization comes with the model — pick a q5 or
+        q8 GGML model in the Server view for lower memory use.
+      </p>
+
+      <div className="flex items-center justify-between gap-2">
+        <span className="text-xs text-slate-500">
+          {measured
+            ? `Benchmarked ${new Date(measured.measuredAt).toLocaleDateString()}` +
+              (measured.model ? ` with ${measured.model.split('/').pop()}` : '')
+            : 'Not benchmarked on this machine yet'}
+        </span>
+        <Button
+          variant="secondary"
+          size="sm"
+          icon={<Gauge size={14} />}
+          onClick={() => void benchmark()}
+          disabled={progress !== null}
+        >
+          {progress
+            ? `Benchmarking${progress.total ? ` ${progress.done}/${progress.total}` : ''}…`
+            : measured
+              ? 'Run again'
+              : 'Run benchmark'}
+        </Button>
+      </div>
+      {runs.length > 0 && (
+        <ul className="space-y-1">
+          {runs.map((run, i) => (
+            <li
+              key={i}
+              className={`flex justify-between rounded bg-black/20 px-2 py-1 text-xs ${
+                run.seconds === fastest ? 'text-accent-cyan' : 'text-slate-400'
+              }`}
+            >
+              <span>
+                {describeRun(run)}
+                {sameSettings(run, tuning) && ' (in use)'}
+              </span>
+              <span title={run.error}>
+                {run.seconds === null ? 'failed' : `${run.seconds.toFixed(2)} s`}
+              </span>
+            </li>
+          ))}
+        </ul>
+      )}
+      {error && <p className="text-xs text-red-400">{error}</p>}
+      {dialog}
+    </div>
+  );
+};
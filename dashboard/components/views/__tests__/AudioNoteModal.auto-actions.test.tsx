@@ -56,6 +56,54 @@ vi.mock('../../../src/hooks/useRecordingAliases', () => ({
   }),
 }));
 
+vi.mock('../../../src/hooks/useRecordingTimecode', () => ({
+  useRecordingTimecode: () => ({
+    timecode: null,
+    loading: false,
+    error: null,
+    setTimecode: vi.fn().mockResolvedValue(null),
+  }),
+}));
+vi.mock('../../../src/hooks/useLowConfidenceRegions', () => ({
+  useLowConfidenceRegions: () => ({ regions: [], coverage: 0, loading: false, refresh: vi.fn() }),
+}));
+vi.mock('../../../src/hooks/useAnnotations', () => ({
+  useAnnotations: () => ({
+    threads: [],
+    loading: false,
+    add: vi.fn().mockResolvedValue(undefined),
+    edit: vi.fn().mockResolvedValue(undefined),
+    remove: vi.fn().mockResolvedValue(undefined),
+    refresh: vi.fn().mockResolvedValue(undefined),
+  }),
+}));
+vi.mock('../../../src/hooks/useRedactions', () => ({
+  useRedactions: () => ({
+    ranges: [],
+    loading: false,
+    add: vi.fn().mockResolvedValue(undefined),
+    remove: vi.fn().mockResolvedValue(undefined),
+    scanPii: vi.fn().mockResolvedValue(0),
+    refresh: vi.fn().mockResolvedValue(undefined),
+  }),
+}));
+vi.mock('../../../src/hooks/useOutline', () => ({
+  chapterIndexAt: () => -1,
+  useOutline: () => ({ chapters: [], loading: false, rebuild: vi.fn().mockResolvedValue(0) }),
+}));
+vi.mock('../../../src/hooks/useSpeakerStats', () => ({
+  formatDuration: () => '0:00',
+  useSpeakerStats: () => null,
+}));
+vi.mock('../../../src/hooks/useSegmentReview', () => ({
+  useSegmentReview: () => ({
+    states: new Map(),
+    progress: { total: 0, approved: 0, flagged: 0, unreviewed: 0, reviewed_fraction: 0 },
+    loading: false,
+    setState: vi.fn().mockResolvedValue(undefined),
+  }),
+}));
+
 vi.mock('../../../src/hooks/useWordHighlighter', () => ({
   useWordHighlighter: () => ({ activeWordIndex: -1, registerWord: vi.fn(), scrollTo: vi.fn() }),
 }));
@@ -106,6 +154,7 @@ vi.mock('../../../src/api/client', () => ({
     updateRecordingSummary: vi.fn().mockResolvedValue(undefined),
     getExportUrl: vi.fn().mockReturnValue('http://localhost/export'),
     retryAutoAction: vi.fn().mockResolvedValue({ status: 'retry_initiated' }),
+    listShareTargets: vi.fn().mockResolvedValue({ targets: [] }),
   },
 }));
 
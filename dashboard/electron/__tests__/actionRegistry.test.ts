@@ -0,0 +1,84 @@
+// @vitest-environment node
+
+import { describe, it, expect, vi } from 'vitest';
+
+import { ActionRegistry } from '../actionRegistry.js';
+
+const registry = () =>
+  new ActionRegistry({ accelerator: (id) => (id === 'start-recording' ? 'Alt+Ctrl+Z' : null) });
+
+describe('ActionRegistry', () => {
+  it('lists actions with their accelerator and enablement', () => {
+    const actions = registry();
+    let recording = false;
+    actions.register(
+      {
+        id: 'recording.start',
+        title: 'Start recording',
+        category: 'Recording',
+        shortcut: 'start-recording',
+        enabled: () => (recording ? 'Already recording' : true),
+        run: () => {},
+      },
+      { id: 'settings.open', title: 'Open settings', category: 'App', run: () => {} },
+    );
+
+    expect(actions.list()).toEqual([
+      {
+        id: 'recording.start',
+        title: 'Start recording',
+        category: 'Recording',
+        keywords: [],
+        accelerator: 'Alt+Ctrl+Z',
+        enabled: true,
+        disabledReason: null,
+      },
+      expect.objectContaining({ id: 'settings.open', accelerator: null, enabled: true }),
+    ]);
+
+    recording = true;
+    expect(actions.list()[0]).toMatchObject({
+      enabled: false,
+      disabledReason: 'Already recording',
+    });
+  });
+
+  it('runs enabled actions and reports why others did not run', async () => {
+    const actions = registry();
+    const run = vi.fn();
+    actions.register(
+      { id: 'live.start', title: 'Start live mode', category: 'Live Mode', run },
+      {
+        id: 'live.stop',
+        title: 'Stop live mode',
+        category: 'Live Mode',
+        enabled: () => 'Live mode is off',
+        run,
+      },
+      {
+        id: 'app.checkUpdates',
+        title: 'Check for updates',
+        category: 'App',
+        run: async () => {
+          throw new Error('offline');
+        },
+      },
+    );
+
+    expect(await actions.invoke('live.start')).toEqual({ ok: true });
+    expect(await actions.invoke('live.stop')).toEqual({ ok: false, error: 'Live mode is off' });
+    expect(await actions.invoke('app.checkUpdates')).toEqual({ ok: false, error: 'offline' });
+    expect(await actions.invoke('nope')).toEqual({ ok: false, error: 'Unknown action "nope"' });
+    expect(run).toHaveBeenCalledTimes(1);
+  });
+
+  it('rejects a duplicate id and unregisters what it registered', () => {
+    const actions = registry();
+    const first = { id: 'a', title: 'A', category: 'App' as const, run: () => {} };
+    const unregister = actions.register(first);
+
+    expect(() => actions.register({ ...first, title: 'Again' })).toThrow(/already registered/);
+    unregister();
+    expect(actions.list()).toEqual([]);
+  });
+});
@@ -0,0 +1,129 @@
+// @vitest-environment node
+
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { describe, expect, it, vi } from 'vitest';
+import {
+  LAYOUT_MARKER,
+  legacyMoves,
+  migrateLegacyLayout,
+  resolveAppPaths,
+  type AppPathsContext,
+} from '../appPaths.js';
+
+const ctx = (overrides: Partial<AppPathsContext> = {}): AppPathsContext => ({
+  platform: 'linux',
+  env: {},
+  home: '/home/u',
+  userData: '/home/u/.config/TranscriptionSuite',
+  portable: false,
+  ...overrides,
+});
+
+describe('resolveAppPaths', () => {
+  it('follows the XDG base directories on Linux', () => {
+    expect(resolveAppPaths(ctx())).toEqual({
+      config: '/home/u/.config/TranscriptionSuite',
+      data: '/home/u/.local/share/TranscriptionSuite',
+      cache: '/home/u/.cache/TranscriptionSuite',
+      logs: '/home/u/.local/state/TranscriptionSuite/logs',
+    });
+    const paths = resolveAppPaths(
+      ctx({ env: { XDG_DATA_HOME: '/srv/data', XDG_CACHE_HOME: 'relative/ignored' } }),
+    );
+    expect(paths.data).toBe('/srv/data/TranscriptionSuite');
+    expect(paths.cache).toBe('/home/u/.cache/TranscriptionSuite');
+  });
+
+  it('uses Library folders on macOS and LOCALAPPDATA on Windows', () => {
+    const support = '/Users/u/Library/Application Support/TranscriptionSuite';
+    expect(
+      resolveAppPaths(ctx({ platform: 'darwin', home: '/Users/u', userData: support })),
+    ).toEqual({
+      config: support,
+      data: support,
+      cache: '/Users/u/Library/Caches/TranscriptionSuite',
+      logs: '/Users/u/Library/Logs/TranscriptionSuite',
+    });
+    const win = resolveAppPaths(
+      ctx({ platform: 'win32', env: { LOCALAPPDATA: '/c/Local' }, userData: '/c/Roaming/TS' }),
+    );
+    expect(win.data).toBe(path.join('/c/Local', 'TranscriptionSuite'));
+    expect(win.logs).toBe(path.join('/c/Local', 'TranscriptionSuite', 'Logs'));
+  });
+
+  it('keeps the single-directory layout in portable mode', () => {
+    const paths = resolveAppPaths(ctx({ portable: true, userData: '/usb/data/TS' }));
+    expect(paths).toEqual({
+      config: '/usb/data/TS',
+      data: '/usb/data/TS',
+      cache: '/usb/data/TS',
+      logs: '/usb/data/TS/logs',
+    });
+    expect(legacyMoves(paths)).toEqual([]);
+  });
+});
+
+describe('migrateLegacyLayout', () => {
+  const setup = () => {
+    const root = fs.mkdtempSync(path.join(os.tmpdir(), 'app-paths-'));
+    const paths = {
+      config: path.join(root, 'config'),
+      data: path.join(root, 'data'),
+      cache: path.join(root, 'cache'),
+      logs: path.join(root, 'state', 'logs'),
+    };
+    fs.mkdirSync(path.join(paths.config, 'whisper-models'), { recursive: true });
+    fs.writeFileSync(path.join(paths.config, 'whisper-models', 'ggml-base.bin'), 'model');
+    fs.mkdirSync(path.join(paths.config, 'logs'));
+    fs.writeFileSync(path.join(paths.config, 'logs', 'server.log'), 'log');
+    return paths;
+  };
+
+  it('moves legacy directories once and records the layout version', () => {
+    const paths = setup();
+    const result = migrateLegacyLayout(paths);
+    expect(result.moved).toHaveLength(2);
+    const model = path.join(paths.data, 'whisper-models', 'ggml-base.bin');
+    expect(fs.readFileSync(model, 'utf-8')).toBe('model');
+    expect(fs.existsSync(path.join(paths.logs, 'server.log'))).toBe(true);
+    expect(fs.existsSync(path.join(paths.config, 'whisper-models'))).toBe(false);
+    expect(fs.existsSync(path.join(paths.config, LAYOUT_MARKER))).toBe(true);
+
+    // A legacy dir recreated by an old build is not touched again.
+    fs.mkdirSync(path.join(paths.config, 'logs'));
+    expect(migrateLegacyLayout(paths)).toEqual({ moved: [], skipped: [] });
+    expect(fs.existsSync(path.join(paths.config, 'logs'))).toBe(true);
+  });
+
+  it('leaves a legacy dir in place when the destination already exists', () => {
+    const paths = setup();
+    fs.mkdirSync(path.join(paths.data, 'whisper-models'), { recursive: true });
+    const result = migrateLegacyLayout(paths);
+    expect(result.skipped).toEqual([path.join(paths.config, 'whisper-models')]);
+    expect(fs.existsSync(path.join(paths.config, 'whisper-models', 'ggml-base.bin'))).toBe(true);
+  });
+
+  it('copies across filesystems and does not write the marker when a move fails', () => {
+    const paths = setup();
+    const rename = vi.spyOn(fs, 'renameSync').mockImplementation(() => {
+      throw Object.assign(new Error('cross-device'), { code: 'EXDEV' });
+    });
+    try {
+      migrateLegacyLayout(paths);
+      expect(fs.existsSync(path.join(paths.data, 'whisper-models', 'ggml-base.bin'))).toBe(true);
+      expect(fs.existsSync(path.join(paths.config, 'whisper-models'))).toBe(false);
+
+      fs.rmSync(path.join(paths.config, LAYOUT_MARKER));
+      fs.mkdirSync(path.join(paths.config, 'previous-installer'));
+      rename.mockImplementation(() => {
+        throw Object.assign(new Error('denied'), { code: 'EACCES' });
+      });
+      expect(() => migrateLegacyLayout(paths)).toThrow('denied');
+      expect(fs.existsSync(path.join(paths.config, LAYOUT_MARKER))).toBe(false);
+    } finally {
+      rename.mockRestore();
+    }
+  });
+});
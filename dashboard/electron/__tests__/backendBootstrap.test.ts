@@ -0,0 +1,94 @@
+// @vitest-environment node
+
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { describe, expect, it } from 'vitest';
+import {
+  UvProgressTracker,
+  backendLayout,
+  bootstrapFingerprint,
+  findUv,
+  stageSource,
+  syncArgs,
+} from '../backendBootstrap.js';
+
+describe('findUv', () => {
+  it('prefers the override, then the bundled binary, then PATH', () => {
+    const present = new Set(['/opt/uv-custom', '/res/uv/uv', '/usr/bin/uv']);
+    const exists = (p: string) => present.has(p);
+    const base = { platform: 'linux' as const, resourcesPath: '/res', exists };
+    const env = { PATH: ['/usr/local/bin', '/usr/bin'].join(path.delimiter) };
+    expect(findUv({ ...base, env: { ...env, TRANSCRIPTIONSUITE_UV: '/opt/uv-custom' } })).toBe(
+      '/opt/uv-custom',
+    );
+    expect(findUv({ ...base, env })).toBe('/res/uv/uv');
+    expect(findUv({ ...base, resourcesPath: undefined, env })).toBe('/usr/bin/uv');
+    expect(findUv({ ...base, resourcesPath: undefined, env: {} })).toBeNull();
+  });
+});
+
+describe('syncArgs', () => {
+  it('syncs the frozen lock for CUDA and swaps the torch index for CPU', () => {
+    expect(syncArgs('/src/server', { extras: ['whisper'], pytorchVariant: 'cu129' })).toEqual([
+      'sync',
+      '--frozen',
+      '--no-dev',
+      '--no-install-project',
+      '--project',
+      '/src/server',
+      '--extra',
+      'whisper',
+    ]);
+    const cpu = syncArgs('/src/server', { extras: [], pytorchVariant: 'cpu' });
+    expect(cpu).not.toContain('--frozen');
+    expect(cpu).toContain('pytorch-cu129=https://download.pytorch.org/whl/cpu');
+  });
+});
+
+describe('bootstrapFingerprint', () => {
+  it('changes with the lock, variant and extras but not extras order', () => {
+    const fp = (lock: string, extras: string[], variant: 'cu129' | 'cpu' = 'cu129') =>
+      bootstrapFingerprint(lock, { extras, pytorchVariant: variant }, '1.0.0');
+    expect(fp('a', ['nemo', 'whisper'])).toBe(fp('a', ['whisper', 'nemo']));
+    expect(fp('a', ['whisper'])).not.toBe(fp('b', ['whisper']));
+    expect(fp('a', ['whisper'])).not.toBe(fp('a', ['whisper'], 'cpu'));
+    expect(fp('a', ['whisper'])).not.toBe(fp('a', []));
+  });
+});
+
+describe('UvProgressTracker', () => {
+  it('maps uv output to staged progress', () => {
+    const tracker = new UvProgressTracker();
+    expect(tracker.feed('Resolved 152 packages in 3ms')?.percent).toBe(5);
+    expect(tracker.feed('Downloading torch (846.7MiB)')).toEqual({
+      stage: 'sync',
+      message: 'Downloading torch (846.7MiB)',
+      percent: 5,
+    });
+    tracker.feed('Downloading numpy (15.1MiB)');
+    expect(tracker.feed(' Downloaded numpy')?.percent).toBe(43);
+    expect(tracker.feed('Installed 150 packages in 812ms')?.percent).toBe(95);
+    expect(tracker.feed(' + torch==2.8.0')).toBeNull();
+  });
+});
+
+describe('stageSource', () => {
+  it('copies the backend without venvs, tests or the dev symlink', () => {
+    const root = fs.mkdtempSync(path.join(os.tmpdir(), 'backend-bootstrap-'));
+    const backend = path.join(root, 'backend');
+    for (const dir of ['api', '.venv/bin', 'tests', 'api/__pycache__']) {
+      fs.mkdirSync(path.join(backend, dir), { recursive: true });
+    }
+    fs.writeFileSync(path.join(backend, 'api', 'main.py'), '');
+    fs.writeFileSync(path.join(backend, 'uv.lock'), '');
+    fs.symlinkSync('.', path.join(backend, 'server'));
+    fs.writeFileSync(path.join(root, 'config.yaml'), 'a: 1\n');
+
+    const { src } = backendLayout(path.join(root, 'data'), 'linux');
+    stageSource(backend, path.join(root, 'config.yaml'), src);
+    expect(fs.readdirSync(path.join(src, 'server')).sort()).toEqual(['api', 'uv.lock']);
+    expect(fs.readdirSync(path.join(src, 'server', 'api'))).toEqual(['main.py']);
+    expect(fs.readFileSync(path.join(src, 'config.yaml'), 'utf-8')).toBe('a: 1\n');
+  });
+});
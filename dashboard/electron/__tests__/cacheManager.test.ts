@@ -0,0 +1,154 @@
+// @vitest-environment node
+
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { afterEach, beforeEach, describe, expect, it } from 'vitest';
+import {
+  CacheManager,
+  EVICTION_GRACE_MS,
+  PENDING_MARKER,
+  isCacheCategory,
+} from '../cacheManager.js';
+
+const MB = 1024 * 1024;
+const NOW = 1_800_000_000_000;
+
+function fakeStore(initial: Record<string, unknown> = {}) {
+  const data: Record<string, unknown> = { ...initial };
+  return {
+    data,
+    get: (key: string) => data[key],
+    set: (key: string, value: unknown) => {
+      data[key] = value;
+    },
+  };
+}
+
+describe('CacheManager', () => {
+  let root: string;
+
+  beforeEach(() => {
+    root = fs.mkdtempSync(path.join(os.tmpdir(), 'cache-manager-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(root, { recursive: true, force: true });
+  });
+
+  /** Write `bytes` to a cache file last used `ageMs` before NOW. */
+  function put(relative: string, bytes: number, ageMs: number): string {
+    const file = path.join(root, relative);
+    fs.mkdirSync(path.dirname(file), { recursive: true });
+    fs.writeFileSync(file, Buffer.alloc(bytes));
+    const stamp = new Date(NOW - ageMs);
+    fs.utimesSync(file, stamp, stamp);
+    return file;
+  }
+
+  function manager(store = fakeStore()) {
+    return new CacheManager({ store, defaultRoot: root, now: () => NOW });
+  }
+
+  it('reports usage per category against its quota', async () => {
+    put('cloud-import/s1/abc/a.mp3', 3000, 0);
+    put('phone-ingest/1-x/memo.m4a', 1000, 0);
+    const usage = await manager(fakeStore({ 'cache.quotasMb': { media: 10 } })).usage();
+    expect(usage.root).toBe(root);
+    expect(usage.custom).toBe(false);
+    const byCategory = Object.fromEntries(usage.categories.map((c) => [c.category, c]));
+    expect(byCategory['cloud-import']).toMatchObject({ bytes: 3000, files: 1 });
+    expect(byCategory['phone-ingest']).toMatchObject({ bytes: 1000, files: 1 });
+    expect(byCategory.media.quotaBytes).toBe(10 * MB);
+    expect(byCategory.waveforms).toMatchObject({ bytes: 0, quotaBytes: 256 * MB });
+  });
+
+  it('evicts least recently used files down to the quota, sparing recent ones', async () => {
+    const store = fakeStore({ 'cache.quotasMb': { 'cloud-import': 1 } });
+    const oldest = put('cloud-import/s1/a/old.wav', 600_000, 3 * EVICTION_GRACE_MS);
+    const older = put('cloud-import/s1/b/older.wav', 600_000, 2 * EVICTION_GRACE_MS);
+    const fresh = put('cloud-import/s1/c/fresh.wav', 600_000, 0);
+
+    const freed = await manager(store).evict('cloud-import');
+
+    expect(freed).toBe(1_200_000);
+    expect(fs.existsSync(oldest)).toBe(false);
+    expect(fs.existsSync(older)).toBe(false);
+    expect(fs.existsSync(path.join(root, 'cloud-import', 's1', 'a'))).toBe(false);
+    expect(fs.existsSync(fresh)).toBe(true);
+  });
+
+  it('keeps a touched file over an untouched one', async () => {
+    const store = fakeStore({ 'cache.quotasMb': { media: 1 } });
+    const reused = put('media/reused.wav', 600_000, 3 * EVICTION_GRACE_MS);
+    const stale = put('media/stale.wav', 600_000, 2 * EVICTION_GRACE_MS);
+    const cache = manager(store);
+
+    await cache.touch(reused);
+    await cache.evict('media');
+
+    expect(fs.existsSync(reused)).toBe(true);
+    expect(fs.existsSync(stale)).toBe(false);
+  });
+
+  it('clears one category or all of them', async () => {
+    put('cloud-import/s1/a/x.wav', 100, 0);
+    put('waveforms/1.json', 50, 0);
+    const cache = manager();
+
+    expect(await cache.clear('waveforms')).toBe(50);
+    expect(fs.existsSync(path.join(root, 'waveforms'))).toBe(false);
+    expect(fs.existsSync(path.join(root, 'cloud-import'))).toBe(true);
+
+    expect(await cache.clear()).toBe(100);
+    expect(fs.readdirSync(root)).toEqual([]);
+  });
+
+  it('moves to a new root, deleting only its own folders at the old one', async () => {
+    put('cloud-import/s1/a/x.wav', 100, 0);
+    put('unrelated.txt', 10, 0);
+    const store = fakeStore();
+    const cache = manager(store);
+    const elsewhere = path.join(root, 'elsewhere');
+
+    await cache.setRoot(elsewhere);
+
+    expect(store.data['cache.directory']).toBe(elsewhere);
+    expect(cache.root).toBe(elsewhere);
+    expect(cache.dir('media')).toBe(path.join(elsewhere, 'media'));
+    expect(fs.existsSync(path.join(root, 'cloud-import'))).toBe(false);
+    expect(fs.existsSync(path.join(root, 'unrelated.txt'))).toBe(true);
+    expect((await cache.usage()).custom).toBe(true);
+
+    await expect(cache.setRoot('relative/dir')).rejects.toThrow(/absolute/);
+    await cache.setRoot(null);
+    expect(cache.root).toBe(root);
+  });
+
+  it('never evicts, clears or leaves behind a pending import', async () => {
+    const store = fakeStore({ 'cache.quotasMb': { 'phone-ingest': 1 } });
+    const pending = put('phone-ingest/1-a/memo.m4a', 900_000, 3 * EVICTION_GRACE_MS);
+    put(`phone-ingest/1-a/${PENDING_MARKER}`, 10, 3 * EVICTION_GRACE_MS);
+    const done = put('phone-ingest/2-b/done.m4a', 900_000, 2 * EVICTION_GRACE_MS);
+    const cache = manager(store);
+
+    expect(await cache.evict('phone-ingest')).toBe(900_000);
+    expect(fs.existsSync(pending)).toBe(true);
+    expect(fs.existsSync(done)).toBe(false);
+
+    put('phone-ingest/3-c/other.m4a', 100, 0);
+    expect(await cache.clear('phone-ingest')).toBe(100);
+    expect(fs.existsSync(pending)).toBe(true);
+
+    const elsewhere = path.join(root, 'elsewhere');
+    await cache.setRoot(elsewhere);
+    expect(fs.existsSync(pending)).toBe(false);
+    expect(fs.existsSync(path.join(elsewhere, 'phone-ingest', '1-a', 'memo.m4a'))).toBe(true);
+  });
+
+  it('validates categories and quotas', () => {
+    expect(isCacheCategory('media')).toBe(true);
+    expect(isCacheCategory('toString')).toBe(false);
+    expect(() => manager().setQuota('media', 0)).toThrow(/Invalid cache quota/);
+  });
+});
@@ -0,0 +1,161 @@
+// @vitest-environment node
+
+import { afterEach, describe, expect, it, vi } from 'vitest';
+import {
+  CaptionOutputs,
+  DEFAULT_OBS_INPUT_NAME,
+  DEFAULT_OBS_URL,
+  captionText,
+  obsAuthentication,
+  readCaptionOutputSettings,
+  type CaptionOutputSettings,
+  type ObsSocketHandlers,
+} from '../captionOutputs.js';
+
+const OFF: CaptionOutputSettings = {
+  textFileEnabled: false,
+  textFilePath: '',
+  obsEnabled: false,
+  obsUrl: DEFAULT_OBS_URL,
+  obsPassword: '',
+  obsInputName: DEFAULT_OBS_INPUT_NAME,
+};
+
+function fakeObs() {
+  const sockets: { url: string; handlers: ObsSocketHandlers; sent: unknown[]; closed: boolean }[] =
+    [];
+  const createSocket = vi.fn((url: string, handlers: ObsSocketHandlers) => {
+    const socket = { url, handlers, sent: [] as unknown[], closed: false };
+    sockets.push(socket);
+    return {
+      send: (data: string) => socket.sent.push(JSON.parse(data)),
+      close: () => {
+        socket.closed = true;
+      },
+    };
+  });
+  return { sockets, createSocket };
+}
+
+function hello(handlers: ObsSocketHandlers, authentication?: object) {
+  handlers.onMessage(JSON.stringify({ op: 0, d: { rpcVersion: 1, authentication } }));
+}
+
+function identified(handlers: ObsSocketHandlers) {
+  handlers.onMessage(JSON.stringify({ op: 2, d: { negotiatedRpcVersion: 1 } }));
+}
+
+afterEach(() => {
+  vi.useRealTimers();
+});
+
+describe('caption output settings', () => {
+  it('falls back to the OBS defaults and keeps the password verbatim', () => {
+    const values: Record<string, unknown> = {
+      'captions.textFile.enabled': true,
+      'captions.textFile.path': ' /tmp/captions.txt ',
+      'captions.obs.url': '',
+      'captions.obs.password': ' secret ',
+    };
+    expect(readCaptionOutputSettings({ get: (key) => values[key] })).toEqual({
+      textFileEnabled: true,
+      textFilePath: '/tmp/captions.txt',
+      obsEnabled: false,
+      obsUrl: DEFAULT_OBS_URL,
+      obsPassword: ' secret ',
+      obsInputName: DEFAULT_OBS_INPUT_NAME,
+    });
+  });
+
+  it('renders the same tail of the transcript as the overlay', () => {
+    expect(captionText({ lines: ['a', 'b', 'c'], partial: 'd' })).toBe('b\nc\nd');
+  });
+
+  it('computes the obs-websocket v5 authentication string', () => {
+    // Worked example from the obs-websocket protocol documentation.
+    expect(
+      obsAuthentication(
+        'supersecretpassword',
+        'lM1GncleQOaCu9lT1yeUZhFYnqhsLLP1G5lAGo3ixaI=',
+        '+IxH4CnCiqpX1rM9scsNynZzbOe4KhDeYcTNS3PDaeY=',
+      ),
+    ).toBe('1Ct943GAT+6YQUUX47Ia/ncufilbe6+oD6lY+5kaCu4=');
+  });
+});
+
+describe('CaptionOutputs text file', () => {
+  it('writes each change once and nothing while disabled', async () => {
+    const writeFile = vi.fn(async () => undefined);
+    const outputs = new CaptionOutputs({ createSocket: fakeObs().createSocket, writeFile });
+
+    outputs.configure(OFF);
+    outputs.update({ lines: ['hello'], partial: '' });
+    expect(writeFile).not.toHaveBeenCalled();
+
+    outputs.configure({ ...OFF, textFileEnabled: true, textFilePath: '/tmp/c.txt' });
+    await vi.waitFor(() => expect(writeFile).toHaveBeenCalledWith('/tmp/c.txt', 'hello'));
+
+    outputs.update({ lines: ['hello'], partial: '' });
+    outputs.update({ lines: ['hello'], partial: 'wor' });
+    await vi.waitFor(() => expect(writeFile).toHaveBeenLastCalledWith('/tmp/c.txt', 'hello\nwor'));
+    expect(writeFile).toHaveBeenCalledTimes(2);
+  });
+});
+
+describe('CaptionOutputs OBS', () => {
+  it('authenticates, then sets the text source on every change', () => {
+    const { sockets, createSocket } = fakeObs();
+    const outputs = new CaptionOutputs({ createSocket });
+    outputs.configure({ ...OFF, obsEnabled: true, obsPassword: 'pw', obsInputName: 'Subs' });
+    expect(createSocket).toHaveBeenCalledWith(DEFAULT_OBS_URL, expect.anything());
+
+    const [socket] = sockets;
+    outputs.update({ lines: ['early'], partial: '' });
+    expect(socket.sent).toEqual([]);
+
+    hello(socket.handlers, { challenge: 'c', salt: 's' });
+    expect(socket.sent[0]).toEqual({
+      op: 1,
+      d: {
+        rpcVersion: 1,
+        eventSubscriptions: 0,
+        authentication: obsAuthentication('pw', 's', 'c'),
+      },
+    });
+
+    identified(socket.handlers);
+    expect(socket.sent[1]).toMatchObject({
+      op: 6,
+      d: {
+        requestType: 'SetInputSettings',
+        requestData: { inputName: 'Subs', inputSettings: { text: 'early' }, overlay: true },
+      },
+    });
+
+    outputs.update({ lines: ['early'], partial: '' });
+    expect(socket.sent).toHaveLength(2);
+    outputs.update({ lines: ['early', 'late'], partial: '' });
+    expect(socket.sent).toHaveLength(3);
+  });
+
+  it('reconnects after OBS closes and disconnects when disabled', () => {
+    vi.useFakeTimers();
+    const { sockets, createSocket } = fakeObs();
+    const outputs = new CaptionOutputs({ createSocket });
+    const enabled = { ...OFF, obsEnabled: true };
+    outputs.configure(enabled);
+
+    sockets[0].handlers.onClose();
+    expect(createSocket).toHaveBeenCalledTimes(1);
+    vi.advanceTimersByTime(5000);
+    expect(createSocket).toHaveBeenCalledTimes(2);
+
+    outputs.configure(enabled);
+    expect(createSocket).toHaveBeenCalledTimes(2);
+
+    outputs.configure(OFF);
+    expect(sockets[1].closed).toBe(true);
+    vi.advanceTimersByTime(60_000);
+    expect(createSocket).toHaveBeenCalledTimes(2);
+  });
+});
@@ -0,0 +1,116 @@
+// @vitest-environment node
+
+import { describe, expect, it, vi } from 'vitest';
+import {
+  CaptionOverlay,
+  buildCaptionOverlayHtml,
+  overlayBounds,
+  visibleCaptionLines,
+  type OverlayWindow,
+} from '../captionOverlay.js';
+
+function fakeWindow() {
+  let visible = false;
+  let closed: (() => void) | null = null;
+  const executeJavaScript = vi.fn(async (_code: string) => undefined);
+  const win = {
+    loadURL: vi.fn(async () => undefined),
+    showInactive: vi.fn(() => {
+      visible = true;
+    }),
+    hide: vi.fn(() => {
+      visible = false;
+    }),
+    destroy: vi.fn(),
+    isDestroyed: () => false,
+    isVisible: () => visible,
+    setBounds: vi.fn(),
+    setIgnoreMouseEvents: vi.fn(),
+    setAlwaysOnTop: vi.fn(),
+    setVisibleOnAllWorkspaces: vi.fn(),
+    on: (_event: 'closed', listener: () => void) => {
+      closed = listener;
+    },
+    webContents: { executeJavaScript },
+  } satisfies OverlayWindow;
+  return { win, executeJavaScript, close: () => closed?.() };
+}
+
+const AREA = { x: 0, y: 0, width: 1920, height: 1040 };
+
+describe('caption layout', () => {
+  it('keeps the last sentences plus the partial', () => {
+    const lines = visibleCaptionLines({ lines: ['one', ' ', 'two', 'three'], partial: ' fo' });
+    expect(lines).toEqual(['two', 'three', 'fo']);
+    expect(visibleCaptionLines({ lines: ['a'], partial: '' })).toEqual(['a']);
+  });
+
+  it('centres a strip near the chosen edge', () => {
+    expect(overlayBounds(AREA, 'bottom')).toEqual({ x: 192, y: 812, width: 1536, height: 180 });
+    expect(overlayBounds({ ...AREA, x: 1920 }, 'top')).toMatchObject({ x: 2112, y: 48 });
+  });
+
+  it('sets caption text without parsing it as HTML', () => {
+    const html = buildCaptionOverlayHtml();
+    expect(html).toContain('textContent = text');
+    expect(html).not.toContain('innerHTML');
+    expect(html).toContain("default-src 'none'");
+  });
+});
+
+describe('CaptionOverlay', () => {
+  it('creates one click-through, always-on-top window and renders on show', async () => {
+    const { win, executeJavaScript } = fakeWindow();
+    const createWindow = vi.fn(() => win);
+    const onVisibilityChanged = vi.fn();
+    const overlay = new CaptionOverlay({
+      createWindow,
+      getWorkArea: () => AREA,
+      onVisibilityChanged,
+    });
+
+    await overlay.update({ lines: ['hello'], partial: 'wor' });
+    expect(executeJavaScript).not.toHaveBeenCalled();
+
+    await overlay.show();
+    expect(win.setIgnoreMouseEvents).toHaveBeenCalledWith(true);
+    expect(win.setAlwaysOnTop).toHaveBeenCalledWith(true, 'screen-saver');
+    expect(win.loadURL.mock.calls[0][0]).toMatch(/^data:text\/html/);
+    expect(executeJavaScript).toHaveBeenLastCalledWith(
+      'window.__setCaptions(["hello","wor"], true)',
+    );
+    expect(overlay.isVisible()).toBe(true);
+    expect(onVisibilityChanged).toHaveBeenLastCalledWith(true);
+
+    await overlay.update({ lines: ['</script>"'], partial: '' });
+    expect(executeJavaScript).toHaveBeenLastCalledWith(
+      'window.__setCaptions(["</script>\\""], false)',
+    );
+
+    overlay.hide();
+    await overlay.show('top');
+    expect(createWindow).toHaveBeenCalledTimes(1);
+    expect(win.setBounds).toHaveBeenCalledWith(overlayBounds(AREA, 'top'));
+  });
+
+  it('recreates the window after it is closed externally', async () => {
+    const first = fakeWindow();
+    const second = fakeWindow();
+    const createWindow = vi.fn().mockReturnValueOnce(first.win).mockReturnValueOnce(second.win);
+    const onVisibilityChanged = vi.fn();
+    const overlay = new CaptionOverlay({
+      createWindow,
+      getWorkArea: () => AREA,
+      onVisibilityChanged,
+    });
+
+    await overlay.show();
+    first.close();
+    expect(onVisibilityChanged).toHaveBeenLastCalledWith(false);
+    expect(overlay.isVisible()).toBe(false);
+
+    await overlay.show();
+    expect(createWindow).toHaveBeenCalledTimes(2);
+    expect(overlay.isVisible()).toBe(true);
+  });
+});
@@ -0,0 +1,65 @@
+// @vitest-environment node
+
+import { describe, expect, it, vi } from 'vitest';
+import { SILENCE_DB, channelLabels, channelLevels, probeChannels } from '../channelProbe.js';
+import { MediaClipError } from '../mediaClip.js';
+
+/** Interleaved float PCM, one row per frame. */
+function pcm(frames: number[][]): Buffer {
+  return Buffer.from(new Float32Array(frames.flat()).buffer);
+}
+
+describe('channelLevels', () => {
+  it('measures each interleaved channel separately', () => {
+    const samples = new Float32Array([0.5, 0, -0.5, 0, 0.5, 0.1, -0.5, -0.1]);
+
+    const [loud, quiet] = channelLevels(samples, 2);
+
+    expect(loud).toEqual({ rmsDb: -6, peakDb: -6 });
+    expect(quiet).toEqual({ rmsDb: -23, peakDb: -20 });
+  });
+
+  it('floors silent channels instead of returning -Infinity', () => {
+    expect(channelLevels(new Float32Array(6), 3)).toEqual(
+      Array(3).fill({ rmsDb: SILENCE_DB, peakDb: SILENCE_DB }),
+    );
+  });
+});
+
+describe('channelLabels', () => {
+  it('names stereo sides and numbers everything else from 1', () => {
+    expect(channelLabels(2, 'stereo')).toEqual(['Left', 'Right']);
+    expect(channelLabels(4, '4.0')).toEqual(['Channel 1', 'Channel 2', 'Channel 3', 'Channel 4']);
+  });
+});
+
+describe('probeChannels', () => {
+  it('combines the ffprobe layout with levels from the decoded preview', async () => {
+    const run = vi.fn(async (command: string) =>
+      command === 'ffprobe'
+        ? Buffer.from(JSON.stringify({ streams: [{ channels: 3, channel_layout: '3.0' }] }))
+        : pcm([
+            [0, 0.5, 0],
+            [0, -0.5, 0],
+          ]),
+    );
+
+    const probe = await probeChannels('/rec/panel.wav', { run });
+
+    expect(probe.channels).toBe(3);
+    expect(probe.layout).toBe('3.0');
+    expect(probe.levels.map((l) => [l.index, l.label, l.rmsDb])).toEqual([
+      [0, 'Channel 1', SILENCE_DB],
+      [1, 'Channel 2', -6],
+      [2, 'Channel 3', SILENCE_DB],
+    ]);
+    expect(run.mock.calls[1][1]).toContain('f32le');
+  });
+
+  it('rejects files without an audio stream', async () => {
+    const run = vi.fn(async () => Buffer.from('{"streams":[]}'));
+
+    await expect(probeChannels('/rec/slides.pdf', { run })).rejects.toBeInstanceOf(MediaClipError);
+    expect(run).toHaveBeenCalledTimes(1);
+  });
+});
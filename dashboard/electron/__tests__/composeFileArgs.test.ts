@@ -86,6 +86,21 @@ describe('[P1] composeFileArgs', () => {
     ]);
   });
 
+  it('Linux + GPU (Docker legacy) pinned to one device: gpu-device instead of gpu', () => {
+    setPlatform('linux');
+    const files = extractFiles(composeFileArgs('gpu', 'docker', 'legacy', true));
+
+    expect(files).toEqual([
+      'docker-compose.yml',
+      'docker-compose.linux-host.yml',
+      'docker-compose.gpu-device.yml',
+    ]);
+    // CDI selects the device through NVIDIA_GPU in its own overlay.
+    expect(extractFiles(composeFileArgs('gpu', 'docker', 'cdi', true))).toContain(
+      'docker-compose.gpu-cdi.yml',
+    );
+  });
+
   it('Linux + GPU (Podman): base + linux-host + podman gpu', () => {
     setPlatform('linux');
     const args = composeFileArgs('gpu', 'podman', null);
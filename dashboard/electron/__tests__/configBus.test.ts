@@ -0,0 +1,74 @@
+// @vitest-environment node
+
+import { describe, it, expect, vi } from 'vitest';
+
+import { ConfigBus } from '../configBus.js';
+
+describe('ConfigBus', () => {
+  it('delivers writes from the same tick once, filtered by prefix', async () => {
+    const bus = new ConfigBus();
+    const shortcuts = vi.fn();
+    const port = vi.fn();
+    bus.subscribe('shortcuts', 'shortcuts.', shortcuts);
+    bus.subscribe('phoneIngest', ['phoneIngest.port', 'phoneIngest.enabled'], port);
+
+    bus.publish('shortcuts.startRecording');
+    bus.publish('shortcuts.stopRecording', 'ui.theme');
+    await bus.idle();
+
+    expect(shortcuts).toHaveBeenCalledTimes(1);
+    expect(shortcuts).toHaveBeenCalledWith(['shortcuts.startRecording', 'shortcuts.stopRecording']);
+    expect(port).not.toHaveBeenCalled();
+  });
+
+  it('holds changes made while a handler runs and re-delivers them after', async () => {
+    const bus = new ConfigBus();
+    let release!: () => void;
+    const calls: string[][] = [];
+    bus.subscribe('followAlong', 'followAlong.', async (keys) => {
+      calls.push(keys);
+      if (calls.length === 1) await new Promise<void>((resolve) => (release = resolve));
+    });
+
+    bus.publish('followAlong.port');
+    await Promise.resolve();
+    bus.publish('followAlong.port');
+    bus.publish('followAlong.title');
+    await new Promise((resolve) => setTimeout(resolve, 0));
+    expect(calls).toHaveLength(1);
+
+    release();
+    await bus.idle();
+    expect(calls).toEqual([['followAlong.port'], ['followAlong.port', 'followAlong.title']]);
+  });
+
+  it('reports a failing subscriber without stopping the others', async () => {
+    const onError = vi.fn();
+    const bus = new ConfigBus({ onError });
+    const other = vi.fn();
+    const warn = vi.spyOn(console, 'warn').mockImplementation(() => {});
+    bus.subscribe('phoneIngest', 'phoneIngest.', () => {
+      throw new Error('EADDRINUSE');
+    });
+    bus.subscribe('windows', '', other);
+
+    bus.publish('phoneIngest.port');
+    await bus.idle();
+
+    expect(onError).toHaveBeenCalledWith('phoneIngest', expect.any(Error));
+    expect(other).toHaveBeenCalledWith(['phoneIngest.port']);
+    warn.mockRestore();
+  });
+
+  it('stops delivering after unsubscribe', async () => {
+    const bus = new ConfigBus();
+    const handler = vi.fn();
+    const unsubscribe = bus.subscribe('captions', 'captions.', handler);
+
+    unsubscribe();
+    bus.publish('captions.enabled');
+    await bus.idle();
+
+    expect(handler).not.toHaveBeenCalled();
+  });
+});
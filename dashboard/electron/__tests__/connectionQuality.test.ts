@@ -0,0 +1,121 @@
+// @vitest-environment node
+
+import { describe, it, expect, vi } from 'vitest';
+import type Store from 'electron-store';
+
+import {
+  ConnectionQualityMonitor,
+  classify,
+  connectionTargets,
+  profileServerUrl,
+  type ConnectionQualityReport,
+  type ConnectionQualityTargets,
+} from '../connectionQuality.js';
+import type { ConnectionProfile } from '../connectionTuning.js';
+
+function makeStore(data: Record<string, unknown>): Store<any> {
+  return { get: vi.fn((key: string) => data[key]) } as unknown as Store<any>;
+}
+
+function makeMonitor(latency: Partial<Record<ConnectionProfile, number | null>>) {
+  const targets: ConnectionQualityTargets = { active: 'tailscale', alternative: 'lan' };
+  const reports: ConnectionQualityReport[] = [];
+  const monitor = new ConnectionQualityMonitor({
+    probe: async (profile) => latency[profile] ?? null,
+    targets: () => targets,
+    emit: (report) => reports.push(report),
+  });
+  return { monitor, targets, reports };
+}
+
+describe('connection quality', () => {
+  it('classifies a window of probe results', () => {
+    expect(classify('lan', [20, 30, 40], [40])).toEqual({
+      profile: 'lan',
+      quality: 'good',
+      latencyMs: 30,
+      loss: 0,
+    });
+    expect(classify('lan', [700, 800, 900], [900]).quality).toBe('degraded');
+    expect(classify('lan', [20, null, 30, null], [30]).quality).toBe('degraded');
+    expect(classify('lan', [20, 30, null], [null])).toMatchObject({
+      quality: 'offline',
+      latencyMs: 25,
+      loss: 0.33,
+    });
+  });
+
+  it('suggests the alternative once the active link stays bad', async () => {
+    const { monitor } = makeMonitor({ tailscale: null, lan: 15 });
+
+    const first = await monitor.checkNow();
+    expect(first.quality).toBe('offline');
+    expect(first.alternative?.quality).toBe('good');
+    expect(first.suggestSwitchTo).toBeNull();
+
+    expect((await monitor.checkNow()).suggestSwitchTo).toBe('lan');
+    expect(monitor.latest()?.suggestSwitchTo).toBe('lan');
+  });
+
+  it('never suggests an alternative that is not good', async () => {
+    const { monitor } = makeMonitor({ tailscale: null, lan: null });
+
+    await monitor.checkNow();
+    expect((await monitor.checkNow()).suggestSwitchTo).toBeNull();
+  });
+
+  it('starts over when the active profile changes', async () => {
+    const { monitor, targets } = makeMonitor({ tailscale: null, lan: 15 });
+    await monitor.checkNow();
+
+    targets.active = 'lan';
+    targets.alternative = 'tailscale';
+    const report = await monitor.checkNow();
+    expect(report.profile).toBe('lan');
+    expect(report.quality).toBe('good');
+    expect(report.suggestSwitchTo).toBeNull();
+  });
+
+  it('shares a round between concurrent callers', async () => {
+    const probe = vi.fn(async () => 10);
+    const monitor = new ConnectionQualityMonitor({
+      probe,
+      targets: () => ({ active: 'local', alternative: null }),
+      emit: () => {},
+      burst: 2,
+    });
+
+    const [a, b] = await Promise.all([monitor.checkNow(), monitor.checkNow()]);
+    expect(a).toBe(b);
+    expect(probe).toHaveBeenCalledTimes(2);
+  });
+
+  it('only offers the other remote path when its host is set', () => {
+    expect(connectionTargets(makeStore({ 'connection.useRemote': false }))).toEqual({
+      active: 'local',
+      alternative: null,
+    });
+    expect(
+      connectionTargets(
+        makeStore({ 'connection.useRemote': true, 'connection.remoteHost': 'box.ts.net' }),
+      ),
+    ).toEqual({ active: 'tailscale', alternative: null });
+    expect(
+      connectionTargets(
+        makeStore({
+          'connection.useRemote': true,
+          'connection.remoteProfile': 'lan',
+          'connection.lanHost': '192.168.1.5',
+          'connection.remoteHost': 'box.ts.net',
+        }),
+      ),
+    ).toEqual({ active: 'lan', alternative: 'tailscale' });
+  });
+
+  it('builds HTTPS URLs for remote profiles', () => {
+    const store = makeStore({ 'connection.lanHost': '192.168.1.5', 'connection.port': 8443 });
+    expect(profileServerUrl(store, 'lan')).toBe('https://192.168.1.5:8443');
+    expect(profileServerUrl(store, 'local')).toBe('http://localhost:8443');
+    expect(profileServerUrl(store, 'tailscale')).toBeNull();
+  });
+});
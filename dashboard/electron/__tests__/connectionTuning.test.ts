@@ -0,0 +1,94 @@
+// @vitest-environment node
+
+import { EventEmitter } from 'events';
+import type http from 'http';
+import { describe, it, expect, vi, afterEach } from 'vitest';
+import type Store from 'electron-store';
+
+import {
+  DEFAULT_CONNECTION_TUNING,
+  activeConnectionProfile,
+  armConnectTimeout,
+  getConnectionTuning,
+  normalizeConnectionTuning,
+  sharedAgent,
+} from '../connectionTuning.js';
+
+function makeStore(data: Record<string, unknown>): Store<any> {
+  return { get: vi.fn((key: string) => data[key]) } as unknown as Store<any>;
+}
+
+describe('connection tuning', () => {
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it('picks the profile the app connects with', () => {
+    expect(activeConnectionProfile(makeStore({ 'connection.useRemote': false }))).toBe('local');
+    expect(
+      activeConnectionProfile(
+        makeStore({ 'connection.useRemote': true, 'connection.remoteProfile': 'lan' }),
+      ),
+    ).toBe('lan');
+    expect(activeConnectionProfile(makeStore({ 'connection.useRemote': true }))).toBe('tailscale');
+  });
+
+  it('reads the stored tuning and repairs invalid values', () => {
+    const store = makeStore({
+      'connection.useRemote': true,
+      'connection.tuning.tailscale': {
+        connectTimeoutSeconds: 30,
+        readTimeoutSeconds: 'soon',
+        keepAlive: false,
+      },
+    });
+    expect(getConnectionTuning(store)).toEqual({
+      ...DEFAULT_CONNECTION_TUNING.tailscale,
+      connectTimeoutSeconds: 30,
+      keepAlive: false,
+    });
+    // A read timeout below the connect timeout is raised to match it.
+    expect(
+      normalizeConnectionTuning({ connectTimeoutSeconds: 60, readTimeoutSeconds: 10 }, 'local')
+        .readTimeoutSeconds,
+    ).toBe(60);
+  });
+
+  it('rebuilds the pooled agent when keep-alive changes', () => {
+    const keepAlive = { ...DEFAULT_CONNECTION_TUNING.local, keepAlive: true };
+    const first = sharedAgent(false, keepAlive);
+    expect(sharedAgent(false, keepAlive)).toBe(first);
+
+    const destroy = vi.spyOn(first, 'destroy');
+    const closing = sharedAgent(false, { ...keepAlive, keepAlive: false });
+    expect(closing).not.toBe(first);
+    expect(destroy).toHaveBeenCalled();
+  });
+
+  it('fails a request whose socket does not connect in time', () => {
+    vi.useFakeTimers();
+    const req = Object.assign(new EventEmitter(), { destroy: vi.fn() });
+    armConnectTimeout(req as unknown as http.ClientRequest, {
+      ...DEFAULT_CONNECTION_TUNING.local,
+      connectTimeoutSeconds: 3,
+    });
+    req.emit('socket', Object.assign(new EventEmitter(), { connecting: true }));
+
+    vi.advanceTimersByTime(3000);
+
+    expect(req.destroy).toHaveBeenCalledWith(expect.objectContaining({ code: 'ETIMEDOUT' }));
+  });
+
+  it('leaves a request alone once its socket has connected', () => {
+    vi.useFakeTimers();
+    const req = Object.assign(new EventEmitter(), { destroy: vi.fn() });
+    armConnectTimeout(req as unknown as http.ClientRequest, DEFAULT_CONNECTION_TUNING.local);
+    const socket = Object.assign(new EventEmitter(), { connecting: true });
+    req.emit('socket', socket);
+    socket.emit('connect');
+
+    vi.advanceTimersByTime(60_000);
+
+    expect(req.destroy).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,133 @@
+// @vitest-environment node
+
+import { describe, expect, it } from 'vitest';
+import {
+  DEMO_JOB_SECONDS,
+  createDemoState,
+  handleDemoRequest,
+  isDemoMode,
+  startDemoServer,
+  toneWav,
+} from '../demoServer.js';
+
+const START = new Date(2026, 9, 15, 12, 0, 0).getTime();
+
+function demo() {
+  let now = START;
+  const state = createDemoState(() => now);
+  const get = (url: string) => {
+    const reply = handleDemoRequest(state, 'GET', url);
+    return { status: reply.status, body: JSON.parse(String(reply.body)) };
+  };
+  const advance = (seconds: number) => {
+    now += seconds * 1000;
+  };
+  return { state, get, advance };
+}
+
+const upload = (filename: string) =>
+  Buffer.from(`--x\r\nContent-Disposition: form-data; name="file"; filename="${filename}"\r\n`);
+
+describe('demoServer', () => {
+  it('is enabled by the flag or the environment', () => {
+    expect(isDemoMode(['electron', '.'], {})).toBe(false);
+    expect(isDemoMode(['electron', '.', '--demo'], {})).toBe(true);
+    expect(isDemoMode(['electron'], { TRANSCRIPTIONSUITE_DEMO: '1' })).toBe(true);
+  });
+
+  it('reports a ready server with admin permissions', () => {
+    const { get } = demo();
+    expect(get('/api/status').body).toMatchObject({ ready: true, version: 'demo' });
+    expect(get('/api/auth/permissions').body.permissions.can_manage_models).toBe(true);
+    expect(get('/api/llm/status').body.available).toBe(false);
+    expect(get('/api/transcribe/summary').status).toBe(404);
+  });
+
+  it('serves the sample library through list, detail, calendar and search', () => {
+    const { get } = demo();
+    const list = get('/api/notebook/recordings').body;
+    expect(list).toHaveLength(3);
+    expect(list[0]).not.toHaveProperty('segments');
+
+    const detail = get(`/api/notebook/recordings/${list[0].id}`).body;
+    expect(detail.segments.length).toBeGreaterThan(0);
+    expect(detail.words).toHaveLength(detail.word_count);
+
+    const calendar = get('/api/notebook/calendar?year=2026&month=10').body;
+    expect(calendar.total_recordings).toBe(3);
+    expect(calendar.days['2026-10-14'][0].title).toBe('Weekly planning');
+
+    const hits = get('/api/search/?q=lectures').body;
+    expect(hits.count).toBe(1);
+    expect(hits.results[0].title).toBe('Interview notes');
+  });
+
+  it('exports and deletes recordings', () => {
+    const { state, get } = demo();
+    const srt = handleDemoRequest(state, 'GET', '/api/notebook/recordings/3/export?format=srt');
+    expect(String(srt.body)).toMatch(/^1\n00:00:00,500 --> 00:00:0\d,\d{3}\nRemember/);
+
+    expect(handleDemoRequest(state, 'DELETE', '/api/notebook/recordings/3').status).toBe(200);
+    expect(get('/api/notebook/recordings/3').status).toBe(404);
+    expect(get('/api/notebook/recordings').body).toHaveLength(2);
+  });
+
+  it('runs an upload as a job with progress, then adds the recording', () => {
+    const { state, get, advance } = demo();
+    const accepted = handleDemoRequest(
+      state,
+      'POST',
+      '/api/notebook/transcribe/upload',
+      upload('standup.mp3'),
+    );
+    expect(accepted.status).toBe(202);
+    const jobId = JSON.parse(String(accepted.body)).job_id;
+
+    const second = handleDemoRequest(state, 'POST', '/api/transcribe/import', upload('a.wav'));
+    expect(second.status).toBe(409);
+
+    advance(DEMO_JOB_SECONDS / 2);
+    let tracker = get('/api/admin/status').body.models.job_tracker;
+    expect(tracker).toMatchObject({ is_busy: true, active_job_id: jobId });
+    expect(tracker.progress.phase).toBe('transcribing');
+    expect(tracker.progress.current).toBeGreaterThan(0);
+    expect(get('/api/transcribe/queue').body.active.mine).toBe(true);
+
+    advance(DEMO_JOB_SECONDS);
+    tracker = get('/api/admin/status').body.models.job_tracker;
+    expect(tracker.is_busy).toBe(false);
+    expect(tracker.result.job_id).toBe(jobId);
+    const created = get(`/api/notebook/recordings/${tracker.result.recording_id}`).body;
+    expect(created.title).toBe('standup');
+    expect(get('/api/notebook/recordings').body).toHaveLength(4);
+  });
+
+  it('returns an import transcript without touching the library', () => {
+    const { state, get, advance } = demo();
+    handleDemoRequest(state, 'POST', '/api/transcribe/import', upload('clip.wav'));
+    advance(DEMO_JOB_SECONDS);
+    const result = get('/api/admin/status').body.models.job_tracker.result;
+    expect(result.transcription.text).toContain('demo transcription');
+    expect(get('/api/notebook/recordings').body).toHaveLength(3);
+  });
+
+  it('generates playable audio of the recording length', () => {
+    const wav = toneWav(2);
+    expect(wav.subarray(0, 4).toString()).toBe('RIFF');
+    expect(wav.readUInt32LE(40)).toBe(2 * 16_000 * 2);
+  });
+
+  it('answers over HTTP, including CORS preflight', async () => {
+    const server = await startDemoServer(0);
+    try {
+      const base = `http://localhost:${server.port}`;
+      const health = await fetch(`${base}/health`);
+      expect(await health.json()).toEqual({ status: 'healthy', service: 'transcriptionsuite' });
+      expect(health.headers.get('access-control-allow-origin')).toBe('*');
+      const preflight = await fetch(`${base}/api/status`, { method: 'OPTIONS' });
+      expect(preflight.status).toBe(204);
+    } finally {
+      await server.close();
+    }
+  });
+});
@@ -0,0 +1,56 @@
+// @vitest-environment node
+
+import { describe, it, expect, vi } from 'vitest';
+
+// dockerManager.ts imports `app` at the top level.
+vi.mock('electron', () => ({
+  app: {
+    isPackaged: false,
+    getPath: (name: string) => `/tmp/mock-${name}`,
+    setPath: vi.fn(),
+  },
+}));
+
+vi.mock('electron-store', () => ({
+  default: class MockStore {
+    get() {
+      return undefined;
+    }
+    set() {}
+  },
+}));
+
+import { isReplicaContainerName, replicaSpecs } from '../dockerManager.js';
+
+describe('server replicas', () => {
+  it('puts each extra replica on the next port and GPU', () => {
+    expect(replicaSpecs(3, 9786)).toEqual([
+      {
+        index: 1,
+        containerName: 'transcriptionsuite-container-2',
+        project: 'transcriptionsuite-replica-2',
+        port: 9787,
+        gpu: '1',
+      },
+      {
+        index: 2,
+        containerName: 'transcriptionsuite-container-3',
+        project: 'transcriptionsuite-replica-3',
+        port: 9788,
+        gpu: '2',
+      },
+    ]);
+  });
+
+  it('has no extras for a single server and caps the count', () => {
+    expect(replicaSpecs(1, 9786)).toEqual([]);
+    expect(replicaSpecs(0, 9786)).toEqual([]);
+    expect(replicaSpecs(50, 9786)).toHaveLength(7);
+  });
+
+  it('recognises replica containers only', () => {
+    expect(isReplicaContainerName('transcriptionsuite-container-2')).toBe(true);
+    expect(isReplicaContainerName('transcriptionsuite-container')).toBe(false);
+    expect(isReplicaContainerName('transcriptionsuite-container-whisper')).toBe(false);
+  });
+});
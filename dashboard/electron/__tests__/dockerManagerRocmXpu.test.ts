@@ -0,0 +1,111 @@
+// @vitest-environment node
+
+/**
+ * AMD ROCm / Intel oneAPI (XPU) container variants.
+ *
+ * Covers the pure helpers behind the `rocm` and `xpu` runtime profiles:
+ * DRM vendor classification, render-group GID lookup, the device-node
+ * pre-flight, image repo routing and compose overlay selection.
+ */
+
+import { describe, it, expect, vi, afterEach } from 'vitest';
+
+vi.mock('electron', () => ({
+  app: {
+    isPackaged: false,
+    getPath: (name: string) => `/tmp/mock-${name}`,
+    setPath: vi.fn(),
+  },
+}));
+
+vi.mock('electron-store', () => ({
+  default: class MockStore {
+    get() {
+      return undefined;
+    }
+    set() {}
+  },
+}));
+
+import {
+  ROCM_IMAGE_REPO,
+  XPU_IMAGE_REPO,
+  checkGpuDeviceSupport,
+  composeFileArgs,
+  parseDrmVendors,
+  parseGroupGid,
+  resolveImageRepo,
+} from '../dockerManager.js';
+
+const originalPlatform = process.platform;
+
+afterEach(() => {
+  Object.defineProperty(process, 'platform', { value: originalPlatform, writable: true });
+});
+
+function existsFor(present: string[]): (p: string) => boolean {
+  return (p: string) => present.includes(p);
+}
+
+describe('GPU vendor detection', () => {
+  it('classifies DRM card vendor IDs', () => {
+    expect(parseDrmVendors(['0x1002\n'])).toEqual({ amd: true, intel: false });
+    expect(parseDrmVendors(['0x8086\n', '0x10de\n'])).toEqual({ amd: false, intel: true });
+    expect(parseDrmVendors([])).toEqual({ amd: false, intel: false });
+  });
+
+  it('reads the render group GID from /etc/group', () => {
+    const etcGroup = 'root:x:0:\nvideo:x:44:alice\nrender:x:992:alice\n';
+    expect(parseGroupGid(etcGroup, 'render')).toBe('992');
+    expect(parseGroupGid(etcGroup, 'kvm')).toBeNull();
+  });
+});
+
+describe('checkGpuDeviceSupport', () => {
+  const dri = ['/dev/dri', '/dev/dri/renderD128'];
+
+  it('accepts a Linux host with the required device nodes', () => {
+    const exists = existsFor([...dri, '/dev/kfd']);
+    expect(checkGpuDeviceSupport({ platform: 'linux', exists, profile: 'rocm' })).toBeNull();
+    expect(checkGpuDeviceSupport({ platform: 'linux', exists, profile: 'xpu' })).toBeNull();
+  });
+
+  it('requires /dev/kfd for ROCm only', () => {
+    const exists = existsFor(dri);
+    expect(checkGpuDeviceSupport({ platform: 'linux', exists, profile: 'rocm' })).toMatch(
+      /\/dev\/kfd was not found/,
+    );
+    expect(checkGpuDeviceSupport({ platform: 'linux', exists, profile: 'xpu' })).toBeNull();
+  });
+
+  it('rejects hosts without a render node or outside Linux', () => {
+    const noDri = existsFor(['/dev/kfd']);
+    expect(checkGpuDeviceSupport({ platform: 'linux', exists: noDri, profile: 'xpu' })).toMatch(
+      /\/dev\/dri was not found/,
+    );
+    expect(
+      checkGpuDeviceSupport({ platform: 'win32', exists: existsFor(dri), profile: 'rocm' }),
+    ).toMatch(/only supported on Linux/);
+  });
+});
+
+describe('rocm / xpu image and compose selection', () => {
+  it('routes each profile to its dedicated repo, ignoring the legacy toggle', () => {
+    expect(resolveImageRepo(false, 'rocm')).toBe(ROCM_IMAGE_REPO);
+    expect(resolveImageRepo(true, 'rocm')).toBe(ROCM_IMAGE_REPO);
+    expect(resolveImageRepo(false, 'xpu')).toBe(XPU_IMAGE_REPO);
+  });
+
+  it('adds the device overlay after the Linux host overlay', () => {
+    Object.defineProperty(process, 'platform', { value: 'linux', writable: true });
+    expect(composeFileArgs('rocm', 'docker', null)).toEqual([
+      '-f',
+      'docker-compose.yml',
+      '-f',
+      'docker-compose.linux-host.yml',
+      '-f',
+      'docker-compose.rocm.yml',
+    ]);
+    expect(composeFileArgs('xpu', 'podman', 'cdi').at(-1)).toBe('docker-compose.xpu.yml');
+  });
+});
@@ -0,0 +1,116 @@
+// @vitest-environment node
+
+import http from 'http';
+import { afterEach, beforeEach, describe, expect, it } from 'vitest';
+import { FollowAlongServer } from '../followAlong.js';
+
+/** Open the event stream and collect `event: data` pairs as they arrive. */
+function subscribe(url: string) {
+  const events: { event: string; data: unknown }[] = [];
+  let buffer = '';
+  let ended = false;
+  const waiters: (() => void)[] = [];
+  const notify = () => waiters.splice(0).forEach((wake) => wake());
+  const req = http.get(`${url}/events`, (res) => {
+    res.setEncoding('utf8');
+    res.on('data', (chunk: string) => {
+      buffer += chunk;
+      let split: number;
+      while ((split = buffer.indexOf('\n\n')) !== -1) {
+        const block = buffer.slice(0, split);
+        buffer = buffer.slice(split + 2);
+        const event = /^event: (.*)$/m.exec(block)?.[1];
+        const data = /^data: (.*)$/m.exec(block)?.[1];
+        if (event && data) events.push({ event, data: JSON.parse(data) });
+      }
+      notify();
+    });
+    res.on('end', () => {
+      ended = true;
+      notify();
+    });
+  });
+  const until = async (done: () => boolean) => {
+    while (!done()) await new Promise<void>((wake) => waiters.push(wake));
+  };
+  return {
+    events,
+    ended: () => ended,
+    count: (n: number) => until(() => events.length >= n),
+    end: () => until(() => ended),
+    close: () => req.destroy(),
+  };
+}
+
+describe('FollowAlongServer', () => {
+  let server: FollowAlongServer;
+
+  beforeEach(() => {
+    server = new FollowAlongServer({ host: '127.0.0.1' });
+  });
+
+  afterEach(async () => {
+    await server.stop();
+  });
+
+  it('serves the page only behind the session token', async () => {
+    const { url, qrSvg } = await server.start(0);
+
+    expect(url).toMatch(/^http:\/\/127\.0\.0\.1:\d+\/f\/[\w-]{22}$/);
+    expect(qrSvg).toMatch(/^<svg /);
+    expect((await fetch(url!)).status).toBe(200);
+    expect((await fetch(`${url}x`)).status).toBe(404);
+    expect((await fetch(url!, { method: 'POST' })).status).toBe(404);
+  });
+
+  it('streams a snapshot, then only what changed', async () => {
+    const { url } = await server.start(0);
+    server.update({ sentences: ['Hello.'], partial: 'and' });
+    const viewer = subscribe(url!);
+    await viewer.count(1);
+    expect(server.status().viewers).toBe(1);
+
+    server.update({ sentences: ['Hello.', 'And welcome.'], partial: '' });
+    server.update({ sentences: ['Hello.', 'And welcome.'], partial: '' });
+    server.update({ sentences: ['Restarted.'], partial: '' });
+    await viewer.count(4);
+
+    expect(viewer.events).toEqual([
+      { event: 'snapshot', data: { sentences: ['Hello.'], partial: 'and' } },
+      { event: 'sentence', data: { index: 1, text: 'And welcome.' } },
+      { event: 'partial', data: { text: '' } },
+      { event: 'snapshot', data: { sentences: ['Restarted.'], partial: '' } },
+    ]);
+    viewer.close();
+  });
+
+  it('ends the session for viewers and retires the link on stop', async () => {
+    const { url: first } = await server.start(0);
+    const viewer = subscribe(first!);
+    await viewer.count(1);
+
+    await server.stop();
+    await viewer.end();
+
+    expect(viewer.events.at(-1)).toEqual({ event: 'end', data: {} });
+    expect(server.status()).toMatchObject({ running: false, url: null, viewers: 0 });
+
+    const { url: second } = await server.start(0);
+    expect(second).not.toBe(first);
+    const stale = new URL(new URL(first!).pathname, second!);
+    expect((await fetch(stale)).status).toBe(404);
+  });
+
+  it('starts a restarted session with an empty transcript', async () => {
+    await server.start(0);
+    server.update({ sentences: ['From the last meeting.'], partial: 'and then' });
+    await server.stop();
+
+    const { url } = await server.start(0);
+    const viewer = subscribe(url!);
+    await viewer.count(1);
+
+    expect(viewer.events).toEqual([{ event: 'snapshot', data: { sentences: [], partial: '' } }]);
+    viewer.close();
+  });
+});
@@ -0,0 +1,25 @@
+// @vitest-environment node
+
+import { describe, expect, it, vi } from 'vitest';
+import { installHidAccess, isHidGrant } from '../hidAccess.js';
+
+describe('hidAccess', () => {
+  it('grants HID to the packaged app only', () => {
+    expect(isHidGrant({ deviceType: 'hid', origin: 'file://' })).toBe(true);
+    expect(isHidGrant({ deviceType: 'usb', origin: 'file://' })).toBe(false);
+    expect(isHidGrant({ deviceType: 'hid', origin: 'https://example.com' })).toBe(false);
+  });
+
+  it('grants HID to the dev server origin when one is running', () => {
+    const dev = 'http://localhost:3000/';
+    expect(isHidGrant({ deviceType: 'hid', origin: 'http://localhost:3000' }, dev)).toBe(true);
+    expect(isHidGrant({ deviceType: 'hid', origin: 'http://localhost:4000' }, dev)).toBe(false);
+  });
+
+  it('installs the handler on the session', () => {
+    const setDevicePermissionHandler = vi.fn();
+    installHidAccess({ setDevicePermissionHandler }, undefined);
+    const handler = setDevicePermissionHandler.mock.calls[0][0];
+    expect(handler({ deviceType: 'hid', origin: 'file://' })).toBe(true);
+  });
+});
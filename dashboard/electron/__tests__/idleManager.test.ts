@@ -0,0 +1,106 @@
+// @vitest-environment node
+
+import { describe, it, expect, vi } from 'vitest';
+
+import { IdleManager, type IdleStatus } from '../idleManager.js';
+
+function makeManager(overrides: { busy?: boolean; running?: boolean; manages?: boolean } = {}) {
+  const state = { now: 0, running: overrides.running ?? true, busy: overrides.busy ?? false };
+  const settings = { enabled: true, minutes: 10 };
+  const statuses: IdleStatus[] = [];
+  const stop = vi.fn(async () => {
+    state.running = false;
+  });
+  const start = vi.fn(async () => {
+    state.running = true;
+  });
+  const manager = new IdleManager({
+    settings: () => settings,
+    manages: () => overrides.manages ?? true,
+    containerRunning: async () => state.running,
+    serverBusy: async () => state.busy,
+    stop,
+    start,
+    emit: (status) => statuses.push(status),
+    now: () => state.now,
+  });
+  return { manager, state, settings, statuses, stop, start };
+}
+
+const MINUTE = 60_000;
+
+describe('idle manager', () => {
+  it('stops the container after the idle period and wakes it on demand', async () => {
+    const { manager, state, stop, start, statuses } = makeManager();
+
+    await manager.check();
+    expect(manager.status()).toMatchObject({ state: 'active', lastActivityAt: 0 });
+
+    state.now = 9 * MINUTE;
+    await manager.check();
+    expect(stop).not.toHaveBeenCalled();
+
+    state.now = 10 * MINUTE;
+    await manager.check();
+    expect(stop).toHaveBeenCalledTimes(1);
+    expect(manager.status()).toMatchObject({ state: 'sleeping', sleepingSince: 10 * MINUTE });
+    expect(statuses.map((s) => s.state)).toContain('stopping');
+
+    state.now = 30 * MINUTE;
+    const [first, second] = await Promise.all([manager.wake(), manager.wake()]);
+    expect([first, second]).toEqual([true, true]);
+    expect(start).toHaveBeenCalledTimes(1);
+    expect(manager.status()).toMatchObject({ state: 'active', lastActivityAt: 30 * MINUTE });
+  });
+
+  it('keeps a busy server up and counts wake-ups as activity', async () => {
+    const { manager, state, stop } = makeManager({ busy: true });
+    await manager.check();
+
+    state.now = 20 * MINUTE;
+    await manager.check();
+    expect(stop).not.toHaveBeenCalled();
+    expect(manager.status().lastActivityAt).toBe(20 * MINUTE);
+
+    state.busy = false;
+    state.now = 29 * MINUTE;
+    expect(await manager.wake()).toBe(false);
+    state.now = 35 * MINUTE;
+    await manager.check();
+    expect(stop).not.toHaveBeenCalled();
+  });
+
+  it('leaves a container the user stopped alone', async () => {
+    const { manager, state, start } = makeManager();
+    await manager.check();
+
+    state.running = false;
+    await manager.check();
+    expect(manager.status().state).toBe('off');
+    expect(await manager.wake()).toBe(false);
+    expect(start).not.toHaveBeenCalled();
+  });
+
+  it('turns off when disabled or for a remote server', async () => {
+    const remote = makeManager({ manages: false });
+    await remote.manager.check();
+    expect(remote.manager.status().state).toBe('off');
+
+    const { manager, settings } = makeManager();
+    await manager.check();
+    settings.enabled = false;
+    await manager.check();
+    expect(manager.status()).toMatchObject({ state: 'off', lastActivityAt: null });
+  });
+
+  it('stays sleeping with the error when the start fails', async () => {
+    const { manager, state, start } = makeManager();
+    await manager.check();
+    state.now = 10 * MINUTE;
+    await manager.check();
+
+    start.mockRejectedValueOnce(new Error('no runtime'));
+    await expect(manager.wake()).rejects.toThrow('no runtime');
+    expect(manager.status()).toMatchObject({ state: 'sleeping', error: 'no runtime' });
+  });
+});
@@ -0,0 +1,90 @@
+// @vitest-environment node
+
+import { createHash } from 'crypto';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest';
+import { JobMediaIntegrityError, downloadJobMedia, jobMediaUrl } from '../jobMedia.js';
+
+const AUDIO = Buffer.from('RIFF....WAVEfmt kept on the server');
+const SHA256 = createHash('sha256').update(AUDIO).digest('hex');
+
+function serve(body: Buffer, init: ResponseInit = {}) {
+  return vi.fn(async () => new Response(new Uint8Array(body), init)) as unknown as typeof fetch;
+}
+
+describe('downloadJobMedia', () => {
+  let dir: string;
+  let destination: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'job-media-'));
+    destination = path.join(dir, 'interview.wav');
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('writes the file once its digest matches', async () => {
+    const fetchImpl = serve(AUDIO, { headers: { 'X-Content-SHA256': SHA256 } });
+
+    const result = await downloadJobMedia({
+      serverUrl: 'https://host:9786/',
+      token: 'tok',
+      jobId: 'job-full-id',
+      destination,
+      expectedSha256: SHA256.toUpperCase(),
+      fetch: fetchImpl,
+    });
+
+    expect(result).toEqual({ path: destination, bytes: AUDIO.length, sha256: SHA256 });
+    expect(fs.readFileSync(destination)).toEqual(AUDIO);
+    expect(fs.existsSync(`${destination}.part`)).toBe(false);
+    expect(fetchImpl).toHaveBeenCalledWith(jobMediaUrl('https://host:9786', 'job-full-id'), {
+      headers: { Authorization: 'Bearer tok' },
+      signal: undefined,
+    });
+  });
+
+  it('discards a download whose bytes do not match', async () => {
+    const download = downloadJobMedia({
+      serverUrl: 'http://localhost:9786',
+      jobId: 'job-full-id',
+      destination,
+      expectedSha256: SHA256,
+      fetch: serve(Buffer.from('something else')),
+    });
+
+    await expect(download).rejects.toBeInstanceOf(JobMediaIntegrityError);
+    expect(fs.readdirSync(dir)).toEqual([]);
+  });
+
+  it('refuses a server copy announced with another digest', async () => {
+    const download = downloadJobMedia({
+      serverUrl: 'http://localhost:9786',
+      jobId: 'job-full-id',
+      destination,
+      expectedSha256: SHA256,
+      fetch: serve(AUDIO, { headers: { 'X-Content-SHA256': 'ab'.repeat(32) } }),
+    });
+
+    await expect(download).rejects.toThrow('not the file that was uploaded');
+    expect(fs.readdirSync(dir)).toEqual([]);
+  });
+
+  it('explains a copy the server no longer keeps', async () => {
+    const download = downloadJobMedia({
+      serverUrl: 'http://localhost:9786',
+      jobId: 'job-full-id',
+      destination,
+      expectedSha256: SHA256,
+      fetch: serve(Buffer.from('{"detail":"No server copy is kept for this job"}'), {
+        status: 410,
+      }),
+    });
+
+    await expect(download).rejects.toThrow('no longer keeps a copy');
+  });
+});
@@ -0,0 +1,77 @@
+// @vitest-environment node
+
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { afterEach, beforeEach, describe, expect, it } from 'vitest';
+import {
+  isLocaleCode,
+  listLocales,
+  loadCatalog,
+  parseFtl,
+  resolveInitialLocale,
+  type CatalogDirs,
+} from '../localeCatalog.js';
+
+describe('localeCatalog', () => {
+  let dirs: CatalogDirs;
+
+  beforeEach(() => {
+    const root = fs.mkdtempSync(path.join(os.tmpdir(), 'locales-'));
+    dirs = { bundled: path.join(root, 'bundled'), user: path.join(root, 'user') };
+    fs.mkdirSync(dirs.bundled);
+    fs.mkdirSync(dirs.user);
+    fs.writeFileSync(path.join(dirs.bundled, 'en.ftl'), 'locale-name = English\nhello = Hello\n');
+    fs.writeFileSync(
+      path.join(dirs.bundled, 'el.ftl'),
+      'locale-name = Ελληνικά\nhello = Γεια\nbye = Αντίο\n',
+    );
+  });
+
+  afterEach(() => {
+    fs.rmSync(path.dirname(dirs.bundled), { recursive: true, force: true });
+  });
+
+  it('parses simple Fluent messages with continuation lines', () => {
+    const source = [
+      '# comment',
+      'title = Settings',
+      'hint = First line',
+      '    second line',
+      '    .tooltip = ignored attribute',
+      '-brand = Term (skipped)',
+      'greet = Hi { $name }',
+    ].join('\n');
+    expect(parseFtl(source)).toEqual({
+      title: 'Settings',
+      hint: 'First line second line',
+      greet: 'Hi { $name }',
+    });
+  });
+
+  it('lists bundled and user locales, and user files override messages', () => {
+    fs.writeFileSync(path.join(dirs.user, 'el.ftl'), 'bye = Γεια χαρά\n');
+    fs.writeFileSync(path.join(dirs.user, 'pt-BR.ftl'), 'locale-name = Português\n');
+    fs.writeFileSync(path.join(dirs.user, '..evil.ftl'), 'x = y\n');
+    expect(listLocales(dirs)).toEqual([
+      { code: 'el', name: 'Ελληνικά', source: 'bundled' },
+      { code: 'en', name: 'English', source: 'bundled' },
+      { code: 'pt-BR', name: 'Português', source: 'user' },
+    ]);
+    expect(loadCatalog(dirs, 'el').messages).toMatchObject({ hello: 'Γεια', bye: 'Γεια χαρά' });
+  });
+
+  it('falls back to English for unknown or malformed codes', () => {
+    expect(loadCatalog(dirs, 'fr').code).toBe('en');
+    expect(loadCatalog(dirs, '../en').messages.hello).toBe('Hello');
+    expect(isLocaleCode('zh-Hant')).toBe(true);
+    expect(isLocaleCode('../etc')).toBe(false);
+  });
+
+  it('picks the stored locale, then the system locale or its base language', () => {
+    const available = listLocales(dirs);
+    expect(resolveInitialLocale('el', 'en-US', available)).toBe('el');
+    expect(resolveInitialLocale(undefined, 'el-GR', available)).toBe('el');
+    expect(resolveInitialLocale('xx', 'fr-FR', available)).toBe('en');
+  });
+});
@@ -0,0 +1,129 @@
+// @vitest-environment node
+
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest';
+import {
+  MediaClipError,
+  clipArgs,
+  clipDir,
+  clipMedia,
+  panFilter,
+  removeClip,
+} from '../mediaClip.js';
+
+describe('clipArgs', () => {
+  it('seeks before the input and keeps only the first audio stream', () => {
+    const edit = { startMs: 600_000, endMs: 1_200_500 };
+    expect(clipArgs('/rec/talk.mp4', edit, '/tmp/out.flac')).toEqual([
+      '-hide_banner',
+      '-nostdin',
+      '-loglevel',
+      'error',
+      '-y',
+      '-ss',
+      '600.000',
+      '-i',
+      '/rec/talk.mp4',
+      '-t',
+      '600.500',
+      '-map',
+      '0:a:0',
+      '-vn',
+      '-c:a',
+      'flac',
+      '/tmp/out.flac',
+    ]);
+  });
+
+  it('isolates or downmixes channels without cutting when there is no range', () => {
+    const args = clipArgs('/rec/panel.wav', { channels: [2] }, '/tmp/out.flac');
+
+    expect(args).not.toContain('-ss');
+    expect(args).not.toContain('-t');
+    expect(args[args.indexOf('-af') + 1]).toBe('pan=mono|c0=c2');
+    expect(panFilter([0, 2])).toBe('pan=mono|c0=0.5*c0+0.5*c2');
+  });
+});
+
+describe('clipMedia', () => {
+  let tmpRoot: string;
+  let source: string;
+
+  beforeEach(() => {
+    tmpRoot = fs.mkdtempSync(path.join(os.tmpdir(), 'media-clip-'));
+    source = path.join(tmpRoot, 'talk.wav');
+    fs.writeFileSync(source, 'RIFF....WAVE');
+  });
+
+  afterEach(() => {
+    fs.rmSync(tmpRoot, { recursive: true, force: true });
+  });
+
+  /** Stands in for ffmpeg: writes `bytes` to the last argument. */
+  const fakeFfmpeg = (bytes: string) =>
+    vi.fn(async (_command: string, args: string[]) => {
+      fs.writeFileSync(args[args.length - 1], bytes);
+    });
+
+  it('writes the clip into the clip folder, named after the key', async () => {
+    const run = fakeFfmpeg('fLaC');
+
+    const clip = await clipMedia({
+      source,
+      startMs: 1000,
+      endMs: 3000,
+      key: 'session-import-1/2',
+      tmpRoot,
+      run,
+    });
+
+    expect(clip).toEqual({
+      path: path.join(clipDir(tmpRoot), 'session-import-1_2.flac'),
+      startMs: 1000,
+      endMs: 3000,
+    });
+    expect(run.mock.calls[0][0]).toBe('ffmpeg');
+    expect(fs.readFileSync(clip.path, 'utf8')).toBe('fLaC');
+  });
+
+  it('rejects a range that does not move forward', async () => {
+    const run = fakeFfmpeg('fLaC');
+    const clip = clipMedia({ source, startMs: 5000, endMs: 5000, key: 'k', tmpRoot, run });
+
+    await expect(clip).rejects.toThrow('must end after it starts');
+    expect(run).not.toHaveBeenCalled();
+  });
+
+  it('discards an empty clip from a range past the end', async () => {
+    const clip = clipMedia({
+      source,
+      startMs: 9_000_000,
+      endMs: 9_600_000,
+      key: 'k',
+      tmpRoot,
+      run: fakeFfmpeg(''),
+    });
+
+    await expect(clip).rejects.toBeInstanceOf(MediaClipError);
+    expect(fs.readdirSync(clipDir(tmpRoot))).toEqual([]);
+  });
+
+  it('only removes files inside the clip folder', async () => {
+    const clip = await clipMedia({
+      source,
+      startMs: 0,
+      endMs: 1000,
+      key: 'k',
+      tmpRoot,
+      run: fakeFfmpeg('fLaC'),
+    });
+
+    await removeClip(source, tmpRoot);
+    await removeClip(clip.path, tmpRoot);
+
+    expect(fs.existsSync(source)).toBe(true);
+    expect(fs.existsSync(clip.path)).toBe(false);
+  });
+});
@@ -0,0 +1,57 @@
+// @vitest-environment node
+
+import { describe, expect, it } from 'vitest';
+import { MLX_DEFAULT_MODEL, prefersNativeMetal, selectNativeMetalOnce } from '../nativeLaunch.js';
+
+function makeStore(initial: Record<string, unknown> = {}) {
+  const data = new Map<string, unknown>(Object.entries(initial));
+  return {
+    get: (k: string) => data.get(k),
+    set: (k: string, v: unknown) => {
+      data.set(k, v);
+    },
+    _data: data,
+  };
+}
+
+const appleSilicon = { platform: 'darwin' as const, arch: 'arm64', metalBackendBundled: true };
+
+describe('prefersNativeMetal', () => {
+  it('only picks Metal on arm64 Macs with the backend bundled', () => {
+    expect(prefersNativeMetal(appleSilicon)).toBe(true);
+    expect(prefersNativeMetal({ ...appleSilicon, arch: 'x64' })).toBe(false);
+    expect(prefersNativeMetal({ ...appleSilicon, metalBackendBundled: false })).toBe(false);
+    expect(prefersNativeMetal({ ...appleSilicon, platform: 'linux' })).toBe(false);
+  });
+});
+
+describe('selectNativeMetalOnce', () => {
+  it('selects Metal and the MLX model on first launch', () => {
+    const store = makeStore({
+      'server.runtimeProfile': 'cpu',
+      'server.mainModelSelection': 'nvidia/parakeet-tdt-0.6b-v3',
+    });
+    expect(selectNativeMetalOnce(store, appleSilicon)).toBe(true);
+    expect(store._data.get('server.runtimeProfile')).toBe('metal');
+    expect(store._data.get('server.mainModelSelection')).toBe(MLX_DEFAULT_MODEL);
+    expect(store._data.get('server.gpuAutoDetectDone')).toBe(true);
+  });
+
+  it('keeps a model the user chose', () => {
+    const store = makeStore({ 'server.mainModelSelection': 'mlx-community/whisper-large-v3-turbo' });
+    selectNativeMetalOnce(store, appleSilicon);
+    expect(store._data.get('server.mainModelSelection')).toBe(
+      'mlx-community/whisper-large-v3-turbo',
+    );
+  });
+
+  it('never overrides an earlier choice or other hosts', () => {
+    const done = makeStore({ 'server.gpuAutoDetectDone': true, 'server.runtimeProfile': 'cpu' });
+    expect(selectNativeMetalOnce(done, appleSilicon)).toBe(false);
+    expect(done._data.get('server.runtimeProfile')).toBe('cpu');
+
+    const intel = makeStore({ 'server.runtimeProfile': 'cpu' });
+    expect(selectNativeMetalOnce(intel, { ...appleSilicon, arch: 'x64' })).toBe(false);
+    expect(intel._data.has('server.gpuAutoDetectDone')).toBe(false);
+  });
+});
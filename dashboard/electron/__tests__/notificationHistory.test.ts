@@ -0,0 +1,78 @@
+// @vitest-environment node
+
+import { describe, it, expect } from 'vitest';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+import { NotificationHistory } from '../notificationHistory';
+
+function makeDir(): string {
+  return fs.mkdtempSync(path.join(os.tmpdir(), 'notif-history-'));
+}
+
+const entry = (entryId: string, createdAt: number, status = 'complete') => ({
+  entryId,
+  id: entryId.split('#')[0],
+  category: 'transcription',
+  title: `Job ${entryId}`,
+  status,
+  createdAt,
+  toastDismissed: false,
+});
+
+describe('NotificationHistory', () => {
+  it('lists recorded entries newest first and survives reopening', () => {
+    const dir = makeDir();
+    const history = new NotificationHistory(dir);
+    history.record([entry('a#1', 1000), entry('b#1', 2000), { title: 'malformed' }]);
+    history.close();
+
+    const reopened = new NotificationHistory(dir);
+    expect(reopened.list().map((n) => n.entryId)).toEqual(['b#1', 'a#1']);
+    expect(reopened.unreadCount()).toBe(2);
+    reopened.close();
+  });
+
+  it('tracks read state and makes a finished entry unread again', () => {
+    const history = new NotificationHistory(makeDir());
+    history.record([entry('job#1', 1000, 'active')]);
+    history.markRead(['job#1']);
+    expect(history.list({ unreadOnly: true })).toEqual([]);
+
+    history.record([entry('job#1', 1000, 'complete')]);
+    expect(history.list({ unreadOnly: true })).toMatchObject([
+      { entryId: 'job#1', status: 'complete', read: false },
+    ]);
+
+    history.markRead();
+    expect(history.unreadCount()).toBe(0);
+  });
+
+  it('hides dismissed entries', () => {
+    const history = new NotificationHistory(makeDir());
+    history.record([entry('a#1', 1000), entry('b#1', 2000)]);
+
+    expect(history.dismiss('a#1')).toBe(true);
+    expect(history.dismiss('missing')).toBe(false);
+    expect(history.list().map((n) => n.entryId)).toEqual(['b#1']);
+  });
+
+  it('marks entries left active by a previous session as interrupted', () => {
+    const dir = makeDir();
+    const history = new NotificationHistory(dir);
+    history.record([entry('download#1', 1000, 'active')]);
+    history.close();
+
+    const [record] = new NotificationHistory(dir).list();
+    expect(record.status).toBe('error');
+    expect(record.error).toMatch(/interrupted/i);
+  });
+
+  it('pages with before and limit', () => {
+    const history = new NotificationHistory(makeDir());
+    history.record([entry('a#1', 1000), entry('b#1', 2000), entry('c#1', 3000)]);
+
+    expect(history.list({ limit: 1 }).map((n) => n.entryId)).toEqual(['c#1']);
+    expect(history.list({ before: 3000 }).map((n) => n.entryId)).toEqual(['b#1', 'a#1']);
+  });
+});
@@ -0,0 +1,37 @@
+// @vitest-environment node
+import { describe, it, expect, vi } from 'vitest';
+
+vi.mock('electron', () => ({ BrowserWindow: vi.fn() }));
+
+import { buildPrintToPdfOptions } from '../pdfExport';
+
+describe('buildPrintToPdfOptions', () => {
+  it('defaults to A4 with "Page x of y" numbering', () => {
+    const opts = buildPrintToPdfOptions({});
+    expect(opts.pageSize).toBe('A4');
+    expect(opts.preferCSSPageSize).toBe(true);
+    expect(opts.displayHeaderFooter).toBe(true);
+    expect(opts.footerTemplate).toContain('class="pageNumber"');
+    expect(opts.footerTemplate).toContain('class="totalPages"');
+  });
+
+  it('escapes header and footer text', () => {
+    const opts = buildPrintToPdfOptions({ header: 'A <b> & C', footer: '"quoted"' });
+    expect(opts.headerTemplate).toContain('A &lt;b&gt; &amp; C');
+    expect(opts.footerTemplate).toContain('&quot;quoted&quot;');
+  });
+
+  it('turns header/footer off entirely when nothing is requested', () => {
+    const opts = buildPrintToPdfOptions({ pageNumbers: 'none', header: '  ' });
+    expect(opts.displayHeaderFooter).toBe(false);
+    expect(opts.headerTemplate).toBe('<span></span>');
+    expect(opts.footerTemplate).toBe('<span></span>');
+  });
+
+  it('renders a bare page number for the "page" style', () => {
+    const opts = buildPrintToPdfOptions({ pageNumbers: 'page', pageSize: 'Letter' });
+    expect(opts.pageSize).toBe('Letter');
+    expect(opts.footerTemplate).toContain('class="pageNumber"');
+    expect(opts.footerTemplate).not.toContain('totalPages');
+  });
+});
@@ -0,0 +1,152 @@
+// @vitest-environment node
+
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { afterEach, beforeEach, describe, expect, it } from 'vitest';
+import {
+  PhoneIngestServer,
+  TOKEN_STORE_KEY,
+  lanAddress,
+  type PhoneIngestFile,
+} from '../phoneIngest.js';
+import { PENDING_MARKER } from '../cacheManager.js';
+import { encodeQr } from '../qrCode.js';
+
+function fakeStore() {
+  const data: Record<string, unknown> = {};
+  return {
+    data,
+    get: (key: string) => data[key],
+    set: (key: string, value: unknown) => {
+      data[key] = value;
+    },
+  };
+}
+
+describe('encodeQr', () => {
+  it('picks the smallest version that fits and draws the finder patterns', () => {
+    expect(encodeQr('hello')).toHaveLength(21);
+    const grid = encodeQr(`http://192.168.1.23:9787/p/${'x'.repeat(43)}`);
+    expect(grid).toHaveLength(37);
+    const size = grid.length;
+    for (const [x, y] of [
+      [0, 0],
+      [size - 7, 0],
+      [0, size - 7],
+    ]) {
+      // Outer ring dark, next ring light, 3×3 core dark.
+      expect(grid[y][x]).toBe(true);
+      expect(grid[y + 1][x + 1]).toBe(false);
+      expect(grid[y + 3][x + 3]).toBe(true);
+    }
+  });
+
+  it('refuses text past version 10', () => {
+    expect(() => encodeQr('x'.repeat(300))).toThrow('too long');
+  });
+});
+
+describe('lanAddress', () => {
+  it('skips loopback and IPv6 addresses', () => {
+    const iface = (address: string, family: 'IPv4' | 'IPv6', internal: boolean) =>
+      ({ address, family, internal }) as os.NetworkInterfaceInfo;
+    expect(
+      lanAddress({
+        lo: [iface('127.0.0.1', 'IPv4', true)],
+        wlan0: [iface('fe80::1', 'IPv6', false), iface('192.168.1.23', 'IPv4', false)],
+      }),
+    ).toBe('192.168.1.23');
+    expect(lanAddress({})).toBe('127.0.0.1');
+  });
+});
+
+describe('PhoneIngestServer', () => {
+  let cacheDir: string;
+  let server: PhoneIngestServer;
+  let received: PhoneIngestFile[];
+  let store: ReturnType<typeof fakeStore>;
+
+  beforeEach(() => {
+    cacheDir = fs.mkdtempSync(path.join(os.tmpdir(), 'phone-ingest-'));
+    received = [];
+    store = fakeStore();
+    server = new PhoneIngestServer({
+      store,
+      cacheDir,
+      host: '127.0.0.1',
+      maxBytes: 16,
+      onFile: (file) => received.push(file),
+    });
+  });
+
+  afterEach(async () => {
+    await server.stop();
+    fs.rmSync(cacheDir, { recursive: true, force: true });
+  });
+
+  const upload = (url: string, name: string, body: Uint8Array) =>
+    fetch(`${url}/upload`, {
+      method: 'POST',
+      headers: { 'X-Filename': encodeURIComponent(name) },
+      body,
+    });
+
+  it('serves the upload page only behind the pairing token', async () => {
+    const { url, qrSvg } = await server.start(0);
+
+    expect(url).toBe(`http://127.0.0.1:${server.status().port}/p/${store.data[TOKEN_STORE_KEY]}`);
+    expect(qrSvg).toMatch(/^<svg /);
+    expect((await fetch(url!)).status).toBe(200);
+    expect((await fetch(`${url}x`)).status).toBe(404);
+    expect((await fetch(new URL('/', url!))).status).toBe(404);
+  });
+
+  it('streams an upload into the cache and reports it', async () => {
+    const { url } = await server.start(0);
+
+    const res = await upload(url!, '../Voice Memo: 12.m4a', new Uint8Array([1, 2, 3]));
+
+    expect(res.status).toBe(201);
+    expect(received).toHaveLength(1);
+    expect(received[0]).toMatchObject({ name: 'Voice Memo_ 12.m4a', size: 3 });
+    expect(received[0].path.startsWith(path.join(cacheDir, 'phone-ingest'))).toBe(true);
+    expect([...fs.readFileSync(received[0].path)]).toEqual([1, 2, 3]);
+    expect(fs.existsSync(path.join(path.dirname(received[0].path), PENDING_MARKER))).toBe(true);
+  });
+
+  it('lists uploads until they are marked imported, across restarts', async () => {
+    const { url } = await server.start(0);
+    await upload(url!, 'a.m4a', new Uint8Array([1]));
+    await upload(url!, 'b.m4a', new Uint8Array([2]));
+    await server.stop();
+
+    const restarted = new PhoneIngestServer({ store, cacheDir, onFile: () => {} });
+    expect((await restarted.pending()).map((f) => f.name).sort()).toEqual(['a.m4a', 'b.m4a']);
+
+    expect(await restarted.markImported(received[0].path)).toBe(true);
+    expect(await restarted.markImported(received[0].path)).toBe(false);
+    expect(await restarted.markImported(path.join(cacheDir, 'elsewhere.m4a'))).toBe(false);
+    expect((await restarted.pending()).map((f) => f.path)).toEqual([received[1].path]);
+    expect(fs.existsSync(received[0].path)).toBe(true);
+  });
+
+  it('rejects non-media files and oversized uploads', async () => {
+    const { url } = await server.start(0);
+
+    expect((await upload(url!, 'notes.txt', new Uint8Array([1]))).status).toBe(415);
+    expect((await upload(url!, 'long.wav', new Uint8Array(64))).status).toBe(413);
+    expect(received).toEqual([]);
+    expect(fs.existsSync(path.join(cacheDir, 'phone-ingest'))).toBe(false);
+  });
+
+  it('unpairs old phones when the pairing is reset', async () => {
+    const { url: before } = await server.start(0);
+
+    const { url: after } = server.resetPairing();
+
+    expect(after).not.toBe(before);
+    expect((await fetch(before!)).status).toBe(404);
+    expect((await fetch(after!)).status).toBe(200);
+  });
+});
@@ -0,0 +1,154 @@
+// @vitest-environment node
+
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { afterEach, beforeEach, describe, expect, it } from 'vitest';
+import {
+  PLUGIN_ABI_VERSION,
+  PluginHost,
+  checkModule,
+  parseManifest,
+  runSandboxed,
+  type PluginTranscript,
+} from '../pluginHost.js';
+
+// ─── Hand-assembled ABI v1 modules ──────────────────────────────────────────
+
+const str = (s: string) => [s.length, ...Buffer.from(s)];
+const section = (id: number, bytes: number[]) => [id, bytes.length, ...bytes];
+const vec = (items: number[][]) => [items.length, ...items.flat()];
+
+/** `alloc` always answers 1024; `run` is the given body over (ptr, len). */
+function wasmModule(runBody: number[], extraImport = false): Uint8Array {
+  const imports = [[...str('env'), ...str('memory'), 0x02, 0x00, 0x01]];
+  if (extraImport) imports.push([...str('env'), ...str('print'), 0x00, 0x00]);
+  const first = extraImport ? 1 : 0;
+  const bodies = [
+    [0x00, 0x41, 0x80, 0x08, 0x0b],
+    [0x00, ...runBody, 0x0b],
+  ].map((b) => [b.length, ...b]);
+  return new Uint8Array([
+    ...[0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00],
+    ...section(1, vec([[0x60, 0x01, 0x7f, 0x01, 0x7f], [0x60, 0x02, 0x7f, 0x7f, 0x01, 0x7e]])),
+    ...section(2, vec(imports)),
+    ...section(3, vec([[0x00], [0x01]])),
+    ...section(7, vec([[...str('alloc'), 0x00, first], [...str('run'), 0x00, first + 1]])),
+    ...section(10, vec(bodies)),
+  ]);
+}
+
+/** Returns its input unchanged: (ptr << 32) | len. */
+const ECHO = [0x20, 0x00, 0xad, 0x42, 0x20, 0x86, 0x20, 0x01, 0xad, 0x84];
+/** Never returns. */
+const SPIN = [0x03, 0x40, 0x0c, 0x00, 0x0b, 0x42, 0x00];
+
+const LIMITS = { timeoutMs: 500, memoryPages: 16, maxOutputBytes: 4096 };
+
+const manifest = (overrides: Record<string, unknown> = {}) => ({
+  id: 'echo',
+  name: 'Echo',
+  version: '1.0.0',
+  kind: 'exporter',
+  abi: PLUGIN_ABI_VERSION,
+  wasm: 'echo.wasm',
+  exporter: { label: 'Echo JSON', extension: 'json' },
+  ...overrides,
+});
+
+function fakeStore(initial: Record<string, unknown> = {}) {
+  const data: Record<string, unknown> = { ...initial };
+  return {
+    get: (key: string) => data[key],
+    set: (key: string, value: unknown) => {
+      data[key] = value;
+    },
+  };
+}
+
+const TRANSCRIPT: PluginTranscript = {
+  abi: PLUGIN_ABI_VERSION,
+  title: 'Standup',
+  language: 'en',
+  duration: 4,
+  segments: [{ start: 0, end: 4, text: 'Morning all.', speaker: 'Speaker 1' }],
+};
+
+describe('parseManifest', () => {
+  it('accepts an exporter and rejects what the host cannot run', () => {
+    expect(parseManifest(manifest())).toMatchObject({ id: 'echo', kind: 'exporter' });
+    expect(() => parseManifest(manifest({ abi: 2 }))).toThrow(/ABI 2/);
+    expect(() => parseManifest(manifest({ id: '../evil' }))).toThrow(/"id"/);
+    expect(() => parseManifest(manifest({ wasm: '../x.wasm' }))).toThrow(/"wasm"/);
+    expect(() => parseManifest(manifest({ exporter: undefined }))).toThrow(/exporters need/);
+  });
+});
+
+describe('checkModule', () => {
+  it('allows only the env.memory import', () => {
+    expect(() => checkModule(new WebAssembly.Module(wasmModule(ECHO)))).not.toThrow();
+    expect(() => checkModule(new WebAssembly.Module(wasmModule(ECHO, true)))).toThrow(
+      /env\.print/,
+    );
+  });
+});
+
+describe('runSandboxed', () => {
+  it('passes the input in and the output back', async () => {
+    const result = await runSandboxed(wasmModule(ECHO), new TextEncoder().encode('hi'), LIMITS);
+    expect(result.ok && new TextDecoder().decode(result.output)).toBe('hi');
+  });
+
+  it('stops a plugin that runs too long or returns too much', async () => {
+    expect(await runSandboxed(wasmModule(SPIN), new Uint8Array(1), LIMITS)).toEqual({
+      ok: false,
+      error: 'Plugin timed out after 0.5 s',
+    });
+    const big = await runSandboxed(wasmModule(ECHO), new Uint8Array(5000), LIMITS);
+    expect(big).toEqual({ ok: false, error: 'output is larger than 4096 bytes' });
+  });
+});
+
+describe('PluginHost', () => {
+  let root: string;
+  let source: string;
+
+  beforeEach(() => {
+    root = fs.mkdtempSync(path.join(os.tmpdir(), 'plugin-host-'));
+    source = path.join(root, 'download');
+    fs.mkdirSync(source);
+    fs.writeFileSync(path.join(source, 'plugin.json'), JSON.stringify(manifest()));
+    fs.writeFileSync(path.join(source, 'echo.wasm'), wasmModule(ECHO));
+  });
+
+  afterEach(() => {
+    fs.rmSync(root, { recursive: true, force: true });
+  });
+
+  it('installs, runs, disables and removes a plugin', async () => {
+    const host = new PluginHost(path.join(root, 'plugins'), fakeStore(), LIMITS);
+
+    await host.install(path.join(source, 'plugin.json'));
+    expect(host.list()).toEqual([expect.objectContaining({ id: 'echo', enabled: true })]);
+
+    const result = await host.run('echo', TRANSCRIPT);
+    expect(result.ok && JSON.parse(new TextDecoder().decode(result.output))).toEqual(TRANSCRIPT);
+
+    host.setEnabled('echo', false);
+    expect(await host.run('echo', TRANSCRIPT)).toEqual({
+      ok: false,
+      error: 'Plugin "Echo" is disabled',
+    });
+
+    await host.remove('echo');
+    expect(host.list()).toEqual([]);
+  });
+
+  it('refuses a module that breaks the ABI without installing it', async () => {
+    fs.writeFileSync(path.join(source, 'echo.wasm'), wasmModule(ECHO, true));
+    const host = new PluginHost(path.join(root, 'plugins'), fakeStore(), LIMITS);
+
+    await expect(host.install(path.join(source, 'plugin.json'))).rejects.toThrow(/env\.print/);
+    expect(host.list()).toEqual([]);
+  });
+});
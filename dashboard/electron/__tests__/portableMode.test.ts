@@ -0,0 +1,70 @@
+// @vitest-environment node
+
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { describe, expect, it, vi } from 'vitest';
+import {
+  PORTABLE_DATA_DIR,
+  applyPortablePaths,
+  executableDir,
+  resolvePortableRoot,
+  type PortableContext,
+} from '../portableMode.js';
+
+const ctx = (overrides: Partial<PortableContext> = {}): PortableContext => ({
+  argv: ['/opt/ts/transcriptionsuite'],
+  execPath: '/opt/ts/transcriptionsuite',
+  env: {},
+  platform: 'linux',
+  exists: () => false,
+  ...overrides,
+});
+
+describe('portableMode', () => {
+  it('is off unless flagged, marked or set in the environment', () => {
+    expect(resolvePortableRoot(ctx())).toBeNull();
+    const root = path.join('/opt/ts', PORTABLE_DATA_DIR);
+    expect(resolvePortableRoot(ctx({ argv: ['x', '--portable'] }))).toBe(root);
+    expect(resolvePortableRoot(ctx({ env: { TRANSCRIPTIONSUITE_PORTABLE: '1' } }))).toBe(root);
+    const marker = path.join('/opt/ts', 'portable.txt');
+    expect(resolvePortableRoot(ctx({ exists: (p) => p === marker }))).toBe(root);
+  });
+
+  it('resolves the folder the user sees for AppImage, Windows portable and .app bundles', () => {
+    expect(executableDir(ctx({ env: { APPIMAGE: '/media/usb/TS.AppImage' } }))).toBe('/media/usb');
+    expect(
+      executableDir(
+        ctx({
+          platform: 'win32',
+          env: { PORTABLE_EXECUTABLE_DIR: 'E:\\Apps' },
+          execPath: 'C:\\t\\a.exe',
+        }),
+      ),
+    ).toBe('E:\\Apps');
+    expect(
+      executableDir(
+        ctx({
+          platform: 'darwin',
+          execPath: '/Volumes/USB/TS.app/Contents/MacOS/TranscriptionSuite',
+        }),
+      ),
+    ).toBe('/Volumes/USB');
+  });
+
+  it('redirects every data path into the portable root', () => {
+    const root = fs.mkdtempSync(path.join(os.tmpdir(), 'portable-'));
+    const app = { setPath: vi.fn(), setAppLogsPath: vi.fn() };
+    const userData = applyPortablePaths(app, root);
+    expect(userData).toBe(path.join(root, 'TranscriptionSuite'));
+    expect(fs.existsSync(userData)).toBe(true);
+    expect(Object.fromEntries(app.setPath.mock.calls)).toEqual({
+      appData: root,
+      userData,
+      sessionData: userData,
+      crashDumps: path.join(userData, 'Crashpad'),
+    });
+    expect(app.setAppLogsPath).toHaveBeenCalledWith(path.join(userData, 'logs'));
+    fs.rmSync(root, { recursive: true, force: true });
+  });
+});
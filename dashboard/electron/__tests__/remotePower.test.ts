@@ -0,0 +1,107 @@
+// @vitest-environment node
+
+import { describe, it, expect, vi } from 'vitest';
+
+import {
+  RemotePower,
+  magicPacket,
+  parseMacAddress,
+  sshArgs,
+  sshSuspendFailed,
+  type RemotePowerSettings,
+} from '../remotePower.js';
+
+function makePower(upAfterProbes: number, overrides: Partial<RemotePowerSettings> = {}) {
+  const clock = { now: 0, probes: 0, busy: false };
+  const settings: RemotePowerSettings = {
+    macAddress: 'AA:BB:CC:DD:EE:FF',
+    broadcastAddress: '192.168.1.255',
+    port: 9,
+    wakeTimeoutSeconds: 60,
+    sshTarget: 'me@gpu-box',
+    sshCommand: '',
+    ...overrides,
+  };
+  const sendPacket = vi.fn(async () => {});
+  const runSsh = vi.fn(async () => {});
+  const power = new RemotePower({
+    settings: () => settings,
+    probe: async () => ++clock.probes > upAfterProbes,
+    serverBusy: async () => clock.busy,
+    sendPacket,
+    runSsh,
+    emit: () => {},
+    delay: async (ms) => {
+      clock.now += ms;
+    },
+    now: () => clock.now,
+  });
+  return { power, clock, sendPacket, runSsh };
+}
+
+describe('remote power', () => {
+  it('builds the magic packet from any common MAC notation', () => {
+    const mac = parseMacAddress('aa-bb-cc-dd-ee-ff');
+    expect(mac).toEqual(parseMacAddress('aabb.ccdd.eeff'));
+    expect(parseMacAddress('aa:bb:cc')).toBeNull();
+
+    const packet = magicPacket(mac!);
+    expect(packet.length).toBe(102);
+    expect(packet.subarray(0, 6).toString('hex')).toBe('ffffffffffff');
+    expect(packet.subarray(96).toString('hex')).toBe('aabbccddeeff');
+  });
+
+  it('does nothing when the server already answers', async () => {
+    const { power, sendPacket } = makePower(0);
+
+    expect(await power.wake('lan')).toBe(false);
+    expect(sendPacket).not.toHaveBeenCalled();
+    expect(power.status().wokenByUs).toBe(false);
+  });
+
+  it('sends the packet, resends while waiting and resolves once the server answers', async () => {
+    const { power, sendPacket } = makePower(12);
+
+    expect(await power.wake('tailscale')).toBe(true);
+    expect(sendPacket).toHaveBeenCalledTimes(2);
+    expect(sendPacket.mock.calls[0].slice(1)).toEqual(['192.168.1.255', 9]);
+    expect(power.status()).toMatchObject({ state: 'awake', wokenByUs: true });
+  });
+
+  it('fails after the timeout or without a MAC', async () => {
+    await expect(makePower(1000).power.wake('lan')).rejects.toThrow('did not answer within 60 s');
+    await expect(makePower(1, { macAddress: '' }).power.wake('lan')).rejects.toThrow(
+      'No valid MAC address',
+    );
+  });
+
+  it('suspends only a server it woke, once the queue is empty', async () => {
+    const { power, clock, runSsh } = makePower(1);
+    expect(await power.sleepIfIdle()).toBe(false);
+
+    await power.wake('lan');
+    clock.busy = true;
+    expect(await power.sleepIfIdle()).toBe(false);
+
+    clock.busy = false;
+    expect(await power.sleepIfIdle()).toBe(true);
+    expect(runSsh).toHaveBeenCalledWith('me@gpu-box', 'sudo systemctl suspend');
+    expect(power.status()).toMatchObject({ state: 'suspended', wokenByUs: false });
+  });
+
+  it('runs ssh without prompting and tells a suspend from a failure', () => {
+    expect(sshArgs('me@gpu-box', 'sudo systemctl suspend')).toEqual([
+      '-o',
+      'BatchMode=yes',
+      '-o',
+      'ConnectTimeout=10',
+      '--',
+      'me@gpu-box',
+      'sudo systemctl suspend',
+    ]);
+    expect(() => sshArgs('-oProxyCommand=touch /tmp/x', 'true')).toThrow(/Invalid ssh target/);
+    expect(sshSuspendFailed(255, 'Connection to gpu-box closed by remote host.')).toBe(false);
+    expect(sshSuspendFailed(255, 'me@gpu-box: Permission denied (publickey).')).toBe(true);
+    expect(sshSuspendFailed(1, 'sudo: a password is required')).toBe(true);
+  });
+});
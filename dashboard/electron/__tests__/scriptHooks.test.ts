@@ -0,0 +1,119 @@
+// @vitest-environment node
+
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest';
+import {
+  HOOK_LOG_FILE,
+  ScriptHooks,
+  hookTimeoutMs,
+  parseScripts,
+  runScript,
+  type HookScript,
+} from '../scriptHooks.js';
+
+/** A shell command running `script` with this test's node binary. */
+const node = (script: string) => `"${process.execPath}" -e "${script}"`;
+
+describe('parseScripts', () => {
+  it('keeps well-formed hooks and drops the rest', () => {
+    expect(
+      parseScripts([
+        { id: 'a', event: 'on_export', command: 'notify-send done' },
+        { id: 'b', event: 'on_startup', command: 'x', enabled: true },
+        { id: 'c', event: 'on_export', command: '  ', enabled: true },
+        null,
+      ]),
+    ).toEqual([{ id: 'a', event: 'on_export', command: 'notify-send done', enabled: true }]);
+    expect(parseScripts('nope')).toEqual([]);
+  });
+});
+
+describe('hookTimeoutMs', () => {
+  it('clamps the stored seconds and falls back to the default', () => {
+    const store = (value: unknown) => ({ get: () => value });
+    expect(hookTimeoutMs(store(5))).toBe(5000);
+    expect(hookTimeoutMs(store(0))).toBe(1000);
+    expect(hookTimeoutMs(store(undefined))).toBe(30_000);
+  });
+});
+
+describe('runScript', () => {
+  it('passes the input on stdin and collects the output', async () => {
+    const result = await runScript(
+      node("process.stdin.on('data', d => process.stdout.write('got ' + d))"),
+      'hello',
+      { timeoutMs: 10_000 },
+    );
+    expect(result).toEqual({ exitCode: 0, timedOut: false, error: null, output: 'got hello' });
+  });
+
+  it('reports the exit code and kills a script that runs too long', async () => {
+    expect(await runScript(node('process.exit(3)'), '', { timeoutMs: 10_000 })).toMatchObject({
+      exitCode: 3,
+      timedOut: false,
+    });
+    expect(await runScript(node('setInterval(() => {}, 1000)'), '', { timeoutMs: 300 })).toEqual(
+      expect.objectContaining({ exitCode: null, timedOut: true }),
+    );
+  });
+});
+
+describe('ScriptHooks', () => {
+  let logDir: string;
+
+  beforeEach(() => {
+    logDir = fs.mkdtempSync(path.join(os.tmpdir(), 'script-hooks-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(logDir, { recursive: true, force: true });
+  });
+
+  const hooksWith = (scripts: HookScript[]) => {
+    const data: Record<string, unknown> = { 'hooks.scripts': scripts };
+    const onRun = vi.fn();
+    return { hooks: new ScriptHooks({ store: { get: (k) => data[k] }, logDir, onRun }), onRun };
+  };
+
+  it('runs the enabled hooks for an event with the JSON payload and logs them', async () => {
+    const echo = node(
+      "process.stdin.on('data', d => { const e = JSON.parse(d); " +
+        "console.log(e.event, e.path, process.env.TRANSCRIPTIONSUITE_HOOK_EVENT) })",
+    );
+    const { hooks, onRun } = hooksWith([
+      { id: 'a', event: 'on_export', command: echo, enabled: true },
+      { id: 'b', event: 'on_export', command: echo, enabled: false },
+      { id: 'c', event: 'on_job_complete', command: echo, enabled: true },
+    ]);
+
+    const runs = await hooks.fire('on_export', { path: '/tmp/x.srt' });
+
+    expect(runs).toEqual([
+      expect.objectContaining({
+        scriptId: 'a',
+        exitCode: 0,
+        output: 'on_export /tmp/x.srt on_export\n',
+      }),
+    ]);
+    expect(onRun).toHaveBeenCalledTimes(1);
+    expect(hooks.history()).toEqual(runs);
+    const log = fs.readFileSync(path.join(logDir, HOOK_LOG_FILE), 'utf-8');
+    expect(log).toMatch(/\[on_export\] .* — exit 0 in \d+ ms\n {4}on_export \/tmp\/x\.srt/);
+  });
+
+  it('tests a hook with an example payload', async () => {
+    const { hooks } = hooksWith([
+      {
+        id: 'a',
+        event: 'on_recording_saved',
+        command: node("process.stdin.on('data', d => console.log(JSON.parse(d).test))"),
+        enabled: false,
+      },
+    ]);
+
+    expect(await hooks.test('a')).toMatchObject({ exitCode: 0, output: 'true\n' });
+    await expect(hooks.test('missing')).rejects.toThrow(/does not exist/);
+  });
+});
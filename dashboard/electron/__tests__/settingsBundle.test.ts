@@ -0,0 +1,139 @@
+// @vitest-environment node
+
+import crypto from 'crypto';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { describe, it, expect } from 'vitest';
+
+import {
+  buildSettingsBundle,
+  importSettings,
+  keyFingerprint,
+  localPath,
+  planImport,
+  portablePath,
+} from '../settingsBundle.js';
+
+const home = path.join(path.sep, 'home', 'ana');
+const key = crypto.generateKeyPairSync('ed25519').privateKey;
+
+const values: Record<string, unknown> = {
+  'connection.remoteHost': 'gpu-box.tailnet.ts.net',
+  'connection.authToken': 'secret-token',
+  'server.hfToken': 'hf_abc',
+  'captions.obs.password': 'hunter2',
+  'updates.lastStatus': { app: '1.0.0' },
+  'folderWatch.sessionWatchActive': true,
+  'folderWatch.sessionPath': path.join(home, 'Recordings', 'inbox'),
+  'output.filter.words': ['um', 'uh'],
+  'hooks.scripts': [{ id: 'h1', event: 'job.completed', command: 'notify', enabled: true }],
+  'app.autoCopy': false,
+};
+
+function makeStore(keys = Object.keys(values)) {
+  const written: Record<string, unknown> = {};
+  return {
+    store: values,
+    has: (k: string) => keys.includes(k),
+    set: (k: string, v: unknown) => {
+      written[k] = v;
+    },
+    written,
+  };
+}
+
+describe('settings bundle', () => {
+  it('strips secrets and machine state and sorts keys into sections', () => {
+    const bundle = buildSettingsBundle(values, key, { home, appVersion: '1.2.0' });
+    const all = Object.assign({}, ...Object.values(bundle.sections));
+
+    expect(Object.keys(all)).not.toContain('connection.authToken');
+    expect(Object.keys(all)).not.toContain('server.hfToken');
+    expect(Object.keys(all)).not.toContain('captions.obs.password');
+    expect(Object.keys(all)).not.toContain('updates.lastStatus');
+    expect(Object.keys(all)).not.toContain('folderWatch.sessionWatchActive');
+    expect(bundle.sections.profiles).toEqual({ 'connection.remoteHost': 'gpu-box.tailnet.ts.net' });
+    expect(bundle.sections.vocabularies).toEqual({ 'output.filter.words': ['um', 'uh'] });
+    expect(Object.keys(bundle.sections.rules)).toEqual(['hooks.scripts']);
+    expect(bundle.sections.settings['folderWatch.sessionPath']).toBe('~/Recordings/inbox');
+  });
+
+  it('maps paths into the new home and through user remaps', () => {
+    expect(portablePath(path.join(home, 'a', 'b'), home)).toBe('~/a/b');
+    expect(localPath('~/a/b', path.join(path.sep, 'Users', 'bo'))).toBe(
+      path.join(path.sep, 'Users', 'bo', 'a', 'b'),
+    );
+    const remaps = [
+      { from: 'D:\\Audio', to: '/mnt/audio' },
+      { from: 'D:\\Audio\\Talks', to: '/srv/talks' },
+    ];
+    expect(localPath('D:\\Audio\\Talks\\2024', home, remaps)).toBe(path.join('/srv/talks', '2024'));
+    expect(localPath('D:\\Audio\\x', home, remaps)).toBe(path.join('/mnt/audio', 'x'));
+  });
+
+  it('imports a signed bundle with hooks disabled and missing paths reported', () => {
+    const exported = buildSettingsBundle(values, key, { home, appVersion: '1.2.0' });
+    const bundle = JSON.parse(JSON.stringify(exported));
+    const newHome = path.join(path.sep, 'home', 'bo');
+    const plan = planImport(bundle, makeStore(), { home: newHome, exists: () => false });
+
+    expect(plan.values['folderWatch.sessionPath']).toBe(path.join(newHome, 'Recordings', 'inbox'));
+    expect(plan.missingPaths).toEqual([path.join(newHome, 'Recordings', 'inbox')]);
+    expect(plan.values['hooks.scripts']).toEqual([
+      { id: 'h1', event: 'job.completed', command: 'notify', enabled: false },
+    ]);
+    expect(plan.disabledHooks).toBe(1);
+    expect(plan.switchedOff).toEqual(['folderWatch.sessionWatchActive']);
+    expect(plan.values['folderWatch.sessionWatchActive']).toBe(false);
+    expect(plan.signer).toBe(keyFingerprint(crypto.createPublicKey(key)));
+    expect(plan.counts).toEqual({ settings: 2, profiles: 1, vocabularies: 1, rules: 1 });
+  });
+
+  it('turns off the switches for imported commands and paths', () => {
+    const armed = {
+      'power.sleepAfterJobs': true,
+      'power.sshTarget': 'root@gpu-box',
+      'power.sshCommand': 'rm -rf ~',
+      'captions.textFile.enabled': true,
+      'captions.textFile.path': '~/captions.txt',
+      'app.autoCopy': false,
+    };
+    const bundle = buildSettingsBundle(armed, key, { home, appVersion: '1.2.0' });
+    const plan = planImport(bundle, makeStore(Object.keys(armed)), { home, exists: () => true });
+
+    expect(plan.switchedOff).toEqual(['captions.textFile.enabled', 'power.sleepAfterJobs']);
+    expect(plan.values['power.sleepAfterJobs']).toBe(false);
+    expect(plan.values['captions.textFile.enabled']).toBe(false);
+    expect(plan.values['power.sshCommand']).toBe('rm -rf ~');
+    expect(plan.values['app.autoCopy']).toBe(false);
+  });
+
+  it('refuses an edited bundle and skips keys this app does not know', () => {
+    const bundle = buildSettingsBundle(values, key, { home, appVersion: '1.2.0' });
+    const edited = JSON.parse(JSON.stringify(bundle));
+    edited.sections.profiles['connection.remoteHost'] = 'evil.example.com';
+    expect(() => planImport(edited, makeStore(), { home })).toThrow('signature does not match');
+
+    const plan = planImport(bundle, makeStore(['app.autoCopy']), { home });
+    expect(Object.keys(plan.values)).toEqual(['app.autoCopy']);
+  });
+
+  it('writes nothing when the user declines or the file is not a bundle', async () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'bundle-'));
+    const file = path.join(dir, 'settings.json');
+    const bundle = buildSettingsBundle(values, key, { home, appVersion: '1.2.0' });
+    fs.writeFileSync(file, JSON.stringify(bundle));
+    const store = makeStore();
+
+    expect(await importSettings(store, file, { home, confirm: async () => false })).toBeNull();
+    expect(store.written).toEqual({});
+
+    fs.writeFileSync(file, '{"format":"other"}');
+    expect(await importSettings(store, file, { home })).toEqual({
+      ok: false,
+      error: 'Not a settings bundle',
+    });
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+});
@@ -0,0 +1,72 @@
+// @vitest-environment node
+
+import { describe, expect, it } from 'vitest';
+import {
+  exportKeymap,
+  importKeymap,
+  normalizeAccelerator,
+  resolveKeymap,
+  setShortcut,
+} from '../shortcutRegistry.js';
+
+function memoryStore(initial: Record<string, unknown> = {}) {
+  const data = new Map(Object.entries(initial));
+  return {
+    get: (key: string) => data.get(key),
+    set: (key: string, value: unknown) => void data.set(key, value),
+    delete: (key: string) => void data.delete(key),
+    data,
+  };
+}
+
+const accel = (store: ReturnType<typeof memoryStore>, id: string, platform = 'linux') =>
+  resolveKeymap(store, platform).find((e) => e.id === id)?.accelerator;
+
+describe('shortcutRegistry', () => {
+  it('normalizes modifier order, aliases and CommandOrControl per platform', () => {
+    expect(normalizeAccelerator('shift+ctrl+k', 'linux')).toBe('Ctrl+Shift+K');
+    expect(normalizeAccelerator('CommandOrControl+K', 'darwin')).toBe('Command+K');
+    expect(normalizeAccelerator('CmdOrCtrl+K', 'win32')).toBe('Ctrl+K');
+    expect(normalizeAccelerator('Ctrl+Shift', 'linux')).toBeNull();
+    expect(normalizeAccelerator('A+B', 'linux')).toBeNull();
+  });
+
+  it('applies per-platform defaults under stored overrides', () => {
+    const store = memoryStore({ 'shortcuts.app.playbackToggle': 'P' });
+    expect(accel(store, 'settings.open', 'darwin')).toBe('Command+,');
+    expect(accel(store, 'settings.open', 'linux')).toBe('Ctrl+,');
+    expect(accel(store, 'playback.toggle')).toBe('P');
+  });
+
+  it('refuses conflicting and malformed bindings, and resets with null', () => {
+    const store = memoryStore();
+    const clash = setShortcut(store, 'playback.back', 'l', 'linux');
+    expect(clash).toMatchObject({ ok: false, conflicts: ['playback.forward'] });
+    // A global shortcut conflicts with in-app ones too.
+    expect(setShortcut(store, 'start-recording', 'CommandOrControl+,', 'linux').ok).toBe(false);
+    expect(setShortcut(store, 'playback.back', 'Ctrl', 'linux').ok).toBe(false);
+    expect(setShortcut(store, 'nope', 'Ctrl+Q', 'linux').ok).toBe(false);
+
+    expect(setShortcut(store, 'playback.back', 'Shift+J', 'linux').ok).toBe(true);
+    expect(store.data.get('shortcuts.app.playbackBack')).toBe('Shift+J');
+    setShortcut(store, 'playback.back', null, 'linux');
+    expect(accel(store, 'playback.back')).toBe('J');
+  });
+
+  it('round-trips a keymap and rejects an import with a conflict', () => {
+    const source = memoryStore({ 'shortcuts.startRecording': 'Alt+Ctrl+R' });
+    const file = exportKeymap(source, 'linux');
+    expect(file.shortcuts['start-recording']).toBe('Alt+Ctrl+R');
+
+    const target = memoryStore();
+    expect(importKeymap(target, file, 'linux').ok).toBe(true);
+    expect(target.data.get('shortcuts.startRecording')).toBe('Alt+Ctrl+R');
+    // Defaults are not pinned into the store.
+    expect(target.data.has('shortcuts.app.playbackToggle')).toBe(false);
+
+    const bad = { ...file, shortcuts: { ...file.shortcuts, 'playback.toggle': 'J' } };
+    expect(importKeymap(target, bad, 'linux').ok).toBe(false);
+    expect(importKeymap(target, { version: 2, shortcuts: {} }, 'linux').ok).toBe(false);
+    expect(accel(target, 'playback.toggle')).toBe('K');
+  });
+});
@@ -0,0 +1,136 @@
+// @vitest-environment node
+
+import { describe, expect, it } from 'vitest';
+import {
+  formatStartupReport,
+  StartupSequencer,
+  type ProbeResult,
+  type StartupState,
+  type StartupSequencerDeps,
+} from '../startupSequencer.js';
+
+/** Fake clock: sleeping advances time instantly. */
+function harness(
+  respond: (pathname: string, now: number) => ProbeResult,
+  overrides: Partial<StartupSequencerDeps> = {},
+) {
+  let now = 1_000_000;
+  const states: StartupState[] = [];
+  let settle: (state: StartupState) => void = () => {};
+  const settled = new Promise<StartupState>((resolve) => (settle = resolve));
+  const sequencer = new StartupSequencer({
+    serverUrl: () => 'http://localhost:9786',
+    fetchJson: async (url) => respond(new URL(url).pathname, now),
+    checkVersion: () => null,
+    onState: (state) => {
+      states.push(state);
+      if (state.status === 'ready' || state.status === 'failed') settle(state);
+    },
+    now: () => now,
+    sleep: async (ms) => {
+      now += ms;
+    },
+    ...overrides,
+  });
+  return { sequencer, states, settled };
+}
+
+const healthy = (pathname: string): ProbeResult =>
+  pathname === '/api/status'
+    ? { status: 200, body: { version: '1.2.0' } }
+    : { status: 200, body: { status: 'ok' } };
+
+describe('StartupSequencer', () => {
+  it('walks every stage before reporting ready', async () => {
+    let modelLoadedAt = Infinity;
+    const { sequencer, states, settled } = harness((pathname, now) => {
+      if (pathname === '/ready' && now < modelLoadedAt) return { status: 503, body: {} };
+      return healthy(pathname);
+    });
+    await sequencer.run('docker', async () => {
+      modelLoadedAt = 1_000_000 + 5_000;
+    });
+
+    const final = await settled;
+    expect(final.status).toBe('ready');
+    expect(final.percent).toBe(100);
+    expect(final.stages.map((s) => s.status)).toEqual(['done', 'done', 'done', 'done']);
+    expect(final.stages[3].message).toBe('Server 1.2.0');
+    // Never ready before the model stage finished.
+    const firstReady = states.findIndex((s) => s.status === 'ready');
+    expect(states.slice(0, firstReady).every((s) => s.stages[2]?.status !== 'done')).toBe(true);
+  });
+
+  it('fails with a report when /ready stalls', async () => {
+    const loading = { status: 503, body: { status: 'loading' } };
+    const { sequencer, settled } = harness(
+      (pathname) => (pathname === '/ready' ? loading : healthy(pathname)),
+      { collectDiagnostics: async () => 'container: running' },
+    );
+    await sequencer.run('attach');
+
+    const final = await settled;
+    expect(final.status).toBe('failed');
+    expect(final.report?.failedStage).toBe('model');
+    expect(final.report?.reason).toMatch(/No progress for 600s/);
+    expect(final.report?.probes.length).toBe(20);
+    const text = formatStartupReport(final.report!);
+    expect(text).toContain('Failed stage: model');
+    expect(text).toContain('model GET /ready → 503 loading');
+    expect(text).toContain('container: running');
+  });
+
+  it('keeps waiting while activity is reported', async () => {
+    let probes = 0;
+    let noteActivity = () => {};
+    const { sequencer, settled } = harness((pathname) => {
+      if (pathname !== '/ready') return healthy(pathname);
+      probes += 1;
+      // Downloading a model: report activity every probe for 20 fake minutes.
+      if (probes < 1_200) {
+        noteActivity();
+        return { status: 503, body: {} };
+      }
+      return { status: 200, body: {} };
+    });
+    noteActivity = () => sequencer.noteActivity();
+    await sequencer.run('mlx', async () => {});
+    expect((await settled).status).toBe('ready');
+  });
+
+  it('surfaces a version mismatch as a warning, not a failure', async () => {
+    const { sequencer, settled } = harness(healthy, {
+      checkVersion: (version) => `Server ${version} is older than the app`,
+    });
+    await sequencer.run('docker', async () => {});
+    const final = await settled;
+    expect(final.status).toBe('ready');
+    expect(final.stages[3]).toMatchObject({ status: 'warning' });
+  });
+
+  it('rejects and reports when the backend fails or hangs', async () => {
+    const failing = harness(healthy);
+    await expect(
+      failing.sequencer.run('native', async () => {
+        throw new Error('uv sync failed');
+      }),
+    ).rejects.toThrow('uv sync failed');
+    expect((await failing.settled).report?.failedStage).toBe('backend');
+
+    const hanging = harness(healthy).sequencer;
+    await expect(hanging.run('docker', () => new Promise(() => {}))).rejects.toThrow(
+      /No progress for 900s/,
+    );
+    expect(hanging.getState().status).toBe('failed');
+  });
+
+  it('drops a superseded run', async () => {
+    const { sequencer, settled } = harness(healthy);
+    const first = sequencer.run('docker', () => new Promise((r) => setTimeout(r, 10)));
+    sequencer.cancel();
+    await first;
+    expect(sequencer.getState().status).toBe('idle');
+    await sequencer.run('attach');
+    expect((await settled).source).toBe('attach');
+  });
+});
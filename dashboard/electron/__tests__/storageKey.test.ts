@@ -0,0 +1,57 @@
+// @vitest-environment node
+
+import { describe, expect, it, vi } from 'vitest';
+import { STORAGE_KEY_STORE_KEY, getOrCreateStorageMasterKey } from '../storageKey.js';
+
+function fakeStore(initial: Record<string, unknown> = {}) {
+  const data = { ...initial };
+  return {
+    data,
+    get: (key: string) => data[key],
+    set: vi.fn((key: string, value: unknown) => {
+      data[key] = value;
+    }),
+  };
+}
+
+// Reversible stand-in for the OS keychain: "sealing" prefixes the text.
+const safeStorage = {
+  isEncryptionAvailable: () => true,
+  encryptString: (plain: string) => Buffer.from(`sealed:${plain}`),
+  decryptString: (sealed: Buffer) => {
+    const text = sealed.toString();
+    if (!text.startsWith('sealed:')) throw new Error('bad seal');
+    return text.slice('sealed:'.length);
+  },
+};
+
+describe('getOrCreateStorageMasterKey', () => {
+  it('creates a 32-byte key once and stores it only sealed', () => {
+    const store = fakeStore();
+    const key = getOrCreateStorageMasterKey(store, safeStorage);
+
+    expect(Buffer.from(key!, 'base64')).toHaveLength(32);
+    expect(store.data[STORAGE_KEY_STORE_KEY]).not.toContain(key);
+    expect(getOrCreateStorageMasterKey(store, safeStorage)).toBe(key);
+    expect(store.set).toHaveBeenCalledTimes(1);
+  });
+
+  it('returns null without secure storage and never writes a key', () => {
+    const store = fakeStore();
+    const key = getOrCreateStorageMasterKey(store, {
+      ...safeStorage,
+      isEncryptionAvailable: () => false,
+    });
+
+    expect(key).toBeNull();
+    expect(store.set).not.toHaveBeenCalled();
+  });
+
+  it('keeps an unreadable sealed key instead of replacing it', () => {
+    const store = fakeStore({ [STORAGE_KEY_STORE_KEY]: Buffer.from('garbage').toString('base64') });
+    vi.spyOn(console, 'error').mockImplementation(() => {});
+
+    expect(getOrCreateStorageMasterKey(store, safeStorage)).toBeNull();
+    expect(store.set).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,110 @@
+// @vitest-environment node
+
+import { describe, it, expect } from 'vitest';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+import {
+  MAX_GAP_MS,
+  TimeTracking,
+  creditedMs,
+  projectTotalsCsv,
+  recordingTotalsCsv,
+} from '../timeTracking';
+
+function makeDir(): string {
+  return fs.mkdtempSync(path.join(os.tmpdir(), 'time-tracking-'));
+}
+
+const working = { playing: false, focused: true };
+const recording = (recordingId: number, project: string, audioSeconds = 600) => ({
+  recordingId,
+  title: `Interview ${recordingId}`,
+  project,
+  audioSeconds,
+});
+
+describe('creditedMs', () => {
+  it('credits focused work under the idle limit and playback regardless of input', () => {
+    expect(creditedMs(15_000, working, 5, 120)).toBe(15_000);
+    expect(creditedMs(15_000, { playing: true, focused: false }, 600, 120)).toBe(15_000);
+    expect(creditedMs(15_000, { playing: false, focused: false }, 0, 120)).toBe(0);
+  });
+
+  it('stops counting once the user is past the idle limit', () => {
+    // Went past the limit 5 s ago: only the first 10 s of the gap count.
+    expect(creditedMs(15_000, working, 125, 120)).toBe(10_000);
+    expect(creditedMs(15_000, working, 300, 120)).toBe(0);
+  });
+
+  it('caps a late heartbeat', () => {
+    expect(creditedMs(3_600_000, { playing: true, focused: true }, 0, 120)).toBe(MAX_GAP_MS);
+  });
+});
+
+describe('TimeTracking', () => {
+  it('accumulates heartbeats per session and survives reopening', () => {
+    const dir = makeDir();
+    const tracking = new TimeTracking(dir);
+    const id = tracking.begin(recording(1, 'Acme'), 0);
+    expect(tracking.heartbeat(id, working, 0, 120, 15_000)).toBe(15_000);
+    expect(tracking.heartbeat(id, working, 0, 120, 30_000)).toBe(30_000);
+    tracking.end(id, 30_000);
+    expect(tracking.heartbeat(id, working, 0, 120, 45_000)).toBeNull();
+    tracking.close();
+
+    const reopened = new TimeTracking(dir);
+    expect(reopened.recordingTotals()).toMatchObject([
+      { project: 'Acme', recordingId: 1, title: 'Interview 1', activeMs: 30_000 },
+    ]);
+    reopened.close();
+  });
+
+  it('totals projects, counting each recording once, and drops empty sessions', () => {
+    const tracking = new TimeTracking(makeDir());
+    for (const [rec, project, start] of [
+      [1, 'Acme', 0],
+      [1, 'Acme', 100_000],
+      [2, 'Acme', 200_000],
+      [3, '', 300_000],
+    ] as const) {
+      const id = tracking.begin(recording(rec, project), start);
+      tracking.heartbeat(id, working, 0, 120, start + 60_000);
+      tracking.end(id, start + 60_000);
+    }
+    tracking.end(tracking.begin(recording(4, 'Globex'), 400_000), 401_000);
+
+    expect(tracking.projectTotals()).toEqual([
+      { project: '', recordings: 1, activeMs: 60_000, audioSeconds: 600 },
+      { project: 'Acme', recordings: 2, activeMs: 180_000, audioSeconds: 1200 },
+    ]);
+    expect(tracking.projectTotals({ from: 150_000, to: 250_000 })).toEqual([
+      { project: 'Acme', recordings: 1, activeMs: 60_000, audioSeconds: 600 },
+    ]);
+  });
+});
+
+describe('CSV export', () => {
+  it('writes a row per project plus a total, quoting where needed', () => {
+    const csv = projectTotalsCsv([
+      { project: 'Acme, Inc.', recordings: 2, activeMs: 5_400_000, audioSeconds: 1200 },
+      { project: '', recordings: 1, activeMs: 1_800_000, audioSeconds: 300 },
+    ]);
+    expect(csv.split('\r\n')).toEqual([
+      'Project,Recordings,Active hours,Active minutes,Audio minutes',
+      '"Acme, Inc.",2,1.50,90.0,20.0',
+      '(No project),1,0.50,30.0,5.0',
+      'Total,3,2.00,120.0,25.0',
+      '',
+    ]);
+  });
+
+  it('lists each recording for per-job invoices', () => {
+    const csv = recordingTotalsCsv([
+      { project: 'Acme', recordingId: 1, title: 'Board "Q3"', activeMs: 90_000, audioSeconds: 60 },
+    ]);
+    expect(csv).toBe(
+      'Project,Recording,Active minutes,Audio minutes\r\nAcme,"Board ""Q3""",1.5,1.0\r\n',
+    );
+  });
+});
@@ -0,0 +1,148 @@
+// @vitest-environment node
+
+import { describe, it, expect } from 'vitest';
+
+import {
+  DEFAULT_WHISPERCPP_TUNING,
+  benchmarkCandidates,
+  benchmarkWav,
+  detectCpuTopology,
+  formatCpuList,
+  normalizeWhispercppTuning,
+  pickBest,
+  sidecarArgs,
+  sidecarComposeEnv,
+  tuningForMachine,
+  type CpuTopology,
+} from '../whispercppTuning.js';
+
+function sysfs(files: Record<string, string>) {
+  return (file: string) => {
+    if (!(file in files)) throw new Error(`ENOENT: ${file}`);
+    return files[file];
+  };
+}
+
+const maxFreq = (cpu: number) => `/sys/devices/system/cpu/cpu${cpu}/cpufreq/cpuinfo_max_freq`;
+
+const HYBRID: CpuTopology = { logical: 8, performance: [0, 1, 2, 3], efficiency: [4, 5, 6, 7] };
+const UNIFORM: CpuTopology = { logical: 8, performance: [], efficiency: [] };
+
+describe('cpu lists', () => {
+  it('round-trips the sysfs/taskset syntax', () => {
+    expect(formatCpuList([11, 0, 1, 2, 3, 8, 10])).toBe('0-3,8,10-11');
+    expect(formatCpuList([])).toBe('');
+  });
+});
+
+describe('detectCpuTopology', () => {
+  it('reads the Intel hybrid PMU core lists', () => {
+    const read = sysfs({
+      '/sys/devices/cpu_core/cpus': '0-11\n',
+      '/sys/devices/cpu_atom/cpus': '12-19\n',
+    });
+    const topology = detectCpuTopology('linux', 20, read);
+    expect(topology.performance).toHaveLength(12);
+    expect(topology.efficiency).toEqual([12, 13, 14, 15, 16, 17, 18, 19]);
+  });
+
+  it('falls back to maximum clocks for other big/little designs', () => {
+    const clocks = ['3000000', '3000000', '1800000', '1800000'];
+    const read = sysfs(Object.fromEntries(clocks.map((khz, cpu) => [maxFreq(cpu), khz])));
+    expect(detectCpuTopology('linux', 4, read)).toEqual({
+      logical: 4,
+      performance: [0, 1],
+      efficiency: [2, 3],
+    });
+  });
+
+  it('keeps small boost differences and missing cpufreq uniform', () => {
+    const boost = sysfs(
+      Object.fromEntries([0, 1].map((cpu) => [maxFreq(cpu), cpu === 0 ? '5000000' : '4600000'])),
+    );
+    expect(detectCpuTopology('linux', 2, boost).efficiency).toEqual([]);
+    expect(detectCpuTopology('linux', 2, sysfs({})).efficiency).toEqual([]);
+  });
+
+  it('reports every CPU as uniform off Linux', () => {
+    expect(detectCpuTopology('win32', 16, sysfs({}))).toEqual({
+      logical: 16,
+      performance: [],
+      efficiency: [],
+    });
+  });
+});
+
+describe('stored settings', () => {
+  it('falls back to the defaults for missing or invalid fields', () => {
+    expect(normalizeWhispercppTuning(undefined)).toEqual(DEFAULT_WHISPERCPP_TUNING);
+    expect(normalizeWhispercppTuning({ threads: 1000, flashAttention: 'yes', cores: 'x' })).toEqual(
+      { threads: 256, flashAttention: false, cores: 'all' },
+    );
+  });
+
+  it('keeps each machine its own entry', () => {
+    const stored = { 'box · CPU · 8 threads': { threads: 6, cores: 'performance' } };
+    expect(tuningForMachine(stored, 'box · CPU · 8 threads')).toMatchObject({
+      threads: 6,
+      cores: 'performance',
+    });
+    expect(tuningForMachine(stored, 'laptop · CPU · 8 threads')).toEqual(
+      DEFAULT_WHISPERCPP_TUNING,
+    );
+  });
+});
+
+describe('sidecar launch', () => {
+  it('passes only non-default flags to the native exe', () => {
+    expect(sidecarArgs(DEFAULT_WHISPERCPP_TUNING)).toEqual([]);
+    expect(sidecarArgs({ threads: 6, flashAttention: true, cores: 'all' })).toEqual([
+      '--threads',
+      '6',
+      '--flash-attn',
+    ]);
+  });
+
+  it('pins the compose sidecar only on a hybrid CPU', () => {
+    const tuning = { threads: 4, flashAttention: false, cores: 'performance' as const };
+    expect(sidecarComposeEnv(tuning, HYBRID)).toEqual({
+      WHISPERCPP_THREADS: '4',
+      WHISPERCPP_FLASH_ATTN: '',
+      WHISPERCPP_CPUSET: '0-3',
+    });
+    expect(sidecarComposeEnv(tuning, UNIFORM).WHISPERCPP_CPUSET).toBe('');
+  });
+});
+
+describe('benchmark', () => {
+  it('adds a performance-cores candidate on hybrid CPUs', () => {
+    const uniform = benchmarkCandidates(UNIFORM);
+    expect(uniform).toHaveLength(4); // 4 and 8 threads, flash attention off/on
+    expect(uniform.every((c) => c.cores === 'all')).toBe(true);
+
+    const hybrid = benchmarkCandidates(HYBRID);
+    expect(hybrid.filter((c) => c.cores === 'performance')).toEqual([
+      { threads: 4, cores: 'performance', flashAttention: false },
+      { threads: 4, cores: 'performance', flashAttention: true },
+    ]);
+  });
+
+  it('picks the fastest successful run, preferring fewer threads on a tie', () => {
+    expect(
+      pickBest([
+        { threads: 8, flashAttention: false, cores: 'all', seconds: 2 },
+        { threads: 4, flashAttention: true, cores: 'all', seconds: 2 },
+        { threads: 2, flashAttention: true, cores: 'all', seconds: null, error: 'crashed' },
+      ]),
+    ).toEqual({ threads: 4, flashAttention: true, cores: 'all' });
+    expect(pickBest([])).toBeNull();
+  });
+
+  it('generates a 30 s 16 kHz mono PCM WAV', () => {
+    const wav = benchmarkWav();
+    expect(wav.toString('ascii', 0, 4)).toBe('RIFF');
+    expect(wav.readUInt32LE(24)).toBe(16_000);
+    expect(wav.readUInt32LE(40)).toBe(30 * 16_000 * 2);
+    expect(wav).toHaveLength(44 + 30 * 16_000 * 2);
+  });
+});
@@ -0,0 +1,128 @@
+// @vitest-environment node
+
+import { describe, expect, it, vi } from 'vitest';
+import {
+  decodeWslOutput,
+  firstIpv4,
+  parseInspect,
+  parseWslList,
+  WslBackendManager,
+  wslDockerRunArgs,
+  type WslBackendDeps,
+} from '../wslBackend.js';
+
+const LIST = [
+  '  NAME                   STATE           VERSION',
+  '* Ubuntu-24.04           Running         2',
+  '  docker-desktop         Stopped         2',
+  '  Legacy Debian          Stopped         1',
+  '',
+].join('\r\n');
+
+describe('wsl.exe output parsing', () => {
+  it('decodes UTF-16LE and plain UTF-8 output', () => {
+    expect(decodeWslOutput(Buffer.from('\uFEFFUbuntu\r\n', 'utf16le'))).toBe('Ubuntu\r\n');
+    expect(decodeWslOutput(Buffer.from('27.3.1\n', 'utf-8'))).toBe('27.3.1\n');
+  });
+
+  it('lists user distros and skips Docker Desktop internals', () => {
+    expect(parseWslList(LIST)).toEqual([
+      { name: 'Ubuntu-24.04', state: 'Running', version: 2, isDefault: true },
+      { name: 'Legacy Debian', state: 'Stopped', version: 1, isDefault: false },
+    ]);
+  });
+
+  it('reads container state and the distro address', () => {
+    expect(parseInspect('running\thealthy\t2026-01-01T00:00:00Z\n')).toMatchObject({
+      running: true,
+      health: 'healthy',
+    });
+    expect(parseInspect('exited\t<nil>\t')).toMatchObject({ running: false, health: undefined });
+    expect(firstIpv4('fe80::1 172.22.160.5 10.0.0.2\n')).toBe('172.22.160.5');
+    expect(firstIpv4('')).toBeNull();
+  });
+});
+
+describe('wslDockerRunArgs', () => {
+  it('mirrors the compose volumes, port and GPU reservation', () => {
+    const args = wslDockerRunArgs({
+      image: 'ghcr.io/homelab-00/transcriptionsuite-server:latest',
+      containerName: 'transcriptionsuite-container',
+      port: 9786,
+      gpu: true,
+      env: { HF_TOKEN: 'hf_x' },
+      userConfigDir: '/mnt/c/Users/u/AppData/Roaming/TranscriptionSuite/server-config',
+    });
+    expect(args.slice(0, 4)).toEqual(['run', '-d', '--name', 'transcriptionsuite-container']);
+    expect(args).toContain('9786:9786');
+    expect(args).toContain('transcriptionsuite-models:/models');
+    expect(args.join(' ')).toContain('--gpus all');
+    expect(args).toContain('HF_TOKEN=hf_x');
+    expect(args).toContain('SERVER_PORT=9786');
+    expect(args.at(-1)).toBe('ghcr.io/homelab-00/transcriptionsuite-server:latest');
+
+    const cpu = wslDockerRunArgs({
+      image: 'img',
+      containerName: 'c',
+      port: 1,
+      gpu: false,
+      env: {},
+    });
+    expect(cpu).not.toContain('--gpus');
+    expect(cpu.join(' ')).not.toContain('/user-config');
+  });
+});
+
+describe('WslBackendManager', () => {
+  const deps = (overrides: Partial<WslBackendDeps> = {}): WslBackendDeps => ({
+    runWsl: vi.fn(async () => ''),
+    canConnect: vi.fn(async () => false),
+    onStatus: vi.fn(),
+    ...overrides,
+  });
+
+  it('runs docker through the selected distro', async () => {
+    const runWsl = vi.fn(async () => '27.3.1\n');
+    const probe = await new WslBackendManager(deps({ runWsl })).probe('Ubuntu');
+    expect(probe).toEqual({ available: true, dockerVersion: '27.3.1' });
+    expect(runWsl).toHaveBeenCalledWith(
+      ['-d', 'Ubuntu', '--', 'docker', 'version', '--format', '{{.Server.Version}}'],
+      undefined,
+    );
+
+    const failing = deps({ runWsl: vi.fn(async () => Promise.reject(new Error('not found'))) });
+    expect(await new WslBackendManager(failing).probe('Ubuntu')).toMatchObject({
+      available: false,
+    });
+  });
+
+  it('falls back to the distro address when localhost is not forwarded', async () => {
+    const canConnect = vi.fn(async (host: string) => host === '172.22.160.5');
+    const runWsl = vi.fn(async () => '172.22.160.5 \n');
+    const manager = new WslBackendManager(deps({ canConnect, runWsl }));
+    expect(await manager.resolveHost('Ubuntu', 9786)).toBe('172.22.160.5');
+
+    const forwarded = new WslBackendManager(deps({ canConnect: vi.fn(async () => true) }));
+    expect(await forwarded.resolveHost('Ubuntu', 9786)).toBe('localhost');
+  });
+
+  it('reports status changes while monitoring', async () => {
+    vi.useFakeTimers();
+    try {
+      let state = 'running\thealthy\t';
+      const onStatus = vi.fn();
+      const manager = new WslBackendManager(deps({ runWsl: vi.fn(async () => state), onStatus }));
+      manager.startMonitor('Ubuntu', 'c');
+      await vi.advanceTimersByTimeAsync(5_000);
+      await vi.advanceTimersByTimeAsync(5_000);
+      expect(onStatus).toHaveBeenCalledTimes(1);
+      state = 'exited\t<nil>\t';
+      await vi.advanceTimersByTimeAsync(5_000);
+      expect(onStatus).toHaveBeenLastCalledWith(expect.objectContaining({ status: 'exited' }));
+      await vi.advanceTimersByTimeAsync(20_000);
+      expect(onStatus).toHaveBeenCalledTimes(2);
+    } finally {
+      vi.useRealTimers();
+    }
+  });
+});
@@ -0,0 +1,115 @@
+/**
+ * Action registry — every command the app can run, listed in one place for
+ * the Ctrl+K command palette.
+ *
+ * Subsystems register their actions in main.ts next to where they are
+ * created, so a new feature shows up in the palette by registering, with
+ * no palette change. Each action has a title and category to search by, an
+ * optional shortcut registry id (shortcutRegistry.ts) whose accelerator is
+ * shown beside it, and an `enabled` check that returns `true` or the reason
+ * it can't run right now ("Already recording") — so disabled actions stay
+ * discoverable and say why.
+ *
+ * `run` executes in the main process. Actions that belong to the renderer
+ * (recording, navigation) forward themselves to the window, the way the
+ * tray menu does.
+ */
+
+export type ActionCategory =
+  | 'Recording'
+  | 'Live Mode'
+  | 'Files'
+  | 'Server'
+  | 'Sharing'
+  | 'Navigation'
+  | 'App';
+
+export interface ActionDefinition {
+  /** Stable id, e.g. `recording.start`. */
+  id: string;
+  title: string;
+  category: ActionCategory;
+  /** Extra words the palette matches on. */
+  keywords?: string[];
+  /** Shortcut registry id whose current accelerator is shown with the action. */
+  shortcut?: string;
+  enabled?: () => true | string;
+  run: () => void | Promise<void>;
+}
+
+export interface ActionEntry {
+  id: string;
+  title: string;
+  category: ActionCategory;
+  keywords: string[];
+  accelerator: string | null;
+  enabled: boolean;
+  /** Why the action can't run now; null when enabled. */
+  disabledReason: string | null;
+}
+
+export type InvokeActionResult = { ok: true } | { ok: false; error: string };
+
+export interface ActionRegistryDeps {
+  /** Current accelerator for a shortcut registry id, null when unbound. */
+  accelerator: (shortcutId: string) => string | null;
+}
+
+export class ActionRegistry {
+  private readonly actions = new Map<string, ActionDefinition>();
+
+  constructor(private readonly deps: ActionRegistryDeps) {}
+
+  /** Add an action; returns a function that removes it again. */
+  register(...definitions: ActionDefinition[]): () => void {
+    for (const definition of definitions) {
+      if (this.actions.has(definition.id)) {
+        throw new Error(`Action "${definition.id}" is already registered`);
+      }
+    }
+    for (const definition of definitions) this.actions.set(definition.id, definition);
+    return () => {
+      for (const definition of definitions) {
+        if (this.actions.get(definition.id) === definition) this.actions.delete(definition.id);
+      }
+    };
+  }
+
+  /** Every action with its enablement right now, in registration order. */
+  list(): ActionEntry[] {
+    return [...this.actions.values()].map((action) => {
+      const state = this.check(action);
+      return {
+        id: action.id,
+        title: action.title,
+        category: action.category,
+        keywords: action.keywords ?? [],
+        accelerator: action.shortcut ? this.deps.accelerator(action.shortcut) : null,
+        enabled: state === true,
+        disabledReason: state === true ? null : state,
+      };
+    });
+  }
+
+  /** Run an action if it exists and is enabled; its own failure is returned, not thrown. */
+  async invoke(id: string): Promise<InvokeActionResult> {
+    const action = this.actions.get(id);
+    if (!action) return { ok: false, error: `Unknown action "${id}"` };
+    const state = this.check(action);
+    if (state !== true) return { ok: false, error: state };
+    try {
+      await action.run();
+      return { ok: true };
+    } catch (err) {
+      return { ok: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  }
+
+  private check(action: ActionDefinition): true | string {
+    try {
+      return action.enabled?.() ?? true;
+    } catch (err) {
+      return err instanceof Error ? err.message : String(err);
+    }
+  }
+}
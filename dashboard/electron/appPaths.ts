@@ -0,0 +1,141 @@
+/**
+ * Where the app keeps its files, by platform convention.
+ *
+ *   Linux    config  $XDG_CONFIG_HOME/TranscriptionSuite   (~/.config)
+ *            data    $XDG_DATA_HOME/TranscriptionSuite     (~/.local/share)
+ *            cache   $XDG_CACHE_HOME/TranscriptionSuite    (~/.cache)
+ *            logs    $XDG_STATE_HOME/TranscriptionSuite/logs (~/.local/state)
+ *   macOS    config + data in ~/Library/Application Support/TranscriptionSuite,
+ *            cache in ~/Library/Caches, logs in ~/Library/Logs
+ *   Windows  config in %APPDATA%, data/cache/logs in %LOCALAPPDATA%
+ *
+ * `config` is always Electron's `userData` (settings, server config, the
+ * Chromium profile). Earlier versions put everything there, so
+ * {@link migrateLegacyLayout} moves the big or disposable directories —
+ * whisper models, logs, the installer cache — to their new homes once,
+ * recording the layout version in a marker file.
+ *
+ * Portable mode keeps the old single-directory layout next to the
+ * executable; there is nothing to migrate.
+ */
+
+import fs from 'fs';
+import path from 'path';
+
+export const APP_DIR_NAME = 'TranscriptionSuite';
+export const LAYOUT_MARKER = '.data-layout';
+export const LAYOUT_VERSION = 1;
+
+export type AppPathKind = 'config' | 'data' | 'cache' | 'logs';
+
+export type AppPaths = Record<AppPathKind, string>;
+
+export interface AppPathsContext {
+  platform: NodeJS.Platform;
+  env: NodeJS.ProcessEnv;
+  home: string;
+  /** Electron's `userData` — the config dir on every platform. */
+  userData: string;
+  portable: boolean;
+}
+
+export interface MigrationResult {
+  moved: string[];
+  /** Legacy entries left in place because the destination already exists. */
+  skipped: string[];
+}
+
+/** XDG base dir from `name`, ignoring relative values as the spec requires. */
+function xdg(env: NodeJS.ProcessEnv, name: string, fallback: string): string {
+  const value = env[name];
+  return value && path.isAbsolute(value) ? value : fallback;
+}
+
+export function resolveAppPaths(ctx: AppPathsContext): AppPaths {
+  const config = ctx.userData;
+  if (ctx.portable) {
+    return { config, data: config, cache: config, logs: path.join(config, 'logs') };
+  }
+  if (ctx.platform === 'darwin') {
+    const library = path.join(ctx.home, 'Library');
+    return {
+      config,
+      data: config,
+      cache: path.join(library, 'Caches', APP_DIR_NAME),
+      logs: path.join(library, 'Logs', APP_DIR_NAME),
+    };
+  }
+  if (ctx.platform === 'win32') {
+    const local = ctx.env.LOCALAPPDATA || path.join(ctx.home, 'AppData', 'Local');
+    const data = path.join(local, APP_DIR_NAME);
+    return { config, data, cache: path.join(data, 'Cache'), logs: path.join(data, 'Logs') };
+  }
+  const dataHome = xdg(ctx.env, 'XDG_DATA_HOME', path.join(ctx.home, '.local', 'share'));
+  const cacheHome = xdg(ctx.env, 'XDG_CACHE_HOME', path.join(ctx.home, '.cache'));
+  const stateHome = xdg(ctx.env, 'XDG_STATE_HOME', path.join(ctx.home, '.local', 'state'));
+  return {
+    config,
+    data: path.join(dataHome, APP_DIR_NAME),
+    cache: path.join(cacheHome, APP_DIR_NAME),
+    logs: path.join(stateHome, APP_DIR_NAME, 'logs'),
+  };
+}
+
+/** Legacy `<config>/<entry>` → new location. Same-path entries are no-ops. */
+export function legacyMoves(paths: AppPaths): Array<{ from: string; to: string }> {
+  const legacy = (name: string) => path.join(paths.config, name);
+  return [
+    { from: legacy('logs'), to: paths.logs },
+    { from: legacy('gpu-diagnostics'), to: path.join(paths.logs, 'gpu-diagnostics') },
+    { from: legacy('whisper-models'), to: path.join(paths.data, 'whisper-models') },
+    { from: legacy('whisper-server'), to: path.join(paths.data, 'whisper-server') },
+    { from: legacy('whisper-server.pid'), to: path.join(paths.data, 'whisper-server.pid') },
+    { from: legacy('previous-installer'), to: path.join(paths.cache, 'previous-installer') },
+  ].filter((move) => path.resolve(move.from) !== path.resolve(move.to));
+}
+
+function moveEntry(from: string, to: string): void {
+  fs.mkdirSync(path.dirname(to), { recursive: true });
+  try {
+    fs.renameSync(from, to);
+  } catch (err) {
+    // Data and config may sit on different filesystems.
+    if ((err as NodeJS.ErrnoException).code !== 'EXDEV') throw err;
+    fs.cpSync(from, to, { recursive: true, preserveTimestamps: true });
+    fs.rmSync(from, { recursive: true, force: true });
+  }
+}
+
+/**
+ * Move pre-layout data out of the config dir, once. Throws (without writing
+ * the marker, so the next launch retries) if a move fails part-way.
+ */
+export function migrateLegacyLayout(paths: AppPaths): MigrationResult {
+  const result: MigrationResult = { moved: [], skipped: [] };
+  const marker = path.join(paths.config, LAYOUT_MARKER);
+  if (fs.existsSync(marker)) return result;
+
+  for (const { from, to } of legacyMoves(paths)) {
+    if (!fs.existsSync(from)) continue;
+    if (fs.existsSync(to)) {
+      result.skipped.push(from);
+      continue;
+    }
+    moveEntry(from, to);
+    result.moved.push(`${from} -> ${to}`);
+  }
+  fs.mkdirSync(paths.config, { recursive: true });
+  fs.writeFileSync(marker, `${LAYOUT_VERSION}\n`, 'utf-8');
+  return result;
+}
+
+let current: AppPaths | null = null;
+
+/** Set once by main.ts at startup; read by modules that store data outside `userData`. */
+export function setAppPaths(paths: AppPaths): void {
+  current = paths;
+}
+
+export function getAppPaths(): AppPaths | null {
+  return current;
+}
@@ -0,0 +1,353 @@
+/**
+ * Backend bootstrap — a managed Python environment for running the server
+ * without Docker or Podman.
+ *
+ * Layout under `<appPaths.data>/backend/`:
+ *
+ *   python/          uv-managed CPython (never the system interpreter)
+ *   venv/            the backend's virtualenv, synced from uv.lock
+ *   src/server/      copy of the backend package (server/backend)
+ *   src/config.yaml  bundled defaults, where config.py looks for them
+ *   bootstrap.json   fingerprint of the last successful sync
+ *
+ * The source is copied rather than installed so the `server` package
+ * resolves through PYTHONPATH=src without the `server -> .` symlink the
+ * editable install needs — `src/` holds nothing else, so nothing in it can
+ * shadow a stdlib module. Dependencies come from `uv sync --frozen
+ * --no-install-project`, the same pinned lock the Docker image uses (see
+ * run_dependency_sync in server/docker/bootstrap_runtime.py).
+ *
+ * uv itself is bundled in `<resources>/uv/`; a uv on PATH is the fallback
+ * for source checkouts.
+ */
+
+import { spawn } from 'child_process';
+import crypto from 'crypto';
+import fs from 'fs';
+import path from 'path';
+
+export const BACKEND_PYTHON_VERSION = '3.13';
+export const STAMP_FILE = 'bootstrap.json';
+export const UV_OVERRIDE_ENV = 'TRANSCRIPTIONSUITE_UV';
+/** Bump to force every install to re-sync (layout or sync flags changed). */
+const BOOTSTRAP_SCHEMA = 1;
+
+export type BootstrapStage = 'locate' | 'source' | 'sync' | 'verify' | 'ready' | 'error';
+
+export interface BootstrapProgress {
+  stage: BootstrapStage;
+  message: string;
+  /** 0–100, or null while indeterminate. */
+  percent: number | null;
+}
+
+export type PytorchVariant = 'cu129' | 'cpu';
+
+export interface BootstrapOptions {
+  extras: string[];
+  pytorchVariant: PytorchVariant;
+  /** Re-sync even when the fingerprint matches (repair). */
+  force?: boolean;
+}
+
+export interface BackendLayout {
+  root: string;
+  pythonInstallDir: string;
+  venv: string;
+  python: string;
+  src: string;
+  stamp: string;
+}
+
+export interface UvLookup {
+  platform: NodeJS.Platform;
+  env: NodeJS.ProcessEnv;
+  resourcesPath?: string;
+  exists?: (p: string) => boolean;
+}
+
+export function backendLayout(dataDir: string, platform: NodeJS.Platform): BackendLayout {
+  const root = path.join(dataDir, 'backend');
+  const venv = path.join(root, 'venv');
+  return {
+    root,
+    pythonInstallDir: path.join(root, 'python'),
+    venv,
+    python:
+      platform === 'win32'
+        ? path.join(venv, 'Scripts', 'python.exe')
+        : path.join(venv, 'bin', 'python3'),
+    src: path.join(root, 'src'),
+    stamp: path.join(root, STAMP_FILE),
+  };
+}
+
+/** The uv binary: explicit override, then the bundled copy, then PATH. */
+export function findUv(lookup: UvLookup): string | null {
+  const exists = lookup.exists ?? fs.existsSync;
+  const exe = lookup.platform === 'win32' ? 'uv.exe' : 'uv';
+  const override = lookup.env[UV_OVERRIDE_ENV];
+  if (override && exists(override)) return override;
+  if (lookup.resourcesPath) {
+    const bundled = path.join(lookup.resourcesPath, 'uv', exe);
+    if (exists(bundled)) return bundled;
+  }
+  const dirs = (lookup.env.PATH ?? lookup.env.Path ?? '').split(path.delimiter).filter(Boolean);
+  for (const dir of dirs) {
+    const candidate = path.join(dir, exe);
+    if (exists(candidate)) return candidate;
+  }
+  return null;
+}
+
+/** `uv sync` arguments — mirrors run_dependency_sync in bootstrap_runtime.py. */
+export function syncArgs(projectDir: string, opts: BootstrapOptions): string[] {
+  const args = ['sync', '--no-dev', '--no-install-project', '--project', projectDir];
+  if (opts.pytorchVariant === 'cpu') {
+    // Swap the URL of the named index torch is pinned to; the lock pins cu129
+    // hashes, so --frozen must go (see Issue #115 in bootstrap_runtime.py).
+    args.push(
+      '--index',
+      'pytorch-cu129=https://download.pytorch.org/whl/cpu',
+      '--index-strategy',
+      'unsafe-best-match',
+    );
+  } else {
+    args.splice(1, 0, '--frozen');
+  }
+  for (const extra of [...opts.extras].sort()) args.push('--extra', extra);
+  return args;
+}
+
+export function bootstrapFingerprint(
+  lockContent: string,
+  opts: BootstrapOptions,
+  appVersion: string,
+): string {
+  return crypto
+    .createHash('sha256')
+    .update(`schema=${BOOTSTRAP_SCHEMA}\n`)
+    .update(`app=${appVersion}\n`)
+    .update(`python=${BACKEND_PYTHON_VERSION}\n`)
+    .update(`variant=${opts.pytorchVariant}\n`)
+    .update(`extras=${[...opts.extras].sort().join(',')}\n`)
+    .update(lockContent)
+    .digest('hex');
+}
+
+/**
+ * Turns uv's line output into progress. uv prints no overall percentage, so
+ * the bar is staged: resolve → download (by packages finished / started) →
+ * prepare → install.
+ */
+export class UvProgressTracker {
+  private _started = 0;
+  private _finished = 0;
+
+  feed(line: string): BootstrapProgress | null {
+    const text = line.trim();
+    let match: RegExpExecArray | null;
+    if ((match = /^Downloading (cpython-\S+)/.exec(text))) {
+      return this._sync(`Downloading Python (${match[1]})`, 2);
+    }
+    if ((match = /^Resolved (\d+) packages?/.exec(text))) {
+      return this._sync(`Resolved ${match[1]} packages`, 5);
+    }
+    if ((match = /^Downloading (\S+)(?: \(([^)]+)\))?/.exec(text))) {
+      this._started += 1;
+      const size = match[2] ? ` (${match[2]})` : '';
+      return this._sync(`Downloading ${match[1]}${size}`, this._downloadPercent());
+    }
+    if ((match = /^Downloaded (\S+)/.exec(text))) {
+      this._finished += 1;
+      return this._sync(`Downloaded ${match[1]}`, this._downloadPercent());
+    }
+    if ((match = /^Prepared (\d+) packages?/.exec(text))) {
+      return this._sync(`Prepared ${match[1]} packages`, 85);
+    }
+    if ((match = /^Installed (\d+) packages?/.exec(text))) {
+      return this._sync(`Installed ${match[1]} packages`, 95);
+    }
+    if (/^(Audited|Checked) \d+ packages?/.test(text)) {
+      return this._sync('Packages already up to date', 95);
+    }
+    return null;
+  }
+
+  private _downloadPercent(): number {
+    if (this._started === 0) return 5;
+    return Math.round(5 + (75 * this._finished) / this._started);
+  }
+
+  private _sync(message: string, percent: number): BootstrapProgress {
+    return { stage: 'sync', message, percent };
+  }
+}
+
+/** Environment for `uv` and for the server: managed Python only, caches in our dirs. */
+export function uvEnv(
+  layout: BackendLayout,
+  cacheDir: string,
+  base: NodeJS.ProcessEnv,
+): NodeJS.ProcessEnv {
+  return {
+    ...base,
+    UV_PROJECT_ENVIRONMENT: layout.venv,
+    UV_PYTHON: BACKEND_PYTHON_VERSION,
+    UV_PYTHON_INSTALL_DIR: layout.pythonInstallDir,
+    UV_PYTHON_PREFERENCE: 'only-managed',
+    UV_CACHE_DIR: path.join(cacheDir, 'uv'),
+    UV_NO_PROGRESS: '1',
+  };
+}
+
+/** Copy the backend package into `src/server`, skipping venvs, tests and bytecode. */
+export function stageSource(sourceDir: string, configYaml: string | null, src: string): void {
+  fs.rmSync(src, { recursive: true, force: true });
+  const skip = new Set(['.venv', 'tests', '__pycache__', '.pytest_cache', '.ruff_cache']);
+  fs.cpSync(sourceDir, path.join(src, 'server'), {
+    recursive: true,
+    // Also drops the `server -> .` dev symlink, which would recurse forever.
+    filter: (p) =>
+      !skip.has(path.basename(p)) && !(path.basename(p) === 'server' && isSymlink(p)),
+  });
+  if (configYaml && fs.existsSync(configYaml)) {
+    fs.copyFileSync(configYaml, path.join(src, 'config.yaml'));
+  }
+}
+
+function isSymlink(p: string): boolean {
+  try {
+    return fs.lstatSync(p).isSymbolicLink();
+  } catch {
+    return false;
+  }
+}
+
+export interface BootstrapperDeps {
+  dataDir: string;
+  cacheDir: string;
+  /** server/backend (dev) or <resources>/backend-src (packaged). */
+  sourceDir: string;
+  configYaml: string | null;
+  appVersion: string;
+  uvLookup: UvLookup;
+  onProgress: (progress: BootstrapProgress) => void;
+}
+
+export class BackendBootstrapper {
+  readonly layout: BackendLayout;
+  private _deps: BootstrapperDeps;
+  private _running: Promise<BackendLayout> | null = null;
+
+  constructor(deps: BootstrapperDeps) {
+    this._deps = deps;
+    this.layout = backendLayout(deps.dataDir, deps.uvLookup.platform);
+  }
+
+  /** Whether a previous bootstrap completed (the fingerprint may still be stale). */
+  isInstalled(): boolean {
+    return fs.existsSync(this.layout.stamp) && fs.existsSync(this.layout.python);
+  }
+
+  /** Create or update the environment; concurrent calls share one run. */
+  ensure(opts: BootstrapOptions): Promise<BackendLayout> {
+    this._running ??= this._ensure(opts).finally(() => {
+      this._running = null;
+    });
+    return this._running;
+  }
+
+  private async _ensure(opts: BootstrapOptions): Promise<BackendLayout> {
+    const { layout } = this;
+    const progress = this._deps.onProgress;
+    try {
+      progress({ stage: 'locate', message: 'Looking for uv', percent: 0 });
+      const uv = findUv(this._deps.uvLookup);
+      if (!uv) {
+        throw new Error(
+          'uv was not found. Reinstall the app, or install uv (https://docs.astral.sh/uv/) ' +
+            `and put it on PATH or in ${UV_OVERRIDE_ENV}.`,
+        );
+      }
+      const lockPath = path.join(this._deps.sourceDir, 'uv.lock');
+      if (!fs.existsSync(lockPath)) {
+        throw new Error(`Backend source not found (no uv.lock in ${this._deps.sourceDir}).`);
+      }
+      const fingerprint = bootstrapFingerprint(
+        fs.readFileSync(lockPath, 'utf-8'),
+        opts,
+        this._deps.appVersion,
+      );
+      if (!opts.force && this.isInstalled() && readStamp(layout.stamp) === fingerprint) {
+        progress({ stage: 'ready', message: 'Python environment up to date', percent: 100 });
+        return layout;
+      }
+
+      progress({ stage: 'source', message: 'Copying server files', percent: 1 });
+      stageSource(this._deps.sourceDir, this._deps.configYaml, layout.src);
+
+      progress({ stage: 'sync', message: 'Installing Python and dependencies', percent: 2 });
+      const env = uvEnv(layout, this._deps.cacheDir, process.env);
+      const tracker = new UvProgressTracker();
+      await runLogged(uv, syncArgs(path.join(layout.src, 'server'), opts), env, (line) => {
+        const update = tracker.feed(line);
+        if (update) progress(update);
+      });
+
+      progress({ stage: 'verify', message: 'Checking the environment', percent: 97 });
+      await runLogged(layout.python, ['-c', 'import uvicorn, fastapi'], env, () => {});
+
+      fs.writeFileSync(
+        layout.stamp,
+        JSON.stringify({ fingerprint, ...opts, completedAt: new Date().toISOString() }, null, 2),
+      );
+      progress({ stage: 'ready', message: 'Python environment ready', percent: 100 });
+      return layout;
+    } catch (err) {
+      const message = err instanceof Error ? err.message : String(err);
+      progress({ stage: 'error', message, percent: null });
+      throw err;
+    }
+  }
+}
+
+function readStamp(stampPath: string): string | null {
+  try {
+    const stamp = JSON.parse(fs.readFileSync(stampPath, 'utf-8')) as { fingerprint?: string };
+    return stamp.fingerprint ?? null;
+  } catch {
+    return null;
+  }
+}
+
+/** Run to completion, streaming lines; rejects with the output tail on failure. */
+function runLogged(
+  command: string,
+  args: string[],
+  env: NodeJS.ProcessEnv,
+  onLine: (line: string) => void,
+): Promise<void> {
+  return new Promise((resolve, reject) => {
+    const tail: string[] = [];
+    const child = spawn(command, args, { env, stdio: ['ignore', 'pipe', 'pipe'] });
+    const consume = (data: Buffer) => {
+      for (const line of data.toString().split(/\r?\n/)) {
+        if (!line.trim()) continue;
+        tail.push(line);
+        if (tail.length > 20) tail.shift();
+        onLine(line);
+      }
+    };
+    child.stdout?.on('data', consume);
+    child.stderr?.on('data', consume);
+    child.on('error', reject);
+    child.on('exit', (code) => {
+      if (code === 0) resolve();
+      else {
+        const detail = tail.slice(-5).join('\n');
+        reject(new Error(`${path.basename(command)} exited with code ${code}\n${detail}`));
+      }
+    });
+  });
+}
@@ -0,0 +1,268 @@
+/**
+ * CacheManager — the app's disposable files: cloud downloads, phone uploads,
+ * extracted media and waveform peaks.
+ *
+ * Each category is a subdirectory of the cache root (appPaths.cache unless
+ * the user picks another location) with its own size quota. When a category
+ * goes over quota, its least recently used files are deleted until it fits
+ * again. "Recently used" is the file's mtime — producers `touch()` a cached
+ * file when they reuse it, since atime is unreliable on relatime/noatime
+ * mounts.
+ *
+ * Files younger than {@link EVICTION_GRACE_MS} are never evicted, so a
+ * download the import queue has not picked up yet cannot vanish under it,
+ * and neither can a `.part` file that is still being written. Clearing a
+ * category is an explicit user action and removes everything else.
+ *
+ * A folder holding a {@link PENDING_MARKER} file belongs to an import that
+ * has not finished (a phone upload the queue has not transcribed yet). It
+ * is never evicted or cleared, and moving the cache takes it along — the
+ * producer removes the marker once the file is safely imported.
+ */
+
+import fs from 'fs';
+import path from 'path';
+
+export const CACHE_CATEGORIES = {
+  'cloud-import': { label: 'Cloud downloads', defaultQuotaMb: 4096 },
+  'phone-ingest': { label: 'Phone uploads', defaultQuotaMb: 2048 },
+  media: { label: 'Extracted media', defaultQuotaMb: 2048 },
+  waveforms: { label: 'Waveform peaks', defaultQuotaMb: 256 },
+} as const;
+
+export type CacheCategory = keyof typeof CACHE_CATEGORIES;
+
+export const EVICTION_GRACE_MS = 60 * 60 * 1000;
+
+export const PENDING_MARKER = '.pending';
+
+const MB = 1024 * 1024;
+
+export interface CacheStore {
+  get(key: string): unknown;
+  set(key: string, value: unknown): void;
+}
+
+export interface CacheCategoryUsage {
+  category: CacheCategory;
+  label: string;
+  bytes: number;
+  files: number;
+  quotaBytes: number;
+}
+
+export interface CacheUsage {
+  root: string;
+  /** False while the root is the platform default. */
+  custom: boolean;
+  categories: CacheCategoryUsage[];
+}
+
+export interface CacheManagerDeps {
+  store: CacheStore;
+  /** appPaths.cache — used while `cache.directory` is unset. */
+  defaultRoot: string;
+  now?: () => number;
+}
+
+interface CachedFile {
+  path: string;
+  size: number;
+  mtimeMs: number;
+}
+
+export function isCacheCategory(value: unknown): value is CacheCategory {
+  return typeof value === 'string' && Object.hasOwn(CACHE_CATEGORIES, value);
+}
+
+/** Files under `dir`; `skipHeld` leaves out folders with a pending marker. */
+async function listFiles(dir: string, skipHeld = false): Promise<CachedFile[]> {
+  const entries = await fs.promises.readdir(dir, { withFileTypes: true }).catch(() => []);
+  if (skipHeld && entries.some((e) => e.isFile() && e.name === PENDING_MARKER)) return [];
+  const files: CachedFile[] = [];
+  for (const entry of entries) {
+    const full = path.join(dir, entry.name);
+    if (entry.isDirectory()) {
+      files.push(...(await listFiles(full, skipHeld)));
+    } else if (entry.isFile()) {
+      const stat = await fs.promises.lstat(full).catch(() => null);
+      if (stat) files.push({ path: full, size: stat.size, mtimeMs: stat.mtimeMs });
+    }
+  }
+  return files;
+}
+
+/** Folders under `dir` (relative to it) that hold a pending marker. */
+async function listHeldDirs(dir: string, relative = ''): Promise<string[]> {
+  const full = path.join(dir, relative);
+  const entries = await fs.promises.readdir(full, { withFileTypes: true }).catch(() => []);
+  if (entries.some((e) => e.isFile() && e.name === PENDING_MARKER)) return [relative];
+  const held: string[] = [];
+  for (const entry of entries) {
+    if (entry.isDirectory()) {
+      held.push(...(await listHeldDirs(dir, path.join(relative, entry.name))));
+    }
+  }
+  return held;
+}
+
+async function moveDir(from: string, to: string): Promise<void> {
+  await fs.promises.mkdir(path.dirname(to), { recursive: true });
+  try {
+    await fs.promises.rename(from, to);
+  } catch (err) {
+    if ((err as NodeJS.ErrnoException).code !== 'EXDEV') throw err;
+    // Another drive: copy, and delete the original only once the copy is whole.
+    await fs.promises.cp(from, to, { recursive: true });
+    await fs.promises.rm(from, { recursive: true, force: true });
+  }
+}
+
+/** Remove `dir` and its now-empty parents, stopping at `stop`. */
+async function pruneEmptyDirs(dir: string, stop: string): Promise<void> {
+  let current = dir;
+  while (current.startsWith(stop + path.sep)) {
+    try {
+      await fs.promises.rmdir(current);
+    } catch {
+      return;
+    }
+    current = path.dirname(current);
+  }
+}
+
+export class CacheManager {
+  constructor(private readonly deps: CacheManagerDeps) {}
+
+  private now(): number {
+    return this.deps.now?.() ?? Date.now();
+  }
+
+  get root(): string {
+    const custom = this.deps.store.get('cache.directory');
+    return typeof custom === 'string' && path.isAbsolute(custom) ? custom : this.deps.defaultRoot;
+  }
+
+  dir(category: CacheCategory): string {
+    return path.join(this.root, category);
+  }
+
+  quotaBytes(category: CacheCategory): number {
+    const quotas = this.deps.store.get('cache.quotasMb') as Record<string, unknown> | undefined;
+    const mb = quotas?.[category];
+    return (
+      (typeof mb === 'number' && mb > 0 ? mb : CACHE_CATEGORIES[category].defaultQuotaMb) * MB
+    );
+  }
+
+  setQuota(category: CacheCategory, mb: number): void {
+    if (!Number.isFinite(mb) || mb <= 0) throw new Error(`Invalid cache quota: ${mb} MB`);
+    const quotas = (this.deps.store.get('cache.quotasMb') as Record<string, number>) ?? {};
+    this.deps.store.set('cache.quotasMb', { ...quotas, [category]: Math.round(mb) });
+  }
+
+  /** Mark a cached file as just used so eviction keeps it longer. */
+  async touch(file: string): Promise<void> {
+    const stamp = new Date(this.now());
+    await fs.promises.utimes(file, stamp, stamp).catch(() => {});
+  }
+
+  async usage(): Promise<CacheUsage> {
+    const categories = await Promise.all(
+      (Object.keys(CACHE_CATEGORIES) as CacheCategory[]).map(async (category) => {
+        const files = await listFiles(this.dir(category));
+        return {
+          category,
+          label: CACHE_CATEGORIES[category].label,
+          bytes: files.reduce((sum, f) => sum + f.size, 0),
+          files: files.length,
+          quotaBytes: this.quotaBytes(category),
+        };
+      }),
+    );
+    return { root: this.root, custom: this.root !== this.deps.defaultRoot, categories };
+  }
+
+  /** Delete least recently used files until the category fits its quota; returns bytes freed. */
+  async evict(category: CacheCategory): Promise<number> {
+    const dir = this.dir(category);
+    const all = await listFiles(dir);
+    const files = (await listFiles(dir, true)).sort((a, b) => a.mtimeMs - b.mtimeMs);
+    // Held files still count against the quota; they just cannot be freed.
+    let total = all.reduce((sum, f) => sum + f.size, 0);
+    const quota = this.quotaBytes(category);
+    const cutoff = this.now() - EVICTION_GRACE_MS;
+    let freed = 0;
+    for (const file of files) {
+      if (total <= quota) break;
+      if (file.mtimeMs > cutoff) continue;
+      try {
+        await fs.promises.rm(file.path, { force: true });
+      } catch {
+        continue;
+      }
+      total -= file.size;
+      freed += file.size;
+      await pruneEmptyDirs(path.dirname(file.path), dir);
+    }
+    return freed;
+  }
+
+  async evictAll(): Promise<number> {
+    let freed = 0;
+    for (const category of Object.keys(CACHE_CATEGORIES) as CacheCategory[]) {
+      freed += await this.evict(category);
+    }
+    return freed;
+  }
+
+  /**
+   * Remove everything but pending imports in one category, or in all of
+   * them; returns bytes freed.
+   */
+  async clear(category?: CacheCategory): Promise<number> {
+    const categories = category ? [category] : (Object.keys(CACHE_CATEGORIES) as CacheCategory[]);
+    let freed = 0;
+    for (const c of categories) {
+      const dir = this.dir(c);
+      if ((await listHeldDirs(dir)).length === 0) {
+        const files = await listFiles(dir);
+        freed += files.reduce((sum, f) => sum + f.size, 0);
+        await fs.promises.rm(dir, { recursive: true, force: true });
+        continue;
+      }
+      for (const file of await listFiles(dir, true)) {
+        await fs.promises.rm(file.path, { force: true }).catch(() => {});
+        freed += file.size;
+        await pruneEmptyDirs(path.dirname(file.path), dir);
+      }
+    }
+    return freed;
+  }
+
+  /**
+   * Move the cache to `dir` (null = back to the default). Pending imports
+   * move along; the rest of the old location's category folders is deleted
+   * — only those, since the old root may be a folder the user also keeps
+   * other files in.
+   */
+  async setRoot(dir: string | null): Promise<void> {
+    if (dir !== null && !path.isAbsolute(dir)) {
+      throw new Error(`Cache directory must be an absolute path: ${dir}`);
+    }
+    const next = dir ?? this.deps.defaultRoot;
+    if (path.resolve(next) === path.resolve(this.root)) {
+      this.deps.store.set('cache.directory', dir ?? '');
+      return;
+    }
+    await fs.promises.mkdir(next, { recursive: true });
+    await fs.promises.access(next, fs.constants.W_OK);
+    for (const category of Object.keys(CACHE_CATEGORIES) as CacheCategory[]) {
+      for (const held of await listHeldDirs(this.dir(category))) {
+        await moveDir(path.join(this.dir(category), held), path.join(next, category, held));
+      }
+    }
+    await this.clear();
+    this.deps.store.set('cache.directory', dir ?? '');
+  }
+}
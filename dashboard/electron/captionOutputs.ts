@@ -0,0 +1,270 @@
+/**
+ * Live caption outputs for streaming software.
+ *
+ * The same transcript the caption overlay shows (captionOverlay.ts) can be
+ * mirrored to two outputs, independently enabled under Settings → Client →
+ * Caption Output:
+ *
+ *  - a text file, rewritten on every change, for an OBS "Text" source with
+ *    "Read from file" (or any other tool that tails a file). Writes go to a
+ *    temp file that is renamed over the target, so readers never see a
+ *    half-written caption;
+ *  - an obs-websocket (protocol v5, built into OBS 28+) connection that sets
+ *    the text of a named Text source directly. The connection is kept alive
+ *    and retried in the background while the output is enabled, so OBS can be
+ *    started before or after the dashboard.
+ */
+
+import crypto from 'crypto';
+import fs from 'fs';
+import path from 'path';
+import { visibleCaptionLines, type CaptionPayload } from './captionOverlay.js';
+
+export const DEFAULT_OBS_URL = 'ws://127.0.0.1:4455';
+export const DEFAULT_OBS_INPUT_NAME = 'TranscriptionSuite Captions';
+
+const OBS_RECONNECT_MS = 5000;
+
+// obs-websocket v5 opcodes.
+const OP_HELLO = 0;
+const OP_IDENTIFY = 1;
+const OP_IDENTIFIED = 2;
+const OP_REQUEST = 6;
+const OP_REQUEST_RESPONSE = 7;
+
+export interface CaptionOutputSettings {
+  textFileEnabled: boolean;
+  textFilePath: string;
+  obsEnabled: boolean;
+  obsUrl: string;
+  obsPassword: string;
+  obsInputName: string;
+}
+
+export interface CaptionSettingsStore {
+  get(key: string): unknown;
+}
+
+function str(value: unknown): string {
+  return typeof value === 'string' ? value.trim() : '';
+}
+
+export function readCaptionOutputSettings(store: CaptionSettingsStore): CaptionOutputSettings {
+  const password = store.get('captions.obs.password');
+  return {
+    textFileEnabled: store.get('captions.textFile.enabled') === true,
+    textFilePath: str(store.get('captions.textFile.path')),
+    obsEnabled: store.get('captions.obs.enabled') === true,
+    obsUrl: str(store.get('captions.obs.url')) || DEFAULT_OBS_URL,
+    // Not trimmed: passwords may legitimately carry surrounding spaces.
+    obsPassword: typeof password === 'string' ? password : '',
+    obsInputName: str(store.get('captions.obs.inputName')) || DEFAULT_OBS_INPUT_NAME,
+  };
+}
+
+/** Plain-text rendering shared by both outputs: one caption line per row. */
+export function captionText(payload: CaptionPayload): string {
+  return visibleCaptionLines(payload).join('\n');
+}
+
+/** `authentication` field of an obs-websocket v5 Identify message. */
+export function obsAuthentication(password: string, salt: string, challenge: string): string {
+  const secret = crypto.createHash('sha256').update(password + salt).digest('base64');
+  return crypto.createHash('sha256').update(secret + challenge).digest('base64');
+}
+
+export async function writeCaptionFile(target: string, text: string): Promise<void> {
+  const tmp = path.join(path.dirname(target), `.${path.basename(target)}.tmp`);
+  await fs.promises.mkdir(path.dirname(target), { recursive: true });
+  await fs.promises.writeFile(tmp, text, 'utf-8');
+  await fs.promises.rename(tmp, target);
+}
+
+export interface ObsSocket {
+  send(data: string): void;
+  close(): void;
+}
+
+export interface ObsSocketHandlers {
+  onMessage(data: string): void;
+  onClose(): void;
+}
+
+export interface CaptionOutputDeps {
+  createSocket: (url: string, handlers: ObsSocketHandlers) => ObsSocket;
+  writeFile?: (target: string, text: string) => Promise<void>;
+  log?: (message: string) => void;
+}
+
+export class CaptionOutputs {
+  private _deps: CaptionOutputDeps;
+  private _settings: CaptionOutputSettings | null = null;
+  private _text = '';
+
+  private _fileWriting = false;
+  private _fileWritten: string | null = null;
+
+  private _socket: ObsSocket | null = null;
+  private _identified = false;
+  private _obsSent: string | null = null;
+  private _reconnectTimer: ReturnType<typeof setTimeout> | null = null;
+  private _requestSeq = 0;
+  private _lastObsError = '';
+
+  constructor(deps: CaptionOutputDeps) {
+    this._deps = deps;
+  }
+
+  /** Apply settings; reconnects to OBS only when the endpoint changed. */
+  configure(settings: CaptionOutputSettings): void {
+    const prev = this._settings;
+    this._settings = settings;
+
+    if (!prev || prev.textFilePath !== settings.textFilePath) this._fileWritten = null;
+    if (this._fileEnabled()) void this._flushFile();
+
+    const obsWanted = settings.obsEnabled;
+    const endpointChanged =
+      !prev || prev.obsUrl !== settings.obsUrl || prev.obsPassword !== settings.obsPassword;
+    if (!obsWanted || endpointChanged) this._disconnect();
+    if (prev?.obsInputName !== settings.obsInputName) this._obsSent = null;
+    if (obsWanted && !this._socket && !this._reconnectTimer) this._connect();
+    else if (obsWanted) this._flushObs();
+  }
+
+  update(payload: CaptionPayload): void {
+    this._text = captionText(payload);
+    if (this._fileEnabled()) void this._flushFile();
+    this._flushObs();
+  }
+
+  close(): void {
+    this._settings = null;
+    this._disconnect();
+  }
+
+  private _fileEnabled(): boolean {
+    return !!this._settings?.textFileEnabled && !!this._settings.textFilePath;
+  }
+
+  // One write in flight at a time; whatever arrived meanwhile is written next.
+  private async _flushFile(): Promise<void> {
+    if (this._fileWriting) return;
+    this._fileWriting = true;
+    try {
+      while (this._fileEnabled() && this._fileWritten !== this._text) {
+        const text = this._text;
+        const target = this._settings!.textFilePath;
+        try {
+          await (this._deps.writeFile ?? writeCaptionFile)(target, text);
+          this._fileWritten = text;
+        } catch (err) {
+          this._deps.log?.(`Caption file write failed (${target}): ${String(err)}`);
+          break;
+        }
+      }
+    } finally {
+      this._fileWriting = false;
+    }
+  }
+
+  private _connect(): void {
+    const settings = this._settings;
+    if (!settings?.obsEnabled) return;
+    let socket: ObsSocket;
+    try {
+      socket = this._deps.createSocket(settings.obsUrl, {
+        onMessage: (data) => {
+          if (this._socket === socket) this._onObsMessage(data);
+        },
+        onClose: () => {
+          if (this._socket !== socket) return;
+          this._socket = null;
+          this._identified = false;
+          this._scheduleReconnect();
+        },
+      });
+    } catch (err) {
+      this._deps.log?.(`OBS connection failed (${settings.obsUrl}): ${String(err)}`);
+      this._scheduleReconnect();
+      return;
+    }
+    this._socket = socket;
+  }
+
+  private _scheduleReconnect(): void {
+    if (!this._settings?.obsEnabled || this._reconnectTimer) return;
+    this._reconnectTimer = setTimeout(() => {
+      this._reconnectTimer = null;
+      if (!this._socket) this._connect();
+    }, OBS_RECONNECT_MS);
+  }
+
+  private _disconnect(): void {
+    if (this._reconnectTimer) clearTimeout(this._reconnectTimer);
+    this._reconnectTimer = null;
+    const socket = this._socket;
+    this._socket = null;
+    this._identified = false;
+    this._obsSent = null;
+    socket?.close();
+  }
+
+  private _onObsMessage(data: string): void {
+    let message: { op?: number; d?: Record<string, unknown> };
+    try {
+      message = JSON.parse(data);
+    } catch {
+      return;
+    }
+    const d = message.d ?? {};
+    if (message.op === OP_HELLO) {
+      const auth = d.authentication as { challenge?: string; salt?: string } | undefined;
+      const identify: Record<string, unknown> = { rpcVersion: 1, eventSubscriptions: 0 };
+      if (auth?.challenge && auth.salt) {
+        identify.authentication = obsAuthentication(
+          this._settings?.obsPassword ?? '',
+          auth.salt,
+          auth.challenge,
+        );
+      }
+      this._socket?.send(JSON.stringify({ op: OP_IDENTIFY, d: identify }));
+    } else if (message.op === OP_IDENTIFIED) {
+      this._identified = true;
+      this._lastObsError = '';
+      this._obsSent = null;
+      this._flushObs();
+    } else if (message.op === OP_REQUEST_RESPONSE) {
+      const status = d.requestStatus as { result?: boolean; comment?: string } | undefined;
+      if (status && !status.result) {
+        // Logged once per distinct error — a missing source fails every update.
+        const error = status.comment ?? 'request failed';
+        if (error !== this._lastObsError) {
+          this._lastObsError = error;
+          this._deps.log?.(`OBS rejected the caption update: ${error}`);
+        }
+      }
+    }
+  }
+
+  private _flushObs(): void {
+    const settings = this._settings;
+    if (!settings?.obsEnabled || !this._socket || !this._identified) return;
+    if (this._obsSent === this._text) return;
+    this._obsSent = this._text;
+    this._socket.send(
+      JSON.stringify({
+        op: OP_REQUEST,
+        d: {
+          requestType: 'SetInputSettings',
+          requestId: `captions-${++this._requestSeq}`,
+          requestData: {
+            inputName: settings.obsInputName,
+            inputSettings: { text: this._text },
+            overlay: true,
+          },
+        },
+      }),
+    );
+  }
+}
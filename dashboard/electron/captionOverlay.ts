@@ -0,0 +1,194 @@
+/**
+ * Live caption overlay — a transparent, click-through, always-on-top window
+ * that shows the Live Mode transcript over every other app, so system-audio
+ * capture turns into real-time captions for calls, videos and games.
+ *
+ * The renderer owns the live session (loopback capture + /ws/live stream) and
+ * pushes the latest sentences and partial text here; this module only owns
+ * the window. The page is a static data: URL with no preload and no Node
+ * access — text is handed over as JSON through `executeJavaScript` and set
+ * with `textContent`, never parsed as HTML.
+ */
+
+export type CaptionPosition = 'top' | 'bottom';
+
+export interface CaptionPayload {
+  /** Completed sentences, oldest first. Only the last few are shown. */
+  lines: string[];
+  /** In-progress text for the sentence being spoken. */
+  partial: string;
+}
+
+export interface WorkArea {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+}
+
+/** The subset of BrowserWindow the overlay drives (injected for tests). */
+export interface OverlayWindow {
+  loadURL(url: string): Promise<void>;
+  showInactive(): void;
+  hide(): void;
+  destroy(): void;
+  isDestroyed(): boolean;
+  isVisible(): boolean;
+  setBounds(bounds: WorkArea): void;
+  setIgnoreMouseEvents(ignore: boolean): void;
+  setAlwaysOnTop(flag: boolean, level?: 'screen-saver'): void;
+  setVisibleOnAllWorkspaces(visible: boolean, options?: { visibleOnFullScreen?: boolean }): void;
+  on(event: 'closed', listener: () => void): void;
+  webContents: { executeJavaScript(code: string): Promise<unknown> };
+}
+
+export interface OverlayWindowOptions {
+  bounds: WorkArea;
+}
+
+export interface CaptionOverlayDeps {
+  createWindow: (options: OverlayWindowOptions) => OverlayWindow;
+  /** Work area of the display the overlay should cover. */
+  getWorkArea: () => WorkArea;
+  onVisibilityChanged?: (visible: boolean) => void;
+}
+
+/** Finished sentences kept on screen above the partial line. */
+export const CAPTION_HISTORY_LINES = 2;
+
+const OVERLAY_HEIGHT = 180;
+const OVERLAY_MARGIN = 48;
+
+/** Keep the tail of the transcript: the last sentences plus the partial. */
+export function visibleCaptionLines(
+  payload: CaptionPayload,
+  historyLines = CAPTION_HISTORY_LINES,
+): string[] {
+  const finished = payload.lines
+    .map((line) => line.trim())
+    .filter(Boolean)
+    .slice(-historyLines);
+  const partial = payload.partial.trim();
+  return partial ? [...finished, partial] : finished;
+}
+
+/** A centred strip, 80% of the work area wide, near the top or bottom edge. */
+export function overlayBounds(area: WorkArea, position: CaptionPosition): WorkArea {
+  const width = Math.round(area.width * 0.8);
+  const x = area.x + Math.round((area.width - width) / 2);
+  const y =
+    position === 'top'
+      ? area.y + OVERLAY_MARGIN
+      : area.y + area.height - OVERLAY_HEIGHT - OVERLAY_MARGIN;
+  return { x, y, width, height: OVERLAY_HEIGHT };
+}
+
+export function buildCaptionOverlayHtml(): string {
+  return `<!doctype html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta http-equiv="Content-Security-Policy" content="default-src 'none'; style-src 'unsafe-inline'; script-src 'unsafe-inline'">
+<style>
+  html, body { margin: 0; height: 100%; background: transparent; overflow: hidden; }
+  body { display: flex; flex-direction: column; justify-content: flex-end; align-items: center;
+    font: 600 28px/1.35 system-ui, -apple-system, 'Segoe UI', sans-serif; user-select: none; }
+  #captions { max-width: 100%; padding: 8px 18px; border-radius: 12px; text-align: center;
+    background: rgba(0, 0, 0, 0.62); color: #fff; text-shadow: 0 1px 2px #000; }
+  #captions:empty { display: none; }
+  #captions div { overflow-wrap: anywhere; }
+  #captions div.partial { color: rgba(255, 255, 255, 0.72); }
+</style>
+</head>
+<body>
+<div id="captions"></div>
+<script>
+  window.__setCaptions = function (lines, partial) {
+    var root = document.getElementById('captions');
+    root.textContent = '';
+    lines.forEach(function (text, i) {
+      var line = document.createElement('div');
+      line.textContent = text;
+      if (partial && i === lines.length - 1) line.className = 'partial';
+      root.appendChild(line);
+    });
+  };
+</script>
+</body>
+</html>`;
+}
+
+export class CaptionOverlay {
+  private _deps: CaptionOverlayDeps;
+  private _win: OverlayWindow | null = null;
+  private _ready: Promise<void> | null = null;
+  private _position: CaptionPosition = 'bottom';
+  private _last: CaptionPayload = { lines: [], partial: '' };
+
+  constructor(deps: CaptionOverlayDeps) {
+    this._deps = deps;
+  }
+
+  isVisible(): boolean {
+    return !!this._win && !this._win.isDestroyed() && this._win.isVisible();
+  }
+
+  async show(position: CaptionPosition = this._position): Promise<void> {
+    this._position = position;
+    const bounds = overlayBounds(this._deps.getWorkArea(), position);
+    if (!this._win || this._win.isDestroyed()) {
+      const win = this._deps.createWindow({ bounds });
+      // Clicks and the cursor fall through to whatever is underneath.
+      win.setIgnoreMouseEvents(true);
+      win.setAlwaysOnTop(true, 'screen-saver');
+      win.setVisibleOnAllWorkspaces(true, { visibleOnFullScreen: true });
+      win.on('closed', () => {
+        this._win = null;
+        this._ready = null;
+        this._deps.onVisibilityChanged?.(false);
+      });
+      this._win = win;
+      this._ready = win.loadURL(
+        `data:text/html;charset=utf-8,${encodeURIComponent(buildCaptionOverlayHtml())}`,
+      );
+    } else {
+      this._win.setBounds(bounds);
+    }
+    await this._ready;
+    await this._render();
+    this._win?.showInactive();
+    this._deps.onVisibilityChanged?.(true);
+  }
+
+  hide(): void {
+    if (!this._win || this._win.isDestroyed()) return;
+    this._win.hide();
+    this._deps.onVisibilityChanged?.(false);
+  }
+
+  /** Store the latest transcript; rendered immediately while visible. */
+  async update(payload: CaptionPayload): Promise<void> {
+    this._last = payload;
+    if (this.isVisible()) await this._render();
+  }
+
+  destroy(): void {
+    if (this._win && !this._win.isDestroyed()) this._win.destroy();
+    this._win = null;
+    this._ready = null;
+  }
+
+  private async _render(): Promise<void> {
+    const win = this._win;
+    if (!win || win.isDestroyed()) return;
+    const lines = visibleCaptionLines(this._last);
+    const hasPartial = this._last.partial.trim().length > 0;
+    try {
+      await win.webContents.executeJavaScript(
+        `window.__setCaptions(${JSON.stringify(lines)}, ${hasPartial})`,
+      );
+    } catch {
+      // Window torn down mid-update — the next show() re-renders.
+    }
+  }
+}
@@ -0,0 +1,160 @@
+/**
+ * channelProbe — how many channels a local recording has and how loud each
+ * one is, so the Import tab can show preview levels before the user picks
+ * which channels to transcribe (electron/mediaClip.ts then isolates them).
+ *
+ * ffprobe reports the channel count and layout; ffmpeg decodes the first
+ * minute of the first audio stream to interleaved 32-bit float PCM at a low
+ * sample rate, which is plenty for RMS and peak.
+ */
+
+import { execFile } from 'child_process';
+import { MediaClipError, ffmpegEnv } from './mediaClip.js';
+
+export interface ChannelLevel {
+  /** 0-based, as `MediaEdit.channels` takes it. */
+  index: number;
+  label: string;
+  rmsDb: number;
+  peakDb: number;
+}
+
+export interface ChannelProbe {
+  channels: number;
+  layout: string | null;
+  levels: ChannelLevel[];
+}
+
+export type RunCapture = (
+  command: string,
+  args: string[],
+  env: NodeJS.ProcessEnv,
+) => Promise<Buffer>;
+
+export interface ProbeChannelsOptions {
+  ffmpegPath?: string;
+  ffprobePath?: string;
+  run?: RunCapture;
+}
+
+export const PREVIEW_SECONDS = 60;
+const PREVIEW_RATE = 8000;
+/** Floor for silent channels, so a level is always a finite number. */
+export const SILENCE_DB = -96;
+
+function toDb(amplitude: number): number {
+  if (amplitude <= 0) return SILENCE_DB;
+  return Math.max(SILENCE_DB, Math.round(20 * Math.log10(amplitude) * 10) / 10);
+}
+
+export function channelLabels(count: number, layout: string | null): string[] {
+  if (count === 2 && (layout === 'stereo' || layout === null)) return ['Left', 'Right'];
+  return Array.from({ length: count }, (_, i) => `Channel ${i + 1}`);
+}
+
+/** RMS and peak per channel of interleaved samples, in dBFS. */
+export function channelLevels(
+  samples: Float32Array,
+  channels: number,
+): { rmsDb: number; peakDb: number }[] {
+  const sumSquares = new Float64Array(channels);
+  const peaks = new Float64Array(channels);
+  const frames = Math.floor(samples.length / channels);
+  for (let frame = 0; frame < frames; frame++) {
+    for (let ch = 0; ch < channels; ch++) {
+      const value = samples[frame * channels + ch];
+      sumSquares[ch] += value * value;
+      const magnitude = Math.abs(value);
+      if (magnitude > peaks[ch]) peaks[ch] = magnitude;
+    }
+  }
+  return Array.from({ length: channels }, (_, ch) => ({
+    rmsDb: toDb(frames > 0 ? Math.sqrt(sumSquares[ch] / frames) : 0),
+    peakDb: toDb(peaks[ch]),
+  }));
+}
+
+const runCapture: RunCapture = (command, args, env) =>
+  new Promise((resolve, reject) => {
+    execFile(
+      command,
+      args,
+      { env, encoding: 'buffer', maxBuffer: 64 * 1024 * 1024 },
+      (err, stdout, stderr) => {
+        if (!err) {
+          resolve(stdout);
+          return;
+        }
+        if ((err as NodeJS.ErrnoException).code === 'ENOENT') {
+          reject(new MediaClipError(`${command} was not found — install ffmpeg to pick channels`));
+          return;
+        }
+        const detail = stderr.toString().trim().split('\n').pop() || err.message;
+        reject(new MediaClipError(`Could not read the channels: ${detail}`));
+      },
+    );
+  });
+
+export async function probeChannels(
+  source: string,
+  options: ProbeChannelsOptions = {},
+): Promise<ChannelProbe> {
+  const run = options.run ?? runCapture;
+  const env = ffmpegEnv();
+  const probed = await run(
+    options.ffprobePath || 'ffprobe',
+    [
+      '-v',
+      'error',
+      '-select_streams',
+      'a:0',
+      '-show_entries',
+      'stream=channels,channel_layout',
+      '-of',
+      'json',
+      source,
+    ],
+    env,
+  );
+  const stream = (JSON.parse(probed.toString()) as { streams?: Record<string, unknown>[] })
+    .streams?.[0];
+  const channels = Number(stream?.channels);
+  if (!Number.isInteger(channels) || channels < 1) {
+    throw new MediaClipError('The file has no audio stream');
+  }
+  const layout = typeof stream?.channel_layout === 'string' ? stream.channel_layout : null;
+
+  const pcm = await run(
+    options.ffmpegPath || 'ffmpeg',
+    [
+      '-hide_banner',
+      '-nostdin',
+      '-loglevel',
+      'error',
+      '-t',
+      String(PREVIEW_SECONDS),
+      '-i',
+      source,
+      '-map',
+      '0:a:0',
+      '-ar',
+      String(PREVIEW_RATE),
+      '-f',
+      'f32le',
+      '-',
+    ],
+    env,
+  );
+  // Copy into an aligned buffer: a Buffer may start at any byte offset.
+  const samples = new Float32Array(new Uint8Array(pcm).buffer, 0, Math.floor(pcm.length / 4));
+  const labels = channelLabels(channels, layout);
+  return {
+    channels,
+    layout,
+    levels: channelLevels(samples, channels).map((level, index) => ({
+      index,
+      label: labels[index],
+      ...level,
+    })),
+  };
+}
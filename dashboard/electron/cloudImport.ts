@@ -0,0 +1,706 @@
+/**
+ * Cloud import sources: browse an S3 bucket, a Google Drive folder or a
+ * Dropbox folder and pull media into the local cache for the import queue.
+ *
+ * Source definitions (provider, label, bucket, OAuth client ID…) live in the
+ * dashboard store under `cloudImport.sources`. Anything secret — the S3
+ * secret access key, OAuth refresh tokens and client secrets — is sealed with
+ * Electron's safeStorage and kept under `cloudImport.secret.<id>`; without
+ * secure storage a source can be defined but not connected.
+ *
+ * Drive and Dropbox sign in through the system browser: an authorization-code
+ * flow with PKCE whose redirect lands on a one-shot HTTP listener bound to
+ * 127.0.0.1 (RFC 8252 loopback redirect). S3 requests are signed locally with
+ * AWS Signature V4, so S3-compatible stores (MinIO, R2, B2) work through a
+ * custom endpoint.
+ *
+ * Downloads stream into `<cache>/cloud-import/<source>/<hash>/<name>`; the
+ * original file name is kept because the server records it with the
+ * recording. A file already cached at the listed size is not fetched again.
+ */
+
+import crypto from 'crypto';
+import fs from 'fs';
+import http from 'http';
+import path from 'path';
+
+export type CloudProvider = 's3' | 'gdrive' | 'dropbox';
+
+export const CLOUD_PROVIDERS: readonly CloudProvider[] = ['s3', 'gdrive', 'dropbox'];
+
+export const SOURCES_STORE_KEY = 'cloudImport.sources';
+const SECRET_STORE_PREFIX = 'cloudImport.secret.';
+
+/** Same set the Notebook import accepts, plus the common video containers. */
+export const IMPORTABLE_EXTENSIONS = [
+  '.mp3',
+  '.wav',
+  '.m4a',
+  '.flac',
+  '.ogg',
+  '.webm',
+  '.opus',
+  '.mp4',
+  '.mkv',
+  '.mov',
+];
+
+const OAUTH_TIMEOUT_MS = 5 * 60 * 1000;
+const EMPTY_SHA256 = 'e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855';
+
+export interface CloudSource {
+  id: string;
+  provider: CloudProvider;
+  label: string;
+  /** S3 only. `endpoint` switches to path-style URLs for S3-compatible stores. */
+  bucket?: string;
+  region?: string;
+  endpoint?: string;
+  prefix?: string;
+  accessKeyId?: string;
+  /** Drive / Dropbox only: the OAuth client registered by the user. */
+  clientId?: string;
+}
+
+export interface CloudSourceState extends CloudSource {
+  connected: boolean;
+}
+
+/** Secrets accepted by `saveSource`; stored sealed, never returned. */
+export interface CloudSourceSecret {
+  secretAccessKey?: string;
+  clientSecret?: string;
+}
+
+interface SealedSecret extends CloudSourceSecret {
+  refreshToken?: string;
+}
+
+export interface CloudEntry {
+  /** S3 key / prefix, Drive file ID or Dropbox lower-case path. */
+  id: string;
+  name: string;
+  isFolder: boolean;
+  size?: number;
+  modifiedAt?: string;
+}
+
+export interface CloudDownloadProgress {
+  sourceId: string;
+  entryId: string;
+  name: string;
+  received: number;
+  total: number | null;
+  done: boolean;
+}
+
+export interface CloudStore {
+  get(key: string): unknown;
+  set(key: string, value: unknown): void;
+  delete(key: string): void;
+}
+
+export interface CloudSafeStorage {
+  isEncryptionAvailable(): boolean;
+  encryptString(plainText: string): Buffer;
+  decryptString(encrypted: Buffer): string;
+}
+
+export interface CloudImportDeps {
+  store: CloudStore;
+  safeStorage: CloudSafeStorage;
+  cacheDir: string;
+  openExternal: (url: string) => Promise<void> | void;
+  fetch?: typeof fetch;
+  now?: () => Date;
+}
+
+interface OAuthProvider {
+  authUrl: string;
+  tokenUrl: string;
+  scope: string;
+  authParams: Record<string, string>;
+}
+
+const OAUTH: Record<Exclude<CloudProvider, 's3'>, OAuthProvider> = {
+  gdrive: {
+    authUrl: 'https://accounts.google.com/o/oauth2/v2/auth',
+    tokenUrl: 'https://oauth2.googleapis.com/token',
+    scope: 'https://www.googleapis.com/auth/drive.readonly',
+    authParams: { access_type: 'offline', prompt: 'consent' },
+  },
+  dropbox: {
+    authUrl: 'https://www.dropbox.com/oauth2/authorize',
+    tokenUrl: 'https://api.dropboxapi.com/oauth2/token',
+    scope: 'files.content.read files.metadata.read',
+    authParams: { token_access_type: 'offline' },
+  },
+};
+
+const DRIVE_FOLDER_MIME = 'application/vnd.google-apps.folder';
+
+export function isImportableName(name: string): boolean {
+  return IMPORTABLE_EXTENSIONS.includes(path.extname(name).toLowerCase());
+}
+
+// ─── AWS Signature V4 ───────────────────────────────────────────────────────
+
+/** RFC 3986 encoding, as SigV4 requires (encodeURIComponent leaves !'()*). */
+export function encodeRfc3986(value: string): string {
+  return encodeURIComponent(value).replace(
+    /[!'()*]/g,
+    (c) => `%${c.charCodeAt(0).toString(16).toUpperCase()}`,
+  );
+}
+
+function sha256Hex(data: string): string {
+  return crypto.createHash('sha256').update(data).digest('hex');
+}
+
+function hmac(key: Buffer | string, data: string): Buffer {
+  return crypto.createHmac('sha256', key).update(data).digest();
+}
+
+export interface S3SignInput {
+  method: string;
+  url: string;
+  region: string;
+  accessKeyId: string;
+  secretAccessKey: string;
+  now: Date;
+  /** Extra headers to sign (e.g. Range). */
+  headers?: Record<string, string>;
+}
+
+/** Headers (incl. Authorization) for a bodiless S3 request, signed with SigV4. */
+export function signS3Request(input: S3SignInput): Record<string, string> {
+  const url = new URL(input.url);
+  const amzDate = input.now.toISOString().replace(/[:-]|\.\d{3}/g, '');
+  const date = amzDate.slice(0, 8);
+  const headers: Record<string, string> = {
+    host: url.host,
+    'x-amz-content-sha256': EMPTY_SHA256,
+    'x-amz-date': amzDate,
+  };
+  for (const [name, value] of Object.entries(input.headers ?? {})) {
+    headers[name.toLowerCase()] = value.trim();
+  }
+  const names = Object.keys(headers).sort();
+  const canonicalQuery = [...url.searchParams.entries()]
+    .map(([k, v]) => [encodeRfc3986(k), encodeRfc3986(v)])
+    .sort(([a, av], [b, bv]) => (a === b ? (av < bv ? -1 : 1) : a < b ? -1 : 1))
+    .map(([k, v]) => `${k}=${v}`)
+    .join('&');
+  const canonicalRequest = [
+    input.method,
+    url.pathname,
+    canonicalQuery,
+    names.map((n) => `${n}:${headers[n]}\n`).join(''),
+    names.join(';'),
+    EMPTY_SHA256,
+  ].join('\n');
+  const scope = `${date}/${input.region}/s3/aws4_request`;
+  const stringToSign = ['AWS4-HMAC-SHA256', amzDate, scope, sha256Hex(canonicalRequest)];
+  const signingKey = ['s3', 'aws4_request'].reduce<Buffer>(
+    (key, part) => hmac(key, part),
+    hmac(hmac(`AWS4${input.secretAccessKey}`, date), input.region),
+  );
+  const signature = hmac(signingKey, stringToSign.join('\n')).toString('hex');
+  // fetch sets Host itself from the URL.
+  const sent = Object.fromEntries(Object.entries(headers).filter(([name]) => name !== 'host'));
+  return {
+    ...sent,
+    authorization:
+      `AWS4-HMAC-SHA256 Credential=${input.accessKeyId}/${scope}, ` +
+      `SignedHeaders=${names.join(';')}, Signature=${signature}`,
+  };
+}
+
+function s3BaseUrl(source: CloudSource): string {
+  const region = source.region || 'us-east-1';
+  if (source.endpoint) {
+    return `${source.endpoint.replace(/\/+$/, '')}/${encodeRfc3986(source.bucket ?? '')}`;
+  }
+  return `https://${source.bucket}.s3.${region}.amazonaws.com`;
+}
+
+function s3ObjectUrl(source: CloudSource, key: string): string {
+  return `${s3BaseUrl(source)}/${key.split('/').map(encodeRfc3986).join('/')}`;
+}
+
+function xmlText(block: string, tag: string): string | undefined {
+  const match = block.match(new RegExp(`<${tag}>([\\s\\S]*?)</${tag}>`));
+  if (!match) return undefined;
+  return match[1]
+    .replace(/&lt;/g, '<')
+    .replace(/&gt;/g, '>')
+    .replace(/&quot;/g, '"')
+    .replace(/&apos;/g, "'")
+    .replace(/&amp;/g, '&');
+}
+
+function xmlBlocks(xml: string, tag: string): string[] {
+  return [...xml.matchAll(new RegExp(`<${tag}>([\\s\\S]*?)</${tag}>`, 'g'))].map((m) => m[1]);
+}
+
+/** Entries of one ListObjectsV2 page (delimiter `/`) plus its continuation token. */
+export function parseS3Listing(
+  xml: string,
+  prefix: string,
+): { entries: CloudEntry[]; next?: string } {
+  const folders = xmlBlocks(xml, 'CommonPrefixes').map((block) => {
+    const id = xmlText(block, 'Prefix') ?? '';
+    return { id, name: id.slice(prefix.length).replace(/\/$/, ''), isFolder: true };
+  });
+  const files = xmlBlocks(xml, 'Contents')
+    .map((block) => {
+      const id = xmlText(block, 'Key') ?? '';
+      return {
+        id,
+        name: id.slice(prefix.length),
+        isFolder: false,
+        size: Number(xmlText(block, 'Size') ?? 0),
+        modifiedAt: xmlText(block, 'LastModified'),
+      };
+    })
+    .filter((entry) => entry.name && !entry.name.endsWith('/'));
+  const truncated = xmlText(xml, 'IsTruncated') === 'true';
+  return {
+    entries: [...folders, ...files],
+    next: truncated ? xmlText(xml, 'NextContinuationToken') : undefined,
+  };
+}
+
+// ─── OAuth loopback ─────────────────────────────────────────────────────────
+
+function base64Url(buffer: Buffer): string {
+  return buffer.toString('base64').replace(/\+/g, '-').replace(/\//g, '_').replace(/=+$/, '');
+}
+
+export function pkcePair(random: Buffer = crypto.randomBytes(32)): {
+  verifier: string;
+  challenge: string;
+} {
+  const verifier = base64Url(random);
+  const challenge = base64Url(crypto.createHash('sha256').update(verifier).digest());
+  return { verifier, challenge };
+}
+
+const LOOPBACK_PAGE =
+  '<!doctype html><meta charset="utf-8"><title>TranscriptionSuite</title>' +
+  '<p style="font-family:sans-serif">%s You can close this tab.</p>';
+
+/**
+ * One-shot redirect listener on 127.0.0.1. `onListening` gets the redirect
+ * URI (random free port); resolves with the authorization code once the
+ * browser comes back with the matching `state`.
+ */
+export function awaitLoopbackRedirect(
+  state: string,
+  onListening: (redirectUri: string) => void,
+  timeoutMs = OAUTH_TIMEOUT_MS,
+): Promise<{ code: string; redirectUri: string }> {
+  return new Promise((resolve, reject) => {
+    let redirectUri = '';
+    const server = http.createServer((req, res) => {
+      const url = new URL(req.url ?? '/', 'http://127.0.0.1');
+      if (url.pathname !== '/callback') {
+        res.writeHead(404).end();
+        return;
+      }
+      const error = url.searchParams.get('error');
+      const code = url.searchParams.get('code');
+      const ok = !error && code && url.searchParams.get('state') === state;
+      res
+        .writeHead(ok ? 200 : 400, { 'Content-Type': 'text/html; charset=utf-8' })
+        .end(LOOPBACK_PAGE.replace('%s', ok ? 'Signed in.' : 'Sign-in failed.'));
+      finish(
+        ok ? null : new Error(error ? `Sign-in refused: ${error}` : 'Sign-in state mismatch'),
+        code ?? '',
+      );
+    });
+    const timer = setTimeout(() => finish(new Error('Sign-in timed out'), ''), timeoutMs);
+    function finish(err: Error | null, code: string) {
+      clearTimeout(timer);
+      server.close();
+      if (err) reject(err);
+      else resolve({ code, redirectUri });
+    }
+    server.on('error', (err) => finish(err, ''));
+    server.listen(0, '127.0.0.1', () => {
+      const address = server.address();
+      const port = typeof address === 'object' && address ? address.port : 0;
+      redirectUri = `http://127.0.0.1:${port}/callback`;
+      onListening(redirectUri);
+    });
+  });
+}
+
+// ─── Sources ────────────────────────────────────────────────────────────────
+
+function dropboxApiArg(value: unknown): string {
+  // HTTP headers are ASCII: Dropbox wants non-ASCII escaped as \uXXXX.
+  return JSON.stringify(value).replace(
+    /[\u007f-\uffff]/g,
+    (c) => `\\u${c.charCodeAt(0).toString(16).padStart(4, '0')}`,
+  );
+}
+
+export class CloudImport {
+  private readonly deps: CloudImportDeps;
+  private readonly fetch: typeof fetch;
+  private readonly accessTokens = new Map<string, { token: string; expiresAt: number }>();
+
+  constructor(deps: CloudImportDeps) {
+    this.deps = deps;
+    this.fetch = deps.fetch ?? globalThis.fetch;
+  }
+
+  private now(): Date {
+    return this.deps.now?.() ?? new Date();
+  }
+
+  private rawSources(): CloudSource[] {
+    const value = this.deps.store.get(SOURCES_STORE_KEY);
+    return Array.isArray(value) ? (value as CloudSource[]) : [];
+  }
+
+  private source(id: string): CloudSource {
+    const found = this.rawSources().find((s) => s.id === id);
+    if (!found) throw new Error(`Unknown cloud source: ${id}`);
+    return found;
+  }
+
+  private readSecret(id: string): SealedSecret {
+    const sealed = this.deps.store.get(SECRET_STORE_PREFIX + id);
+    if (typeof sealed !== 'string' || !sealed) return {};
+    if (!this.deps.safeStorage.isEncryptionAvailable()) return {};
+    try {
+      return JSON.parse(this.deps.safeStorage.decryptString(Buffer.from(sealed, 'base64')));
+    } catch {
+      return {};
+    }
+  }
+
+  private writeSecret(id: string, secret: SealedSecret): void {
+    if (!this.deps.safeStorage.isEncryptionAvailable()) {
+      throw new Error('Secure storage is unavailable — cloud credentials cannot be saved');
+    }
+    const sealed = this.deps.safeStorage.encryptString(JSON.stringify(secret));
+    this.deps.store.set(SECRET_STORE_PREFIX + id, sealed.toString('base64'));
+  }
+
+  private isConnected(source: CloudSource): boolean {
+    const secret = this.readSecret(source.id);
+    return source.provider === 's3' ? !!secret.secretAccessKey : !!secret.refreshToken;
+  }
+
+  listSources(): CloudSourceState[] {
+    return this.rawSources().map((s) => ({ ...s, connected: this.isConnected(s) }));
+  }
+
+  /** Create or update a source; blank secret fields keep the stored value. */
+  saveSource(input: Omit<CloudSource, 'id'> & { id?: string }, secret?: CloudSourceSecret) {
+    if (!CLOUD_PROVIDERS.includes(input.provider)) {
+      throw new Error(`Unsupported cloud provider: ${input.provider}`);
+    }
+    if (input.provider === 's3' && !input.bucket) throw new Error('An S3 source needs a bucket');
+    if (input.provider !== 's3' && !input.clientId) throw new Error('An OAuth client ID is needed');
+    const id = input.id || crypto.randomUUID();
+    const source: CloudSource =
+      input.provider === 's3'
+        ? {
+            id,
+            provider: 's3',
+            label: input.label.trim() || input.bucket || 's3',
+            bucket: input.bucket,
+            region: input.region?.trim() || undefined,
+            endpoint: input.endpoint?.trim() || undefined,
+            prefix: input.prefix?.trim() || undefined,
+            accessKeyId: input.accessKeyId?.trim(),
+          }
+        : {
+            id,
+            provider: input.provider,
+            label: input.label.trim() || input.provider,
+            clientId: input.clientId?.trim(),
+          };
+    const fresh = Object.fromEntries(
+      Object.entries(secret ?? {}).filter(([, value]) => typeof value === 'string' && value),
+    );
+    if (Object.keys(fresh).length > 0) {
+      this.writeSecret(source.id, { ...this.readSecret(source.id), ...fresh });
+    }
+    const others = this.rawSources().filter((s) => s.id !== source.id);
+    this.deps.store.set(SOURCES_STORE_KEY, [...others, source]);
+    return { ...source, connected: this.isConnected(source) };
+  }
+
+  removeSource(id: string): void {
+    this.deps.store.set(SOURCES_STORE_KEY, this.rawSources().filter((s) => s.id !== id));
+    this.deps.store.delete(SECRET_STORE_PREFIX + id);
+    this.accessTokens.delete(id);
+  }
+
+  /** Browser sign-in for Drive / Dropbox; stores the refresh token sealed. */
+  async connect(id: string): Promise<void> {
+    const source = this.source(id);
+    if (source.provider === 's3') return;
+    const provider = OAUTH[source.provider];
+    const state = base64Url(crypto.randomBytes(16));
+    const { verifier, challenge } = pkcePair();
+    const { code, redirectUri } = await awaitLoopbackRedirect(state, (redirect) => {
+      const params = new URLSearchParams({
+        client_id: source.clientId ?? '',
+        redirect_uri: redirect,
+        response_type: 'code',
+        scope: provider.scope,
+        state,
+        code_challenge: challenge,
+        code_challenge_method: 'S256',
+        ...provider.authParams,
+      });
+      void this.deps.openExternal(`${provider.authUrl}?${params}`);
+    });
+    const tokens = await this.tokenRequest(source, {
+      grant_type: 'authorization_code',
+      code,
+      redirect_uri: redirectUri,
+      code_verifier: verifier,
+    });
+    if (!tokens.refresh_token) throw new Error('The provider returned no refresh token');
+    this.writeSecret(id, { ...this.readSecret(id), refreshToken: tokens.refresh_token });
+  }
+
+  private async tokenRequest(
+    source: CloudSource,
+    params: Record<string, string>,
+  ): Promise<{ access_token: string; refresh_token?: string; expires_in?: number }> {
+    const provider = OAUTH[source.provider as Exclude<CloudProvider, 's3'>];
+    const { clientSecret } = this.readSecret(source.id);
+    const body = new URLSearchParams({ client_id: source.clientId ?? '', ...params });
+    if (clientSecret) body.set('client_secret', clientSecret);
+    const res = await this.fetch(provider.tokenUrl, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/x-www-form-urlencoded' },
+      body,
+    });
+    if (!res.ok) throw new Error(`Token request failed (${res.status}): ${await res.text()}`);
+    const tokens = (await res.json()) as { access_token: string; expires_in?: number };
+    this.accessTokens.set(source.id, {
+      token: tokens.access_token,
+      expiresAt: this.now().getTime() + ((tokens.expires_in ?? 3600) - 60) * 1000,
+    });
+    return tokens;
+  }
+
+  private async accessToken(source: CloudSource): Promise<string> {
+    const cached = this.accessTokens.get(source.id);
+    if (cached && cached.expiresAt > this.now().getTime()) return cached.token;
+    const { refreshToken } = this.readSecret(source.id);
+    if (!refreshToken) throw new Error(`"${source.label}" is not signed in`);
+    const tokens = await this.tokenRequest(source, {
+      grant_type: 'refresh_token',
+      refresh_token: refreshToken,
+    });
+    return tokens.access_token;
+  }
+
+  private async request(source: CloudSource, url: string, init: RequestInit = {}) {
+    let headers: Record<string, string> = { ...(init.headers as Record<string, string>) };
+    if (source.provider === 's3') {
+      const { secretAccessKey } = this.readSecret(source.id);
+      if (!secretAccessKey) throw new Error(`"${source.label}" has no secret access key`);
+      headers = signS3Request({
+        method: init.method ?? 'GET',
+        url,
+        region: source.region || 'us-east-1',
+        accessKeyId: source.accessKeyId ?? '',
+        secretAccessKey,
+        now: this.now(),
+      });
+    } else {
+      headers.Authorization = `Bearer ${await this.accessToken(source)}`;
+    }
+    const res = await this.fetch(url, { ...init, headers });
+    if (!res.ok) {
+      throw new Error(`${source.label}: request failed (${res.status}) ${await res.text()}`);
+    }
+    return res;
+  }
+
+  /**
+   * Folders and importable files inside `folderId` (S3 prefix, Drive folder
+   * ID, Dropbox path). The source's own root is used when omitted.
+   */
+  async browse(id: string, folderId?: string): Promise<CloudEntry[]> {
+    const source = this.source(id);
+    const entries =
+      source.provider === 's3'
+        ? await this.browseS3(source, folderId ?? source.prefix ?? '')
+        : source.provider === 'gdrive'
+          ? await this.browseDrive(source, folderId || 'root')
+          : await this.browseDropbox(source, folderId ?? '');
+    return entries
+      .filter((e) => e.isFolder || isImportableName(e.name))
+      .sort((a, b) => Number(b.isFolder) - Number(a.isFolder) || a.name.localeCompare(b.name));
+  }
+
+  private async browseS3(source: CloudSource, prefix: string): Promise<CloudEntry[]> {
+    const entries: CloudEntry[] = [];
+    let token: string | undefined;
+    do {
+      const params = new URLSearchParams({ 'list-type': '2', delimiter: '/', prefix });
+      if (token) params.set('continuation-token', token);
+      const query = [...params.entries()]
+        .map(([k, v]) => `${encodeRfc3986(k)}=${encodeRfc3986(v)}`)
+        .join('&');
+      const res = await this.request(source, `${s3BaseUrl(source)}/?${query}`);
+      const page = parseS3Listing(await res.text(), prefix);
+      entries.push(...page.entries);
+      token = page.next;
+    } while (token);
+    return entries;
+  }
+
+  private async browseDrive(source: CloudSource, folderId: string): Promise<CloudEntry[]> {
+    const entries: CloudEntry[] = [];
+    let pageToken: string | undefined;
+    do {
+      const params = new URLSearchParams({
+        q: `'${folderId.replace(/'/g, "\\'")}' in parents and trashed = false`,
+        fields: 'nextPageToken, files(id, name, mimeType, size, modifiedTime)',
+        pageSize: '1000',
+      });
+      if (pageToken) params.set('pageToken', pageToken);
+      const res = await this.request(source, `https://www.googleapis.com/drive/v3/files?${params}`);
+      const page = (await res.json()) as {
+        nextPageToken?: string;
+        files: {
+          id: string;
+          name: string;
+          mimeType: string;
+          size?: string;
+          modifiedTime?: string;
+        }[];
+      };
+      for (const file of page.files) {
+        entries.push({
+          id: file.id,
+          name: file.name,
+          isFolder: file.mimeType === DRIVE_FOLDER_MIME,
+          size: file.size ? Number(file.size) : undefined,
+          modifiedAt: file.modifiedTime,
+        });
+      }
+      pageToken = page.nextPageToken;
+    } while (pageToken);
+    return entries;
+  }
+
+  private async browseDropbox(source: CloudSource, folderPath: string): Promise<CloudEntry[]> {
+    type Page = {
+      entries: {
+        '.tag': string;
+        name: string;
+        path_lower: string;
+        size?: number;
+        server_modified?: string;
+      }[];
+      cursor: string;
+      has_more: boolean;
+    };
+    const entries: CloudEntry[] = [];
+    const post = async (endpoint: string, body: unknown): Promise<Page> => {
+      const res = await this.request(source, `https://api.dropboxapi.com/2/files/${endpoint}`, {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify(body),
+      });
+      return (await res.json()) as Page;
+    };
+    let page = await post('list_folder', { path: folderPath });
+    for (;;) {
+      for (const entry of page.entries) {
+        if (entry['.tag'] === 'deleted') continue;
+        entries.push({
+          id: entry.path_lower,
+          name: entry.name,
+          isFolder: entry['.tag'] === 'folder',
+          size: entry.size,
+          modifiedAt: entry.server_modified,
+        });
+      }
+      if (!page.has_more) break;
+      page = await post('list_folder/continue', { cursor: page.cursor });
+    }
+    return entries;
+  }
+
+  /** Local cache path for an entry: the original name in a per-entry folder. */
+  cachePath(sourceId: string, entry: CloudEntry): string {
+    const bucket = crypto.createHash('sha1').update(entry.id).digest('hex').slice(0, 12);
+    const name = entry.name.replace(/[\\/:*?"<>|]/g, '_');
+    return path.join(this.deps.cacheDir, 'cloud-import', sourceId, bucket, name);
+  }
+
+  /** Stream one file into the cache and return its local path. */
+  async download(
+    id: string,
+    entry: CloudEntry,
+    onProgress?: (progress: CloudDownloadProgress) => void,
+  ): Promise<string> {
+    const source = this.source(id);
+    const target = this.cachePath(id, entry);
+    const report = (received: number, total: number | null, done: boolean) =>
+      onProgress?.({ sourceId: id, entryId: entry.id, name: entry.name, received, total, done });
+
+    const cached = await fs.promises.stat(target).catch(() => null);
+    if (cached && entry.size !== undefined && cached.size === entry.size) {
+      // Reused: bump the mtime so cache eviction (cacheManager.ts) keeps it.
+      const now = this.now();
+      await fs.promises.utimes(target, now, now).catch(() => {});
+      report(cached.size, cached.size, true);
+      return target;
+    }
+
+    const res =
+      source.provider === 's3'
+        ? await this.request(source, s3ObjectUrl(source, entry.id))
+        : source.provider === 'gdrive'
+          ? await this.request(
+              source,
+              `https://www.googleapis.com/drive/v3/files/${encodeURIComponent(entry.id)}?alt=media`,
+            )
+          : await this.request(source, 'https://content.dropboxapi.com/2/files/download', {
+              method: 'POST',
+              headers: { 'Dropbox-API-Arg': dropboxApiArg({ path: entry.id }) },
+            });
+    const length = Number(res.headers.get('content-length'));
+    const total = Number.isFinite(length) && length > 0 ? length : (entry.size ?? null);
+
+    await fs.promises.mkdir(path.dirname(target), { recursive: true });
+    const partial = `${target}.part`;
+    const handle = await fs.promises.open(partial, 'w');
+    let received = 0;
+    try {
+      const reader = res.body?.getReader();
+      if (!reader) throw new Error(`${entry.name}: empty response`);
+      for (;;) {
+        const { done, value } = await reader.read();
+        if (done) break;
+        await handle.write(value);
+        received += value.byteLength;
+        report(received, total, false);
+      }
+    } catch (err) {
+      await handle.close();
+      await fs.promises.rm(partial, { force: true });
+      throw err;
+    }
+    await handle.close();
+    await fs.promises.rename(partial, target);
+    report(received, total ?? received, true);
+    return target;
+  }
+}
@@ -0,0 +1,106 @@
+/**
+ * Config change bus — lets subsystems reconfigure live when settings change
+ * instead of waiting for an app restart.
+ *
+ * `config:set` publishes every written key. Subscribers name the key
+ * prefixes they depend on (`'shortcuts.'`, `'phoneIngest.port'`) and get a
+ * handler call with the keys that changed. Writes that land in the same
+ * tick are delivered together, so a settings form saving ten keys re-binds
+ * a port or re-registers hotkeys once, not ten times.
+ *
+ * Each subscriber's handler runs one at a time: a change arriving while it
+ * is still reconfiguring (say, closing a server before re-listening) is
+ * held and delivered once it finishes. A handler that throws is logged and
+ * reported through `onError`; it stays subscribed and the others still run.
+ *
+ * Settings read once at launch by Chromium itself (`connection.tuning.*`
+ * HTTP/2, which becomes a command-line switch) still need a restart.
+ */
+
+export type ConfigChangeHandler = (keys: string[]) => void | Promise<void>;
+
+export interface ConfigBusOptions {
+  onError?: (subscriber: string, err: unknown) => void;
+}
+
+interface Subscriber {
+  name: string;
+  prefixes: string[];
+  handler: ConfigChangeHandler;
+  running: boolean;
+  /** Keys that changed while the handler was running. */
+  held: Set<string>;
+}
+
+const matches = (key: string, prefixes: string[]) =>
+  prefixes.some((prefix) => key === prefix || key.startsWith(prefix));
+
+export class ConfigBus {
+  private readonly subscribers = new Set<Subscriber>();
+  private pending = new Set<string>();
+  private flushScheduled = false;
+
+  constructor(private readonly options: ConfigBusOptions = {}) {}
+
+  /** Call `handler` when a key starting with one of `prefixes` changes. Returns an unsubscribe. */
+  subscribe(name: string, prefixes: string | string[], handler: ConfigChangeHandler): () => void {
+    const subscriber: Subscriber = {
+      name,
+      prefixes: Array.isArray(prefixes) ? prefixes : [prefixes],
+      handler,
+      running: false,
+      held: new Set(),
+    };
+    this.subscribers.add(subscriber);
+    return () => {
+      this.subscribers.delete(subscriber);
+    };
+  }
+
+  /** Announce written keys; delivery happens once the current tick's writes are in. */
+  publish(...keys: string[]): void {
+    for (const key of keys) this.pending.add(key);
+    if (this.flushScheduled) return;
+    this.flushScheduled = true;
+    queueMicrotask(() => this.flush());
+  }
+
+  /** Resolves once every delivery in flight, including held re-runs, has finished. */
+  async idle(): Promise<void> {
+    while (this.flushScheduled || [...this.subscribers].some((s) => s.running)) {
+      await new Promise((resolve) => setTimeout(resolve, 0));
+    }
+  }
+
+  private flush(): void {
+    this.flushScheduled = false;
+    const keys = [...this.pending];
+    this.pending = new Set();
+    for (const subscriber of this.subscribers) {
+      const relevant = keys.filter((key) => matches(key, subscriber.prefixes));
+      if (relevant.length === 0) continue;
+      if (subscriber.running) {
+        relevant.forEach((key) => subscriber.held.add(key));
+      } else {
+        void this.deliver(subscriber, relevant);
+      }
+    }
+  }
+
+  private async deliver(subscriber: Subscriber, keys: string[]): Promise<void> {
+    subscriber.running = true;
+    try {
+      await subscriber.handler(keys);
+    } catch (err) {
+      console.warn(`[ConfigBus] ${subscriber.name} failed to reconfigure:`, err);
+      this.options.onError?.(subscriber.name, err);
+    } finally {
+      subscriber.running = false;
+    }
+    if (subscriber.held.size > 0 && this.subscribers.has(subscriber)) {
+      const held = [...subscriber.held];
+      subscriber.held.clear();
+      await this.deliver(subscriber, held);
+    }
+  }
+}
@@ -0,0 +1,216 @@
+/**
+ * Connection quality monitor — active probing of the link to the server.
+ *
+ * Every round sends a short burst of `GET /health` probes to the active
+ * connection profile and one to the alternative remote path (Tailscale ↔
+ * LAN reach the same server two ways), keeps a sliding window of the
+ * results per profile and classifies each link:
+ *
+ *   offline   no probe in the latest round answered
+ *   degraded  loss over the window ≥ DEGRADED_LOSS, or median latency
+ *             ≥ DEGRADED_LATENCY_MS
+ *   good      otherwise
+ *
+ * Each round is emitted as a `ConnectionQualityReport`. When the active link
+ * has been bad for `SWITCH_AFTER_BAD_ROUNDS` rounds in a row while the
+ * alternative is good, the report carries `suggestSwitchTo`; the renderer
+ * decides whether to act on it (`connection.autoFailover`).
+ */
+
+import http from 'http';
+import https from 'https';
+import type Store from 'electron-store';
+
+import type { ConnectionProfile } from './connectionTuning.js';
+
+type AnyStore = Store<any>;
+
+export type LinkQuality = 'good' | 'degraded' | 'offline';
+
+export interface ProfileQuality {
+  profile: ConnectionProfile;
+  quality: LinkQuality;
+  /** Median round trip of the answered probes in the window; null if none answered. */
+  latencyMs: number | null;
+  /** Share of probes in the window (0–1) that failed or timed out. */
+  loss: number;
+}
+
+export interface ConnectionQualityReport extends ProfileQuality {
+  checkedAt: number;
+  /** The other remote path to the same server, when one is configured. */
+  alternative: ProfileQuality | null;
+  /** Set once the active link has stayed bad while the alternative is good. */
+  suggestSwitchTo: ConnectionProfile | null;
+}
+
+export interface ConnectionQualityTargets {
+  active: ConnectionProfile;
+  alternative: ConnectionProfile | null;
+}
+
+export interface ConnectionQualityDeps {
+  /** Round trip in ms, or null when the probe failed or timed out. */
+  probe: (profile: ConnectionProfile) => Promise<number | null>;
+  targets: () => ConnectionQualityTargets;
+  emit: (report: ConnectionQualityReport) => void;
+  intervalMs?: number;
+  /** Probes per round to the active profile. */
+  burst?: number;
+}
+
+export const DEFAULT_INTERVAL_MS = 15_000;
+export const PROBE_TIMEOUT_MS = 5_000;
+export const DEGRADED_LATENCY_MS = 500;
+export const DEGRADED_LOSS = 0.2;
+export const SWITCH_AFTER_BAD_ROUNDS = 2;
+const WINDOW_SIZE = 20;
+const DEFAULT_BURST = 3;
+
+function median(values: number[]): number {
+  const sorted = [...values].sort((a, b) => a - b);
+  const mid = Math.floor(sorted.length / 2);
+  return sorted.length % 2 ? sorted[mid] : (sorted[mid - 1] + sorted[mid]) / 2;
+}
+
+/** Classify a window of samples (`null` = lost) given the latest round's. */
+export function classify(
+  profile: ConnectionProfile,
+  window: (number | null)[],
+  latestRound: (number | null)[],
+): ProfileQuality {
+  const answered = window.filter((s): s is number => s !== null);
+  const loss = window.length ? (window.length - answered.length) / window.length : 1;
+  const latencyMs = answered.length ? Math.round(median(answered)) : null;
+  let quality: LinkQuality;
+  if (latestRound.every((s) => s === null)) quality = 'offline';
+  else if (loss >= DEGRADED_LOSS || (latencyMs ?? 0) >= DEGRADED_LATENCY_MS) quality = 'degraded';
+  else quality = 'good';
+  return { profile, quality, latencyMs, loss: Math.round(loss * 100) / 100 };
+}
+
+export class ConnectionQualityMonitor {
+  private readonly windows = new Map<ConnectionProfile, (number | null)[]>();
+  private timer: NodeJS.Timeout | null = null;
+  private running: Promise<ConnectionQualityReport> | null = null;
+  private badRounds = 0;
+  private lastActive: ConnectionProfile | null = null;
+  private last: ConnectionQualityReport | null = null;
+
+  constructor(private readonly deps: ConnectionQualityDeps) {}
+
+  start(): void {
+    if (this.timer) return;
+    void this.checkNow();
+    const intervalMs = this.deps.intervalMs ?? DEFAULT_INTERVAL_MS;
+    this.timer = setInterval(() => void this.checkNow(), intervalMs);
+  }
+
+  stop(): void {
+    if (this.timer) clearInterval(this.timer);
+    this.timer = null;
+  }
+
+  latest(): ConnectionQualityReport | null {
+    return this.last;
+  }
+
+  /** Run a round now; concurrent callers share the round in flight. */
+  checkNow(): Promise<ConnectionQualityReport> {
+    this.running ??= this.round().finally(() => {
+      this.running = null;
+    });
+    return this.running;
+  }
+
+  private async sample(profile: ConnectionProfile, count: number): Promise<(number | null)[]> {
+    const round: (number | null)[] = [];
+    for (let i = 0; i < count; i++) {
+      round.push(await this.deps.probe(profile).catch(() => null));
+    }
+    const window = [...(this.windows.get(profile) ?? []), ...round].slice(-WINDOW_SIZE);
+    this.windows.set(profile, window);
+    return round;
+  }
+
+  private async round(): Promise<ConnectionQualityReport> {
+    const { active, alternative } = this.deps.targets();
+    if (active !== this.lastActive) {
+      // A new profile starts with a clean slate: no inherited failover streak.
+      this.badRounds = 0;
+      this.windows.delete(active);
+      this.lastActive = active;
+    }
+    const [activeRound, alternativeRound] = await Promise.all([
+      this.sample(active, this.deps.burst ?? DEFAULT_BURST),
+      alternative ? this.sample(alternative, 1) : Promise.resolve(null),
+    ]);
+    const current = classify(active, this.windows.get(active) ?? [], activeRound);
+    const other =
+      alternative && alternativeRound
+        ? classify(alternative, this.windows.get(alternative) ?? [], alternativeRound)
+        : null;
+
+    this.badRounds = current.quality === 'good' ? 0 : this.badRounds + 1;
+    const suggestSwitchTo =
+      other?.quality === 'good' && this.badRounds >= SWITCH_AFTER_BAD_ROUNDS
+        ? other.profile
+        : null;
+
+    const report = { ...current, checkedAt: Date.now(), alternative: other, suggestSwitchTo };
+    this.last = report;
+    this.deps.emit(report);
+    return report;
+  }
+}
+
+/** The profile in use and the other remote path, if its host is filled in. */
+export function connectionTargets(store: AnyStore): ConnectionQualityTargets {
+  if (!((store.get('connection.useRemote') as boolean) ?? false)) {
+    return { active: 'local', alternative: null };
+  }
+  const active: ConnectionProfile =
+    store.get('connection.remoteProfile') === 'lan' ? 'lan' : 'tailscale';
+  const alternative: ConnectionProfile = active === 'lan' ? 'tailscale' : 'lan';
+  return { active, alternative: profileHost(store, alternative) ? alternative : null };
+}
+
+function profileHost(store: AnyStore, profile: ConnectionProfile): string {
+  if (profile === 'local') {
+    return ((store.get('connection.localHost') as string) ?? 'localhost').trim();
+  }
+  const key = profile === 'lan' ? 'connection.lanHost' : 'connection.remoteHost';
+  return ((store.get(key) as string) ?? '').trim();
+}
+
+/** Base URL for `profile`; remote profiles always use HTTPS. */
+export function profileServerUrl(store: AnyStore, profile: ConnectionProfile): string | null {
+  const host = profileHost(store, profile);
+  if (!host) return null;
+  const port = (store.get('connection.port') as number) ?? 9786;
+  const useHttps = profile !== 'local' || ((store.get('connection.useHttps') as boolean) ?? false);
+  return `${useHttps ? 'https' : 'http'}://${host}:${port}`;
+}
+
+/** Time one `GET <baseUrl>/health`; null on any failure or after `timeoutMs`. */
+export function probeLatency(
+  baseUrl: string,
+  agent: http.Agent,
+  timeoutMs = PROBE_TIMEOUT_MS,
+): Promise<number | null> {
+  return new Promise((resolve) => {
+    const url = new URL('/health', baseUrl);
+    const mod = url.protocol === 'https:' ? https : http;
+    const started = performance.now();
+    const req = mod.get(url, { agent, timeout: timeoutMs }, (res) => {
+      res.resume();
+      res.on('error', () => resolve(null));
+      res.on('end', () => {
+        const ok = (res.statusCode ?? 0) >= 200 && (res.statusCode ?? 0) < 500;
+        resolve(ok ? performance.now() - started : null);
+      });
+    });
+    req.on('timeout', () => req.destroy());
+    req.on('error', () => resolve(null));
+  });
+}
@@ -0,0 +1,121 @@
+/**
+ * Per-profile HTTP tuning for connections to the server.
+ *
+ * Each connection profile — the local server, Tailscale and LAN — keeps its
+ * own connect/read timeouts, keep-alive and HTTP/2 settings under
+ * `connection.tuning.<profile>`, because a remote server over a slow link
+ * needs far more patience than localhost. The main process applies them to
+ * its own requests (the connection probe) through a Node agent and to
+ * Chromium's network stack via `--disable-http2`, which only takes effect at
+ * startup. The renderer applies the timeouts in `src/api/client.ts`.
+ *
+ * Main-process mirror of `src/config/store.ts::normalizeConnectionTuning`.
+ */
+
+import http from 'http';
+import https from 'https';
+import type Store from 'electron-store';
+
+type AnyStore = Store<any>;
+
+export type ConnectionProfile = 'local' | 'tailscale' | 'lan';
+
+export interface ConnectionTuning {
+  connectTimeoutSeconds: number;
+  readTimeoutSeconds: number;
+  keepAlive: boolean;
+  http2: boolean;
+}
+
+export const DEFAULT_CONNECTION_TUNING: Record<ConnectionProfile, ConnectionTuning> = {
+  local: { connectTimeoutSeconds: 5, readTimeoutSeconds: 600, keepAlive: true, http2: true },
+  tailscale: { connectTimeoutSeconds: 20, readTimeoutSeconds: 900, keepAlive: true, http2: true },
+  lan: { connectTimeoutSeconds: 10, readTimeoutSeconds: 600, keepAlive: true, http2: true },
+};
+
+export const CONNECTION_TUNING_LIMITS = {
+  connectTimeoutSeconds: { min: 1, max: 120 },
+  readTimeoutSeconds: { min: 5, max: 3600 },
+} as const;
+
+/** Missing or out-of-range fields fall back to the profile's defaults. */
+export function normalizeConnectionTuning(
+  raw: unknown,
+  profile: ConnectionProfile,
+): ConnectionTuning {
+  const defaults = DEFAULT_CONNECTION_TUNING[profile];
+  const stored = (raw && typeof raw === 'object' ? raw : {}) as Partial<ConnectionTuning>;
+  const seconds = (key: keyof typeof CONNECTION_TUNING_LIMITS): number => {
+    const { min, max } = CONNECTION_TUNING_LIMITS[key];
+    const value = stored[key];
+    return typeof value === 'number' && Number.isFinite(value) && value >= min && value <= max
+      ? value
+      : defaults[key];
+  };
+  const connectTimeoutSeconds = seconds('connectTimeoutSeconds');
+  return {
+    connectTimeoutSeconds,
+    readTimeoutSeconds: Math.max(seconds('readTimeoutSeconds'), connectTimeoutSeconds),
+    keepAlive: typeof stored.keepAlive === 'boolean' ? stored.keepAlive : defaults.keepAlive,
+    http2: typeof stored.http2 === 'boolean' ? stored.http2 : defaults.http2,
+  };
+}
+
+export function activeConnectionProfile(store: AnyStore): ConnectionProfile {
+  const useRemote = (store.get('connection.useRemote') as boolean) ?? false;
+  if (!useRemote) return 'local';
+  return store.get('connection.remoteProfile') === 'lan' ? 'lan' : 'tailscale';
+}
+
+/** Tuning for the profile the app currently connects with. */
+export function getConnectionTuning(store: AnyStore): ConnectionTuning {
+  const profile = activeConnectionProfile(store);
+  return normalizeConnectionTuning(store.get(`connection.tuning.${profile}`), profile);
+}
+
+/** Agent options carrying the keep-alive setting; the read timeout is per request. */
+export function agentOptions(tuning: ConnectionTuning): http.AgentOptions {
+  return { keepAlive: tuning.keepAlive, keepAliveMsecs: 1000 };
+}
+
+// One pooled agent per scheme, rebuilt when keep-alive is toggled so idle
+// sockets opened under the old setting are not reused.
+const sharedAgents = new Map<string, http.Agent>();
+
+export function sharedAgent(isHttps: boolean, tuning: ConnectionTuning): http.Agent {
+  const key = `${isHttps ? 'https' : 'http'}:${tuning.keepAlive}`;
+  let agent = sharedAgents.get(key);
+  if (!agent) {
+    for (const [staleKey, stale] of sharedAgents) {
+      if (staleKey.startsWith(isHttps ? 'https:' : 'http:')) {
+        stale.destroy();
+        sharedAgents.delete(staleKey);
+      }
+    }
+    agent = isHttps ? new https.Agent(agentOptions(tuning)) : new http.Agent(agentOptions(tuning));
+    sharedAgents.set(key, agent);
+  }
+  return agent;
+}
+
+/**
+ * Fail `req` with ETIMEDOUT unless its socket connects within the connect
+ * timeout. A reused keep-alive socket is already connected and never trips it.
+ */
+export function armConnectTimeout(req: http.ClientRequest, tuning: ConnectionTuning): void {
+  const timer = setTimeout(() => {
+    const err: NodeJS.ErrnoException = new Error(
+      `Connection timed out after ${tuning.connectTimeoutSeconds}s`,
+    );
+    err.code = 'ETIMEDOUT';
+    req.destroy(err);
+  }, tuning.connectTimeoutSeconds * 1000);
+  const clear = () => clearTimeout(timer);
+  req.once('socket', (socket) => {
+    if (socket.connecting) socket.once('connect', clear);
+    else clear();
+  });
+  req.once('response', clear);
+  req.once('error', clear);
+  req.once('close', clear);
+}
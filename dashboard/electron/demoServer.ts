@@ -0,0 +1,641 @@
+/**
+ * Demo mode — a mock TranscriptionSuite server inside the Electron main
+ * process, so the whole UI runs without Docker, a GPU or Python.
+ *
+ * Enabled by `--demo` or `TRANSCRIPTIONSUITE_DEMO=1`. main.ts then moves all
+ * app data into a throwaway profile (the user's real settings and history
+ * are never touched) and points the local connection here. The server
+ * answers the read paths the dashboard polls — health, status, admin
+ * status, permissions, languages, the queue, the notebook library (with
+ * SRT/text export and delete), calendar and search — from a small sample
+ * library, and fakes transcription: an upload or import becomes a job whose
+ * progress advances over a few seconds in `/api/admin/status` and then lands
+ * as a new recording with the sample transcript. Recording audio is a generated tone, so the player works too.
+ *
+ * Anything else answers 404 "Not available in demo mode". State lives in
+ * memory and is gone on quit.
+ */
+
+import http from 'node:http';
+import type { AddressInfo } from 'node:net';
+
+export const DEMO_FLAG = '--demo';
+export const DEMO_ENV = 'TRANSCRIPTIONSUITE_DEMO';
+export const DEMO_SERVER_PORT = 9796;
+export const DEMO_VERSION = 'demo';
+
+/** Seconds a fake transcription job takes, model "loading" included. */
+export const DEMO_JOB_SECONDS = 12;
+const DEMO_LOAD_SECONDS = 2;
+const DEMO_USER = 'demo';
+const NOT_AVAILABLE = 'Not available in demo mode';
+
+export function isDemoMode(argv: string[], env: NodeJS.ProcessEnv): boolean {
+  return (
+    argv.includes(DEMO_FLAG) ||
+    ['1', 'true', 'yes'].includes((env[DEMO_ENV] ?? '').toLowerCase())
+  );
+}
+
+// ─── Sample library ─────────────────────────────────────────────────────────
+
+interface Word {
+  word: string;
+  start: number;
+  end: number;
+  confidence: number;
+  speaker?: string;
+}
+
+interface Segment {
+  id: number;
+  text: string;
+  start: number;
+  end: number;
+  speaker?: string;
+  words: Word[];
+}
+
+interface DemoRecording {
+  id: number;
+  filename: string;
+  filepath: string;
+  title: string | null;
+  duration_seconds: number;
+  recorded_at: string;
+  imported_at: string | null;
+  word_count: number;
+  has_diarization: boolean;
+  summary: string | null;
+  summary_model: string | null;
+  transcript_corrected: string | null;
+  transcription_backend: 'whisper';
+  keep_audio: boolean;
+  audio_retired_at: string | null;
+  owner: string | null;
+  segments: Segment[];
+}
+
+type Script = [speaker: string | undefined, text: string][];
+
+interface Sample {
+  title: string;
+  daysAgo: number;
+  hour: number;
+  summary: string | null;
+  script: Script;
+}
+
+const SAMPLES: Sample[] = [
+  {
+    title: 'Weekly planning',
+    daysAgo: 1,
+    hour: 10,
+    summary:
+      'The team agreed to ship the search rework first and move the export fixes to next week.',
+    script: [
+      ['SPEAKER_00', 'Good morning everyone, let us go through the plan for this week.'],
+      ['SPEAKER_01', 'The search rework is nearly done, I only need to finish the word index.'],
+      ['SPEAKER_00', 'Great. Can we ship that first and move the export fixes to next week?'],
+      ['SPEAKER_01', 'That works for me. I will write the release notes tomorrow.'],
+      ['SPEAKER_00', 'Perfect, then we are done. Thanks everyone.'],
+    ],
+  },
+  {
+    title: 'Interview notes',
+    daysAgo: 3,
+    hour: 15,
+    summary: null,
+    script: [
+      ['SPEAKER_00', 'Thanks for taking the time to talk to us today.'],
+      ['SPEAKER_01', 'Happy to. I have been using the app for about six months now.'],
+      ['SPEAKER_00', 'What do you use it for most of the time?'],
+      ['SPEAKER_01', 'Mostly lectures. I record them and search the transcripts before exams.'],
+    ],
+  },
+  {
+    title: 'Voice memo',
+    daysAgo: 8,
+    hour: 19,
+    summary: null,
+    script: [
+      [undefined, 'Remember to book the train tickets and send the slides to Anna.'],
+      [undefined, 'Also check whether the new microphone arrived at the office.'],
+    ],
+  },
+];
+
+/** A local "YYYY-MM-DDTHH:MM:SS" timestamp, like the server stores. */
+function localIso(date: Date): string {
+  const pad = (n: number) => String(n).padStart(2, '0');
+  return (
+    `${date.getFullYear()}-${pad(date.getMonth() + 1)}-${pad(date.getDate())}` +
+    `T${pad(date.getHours())}:${pad(date.getMinutes())}:${pad(date.getSeconds())}`
+  );
+}
+
+/** Turn a script into timed segments, spreading each line's words evenly. */
+function buildSegments(script: Script, firstId: number): Segment[] {
+  const segments: Segment[] = [];
+  let clock = 0.5;
+  script.forEach(([speaker, text], index) => {
+    const tokens = text.split(' ');
+    const start = clock;
+    const words = tokens.map((token, i) => ({
+      word: i === 0 ? token : ` ${token}`,
+      start: Math.round((start + i * 0.4) * 100) / 100,
+      end: Math.round((start + i * 0.4 + 0.35) * 100) / 100,
+      confidence: 0.97,
+      ...(speaker ? { speaker } : {}),
+    }));
+    const end = words[words.length - 1].end;
+    const id = firstId + index;
+    segments.push({ id, text, start, end, words, ...(speaker ? { speaker } : {}) });
+    clock = end + 0.8;
+  });
+  return segments;
+}
+
+function makeRecording(
+  id: number,
+  title: string,
+  script: Script,
+  recordedAt: Date,
+  extra: Partial<DemoRecording> = {},
+): DemoRecording {
+  const segments = buildSegments(script, id * 1000);
+  const filename = `${title.toLowerCase().replace(/[^a-z0-9]+/g, '-')}.wav`;
+  return {
+    id,
+    filename,
+    filepath: `/demo/${filename}`,
+    title,
+    duration_seconds: Math.ceil(segments[segments.length - 1].end + 1),
+    recorded_at: localIso(recordedAt),
+    imported_at: null,
+    word_count: segments.reduce((n, s) => n + s.words.length, 0),
+    has_diarization: segments.some((s) => s.speaker !== undefined),
+    summary: null,
+    summary_model: null,
+    transcript_corrected: null,
+    transcription_backend: 'whisper',
+    keep_audio: false,
+    audio_retired_at: null,
+    owner: null,
+    segments,
+    ...extra,
+  };
+}
+
+const listItem = ({ segments: _segments, ...recording }: DemoRecording) => recording;
+
+// ─── Jobs ───────────────────────────────────────────────────────────────────
+
+interface DemoJob {
+  id: string;
+  kind: 'notebook' | 'import';
+  title: string;
+  startedAt: number;
+}
+
+export interface DemoState {
+  now: () => number;
+  recordings: DemoRecording[];
+  nextId: number;
+  job: DemoJob | null;
+  result: Record<string, unknown> | null;
+}
+
+export function createDemoState(now: () => number = Date.now): DemoState {
+  const today = new Date(now());
+  const recordings = SAMPLES.map((sample, index) => {
+    const at = new Date(today);
+    at.setDate(at.getDate() - sample.daysAgo);
+    at.setHours(sample.hour, 0, 0, 0);
+    return makeRecording(index + 1, sample.title, sample.script, at, {
+      summary: sample.summary,
+      summary_model: sample.summary ? 'demo' : null,
+    });
+  });
+  return { now, recordings, nextId: recordings.length + 1, job: null, result: null };
+}
+
+/** The transcript every fake job produces. */
+const JOB_SCRIPT: Script = [
+  [undefined, 'This is a demo transcription. No audio was sent anywhere.'],
+  [undefined, 'In demo mode every file turns into this same short sample transcript.'],
+];
+
+/** Finish the running job once its time is up. */
+function settleJob(state: DemoState): void {
+  const job = state.job;
+  if (!job || (state.now() - job.startedAt) / 1000 < DEMO_JOB_SECONDS) return;
+  state.job = null;
+  const recording = makeRecording(state.nextId++, job.title, JOB_SCRIPT, new Date(state.now()), {
+    imported_at: localIso(new Date(state.now())),
+  });
+  const diarization = { requested: false, performed: false, reason: null };
+  if (job.kind === 'notebook') {
+    state.recordings.push(recording);
+    state.result = {
+      job_id: job.id,
+      recording_id: recording.id,
+      message: 'Transcription complete',
+      diarization,
+    };
+  } else {
+    state.result = {
+      job_id: job.id,
+      transcription: {
+        text: recording.segments.map((s) => s.text).join(' '),
+        segments: recording.segments.map(({ id: _id, ...segment }) => segment),
+      },
+      diarization,
+    };
+  }
+}
+
+function jobTracker(state: DemoState) {
+  settleJob(state);
+  const job = state.job;
+  if (!job) {
+    return {
+      is_busy: false,
+      active_user: null,
+      active_job_id: null,
+      cancellation_requested: false,
+      progress: null,
+      started_at: null,
+      result: state.result,
+      queue_length: 0,
+    };
+  }
+  const elapsed = (state.now() - job.startedAt) / 1000;
+  const total = DEMO_JOB_SECONDS - DEMO_LOAD_SECONDS;
+  const loading = elapsed < DEMO_LOAD_SECONDS;
+  return {
+    is_busy: true,
+    active_user: DEMO_USER,
+    active_job_id: job.id,
+    cancellation_requested: false,
+    progress: loading
+      ? { current: 0, total, message: 'Loading model...', phase: 'loading_model' }
+      : {
+          current: Math.min(total, Math.round(elapsed - DEMO_LOAD_SECONDS)),
+          total,
+          message: 'Transcribing...',
+          phase: 'transcribing',
+        },
+    started_at: job.startedAt / 1000,
+    result: state.result,
+    queue_length: 0,
+  };
+}
+
+function startJob(state: DemoState, kind: DemoJob['kind'], filename: string | null): Reply {
+  settleJob(state);
+  if (state.job) return json(409, { detail: 'A transcription is already running' });
+  const id = Math.floor(state.now() * 1000 + Math.random() * 1000)
+    .toString(16)
+    .slice(-8);
+  const title = (filename ?? 'Demo upload').replace(/\.[^.]+$/, '');
+  state.job = { id, kind, title, startedAt: state.now() };
+  return json(202, { job_id: id, dedup_matches: [] });
+}
+
+// ─── Requests ───────────────────────────────────────────────────────────────
+
+export interface Reply {
+  status: number;
+  contentType: string;
+  body: string | Buffer;
+}
+
+function json(status: number, body: unknown): Reply {
+  return { status, contentType: 'application/json', body: JSON.stringify(body) };
+}
+
+const notFound = () => json(404, { detail: NOT_AVAILABLE });
+
+/** A mono 16 kHz WAV of `seconds` of quiet tone, for the transcript player. */
+export function toneWav(seconds: number): Buffer {
+  const rate = 16_000;
+  const samples = Math.max(1, Math.round(seconds * rate));
+  const wav = Buffer.alloc(44 + samples * 2);
+  wav.write('RIFF', 0);
+  wav.writeUInt32LE(36 + samples * 2, 4);
+  wav.write('WAVEfmt ', 8);
+  wav.writeUInt32LE(16, 16);
+  wav.writeUInt16LE(1, 20); // PCM
+  wav.writeUInt16LE(1, 22); // mono
+  wav.writeUInt32LE(rate, 24);
+  wav.writeUInt32LE(rate * 2, 28);
+  wav.writeUInt16LE(2, 32);
+  wav.writeUInt16LE(16, 34);
+  wav.write('data', 36);
+  wav.writeUInt32LE(samples * 2, 40);
+  for (let i = 0; i < samples; i++) {
+    wav.writeInt16LE(Math.round(Math.sin((2 * Math.PI * 220 * i) / rate) * 1500), 44 + i * 2);
+  }
+  return wav;
+}
+
+function srtTime(seconds: number): string {
+  const ms = Math.round(seconds * 1000);
+  const pad = (n: number, width = 2) => String(n).padStart(width, '0');
+  const h = Math.floor(ms / 3_600_000);
+  const m = Math.floor(ms / 60_000) % 60;
+  const s = Math.floor(ms / 1000) % 60;
+  return `${pad(h)}:${pad(m)}:${pad(s)},${pad(ms % 1000, 3)}`;
+}
+
+function toSrt(segments: Segment[]): string {
+  return segments
+    .map((s, i) => `${i + 1}\n${srtTime(s.start)} --> ${srtTime(s.end)}\n${s.text}\n`)
+    .join('\n');
+}
+
+function toText(segments: Segment[]): string {
+  return segments.map((s) => (s.speaker ? `${s.speaker}: ${s.text}` : s.text)).join('\n');
+}
+
+/** The uploaded file's name from a multipart body, if there is one. */
+function uploadFilename(body: Buffer): string | null {
+  const match = /filename="([^"]*)"/.exec(body.subarray(0, 4096).toString('latin1'));
+  return match?.[1] || null;
+}
+
+function modelStatus(state: DemoState) {
+  return {
+    gpu_available: false,
+    gpu_memory: null,
+    transcription: { selected_model: 'demo/whisper-sample', disabled: false, loaded: true },
+    job_tracker: jobTracker(state),
+    features: {
+      diarization: { available: true, reason: 'demo' },
+      whisper: { available: true, reason: 'demo' },
+    },
+  };
+}
+
+function search(state: DemoState, query: string) {
+  const needle = query.trim().toLowerCase();
+  const results = needle
+    ? state.recordings.flatMap((recording) =>
+        recording.segments.flatMap((segment) =>
+          segment.words
+            .filter((word) => word.word.trim().toLowerCase().includes(needle))
+            .map((word) => ({
+              recording_id: recording.id,
+              segment_id: segment.id,
+              word: word.word.trim(),
+              start_time: word.start,
+              end_time: word.end,
+              filename: recording.filename,
+              title: recording.title,
+              recorded_at: recording.recorded_at,
+              speaker: segment.speaker ?? null,
+              context: segment.text,
+              match_type: 'word' as const,
+            })),
+        ),
+      )
+    : [];
+  return { query, results, count: results.length };
+}
+
+function inRange(recording: DemoRecording, start: string | null, end: string | null): boolean {
+  const day = recording.recorded_at.slice(0, 10);
+  return (!start || day >= start) && (!end || day < end);
+}
+
+/** Answer one request against `state`. `body` is only read for uploads. */
+export function handleDemoRequest(
+  state: DemoState,
+  method: string,
+  rawUrl: string,
+  body: Buffer = Buffer.alloc(0),
+): Reply {
+  const url = new URL(rawUrl, 'http://demo.invalid');
+  const path = url.pathname.replace(/\/+$/, '') || '/';
+  const query = url.searchParams;
+  const recording = /^\/api\/notebook\/recordings\/(\d+)(\/transcription|\/audio|\/export)?$/.exec(
+    path,
+  );
+
+  if (method === 'GET') {
+    if (path === '/health') return json(200, { status: 'healthy', service: 'transcriptionsuite' });
+    if (path === '/ready') return json(200, { status: 'ready', models: modelStatus(state) });
+    if (path === '/api/status') {
+      const models = modelStatus(state);
+      return json(200, {
+        status: 'running',
+        version: DEMO_VERSION,
+        models,
+        features: models.features,
+        ready: true,
+        gpu_available: false,
+        library_read_only: false,
+      });
+    }
+    if (path === '/api/admin/status') {
+      const model = { model: 'demo/whisper-sample', device: 'cpu' };
+      return json(200, {
+        status: 'running',
+        version: DEMO_VERSION,
+        models: modelStatus(state),
+        config: {
+          server: { host: 'localhost', port: DEMO_SERVER_PORT },
+          main_transcriber: model,
+          live_transcriber: model,
+          diarization: { parallel: false },
+          transcription: model,
+          live_transcription: model,
+        },
+      });
+    }
+    if (path === '/api/auth/permissions') {
+      return json(200, {
+        user: DEMO_USER,
+        is_admin: true,
+        permissions: {
+          can_manage_models: true,
+          can_manage_tokens: true,
+          can_change_server_settings: true,
+          can_manage_library: true,
+          can_view_all_jobs: true,
+          can_view_audit_log: true,
+        },
+      });
+    }
+    if (path === '/api/transcribe/languages') {
+      const languages = { en: 'English', de: 'German', fr: 'French', es: 'Spanish' };
+      return json(200, {
+        languages,
+        count: Object.keys(languages).length,
+        auto_detect: true,
+        backend_type: 'whisper',
+        supports_translation: true,
+      });
+    }
+    if (path === '/api/transcribe/queue') {
+      const tracker = jobTracker(state);
+      return json(200, {
+        now: state.now() / 1000,
+        active: tracker.is_busy
+          ? {
+              user: DEMO_USER,
+              mine: true,
+              started_at: tracker.started_at,
+              progress: tracker.progress,
+              estimated_end_at: (tracker.started_at ?? 0) + DEMO_JOB_SECONDS,
+            }
+          : null,
+        entries: [],
+      });
+    }
+    if (path === '/api/notebook/recordings') {
+      const start = query.get('start_date');
+      const end = query.get('end_date');
+      return json(200, state.recordings.filter((r) => inRange(r, start, end)).map(listItem));
+    }
+    if (recording) {
+      const found = state.recordings.find((r) => r.id === Number(recording[1]));
+      if (!found) return json(404, { detail: 'Recording not found' });
+      if (recording[2] === '/audio') {
+        return { status: 200, contentType: 'audio/wav', body: toneWav(found.duration_seconds) };
+      }
+      if (recording[2] === '/export') {
+        return {
+          status: 200,
+          contentType: 'text/plain; charset=utf-8',
+          body: query.get('format') === 'srt' ? toSrt(found.segments) : toText(found.segments),
+        };
+      }
+      if (recording[2] === '/transcription') {
+        return json(200, { recording_id: found.id, segments: found.segments });
+      }
+      return json(200, {
+        ...found,
+        words: found.segments.flatMap((s) => s.words),
+        webhook_status: null,
+        webhook_error: null,
+      });
+    }
+    if (path === '/api/notebook/calendar') {
+      const year = Number(query.get('year'));
+      const month = Number(query.get('month'));
+      const prefix = `${year}-${String(month).padStart(2, '0')}`;
+      const days: Record<string, ReturnType<typeof listItem>[]> = {};
+      let total = 0;
+      for (const r of state.recordings) {
+        if (!r.recorded_at.startsWith(prefix)) continue;
+        (days[r.recorded_at.slice(0, 10)] ??= []).push(listItem(r));
+        total += 1;
+      }
+      return json(200, { year, month, days, total_recordings: total });
+    }
+    if (path === '/api/notebook/timeslot') {
+      const hour = `${query.get('date')}T${String(query.get('hour')).padStart(2, '0')}`;
+      const inSlot = state.recordings.filter((r) => r.recorded_at.startsWith(hour));
+      const used = inSlot.reduce((n, r) => n + r.duration_seconds, 0);
+      return json(200, {
+        recordings: inSlot.map(listItem),
+        next_available: null,
+        total_duration: used,
+        available_seconds: Math.max(0, 3600 - used),
+        is_full: used >= 3600,
+      });
+    }
+    if (path === '/api/search' || path === '/api/search/words') {
+      const result = search(state, query.get('q') ?? '');
+      return json(200, path === '/api/search' ? { ...result, fuzzy: false } : result);
+    }
+    if (path === '/api/llm/status') {
+      return json(200, {
+        available: false,
+        base_url: '',
+        model: null,
+        model_state: null,
+        error: NOT_AVAILABLE,
+        has_api_key: false,
+      });
+    }
+    if (path === '/api/profiles') return json(200, []);
+  }
+
+  if (method === 'DELETE' && recording && !recording[2]) {
+    const index = state.recordings.findIndex((r) => r.id === Number(recording[1]));
+    if (index < 0) return json(404, { detail: 'Recording not found' });
+    state.recordings.splice(index, 1);
+    return json(200, { status: 'deleted', id: recording[1], artifact_failures: [] });
+  }
+
+  if (method === 'POST') {
+    if (path === '/api/auth/login') {
+      return json(200, {
+        success: true,
+        user: { name: DEMO_USER, is_admin: true, token_id: 'demo' },
+      });
+    }
+    if (path === '/api/notebook/transcribe/upload') {
+      return startJob(state, 'notebook', uploadFilename(body));
+    }
+    if (path === '/api/transcribe/import') return startJob(state, 'import', uploadFilename(body));
+    if (path === '/api/transcribe/cancel') {
+      const cancelled = state.job !== null;
+      state.job = null;
+      return json(200, {
+        success: cancelled,
+        message: cancelled ? 'Transcription cancelled' : 'No transcription running',
+      });
+    }
+  }
+
+  return notFound();
+}
+
+// ─── HTTP server ────────────────────────────────────────────────────────────
+
+export interface DemoServer {
+  port: number;
+  close(): Promise<void>;
+}
+
+/**
+ * Serve the demo API on localhost; `port` 0 picks a free one. Not 127.0.0.1:
+ * the renderer's CSP only allows `http://localhost:*`.
+ */
+export function startDemoServer(
+  port: number = DEMO_SERVER_PORT,
+  state: DemoState = createDemoState(),
+): Promise<DemoServer> {
+  const server = http.createServer((req, res) => {
+    const chunks: Buffer[] = [];
+    req.on('data', (chunk: Buffer) => chunks.push(chunk));
+    req.on('end', () => {
+      const method = req.method ?? 'GET';
+      const reply =
+        method === 'OPTIONS'
+          ? { status: 204, contentType: 'text/plain', body: '' }
+          : handleDemoRequest(state, method, req.url ?? '/', Buffer.concat(chunks));
+      res.writeHead(reply.status, {
+        'Content-Type': reply.contentType,
+        // The renderer calls from its own origin (Vite dev server or file://).
+        'Access-Control-Allow-Origin': '*',
+        'Access-Control-Allow-Headers': '*',
+        'Access-Control-Allow-Methods': 'GET, POST, PUT, PATCH, DELETE, OPTIONS',
+      });
+      res.end(reply.body);
+    });
+  });
+  return new Promise((resolve, reject) => {
+    server.once('error', reject);
+    server.listen(port, 'localhost', () => {
+      resolve({
+        port: (server.address() as AddressInfo).port,
+        close: () => new Promise<void>((done) => server.close(() => done())),
+      });
+    });
+  });
+}
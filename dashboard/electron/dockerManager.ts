@@ -40,6 +40,18 @@ import {
 } from './containerRuntime.js';
 import { type WslSupport, resetWslSupportCache } from './wslDetect.js';
 import { hfCacheDirName } from './hfRepoAliases.js';
+import { getAppPaths } from './appPaths.js';
+import {
+  type WhispercppBenchmark,
+  type WhispercppTuning,
+  WHISPERCPP_TUNING_STORE_KEY,
+  benchmarkCandidates,
+  benchmarkWav,
+  detectCpuTopology,
+  sidecarArgs,
+  sidecarComposeEnv,
+  tuningForMachine,
+} from './whispercppTuning.js';
 
 const execFileAsync = promisify(execFile);
 
@@ -55,6 +67,8 @@ export const IMAGE_REPO = 'ghcr.io/homelab-00/transcriptionsuite-server';
 export const LEGACY_IMAGE_REPO = 'ghcr.io/homelab-00/transcriptionsuite-server-legacy';
 export const VULKAN_WSL2_IMAGE_REPO = 'ghcr.io/homelab-00/transcriptionsuite-server-vulkan-wsl2';
 export const VULKAN_LINUX_IMAGE_REPO = 'ghcr.io/homelab-00/transcriptionsuite-server-vulkan-linux';
+export const ROCM_IMAGE_REPO = 'ghcr.io/homelab-00/transcriptionsuite-server-rocm';
+export const XPU_IMAGE_REPO = 'ghcr.io/homelab-00/transcriptionsuite-server-xpu';
 
 /**
  * Server image variants selectable from the Docker Image card. The active
@@ -64,21 +78,23 @@ export const VULKAN_LINUX_IMAGE_REPO = 'ghcr.io/homelab-00/transcriptionsuite-se
  * goes through resolveImageRepo() below, which routes the vulkan runtime
  * profiles to their dedicated repos.
  */
-export type ImageVariant = 'cuda' | 'cuda-legacy' | 'vulkan-wsl2' | 'vulkan-linux';
+export type ImageVariant = 'cuda' | 'cuda-legacy' | 'vulkan-wsl2' | 'vulkan-linux' | 'rocm' | 'xpu';
 
 export const IMAGE_VARIANT_REPOS: Record<ImageVariant, string> = {
   cuda: IMAGE_REPO,
   'cuda-legacy': LEGACY_IMAGE_REPO,
   'vulkan-wsl2': VULKAN_WSL2_IMAGE_REPO,
   'vulkan-linux': VULKAN_LINUX_IMAGE_REPO,
+  rocm: ROCM_IMAGE_REPO,
+  xpu: XPU_IMAGE_REPO,
 };
 
 /**
  * Select the GHCR image repo for this session based on the persisted
  * `server.useLegacyGpu` setting (Issue #83 — Pascal/Maxwell support) and
- * the active runtime profile. Vulkan-WSL2 and Linux Vulkan each get their
- * own dedicated repo so their tag lists never mix with the standard or
- * legacy-GPU variants. The dashboard uses exactly one repo at a time — never
+ * the active runtime profile. Vulkan-WSL2, Linux Vulkan, ROCm and oneAPI XPU
+ * each get their own dedicated repo so their tag lists never mix with the
+ * standard or legacy-GPU variants. The dashboard uses exactly one repo at a time — never
  * mixes repos within a single session.
  */
 export function resolveImageRepo(
@@ -87,6 +103,8 @@ export function resolveImageRepo(
 ): string {
   if (runtimeProfile === 'vulkan-wsl2') return VULKAN_WSL2_IMAGE_REPO;
   if (runtimeProfile === 'vulkan') return VULKAN_LINUX_IMAGE_REPO;
+  if (runtimeProfile === 'rocm') return ROCM_IMAGE_REPO;
+  if (runtimeProfile === 'xpu') return XPU_IMAGE_REPO;
   return useLegacyGpu ? LEGACY_IMAGE_REPO : IMAGE_REPO;
 }
 
@@ -136,6 +154,43 @@ export function buildGhcrUrlsForRepo(imageRepo: string): GhcrUrls {
 
 export const CONTAINER_NAME = 'transcriptionsuite-container';
 
+/** Upper bound for `server.replicas` — one server per GPU. */
+export const MAX_REPLICAS = 8;
+
+/** An extra server container on a multi-GPU host (the main one is replica 0). */
+export interface ReplicaSpec {
+  index: number;
+  containerName: string;
+  /** Compose project, so `up` does not recreate the main container. */
+  project: string;
+  port: number;
+  /** NVIDIA device index the replica is pinned to. */
+  gpu: string;
+}
+
+/** The extra replicas for `count` servers, on the ports after `basePort`. */
+export function replicaSpecs(count: number, basePort: number): ReplicaSpec[] {
+  const total = Math.min(MAX_REPLICAS, Math.max(1, Math.floor(count)));
+  return Array.from({ length: total - 1 }, (_, i) => {
+    const index = i + 1;
+    return {
+      index,
+      containerName: `${CONTAINER_NAME}-${index + 1}`,
+      project: `transcriptionsuite-replica-${index + 1}`,
+      port: basePort + index,
+      gpu: String(index),
+    };
+  });
+}
+
+export function isReplicaContainerName(name: string): boolean {
+  return new RegExp(`^${CONTAINER_NAME}-\\d+$`).test(name);
+}
+
+/** Compose files and env of the last start, reused for its replicas. */
+let _replicaLaunch: { fileArgs: string[]; env: Record<string, string>; count: number } | null =
+  null;
+
 /** Host-side path to the startup events file (set during startContainer). */
 let _startupEventsFilePath: string | null = null;
 
@@ -244,6 +299,101 @@ export function checkVulkanSupport(opts: CheckVulkanSupportOptions): string | nu
   return null;
 }
 
+// ─── AMD ROCm / Intel oneAPI (Linux) ────────────────────────────────────────
+// The `rocm` and `xpu` profiles run the regular PyTorch stack on an AMD or
+// Intel GPU from a dedicated image (PyTorch built against ROCm / oneAPI).
+// Unlike NVIDIA there is no container toolkit: the kernel device nodes are
+// mounted directly (docker-compose.rocm.yml / docker-compose.xpu.yml) and the
+// container user joins the host's `render` group by numeric GID.
+
+/** PCI vendor IDs as read from /sys/class/drm/<card>/device/vendor. */
+const PCI_VENDOR_AMD = '0x1002';
+const PCI_VENDOR_INTEL = '0x8086';
+
+export interface DrmVendors {
+  amd: boolean;
+  intel: boolean;
+}
+
+/** Classify the vendor IDs of the host's DRM cards. */
+export function parseDrmVendors(vendorIds: string[]): DrmVendors {
+  const ids = new Set(vendorIds.map((id) => id.trim().toLowerCase()));
+  return { amd: ids.has(PCI_VENDOR_AMD), intel: ids.has(PCI_VENDOR_INTEL) };
+}
+
+function readDrmVendorIds(): string[] {
+  const root = '/sys/class/drm';
+  try {
+    return fs
+      .readdirSync(root)
+      .filter((name) => /^card\d+$/.test(name))
+      .flatMap((card) => {
+        try {
+          return [fs.readFileSync(path.join(root, card, 'device', 'vendor'), 'utf-8')];
+        } catch {
+          return [];
+        }
+      });
+  } catch {
+    return [];
+  }
+}
+
+/** Numeric GID of `group` from /etc/group content, or null if absent. */
+export function parseGroupGid(etcGroup: string, group: string): string | null {
+  for (const line of etcGroup.split('\n')) {
+    const [name, , gid] = line.split(':');
+    if (name === group && gid && /^\d+$/.test(gid)) return gid;
+  }
+  return null;
+}
+
+function readRenderGroupGid(): string | null {
+  try {
+    return parseGroupGid(fs.readFileSync('/etc/group', 'utf-8'), 'render');
+  } catch {
+    return null;
+  }
+}
+
+export interface CheckGpuDeviceSupportOptions {
+  platform: NodeJS.Platform;
+  exists: (p: string) => boolean;
+  profile: 'rocm' | 'xpu';
+}
+
+/**
+ * Pre-flight check for the `rocm` and `xpu` runtime profiles. Returns an
+ * actionable error if the device nodes the overlay mounts are missing, or
+ * `null` when the profile is viable. Pure, like checkVulkanSupport().
+ */
+export function checkGpuDeviceSupport(opts: CheckGpuDeviceSupportOptions): string | null {
+  const { platform, exists, profile } = opts;
+  const name = profile === 'rocm' ? 'ROCm' : 'Intel XPU';
+  if (platform !== 'linux') {
+    return (
+      `The ${name} runtime is only supported on Linux. Docker Desktop on Windows/macOS ` +
+      'runs containers in a VM without GPU device passthrough. ' +
+      'Switch the Runtime Profile to "CPU Only" and try again.'
+    );
+  }
+  if (profile === 'rocm' && !exists('/dev/kfd')) {
+    return (
+      '/dev/kfd was not found on this system. The ROCm runtime profile requires an AMD GPU ' +
+      'with the amdgpu kernel driver loaded. Switch the Runtime Profile to "Vulkan Linux" ' +
+      'or "CPU Only" and try again.'
+    );
+  }
+  if (!exists('/dev/dri') || !exists('/dev/dri/renderD128')) {
+    return (
+      `/dev/dri was not found on this system (or has no render node). The ${name} runtime ` +
+      'profile requires a DRI-capable GPU with kernel driver support. ' +
+      'Switch the Runtime Profile to "CPU Only" and try again.'
+    );
+  }
+  return null;
+}
+
 // ─── GPU Preflight (NVIDIA, Linux) ─────────────────────────────────────────
 // Runs the cheap subset of scripts/diagnose-gpu.sh at dashboard startup so
 // the GpuHealthCard can warn about misconfigurations before the container
@@ -553,7 +703,7 @@ export async function runGpuDiagnostic(): Promise<RunGpuDiagnosticResult> {
   // attack surface flagged by CodeQL js/insecure-temporary-file. Random suffix
   // on the filename makes same-second back-to-back clicks not collide on the
   // O_EXCL check.
-  const dir = path.join(app.getPath('userData'), 'gpu-diagnostics');
+  const dir = path.join(getAppPaths()?.logs ?? app.getPath('userData'), 'gpu-diagnostics');
   fs.mkdirSync(dir, { recursive: true, mode: 0o700 });
   const ts = new Date().toISOString().replace(/[-:.]/g, '').slice(0, 15);
   const suffix = crypto.randomBytes(3).toString('hex');
@@ -715,9 +865,10 @@ function getComposeDir(): string {
 /**
  * Runtime profile: GPU (NVIDIA CUDA), Vulkan (AMD/Intel GPU on Linux DRI),
  * Vulkan-WSL2 (AMD/Intel GPU on Windows + Docker Desktop with WSL2 backend —
- * experimental, opt-in, GH-101 follow-up), CPU-only, or Metal (Apple Silicon MLX).
+ * experimental, opt-in, GH-101 follow-up), ROCm (AMD GPU on Linux), XPU (Intel
+ * GPU via oneAPI on Linux), CPU-only, or Metal (Apple Silicon MLX).
  */
-export type RuntimeProfile = 'gpu' | 'cpu' | 'vulkan' | 'vulkan-wsl2' | 'metal';
+export type RuntimeProfile = 'gpu' | 'cpu' | 'vulkan' | 'vulkan-wsl2' | 'rocm' | 'xpu' | 'metal';
 export type HfTokenDecision = 'unset' | 'provided' | 'skipped';
 
 const RUNTIME_PROFILE_VALUES: readonly RuntimeProfile[] = [
@@ -725,6 +876,8 @@ const RUNTIME_PROFILE_VALUES: readonly RuntimeProfile[] = [
   'cpu',
   'vulkan',
   'vulkan-wsl2',
+  'rocm',
+  'xpu',
   'metal',
 ];
 
@@ -754,6 +907,21 @@ export function readRuntimeProfileFromStore(): RuntimeProfile | null {
   }
 }
 
+/**
+ * Read this machine's whisper.cpp sidecar tuning (whispercppTuning.ts) from the
+ * electron-store JSON file on disk, like `readRuntimeProfileFromStore`.
+ * Defaults when the file or entry is missing.
+ */
+export function readWhispercppTuningFromStore(): WhispercppTuning {
+  try {
+    const storePath = path.join(app.getPath('userData'), 'dashboard-config.json');
+    const data = JSON.parse(fs.readFileSync(storePath, 'utf8')) as Record<string, unknown>;
+    return tuningForMachine(data[WHISPERCPP_TUNING_STORE_KEY]);
+  } catch {
+    return tuningForMachine(undefined);
+  }
+}
+
 /**
  * Resolve the runtime profile to actually launch with. Prefers the persisted
  * store value (durable user intent) over the renderer-supplied request, which
@@ -837,6 +1005,8 @@ export interface StartContainerOptions {
   diarizationModel?: string;
   sensevoiceDiarizationEngine?: string;
   whispercppModel?: string;
+  /** At-rest encryption key from the OS keychain (storageKey.ts); never persisted to `.env`. */
+  storageMasterKey?: string;
 }
 
 const HF_DECISION_VALUES = new Set<HfTokenDecision>(['unset', 'provided', 'skipped']);
@@ -912,6 +1082,19 @@ function readRemoteTlsProfile(): RemoteTlsProfile {
 /** Default server port — must match dashboard/src/config/store.ts::DEFAULT_SERVER_PORT */
 const DEFAULT_SERVER_PORT = 9786;
 
+/** `server.replicas` from the electron-store file: servers to run, one per GPU. */
+function readReplicaCountFromStore(): number {
+  try {
+    const storePath = path.join(app.getPath('userData'), 'dashboard-config.json');
+    const data = JSON.parse(fs.readFileSync(storePath, 'utf8')) as Record<string, unknown>;
+    const count = data['server.replicas'];
+    if (typeof count === 'number' && count >= 1) return Math.min(MAX_REPLICAS, Math.floor(count));
+  } catch {
+    // fall through
+  }
+  return 1;
+}
+
 /**
  * Read the configured server port from the electron-store JSON on disk.
  * Falls back to {@link DEFAULT_SERVER_PORT} if the file is missing or the key is absent.
@@ -1336,6 +1519,9 @@ function checkTailscaleCertsExist(): boolean {
   return fs.existsSync(certPath) && fs.existsSync(keyPath);
 }
 
+/** PyTorch wheel variant the ROCm image is built with (bootstrap_runtime.py). */
+export const ROCM_PYTORCH_VARIANT = 'rocm6.4';
+
 // ─── Compose File Selection ─────────────────────────────────────────────────
 
 /**
@@ -1346,11 +1532,15 @@ function checkTailscaleCertsExist(): boolean {
  *                  to the module-level `detectedGpuMode` (set by `checkGpu()`).
  *                  Exposed as a parameter so unit tests can exercise all branches
  *                  without mutating module state.
+ * @param pinGpu  - One GPU per container (server replicas): the legacy runtime
+ *                  gets the `NVIDIA_GPU` device overlay instead of `count: 1`.
+ *                  CDI and Podman read `NVIDIA_GPU` from their own overlays.
  */
 export function composeFileArgs(
   runtimeProfile: RuntimeProfile,
   runtimeKind: ContainerRuntimeKind = 'docker',
   gpuMode: 'cdi' | 'legacy' | null = detectedGpuMode,
+  pinGpu = false,
 ): string[] {
   const files: string[] = ['docker-compose.yml'];
 
@@ -1368,6 +1558,8 @@ export function composeFileArgs(
       files.push('podman-compose.gpu.yml');
     } else if (gpuMode === 'cdi') {
       files.push('docker-compose.gpu-cdi.yml');
+    } else if (pinGpu) {
+      files.push('docker-compose.gpu-device.yml');
     } else {
       files.push('docker-compose.gpu.yml'); // legacy nvidia runtime
     }
@@ -1378,6 +1570,13 @@ export function composeFileArgs(
     files.push('docker-compose.vulkan.yml');
   }
 
+  // AMD ROCm / Intel oneAPI: device-node passthrough for the main container.
+  if (runtimeProfile === 'rocm') {
+    files.push('docker-compose.rocm.yml');
+  } else if (runtimeProfile === 'xpu') {
+    files.push('docker-compose.xpu.yml');
+  }
+
   // vulkan-wsl2: whisper-server.exe runs natively on Windows (no AVX2 in the
   // host CPU means the containerised whisper-server cannot start).  Docker only
   // handles the main transcription backend; it reaches the native exe via
@@ -2215,6 +2414,7 @@ async function startContainer(options: StartContainerOptions): Promise<string> {
     diarizationModel,
     sensevoiceDiarizationEngine,
     whispercppModel,
+    storageMasterKey,
   } = options;
 
   // The persisted `server.runtimeProfile` is the source of truth for what the
@@ -2281,6 +2481,18 @@ async function startContainer(options: StartContainerOptions): Promise<string> {
     }
   }
 
+  // Pre-flight: AMD ROCm / Intel oneAPI (kernel device nodes, Linux only).
+  if (runtimeProfile === 'rocm' || runtimeProfile === 'xpu') {
+    const deviceError = checkGpuDeviceSupport({
+      platform: process.platform,
+      exists: (p) => fs.existsSync(p),
+      profile: runtimeProfile,
+    });
+    if (deviceError) {
+      throw new Error(deviceError);
+    }
+  }
+
   // Pre-flight: Windows vulkan-wsl2 (native whisper-server.exe, no AVX2 needed).
   // Docker Desktop with WSL2 backend is still required for the main backend
   // container; only the whisper sidecar is replaced by the native exe.
@@ -2371,6 +2583,16 @@ async function startContainer(options: StartContainerOptions): Promise<string> {
     composeEnv['PYTORCH_VARIANT'] = 'cpu';
   }
 
+  // ROCm / XPU images are built with their own PyTorch variant; passing the
+  // same value keeps the bootstrap's baked-vs-runtime cross-check quiet.
+  // RENDER_GID lets the overlay add the container user to the host's
+  // `render` group, whose GID differs between distros.
+  if (runtimeProfile === 'rocm' || runtimeProfile === 'xpu') {
+    composeEnv['PYTORCH_VARIANT'] = runtimeProfile === 'rocm' ? ROCM_PYTORCH_VARIANT : 'xpu';
+    const renderGid = readRenderGroupGid();
+    if (renderGid) composeEnv['RENDER_GID'] = renderGid;
+  }
+
   // GH-125: guarantee CPU launches never request a NeMo model. The UI-side
   // reset can be bypassed on first-run auto-detect (profile is set before the
   // model selection hydrates), so enforce the faster-whisper substitution here
@@ -2391,6 +2613,10 @@ async function startContainer(options: StartContainerOptions): Promise<string> {
   if (normalizedHfDecision) {
     composeEnv['HUGGINGFACE_TOKEN_DECISION'] = normalizedHfDecision;
   }
+  // Process env only: the key must not land in the compose `.env` file.
+  if (storageMasterKey) {
+    composeEnv['STORAGE_MASTER_KEY'] = storageMasterKey;
+  }
 
   const envUpdates: Record<string, string> = {};
   // Persist TLS mode so readComposeEnvValue('TLS_ENABLED') reflects reality
@@ -2484,12 +2710,21 @@ async function startContainer(options: StartContainerOptions): Promise<string> {
     const externalMgmt = runtimeProfile === 'vulkan-wsl2' ? '1' : '';
     composeEnv['WHISPERCPP_EXTERNAL_MODEL_MGMT'] = externalMgmt;
     envUpdates['WHISPERCPP_EXTERNAL_MODEL_MGMT'] = externalMgmt;
+
+    // Threads / flash attention / core pinning for the Linux sidecar's start
+    // command (the native exe gets them as arguments in launchWhisperServerNative).
+    const tuningEnv = sidecarComposeEnv(readWhispercppTuningFromStore(), detectCpuTopology());
+    Object.assign(composeEnv, tuningEnv);
+    Object.assign(envUpdates, tuningEnv);
   } else {
     // Clear stale vulkan env vars from a previous profile switch so they
     // don't linger in the .env file.
     envUpdates['WHISPERCPP_SERVER_URL'] = '';
     envUpdates['WHISPERCPP_MODEL'] = '';
     envUpdates['WHISPERCPP_EXTERNAL_MODEL_MGMT'] = '';
+    envUpdates['WHISPERCPP_THREADS'] = '';
+    envUpdates['WHISPERCPP_FLASH_ATTN'] = '';
+    envUpdates['WHISPERCPP_CPUSET'] = '';
   }
   // Always clear MESA_D3D12_DEFAULT_ADAPTER_NAME — it was only used by the
   // now-retired containerised whisper-server sidecar (docker-compose.vulkan-wsl2.yml).
@@ -2568,10 +2803,16 @@ async function startContainer(options: StartContainerOptions): Promise<string> {
     _whisperServerCurrentModel = ggmlFilename;
   }
 
+  // One server per GPU (`server.replicas`): this container takes GPU 0 and
+  // startReplicas() brings up the others once it is ready.
+  const replicaCount = runtimeProfile === 'gpu' ? readReplicaCountFromStore() : 1;
+  if (replicaCount > 1) composeEnv['NVIDIA_GPU'] = '0';
+
   const fileArgs = composeFileArgs(
     runtimeProfile,
     detectedRuntimeKind ?? undefined,
     detectedGpuMode,
+    replicaCount > 1,
   );
 
   // If a previous server was killed ungracefully (crash, kill -9, power loss),
@@ -2608,10 +2849,60 @@ async function startContainer(options: StartContainerOptions): Promise<string> {
   if (detectedRuntimeKind !== 'podman') {
     upArgs.push('--no-build');
   }
-  return exec(await runtimeBin(), upArgs, {
+  const output = await exec(await runtimeBin(), upArgs, {
     cwd: getComposeDir(),
     env: composeEnv,
   });
+  _replicaLaunch =
+    replicaCount > 1 ? { fileArgs, env: { ...composeEnv }, count: replicaCount } : null;
+  return output;
+}
+
+/**
+ * Start the extra server replicas of the last startContainer, each on its
+ * own GPU and port and sharing the data and model volumes. Runs after the
+ * main server is ready so only one of them migrates the database. A no-op
+ * (empty list) with a single server.
+ */
+async function startReplicas(): Promise<ReplicaSpec[]> {
+  const launch = _replicaLaunch;
+  if (!launch) return [];
+  const specs = replicaSpecs(launch.count, Number(launch.env['SERVER_PORT']));
+  for (const spec of specs) {
+    const env: Record<string, string> = {
+      ...launch.env,
+      SERVER_PORT: String(spec.port),
+      CONTAINER_NAME: spec.containerName,
+      NVIDIA_GPU: spec.gpu,
+    };
+    // Startup events belong to the main container's sequence.
+    delete env['STARTUP_EVENTS_DIR'];
+    const upArgs = ['compose', '-p', spec.project, ...launch.fileArgs, 'up', '-d'];
+    if (detectedRuntimeKind !== 'podman') upArgs.push('--no-build');
+    await exec(await runtimeBin(), upArgs, { cwd: getComposeDir(), env });
+  }
+  return specs;
+}
+
+/** Stop (or remove) every replica container, whatever count started them. */
+async function stopReplicas(remove = false, timeoutSeconds = 30): Promise<void> {
+  const bin = await runtimeBin();
+  const listed = await exec(bin, [
+    'ps',
+    '-a',
+    '--filter',
+    `name=${CONTAINER_NAME}-`,
+    '--format',
+    '{{.Names}}',
+  ]);
+  const names = listed
+    .split('\n')
+    .map((name) => name.trim())
+    .filter(isReplicaContainerName);
+  const seconds = String(Math.max(0, Math.floor(timeoutSeconds)));
+  await Promise.all(
+    names.map((name) => exec(bin, remove ? ['rm', '-f', name] : ['stop', '--time', seconds, name])),
+  );
 }
 
 /**
@@ -2626,6 +2917,9 @@ async function stopContainer(): Promise<string> {
   // switchWhisperServerModel() before the next start doesn't act on stale state.
   _whisperServerDefaultModel = null;
   _whisperServerCurrentModel = null;
+  await stopReplicas().catch((err) => {
+    console.warn('[DockerManager] Stopping server replicas failed:', err?.message ?? err);
+  });
   try {
     return await exec(await runtimeBin(), ['compose', 'stop'], { cwd: getComposeDir() });
   } catch (composeErr: any) {
@@ -2649,6 +2943,9 @@ async function stopContainer(): Promise<string> {
  */
 async function forceStopContainer(timeoutSeconds = 3): Promise<string> {
   const seconds = Math.max(0, Math.floor(timeoutSeconds));
+  await stopReplicas(false, seconds).catch((err) => {
+    console.warn('[DockerManager] Stopping server replicas failed:', err?.message ?? err);
+  });
   try {
     return await exec(await runtimeBin(), ['stop', '--time', String(seconds), CONTAINER_NAME]);
   } catch (err: any) {
@@ -2661,10 +2958,30 @@ async function forceStopContainer(timeoutSeconds = 3): Promise<string> {
   }
 }
 
+/**
+ * Restart the running container in place (scheduled housekeeping). Keeps the
+ * options it was started with, unlike a stop + startContainer round trip.
+ */
+async function restartContainer(timeoutSeconds = 30): Promise<string> {
+  const seconds = Math.max(0, Math.floor(timeoutSeconds));
+  return exec(await runtimeBin(), ['restart', '--time', String(seconds), CONTAINER_NAME]);
+}
+
+/**
+ * Start the stopped container again with the options it was created with
+ * (idle stop). Only valid after forceStopContainer — compose down removes it.
+ */
+async function resumeContainer(): Promise<string> {
+  return exec(await runtimeBin(), ['start', CONTAINER_NAME]);
+}
+
 /**
  * Remove the container (docker compose down).
  */
 async function removeContainer(): Promise<string> {
+  await stopReplicas(true).catch((err) => {
+    console.warn('[DockerManager] Removing server replicas failed:', err?.message ?? err);
+  });
   return exec(await runtimeBin(), ['compose', 'down'], { cwd: getComposeDir() });
 }
 
@@ -2944,7 +3261,7 @@ let containerSessionStart: string | undefined;
  * Resolve the path to the persistent server log file inside the user config dir.
  */
 function getServerLogPath(): string {
-  const logDir = path.join(app.getPath('userData'), 'logs');
+  const logDir = getAppPaths()?.logs ?? path.join(app.getPath('userData'), 'logs');
   fs.mkdirSync(logDir, { recursive: true });
   return path.join(logDir, 'server.log');
 }
@@ -3376,17 +3693,21 @@ function unsubscribeFromDownloadEvents(callback: (event: BootstrapDownloadEvent)
  * (GH-101 follow-up) — surfaced via the optional `wslSupport` field, which is
  * `undefined` on non-Win32 platforms.
  *
- * Returns { gpu, toolkit, vulkan, wslSupport? }.
+ * Returns { gpu, toolkit, vulkan, rocm, xpu, wslSupport? }.
  */
 async function checkGpu(): Promise<{
   gpu: boolean;
   toolkit: boolean;
   vulkan: boolean;
+  rocm: boolean;
+  xpu: boolean;
   wslSupport?: WslSupport;
 }> {
   let gpu = false;
   let toolkit = false;
   let vulkan = false;
+  let rocm = false;
+  let xpu = false;
   try {
     const gpuName = await exec('nvidia-smi', ['--query-gpu=name', '--format=csv,noheader']);
     gpu = true;
@@ -3454,6 +3775,21 @@ async function checkGpu(): Promise<{
     }
   }
 
+  // AMD / Intel compute runtimes: identify the vendor of the DRM cards so the
+  // ROCm (needs /dev/kfd from amdgpu) and oneAPI XPU profiles can be offered.
+  if (!gpu && vulkan) {
+    const vendors = parseDrmVendors(readDrmVendorIds());
+    rocm = vendors.amd && fs.existsSync('/dev/kfd');
+    xpu = vendors.intel;
+    if (rocm || xpu) {
+      console.log(
+        `[DockerManager] GPU compute runtimes available: ${[rocm && 'ROCm', xpu && 'XPU']
+          .filter(Boolean)
+          .join(', ')}`,
+      );
+    }
+  }
+
   // Win32: surface the `vulkan-wsl2` profile unconditionally.
   //
   // Historical note: this used to run a `docker run alpine:3 --device /dev/dxg`
@@ -3484,7 +3820,7 @@ async function checkGpu(): Promise<{
     );
   }
 
-  return { gpu, toolkit, vulkan, wslSupport };
+  return { gpu, toolkit, vulkan, rocm, xpu, wslSupport };
 }
 
 /**
@@ -3660,21 +3996,128 @@ export async function switchWhisperServerModel(
   return { switched: true, model: target };
 }
 
-function getWhisperServerExePath(): string {
-  return path.join(
-    app.getPath('appData'),
-    'TranscriptionSuite',
-    'whisper-server',
-    'whisper-server.exe',
+// ─── whisper.cpp sidecar tuning (whispercppTuning.ts) ───────────────────────
+
+const WHISPER_SERVER_URL = 'http://127.0.0.1:8080';
+/** A recreated sidecar reloads its model; large GGML files take a while. */
+const WHISPER_HEALTHY_TIMEOUT_MS = 180_000;
+let _whispercppBenchmarkRunning = false;
+
+/** Poll whisper-server's /health (503 while the model loads) until it answers 200. */
+async function waitForWhisperServerHealthy(): Promise<void> {
+  const deadline = Date.now() + WHISPER_HEALTHY_TIMEOUT_MS;
+  while (Date.now() < deadline) {
+    try {
+      const res = await fetch(`${WHISPER_SERVER_URL}/health`);
+      if (res.ok) return;
+    } catch {
+      // not listening yet
+    }
+    await new Promise((r) => setTimeout(r, 1000));
+  }
+  throw new Error(
+    `whisper-server did not become ready within ${WHISPER_HEALTHY_TIMEOUT_MS / 1000}s`,
   );
 }
 
+/**
+ * Restart the whisper.cpp sidecar with `tuning` and wait until it serves
+ * again: recreate the compose service (`vulkan`, which re-reads the tuning
+ * env from `.env`) or relaunch the native exe on its current model
+ * (`vulkan-wsl2`). Transcriptions in flight on the sidecar fail.
+ */
+export async function restartWhispercppSidecar(tuning: WhispercppTuning): Promise<void> {
+  const profile = readRuntimeProfileFromStore();
+  if (profile === 'vulkan') {
+    upsertComposeEnvValues(sidecarComposeEnv(tuning, detectCpuTopology()));
+    // No -f overlays: the persisted COMPOSE_FILE names the sidecar's overlay.
+    await exec(
+      await runtimeBin(),
+      ['compose', 'up', '-d', '--no-deps', '--force-recreate', 'whisper-server'],
+      { cwd: getComposeDir() },
+    );
+  } else if (profile === 'vulkan-wsl2') {
+    if (!_whisperServerCurrentModel) {
+      throw new Error('whisper-server.exe is not running — start the server first.');
+    }
+    await killExistingWhisperServer();
+    await launchWhisperServerNative(
+      path.join(getWhisperModelsDir(), _whisperServerCurrentModel),
+      tuning,
+    );
+  } else {
+    throw new Error('The whisper.cpp engine is only used by the Vulkan runtime profiles.');
+  }
+  await waitForWhisperServerHealthy();
+}
+
+/** Seconds one /inference of `wav` takes. */
+async function timeWhisperInference(wav: Buffer): Promise<number> {
+  const form = new FormData();
+  form.append('file', new Blob([wav], { type: 'audio/wav' }), 'benchmark.wav');
+  form.append('response_format', 'json');
+  form.append('temperature', '0');
+  const started = performance.now();
+  const res = await fetch(`${WHISPER_SERVER_URL}/inference`, { method: 'POST', body: form });
+  await res.arrayBuffer();
+  if (!res.ok) throw new Error(`whisper-server answered ${res.status}`);
+  return (performance.now() - started) / 1000;
+}
+
+/**
+ * Time the sidecar under each of `benchmarkCandidates` — restart with the
+ * candidate, one warm-up inference, best of two timed ones. Leaves the
+ * sidecar on the last candidate; the caller stores the winner and restarts
+ * onto it. Takes a few minutes; refuses to run twice at once.
+ */
+export async function benchmarkWhispercpp(
+  onProgress?: (done: number, total: number) => void,
+): Promise<WhispercppBenchmark> {
+  if (_whispercppBenchmarkRunning) throw new Error('A benchmark is already running.');
+  _whispercppBenchmarkRunning = true;
+  try {
+    const model =
+      readRuntimeProfileFromStore() === 'vulkan-wsl2'
+        ? _whisperServerCurrentModel
+        : readComposeEnvValue('WHISPERCPP_MODEL');
+    const candidates = benchmarkCandidates(detectCpuTopology());
+    const wav = benchmarkWav();
+    const runs: WhispercppBenchmark['runs'] = [];
+    for (const candidate of candidates) {
+      onProgress?.(runs.length, candidates.length);
+      try {
+        await restartWhispercppSidecar(candidate);
+        await timeWhisperInference(wav);
+        const seconds = Math.min(await timeWhisperInference(wav), await timeWhisperInference(wav));
+        runs.push({ ...candidate, seconds });
+      } catch (err) {
+        const error = err instanceof Error ? err.message : String(err);
+        console.warn('[DockerManager] whisper.cpp benchmark candidate failed:', candidate, error);
+        runs.push({ ...candidate, seconds: null, error });
+      }
+    }
+    onProgress?.(runs.length, candidates.length);
+    return { measuredAt: new Date().toISOString(), model, runs };
+  } finally {
+    _whispercppBenchmarkRunning = false;
+  }
+}
+
+/** Platform data dir (appPaths.ts); falls back to the pre-layout location. */
+function getNativeDataDir(): string {
+  return getAppPaths()?.data ?? path.join(app.getPath('appData'), 'TranscriptionSuite');
+}
+
+function getWhisperServerExePath(): string {
+  return path.join(getNativeDataDir(), 'whisper-server', 'whisper-server.exe');
+}
+
 function getWhisperServerPidPath(): string {
-  return path.join(app.getPath('appData'), 'TranscriptionSuite', 'whisper-server.pid');
+  return path.join(getNativeDataDir(), 'whisper-server.pid');
 }
 
 function getWhisperModelsDir(): string {
-  return path.join(app.getPath('appData'), 'TranscriptionSuite', 'whisper-models');
+  return path.join(getNativeDataDir(), 'whisper-models');
 }
 
 /**
@@ -3838,11 +4281,16 @@ async function downloadGgmlModelToHost(fileName: string): Promise<void> {
 
 /**
  * Spawn whisper-server.exe as a detached background process and persist its
- * PID so it can be killed on the next start or on clean app exit.
+ * PID so it can be killed on the next start or on clean app exit. `tuning`
+ * adds the thread and flash-attention arguments; core pinning is Linux-only.
  */
-async function launchWhisperServerNative(modelPath: string): Promise<void> {
+async function launchWhisperServerNative(
+  modelPath: string,
+  tuning: WhispercppTuning = readWhispercppTuningFromStore(),
+): Promise<void> {
   const exePath = getWhisperServerExePath();
-  const child = spawn(exePath, ['--model', modelPath, '--host', '0.0.0.0', '--port', '8080'], {
+  const args = ['--model', modelPath, '--host', '0.0.0.0', '--port', '8080'];
+  const child = spawn(exePath, [...args, ...sidecarArgs(tuning)], {
     detached: true,
     stdio: 'ignore',
   });
@@ -4477,6 +4925,10 @@ export const dockerManager = {
   getContainerStatus,
   startContainer,
   stopContainer,
+  restartContainer,
+  resumeContainer,
+  startReplicas,
+  stopReplicas,
   forceStopContainer,
   removeContainer,
   getVolumes,
@@ -4495,6 +4947,8 @@ export const dockerManager = {
   isGgmlModelDownloadedOnHost,
   downloadGgmlModelToHost,
   switchWhisperServerModel,
+  restartWhispercppSidecar,
+  benchmarkWhispercpp,
   ensureWhisperDirectories,
   downloadWhisperServerExe,
   checkTailscaleCertsExist,
@@ -0,0 +1,233 @@
+/**
+ * Live follow-along: a read-only web page on the LAN where meeting
+ * participants can watch the Live Mode transcript as it is written.
+ *
+ * Each share is a session with its own random token in the URL
+ * (`http://<lan-ip>:<port>/f/<token>`); anything without it gets a plain 404.
+ * Stopping the share tells every open page the session has ended, closes the
+ * streams and shuts the listener down, so an old link never comes back to
+ * life — starting again issues a new token and an empty transcript.
+ *
+ * The page receives the transcript over Server-Sent Events: a `snapshot` on
+ * connect (and whenever the transcript is replaced wholesale), then one
+ * `sentence` event per finished sentence and `partial` events for the line in
+ * progress. Viewers can only read; there is no route that accepts input.
+ */
+
+import crypto from 'crypto';
+import http from 'http';
+
+import { lanAddress } from './phoneIngest.js';
+import { qrSvg } from './qrCode.js';
+
+export const DEFAULT_FOLLOW_ALONG_PORT = 9788;
+const HEARTBEAT_MS = 15_000;
+
+export interface FollowAlongPayload {
+  sentences: string[];
+  partial: string;
+}
+
+export interface FollowAlongStatus {
+  running: boolean;
+  url: string | null;
+  qrSvg: string | null;
+  viewers: number;
+}
+
+export interface FollowAlongDeps {
+  /** Overrides the LAN address advertised in the share URL. */
+  host?: string;
+}
+
+export class FollowAlongServer {
+  private server: http.Server | null = null;
+  private token: string | null = null;
+  private port: number | null = null;
+  private heartbeat: NodeJS.Timeout | null = null;
+  private readonly viewers = new Set<http.ServerResponse>();
+  private sentences: string[] = [];
+  private partial = '';
+
+  constructor(private readonly deps: FollowAlongDeps = {}) {}
+
+  status(): FollowAlongStatus {
+    if (!this.server || !this.token || this.port === null) {
+      return { running: false, url: null, qrSvg: null, viewers: 0 };
+    }
+    const host = this.deps.host ?? lanAddress();
+    const url = `http://${host}:${this.port}/f/${this.token}`;
+    return { running: true, url, qrSvg: qrSvg(url), viewers: this.viewers.size };
+  }
+
+  /** Start a new share session. A running session is kept as is. */
+  async start(port = DEFAULT_FOLLOW_ALONG_PORT): Promise<FollowAlongStatus> {
+    if (this.server) return this.status();
+    const server = http.createServer((req, res) => this.handle(req, res));
+    await new Promise<void>((resolve, reject) => {
+      server.once('error', reject);
+      server.listen(port, '0.0.0.0', () => {
+        server.off('error', reject);
+        resolve();
+      });
+    });
+    this.server = server;
+    this.port = (server.address() as { port: number }).port;
+    this.token = crypto.randomBytes(16).toString('base64url');
+    // Comment lines keep idle streams from being dropped by phones and proxies.
+    this.heartbeat = setInterval(
+      () => this.viewers.forEach((res) => res.write(': ping\n\n')),
+      HEARTBEAT_MS,
+    );
+    return this.status();
+  }
+
+  /** End the session for every viewer and invalidate its link. */
+  async stop(): Promise<void> {
+    const server = this.server;
+    if (!server) return;
+    this.server = null;
+    this.token = null;
+    this.port = null;
+    if (this.heartbeat) clearInterval(this.heartbeat);
+    this.heartbeat = null;
+    for (const res of this.viewers) {
+      send(res, 'end', {});
+      res.end();
+    }
+    this.viewers.clear();
+    // A new session starts empty rather than showing the previous meeting.
+    this.sentences = [];
+    this.partial = '';
+    await new Promise<void>((resolve) => {
+      server.close(() => resolve());
+      server.closeAllConnections();
+    });
+  }
+
+  /** Latest Live Mode transcript; only the difference goes out to viewers. */
+  update(payload: FollowAlongPayload): void {
+    const previous = this.sentences;
+    const appended =
+      payload.sentences.length >= previous.length &&
+      previous.every((text, i) => payload.sentences[i] === text);
+    this.sentences = [...payload.sentences];
+    const partialChanged = payload.partial !== this.partial;
+    this.partial = payload.partial;
+
+    if (!appended) {
+      this.broadcast('snapshot', { sentences: this.sentences, partial: this.partial });
+      return;
+    }
+    for (let i = previous.length; i < this.sentences.length; i++) {
+      this.broadcast('sentence', { index: i, text: this.sentences[i] });
+    }
+    if (partialChanged) this.broadcast('partial', { text: this.partial });
+  }
+
+  private broadcast(event: string, data: unknown): void {
+    for (const res of this.viewers) send(res, event, data);
+  }
+
+  private handle(req: http.IncomingMessage, res: http.ServerResponse): void {
+    const { pathname } = new URL(req.url ?? '/', 'http://localhost');
+    const match = /^\/f\/([A-Za-z0-9_-]+)(\/events)?$/.exec(pathname);
+    if (!match || req.method !== 'GET' || !this.authorized(match[1])) {
+      res.writeHead(404, { 'Content-Type': 'text/plain; charset=utf-8' });
+      res.end('Not found');
+      return;
+    }
+    if (!match[2]) {
+      res.writeHead(200, {
+        'Content-Type': 'text/html; charset=utf-8',
+        'Cache-Control': 'no-store',
+        'Referrer-Policy': 'no-referrer',
+      });
+      res.end(FOLLOW_PAGE);
+      return;
+    }
+    res.writeHead(200, {
+      'Content-Type': 'text/event-stream',
+      'Cache-Control': 'no-store',
+      Connection: 'keep-alive',
+    });
+    send(res, 'snapshot', { sentences: this.sentences, partial: this.partial });
+    this.viewers.add(res);
+    req.on('close', () => this.viewers.delete(res));
+  }
+
+  private authorized(candidate: string): boolean {
+    if (!this.token) return false;
+    const expected = Buffer.from(this.token);
+    const given = Buffer.from(candidate);
+    return given.length === expected.length && crypto.timingSafeEqual(given, expected);
+  }
+}
+
+function send(res: http.ServerResponse, event: string, data: unknown): void {
+  res.write(`event: ${event}\ndata: ${JSON.stringify(data)}\n\n`);
+}
+
+const FOLLOW_PAGE = `<!doctype html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Live transcript</title>
+<style>
+  body { font-family: system-ui, sans-serif; background: #0f172a; color: #e2e8f0;
+    margin: 0 auto; padding: 24px; max-width: 760px; line-height: 1.6; font-size: 1.1rem; }
+  header { display: flex; justify-content: space-between; align-items: center;
+    color: #94a3b8; font-size: 0.85rem; margin-bottom: 16px; }
+  #state::before { content: '●'; margin-right: 6px; color: #4ade80; }
+  #state.off::before { color: #64748b; }
+  p { margin: 0 0 12px; }
+  #partial { color: #94a3b8; font-style: italic; }
+</style>
+</head>
+<body>
+<header><span>Live transcript</span><span id="state">Live</span></header>
+<main id="lines"></main>
+<p id="partial"></p>
+<script>
+const lines = document.getElementById('lines');
+const partial = document.getElementById('partial');
+const state = document.getElementById('state');
+function follow(render) {
+  const atBottom = innerHeight + scrollY >= document.body.scrollHeight - 80;
+  render();
+  if (atBottom) scrollTo(0, document.body.scrollHeight);
+}
+function line(text) {
+  const p = document.createElement('p');
+  p.textContent = text;
+  return p;
+}
+const events = new EventSource(location.pathname.replace(/\\/$/, '') + '/events');
+events.addEventListener('snapshot', (e) => follow(() => {
+  const data = JSON.parse(e.data);
+  lines.replaceChildren(...data.sentences.map(line));
+  partial.textContent = data.partial;
+}));
+events.addEventListener('sentence', (e) => follow(() => {
+  lines.append(line(JSON.parse(e.data).text));
+}));
+events.addEventListener('partial', (e) => follow(() => {
+  partial.textContent = JSON.parse(e.data).text;
+}));
+events.addEventListener('end', () => {
+  events.close();
+  partial.textContent = '';
+  state.textContent = 'Session ended';
+  state.className = 'off';
+});
+events.onerror = () => {
+  if (events.readyState === EventSource.CLOSED) {
+    state.textContent = 'Disconnected';
+    state.className = 'off';
+  }
+};
+</script>
+</body>
+</html>
+`;
@@ -0,0 +1,34 @@
+/**
+ * HID device access for foot pedals (see src/services/hidPedal.ts).
+ *
+ * The renderer reads pedals through WebHID; Chromium only lists devices the
+ * embedder has granted, so the main process grants every HID device to the
+ * app's own pages. Chromium still blocks the protected usage pages (keyboards,
+ * mice, FIDO keys), so this cannot be used to sniff keystrokes.
+ */
+
+import type { Session } from 'electron';
+
+interface DevicePermissionDetails {
+  deviceType: string;
+  origin: string;
+}
+
+/** Grant only HID, and only to the app itself (file:// in production, the Vite server in dev). */
+export function isHidGrant(details: DevicePermissionDetails, devServerUrl?: string): boolean {
+  if (details.deviceType !== 'hid') return false;
+  if (details.origin === 'file://') return true;
+  if (!devServerUrl) return false;
+  try {
+    return new URL(devServerUrl).origin === details.origin;
+  } catch {
+    return false;
+  }
+}
+
+export function installHidAccess(
+  ses: Pick<Session, 'setDevicePermissionHandler'>,
+  devServerUrl?: string,
+): void {
+  ses.setDevicePermissionHandler((details) => isHidGrant(details, devServerUrl));
+}
@@ -0,0 +1,184 @@
+/**
+ * Idle manager — runs the local server container only while it is needed.
+ *
+ * With `server.idleStop.enabled`, a round every minute asks the server for
+ * its job queue; after `server.idleStop.minutes` with nothing running or
+ * waiting (and no wake-up from the renderer) the container is stopped, so
+ * shared desktops get their VRAM back for games and other workloads. When
+ * a job or recording needs the server again the renderer calls
+ * {@link IdleManager.wake}, which starts the same container and resolves
+ * once it is up.
+ *
+ * Only a container this manager stopped is started again — one the user
+ * stopped stays stopped. Every change of state is emitted so the renderer
+ * can show "sleeping" instead of "offline".
+ */
+
+export type IdleState = 'off' | 'active' | 'stopping' | 'sleeping' | 'waking';
+
+export interface IdleStatus {
+  state: IdleState;
+  /** Minutes without activity after which the container stops. */
+  idleMinutes: number;
+  /** Epoch ms of the last job, queue entry or wake-up; null while off. */
+  lastActivityAt: number | null;
+  /** Epoch ms the container was put to sleep. */
+  sleepingSince: number | null;
+  /** Last stop or start failure, cleared by the next success. */
+  error: string | null;
+}
+
+export interface IdleSettings {
+  enabled: boolean;
+  minutes: number;
+}
+
+export interface IdleManagerDeps {
+  settings: () => IdleSettings;
+  /** False for remote connections and non-container runtimes. */
+  manages: () => boolean;
+  containerRunning: () => Promise<boolean>;
+  /** True while a job runs or waits; an unreachable server counts as busy. */
+  serverBusy: () => Promise<boolean>;
+  stop: () => Promise<unknown>;
+  /** Start the stopped container and resolve once the server answers. */
+  start: () => Promise<unknown>;
+  emit: (status: IdleStatus) => void;
+  now?: () => number;
+  intervalMs?: number;
+}
+
+export const DEFAULT_IDLE_MINUTES = 15;
+const CHECK_INTERVAL_MS = 60_000;
+
+export class IdleManager {
+  private readonly deps: IdleManagerDeps;
+  private readonly now: () => number;
+  private timer: NodeJS.Timeout | null = null;
+  private checking = false;
+  private waking: Promise<boolean> | null = null;
+  private current: IdleStatus;
+
+  constructor(deps: IdleManagerDeps) {
+    this.deps = deps;
+    this.now = deps.now ?? Date.now;
+    this.current = {
+      state: 'off',
+      idleMinutes: this.minutes(),
+      lastActivityAt: null,
+      sleepingSince: null,
+      error: null,
+    };
+  }
+
+  start(): void {
+    if (this.timer) return;
+    this.timer = setInterval(() => void this.check(), this.deps.intervalMs ?? CHECK_INTERVAL_MS);
+  }
+
+  stop(): void {
+    if (this.timer) clearInterval(this.timer);
+    this.timer = null;
+  }
+
+  status(): IdleStatus {
+    return this.current;
+  }
+
+  /** Work is about to start — keeps a running container up for another full period. */
+  noteActivity(): void {
+    if (this.current.state === 'active') this.set({ lastActivityAt: this.now() });
+  }
+
+  /**
+   * Make sure the server is up before a job or recording. Resolves true when
+   * the container had to be started; concurrent callers share one start.
+   */
+  wake(): Promise<boolean> {
+    if (this.waking) return this.waking;
+    if (this.current.state !== 'sleeping') {
+      this.noteActivity();
+      return Promise.resolve(false);
+    }
+    this.set({ state: 'waking', error: null });
+    this.waking = this.deps
+      .start()
+      .then(() => {
+        this.set({ state: 'active', lastActivityAt: this.now(), sleepingSince: null });
+        return true;
+      })
+      .catch((err) => {
+        this.set({ state: 'sleeping', error: errorMessage(err) });
+        throw err;
+      })
+      .finally(() => {
+        this.waking = null;
+      });
+    return this.waking;
+  }
+
+  /** One round: follow the settings, note activity and stop the container when idle. */
+  async check(): Promise<void> {
+    if (this.checking || this.waking) return;
+    this.checking = true;
+    try {
+      await this.round();
+    } finally {
+      this.checking = false;
+    }
+  }
+
+  private async round(): Promise<void> {
+    const { enabled } = this.deps.settings();
+    const idleMinutes = this.minutes();
+    if (!enabled || !this.deps.manages()) {
+      if (this.current.state !== 'off') {
+        this.set({ state: 'off', lastActivityAt: null, sleepingSince: null, error: null });
+      }
+      return;
+    }
+    if (idleMinutes !== this.current.idleMinutes) this.set({ idleMinutes });
+
+    const running = await this.deps.containerRunning();
+    if (!running) {
+      // Stopped by us: keep sleeping. Stopped by the user: not ours to wake.
+      if (this.current.state !== 'sleeping' && this.current.state !== 'off') {
+        this.set({ state: 'off', lastActivityAt: null });
+      }
+      return;
+    }
+    const now = this.now();
+    if (this.current.state !== 'active' || this.current.lastActivityAt === null) {
+      // Started from the UI, or first round after enabling: a fresh period.
+      this.set({ state: 'active', lastActivityAt: now, sleepingSince: null });
+      return;
+    }
+    if (await this.deps.serverBusy()) {
+      this.set({ lastActivityAt: now });
+      return;
+    }
+    if (now - this.current.lastActivityAt < idleMinutes * 60_000) return;
+
+    this.set({ state: 'stopping', error: null });
+    try {
+      await this.deps.stop();
+      this.set({ state: 'sleeping', sleepingSince: this.now() });
+    } catch (err) {
+      this.set({ state: 'active', lastActivityAt: this.now(), error: errorMessage(err) });
+    }
+  }
+
+  private minutes(): number {
+    const minutes = Number(this.deps.settings().minutes);
+    return Number.isFinite(minutes) && minutes >= 1 ? Math.floor(minutes) : DEFAULT_IDLE_MINUTES;
+  }
+
+  private set(patch: Partial<IdleStatus>): void {
+    this.current = { ...this.current, ...patch };
+    this.deps.emit(this.current);
+  }
+}
+
+function errorMessage(err: unknown): string {
+  return err instanceof Error ? err.message : String(err);
+}
@@ -0,0 +1,100 @@
+/**
+ * jobMedia — fetch the copy of a session import the server kept
+ * (`keep_media` on /api/transcribe/import) and verify it before it lands.
+ *
+ * The expected digest is the one the server reported when the upload was
+ * accepted, so a copy that changed on the server — or on the way back —
+ * is caught, not just a truncated transfer. Bytes stream through the hash
+ * into `<destination>.part`, which is renamed only once the digest matches
+ * and deleted otherwise.
+ */
+
+import { createHash } from 'crypto';
+import fs from 'fs';
+import { Readable, Transform } from 'stream';
+import { pipeline } from 'stream/promises';
+import type { ReadableStream as WebReadableStream } from 'stream/web';
+
+export interface JobMediaDownload {
+  path: string;
+  bytes: number;
+  sha256: string;
+}
+
+export class JobMediaIntegrityError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'JobMediaIntegrityError';
+  }
+}
+
+export interface DownloadJobMediaOptions {
+  serverUrl: string;
+  token?: string | null;
+  /** Full server job id (`kept_media.job_id`), not the 8-char display id. */
+  jobId: string;
+  destination: string;
+  /** `kept_media.sha256` — the digest of the bytes originally uploaded. */
+  expectedSha256: string;
+  fetch?: typeof fetch;
+  signal?: AbortSignal;
+}
+
+export function jobMediaUrl(serverUrl: string, jobId: string): string {
+  return `${serverUrl.replace(/\/+$/, '')}/api/transcribe/jobs/${encodeURIComponent(jobId)}/media`;
+}
+
+export async function downloadJobMedia(
+  options: DownloadJobMediaOptions,
+): Promise<JobMediaDownload> {
+  const doFetch = options.fetch ?? fetch;
+  const headers: Record<string, string> = {};
+  if (options.token) headers.Authorization = `Bearer ${options.token}`;
+  const res = await doFetch(jobMediaUrl(options.serverUrl, options.jobId), {
+    headers,
+    signal: options.signal,
+  });
+  if (!res.ok || !res.body) {
+    if (res.status === 410) throw new Error('The server no longer keeps a copy of this file');
+    const detail = await res
+      .json()
+      .then((body: { detail?: string } | null) => body?.detail)
+      .catch(() => undefined);
+    throw new Error(`Download failed: ${detail ?? `HTTP ${res.status}`}`);
+  }
+
+  const expected = options.expectedSha256.toLowerCase();
+  const announced = res.headers.get('x-content-sha256')?.toLowerCase();
+  if (announced && announced !== expected) {
+    await res.body.cancel().catch(() => {});
+    throw new JobMediaIntegrityError('The server copy is not the file that was uploaded');
+  }
+
+  const partial = `${options.destination}.part`;
+  const hash = createHash('sha256');
+  let bytes = 0;
+  try {
+    await pipeline(
+      Readable.fromWeb(res.body as WebReadableStream<Uint8Array>),
+      new Transform({
+        transform(chunk: Buffer, _encoding, callback) {
+          hash.update(chunk);
+          bytes += chunk.length;
+          callback(null, chunk);
+        },
+      }),
+      fs.createWriteStream(partial),
+    );
+    const actual = hash.digest('hex');
+    if (actual !== expected) {
+      throw new JobMediaIntegrityError(
+        `Checksum mismatch: expected ${expected}, received ${actual} (${bytes} bytes)`,
+      );
+    }
+    await fs.promises.rename(partial, options.destination);
+    return { path: options.destination, bytes, sha256: actual };
+  } catch (err) {
+    await fs.promises.rm(partial, { force: true });
+    throw err;
+  }
+}
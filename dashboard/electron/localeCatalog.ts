@@ -0,0 +1,124 @@
+/**
+ * Locale catalogs for the UI.
+ *
+ * Catalogs are Fluent (`.ftl`) files: bundled ones ship in `public/locales`
+ * (copied to `dist/locales` by Vite), and users can drop community
+ * translations into `<userData>/locales` — no rebuild needed. A user file
+ * for a bundled locale overrides it message by message.
+ *
+ * Only simple Fluent messages are supported (`id = text`, indented
+ * continuation lines, `{ $var }` placeables); terms, attributes and
+ * selectors are skipped. The renderer receives the parsed messages and
+ * falls back to its bundled English copy for anything missing.
+ */
+
+import fs from 'fs';
+import path from 'path';
+
+export const DEFAULT_LOCALE = 'en';
+
+export interface LocaleInfo {
+  code: string;
+  name: string;
+  source: 'bundled' | 'user';
+}
+
+export interface LocaleCatalog {
+  code: string;
+  messages: Record<string, string>;
+}
+
+export interface CatalogDirs {
+  bundled: string;
+  user: string;
+}
+
+const LOCALE_CODE = /^[a-z]{2,3}(?:-[A-Za-z0-9]{2,8})*$/;
+
+/** `en`, `pt-BR`, `zh-Hant` — also the guard against path traversal. */
+export function isLocaleCode(code: string): boolean {
+  return LOCALE_CODE.test(code);
+}
+
+export function parseFtl(source: string): Record<string, string> {
+  const messages: Record<string, string> = {};
+  let current: string | null = null;
+  for (const raw of source.replace(/\r\n?/g, '\n').split('\n')) {
+    const line = raw.replace(/\s+$/, '');
+    if (!line || line.startsWith('#')) {
+      current = null;
+      continue;
+    }
+    if (/^\s/.test(line)) {
+      // Continuation of a multi-line value (attributes start with '.').
+      if (current && !line.trim().startsWith('.')) {
+        messages[current] = [messages[current], line.trim()].filter(Boolean).join(' ');
+      }
+      continue;
+    }
+    const match = /^([a-zA-Z][\w-]*)\s*=\s*(.*)$/.exec(line);
+    current = match ? match[1] : null;
+    if (current) messages[current] = match![2];
+  }
+  return messages;
+}
+
+function readCatalog(dir: string, code: string): Record<string, string> | null {
+  try {
+    return parseFtl(fs.readFileSync(path.join(dir, `${code}.ftl`), 'utf-8'));
+  } catch {
+    return null;
+  }
+}
+
+function codesIn(dir: string): string[] {
+  try {
+    return fs
+      .readdirSync(dir)
+      .filter((f) => f.endsWith('.ftl'))
+      .map((f) => f.slice(0, -'.ftl'.length))
+      .filter(isLocaleCode);
+  } catch {
+    return [];
+  }
+}
+
+/** Every available locale, bundled first; re-read on each call so new files show up. */
+export function listLocales(dirs: CatalogDirs): LocaleInfo[] {
+  const found = new Map<string, LocaleInfo>();
+  for (const [source, dir] of [
+    ['bundled', dirs.bundled],
+    ['user', dirs.user],
+  ] as const) {
+    for (const code of codesIn(dir)) {
+      if (found.has(code)) continue;
+      const name = readCatalog(dir, code)?.['locale-name'] || code;
+      found.set(code, { code, name, source });
+    }
+  }
+  return [...found.values()].sort((a, b) => a.code.localeCompare(b.code));
+}
+
+/** Messages for `code`: bundled catalog, then the user file on top. Unknown codes yield English. */
+export function loadCatalog(dirs: CatalogDirs, code: string): LocaleCatalog {
+  if (!isLocaleCode(code)) code = DEFAULT_LOCALE;
+  const bundled = readCatalog(dirs.bundled, code);
+  const user = readCatalog(dirs.user, code);
+  if (!bundled && !user) {
+    return { code: DEFAULT_LOCALE, messages: readCatalog(dirs.bundled, DEFAULT_LOCALE) ?? {} };
+  }
+  return { code, messages: { ...bundled, ...user } };
+}
+
+/** Pick the stored locale, else the OS locale (or its base language) when a catalog exists. */
+export function resolveInitialLocale(
+  stored: unknown,
+  systemLocale: string,
+  available: LocaleInfo[],
+): string {
+  const codes = new Set(available.map((l) => l.code));
+  if (typeof stored === 'string' && codes.has(stored)) return stored;
+  if (codes.has(systemLocale)) return systemLocale;
+  const base = systemLocale.split('-')[0];
+  return codes.has(base) ? base : DEFAULT_LOCALE;
+}
@@ -19,6 +19,9 @@ import {
   desktopCapturer,
   ipcMain,
   Notification,
+  powerMonitor,
+  safeStorage,
+  screen,
   session,
   shell,
   dialog,
@@ -26,10 +29,31 @@ import {
 
 const execFileAsync = promisify(execFile);
 import Store from 'electron-store';
-import { CONTAINER_NAME, dockerManager, type StartContainerOptions } from './dockerManager.js';
+import semver from 'semver';
+import {
+  CONTAINER_NAME,
+  dockerManager,
+  readRuntimeProfileFromStore,
+  readUseLegacyGpuFromStore,
+  resolveImageRepo,
+  type StartContainerOptions,
+} from './dockerManager.js';
 import { NotificationLog } from './notificationLog.js';
+import { NotificationHistory, type ListNotificationsOptions } from './notificationHistory.js';
+import {
+  TimeTracking,
+  projectTotalsCsv,
+  recordingTotalsCsv,
+  type Heartbeat,
+  type TimeRange,
+  type WorkSessionInfo,
+} from './timeTracking.js';
 import { StartupEventWatcher } from './startupEventWatcher.js';
 import { MLXServerManager, type MLXStartOptions } from './mlxServerManager.js';
+import { NativeBackendManager, type NativeBackendStartOptions } from './nativeBackendManager.js';
+import type { BootstrapOptions } from './backendBootstrap.js';
+import { WslBackendManager, decodeWslOutput } from './wslBackend.js';
+import { StartupSequencer, formatStartupReport, type StartupState } from './startupSequencer.js';
 import { createMlxLogSink, type MlxLogSink } from './mlxLogSink.js';
 import { TrayManager, type TrayState } from './trayManager.js';
 import { UpdateManager, type InstallerStatus } from './updateManager.js';
@@ -37,7 +61,31 @@ import { UpdateInstaller } from './updateInstaller.js';
 import { detectAppVariant } from './appVariant.js';
 import { downloadMacVariantDmg } from './macVariantUpdater.js';
 import { forceEnableWeeklyUpdatesOnce } from './updateMigration.js';
-import { createAppState, InstallGate } from './appState.js';
+import { selectNativeMetalOnce } from './nativeLaunch.js';
+import { createAppState, getAuthToken, getServerUrl, InstallGate } from './appState.js';
+import {
+  ConnectionQualityMonitor,
+  connectionTargets,
+  probeLatency,
+  profileServerUrl,
+} from './connectionQuality.js';
+import { DEFAULT_IDLE_MINUTES, IdleManager } from './idleManager.js';
+import {
+  DEFAULT_SUSPEND_COMMAND,
+  DEFAULT_WOL_PORT,
+  RemotePower,
+  sendUdpBroadcast,
+  sshArgs,
+  sshSuspendFailed,
+} from './remotePower.js';
+import {
+  DEFAULT_CONNECTION_TUNING,
+  agentOptions,
+  armConnectTimeout,
+  getConnectionTuning,
+  sharedAgent,
+  type ConnectionProfile,
+} from './connectionTuning.js';
 import { CompatGuard } from './compatGuard.js';
 import { verifyChecksum } from './checksumVerifier.js';
 import {
@@ -59,8 +107,80 @@ import {
 } from './shortcutManager.js';
 import { pasteAtCursor } from './pasteAtCursor.js';
 import { ensureDesktopFileInstalled } from './desktopIntegration.js';
+import { applyPortablePaths, resolvePortableRoot } from './portableMode.js';
+import { DEMO_SERVER_PORT, isDemoMode, startDemoServer, type DemoServer } from './demoServer.js';
+import {
+  migrateLegacyLayout,
+  resolveAppPaths,
+  setAppPaths,
+  type AppPathKind,
+} from './appPaths.js';
 import { reliableWriteText, cleanupClipboard } from './clipboardWayland.js';
 import { WatcherManager } from './watcherManager.js';
+import { exportHtmlToPdf, type PdfExportOptions } from './pdfExport.js';
+import { CaptionOverlay, type CaptionPayload, type CaptionPosition } from './captionOverlay.js';
+import { CaptionOutputs, readCaptionOutputSettings } from './captionOutputs.js';
+import { ConfigBus } from './configBus.js';
+import { ActionRegistry } from './actionRegistry.js';
+import {
+  PLUGIN_MANIFEST,
+  PluginHost,
+  type PluginInfo,
+  type PluginTranscript,
+} from './pluginHost.js';
+import {
+  DEFAULT_TIMEOUT_SECONDS as HOOK_DEFAULT_TIMEOUT_SECONDS,
+  ScriptHooks,
+  isHookEvent,
+  type HookEvent,
+} from './scriptHooks.js';
+import { getOrCreateStorageMasterKey } from './storageKey.js';
+import { downloadJobMedia } from './jobMedia.js';
+import { clipMedia, removeClip, type MediaEdit } from './mediaClip.js';
+import { probeChannels } from './channelProbe.js';
+import {
+  WHISPERCPP_TUNING_STORE_KEY,
+  detectCpuTopology,
+  machineKey,
+  normalizeWhispercppTuning,
+  pickBest,
+  tuningForMachine,
+  type StoredWhispercppTuning,
+  type WhispercppTuning,
+} from './whispercppTuning.js';
+import {
+  CloudImport,
+  type CloudEntry,
+  type CloudSource,
+  type CloudSourceSecret,
+} from './cloudImport.js';
+import { installHidAccess } from './hidAccess.js';
+import { PhoneIngestServer } from './phoneIngest.js';
+import { CacheManager, isCacheCategory } from './cacheManager.js';
+import { FollowAlongServer, type FollowAlongPayload } from './followAlong.js';
+import {
+  exportKeymap,
+  importKeymap,
+  resolveKeymap,
+  setShortcut,
+  type SetShortcutResult,
+} from './shortcutRegistry.js';
+import {
+  exportSettings,
+  importSettings,
+  keyFingerprint,
+  loadSigningKey,
+  type ImportPlan,
+  type ImportResult,
+  type PathRemap,
+} from './settingsBundle.js';
+import {
+  isLocaleCode,
+  listLocales,
+  loadCatalog,
+  resolveInitialLocale,
+  type CatalogDirs,
+} from './localeCatalog.js';
 
 // When launched via a wrapper (e.g. AppImage through GearLevel), the stdout/stderr
 // pipes may already be closed.  Any console.log/warn/error call will then raise
@@ -75,6 +195,19 @@ for (const stream of [process.stdout, process.stderr] as NodeJS.WriteStream[]) {
 const __filename = fileURLToPath(import.meta.url);
 const __dirname = path.dirname(__filename);
 
+// Portable mode (--portable, or portable.txt beside the executable): resolved
+// first so every later app.getPath() already points next to the executable.
+const portableRoot = resolvePortableRoot({
+  argv: process.argv,
+  execPath: process.execPath,
+  env: process.env,
+  platform: process.platform,
+});
+
+// Demo mode (--demo): an in-process mock server instead of Docker, and a fresh
+// throwaway profile so demo settings never leak into the real ones.
+const demoMode = isDemoMode(process.argv, process.env);
+
 // AppImage on Linux: the afterPack build hook wraps the Electron binary with a
 // shell script that passes --no-sandbox as a real CLI argument (the zygote sandbox
 // check runs before this JS executes, so the flag must be in argv from the start).
@@ -90,14 +223,15 @@ if (process.platform === 'linux' && process.env.APPIMAGE) {
   // /tmp/.mount_*. Done here at module load — as early as possible — so the
   // portal's file monitor has the largest window to see the new file before the
   // much-later shortcut registration calls Register. Non-fatal best-effort.
-  ensureDesktopFileInstalled({ version: app.getVersion() });
+  // Skipped in portable mode, which must not write into the user profile.
+  if (!portableRoot) ensureDesktopFileInstalled({ version: app.getVersion() });
 }
 
 // GlobalShortcutsPortal Chromium feature flag removed — our D-Bus module
 // (waylandShortcuts.ts) handles portal communication directly, which avoids
 // duplicate portal sessions and lets us set human-readable descriptions.
 
-// ─── System audio loopback flags ────────────────────────────────────────────
+// ─── Chromium feature flags (audio loopback, media keys) ────────────────────
 // These must be set before app.whenReady() so Chromium picks them up.
 if (process.platform === 'linux') {
   // Disable VA-API probing — NVIDIA GPUs don't support it (they use NVDEC),
@@ -106,27 +240,75 @@ if (process.platform === 'linux') {
     'disable-features',
     'VaapiVideoDecoder,VaapiVideoEncoder,VaapiVideoDecodeLinuxGL',
   );
-  app.commandLine.appendSwitch('enable-features', 'PulseaudioLoopbackForScreenShare');
+  // MediaSessionService exposes navigator.mediaSession over MPRIS (D-Bus),
+  // so media keys and desktop media widgets control transcript playback.
+  app.commandLine.appendSwitch(
+    'enable-features',
+    'PulseaudioLoopbackForScreenShare,HardwareMediaKeyHandling,MediaSessionService',
+  );
 } else if (process.platform === 'darwin') {
   app.commandLine.appendSwitch(
     'enable-features',
-    'MacLoopbackAudioForScreenShare,MacSckSystemAudioLoopbackOverride',
+    'MacLoopbackAudioForScreenShare,MacSckSystemAudioLoopbackOverride,HardwareMediaKeyHandling',
   );
+} else {
+  // Windows: native WASAPI loopback needs no flags; media keys and the SMTC
+  // overlay come from HardwareMediaKeyHandling.
+  app.commandLine.appendSwitch('enable-features', 'HardwareMediaKeyHandling');
 }
-// Windows: native WASAPI loopback — no flags needed.
 
 // Ensure all Electron paths use PascalCase: ~/.config/TranscriptionSuite (not lowercase).
 // Both 'userData' AND 'crashDumps' must be set explicitly — Electron derives them
 // independently, and 'crashDumps' defaults to ~/.config/{package.name}/Crashpad (which
 // would be lowercase because npm requires lowercase package names).
-app.setPath('userData', path.join(app.getPath('appData'), 'TranscriptionSuite'));
-app.setPath('crashDumps', path.join(app.getPath('appData'), 'TranscriptionSuite', 'Crashpad'));
+if (demoMode) {
+  const demoRoot = path.join(app.getPath('temp'), 'TranscriptionSuite-demo');
+  fs.rmSync(demoRoot, { recursive: true, force: true });
+  console.log(`[Demo] Data directory: ${applyPortablePaths(app, demoRoot)}`);
+} else if (portableRoot) {
+  console.log(`[Portable] Data directory: ${applyPortablePaths(app, portableRoot)}`);
+} else {
+  app.setPath('userData', path.join(app.getPath('appData'), 'TranscriptionSuite'));
+  app.setPath('crashDumps', path.join(app.getPath('appData'), 'TranscriptionSuite', 'Crashpad'));
+}
+
+// Platform-convention data/cache/log dirs (see appPaths.ts); userData stays the
+// config dir. Older installs kept everything in userData — move it out once.
+const appPaths = resolveAppPaths({
+  platform: process.platform,
+  env: process.env,
+  home: app.getPath('home'),
+  userData: app.getPath('userData'),
+  portable: portableRoot !== null || demoMode,
+});
+setAppPaths(appPaths);
+if (!portableRoot && !demoMode) app.setAppLogsPath(appPaths.logs);
+try {
+  const { moved, skipped } = migrateLegacyLayout(appPaths);
+  for (const entry of moved) console.log(`[AppPaths] Migrated ${entry}`);
+  for (const entry of skipped) console.warn(`[AppPaths] Left ${entry} in place (already migrated)`);
+} catch (err) {
+  console.error('[AppPaths] Data migration failed; will retry on next launch:', err);
+}
 
 // Session notification log - wiped at boot and on quit (semi-persistent).
 const notificationLog = new NotificationLog(app.getPath('userData'));
+// Notification history across sessions (SQLite) - never wiped automatically.
+let notificationHistory: NotificationHistory | null = null;
+try {
+  notificationHistory = new NotificationHistory(app.getPath('userData'));
+} catch (err) {
+  console.warn('[NotificationHistory] Unavailable; history will not persist:', err);
+}
+// Active review time per transcript (SQLite) - kept until the user deletes it.
+let timeTracking: TimeTracking | null = null;
+try {
+  timeTracking = new TimeTracking(app.getPath('userData'));
+} catch (err) {
+  console.warn('[TimeTracking] Unavailable; review time will not be tracked:', err);
+}
 
 const isDev = !app.isPackaged;
-const CLIENT_LOG_DIR = 'logs';
 const CLIENT_LOG_FILE = 'client-debug.log';
 const CLIENT_SESSION_MARKER = '══════ CLIENT START';
 const MAX_CLIENT_LOG_SESSIONS = 5;
@@ -178,7 +360,7 @@ function flushEarlyLogBuffer(): void {
 }
 
 function ensureClientLogFilePath(): string {
-  const logDir = path.join(app.getPath('userData'), CLIENT_LOG_DIR);
+  const logDir = appPaths.logs;
   fs.mkdirSync(logDir, { recursive: true });
   const logFilePath = path.join(logDir, CLIENT_LOG_FILE);
 
@@ -222,7 +404,7 @@ function ensureClientLogFilePath(): string {
 }
 
 function ensureMlxLogFilePath(): string {
-  const logDir = path.join(app.getPath('userData'), CLIENT_LOG_DIR);
+  const logDir = appPaths.logs;
   fs.mkdirSync(logDir, { recursive: true });
   const logFilePath = path.join(logDir, MLX_LOG_FILE);
 
@@ -480,16 +662,56 @@ const store = new Store({
     'connection.authToken': '',
     'connection.port': 9786,
     'connection.useHttps': false,
+    // Per-profile timeouts / keep-alive / HTTP/2 (connectionTuning.ts).
+    'connection.tuning.local': DEFAULT_CONNECTION_TUNING.local,
+    'connection.tuning.tailscale': DEFAULT_CONNECTION_TUNING.tailscale,
+    'connection.tuning.lan': DEFAULT_CONNECTION_TUNING.lan,
+    // Follow the connection-quality monitor's switch suggestions (connectionQuality.ts).
+    'connection.autoFailover': false,
+    // Wake-on-LAN and SSH suspend for a remote server that sleeps (remotePower.ts).
+    'power.autoWake': false,
+    'power.macAddress': '',
+    'power.broadcastAddress': '255.255.255.255',
+    'power.port': DEFAULT_WOL_PORT,
+    'power.wakeTimeoutSeconds': 120,
+    'power.sleepAfterJobs': false,
+    'power.sshTarget': '',
+    'power.sshCommand': DEFAULT_SUSPEND_COMMAND,
+    // Per-user namespace on a shared server (X-TranscriptionSuite-User header).
+    'identity.userName': '',
+    'identity.showOnlyMine': false,
+    'housekeeping.enabled': false,
+    'housekeeping.windowStart': '03:00',
+    'housekeeping.windowEnd': '05:00',
+    'housekeeping.pruneModelCache': true,
+    'housekeeping.pruneUnusedDays': 30,
+    'housekeeping.rotateLogs': true,
+    'housekeeping.goldenCheck': false,
+    'housekeeping.restartContainer': false,
+    'housekeeping.lastRunDate': '',
+    // Default job target, recommended by the last benchmark (src/services/benchmark.ts).
+    'dispatch.profile': '',
+    'dispatch.model': '',
+    'dispatch.lastBenchmark': null,
     'session.audioSource': 'mic',
     'session.micDevice': 'Default Microphone',
     'session.systemDevice': 'Default Output',
     'session.mainLanguage': 'Auto Detect',
     'session.liveLanguage': 'Auto Detect',
+    'session.interviewLocalName': 'Me',
+    'session.interviewRemoteName': 'Guest',
+    // Retro-record: keep the last N minutes of the input in memory (RetroRecordCard).
+    'session.retroRecord': false,
+    'session.retroMinutes': 5,
     'audio.gracePeriod': 1.0,
     'audio.previewDurationSeconds': 20,
     'diarization.constrainSpeakers': true,
     'diarization.numSpeakers': 2,
     'notebook.autoAdd': false,
+    // Active review time per transcript (timeTracking.ts).
+    'timeTracking.enabled': false,
+    'timeTracking.projectField': 'Client',
+    'timeTracking.idleMinutes': 2,
     'app.autoCopy': true,
     'app.showNotifications': true,
     'app.stopServerOnQuit': true,
@@ -499,6 +721,21 @@ const store = new Store({
     'app.updateCheckCustomHours': 24,
     'app.modelSelectionOnboardingCompleted': false,
     'output.hideTimestamps': false,
+    'output.speakerStats': false,
+    'output.normalizeNumbers': false,
+    // Output filter (src/services/outputFilter.ts) — exports and clipboard copies only.
+    'output.filter.enabled': false,
+    'output.filter.builtInList': true,
+    'output.filter.words': [],
+    'output.filter.maskStyle': 'asterisks',
+    'output.filter.replacements': [],
+    'pdf.header': '{title}',
+    'pdf.footer': '{date}',
+    'pdf.speakerStyle': 'bold',
+    'pdf.timestamps': true,
+    'pdf.pageNumbers': 'page-of-total',
+    'pdf.pageSize': 'A4',
+    'pdf.fontSize': 11,
     'ui.sidebarCollapsed': false,
     // Issue #87 — user-facing escape valve for backdrop-blur CPU/GPU cost.
     // Default true preserves the iOS-glass design; users can opt out per
@@ -516,6 +753,7 @@ const store = new Store({
     'server.https': false,
     'server.hfToken': '',
     'server.hfTokenDecision': 'unset',
+    'server.storageMasterKeySealed': '',
     'server.containerExistsLastSeen': false,
     'updates.lastStatus': null,
     'updates.lastNotified': { appLatest: '', serverLatest: '' },
@@ -533,14 +771,29 @@ const store = new Store({
     // Default false keeps behaviour unchanged for existing users. When true,
     // the dashboard uses the `-legacy` GHCR repo for list/pull/tag operations.
     'server.useLegacyGpu': false,
+    // Windows only: 'wsl' runs the container with Docker Engine inside
+    // `server.wslDistro` (wslBackend.ts) instead of Docker Desktop.
+    'server.containerRuntime': 'auto',
+    'server.wslDistro': '',
+    // Stop the local container after this many idle minutes (idleManager.ts).
+    'server.idleStop.enabled': false,
+    'server.idleStop.minutes': DEFAULT_IDLE_MINUTES,
+    // Server containers to run with the GPU profile, one per GPU (dockerManager.ts).
+    'server.replicas': 1,
     'server.mainModelSelection': 'nvidia/parakeet-tdt-0.6b-v3',
     'server.liveModelSelection': 'Systran/faster-whisper-medium',
     'server.diarizationModelSelection': 'pyannote/speaker-diarization-community-1',
+    // Foot pedal bindings (src/services/hidPedal.ts): { deviceKey, button, action }[].
+    'hidPedal.bindings': [],
+    'hidPedal.rewindSeconds': 5,
+    'hidPedal.backstepSeconds': 1,
     'shortcuts.startRecording': 'Alt+Ctrl+Z',
     'shortcuts.stopTranscribe': 'Alt+Ctrl+X',
     'app.pasteAtCursor': false,
     'app.cumulativeUsageMs': 0,
     'app.starPopupShown': false,
+    'app.sampleJobOffered': false,
+    'app.networkTrace': false,
     'folderWatch.sessionPath': '',
     'folderWatch.notebookPath': '',
     'folderWatch.sessionWatchActive': false,
@@ -549,6 +802,36 @@ const store = new Store({
     // the import queue on the interactive modal. 'create_new' | 'ask'.
     // Default 'create_new': unattended batch never stalls and never drops a file.
     'folderWatch.duplicatePolicy': 'create_new',
+    // LAN upload page for phones (phoneIngest.ts); off until the user opts in.
+    'phoneIngest.enabled': false,
+    'phoneIngest.port': 9787,
+    // Cache location ('' = the platform cache dir) and per-category quotas in
+    // MB (cacheManager.ts); categories without an entry use their default.
+    'cache.directory': '',
+    'cache.quotasMb': {},
+    // Batch import uploads (src/services/uploadPipeline.ts); 0 Mbit/s = unlimited.
+    'upload.parallelStreams': 3,
+    'upload.bandwidthLimitMbps': 0,
+    // Read-only live transcript page for meeting participants (followAlong.ts).
+    'followAlong.port': 9788,
+    // Live caption mirrors for streaming (captionOutputs.ts).
+    'captions.textFile.enabled': false,
+    'captions.textFile.path': '',
+    'captions.obs.enabled': false,
+    'captions.obs.url': 'ws://127.0.0.1:4455',
+    'captions.obs.password': '',
+    'captions.obs.inputName': 'TranscriptionSuite Captions',
+    // Live Mode keyword alerts — plain phrases or /regex/, matched server-side.
+    'live.watchKeywords': [],
+    // Live Mode streaming: 'fast' partial hypotheses (captions) or 'accurate' finals (notes).
+    'live.streamingMode': 'accurate',
+    // User scripts run on lifecycle events (scriptHooks.ts): { id, event, command, enabled }[].
+    'hooks.scripts': [],
+    'hooks.timeoutSeconds': HOOK_DEFAULT_TIMEOUT_SECONDS,
+    // Session imports: keep the upload on the server for re-download; the
+    // copies kept so far (src/services/serverCopies.ts).
+    'sessionImport.keepServerCopy': false,
+    'sessionImport.serverCopies': [],
   },
 });
 
@@ -558,6 +841,21 @@ for (const key of ['connection.port', 'server.port'] as const) {
   if (store.has(key) && store.get(key) === 8000) store.set(key, 9786);
 }
 
+if (demoMode) {
+  store.set('connection.useRemote', false);
+  store.set('connection.localHost', 'localhost');
+  store.set('connection.port', DEMO_SERVER_PORT);
+  store.set('connection.useHttps', false);
+}
+let demoServer: DemoServer | null = null;
+
+// HTTP/2 off for the active connection profile forces the renderer's requests
+// onto HTTP/1.1 (some proxies in front of remote servers mishandle h2). Like
+// the feature flags above, Chromium only reads this before app.whenReady().
+if (!getConnectionTuning(store).http2) {
+  app.commandLine.appendSwitch('disable-http2');
+}
+
 // ─── Tray Manager ───────────────────────────────────────────────────────────
 
 const trayManager = new TrayManager(isDev, () => mainWindow);
@@ -573,6 +871,181 @@ const mlxLogSink: MlxLogSink = createMlxLogSink({
 
 const mlxServerManager = new MLXServerManager(() => mainWindow ?? null, mlxLogSink);
 
+// Docker-free server: managed Python env under <data>/backend (backendBootstrap.ts).
+const nativeBackendManager = new NativeBackendManager(
+  () => mainWindow ?? null,
+  {
+    dataDir: appPaths.data,
+    cacheDir: appPaths.cache,
+    sourceDir: app.isPackaged
+      ? path.join(process.resourcesPath, 'backend-src')
+      : path.resolve(__dirname, '../../server/backend'),
+    configYaml: app.isPackaged
+      ? path.join(process.resourcesPath, 'config.yaml')
+      : path.resolve(__dirname, '../../server/config.yaml'),
+    appVersion: app.getVersion(),
+    uvLookup: {
+      platform: process.platform,
+      env: process.env,
+      resourcesPath: app.isPackaged ? process.resourcesPath : undefined,
+    },
+  },
+  () => startupSequencer.noteActivity(),
+);
+
+// ─── WSL Backend ────────────────────────────────────────────────────────────
+// Docker Engine inside a WSL2 distro. When selected, the docker:* handlers
+// below that manage the server container route here instead of dockerManager.
+
+const wslBackendManager = new WslBackendManager({
+  runWsl: async (args, timeoutMs = 120_000) => {
+    try {
+      const { stdout } = await execFileAsync('wsl.exe', args, {
+        encoding: 'buffer',
+        env: { ...process.env, WSL_UTF8: '1' },
+        timeout: timeoutMs,
+        maxBuffer: 10 * 1024 * 1024,
+        windowsHide: true,
+      });
+      return decodeWslOutput(stdout);
+    } catch (err: any) {
+      const stderr = Buffer.isBuffer(err?.stderr) ? decodeWslOutput(err.stderr).trim() : '';
+      throw new Error(stderr || err?.message || 'wsl.exe failed');
+    }
+  },
+  canConnect: (host, port) =>
+    new Promise((resolve) => {
+      const socket = net.connect({ host, port });
+      const done = (ok: boolean) => {
+        socket.destroy();
+        resolve(ok);
+      };
+      socket.setTimeout(1500, () => done(false));
+      socket.once('connect', () => done(true));
+      socket.once('error', () => done(false));
+    }),
+  onStatus: (status) => broadcastToWindows('wsl:containerStatus', status),
+});
+
+function useWslRuntime(): boolean {
+  return process.platform === 'win32' && store.get('server.containerRuntime') === 'wsl';
+}
+
+function wslDistro(): string {
+  return (store.get('server.wslDistro') as string) ?? '';
+}
+
+function wslImageRepo(): string {
+  return resolveImageRepo(readUseLegacyGpuFromStore(), readRuntimeProfileFromStore());
+}
+
+async function startWslContainer(options: StartContainerOptions): Promise<string> {
+  const profile = options.runtimeProfile;
+  if (profile !== 'gpu' && profile !== 'cpu') {
+    throw new Error('Docker in WSL supports the CUDA and CPU runtime profiles only.');
+  }
+  const distro = wslDistro();
+  const legacyGpu = readUseLegacyGpuFromStore();
+  const port = (store.get('server.port') as number) ?? 9786;
+  let userConfigDir: string | undefined;
+  try {
+    userConfigDir = await wslBackendManager.toDistroPath(distro, getServerConfigDir());
+  } catch (err) {
+    console.warn('[WSL] Could not translate the server config dir; using defaults:', err);
+  }
+  const id = await wslBackendManager.start(distro, {
+    image: `${resolveImageRepo(legacyGpu, profile)}:${options.imageTag ?? 'latest'}`,
+    containerName: CONTAINER_NAME,
+    port,
+    gpu: profile === 'gpu',
+    userConfigDir,
+    env: {
+      LOG_LEVEL: 'INFO',
+      HF_TOKEN: options.hfToken ?? '',
+      PYTORCH_VARIANT: profile === 'cpu' ? 'cpu' : legacyGpu ? 'cu126' : 'cu129',
+      MAIN_TRANSCRIBER_MODEL: options.mainTranscriberModel ?? '',
+      LIVE_TRANSCRIBER_MODEL: options.liveTranscriberModel ?? '',
+      DIARIZATION_MODEL: options.diarizationModel ?? '',
+      SENSEVOICE_DIARIZATION_ENGINE: options.sensevoiceDiarizationEngine ?? 'funasr',
+      INSTALL_WHISPER: String(options.installWhisper ?? false),
+      INSTALL_NEMO: String(options.installNemo ?? false),
+      INSTALL_VIBEVOICE_ASR: String(options.installVibeVoiceAsr ?? false),
+      INSTALL_FUNASR: String(options.installFunasr ?? false),
+      LM_STUDIO_URL: 'http://host.docker.internal:1234',
+    },
+  });
+
+  // Point the dashboard at the distro's address when WSL does not forward
+  // localhost — unless the user configured a host of their own.
+  const host = await wslBackendManager.resolveHost(distro, port);
+  const current = (store.get('connection.localHost') as string) ?? 'localhost';
+  const previous = store.get('server.wslResolvedHost') as string | undefined;
+  if (current === 'localhost' || current === previous) {
+    store.set('connection.localHost', host);
+    store.set('server.wslResolvedHost', host);
+  }
+  return id;
+}
+
+// ─── Startup Sequencer ──────────────────────────────────────────────────────
+// Every local start path (docker:startContainer, mlx:start,
+// nativeBackend:start) runs through the sequencer, which only reports the
+// server usable once /ready answers 200 and the version checks out. A stage
+// with no progress for too long fails with a report written to the logs dir
+// — the renderer shows it instead of sitting on "Starting…".
+
+const STARTUP_REPORT_FILE = 'startup-report.txt';
+
+async function collectStartupDiagnostics(source: string | null): Promise<string> {
+  const tail = (lines: string[]) => lines.slice(-40).join('\n');
+  if (source === 'mlx') return tail(mlxServerManager.getLogs(40));
+  if (source === 'native') return tail(nativeBackendManager.getLogs(40));
+  if (source === 'wsl') {
+    const status = await wslBackendManager.getContainerStatus(wslDistro(), CONTAINER_NAME);
+    const logs = await wslBackendManager.getLogs(wslDistro(), CONTAINER_NAME, 40);
+    return `WSL distro: ${wslDistro()}\nContainer: ${JSON.stringify(status)}\n\n${tail(logs)}`;
+  }
+  const status = await dockerManager.getContainerStatus().catch(() => null);
+  const logs = await dockerManager.getLogs(40).catch(() => [] as string[]);
+  return `Container: ${JSON.stringify(status)}\n\n${tail(logs)}`;
+}
+
+const startupSequencer: StartupSequencer = new StartupSequencer({
+  serverUrl: () => getServerUrl(store),
+  fetchJson: async (url, timeoutMs) => {
+    const token = getAuthToken(store);
+    const headers: Record<string, string> = { Accept: 'application/json' };
+    if (token) headers.Authorization = `Bearer ${token}`;
+    const resp = await fetch(url, { headers, signal: AbortSignal.timeout(timeoutMs) });
+    return { status: resp.status, body: await resp.json().catch(() => null) };
+  },
+  checkVersion: (serverVersion) => {
+    const appMajor = semver.major(app.getVersion());
+    const serverMajor = serverVersion ? semver.coerce(serverVersion)?.major : undefined;
+    if (serverMajor === undefined) return 'Server did not report its version';
+    return serverMajor === appMajor
+      ? null
+      : `Server ${serverVersion} may not be compatible with app ${app.getVersion()}`;
+  },
+  collectDiagnostics: () => collectStartupDiagnostics(startupSequencer.getState().source),
+  onState: (state: StartupState) => {
+    broadcastToWindows('startup:stateChanged', state);
+    if (state.status === 'ready' && state.source === 'docker') {
+      // Replicas wait for the main server so only it migrates the database.
+      dockerManager.startReplicas().catch((err) => {
+        console.warn('[Startup] Could not start server replicas:', err?.message ?? err);
+      });
+    }
+    if (state.status === 'failed' && state.report) {
+      const file = path.join(appPaths.logs, STARTUP_REPORT_FILE);
+      fs.promises
+        .mkdir(appPaths.logs, { recursive: true })
+        .then(() => fs.promises.writeFile(file, formatStartupReport(state.report!)))
+        .catch((err) => console.warn('[Startup] Could not write startup report:', err));
+    }
+  },
+});
+
 // ─── Update Manager ─────────────────────────────────────────────────────────
 
 const updateManager = new UpdateManager(store);
@@ -630,7 +1103,7 @@ const updateInstaller = new UpdateInstaller(undefined, undefined, {
     const result = await cachePreviousInstaller({
       sourcePath: process.env.APPIMAGE,
       version: ctx.version,
-      userDataDir: app.getPath('userData'),
+      userDataDir: appPaths.cache,
     });
     if (!result.ok) {
       console.warn(
@@ -748,7 +1221,7 @@ const compatGuard = new CompatGuard({ store });
 const launchWatchdog = new LaunchWatchdog(store);
 
 // Wire tray context-menu actions → IPC messages to the renderer
-trayManager.setActions({
+const trayActions = {
   startRecording: () => {
     mainWindow?.webContents.send('tray:action', 'start-recording');
   },
@@ -791,8 +1264,132 @@ trayManager.setActions({
       }
     }
   },
+};
+trayManager.setActions(trayActions);
+
+// ─── Command Palette Actions (actionRegistry.ts) ────────────────────────────
+
+const actionRegistry = new ActionRegistry({
+  accelerator: (id) =>
+    resolveKeymap(store, process.platform).find((entry) => entry.id === id)?.accelerator ?? null,
 });
 
+/** Bring the window forward and hand a renderer-side action to it. */
+function runInWindow(id: string): void {
+  if (!mainWindow) throw new Error('The app window is not open');
+  if (!mainWindow.isVisible()) mainWindow.show();
+  mainWindow.focus();
+  mainWindow.webContents.send('actions:run', id);
+}
+
+const menu = () => trayManager.getMenuState();
+const needsServer = () => (menu().isStandby ? true : 'The server is not ready');
+
+actionRegistry.register(
+  {
+    id: 'recording.start',
+    title: 'Start recording',
+    category: 'Recording',
+    keywords: ['dictate', 'microphone'],
+    shortcut: 'start-recording',
+    enabled: () => {
+      const { isRecording, isLive } = menu();
+      if (isRecording) return 'Already recording';
+      if (isLive) return 'Live mode is on';
+      return needsServer();
+    },
+    run: trayActions.startRecording,
+  },
+  {
+    id: 'recording.stop',
+    title: 'Stop recording and transcribe',
+    category: 'Recording',
+    shortcut: 'stop-recording',
+    enabled: () => (menu().isRecording && !menu().isLive ? true : 'Not recording'),
+    run: trayActions.stopRecording,
+  },
+  {
+    id: 'recording.cancel',
+    title: 'Cancel recording',
+    category: 'Recording',
+    keywords: ['discard', 'abort'],
+    enabled: () => (menu().canCancel ? true : 'Nothing to cancel'),
+    run: trayActions.cancelRecording,
+  },
+  {
+    id: 'recording.toggleMute',
+    title: 'Mute / unmute microphone',
+    category: 'Recording',
+    enabled: () => (menu().isRecording || menu().isLive ? true : 'Not recording'),
+    run: () => (menu().isLive ? trayActions.toggleLiveMute() : trayActions.toggleMute()),
+  },
+  {
+    id: 'live.start',
+    title: 'Start live mode',
+    category: 'Live Mode',
+    keywords: ['realtime', 'streaming'],
+    enabled: () => (menu().isLive ? 'Live mode is already on' : needsServer()),
+    run: trayActions.startLiveMode,
+  },
+  {
+    id: 'live.stop',
+    title: 'Stop live mode',
+    category: 'Live Mode',
+    enabled: () => (menu().isLive ? true : 'Live mode is off'),
+    run: trayActions.stopLiveMode,
+  },
+  {
+    id: 'files.transcribe',
+    title: 'Transcribe a file…',
+    category: 'Files',
+    keywords: ['import', 'upload', 'audio'],
+    enabled: () => (menu().canTranscribeFile ? true : 'The server is busy or not ready'),
+    run: trayActions.transcribeFile,
+  },
+  {
+    id: 'server.toggleModels',
+    title: 'Unload / reload models',
+    category: 'Server',
+    keywords: ['vram', 'gpu', 'memory'],
+    enabled: () => (menu().isLocalConnection ? needsServer() : 'Only for a local server'),
+    run: trayActions.toggleModels,
+  },
+  {
+    id: 'app.checkUpdates',
+    title: 'Check for updates',
+    category: 'App',
+    run: async () => {
+      await updateManager.check();
+    },
+  },
+  {
+    id: 'settings.open',
+    title: 'Open settings',
+    category: 'App',
+    keywords: ['preferences', 'options'],
+    shortcut: 'settings.open',
+    run: () => runInWindow('settings.open'),
+  },
+  ...(
+    [
+      ['session', 'Session', ['record', 'transcribe']],
+      ['notebook', 'Notebook', ['calendar', 'search', 'recordings']],
+      ['server', 'Server', ['docker', 'models']],
+      ['logs', 'Logs', ['debug']],
+      ['notifications', 'Notifications', ['history', 'alerts']],
+    ] as const
+  ).map(([view, label, keywords]) => ({
+    id: `navigate.${view}`,
+    title: `Go to ${label}`,
+    category: 'Navigation' as const,
+    keywords: [...keywords],
+    run: () => runInWindow(`navigate.${view}`),
+  })),
+);
+
+ipcMain.handle('actions:list', () => actionRegistry.list());
+ipcMain.handle('actions:invoke', (_event, id: string) => actionRegistry.invoke(id));
+
 // ─── Window Creation ────────────────────────────────────────────────────────
 
 function createWindow(): void {
@@ -913,8 +1510,23 @@ function createWindow(): void {
 
 // ─── IPC Handlers ───────────────────────────────────────────────────────────
 
-// Microtask coalescing flag for shortcut re-registration (see config:set handler).
-let shortcutReregisterScheduled = false;
+// Subsystems reconfigure live through the config bus (configBus.ts) instead of
+// on restart; each subscribes next to where it is created. Windows get the
+// changed keys as `config:changed` and failed reloads as `config:reloadFailed`.
+const configBus = new ConfigBus({
+  onError: (subscriber, err) =>
+    broadcastToWindows('config:reloadFailed', {
+      subscriber,
+      message: err instanceof Error ? err.message : String(err),
+    }),
+});
+configBus.subscribe('windows', '', (keys) => broadcastToWindows('config:changed', keys));
+configBus.subscribe('updates', 'app.updateCheck', () => updateManager.reconfigure());
+// The bus delivers a burst of shortcuts.* writes as one call; the serialization
+// guard in registerShortcuts() still protects against concurrent D-Bus sessions.
+configBus.subscribe('shortcuts', 'shortcuts.', () =>
+  registerShortcuts(store, () => mainWindow).then(() => undefined),
+);
 
 // Config: get/set client settings via electron-store
 // Use store.has() so that unset keys return null rather than the electron-store
@@ -928,26 +1540,7 @@ ipcMain.handle('config:get', async (_event, key: string) => {
 
 ipcMain.handle('config:set', async (_event, key: string, value: unknown) => {
   store.set(key, value);
-  // Reconfigure update manager when update-related settings change
-  if (key.startsWith('app.updateCheck')) {
-    updateManager.reconfigure();
-  }
-  // Re-register shortcuts when accelerators change.
-  // Best-effort coalescing: if multiple shortcuts.* writes land in the same
-  // microtask checkpoint they merge into one call.  IPC handlers may run on
-  // separate event-loop turns, so the serialization guard in registerShortcuts()
-  // is the primary protection against concurrent D-Bus sessions.
-  if (key.startsWith('shortcuts.')) {
-    if (!shortcutReregisterScheduled) {
-      shortcutReregisterScheduled = true;
-      queueMicrotask(() => {
-        shortcutReregisterScheduled = false;
-        registerShortcuts(store, () => mainWindow).catch((err) =>
-          console.warn('[Shortcuts] Re-registration failed:', err),
-        );
-      });
-    }
-  }
+  configBus.publish(key);
 });
 
 ipcMain.handle('config:getAll', async () => {
@@ -974,6 +1567,21 @@ ipcMain.handle('app:getConfigDir', () => {
   return app.getPath('userData');
 });
 
+ipcMain.handle('app:getPortableRoot', () => {
+  return portableRoot;
+});
+
+ipcMain.handle('app:getDataPaths', () => {
+  return appPaths;
+});
+
+ipcMain.handle('app:openDataDir', async (_event, kind: AppPathKind = 'data') => {
+  const dir = appPaths[kind];
+  if (!dir) return `Unknown data directory '${String(kind)}'`;
+  fs.mkdirSync(dir, { recursive: true });
+  return shell.openPath(dir);
+});
+
 // Dedicated dir that actually holds the server's config.yaml (a subdir of
 // userData). Distinct from app:getConfigDir, which returns the userData root
 // used for native data/model storage labels.
@@ -1068,9 +1676,16 @@ ipcMain.handle('app:removeConfigAndCache', async () => {
     session.defaultSession.clearAuthCache(),
   ]);
 
+  // Portable installs keep no cache outside their data dir — and must not
+  // wipe the cache of a regular install on the same machine.
   await Promise.all([
     fs.promises.rm(userDataDir, { recursive: true, force: true }),
-    fs.promises.rm(externalCacheDir, { recursive: true, force: true }),
+    portableRoot ? null : fs.promises.rm(externalCacheDir, { recursive: true, force: true }),
+    ...(portableRoot
+      ? []
+      : [appPaths.data, appPaths.cache, appPaths.logs]
+          .filter((dir) => dir !== userDataDir && dir !== externalCacheDir)
+          .map((dir) => fs.promises.rm(dir, { recursive: true, force: true }))),
   ]);
 
   // Do NOT recreate userDataDir here — ensureClientLogFilePath() already calls
@@ -1091,7 +1706,7 @@ ipcMain.handle('app:appendClientLogLine', async (_event, line: string) => {
 });
 
 ipcMain.handle('app:readLogFiles', async (_event, tailLines: number) => {
-  const logDir = path.join(app.getPath('userData'), 'logs');
+  const logDir = appPaths.logs;
   const clientLogPath = path.join(logDir, 'client-debug.log');
   const serverLogPath = path.join(logDir, 'server.log');
 
@@ -1113,32 +1728,400 @@ ipcMain.handle('app:readLogFiles', async (_event, tailLines: number) => {
   };
 });
 
-ipcMain.handle('app:readLocalFile', async (_event, filePath: string) => {
-  const buffer = fs.readFileSync(filePath);
-  const name = path.basename(filePath);
+const LOCAL_AUDIO_MIME: Record<string, string> = {
+  mp3: 'audio/mpeg',
+  wav: 'audio/wav',
+  m4a: 'audio/mp4',
+  flac: 'audio/flac',
+  ogg: 'audio/ogg',
+  webm: 'audio/webm',
+  opus: 'audio/opus',
+};
+
+function localAudioMime(filePath: string): string {
   const ext = path.extname(filePath).toLowerCase().slice(1);
-  const mimeMap: Record<string, string> = {
-    mp3: 'audio/mpeg',
-    wav: 'audio/wav',
-    m4a: 'audio/mp4',
-    flac: 'audio/flac',
-    ogg: 'audio/ogg',
-    webm: 'audio/webm',
-    opus: 'audio/opus',
+  return LOCAL_AUDIO_MIME[ext] || 'audio/mpeg';
+}
+
+ipcMain.handle('app:readLocalFile', async (_event, filePath: string) => {
+  const buffer = await fs.promises.readFile(filePath);
+  return {
+    name: path.basename(filePath),
+    buffer: buffer.buffer,
+    mimeType: localAudioMime(filePath),
   };
-  return { name, buffer: buffer.buffer, mimeType: mimeMap[ext] || 'audio/mpeg' };
 });
 
+ipcMain.handle('app:statLocalFile', async (_event, filePath: string) => {
+  const stats = await fs.promises.stat(filePath);
+  return { name: path.basename(filePath), size: stats.size };
+});
+
+// Largest range the renderer may read at once — uploads read one chunk at a
+// time so a 10 GB watched video never crosses IPC, or sits in memory, whole.
+const MAX_LOCAL_READ_BYTES = 16 * 1024 * 1024;
+
+ipcMain.handle(
+  'app:readLocalFileRange',
+  async (_event, filePath: string, offset: number, length: number) => {
+    const size = Math.max(0, Math.min(length, MAX_LOCAL_READ_BYTES));
+    const handle = await fs.promises.open(filePath, 'r');
+    try {
+      const buffer = Buffer.allocUnsafe(size);
+      const { bytesRead } = await handle.read(buffer, 0, size, offset);
+      return buffer.buffer.slice(buffer.byteOffset, buffer.byteOffset + bytesRead);
+    } finally {
+      await handle.close();
+    }
+  },
+);
+
 // ─── File I/O IPC (Session Import) ─────────────────────────────────────────
 
 ipcMain.handle('app:getDownloadsPath', () => {
   return app.getPath('downloads');
 });
 
+// Paths the user picked in dialog:saveFile. Writing one is an export and
+// fires the on_export script hook (scriptHooks.ts).
+const exportTargets = new Set<string>();
+
+function exported(resolved: string): void {
+  if (!exportTargets.delete(resolved)) return;
+  void scriptHooks.fire('on_export', {
+    path: resolved,
+    format: path.extname(resolved).slice(1).toLowerCase(),
+  });
+}
+
 ipcMain.handle('file:writeText', async (_event, filePath: string, content: string) => {
   // Validate the path is under a user-accessible directory (no path traversal)
   const resolved = path.resolve(filePath);
   await fs.promises.writeFile(resolved, content, 'utf-8');
+  exported(resolved);
+});
+
+ipcMain.handle(
+  'file:exportPdf',
+  async (_event, html: string, filePath: string, opts: PdfExportOptions) => {
+    const resolved = path.resolve(filePath);
+    const result = await exportHtmlToPdf(html, resolved, opts);
+    exported(resolved);
+    return result;
+  },
+);
+
+// ─── Live Caption Overlay ───────────────────────────────────────────────────
+
+const captionOverlay = new CaptionOverlay({
+  createWindow: ({ bounds }) =>
+    new BrowserWindow({
+      ...bounds,
+      show: false,
+      frame: false,
+      transparent: true,
+      resizable: false,
+      movable: false,
+      focusable: false,
+      skipTaskbar: true,
+      hasShadow: false,
+      alwaysOnTop: true,
+      webPreferences: {
+        // Static page with one inline helper — no preload, no node.
+        sandbox: true,
+        contextIsolation: true,
+        nodeIntegration: false,
+      },
+    }),
+  getWorkArea: () => screen.getPrimaryDisplay().workArea,
+  onVisibilityChanged: (visible) => broadcastToWindows('captions:visibilityChanged', visible),
+});
+
+ipcMain.handle('captions:show', async (_event, position?: CaptionPosition) => {
+  await captionOverlay.show(position === 'top' ? 'top' : 'bottom');
+});
+
+ipcMain.handle('captions:hide', () => {
+  captionOverlay.hide();
+});
+
+ipcMain.handle('captions:isVisible', () => captionOverlay.isVisible());
+
+// Text-file and OBS mirrors of the same captions. Node's built-in WebSocket
+// client carries the obs-websocket connection.
+const captionOutputs = new CaptionOutputs({
+  createSocket: (url, handlers) => {
+    const ws = new WebSocket(url);
+    ws.addEventListener('message', (event) => handlers.onMessage(String(event.data)));
+    ws.addEventListener('close', () => handlers.onClose());
+    return { send: (data) => ws.send(data), close: () => ws.close() };
+  },
+  log: (message) => console.warn(`[Captions] ${message}`),
+});
+captionOutputs.configure(readCaptionOutputSettings(store));
+configBus.subscribe('captions', 'captions.', () =>
+  captionOutputs.configure(readCaptionOutputSettings(store)),
+);
+
+// Fire-and-forget: sent on every partial, so no invoke round-trip.
+ipcMain.on('captions:update', (_event, payload: CaptionPayload) => {
+  if (!payload || !Array.isArray(payload.lines) || typeof payload.partial !== 'string') return;
+  void captionOverlay.update(payload);
+  captionOutputs.update(payload);
+});
+
+// ─── Cache (downloads, phone uploads, extracted media, waveform peaks) ──────
+
+const cacheManager = new CacheManager({ store, defaultRoot: appPaths.cache });
+void cacheManager.evictAll().catch((err) => console.warn('[Cache] Eviction failed:', err));
+
+ipcMain.handle('cache:getUsage', () => cacheManager.usage());
+
+ipcMain.handle('cache:clear', async (_event, category?: string) => {
+  if (category !== undefined && !isCacheCategory(category)) {
+    throw new Error(`Unknown cache category: ${category}`);
+  }
+  await cacheManager.clear(category);
+  return cacheManager.usage();
+});
+
+ipcMain.handle('cache:setQuota', async (_event, category: string, mb: number) => {
+  if (!isCacheCategory(category)) throw new Error(`Unknown cache category: ${category}`);
+  cacheManager.setQuota(category, mb);
+  await cacheManager.evict(category);
+  return cacheManager.usage();
+});
+
+ipcMain.handle('cache:setDirectory', async (_event, dir: string | null) => {
+  await cacheManager.setRoot(dir);
+  return cacheManager.usage();
+});
+
+// ─── Plugins (WebAssembly post-processors and exporters) ────────────────────
+
+const pluginHost = new PluginHost(path.join(appPaths.data, 'plugins'), store);
+
+async function installPlugin(): Promise<PluginInfo | null> {
+  const result = await dialog.showOpenDialog(BrowserWindow.getAllWindows()[0], {
+    title: 'Choose the plugin.json of the plugin to install',
+    properties: ['openFile'],
+    filters: [{ name: 'Plugin manifest', extensions: ['json'] }],
+  });
+  if (result.canceled || result.filePaths.length === 0) return null;
+  if (path.basename(result.filePaths[0]) !== PLUGIN_MANIFEST) {
+    throw new Error(`Choose the plugin's ${PLUGIN_MANIFEST}`);
+  }
+  const plugin = await pluginHost.install(result.filePaths[0]);
+  broadcastToWindows('plugins:changed', pluginHost.list());
+  return plugin;
+}
+
+ipcMain.handle('plugins:list', () => pluginHost.list());
+ipcMain.handle('plugins:install', () => installPlugin());
+ipcMain.handle('plugins:remove', async (_event, id: string) => {
+  await pluginHost.remove(id);
+  broadcastToWindows('plugins:changed', pluginHost.list());
+});
+ipcMain.handle('plugins:setEnabled', (_event, id: string, enabled: boolean) => {
+  pluginHost.setEnabled(id, enabled);
+  broadcastToWindows('plugins:changed', pluginHost.list());
+});
+ipcMain.handle('plugins:run', (_event, id: string, transcript: PluginTranscript) =>
+  pluginHost.run(id, transcript),
+);
+
+actionRegistry.register({
+  id: 'plugins.install',
+  title: 'Install a plugin…',
+  category: 'App',
+  keywords: ['wasm', 'exporter', 'post-processor', 'extension'],
+  run: async () => {
+    await installPlugin();
+  },
+});
+
+// ─── Script Hooks (user commands on lifecycle events) ───────────────────────
+
+const scriptHooks = new ScriptHooks({
+  store,
+  logDir: appPaths.logs,
+  onRun: (run) => broadcastToWindows('hooks:ran', run),
+});
+
+ipcMain.handle('hooks:history', () => scriptHooks.history());
+ipcMain.handle('hooks:test', (_event, id: string) => scriptHooks.test(id));
+// on_export is fired here, from writes to a save-dialog target (file:writeText).
+ipcMain.handle('hooks:fire', (_event, event: HookEvent, payload: Record<string, unknown>) => {
+  if (!isHookEvent(event) || event === 'on_export') throw new Error(`Unknown hook "${event}"`);
+  void scriptHooks.fire(event, payload);
+});
+
+// ─── Kept Import Media ──────────────────────────────────────────────────────
+// Session imports sent with "Keep server copy" can be fetched again later;
+// the file only lands at its destination once its SHA-256 checks out.
+
+ipcMain.handle(
+  'jobMedia:download',
+  (_event, jobId: string, destination: string, sha256: string) =>
+    downloadJobMedia({
+      serverUrl: getServerUrl(store),
+      token: getAuthToken(store),
+      jobId,
+      destination: path.resolve(destination),
+      expectedSha256: sha256,
+    }),
+);
+
+// ─── Time-Range / Channel Transcription ─────────────────────────────────────
+// Only the chosen span and channels of a long recording are cut out locally
+// and uploaded; the renderer shifts the transcript back onto the recording's
+// timeline.
+
+ipcMain.handle('mediaClip:selectSource', async () => {
+  const result = await dialog.showOpenDialog(BrowserWindow.getAllWindows()[0], {
+    title: 'Select Recording',
+    filters: [
+      {
+        name: 'Audio and Video',
+        extensions: ['mp3', 'wav', 'm4a', 'flac', 'ogg', 'webm', 'opus', 'mp4', 'mkv', 'mov'],
+      },
+    ],
+    properties: ['openFile'],
+  });
+  return result.canceled || result.filePaths.length === 0 ? null : result.filePaths[0];
+});
+
+ipcMain.handle('mediaClip:probeChannels', (_event, source: string) =>
+  probeChannels(path.resolve(source)),
+);
+
+ipcMain.handle('mediaClip:create', (_event, source: string, edit: MediaEdit, key: string) =>
+  clipMedia({ source: path.resolve(source), ...edit, key }),
+);
+
+ipcMain.handle('mediaClip:remove', (_event, clipPath: string) => removeClip(clipPath));
+
+// ─── Cloud Import (S3 / Google Drive / Dropbox) ─────────────────────────────
+
+// Getters, so a cache location change applies to the next download.
+const cloudImport = new CloudImport({
+  store,
+  safeStorage,
+  get cacheDir() {
+    return cacheManager.root;
+  },
+  openExternal: (url) => shell.openExternal(url),
+});
+
+ipcMain.handle('cloud:listSources', () => cloudImport.listSources());
+
+ipcMain.handle(
+  'cloud:saveSource',
+  (_event, source: Omit<CloudSource, 'id'> & { id?: string }, secret?: CloudSourceSecret) =>
+    cloudImport.saveSource(source, secret),
+);
+
+ipcMain.handle('cloud:removeSource', (_event, id: string) => cloudImport.removeSource(id));
+
+ipcMain.handle('cloud:connect', (_event, id: string) => cloudImport.connect(id));
+
+ipcMain.handle('cloud:browse', (_event, id: string, folderId?: string) =>
+  cloudImport.browse(id, folderId),
+);
+
+// Downloads run one at a time so progress reads top to bottom; each finished
+// path is returned for the renderer to hand to the import queue.
+ipcMain.handle('cloud:download', async (_event, id: string, entries: CloudEntry[]) => {
+  const paths: string[] = [];
+  for (const entry of entries) {
+    paths.push(
+      await cloudImport.download(id, entry, (progress) =>
+        broadcastToWindows('cloud:downloadProgress', progress),
+      ),
+    );
+  }
+  void cacheManager.evict('cloud-import').catch(() => {});
+  return paths;
+});
+
+// ─── Phone Ingest (LAN upload page + QR pairing) ────────────────────────────
+
+const phoneIngest = new PhoneIngestServer({
+  store,
+  get cacheDir() {
+    return cacheManager.root;
+  },
+  onFile: (file) => {
+    broadcastToWindows('phoneIngest:fileReceived', file);
+    void cacheManager.evict('phone-ingest').catch(() => {});
+  },
+});
+
+ipcMain.handle('phoneIngest:getStatus', () => phoneIngest.status());
+
+ipcMain.handle('phoneIngest:setEnabled', async (_event, enabled: boolean, port?: number) => {
+  if (port !== undefined) store.set('phoneIngest.port', port);
+  if (!enabled) {
+    await phoneIngest.stop();
+    store.set('phoneIngest.enabled', false);
+    return phoneIngest.status();
+  }
+  const status = await phoneIngest.start(store.get('phoneIngest.port') as number);
+  store.set('phoneIngest.enabled', true);
+  return status;
+});
+
+ipcMain.handle('phoneIngest:resetPairing', () => phoneIngest.resetPairing());
+ipcMain.handle('phoneIngest:listPending', () => phoneIngest.pending());
+ipcMain.handle('phoneIngest:markImported', async (_event, filePath: string) => {
+  if (typeof filePath !== 'string') return false;
+  const done = await phoneIngest.markImported(filePath);
+  if (done) void cacheManager.evict('phone-ingest').catch(() => {});
+  return done;
+});
+
+// start() re-binds when the port differs; paired phones keep their token.
+configBus.subscribe('phoneIngest', 'phoneIngest.', async () => {
+  if (store.get('phoneIngest.enabled')) {
+    await phoneIngest.start(store.get('phoneIngest.port') as number);
+  } else {
+    await phoneIngest.stop();
+  }
+});
+
+// ─── Live Follow-Along (read-only transcript page on the LAN) ───────────────
+
+const followAlong = new FollowAlongServer();
+
+ipcMain.handle('followAlong:getStatus', () => followAlong.status());
+
+ipcMain.handle('followAlong:start', () =>
+  followAlong.start(store.get('followAlong.port') as number),
+);
+
+ipcMain.handle('followAlong:stop', () => followAlong.stop());
+
+actionRegistry.register({
+  id: 'sharing.stopFollowAlong',
+  title: 'Stop sharing the live transcript',
+  category: 'Sharing',
+  keywords: ['follow along', 'viewers'],
+  enabled: () => (followAlong.status().running ? true : 'Not sharing'),
+  run: () => followAlong.stop(),
+});
+
+// A running share moves to the new port; that starts a new session, so the
+// old link stops working and viewers need the new one.
+configBus.subscribe('followAlong', 'followAlong.port', async () => {
+  if (!followAlong.status().running) return;
+  await followAlong.stop();
+  await followAlong.start(store.get('followAlong.port') as number);
+});
+
+// Fire-and-forget like captions:update — sent on every partial.
+ipcMain.on('followAlong:update', (_event, payload: FollowAlongPayload) => {
+  if (!payload || !Array.isArray(payload.sentences) || typeof payload.partial !== 'string') return;
+  followAlong.update(payload);
 });
 
 ipcMain.handle('dialog:selectFolder', async () => {
@@ -1169,6 +2152,7 @@ ipcMain.handle(
       ],
     });
     if (result.canceled || !result.filePath) return null;
+    exportTargets.add(path.resolve(result.filePath));
     return result.filePath;
   },
 );
@@ -1176,6 +2160,7 @@ ipcMain.handle(
 // ─── Docker Management IPC ──────────────────────────────────────────────────
 
 ipcMain.handle('docker:available', async () => {
+  if (useWslRuntime()) return (await wslBackendManager.probe(wslDistro())).available;
   return dockerManager.dockerAvailable();
 });
 
@@ -1215,6 +2200,7 @@ ipcMain.handle('docker:runGpuDiagnostic', async () => {
 });
 
 ipcMain.handle('docker:listImages', async () => {
+  if (useWslRuntime()) return wslBackendManager.listImages(wslDistro(), wslImageRepo());
   return dockerManager.listImages();
 });
 
@@ -1231,6 +2217,7 @@ ipcMain.handle('docker:fetchRemoteTagDates', async (_event, tags: string[]) => {
 });
 
 ipcMain.handle('docker:pullImage', async (_event, tag: string) => {
+  if (useWslRuntime()) return wslBackendManager.pullImage(wslDistro(), `${wslImageRepo()}:${tag}`);
   return dockerManager.pullImage(tag);
 });
 
@@ -1262,6 +2249,43 @@ ipcMain.handle('whisper:switchModel', async (_event, model: string | null) => {
   return dockerManager.switchWhisperServerModel(model);
 });
 
+// whisper.cpp sidecar tuning for this machine (whispercppTuning.ts). Saved
+// settings apply at the next server start; the benchmark restarts the sidecar
+// once per candidate and leaves it running on the winner.
+function saveWhispercppTuning(tuning: StoredWhispercppTuning): StoredWhispercppTuning {
+  const byMachine = (store.get(WHISPERCPP_TUNING_STORE_KEY) as Record<string, unknown>) ?? {};
+  const next = normalizeWhispercppTuning(tuning);
+  store.set(WHISPERCPP_TUNING_STORE_KEY, { ...byMachine, [machineKey()]: next });
+  return next;
+}
+
+ipcMain.handle('whisper:getTuning', () => {
+  const profile = readRuntimeProfileFromStore();
+  return {
+    available: profile === 'vulkan' || profile === 'vulkan-wsl2',
+    machine: machineKey(),
+    topology: detectCpuTopology(),
+    tuning: tuningForMachine(store.get(WHISPERCPP_TUNING_STORE_KEY)),
+  };
+});
+
+ipcMain.handle('whisper:setTuning', (_event, tuning: WhispercppTuning) => {
+  const { benchmark } = tuningForMachine(store.get(WHISPERCPP_TUNING_STORE_KEY));
+  return saveWhispercppTuning({ ...tuning, benchmark });
+});
+
+ipcMain.handle('whisper:benchmark', async (event) => {
+  const benchmark = await dockerManager.benchmarkWhispercpp((done, total) => {
+    if (!event.sender.isDestroyed()) {
+      event.sender.send('whisper:benchmarkProgress', { done, total });
+    }
+  });
+  const current = tuningForMachine(store.get(WHISPERCPP_TUNING_STORE_KEY));
+  const saved = saveWhispercppTuning({ ...(pickBest(benchmark.runs) ?? current), benchmark });
+  await dockerManager.restartWhispercppSidecar(saved);
+  return saved;
+});
+
 ipcMain.handle('docker:cancelSidecarPull', () => {
   return dockerManager.cancelSidecarPull();
 });
@@ -1275,6 +2299,7 @@ ipcMain.handle('docker:removeImage', async (_event, tag: string) => {
 });
 
 ipcMain.handle('docker:getContainerStatus', async () => {
+  if (useWslRuntime()) return wslBackendManager.getContainerStatus(wslDistro(), CONTAINER_NAME);
   return dockerManager.getContainerStatus();
 });
 
@@ -1285,7 +2310,17 @@ ipcMain.handle('docker:getContainerStatus', async () => {
 const startupEventWatcher = new StartupEventWatcher();
 
 ipcMain.handle('docker:startContainer', async (_event, options: StartContainerOptions) => {
-  const result = await dockerManager.startContainer(options);
+  let result = '';
+  if (useWslRuntime()) {
+    await startupSequencer.run('wsl', async () => {
+      result = await startWslContainer(options);
+    });
+    return result;
+  }
+  const storageMasterKey = getOrCreateStorageMasterKey(store, safeStorage) ?? undefined;
+  await startupSequencer.run('docker', async () => {
+    result = await dockerManager.startContainer({ ...options, storageMasterKey });
+  });
   // Begin writing to server.log as soon as the container is running.
   dockerManager.startBackgroundLogStream();
 
@@ -1293,6 +2328,7 @@ ipcMain.handle('docker:startContainer', async (_event, options: StartContainerOp
   const eventsFile = dockerManager.getStartupEventsFilePath();
   if (eventsFile) {
     startupEventWatcher.start(eventsFile, (event) => {
+      startupSequencer.noteActivity();
       mainWindow?.webContents.send('activity:event', event);
     });
   }
@@ -1302,10 +2338,18 @@ ipcMain.handle('docker:startContainer', async (_event, options: StartContainerOp
 
 ipcMain.handle('docker:stopContainer', async () => {
   startupEventWatcher.stop();
+  startupSequencer.cancel();
+  if (useWslRuntime()) return wslBackendManager.stop(wslDistro(), CONTAINER_NAME);
   return dockerManager.stopContainer();
 });
 
+ipcMain.handle('docker:restartContainer', async () => {
+  if (useWslRuntime()) throw new Error('Restarting the WSL backend is not supported');
+  return dockerManager.restartContainer();
+});
+
 ipcMain.handle('docker:removeContainer', async () => {
+  if (useWslRuntime()) return wslBackendManager.stop(wslDistro(), CONTAINER_NAME);
   return dockerManager.removeContainer();
 });
 
@@ -1403,9 +2447,20 @@ ipcMain.handle(
 );
 
 ipcMain.handle('docker:getLogs', async (_event, tail?: number) => {
+  if (useWslRuntime()) return wslBackendManager.getLogs(wslDistro(), CONTAINER_NAME, tail);
   return dockerManager.getLogs(tail);
 });
 
+// ─── WSL Backend IPC ────────────────────────────────────────────────────────
+
+ipcMain.handle('wsl:listDistros', async () => {
+  return process.platform === 'win32' ? wslBackendManager.listDistros() : [];
+});
+
+ipcMain.handle('wsl:probe', async (_event, distro: string) => {
+  return wslBackendManager.probe(distro);
+});
+
 // ─── Docker Log Streaming IPC ───────────────────────────────────────────────
 
 // Stable callback reference so subscribe and unsubscribe target the same function.
@@ -1626,6 +2681,14 @@ app.on('will-quit', unloadLoopbackModuleSync);
 
 // Kill any lingering wl-copy child on quit.
 app.on('will-quit', cleanupClipboard);
+app.on('will-quit', () => {
+  captionOverlay.destroy();
+  captionOutputs.close();
+  void phoneIngest.stop();
+  void followAlong.stop();
+  connectionQuality.stop();
+  idleManager.stop();
+});
 
 // ─── Clipboard IPC ──────────────────────────────────────────────────────────
 
@@ -1893,7 +2956,7 @@ function tryLoadCertFromConfig(
  *
  * All tiers avoid `rejectUnauthorized: false` → no CodeQL flag.
  */
-function buildLanTlsAgent(): https.Agent {
+function buildLanTlsAgent(options: https.AgentOptions = {}): https.Agent {
   const cfg = readTlsConfig();
 
   // Helper: build a fingerprint-pinned agent from a PEM buffer.
@@ -1901,6 +2964,7 @@ function buildLanTlsAgent(): https.Agent {
     const x509 = new crypto.X509Certificate(certPem);
     const expectedFp = x509.fingerprint256;
     return new https.Agent({
+      ...options,
       ca: [certPem],
       checkServerIdentity: (_host: string, peer: { fingerprint256?: string }) => {
         if (peer.fingerprint256 === expectedFp) return undefined;
@@ -1922,10 +2986,157 @@ function buildLanTlsAgent(): https.Agent {
   // Tier 3: Skip hostname check only — cert chain must still validate
   // against the system CA store (e.g. LE-signed certs pass).
   return new https.Agent({
+    ...options,
     checkServerIdentity: () => undefined,
   });
 }
 
+// A status probe answers in milliseconds; never hang on it for the full read
+// timeout meant for long API calls.
+const PROBE_READ_TIMEOUT_CAP_SECONDS = 30;
+
+// ─── Connection Quality Monitor ─────────────────────────────────────────────
+// Background latency/loss probing of the active profile and the alternative
+// remote path; reports go to every window as `connectionQuality:report`.
+
+// Kept across rounds so probes reuse one TLS session and time the round trip,
+// not a fresh handshake. Rebuilt when keep-alive changes.
+let lanQualityAgent: { agent: https.Agent; keepAlive: boolean } | null = null;
+
+/** Round trip to the profile's /health in ms; null when it does not answer. */
+async function probeProfile(profile: ConnectionProfile): Promise<number | null> {
+  const baseUrl = profileServerUrl(store, profile);
+  if (!baseUrl) return null;
+  const tuning = getConnectionTuning(store);
+  const isHttps = baseUrl.startsWith('https:');
+  let agent: http.Agent = sharedAgent(isHttps, tuning);
+  if (isHttps && profile === 'lan') {
+    if (lanQualityAgent?.keepAlive !== tuning.keepAlive) {
+      lanQualityAgent?.agent.destroy();
+      lanQualityAgent = {
+        agent: buildLanTlsAgent(agentOptions(tuning)),
+        keepAlive: tuning.keepAlive,
+      };
+    }
+    agent = lanQualityAgent.agent;
+  }
+  return probeLatency(baseUrl, agent);
+}
+
+const connectionQuality = new ConnectionQualityMonitor({
+  targets: () => connectionTargets(store),
+  probe: probeProfile,
+  emit: (report) => broadcastToWindows('connectionQuality:report', report),
+});
+
+ipcMain.handle('connectionQuality:getLatest', () => connectionQuality.latest());
+ipcMain.handle('connectionQuality:checkNow', () => connectionQuality.checkNow());
+// New hosts or tuning: probe now rather than at the next interval.
+configBus.subscribe('connectionQuality', 'connection.', () =>
+  connectionQuality.checkNow().then(() => undefined),
+);
+
+// ─── Idle Stop ──────────────────────────────────────────────────────────────
+// Stops the local Docker container after a quiet period and starts it again
+// when the renderer needs it (serverIdle:wake). State goes to every window
+// as `serverIdle:status`.
+
+const IDLE_STOP_TIMEOUT_SECONDS = 30;
+
+/** True while the server runs or queues a job; an unreachable server counts as busy. */
+async function serverQueueBusy(): Promise<boolean> {
+  try {
+    const token = getAuthToken(store);
+    const headers: Record<string, string> = { Accept: 'application/json' };
+    if (token) headers.Authorization = `Bearer ${token}`;
+    const resp = await fetch(`${getServerUrl(store)}/api/transcribe/queue`, {
+      headers,
+      signal: AbortSignal.timeout(10_000),
+    });
+    if (!resp.ok) return true;
+    const body = (await resp.json()) as { active?: unknown; entries?: unknown[] };
+    return Boolean(body.active) || (body.entries?.length ?? 0) > 0;
+  } catch {
+    // Still loading or unreachable — never stop a server mid-startup.
+    return true;
+  }
+}
+
+/** Resolve once the startup sequence after a wake reaches ready, reject if it fails. */
+async function waitForStartupReady(): Promise<void> {
+  for (;;) {
+    const { status, report } = startupSequencer.getState();
+    if (status === 'ready') return;
+    if (status !== 'running') throw new Error(report?.reason ?? 'The server did not come back up');
+    await new Promise((resolve) => setTimeout(resolve, 500));
+  }
+}
+
+const idleManager = new IdleManager({
+  settings: () => ({
+    enabled: store.get('server.idleStop.enabled') === true,
+    minutes: store.get('server.idleStop.minutes') as number,
+  }),
+  manages: () =>
+    store.get('connection.useRemote') !== true &&
+    !useWslRuntime() &&
+    readRuntimeProfileFromStore() !== 'metal',
+  containerRunning: async () => (await dockerManager.getContainerStatus()).running,
+  serverBusy: serverQueueBusy,
+  stop: async () => {
+    startupSequencer.cancel();
+    await dockerManager.forceStopContainer(IDLE_STOP_TIMEOUT_SECONDS);
+  },
+  start: async () => {
+    await startupSequencer.run('docker', () => dockerManager.resumeContainer());
+    dockerManager.startBackgroundLogStream();
+    await waitForStartupReady();
+  },
+  emit: (status) => broadcastToWindows('serverIdle:status', status),
+});
+
+ipcMain.handle('serverIdle:getStatus', () => idleManager.status());
+ipcMain.handle('serverIdle:wake', () => idleManager.wake());
+ipcMain.handle('serverIdle:noteActivity', () => idleManager.noteActivity());
+configBus.subscribe('serverIdle', 'server.idleStop.', () => idleManager.check());
+
+// ─── Remote Power ───────────────────────────────────────────────────────────
+// Wake-on-LAN for a remote server that sleeps, and an optional SSH suspend
+// once the queue this session filled has drained. Status goes to every
+// window as `power:status`.
+
+const remotePower = new RemotePower({
+  settings: () => ({
+    macAddress: (store.get('power.macAddress') as string) ?? '',
+    broadcastAddress: (store.get('power.broadcastAddress') as string) || '255.255.255.255',
+    port: (store.get('power.port') as number) ?? DEFAULT_WOL_PORT,
+    wakeTimeoutSeconds: (store.get('power.wakeTimeoutSeconds') as number) ?? 120,
+    sshTarget: (store.get('power.sshTarget') as string) ?? '',
+    sshCommand: (store.get('power.sshCommand') as string) ?? DEFAULT_SUSPEND_COMMAND,
+  }),
+  probe: async (profile) => (await probeProfile(profile)) !== null,
+  serverBusy: serverQueueBusy,
+  sendPacket: sendUdpBroadcast,
+  runSsh: (target, command) =>
+    new Promise((resolve, reject) => {
+      execFile('ssh', sshArgs(target, command), { timeout: 30_000 }, (err, _stdout, stderr) => {
+        const code = (err as { code?: unknown } | null)?.code;
+        if (err && sshSuspendFailed(typeof code === 'number' ? code : -1, stderr)) {
+          reject(new Error(stderr.trim() || err.message));
+        } else {
+          resolve();
+        }
+      });
+    }),
+  emit: (status) => broadcastToWindows('power:status', status),
+});
+
+ipcMain.handle('power:getStatus', () => remotePower.status());
+ipcMain.handle('power:wake', (_event, profile?: ConnectionProfile) =>
+  remotePower.wake(profile ?? connectionTargets(store).active),
+);
+ipcMain.handle('power:sleepIfIdle', () => remotePower.sleepIfIdle());
+
 /** Probe a URL from the main process using Node.js http(s) for specific error codes. */
 ipcMain.handle(
   'server:probeConnection',
@@ -1946,17 +3157,21 @@ ipcMain.handle(
         const isHttps = parsed.protocol === 'https:';
         const mod = isHttps ? https : http;
 
+        const tuning = getConnectionTuning(store);
         // For LAN profile: use a certificate-aware agent that tolerates
         // hostname mismatch without disabling validation entirely.
-        const lanAgent = isHttps && skipCertVerify ? buildLanTlsAgent() : undefined;
+        const agent =
+          isHttps && skipCertVerify
+            ? buildLanTlsAgent(agentOptions(tuning))
+            : sharedAgent(isHttps, tuning);
 
         const options: https.RequestOptions = {
           hostname: parsed.hostname,
           port: parsed.port || (isHttps ? 443 : 80),
           path: parsed.pathname + parsed.search,
           method: 'GET',
-          timeout: 10_000,
-          ...(lanAgent ? { agent: lanAgent } : {}),
+          timeout: Math.min(tuning.readTimeoutSeconds, PROBE_READ_TIMEOUT_CAP_SECONDS) * 1000,
+          agent,
         };
 
         const req = mod.request(options, (res) => {
@@ -2019,6 +3234,8 @@ ipcMain.handle(
           resolve({ ok: false, error, errorCode: code });
         });
 
+        armConnectTimeout(req, tuning);
+
         req.on('timeout', () => {
           req.destroy();
           const isTsNet = parsed.hostname.endsWith('.ts.net');
@@ -2339,8 +3556,12 @@ function gracefulShutdown(): Promise<void> {
     updateInstaller.destroy();
     launchWatchdog.destroy();
     await mlxServerManager.destroy();
+    await nativeBackendManager.destroy();
     await watcherManager.destroyAll();
     notificationLog.clear();
+    notificationHistory?.close();
+    timeTracking?.close();
+    await demoServer?.close();
     shutdownLog('[Shutdown] Cleanup complete.');
   })();
 
@@ -2387,12 +3608,89 @@ app.whenReady().then(async () => {
   // load-module (source name taken). Sweep it before anything records.
   if (process.platform === 'linux') void enqueueLoopbackOp(sweepStaleLoopbackModules);
 
+  // Foot pedals: let the renderer open HID devices via WebHID.
+  installHidAccess(session.defaultSession, isDev ? 'http://localhost:3000' : undefined);
+
+  if (demoMode) {
+    startDemoServer()
+      .then((server) => {
+        demoServer = server;
+        console.log(`[Demo] Mock server listening on localhost:${server.port}`);
+      })
+      .catch((err) => console.error('[Demo] Could not start the mock server:', err));
+  }
+
+  if (store.get('phoneIngest.enabled')) {
+    phoneIngest
+      .start(store.get('phoneIngest.port') as number)
+      .catch((err) => console.warn('[PhoneIngest] Could not start:', err));
+  }
+
+  connectionQuality.start();
+  idleManager.start();
+
   // Fresh app session: drop any notification log a crashed session left behind.
   notificationLog.clear();
   ipcMain.handle('notificationLog:load', async () => notificationLog.load());
   ipcMain.handle('notificationLog:persist', async (_event, items: unknown) => {
     if (Array.isArray(items)) notificationLog.persist(items);
   });
+  ipcMain.handle('notificationHistory:record', async (_event, items: unknown) => {
+    if (Array.isArray(items)) notificationHistory?.record(items);
+  });
+  ipcMain.handle(
+    'notificationHistory:list',
+    async (_event, options?: ListNotificationsOptions) => notificationHistory?.list(options) ?? [],
+  );
+  ipcMain.handle('notificationHistory:unreadCount', async () => {
+    return notificationHistory?.unreadCount() ?? 0;
+  });
+  ipcMain.handle('notificationHistory:markRead', async (_event, entryIds?: string[]) => {
+    notificationHistory?.markRead(Array.isArray(entryIds) ? entryIds : undefined);
+  });
+  ipcMain.handle('notificationHistory:dismiss', async (_event, entryId: string) => {
+    return typeof entryId === 'string' && (notificationHistory?.dismiss(entryId) ?? false);
+  });
+  ipcMain.handle('timeTracking:begin', async (_event, info: WorkSessionInfo) => {
+    if (!timeTracking || typeof info?.recordingId !== 'number') return null;
+    return timeTracking.begin({ ...info, title: String(info.title ?? '') });
+  });
+  ipcMain.handle('timeTracking:heartbeat', async (_event, sessionId: number, beat: Heartbeat) => {
+    const idleMinutes = Number(store.get('timeTracking.idleMinutes')) || 2;
+    return (
+      timeTracking?.heartbeat(
+        sessionId,
+        { playing: beat?.playing === true, focused: beat?.focused === true },
+        powerMonitor.getSystemIdleTime(),
+        idleMinutes * 60,
+      ) ?? null
+    );
+  });
+  ipcMain.handle('timeTracking:end', async (_event, sessionId: number) => {
+    timeTracking?.end(sessionId);
+  });
+  ipcMain.handle(
+    'timeTracking:projectTotals',
+    async (_event, range?: TimeRange) => timeTracking?.projectTotals(range) ?? [],
+  );
+  ipcMain.handle(
+    'timeTracking:exportCsv',
+    async (_event, range: TimeRange = {}, perRecording = false) => {
+      if (!timeTracking) return null;
+      const win = BrowserWindow.getAllWindows()[0];
+      const stamp = new Date().toISOString().slice(0, 10);
+      const result = await dialog.showSaveDialog(win, {
+        defaultPath: `transcriptionsuite-time-${stamp}.csv`,
+        filters: [{ name: 'CSV', extensions: ['csv'] }],
+      });
+      if (result.canceled || !result.filePath) return null;
+      const csv = perRecording
+        ? recordingTotalsCsv(timeTracking.recordingTotals(range))
+        : projectTotalsCsv(timeTracking.projectTotals(range));
+      await fs.promises.writeFile(result.filePath, csv, 'utf-8');
+      return result.filePath;
+    },
+  );
 
   // ─── Certificate Error Handler (LAN profile) ────────────────────────────
   // Tailscale certs only cover *.ts.net FQDNs, not IP addresses. LAN
@@ -2415,6 +3713,17 @@ app.whenReady().then(async () => {
   if (forceEnableWeeklyUpdatesOnce(store)) {
     console.log('[UpdateMigration] forced weekly update checks ON (one-time).');
   }
+  // Apple Silicon: run the server natively with Metal instead of in a
+  // CPU-only Docker VM (nativeLaunch.ts). First launch only.
+  const nativeMetalHost = {
+    platform: process.platform,
+    arch: process.arch,
+    metalBackendBundled:
+      !app.isPackaged || fs.existsSync(path.join(process.resourcesPath, 'backend')),
+  };
+  if (selectNativeMetalOnce(store, nativeMetalHost)) {
+    console.log('[NativeLaunch] Apple Silicon detected — selected the Metal runtime profile.');
+  }
   updateManager.start();
 
   if (process.platform === 'win32') {
@@ -2427,7 +3736,7 @@ app.whenReady().then(async () => {
   // is cached on disk, offer the rollback dialog BEFORE creating the
   // main window so a renderer that crashes on init can't race us.
   try {
-    const cached: CachedInstaller | null = await getCachedInstaller(app.getPath('userData'));
+    const cached: CachedInstaller | null = await getCachedInstaller(appPaths.cache);
     const { count, shouldPromptRestore } = launchWatchdog.recordLaunchAttempt(
       app.getVersion(),
       cached,
@@ -2588,11 +3897,12 @@ app.whenReady().then(async () => {
 // ─── MLX Server IPC Handlers ────────────────────────────────────────────────
 
 ipcMain.handle('mlx:start', async (_event, opts: MLXStartOptions) => {
-  await mlxServerManager.start(opts);
+  await startupSequencer.run('mlx', () => mlxServerManager.start(opts));
   store.set('server.mlxDesiredRunning', true);
 });
 
 ipcMain.handle('mlx:stop', async () => {
+  startupSequencer.cancel();
   await mlxServerManager.stop();
   store.set('server.mlxDesiredRunning', false);
 });
@@ -2614,6 +3924,49 @@ ipcMain.handle('mlx:removeModelCache', async (_event, modelId: string) => {
   await mlxServerManager.removeModelCache(modelId);
 });
 
+// ─── Native Backend IPC Handlers ─────────────────────────────────────────────
+
+ipcMain.handle('nativeBackend:getState', () => {
+  return {
+    status: nativeBackendManager.getStatus(),
+    progress: nativeBackendManager.getProgress(),
+    installed: nativeBackendManager.isInstalled(),
+  };
+});
+
+ipcMain.handle('nativeBackend:install', async (_event, opts: BootstrapOptions) => {
+  await nativeBackendManager.install(opts);
+});
+
+ipcMain.handle('nativeBackend:start', async (_event, opts: NativeBackendStartOptions) => {
+  await startupSequencer.run('native', () => nativeBackendManager.start(opts));
+});
+
+ipcMain.handle('nativeBackend:stop', async () => {
+  startupSequencer.cancel();
+  await nativeBackendManager.stop();
+});
+
+ipcMain.handle('nativeBackend:getLogs', (_event, tail?: number) => {
+  return nativeBackendManager.getLogs(tail);
+});
+
+// ─── Startup IPC Handlers ────────────────────────────────────────────────────
+
+ipcMain.handle('startup:getState', () => {
+  return startupSequencer.getState();
+});
+
+// Follow a server that was already starting when the app launched.
+ipcMain.handle('startup:attach', async () => {
+  await startupSequencer.run('attach');
+});
+
+ipcMain.handle('startup:getReportText', () => {
+  const report = startupSequencer.getState().report;
+  return report ? formatStartupReport(report) : null;
+});
+
 // ─── Watcher IPC Handlers ────────────────────────────────────────────────────
 
 ipcMain.handle('watcher:startSession', async (_event, folderPath: string) => {
@@ -2659,17 +4012,170 @@ ipcMain.handle('shortcuts:isWaylandPortal', () => {
   return isWaylandPortalActive();
 });
 
+// ─── Locale IPC Handlers ────────────────────────────────────────────────────
+
+// Bundled catalogs live in dist/locales once built (public/locales in dev);
+// user-supplied ones in <userData>/locales. See localeCatalog.ts.
+function localeDirs(): CatalogDirs {
+  const built = path.join(__dirname, '../dist/locales');
+  return {
+    bundled: fs.existsSync(built) ? built : path.join(__dirname, '../public/locales'),
+    user: path.join(app.getPath('userData'), 'locales'),
+  };
+}
+
+ipcMain.handle('locale:list', () => {
+  const dirs = localeDirs();
+  return { locales: listLocales(dirs), userDir: dirs.user };
+});
+
+ipcMain.handle('locale:get', () => {
+  const dirs = localeDirs();
+  return loadCatalog(
+    dirs,
+    resolveInitialLocale(store.get('app.locale'), app.getLocale(), listLocales(dirs)),
+  );
+});
+
+// Hot-swap: persist and push the new catalog to every window — no restart.
+ipcMain.handle('locale:set', (_event, code: string) => {
+  if (!isLocaleCode(code)) throw new Error(`Invalid locale code: ${code}`);
+  const catalog = loadCatalog(localeDirs(), code);
+  store.set('app.locale', catalog.code);
+  for (const win of BrowserWindow.getAllWindows()) {
+    win.webContents.send('locale:changed', catalog);
+  }
+  return catalog;
+});
+
+// Shortcut registry (shortcutRegistry.ts): every global + in-app binding.
+function applyKeymapResult(result: SetShortcutResult): SetShortcutResult {
+  if (result.ok) {
+    mainWindow?.webContents.send('shortcuts:keymapChanged', result.keymap);
+    registerShortcuts(store, () => mainWindow).catch((err) =>
+      console.warn('[Shortcuts] Re-registration failed:', err),
+    );
+  }
+  return result;
+}
+
+ipcMain.handle('shortcuts:getKeymap', () => {
+  return resolveKeymap(store, process.platform);
+});
+
+ipcMain.handle('shortcuts:set', (_event, action: string, accelerator: string | null) => {
+  return applyKeymapResult(setShortcut(store, action, accelerator, process.platform));
+});
+
+ipcMain.handle('shortcuts:exportKeymap', async () => {
+  const win = BrowserWindow.getAllWindows()[0];
+  const result = await dialog.showSaveDialog(win, {
+    defaultPath: 'transcriptionsuite-keymap.json',
+    filters: [{ name: 'Keymap', extensions: ['json'] }],
+  });
+  if (result.canceled || !result.filePath) return null;
+  const keymap = exportKeymap(store, process.platform);
+  await fs.promises.writeFile(result.filePath, JSON.stringify(keymap, null, 2), 'utf-8');
+  return result.filePath;
+});
+
+ipcMain.handle('shortcuts:importKeymap', async (): Promise<SetShortcutResult | null> => {
+  const win = BrowserWindow.getAllWindows()[0];
+  const result = await dialog.showOpenDialog(win, {
+    properties: ['openFile'],
+    filters: [{ name: 'Keymap', extensions: ['json'] }],
+  });
+  if (result.canceled || result.filePaths.length === 0) return null;
+  let data: unknown;
+  try {
+    data = JSON.parse(await fs.promises.readFile(result.filePaths[0], 'utf-8'));
+  } catch {
+    return { ok: false, error: 'The file is not valid JSON' };
+  }
+  return applyKeymapResult(importKeymap(store, data, process.platform));
+});
+
+ipcMain.handle('settings:exportBundle', async () => {
+  const win = BrowserWindow.getAllWindows()[0];
+  const result = await dialog.showSaveDialog(win, {
+    defaultPath: 'transcriptionsuite-settings.json',
+    filters: [{ name: 'Settings bundle', extensions: ['json'] }],
+  });
+  if (result.canceled || !result.filePath) return null;
+  await exportSettings(store, result.filePath, loadSigningKey(appPaths.config), {
+    home: os.homedir(),
+    appVersion: getResolvedAppVersion(),
+  });
+  return result.filePath;
+});
+
+ipcMain.handle(
+  'settings:importBundle',
+  async (_event, remaps: PathRemap[] = []): Promise<ImportResult | null> => {
+    const win = BrowserWindow.getAllWindows()[0];
+    const result = await dialog.showOpenDialog(win, {
+      properties: ['openFile'],
+      filters: [{ name: 'Settings bundle', extensions: ['json'] }],
+    });
+    if (result.canceled || result.filePaths.length === 0) return null;
+    const ownKey = crypto.createPublicKey(loadSigningKey(appPaths.config));
+    return importSettings(store, result.filePaths[0], {
+      home: os.homedir(),
+      remaps,
+      ownFingerprint: keyFingerprint(ownKey),
+      confirm: async (plan: ImportPlan) => {
+        const { response } = await dialog.showMessageBox(win, {
+          type: 'question',
+          buttons: ['Import', 'Cancel'],
+          defaultId: 0,
+          cancelId: 1,
+          message: 'Replace these settings with the bundle?',
+          detail: [
+            `${plan.counts.settings} settings, ${plan.counts.profiles} connection settings, ` +
+              `${plan.counts.vocabularies} word lists, ${plan.counts.rules} hook settings.`,
+            plan.signedHere
+              ? 'Signed by this installation.'
+              : `Signed by another installation (key ${plan.signer}).`,
+            plan.missingPaths.length > 0
+              ? `Folders that do not exist here: ${plan.missingPaths.join(', ')}`
+              : '',
+            plan.disabledHooks > 0 || plan.switchedOff.length > 0
+              ? 'Script hooks, SSH suspend, the caption file and folder watching are ' +
+                'imported turned off; turn them on once you have checked them.'
+              : '',
+          ]
+            .filter(Boolean)
+            .join('\n\n'),
+        });
+        return response === 0;
+      },
+      onWritten: (keys) => keys.forEach((key) => configBus.publish(key)),
+    });
+  },
+);
+
 // Desktop notifications via Electron's async Notification module.
 // The Web Notification API (`new Notification()` in the renderer) delegates to
 // Chromium's libnotify_notification.cc which calls notify_notification_show()
 // synchronously — blocking the main process for 100+ seconds when the D-Bus
 // notification proxy is unresponsive.  This IPC channel uses Electron's own
 // Notification class whose .show() is non-blocking.
+// With a `clickPayload`, clicking the notification brings the window forward
+// and broadcasts the payload on `notifications:clicked` (e.g. keyword alerts
+// jumping to the matching sentence). Such notifications are kept referenced
+// until clicked or closed so they are not garbage-collected mid-display.
+const clickableNotifications = new Set<Notification>();
 ipcMain.handle(
   'notifications:show',
   async (
     _event,
-    options: { title: string; body: string; silent?: boolean; timeoutMs?: number },
+    options: {
+      title: string;
+      body: string;
+      silent?: boolean;
+      timeoutMs?: number;
+      clickPayload?: unknown;
+    },
   ) => {
     const timeout = options.timeoutMs ?? 3000;
     return new Promise<boolean>((resolve) => {
@@ -2680,6 +4186,19 @@ ipcMain.handle(
           body: options.body,
           silent: options.silent ?? true,
         });
+        if (options.clickPayload !== undefined) {
+          clickableNotifications.add(notification);
+          notification.on('click', () => {
+            clickableNotifications.delete(notification);
+            if (mainWindow) {
+              if (mainWindow.isMinimized()) mainWindow.restore();
+              if (!mainWindow.isVisible()) mainWindow.show();
+              mainWindow.focus();
+            }
+            broadcastToWindows('notifications:clicked', options.clickPayload);
+          });
+          notification.on('close', () => clickableNotifications.delete(notification));
+        }
         notification.on('show', () => {
           clearTimeout(timer);
           resolve(true);
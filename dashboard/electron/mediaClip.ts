@@ -0,0 +1,150 @@
+/**
+ * mediaClip — prepare a local recording with ffmpeg before it is uploaded:
+ * cut out a time range ("Transcribe a range" on the Import tab) and/or keep
+ * only some of its channels (say, the lav mic on channel 3).
+ *
+ * The clip is re-encoded to FLAC — lossless, and every backend decodes it —
+ * into a temp folder the app owns. `-ss` before `-i` seeks in the input, so
+ * cutting ten minutes out of the end of a 4-hour file does not decode the
+ * hours before it; the audio is re-encoded from the exact sample, so the
+ * renderer can shift the returned timestamps by the range start. Selected
+ * channels are isolated, or averaged into one when there are several.
+ *
+ * ffmpeg is found on PATH (plus the Homebrew prefixes, which a Finder-launched
+ * macOS app does not inherit).
+ */
+
+import { execFile } from 'child_process';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+
+export interface MediaEdit {
+  startMs?: number;
+  endMs?: number;
+  /** 0-based input channels to keep; several are downmixed to mono. */
+  channels?: number[];
+}
+
+export interface MediaClip extends MediaEdit {
+  path: string;
+}
+
+export class MediaClipError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'MediaClipError';
+  }
+}
+
+export type RunFfmpeg = (
+  command: string,
+  args: string[],
+  env: NodeJS.ProcessEnv,
+) => Promise<void>;
+
+export interface ClipMediaOptions extends MediaEdit {
+  source: string;
+  /** Names the clip file; one clip per key, so a retry overwrites the last. */
+  key: string;
+  ffmpegPath?: string;
+  tmpRoot?: string;
+  run?: RunFfmpeg;
+}
+
+const CLIP_DIR_NAME = 'transcriptionsuite-clips';
+
+export function clipDir(tmpRoot: string = os.tmpdir()): string {
+  return path.join(tmpRoot, CLIP_DIR_NAME);
+}
+
+function seconds(ms: number): string {
+  return (ms / 1000).toFixed(3);
+}
+
+/** ffmpeg `pan` filter keeping `channels`, averaged into one mono channel. */
+export function panFilter(channels: number[]): string {
+  const gain = channels.length > 1 ? `${+(1 / channels.length).toFixed(4)}*` : '';
+  return `pan=mono|c0=${channels.map((c) => `${gain}c${c}`).join('+')}`;
+}
+
+export function clipArgs(source: string, edit: MediaEdit, destination: string): string[] {
+  const args = ['-hide_banner', '-nostdin', '-loglevel', 'error', '-y'];
+  if (edit.startMs) args.push('-ss', seconds(edit.startMs));
+  args.push('-i', source);
+  if (edit.endMs !== undefined) args.push('-t', seconds(edit.endMs - (edit.startMs ?? 0)));
+  args.push('-map', '0:a:0', '-vn');
+  if (edit.channels?.length) args.push('-af', panFilter(edit.channels));
+  args.push('-c:a', 'flac', destination);
+  return args;
+}
+
+export function ffmpegEnv(): NodeJS.ProcessEnv {
+  if (process.platform !== 'darwin') return process.env;
+  const inherited = process.env.PATH ?? '/usr/bin:/bin:/usr/sbin:/sbin';
+  if (inherited.includes('/opt/homebrew')) return process.env;
+  return { ...process.env, PATH: `/opt/homebrew/bin:/usr/local/bin:${inherited}` };
+}
+
+const runFfmpeg: RunFfmpeg = (command, args, env) =>
+  new Promise((resolve, reject) => {
+    execFile(command, args, { env, maxBuffer: 1024 * 1024 }, (err, _stdout, stderr) => {
+      if (!err) {
+        resolve();
+        return;
+      }
+      if ((err as NodeJS.ErrnoException).code === 'ENOENT') {
+        reject(new MediaClipError(`${command} was not found — install ffmpeg to edit imports`));
+        return;
+      }
+      const detail = String(stderr).trim().split('\n').pop() || err.message;
+      reject(new MediaClipError(`ffmpeg could not prepare the file: ${detail}`));
+    });
+  });
+
+function validateEdit({ startMs = 0, endMs, channels }: MediaEdit): void {
+  if (!Number.isFinite(startMs) || startMs < 0) {
+    throw new MediaClipError('The range must start at or after 0:00');
+  }
+  if (endMs !== undefined && !(Number.isFinite(endMs) && endMs > startMs)) {
+    throw new MediaClipError('The range must end after it starts');
+  }
+  if (channels && !channels.every((c) => Number.isInteger(c) && c >= 0 && c < 64)) {
+    throw new MediaClipError('Unknown channel selection');
+  }
+}
+
+export async function clipMedia(options: ClipMediaOptions): Promise<MediaClip> {
+  const { source, startMs, endMs, channels } = options;
+  validateEdit(options);
+  await fs.promises.access(source, fs.constants.R_OK).catch(() => {
+    throw new MediaClipError(`Cannot read ${path.basename(source)}`);
+  });
+
+  const dir = clipDir(options.tmpRoot);
+  await fs.promises.mkdir(dir, { recursive: true });
+  const destination = path.join(dir, `${options.key.replace(/[^\w.-]/g, '_')}.flac`);
+  const run = options.run ?? runFfmpeg;
+  try {
+    await run(
+      options.ffmpegPath || 'ffmpeg',
+      clipArgs(source, { startMs, endMs, channels }, destination),
+      ffmpegEnv(),
+    );
+    const { size } = await fs.promises.stat(destination);
+    // ffmpeg succeeds with no audio frames when the range starts past the end.
+    if (size === 0) throw new MediaClipError('The range is past the end of the recording');
+  } catch (err) {
+    await fs.promises.rm(destination, { force: true });
+    if (err instanceof MediaClipError) throw err;
+    throw new MediaClipError(err instanceof Error ? err.message : String(err));
+  }
+  return { path: destination, startMs, endMs, channels };
+}
+
+/** Delete a clip; paths outside the clip folder are left alone. */
+export async function removeClip(clipPath: string, tmpRoot?: string): Promise<void> {
+  const resolved = path.resolve(clipPath);
+  if (path.dirname(resolved) !== clipDir(tmpRoot)) return;
+  await fs.promises.rm(resolved, { force: true });
+}
@@ -0,0 +1,212 @@
+/**
+ * Native Backend Manager — runs the server from the managed Python
+ * environment (backendBootstrap.ts) when no container runtime is available.
+ *
+ * `start()` bootstraps first (a no-op when the environment is current),
+ * then spawns `python -m uvicorn server.api.main:app` with the same data,
+ * model-cache and config-dir environment the Metal server uses. Status and
+ * bootstrap progress are pushed to the renderer as
+ * `nativeBackend:statusChanged` / `nativeBackend:progress`.
+ */
+
+import { ChildProcess, spawn } from 'child_process';
+import { BrowserWindow } from 'electron';
+import fs from 'fs';
+import path from 'path';
+import {
+  BackendBootstrapper,
+  uvEnv,
+  type BackendLayout,
+  type BootstrapOptions,
+  type BootstrapProgress,
+  type BootstrapperDeps,
+} from './backendBootstrap.js';
+import { ensureServerConfigSeed, getServerConfigDir } from './serverConfigPaths.js';
+
+export type NativeBackendStatus =
+  | 'stopped'
+  | 'installing'
+  | 'starting'
+  | 'running'
+  | 'stopping'
+  | 'error';
+
+export interface NativeBackendStartOptions extends BootstrapOptions {
+  port: number;
+  hfToken?: string;
+}
+
+const MAX_LOG_LINES = 500;
+
+export class NativeBackendManager {
+  private _process: ChildProcess | null = null;
+  private _status: NativeBackendStatus = 'stopped';
+  private _progress: BootstrapProgress | null = null;
+  private _logs: string[] = [];
+  private _bootstrapper: BackendBootstrapper;
+  private _deps: Omit<BootstrapperDeps, 'onProgress'>;
+  private _getWindow: () => BrowserWindow | null;
+  private _onActivity: () => void;
+
+  /** `onActivity` fires on every bootstrap progress update and server log line. */
+  constructor(
+    getWindow: () => BrowserWindow | null,
+    deps: Omit<BootstrapperDeps, 'onProgress'>,
+    onActivity: () => void = () => {},
+  ) {
+    this._getWindow = getWindow;
+    this._deps = deps;
+    this._onActivity = onActivity;
+    this._bootstrapper = new BackendBootstrapper({
+      ...deps,
+      onProgress: (progress) => {
+        this._progress = progress;
+        this._appendLog(`[Bootstrap] ${progress.message}`);
+        this._emit('nativeBackend:progress', progress);
+      },
+    });
+  }
+
+  getStatus(): NativeBackendStatus {
+    return this._status;
+  }
+
+  getProgress(): BootstrapProgress | null {
+    return this._progress;
+  }
+
+  getLogs(tail = 200): string[] {
+    return this._logs.slice(-tail);
+  }
+
+  isInstalled(): boolean {
+    return this._bootstrapper.isInstalled();
+  }
+
+  /** Create or repair the environment without starting the server. */
+  async install(opts: BootstrapOptions): Promise<void> {
+    if (this._process) throw new Error('Stop the server before reinstalling its environment');
+    this._setStatus('installing');
+    try {
+      await this._bootstrapper.ensure(opts);
+      this._setStatus('stopped');
+    } catch (err) {
+      this._setStatus('error');
+      throw err;
+    }
+  }
+
+  async start(opts: NativeBackendStartOptions): Promise<void> {
+    if (this._process) {
+      if (this._status === 'running' || this._status === 'starting') return;
+      await this.stop();
+    }
+    this._setStatus('installing');
+    let layout: BackendLayout;
+    try {
+      layout = await this._bootstrapper.ensure(opts);
+    } catch (err) {
+      this._setStatus('error');
+      throw err;
+    }
+
+    const dataDir = path.join(this._deps.dataDir, 'data');
+    for (const dir of ['logs', 'audio', 'tokens']) {
+      fs.mkdirSync(path.join(dataDir, dir), { recursive: true });
+    }
+    try {
+      this._appendLog(`[Native] Server config ready at ${ensureServerConfigSeed()}`);
+    } catch (err) {
+      this._appendLog(`[Native] Warning: could not seed server config: ${err}`);
+    }
+
+    const env: NodeJS.ProcessEnv = {
+      ...uvEnv(layout, this._deps.cacheDir, process.env),
+      // src/ holds only the server package and config.yaml — see backendBootstrap.ts.
+      PYTHONPATH: layout.src,
+      DATA_DIR: dataDir,
+      HF_HOME: path.join(this._deps.dataDir, 'models'),
+      USER_CONFIG_DIR: getServerConfigDir(),
+      LOG_DIR: path.join(dataDir, 'logs'),
+      LOG_LEVEL: 'INFO',
+      PYTHONUNBUFFERED: '1',
+    };
+    if (opts.hfToken) env.HF_TOKEN = opts.hfToken;
+
+    this._setStatus('starting');
+    this._appendLog(`[Native] Starting uvicorn on port ${opts.port}…`);
+    const child = spawn(
+      layout.python,
+      ['-m', 'uvicorn', 'server.api.main:app', '--host', '0.0.0.0', '--port', String(opts.port)],
+      { cwd: layout.root, env, stdio: ['ignore', 'pipe', 'pipe'] },
+    );
+    this._process = child;
+
+    const consume = (prefix: string) => (data: Buffer) => {
+      for (const line of data.toString().split('\n').filter(Boolean)) {
+        this._appendLog(`${prefix}${line}`);
+        if (this._status === 'starting' && line.includes('Application startup complete')) {
+          this._setStatus('running');
+        }
+      }
+    };
+    child.stdout?.on('data', consume(''));
+    child.stderr?.on('data', consume('[stderr] '));
+    child.on('error', (err) => {
+      this._appendLog(`[Native] Process error: ${err.message}`);
+      this._process = null;
+      this._setStatus('error');
+    });
+    child.on('exit', (code, signal) => {
+      this._appendLog(
+        code !== null
+          ? `[Native] Process exited with code ${code}`
+          : `[Native] Process killed by signal ${signal}`,
+      );
+      this._process = null;
+      this._setStatus(this._status === 'stopping' ? 'stopped' : 'error');
+    });
+  }
+
+  async stop(): Promise<void> {
+    const child = this._process;
+    if (!child) {
+      this._setStatus('stopped');
+      return;
+    }
+    this._setStatus('stopping');
+    await new Promise<void>((resolve) => {
+      const timeout = setTimeout(() => {
+        child.kill('SIGKILL');
+        resolve();
+      }, 10_000);
+      child.once('exit', () => {
+        clearTimeout(timeout);
+        resolve();
+      });
+      child.kill('SIGTERM');
+    });
+  }
+
+  destroy(): Promise<void> {
+    return this.stop();
+  }
+
+  private _appendLog(line: string): void {
+    this._logs.push(line);
+    this._onActivity();
+    if (this._logs.length > MAX_LOG_LINES) this._logs = this._logs.slice(-MAX_LOG_LINES);
+    this._emit('nativeBackend:logLine', line);
+  }
+
+  private _setStatus(status: NativeBackendStatus): void {
+    if (this._status === status) return;
+    this._status = status;
+    this._emit('nativeBackend:statusChanged', status);
+  }
+
+  private _emit(channel: string, ...args: unknown[]): void {
+    const win = this._getWindow();
+    if (win && !win.isDestroyed()) win.webContents.send(channel, ...args);
+  }
+}
@@ -0,0 +1,45 @@
+/**
+ * Native launch selection for Apple Silicon.
+ *
+ * Docker Desktop on macOS runs containers in a Linux VM with no Metal
+ * passthrough, so the container path is CPU-only there. On arm64 Macs with
+ * the Metal backend bundled, the first launch switches the runtime profile
+ * to `metal`, which starts the server natively via MLXServerManager instead
+ * of a container. The choice is made once, before the renderer's own
+ * hardware auto-detect (gated by the same `server.gpuAutoDetectDone` flag),
+ * so a profile the user picks later is never overridden.
+ */
+
+import type { MigratableStore } from './updateMigration.js';
+
+export interface NativeLaunchHost {
+  platform: NodeJS.Platform;
+  arch: string;
+  /** `<resources>/backend` exists (or running from source). */
+  metalBackendBundled: boolean;
+}
+
+// Keep in sync with src/services/instanceMatrix.ts (MLX_DEFAULT_MODEL) and
+// the 'server.mainModelSelection' default in main.ts.
+export const MLX_DEFAULT_MODEL = 'mlx-community/parakeet-tdt-0.6b-v3';
+const CONTAINER_DEFAULT_MODEL = 'nvidia/parakeet-tdt-0.6b-v3';
+
+export function prefersNativeMetal(host: NativeLaunchHost): boolean {
+  return host.platform === 'darwin' && host.arch === 'arm64' && host.metalBackendBundled;
+}
+
+/**
+ * Returns true if the Metal profile was selected this call, false if the
+ * host does not qualify or hardware auto-detection already ran.
+ */
+export function selectNativeMetalOnce(store: MigratableStore, host: NativeLaunchHost): boolean {
+  if (store.get('server.gpuAutoDetectDone') === true) return false;
+  if (!prefersNativeMetal(host)) return false;
+  store.set('server.runtimeProfile', 'metal');
+  const model = store.get('server.mainModelSelection');
+  if (!model || model === CONTAINER_DEFAULT_MODEL) {
+    store.set('server.mainModelSelection', MLX_DEFAULT_MODEL);
+  }
+  store.set('server.gpuAutoDetectDone', true);
+  return true;
+}
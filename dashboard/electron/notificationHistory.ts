@@ -0,0 +1,214 @@
+/**
+ * NotificationHistory - the persistent notification history (SQLite).
+ *
+ * The session log (notificationsStore + notificationLog.ts) is wiped on
+ * quit; this keeps every entry across sessions, so a toast missed while
+ * away from the machine can still be found later. Entries are upserted by
+ * `entryId` as the renderer's store changes, carry read/unread state, and
+ * can be dismissed (hidden) from the history one by one.
+ *
+ * Uses the runtime's built-in `node:sqlite`, so there is no native module to
+ * rebuild per platform. Transcripts are not stored - they live with the
+ * recording.
+ */
+
+import { DatabaseSync } from 'node:sqlite';
+import path from 'node:path';
+
+const FILE_NAME = 'notifications.db';
+
+/** Oldest rows beyond this are pruned on every write batch. */
+export const MAX_HISTORY_ROWS = 5000;
+
+export interface NotificationRecord {
+  entryId: string;
+  id: string;
+  category: string;
+  title: string;
+  detail?: string;
+  status: 'active' | 'complete' | 'error';
+  severity?: 'warning' | 'error';
+  error?: string;
+  createdAt: number;
+  completedAt?: number;
+  read: boolean;
+}
+
+export interface ListNotificationsOptions {
+  unreadOnly?: boolean;
+  limit?: number;
+  /** Only entries created before this epoch-ms timestamp (paging). */
+  before?: number;
+}
+
+interface Row {
+  entry_id: string;
+  event_id: string;
+  category: string;
+  title: string;
+  detail: string | null;
+  status: NotificationRecord['status'];
+  severity: NotificationRecord['severity'] | null;
+  error: string | null;
+  created_at: number;
+  completed_at: number | null;
+  read: number;
+}
+
+const STATUSES = new Set(['active', 'complete', 'error']);
+
+const optionalString = (value: unknown): string | null =>
+  typeof value === 'string' && value ? value : null;
+
+function toRecord(row: Row): NotificationRecord {
+  return {
+    entryId: row.entry_id,
+    id: row.event_id,
+    category: row.category,
+    title: row.title,
+    ...(row.detail !== null ? { detail: row.detail } : {}),
+    status: row.status,
+    ...(row.severity !== null ? { severity: row.severity } : {}),
+    ...(row.error !== null ? { error: row.error } : {}),
+    createdAt: row.created_at,
+    ...(row.completed_at !== null ? { completedAt: row.completed_at } : {}),
+    read: row.read === 1,
+  };
+}
+
+export class NotificationHistory {
+  private readonly db: DatabaseSync;
+
+  constructor(userDataDir: string, fileName = FILE_NAME) {
+    this.db = new DatabaseSync(path.join(userDataDir, fileName));
+    this.db.exec(`
+      CREATE TABLE IF NOT EXISTS notifications (
+        entry_id     TEXT PRIMARY KEY,
+        event_id     TEXT NOT NULL,
+        category     TEXT NOT NULL,
+        title        TEXT NOT NULL,
+        detail       TEXT,
+        status       TEXT NOT NULL,
+        severity     TEXT,
+        error        TEXT,
+        created_at   INTEGER NOT NULL,
+        completed_at INTEGER,
+        read         INTEGER NOT NULL DEFAULT 0,
+        dismissed    INTEGER NOT NULL DEFAULT 0
+      );
+      CREATE INDEX IF NOT EXISTS idx_notifications_created ON notifications(created_at);
+    `);
+    // Anything still "active" belongs to a previous app session that quit
+    // before it finished - nothing will ever complete it now.
+    this.db
+      .prepare(
+        `UPDATE notifications SET status = 'error', error = 'Interrupted when the app closed'
+         WHERE status = 'active'`,
+      )
+      .run();
+  }
+
+  /** Upsert entries from the renderer store; malformed items are skipped. */
+  record(items: unknown[]): void {
+    const upsert = this.db.prepare(`
+      INSERT INTO notifications
+        (entry_id, event_id, category, title, detail, status, severity, error,
+         created_at, completed_at)
+      VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+      ON CONFLICT(entry_id) DO UPDATE SET
+        title = excluded.title,
+        detail = excluded.detail,
+        status = excluded.status,
+        severity = excluded.severity,
+        error = excluded.error,
+        completed_at = excluded.completed_at,
+        -- A finished job is news again even if its start was already seen.
+        read = CASE WHEN notifications.status != excluded.status THEN 0 ELSE read END
+    `);
+    this.db.exec('BEGIN');
+    try {
+      for (const item of items) {
+        if (typeof item !== 'object' || item === null) continue;
+        const n = item as Record<string, unknown>;
+        if (
+          typeof n.entryId !== 'string' ||
+          typeof n.id !== 'string' ||
+          typeof n.category !== 'string' ||
+          typeof n.title !== 'string' ||
+          !STATUSES.has(n.status as string) ||
+          typeof n.createdAt !== 'number' ||
+          !Number.isFinite(n.createdAt)
+        ) {
+          continue;
+        }
+        upsert.run(
+          n.entryId,
+          n.id,
+          n.category,
+          n.title,
+          optionalString(n.detail),
+          n.status as string,
+          optionalString(n.severity),
+          optionalString(n.error),
+          n.createdAt,
+          typeof n.completedAt === 'number' ? n.completedAt : null,
+        );
+      }
+      this.db
+        .prepare(
+          `DELETE FROM notifications WHERE entry_id NOT IN
+             (SELECT entry_id FROM notifications ORDER BY created_at DESC LIMIT ?)`,
+        )
+        .run(MAX_HISTORY_ROWS);
+      this.db.exec('COMMIT');
+    } catch (err) {
+      this.db.exec('ROLLBACK');
+      console.warn('[NotificationHistory] record failed:', err);
+    }
+  }
+
+  /** Entries not dismissed, newest first. */
+  list(options: ListNotificationsOptions = {}): NotificationRecord[] {
+    const limit = Math.min(Math.max(1, Math.floor(options.limit ?? 200)), MAX_HISTORY_ROWS);
+    const rows = this.db
+      .prepare(
+        `SELECT entry_id, event_id, category, title, detail, status, severity, error,
+                created_at, completed_at, read
+         FROM notifications
+         WHERE dismissed = 0 AND (? = 0 OR read = 0) AND created_at < ?
+         ORDER BY created_at DESC
+         LIMIT ?`,
+      )
+      .all(options.unreadOnly ? 1 : 0, options.before ?? Number.MAX_SAFE_INTEGER, limit);
+    return (rows as unknown as Row[]).map(toRecord);
+  }
+
+  unreadCount(): number {
+    const row = this.db
+      .prepare('SELECT COUNT(*) AS n FROM notifications WHERE dismissed = 0 AND read = 0')
+      .get() as { n: number };
+    return row.n;
+  }
+
+  /** Mark the given entries read, or every entry when `entryIds` is omitted. */
+  markRead(entryIds?: string[]): void {
+    if (entryIds === undefined) {
+      this.db.prepare('UPDATE notifications SET read = 1 WHERE read = 0').run();
+      return;
+    }
+    const mark = this.db.prepare('UPDATE notifications SET read = 1 WHERE entry_id = ?');
+    for (const entryId of entryIds) mark.run(entryId);
+  }
+
+  /** Hide an entry from the history. Returns false if it does not exist. */
+  dismiss(entryId: string): boolean {
+    const result = this.db
+      .prepare('UPDATE notifications SET dismissed = 1, read = 1 WHERE entry_id = ?')
+      .run(entryId);
+    return Number(result.changes) > 0;
+  }
+
+  close(): void {
+    this.db.close();
+  }
+}
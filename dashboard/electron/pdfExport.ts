@@ -0,0 +1,102 @@
+/**
+ * PdfExport - render the server's print-ready HTML export to a PDF file.
+ *
+ * The body comes from `GET /api/notebook/recordings/:id/export?format=html`
+ * (server/backend/core/print_export.py). Running header/footer text and page
+ * numbering are applied here via Chromium's print pipeline so they repeat on
+ * every page — the browser "Print" dialog cannot do this reliably.
+ */
+
+import fs from 'node:fs';
+import { BrowserWindow } from 'electron';
+
+export type PageNumberStyle = 'none' | 'page' | 'page-of-total';
+
+export interface PdfExportOptions {
+  header?: string;
+  footer?: string;
+  pageNumbers?: PageNumberStyle;
+  pageSize?: 'A4' | 'Letter' | 'Legal';
+  landscape?: boolean;
+}
+
+const TEMPLATE_STYLE =
+  'font-family: sans-serif; font-size: 8pt; color: #555; width: 100%; padding: 0 18mm;';
+
+function escapeHtml(text: string): string {
+  return text
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;');
+}
+
+function pageNumberMarkup(style: PageNumberStyle): string {
+  if (style === 'page') return '<span class="pageNumber"></span>';
+  if (style === 'page-of-total') {
+    return 'Page <span class="pageNumber"></span> of <span class="totalPages"></span>';
+  }
+  return '';
+}
+
+/**
+ * Build the `printToPDF` options. Pure so it can be unit-tested without a
+ * BrowserWindow. Chromium requires BOTH templates when `displayHeaderFooter`
+ * is on; an empty `<span>` suppresses its default date/URL header.
+ */
+export function buildPrintToPdfOptions(opts: PdfExportOptions): Electron.PrintToPDFOptions {
+  const header = opts.header?.trim() ?? '';
+  const footer = opts.footer?.trim() ?? '';
+  const pageNumbers = opts.pageNumbers ?? 'page-of-total';
+  const numbers = pageNumberMarkup(pageNumbers);
+  const displayHeaderFooter = header.length > 0 || footer.length > 0 || numbers.length > 0;
+
+  const headerTemplate = header
+    ? `<div style="${TEMPLATE_STYLE} text-align: center;">${escapeHtml(header)}</div>`
+    : '<span></span>';
+  const footerTemplate =
+    footer || numbers
+      ? `<div style="${TEMPLATE_STYLE} display: flex; justify-content: space-between;">` +
+        `<span>${escapeHtml(footer)}</span><span>${numbers}</span></div>`
+      : '<span></span>';
+
+  return {
+    pageSize: opts.pageSize ?? 'A4',
+    landscape: opts.landscape ?? false,
+    printBackground: true,
+    // The HTML's own @page rule owns margins and page size.
+    preferCSSPageSize: true,
+    displayHeaderFooter,
+    headerTemplate,
+    footerTemplate,
+  };
+}
+
+/**
+ * Load `html` into an offscreen window, print it and write the PDF to
+ * `outputPath`. The window never becomes visible and is always destroyed.
+ */
+export async function exportHtmlToPdf(
+  html: string,
+  outputPath: string,
+  opts: PdfExportOptions = {},
+): Promise<{ path: string; bytes: number }> {
+  const win = new BrowserWindow({
+    show: false,
+    webPreferences: {
+      // The document is static server output — no script, no node.
+      javascript: false,
+      sandbox: true,
+      contextIsolation: true,
+      nodeIntegration: false,
+    },
+  });
+  try {
+    await win.loadURL(`data:text/html;charset=utf-8,${encodeURIComponent(html)}`);
+    const pdf = await win.webContents.printToPDF(buildPrintToPdfOptions(opts));
+    await fs.promises.writeFile(outputPath, pdf);
+    return { path: outputPath, bytes: pdf.length };
+  } finally {
+    win.destroy();
+  }
+}
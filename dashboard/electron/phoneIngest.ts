@@ -0,0 +1,354 @@
+/**
+ * Phone ingest: a small HTTP server on the LAN that a phone browser can
+ * upload voice memos to, straight into the desktop import queue.
+ *
+ * Pairing is a QR code of `http://<lan-ip>:<port>/p/<token>`. The token is a
+ * random 256-bit value kept in the dashboard store under `phoneIngest.token`;
+ * every route lives under it, so a request without the token gets a plain 404
+ * and the server gives nothing away to the rest of the network. Resetting the
+ * pairing rotates the token and unpairs every phone at once.
+ *
+ * The upload page is a single inline HTML form — nothing to install on the
+ * phone. Files stream to `<cache>/phone-ingest/<stamp>/<name>` (original name
+ * kept, because the server records it with the recording) and each finished
+ * path is reported through `onFile` for the renderer to queue.
+ *
+ * The phone only hears "Queued" once the file and its pending marker are on
+ * disk: the marker is the durable queue entry. It stays until the renderer
+ * reports the recording imported (`markImported`), keeps the cache manager
+ * from evicting the file, and `pending()` lists every marked upload so one
+ * that arrived with no window open — or before a crash — is queued on the
+ * next start.
+ */
+
+import crypto from 'crypto';
+import fs from 'fs';
+import http from 'http';
+import os from 'os';
+import path from 'path';
+
+import { PENDING_MARKER } from './cacheManager.js';
+import { IMPORTABLE_EXTENSIONS } from './cloudImport.js';
+import { qrSvg } from './qrCode.js';
+
+export const DEFAULT_PHONE_INGEST_PORT = 9787;
+export const TOKEN_STORE_KEY = 'phoneIngest.token';
+/** Generous for an hour-long memo in lossless WAV. */
+export const MAX_UPLOAD_BYTES = 2 * 1024 ** 3;
+
+export interface PhoneIngestStore {
+  get(key: string): unknown;
+  set(key: string, value: unknown): void;
+}
+
+export interface PhoneIngestFile {
+  path: string;
+  name: string;
+  size: number;
+  receivedAt: string;
+}
+
+export interface PhoneIngestStatus {
+  running: boolean;
+  port: number | null;
+  /** Pairing URL for the phone; null while stopped. */
+  url: string | null;
+  /** SVG QR code of `url`. */
+  qrSvg: string | null;
+}
+
+export interface PhoneIngestDeps {
+  store: PhoneIngestStore;
+  cacheDir: string;
+  onFile: (file: PhoneIngestFile) => void;
+  maxBytes?: number;
+  /** Overrides the LAN address advertised in the pairing URL. */
+  host?: string;
+}
+
+/** First non-internal IPv4 address — what a phone on the same Wi-Fi can reach. */
+export function lanAddress(
+  interfaces: NodeJS.Dict<os.NetworkInterfaceInfo[]> = os.networkInterfaces(),
+): string {
+  for (const infos of Object.values(interfaces)) {
+    for (const info of infos ?? []) {
+      if (info.family === 'IPv4' && !info.internal) return info.address;
+    }
+  }
+  return '127.0.0.1';
+}
+
+/** Write `data` (or nothing, to just flush `file`) and fsync before returning. */
+async function writeDurably(file: string, data?: string): Promise<void> {
+  const handle = await fs.promises.open(file, data === undefined ? 'r+' : 'w');
+  try {
+    if (data !== undefined) await handle.writeFile(data, 'utf-8');
+    await handle.sync();
+  } finally {
+    await handle.close();
+  }
+}
+
+function safeFileName(name: string): string {
+  // Phones send a bare name, but never trust a path from the network.
+  const base = path.basename(name.replace(/\\/g, '/'));
+  return base.replace(/[\\/:*?"<>|]/g, '_').slice(0, 200);
+}
+
+export class PhoneIngestServer {
+  private server: http.Server | null = null;
+  private port: number | null = null;
+
+  constructor(private readonly deps: PhoneIngestDeps) {}
+
+  private token(): string {
+    const stored = this.deps.store.get(TOKEN_STORE_KEY);
+    if (typeof stored === 'string' && stored.length > 0) return stored;
+    const token = crypto.randomBytes(32).toString('base64url');
+    this.deps.store.set(TOKEN_STORE_KEY, token);
+    return token;
+  }
+
+  status(): PhoneIngestStatus {
+    if (!this.server || this.port === null) {
+      return { running: false, port: null, url: null, qrSvg: null };
+    }
+    const host = this.deps.host ?? lanAddress();
+    const url = `http://${host}:${this.port}/p/${this.token()}`;
+    return { running: true, port: this.port, url, qrSvg: qrSvg(url) };
+  }
+
+  async start(port = DEFAULT_PHONE_INGEST_PORT): Promise<PhoneIngestStatus> {
+    if (this.server) {
+      if (this.port === port) return this.status();
+      await this.stop();
+    }
+    const server = http.createServer((req, res) => void this.handle(req, res));
+    await new Promise<void>((resolve, reject) => {
+      server.once('error', reject);
+      server.listen(port, '0.0.0.0', () => {
+        server.off('error', reject);
+        resolve();
+      });
+    });
+    this.server = server;
+    this.port = (server.address() as { port: number }).port;
+    return this.status();
+  }
+
+  async stop(): Promise<void> {
+    const server = this.server;
+    this.server = null;
+    this.port = null;
+    if (server) await new Promise<void>((resolve) => server.close(() => resolve()));
+  }
+
+  private ingestDir(): string {
+    return path.join(this.deps.cacheDir, 'phone-ingest');
+  }
+
+  /** Uploads not yet reported imported, oldest first. */
+  async pending(): Promise<PhoneIngestFile[]> {
+    const root = this.ingestDir();
+    const stamps = await fs.promises.readdir(root).catch(() => [] as string[]);
+    const files: PhoneIngestFile[] = [];
+    for (const stamp of stamps.sort()) {
+      try {
+        const raw = await fs.promises.readFile(path.join(root, stamp, PENDING_MARKER), 'utf-8');
+        const entry = JSON.parse(raw) as PhoneIngestFile;
+        const target = path.join(root, stamp, safeFileName(entry.name));
+        await fs.promises.access(target);
+        files.push({ ...entry, path: target });
+      } catch {
+        // No marker (already imported), or a half-written folder.
+      }
+    }
+    return files;
+  }
+
+  /** Drop the pending marker of an imported upload; false if it had none. */
+  async markImported(filePath: string): Promise<boolean> {
+    const dir = path.dirname(path.resolve(filePath));
+    if (path.dirname(dir) !== path.resolve(this.ingestDir())) return false;
+    try {
+      await fs.promises.rm(path.join(dir, PENDING_MARKER));
+      return true;
+    } catch {
+      return false;
+    }
+  }
+
+  /** Issue a new token; phones paired with the old QR code stop working. */
+  resetPairing(): PhoneIngestStatus {
+    this.deps.store.set(TOKEN_STORE_KEY, crypto.randomBytes(32).toString('base64url'));
+    return this.status();
+  }
+
+  private authorized(candidate: string): boolean {
+    const expected = Buffer.from(this.token());
+    const given = Buffer.from(candidate);
+    return given.length === expected.length && crypto.timingSafeEqual(given, expected);
+  }
+
+  private async handle(req: http.IncomingMessage, res: http.ServerResponse): Promise<void> {
+    const { pathname } = new URL(req.url ?? '/', 'http://localhost');
+    const match = /^\/p\/([A-Za-z0-9_-]+)(\/upload)?$/.exec(pathname);
+    if (!match || !this.authorized(match[1])) {
+      reply(res, 404, 'Not found');
+      return;
+    }
+    if (!match[2] && req.method === 'GET') {
+      res.writeHead(200, {
+        'Content-Type': 'text/html; charset=utf-8',
+        'Cache-Control': 'no-store',
+        'Referrer-Policy': 'no-referrer',
+      });
+      res.end(UPLOAD_PAGE);
+      return;
+    }
+    if (match[2] && req.method === 'POST') {
+      await this.receive(req, res);
+      return;
+    }
+    reply(res, 405, 'Method not allowed');
+  }
+
+  private async receive(req: http.IncomingMessage, res: http.ServerResponse): Promise<void> {
+    const maxBytes = this.deps.maxBytes ?? MAX_UPLOAD_BYTES;
+    let name: string;
+    try {
+      name = safeFileName(decodeURIComponent(String(req.headers['x-filename'] ?? '')));
+      if (name.startsWith('.')) name = `memo${name}`;
+    } catch {
+      reply(res, 400, 'Bad file name');
+      return;
+    }
+    if (!IMPORTABLE_EXTENSIONS.includes(path.extname(name).toLowerCase())) {
+      reply(res, 415, 'Only audio and video files can be sent');
+      return;
+    }
+    if (Number(req.headers['content-length'] ?? 0) > maxBytes) {
+      reply(res, 413, 'File too large');
+      req.resume();
+      return;
+    }
+
+    const stamp = `${Date.now()}-${crypto.randomBytes(3).toString('hex')}`;
+    const dir = path.join(this.ingestDir(), stamp);
+    await fs.promises.mkdir(dir, { recursive: true });
+    const target = path.join(dir, name);
+    const partial = `${target}.part`;
+
+    let size = 0;
+    const ok = await new Promise<boolean>((resolve) => {
+      const out = fs.createWriteStream(partial);
+      req.on('data', (chunk: Buffer) => {
+        size += chunk.length;
+        if (size > maxBytes) {
+          req.unpipe(out);
+          out.destroy();
+          resolve(false);
+        }
+      });
+      req.on('aborted', () => {
+        out.destroy();
+        resolve(false);
+      });
+      out.on('error', () => resolve(false));
+      out.on('finish', () => resolve(size <= maxBytes));
+      req.pipe(out);
+    });
+
+    if (!ok) {
+      await fs.promises.rm(dir, { recursive: true, force: true });
+      if (!res.headersSent && !req.destroyed) {
+        reply(res, size > maxBytes ? 413 : 400, 'Upload failed');
+      }
+      return;
+    }
+    const file = { path: target, name, size, receivedAt: new Date().toISOString() };
+    try {
+      await writeDurably(partial);
+      await fs.promises.rename(partial, target);
+      await writeDurably(path.join(dir, PENDING_MARKER), JSON.stringify(file));
+    } catch (err) {
+      console.warn('[PhoneIngest] Could not save upload:', err);
+      await fs.promises.rm(dir, { recursive: true, force: true });
+      reply(res, 500, 'Could not save the file');
+      return;
+    }
+    this.deps.onFile(file);
+    res.writeHead(201, { 'Content-Type': 'application/json' });
+    res.end(JSON.stringify({ name, size }));
+  }
+}
+
+function reply(res: http.ServerResponse, status: number, message: string): void {
+  res.writeHead(status, { 'Content-Type': 'text/plain; charset=utf-8', Connection: 'close' });
+  res.end(message);
+}
+
+// Posts each picked file as a raw body to `<page>/upload`, one at a time, so a
+// flaky phone connection fails one memo rather than the whole batch.
+const UPLOAD_PAGE = `<!doctype html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Send to TranscriptionSuite</title>
+<style>
+  body { font-family: system-ui, sans-serif; background: #0f172a; color: #e2e8f0;
+    margin: 0; padding: 24px; }
+  h1 { font-size: 1.25rem; }
+  label { display: block; padding: 28px 16px; border: 2px dashed #22d3ee; border-radius: 16px;
+    text-align: center; font-size: 1.1rem; }
+  input { display: none; }
+  ul { list-style: none; padding: 0; }
+  li { padding: 8px 0; border-bottom: 1px solid #1e293b; display: flex;
+    justify-content: space-between; gap: 12px; }
+  li span:first-child { overflow: hidden; text-overflow: ellipsis; white-space: nowrap; }
+  .ok { color: #4ade80; } .err { color: #f87171; }
+</style>
+</head>
+<body>
+<h1>Send to TranscriptionSuite</h1>
+<label>Choose or record audio<input id="pick" type="file" accept="audio/*,video/*" multiple></label>
+<ul id="list"></ul>
+<script>
+const list = document.getElementById('list');
+function send(file) {
+  const row = document.createElement('li');
+  const label = document.createElement('span');
+  const state = document.createElement('span');
+  label.textContent = file.name;
+  row.append(label, state);
+  list.prepend(row);
+  return new Promise((resolve) => {
+    const xhr = new XMLHttpRequest();
+    xhr.open('POST', location.pathname.replace(/\\/$/, '') + '/upload');
+    xhr.setRequestHeader('X-Filename', encodeURIComponent(file.name));
+    xhr.upload.onprogress = (e) => {
+      if (e.lengthComputable) state.textContent = Math.round((e.loaded / e.total) * 100) + '%';
+    };
+    xhr.onload = () => {
+      const ok = xhr.status === 201;
+      state.textContent = ok ? 'Queued' : xhr.responseText || 'Failed';
+      state.className = ok ? 'ok' : 'err';
+      resolve();
+    };
+    xhr.onerror = () => {
+      state.textContent = 'Connection lost';
+      state.className = 'err';
+      resolve();
+    };
+    xhr.send(file);
+  });
+}
+document.getElementById('pick').addEventListener('change', async (e) => {
+  for (const file of Array.from(e.target.files)) await send(file);
+  e.target.value = '';
+});
+</script>
+</body>
+</html>
+`;
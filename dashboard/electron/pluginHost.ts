@@ -0,0 +1,294 @@
+/**
+ * WebAssembly plugin host — transcript post-processors and custom export
+ * formats the community can ship without forking the app.
+ *
+ * A plugin is a folder with a `plugin.json` manifest next to its `.wasm`
+ * module. Installing copies both into `<data>/plugins/<id>/`.
+ *
+ * ABI (version 1) — the stable contract between host and plugin:
+ *
+ *   imports  env.memory   linear memory, provided by the host. Declare no
+ *                         maximum, or one of at least `memoryPages`.
+ *   exports  alloc(len: i32) -> i32
+ *                         reserve `len` bytes for the input; returns a pointer.
+ *            run(ptr: i32, len: i32) -> i64
+ *                         process the input; returns `(outPtr << 32) | outLen`.
+ *
+ * Input is UTF-8 JSON — a `PluginTranscript` (below). A post-processor
+ * returns JSON `{ "segments": [...] }` in the same segment shape; an
+ * exporter returns the file's text (UTF-8) and names its extension in the
+ * manifest.
+ *
+ * Sandboxing: `env.memory` is the only import a module may declare, so a
+ * plugin has no file, network, clock or process access — it sees its
+ * input and nothing else. Each run is a fresh instance in its own worker
+ * thread, capped at `memoryPages` of linear memory and a small JS heap, and
+ * terminated after `timeoutMs`.
+ */
+
+import fs from 'fs';
+import path from 'path';
+import { Worker } from 'worker_threads';
+
+export const PLUGIN_ABI_VERSION = 1;
+export const PLUGIN_MANIFEST = 'plugin.json';
+
+export type PluginKind = 'postprocessor' | 'exporter';
+
+export interface PluginManifest {
+  /** Lowercase letters, digits and dashes; also the install folder name. */
+  id: string;
+  name: string;
+  version: string;
+  description?: string;
+  kind: PluginKind;
+  abi: number;
+  /** The module's file name, next to the manifest. */
+  wasm: string;
+  /** Exporters only: what the format is called and the file extension it writes. */
+  exporter?: { label: string; extension: string };
+}
+
+export interface PluginInfo extends PluginManifest {
+  enabled: boolean;
+}
+
+export interface PluginSegment {
+  start: number;
+  end: number;
+  text: string;
+  speaker: string | null;
+}
+
+export interface PluginTranscript {
+  abi: number;
+  title: string;
+  language: string | null;
+  duration: number;
+  segments: PluginSegment[];
+}
+
+export interface PluginLimits {
+  timeoutMs: number;
+  /** Linear memory cap, in 64 KiB pages. */
+  memoryPages: number;
+  maxOutputBytes: number;
+}
+
+export const DEFAULT_PLUGIN_LIMITS: PluginLimits = {
+  timeoutMs: 10_000,
+  memoryPages: 1024, // 64 MiB
+  maxOutputBytes: 32 * 1024 * 1024,
+};
+
+export type PluginRunResult = { ok: true; output: Uint8Array } | { ok: false; error: string };
+
+/** Minimal store interface — accepts any electron-store instance. */
+interface PluginStore {
+  get(key: string): unknown;
+  set(key: string, value: unknown): void;
+}
+
+const DISABLED_KEY = 'plugins.disabled';
+const ID_RE = /^[a-z0-9][a-z0-9-]{0,63}$/;
+const EXTENSION_RE = /^[a-z0-9]{1,10}$/;
+
+/** Validate a parsed `plugin.json`; throws with the first problem found. */
+export function parseManifest(raw: unknown): PluginManifest {
+  if (!raw || typeof raw !== 'object') throw new Error('plugin.json is not an object');
+  const m = raw as Record<string, unknown>;
+  const text = (key: string) => {
+    if (typeof m[key] !== 'string' || !(m[key] as string).trim()) {
+      throw new Error(`plugin.json: "${key}" must be a non-empty string`);
+    }
+    return (m[key] as string).trim();
+  };
+  const id = text('id');
+  if (!ID_RE.test(id)) throw new Error('plugin.json: "id" may only use a-z, 0-9 and dashes');
+  if (m.abi !== PLUGIN_ABI_VERSION) {
+    throw new Error(
+      `plugin.json: ABI ${String(m.abi)} is not supported (this app runs ${PLUGIN_ABI_VERSION})`,
+    );
+  }
+  if (m.kind !== 'postprocessor' && m.kind !== 'exporter') {
+    throw new Error('plugin.json: "kind" must be "postprocessor" or "exporter"');
+  }
+  const wasm = text('wasm');
+  if (path.basename(wasm) !== wasm || !wasm.endsWith('.wasm')) {
+    throw new Error('plugin.json: "wasm" must be a .wasm file name next to the manifest');
+  }
+  const manifest: PluginManifest = {
+    id,
+    name: text('name'),
+    version: text('version'),
+    kind: m.kind,
+    abi: PLUGIN_ABI_VERSION,
+    wasm,
+  };
+  if (typeof m.description === 'string') manifest.description = m.description;
+  if (m.kind === 'exporter') {
+    const exporter = m.exporter as Record<string, unknown> | undefined;
+    const extension = typeof exporter?.extension === 'string' ? exporter.extension : '';
+    if (typeof exporter?.label !== 'string' || !EXTENSION_RE.test(extension)) {
+      throw new Error('plugin.json: exporters need "exporter": { "label", "extension" }');
+    }
+    manifest.exporter = { label: exporter.label, extension };
+  }
+  return manifest;
+}
+
+/** Check a compiled module against the ABI: imports `env.memory` only, exports `alloc` + `run`. */
+export function checkModule(module: WebAssembly.Module): void {
+  for (const imp of WebAssembly.Module.imports(module)) {
+    if (imp.module !== 'env' || imp.name !== 'memory' || imp.kind !== 'memory') {
+      throw new Error(`Plugin imports ${imp.module}.${imp.name}; only env.memory is allowed`);
+    }
+  }
+  const exports = new Map(WebAssembly.Module.exports(module).map((e) => [e.name, e.kind]));
+  for (const name of ['alloc', 'run']) {
+    if (exports.get(name) !== 'function') throw new Error(`Plugin does not export ${name}()`);
+  }
+}
+
+// Runs inside the worker (eval'd as CommonJS, so no module path to resolve
+// from inside an asar archive).
+const WORKER_SOURCE = `
+const { parentPort, workerData } = require('worker_threads');
+const { bytes, input, memoryPages, maxOutputBytes } = workerData;
+try {
+  const memory = new WebAssembly.Memory({ initial: memoryPages, maximum: memoryPages });
+  const instance = new WebAssembly.Instance(new WebAssembly.Module(bytes), { env: { memory } });
+  const { alloc, run } = instance.exports;
+  const inPtr = alloc(input.length) >>> 0;
+  new Uint8Array(memory.buffer, inPtr, input.length).set(input);
+  const packed = BigInt.asUintN(64, BigInt(run(inPtr, input.length)));
+  const ptr = Number(packed >> 32n);
+  const len = Number(packed & 0xffffffffn);
+  if (len > maxOutputBytes) throw new Error('output is larger than ' + maxOutputBytes + ' bytes');
+  if (ptr + len > memory.buffer.byteLength) throw new Error('output lies outside its memory');
+  const output = new Uint8Array(memory.buffer.slice(ptr, ptr + len));
+  parentPort.postMessage({ ok: true, output }, [output.buffer]);
+} catch (err) {
+  parentPort.postMessage({ ok: false, error: err && err.message ? err.message : String(err) });
+}
+`;
+
+/** Run one module on one input in a throwaway worker, within `limits`. */
+export function runSandboxed(
+  bytes: Uint8Array,
+  input: Uint8Array,
+  limits: PluginLimits = DEFAULT_PLUGIN_LIMITS,
+): Promise<PluginRunResult> {
+  return new Promise((resolve) => {
+    const worker = new Worker(WORKER_SOURCE, {
+      eval: true,
+      workerData: {
+        bytes,
+        input,
+        memoryPages: limits.memoryPages,
+        maxOutputBytes: limits.maxOutputBytes,
+      },
+      resourceLimits: { maxOldGenerationSizeMb: 64, maxYoungGenerationSizeMb: 16 },
+    });
+    let settled = false;
+    const finish = (result: PluginRunResult) => {
+      if (settled) return;
+      settled = true;
+      clearTimeout(timer);
+      void worker.terminate();
+      resolve(result);
+    };
+    const timer = setTimeout(
+      () => finish({ ok: false, error: `Plugin timed out after ${limits.timeoutMs / 1000} s` }),
+      limits.timeoutMs,
+    );
+    worker.once('message', (result: PluginRunResult) => finish(result));
+    worker.once('error', (err) => finish({ ok: false, error: err.message }));
+    worker.once('exit', (code) =>
+      finish({ ok: false, error: `Plugin stopped unexpectedly (exit code ${code})` }),
+    );
+  });
+}
+
+export class PluginHost {
+  constructor(
+    private readonly dir: string,
+    private readonly store: PluginStore,
+    private readonly limits: PluginLimits = DEFAULT_PLUGIN_LIMITS,
+  ) {}
+
+  /** Installed plugins; a folder with a broken manifest is skipped. */
+  list(): PluginInfo[] {
+    if (!fs.existsSync(this.dir)) return [];
+    const disabled = this.disabled();
+    const plugins: PluginInfo[] = [];
+    for (const entry of fs.readdirSync(this.dir, { withFileTypes: true })) {
+      if (!entry.isDirectory()) continue;
+      try {
+        const manifest = this.readManifest(path.join(this.dir, entry.name));
+        if (manifest.id !== entry.name) continue;
+        plugins.push({ ...manifest, enabled: !disabled.has(manifest.id) });
+      } catch (err) {
+        console.warn(`[Plugins] Skipping ${entry.name}:`, err);
+      }
+    }
+    return plugins.sort((a, b) => a.name.localeCompare(b.name));
+  }
+
+  /**
+   * Install (or upgrade) from a `plugin.json` path. The module is compiled
+   * and checked against the ABI before anything is copied.
+   */
+  async install(manifestPath: string): Promise<PluginInfo> {
+    const sourceDir = path.dirname(manifestPath);
+    const manifest = this.readManifest(sourceDir);
+    const bytes = await fs.promises.readFile(path.join(sourceDir, manifest.wasm));
+    checkModule(await WebAssembly.compile(bytes));
+
+    const target = path.join(this.dir, manifest.id);
+    const staging = `${target}.installing`;
+    await fs.promises.rm(staging, { recursive: true, force: true });
+    await fs.promises.mkdir(staging, { recursive: true });
+    await fs.promises.writeFile(
+      path.join(staging, PLUGIN_MANIFEST),
+      JSON.stringify(manifest, null, 2),
+    );
+    await fs.promises.writeFile(path.join(staging, manifest.wasm), bytes);
+    await fs.promises.rm(target, { recursive: true, force: true });
+    await fs.promises.rename(staging, target);
+    return { ...manifest, enabled: !this.disabled().has(manifest.id) };
+  }
+
+  async remove(id: string): Promise<void> {
+    if (!ID_RE.test(id)) throw new Error(`Invalid plugin id "${id}"`);
+    await fs.promises.rm(path.join(this.dir, id), { recursive: true, force: true });
+    this.setEnabled(id, true);
+  }
+
+  setEnabled(id: string, enabled: boolean): void {
+    const disabled = this.disabled();
+    if (enabled) disabled.delete(id);
+    else disabled.add(id);
+    this.store.set(DISABLED_KEY, [...disabled]);
+  }
+
+  /** Run an installed, enabled plugin on a transcript. */
+  async run(id: string, transcript: PluginTranscript): Promise<PluginRunResult> {
+    const plugin = this.list().find((p) => p.id === id);
+    if (!plugin) return { ok: false, error: `Plugin "${id}" is not installed` };
+    if (!plugin.enabled) return { ok: false, error: `Plugin "${plugin.name}" is disabled` };
+    const bytes = await fs.promises.readFile(path.join(this.dir, id, plugin.wasm));
+    const input = new TextEncoder().encode(JSON.stringify(transcript));
+    return runSandboxed(bytes, input, this.limits);
+  }
+
+  private readManifest(dir: string): PluginManifest {
+    const raw = fs.readFileSync(path.join(dir, PLUGIN_MANIFEST), 'utf-8');
+    return parseManifest(JSON.parse(raw));
+  }
+
+  private disabled(): Set<string> {
+    const value = this.store.get(DISABLED_KEY);
+    return new Set(Array.isArray(value) ? value.filter((v) => typeof v === 'string') : []);
+  }
+}
@@ -0,0 +1,81 @@
+/**
+ * Portable mode — keep every file the app writes next to the executable.
+ *
+ * Enabled by `--portable`, by a `portable.txt` marker file beside the
+ * executable, or by `TRANSCRIPTIONSUITE_PORTABLE=1`. All app-data paths
+ * (settings, notification log, client logs, server config, installer and
+ * model caches, Chromium session data) then live under
+ * `<exe dir>/TranscriptionSuite-data/`, so the app can run from a USB stick
+ * without leaving anything in the user profile.
+ *
+ * Path resolution everywhere else goes through `app.getPath('appData')` /
+ * `app.getPath('userData')`, so redirecting those here — before anything
+ * reads them — is enough. Host integration that writes outside the app's
+ * data (the Linux .desktop file) is skipped while portable.
+ *
+ * Transcripts held by the server container stay in its Docker volumes.
+ */
+
+import fs from 'fs';
+import path from 'path';
+
+export const PORTABLE_FLAG = '--portable';
+export const PORTABLE_MARKER = 'portable.txt';
+export const PORTABLE_ENV = 'TRANSCRIPTIONSUITE_PORTABLE';
+export const PORTABLE_DATA_DIR = 'TranscriptionSuite-data';
+
+export interface PortableContext {
+  argv: string[];
+  execPath: string;
+  env: NodeJS.ProcessEnv;
+  platform: NodeJS.Platform;
+  exists?: (p: string) => boolean;
+}
+
+/**
+ * The folder the user sees the app in — not always `dirname(execPath)`:
+ * an AppImage runs from a temporary mount, a Windows portable build from a
+ * temp extraction, and a macOS binary sits three levels inside its `.app`.
+ */
+export function executableDir(ctx: PortableContext): string {
+  if (ctx.platform === 'linux' && ctx.env.APPIMAGE) return path.dirname(ctx.env.APPIMAGE);
+  if (ctx.platform === 'win32' && ctx.env.PORTABLE_EXECUTABLE_DIR) {
+    return ctx.env.PORTABLE_EXECUTABLE_DIR;
+  }
+  const dir = path.dirname(ctx.execPath);
+  const bundle = /^(.*?\.app)[\\/]Contents[\\/]MacOS$/.exec(dir);
+  if (ctx.platform === 'darwin' && bundle) return path.dirname(bundle[1]);
+  return dir;
+}
+
+/** The portable data root, or `null` when portable mode is off. */
+export function resolvePortableRoot(ctx: PortableContext): string | null {
+  const exists = ctx.exists ?? fs.existsSync;
+  const base = executableDir(ctx);
+  const enabled =
+    ctx.argv.includes(PORTABLE_FLAG) ||
+    ['1', 'true', 'yes'].includes((ctx.env[PORTABLE_ENV] ?? '').toLowerCase()) ||
+    exists(path.join(base, PORTABLE_MARKER));
+  return enabled ? path.join(base, PORTABLE_DATA_DIR) : null;
+}
+
+interface PathSetter {
+  setPath(name: string, value: string): void;
+  setAppLogsPath(value?: string): void;
+}
+
+/**
+ * Point Electron's data paths into `root`. `appData` is redirected as well
+ * as `userData`, because several modules derive
+ * `<appData>/TranscriptionSuite` directly.
+ */
+export function applyPortablePaths(app: PathSetter, root: string): string {
+  const userData = path.join(root, 'TranscriptionSuite');
+  fs.mkdirSync(userData, { recursive: true });
+  app.setPath('appData', root);
+  app.setPath('userData', userData);
+  app.setPath('sessionData', userData);
+  app.setPath('crashDumps', path.join(userData, 'Crashpad'));
+  app.setAppLogsPath(path.join(userData, 'logs'));
+  return userData;
+}
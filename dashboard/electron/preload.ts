@@ -1,4 +1,14 @@
 import { contextBridge, ipcRenderer } from 'electron';
+import type { SetShortcutResult, ShortcutEntry } from './shortcutRegistry.js';
+import type { ImportResult as SettingsImportResult, PathRemap } from './settingsBundle.js';
+import type { ActionEntry, InvokeActionResult } from './actionRegistry.js';
+import type { PluginInfo, PluginRunResult, PluginTranscript } from './pluginHost.js';
+import type { HookEvent, HookRun } from './scriptHooks.js';
+import type { JobMediaDownload } from './jobMedia.js';
+import type { MediaClip, MediaEdit } from './mediaClip.js';
+import type { ChannelProbe } from './channelProbe.js';
+import type { LocaleCatalog, LocaleInfo } from './localeCatalog.js';
+import type { CpuTopology, StoredWhispercppTuning, WhispercppTuning } from './whispercppTuning.js';
 
 /**
  * Preload script — exposes a safe IPC bridge to the renderer process.
@@ -78,7 +88,7 @@ export type CompatResult =
   | { result: 'unknown'; reason: CompatUnknownReason; detail?: string };
 
 // Keep in sync with src/types/runtime.ts (canonical) and src/types/electron.d.ts
-export type RuntimeProfile = 'gpu' | 'cpu' | 'vulkan' | 'vulkan-wsl2' | 'metal';
+export type RuntimeProfile = 'gpu' | 'cpu' | 'vulkan' | 'vulkan-wsl2' | 'rocm' | 'xpu' | 'metal';
 
 export interface WslSupport {
   available: boolean;
@@ -111,11 +121,31 @@ export interface StartContainerOptions {
   diarizationModel?: string;
 }
 
+/** whisper.cpp sidecar tuning for this machine (whispercppTuning.ts). */
+export interface WhispercppTuningState {
+  /** Whether the active runtime profile uses the sidecar (Vulkan). */
+  available: boolean;
+  machine: string;
+  topology: CpuTopology;
+  tuning: StoredWhispercppTuning;
+}
+
+export interface PdfExportOptions {
+  header?: string;
+  footer?: string;
+  pageNumbers?: 'none' | 'page' | 'page-of-total';
+  pageSize?: 'A4' | 'Letter' | 'Legal';
+  landscape?: boolean;
+}
+
 export interface ElectronAPI {
   config: {
     get: (key: string) => Promise<unknown>;
     set: (key: string, value: unknown) => Promise<void>;
     getAll: () => Promise<Record<string, unknown>>;
+    /** Keys written since the last call, however they were set (configBus.ts). */
+    onChanged: (callback: (keys: string[]) => void) => () => void;
+    onReloadFailed: (callback: (failure: ConfigReloadFailure) => void) => () => void;
   };
   app: {
     getVersion: () => Promise<string>;
@@ -126,6 +156,10 @@ export interface ElectronAPI {
     openExternal: (url: string) => Promise<void>;
     openPath: (filePath: string) => Promise<string>;
     getConfigDir: () => Promise<string>;
+    /** Data directory when running in portable mode, else null. */
+    getPortableRoot: () => Promise<string | null>;
+    getDataPaths: () => Promise<Record<'config' | 'data' | 'cache' | 'logs', string>>;
+    openDataDir: (kind?: 'config' | 'data' | 'cache' | 'logs') => Promise<string>;
     getServerConfigDir: () => Promise<string>;
     ensureServerConfig: () => Promise<string>;
     removeConfigAndCache: () => Promise<void>;
@@ -141,6 +175,8 @@ export interface ElectronAPI {
     readLocalFile: (
       filePath: string,
     ) => Promise<{ name: string; buffer: ArrayBuffer; mimeType: string }>;
+    statLocalFile: (filePath: string) => Promise<{ name: string; size: number }>;
+    readLocalFileRange: (filePath: string, offset: number, length: number) => Promise<ArrayBuffer>;
   };
   docker: {
     available: () => Promise<boolean>;
@@ -152,6 +188,8 @@ export interface ElectronAPI {
       gpu: boolean;
       toolkit: boolean;
       vulkan: boolean;
+      rocm: boolean;
+      xpu: boolean;
       wslSupport?: WslSupport;
     }>;
     resetGpuCache: () => Promise<void>;
@@ -195,7 +233,7 @@ export interface ElectronAPI {
     >;
     fetchRemoteTagDates: (tags: string[]) => Promise<Record<string, string | null>>;
     listVariantTags: () => Promise<
-      Record<'cuda' | 'cuda-legacy' | 'vulkan-wsl2' | 'vulkan-linux', string[]>
+      Record<'cuda' | 'cuda-legacy' | 'vulkan-wsl2' | 'vulkan-linux' | 'rocm' | 'xpu', string[]>
     >;
     pullImage: (tag: string) => Promise<string>;
     cancelPull: () => Promise<boolean>;
@@ -214,6 +252,7 @@ export interface ElectronAPI {
     }>;
     startContainer: (options: StartContainerOptions) => Promise<string>;
     stopContainer: () => Promise<string>;
+    restartContainer: () => Promise<string>;
     removeContainer: () => Promise<string>;
     getVolumes: () => Promise<
       Array<{ name: string; label: string; driver: string; mountpoint: string; size?: string }>
@@ -230,6 +269,12 @@ export interface ElectronAPI {
     switchWhisperServerModel: (
       model: string | null,
     ) => Promise<{ switched: boolean; model: string | null }>;
+    getWhispercppTuning: () => Promise<WhispercppTuningState>;
+    setWhispercppTuning: (tuning: WhispercppTuning) => Promise<StoredWhispercppTuning>;
+    benchmarkWhispercpp: () => Promise<StoredWhispercppTuning>;
+    onWhispercppBenchmarkProgress: (
+      callback: (progress: { done: number; total: number }) => void,
+    ) => () => void;
     removeVolume: (name: string) => Promise<string>;
     readComposeEnvValue: (key: string) => Promise<string | null>;
     volumeExists: (name: string) => Promise<boolean>;
@@ -368,6 +413,53 @@ export interface ElectronAPI {
     onPortalChanged: (
       callback: (bindings: Array<{ id: string; trigger: string }>) => void,
     ) => () => void;
+    getKeymap: () => Promise<ShortcutEntry[]>;
+    set: (action: string, accelerator: string | null) => Promise<SetShortcutResult>;
+    exportKeymap: () => Promise<string | null>;
+    importKeymap: () => Promise<SetShortcutResult | null>;
+    onKeymapChanged: (callback: (keymap: ShortcutEntry[]) => void) => () => void;
+  };
+  plugins: {
+    list: () => Promise<PluginInfo[]>;
+    /** Opens a file picker for the plugin's plugin.json; null when cancelled. */
+    install: () => Promise<PluginInfo | null>;
+    remove: (id: string) => Promise<void>;
+    setEnabled: (id: string, enabled: boolean) => Promise<void>;
+    run: (id: string, transcript: PluginTranscript) => Promise<PluginRunResult>;
+    onChanged: (callback: (plugins: PluginInfo[]) => void) => () => void;
+  };
+  hooks: {
+    /** Fire on_job_complete / on_recording_saved; on_export fires in main. */
+    fire: (event: HookEvent, payload: Record<string, unknown>) => Promise<void>;
+    history: () => Promise<HookRun[]>;
+    /** Run one hook now with an example payload. */
+    test: (id: string) => Promise<HookRun>;
+    onRan: (callback: (run: HookRun) => void) => () => void;
+  };
+  jobMedia: {
+    /** Fetch a session import's server copy to `destination`, verified against `sha256`. */
+    download: (jobId: string, destination: string, sha256: string) => Promise<JobMediaDownload>;
+  };
+  mediaClip: {
+    /** Pick a local recording (audio or video) to transcribe part of. */
+    selectSource: () => Promise<string | null>;
+    /** Channel count and preview levels of the first minute of `source`. */
+    probeChannels: (source: string) => Promise<ChannelProbe>;
+    /** Cut the range / channels of `edit` out of `source` into a temp FLAC named after `key`. */
+    create: (source: string, edit: MediaEdit, key: string) => Promise<MediaClip>;
+    remove: (clipPath: string) => Promise<void>;
+  };
+  actions: {
+    list: () => Promise<ActionEntry[]>;
+    invoke: (id: string) => Promise<InvokeActionResult>;
+    /** Renderer-side actions (navigation, settings) handed over by main. */
+    onRun: (callback: (id: string) => void) => () => void;
+  };
+  locale: {
+    list: () => Promise<{ locales: LocaleInfo[]; userDir: string }>;
+    get: () => Promise<LocaleCatalog>;
+    set: (code: string) => Promise<LocaleCatalog>;
+    onChanged: (callback: (catalog: LocaleCatalog) => void) => () => void;
   };
   serverConfig: {
     readTemplate: () => Promise<string | null>;
@@ -420,6 +512,12 @@ export interface ElectronAPI {
   fileIO: {
     getDownloadsPath: () => Promise<string>;
     writeText: (filePath: string, content: string) => Promise<void>;
+    /** Render print-ready export HTML to a PDF with running header/footer + page numbers. */
+    exportPdf: (
+      html: string,
+      filePath: string,
+      opts: PdfExportOptions,
+    ) => Promise<{ path: string; bytes: number }>;
     selectFolder: () => Promise<string | null>;
     /**
      * Issue #104, Story 3.5 — native file-save dialog. Returns the user-
@@ -453,12 +551,33 @@ export interface ElectronAPI {
       body: string;
       silent?: boolean;
       timeoutMs?: number;
+      /** Broadcast on `notifications:clicked` when the user clicks it. */
+      clickPayload?: unknown;
     }) => Promise<boolean>;
+    onClicked: (callback: (payload: unknown) => void) => () => void;
   };
   notificationLog: {
     load: () => Promise<unknown[]>;
     persist: (items: unknown[]) => Promise<void>;
   };
+  notificationHistory: {
+    record: (items: unknown[]) => Promise<void>;
+    list: (options?: NotificationHistoryQuery) => Promise<NotificationHistoryRecord[]>;
+    unreadCount: () => Promise<number>;
+    markRead: (entryIds?: string[]) => Promise<void>;
+    dismiss: (entryId: string) => Promise<boolean>;
+  };
+  timeTracking: {
+    begin: (info: WorkSessionInfo) => Promise<number | null>;
+    heartbeat: (
+      sessionId: number,
+      beat: { playing: boolean; focused: boolean },
+    ) => Promise<number | null>;
+    end: (sessionId: number) => Promise<void>;
+    projectTotals: (range?: TimeTrackingRange) => Promise<ProjectTimeTotals[]>;
+    /** Save dialog + CSV write; resolves to the path, or null when cancelled. */
+    exportCsv: (range?: TimeTrackingRange, perRecording?: boolean) => Promise<string | null>;
+  };
   mlx: {
     start: (opts: {
       port: number;
@@ -479,6 +598,334 @@ export interface ElectronAPI {
     ) => () => void;
     onLogLine: (callback: (line: string) => void) => () => void;
   };
+  nativeBackend: {
+    getState: () => Promise<NativeBackendState>;
+    install: (opts: NativeBackendBootstrapOptions) => Promise<void>;
+    start: (
+      opts: NativeBackendBootstrapOptions & { port: number; hfToken?: string },
+    ) => Promise<void>;
+    stop: () => Promise<void>;
+    getLogs: (tail?: number) => Promise<string[]>;
+    onStatusChanged: (callback: (status: NativeBackendStatus) => void) => () => void;
+    onProgress: (callback: (progress: NativeBackendProgress) => void) => () => void;
+  };
+  startup: {
+    getState: () => Promise<StartupState>;
+    attach: () => Promise<void>;
+    getReportText: () => Promise<string | null>;
+    onStateChanged: (callback: (state: StartupState) => void) => () => void;
+  };
+  wsl: {
+    listDistros: () => Promise<WslDistro[]>;
+    probe: (distro: string) => Promise<WslDockerProbe>;
+  };
+  captions: {
+    show: (position?: CaptionPosition) => Promise<void>;
+    hide: () => Promise<void>;
+    isVisible: () => Promise<boolean>;
+    update: (payload: CaptionPayload) => void;
+    onVisibilityChanged: (callback: (visible: boolean) => void) => () => void;
+  };
+  cloud: {
+    listSources: () => Promise<CloudSourceState[]>;
+    saveSource: (
+      source: Omit<CloudSource, 'id'> & { id?: string },
+      secret?: CloudSourceSecret,
+    ) => Promise<CloudSourceState>;
+    removeSource: (id: string) => Promise<void>;
+    connect: (id: string) => Promise<void>;
+    browse: (id: string, folderId?: string) => Promise<CloudEntry[]>;
+    download: (id: string, entries: CloudEntry[]) => Promise<string[]>;
+    onDownloadProgress: (callback: (progress: CloudDownloadProgress) => void) => () => void;
+  };
+  phoneIngest: {
+    getStatus: () => Promise<PhoneIngestStatus>;
+    setEnabled: (enabled: boolean, port?: number) => Promise<PhoneIngestStatus>;
+    resetPairing: () => Promise<PhoneIngestStatus>;
+    /** Uploads still waiting to be imported, including ones from earlier runs. */
+    listPending: () => Promise<PhoneIngestFile[]>;
+    /** Release an upload once its recording is in the notebook. */
+    markImported: (path: string) => Promise<boolean>;
+    onFileReceived: (callback: (file: PhoneIngestFile) => void) => () => void;
+  };
+  cache: {
+    getUsage: () => Promise<CacheUsage>;
+    clear: (category?: CacheCategory) => Promise<CacheUsage>;
+    setQuota: (category: CacheCategory, mb: number) => Promise<CacheUsage>;
+    setDirectory: (dir: string | null) => Promise<CacheUsage>;
+  };
+  followAlong: {
+    getStatus: () => Promise<FollowAlongStatus>;
+    start: () => Promise<FollowAlongStatus>;
+    stop: () => Promise<void>;
+    update: (payload: FollowAlongPayload) => void;
+  };
+  connectionQuality: {
+    getLatest: () => Promise<ConnectionQualityReport | null>;
+    checkNow: () => Promise<ConnectionQualityReport>;
+    onReport: (callback: (report: ConnectionQualityReport) => void) => () => void;
+  };
+  serverIdle: {
+    getStatus: () => Promise<ServerIdleStatus>;
+    wake: () => Promise<boolean>;
+    noteActivity: () => Promise<void>;
+    onStatus: (callback: (status: ServerIdleStatus) => void) => () => void;
+  };
+  power: {
+    getStatus: () => Promise<RemotePowerStatus>;
+    wake: (profile?: ConnectionProfile) => Promise<boolean>;
+    sleepIfIdle: () => Promise<boolean>;
+    onStatus: (callback: (status: RemotePowerStatus) => void) => () => void;
+  };
+  settingsBundle: {
+    /** Save dialog, then a signed bundle without secrets; null when cancelled. */
+    exportBundle: () => Promise<string | null>;
+    importBundle: (remaps?: PathRemap[]) => Promise<SettingsImportResult | null>;
+  };
+}
+
+// Keep in sync with electron/captionOverlay.ts
+export type CaptionPosition = 'top' | 'bottom';
+
+export interface CaptionPayload {
+  lines: string[];
+  partial: string;
+}
+
+// Keep in sync with electron/cloudImport.ts
+export type CloudProvider = 's3' | 'gdrive' | 'dropbox';
+
+export interface CloudSource {
+  id: string;
+  provider: CloudProvider;
+  label: string;
+  bucket?: string;
+  region?: string;
+  endpoint?: string;
+  prefix?: string;
+  accessKeyId?: string;
+  clientId?: string;
+}
+
+export interface CloudSourceState extends CloudSource {
+  connected: boolean;
+}
+
+export interface CloudSourceSecret {
+  secretAccessKey?: string;
+  clientSecret?: string;
+}
+
+export interface CloudEntry {
+  id: string;
+  name: string;
+  isFolder: boolean;
+  size?: number;
+  modifiedAt?: string;
+}
+
+export interface CloudDownloadProgress {
+  sourceId: string;
+  entryId: string;
+  name: string;
+  received: number;
+  total: number | null;
+  done: boolean;
+}
+
+// Sent by the config bus in electron/main.ts
+export interface ConfigReloadFailure {
+  subscriber: string;
+  message: string;
+}
+
+// Keep in sync with electron/phoneIngest.ts
+export interface PhoneIngestStatus {
+  running: boolean;
+  port: number | null;
+  url: string | null;
+  qrSvg: string | null;
+}
+
+export interface PhoneIngestFile {
+  path: string;
+  name: string;
+  size: number;
+  receivedAt: string;
+}
+
+// Keep in sync with electron/cacheManager.ts
+export type CacheCategory = 'cloud-import' | 'phone-ingest' | 'media' | 'waveforms';
+
+export interface CacheCategoryUsage {
+  category: CacheCategory;
+  label: string;
+  bytes: number;
+  files: number;
+  quotaBytes: number;
+}
+
+export interface CacheUsage {
+  root: string;
+  custom: boolean;
+  categories: CacheCategoryUsage[];
+}
+
+// Keep in sync with electron/followAlong.ts
+export interface FollowAlongStatus {
+  running: boolean;
+  url: string | null;
+  qrSvg: string | null;
+  viewers: number;
+}
+
+export interface FollowAlongPayload {
+  sentences: string[];
+  partial: string;
+}
+
+// Keep in sync with electron/connectionQuality.ts
+export type ConnectionProfile = 'local' | 'tailscale' | 'lan';
+
+export interface ConnectionProfileQuality {
+  profile: ConnectionProfile;
+  quality: 'good' | 'degraded' | 'offline';
+  latencyMs: number | null;
+  loss: number;
+}
+
+export interface ConnectionQualityReport extends ConnectionProfileQuality {
+  checkedAt: number;
+  alternative: ConnectionProfileQuality | null;
+  suggestSwitchTo: ConnectionProfile | null;
+}
+
+// Keep in sync with electron/idleManager.ts
+export interface ServerIdleStatus {
+  state: 'off' | 'active' | 'stopping' | 'sleeping' | 'waking';
+  idleMinutes: number;
+  lastActivityAt: number | null;
+  sleepingSince: number | null;
+  error: string | null;
+}
+
+// Keep in sync with electron/remotePower.ts
+export interface RemotePowerStatus {
+  state: 'unknown' | 'waking' | 'awake' | 'suspending' | 'suspended';
+  wokenByUs: boolean;
+  error: string | null;
+}
+
+// Keep in sync with electron/notificationHistory.ts
+export interface NotificationHistoryRecord {
+  entryId: string;
+  id: string;
+  category: string;
+  title: string;
+  detail?: string;
+  status: 'active' | 'complete' | 'error';
+  severity?: 'warning' | 'error';
+  error?: string;
+  createdAt: number;
+  completedAt?: number;
+  read: boolean;
+}
+
+export interface NotificationHistoryQuery {
+  unreadOnly?: boolean;
+  limit?: number;
+  before?: number;
+}
+
+// Keep in sync with electron/timeTracking.ts
+export interface WorkSessionInfo {
+  recordingId: number;
+  title: string;
+  project?: string;
+  audioSeconds: number;
+}
+
+export interface TimeTrackingRange {
+  from?: number;
+  to?: number;
+}
+
+export interface ProjectTimeTotals {
+  project: string;
+  recordings: number;
+  activeMs: number;
+  audioSeconds: number;
+}
+
+// Keep in sync with electron/wslBackend.ts
+export interface WslDistro {
+  name: string;
+  state: string;
+  version: number;
+  isDefault: boolean;
+}
+
+export interface WslDockerProbe {
+  available: boolean;
+  dockerVersion?: string;
+  reason?: string;
+}
+
+// Keep in sync with electron/startupSequencer.ts
+export type StartupStage = 'backend' | 'health' | 'model' | 'version';
+
+export interface StartupStageState {
+  stage: StartupStage;
+  status: 'pending' | 'running' | 'done' | 'warning' | 'failed';
+  message: string;
+  startedAt: number | null;
+  finishedAt: number | null;
+}
+
+export interface StartupReport {
+  source: string | null;
+  failedStage: StartupStage;
+  reason: string;
+  elapsedMs: number;
+  stages: StartupStageState[];
+  probes: string[];
+  diagnostics: string;
+  createdAt: string;
+}
+
+export interface StartupState {
+  status: 'idle' | 'running' | 'ready' | 'failed';
+  source: string | null;
+  stages: StartupStageState[];
+  percent: number;
+  report: StartupReport | null;
+}
+
+// Keep in sync with electron/backendBootstrap.ts and electron/nativeBackendManager.ts
+export type NativeBackendStatus =
+  | 'stopped'
+  | 'installing'
+  | 'starting'
+  | 'running'
+  | 'stopping'
+  | 'error';
+
+export interface NativeBackendProgress {
+  stage: 'locate' | 'source' | 'sync' | 'verify' | 'ready' | 'error';
+  message: string;
+  percent: number | null;
+}
+
+export interface NativeBackendBootstrapOptions {
+  extras: string[];
+  pytorchVariant: 'cu129' | 'cpu';
+  force?: boolean;
+}
+
+export interface NativeBackendState {
+  status: NativeBackendStatus;
+  progress: NativeBackendProgress | null;
+  installed: boolean;
 }
 
 export interface ComponentUpdateStatus {
@@ -501,6 +948,17 @@ contextBridge.exposeInMainWorld('electronAPI', {
     get: (key: string) => ipcRenderer.invoke('config:get', key),
     set: (key: string, value: unknown) => ipcRenderer.invoke('config:set', key, value),
     getAll: () => ipcRenderer.invoke('config:getAll'),
+    onChanged: (callback: (keys: string[]) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, keys: string[]) => callback(keys);
+      ipcRenderer.on('config:changed', handler);
+      return () => ipcRenderer.removeListener('config:changed', handler);
+    },
+    onReloadFailed: (callback: (failure: ConfigReloadFailure) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, failure: ConfigReloadFailure) =>
+        callback(failure);
+      ipcRenderer.on('config:reloadFailed', handler);
+      return () => ipcRenderer.removeListener('config:reloadFailed', handler);
+    },
   },
   app: {
     getVersion: () => ipcRenderer.invoke('app:getVersion'),
@@ -517,6 +975,10 @@ contextBridge.exposeInMainWorld('electronAPI', {
     openExternal: (url: string) => ipcRenderer.invoke('app:openExternal', url),
     openPath: (filePath: string) => ipcRenderer.invoke('app:openPath', filePath),
     getConfigDir: () => ipcRenderer.invoke('app:getConfigDir'),
+    getPortableRoot: () => ipcRenderer.invoke('app:getPortableRoot'),
+    getDataPaths: () => ipcRenderer.invoke('app:getDataPaths'),
+    openDataDir: (kind?: 'config' | 'data' | 'cache' | 'logs') =>
+      ipcRenderer.invoke('app:openDataDir', kind),
     getServerConfigDir: () => ipcRenderer.invoke('app:getServerConfigDir') as Promise<string>,
     ensureServerConfig: () => ipcRenderer.invoke('app:ensureServerConfig') as Promise<string>,
     removeConfigAndCache: () => ipcRenderer.invoke('app:removeConfigAndCache'),
@@ -540,6 +1002,15 @@ contextBridge.exposeInMainWorld('electronAPI', {
         buffer: ArrayBuffer;
         mimeType: string;
       }>,
+    statLocalFile: (filePath: string) =>
+      ipcRenderer.invoke('app:statLocalFile', filePath) as Promise<{ name: string; size: number }>,
+    readLocalFileRange: (filePath: string, offset: number, length: number) =>
+      ipcRenderer.invoke(
+        'app:readLocalFileRange',
+        filePath,
+        offset,
+        length,
+      ) as Promise<ArrayBuffer>,
   },
   docker: {
     available: () => ipcRenderer.invoke('docker:available'),
@@ -567,7 +1038,7 @@ contextBridge.exposeInMainWorld('electronAPI', {
       >,
     listVariantTags: () =>
       ipcRenderer.invoke('docker:listVariantTags') as Promise<
-        Record<'cuda' | 'cuda-legacy' | 'vulkan-wsl2' | 'vulkan-linux', string[]>
+        Record<'cuda' | 'cuda-legacy' | 'vulkan-wsl2' | 'vulkan-linux' | 'rocm' | 'xpu', string[]>
       >,
     pullImage: (tag: string) => ipcRenderer.invoke('docker:pullImage', tag),
     cancelPull: () => ipcRenderer.invoke('docker:cancelPull'),
@@ -581,6 +1052,7 @@ contextBridge.exposeInMainWorld('electronAPI', {
     startContainer: (options: StartContainerOptions) =>
       ipcRenderer.invoke('docker:startContainer', options),
     stopContainer: () => ipcRenderer.invoke('docker:stopContainer'),
+    restartContainer: () => ipcRenderer.invoke('docker:restartContainer'),
     removeContainer: () => ipcRenderer.invoke('docker:removeContainer'),
     getVolumes: () => ipcRenderer.invoke('docker:getVolumes'),
     checkModelsCached: (modelIds: string[]) =>
@@ -602,6 +1074,22 @@ contextBridge.exposeInMainWorld('electronAPI', {
         switched: boolean;
         model: string | null;
       }>,
+    getWhispercppTuning: () =>
+      ipcRenderer.invoke('whisper:getTuning') as Promise<WhispercppTuningState>,
+    setWhispercppTuning: (tuning: WhispercppTuning) =>
+      ipcRenderer.invoke('whisper:setTuning', tuning) as Promise<StoredWhispercppTuning>,
+    benchmarkWhispercpp: () =>
+      ipcRenderer.invoke('whisper:benchmark') as Promise<StoredWhispercppTuning>,
+    onWhispercppBenchmarkProgress: (
+      callback: (progress: { done: number; total: number }) => void,
+    ) => {
+      const handler = (
+        _event: Electron.IpcRendererEvent,
+        progress: { done: number; total: number },
+      ) => callback(progress);
+      ipcRenderer.on('whisper:benchmarkProgress', handler);
+      return () => ipcRenderer.removeListener('whisper:benchmarkProgress', handler);
+    },
     removeVolume: (name: string) => ipcRenderer.invoke('docker:removeVolume', name),
     readComposeEnvValue: (key: string) =>
       ipcRenderer.invoke('docker:readComposeEnvValue', key) as Promise<string | null>,
@@ -751,6 +1239,74 @@ contextBridge.exposeInMainWorld('electronAPI', {
       ipcRenderer.on('shortcuts:portalChanged', handler);
       return () => ipcRenderer.removeListener('shortcuts:portalChanged', handler);
     },
+    getKeymap: () => ipcRenderer.invoke('shortcuts:getKeymap'),
+    set: (action: string, accelerator: string | null) =>
+      ipcRenderer.invoke('shortcuts:set', action, accelerator),
+    exportKeymap: () => ipcRenderer.invoke('shortcuts:exportKeymap'),
+    importKeymap: () => ipcRenderer.invoke('shortcuts:importKeymap'),
+    onKeymapChanged: (callback: (keymap: ShortcutEntry[]) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, keymap: ShortcutEntry[]) =>
+        callback(keymap);
+      ipcRenderer.on('shortcuts:keymapChanged', handler);
+      return () => ipcRenderer.removeListener('shortcuts:keymapChanged', handler);
+    },
+  },
+  plugins: {
+    list: () => ipcRenderer.invoke('plugins:list'),
+    install: () => ipcRenderer.invoke('plugins:install'),
+    remove: (id: string) => ipcRenderer.invoke('plugins:remove', id),
+    setEnabled: (id: string, enabled: boolean) =>
+      ipcRenderer.invoke('plugins:setEnabled', id, enabled),
+    run: (id: string, transcript: PluginTranscript) =>
+      ipcRenderer.invoke('plugins:run', id, transcript),
+    onChanged: (callback: (plugins: PluginInfo[]) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, plugins: PluginInfo[]) =>
+        callback(plugins);
+      ipcRenderer.on('plugins:changed', handler);
+      return () => ipcRenderer.removeListener('plugins:changed', handler);
+    },
+  },
+  hooks: {
+    fire: (event: HookEvent, payload: Record<string, unknown>) =>
+      ipcRenderer.invoke('hooks:fire', event, payload),
+    history: () => ipcRenderer.invoke('hooks:history'),
+    test: (id: string) => ipcRenderer.invoke('hooks:test', id),
+    onRan: (callback: (run: HookRun) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, run: HookRun) => callback(run);
+      ipcRenderer.on('hooks:ran', handler);
+      return () => ipcRenderer.removeListener('hooks:ran', handler);
+    },
+  },
+  jobMedia: {
+    download: (jobId: string, destination: string, sha256: string) =>
+      ipcRenderer.invoke('jobMedia:download', jobId, destination, sha256),
+  },
+  mediaClip: {
+    selectSource: () => ipcRenderer.invoke('mediaClip:selectSource'),
+    probeChannels: (source: string) => ipcRenderer.invoke('mediaClip:probeChannels', source),
+    create: (source: string, edit: MediaEdit, key: string) =>
+      ipcRenderer.invoke('mediaClip:create', source, edit, key),
+    remove: (clipPath: string) => ipcRenderer.invoke('mediaClip:remove', clipPath),
+  },
+  actions: {
+    list: () => ipcRenderer.invoke('actions:list'),
+    invoke: (id: string) => ipcRenderer.invoke('actions:invoke', id),
+    onRun: (callback: (id: string) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, id: string) => callback(id);
+      ipcRenderer.on('actions:run', handler);
+      return () => ipcRenderer.removeListener('actions:run', handler);
+    },
+  },
+  locale: {
+    list: () => ipcRenderer.invoke('locale:list'),
+    get: () => ipcRenderer.invoke('locale:get'),
+    set: (code: string) => ipcRenderer.invoke('locale:set', code),
+    onChanged: (callback: (catalog: LocaleCatalog) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, catalog: LocaleCatalog) =>
+        callback(catalog);
+      ipcRenderer.on('locale:changed', handler);
+      return () => ipcRenderer.removeListener('locale:changed', handler);
+    },
   },
   serverConfig: {
     readTemplate: () => ipcRenderer.invoke('serverConfig:readTemplate') as Promise<string | null>,
@@ -788,6 +1344,11 @@ contextBridge.exposeInMainWorld('electronAPI', {
     getDownloadsPath: () => ipcRenderer.invoke('app:getDownloadsPath') as Promise<string>,
     writeText: (filePath: string, content: string) =>
       ipcRenderer.invoke('file:writeText', filePath, content) as Promise<void>,
+    exportPdf: (html: string, filePath: string, opts: PdfExportOptions) =>
+      ipcRenderer.invoke('file:exportPdf', html, filePath, opts) as Promise<{
+        path: string;
+        bytes: number;
+      }>,
     selectFolder: () => ipcRenderer.invoke('dialog:selectFolder') as Promise<string | null>,
     saveFile: (opts) => ipcRenderer.invoke('dialog:saveFile', opts) as Promise<string | null>,
   },
@@ -824,14 +1385,48 @@ contextBridge.exposeInMainWorld('electronAPI', {
     },
   },
   notifications: {
-    show: (options: { title: string; body: string; silent?: boolean; timeoutMs?: number }) =>
-      ipcRenderer.invoke('notifications:show', options) as Promise<boolean>,
+    show: (options: {
+      title: string;
+      body: string;
+      silent?: boolean;
+      timeoutMs?: number;
+      clickPayload?: unknown;
+    }) => ipcRenderer.invoke('notifications:show', options) as Promise<boolean>,
+    onClicked: (callback: (payload: unknown) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, payload: unknown) => callback(payload);
+      ipcRenderer.on('notifications:clicked', handler);
+      return () => ipcRenderer.removeListener('notifications:clicked', handler);
+    },
   },
   notificationLog: {
     load: () => ipcRenderer.invoke('notificationLog:load') as Promise<unknown[]>,
     persist: (items: unknown[]) =>
       ipcRenderer.invoke('notificationLog:persist', items) as Promise<void>,
   },
+  notificationHistory: {
+    record: (items: unknown[]) =>
+      ipcRenderer.invoke('notificationHistory:record', items) as Promise<void>,
+    list: (options?: NotificationHistoryQuery) =>
+      ipcRenderer.invoke('notificationHistory:list', options) as Promise<
+        NotificationHistoryRecord[]
+      >,
+    unreadCount: () => ipcRenderer.invoke('notificationHistory:unreadCount') as Promise<number>,
+    markRead: (entryIds?: string[]) =>
+      ipcRenderer.invoke('notificationHistory:markRead', entryIds) as Promise<void>,
+    dismiss: (entryId: string) =>
+      ipcRenderer.invoke('notificationHistory:dismiss', entryId) as Promise<boolean>,
+  },
+  timeTracking: {
+    begin: (info: WorkSessionInfo) =>
+      ipcRenderer.invoke('timeTracking:begin', info) as Promise<number | null>,
+    heartbeat: (sessionId: number, beat: { playing: boolean; focused: boolean }) =>
+      ipcRenderer.invoke('timeTracking:heartbeat', sessionId, beat) as Promise<number | null>,
+    end: (sessionId: number) => ipcRenderer.invoke('timeTracking:end', sessionId) as Promise<void>,
+    projectTotals: (range?: TimeTrackingRange) =>
+      ipcRenderer.invoke('timeTracking:projectTotals', range) as Promise<ProjectTimeTotals[]>,
+    exportCsv: (range?: TimeTrackingRange, perRecording?: boolean) =>
+      ipcRenderer.invoke('timeTracking:exportCsv', range, perRecording) as Promise<string | null>,
+  },
   mlx: {
     start: (opts: {
       port: number;
@@ -868,4 +1463,138 @@ contextBridge.exposeInMainWorld('electronAPI', {
       return () => ipcRenderer.removeListener('mlx:logLine', handler);
     },
   },
+  nativeBackend: {
+    getState: () => ipcRenderer.invoke('nativeBackend:getState') as Promise<NativeBackendState>,
+    install: (opts: NativeBackendBootstrapOptions) =>
+      ipcRenderer.invoke('nativeBackend:install', opts) as Promise<void>,
+    start: (opts: NativeBackendBootstrapOptions & { port: number; hfToken?: string }) =>
+      ipcRenderer.invoke('nativeBackend:start', opts) as Promise<void>,
+    stop: () => ipcRenderer.invoke('nativeBackend:stop') as Promise<void>,
+    getLogs: (tail?: number) =>
+      ipcRenderer.invoke('nativeBackend:getLogs', tail) as Promise<string[]>,
+    onStatusChanged: (callback: (status: NativeBackendStatus) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, status: NativeBackendStatus) =>
+        callback(status);
+      ipcRenderer.on('nativeBackend:statusChanged', handler);
+      return () => ipcRenderer.removeListener('nativeBackend:statusChanged', handler);
+    },
+    onProgress: (callback: (progress: NativeBackendProgress) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, progress: NativeBackendProgress) =>
+        callback(progress);
+      ipcRenderer.on('nativeBackend:progress', handler);
+      return () => ipcRenderer.removeListener('nativeBackend:progress', handler);
+    },
+  },
+  startup: {
+    getState: () => ipcRenderer.invoke('startup:getState') as Promise<StartupState>,
+    attach: () => ipcRenderer.invoke('startup:attach') as Promise<void>,
+    getReportText: () => ipcRenderer.invoke('startup:getReportText') as Promise<string | null>,
+    onStateChanged: (callback: (state: StartupState) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, state: StartupState) => callback(state);
+      ipcRenderer.on('startup:stateChanged', handler);
+      return () => ipcRenderer.removeListener('startup:stateChanged', handler);
+    },
+  },
+  wsl: {
+    listDistros: () => ipcRenderer.invoke('wsl:listDistros') as Promise<WslDistro[]>,
+    probe: (distro: string) => ipcRenderer.invoke('wsl:probe', distro) as Promise<WslDockerProbe>,
+  },
+  captions: {
+    show: (position?: CaptionPosition) =>
+      ipcRenderer.invoke('captions:show', position) as Promise<void>,
+    hide: () => ipcRenderer.invoke('captions:hide') as Promise<void>,
+    isVisible: () => ipcRenderer.invoke('captions:isVisible') as Promise<boolean>,
+    update: (payload: CaptionPayload) => ipcRenderer.send('captions:update', payload),
+    onVisibilityChanged: (callback: (visible: boolean) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, visible: boolean) => callback(visible);
+      ipcRenderer.on('captions:visibilityChanged', handler);
+      return () => ipcRenderer.removeListener('captions:visibilityChanged', handler);
+    },
+  },
+  cloud: {
+    listSources: () => ipcRenderer.invoke('cloud:listSources') as Promise<CloudSourceState[]>,
+    saveSource: (source: Omit<CloudSource, 'id'> & { id?: string }, secret?: CloudSourceSecret) =>
+      ipcRenderer.invoke('cloud:saveSource', source, secret) as Promise<CloudSourceState>,
+    removeSource: (id: string) => ipcRenderer.invoke('cloud:removeSource', id) as Promise<void>,
+    connect: (id: string) => ipcRenderer.invoke('cloud:connect', id) as Promise<void>,
+    browse: (id: string, folderId?: string) =>
+      ipcRenderer.invoke('cloud:browse', id, folderId) as Promise<CloudEntry[]>,
+    download: (id: string, entries: CloudEntry[]) =>
+      ipcRenderer.invoke('cloud:download', id, entries) as Promise<string[]>,
+    onDownloadProgress: (callback: (progress: CloudDownloadProgress) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, progress: CloudDownloadProgress) =>
+        callback(progress);
+      ipcRenderer.on('cloud:downloadProgress', handler);
+      return () => ipcRenderer.removeListener('cloud:downloadProgress', handler);
+    },
+  },
+  phoneIngest: {
+    getStatus: () => ipcRenderer.invoke('phoneIngest:getStatus') as Promise<PhoneIngestStatus>,
+    setEnabled: (enabled: boolean, port?: number) =>
+      ipcRenderer.invoke('phoneIngest:setEnabled', enabled, port) as Promise<PhoneIngestStatus>,
+    resetPairing: () =>
+      ipcRenderer.invoke('phoneIngest:resetPairing') as Promise<PhoneIngestStatus>,
+    listPending: () => ipcRenderer.invoke('phoneIngest:listPending') as Promise<PhoneIngestFile[]>,
+    markImported: (path: string) =>
+      ipcRenderer.invoke('phoneIngest:markImported', path) as Promise<boolean>,
+    onFileReceived: (callback: (file: PhoneIngestFile) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, file: PhoneIngestFile) => callback(file);
+      ipcRenderer.on('phoneIngest:fileReceived', handler);
+      return () => ipcRenderer.removeListener('phoneIngest:fileReceived', handler);
+    },
+  },
+  cache: {
+    getUsage: () => ipcRenderer.invoke('cache:getUsage') as Promise<CacheUsage>,
+    clear: (category?: CacheCategory) =>
+      ipcRenderer.invoke('cache:clear', category) as Promise<CacheUsage>,
+    setQuota: (category: CacheCategory, mb: number) =>
+      ipcRenderer.invoke('cache:setQuota', category, mb) as Promise<CacheUsage>,
+    setDirectory: (dir: string | null) =>
+      ipcRenderer.invoke('cache:setDirectory', dir) as Promise<CacheUsage>,
+  },
+  followAlong: {
+    getStatus: () => ipcRenderer.invoke('followAlong:getStatus') as Promise<FollowAlongStatus>,
+    start: () => ipcRenderer.invoke('followAlong:start') as Promise<FollowAlongStatus>,
+    stop: () => ipcRenderer.invoke('followAlong:stop') as Promise<void>,
+    update: (payload: FollowAlongPayload) => ipcRenderer.send('followAlong:update', payload),
+  },
+  connectionQuality: {
+    getLatest: () =>
+      ipcRenderer.invoke('connectionQuality:getLatest') as Promise<ConnectionQualityReport | null>,
+    checkNow: () =>
+      ipcRenderer.invoke('connectionQuality:checkNow') as Promise<ConnectionQualityReport>,
+    onReport: (callback: (report: ConnectionQualityReport) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, report: ConnectionQualityReport) =>
+        callback(report);
+      ipcRenderer.on('connectionQuality:report', handler);
+      return () => ipcRenderer.removeListener('connectionQuality:report', handler);
+    },
+  },
+  serverIdle: {
+    getStatus: () => ipcRenderer.invoke('serverIdle:getStatus') as Promise<ServerIdleStatus>,
+    wake: () => ipcRenderer.invoke('serverIdle:wake') as Promise<boolean>,
+    noteActivity: () => ipcRenderer.invoke('serverIdle:noteActivity') as Promise<void>,
+    onStatus: (callback: (status: ServerIdleStatus) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, status: ServerIdleStatus) =>
+        callback(status);
+      ipcRenderer.on('serverIdle:status', handler);
+      return () => ipcRenderer.removeListener('serverIdle:status', handler);
+    },
+  },
+  power: {
+    getStatus: () => ipcRenderer.invoke('power:getStatus') as Promise<RemotePowerStatus>,
+    wake: (profile?: ConnectionProfile) =>
+      ipcRenderer.invoke('power:wake', profile) as Promise<boolean>,
+    sleepIfIdle: () => ipcRenderer.invoke('power:sleepIfIdle') as Promise<boolean>,
+    onStatus: (callback: (status: RemotePowerStatus) => void) => {
+      const handler = (_event: Electron.IpcRendererEvent, status: RemotePowerStatus) =>
+        callback(status);
+      ipcRenderer.on('power:status', handler);
+      return () => ipcRenderer.removeListener('power:status', handler);
+    },
+  },
+  settingsBundle: {
+    exportBundle: () => ipcRenderer.invoke('settings:exportBundle'),
+    importBundle: (remaps?: PathRemap[]) => ipcRenderer.invoke('settings:importBundle', remaps),
+  },
 } satisfies ElectronAPI);
@@ -0,0 +1,360 @@
+/**
+ * Minimal QR Code encoder (ISO/IEC 18004) for the phone-pairing link.
+ *
+ * Byte mode, error-correction level M, versions 1–10 (up to 213 bytes) —
+ * enough for a LAN URL with a pairing token. The mask is chosen with the
+ * standard penalty rules so phone cameras lock on quickly. Output is a
+ * self-contained SVG the renderer can show as an <img>.
+ *
+ * Adapted from QR Code generator library (https://github.com/nayuki/QR-Code-generator)
+ * — Reed-Solomon ECC, codeword interleaving, function patterns, masking and
+ * penalty scoring, cut down to byte mode at level M.
+ * Copyright (c) Project Nayuki — MIT License.
+ */
+
+const MAX_VERSION = 10;
+// Level M, indexed by version (index 0 unused).
+const ECC_CODEWORDS_PER_BLOCK = [0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26];
+const NUM_ECC_BLOCKS = [0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5];
+const FORMAT_ECC_M = 0;
+
+type Grid = boolean[][];
+
+function rawDataModules(version: number): number {
+  let result = (16 * version + 128) * version + 64;
+  if (version >= 2) {
+    const numAlign = Math.floor(version / 7) + 2;
+    result -= (25 * numAlign - 10) * numAlign - 55;
+    if (version >= 7) result -= 36;
+  }
+  return result;
+}
+
+function dataCodewords(version: number): number {
+  return (
+    Math.floor(rawDataModules(version) / 8) -
+    ECC_CODEWORDS_PER_BLOCK[version] * NUM_ECC_BLOCKS[version]
+  );
+}
+
+function alignmentPositions(version: number): number[] {
+  if (version === 1) return [];
+  const size = version * 4 + 17;
+  const numAlign = Math.floor(version / 7) + 2;
+  const step = Math.ceil((version * 4 + 4) / (numAlign * 2 - 2)) * 2;
+  const result = [6];
+  for (let pos = size - 7; result.length < numAlign; pos -= step) result.splice(1, 0, pos);
+  return result;
+}
+
+// ─── Reed–Solomon over GF(2^8), polynomial 0x11D ────────────────────────────
+
+function gfMultiply(x: number, y: number): number {
+  let z = 0;
+  for (let i = 7; i >= 0; i--) {
+    z = (z << 1) ^ ((z >>> 7) * 0x11d);
+    z ^= ((y >>> i) & 1) * x;
+  }
+  return z;
+}
+
+function rsDivisor(degree: number): number[] {
+  const result = new Array<number>(degree).fill(0);
+  result[degree - 1] = 1;
+  let root = 1;
+  for (let i = 0; i < degree; i++) {
+    for (let j = 0; j < degree; j++) {
+      result[j] = gfMultiply(result[j], root);
+      if (j + 1 < degree) result[j] ^= result[j + 1];
+    }
+    root = gfMultiply(root, 0x02);
+  }
+  return result;
+}
+
+function rsRemainder(data: number[], divisor: number[]): number[] {
+  const result = divisor.map(() => 0);
+  for (const b of data) {
+    const factor = b ^ (result.shift() as number);
+    result.push(0);
+    divisor.forEach((coef, i) => (result[i] ^= gfMultiply(coef, factor)));
+  }
+  return result;
+}
+
+// ─── Codewords ───────────────────────────────────────────────────────────────
+
+function encodeData(bytes: Uint8Array, version: number): number[] {
+  const bits: number[] = [];
+  const push = (value: number, length: number) => {
+    for (let i = length - 1; i >= 0; i--) bits.push((value >>> i) & 1);
+  };
+  push(0b0100, 4);
+  push(bytes.length, version <= 9 ? 8 : 16);
+  bytes.forEach((b) => push(b, 8));
+
+  const capacity = dataCodewords(version) * 8;
+  push(0, Math.min(4, capacity - bits.length));
+  push(0, (8 - (bits.length % 8)) % 8);
+  for (let pad = 0xec; bits.length < capacity; pad ^= 0xec ^ 0x11) push(pad, 8);
+
+  const codewords: number[] = [];
+  for (let i = 0; i < bits.length; i += 8) {
+    codewords.push(bits.slice(i, i + 8).reduce((acc, bit) => (acc << 1) | bit, 0));
+  }
+  return codewords;
+}
+
+function addEccAndInterleave(data: number[], version: number): number[] {
+  const numBlocks = NUM_ECC_BLOCKS[version];
+  const eccLen = ECC_CODEWORDS_PER_BLOCK[version];
+  const rawCodewords = Math.floor(rawDataModules(version) / 8);
+  const numShortBlocks = numBlocks - (rawCodewords % numBlocks);
+  const shortBlockLen = Math.floor(rawCodewords / numBlocks);
+  const divisor = rsDivisor(eccLen);
+
+  const blocks: number[][] = [];
+  for (let i = 0, k = 0; i < numBlocks; i++) {
+    const dat = data.slice(k, k + shortBlockLen - eccLen + (i < numShortBlocks ? 0 : 1));
+    k += dat.length;
+    const ecc = rsRemainder(dat, divisor);
+    if (i < numShortBlocks) dat.push(0);
+    blocks.push(dat.concat(ecc));
+  }
+
+  const result: number[] = [];
+  for (let i = 0; i < blocks[0].length; i++) {
+    blocks.forEach((block, j) => {
+      // Skip the placeholder byte in short blocks.
+      if (i !== shortBlockLen - eccLen || j >= numShortBlocks) result.push(block[i]);
+    });
+  }
+  return result;
+}
+
+// ─── Module placement ────────────────────────────────────────────────────────
+
+class Matrix {
+  readonly size: number;
+  readonly modules: Grid;
+  readonly isFunction: Grid;
+
+  constructor(readonly version: number) {
+    this.size = version * 4 + 17;
+    this.modules = Array.from({ length: this.size }, () => new Array(this.size).fill(false));
+    this.isFunction = Array.from({ length: this.size }, () => new Array(this.size).fill(false));
+    this.drawFunctionPatterns();
+  }
+
+  private setFunction(x: number, y: number, dark: boolean): void {
+    this.modules[y][x] = dark;
+    this.isFunction[y][x] = true;
+  }
+
+  private drawFunctionPatterns(): void {
+    const { size } = this;
+    for (let i = 0; i < size; i++) {
+      this.setFunction(6, i, i % 2 === 0);
+      this.setFunction(i, 6, i % 2 === 0);
+    }
+    for (const [cx, cy] of [
+      [3, 3],
+      [size - 4, 3],
+      [3, size - 4],
+    ]) {
+      for (let dy = -4; dy <= 4; dy++) {
+        for (let dx = -4; dx <= 4; dx++) {
+          const x = cx + dx;
+          const y = cy + dy;
+          const dist = Math.max(Math.abs(dx), Math.abs(dy));
+          if (x >= 0 && x < size && y >= 0 && y < size) {
+            this.setFunction(x, y, dist !== 2 && dist !== 4);
+          }
+        }
+      }
+    }
+    const align = alignmentPositions(this.version);
+    const last = align.length - 1;
+    align.forEach((ax, i) =>
+      align.forEach((ay, j) => {
+        // The three corners are taken by finder patterns.
+        if ((i === 0 && j === 0) || (i === 0 && j === last) || (i === last && j === 0)) return;
+        for (let dy = -2; dy <= 2; dy++) {
+          for (let dx = -2; dx <= 2; dx++) {
+            this.setFunction(ax + dx, ay + dy, Math.max(Math.abs(dx), Math.abs(dy)) !== 1);
+          }
+        }
+      }),
+    );
+    this.drawFormatBits(0); // reserve the area; redrawn once the mask is chosen
+    this.drawVersionBits();
+  }
+
+  drawFormatBits(mask: number): void {
+    const data = (FORMAT_ECC_M << 3) | mask;
+    let rem = data;
+    for (let i = 0; i < 10; i++) rem = (rem << 1) ^ ((rem >>> 9) * 0x537);
+    const bits = ((data << 10) | rem) ^ 0x5412;
+    const bit = (i: number) => ((bits >>> i) & 1) !== 0;
+    const { size } = this;
+
+    for (let i = 0; i <= 5; i++) this.setFunction(8, i, bit(i));
+    this.setFunction(8, 7, bit(6));
+    this.setFunction(8, 8, bit(7));
+    this.setFunction(7, 8, bit(8));
+    for (let i = 9; i < 15; i++) this.setFunction(14 - i, 8, bit(i));
+
+    for (let i = 0; i < 8; i++) this.setFunction(size - 1 - i, 8, bit(i));
+    for (let i = 8; i < 15; i++) this.setFunction(8, size - 15 + i, bit(i));
+    this.setFunction(8, size - 8, true);
+  }
+
+  private drawVersionBits(): void {
+    if (this.version < 7) return;
+    let rem = this.version;
+    for (let i = 0; i < 12; i++) rem = (rem << 1) ^ ((rem >>> 11) * 0x1f25);
+    const bits = (this.version << 12) | rem;
+    for (let i = 0; i < 18; i++) {
+      const dark = ((bits >>> i) & 1) !== 0;
+      const a = this.size - 11 + (i % 3);
+      const b = Math.floor(i / 3);
+      this.setFunction(a, b, dark);
+      this.setFunction(b, a, dark);
+    }
+  }
+
+  drawCodewords(codewords: number[]): void {
+    const { size } = this;
+    let i = 0;
+    for (let right = size - 1; right >= 1; right -= 2) {
+      if (right === 6) right = 5;
+      const upward = ((right + 1) & 2) === 0;
+      for (let vert = 0; vert < size; vert++) {
+        for (let j = 0; j < 2; j++) {
+          const x = right - j;
+          const y = upward ? size - 1 - vert : vert;
+          if (!this.isFunction[y][x] && i < codewords.length * 8) {
+            this.modules[y][x] = ((codewords[i >>> 3] >>> (7 - (i & 7))) & 1) !== 0;
+            i++;
+          }
+        }
+      }
+    }
+  }
+
+  applyMask(mask: number): void {
+    for (let y = 0; y < this.size; y++) {
+      for (let x = 0; x < this.size; x++) {
+        if (!this.isFunction[y][x] && maskBit(mask, x, y)) this.modules[y][x] = !this.modules[y][x];
+      }
+    }
+  }
+}
+
+function maskBit(mask: number, x: number, y: number): boolean {
+  switch (mask) {
+    case 0:
+      return (x + y) % 2 === 0;
+    case 1:
+      return y % 2 === 0;
+    case 2:
+      return x % 3 === 0;
+    case 3:
+      return (x + y) % 3 === 0;
+    case 4:
+      return (Math.floor(x / 3) + Math.floor(y / 2)) % 2 === 0;
+    case 5:
+      return ((x * y) % 2) + ((x * y) % 3) === 0;
+    case 6:
+      return (((x * y) % 2) + ((x * y) % 3)) % 2 === 0;
+    default:
+      return (((x + y) % 2) + ((x * y) % 3)) % 2 === 0;
+  }
+}
+
+function penalty(modules: Grid): number {
+  const size = modules.length;
+  const lines: boolean[][] = [];
+  for (let i = 0; i < size; i++) {
+    lines.push(modules[i]);
+    lines.push(modules.map((row) => row[i]));
+  }
+
+  let score = 0;
+  for (const line of lines) {
+    // N1: runs of five or more same-coloured modules.
+    let run = 1;
+    for (let i = 1; i <= size; i++) {
+      if (i < size && line[i] === line[i - 1]) {
+        run++;
+      } else {
+        if (run >= 5) score += run - 2;
+        run = 1;
+      }
+    }
+    // N3: finder-like 1:1:3:1:1 with four light modules on either side.
+    const text = line.map((dark) => (dark ? '1' : '0')).join('');
+    for (const pattern of ['10111010000', '00001011101']) {
+      for (let at = text.indexOf(pattern); at !== -1; at = text.indexOf(pattern, at + 1)) {
+        score += 40;
+      }
+    }
+  }
+  // N2: 2×2 blocks of one colour.
+  for (let y = 0; y < size - 1; y++) {
+    for (let x = 0; x < size - 1; x++) {
+      const c = modules[y][x];
+      if (c === modules[y][x + 1] && c === modules[y + 1][x] && c === modules[y + 1][x + 1]) {
+        score += 3;
+      }
+    }
+  }
+  // N4: dark/light balance.
+  const dark = modules.reduce((n, row) => n + row.filter(Boolean).length, 0);
+  const total = size * size;
+  score += (Math.ceil(Math.abs(dark * 20 - total * 10) / total) - 1) * 10;
+  return score;
+}
+
+/** Encode `text` (UTF-8) as a QR module grid; `true` is a dark module. */
+export function encodeQr(text: string): Grid {
+  const bytes = new TextEncoder().encode(text);
+  let version = 1;
+  while (4 + (version <= 9 ? 8 : 16) + bytes.length * 8 > dataCodewords(version) * 8) {
+    if (++version > MAX_VERSION) throw new Error('Text too long for a QR code');
+  }
+  const codewords = addEccAndInterleave(encodeData(bytes, version), version);
+
+  let best: Grid | null = null;
+  let bestScore = Infinity;
+  for (let mask = 0; mask < 8; mask++) {
+    const matrix = new Matrix(version);
+    matrix.drawCodewords(codewords);
+    matrix.applyMask(mask);
+    matrix.drawFormatBits(mask);
+    const score = penalty(matrix.modules);
+    if (score < bestScore) {
+      best = matrix.modules;
+      bestScore = score;
+    }
+  }
+  return best as Grid;
+}
+
+/** Render `text` as a black-on-white SVG QR code with a four-module quiet zone. */
+export function qrSvg(text: string): string {
+  const grid = encodeQr(text);
+  const quiet = 4;
+  const size = grid.length + quiet * 2;
+  let path = '';
+  grid.forEach((row, y) =>
+    row.forEach((dark, x) => {
+      if (dark) path += `M${x + quiet} ${y + quiet}h1v1h-1z`;
+    }),
+  );
+  return (
+    `<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 ${size} ${size}" ` +
+    `shape-rendering="crispEdges"><rect width="${size}" height="${size}" fill="#fff"/>` +
+    `<path d="${path}" fill="#000"/></svg>`
+  );
+}
@@ -0,0 +1,208 @@
+/**
+ * Remote power control — wake a sleeping GPU server with a Wake-on-LAN
+ * magic packet and, optionally, suspend it over SSH once the jobs are done.
+ *
+ * The renderer calls {@link RemotePower.wake} before queued work when the
+ * remote server does not answer (`power.autoWake`); it resolves once the
+ * profile's /health responds. {@link RemotePower.sleepIfIdle} runs after the
+ * import queue drains (`power.sleepAfterJobs`) and only suspends a machine
+ * this session woke, and only while its job queue is empty — a server that
+ * was already up, or that someone else is using, is left alone.
+ */
+
+import dgram from 'dgram';
+import type { ConnectionProfile } from './connectionTuning.js';
+
+export interface RemotePowerSettings {
+  /** MAC address of the server's wake-capable NIC; empty disables waking. */
+  macAddress: string;
+  /** Where the packet goes — the LAN broadcast address or the server's subnet broadcast. */
+  broadcastAddress: string;
+  port: number;
+  /** Seconds to wait for /health after the packet. */
+  wakeTimeoutSeconds: number;
+  /** `user@host` for the suspend command; empty disables it. */
+  sshTarget: string;
+  sshCommand: string;
+}
+
+export type PowerState = 'unknown' | 'waking' | 'awake' | 'suspending' | 'suspended';
+
+export interface PowerStatus {
+  state: PowerState;
+  /** This session sent the packet that brought the server up. */
+  wokenByUs: boolean;
+  error: string | null;
+}
+
+export interface RemotePowerDeps {
+  settings: () => RemotePowerSettings;
+  /** True when the profile's /health answers. */
+  probe: (profile: ConnectionProfile) => Promise<boolean>;
+  /** True while a job runs or waits on the server. */
+  serverBusy: () => Promise<boolean>;
+  sendPacket: (packet: Buffer, address: string, port: number) => Promise<void>;
+  runSsh: (target: string, command: string) => Promise<void>;
+  emit: (status: PowerStatus) => void;
+  delay?: (ms: number) => Promise<void>;
+  now?: () => number;
+}
+
+export const DEFAULT_WOL_PORT = 9;
+export const DEFAULT_SUSPEND_COMMAND = 'sudo systemctl suspend';
+const PROBE_INTERVAL_MS = 3_000;
+const RESEND_INTERVAL_MS = 30_000;
+
+/** Six bytes from `aa:bb:cc:dd:ee:ff`, `AA-BB-…` or `aabbccddeeff`; null if malformed. */
+export function parseMacAddress(value: string): Buffer | null {
+  const hex = value.trim().replace(/[:.-]/g, '');
+  if (!/^[0-9a-fA-F]{12}$/.test(hex)) return null;
+  return Buffer.from(hex, 'hex');
+}
+
+/** Six 0xFF bytes followed by the MAC sixteen times. */
+export function magicPacket(mac: Buffer): Buffer {
+  return Buffer.concat([Buffer.alloc(6, 0xff), ...Array.from({ length: 16 }, () => mac)]);
+}
+
+/** Send one UDP broadcast datagram. */
+export function sendUdpBroadcast(packet: Buffer, address: string, port: number): Promise<void> {
+  return new Promise((resolve, reject) => {
+    const socket = dgram.createSocket(address.includes(':') ? 'udp6' : 'udp4');
+    socket.once('error', (err) => {
+      socket.close();
+      reject(err);
+    });
+    socket.bind(() => {
+      socket.setBroadcast(true);
+      socket.send(packet, port, address, (err) => {
+        socket.close();
+        if (err) reject(err);
+        else resolve();
+      });
+    });
+  });
+}
+
+/**
+ * Non-interactive ssh: key auth only, so a password prompt fails instead of
+ * hanging. A target starting with `-` would be read as an ssh option (e.g.
+ * `-oProxyCommand=…` runs a local command), so it is refused, and `--` ends
+ * option parsing before the target.
+ */
+export function sshArgs(target: string, command: string): string[] {
+  if (target.startsWith('-')) throw new Error(`Invalid ssh target: ${target}`);
+  return ['-o', 'BatchMode=yes', '-o', 'ConnectTimeout=10', '--', target, command];
+}
+
+/**
+ * Whether an ssh run of the suspend command failed. Exit 255 is ssh's own
+ * error, but the connection dropping as the machine suspends ends the same
+ * way — only a connect or auth failure counts there.
+ */
+export function sshSuspendFailed(exitCode: number, stderr: string): boolean {
+  if (exitCode === 0) return false;
+  if (exitCode !== 255) return true;
+  return /permission denied|could not resolve|connection refused|connection timed out/i.test(
+    stderr,
+  );
+}
+
+export class RemotePower {
+  private readonly deps: RemotePowerDeps;
+  private readonly delay: (ms: number) => Promise<void>;
+  private readonly now: () => number;
+  private waking: Promise<boolean> | null = null;
+  private current: PowerStatus = { state: 'unknown', wokenByUs: false, error: null };
+
+  constructor(deps: RemotePowerDeps) {
+    this.deps = deps;
+    this.delay = deps.delay ?? ((ms) => new Promise((resolve) => setTimeout(resolve, ms)));
+    this.now = deps.now ?? Date.now;
+  }
+
+  status(): PowerStatus {
+    return this.current;
+  }
+
+  /**
+   * Bring the server behind `profile` up. Resolves false when it already
+   * answered, true once it answers after a magic packet; throws when no MAC
+   * is configured or it is still down after `wakeTimeoutSeconds`. Concurrent
+   * callers share one wake-up.
+   */
+  wake(profile: ConnectionProfile): Promise<boolean> {
+    if (!this.waking) {
+      this.waking = this.runWake(profile).finally(() => {
+        this.waking = null;
+      });
+    }
+    return this.waking;
+  }
+
+  /** Suspend the server over SSH if this session woke it and nothing is queued. */
+  async sleepIfIdle(): Promise<boolean> {
+    const { sshTarget, sshCommand } = this.deps.settings();
+    if (!sshTarget.trim() || !this.current.wokenByUs || this.current.state !== 'awake') {
+      return false;
+    }
+    if (await this.deps.serverBusy()) return false;
+    this.set({ state: 'suspending', error: null });
+    try {
+      await this.deps.runSsh(sshTarget.trim(), sshCommand.trim() || DEFAULT_SUSPEND_COMMAND);
+      this.set({ state: 'suspended', wokenByUs: false });
+      return true;
+    } catch (err) {
+      this.set({ state: 'awake', error: errorMessage(err) });
+      throw err;
+    }
+  }
+
+  private async runWake(profile: ConnectionProfile): Promise<boolean> {
+    if (await this.deps.probe(profile)) {
+      if (this.current.state !== 'awake') this.set({ state: 'awake', error: null });
+      return false;
+    }
+    const settings = this.deps.settings();
+    const mac = parseMacAddress(settings.macAddress);
+    if (!mac) {
+      const error = 'No valid MAC address is set for Wake-on-LAN';
+      this.set({ state: 'unknown', error });
+      throw new Error(error);
+    }
+    this.set({ state: 'waking', error: null });
+    const packet = magicPacket(mac);
+    const timeoutSeconds = Math.max(10, settings.wakeTimeoutSeconds);
+    const deadline = this.now() + timeoutSeconds * 1000;
+    let lastSent = -Infinity;
+    try {
+      while (this.now() < deadline) {
+        // Resend now and then: the first packet can race the NIC's link-up.
+        if (this.now() - lastSent >= RESEND_INTERVAL_MS) {
+          await this.deps.sendPacket(packet, settings.broadcastAddress, settings.port);
+          lastSent = this.now();
+        }
+        await this.delay(PROBE_INTERVAL_MS);
+        if (await this.deps.probe(profile)) {
+          this.set({ state: 'awake', wokenByUs: true });
+          return true;
+        }
+      }
+    } catch (err) {
+      this.set({ state: 'unknown', error: errorMessage(err) });
+      throw err;
+    }
+    const error = `The server did not answer within ${timeoutSeconds} s`;
+    this.set({ state: 'unknown', error });
+    throw new Error(error);
+  }
+
+  private set(patch: Partial<PowerStatus>): void {
+    this.current = { ...this.current, ...patch };
+    this.deps.emit(this.current);
+  }
+}
+
+function errorMessage(err: unknown): string {
+  return err instanceof Error ? err.message : String(err);
+}
@@ -0,0 +1,232 @@
+/**
+ * Script hooks — run the user's own shell commands on lifecycle events, for
+ * automation that is easier to write as a script than as a plugin.
+ *
+ *   on_job_complete     an import-queue job finished (session or notebook)
+ *   on_recording_saved  a recording landed in the notebook
+ *   on_export           a transcript was written to a file the user picked
+ *
+ * Each hook is a command line run through the system shell, so arguments,
+ * `~` and pipes work as in a terminal. The event arrives as one line of
+ * JSON on stdin — `{ "event", "firedAt", ...payload }` — and in the
+ * TRANSCRIPTIONSUITE_HOOK_EVENT environment variable. Hooks for one event
+ * run in parallel; a hook that outlives `hooks.timeoutSeconds` is killed
+ * with its whole process group. Every run is appended to `hooks.log` in the
+ * logs dir, exit code and output included.
+ */
+
+import { spawn } from 'child_process';
+import fs from 'fs';
+import path from 'path';
+
+export const HOOK_EVENTS = ['on_job_complete', 'on_recording_saved', 'on_export'] as const;
+export type HookEvent = (typeof HOOK_EVENTS)[number];
+
+export interface HookScript {
+  id: string;
+  event: HookEvent;
+  command: string;
+  enabled: boolean;
+}
+
+export interface HookRun {
+  scriptId: string;
+  event: HookEvent;
+  command: string;
+  startedAt: number;
+  durationMs: number;
+  /** Null when the command was killed or could not start. */
+  exitCode: number | null;
+  timedOut: boolean;
+  /** Why the command could not start, e.g. a spawn error. */
+  error: string | null;
+  /** The tail of stdout + stderr. */
+  output: string;
+}
+
+export const SCRIPTS_KEY = 'hooks.scripts';
+export const TIMEOUT_KEY = 'hooks.timeoutSeconds';
+export const DEFAULT_TIMEOUT_SECONDS = 30;
+export const HOOK_LOG_FILE = 'hooks.log';
+
+const OUTPUT_TAIL_CHARS = 4096;
+const HISTORY_SIZE = 50;
+
+/** Minimal store interface — accepts any electron-store instance. */
+interface HookStore {
+  get(key: string): unknown;
+}
+
+export function isHookEvent(value: unknown): value is HookEvent {
+  return typeof value === 'string' && (HOOK_EVENTS as readonly string[]).includes(value);
+}
+
+/** The stored hook list, dropping malformed entries. */
+export function parseScripts(value: unknown): HookScript[] {
+  if (!Array.isArray(value)) return [];
+  return value.flatMap((raw): HookScript[] => {
+    const s = raw as Partial<HookScript> | null;
+    if (!s || typeof s.id !== 'string' || !isHookEvent(s.event)) return [];
+    if (typeof s.command !== 'string' || !s.command.trim()) return [];
+    return [{ id: s.id, event: s.event, command: s.command, enabled: s.enabled !== false }];
+  });
+}
+
+export function hookTimeoutMs(store: HookStore): number {
+  const seconds = Number(store.get(TIMEOUT_KEY));
+  const clamped = Number.isFinite(seconds) ? Math.min(Math.max(seconds, 1), 600) : NaN;
+  return (Number.isNaN(clamped) ? DEFAULT_TIMEOUT_SECONDS : clamped) * 1000;
+}
+
+type ScriptResult = Pick<HookRun, 'exitCode' | 'timedOut' | 'error' | 'output'>;
+
+/** Run one command with `input` on stdin, killing it after `timeoutMs`. */
+export function runScript(
+  command: string,
+  input: string,
+  options: { timeoutMs: number; env?: NodeJS.ProcessEnv },
+): Promise<ScriptResult> {
+  return new Promise((resolve) => {
+    let output = '';
+    let timedOut = false;
+    const collect = (chunk: Buffer) => {
+      output = (output + chunk.toString('utf-8')).slice(-OUTPUT_TAIL_CHARS);
+    };
+    // Its own process group on POSIX, so a timeout also takes down whatever
+    // the shell started.
+    const child = spawn(command, {
+      shell: true,
+      detached: process.platform !== 'win32',
+      windowsHide: true,
+      env: { ...process.env, ...options.env },
+      stdio: ['pipe', 'pipe', 'pipe'],
+    });
+    const kill = () => {
+      try {
+        if (process.platform !== 'win32' && child.pid) process.kill(-child.pid, 'SIGKILL');
+        else child.kill('SIGKILL');
+      } catch {
+        // Already gone.
+      }
+    };
+    const timer = setTimeout(() => {
+      timedOut = true;
+      kill();
+    }, options.timeoutMs);
+
+    child.stdout.on('data', collect);
+    child.stderr.on('data', collect);
+    // A script that never reads stdin closes the pipe early; that's fine.
+    child.stdin.on('error', () => {});
+    child.stdin.end(input);
+
+    child.once('error', (err) => {
+      clearTimeout(timer);
+      resolve({ exitCode: null, timedOut: false, error: err.message, output });
+    });
+    child.once('close', (code) => {
+      clearTimeout(timer);
+      resolve({ exitCode: timedOut ? null : code, timedOut, error: null, output });
+    });
+  });
+}
+
+export interface ScriptHooksDeps {
+  store: HookStore;
+  logDir: string;
+  /** Called after every run, e.g. to update the settings view. */
+  onRun?: (run: HookRun) => void;
+}
+
+export class ScriptHooks {
+  private readonly runs: HookRun[] = [];
+
+  constructor(private readonly deps: ScriptHooksDeps) {}
+
+  scripts(): HookScript[] {
+    return parseScripts(this.deps.store.get(SCRIPTS_KEY));
+  }
+
+  /** The most recent runs, newest first. */
+  history(): HookRun[] {
+    return [...this.runs];
+  }
+
+  /** Run every enabled hook for `event`. Never rejects — failures are in the runs. */
+  fire(event: HookEvent, payload: Record<string, unknown> = {}): Promise<HookRun[]> {
+    const scripts = this.scripts().filter((s) => s.enabled && s.event === event);
+    return Promise.all(scripts.map((script) => this.run(script, payload)));
+  }
+
+  /** Run one hook (enabled or not) with an example payload marked `"test": true`. */
+  async test(id: string): Promise<HookRun> {
+    const script = this.scripts().find((s) => s.id === id);
+    if (!script) throw new Error(`Hook "${id}" does not exist`);
+    return this.run(script, { test: true, ...EXAMPLE_PAYLOADS[script.event] });
+  }
+
+  private async run(script: HookScript, payload: Record<string, unknown>): Promise<HookRun> {
+    const startedAt = Date.now();
+    const input = JSON.stringify({
+      ...payload,
+      event: script.event,
+      firedAt: new Date(startedAt).toISOString(),
+    });
+    const result = await runScript(script.command, `${input}\n`, {
+      timeoutMs: hookTimeoutMs(this.deps.store),
+      env: { TRANSCRIPTIONSUITE_HOOK_EVENT: script.event },
+    });
+    const run: HookRun = {
+      scriptId: script.id,
+      event: script.event,
+      command: script.command,
+      startedAt,
+      durationMs: Date.now() - startedAt,
+      ...result,
+    };
+    this.runs.unshift(run);
+    this.runs.length = Math.min(this.runs.length, HISTORY_SIZE);
+    await this.log(run);
+    this.deps.onRun?.(run);
+    return run;
+  }
+
+  private async log(run: HookRun): Promise<void> {
+    const outcome = run.timedOut
+      ? 'timed out'
+      : run.error
+        ? `failed to start: ${run.error}`
+        : `exit ${run.exitCode}`;
+    const lines = [
+      `${new Date(run.startedAt).toISOString()} [${run.event}] ${run.command} — ${outcome}` +
+        ` in ${run.durationMs} ms`,
+      ...run.output
+        .trimEnd()
+        .split('\n')
+        .filter(Boolean)
+        .map((line) => `    ${line}`),
+    ];
+    try {
+      await fs.promises.mkdir(this.deps.logDir, { recursive: true });
+      await fs.promises.appendFile(
+        path.join(this.deps.logDir, HOOK_LOG_FILE),
+        `${lines.join('\n')}\n`,
+      );
+    } catch (err) {
+      console.warn('[ScriptHooks] Could not write the hook log:', err);
+    }
+  }
+}
+
+/** What "Test" sends, so a script can be tried without waiting for the event. */
+const EXAMPLE_PAYLOADS: Record<HookEvent, Record<string, unknown>> = {
+  on_job_complete: {
+    jobId: 'job-example',
+    kind: 'session',
+    source: 'meeting.mp3',
+    outputs: ['/path/to/meeting.srt'],
+    recordingId: null,
+  },
+  on_recording_saved: { recordingId: 0, source: 'meeting.mp3' },
+  on_export: { path: '/path/to/meeting.txt', format: 'txt' },
+};
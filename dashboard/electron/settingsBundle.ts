@@ -0,0 +1,338 @@
+/**
+ * Settings bundles — copy a setup to another machine.
+ *
+ * {@link exportSettings} writes the dashboard settings, connection profiles,
+ * vocabularies (output filter lists, keyword alerts) and rules (script
+ * hooks) as one JSON file. Secrets never leave the machine: tokens,
+ * passwords and sealed keys are dropped, as is per-machine state (update
+ * checks, usage counters, which watchers were running). Paths under the home
+ * directory are written as `~/…`, so they land in the new user's home.
+ *
+ * The bundle is signed with this installation's Ed25519 key
+ * (`settings-signing-key.pem` in the config dir) and carries the public
+ * key. {@link importSettings} refuses a bundle whose signature does not
+ * match — an edited or truncated file changes nothing — and reports the
+ * signer's fingerprint so the user can tell where it came from. A valid
+ * signature only says the file is intact, not that its author is trusted, so
+ * anything that runs commands or touches files on its own arrives switched
+ * off: imported hooks are disabled, and importing an SSH suspend target,
+ * caption file or watch folder turns off the switch that acts on it
+ * ({@link GUARDED_KEYS}). The user reviews the values and turns them on.
+ */
+
+import crypto from 'crypto';
+import fs from 'fs';
+import path from 'path';
+
+export const SETTINGS_BUNDLE_FORMAT = 'transcriptionsuite-settings';
+export const SETTINGS_BUNDLE_VERSION = 1;
+export const SIGNING_KEY_FILE = 'settings-signing-key.pem';
+
+export type BundleSection = 'settings' | 'profiles' | 'vocabularies' | 'rules';
+
+export interface SettingsBundle {
+  format: typeof SETTINGS_BUNDLE_FORMAT;
+  version: number;
+  createdAt: string;
+  appVersion: string;
+  sections: Record<BundleSection, Record<string, unknown>>;
+  signature: { publicKey: string; value: string };
+}
+
+export interface SettingsStore {
+  readonly store: Record<string, unknown>;
+  has(key: string): boolean;
+  set(key: string, value: unknown): void;
+}
+
+/** A path prefix to rewrite on import, e.g. `D:\Audio` → `/mnt/audio`. */
+export interface PathRemap {
+  from: string;
+  to: string;
+}
+
+export interface ImportOptions {
+  home: string;
+  remaps?: PathRemap[];
+  /** Own public key fingerprint, to tell a bundle from this machine apart. */
+  ownFingerprint?: string;
+  exists?: (p: string) => boolean;
+}
+
+export interface ImportPlan {
+  values: Record<string, unknown>;
+  counts: Record<BundleSection, number>;
+  /** Imported paths that do not exist here. */
+  missingPaths: string[];
+  disabledHooks: number;
+  /** Switches turned off because the bundle sets what they act on. */
+  switchedOff: string[];
+  signer: string;
+  signedHere: boolean;
+}
+
+export type ImportResult =
+  | ({ ok: true } & Omit<ImportPlan, 'values'>)
+  | { ok: false; error: string };
+
+const SECTION_KEYS: [BundleSection, string[]][] = [
+  ['profiles', ['connection.', 'power.', 'identity.', 'dispatch.']],
+  ['vocabularies', ['output.filter.words', 'output.filter.replacements', 'live.watchKeywords']],
+  ['rules', ['hooks.']],
+];
+
+const SECRET_KEY = /token|password|secret|masterkey/i;
+
+/** Per-machine state rather than preferences. */
+const MACHINE_KEYS = [
+  'updates.',
+  'app.cumulativeUsageMs',
+  'app.starPopupShown',
+  'app.sampleJobOffered',
+  'app.modelSelectionOnboardingCompleted',
+  'housekeeping.lastRunDate',
+  'server.containerExistsLastSeen',
+  'server.gpuAutoDetectDone',
+  'server.mlxDesiredRunning',
+  'server.containerRuntime',
+  'server.wslDistro',
+  'dispatch.lastBenchmark',
+  'sessionImport.serverCopies',
+  'folderWatch.sessionWatchActive',
+  'folderWatch.notebookWatchActive',
+];
+
+export const PATH_KEYS = [
+  'folderWatch.sessionPath',
+  'folderWatch.notebookPath',
+  'cache.directory',
+  'captions.textFile.path',
+];
+
+/**
+ * Settings that make the app run a command or read or write a path by
+ * themselves, each with the switch that arms it. The switch is imported as
+ * off whenever the bundle carries any of them.
+ */
+export const GUARDED_KEYS: Record<string, string> = {
+  'power.sleepAfterJobs': 'power.sleepAfterJobs',
+  'power.sshTarget': 'power.sleepAfterJobs',
+  'power.sshCommand': 'power.sleepAfterJobs',
+  'captions.textFile.enabled': 'captions.textFile.enabled',
+  'captions.textFile.path': 'captions.textFile.enabled',
+  'folderWatch.sessionPath': 'folderWatch.sessionWatchActive',
+  'folderWatch.notebookPath': 'folderWatch.notebookWatchActive',
+};
+
+const matches = (key: string, prefixes: string[]) =>
+  prefixes.some((prefix) => key === prefix || key.startsWith(prefix));
+
+export function isExportable(key: string): boolean {
+  return !key.startsWith('__') && !SECRET_KEY.test(key) && !matches(key, MACHINE_KEYS);
+}
+
+function sectionOf(key: string): BundleSection {
+  return SECTION_KEYS.find(([, prefixes]) => matches(key, prefixes))?.[0] ?? 'settings';
+}
+
+/** `~/…` for a path inside `home`, forward slashes either way. */
+export function portablePath(value: string, home: string): string {
+  if (!value) return value;
+  const relative = path.relative(home, value);
+  if (relative && !relative.startsWith('..') && !path.isAbsolute(relative)) {
+    return `~/${relative.split(path.sep).join('/')}`;
+  }
+  return value === home ? '~' : value;
+}
+
+/** The local path for an exported one: remaps first (longest prefix wins), then `~`. */
+export function localPath(value: string, home: string, remaps: PathRemap[] = []): string {
+  if (!value) return value;
+  const portable = value.replace(/\\/g, '/');
+  const remap = [...remaps]
+    .sort((a, b) => b.from.length - a.from.length)
+    .find(({ from }) => {
+      const prefix = from.replace(/\\/g, '/').replace(/\/+$/, '');
+      return prefix && (portable === prefix || portable.startsWith(`${prefix}/`));
+    });
+  if (remap) {
+    const rest = portable.slice(remap.from.replace(/\\/g, '/').replace(/\/+$/, '').length);
+    return path.join(remap.to, ...rest.split('/').filter(Boolean));
+  }
+  if (portable === '~') return home;
+  if (portable.startsWith('~/')) return path.join(home, ...portable.slice(2).split('/'));
+  return value;
+}
+
+/** JSON with sorted keys, so the signed bytes do not depend on insertion order. */
+export function canonicalJson(value: unknown): string {
+  if (Array.isArray(value)) return `[${value.map(canonicalJson).join(',')}]`;
+  if (value && typeof value === 'object') {
+    const entries = Object.keys(value)
+      .filter((k) => (value as Record<string, unknown>)[k] !== undefined)
+      .sort()
+      .map((k) => `${JSON.stringify(k)}:${canonicalJson((value as Record<string, unknown>)[k])}`);
+    return `{${entries.join(',')}}`;
+  }
+  return JSON.stringify(value ?? null);
+}
+
+/** Short SHA-256 fingerprint of a public key, `ab12:cd34:…`. */
+export function keyFingerprint(publicKey: crypto.KeyObject): string {
+  const der = publicKey.export({ type: 'spki', format: 'der' });
+  const hex = crypto.createHash('sha256').update(der).digest('hex').slice(0, 16);
+  return hex.match(/.{4}/g)!.join(':');
+}
+
+/** This installation's signing key, created on first use. */
+export function loadSigningKey(configDir: string): crypto.KeyObject {
+  const file = path.join(configDir, SIGNING_KEY_FILE);
+  try {
+    return crypto.createPrivateKey(fs.readFileSync(file, 'utf8'));
+  } catch {
+    const { privateKey } = crypto.generateKeyPairSync('ed25519');
+    fs.mkdirSync(configDir, { recursive: true });
+    fs.writeFileSync(file, privateKey.export({ type: 'pkcs8', format: 'pem' }), { mode: 0o600 });
+    return privateKey;
+  }
+}
+
+export function buildSettingsBundle(
+  values: Record<string, unknown>,
+  privateKey: crypto.KeyObject,
+  opts: { home: string; appVersion: string; now?: Date },
+): SettingsBundle {
+  const sections: SettingsBundle['sections'] = {
+    settings: {},
+    profiles: {},
+    vocabularies: {},
+    rules: {},
+  };
+  for (const key of Object.keys(values).sort()) {
+    if (!isExportable(key)) continue;
+    const value = values[key];
+    sections[sectionOf(key)][key] =
+      PATH_KEYS.includes(key) && typeof value === 'string' ? portablePath(value, opts.home) : value;
+  }
+  const unsigned = {
+    format: SETTINGS_BUNDLE_FORMAT,
+    version: SETTINGS_BUNDLE_VERSION,
+    createdAt: (opts.now ?? new Date()).toISOString(),
+    appVersion: opts.appVersion,
+    sections,
+  };
+  const publicKey = crypto.createPublicKey(privateKey);
+  return {
+    ...unsigned,
+    signature: {
+      publicKey: publicKey.export({ type: 'spki', format: 'pem' }).toString(),
+      value: crypto.sign(null, Buffer.from(canonicalJson(unsigned)), privateKey).toString('base64'),
+    },
+  };
+}
+
+/**
+ * Check a parsed bundle and work out what importing it would write. Throws
+ * with a user-facing message when it is not a bundle or the signature fails.
+ */
+export function planImport(
+  data: unknown,
+  store: Pick<SettingsStore, 'has'>,
+  opts: ImportOptions,
+): ImportPlan {
+  const bundle = data as Partial<SettingsBundle> | null;
+  if (!bundle || typeof bundle !== 'object' || bundle.format !== SETTINGS_BUNDLE_FORMAT) {
+    throw new Error('Not a settings bundle');
+  }
+  if (bundle.version !== SETTINGS_BUNDLE_VERSION) {
+    throw new Error(`Unsupported settings bundle version ${String(bundle.version)}`);
+  }
+  const { signature, ...unsigned } = bundle;
+  let publicKey: crypto.KeyObject;
+  try {
+    publicKey = crypto.createPublicKey(signature?.publicKey ?? '');
+  } catch {
+    throw new Error('The bundle is not signed');
+  }
+  const valid = crypto.verify(
+    null,
+    Buffer.from(canonicalJson(unsigned)),
+    publicKey,
+    Buffer.from(signature?.value ?? '', 'base64'),
+  );
+  if (!valid) throw new Error('The signature does not match — the file was changed after export');
+
+  const exists = opts.exists ?? fs.existsSync;
+  const plan: ImportPlan = {
+    values: {},
+    counts: { settings: 0, profiles: 0, vocabularies: 0, rules: 0 },
+    missingPaths: [],
+    disabledHooks: 0,
+    switchedOff: [],
+    signer: keyFingerprint(publicKey),
+    signedHere: false,
+  };
+  plan.signedHere = plan.signer === opts.ownFingerprint;
+  for (const section of Object.keys(plan.counts) as BundleSection[]) {
+    for (const [key, raw] of Object.entries(bundle.sections?.[section] ?? {})) {
+      // Unknown keys (a newer app's settings) and secrets are skipped, whatever the file says.
+      if (!store.has(key) || !isExportable(key)) continue;
+      let value = raw;
+      if (PATH_KEYS.includes(key) && typeof raw === 'string' && raw) {
+        value = localPath(raw, opts.home, opts.remaps);
+        if (!exists(value as string)) plan.missingPaths.push(value as string);
+      }
+      if (key === 'hooks.scripts' && Array.isArray(raw)) {
+        value = raw.map((hook) => ({ ...hook, enabled: false }));
+        plan.disabledHooks = raw.filter((hook) => hook?.enabled).length;
+      }
+      const guard = GUARDED_KEYS[key];
+      if (guard && store.has(guard) && !plan.switchedOff.includes(guard)) {
+        plan.switchedOff.push(guard);
+      }
+      plan.values[key] = value;
+      plan.counts[section]++;
+    }
+  }
+  // Watch flags are per-machine and never exported, so these may be new keys.
+  for (const guard of plan.switchedOff) plan.values[guard] = false;
+  return plan;
+}
+
+/** Write this machine's settings to `filePath` as a signed bundle. */
+export async function exportSettings(
+  store: Pick<SettingsStore, 'store'>,
+  filePath: string,
+  privateKey: crypto.KeyObject,
+  opts: { home: string; appVersion: string },
+): Promise<void> {
+  const bundle = buildSettingsBundle(store.store, privateKey, opts);
+  await fs.promises.writeFile(filePath, JSON.stringify(bundle, null, 2), 'utf-8');
+}
+
+/**
+ * Read a bundle from `filePath`, verify it, and — once `confirm` agrees to
+ * the plan — write its settings. Returns the keys written through
+ * `onWritten` so live subsystems can reconfigure.
+ */
+export async function importSettings(
+  store: SettingsStore,
+  filePath: string,
+  opts: ImportOptions & {
+    confirm?: (plan: ImportPlan) => Promise<boolean>;
+    onWritten?: (keys: string[]) => void;
+  },
+): Promise<ImportResult | null> {
+  let plan: ImportPlan;
+  try {
+    plan = planImport(JSON.parse(await fs.promises.readFile(filePath, 'utf-8')), store, opts);
+  } catch (err) {
+    if (err instanceof SyntaxError) return { ok: false, error: 'The file is not valid JSON' };
+    return { ok: false, error: err instanceof Error ? err.message : String(err) };
+  }
+  if (opts.confirm && !(await opts.confirm(plan))) return null;
+  const { values, ...summary } = plan;
+  for (const [key, value] of Object.entries(values)) store.set(key, value);
+  opts.onWritten?.(Object.keys(values));
+  return { ok: true, ...summary };
+}
@@ -14,6 +14,7 @@ import {
   isPortalConnected,
   type PortalShortcutInfo,
 } from './waylandShortcuts.js';
+import { resolveKeymap } from './shortcutRegistry.js';
 
 /** Minimal store interface — accepts any electron-store instance. */
 interface ReadableStore {
@@ -107,13 +108,9 @@ async function doRegisterShortcuts(
   }
 
   // Standard Electron globalShortcut path (X11, macOS, Windows, or portal fallback)
-  const startAccelerator = (store.get('shortcuts.startRecording') as string) || 'Alt+Ctrl+Z';
-  const stopAccelerator = (store.get('shortcuts.stopTranscribe') as string) || 'Alt+Ctrl+X';
-
-  const bindings: Array<{ accelerator: string; action: string }> = [
-    { accelerator: startAccelerator, action: 'start-recording' },
-    { accelerator: stopAccelerator, action: 'stop-recording' },
-  ];
+  const bindings = resolveKeymap(store, process.platform)
+    .filter((entry) => entry.scope === 'global')
+    .map((entry) => ({ accelerator: entry.accelerator, action: entry.id }));
 
   for (const { accelerator, action } of bindings) {
     try {
@@ -0,0 +1,278 @@
+/**
+ * Keyboard shortcut registry — the one list of every shortcut the app binds.
+ *
+ * Global shortcuts (start/stop recording) are registered with the OS by
+ * shortcutManager.ts; app shortcuts (transcript playback) are matched by
+ * the renderer against the keymap it fetches over IPC, so nothing is
+ * hard-coded in the webview. Each action keeps its accelerator under its
+ * own `shortcuts.*` store key; unset keys fall back to per-platform defaults.
+ *
+ * Accelerators use Electron's syntax (`CommandOrControl+Shift+K`). Two
+ * actions conflict when their normalized accelerators are equal — a global
+ * shortcut conflicts with everything, app shortcuts only with each other.
+ */
+
+export type ShortcutScope = 'global' | 'app';
+
+export interface ShortcutAction {
+  id: string;
+  label: string;
+  scope: ShortcutScope;
+  storeKey: string;
+  /** Default accelerator, optionally per `process.platform`. */
+  defaults: string | Partial<Record<NodeJS.Platform, string>> & { default: string };
+}
+
+export const SHORTCUT_ACTIONS: readonly ShortcutAction[] = [
+  {
+    id: 'start-recording',
+    label: 'Start recording',
+    scope: 'global',
+    storeKey: 'shortcuts.startRecording',
+    defaults: 'Alt+Ctrl+Z',
+  },
+  {
+    id: 'stop-recording',
+    label: 'Stop & transcribe',
+    scope: 'global',
+    storeKey: 'shortcuts.stopTranscribe',
+    defaults: 'Alt+Ctrl+X',
+  },
+  {
+    id: 'playback.toggle',
+    label: 'Play / pause',
+    scope: 'app',
+    storeKey: 'shortcuts.app.playbackToggle',
+    defaults: 'K',
+  },
+  {
+    id: 'playback.back',
+    label: 'Rewind 10 seconds',
+    scope: 'app',
+    storeKey: 'shortcuts.app.playbackBack',
+    defaults: 'J',
+  },
+  {
+    id: 'playback.forward',
+    label: 'Forward 10 seconds',
+    scope: 'app',
+    storeKey: 'shortcuts.app.playbackForward',
+    defaults: 'L',
+  },
+  {
+    id: 'settings.open',
+    label: 'Open settings',
+    scope: 'app',
+    storeKey: 'shortcuts.app.openSettings',
+    defaults: { darwin: 'Command+,', default: 'Ctrl+,' },
+  },
+  {
+    id: 'palette.open',
+    label: 'Open command palette',
+    scope: 'app',
+    storeKey: 'shortcuts.app.openPalette',
+    defaults: { darwin: 'Command+K', default: 'Ctrl+K' },
+  },
+];
+
+export const KEYMAP_VERSION = 1;
+
+export interface ShortcutEntry {
+  id: string;
+  label: string;
+  scope: ShortcutScope;
+  accelerator: string;
+  defaultAccelerator: string;
+  /** Ids of other actions bound to the same keys. */
+  conflicts: string[];
+}
+
+export interface KeymapFile {
+  version: number;
+  platform: string;
+  shortcuts: Record<string, string>;
+}
+
+export type SetShortcutResult =
+  | { ok: true; keymap: ShortcutEntry[] }
+  | { ok: false; error: string; conflicts?: string[] };
+
+/** Minimal store interface — accepts any electron-store instance. */
+interface KeymapStore {
+  get(key: string): unknown;
+  set(key: string, value: unknown): void;
+  delete(key: string): void;
+}
+
+const MODIFIER_ORDER = ['Command', 'Ctrl', 'Alt', 'Shift', 'Super'];
+const MODIFIER_ALIASES: Record<string, string> = {
+  cmd: 'Command',
+  command: 'Command',
+  ctrl: 'Ctrl',
+  control: 'Ctrl',
+  alt: 'Alt',
+  option: 'Alt',
+  altgr: 'Alt',
+  shift: 'Shift',
+  super: 'Super',
+  meta: 'Super',
+};
+
+export function findAction(id: string): ShortcutAction | undefined {
+  return SHORTCUT_ACTIONS.find((a) => a.id === id);
+}
+
+export function defaultAccelerator(action: ShortcutAction, platform: string): string {
+  if (typeof action.defaults === 'string') return action.defaults;
+  return action.defaults[platform as NodeJS.Platform] ?? action.defaults.default;
+}
+
+/**
+ * Canonical form for comparison: modifiers in a fixed order, `CommandOrControl`
+ * resolved for the platform, key upper-cased. Returns null for a malformed
+ * accelerator (no key, or only modifiers).
+ */
+export function normalizeAccelerator(accelerator: string, platform: string): string | null {
+  const parts = accelerator
+    .split('+')
+    .map((p) => p.trim())
+    .filter(Boolean);
+  if (parts.length === 0) return null;
+  const modifiers = new Set<string>();
+  let key: string | null = null;
+  for (const part of parts) {
+    const lower = part.toLowerCase();
+    if (lower === 'commandorcontrol' || lower === 'cmdorctrl') {
+      modifiers.add(platform === 'darwin' ? 'Command' : 'Ctrl');
+    } else if (MODIFIER_ALIASES[lower]) {
+      modifiers.add(MODIFIER_ALIASES[lower]);
+    } else if (key === null) {
+      key = part.length === 1 ? part.toUpperCase() : part[0].toUpperCase() + part.slice(1);
+    } else {
+      return null;
+    }
+  }
+  if (key === null) return null;
+  return [...MODIFIER_ORDER.filter((m) => modifiers.has(m)), key].join('+');
+}
+
+/** Current accelerators: stored overrides over per-platform defaults, with conflicts. */
+export function resolveKeymap(store: Pick<KeymapStore, 'get'>, platform: string): ShortcutEntry[] {
+  const entries = SHORTCUT_ACTIONS.map((action) => {
+    const stored = store.get(action.storeKey);
+    const fallback = defaultAccelerator(action, platform);
+    return {
+      id: action.id,
+      label: action.label,
+      scope: action.scope,
+      accelerator: typeof stored === 'string' && stored.trim() ? stored.trim() : fallback,
+      defaultAccelerator: fallback,
+      conflicts: [] as string[],
+    };
+  });
+  for (const entry of entries) {
+    entry.conflicts = conflictsFor(entries, entry.id, entry.scope, entry.accelerator, platform);
+  }
+  return entries;
+}
+
+function conflictsFor(
+  entries: Pick<ShortcutEntry, 'id' | 'scope' | 'accelerator'>[],
+  id: string,
+  scope: ShortcutScope,
+  accelerator: string,
+  platform: string,
+): string[] {
+  const target = normalizeAccelerator(accelerator, platform);
+  if (!target) return [];
+  return entries
+    .filter((other) => other.id !== id)
+    .filter((other) => scope === 'global' || other.scope === 'global' || other.scope === scope)
+    .filter((other) => normalizeAccelerator(other.accelerator, platform) === target)
+    .map((other) => other.id);
+}
+
+/**
+ * Bind `action` to `accelerator`; `null` restores the default. Refuses
+ * unknown actions, malformed accelerators and conflicting bindings.
+ */
+export function setShortcut(
+  store: KeymapStore,
+  actionId: string,
+  accelerator: string | null,
+  platform: string,
+): SetShortcutResult {
+  const action = findAction(actionId);
+  if (!action) return { ok: false, error: `Unknown shortcut action '${actionId}'` };
+  if (accelerator === null || !accelerator.trim()) {
+    store.delete(action.storeKey);
+    return { ok: true, keymap: resolveKeymap(store, platform) };
+  }
+  const value = accelerator.trim();
+  if (!normalizeAccelerator(value, platform)) {
+    return { ok: false, error: `'${value}' is not a valid shortcut` };
+  }
+  const current = resolveKeymap(store, platform);
+  const conflicts = conflictsFor(current, action.id, action.scope, value, platform);
+  if (conflicts.length > 0) {
+    const labels = conflicts.map((id) => findAction(id)?.label ?? id).join(', ');
+    return { ok: false, error: `${value} is already used by ${labels}`, conflicts };
+  }
+  store.set(action.storeKey, value);
+  return { ok: true, keymap: resolveKeymap(store, platform) };
+}
+
+export function exportKeymap(store: Pick<KeymapStore, 'get'>, platform: string): KeymapFile {
+  return {
+    version: KEYMAP_VERSION,
+    platform,
+    shortcuts: Object.fromEntries(
+      resolveKeymap(store, platform).map((entry) => [entry.id, entry.accelerator]),
+    ),
+  };
+}
+
+/**
+ * Replace the keymap with an exported one. Validated as a whole first — a
+ * file with an unknown action, a bad accelerator or a conflict changes
+ * nothing. Actions missing from the file keep their current binding.
+ */
+export function importKeymap(
+  store: KeymapStore,
+  data: unknown,
+  platform: string,
+): SetShortcutResult {
+  const file = data as Partial<KeymapFile> | null;
+  if (!file || typeof file !== 'object' || typeof file.shortcuts !== 'object' || !file.shortcuts) {
+    return { ok: false, error: 'Not a keymap file' };
+  }
+  if (file.version !== KEYMAP_VERSION) {
+    return { ok: false, error: `Unsupported keymap version ${String(file.version)}` };
+  }
+  const current = resolveKeymap(store, platform);
+  const next = current.map((entry) => ({ ...entry }));
+  for (const [id, accelerator] of Object.entries(file.shortcuts)) {
+    const entry = next.find((e) => e.id === id);
+    if (!entry) return { ok: false, error: `Unknown shortcut action '${id}'` };
+    if (typeof accelerator !== 'string' || !normalizeAccelerator(accelerator, platform)) {
+      return { ok: false, error: `'${String(accelerator)}' is not a valid shortcut for ${id}` };
+    }
+    entry.accelerator = accelerator.trim();
+  }
+  for (const entry of next) {
+    const conflicts = conflictsFor(next, entry.id, entry.scope, entry.accelerator, platform);
+    if (conflicts.length > 0) {
+      return {
+        ok: false,
+        error: `${entry.accelerator} is bound to both ${[entry.id, ...conflicts].join(' and ')}`,
+        conflicts: [entry.id, ...conflicts],
+      };
+    }
+  }
+  for (const entry of next) {
+    const action = findAction(entry.id)!;
+    if (entry.accelerator === entry.defaultAccelerator) store.delete(action.storeKey);
+    else store.set(action.storeKey, entry.accelerator);
+  }
+  return { ok: true, keymap: resolveKeymap(store, platform) };
+}
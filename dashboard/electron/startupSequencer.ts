@@ -0,0 +1,342 @@
+/**
+ * Startup sequencer — one owner for "is the server actually usable yet?".
+ *
+ * Every local start path (Docker container, Metal server, Docker-free
+ * server) runs through {@link StartupSequencer.run}:
+ *
+ *   backend  start (or attach to) the server process
+ *   health   poll GET /health until the HTTP server answers
+ *   model    poll GET /ready until the transcription model is loaded
+ *   version  read /api/status and check it against the app version
+ *
+ * Only after the last stage does the UI get `ready`. Each stage has a
+ * stall timeout rather than a total one — first-run dependency installs
+ * and model downloads legitimately take many minutes, so activity
+ * reported through {@link StartupSequencer.noteActivity} (startup events,
+ * bootstrap progress) resets the clock. A stage that stalls fails the
+ * sequence with a diagnostic report instead of leaving the UI on
+ * "Starting…" forever.
+ */
+
+export type StartupStage = 'backend' | 'health' | 'model' | 'version';
+
+export const STARTUP_STAGES: readonly StartupStage[] = ['backend', 'health', 'model', 'version'];
+
+export type StartupStatus = 'idle' | 'running' | 'ready' | 'failed';
+
+export interface StartupStageState {
+  stage: StartupStage;
+  status: 'pending' | 'running' | 'done' | 'warning' | 'failed';
+  message: string;
+  startedAt: number | null;
+  finishedAt: number | null;
+}
+
+export interface StartupState {
+  status: StartupStatus;
+  source: string | null;
+  stages: StartupStageState[];
+  /** 0–100 across all stages. */
+  percent: number;
+  report: StartupReport | null;
+}
+
+export interface StartupReport {
+  source: string | null;
+  failedStage: StartupStage;
+  reason: string;
+  elapsedMs: number;
+  stages: StartupStageState[];
+  /** Last few probe results per stage, newest last. */
+  probes: string[];
+  /** Extra context from the caller (container state, log tail, …). */
+  diagnostics: string;
+  createdAt: string;
+}
+
+export interface ProbeResult {
+  status: number;
+  body: unknown;
+}
+
+export interface StartupSequencerDeps {
+  /** Base server URL, e.g. `http://localhost:9786`. */
+  serverUrl: () => string;
+  fetchJson: (url: string, timeoutMs: number) => Promise<ProbeResult>;
+  /** Null when compatible, else a warning shown with the ready state. */
+  checkVersion: (serverVersion: string | null) => string | null;
+  collectDiagnostics?: () => Promise<string>;
+  onState: (state: StartupState) => void;
+  now?: () => number;
+  sleep?: (ms: number) => Promise<void>;
+}
+
+/** Stall timeouts (ms without activity) per stage. */
+export const STAGE_TIMEOUTS: Record<StartupStage, number> = {
+  backend: 15 * 60_000,
+  health: 5 * 60_000,
+  model: 10 * 60_000,
+  version: 30_000,
+};
+
+const POLL_INTERVAL_MS = 1_000;
+const PROBE_TIMEOUT_MS = 5_000;
+const MAX_PROBES = 20;
+
+const STAGE_LABEL: Record<StartupStage, string> = {
+  backend: 'Starting server',
+  health: 'Waiting for the server to respond',
+  model: 'Loading the transcription model',
+  version: 'Checking server version',
+};
+
+class Superseded extends Error {}
+
+export class StartupSequencer {
+  private _deps: StartupSequencerDeps;
+  private _now: () => number;
+  private _sleep: (ms: number) => Promise<void>;
+  private _state: StartupState = idleState();
+  private _runId = 0;
+  private _lastActivity = 0;
+  private _probes: string[] = [];
+  private _timeouts: Record<StartupStage, number>;
+
+  constructor(deps: StartupSequencerDeps, timeouts: Partial<Record<StartupStage, number>> = {}) {
+    this._deps = deps;
+    this._now = deps.now ?? Date.now;
+    this._sleep = deps.sleep ?? ((ms) => new Promise((resolve) => setTimeout(resolve, ms)));
+    this._timeouts = { ...STAGE_TIMEOUTS, ...timeouts };
+  }
+
+  getState(): StartupState {
+    return this._state;
+  }
+
+  /** Progress from anywhere in the start path — resets the current stage's stall clock. */
+  noteActivity(): void {
+    this._lastActivity = this._now();
+  }
+
+  /**
+   * Start a sequence. Resolves (or rejects) once `start` has — callers keep
+   * their existing await semantics — while the health/model/version stages
+   * continue in the background. Omit `start` to attach to a running server.
+   * A new run supersedes any in flight.
+   */
+  async run(source: string, start?: () => Promise<unknown>): Promise<void> {
+    const runId = ++this._runId;
+    this._probes = [];
+    this._state = {
+      ...idleState(),
+      status: 'running',
+      source,
+      stages: STARTUP_STAGES.map((stage) => pendingStage(stage)),
+    };
+    this._emit();
+
+    this._enter(runId, 'backend', start ? STAGE_LABEL.backend : 'Attaching to running server');
+    if (start) {
+      try {
+        await this._guard(runId, 'backend', start());
+      } catch (err) {
+        if (runId === this._runId) await this._fail('backend', errorMessage(err));
+        throw err;
+      }
+    }
+    if (runId !== this._runId) return;
+    this._finish('backend', 'done', start ? 'Server process started' : 'Attached');
+    void this._continue(runId);
+  }
+
+  /** Abandon the current sequence (server stopped by the user). */
+  cancel(): void {
+    this._runId += 1;
+    this._state = idleState();
+    this._emit();
+  }
+
+  private async _continue(runId: number): Promise<void> {
+    try {
+      this._enter(runId, 'health', STAGE_LABEL.health);
+      await this._poll(runId, 'health', '/health', (r) => r.status === 200);
+      this._finish('health', 'done', 'Server is responding');
+
+      this._enter(runId, 'model', STAGE_LABEL.model);
+      await this._poll(runId, 'model', '/ready', (r) => r.status === 200);
+      this._finish('model', 'done', 'Model loaded');
+
+      this._enter(runId, 'version', STAGE_LABEL.version);
+      const status = await this._probeOnce(runId, 'version', '/api/status');
+      const body = (status?.body ?? {}) as { version?: unknown };
+      const version = typeof body.version === 'string' ? body.version : null;
+      const warning = this._deps.checkVersion(version);
+      this._finish('version', warning ? 'warning' : 'done', warning ?? `Server ${version}`);
+
+      this._state = { ...this._state, status: 'ready', percent: 100 };
+      this._emit();
+    } catch (err) {
+      if (err instanceof Superseded) return;
+      const stage = this._state.stages.find((s) => s.status === 'running')?.stage ?? 'health';
+      await this._fail(stage, errorMessage(err));
+    }
+  }
+
+  private async _poll(
+    runId: number,
+    stage: StartupStage,
+    pathname: string,
+    done: (result: ProbeResult) => boolean,
+  ): Promise<void> {
+    for (;;) {
+      const result = await this._probeOnce(runId, stage, pathname);
+      if (result && done(result)) return;
+      if (this._stalled(stage)) throw this._stallError(stage);
+      await this._sleep(POLL_INTERVAL_MS);
+      if (runId !== this._runId) throw new Superseded();
+    }
+  }
+
+  /** Race `work` against the stage's stall timeout. */
+  private async _guard<T>(runId: number, stage: StartupStage, work: Promise<T>): Promise<T> {
+    let settled = false;
+    const watchdog = new Promise<never>((_resolve, reject) => {
+      void (async () => {
+        while (!settled && runId === this._runId) {
+          await this._sleep(POLL_INTERVAL_MS);
+          if (!settled && this._stalled(stage)) {
+            reject(this._stallError(stage));
+            return;
+          }
+        }
+      })();
+    });
+    try {
+      return await Promise.race([work, watchdog]);
+    } finally {
+      settled = true;
+    }
+  }
+
+  private _stalled(stage: StartupStage): boolean {
+    return this._now() - this._lastActivity > this._timeouts[stage];
+  }
+
+  private _stallError(stage: StartupStage): Error {
+    const seconds = Math.round(this._timeouts[stage] / 1000);
+    return new Error(`No progress for ${seconds}s: ${STAGE_LABEL[stage].toLowerCase()}`);
+  }
+
+  private async _probeOnce(
+    runId: number,
+    stage: StartupStage,
+    pathname: string,
+  ): Promise<ProbeResult | null> {
+    const url = `${this._deps.serverUrl()}${pathname}`;
+    let result: ProbeResult | null = null;
+    let line: string;
+    try {
+      result = await this._deps.fetchJson(url, PROBE_TIMEOUT_MS);
+      // Probes never count as activity: a server stuck answering 503 is stalled.
+      line = `${stage} GET ${pathname} → ${result.status} ${summarize(result.body)}`;
+    } catch (err) {
+      line = `${stage} GET ${pathname} → ${errorMessage(err)}`;
+    }
+    if (runId !== this._runId) throw new Superseded();
+    this._probes.push(`${new Date(this._now()).toISOString()} ${line}`);
+    if (this._probes.length > MAX_PROBES) this._probes.shift();
+    return result;
+  }
+
+  private _enter(runId: number, stage: StartupStage, message: string): void {
+    if (runId !== this._runId) throw new Superseded();
+    this.noteActivity();
+    this._update(stage, { status: 'running', message, startedAt: this._now() });
+  }
+
+  private _finish(stage: StartupStage, status: 'done' | 'warning', message: string): void {
+    this._update(stage, { status, message, finishedAt: this._now() });
+  }
+
+  private async _fail(stage: StartupStage, reason: string): Promise<void> {
+    this._update(stage, { status: 'failed', message: reason, finishedAt: this._now() });
+    let diagnostics = '';
+    try {
+      diagnostics = (await this._deps.collectDiagnostics?.()) ?? '';
+    } catch (err) {
+      diagnostics = `Could not collect diagnostics: ${errorMessage(err)}`;
+    }
+    const startedAt = this._state.stages[0].startedAt ?? this._now();
+    this._state = {
+      ...this._state,
+      status: 'failed',
+      report: {
+        source: this._state.source,
+        failedStage: stage,
+        reason,
+        elapsedMs: this._now() - startedAt,
+        stages: this._state.stages,
+        probes: [...this._probes],
+        diagnostics,
+        createdAt: new Date(this._now()).toISOString(),
+      },
+    };
+    this._emit();
+  }
+
+  private _update(stage: StartupStage, patch: Partial<StartupStageState>): void {
+    const stages = this._state.stages.map((s) => (s.stage === stage ? { ...s, ...patch } : s));
+    const finished = stages.filter((s) => s.status === 'done' || s.status === 'warning').length;
+    this._state = { ...this._state, stages, percent: Math.round((finished / stages.length) * 100) };
+    this._emit();
+  }
+
+  private _emit(): void {
+    this._deps.onState(this._state);
+  }
+}
+
+/** Plain-text rendering of a failure report, for the clipboard and the log file. */
+export function formatStartupReport(report: StartupReport): string {
+  const lines = [
+    'TranscriptionSuite startup report',
+    `Created: ${report.createdAt}`,
+    `Source: ${report.source ?? 'unknown'}`,
+    `Failed stage: ${report.failedStage}`,
+    `Reason: ${report.reason}`,
+    `Elapsed: ${Math.round(report.elapsedMs / 1000)}s`,
+    '',
+    'Stages:',
+    ...report.stages.map((s) => {
+      const took =
+        s.startedAt !== null && s.finishedAt !== null
+          ? ` (${Math.round((s.finishedAt - s.startedAt) / 1000)}s)`
+          : '';
+      return `  ${s.stage}: ${s.status}${took} — ${s.message}`;
+    }),
+    '',
+    'Recent probes:',
+    ...(report.probes.length ? report.probes.map((p) => `  ${p}`) : ['  (none)']),
+  ];
+  if (report.diagnostics) lines.push('', 'Diagnostics:', report.diagnostics);
+  return `${lines.join('\n')}\n`;
+}
+
+function idleState(): StartupState {
+  return { status: 'idle', source: null, stages: [], percent: 0, report: null };
+}
+
+function pendingStage(stage: StartupStage): StartupStageState {
+  return { stage, status: 'pending', message: '', startedAt: null, finishedAt: null };
+}
+
+function errorMessage(err: unknown): string {
+  return err instanceof Error ? err.message : String(err);
+}
+
+function summarize(body: unknown): string {
+  if (body && typeof body === 'object' && 'status' in body) {
+    return String((body as { status: unknown }).status);
+  }
+  return '';
+}
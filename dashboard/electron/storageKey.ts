@@ -0,0 +1,51 @@
+/**
+ * Master key for the server's at-rest audio encryption.
+ *
+ * The key is generated once, sealed with Electron's safeStorage (macOS
+ * Keychain, Windows DPAPI, libsecret/kwallet on Linux) and kept in the
+ * dashboard store only in that sealed form. Every container start passes it
+ * as STORAGE_MASTER_KEY through the compose process environment — never the
+ * persisted compose `.env` — so the plaintext key only ever lives in memory.
+ *
+ * When the OS offers no secure storage the key is not created: the server
+ * then falls back to its own keychain or `storage_encryption.key_file`.
+ */
+
+import crypto from 'crypto';
+
+export const STORAGE_KEY_STORE_KEY = 'server.storageMasterKeySealed';
+const KEY_BYTES = 32;
+
+export interface StorageKeySafeStorage {
+  isEncryptionAvailable(): boolean;
+  encryptString(plainText: string): Buffer;
+  decryptString(encrypted: Buffer): string;
+}
+
+export interface StorageKeyStore {
+  get(key: string): unknown;
+  set(key: string, value: unknown): void;
+}
+
+/** The base64 master key, created on first use; null without secure storage. */
+export function getOrCreateStorageMasterKey(
+  store: StorageKeyStore,
+  safeStorage: StorageKeySafeStorage,
+  randomBytes: (size: number) => Buffer = crypto.randomBytes,
+): string | null {
+  if (!safeStorage.isEncryptionAvailable()) return null;
+  const sealed = store.get(STORAGE_KEY_STORE_KEY);
+  if (typeof sealed === 'string' && sealed) {
+    try {
+      return safeStorage.decryptString(Buffer.from(sealed, 'base64'));
+    } catch (err) {
+      // Never replace an unreadable key: recordings sealed with it would be
+      // lost for good. The server keeps unreadable files and reports them.
+      console.error('[StorageKey] Could not unseal the storage master key:', err);
+      return null;
+    }
+  }
+  const key = randomBytes(KEY_BYTES).toString('base64');
+  store.set(STORAGE_KEY_STORE_KEY, safeStorage.encryptString(key).toString('base64'));
+  return key;
+}
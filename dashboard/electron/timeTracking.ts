@@ -0,0 +1,251 @@
+/**
+ * TimeTracking - active review/editing time per transcript (SQLite).
+ *
+ * While a recording is open in the notebook the renderer starts a work
+ * session and sends a heartbeat every {@link HEARTBEAT_MS}. Each heartbeat
+ * credits the time since the previous one, but only while the user is
+ * actually working: audio is playing, or the window has focus and the
+ * system idle time (powerMonitor) is under the idle limit. A heartbeat that
+ * arrives after a long gap (sleep, a hung renderer) credits at most
+ * {@link MAX_GAP_MS}.
+ *
+ * Sessions carry the recording's project - a metadata field chosen in
+ * Settings - so the totals can be exported per project as CSV for
+ * invoicing, together with the audio minutes of the recordings worked on.
+ * Nothing here is sent to the server.
+ */
+
+import { DatabaseSync } from 'node:sqlite';
+import path from 'node:path';
+
+export { HEARTBEAT_MS } from './timeTrackingShared.js';
+
+const FILE_NAME = 'time-tracking.db';
+
+/** The most one heartbeat can credit, however late it arrives. */
+export const MAX_GAP_MS = 60_000;
+export const DEFAULT_IDLE_SECONDS = 120;
+
+export interface WorkSessionInfo {
+  recordingId: number;
+  title: string;
+  /** Empty when the recording has no value for the project field. */
+  project?: string;
+  audioSeconds: number;
+}
+
+export interface Heartbeat {
+  /** Audio is playing - listening counts as work even without input. */
+  playing: boolean;
+  /** The app window has focus. */
+  focused: boolean;
+}
+
+export interface TimeRange {
+  /** Epoch ms, inclusive; sessions are counted by when they started. */
+  from?: number;
+  /** Epoch ms, exclusive. */
+  to?: number;
+}
+
+export interface ProjectTotals {
+  project: string;
+  recordings: number;
+  activeMs: number;
+  audioSeconds: number;
+}
+
+export interface RecordingTotals {
+  project: string;
+  recordingId: number;
+  title: string;
+  activeMs: number;
+  audioSeconds: number;
+}
+
+/**
+ * Milliseconds of `gapMs` that count as work. When the user has been idle
+ * longer than the limit, only the part of the gap before they went past it
+ * is credited.
+ */
+export function creditedMs(
+  gapMs: number,
+  beat: Heartbeat,
+  idleSeconds: number,
+  idleLimitSeconds: number,
+): number {
+  const gap = Math.min(Math.max(0, gapMs), MAX_GAP_MS);
+  if (beat.playing) return gap;
+  if (!beat.focused) return 0;
+  const overMs = (idleSeconds - idleLimitSeconds) * 1000;
+  return overMs <= 0 ? gap : Math.max(0, gap - overMs);
+}
+
+const NO_PROJECT = '(No project)';
+
+function csvField(value: string | number): string {
+  const text = String(value);
+  return /[",\r\n]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text;
+}
+
+const minutes = (ms: number) => (ms / 60_000).toFixed(1);
+const hours = (ms: number) => (ms / 3_600_000).toFixed(2);
+
+/** Per-project totals as CSV, one row per project plus a total row. */
+export function projectTotalsCsv(rows: ProjectTotals[]): string {
+  const lines = [['Project', 'Recordings', 'Active hours', 'Active minutes', 'Audio minutes']];
+  for (const r of rows) {
+    lines.push([
+      r.project || NO_PROJECT,
+      String(r.recordings),
+      hours(r.activeMs),
+      minutes(r.activeMs),
+      minutes(r.audioSeconds * 1000),
+    ]);
+  }
+  const active = rows.reduce((sum, r) => sum + r.activeMs, 0);
+  const audio = rows.reduce((sum, r) => sum + r.audioSeconds, 0);
+  const recordings = rows.reduce((sum, r) => sum + r.recordings, 0);
+  lines.push(['Total', String(recordings), hours(active), minutes(active), minutes(audio * 1000)]);
+  return lines.map((line) => line.map(csvField).join(',')).join('\r\n') + '\r\n';
+}
+
+/** Per-recording totals as CSV, for invoices that list each job. */
+export function recordingTotalsCsv(rows: RecordingTotals[]): string {
+  const lines = [['Project', 'Recording', 'Active minutes', 'Audio minutes']];
+  for (const r of rows) {
+    lines.push([
+      r.project || NO_PROJECT,
+      r.title,
+      minutes(r.activeMs),
+      minutes(r.audioSeconds * 1000),
+    ]);
+  }
+  return lines.map((line) => line.map(csvField).join(',')).join('\r\n') + '\r\n';
+}
+
+export class TimeTracking {
+  private readonly db: DatabaseSync;
+  /** Epoch ms of each open session's previous heartbeat. */
+  private readonly lastBeat = new Map<number, number>();
+
+  constructor(userDataDir: string, fileName = FILE_NAME) {
+    this.db = new DatabaseSync(path.join(userDataDir, fileName));
+    this.db.exec(`
+      CREATE TABLE IF NOT EXISTS work_sessions (
+        id            INTEGER PRIMARY KEY AUTOINCREMENT,
+        recording_id  INTEGER NOT NULL,
+        title         TEXT NOT NULL,
+        project       TEXT NOT NULL DEFAULT '',
+        audio_seconds REAL NOT NULL DEFAULT 0,
+        started_at    INTEGER NOT NULL,
+        -- Last heartbeat until the session ends, so a session the app never
+        -- got to close still has a sensible end.
+        ended_at      INTEGER,
+        active_ms     INTEGER NOT NULL DEFAULT 0
+      );
+      CREATE INDEX IF NOT EXISTS idx_work_sessions_started ON work_sessions(started_at);
+    `);
+  }
+
+  /** Open a work session for a recording; returns its id. */
+  begin(info: WorkSessionInfo, now = Date.now()): number {
+    const result = this.db
+      .prepare(
+        `INSERT INTO work_sessions (recording_id, title, project, audio_seconds, started_at)
+         VALUES (?, ?, ?, ?, ?)`,
+      )
+      .run(
+        info.recordingId,
+        info.title,
+        info.project?.trim() ?? '',
+        Number.isFinite(info.audioSeconds) ? Math.max(0, info.audioSeconds) : 0,
+        now,
+      );
+    const id = Number(result.lastInsertRowid);
+    this.lastBeat.set(id, now);
+    return id;
+  }
+
+  /**
+   * Credit the time since the previous heartbeat. Returns the session's
+   * active milliseconds so far, or null for a session that is not open.
+   */
+  heartbeat(
+    sessionId: number,
+    beat: Heartbeat,
+    idleSeconds: number,
+    idleLimitSeconds = DEFAULT_IDLE_SECONDS,
+    now = Date.now(),
+  ): number | null {
+    const last = this.lastBeat.get(sessionId);
+    if (last === undefined) return null;
+    this.lastBeat.set(sessionId, now);
+    const credit = Math.round(creditedMs(now - last, beat, idleSeconds, idleLimitSeconds));
+    const row = this.db
+      .prepare(
+        `UPDATE work_sessions SET active_ms = active_ms + ?, ended_at = ?
+         WHERE id = ? RETURNING active_ms`,
+      )
+      .get(credit, now, sessionId) as { active_ms: number } | undefined;
+    return row?.active_ms ?? null;
+  }
+
+  /** Close a session; one that never earned any time is dropped. */
+  end(sessionId: number, now = Date.now()): void {
+    if (!this.lastBeat.delete(sessionId)) return;
+    this.db.prepare('DELETE FROM work_sessions WHERE id = ? AND active_ms = 0').run(sessionId);
+    this.db.prepare('UPDATE work_sessions SET ended_at = ? WHERE id = ?').run(now, sessionId);
+  }
+
+  /**
+   * Active time and audio minutes per recording. A recording's audio is
+   * counted once however many sessions were spent on it.
+   */
+  recordingTotals(range: TimeRange = {}): RecordingTotals[] {
+    const rows = this.db
+      .prepare(
+        `SELECT project, recording_id, MAX(title) AS title,
+                SUM(active_ms) AS active_ms, MAX(audio_seconds) AS audio_seconds
+         FROM work_sessions
+         WHERE started_at >= ? AND started_at < ? AND active_ms > 0
+         GROUP BY project, recording_id
+         ORDER BY project COLLATE NOCASE, MIN(started_at)`,
+      )
+      .all(range.from ?? 0, range.to ?? Number.MAX_SAFE_INTEGER) as unknown as {
+      project: string;
+      recording_id: number;
+      title: string;
+      active_ms: number;
+      audio_seconds: number;
+    }[];
+    return rows.map((r) => ({
+      project: r.project,
+      recordingId: r.recording_id,
+      title: r.title,
+      activeMs: r.active_ms,
+      audioSeconds: r.audio_seconds,
+    }));
+  }
+
+  projectTotals(range: TimeRange = {}): ProjectTotals[] {
+    const byProject = new Map<string, ProjectTotals>();
+    for (const r of this.recordingTotals(range)) {
+      const totals = byProject.get(r.project) ?? {
+        project: r.project,
+        recordings: 0,
+        activeMs: 0,
+        audioSeconds: 0,
+      };
+      totals.recordings += 1;
+      totals.activeMs += r.activeMs;
+      totals.audioSeconds += r.audioSeconds;
+      byProject.set(r.project, totals);
+    }
+    return [...byProject.values()];
+  }
+
+  close(): void {
+    this.db.close();
+  }
+}
@@ -0,0 +1,8 @@
+/**
+ * Time-tracking timing shared by the main process (timeTracking.ts) and the
+ * renderer (src/hooks/useReviewTimeTracking.ts). Free of Node and Electron
+ * imports so the renderer bundle can import it directly.
+ */
+
+/** How often the renderer sends a heartbeat for an open work session. */
+export const HEARTBEAT_MS = 15_000;
@@ -230,6 +230,10 @@ export class TrayManager {
     }
   }
 
+  getMenuState(): TrayMenuState {
+    return { ...this.menuState };
+  }
+
   setMenuState(menuState: Partial<TrayMenuState>): void {
     this.menuState = { ...this.menuState, ...menuState };
     this.rebuildMenu();
@@ -0,0 +1,276 @@
+/**
+ * Per-machine tuning of the whisper.cpp sidecar (`whisper-server`).
+ *
+ * The Vulkan profiles transcribe through a whisper-server launched by this
+ * app — a compose sidecar on Linux, a native exe on Windows — and its CPU
+ * side is configured only at launch: `--threads`, `--flash-attn`, and (Linux
+ * only) which cores it may run on, via `taskset`. On hybrid CPUs the default
+ * of spreading threads over every core lets the slow efficiency cores hold
+ * the fast ones back, so pinning to the performance cores is often quicker.
+ *
+ * Settings live in the store under `server.whispercppTuning`, keyed by
+ * `machineKey()`, because the best values belong to the hardware — a
+ * portable install or copied config must not carry them to another machine.
+ * `benchmarkCandidates` lists what a one-time benchmark tries; the runner
+ * (restart the sidecar per candidate, time a fixed inference) lives in
+ * dockerManager, which owns the sidecar's lifecycle. Quantization is a
+ * property of the GGML file, so it is chosen with the model, not here.
+ */
+
+import fs from 'fs';
+import os from 'os';
+
+export type CoreSet = 'all' | 'performance';
+
+export interface WhispercppTuning {
+  /** `--threads`; 0 leaves whisper-server's own default (4). */
+  threads: number;
+  /** Pass `--flash-attn`; off leaves whisper-server's own default. */
+  flashAttention: boolean;
+  /** Cores the sidecar may use; `performance` needs a detected hybrid CPU. */
+  cores: CoreSet;
+}
+
+export interface WhispercppBenchmarkRun extends WhispercppTuning {
+  /** Fastest of the timed inferences, or null when the candidate failed. */
+  seconds: number | null;
+  error?: string;
+}
+
+export interface WhispercppBenchmark {
+  measuredAt: string;
+  model: string | null;
+  runs: WhispercppBenchmarkRun[];
+}
+
+export interface StoredWhispercppTuning extends WhispercppTuning {
+  /** Present once the benchmark has run on this machine. */
+  benchmark?: WhispercppBenchmark;
+}
+
+/**
+ * Logical CPU ids by core type. Both lists are empty unless the CPU is
+ * hybrid (Intel P/E cores, ARM big.LITTLE) and the OS says which is which.
+ */
+export interface CpuTopology {
+  logical: number;
+  performance: number[];
+  efficiency: number[];
+}
+
+export const DEFAULT_WHISPERCPP_TUNING: WhispercppTuning = {
+  threads: 0,
+  flashAttention: false,
+  cores: 'all',
+};
+
+export const WHISPERCPP_THREAD_LIMITS = { min: 0, max: 256 } as const;
+
+export const WHISPERCPP_TUNING_STORE_KEY = 'server.whispercppTuning';
+
+// Cores clocked below this fraction of the fastest count as efficiency cores.
+// Generous enough that the small per-core boost differences of non-hybrid
+// CPUs (AMD "preferred cores") never split them.
+const EFFICIENCY_CLOCK_RATIO = 0.85;
+
+/** Missing or out-of-range fields fall back to the defaults. */
+export function normalizeWhispercppTuning(raw: unknown): StoredWhispercppTuning {
+  const stored = (raw && typeof raw === 'object' ? raw : {}) as Partial<StoredWhispercppTuning>;
+  const { min, max } = WHISPERCPP_THREAD_LIMITS;
+  const threads =
+    typeof stored.threads === 'number' && Number.isInteger(stored.threads)
+      ? Math.min(max, Math.max(min, stored.threads))
+      : DEFAULT_WHISPERCPP_TUNING.threads;
+  return {
+    threads,
+    flashAttention:
+      typeof stored.flashAttention === 'boolean'
+        ? stored.flashAttention
+        : DEFAULT_WHISPERCPP_TUNING.flashAttention,
+    cores: stored.cores === 'performance' ? 'performance' : 'all',
+    ...(stored.benchmark && Array.isArray(stored.benchmark.runs)
+      ? { benchmark: stored.benchmark }
+      : {}),
+  };
+}
+
+/** Identifies this machine's hardware within the per-machine settings map. */
+export function machineKey(): string {
+  const cpus = os.cpus();
+  return `${os.hostname()} · ${cpus[0]?.model.trim() || 'unknown CPU'} · ${cpus.length} threads`;
+}
+
+/** This machine's entry of the stored per-machine map. */
+export function tuningForMachine(stored: unknown, key = machineKey()): StoredWhispercppTuning {
+  const byMachine = (stored && typeof stored === 'object' ? stored : {}) as Record<string, unknown>;
+  return normalizeWhispercppTuning(byMachine[key]);
+}
+
+/** `"0-3,8,10-11"` (sysfs / taskset syntax) as `[0, 1, 2, 3, 8, 10, 11]`. */
+export function parseCpuList(text: string): number[] {
+  const cpus: number[] = [];
+  for (const part of text.trim().split(',')) {
+    const match = /^(\d+)(?:-(\d+))?$/.exec(part.trim());
+    if (!match) continue;
+    const first = Number(match[1]);
+    const last = match[2] === undefined ? first : Number(match[2]);
+    for (let cpu = first; cpu <= last; cpu++) cpus.push(cpu);
+  }
+  return cpus;
+}
+
+/** Inverse of `parseCpuList`, collapsing runs into ranges. */
+export function formatCpuList(cpus: number[]): string {
+  const sorted = [...new Set(cpus)].sort((a, b) => a - b);
+  const parts: string[] = [];
+  for (let i = 0; i < sorted.length; i++) {
+    const start = sorted[i];
+    while (i + 1 < sorted.length && sorted[i + 1] === sorted[i] + 1) i++;
+    parts.push(start === sorted[i] ? String(start) : `${start}-${sorted[i]}`);
+  }
+  return parts.join(',');
+}
+
+type ReadText = (file: string) => string;
+
+const readSysfs: ReadText = (file) => fs.readFileSync(file, 'utf8');
+
+/**
+ * Split the CPUs into performance and efficiency cores. Linux only: Intel
+ * hybrid CPUs expose `cpu_core`/`cpu_atom` PMUs listing their CPUs; other
+ * hybrid designs are told apart by maximum clock. Elsewhere, and on
+ * uniform CPUs, both lists are empty.
+ */
+export function detectCpuTopology(
+  platform: NodeJS.Platform = process.platform,
+  logical = os.cpus().length,
+  read: ReadText = readSysfs,
+): CpuTopology {
+  const uniform: CpuTopology = { logical, performance: [], efficiency: [] };
+  if (platform !== 'linux') return uniform;
+
+  try {
+    const performance = parseCpuList(read('/sys/devices/cpu_core/cpus'));
+    const efficiency = parseCpuList(read('/sys/devices/cpu_atom/cpus'));
+    if (performance.length > 0 && efficiency.length > 0) {
+      return { logical, performance, efficiency };
+    }
+  } catch {
+    // Not an Intel hybrid CPU — fall through to clock speeds.
+  }
+
+  const clocks: { cpu: number; khz: number }[] = [];
+  for (let cpu = 0; cpu < logical; cpu++) {
+    try {
+      const khz = Number(read(`/sys/devices/system/cpu/cpu${cpu}/cpufreq/cpuinfo_max_freq`));
+      if (Number.isFinite(khz) && khz > 0) clocks.push({ cpu, khz });
+    } catch {
+      return uniform; // no cpufreq (VMs, some containers) — can't tell
+    }
+  }
+  const fastest = Math.max(...clocks.map((c) => c.khz));
+  const slow = clocks.filter((c) => c.khz < fastest * EFFICIENCY_CLOCK_RATIO);
+  if (slow.length === 0) return uniform;
+  return {
+    logical,
+    performance: clocks.filter((c) => !slow.includes(c)).map((c) => c.cpu),
+    efficiency: slow.map((c) => c.cpu),
+  };
+}
+
+export const isHybrid = (topology: CpuTopology) =>
+  topology.performance.length > 0 && topology.efficiency.length > 0;
+
+/** CPUs to pin the sidecar to, or null to leave it on every core. */
+export function pinnedCpus(tuning: WhispercppTuning, topology: CpuTopology): number[] | null {
+  return tuning.cores === 'performance' && isHybrid(topology) ? topology.performance : null;
+}
+
+/** Extra whisper-server arguments for the native exe. */
+export function sidecarArgs(tuning: WhispercppTuning): string[] {
+  return [
+    ...(tuning.threads > 0 ? ['--threads', String(tuning.threads)] : []),
+    ...(tuning.flashAttention ? ['--flash-attn'] : []),
+  ];
+}
+
+/**
+ * Compose env read by the sidecar's start command
+ * (server/docker/docker-compose.vulkan.yml). Empty values mean "default".
+ */
+export function sidecarComposeEnv(
+  tuning: WhispercppTuning,
+  topology: CpuTopology,
+): Record<string, string> {
+  const cpus = pinnedCpus(tuning, topology);
+  return {
+    WHISPERCPP_THREADS: tuning.threads > 0 ? String(tuning.threads) : '',
+    WHISPERCPP_FLASH_ATTN: tuning.flashAttention ? '1' : '',
+    WHISPERCPP_CPUSET: cpus ? formatCpuList(cpus) : '',
+  };
+}
+
+/**
+ * What the benchmark tries: a few thread counts over every core, the
+ * performance cores alone on a hybrid CPU, each with and without flash
+ * attention. Kept small — every candidate restarts the sidecar.
+ */
+export function benchmarkCandidates(topology: CpuTopology): WhispercppTuning[] {
+  const hybrid = isHybrid(topology);
+  const threadCounts = [
+    ...new Set([
+      ...(hybrid ? [topology.performance.length] : []),
+      Math.max(1, Math.floor(topology.logical / 2)),
+      topology.logical,
+    ]),
+  ];
+  const placements: Omit<WhispercppTuning, 'flashAttention'>[] = [
+    ...threadCounts.map((threads) => ({ threads, cores: 'all' as const })),
+    ...(hybrid ? [{ threads: topology.performance.length, cores: 'performance' as const }] : []),
+  ];
+  return placements.flatMap((p) => [
+    { ...p, flashAttention: false },
+    { ...p, flashAttention: true },
+  ]);
+}
+
+/** The fastest successful run; ties go to fewer threads. */
+export function pickBest(runs: WhispercppBenchmarkRun[]): WhispercppTuning | null {
+  const ok = runs.filter(
+    (r): r is WhispercppBenchmarkRun & { seconds: number } => r.seconds !== null,
+  );
+  if (ok.length === 0) return null;
+  const best = ok.reduce((a, b) =>
+    b.seconds < a.seconds || (b.seconds === a.seconds && b.threads < a.threads) ? b : a,
+  );
+  return { threads: best.threads, flashAttention: best.flashAttention, cores: best.cores };
+}
+
+/**
+ * 30 s of faint noise as a 16 kHz mono WAV. whisper.cpp always encodes a full
+ * 30 s window, and the encoder dominates its run time, so this times the part
+ * the settings affect without shipping a speech sample. Deterministic, so
+ * runs are comparable.
+ */
+export function benchmarkWav(seconds = 30, sampleRate = 16_000): Buffer {
+  const samples = seconds * sampleRate;
+  const wav = Buffer.alloc(44 + samples * 2);
+  wav.write('RIFF', 0, 'ascii');
+  wav.writeUInt32LE(36 + samples * 2, 4);
+  wav.write('WAVEfmt ', 8, 'ascii');
+  wav.writeUInt32LE(16, 16);
+  wav.writeUInt16LE(1, 20); // PCM
+  wav.writeUInt16LE(1, 22); // mono
+  wav.writeUInt32LE(sampleRate, 24);
+  wav.writeUInt32LE(sampleRate * 2, 28);
+  wav.writeUInt16LE(2, 32);
+  wav.writeUInt16LE(16, 34);
+  wav.write('data', 36, 'ascii');
+  wav.writeUInt32LE(samples * 2, 40);
+  let seed = 1;
+  for (let i = 0; i < samples; i++) {
+    seed = (seed * 1_103_515_245 + 12_345) & 0x7fffffff;
+    wav.writeInt16LE((seed % 200) - 100, 44 + i * 2);
+  }
+  return wav;
+}
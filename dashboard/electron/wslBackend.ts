@@ -0,0 +1,263 @@
+/**
+ * WSL backend — runs the server container with a Docker engine installed
+ * *inside* a WSL2 distro, for Windows users without Docker Desktop.
+ *
+ * Every command goes through `wsl.exe -d <distro> -- docker …`, so nothing
+ * beyond WSL itself is needed on the Windows side. The container uses the
+ * same name and named volumes as the compose stack, which keeps the rest
+ * of the dashboard (status, logs, model cache) agnostic of where it runs.
+ *
+ * Port forwarding: WSL2 normally forwards `localhost` to the distro. When
+ * `localhostForwarding` is disabled (or in some VPN setups) the server is
+ * only reachable on the distro's own address, which
+ * {@link WslBackendManager.resolveHost} falls back to.
+ */
+
+import type { ContainerStatus, DockerImage } from './dockerManager.js';
+
+export interface WslDistro {
+  name: string;
+  state: string;
+  version: number;
+  isDefault: boolean;
+}
+
+export interface WslDockerProbe {
+  available: boolean;
+  dockerVersion?: string;
+  reason?: string;
+}
+
+export interface WslRunOptions {
+  image: string;
+  containerName: string;
+  port: number;
+  gpu: boolean;
+  env: Record<string, string>;
+  /** Distro-side path (see `wslpath`) mounted as /user-config. */
+  userConfigDir?: string;
+}
+
+export interface WslBackendDeps {
+  /** Run `wsl.exe` with `args`; resolves with decoded stdout, rejects on non-zero exit. */
+  runWsl: (args: string[], timeoutMs?: number) => Promise<string>;
+  canConnect: (host: string, port: number) => Promise<boolean>;
+  onStatus: (status: ContainerStatus) => void;
+}
+
+/** Distros Docker Desktop installs for its own engine — never a target. */
+const INTERNAL_DISTROS = new Set(['docker-desktop', 'docker-desktop-data']);
+
+const MONITOR_INTERVAL_MS = 5_000;
+
+const INSPECT_FORMAT = '{{.State.Status}}\t{{.State.Health.Status}}\t{{.State.StartedAt}}';
+
+/** Named volumes shared with docker-compose.yml. */
+const VOLUMES: Array<[string, string]> = [
+  ['transcriptionsuite-data', '/data'],
+  ['transcriptionsuite-models', '/models'],
+  ['transcriptionsuite-runtime', '/runtime'],
+];
+
+/**
+ * Decode `wsl.exe` output. Its own messages (`--list`, errors) are UTF-16LE
+ * unless WSL_UTF8=1 is honoured; output of commands run inside a distro is
+ * passed through as-is (UTF-8).
+ */
+export function decodeWslOutput(buf: Buffer): string {
+  const bom = buf.length >= 2 && buf[0] === 0xff && buf[1] === 0xfe;
+  const looksUtf16 = bom || (buf.length >= 2 && buf[1] === 0 && buf[0] !== 0);
+  const text = looksUtf16 ? buf.toString('utf16le') : buf.toString('utf-8');
+  return text.replace(/^\uFEFF/, '').replace(/\0/g, '');
+}
+
+/** Parse `wsl.exe --list --verbose`. */
+export function parseWslList(text: string): WslDistro[] {
+  const distros: WslDistro[] = [];
+  for (const raw of text.split(/\r?\n/).slice(1)) {
+    const match = /^\s*(\*)?\s*(.+?)\s+(\S+)\s+(\d+)\s*$/.exec(raw);
+    if (!match) continue;
+    const [, star, name, state, version] = match;
+    if (INTERNAL_DISTROS.has(name.toLowerCase())) continue;
+    distros.push({ name, state, version: Number(version), isDefault: star === '*' });
+  }
+  return distros;
+}
+
+export function wslCommand(distro: string, command: string[]): string[] {
+  return ['-d', distro, '--', ...command];
+}
+
+/** `docker run` arguments mirroring docker-compose.yml + desktop-vm overlay. */
+export function wslDockerRunArgs(opts: WslRunOptions): string[] {
+  const env: Record<string, string> = {
+    DATA_DIR: '/data',
+    SERVER_HOST: '0.0.0.0',
+    SERVER_PORT: String(opts.port),
+    HF_HOME: '/models',
+    TORCH_HOME: '/models/torch-cache',
+    BOOTSTRAP_RUNTIME_DIR: '/runtime',
+    BOOTSTRAP_CACHE_DIR: '/runtime/cache',
+    BOOTSTRAP_STATUS_FILE: '/runtime/bootstrap-status.json',
+    BOOTSTRAP_REQUIRE_HF_TOKEN: 'false',
+    PYTORCH_CUDA_ALLOC_CONF: 'expandable_segments:True,garbage_collection_threshold:0.8',
+    ...opts.env,
+  };
+  const args = ['run', '-d', '--name', opts.containerName, '--restart', 'no'];
+  args.push('--stop-timeout', '130', '-p', `${opts.port}:${opts.port}`);
+  args.push('--add-host', 'host.docker.internal:host-gateway');
+  if (opts.gpu) args.push('--gpus', 'all');
+  for (const [volume, target] of VOLUMES) args.push('-v', `${volume}:${target}`);
+  if (opts.userConfigDir) args.push('-v', `${opts.userConfigDir}:/user-config`);
+  for (const [key, value] of Object.entries(env)) args.push('-e', `${key}=${value}`);
+  args.push(opts.image);
+  return args;
+}
+
+export function parseInspect(output: string): ContainerStatus {
+  const [status, health, startedAt] = output.trim().split('\t');
+  return {
+    exists: true,
+    running: status === 'running',
+    status: status || 'unknown',
+    health: health && health !== '<nil>' ? health : undefined,
+    startedAt,
+  };
+}
+
+/** First IPv4 address from `hostname -I`. */
+export function firstIpv4(output: string): string | null {
+  return output.split(/\s+/).find((addr) => /^\d{1,3}(\.\d{1,3}){3}$/.test(addr)) ?? null;
+}
+
+export class WslBackendManager {
+  private _deps: WslBackendDeps;
+  private _monitor: ReturnType<typeof setInterval> | null = null;
+  private _lastStatus: ContainerStatus | null = null;
+
+  constructor(deps: WslBackendDeps) {
+    this._deps = deps;
+  }
+
+  async listDistros(): Promise<WslDistro[]> {
+    try {
+      return parseWslList(await this._deps.runWsl(['--list', '--verbose'], 15_000));
+    } catch {
+      return [];
+    }
+  }
+
+  async probe(distro: string): Promise<WslDockerProbe> {
+    if (!distro) return { available: false, reason: 'No WSL distro selected' };
+    try {
+      const version = await this._docker(distro, ['version', '--format', '{{.Server.Version}}']);
+      return { available: true, dockerVersion: version.trim() };
+    } catch (err) {
+      const message = err instanceof Error ? err.message : String(err);
+      return { available: false, reason: `Docker is not usable in ${distro}: ${message}` };
+    }
+  }
+
+  async getContainerStatus(distro: string, containerName: string): Promise<ContainerStatus> {
+    try {
+      return parseInspect(
+        await this._docker(distro, ['inspect', '--format', INSPECT_FORMAT, containerName]),
+      );
+    } catch {
+      return { exists: false, running: false, status: 'not found' };
+    }
+  }
+
+  async listImages(distro: string, repo: string): Promise<DockerImage[]> {
+    const format = '{{.Repository}}:{{.Tag}}\t{{.Size}}\t{{.CreatedSince}}\t{{.ID}}';
+    try {
+      const output = await this._docker(distro, ['images', '--format', format, repo]);
+      return output
+        .split(/\r?\n/)
+        .filter(Boolean)
+        .map((line) => {
+          const [fullName = '', size = '', created = '', id = ''] = line.split('\t');
+          const tag = fullName.slice(fullName.lastIndexOf(':') + 1);
+          return { tag, fullName, size, created, id };
+        })
+        .filter((img) => img.tag !== '<none>');
+    } catch {
+      return [];
+    }
+  }
+
+  async pullImage(distro: string, image: string): Promise<string> {
+    return this._docker(distro, ['pull', image], 60 * 60_000);
+  }
+
+  /** Translate a Windows path to its distro-side form, e.g. C:\x → /mnt/c/x. */
+  async toDistroPath(distro: string, windowsPath: string): Promise<string> {
+    const out = await this._deps.runWsl(wslCommand(distro, ['wslpath', '-a', '-u', windowsPath]));
+    return out.trim();
+  }
+
+  async start(distro: string, opts: WslRunOptions): Promise<string> {
+    await this._docker(distro, ['rm', '-f', opts.containerName]).catch(() => '');
+    const id = await this._docker(distro, wslDockerRunArgs(opts), 60 * 60_000);
+    this.startMonitor(distro, opts.containerName);
+    return id.trim();
+  }
+
+  async stop(distro: string, containerName: string): Promise<void> {
+    this.stopMonitor();
+    await this._docker(distro, ['stop', '-t', '130', containerName], 150_000);
+    await this._docker(distro, ['rm', containerName]).catch(() => '');
+    this._deps.onStatus({ exists: false, running: false, status: 'not found' });
+  }
+
+  async getLogs(distro: string, containerName: string, tail = 200): Promise<string[]> {
+    try {
+      const args = ['logs', '--timestamps', '--tail', String(tail), containerName];
+      return (await this._docker(distro, args)).split(/\r?\n/).filter(Boolean);
+    } catch {
+      return [];
+    }
+  }
+
+  /**
+   * Host the dashboard should use for the server: `localhost` when WSL
+   * forwards the port, else the distro's own address.
+   */
+  async resolveHost(distro: string, port: number): Promise<string> {
+    if (await this._deps.canConnect('127.0.0.1', port)) return 'localhost';
+    try {
+      const ip = firstIpv4(await this._deps.runWsl(wslCommand(distro, ['hostname', '-I'])));
+      if (ip && (await this._deps.canConnect(ip, port))) return ip;
+    } catch {
+      // Fall through — localhost is the best guess while the server boots.
+    }
+    return 'localhost';
+  }
+
+  /**
+   * Poll the container so a crash, `wsl --shutdown` or the distro idling out
+   * is noticed without the user refreshing.
+   */
+  startMonitor(distro: string, containerName: string): void {
+    this.stopMonitor();
+    this._lastStatus = null;
+    this._monitor = setInterval(() => {
+      void this.getContainerStatus(distro, containerName).then((status) => {
+        const last = this._lastStatus;
+        this._lastStatus = status;
+        if (last && last.status === status.status && last.health === status.health) return;
+        this._deps.onStatus(status);
+        if (!status.exists || status.status === 'exited') this.stopMonitor();
+      });
+    }, MONITOR_INTERVAL_MS);
+  }
+
+  stopMonitor(): void {
+    if (this._monitor) clearInterval(this._monitor);
+    this._monitor = null;
+  }
+
+  private _docker(distro: string, args: string[], timeoutMs?: number): Promise<string> {
+    return this._deps.runWsl(wslCommand(distro, ['docker', ...args]), timeoutMs);
+  }
+}
@@ -0,0 +1,18 @@
+locale-name = Ελληνικά
+
+nav-session = Συνεδρία
+nav-notebook = Σημειωματάριο
+nav-server = Διακομιστής
+nav-logs = Αρχεία καταγραφής
+nav-search = Αναζήτηση
+nav-import = Εισαγωγή
+
+settings-title = Ρυθμίσεις
+settings-tab-app = Εφαρμογή
+settings-tab-client = Πελάτης
+settings-tab-server = Διακομιστής
+settings-tab-ai = AI
+settings-tab-notebook = Σημειωματάριο
+settings-tab-profiles = Προφίλ
+settings-language = Γλώσσα
+settings-language-hint = Εφαρμόζεται αμέσως. Προσθέστε μεταφράσεις της κοινότητας τοποθετώντας ένα αρχείο .ftl στον φάκελο { $folder }.
@@ -0,0 +1,20 @@
+# English (bundled fallback). Every other catalog is compared against this one;
+# a message missing from a translation falls back to the text here.
+locale-name = English
+
+nav-session = Session
+nav-notebook = Notebook
+nav-server = Server
+nav-logs = Logs
+nav-search = Search
+nav-import = Import
+
+settings-title = Settings
+settings-tab-app = App
+settings-tab-client = Client
+settings-tab-server = Server
+settings-tab-ai = AI
+settings-tab-notebook = Notebook
+settings-tab-profiles = Profiles
+settings-language = Language
+settings-language-hint = Applies immediately. Add community translations by dropping a .ftl file into { $folder }.
@@ -4,12 +4,14 @@
  * 2. Compiles Electron main/preload TypeScript
  * 3. Launches Electron once Vite is ready
  *
- * Usage: node scripts/dev-electron.mjs
+ * Usage: node scripts/dev-electron.mjs [electron args, e.g. --demo]
  */
 
 import { spawn } from 'child_process';
 
 const VITE_PORT = 3000;
+// Extra arguments are passed on to Electron (`--demo` runs against the mock server).
+const electronArgs = process.argv.slice(2);
 
 // 1. Start Vite dev server
 const vite = spawn('npx', ['vite'], {
@@ -46,7 +48,7 @@ tsc.on('close', (code) => {
         });
         delete electronEnv.ELECTRON_LOG_FILE;
         delete electronEnv.CHROME_LOG_FILE;
-        const electron = spawn('npx', ['electron', '.'], {
+        const electron = spawn('npx', ['electron', '.', ...electronArgs], {
           stdio: 'inherit',
           shell: true,
           env: electronEnv,
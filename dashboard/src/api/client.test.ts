@@ -1,6 +1,6 @@
 import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
 
-import { APIClient, apiClient, initApiClient } from './client';
+import { APIClient, PermissionDeniedError, apiClient, initApiClient } from './client';
 
 // Renderer-side install-gate mirror: when useRemote=true with a blank active-
 // profile host, checkConnection must return `'remote-host-not-configured'`
@@ -444,6 +444,26 @@ describe('APIClient.getAudioUrl / getExportUrl — return null when not configur
     expect(url).not.toBeNull();
     expect(url).toMatch(/\/api\/notebook\/recordings\/42\/audio/);
   });
+
+  it('getExportUrl forwards a marker keyword as ?q=', async () => {
+    (window as any).electronAPI = {
+      config: {
+        get: vi.fn(async (key: string) => {
+          const seed: Record<string, unknown> = {
+            'connection.useRemote': false,
+          };
+          return seed[key];
+        }),
+        set: vi.fn(),
+      },
+    };
+    const client = new APIClient();
+    await client.syncFromConfig();
+    const url = new URL(client.getExportUrl(42, 'edl', '  budget review ')!);
+    expect(url.searchParams.get('format')).toBe('edl');
+    expect(url.searchParams.get('q')).toBe('budget review');
+    expect(new URL(client.getExportUrl(42, 'edl', '')!).searchParams.has('q')).toBe(false);
+  });
 });
 
 // Install-gate hardening: syncFromConfig is now throw-safe (catches IPC
@@ -724,3 +744,203 @@ describe('APIClient — transcription-result recovery uses absolute URLs (GH-202
     ).toBe('Bearer tok123');
   });
 });
+
+describe('APIClient — per-user identity on shared servers', () => {
+  let fetchSpy: ReturnType<typeof vi.fn>;
+
+  const recording = (id: number, owner: string | null) => ({ id, owner, title: `rec ${id}` });
+
+  async function syncedClient(seed: ConfigSeed, body: unknown): Promise<APIClient> {
+    installElectronBridge({ 'connection.useRemote': false, ...seed }, vi.fn());
+    fetchSpy.mockImplementation(async () => new Response(JSON.stringify(body), { status: 200 }));
+    const client = new APIClient();
+    await client.syncFromConfig();
+    return client;
+  }
+
+  beforeEach(() => {
+    fetchSpy = vi.fn();
+    vi.stubGlobal('fetch', fetchSpy);
+  });
+
+  afterEach(() => {
+    vi.unstubAllGlobals();
+    vi.restoreAllMocks();
+    delete (window as any).electronAPI;
+  });
+
+  it('sends the user name percent-encoded, and no header without one', async () => {
+    const named = await syncedClient({ 'identity.userName': ' Zoë ' }, []);
+    await named.listRecordings();
+    const headers = (fetchSpy.mock.calls[0][1] as RequestInit).headers as Record<string, string>;
+    expect(headers['X-TranscriptionSuite-User']).toBe('Zo%C3%AB');
+
+    const anonymous = await syncedClient({}, []);
+    await anonymous.listRecordings();
+    expect((fetchSpy.mock.calls[1][1] as RequestInit).headers).not.toHaveProperty(
+      'X-TranscriptionSuite-User',
+    );
+  });
+
+  it('hides recordings of other users only when the filter is on', async () => {
+    const rows = [recording(1, 'alice'), recording(2, 'bob'), recording(3, null)];
+
+    const all = await syncedClient({ 'identity.userName': 'alice' }, rows);
+    expect((await all.listRecordings()).map((r) => r.id)).toEqual([1, 2, 3]);
+
+    const mine = await syncedClient(
+      { 'identity.userName': 'alice', 'identity.showOnlyMine': true },
+      rows,
+    );
+    expect((await mine.listRecordings()).map((r) => r.id)).toEqual([1, 3]);
+  });
+
+  it('recounts the calendar after filtering', async () => {
+    const client = await syncedClient(
+      { 'identity.userName': 'alice', 'identity.showOnlyMine': true },
+      {
+        year: 2026,
+        month: 10,
+        days: {
+          '2026-10-01': [recording(1, 'bob')],
+          '2026-10-02': [recording(2, 'alice'), recording(3, 'bob')],
+        },
+        total_recordings: 3,
+      },
+    );
+    const calendar = await client.getCalendar(2026, 10);
+    expect(Object.keys(calendar.days)).toEqual(['2026-10-02']);
+    expect(calendar.total_recordings).toBe(1);
+  });
+
+  it('keeps only our own queue entries, with their original positions', async () => {
+    const now = Date.now() / 1000;
+    const entry = (position: number, mine: boolean) => ({
+      id: mine ? `e${position}` : null,
+      position,
+      user: mine ? 'me' : 'other',
+      mine,
+      label: null,
+      duration_seconds: null,
+      enqueued_at: now,
+      estimated_start_at: null,
+    });
+    const client = await syncedClient(
+      { 'identity.userName': 'alice', 'identity.showOnlyMine': true },
+      { now, active: null, entries: [entry(0, false), entry(1, true)] },
+    );
+    const queue = await client.getServerQueue();
+    expect(queue.entries.map((e) => e.position)).toEqual([1]);
+  });
+});
+
+describe('APIClient — capability gating from server permissions', () => {
+  let fetchSpy: ReturnType<typeof vi.fn>;
+
+  const respond = (routes: Record<string, { status: number; body: unknown }>) =>
+    fetchSpy.mockImplementation(async (url: string) => {
+      const path = new URL(url).pathname;
+      const { status, body } = routes[path] ?? { status: 200, body: {} };
+      return new Response(JSON.stringify(body), { status });
+    });
+
+  const permissions = (granted: boolean) => ({
+    user: 'kid',
+    is_admin: granted,
+    permissions: {
+      can_manage_models: granted,
+      can_manage_tokens: granted,
+      can_change_server_settings: granted,
+      can_manage_library: granted,
+      can_view_all_jobs: granted,
+      can_view_audit_log: granted,
+    },
+  });
+
+  async function syncedClient(): Promise<APIClient> {
+    installElectronBridge({ 'connection.useRemote': false }, vi.fn());
+    const client = new APIClient();
+    await client.syncFromConfig();
+    return client;
+  }
+
+  beforeEach(() => {
+    fetchSpy = vi.fn();
+    vi.stubGlobal('fetch', fetchSpy);
+  });
+
+  afterEach(() => {
+    vi.unstubAllGlobals();
+    vi.restoreAllMocks();
+    delete (window as any).electronAPI;
+  });
+
+  it('refuses locally when the server withholds the capability', async () => {
+    respond({ '/api/auth/permissions': { status: 200, body: permissions(false) } });
+    const client = await syncedClient();
+
+    const err = await client.unloadModels().catch((e: unknown) => e);
+    expect(err).toBeInstanceOf(PermissionDeniedError);
+    expect(err).toMatchObject({ status: 403, capability: 'can_manage_models' });
+    expect(fetchSpy).toHaveBeenCalledTimes(1); // only the permissions lookup
+  });
+
+  it('turns a server 403 into PermissionDeniedError', async () => {
+    respond({
+      '/api/auth/permissions': { status: 200, body: permissions(true) },
+      '/api/auth/tokens': { status: 403, body: { detail: 'Admin access required' } },
+    });
+    const client = await syncedClient();
+
+    await expect(client.listTokens()).rejects.toMatchObject({
+      name: 'PermissionDeniedError',
+      capability: 'can_manage_tokens',
+    });
+  });
+
+  it('lets the call through on servers without the permissions endpoint', async () => {
+    respond({
+      '/api/auth/permissions': { status: 404, body: { detail: 'Not Found' } },
+      '/api/admin/models/load': { status: 200, body: { status: 'loaded' } },
+    });
+    const client = await syncedClient();
+
+    expect(await client.getPermissions()).toBeNull();
+    await expect(client.loadModels()).resolves.toEqual({ status: 'loaded' });
+  });
+
+  it('asks again after the auth token changes', async () => {
+    respond({ '/api/auth/permissions': { status: 200, body: permissions(true) } });
+    const client = await syncedClient();
+
+    await client.getPermissions();
+    await client.getPermissions();
+    client.setAuthToken('other-token');
+    await client.getPermissions();
+    expect(fetchSpy).toHaveBeenCalledTimes(2);
+  });
+
+  it('repeats the user name as confirmation when removing a server user', async () => {
+    respond({
+      '/api/auth/permissions': { status: 200, body: permissions(true) },
+      '/api/auth/users/Kid%20Laptop': { status: 200, body: { success: true, revoked: 2 } },
+    });
+    const client = await syncedClient();
+
+    await expect(client.removeServerUser('Kid Laptop')).resolves.toEqual({
+      success: true,
+      revoked: 2,
+    });
+    const [url, init] = fetchSpy.mock.calls[1];
+    expect(init.method).toBe('DELETE');
+    expect(new URL(url).searchParams.get('confirm')).toBe('Kid Laptop');
+  });
+
+  it('does not rotate tokens without the capability', async () => {
+    respond({ '/api/auth/permissions': { status: 200, body: permissions(false) } });
+    const client = await syncedClient();
+
+    await expect(client.rotateToken('abc')).rejects.toBeInstanceOf(PermissionDeniedError);
+    expect(fetchSpy).toHaveBeenCalledTimes(1);
+  });
+});
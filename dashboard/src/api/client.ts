@@ -4,37 +4,107 @@
  */
 
 import {
+  DEFAULT_CONFIG,
   DEFAULT_SERVER_PORT,
   getAuthToken,
+  getConnectionTuning,
+  getConfig,
+  getIdentity,
+  getProfileBaseUrl,
   getServerBaseUrl,
   isServerUrlConfigured,
+  normalizeConnectionTuning,
+  type ConnectionProfile,
+  type ConnectionTuning,
 } from '../config/store';
+import { tracedFetch } from '../services/networkTrace';
+import { canCompressUploads, gzipBlob, isCompressibleAudio } from '../services/uploadCompression';
 import type {
   HealthResponse,
   ReadyResponse,
   ServerStatus,
   LoginRequest,
   LoginResponse,
+  Capability,
+  Permissions,
+  PermissionsResponse,
   AuthToken,
+  ModelBenchmarkResponse,
+  ModelCachePruneResult,
+  GoldenCheckResult,
+  GoldenSetReport,
+  ServerUser,
+  UsageReport,
+  AuditEvent,
+  AuditLogQuery,
   CreateTokenRequest,
   TranscriptionResponse,
   TranscriptionUploadOptions,
   TranscriptionCancelResponse,
   LanguagesResponse,
+  LanguageDetectionResult,
+  MediaInfo,
   Recording,
   RecordingDetail,
   RecordingTranscription,
   TranscriptionAccepted,
+  StagedUploadStatus,
+  UploadSource,
   DedupCheckResponse,
   CalendarResponse,
   TimeslotResponse,
   ExportFormat,
+  ShareFormat,
+  ShareTarget,
+  ShareResult,
+  TimecodeSettings,
+  RecordingTimecodeResponse,
+  LabelImportResult,
+  SubtitleImportOptions,
+  SubtitleImportResult,
+  TranscriptImportOptions,
+  TranscriptImportResult,
+  ProjectImportResult,
+  RealignResult,
+  SegmentRetranscriptionResult,
+  PartialResumeResult,
+  SegmentSplitResult,
+  SegmentMergeResult,
+  CaptionLintResult,
+  OutputRouteRequest,
+  LibraryExportRequest,
+  LibraryExportEvent,
+  PlannedOutput,
+  RoutedOutput,
+  PostRuleReport,
+  PostRuleTestRequest,
+  LowConfidenceResponse,
+  SegmentReviewResponse,
+  SegmentReviewState,
+  Annotation,
+  AnnotationCreate,
+  AnnotationsResponse,
+  Redaction,
+  RedactionAudioMode,
+  RedactionsResponse,
+  OutlineResponse,
+  SpeakerStatsResponse,
+  TranscriptDiffResponse,
+  SpeakerIdentificationResult,
+  VoiceProfile,
+  VoiceProfilesResponse,
   BackupsResponse,
   BackupCreateResponse,
   RestoreResponse,
+  MaintenanceEvent,
+  MaintenanceStatus,
   SearchResponse,
   WordSearchResponse,
+  MetadataFieldSummary,
+  ProjectTemplate,
+  ProjectTemplatePayload,
   AdminStatus,
+  LibraryLockStatus,
   LogsResponse,
   LLMStatus,
   LLMResponse,
@@ -44,11 +114,23 @@ import type {
   LLMModel,
   Conversation,
   ChatMessage,
+  ServerQueue,
 } from './types';
 
 // Re-export types that consumers need
 export type { HealthResponse, ReadyResponse, ServerStatus } from './types';
 
+/** Names the person using this client on a shared server (identity.userName). */
+const USER_HEADER = 'X-TranscriptionSuite-User';
+
+/** Start a multipart body with the file, or the id of its staged upload. */
+function uploadFormData(source: UploadSource): FormData {
+  const fd = new FormData();
+  if (source instanceof File) fd.append('file', source);
+  else fd.append('upload_id', source.upload_id);
+  return fd;
+}
+
 // ─── Profiles types (Issue #104, Story 1.2) ──────────────────────────────────
 
 export interface ProfilePublicFields {
@@ -56,6 +138,8 @@ export interface ProfilePublicFields {
   destination_folder: string;
   auto_summary_enabled: boolean;
   auto_export_enabled: boolean;
+  /** Suggest PII redactions on completion (before auto-export). */
+  pii_scan_enabled?: boolean;
   summary_model_id: string | null;
   summary_prompt_template: string | null;
   export_format: string;
@@ -92,6 +176,14 @@ export class APIClient {
   private baseUrl: string;
   private authToken: string | null = null;
   private synced: boolean = false;
+  private tuning: ConnectionTuning = DEFAULT_CONFIG.connection.tuning.local;
+  private identity = DEFAULT_CONFIG.identity;
+  // Capabilities of the current token; reset whenever the token or server
+  // changes. `knownPermissions` is the settled value for synchronous checks.
+  private permissionsRequest: Promise<Permissions | null> | null = null;
+  private knownPermissions: Permissions | null = null;
+  // Request body encodings the server decodes (/api/status); reset with the server.
+  private requestEncodings: Promise<string[]> | null = null;
   // Listeners notified after any syncFromConfig() attempt — success OR failure.
   // Consumers (socket-owning hooks) re-check predicate state on event because
   // a failed sync still mutates the gate from "pre-sync" to "post-sync-failed".
@@ -131,6 +223,40 @@ export class APIClient {
     }
   }
 
+  /**
+   * A client for another connection profile (same token and identity), for
+   * comparing servers without switching the app's connection. Null when the
+   * profile has no host configured.
+   */
+  static async forProfile(profile: ConnectionProfile): Promise<APIClient | null> {
+    const url = await getProfileBaseUrl(profile);
+    if (!url) return null;
+    const client = new APIClient(url);
+    client.tuning = normalizeConnectionTuning(
+      await getConfig(`connection.tuning.${profile}`),
+      profile,
+    );
+    client.identity = await getIdentity();
+    client.authToken = await getAuthToken();
+    client.synced = true;
+    return client;
+  }
+
+  /**
+   * A client for another server on the same host, e.g. a server replica on
+   * the next port. Shares this client's token, identity and tuning.
+   */
+  atPort(port: number): APIClient {
+    const url = new URL(this.baseUrl);
+    url.port = String(port);
+    const client = new APIClient(url.toString());
+    client.tuning = this.tuning;
+    client.identity = this.identity;
+    client.authToken = this.authToken;
+    client.synced = this.synced;
+    return client;
+  }
+
   // ─── Configuration ────────────────────────────────────────────────────────
 
   /** Update the server base URL */
@@ -145,6 +271,7 @@ export class APIClient {
 
   /** Set the auth token for authenticated requests */
   setAuthToken(token: string | null): void {
+    if (token !== this.authToken) this.resetPermissions();
     this.authToken = token;
   }
 
@@ -168,7 +295,11 @@ export class APIClient {
   async syncFromConfig(): Promise<void> {
     try {
       const url = await getServerBaseUrl();
+      this.tuning = await getConnectionTuning();
+      this.identity = await getIdentity();
       this.setBaseUrl(url);
+      this.resetPermissions();
+      this.requestEncodings = null;
       this.synced = true;
     } catch (err) {
       const detail = err instanceof Error ? err.message : String(err);
@@ -202,6 +333,33 @@ export class APIClient {
 
   // ─── Internal helpers ─────────────────────────────────────────────────────
 
+  private resetPermissions(): void {
+    this.permissionsRequest = null;
+    this.knownPermissions = null;
+  }
+
+  /**
+   * Run an admin-only call: fail fast with PermissionDeniedError when the
+   * server has said the token lacks `capability`, and turn the server's own
+   * 403 into the same error. Unknown permissions never block the call.
+   */
+  private async withCapability<T>(
+    capability: Capability,
+    path: string,
+    call: () => Promise<T>,
+  ): Promise<T> {
+    const permissions = await this.getPermissions().catch(() => null);
+    if (permissions?.[capability] === false) throw new PermissionDeniedError(capability, path);
+    try {
+      return await call();
+    } catch (err) {
+      if (err instanceof APIError && err.status === 403) {
+        throw new PermissionDeniedError(capability, path);
+      }
+      throw err;
+    }
+  }
+
   private ensureConfigured(path: string): void {
     if (!this.isBaseUrlConfigured()) {
       throw new APIError(0, 'remote-host-not-configured', path);
@@ -209,20 +367,51 @@ export class APIClient {
   }
 
   private headers(): Record<string, string> {
-    const h: Record<string, string> = { 'Content-Type': 'application/json' };
-    if (this.authToken) h['Authorization'] = `Bearer ${this.authToken}`;
-    return h;
+    return { 'Content-Type': 'application/json', ...this.authHeaders() };
   }
 
   private authHeaders(): Record<string, string> {
     const h: Record<string, string> = {};
     if (this.authToken) h['Authorization'] = `Bearer ${this.authToken}`;
+    // Header values must be ISO-8859-1; the server percent-decodes the name.
+    if (this.identity.userName) h[USER_HEADER] = encodeURIComponent(this.identity.userName);
     return h;
   }
 
+  /**
+   * Per-user namespaces on a shared server: with `identity.showOnlyMine` on,
+   * drop recordings another user created. Unowned recordings (live mode,
+   * older ones, clients without a user name) stay visible to everyone.
+   */
+  private ownRecordings<T extends Recording>(recordings: T[]): T[] {
+    const { userName, showOnlyMine } = this.identity;
+    if (!showOnlyMine || !userName) return recordings;
+    return recordings.filter((rec) => !rec.owner || rec.owner === userName);
+  }
+
+  /**
+   * fetch() bounded by the active profile's timeouts. The renderer cannot
+   * tell connecting apart from waiting, so the deadline is connect + read.
+   * Uploads and event streams run for as long as they need and skip this.
+   */
+  private async timedFetch(path: string, init: RequestInit = {}): Promise<Response> {
+    const seconds = this.tuning.connectTimeoutSeconds + this.tuning.readTimeoutSeconds;
+    try {
+      return await tracedFetch(`${this.baseUrl}${path}`, {
+        ...init,
+        signal: AbortSignal.timeout(seconds * 1000),
+      });
+    } catch (err) {
+      if (err instanceof DOMException && err.name === 'TimeoutError') {
+        throw new APIError(0, `Request timed out after ${seconds}s`, path);
+      }
+      throw err;
+    }
+  }
+
   private async get<T>(path: string): Promise<T> {
     this.ensureConfigured(path);
-    const res = await fetch(`${this.baseUrl}${path}`, {
+    const res = await this.timedFetch(path, {
       headers: this.authHeaders(),
     });
     if (!res.ok) throw new APIError(res.status, await res.text(), path);
@@ -231,7 +420,7 @@ export class APIClient {
 
   private async post<T>(path: string, body?: unknown): Promise<T> {
     this.ensureConfigured(path);
-    const res = await fetch(`${this.baseUrl}${path}`, {
+    const res = await this.timedFetch(path, {
       method: 'POST',
       headers: this.headers(),
       body: body !== undefined ? JSON.stringify(body) : undefined,
@@ -242,7 +431,7 @@ export class APIClient {
 
   private async patch<T>(path: string, body: unknown): Promise<T> {
     this.ensureConfigured(path);
-    const res = await fetch(`${this.baseUrl}${path}`, {
+    const res = await this.timedFetch(path, {
       method: 'PATCH',
       headers: this.headers(),
       body: JSON.stringify(body),
@@ -253,7 +442,7 @@ export class APIClient {
 
   private async put<T>(path: string, body?: unknown): Promise<T> {
     this.ensureConfigured(path);
-    const res = await fetch(`${this.baseUrl}${path}`, {
+    const res = await this.timedFetch(path, {
       method: 'PUT',
       headers: this.headers(),
       body: body !== undefined ? JSON.stringify(body) : undefined,
@@ -264,7 +453,7 @@ export class APIClient {
 
   private async del<T>(path: string): Promise<T> {
     this.ensureConfigured(path);
-    const res = await fetch(`${this.baseUrl}${path}`, {
+    const res = await this.timedFetch(path, {
       method: 'DELETE',
       headers: this.authHeaders(),
     });
@@ -274,15 +463,49 @@ export class APIClient {
 
   private async postFormData<T>(path: string, formData: FormData): Promise<T> {
     this.ensureConfigured(path);
-    const res = await fetch(`${this.baseUrl}${path}`, {
+    const res = await tracedFetch(`${this.baseUrl}${path}`, {
       method: 'POST',
-      headers: this.authHeaders(), // No Content-Type — browser sets multipart boundary
-      body: formData,
+      ...(await this.multipartInit(formData)),
     });
     if (!res.ok) throw new APIError(res.status, await res.text(), path);
     return res.json();
   }
 
+  /**
+   * Whether the server decodes gzip request bodies, cached until the server
+   * changes. Servers that predate `request_encodings` get plain bodies.
+   */
+  private async acceptsGzipBodies(): Promise<boolean> {
+    if (!canCompressUploads()) return false;
+    this.requestEncodings ??= this.getStatus().then(
+      (status) => status.request_encodings ?? [],
+      () => {
+        this.requestEncodings = null;
+        return [];
+      },
+    );
+    return (await this.requestEncodings).includes('gzip');
+  }
+
+  /** Headers and body for a multipart upload; forms carrying WAV/PCM audio are gzipped. */
+  private async multipartInit(formData: FormData): Promise<RequestInit> {
+    const headers = this.authHeaders(); // No Content-Type — browser sets multipart boundary
+    const hasAudio = [...formData.values()].some(
+      (value) => value instanceof File && isCompressibleAudio(value),
+    );
+    if (!hasAudio || !(await this.acceptsGzipBodies())) return { headers, body: formData };
+    // Let the browser encode the form (and pick the boundary), then gzip the result.
+    const encoded = new Request(this.baseUrl, { method: 'POST', body: formData });
+    return {
+      headers: {
+        ...headers,
+        'Content-Type': encoded.headers.get('Content-Type') ?? 'multipart/form-data',
+        'Content-Encoding': 'gzip',
+      },
+      body: await gzipBlob(await encoded.blob()),
+    };
+  }
+
   // ─── Health / Status ──────────────────────────────────────────────────────
 
   /** GET /health — basic liveness check */
@@ -310,14 +533,14 @@ export class APIClient {
 
   /** GET /api/transcribe/result/{jobId} — fetch a persisted (possibly >1 MB) result. */
   async fetchTranscriptionResult(jobId: string): Promise<Response> {
-    return fetch(`${this.baseUrl}/api/transcribe/result/${jobId}`, {
+    return tracedFetch(`${this.baseUrl}/api/transcribe/result/${jobId}`, {
       headers: this.authHeaders(),
     });
   }
 
   /** GET /api/transcribe/recent — recently completed but undelivered results for this caller. */
   async fetchRecentUndelivered(): Promise<Response> {
-    return fetch(`${this.baseUrl}/api/transcribe/recent`, {
+    return tracedFetch(`${this.baseUrl}/api/transcribe/recent`, {
       headers: this.authHeaders(),
     });
   }
@@ -335,7 +558,7 @@ export class APIClient {
 
   /** POST /api/transcribe/result/{jobId}/dismiss — mark a recovered result dismissed. */
   async dismissTranscriptionResult(jobId: string): Promise<Response> {
-    return fetch(`${this.baseUrl}/api/transcribe/result/${jobId}/dismiss`, {
+    return tracedFetch(`${this.baseUrl}/api/transcribe/result/${jobId}/dismiss`, {
       method: 'POST',
       headers: this.authHeaders(),
     });
@@ -528,21 +751,80 @@ export class APIClient {
     return this.post('/api/auth/login', body);
   }
 
+  /**
+   * GET /api/auth/permissions — what the current token may do, cached until
+   * the token or server changes. Null when the server predates the endpoint;
+   * callers then leave every feature visible and let the server decide.
+   */
+  async getPermissions(): Promise<Permissions | null> {
+    this.permissionsRequest ??= this.get<PermissionsResponse>('/api/auth/permissions').then(
+      (res) => (this.knownPermissions = res.permissions),
+      (err) => {
+        if (err instanceof APIError && err.status === 404) return null;
+        this.permissionsRequest = null;
+        throw err;
+      },
+    );
+    return this.permissionsRequest;
+  }
+
   /** GET /api/auth/tokens — admin only */
   async listTokens(): Promise<{ tokens: AuthToken[] }> {
-    return this.get('/api/auth/tokens');
+    return this.withCapability('can_manage_tokens', '/api/auth/tokens', () =>
+      this.get('/api/auth/tokens'),
+    );
   }
 
   /** POST /api/auth/tokens — admin only */
   async createToken(
     req: CreateTokenRequest,
   ): Promise<{ success: boolean; message: string; token: AuthToken }> {
-    return this.post('/api/auth/tokens', req);
+    return this.withCapability('can_manage_tokens', '/api/auth/tokens', () =>
+      this.post('/api/auth/tokens', req),
+    );
   }
 
   /** DELETE /api/auth/tokens/:id — admin only */
   async revokeToken(tokenId: string): Promise<{ success: boolean }> {
-    return this.del(`/api/auth/tokens/${tokenId}`);
+    const path = `/api/auth/tokens/${tokenId}`;
+    return this.withCapability('can_manage_tokens', path, () => this.del(path));
+  }
+
+  /** POST /api/auth/tokens/:id/rotate — admin only; the old secret stops working */
+  async rotateToken(tokenId: string): Promise<{ success: boolean; token: AuthToken }> {
+    const path = `/api/auth/tokens/${tokenId}/rotate`;
+    return this.withCapability('can_manage_tokens', path, () => this.post(path));
+  }
+
+  /** GET /api/auth/users — admin only */
+  async listServerUsers(): Promise<{ users: ServerUser[] }> {
+    return this.withCapability('can_manage_tokens', '/api/auth/users', () =>
+      this.get('/api/auth/users'),
+    );
+  }
+
+  /** DELETE /api/auth/users/:name — admin only; revokes every token of the user */
+  async removeServerUser(name: string): Promise<{ success: boolean; revoked: number }> {
+    const encoded = encodeURIComponent(name);
+    const path = `/api/auth/users/${encoded}?confirm=${encoded}`;
+    return this.withCapability('can_manage_tokens', path, () => this.del(path));
+  }
+
+  /** GET /api/admin/usage — admin only; jobs per client and recordings per owner */
+  async getUsage(days?: number): Promise<UsageReport> {
+    const path = days ? `/api/admin/usage?days=${days}` : '/api/admin/usage';
+    return this.withCapability('can_view_all_jobs', path, () => this.get(path));
+  }
+
+  /** GET /api/admin/audit-log — admin only; deletions, redactions and settings changes */
+  async getAuditLog(query: AuditLogQuery = {}): Promise<{ events: AuditEvent[] }> {
+    const params = new URLSearchParams();
+    for (const [key, value] of Object.entries(query)) {
+      if (value !== undefined && value !== '') params.set(key, String(value));
+    }
+    const qs = params.toString();
+    const path = qs ? `/api/admin/audit-log?${qs}` : '/api/admin/audit-log';
+    return this.withCapability('can_view_audit_log', path, () => this.get(path));
   }
 
   // ─── Transcription ────────────────────────────────────────────────────────
@@ -581,11 +863,82 @@ export class APIClient {
     return this.post('/api/transcribe/cancel');
   }
 
+  /**
+   * GET /api/transcribe/queue — the running job and the clients waiting for
+   * the slot. Server timestamps are shifted onto this machine's clock so the
+   * start estimates stay right when the two clocks disagree. Polling keeps
+   * this client's queue entries alive.
+   */
+  async getServerQueue(): Promise<ServerQueue> {
+    return this.normalizeServerQueue(
+      await this.get<ServerQueue & { now: number }>('/api/transcribe/queue'),
+    );
+  }
+
+  /** POST /api/transcribe/queue — take a place in the queue; returns the entry id. */
+  async joinServerQueue(label: string, durationSeconds?: number): Promise<{ id: string }> {
+    return this.post('/api/transcribe/queue', {
+      label,
+      duration_seconds: durationSeconds ?? null,
+    });
+  }
+
+  /** DELETE /api/transcribe/queue/{id} — give up one of our places. */
+  async leaveServerQueue(entryId: string): Promise<{ success: boolean }> {
+    return this.del(`/api/transcribe/queue/${encodeURIComponent(entryId)}`);
+  }
+
+  /**
+   * PUT /api/transcribe/queue/order — reorder our own entries; `entryIds`
+   * must list all of them. Other clients' entries keep their place.
+   */
+  async reorderServerQueue(entryIds: string[]): Promise<ServerQueue> {
+    return this.normalizeServerQueue(
+      await this.put<ServerQueue & { now: number }>('/api/transcribe/queue/order', {
+        entry_ids: entryIds,
+      }),
+    );
+  }
+
+  private normalizeServerQueue(raw: ServerQueue & { now: number }): ServerQueue {
+    const skew = Date.now() / 1000 - raw.now;
+    const shift = (t: number | null) => (t === null ? null : t + skew);
+    // Own-items filter: keep only our entries. Positions and start estimates
+    // still count everyone ahead of us, so the wait stays honest.
+    const entries = this.identity.showOnlyMine
+      ? raw.entries.filter((entry) => entry.mine)
+      : raw.entries;
+    return {
+      active: raw.active && {
+        ...raw.active,
+        started_at: shift(raw.active.started_at),
+        estimated_end_at: shift(raw.active.estimated_end_at),
+      },
+      entries: entries.map((entry) => ({
+        ...entry,
+        enqueued_at: entry.enqueued_at + skew,
+        estimated_start_at: shift(entry.estimated_start_at),
+      })),
+    };
+  }
+
   /** GET /api/transcribe/languages */
   async getLanguages(): Promise<LanguagesResponse> {
     return this.get('/api/transcribe/languages');
   }
 
+  /** POST /api/transcribe/detect-language */
+  async detectLanguage(
+    sample: Blob,
+    filename: string,
+    seconds?: number,
+  ): Promise<LanguageDetectionResult> {
+    const fd = new FormData();
+    fd.append('file', sample, filename);
+    if (seconds) fd.append('seconds', String(seconds));
+    return this.postFormData('/api/transcribe/detect-language', fd);
+  }
+
   // ─── Notebook: Recordings ─────────────────────────────────────────────────
 
   /** GET /api/notebook/recordings */
@@ -594,7 +947,9 @@ export class APIClient {
     if (startDate) params.set('start_date', startDate);
     if (endDate) params.set('end_date', endDate);
     const qs = params.toString();
-    return this.get(`/api/notebook/recordings${qs ? `?${qs}` : ''}`);
+    return this.ownRecordings(
+      await this.get<Recording[]>(`/api/notebook/recordings${qs ? `?${qs}` : ''}`),
+    );
   }
 
   /** GET /api/notebook/recordings/:id */
@@ -649,6 +1004,30 @@ export class APIClient {
     return this.patch(`/api/notebook/recordings/${id}/date`, { recorded_at: recordedAt });
   }
 
+  /** PATCH /api/notebook/recordings/:id/keep-audio — exempt from source-audio retention. */
+  async updateRecordingKeepAudio(
+    id: number,
+    keepAudio: boolean,
+  ): Promise<{ status: string; id: number; keep_audio: boolean }> {
+    return this.patch(`/api/notebook/recordings/${id}/keep-audio`, { keep_audio: keepAudio });
+  }
+
+  /**
+   * PUT /api/notebook/recordings/:id/metadata
+   * Replaces the custom fields; the server trims them and drops blank values.
+   */
+  async updateRecordingMetadata(
+    id: number,
+    metadata: Record<string, string>,
+  ): Promise<{ recording_id: number; metadata: Record<string, string> }> {
+    return this.put(`/api/notebook/recordings/${id}/metadata`, { metadata });
+  }
+
+  /** GET /api/notebook/metadata/fields — custom fields in use, for filters. */
+  async getMetadataFields(): Promise<MetadataFieldSummary[]> {
+    return this.get('/api/notebook/metadata/fields');
+  }
+
   /** PATCH /api/notebook/recordings/:id/summary */
   async updateRecordingSummary(
     id: number,
@@ -680,9 +1059,22 @@ export class APIClient {
     return this.put(`/api/notebook/recordings/${id}/summary?${params.toString()}`);
   }
 
-  /** GET /api/notebook/recordings/:id/transcription */
-  async getRecordingTranscription(id: number): Promise<RecordingTranscription> {
-    return this.get(`/api/notebook/recordings/${id}/transcription`);
+  /**
+   * GET /api/notebook/recordings/:id/transcription
+   * `align` re-times it onto a fingerprint-matched copy (DedupMatch
+   * `offset_seconds` / `duration_seconds`).
+   */
+  async getRecordingTranscription(
+    id: number,
+    align?: { offsetSeconds: number; durationSeconds?: number | null },
+  ): Promise<RecordingTranscription> {
+    const params = new URLSearchParams();
+    if (align) {
+      params.set('offset_seconds', String(align.offsetSeconds));
+      if (align.durationSeconds) params.set('duration_seconds', String(align.durationSeconds));
+    }
+    const query = params.toString();
+    return this.get(`/api/notebook/recordings/${id}/transcription${query ? `?${query}` : ''}`);
   }
 
   // ─── Notebook: Speaker Aliases (Issue #104, Story 4.2) ───────────────────
@@ -713,6 +1105,141 @@ export class APIClient {
     return this.put(`/api/notebook/recordings/${id}/aliases`, { aliases });
   }
 
+  /**
+   * POST /api/notebook/recordings/:id/identify-speakers
+   *
+   * Match unnamed speakers against the voice profiles; matches are stored
+   * as aliases. Runs automatically after diarized transcriptions.
+   */
+  async identifySpeakers(id: number): Promise<SpeakerIdentificationResult> {
+    return this.post(`/api/notebook/recordings/${id}/identify-speakers`, {});
+  }
+
+  // ─── Notebook: Voice Profiles ────────────────────────────────────────────
+
+  /** GET /api/notebook/voice-profiles */
+  async getVoiceProfiles(): Promise<VoiceProfilesResponse> {
+    return this.get('/api/notebook/voice-profiles');
+  }
+
+  /** POST /api/notebook/voice-profiles — enroll a sample; an existing name gains a sample. */
+  async enrollVoiceSample(
+    name: string,
+    sample: Blob,
+    filename = 'sample.webm',
+  ): Promise<VoiceProfile> {
+    const fd = new FormData();
+    fd.append('name', name);
+    fd.append('file', sample, filename);
+    return this.postFormData('/api/notebook/voice-profiles', fd);
+  }
+
+  /** PATCH /api/notebook/voice-profiles/:id */
+  async renameVoiceProfile(id: number, name: string): Promise<VoiceProfile> {
+    return this.patch(`/api/notebook/voice-profiles/${id}`, { name });
+  }
+
+  /** DELETE /api/notebook/voice-profiles/:id */
+  async deleteVoiceProfile(id: number): Promise<void> {
+    await this.del(`/api/notebook/voice-profiles/${id}`);
+  }
+
+  /**
+   * POST /api/notebook/recordings/:id/labels
+   * Re-segment from an Audacity label track. `dryRun` previews the result.
+   */
+  async importAudacityLabels(
+    id: number,
+    content: string,
+    dryRun = false,
+  ): Promise<LabelImportResult> {
+    return this.post(`/api/notebook/recordings/${id}/labels`, { content, dry_run: dryRun });
+  }
+
+  /**
+   * POST /api/notebook/recordings/{id}/realign
+   * Apply corrected text (default: the saved corrected transcript) to the
+   * segments and re-time the edited words against the audio.
+   */
+  async realignTranscript(
+    id: number,
+    options?: { text?: string; useModel?: boolean; language?: string },
+  ): Promise<RealignResult> {
+    return this.post(`/api/notebook/recordings/${id}/realign`, {
+      text: options?.text ?? null,
+      use_model: options?.useModel ?? true,
+      language: options?.language ?? null,
+    });
+  }
+
+  /**
+   * POST /api/notebook/recordings/{id}/segments/{segmentId}/retranscribe
+   * Redo one segment with another model (server default: a large Whisper)
+   * and splice the result in; `dryRun` only returns the candidate text.
+   */
+  async retranscribeSegment(
+    id: number,
+    segmentId: number,
+    options?: { model?: string; language?: string; dryRun?: boolean },
+  ): Promise<SegmentRetranscriptionResult> {
+    return this.post(`/api/notebook/recordings/${id}/segments/${segmentId}/retranscribe`, {
+      model: options?.model ?? null,
+      language: options?.language ?? null,
+      dry_run: options?.dryRun ?? false,
+    });
+  }
+
+  /**
+   * POST /api/notebook/recordings/{id}/resume
+   * Transcribe the rest of a partial recording from where its job stopped.
+   */
+  async resumeRecording(id: number, language?: string): Promise<PartialResumeResult> {
+    return this.post(`/api/notebook/recordings/${id}/resume`, { language: language ?? null });
+  }
+
+  /** POST /api/notebook/recordings/{id}/segments/{segmentId}/split */
+  async splitSegment(id: number, segmentId: number, atMs: number): Promise<SegmentSplitResult> {
+    return this.post(`/api/notebook/recordings/${id}/segments/${segmentId}/split`, {
+      at_ms: Math.round(atMs),
+    });
+  }
+
+  /** POST /api/notebook/recordings/{id}/segments/merge — adjacent segments only. */
+  async mergeSegments(id: number, segmentIds: number[]): Promise<SegmentMergeResult> {
+    return this.post(`/api/notebook/recordings/${id}/segments/merge`, {
+      segment_ids: segmentIds,
+    });
+  }
+
+  /** GET /api/notebook/recordings/{id}/caption-lint — server default profile when omitted. */
+  async lintCaptions(id: number, profile?: string): Promise<CaptionLintResult> {
+    const params = profile ? `?${new URLSearchParams({ profile })}` : '';
+    return this.get(`/api/notebook/recordings/${id}/caption-lint${params}`);
+  }
+
+  // ─── Notebook: Timecode (video-editor workflows) ──────────────────────────
+
+  /** GET /api/notebook/recordings/:id/timecode — `timecode: null` when unset. */
+  async getRecordingTimecode(id: number): Promise<RecordingTimecodeResponse> {
+    return this.get(`/api/notebook/recordings/${id}/timecode`);
+  }
+
+  /**
+   * PUT /api/notebook/recordings/:id/timecode
+   * Server validates fps/drop-frame and echoes the normalized start label.
+   */
+  async setRecordingTimecode(
+    id: number,
+    timecode: TimecodeSettings,
+  ): Promise<RecordingTimecodeResponse> {
+    return this.put(`/api/notebook/recordings/${id}/timecode`, timecode);
+  }
+
+  /** DELETE /api/notebook/recordings/:id/timecode */
+  async clearRecordingTimecode(id: number): Promise<RecordingTimecodeResponse> {
+    return this.del(`/api/notebook/recordings/${id}/timecode`);
+  }
+
   /**
    * GET /api/notebook/recordings/:id/diarization-confidence
    *
@@ -726,6 +1253,117 @@ export class APIClient {
     return this.get(`/api/notebook/recordings/${id}/diarization-confidence`);
   }
 
+  /**
+   * GET /api/notebook/recordings/{id}/low-confidence
+   * Runs of words scoring under `threshold` (default: server's low bucket).
+   */
+  async getLowConfidenceRegions(id: number, threshold?: number): Promise<LowConfidenceResponse> {
+    const query = threshold === undefined ? '' : `?threshold=${threshold}`;
+    return this.get(`/api/notebook/recordings/${id}/low-confidence${query}`);
+  }
+
+  /** GET /api/notebook/recordings/:id/segment-review — QA state + progress. */
+  async getSegmentReview(id: number): Promise<SegmentReviewResponse> {
+    return this.get(`/api/notebook/recordings/${id}/segment-review`);
+  }
+
+  /** GET /api/notebook/recordings/:id/annotations — note threads by start time. */
+  async getAnnotations(id: number): Promise<AnnotationsResponse> {
+    return this.get(`/api/notebook/recordings/${id}/annotations`);
+  }
+
+  /** POST /api/notebook/recordings/:id/annotations — a note, or a reply via `parent_id`. */
+  async createAnnotation(id: number, payload: AnnotationCreate): Promise<Annotation> {
+    return this.post(`/api/notebook/recordings/${id}/annotations`, payload);
+  }
+
+  /** PATCH /api/notebook/recordings/:id/annotations/:annotationId */
+  async updateAnnotation(id: number, annotationId: number, body: string): Promise<Annotation> {
+    return this.patch(`/api/notebook/recordings/${id}/annotations/${annotationId}`, { body });
+  }
+
+  /** DELETE /api/notebook/recordings/:id/annotations/:annotationId (replies go too). */
+  async deleteAnnotation(id: number, annotationId: number): Promise<void> {
+    await this.del(`/api/notebook/recordings/${id}/annotations/${annotationId}`);
+  }
+
+  /** GET /api/notebook/recordings/:id/redactions — masked ranges by start time. */
+  async getRedactions(id: number): Promise<RedactionsResponse> {
+    return this.get(`/api/notebook/recordings/${id}/redactions`);
+  }
+
+  /** POST /api/notebook/recordings/:id/redactions — redact a time range. */
+  async createRedaction(
+    id: number,
+    payload: { start_time: number; end_time: number; label?: string },
+  ): Promise<Redaction> {
+    return this.post(`/api/notebook/recordings/${id}/redactions`, payload);
+  }
+
+  /** POST /api/notebook/recordings/:id/pii-scan — re-scan; replaces earlier `pii` suggestions. */
+  async scanPii(id: number): Promise<RedactionsResponse> {
+    return this.post(`/api/notebook/recordings/${id}/pii-scan`, {});
+  }
+
+  /** DELETE /api/notebook/recordings/:id/redactions/:redactionId */
+  async deleteRedaction(id: number, redactionId: number): Promise<void> {
+    await this.del(`/api/notebook/recordings/${id}/redactions/${redactionId}`);
+  }
+
+  /** GET /api/notebook/recordings/:id/outline — chapters, built on first request. */
+  async getOutline(id: number): Promise<OutlineResponse> {
+    return this.get(`/api/notebook/recordings/${id}/outline`);
+  }
+
+  /** POST /api/notebook/recordings/:id/outline — rebuild; `useLlm` overrides the config. */
+  async rebuildOutline(id: number, useLlm?: boolean): Promise<OutlineResponse> {
+    return this.post(`/api/notebook/recordings/${id}/outline`, { use_llm: useLlm ?? null });
+  }
+
+  /** PUT /api/notebook/recordings/:id/golden — add to or drop from the accuracy golden set. */
+  async setGoldenReference(
+    id: number,
+    golden: boolean,
+  ): Promise<{ recording_id: number; golden: boolean }> {
+    return this.put(`/api/notebook/recordings/${id}/golden`, { golden });
+  }
+
+  /** GET /api/notebook/recordings/:id/speaker-stats — talk time, pace, interruptions. */
+  async getSpeakerStats(id: number): Promise<SpeakerStatsResponse> {
+    return this.get(`/api/notebook/recordings/${id}/speaker-stats`);
+  }
+
+  /**
+   * GET /api/notebook/recordings/:idA/diff/:idB — word diff and WER, A as reference.
+   * Same id with `bCorrected` compares a transcript with its hand-corrected text.
+   */
+  async diffTranscripts(
+    idA: number,
+    idB: number,
+    { aCorrected = false, bCorrected = false }: { aCorrected?: boolean; bCorrected?: boolean } = {},
+  ): Promise<TranscriptDiffResponse> {
+    const params = new URLSearchParams({
+      a_corrected: String(aCorrected),
+      b_corrected: String(bCorrected),
+    });
+    return this.get(`/api/notebook/recordings/${idA}/diff/${idB}?${params}`);
+  }
+
+  /**
+   * PUT /api/notebook/recordings/:id/segment-review
+   * Sets `state` on every listed segment; `null` marks them unreviewed again.
+   */
+  async setSegmentReview(
+    id: number,
+    segmentIds: number[],
+    state: SegmentReviewState | null,
+  ): Promise<SegmentReviewResponse> {
+    return this.put(`/api/notebook/recordings/${id}/segment-review`, {
+      segment_ids: segmentIds,
+      state,
+    });
+  }
+
   // ─── Notebook: Diarization Review (Issue #104, Stories 5.6 / 5.7 / 5.9) ──
 
   /** GET /api/notebook/recordings/:id/diarization-review */
@@ -772,15 +1410,90 @@ export class APIClient {
     return `${this.baseUrl}/api/notebook/recordings/${id}/audio${tokenParam}`;
   }
 
+  /**
+   * GET /api/notebook/media-info?ids=… — library card metadata keyed by
+   * recording id. Unknown ids are left out of the result.
+   */
+  async getMediaInfo(ids: number[]): Promise<Record<string, MediaInfo>> {
+    return this.get(`/api/notebook/media-info?ids=${ids.join(',')}`);
+  }
+
+  /**
+   * GET /api/notebook/recordings/:id/preview — card preview image URL
+   * (video frame or spectrogram). Null when the base URL is not configured.
+   */
+  getPreviewUrl(id: number): string | null {
+    if (!this.isBaseUrlConfigured()) return null;
+    const tokenParam = this.authToken ? `?token=${encodeURIComponent(this.authToken)}` : '';
+    return `${this.baseUrl}/api/notebook/recordings/${id}/preview${tokenParam}`;
+  }
+
   /**
    * GET /api/notebook/recordings/:id/export
    * Returns a download URL (not fetched directly).
    * Returns null when the base URL is not configured (pre-sync or blank-remote);
    * callers must guard and surface an error rather than open a broken URL.
+   * `query` (marker formats only) emits one marker per keyword hit instead
+   * of one per speaker turn. `annotations` (txt / ass) appends review notes.
+   * `captionProfile` (srt / ass) re-wraps cue lines to that caption profile.
+   * `speakerStats` (plaintext) appends per-speaker statistics.
    */
-  getExportUrl(id: number, format: ExportFormat): string | null {
+  getExportUrl(
+    id: number,
+    format: ExportFormat,
+    query?: string,
+    options: { annotations?: boolean; captionProfile?: string; speakerStats?: boolean } = {},
+  ): string | null {
     if (!this.isBaseUrlConfigured()) return null;
     const params = new URLSearchParams({ format });
+    if (query?.trim()) params.set('q', query.trim());
+    if (options.annotations) params.set('annotations', 'true');
+    if (options.captionProfile) params.set('caption_profile', options.captionProfile);
+    if (options.speakerStats) params.set('speaker_stats', 'true');
+    if (this.authToken) params.set('token', this.authToken);
+    return `${this.baseUrl}/api/notebook/recordings/${id}/export?${params}`;
+  }
+
+  /** GET /api/notebook/recordings/:id/redacted-media — media with redactions muted / bleeped. */
+  getRedactedMediaUrl(id: number, mode: RedactionAudioMode): string | null {
+    if (!this.isBaseUrlConfigured()) return null;
+    const params = new URLSearchParams({ mode });
+    if (this.authToken) params.set('token', this.authToken);
+    return `${this.baseUrl}/api/notebook/recordings/${id}/redacted-media?${params}`;
+  }
+
+  /**
+   * GET /api/notebook/recordings/:id/project — the whole recording as a
+   * `.tsproj` bundle. Without `embedMedia` the bundle only references the audio.
+   */
+  getProjectBundleUrl(id: number, embedMedia = true): string | null {
+    if (!this.isBaseUrlConfigured()) return null;
+    const params = new URLSearchParams({ embed_media: String(embedMedia) });
+    if (this.authToken) params.set('token', this.authToken);
+    return `${this.baseUrl}/api/notebook/recordings/${id}/project?${params}`;
+  }
+
+  /**
+   * GET /api/notebook/recordings/:id/export?format=html
+   * Print-ready HTML body for PDF export (rendered by electron/pdfExport.ts).
+   */
+  getPrintExportUrl(
+    id: number,
+    options: {
+      speakerStyle?: 'bold' | 'caps' | 'plain';
+      timestamps?: boolean;
+      fontSize?: number;
+      pageSize?: 'A4' | 'Letter' | 'Legal';
+      speakerStats?: boolean;
+    } = {},
+  ): string | null {
+    if (!this.isBaseUrlConfigured()) return null;
+    const params = new URLSearchParams({ format: 'html' });
+    if (options.speakerStats) params.set('speaker_stats', 'true');
+    if (options.speakerStyle) params.set('speaker_style', options.speakerStyle);
+    if (options.timestamps !== undefined) params.set('timestamps', String(options.timestamps));
+    if (options.fontSize !== undefined) params.set('font_size', String(options.fontSize));
+    if (options.pageSize) params.set('page_size', options.pageSize);
     if (this.authToken) params.set('token', this.authToken);
     return `${this.baseUrl}/api/notebook/recordings/${id}/export?${params}`;
   }
@@ -793,11 +1506,10 @@ export class APIClient {
    * Returns 202 with job_id immediately. Poll /api/admin/status for result.
    */
   async uploadAndTranscribe(
-    file: File,
+    file: UploadSource,
     options?: TranscriptionUploadOptions,
   ): Promise<TranscriptionAccepted> {
-    const fd = new FormData();
-    fd.append('file', file);
+    const fd = uploadFormData(file);
     if (options?.language) fd.append('language', options.language);
     if (options?.translation_enabled) fd.append('translation_enabled', 'true');
     if (options?.translation_target_language)
@@ -810,12 +1522,69 @@ export class APIClient {
     if (options?.parallel_diarization !== undefined)
       fd.append('parallel_diarization', String(options.parallel_diarization));
     if (options?.multitrack) fd.append('multitrack', 'true');
+    options?.channel_labels?.forEach((label) => fd.append('channel_labels', label));
     if (options?.file_created_at) fd.append('file_created_at', options.file_created_at);
     if (options?.title) fd.append('title', options.title);
     if (options?.profile_id != null) fd.append('profile_id', String(options.profile_id));
+    if (options?.source) fd.append('source', options.source);
+    if (options?.queue_entry_id) fd.append('queue_entry_id', options.queue_entry_id);
+    if (options?.metadata && Object.keys(options.metadata).length > 0)
+      fd.append('metadata', JSON.stringify(options.metadata));
     return this.postFormData('/api/notebook/transcribe/upload', fd);
   }
 
+  /**
+   * POST /api/notebook/import/subtitles
+   * Create a notebook recording from a media file plus existing SRT/VTT/ASS
+   * captions. With `align` the server re-times the cue text against the audio.
+   */
+  async importSubtitles(
+    media: File,
+    subtitles: File,
+    options?: SubtitleImportOptions,
+  ): Promise<SubtitleImportResult> {
+    const fd = new FormData();
+    fd.append('file', media);
+    fd.append('subtitles', subtitles);
+    if (options?.align) fd.append('align', 'true');
+    if (options?.language) fd.append('language', options.language);
+    if (options?.title) fd.append('title', options.title);
+    if (options?.file_created_at) fd.append('file_created_at', options.file_created_at);
+    return this.postFormData('/api/notebook/import/subtitles', fd);
+  }
+
+  /**
+   * POST /api/notebook/import/transcript
+   * Create a notebook recording from another tool's transcript (Whisper JSON,
+   * Otter TXT, YouTube SBV, Descript, SRT/VTT/ASS). The format is detected
+   * server-side unless given; the media file is optional.
+   */
+  async importTranscript(
+    transcript: File,
+    options?: TranscriptImportOptions,
+  ): Promise<TranscriptImportResult> {
+    const fd = new FormData();
+    fd.append('transcript', transcript);
+    if (options?.media) fd.append('file', options.media);
+    if (options?.format) fd.append('format', options.format);
+    if (options?.align) fd.append('align', 'true');
+    if (options?.language) fd.append('language', options.language);
+    if (options?.title) fd.append('title', options.title);
+    if (options?.recorded_at) fd.append('recorded_at', options.recorded_at);
+    return this.postFormData('/api/notebook/import/transcript', fd);
+  }
+
+  /**
+   * POST /api/notebook/import/project
+   * Create a notebook recording from a `.tsproj` bundle exported by any
+   * TranscriptionSuite server.
+   */
+  async importProjectBundle(bundle: File): Promise<ProjectImportResult> {
+    const fd = new FormData();
+    fd.append('bundle', bundle);
+    return this.postFormData('/api/notebook/import/project', fd);
+  }
+
   // ─── File Import (Session) ────────────────────────────────────────────────
 
   /**
@@ -841,6 +1610,81 @@ export class APIClient {
     });
   }
 
+  /** POST /api/notebook/outputs/preview — where each export would land; writes nothing. */
+  async previewOutputs(body: OutputRouteRequest): Promise<{ outputs: PlannedOutput[] }> {
+    return this.post('/api/notebook/outputs/preview', body);
+  }
+
+  /**
+   * POST /api/notebook/outputs/export — write template-named exports to the
+   * per-type output folders. `unrouted` targets need a save dialog instead.
+   */
+  async exportOutputs(
+    body: OutputRouteRequest,
+  ): Promise<{ outputs: RoutedOutput[]; written: number }> {
+    return this.post('/api/notebook/outputs/export', body);
+  }
+
+  /**
+   * POST /api/notebook/outputs/library-export — export every matching
+   * recording, yielding progress events until `done`. Aborting `signal`
+   * closes the stream, which stops the export on the server too.
+   */
+  async *exportLibrary(
+    body: LibraryExportRequest,
+    signal?: AbortSignal,
+  ): AsyncGenerator<LibraryExportEvent, void, unknown> {
+    const path = '/api/notebook/outputs/library-export';
+    this.ensureConfigured(path);
+    const res = await tracedFetch(`${this.baseUrl}${path}`, {
+      method: 'POST',
+      headers: this.headers(),
+      body: JSON.stringify(body),
+      signal,
+    });
+    if (!res.ok) throw new APIError(res.status, await res.text(), path);
+    const reader = res.body?.getReader();
+    if (!reader) return;
+    const decoder = new TextDecoder();
+    let buffer = '';
+    try {
+      while (true) {
+        const { done, value } = await reader.read();
+        if (done) break;
+        buffer += decoder.decode(value, { stream: true });
+        const lines = buffer.split('\n');
+        buffer = lines.pop() ?? '';
+        for (const line of lines) {
+          if (!line.startsWith('data: ')) continue;
+          let event: LibraryExportEvent;
+          try {
+            event = JSON.parse(line.slice(6));
+          } catch {
+            continue; // Skip malformed JSON
+          }
+          yield event;
+        }
+      }
+    } finally {
+      reader.releaseLock();
+    }
+  }
+
+  /** POST /api/notebook/outputs/library-export/cancel — stop after the current file. */
+  async cancelLibraryExport(): Promise<{ cancelled: boolean }> {
+    return this.post('/api/notebook/outputs/library-export/cancel');
+  }
+
+  /**
+   * POST /api/notebook/rules/test — dry-run the post-transcription rules
+   * against a hypothetical job; nothing is written or sent.
+   */
+  async testPostRules(
+    body: PostRuleTestRequest,
+  ): Promise<{ rules: PostRuleReport[]; matched: number }> {
+    return this.post('/api/notebook/rules/test', body);
+  }
+
   /**
    * POST /api/notebook/recordings/{id}/auto-actions/retry — Issue #104, Stories 6.6 + 6.9.
    * Idempotent retry of a failed/deferred/empty/truncated auto-action.
@@ -864,16 +1708,96 @@ export class APIClient {
     });
   }
 
+  /**
+   * GET /api/notebook/share/targets — configured share targets (no secrets).
+   */
+  async listShareTargets(): Promise<{ targets: ShareTarget[] }> {
+    return this.get('/api/notebook/share/targets');
+  }
+
+  /**
+   * PUT /api/notebook/share/targets/{name}/credentials — store a target's
+   * credentials in the server keychain (admin only; an empty value removes one).
+   */
+  async setShareCredentials(
+    target: string,
+    credentials: Record<string, string>,
+  ): Promise<{ target: string; missing_credentials: string[] }> {
+    return this.put(`/api/notebook/share/targets/${encodeURIComponent(target)}/credentials`, {
+      credentials,
+    });
+  }
+
+  /**
+   * POST /api/notebook/recordings/{id}/share — send an export to a share target.
+   * Email targets without an SMTP relay come back as status='draft' with the
+   * unsent message in `eml`; the caller saves and opens it locally.
+   */
+  async shareRecording(
+    recordingId: number,
+    target: string,
+    format: ShareFormat = 'plaintext',
+  ): Promise<ShareResult> {
+    return this.post(`/api/notebook/recordings/${recordingId}/share`, { target, format });
+  }
+
+  /**
+   * POST /api/transcribe/uploads — stage a file ahead of its transcription.
+   * Send the bytes with appendStagedUpload, then pass `{ upload_id }` to
+   * importAndTranscribe or uploadAndTranscribe in place of the file.
+   */
+  async createStagedUpload(filename: string, size: number): Promise<StagedUploadStatus> {
+    return this.post('/api/transcribe/uploads', { filename, size });
+  }
+
+  /**
+   * PUT /api/transcribe/uploads/{id}?offset= — append one chunk. A 409 means
+   * the offset was wrong; its body carries the `received` count to resume from.
+   * With `compress` the chunk is gzipped when the server can decode it;
+   * offsets always count uncompressed bytes.
+   */
+  async appendStagedUpload(
+    uploadId: string,
+    offset: number,
+    chunk: Blob,
+    signal?: AbortSignal,
+    compress = false,
+  ): Promise<StagedUploadStatus> {
+    const path = `/api/transcribe/uploads/${encodeURIComponent(uploadId)}?offset=${offset}`;
+    this.ensureConfigured(path);
+    const headers: Record<string, string> = {
+      ...this.authHeaders(),
+      'Content-Type': 'application/octet-stream',
+    };
+    let body = chunk;
+    if (compress && (await this.acceptsGzipBodies())) {
+      headers['Content-Encoding'] = 'gzip';
+      body = await gzipBlob(chunk);
+    }
+    const res = await tracedFetch(`${this.baseUrl}${path}`, {
+      method: 'PUT',
+      headers,
+      body,
+      signal,
+    });
+    if (!res.ok) throw new APIError(res.status, await res.text(), path);
+    return res.json();
+  }
+
+  /** DELETE /api/transcribe/uploads/{id} — drop a staged file that will not be used. */
+  async discardStagedUpload(uploadId: string): Promise<void> {
+    await this.del(`/api/transcribe/uploads/${encodeURIComponent(uploadId)}`);
+  }
+
   /**
    * POST /api/transcribe/import — start a background file-import transcription.
    * Returns 202 Accepted with { job_id }. Poll /api/admin/status for result.
    */
   async importAndTranscribe(
-    file: File,
+    file: UploadSource,
     options?: Omit<TranscriptionUploadOptions, 'file_created_at' | 'title'>,
   ): Promise<TranscriptionAccepted> {
-    const fd = new FormData();
-    fd.append('file', file);
+    const fd = uploadFormData(file);
     if (options?.language) fd.append('language', options.language);
     if (options?.translation_enabled) fd.append('translation_enabled', 'true');
     if (options?.translation_target_language)
@@ -887,19 +1811,43 @@ export class APIClient {
       fd.append('parallel_diarization', String(options.parallel_diarization));
     if (options?.multitrack) fd.append('multitrack', 'true');
     if (options?.profile_id != null) fd.append('profile_id', String(options.profile_id));
+    if (options?.queue_entry_id) fd.append('queue_entry_id', options.queue_entry_id);
+    if (options?.keep_media) fd.append('keep_media', 'true');
     return this.postFormData('/api/transcribe/import', fd);
   }
 
+  /** DELETE /api/transcribe/jobs/{job_id}/media — drop an import's server copy. */
+  async deleteJobMedia(jobId: string): Promise<void> {
+    await this.del(`/api/transcribe/jobs/${encodeURIComponent(jobId)}/media`);
+  }
+
   // ─── Notebook: Calendar & Timeslot ────────────────────────────────────────
 
   /** GET /api/notebook/calendar?year=&month= */
   async getCalendar(year: number, month: number): Promise<CalendarResponse> {
-    return this.get(`/api/notebook/calendar?year=${year}&month=${month}`);
+    const calendar = await this.get<CalendarResponse>(
+      `/api/notebook/calendar?year=${year}&month=${month}`,
+    );
+    const days: CalendarResponse['days'] = {};
+    let total = 0;
+    for (const [day, recordings] of Object.entries(calendar.days)) {
+      const own = this.ownRecordings(recordings);
+      if (own.length === 0) continue;
+      days[day] = own;
+      total += own.length;
+    }
+    return { ...calendar, days, total_recordings: total };
   }
 
-  /** GET /api/notebook/timeslot?date=&hour= */
+  /**
+   * GET /api/notebook/timeslot?date=&hour= — the own-items filter hides other
+   * users' recordings, but slot capacity still counts them.
+   */
   async getTimeslot(date: string, hour: number): Promise<TimeslotResponse> {
-    return this.get(`/api/notebook/timeslot?date=${date}&hour=${hour}`);
+    const slot = await this.get<TimeslotResponse>(
+      `/api/notebook/timeslot?date=${date}&hour=${hour}`,
+    );
+    return { ...slot, recordings: this.ownRecordings(slot.recordings) };
   }
 
   // ─── Notebook: Backups ────────────────────────────────────────────────────
@@ -919,18 +1867,77 @@ export class APIClient {
     return this.post('/api/notebook/restore', { filename });
   }
 
+  /** GET /api/notebook/maintenance — whether a run is active, and the last report. */
+  async getMaintenanceStatus(): Promise<MaintenanceStatus> {
+    return this.get('/api/notebook/maintenance');
+  }
+
+  /**
+   * POST /api/notebook/maintenance — check (and with `repair`, fix) the
+   * database, yielding progress events until `done`.
+   */
+  async *runMaintenance(
+    options: { repair?: boolean; vacuum?: boolean } = {},
+    signal?: AbortSignal,
+  ): AsyncGenerator<MaintenanceEvent, void, unknown> {
+    const path = '/api/notebook/maintenance';
+    this.ensureConfigured(path);
+    const res = await tracedFetch(`${this.baseUrl}${path}`, {
+      method: 'POST',
+      headers: this.headers(),
+      body: JSON.stringify(options),
+      signal,
+    });
+    if (!res.ok) throw new APIError(res.status, await res.text(), path);
+    const reader = res.body?.getReader();
+    if (!reader) return;
+    const decoder = new TextDecoder();
+    let buffer = '';
+    try {
+      while (true) {
+        const { done, value } = await reader.read();
+        if (done) break;
+        buffer += decoder.decode(value, { stream: true });
+        const lines = buffer.split('\n');
+        buffer = lines.pop() ?? '';
+        for (const line of lines) {
+          if (!line.startsWith('data: ')) continue;
+          let event: MaintenanceEvent;
+          try {
+            event = JSON.parse(line.slice(6));
+          } catch {
+            continue; // Skip malformed JSON
+          }
+          yield event;
+        }
+      }
+    } finally {
+      reader.releaseLock();
+    }
+  }
+
   // ─── Search ───────────────────────────────────────────────────────────────
 
   /** GET /api/search/ — unified search */
   async search(
     query: string,
-    options?: { fuzzy?: boolean; startDate?: string; endDate?: string; limit?: number },
+    options?: {
+      fuzzy?: boolean;
+      startDate?: string;
+      endDate?: string;
+      limit?: number;
+      /** Only recordings whose custom fields match all of these. */
+      metadata?: Record<string, string>;
+    },
   ): Promise<SearchResponse> {
     const params = new URLSearchParams({ q: query });
     if (options?.fuzzy) params.set('fuzzy', 'true');
     if (options?.startDate) params.set('start_date', options.startDate);
     if (options?.endDate) params.set('end_date', options.endDate);
     if (options?.limit) params.set('limit', String(options.limit));
+    for (const [field, value] of Object.entries(options?.metadata ?? {})) {
+      params.append('metadata', `${field}=${value}`);
+    }
     return this.get(`/api/search/?${params}`);
   }
 
@@ -955,9 +1962,21 @@ export class APIClient {
     return this.get('/api/admin/status');
   }
 
+  /** GET /api/admin/library/lock — who owns the library when this server is read-only. */
+  async getLibraryLock(): Promise<LibraryLockStatus> {
+    return this.get('/api/admin/library/lock');
+  }
+
+  /** POST /api/admin/library/take-ownership — make this server the library's writer. */
+  async takeLibraryOwnership(): Promise<LibraryLockStatus> {
+    const path = '/api/admin/library/take-ownership';
+    return this.withCapability('can_manage_library', path, () => this.post(path));
+  }
+
   /** POST /api/admin/models/load */
   async loadModels(): Promise<{ status: string }> {
-    return this.post('/api/admin/models/load');
+    const path = '/api/admin/models/load';
+    return this.withCapability('can_manage_models', path, () => this.post(path));
   }
 
   /**
@@ -979,6 +1998,14 @@ export class APIClient {
         /* no-op: connection was never opened */
       };
     }
+    if (this.knownPermissions?.can_manage_models === false) {
+      callbacks.onError?.(
+        new PermissionDeniedError('can_manage_models', '/api/admin/models/load/stream').message,
+      );
+      return () => {
+        /* no-op: connection was never opened */
+      };
+    }
     const wsProto = this.baseUrl.startsWith('https') ? 'wss' : 'ws';
     const wsBase = this.baseUrl.replace(/^https?/, wsProto);
     const url = `${wsBase}/api/admin/models/load/stream`;
@@ -1030,7 +2057,8 @@ export class APIClient {
 
   /** POST /api/admin/models/unload */
   async unloadModels(): Promise<{ status: string }> {
-    return this.post('/api/admin/models/unload');
+    const path = '/api/admin/models/unload';
+    return this.withCapability('can_manage_models', path, () => this.post(path));
   }
 
   /** GET /api/admin/logs */
@@ -1046,7 +2074,10 @@ export class APIClient {
   async updateDiarizationSettings(settings: {
     parallel: boolean;
   }): Promise<{ status: string; diarization: { parallel: boolean } }> {
-    return this.patch('/api/admin/diarization', settings);
+    const path = '/api/admin/diarization';
+    return this.withCapability('can_change_server_settings', path, () =>
+      this.patch(path, settings),
+    );
   }
 
   // ─── Webhook ─────────────────────────────────────────────────────────────
@@ -1059,7 +2090,54 @@ export class APIClient {
     const body: Record<string, string> = {};
     if (url) body.url = url;
     if (secret) body.secret = secret;
-    return this.post('/api/admin/webhook/test', Object.keys(body).length ? body : undefined);
+    const path = '/api/admin/webhook/test';
+    return this.withCapability('can_change_server_settings', path, () =>
+      this.post(path, Object.keys(body).length ? body : undefined),
+    );
+  }
+
+  // ─── Housekeeping ────────────────────────────────────────────────────────
+
+  /** POST /api/admin/housekeeping/prune-model-cache — drop unused, unconfigured models */
+  async pruneModelCache(unusedDays: number, dryRun = false): Promise<ModelCachePruneResult> {
+    const query = `unused_days=${unusedDays}&dry_run=${dryRun}`;
+    const path = `/api/admin/housekeeping/prune-model-cache?${query}`;
+    return this.withCapability('can_change_server_settings', path, () => this.post(path));
+  }
+
+  /** POST /api/admin/housekeeping/rotate-logs — roll server.log over now */
+  async rotateServerLogs(): Promise<{ rotated: string[] }> {
+    const path = '/api/admin/housekeeping/rotate-logs';
+    return this.withCapability('can_change_server_settings', path, () => this.post(path));
+  }
+
+  /** POST /api/admin/housekeeping/golden-check — re-transcribe the golden set, report drift */
+  async runGoldenCheck(): Promise<GoldenCheckResult> {
+    const path = '/api/admin/housekeeping/golden-check';
+    return this.withCapability('can_change_server_settings', path, () => this.post(path));
+  }
+
+  /** GET /api/admin/golden-set — reference transcripts with WER history and drift */
+  async getGoldenSet(): Promise<GoldenSetReport> {
+    return this.get('/api/admin/golden-set');
+  }
+
+  // ─── Benchmark ────────────────────────────────────────────────────────────
+
+  /** POST /api/admin/benchmark — realtime factor and WER of models on a reference clip */
+  async benchmarkModels(
+    file: File,
+    reference: string,
+    models: string[] = [],
+    language?: string,
+  ): Promise<ModelBenchmarkResponse> {
+    const formData = new FormData();
+    formData.append('file', file);
+    formData.append('reference', reference);
+    formData.append('models', models.join(','));
+    if (language) formData.append('language', language);
+    const path = '/api/admin/benchmark';
+    return this.withCapability('can_manage_models', path, () => this.postFormData(path, formData));
   }
 
   // ─── LLM ──────────────────────────────────────────────────────────────────
@@ -1080,7 +2158,7 @@ export class APIClient {
    */
   async *llmProcessStream(request: LLMRequest): AsyncGenerator<string, void, unknown> {
     this.ensureConfigured('/api/llm/process/stream');
-    const res = await fetch(`${this.baseUrl}/api/llm/process/stream`, {
+    const res = await tracedFetch(`${this.baseUrl}/api/llm/process/stream`, {
       method: 'POST',
       headers: this.headers(),
       body: JSON.stringify(request),
@@ -1105,7 +2183,8 @@ export class APIClient {
   ): AsyncGenerator<string, void, unknown> {
     this.ensureConfigured(`/api/llm/summarize/${recordingId}/stream`);
     const params = customPrompt ? `?custom_prompt=${encodeURIComponent(customPrompt)}` : '';
-    const res = await fetch(`${this.baseUrl}/api/llm/summarize/${recordingId}/stream${params}`, {
+    const url = `${this.baseUrl}/api/llm/summarize/${recordingId}/stream${params}`;
+    const res = await tracedFetch(url, {
       method: 'POST',
       headers: this.headers(),
     });
@@ -1240,7 +2319,7 @@ export class APIClient {
     model?: string;
   }): AsyncGenerator<string, void, unknown> {
     this.ensureConfigured('/api/llm/chat');
-    const res = await fetch(`${this.baseUrl}/api/llm/chat`, {
+    const res = await tracedFetch(`${this.baseUrl}/api/llm/chat`, {
       method: 'POST',
       headers: this.headers(),
       body: JSON.stringify(request),
@@ -1303,12 +2382,36 @@ export class APIClient {
     // Server returns 204 No Content; the private del<T> would try to .json()
     // an empty body. Inline the fetch for the no-content case.
     this.ensureConfigured(`/api/profiles/${id}`);
-    const res = await fetch(`${this.baseUrl}/api/profiles/${id}`, {
+    const res = await tracedFetch(`${this.baseUrl}/api/profiles/${id}`, {
       method: 'DELETE',
       headers: this.authHeaders(),
     });
     if (!res.ok) throw new APIError(res.status, await res.text(), `/api/profiles/${id}`);
   }
+
+  // ─── Project templates ─────────────────────────────────────────────────────
+
+  async listProjectTemplates(): Promise<ProjectTemplate[]> {
+    return this.get<ProjectTemplate[]>('/api/projects');
+  }
+  async createProjectTemplate(payload: ProjectTemplatePayload): Promise<ProjectTemplate> {
+    return this.post<ProjectTemplate>('/api/projects', payload);
+  }
+  async updateProjectTemplate(
+    id: number,
+    payload: Partial<ProjectTemplatePayload>,
+  ): Promise<ProjectTemplate> {
+    return this.put<ProjectTemplate>(`/api/projects/${id}`, payload);
+  }
+  async deleteProjectTemplate(id: number): Promise<void> {
+    // 204 No Content — same inline fetch as deleteProfile.
+    this.ensureConfigured(`/api/projects/${id}`);
+    const res = await tracedFetch(`${this.baseUrl}/api/projects/${id}`, {
+      method: 'DELETE',
+      headers: this.authHeaders(),
+    });
+    if (!res.ok) throw new APIError(res.status, await res.text(), `/api/projects/${id}`);
+  }
 }
 
 // ─── Error class ──────────────────────────────────────────────────────────────
@@ -1324,6 +2427,27 @@ export class APIError extends Error {
   }
 }
 
+const CAPABILITY_ACTIONS: Record<Capability, string> = {
+  can_manage_models: 'load or unload models',
+  can_manage_tokens: 'manage auth tokens',
+  can_change_server_settings: 'change server settings',
+  can_manage_library: 'take ownership of the library',
+  can_view_all_jobs: 'view every job on the server',
+  can_view_audit_log: 'view the audit log',
+};
+
+/** The server does not grant the current token `capability`. */
+export class PermissionDeniedError extends APIError {
+  constructor(
+    public readonly capability: Capability,
+    path: string,
+  ) {
+    super(403, `permission-denied: ${capability}`, path);
+    this.name = 'PermissionDeniedError';
+    this.message = `This account is not allowed to ${CAPABILITY_ACTIONS[capability]}`;
+  }
+}
+
 // ─── Singleton ────────────────────────────────────────────────────────────────
 
 /** Singleton API client instance */
@@ -38,6 +38,10 @@ export interface ServerStatus {
   diarization_available?: boolean;
   active_connections?: number;
   tls_enabled?: boolean;
+  /** Another machine owns the library (synced drive); writes answer 423. */
+  library_read_only?: boolean;
+  /** Content-Encodings the server decodes on request bodies, e.g. ['gzip']. */
+  request_encodings?: string[];
 }
 
 // ─── Auth ─────────────────────────────────────────────────────────────────────
@@ -56,6 +60,23 @@ export interface LoginResponse {
   message?: string;
 }
 
+/** Features the server may withhold from a token (all admin-only today). */
+export type Capability =
+  | 'can_manage_models'
+  | 'can_manage_tokens'
+  | 'can_change_server_settings'
+  | 'can_manage_library'
+  | 'can_view_all_jobs'
+  | 'can_view_audit_log';
+
+export type Permissions = Record<Capability, boolean>;
+
+export interface PermissionsResponse {
+  user: string;
+  is_admin: boolean;
+  permissions: Permissions;
+}
+
 export interface AuthToken {
   token: string;
   token_id: string;
@@ -73,6 +94,128 @@ export interface CreateTokenRequest {
   expiry_days?: number;
 }
 
+/** A client name tokens were issued to — the server's notion of a user. */
+export interface ServerUser {
+  name: string;
+  is_admin: boolean;
+  active_tokens: number;
+  tokens: number;
+  last_token_created_at: string | null;
+}
+
+export interface UsageReport {
+  since: string | null;
+  /** Transcription jobs, grouped by the token's client name. */
+  clients: {
+    client_name: string;
+    jobs: number;
+    failed_jobs: number;
+    audio_seconds: number;
+    last_job_at: string | null;
+  }[];
+  /** Notebook recordings, grouped by the self-declared user name. */
+  owners: {
+    owner: string;
+    recordings: number;
+    recording_seconds: number;
+    last_recorded_at: string | null;
+  }[];
+}
+
+/** One destructive action or settings change (GET /api/admin/audit-log). */
+export interface AuditEvent {
+  id: number;
+  /** UTC ISO timestamp. */
+  occurred_at: string;
+  /** Dotted name, e.g. `recording.delete`, `token.revoke`. */
+  action: string;
+  target_type: string | null;
+  target_id: string | null;
+  /** Token client name, narrowed by the user name when one was sent. */
+  actor: string | null;
+  /** The initiating request, `METHOD /path?query`. */
+  command: string;
+  details: Record<string, unknown>;
+}
+
+export interface AuditLogQuery {
+  /** ISO date or datetime, inclusive. */
+  start?: string;
+  /** ISO date (covers the whole day) or datetime, inclusive. */
+  end?: string;
+  /** Exact action or a prefix such as `recording`. */
+  action?: string;
+  limit?: number;
+}
+
+/** One model's run in POST /api/admin/benchmark; figures are null when it failed. */
+export interface ModelBenchmarkRun {
+  model: string;
+  seconds: number | null;
+  /** Processing seconds per second of audio; lower is faster. */
+  realtime_factor: number | null;
+  /** Word error rate against the reference transcript (0 = perfect). */
+  wer: number | null;
+  text: string;
+  error: string | null;
+}
+
+export interface ModelBenchmarkResponse {
+  /** Length of the reference clip in seconds. */
+  duration: number;
+  runs: ModelBenchmarkRun[];
+}
+
+export interface ModelCachePruneResult {
+  /** Whole models removed (or, on a dry run, that would be). */
+  removed: { model: string; bytes: number; last_used_at: number }[];
+  /** Stale partial downloads. */
+  partial_bytes: number;
+  freed_bytes: number;
+  dry_run: boolean;
+}
+
+/** One re-transcription of a golden-set recording; `wer` is null when it failed. */
+export interface GoldenRun {
+  run_at: string;
+  model: string;
+  server_version: string;
+  wer: number | null;
+  error: string | null;
+}
+
+/** A reference transcript and how its WER moved since the first run. */
+export interface GoldenReference {
+  recording_id: number;
+  title: string;
+  marked_at: string;
+  reference_words: number;
+  runs: number;
+  baseline_wer: number | null;
+  latest_wer: number | null;
+  /** Latest minus baseline WER; null until there are two scored runs. */
+  drift: number | null;
+  regressed: boolean;
+  last_run_at: string | null;
+  last_error: string | null;
+  /** The most recent runs, oldest first. */
+  history: GoldenRun[];
+}
+
+export interface GoldenSetReport {
+  /** Drift above this (absolute WER) counts as a regression. */
+  threshold: number;
+  recordings: GoldenReference[];
+  mean_baseline_wer: number | null;
+  mean_latest_wer: number | null;
+  drift: number | null;
+  regressed: boolean;
+}
+
+export interface GoldenCheckResult extends GoldenSetReport {
+  runs: { recording_id: number; wer: number | null; error: string | null }[];
+}
+
 // ─── Transcription ────────────────────────────────────────────────────────────
 
 export interface TranscriptionResponse {
@@ -83,13 +226,34 @@ export interface TranscriptionResponse {
   language_probability: number;
   duration: number;
   num_speakers: number;
+  /** Set when the job stopped early: `segments` cover only the first `completed_seconds`. */
+  partial?: boolean;
+  partial_reason?: string | null;
+  completed_seconds?: number | null;
+  /** Set when the job ran out of GPU memory and a fallback finished it. */
+  downgrade?: TranscriptionDowngrade | null;
+}
+
+/** How the server finished a job that ran out of GPU memory (server core/oom_fallback.py). */
+export interface TranscriptionDowngrade {
+  from_model: string;
+  to_model: string;
+  /** Set when the file was transcribed in pieces of this many seconds. */
+  chunk_seconds: number | null;
+  error: string;
+  /** GPU memory figures at the failure. */
+  vram: { total_gb?: number; free_gb?: number; allocated_gb?: number; reserved_gb?: number };
+  summary: string;
 }
 
 export interface TranscriptionSegment {
+  /** Database id — present on notebook transcriptions, absent on live results. */
+  id?: number;
   text: string;
   start: number;
   end: number;
   speaker?: string;
+  review_state?: SegmentReviewState | null;
   words?: TranscriptionWord[];
 }
 
@@ -98,6 +262,8 @@ export interface TranscriptionWord {
   start: number;
   end: number;
   probability?: number;
+  /** Stored per-word score (0–1); null when the backend reported none. */
+  confidence?: number | null;
   speaker?: string;
 }
 
@@ -110,10 +276,33 @@ export interface TranscriptionUploadOptions {
   expected_speakers?: number;
   parallel_diarization?: boolean;
   multitrack?: boolean;
+  /** Multitrack only: speaker name per channel, in file channel order. */
+  channel_labels?: string[];
   file_created_at?: string;
   title?: string;
   /** Active recording-profile id (FR18). Snapshotted server-side at job start. */
   profile_id?: number | null;
+  /** Folder Watch folder the file came from; matched by post-transcription rules. */
+  source?: string;
+  /** Server queue entry that reached the front (`/api/transcribe/queue`). */
+  queue_entry_id?: string;
+  /** Notebook uploads: custom fields stored on the recording. */
+  metadata?: Record<string, string>;
+  /** Session imports: keep the upload on the server to download again later. */
+  keep_media?: boolean;
+}
+
+/** A file staged with `/api/transcribe/uploads`, ready to be used by id. */
+export interface StagedUploadRef {
+  upload_id: string;
+}
+
+/** What the upload endpoints accept: the file itself or a complete staged upload. */
+export type UploadSource = File | StagedUploadRef;
+
+export interface StagedUploadStatus extends StagedUploadRef {
+  received: number;
+  size: number;
 }
 
 export interface LanguagesResponse {
@@ -132,6 +321,18 @@ export interface LanguagesResponse {
   supports_translation?: boolean;
 }
 
+/** POST /api/transcribe/detect-language — only the head of the file is sampled. */
+export interface LanguageDetectionResult {
+  supported: boolean;
+  language: string | null;
+  probability: number;
+  languages: { code: string; probability: number }[];
+  /** Top language per 10 s window; null for silent or unsure windows. */
+  window_languages: (string | null)[];
+  multilingual: boolean;
+  sampled_seconds: number;
+}
+
 export interface TranscriptionCancelResponse {
   success: boolean;
   cancelled_user?: string;
@@ -165,8 +366,87 @@ export interface Recording {
   auto_export_status?: string | null;
   auto_export_error?: string | null;
   auto_export_path?: string | null;
+  // Source-audio retention (migration 023): "keep forever" override, and when
+  // the policy deleted or archived the original audio (null = still present).
+  keep_audio?: boolean;
+  audio_retired_at?: string | null;
+  // Per-user namespace (migration 025): the user name of the client that
+  // created it; null = shared between all users of the server.
+  owner?: string | null;
+  /** Custom fields (migration 027), e.g. `{ Client: 'Acme' }`. */
+  metadata?: Record<string, string>;
+  // Interrupted transcription (migration 029): seconds of audio the saved
+  // transcript covers and why it stopped; null = complete.
+  partial_until?: number | null;
+  partial_reason?: string | null;
+}
+
+/** A custom field in use across the library, for filter controls. */
+export interface MetadataFieldSummary {
+  field: string;
+  /** Recordings carrying the field. */
+  count: number;
+  /** Its most common values, most frequent first. */
+  values: string[];
 }
 
+/** A metadata field a project template asks for. */
+export interface ProjectField {
+  key: string;
+  label: string;
+}
+
+/** Transcription options a project template pre-selects; unset = leave as is. */
+export interface ProjectOptions {
+  language?: string;
+  enable_diarization?: boolean;
+  expected_speakers?: number;
+  profile_id?: number;
+}
+
+/** A reusable setup for a recurring workflow (`/api/projects`). */
+export interface ProjectTemplate {
+  id: number;
+  name: string;
+  description: string | null;
+  fields: ProjectField[];
+  /** Pre-filled field values. */
+  defaults: Record<string, string>;
+  options: ProjectOptions;
+  created_at: string;
+  updated_at: string;
+}
+
+export type ProjectTemplatePayload = Pick<
+  ProjectTemplate,
+  'name' | 'description' | 'fields' | 'defaults' | 'options'
+>;
+
+/**
+ * Library card media info (server core/media_previews.py), extracted in the
+ * background after import. `pending` until the workers get to it.
+ */
+export type MediaInfo =
+  | {
+      status: 'ready';
+      duration_seconds: number | null;
+      container: string | null;
+      size_bytes: number | null;
+      bit_rate: number | null;
+      audio: { codec: string | null; sample_rate: number | null; channels: number | null } | null;
+      video: {
+        codec: string | null;
+        width: number | null;
+        height: number | null;
+        frame_rate: number | null;
+      } | null;
+      /** Which preview image `/recordings/:id/preview` serves, if any. */
+      preview: 'thumbnail' | 'spectrogram' | null;
+    }
+  | { status: 'pending' }
+  | { status: 'failed'; error: string }
+  | { status: 'unavailable' };
+
 export interface RecordingDetail extends Recording {
   segments: TranscriptionSegment[];
   words: TranscriptionWord[];
@@ -190,6 +470,9 @@ export interface UploadResponse {
     performed: boolean;
     reason: string | null;
   };
+  /** Seconds the saved transcript covers when the job stopped early. */
+  partial_until?: number | null;
+  downgrade?: TranscriptionDowngrade | null;
 }
 
 /** Returned by POST /api/notebook/transcribe/upload (202 Accepted) */
@@ -199,11 +482,21 @@ export interface TranscriptionAccepted {
    * Issue #104 / Story 2.4 — populated by /api/transcribe/import when a
    * prior job's audio_hash matches this upload. Empty list = J1 happy path
    * (no duplicate). Notebook upload (`/api/notebook/transcribe/upload`)
-   * doesn't currently populate this field; it's reserved for future cross-
-   * flow dedup. Default-empty keeps the response shape backwards-compatible
-   * for callers that ignore it.
+   * reports fingerprint matches only: re-encoded or trimmed copies of
+   * earlier recordings. Default-empty keeps the response shape backwards-
+   * compatible for callers that ignore it.
    */
   dedup_matches?: DedupMatch[];
+  /** /api/transcribe/import with `keep_media`: the server copy, when it was stored. */
+  kept_media?: KeptMedia | null;
+}
+
+/** Where a kept upload lives (`GET /api/transcribe/jobs/{job_id}/media`) and its digest. */
+export interface KeptMedia {
+  /** Full job id — the `job_id` beside it is the 8-char display form. */
+  job_id: string;
+  sha256: string;
+  size: number;
 }
 
 /** A prior item that shares this upload's audio_hash or normalized_audio_hash.
@@ -218,6 +511,16 @@ export interface DedupMatch {
   name: string;
   created_at: string;
   source?: 'transcription_job' | 'recording';
+  /**
+   * `'fingerprint'`: same audio, different samples — a re-encode or a
+   * trimmed copy of a recording. The match's time = upload time + offset.
+   */
+  match?: 'hash' | 'fingerprint';
+  offset_seconds?: number | null;
+  /** Length of the uploaded audio. */
+  duration_seconds?: number | null;
+  /** 0..1 share of matching fingerprint bits. */
+  similarity?: number | null;
 }
 
 /** Returned by POST /api/transcribe/import/dedup-check (Issue #104, Story 2.4). */
@@ -235,6 +538,9 @@ export interface JobTrackerResult {
     performed: boolean;
     reason: string | null;
   };
+  partial?: boolean;
+  partial_until?: number | null;
+  downgrade?: TranscriptionDowngrade | null;
   error?: string;
 }
 
@@ -257,6 +563,37 @@ export interface JobTrackerStatus {
   result: JobTrackerResult | null;
 }
 
+/** The job holding the server's transcription slot, from GET /api/transcribe/queue */
+export interface ServerQueueActive {
+  user: string;
+  /** True when the caller (this client's token) started it */
+  mine: boolean;
+  started_at: number | null; // epoch seconds
+  progress: JobTrackerProgress | null;
+  /** Null until the server has finished a job to estimate from */
+  estimated_end_at: number | null;
+}
+
+/** A client waiting for the transcription slot */
+export interface ServerQueueEntry {
+  /** Only set on the caller's own entries */
+  id: string | null;
+  position: number;
+  user: string;
+  mine: boolean;
+  /** Only set on the caller's own entries */
+  label: string | null;
+  duration_seconds: number | null;
+  enqueued_at: number;
+  estimated_start_at: number | null;
+}
+
+/** Server queue with every timestamp shifted onto this machine's clock (epoch seconds) */
+export interface ServerQueue {
+  active: ServerQueueActive | null;
+  entries: ServerQueueEntry[];
+}
+
 /** Narrow accessor for the loosely-typed AdminStatus.models blob (GH-211). */
 export function jobTrackerFromAdminStatus(
   status: AdminStatus | null,
@@ -279,7 +616,487 @@ export interface TimeslotResponse {
   is_full: boolean;
 }
 
-export type ExportFormat = 'txt' | 'srt' | 'ass';
+export type MarkerExportFormat = 'edl' | 'premiere_xml' | 'resolve_csv';
+
+export type ExportFormat =
+  | 'txt'
+  | 'plaintext'
+  | 'accessible'
+  | 'srt'
+  | 'ass'
+  | 'audacity'
+  | MarkerExportFormat;
+
+/** POST /api/notebook/recordings/:id/labels response. */
+export interface LabelImportResult {
+  recording_id: number;
+  dry_run: boolean;
+  label_count: number;
+  segment_count: number;
+  segments: { text: string; start_time: number; end_time: number; speaker: string | null }[];
+}
+
+export interface LowConfidenceRegion {
+  start: number;
+  end: number;
+  text: string;
+  word_ids: number[];
+  min_confidence: number;
+  mean_confidence: number;
+  segment_id: number | null;
+}
+
+export interface LowConfidenceResponse {
+  recording_id: number;
+  threshold: number;
+  /** Fraction of words carrying a score — 0 means the backend reported none. */
+  coverage: number;
+  regions: LowConfidenceRegion[];
+}
+
+/** Per-segment QA state; null (or absent) = unreviewed. */
+export type SegmentReviewState = 'approved' | 'flagged';
+
+export interface SegmentReviewItem {
+  id: number;
+  segment_index: number;
+  state: SegmentReviewState | null;
+  reviewed_at: string | null;
+}
+
+export interface SegmentReviewProgress {
+  total: number;
+  approved: number;
+  flagged: number;
+  unreviewed: number;
+  reviewed_fraction: number;
+}
+
+export interface SegmentReviewResponse {
+  recording_id: number;
+  segments: SegmentReviewItem[];
+  progress: SegmentReviewProgress;
+}
+
+export interface Annotation {
+  id: number;
+  recording_id: number;
+  segment_id: number | null;
+  /** Thread root id for replies; null on roots. */
+  parent_id: number | null;
+  start_time: number;
+  /** null = a point-in-time note. */
+  end_time: number | null;
+  author: string;
+  body: string;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface AnnotationThread extends Annotation {
+  replies: Annotation[];
+}
+
+export interface AnnotationsResponse {
+  recording_id: number;
+  threads: AnnotationThread[];
+}
+
+export interface AnnotationCreate {
+  body: string;
+  segment_id?: number;
+  start_time?: number;
+  end_time?: number;
+  parent_id?: number;
+  /** Defaults server-side to the client name. */
+  author?: string;
+}
+
+export interface Redaction {
+  id: number;
+  recording_id: number;
+  start_time: number;
+  end_time: number;
+  label: string | null;
+  /** `pii` = suggested by auto-detection. */
+  source: 'manual' | 'pii';
+  /** The words the range covered when it was marked. */
+  text: string | null;
+  created_at: string;
+}
+
+export interface RedactionsResponse {
+  recording_id: number;
+  redactions: Redaction[];
+}
+
+export type RedactionAudioMode = 'mute' | 'bleep';
+
+/** One chapter of a recording's outline. */
+export interface Chapter {
+  id: number;
+  recording_id: number;
+  start_time: number;
+  end_time: number;
+  title: string;
+  /** `llm` = titled by the configured LLM, `auto` = keyword title. */
+  source: 'auto' | 'llm';
+  created_at: string;
+}
+
+export interface OutlineResponse {
+  recording_id: number;
+  /** Empty for recordings too short to chapter. */
+  chapters: Chapter[];
+}
+
+/** Conversation statistics for one diarized speaker. */
+export interface SpeakerStats {
+  /** Raw diarization id. */
+  speaker: string;
+  /** Display name (alias or "Speaker N"). */
+  label: string;
+  talk_seconds: number;
+  /** Fraction (0–1) of all speech. */
+  share: number;
+  words: number;
+  words_per_minute: number;
+  turns: number;
+  longest_monologue_seconds: number;
+  longest_monologue_start: number;
+  /** Times this speaker cut in on someone. */
+  interruptions: number;
+  /** Times this speaker was cut off. */
+  interrupted: number;
+}
+
+export interface SpeakerStatsResponse {
+  recording_id: number;
+  /** Most talk time first; empty without diarization. */
+  speakers: SpeakerStats[];
+  total_talk_seconds: number;
+  duration_seconds: number;
+}
+
+/** One side of a diff hunk; times are null for untimed (corrected) text. */
+export interface TranscriptDiffSide {
+  text: string;
+  start: number | null;
+  end: number | null;
+}
+
+export interface TranscriptDiffHunk {
+  /** `delete` = only in A, `insert` = only in B. */
+  op: 'equal' | 'replace' | 'delete' | 'insert';
+  a: TranscriptDiffSide;
+  b: TranscriptDiffSide;
+}
+
+/** Word alignment of two transcripts of the same audio, A as reference. */
+export interface TranscriptDiffResponse {
+  recording_a: number;
+  recording_b: number;
+  wer: number;
+  words_a: number;
+  words_b: number;
+  matches: number;
+  substitutions: number;
+  deletions: number;
+  insertions: number;
+  hunks: TranscriptDiffHunk[];
+}
+
+/** An enrolled speaker; the embedding stays on the server. */
+export interface VoiceProfile {
+  id: number;
+  name: string;
+  sample_count: number;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface VoiceProfilesResponse {
+  /** False until `speaker_identification.model_path` points at a model. */
+  enabled: boolean;
+  profiles: VoiceProfile[];
+}
+
+export interface SpeakerIdentificationResult {
+  recording_id: number;
+  /** speaker_id → profile name, for the aliases this run added. */
+  matched: Record<string, string>;
+  aliases: { speaker_id: string; alias_name: string }[];
+}
+
+export interface RealignResult {
+  recording_id: number;
+  /** `interpolated` = no aligner ran; unchanged words kept their timings. */
+  method: 'wav2vec2' | 'interpolated';
+  segment_count: number;
+  word_count: number;
+}
+
+export interface SegmentRetranscriptionResult {
+  recording_id: number;
+  segment_id: number;
+  dry_run: boolean;
+  text: string;
+  previous_text: string;
+  words: { word: string; start: number; end: number; confidence?: number | null }[];
+  /** Model that produced `text`. */
+  model: string;
+  language: string | null;
+}
+
+/** POST /api/notebook/recordings/{id}/resume */
+export interface PartialResumeResult {
+  recording_id: number;
+  /** Where the resumed transcription started. */
+  offset: number;
+  segment_count: number;
+  word_count: number;
+  /** Still partial when the resumed run stopped early too. */
+  partial: boolean;
+  partial_until: number | null;
+  partial_reason: string | null;
+}
+
+export interface SegmentSplitResult {
+  recording_id: number;
+  /** `[original, new]` — the original keeps the first half. */
+  segment_ids: [number, number];
+  /** Where the cut landed after snapping to the pause between words. */
+  boundary: number;
+}
+
+export interface SegmentMergeResult {
+  recording_id: number;
+  segment_id: number;
+  merged_ids: number[];
+  start_time: number;
+  end_time: number;
+}
+
+export type CaptionLintRule =
+  | 'line_length'
+  | 'line_count'
+  | 'min_duration'
+  | 'max_duration'
+  | 'reading_speed'
+  | 'overlap'
+  | 'min_gap';
+
+export interface CaptionViolation {
+  rule: CaptionLintRule;
+  severity: 'error' | 'warning';
+  cue_index: number;
+  start: number;
+  end: number;
+  message: string;
+  value: number;
+  limit: number;
+  /** 1-based line within the cue, for per-line rules. */
+  line: number | null;
+}
+
+export interface CaptionProfile {
+  name: string;
+  max_chars_per_line: number;
+  max_lines: number;
+  min_duration: number;
+  max_duration: number;
+  max_cps: number;
+  min_gap: number;
+}
+
+export interface CaptionLintResult {
+  recording_id: number;
+  profile: CaptionProfile;
+  cue_count: number;
+  violations: CaptionViolation[];
+  summary: Partial<Record<CaptionLintRule, number>>;
+  /** Violations an export with `caption_profile` would still contain. */
+  remaining_after_fix: number;
+}
+
+export type OutputAction = 'write' | 'overwrite' | 'skip' | 'unrouted';
+
+export interface OutputRouteRequest {
+  recording_ids: number[];
+  /** Export formats plus `summary` / `recording`; server default when empty. */
+  formats?: string[];
+  template?: string;
+  collision?: 'suffix' | 'overwrite' | 'skip';
+}
+
+export interface PlannedOutput {
+  recording_id: number;
+  format: string;
+  output_type: string;
+  filename: string;
+  /** `null` when the output type has no folder configured. */
+  path: string | null;
+  action: OutputAction;
+  renamed: boolean;
+}
+
+export interface RoutedOutput extends PlannedOutput {
+  status: 'written' | 'skipped' | 'unrouted' | 'failed';
+  error?: string;
+}
+
+/** Body for POST /api/notebook/outputs/library-export; empty filters match everything. */
+export interface LibraryExportRequest {
+  formats: string[];
+  start_date?: string;
+  end_date?: string;
+  /** Case-insensitive substring of the title or filename. */
+  title?: string;
+  recording_ids?: number[];
+  /** Server-side folder for every output; the `output_manager` folders when omitted. */
+  dest?: string;
+  template?: string;
+  collision?: 'suffix' | 'overwrite' | 'skip';
+}
+
+export type LibraryExportStatus = 'written' | 'skipped' | 'unsupported' | 'failed';
+
+export type LibraryExportEvent =
+  | { type: 'start'; total: number; recordings: number }
+  | (PlannedOutput & {
+      type: 'progress';
+      status: LibraryExportStatus;
+      error?: string;
+      done: number;
+      total: number;
+    })
+  | ({ type: 'done'; done: number; total: number; cancelled: boolean } & Record<
+      LibraryExportStatus,
+      number
+    >);
+
+export interface PostRuleAction {
+  type: 'export' | 'webhook';
+  format?: string;
+  /** Planned export path; `null` if it could not be routed. */
+  path?: string | null;
+  action?: OutputAction;
+  url?: string;
+  include_transcript?: boolean;
+}
+
+export interface PostRuleReport {
+  rule: string;
+  matched: boolean;
+  /** Why the rule did not match; empty when it did. */
+  reasons: string[];
+  actions: PostRuleAction[];
+}
+
+export interface PostRuleTestRequest {
+  recording_id?: number;
+  source?: string;
+  filename?: string;
+  language?: string;
+  duration?: number;
+}
+
+export type SubtitleAlignmentMethod = 'wav2vec2' | 'proportional';
+
+export interface SubtitleImportOptions {
+  /** Force-align cue text against the audio to fix drifting timings. */
+  align?: boolean;
+  language?: string;
+  title?: string;
+  file_created_at?: string;
+}
+
+export interface SubtitleImportResult {
+  recording_id: number;
+  format: 'srt' | 'vtt' | 'ass';
+  cues: number;
+  words: number;
+  alignment: SubtitleAlignmentMethod;
+}
+
+export type TranscriptImportFormat =
+  | 'whisper_json'
+  | 'otter'
+  | 'sbv'
+  | 'descript'
+  | 'srt'
+  | 'vtt'
+  | 'ass';
+
+export interface TranscriptImportOptions {
+  /** Optional media file; without it the recording is text-only. */
+  media?: File | null;
+  /** Skip auto-detection. */
+  format?: TranscriptImportFormat;
+  align?: boolean;
+  language?: string;
+  title?: string;
+  recorded_at?: string;
+}
+
+export interface TranscriptImportResult {
+  recording_id: number;
+  format: TranscriptImportFormat;
+  segments: number;
+  words: number;
+  /** `source` when the transcript carried its own word timings. */
+  alignment: SubtitleAlignmentMethod | 'source';
+  has_audio: boolean;
+}
+
+export interface ProjectImportResult {
+  recording_id: number;
+  segments: number;
+  words: number;
+  annotations: number;
+  redactions: number;
+  /** How the bundle carried its audio. */
+  media: 'embedded' | 'reference' | 'none';
+  has_audio: boolean;
+}
+
+// ─── Share targets ────────────────────────────────────────────────────────────
+
+export type ShareTargetType = 'email' | 'slack' | 'discord' | 'webdav';
+
+export type ShareFormat = 'plaintext' | 'srt' | 'ass';
+
+export interface ShareTarget {
+  name: string;
+  type: ShareTargetType;
+  /** Credential fields with no keychain entry yet (e.g. `webhook_url`). */
+  missing_credentials: string[];
+}
+
+export interface ShareResult {
+  target: string;
+  type: ShareTargetType;
+  status: 'sent' | 'uploaded' | 'draft';
+  /** Unsent RFC 5322 message — only for email targets without an SMTP relay. */
+  eml?: string;
+  filename?: string;
+  url?: string;
+  status_code?: number;
+}
+
+export type TimecodeFps = 23.976 | 24 | 25 | 29.97 | 30;
+
+/** Per-recording SMPTE settings (server/backend/core/timecode.py). */
+export interface TimecodeSettings {
+  start_timecode: string;
+  fps: TimecodeFps;
+  drop_frame: boolean;
+}
+
+export interface RecordingTimecodeResponse {
+  recording_id: number;
+  timecode: TimecodeSettings | null;
+}
 
 // ─── File Import (Session) ────────────────────────────────────────────────────
 
@@ -318,6 +1135,43 @@ export interface RestoreResponse {
   restored_from: string;
 }
 
+export type MaintenanceStep = 'integrity' | 'foreign_keys' | 'consistency' | 'fts' | 'vacuum';
+
+export interface MaintenanceIssue {
+  type: 'issue';
+  step: MaintenanceStep;
+  message: string;
+  count: number;
+  repaired: boolean;
+}
+
+export interface MaintenanceReport {
+  type: 'done';
+  /** Unix seconds. */
+  finished_at: number;
+  duration_seconds: number;
+  issues: number;
+  repaired: number;
+  /** Every issue found was repaired (or none were found). */
+  healthy: boolean;
+  /** Backup taken before repairing, or null for a report-only run. */
+  backup: string | null;
+  size_before: number;
+  size_after: number;
+  details: MaintenanceIssue[];
+}
+
+export type MaintenanceEvent =
+  | { type: 'start'; steps: MaintenanceStep[]; repair: boolean; backup: string | null }
+  | { type: 'step'; step: MaintenanceStep; index: number; total: number }
+  | MaintenanceIssue
+  | MaintenanceReport;
+
+export interface MaintenanceStatus {
+  running: boolean;
+  last_report: MaintenanceReport | null;
+}
+
 // ─── Search ───────────────────────────────────────────────────────────────────
 
 export interface SearchResult {
@@ -332,7 +1186,7 @@ export interface SearchResult {
   recorded_at: string;
   speaker: string | null;
   context: string;
-  match_type: 'word' | 'summary' | 'filename';
+  match_type: 'word' | 'summary' | 'filename' | 'metadata';
 }
 
 export interface SearchResponse {
@@ -388,6 +1242,13 @@ export interface AdminStatus {
   };
 }
 
+export interface LibraryLockStatus {
+  read_only: boolean;
+  /** This server's machine name as recorded in the owner file. */
+  machine: string | null;
+  holder: { machine: string; pid: number; last_seen_seconds: number } | null;
+}
+
 export interface LogEntry {
   timestamp: string;
   level: string;
@@ -1,6 +1,13 @@
 import { describe, it, expect, beforeEach, vi } from 'vitest';
 
-import { DEFAULT_SERVER_PORT, getServerBaseUrl, isServerUrlConfigured } from './store';
+import {
+  DEFAULT_CONFIG,
+  DEFAULT_SERVER_PORT,
+  getConnectionTuning,
+  getServerBaseUrl,
+  isServerUrlConfigured,
+  validateConnectionTuning,
+} from './store';
 
 // These tests route getConfig through the Electron `window.electronAPI.config.get`
 // bridge — the production branch. Each test seeds a plain object that the bridge
@@ -166,3 +173,34 @@ describe('getServerBaseUrl — blank-remote non-coercion regression lock', () =>
     expect(url).toBe(`https://foo.ts.net:${DEFAULT_SERVER_PORT}`);
   });
 });
+
+describe('connection tuning', () => {
+  beforeEach(() => {
+    delete (window as any).electronAPI;
+  });
+
+  it('uses the active remote profile and falls back to its defaults for bad values', async () => {
+    installConfigBridge({
+      'connection.useRemote': true,
+      'connection.remoteProfile': 'lan',
+      'connection.tuning.lan': { connectTimeoutSeconds: 0, readTimeoutSeconds: 45, http2: false },
+      'connection.tuning.local': { connectTimeoutSeconds: 2 },
+    });
+    expect(await getConnectionTuning()).toEqual({
+      ...DEFAULT_CONFIG.connection.tuning.lan,
+      readTimeoutSeconds: 45,
+      http2: false,
+    });
+  });
+
+  it('reports out-of-range timeouts and a read timeout shorter than connect', () => {
+    const local = DEFAULT_CONFIG.connection.tuning.local;
+    expect(validateConnectionTuning(local)).toEqual([]);
+    expect(validateConnectionTuning({ ...local, connectTimeoutSeconds: 500 })).toEqual([
+      'Connect timeout must be between 1 and 120 seconds.',
+    ]);
+    expect(
+      validateConnectionTuning({ ...local, connectTimeoutSeconds: 60, readTimeoutSeconds: 10 }),
+    ).toEqual(['Read timeout cannot be shorter than the connect timeout.']);
+  });
+});
@@ -6,12 +6,35 @@
  * The canonical key list lives in electron/main.ts defaults.
  */
 
+import type { BenchmarkReport } from '../services/benchmark';
+import type { HidBinding } from '../services/hidPedal';
+import type { OutputFilter } from '../services/outputFilter';
+
+/** The server a connection goes to: the local one or a remote profile. */
+export type ConnectionProfile = 'local' | 'tailscale' | 'lan';
+
+/** HTTP tuning for one connection profile (Settings → Client → Connection tuning). */
+export interface ConnectionTuning {
+  /** Seconds to wait for the server to accept the connection. */
+  connectTimeoutSeconds: number;
+  /** Seconds to wait for the response once connected. */
+  readTimeoutSeconds: number;
+  /** Reuse connections between requests instead of reconnecting each time. */
+  keepAlive: boolean;
+  /** Allow HTTP/2 over HTTPS; off forces HTTP/1.1 (applies after a restart). */
+  http2: boolean;
+}
+
 export interface ClientConfig {
   /** Server connection */
   server: {
     host: string;
     port: number;
     https: boolean;
+    /** Stop the local container after `minutes` idle (electron/idleManager.ts). */
+    idleStop: { enabled: boolean; minutes: number };
+    /** Server containers with the GPU profile, one per GPU; import jobs spread across them. */
+    replicas: number;
   };
   /** Connection settings (SettingsModal Client tab) */
   connection: {
@@ -23,6 +46,56 @@ export interface ClientConfig {
     authToken: string;
     port: number;
     useHttps: boolean;
+    /** Switch Tailscale ↔ LAN when the quality monitor suggests it. */
+    autoFailover: boolean;
+    /** Stored per profile under `connection.tuning.<profile>`. */
+    tuning: Record<ConnectionProfile, ConnectionTuning>;
+  };
+  /** Wake-on-LAN and SSH suspend for a remote server (electron/remotePower.ts) */
+  power: {
+    /** Wake the server before queued work when it does not answer. */
+    autoWake: boolean;
+    macAddress: string;
+    broadcastAddress: string;
+    port: number;
+    wakeTimeoutSeconds: number;
+    /** Suspend over SSH once the import queue drains, if this session woke it. */
+    sleepAfterJobs: boolean;
+    sshTarget: string;
+    sshCommand: string;
+  };
+  /** Who is using this client on a server shared by several people */
+  identity: {
+    /** Sent with every request; new recordings and queue entries carry it. */
+    userName: string;
+    /** Hide other users' recordings and queue entries (needs a userName). */
+    showOnlyMine: boolean;
+  };
+  /** Scheduled server housekeeping (src/services/housekeeping.ts) */
+  housekeeping: {
+    enabled: boolean;
+    /** Local "HH:MM" window; tasks run once per night inside it, when the server is idle. */
+    windowStart: string;
+    windowEnd: string;
+    pruneModelCache: boolean;
+    /** Cached models nobody configured or used for this many days are removed. */
+    pruneUnusedDays: number;
+    rotateLogs: boolean;
+    /** Re-transcribe the golden set and report WER drift (server core/golden_set.py). */
+    goldenCheck: boolean;
+    /** Local Docker container only; remote servers are never restarted. */
+    restartContainer: boolean;
+    /** Window date (YYYY-MM-DD) of the last run — bookkeeping, not a setting. */
+    lastRunDate: string;
+  };
+  /** Where new jobs go by default, recommended by the last benchmark (services/benchmark.ts) */
+  dispatch: {
+    /** Empty until a benchmark recommends one; jobs then use the active connection. */
+    profile: ConnectionProfile | '';
+    /** Model to transcribe with there; empty means the server's main model. */
+    model: string;
+    /** The benchmark the recommendation came from — bookkeeping, not a setting. */
+    lastBenchmark: BenchmarkReport | null;
   };
   /** Audio capture settings */
   audio: {
@@ -37,6 +110,12 @@ export interface ClientConfig {
     systemDevice: string;
     mainLanguage: string;
     liveLanguage: string;
+    /** Interview mode speaker names: microphone and system-audio channels */
+    interviewLocalName: string;
+    interviewRemoteName: string;
+    /** Keep a rolling buffer of the input so recent audio can be saved afterwards */
+    retroRecord: boolean;
+    retroMinutes: number;
   };
   /** Diarization settings */
   diarization: {
@@ -47,6 +126,14 @@ export interface ClientConfig {
   notebook: {
     autoAdd: boolean;
   };
+  /** Active review time per transcript, exported per project for invoicing */
+  timeTracking: {
+    enabled: boolean;
+    /** Recording metadata field that names the project, e.g. "Client" */
+    projectField: string;
+    /** Minutes without input after which time stops counting */
+    idleMinutes: number;
+  };
   /** App-level settings */
   app: {
     autoCopy: boolean;
@@ -60,15 +147,61 @@ export interface ClientConfig {
     pasteAtCursor: boolean;
     cumulativeUsageMs: number;
     starPopupShown: boolean;
+    /** The first-run sample transcription was offered (services/sampleJob.ts) */
+    sampleJobOffered: boolean;
+    /** Record server traffic for bug reports (services/networkTrace.ts) */
+    networkTrace: boolean;
   };
   /** Global keyboard shortcuts (Electron accelerator strings) */
   shortcuts: {
     startRecording: string;
     stopTranscribe: string;
   };
+  /** Foot pedal / HID controller bindings (services/hidPedal.ts) */
+  hidPedal: {
+    bindings: HidBinding[];
+    /** Seconds skipped by the rewind / fast-forward pedals */
+    rewindSeconds: number;
+    /** Seconds stepped back when a play-while-held pedal is released */
+    backstepSeconds: number;
+  };
   /** Output formatting */
   output: {
     hideTimestamps: boolean;
+    /** Append per-speaker statistics to PDF and plain-text exports */
+    speakerStats: boolean;
+    /** Write spelled-out numbers, dates and amounts as digits in exports (services/textNormalization.ts) */
+    normalizeNumbers: boolean;
+    /** Profanity masking and word replacement for exports and copies (services/outputFilter.ts) */
+    filter: OutputFilter;
+  };
+  /** Batch imports stage upcoming files in parallel (services/uploadPipeline.ts) */
+  upload: {
+    /** Files uploaded at once; 1 = upload each file only when its job starts */
+    parallelStreams: number;
+    /** Shared cap for all upload streams in Mbit/s; 0 = unlimited */
+    bandwidthLimitMbps: number;
+  };
+  /** Live Mode keyword alerts — plain phrases or `/regex/`, matched by the server */
+  live: {
+    watchKeywords: string[];
+    /** `fast` partial hypotheses (captions) or `accurate` finals (notes) */
+    streamingMode: 'fast' | 'accurate';
+  };
+  /** Live caption mirrors for streaming software (electron/captionOutputs.ts) */
+  captions: {
+    textFile: { enabled: boolean; path: string };
+    obs: { enabled: boolean; url: string; password: string; inputName: string };
+  };
+  /** Printable PDF export styling (header/footer use {title} and {date} tokens) */
+  pdf: {
+    header: string;
+    footer: string;
+    speakerStyle: 'bold' | 'caps' | 'plain';
+    timestamps: boolean;
+    pageNumbers: 'none' | 'page' | 'page-of-total';
+    pageSize: 'A4' | 'Letter' | 'Legal';
+    fontSize: number;
   };
   /** UI preferences */
   ui: {
@@ -97,6 +230,8 @@ const DEFAULT_CONFIG: ClientConfig = {
     host: 'localhost',
     port: DEFAULT_SERVER_PORT,
     https: false,
+    idleStop: { enabled: false, minutes: 15 },
+    replicas: 1,
   },
   connection: {
     localHost: 'localhost',
@@ -107,6 +242,47 @@ const DEFAULT_CONFIG: ClientConfig = {
     authToken: '',
     port: DEFAULT_SERVER_PORT,
     useHttps: false,
+    autoFailover: false,
+    tuning: {
+      local: { connectTimeoutSeconds: 5, readTimeoutSeconds: 600, keepAlive: true, http2: true },
+      tailscale: {
+        connectTimeoutSeconds: 20,
+        readTimeoutSeconds: 900,
+        keepAlive: true,
+        http2: true,
+      },
+      lan: { connectTimeoutSeconds: 10, readTimeoutSeconds: 600, keepAlive: true, http2: true },
+    },
+  },
+  power: {
+    autoWake: false,
+    macAddress: '',
+    broadcastAddress: '255.255.255.255',
+    port: 9,
+    wakeTimeoutSeconds: 120,
+    sleepAfterJobs: false,
+    sshTarget: '',
+    sshCommand: 'sudo systemctl suspend',
+  },
+  identity: {
+    userName: '',
+    showOnlyMine: false,
+  },
+  housekeeping: {
+    enabled: false,
+    windowStart: '03:00',
+    windowEnd: '05:00',
+    pruneModelCache: true,
+    pruneUnusedDays: 30,
+    rotateLogs: true,
+    goldenCheck: false,
+    restartContainer: false,
+    lastRunDate: '',
+  },
+  dispatch: {
+    profile: '',
+    model: '',
+    lastBenchmark: null,
   },
   audio: {
     gracePeriod: 1.0,
@@ -118,6 +294,10 @@ const DEFAULT_CONFIG: ClientConfig = {
     systemDevice: 'Default Output',
     mainLanguage: 'Auto Detect',
     liveLanguage: 'Auto Detect',
+    interviewLocalName: 'Me',
+    interviewRemoteName: 'Guest',
+    retroRecord: false,
+    retroMinutes: 5,
   },
   diarization: {
     constrainSpeakers: true,
@@ -126,6 +306,11 @@ const DEFAULT_CONFIG: ClientConfig = {
   notebook: {
     autoAdd: false,
   },
+  timeTracking: {
+    enabled: false,
+    projectField: 'Client',
+    idleMinutes: 2,
+  },
   app: {
     autoCopy: true,
     showNotifications: true,
@@ -138,13 +323,55 @@ const DEFAULT_CONFIG: ClientConfig = {
     pasteAtCursor: false,
     cumulativeUsageMs: 0,
     starPopupShown: false,
+    sampleJobOffered: false,
+    networkTrace: false,
   },
   shortcuts: {
     startRecording: 'Alt+Ctrl+Z',
     stopTranscribe: 'Alt+Ctrl+X',
   },
+  hidPedal: {
+    bindings: [],
+    rewindSeconds: 5,
+    backstepSeconds: 1,
+  },
   output: {
     hideTimestamps: false,
+    speakerStats: false,
+    normalizeNumbers: false,
+    filter: {
+      enabled: false,
+      builtInList: true,
+      words: [],
+      maskStyle: 'asterisks',
+      replacements: [],
+    },
+  },
+  upload: {
+    parallelStreams: 3,
+    bandwidthLimitMbps: 0,
+  },
+  live: {
+    watchKeywords: [],
+    streamingMode: 'accurate',
+  },
+  captions: {
+    textFile: { enabled: false, path: '' },
+    obs: {
+      enabled: false,
+      url: 'ws://127.0.0.1:4455',
+      password: '',
+      inputName: 'TranscriptionSuite Captions',
+    },
+  },
+  pdf: {
+    header: '{title}',
+    footer: '{date}',
+    speakerStyle: 'bold',
+    timestamps: true,
+    pageNumbers: 'page-of-total',
+    pageSize: 'A4',
+    fontSize: 11,
   },
   ui: {
     sidebarCollapsed: false,
@@ -213,6 +440,31 @@ export async function getServerBaseUrl(): Promise<string> {
   // probes on pure-remote users.
   // Spec: _bmad-output/implementation-artifacts/spec-in-app-update-remote-host-validation-renderer.md
   const host = useRemote ? (remoteProfile === 'lan' ? lanHost : remoteHost) : localHost;
+  return serverOrigin(host);
+}
+
+/**
+ * Base URL of any connection profile, active or not — for comparing servers
+ * (services/benchmark.ts). Null when the profile has no host configured.
+ */
+export async function getProfileBaseUrl(profile: ConnectionProfile): Promise<string | null> {
+  const key =
+    profile === 'local'
+      ? 'connection.localHost'
+      : profile === 'lan'
+        ? 'connection.lanHost'
+        : 'connection.remoteHost';
+  const configured = (await getConfig<string>(key)) ?? '';
+  const host = (
+    profile === 'local' && !configured
+      ? ((await getConfig<string>('server.host')) ?? DEFAULT_CONFIG.server.host)
+      : configured
+  ).trim();
+  return host ? serverOrigin(host) : null;
+}
+
+/** `protocol://host:port` with the shared port and HTTPS settings. */
+async function serverOrigin(host: string): Promise<string> {
   const port =
     (await getConfig<number>('connection.port')) ??
     (await getConfig<number>('server.port')) ??
@@ -249,6 +501,72 @@ export async function isServerUrlConfigured(): Promise<boolean> {
   return host.length > 0;
 }
 
+/** Bounds (seconds) enforced on connection tuning values. */
+export const CONNECTION_TUNING_LIMITS = {
+  connectTimeoutSeconds: { min: 1, max: 120 },
+  readTimeoutSeconds: { min: 5, max: 3600 },
+} as const;
+
+/** Problems with a tuning entry, as messages for the Settings form; empty when valid. */
+export function validateConnectionTuning(tuning: ConnectionTuning): string[] {
+  const errors: string[] = [];
+  for (const key of ['connectTimeoutSeconds', 'readTimeoutSeconds'] as const) {
+    const { min, max } = CONNECTION_TUNING_LIMITS[key];
+    const value = tuning[key];
+    if (!Number.isFinite(value) || value < min || value > max) {
+      const label = key === 'connectTimeoutSeconds' ? 'Connect timeout' : 'Read timeout';
+      errors.push(`${label} must be between ${min} and ${max} seconds.`);
+    }
+  }
+  if (errors.length === 0 && tuning.readTimeoutSeconds < tuning.connectTimeoutSeconds) {
+    errors.push('Read timeout cannot be shorter than the connect timeout.');
+  }
+  return errors;
+}
+
+/**
+ * Coerce a stored tuning entry into a valid one: missing or out-of-range
+ * fields fall back to the profile's defaults, so a hand-edited config never
+ * leaves the client with a zero or negative timeout.
+ *
+ * Parity invariant with electron/connectionTuning.ts::normalizeConnectionTuning.
+ */
+export function normalizeConnectionTuning(
+  raw: unknown,
+  profile: ConnectionProfile,
+): ConnectionTuning {
+  const defaults = DEFAULT_CONFIG.connection.tuning[profile];
+  const stored = (raw && typeof raw === 'object' ? raw : {}) as Partial<ConnectionTuning>;
+  const seconds = (key: keyof typeof CONNECTION_TUNING_LIMITS): number => {
+    const { min, max } = CONNECTION_TUNING_LIMITS[key];
+    const value = stored[key];
+    return typeof value === 'number' && Number.isFinite(value) && value >= min && value <= max
+      ? value
+      : defaults[key];
+  };
+  const connectTimeoutSeconds = seconds('connectTimeoutSeconds');
+  return {
+    connectTimeoutSeconds,
+    readTimeoutSeconds: Math.max(seconds('readTimeoutSeconds'), connectTimeoutSeconds),
+    keepAlive: typeof stored.keepAlive === 'boolean' ? stored.keepAlive : defaults.keepAlive,
+    http2: typeof stored.http2 === 'boolean' ? stored.http2 : defaults.http2,
+  };
+}
+
+/** Tuning for the profile the client currently connects with. */
+export async function getConnectionTuning(): Promise<ConnectionTuning> {
+  const useRemote = (await getConfig<boolean>('connection.useRemote')) ?? false;
+  const remoteProfile =
+    (await getConfig<'tailscale' | 'lan'>('connection.remoteProfile')) ??
+    DEFAULT_CONFIG.connection.remoteProfile;
+  const profile: ConnectionProfile = useRemote
+    ? remoteProfile === 'lan'
+      ? 'lan'
+      : 'tailscale'
+    : 'local';
+  return normalizeConnectionTuning(await getConfig(`connection.tuning.${profile}`), profile);
+}
+
 /**
  * Get the stored auth token from config.
  */
@@ -257,4 +575,31 @@ export async function getAuthToken(): Promise<string | null> {
   return token || null;
 }
 
+/** The user name and own-items filter for shared servers. */
+export async function getIdentity(): Promise<ClientConfig['identity']> {
+  const userName = ((await getConfig<string>('identity.userName')) ?? '').trim();
+  const showOnlyMine = (await getConfig<boolean>('identity.showOnlyMine')) ?? false;
+  return { userName, showOnlyMine };
+}
+
+/** The profile and model new jobs go to by default; empty fields mean "the active one". */
+export async function getDispatchDefaults(): Promise<{
+  profile: ConnectionProfile | '';
+  model: string;
+}> {
+  const profile = (await getConfig<ConnectionProfile | ''>('dispatch.profile')) ?? '';
+  const model = (await getConfig<string>('dispatch.model')) ?? '';
+  return { profile, model };
+}
+
+/** Scheduled housekeeping settings, with defaults for keys never saved. */
+export async function getHousekeeping(): Promise<ClientConfig['housekeeping']> {
+  const defaults = DEFAULT_CONFIG.housekeeping;
+  const keys = Object.keys(defaults) as (keyof ClientConfig['housekeeping'])[];
+  const values = await Promise.all(keys.map((key) => getConfig(`housekeeping.${key}`)));
+  return Object.fromEntries(
+    keys.map((key, i) => [key, values[i] ?? defaults[key]]),
+  ) as ClientConfig['housekeeping'];
+}
+
 export { DEFAULT_CONFIG };
@@ -0,0 +1,103 @@
+/**
+ * useMediaInfo — library card metadata: one batched request, re-polled
+ * only while an entry is pending, plus the card's codec label.
+ */
+
+import { act, renderHook } from '@testing-library/react';
+import { QueryClient, QueryClientProvider } from '@tanstack/react-query';
+import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest';
+import React from 'react';
+
+import { describeMedia, useMediaInfo } from '../useMediaInfo';
+import { apiClient } from '../../api/client';
+import type { MediaInfo } from '../../api/types';
+
+vi.mock('../../api/client', () => ({
+  apiClient: {
+    getMediaInfo: vi.fn(),
+  },
+}));
+
+const mockedGetMediaInfo = apiClient.getMediaInfo as unknown as ReturnType<typeof vi.fn>;
+
+function makeWrapper() {
+  const client = new QueryClient({
+    defaultOptions: {
+      queries: { retry: false, gcTime: 0 },
+    },
+  });
+  return function Wrapper({ children }: { children: React.ReactNode }) {
+    return <QueryClientProvider client={client}>{children}</QueryClientProvider>;
+  };
+}
+
+const READY: Extract<MediaInfo, { status: 'ready' }> = {
+  status: 'ready',
+  duration_seconds: 12,
+  container: 'mp3',
+  size_bytes: 1000,
+  bit_rate: 128000,
+  audio: { codec: 'mp3', sample_rate: 44100, channels: 2 },
+  video: null,
+  preview: 'spectrogram',
+};
+
+describe('useMediaInfo', () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+    mockedGetMediaInfo.mockReset();
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  it('fetches all cards at once and polls until none is pending', async () => {
+    mockedGetMediaInfo
+      .mockResolvedValueOnce({ '2': { status: 'pending' }, '5': READY })
+      .mockResolvedValue({ '2': READY, '5': READY });
+
+    const { result } = renderHook(() => useMediaInfo([5, 2, 5]), { wrapper: makeWrapper() });
+    await act(() => vi.advanceTimersByTimeAsync(0));
+
+    expect(mockedGetMediaInfo).toHaveBeenCalledWith([2, 5]);
+    expect(result.current.get(2)).toEqual({ status: 'pending' });
+    expect(result.current.get(5)).toEqual(READY);
+
+    await act(() => vi.advanceTimersByTimeAsync(3_000));
+    expect(result.current.get(2)).toEqual(READY);
+
+    await act(() => vi.advanceTimersByTimeAsync(30_000));
+    expect(mockedGetMediaInfo).toHaveBeenCalledTimes(2);
+  });
+
+  it('does not ask for an empty page', async () => {
+    const { result } = renderHook(() => useMediaInfo([]), { wrapper: makeWrapper() });
+    await act(() => vi.advanceTimersByTimeAsync(0));
+
+    expect(mockedGetMediaInfo).not.toHaveBeenCalled();
+    expect(result.current.size).toBe(0);
+  });
+});
+
+describe('describeMedia', () => {
+  it('labels video by codec and frame size, audio by codec and sample rate', () => {
+    expect(
+      describeMedia({
+        ...READY,
+        video: { codec: 'h264', width: 1920, height: 1080, frame_rate: 29.97 },
+        preview: 'thumbnail',
+      }),
+    ).toBe('H264 · 1920×1080');
+    expect(describeMedia(READY)).toBe('MP3 · 44.1 kHz');
+    expect(describeMedia({ ...READY, audio: { ...READY.audio!, sample_rate: 48000 } })).toBe(
+      'MP3 · 48 kHz',
+    );
+  });
+
+  it('has nothing to say until the info is ready', () => {
+    expect(describeMedia(undefined)).toBeNull();
+    expect(describeMedia({ status: 'pending' })).toBeNull();
+    expect(describeMedia({ status: 'failed', error: 'no streams' })).toBeNull();
+  });
+});
@@ -0,0 +1,27 @@
+import { describe, expect, it } from 'vitest';
+
+import type { Chapter } from '../../api/types';
+import { chapterIndexAt } from '../useOutline';
+
+const chapter = (start: number, end: number): Chapter => ({
+  id: start,
+  recording_id: 1,
+  start_time: start,
+  end_time: end,
+  title: `From ${start}`,
+  source: 'auto',
+  created_at: '2026-01-01T00:00:00Z',
+});
+
+describe('chapterIndexAt', () => {
+  const chapters = [chapter(5, 300), chapter(300, 720), chapter(720, 900)];
+
+  it('finds the chapter containing the playhead', () => {
+    expect(chapterIndexAt(chapters, 0)).toBe(-1);
+    expect(chapterIndexAt(chapters, 5)).toBe(0);
+    expect(chapterIndexAt(chapters, 299.9)).toBe(0);
+    expect(chapterIndexAt(chapters, 300)).toBe(1);
+    expect(chapterIndexAt(chapters, 5000)).toBe(2);
+    expect(chapterIndexAt([], 10)).toBe(-1);
+  });
+});
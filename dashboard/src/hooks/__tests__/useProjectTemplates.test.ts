@@ -0,0 +1,74 @@
+/**
+ * Project templates applied at import: pre-filled fields, what is stored,
+ * and how the template's options override the import tab's toggles.
+ */
+
+import { describe, expect, it } from 'vitest';
+
+import { applyTemplateOptions, filledMetadata, templateMetadata } from '../useProjectTemplates';
+import type { ProjectTemplate } from '../../api/types';
+
+const INTERVIEWS: ProjectTemplate = {
+  id: 1,
+  name: 'Client interviews',
+  description: null,
+  fields: [
+    { key: 'Client', label: 'Client' },
+    { key: 'Case', label: 'Case number' },
+  ],
+  defaults: { Client: 'Acme' },
+  options: { language: 'de', enable_diarization: true, expected_speakers: 2 },
+  created_at: '2026-01-01T00:00:00Z',
+  updated_at: '2026-01-01T00:00:00Z',
+};
+
+describe('templateMetadata', () => {
+  it('lists the template fields in order, pre-filled from its defaults', () => {
+    expect(templateMetadata(INTERVIEWS)).toEqual({ Client: 'Acme', Case: '' });
+    expect(templateMetadata(null)).toEqual({});
+  });
+
+  it('keeps values already typed when switching templates', () => {
+    expect(templateMetadata(INTERVIEWS, { Case: 'C-7', Location: 'Berlin' })).toEqual({
+      Client: 'Acme',
+      Case: 'C-7',
+    });
+  });
+});
+
+describe('filledMetadata', () => {
+  it('drops blank fields and trims the rest', () => {
+    expect(filledMetadata({ Client: ' Acme ', Case: '', ' ': 'x' })).toEqual({ Client: 'Acme' });
+  });
+});
+
+describe('applyTemplateOptions', () => {
+  it('overrides language, diarization and speakers; diarization forces timestamps', () => {
+    expect(
+      applyTemplateOptions(
+        { language: 'en', enable_diarization: false, enable_word_timestamps: false },
+        INTERVIEWS,
+      ),
+    ).toEqual({
+      language: 'de',
+      enable_diarization: true,
+      enable_word_timestamps: true,
+      expected_speakers: 2,
+    });
+  });
+
+  it('leaves unset options to the import tab', () => {
+    const options = { language: 'en', enable_diarization: true, parallel_diarization: true };
+    expect(applyTemplateOptions(options, { ...INTERVIEWS, options: {} })).toEqual(options);
+    expect(applyTemplateOptions(options, null)).toBe(options);
+  });
+
+  it('turning diarization off drops the parallel setting', () => {
+    expect(
+      applyTemplateOptions(
+        { enable_diarization: true, parallel_diarization: true },
+        { ...INTERVIEWS, options: { enable_diarization: false } },
+      ),
+    ).toEqual({ enable_diarization: false, parallel_diarization: undefined });
+  });
+});
@@ -0,0 +1,12 @@
+import { describe, expect, it } from 'vitest';
+
+import { formatDuration } from '../useSpeakerStats';
+
+describe('formatDuration', () => {
+  it('uses m:ss under an hour and h:mm:ss above', () => {
+    expect(formatDuration(0)).toBe('0:00');
+    expect(formatDuration(65.4)).toBe('1:05');
+    expect(formatDuration(3725)).toBe('1:02:05');
+    expect(formatDuration(-3)).toBe('0:00');
+  });
+});
@@ -0,0 +1,73 @@
+/**
+ * useAnnotations — threaded review notes for one recording.
+ *
+ * Mutations re-fetch the thread list afterwards (threads are small and the
+ * server decides reply anchoring), and re-throw so callers can toast.
+ */
+
+import { useCallback, useEffect, useState } from 'react';
+import { apiClient } from '../api/client';
+import type { AnnotationCreate, AnnotationThread } from '../api/types';
+
+export interface AnnotationsState {
+  threads: AnnotationThread[];
+  loading: boolean;
+  add: (payload: AnnotationCreate) => Promise<void>;
+  edit: (annotationId: number, body: string) => Promise<void>;
+  remove: (annotationId: number) => Promise<void>;
+  refresh: () => Promise<void>;
+}
+
+export function useAnnotations(recordingId: number | null): AnnotationsState {
+  const [threads, setThreads] = useState<AnnotationThread[]>([]);
+  const [loading, setLoading] = useState(false);
+
+  const refresh = useCallback(async () => {
+    if (recordingId === null || !Number.isFinite(recordingId) || recordingId <= 0) {
+      setThreads([]);
+      return;
+    }
+    setLoading(true);
+    try {
+      const resp = await apiClient.getAnnotations(recordingId);
+      setThreads(resp.threads ?? []);
+    } catch {
+      setThreads([]);
+    } finally {
+      setLoading(false);
+    }
+  }, [recordingId]);
+
+  useEffect(() => {
+    refresh();
+  }, [refresh]);
+
+  const add = useCallback(
+    async (payload: AnnotationCreate) => {
+      if (recordingId === null) return;
+      await apiClient.createAnnotation(recordingId, payload);
+      await refresh();
+    },
+    [recordingId, refresh],
+  );
+
+  const edit = useCallback(
+    async (annotationId: number, body: string) => {
+      if (recordingId === null) return;
+      await apiClient.updateAnnotation(recordingId, annotationId, body);
+      await refresh();
+    },
+    [recordingId, refresh],
+  );
+
+  const remove = useCallback(
+    async (annotationId: number) => {
+      if (recordingId === null) return;
+      await apiClient.deleteAnnotation(recordingId, annotationId);
+      await refresh();
+    },
+    [recordingId, refresh],
+  );
+
+  return { threads, loading, add, edit, remove, refresh };
+}
@@ -0,0 +1,55 @@
+/**
+ * useCaptionOverlay — drives the always-on-top caption window from the Live
+ * Mode transcript. Every sentence/partial change is forwarded to the main
+ * process, which renders it in the overlay window while shown
+ * (electron/captionOverlay.ts) and mirrors it to the enabled caption outputs
+ * — text file and OBS (electron/captionOutputs.ts).
+ */
+
+import { useCallback, useEffect, useState } from 'react';
+import type { LiveSentence, LiveStatus } from './useLiveMode';
+
+/** Sentences sent per update — the outputs show only the tail. */
+const SENTENCES_SENT = 3;
+
+export interface CaptionOverlayState {
+  /** False outside Electron (browser dev, tests without the bridge). */
+  available: boolean;
+  visible: boolean;
+  toggle: () => void;
+}
+
+export function useCaptionOverlay(
+  sentences: LiveSentence[],
+  partial: string,
+  status: LiveStatus,
+): CaptionOverlayState {
+  const api = window.electronAPI?.captions;
+  const [visible, setVisible] = useState(false);
+
+  useEffect(() => {
+    if (!api) return;
+    api
+      .isVisible()
+      .then(setVisible)
+      .catch(() => {});
+    return api.onVisibilityChanged(setVisible);
+  }, [api]);
+
+  useEffect(() => {
+    if (!api) return;
+    // An idle session clears the overlay instead of freezing the last line.
+    const active = status !== 'idle' && status !== 'error';
+    api.update({
+      lines: active ? sentences.slice(-SENTENCES_SENT).map((s) => s.text) : [],
+      partial: active ? partial : '',
+    });
+  }, [api, sentences, partial, status]);
+
+  const toggle = useCallback(() => {
+    if (!api) return;
+    void (visible ? api.hide() : api.show()).catch(() => {});
+  }, [api, visible]);
+
+  return { available: !!api, visible, toggle };
+}
@@ -1,3 +1,5 @@
+import { filterText, loadOutputFilter } from '../services/outputFilter';
+
 /**
  * Writes text to the clipboard, preferring Electron's native clipboard module
  * over navigator.clipboard. The native path works in Flatpak/AppImage sandboxes
@@ -10,3 +12,12 @@ export async function writeToClipboard(text: string): Promise<void> {
   }
   await navigator.clipboard.writeText(text);
 }
+
+/**
+ * Copies transcript text through the output filter (profanity masking and
+ * word replacements, services/outputFilter.ts). For transcripts only — logs,
+ * tokens and diagnostics go through writeToClipboard unchanged.
+ */
+export async function writeTranscriptToClipboard(text: string): Promise<void> {
+  await writeToClipboard(filterText(text, await loadOutputFilter()));
+}
@@ -0,0 +1,70 @@
+/**
+ * useConnectionQuality — latest report from the main-process connection
+ * quality monitor (electron/connectionQuality.ts), for the link badge.
+ *
+ * useConnectionFailover acts on the monitor's `suggestSwitchTo`: with
+ * `connection.autoFailover` on, it switches the remote profile (Tailscale ↔
+ * LAN) and re-syncs the API client, whose config-changed listeners move the
+ * live and transcription sockets to the new host. Mount it exactly once at
+ * the app root, or a single suggestion would be acted on per subscriber.
+ */
+
+import { useEffect, useState } from 'react';
+import { toast } from 'sonner';
+import { apiClient } from '../api/client';
+import { getConfig, setConfig } from '../config/store';
+
+const PROFILE_LABELS: Record<ConnectionQualityProfile, string> = {
+  local: 'local server',
+  tailscale: 'Tailscale',
+  lan: 'LAN',
+};
+
+export function useConnectionQuality(): ConnectionQualityReport | null {
+  const [report, setReport] = useState<ConnectionQualityReport | null>(null);
+
+  useEffect(() => {
+    const api = window.electronAPI?.connectionQuality;
+    if (!api) return;
+    let cancelled = false;
+    api
+      .getLatest()
+      .then((latest) => {
+        if (!cancelled && latest) setReport(latest);
+      })
+      .catch(() => {});
+    const unsubscribe = api.onReport(setReport);
+    return () => {
+      cancelled = true;
+      unsubscribe();
+    };
+  }, []);
+
+  return report;
+}
+
+export function useConnectionFailover(): void {
+  useEffect(() => {
+    const api = window.electronAPI?.connectionQuality;
+    if (!api) return;
+    let switching = false;
+    return api.onReport(async (report) => {
+      const target = report.suggestSwitchTo;
+      if (!target || target === 'local' || switching) return;
+      if (!(await getConfig<boolean>('connection.autoFailover'))) return;
+      switching = true;
+      try {
+        await setConfig('connection.remoteProfile', target);
+        await apiClient.syncFromConfig();
+        toast.info(
+          `Switched to ${PROFILE_LABELS[target]} — the ${PROFILE_LABELS[report.profile]} link was ${report.quality}.`,
+        );
+        await api.checkNow();
+      } catch (err) {
+        console.warn('[ConnectionFailover] switch failed:', err);
+      } finally {
+        switching = false;
+      }
+    });
+  }, []);
+}
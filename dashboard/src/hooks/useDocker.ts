@@ -24,7 +24,7 @@ export interface RemoteTag {
  * a projection of the runtime profile + `server.useLegacyGpu`, not a new
  * persisted setting.
  */
-export type ImageVariant = 'cuda' | 'cuda-legacy' | 'vulkan-wsl2' | 'vulkan-linux';
+export type ImageVariant = 'cuda' | 'cuda-legacy' | 'vulkan-wsl2' | 'vulkan-linux' | 'rocm' | 'xpu';
 
 /**
  * Version-tag lists per image variant from GHCR. An empty array means the
@@ -0,0 +1,57 @@
+/**
+ * useFollowAlong — shares the Live Mode transcript with meeting participants
+ * through the read-only follow-along page (electron/followAlong.ts).
+ *
+ * While a share is running every sentence/partial change is forwarded to the
+ * main process, which streams only the difference to connected browsers.
+ * Unlike the caption overlay, the whole transcript is sent so late joiners
+ * can scroll back, and it is kept when the live session goes idle.
+ */
+
+import { useCallback, useEffect, useState } from 'react';
+import type { LiveSentence } from './useLiveMode';
+
+export interface FollowAlongState {
+  /** False outside Electron (browser dev, tests without the bridge). */
+  available: boolean;
+  status: FollowAlongStatus | null;
+  start: () => Promise<void>;
+  stop: () => Promise<void>;
+  /** Re-read the status, e.g. to refresh the viewer count. */
+  refresh: () => void;
+}
+
+export function useFollowAlong(sentences: LiveSentence[], partial: string): FollowAlongState {
+  const api = window.electronAPI?.followAlong;
+  const [status, setStatus] = useState<FollowAlongStatus | null>(null);
+
+  const refresh = useCallback(() => {
+    if (!api) return;
+    api
+      .getStatus()
+      .then(setStatus)
+      .catch(() => {});
+  }, [api]);
+
+  useEffect(refresh, [refresh]);
+
+  const running = status?.running ?? false;
+
+  useEffect(() => {
+    if (!api || !running) return;
+    api.update({ sentences: sentences.map((s) => s.text), partial });
+  }, [api, running, sentences, partial]);
+
+  const start = useCallback(async () => {
+    if (!api) return;
+    setStatus(await api.start());
+  }, [api]);
+
+  const stop = useCallback(async () => {
+    if (!api) return;
+    await api.stop();
+    refresh();
+  }, [api, refresh]);
+
+  return { available: !!api, status, start, stop, refresh };
+}
@@ -0,0 +1,85 @@
+/**
+ * useHidPedal — drives an `<audio>` element from bound foot-pedal buttons
+ * (see services/hidPedal.ts for the binding model).
+ *
+ * - `play_pause` toggles on press.
+ * - `play_while_held` plays while the pedal is down; on release it pauses
+ *   and steps back `hidPedal.backstepSeconds`, the transcription-pedal
+ *   convention so the last words are heard again on the next press.
+ * - `rewind` / `forward` seek by `hidPedal.rewindSeconds` on each press.
+ *
+ * Reads the element directly in the report handler, so the subscription is
+ * made once per enable and never goes stale.
+ */
+
+import { useEffect, type RefObject } from 'react';
+import { getConfig } from '../config/store';
+import {
+  deviceKey,
+  diffButtons,
+  loadHidBindings,
+  subscribeHidReports,
+  type HidDeviceLike,
+} from '../services/hidPedal';
+
+const DEFAULT_REWIND_SECONDS = 5;
+const DEFAULT_BACKSTEP_SECONDS = 1;
+
+export function useHidPedal(audioRef: RefObject<HTMLAudioElement | null>, enabled: boolean): void {
+  useEffect(() => {
+    if (!enabled) return;
+    let cancelled = false;
+    let unsubscribe: () => void = () => {};
+
+    void Promise.all([
+      loadHidBindings(),
+      getConfig<number>('hidPedal.rewindSeconds'),
+      getConfig<number>('hidPedal.backstepSeconds'),
+    ]).then(([bindings, rewind, backstep]) => {
+      if (cancelled || bindings.length === 0) return;
+      const rewindSeconds = rewind ?? DEFAULT_REWIND_SECONDS;
+      const backstepSeconds = backstep ?? DEFAULT_BACKSTEP_SECONDS;
+      const last = new Map<HidDeviceLike, number>();
+
+      const seekBy = (audio: HTMLAudioElement, delta: number) => {
+        const end = Number.isFinite(audio.duration) ? audio.duration : Infinity;
+        audio.currentTime = Math.min(Math.max(0, audio.currentTime + delta), end);
+      };
+
+      unsubscribe = subscribeHidReports((device, mask) => {
+        const audio = audioRef.current;
+        const { pressed, released } = diffButtons(last.get(device) ?? 0, mask);
+        last.set(device, mask);
+        if (!audio) return;
+        const key = deviceKey(device);
+        const actionFor = (button: number) =>
+          bindings.find((b) => b.deviceKey === key && b.button === button)?.action;
+
+        for (const button of pressed) {
+          const action = actionFor(button);
+          if (action === 'play_pause') {
+            if (audio.paused) void audio.play().catch(() => {});
+            else audio.pause();
+          } else if (action === 'play_while_held') {
+            void audio.play().catch(() => {});
+          } else if (action === 'rewind') {
+            seekBy(audio, -rewindSeconds);
+          } else if (action === 'forward') {
+            seekBy(audio, rewindSeconds);
+          }
+        }
+        for (const button of released) {
+          if (actionFor(button) === 'play_while_held') {
+            audio.pause();
+            seekBy(audio, -backstepSeconds);
+          }
+        }
+      });
+    });
+
+    return () => {
+      cancelled = true;
+      unsubscribe();
+    };
+  }, [audioRef, enabled]);
+}
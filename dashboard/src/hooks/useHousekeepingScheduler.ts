@@ -0,0 +1,100 @@
+/**
+ * useHousekeepingScheduler — runs the nightly server housekeeping configured
+ * in Settings → Server (services/housekeeping.ts) and reports each run in
+ * the notification center.
+ *
+ * Checks once a minute; a run starts at the first check inside the window
+ * that finds the server idle, so a long transcription just pushes it later
+ * in the window. `housekeeping.lastRunDate` is written before the tasks
+ * start so a reload mid-run cannot repeat it. Mount exactly once at the app
+ * root.
+ */
+
+import { useEffect } from 'react';
+import { apiClient } from '../api/client';
+import { getConfig, getHousekeeping, setConfig } from '../config/store';
+import {
+  TASK_LABELS,
+  dueWindow,
+  runHousekeeping,
+  type HousekeepingActions,
+  type HousekeepingResult,
+  type HousekeepingSettings,
+} from '../services/housekeeping';
+import { useNotificationsStore } from '../stores/notificationsStore';
+
+const CHECK_INTERVAL_MS = 60_000;
+const NOTIFICATION_ID = 'server-housekeeping';
+
+async function serverIsIdle(): Promise<boolean> {
+  try {
+    const queue = await apiClient.getServerQueue();
+    return queue.active === null && queue.entries.length === 0;
+  } catch {
+    return false; // unreachable or too old for housekeeping — try again later
+  }
+}
+
+/** The local Docker container, when this client runs one and it is up. */
+async function localContainerRestart(): Promise<HousekeepingActions['restartContainer']> {
+  const docker = window.electronAPI?.docker;
+  if (!docker?.restartContainer || (await getConfig<boolean>('connection.useRemote'))) {
+    return null;
+  }
+  const status = await docker.getContainerStatus().catch(() => null);
+  return status?.running ? () => docker.restartContainer() : null;
+}
+
+function summarize(results: HousekeepingResult[]): string {
+  return results.map((r) => `${TASK_LABELS[r.task]}: ${r.detail}`).join('\n');
+}
+
+/** Run the enabled tasks now and record the outcome in the notification center. */
+export async function runHousekeepingNow(settings: HousekeepingSettings): Promise<void> {
+  const { notify } = useNotificationsStore.getState();
+  notify({
+    id: NOTIFICATION_ID,
+    category: 'server',
+    title: 'Server housekeeping running...',
+    status: 'active',
+  });
+  const results = await runHousekeeping(settings, {
+    rotateLogs: () => apiClient.rotateServerLogs(),
+    pruneModelCache: (days) => apiClient.pruneModelCache(days),
+    goldenCheck: () => apiClient.runGoldenCheck(),
+    restartContainer: settings.restartContainer ? await localContainerRestart() : null,
+  });
+  const failed = results.filter((r) => r.outcome === 'failed');
+  notify({
+    id: NOTIFICATION_ID,
+    category: 'server',
+    title: failed.length ? 'Server housekeeping finished with errors' : 'Server housekeeping done',
+    detail: summarize(results),
+    status: failed.length ? 'error' : 'complete',
+    error: failed.length ? failed.map((r) => TASK_LABELS[r.task]).join(', ') : undefined,
+  });
+}
+
+export function useHousekeepingScheduler(): void {
+  useEffect(() => {
+    let running = false;
+    const check = async () => {
+      if (running) return;
+      const settings = await getHousekeeping();
+      const opened = dueWindow(settings, new Date());
+      if (!opened || !(await serverIsIdle())) return;
+      running = true;
+      try {
+        await setConfig('housekeeping.lastRunDate', opened);
+        await runHousekeepingNow(settings);
+      } catch (err) {
+        console.warn('[Housekeeping] run failed:', err);
+      } finally {
+        running = false;
+      }
+    };
+    const timer = setInterval(() => void check(), CHECK_INTERVAL_MS);
+    void check();
+    return () => clearInterval(timer);
+  }, []);
+}
@@ -0,0 +1,32 @@
+/**
+ * useKeymap — the app-scope keymap from the main-process shortcut registry,
+ * kept current when a binding is changed or a keymap imported.
+ */
+
+import { useEffect, useState } from 'react';
+import { FALLBACK_APP_KEYMAP, toAppKeymap, type AppKeymap } from '../utils/keymap';
+
+export function useKeymap(): AppKeymap {
+  const [keymap, setKeymap] = useState<AppKeymap>(FALLBACK_APP_KEYMAP);
+
+  useEffect(() => {
+    const shortcuts = window.electronAPI?.shortcuts;
+    if (!shortcuts?.getKeymap) return;
+    let cancelled = false;
+    shortcuts
+      .getKeymap()
+      .then((entries) => {
+        if (!cancelled) setKeymap(toAppKeymap(entries));
+      })
+      .catch(() => {
+        // Keep the fallback keymap; shortcuts still work with their defaults.
+      });
+    const unsubscribe = shortcuts.onKeymapChanged((entries) => setKeymap(toAppKeymap(entries)));
+    return () => {
+      cancelled = true;
+      unsubscribe();
+    };
+  }, []);
+
+  return keymap;
+}
@@ -0,0 +1,82 @@
+/**
+ * useKeywordAlerts — desktop notifications for Live Mode keyword hits.
+ *
+ * The server matches the watch list (server/backend/core/keyword_alerts.py);
+ * each new hit becomes an OS notification whose click payload points back at
+ * the sentence, so clicking it brings the window forward and `onJump`
+ * scrolls the live transcript there.
+ */
+
+import { useEffect, useRef } from 'react';
+import type { LiveKeywordAlert } from './useLiveMode';
+
+export interface KeywordAlertClick {
+  kind: 'liveKeyword';
+  sentenceIndex: number;
+  at: number;
+}
+
+const BODY_MAX_CHARS = 140;
+
+/** "1:05" / "1:02:05" — session time of the hit. */
+export function formatSessionTime(seconds: number): string {
+  const total = Math.max(0, Math.floor(seconds));
+  const h = Math.floor(total / 3600);
+  const m = Math.floor((total % 3600) / 60);
+  const s = String(total % 60).padStart(2, '0');
+  return h > 0 ? `${h}:${String(m).padStart(2, '0')}:${s}` : `${m}:${s}`;
+}
+
+export function alertNotification(alert: LiveKeywordAlert) {
+  const text =
+    alert.text.length > BODY_MAX_CHARS ? `${alert.text.slice(0, BODY_MAX_CHARS)}…` : alert.text;
+  const clickPayload: KeywordAlertClick = {
+    kind: 'liveKeyword',
+    sentenceIndex: alert.sentenceIndex,
+    at: alert.at,
+  };
+  return {
+    title: `"${alert.match || alert.keyword}" mentioned at ${formatSessionTime(alert.at)}`,
+    body: text,
+    silent: false,
+    clickPayload,
+  };
+}
+
+function isAlertClick(payload: unknown): payload is KeywordAlertClick {
+  const p = payload as KeywordAlertClick | null;
+  return (
+    typeof p === 'object' &&
+    p !== null &&
+    p.kind === 'liveKeyword' &&
+    Number.isInteger(p.sentenceIndex) &&
+    p.sentenceIndex >= 0
+  );
+}
+
+export function useKeywordAlerts(
+  alerts: LiveKeywordAlert[],
+  onJump: (sentenceIndex: number) => void,
+): void {
+  const notifiedRef = useRef(0);
+  const onJumpRef = useRef(onJump);
+  onJumpRef.current = onJump;
+
+  useEffect(() => {
+    // A new session (or cleared history) starts the list over.
+    if (alerts.length < notifiedRef.current) notifiedRef.current = 0;
+    const api = window.electronAPI?.notifications;
+    for (const alert of alerts.slice(notifiedRef.current)) {
+      void api?.show(alertNotification(alert)).catch(() => false);
+    }
+    notifiedRef.current = alerts.length;
+  }, [alerts]);
+
+  useEffect(
+    () =>
+      window.electronAPI?.notifications?.onClicked?.((payload) => {
+        if (isAlertClick(payload)) onJumpRef.current(payload.sentenceIndex);
+      }),
+    [],
+  );
+}
@@ -0,0 +1,49 @@
+/**
+ * useLanguageDetection — preview the spoken language of a file before import.
+ *
+ * Uploads only the head of the file (see `utils/languagePreview`). Disabled
+ * while a language is chosen explicitly or the model can't auto-detect.
+ * Failures (busy server, undecodable prefix) are non-fatal — no preview.
+ */
+
+import { useEffect, useState } from 'react';
+import { apiClient } from '../api/client';
+import type { LanguageDetectionResult } from '../api/types';
+import { previewSample, PREVIEW_SECONDS } from '../utils/languagePreview';
+
+export interface LanguageDetectionState {
+  result: LanguageDetectionResult | null;
+  loading: boolean;
+}
+
+export function useLanguageDetection(file: File | null, enabled: boolean): LanguageDetectionState {
+  const [result, setResult] = useState<LanguageDetectionResult | null>(null);
+  const [loading, setLoading] = useState(false);
+
+  useEffect(() => {
+    setResult(null);
+    if (!file || !enabled) return;
+    let cancelled = false;
+    setLoading(true);
+    (async () => {
+      try {
+        const resp = await apiClient.detectLanguage(
+          previewSample(file),
+          file.name,
+          PREVIEW_SECONDS,
+        );
+        if (!cancelled) setResult(resp);
+      } catch {
+        if (!cancelled) setResult(null);
+      } finally {
+        if (!cancelled) setLoading(false);
+      }
+    })();
+    return () => {
+      cancelled = true;
+      setLoading(false);
+    };
+  }, [file, enabled]);
+
+  return { result, loading };
+}
@@ -0,0 +1,68 @@
+/**
+ * Live config reload on the renderer side.
+ *
+ * Main publishes every written key on its config bus (electron/configBus.ts)
+ * and forwards them here as `config:changed` — including writes made by
+ * another window or by main itself, which this window would otherwise only
+ * pick up on restart.
+ *
+ * - `useConfigChanged(prefixes, callback)` calls back when a matching key changes.
+ * - `useLiveConfigReload()` is the singleton (mount once at the app root) that
+ *   re-syncs the API client after connection or identity changes, and toasts
+ *   when a main-process subsystem failed to apply a change.
+ */
+
+import { useEffect, useRef } from 'react';
+import { toast } from 'sonner';
+import { apiClient } from '../api/client';
+import { getAuthToken } from '../config/store';
+
+// A settings form saves key by key, each its own IPC round trip; wait for the
+// burst to end before reconnecting.
+const SYNC_DEBOUNCE_MS = 300;
+
+export function useConfigChanged(prefixes: string[], callback: (keys: string[]) => void): void {
+  const callbackRef = useRef(callback);
+  callbackRef.current = callback;
+  const prefixKey = prefixes.join('\n');
+
+  useEffect(() => {
+    const onChanged = window.electronAPI?.config?.onChanged;
+    if (!onChanged) return;
+    const watched = prefixKey.split('\n');
+    return onChanged((keys) => {
+      const relevant = keys.filter((key) => watched.some((prefix) => key.startsWith(prefix)));
+      if (relevant.length > 0) callbackRef.current(relevant);
+    });
+  }, [prefixKey]);
+}
+
+export function useLiveConfigReload(): void {
+  const timerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
+  const tokenChangedRef = useRef(false);
+
+  useConfigChanged(['connection.', 'server.', 'identity.'], (keys) => {
+    if (timerRef.current) clearTimeout(timerRef.current);
+    if (keys.includes('connection.authToken')) tokenChangedRef.current = true;
+    timerRef.current = setTimeout(() => {
+      timerRef.current = null;
+      const tokenChanged = tokenChangedRef.current;
+      tokenChangedRef.current = false;
+      void (async () => {
+        if (tokenChanged) apiClient.setAuthToken(await getAuthToken());
+        await apiClient.syncFromConfig();
+      })();
+    }, SYNC_DEBOUNCE_MS);
+  });
+
+  useEffect(() => {
+    const onReloadFailed = window.electronAPI?.config?.onReloadFailed;
+    const unsubscribe = onReloadFailed?.(({ subscriber, message }) =>
+      toast.error(`Couldn't apply changed settings (${subscriber}): ${message}`),
+    );
+    return () => {
+      unsubscribe?.();
+      if (timerRef.current) clearTimeout(timerRef.current);
+    };
+  }, []);
+}
@@ -49,9 +49,11 @@ let lastCapture: {
   analyser: null;
   isCapturing: boolean;
 };
+let lastCaptureOnChunk: (chunk: Int16Array) => void;
 
 vi.mock('../services/audioCapture', () => ({
-  AudioCapture: vi.fn().mockImplementation(function () {
+  AudioCapture: vi.fn().mockImplementation(function (onChunk: (chunk: Int16Array) => void) {
+    lastCaptureOnChunk = onChunk;
     lastCapture = {
       start: vi.fn().mockResolvedValue(undefined),
       stop: vi.fn(),
@@ -132,6 +134,7 @@ describe('[P1] useLiveMode', () => {
             config: expect.objectContaining({
               language: 'en',
               model: 'base',
+              segment_deltas: true,
             }),
           }),
         }),
@@ -356,6 +359,45 @@ describe('[P1] useLiveMode', () => {
       expect(result.current.sentences[0].text).toBe('Hello world.');
     });
 
+    it('applies segment deltas and resyncs after a seq gap', async () => {
+      const { result } = renderHook(() => useLiveMode());
+      await driveToListening(result);
+      const segment = (data: Record<string, unknown>) =>
+        act(() => {
+          lastSocketCbs.onMessage!({ type: 'segment', data });
+        });
+
+      segment({ seq: 1, op: 'insert', id: 1, text: 'Hello' });
+      segment({ seq: 2, op: 'update', id: 1, keep: 5, text: ' wor' });
+      expect(result.current.partial).toBe('Hello wor');
+      segment({ seq: 3, op: 'finalize', id: 1, keep: 9, text: 'ld.' });
+      expect(result.current.sentences.map((s) => s.text)).toEqual(['Hello world.']);
+      expect(result.current.partial).toBe('');
+
+      // seq 4 and 5 are lost: ask for what followed the last sentence.
+      segment({ seq: 6, op: 'update', id: 2, keep: 3, text: 'x' });
+      expect(lastSocket.sendJSON).toHaveBeenLastCalledWith({
+        type: 'get_segments',
+        data: { since_id: 1 },
+      });
+      act(() => {
+        lastSocketCbs.onMessage!({
+          type: 'segments',
+          data: {
+            seq: 6,
+            segments: [
+              { id: 2, text: 'Second.', final: true },
+              { id: 3, text: 'Thi', final: false },
+            ],
+          },
+        });
+      });
+      segment({ seq: 7, op: 'update', id: 3, keep: 3, text: 'rd' });
+
+      expect(result.current.sentences.map((s) => s.text)).toEqual(['Hello world.', 'Second.']);
+      expect(result.current.partial).toBe('Third');
+    });
+
     it('restores history from server', async () => {
       const { result } = renderHook(() => useLiveMode());
       await driveToListening(result);
@@ -479,6 +521,74 @@ describe('[P1] useLiveMode', () => {
 
       expect(result.current.sentences).toEqual([]);
     });
+
+    it('records keyword alerts against the sentence that matched', async () => {
+      const { result } = renderHook(() => useLiveMode());
+      await driveToListening(result);
+
+      act(() => {
+        lastSocketCbs.onMessage!({ type: 'sentence', data: { text: 'Hello.' } });
+        lastSocketCbs.onMessage!({ type: 'sentence', data: { text: 'New action item.' } });
+        lastSocketCbs.onMessage!({
+          type: 'keyword',
+          data: {
+            keyword: 'action item',
+            match: 'action item',
+            text: 'New action item.',
+            at: 12.5,
+          },
+        });
+      });
+
+      expect(result.current.alerts).toEqual([
+        {
+          keyword: 'action item',
+          match: 'action item',
+          text: 'New action item.',
+          sentenceIndex: 1,
+          at: 12.5,
+        },
+      ]);
+
+      act(() => {
+        lastSocketCbs.onMessage!({ type: 'history_cleared' });
+      });
+      expect(result.current.alerts).toEqual([]);
+    });
+
+    it('starts in the chosen streaming mode and switches without restarting', async () => {
+      const { result } = renderHook(() => useLiveMode());
+      act(() => {
+        result.current.start({ streamingMode: 'fast' });
+      });
+      act(() => {
+        lastSocketCbs.onMessage!({ type: 'auth_ok' });
+      });
+      expect(lastSocket.sendJSON).toHaveBeenCalledWith(
+        expect.objectContaining({
+          type: 'start',
+          data: { config: expect.objectContaining({ streaming_mode: 'fast' }) },
+        }),
+      );
+      await act(async () => {
+        lastSocketCbs.onMessage!({ type: 'state', data: { state: 'LISTENING' } });
+      });
+
+      act(() => {
+        result.current.setStreamingMode('accurate');
+      });
+      expect(lastSocket.sendJSON).toHaveBeenLastCalledWith({
+        type: 'set_streaming_mode',
+        data: { mode: 'accurate' },
+      });
+      expect(result.current.streamingMode).toBe('accurate');
+      expect(result.current.status).toBe('listening');
+
+      act(() => {
+        lastSocketCbs.onMessage!({ type: 'streaming_mode', data: { mode: 'fast' } });
+      });
+      expect(result.current.streamingMode).toBe('fast');
+    });
   });
 
   // ── config-changed forwarding: the hook no longer branches on socket state
@@ -677,8 +787,8 @@ describe('[P1] useLiveMode', () => {
 
   // ── GH-237: WS reconnect must not resurrect a dead live session ───────
   //
-  // Same root cause as the longform hook: the server cannot resume a dropped
-  // session, so re-sending `start` on an auto-reconnect used to silently
+  // Same root cause as the longform hook: without a resume token the server
+  // cannot pick up a dropped session, so re-sending `start` on an auto-reconnect used to silently
   // resurrect the session in the background (reconnect → start → LISTENING
   // flipped the UI back to active after it had already gone idle).
 
@@ -776,4 +886,94 @@ describe('[P1] useLiveMode', () => {
       expect(result.current.status).toBe('listening');
     });
   });
+
+  // ── Session resumption (server/backend/core/live_resume.py) ───────────
+
+  describe('session resumption', () => {
+    async function driveToResumable(result: { current: ReturnType<typeof useLiveMode> }) {
+      await driveToListening(result);
+      act(() => {
+        lastSocketCbs.onMessage!({ type: 'session', data: { token: 'tok' } });
+      });
+      lastSocket.disconnect.mockClear();
+      lastCapture.stop.mockClear();
+    }
+
+    function drop() {
+      act(() => {
+        lastSocketCbs.onError!('WebSocket connection error');
+        lastSocketCbs.onClose!(1006, '');
+      });
+    }
+
+    it('keeps capturing through a drop and resumes with the session token', async () => {
+      const { result } = renderHook(() => useLiveMode());
+      await driveToResumable(result);
+
+      drop();
+      expect(result.current.status).toBe('reconnecting');
+      expect(result.current.error).toBeNull();
+      expect(lastCapture.stop).not.toHaveBeenCalled();
+      expect(lastSocket.disconnect).not.toHaveBeenCalled();
+
+      act(() => {
+        lastSocketCbs.onMessage!({ type: 'auth_ok' });
+      });
+      expect(lastSocket.sendJSON).toHaveBeenLastCalledWith({
+        type: 'resume',
+        data: { token: 'tok' },
+      });
+    });
+
+    it('replays the audio the engine missed and refetches the transcript', async () => {
+      const { result } = renderHook(() => useLiveMode());
+      await driveToResumable(result);
+      const chunks = [new Int16Array(4), new Int16Array(4), new Int16Array(4)];
+
+      act(() => {
+        chunks.forEach((chunk) => lastCaptureOnChunk(chunk));
+        lastSocketCbs.onMessage!({ type: 'audio_ack', data: { seq: 1 } });
+      });
+      expect(lastSocket.sendAudio.mock.calls).toEqual([
+        [chunks[0], 1],
+        [chunks[1], 2],
+        [chunks[2], 3],
+      ]);
+
+      drop();
+      act(() => {
+        lastSocketCbs.onMessage!({ type: 'auth_ok' });
+      });
+      lastSocket.sendAudio.mockClear();
+      act(() => {
+        lastSocketCbs.onMessage!({ type: 'resumed', data: { audio_seq: 2 } });
+      });
+
+      expect(lastSocket.sendAudio.mock.calls).toEqual([[chunks[2], 3]]);
+      expect(lastSocket.sendJSON).toHaveBeenCalledWith({
+        type: 'get_segments',
+        data: { since_id: 0 },
+      });
+      expect(result.current.status).toBe('listening');
+    });
+
+    it('fails loudly when the server no longer has the session', async () => {
+      const { result } = renderHook(() => useLiveMode());
+      await driveToResumable(result);
+
+      drop();
+      act(() => {
+        lastSocketCbs.onMessage!({ type: 'auth_ok' });
+        lastSocketCbs.onMessage!({
+          type: 'resume_failed',
+          data: { message: 'The live session ended and cannot be resumed' },
+        });
+      });
+
+      expect(result.current.status).toBe('error');
+      expect(result.current.error).toMatch(/cannot be resumed/);
+      expect(lastCapture.stop).toHaveBeenCalled();
+      expect(lastSocket.disconnect).toHaveBeenCalledTimes(1);
+    });
+  });
 });
@@ -2,13 +2,29 @@
  * useLiveMode — orchestrates continuous live transcription via /ws/live.
  *
  * Flow: connect → auth → start → stream audio → receive partial/sentence in real-time.
- * Sentences accumulate; the latest partial is shown as in-progress text.
+ * Sentences accumulate; the latest partial is shown as in-progress text. Both
+ * arrive as numbered segment deltas (services/liveSegments.ts). The streaming
+ * mode — `fast` partials for captions, `accurate` finals for notes — can be
+ * switched while the session runs (server/backend/core/live_engine.py).
+ *
+ * A dropped connection does not end the session: capture keeps buffering
+ * (services/audioReplay.ts), the socket reconnects and resumes with the
+ * server's session token, and the audio the server never acknowledged is
+ * sent again (server/backend/core/live_resume.py).
  */
 
 import { useState, useCallback, useRef, useEffect } from 'react';
 import { apiClient } from '../api/client';
 import { TranscriptionSocket, ServerMessage } from '../services/websocket';
 import { AudioCapture } from '../services/audioCapture';
+import { AudioReplayBuffer } from '../services/audioReplay';
+import {
+  LiveSegmentTracker,
+  type SegmentChange,
+  type SegmentDelta,
+  type SegmentSnapshot,
+} from '../services/liveSegments';
+import { useAriaAnnouncerStore, type AriaPoliteness } from '../stores/ariaAnnouncerStore';
 
 export type LiveStatus =
   | 'idle'
@@ -16,19 +32,38 @@ export type LiveStatus =
   | 'starting' // model swap in progress
   | 'listening'
   | 'processing'
+  | 'reconnecting' // connection lost; resuming the session
   | 'error';
 
+/** `fast`: greedy partials and quick finals (captions); `accurate`: beam-search finals (notes). */
+export type LiveStreamingMode = 'fast' | 'accurate';
+
 export interface LiveSentence {
   text: string;
   timestamp: number; // Date.now() when received
 }
 
+/** A watch keyword the server matched in a finished sentence. */
+export interface LiveKeywordAlert {
+  /** The watch entry as the user wrote it. */
+  keyword: string;
+  /** The matched words in the sentence's own spelling. */
+  match: string;
+  text: string;
+  /** Index into `sentences` of the sentence that matched. */
+  sentenceIndex: number;
+  /** Seconds since the session started. */
+  at: number;
+}
+
 export interface LiveModeState {
   status: LiveStatus;
   /** Completed sentences accumulated during this session */
   sentences: LiveSentence[];
   /** Current partial (in-progress) text */
   partial: string;
+  /** Keyword hits this session, oldest first */
+  alerts: LiveKeywordAlert[];
   /** Status message during model loading */
   statusMessage: string | null;
   error: string | null;
@@ -36,6 +71,7 @@ export interface LiveModeState {
   analyser: AnalyserNode | null;
   /** Whether audio is muted (capture continues but chunks not sent) */
   muted: boolean;
+  streamingMode: LiveStreamingMode;
   /** Start live mode */
   start: (options?: LiveStartOptions) => void;
   /** Stop live mode (reloads main model on server) */
@@ -44,6 +80,8 @@ export interface LiveModeState {
   toggleMute: () => void;
   /** Set capture gain (amplification). Values >1 boost quiet sources. */
   setGain: (value: number) => void;
+  /** Switch streaming mode; a running session switches without restarting. */
+  setStreamingMode: (mode: LiveStreamingMode) => void;
   /** Clear accumulated sentences */
   clearHistory: () => void;
   /** Copy all sentences as text */
@@ -67,16 +105,29 @@ export interface LiveStartOptions {
    * dockerManager.switchWhisperServerModel.
    */
   whisperServerModel?: string;
+  /** Keywords the server watches for (see server/backend/core/keyword_alerts.py). */
+  watchKeywords?: string[];
+  streamingMode?: LiveStreamingMode;
 }
 
 export function useLiveMode(): LiveModeState {
   const [status, setStatus] = useState<LiveStatus>('idle');
   const [sentences, setSentences] = useState<LiveSentence[]>([]);
   const [partial, setPartial] = useState('');
+  const [alerts, setAlerts] = useState<LiveKeywordAlert[]>([]);
+  // Sentence count mirror: a `keyword` message follows its `sentence`, so the
+  // matching sentence is the last one received.
+  const sentenceCountRef = useRef(0);
+  const segmentsRef = useRef(new LiveSegmentTracker());
+  // Resumption: the server's token for this session, and every captured
+  // chunk it has not acknowledged yet.
+  const resumeTokenRef = useRef<string | null>(null);
+  const replayRef = useRef(new AudioReplayBuffer());
   const [statusMessage, setStatusMessage] = useState<string | null>(null);
   const [error, setError] = useState<string | null>(null);
   const [analyser, setAnalyser] = useState<AnalyserNode | null>(null);
   const [muted, setMuted] = useState(false);
+  const [streamingMode, setStreamingModeState] = useState<LiveStreamingMode>('accurate');
 
   const socketRef = useRef<TranscriptionSocket | null>(null);
   const captureRef = useRef<AudioCapture | null>(null);
@@ -94,8 +145,8 @@ export function useLiveMode(): LiveModeState {
     setStatus(s);
   }, []);
   // GH-237: gate the `start` re-send across auto-reconnects (same rationale as
-  // useTranscription). The server cannot resume a dropped live session, so
-  // re-sending `start` on reconnect resurrects it in the background. Flipped
+  // useTranscription). Re-sending `start` on reconnect would resurrect a
+  // session in the background; a reconnect continues one only via `resume`. Flipped
   // true on the first `state` message (engine acknowledged our start); stays
   // true across a server STOPPED + reconnect so nothing resurrects; reopened
   // only by a user-initiated start()/stop().
@@ -119,10 +170,38 @@ export function useLiveMode(): LiveModeState {
     });
   }, []);
 
+  const applySegmentChange = useCallback((change: SegmentChange) => {
+    if (change.kind === 'partial') {
+      setPartial(change.text);
+    } else if (change.kind === 'final') {
+      if (change.sentences.length > 0) {
+        const timestamp = Date.now();
+        sentenceCountRef.current += change.sentences.length;
+        setSentences((prev) => [
+          ...prev,
+          ...change.sentences.map((text) => ({ text, timestamp })),
+        ]);
+      }
+      setPartial(change.partial);
+    } else if (change.kind === 'resync') {
+      socketRef.current?.sendJSON({ type: 'get_segments', data: { since_id: change.sinceId } });
+    }
+  }, []);
+
   const handleMessage = useCallback(
     (msg: ServerMessage) => {
       switch (msg.type) {
         case 'auth_ok':
+          // A dropped session the server kept for us: pick it up where it was.
+          if (sessionEstablishedRef.current && resumeTokenRef.current) {
+            socketRef.current?.sendJSON({
+              type: 'resume',
+              data: { token: resumeTokenRef.current },
+            });
+            break;
+          }
+          // Every other connection is a fresh server session with its own seq.
+          segmentsRef.current.reset();
           // GH-237: only send `start` on the FIRST connect of a user session.
           // After the engine has acknowledged us, an auth_ok is an auto-reconnect
           // and re-sending `start` would resurrect the session (see
@@ -139,6 +218,9 @@ export function useLiveMode(): LiveModeState {
                 translation_enabled: startOptsRef.current.translate ?? false,
                 translation_target_language: startOptsRef.current.translationTarget ?? 'en',
                 post_speech_silence_duration: startOptsRef.current.gracePeriodSeconds,
+                watch_keywords: startOptsRef.current.watchKeywords ?? [],
+                streaming_mode: startOptsRef.current.streamingMode ?? 'accurate',
+                segment_deltas: true,
               },
             },
           });
@@ -163,7 +245,8 @@ export function useLiveMode(): LiveModeState {
             if (!captureRef.current?.isCapturing) {
               captureRef.current?.stop();
               captureRef.current = new AudioCapture((chunk) => {
-                socketRef.current?.sendAudio(chunk);
+                // Numbered and kept until acknowledged, so a drop loses nothing.
+                socketRef.current?.sendAudio(chunk, replayRef.current.push(chunk));
               });
               captureRef.current
                 .start({
@@ -186,6 +269,7 @@ export function useLiveMode(): LiveModeState {
           } else if (state === 'PROCESSING') {
             setStatusTracked('processing');
           } else if (state === 'STOPPED') {
+            resumeTokenRef.current = null;
             // GH-230: a server-initiated stop must tear down capture too —
             // leaving it running kept streaming audio into a dead session and
             // stranded the Linux loopback module (mic indicator stayed lit).
@@ -198,6 +282,7 @@ export function useLiveMode(): LiveModeState {
         }
 
         case 'sentence':
+          sentenceCountRef.current += 1;
           setSentences((prev) => [
             ...prev,
             {
@@ -212,9 +297,48 @@ export function useLiveMode(): LiveModeState {
           setPartial((msg.data?.text as string) ?? '');
           break;
 
+        case 'segment':
+          applySegmentChange(segmentsRef.current.apply(msg.data as unknown as SegmentDelta));
+          break;
+
+        case 'segments':
+          // Snapshot answering a get_segments after a seq gap
+          applySegmentChange(
+            segmentsRef.current.applySnapshot(msg.data as unknown as SegmentSnapshot),
+          );
+          break;
+
+        case 'keyword':
+          if (msg.data?.keyword) {
+            const alert: LiveKeywordAlert = {
+              keyword: msg.data.keyword as string,
+              match: (msg.data.match as string) ?? '',
+              text: (msg.data.text as string) ?? '',
+              sentenceIndex: sentenceCountRef.current - 1,
+              at: Number(msg.data.at) || 0,
+            };
+            setAlerts((prev) => [...prev, alert]);
+          }
+          break;
+
+        case 'announce':
+          // Server-throttled batch of sentences for the ARIA live region
+          // (server/backend/core/accessibility.py).
+          if (msg.data?.text) {
+            useAriaAnnouncerStore
+              .getState()
+              .announce(
+                msg.data.text as string,
+                (msg.data.politeness as AriaPoliteness) === 'assertive' ? 'assertive' : 'polite',
+              );
+          }
+          break;
+
         case 'history':
           // Restore history from server
           if (Array.isArray(msg.data?.sentences)) {
+            sentenceCountRef.current = msg.data.sentences.length;
+            setAlerts([]);
             setSentences(
               (msg.data.sentences as string[]).map((text) => ({
                 text,
@@ -224,11 +348,54 @@ export function useLiveMode(): LiveModeState {
           }
           break;
 
+        case 'session':
+          resumeTokenRef.current = (msg.data?.token as string) || null;
+          break;
+
+        case 'audio_ack':
+          replayRef.current.ack(Number(msg.data?.seq) || 0);
+          break;
+
+        case 'resumed': {
+          // Send what the engine missed, then fetch the transcript the
+          // socket lost while it was down.
+          const audioSeq = Number(msg.data?.audio_seq) || 0;
+          for (const frame of replayRef.current.after(audioSeq)) {
+            socketRef.current?.sendAudio(frame.pcm, frame.seq);
+          }
+          setStatusTracked('listening');
+          setStatusMessage(null);
+          applySegmentChange(segmentsRef.current.resync());
+          break;
+        }
+
+        case 'resume_failed':
+          resumeTokenRef.current = null;
+          setError(
+            (msg.data?.message as string) ??
+              'Connection to the server was lost. Live mode stopped.',
+          );
+          setStatusTracked('error');
+          captureRef.current?.stop();
+          setAnalyser(null);
+          setStatusMessage(null);
+          socketRef.current?.disconnect();
+          break;
+
+        case 'streaming_mode':
+          if (msg.data?.mode === 'fast' || msg.data?.mode === 'accurate') {
+            setStreamingModeState(msg.data.mode as LiveStreamingMode);
+          }
+          break;
+
         case 'history_cleared':
+          sentenceCountRef.current = 0;
           setSentences([]);
+          setAlerts([]);
           break;
 
         case 'error':
+          resumeTokenRef.current = null;
           setError((msg.data?.message as string) ?? 'Live mode error');
           setStatusTracked('error');
           captureRef.current?.stop();
@@ -237,7 +404,7 @@ export function useLiveMode(): LiveModeState {
           break;
       }
     },
-    [setStatusTracked],
+    [setStatusTracked, applySegmentChange],
   );
 
   const start = useCallback(
@@ -248,11 +415,16 @@ export function useLiveMode(): LiveModeState {
       // one — preserve already-accumulated sentences and unmute state so the
       // transcript doesn't visually reset on host change.
       if (!isRetargetingRef.current) {
+        sentenceCountRef.current = 0;
         setSentences([]);
+        setAlerts([]);
         setMuted(false);
       }
       setStatusMessage(null);
       startOptsRef.current = options ?? {};
+      resumeTokenRef.current = null;
+      replayRef.current.clear();
+      setStreamingModeState(startOptsRef.current.streamingMode ?? 'accurate');
       // GH-237: a user-initiated session reopens the start-gate.
       sessionEstablishedRef.current = false;
 
@@ -262,6 +434,9 @@ export function useLiveMode(): LiveModeState {
       socketRef.current = new TranscriptionSocket('/ws/live', {
         onMessage: handleMessage,
         onError: (err) => {
+          // A transport error on a resumable session is the drop itself;
+          // onClose turns it into a reconnect.
+          if (resumeTokenRef.current && err === 'WebSocket connection error') return;
           setError(err);
           setStatusTracked('error');
           captureRef.current?.stop();
@@ -272,15 +447,24 @@ export function useLiveMode(): LiveModeState {
           // already installed the new socket. Swallow the status reset so the
           // UI doesn't flip to 'idle' between hops.
           if (isRetargetingRef.current) return;
+          // The server keeps a session with a resume token alive for a while:
+          // keep capturing into the replay buffer and let the socket reconnect.
+          const running = ['listening', 'processing', 'reconnecting'].includes(statusRef.current);
+          if (resumeTokenRef.current && running) {
+            setStatusTracked('reconnecting');
+            setStatusMessage('Connection lost — reconnecting…');
+            return;
+          }
           captureRef.current?.stop();
           setAnalyser(null);
           setStatusMessage(null);
           // GH-237: if the drop happened while a session was actively running
-          // (listening/processing), it is an unrecoverable interruption — the
-          // server cannot resume, and the start-gate now blocks the reconnect
-          // from resurrecting it. Fail loudly and halt the reconnect. A drop
-          // before the engine acknowledged us (connecting/starting) or after a
-          // clean STOPPED (idle) is not fatal: let the reconnect re-establish.
+          // (listening/processing) and the server gave no resume token
+          // (resumption is off), it is an unrecoverable interruption, and the
+          // start-gate now blocks the reconnect from resurrecting it. Fail
+          // loudly and halt the reconnect. A drop before the engine
+          // acknowledged us (connecting/starting) or after a clean STOPPED
+          // (idle) is not fatal: let the reconnect re-establish.
           if (statusRef.current === 'listening' || statusRef.current === 'processing') {
             setError('Connection to the server was lost. Live mode stopped.');
             setStatusTracked('error');
@@ -358,6 +542,8 @@ export function useLiveMode(): LiveModeState {
   }, [start]);
 
   const stop = useCallback(() => {
+    resumeTokenRef.current = null;
+    replayRef.current.clear();
     socketRef.current?.sendJSON({ type: 'stop' });
     socketRef.current?.disconnect(); // sets intentionalDisconnect=true, prevents reconnect loop
     captureRef.current?.stop();
@@ -392,9 +578,20 @@ export function useLiveMode(): LiveModeState {
     captureRef.current?.setGain(value);
   }, []);
 
+  const setStreamingMode = useCallback((mode: LiveStreamingMode) => {
+    setStreamingModeState(mode);
+    // A retarget or the next start picks the mode up from the options.
+    startOptsRef.current = { ...startOptsRef.current, streamingMode: mode };
+    if (sessionEstablishedRef.current) {
+      socketRef.current?.sendJSON({ type: 'set_streaming_mode', data: { mode } });
+    }
+  }, []);
+
   const clearHistory = useCallback(() => {
     socketRef.current?.sendJSON({ type: 'clear_history' });
+    sentenceCountRef.current = 0;
     setSentences([]);
+    setAlerts([]);
     setPartial('');
   }, []);
 
@@ -406,14 +603,17 @@ export function useLiveMode(): LiveModeState {
     status,
     sentences,
     partial,
+    alerts,
     statusMessage,
     error,
     analyser,
     muted,
+    streamingMode,
     start,
     stop,
     toggleMute,
     setGain,
+    setStreamingMode,
     clearHistory,
     getText,
   };
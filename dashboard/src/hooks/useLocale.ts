@@ -0,0 +1,37 @@
+/**
+ * useT / useLocaleSync — runtime UI translation.
+ *
+ * `useLocaleSync()` is mounted once in App: it loads the catalog the main
+ * process picked and follows `locale:changed`. Components call `useT()` for
+ * a `t(id, args)` bound to the active catalog.
+ */
+
+import { useCallback, useEffect } from 'react';
+import { useLocaleStore } from '../stores/localeStore';
+import { translate, type MessageArgs } from '../utils/i18n';
+
+export function useLocaleSync(): void {
+  const setCatalog = useLocaleStore((s) => s.setCatalog);
+  useEffect(() => {
+    const api = window.electronAPI?.locale;
+    if (!api) return;
+    api
+      .get()
+      .then((catalog) => {
+        setCatalog(catalog);
+        document.documentElement.lang = catalog.code;
+      })
+      .catch(() => {
+        // Stay on the bundled English fallback.
+      });
+    return api.onChanged((catalog) => {
+      setCatalog(catalog);
+      document.documentElement.lang = catalog.code;
+    });
+  }, [setCatalog]);
+}
+
+export function useT(): (id: string, args?: MessageArgs) => string {
+  const messages = useLocaleStore((s) => s.messages);
+  return useCallback((id: string, args?: MessageArgs) => translate(messages, id, args), [messages]);
+}
@@ -0,0 +1,54 @@
+/**
+ * useLowConfidenceRegions — runs of uncertain words for transcript review.
+ *
+ * Mirrors `useDiarizationConfidence` in style. `reloadKey` re-fetches when
+ * the transcript changes underneath (realign, re-segmentation); pass the
+ * loaded segments array. Failures are non-fatal — no regions, no UI.
+ */
+
+import { useCallback, useEffect, useState } from 'react';
+import { apiClient } from '../api/client';
+import type { LowConfidenceRegion } from '../api/types';
+
+export interface LowConfidenceState {
+  regions: LowConfidenceRegion[];
+  /** Fraction of words with a score; 0 hides the review controls. */
+  coverage: number;
+  loading: boolean;
+  refresh: () => void;
+}
+
+export function useLowConfidenceRegions(
+  recordingId: number | null,
+  threshold?: number,
+  reloadKey?: unknown,
+): LowConfidenceState {
+  const [regions, setRegions] = useState<LowConfidenceRegion[]>([]);
+  const [coverage, setCoverage] = useState(0);
+  const [loading, setLoading] = useState(false);
+
+  const fetch = useCallback(async () => {
+    if (recordingId === null || !Number.isFinite(recordingId) || recordingId <= 0) {
+      setRegions([]);
+      setCoverage(0);
+      return;
+    }
+    setLoading(true);
+    try {
+      const resp = await apiClient.getLowConfidenceRegions(recordingId, threshold);
+      setRegions(resp.regions ?? []);
+      setCoverage(resp.coverage ?? 0);
+    } catch {
+      setRegions([]);
+      setCoverage(0);
+    } finally {
+      setLoading(false);
+    }
+  }, [recordingId, threshold]);
+
+  useEffect(() => {
+    fetch();
+  }, [fetch, reloadKey]);
+
+  return { regions, coverage, loading, refresh: fetch };
+}
@@ -0,0 +1,45 @@
+/**
+ * useMediaInfo — library card metadata and previews (server core/media_previews.py).
+ *
+ * The server extracts duration, codecs and a preview image in the
+ * background after import, so a freshly imported recording starts out
+ * `pending`. One request covers every card on screen; it is repeated while
+ * any of them is still pending and stops once all are settled.
+ */
+
+import { useQuery } from '@tanstack/react-query';
+import { apiClient } from '../api/client';
+import type { MediaInfo } from '../api/types';
+
+const PENDING_POLL_MS = 3_000;
+
+export function useMediaInfo(recordingIds: number[]): Map<number, MediaInfo> {
+  const ids = [...new Set(recordingIds)].sort((a, b) => a - b);
+  const { data } = useQuery({
+    queryKey: ['mediaInfo', ids],
+    queryFn: () => apiClient.getMediaInfo(ids),
+    enabled: ids.length > 0,
+    staleTime: Infinity,
+    refetchInterval: (query) =>
+      Object.values(query.state.data ?? {}).some((info) => info.status === 'pending')
+        ? PENDING_POLL_MS
+        : false,
+  });
+  return new Map(Object.entries(data ?? {}).map(([id, info]) => [Number(id), info] as const));
+}
+
+/** "H264 · 1920×1080" for video, "MP3 · 44.1 kHz" for audio; null until ready. */
+export function describeMedia(info: MediaInfo | undefined): string | null {
+  if (info?.status !== 'ready') return null;
+  if (info.video) {
+    const { codec, width, height } = info.video;
+    const size = width && height ? `${width}×${height}` : null;
+    return [codec?.toUpperCase(), size].filter(Boolean).join(' · ') || null;
+  }
+  if (info.audio) {
+    const { codec, sample_rate } = info.audio;
+    const rate = sample_rate ? `${+(sample_rate / 1000).toFixed(1)} kHz` : null;
+    return [codec?.toUpperCase(), rate].filter(Boolean).join(' · ') || null;
+  }
+  return null;
+}
@@ -0,0 +1,116 @@
+/**
+ * useMediaSession — registers an `<audio>` element with the OS media session
+ * (MPRIS on Linux, SMTC on Windows, Now Playing on macOS via Chromium), so
+ * keyboard media keys and the OS media overlay can play, pause and seek
+ * transcript playback. The overlay shows the transcript title and duration.
+ *
+ * Handlers read the element directly rather than React state so they never
+ * go stale; everything is cleared when the hook is disabled or unmounts, so a
+ * closed modal never keeps answering media keys.
+ */
+
+import { useEffect, type RefObject } from 'react';
+import { DEFAULT_SEEK_OFFSET, seekTarget, toPositionState } from '../utils/mediaSession';
+
+export interface MediaSessionOptions {
+  enabled: boolean;
+  title: string;
+  /** Shown as the "artist" line of the OS overlay. */
+  subtitle?: string;
+}
+
+const ACTIONS: MediaSessionAction[] = [
+  'play',
+  'pause',
+  'stop',
+  'seekbackward',
+  'seekforward',
+  'seekto',
+];
+
+export function useMediaSession(
+  audioRef: RefObject<HTMLAudioElement | null>,
+  { enabled, title, subtitle }: MediaSessionOptions,
+): void {
+  useEffect(() => {
+    const session = typeof navigator !== 'undefined' ? navigator.mediaSession : undefined;
+    const audio = audioRef.current;
+    if (!enabled || !session || !audio) return;
+
+    session.metadata = new MediaMetadata({
+      title: title || 'Recording',
+      artist: subtitle ?? '',
+      album: 'TranscriptionSuite',
+    });
+
+    const syncPosition = () => {
+      const state = toPositionState(audio.duration, audio.currentTime, audio.playbackRate);
+      try {
+        session.setPositionState?.(state ?? undefined);
+      } catch {
+        // Older Chromium rejects a state mid-load; the next event retries.
+      }
+    };
+    const syncPlayback = () => {
+      session.playbackState = audio.paused ? 'paused' : 'playing';
+      syncPosition();
+    };
+
+    const handlers: Record<string, MediaSessionActionHandler> = {
+      play: () => void audio.play().catch(() => {}),
+      pause: () => audio.pause(),
+      stop: () => {
+        audio.pause();
+        audio.currentTime = 0;
+      },
+      seekbackward: (details) => {
+        audio.currentTime = seekTarget(
+          audio.currentTime,
+          audio.duration,
+          -(details.seekOffset ?? DEFAULT_SEEK_OFFSET),
+        );
+      },
+      seekforward: (details) => {
+        audio.currentTime = seekTarget(
+          audio.currentTime,
+          audio.duration,
+          details.seekOffset ?? DEFAULT_SEEK_OFFSET,
+        );
+      },
+      seekto: (details) => {
+        if (details.seekTime == null) return;
+        audio.currentTime = seekTarget(details.seekTime, audio.duration, 0);
+      },
+    };
+    for (const action of ACTIONS) {
+      try {
+        session.setActionHandler(action, handlers[action]);
+      } catch {
+        // Action not supported by this Chromium build — skip it.
+      }
+    }
+
+    const events = ['play', 'pause', 'ended'] as const;
+    for (const name of events) audio.addEventListener(name, syncPlayback);
+    audio.addEventListener('loadedmetadata', syncPosition);
+    audio.addEventListener('seeked', syncPosition);
+    audio.addEventListener('ratechange', syncPosition);
+    syncPlayback();
+
+    return () => {
+      for (const name of events) audio.removeEventListener(name, syncPlayback);
+      audio.removeEventListener('loadedmetadata', syncPosition);
+      audio.removeEventListener('seeked', syncPosition);
+      audio.removeEventListener('ratechange', syncPosition);
+      for (const action of ACTIONS) {
+        try {
+          session.setActionHandler(action, null);
+        } catch {
+          // See above.
+        }
+      }
+      session.metadata = null;
+      session.playbackState = 'none';
+    };
+  }, [audioRef, enabled, title, subtitle]);
+}
@@ -0,0 +1,17 @@
+/**
+ * useMetadataFields — the custom fields in use across the library and their
+ * most common values (GET /api/notebook/metadata/fields), for filters.
+ */
+
+import { useQuery } from '@tanstack/react-query';
+import { apiClient } from '../api/client';
+import type { MetadataFieldSummary } from '../api/types';
+
+export function useMetadataFields(): MetadataFieldSummary[] {
+  const { data } = useQuery({
+    queryKey: ['metadataFields'],
+    queryFn: () => apiClient.getMetadataFields(),
+    staleTime: 60_000,
+  });
+  return data ?? [];
+}
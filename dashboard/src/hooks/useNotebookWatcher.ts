@@ -4,7 +4,8 @@
  * Mirror of useSessionWatcher but for notebook-auto jobs.
  * Auto-watch jobs include file creation timestamps so they land on the
  * correct calendar date in the notebook.
- * - Loads the persisted watch path AND active flag from config on mount (Issue #100).
+ * - Loads the persisted watch path AND active flag from config on mount (Issue #100),
+ *   and again whenever either key changes (useLiveConfigReload.ts).
  * - Re-arms the watcher on launch only when both the saved path and active flag are truthy.
  * - Polls folder accessibility every 10s when watch is active (4.1).
  *
@@ -16,6 +17,7 @@
 import { useState, useEffect, useCallback } from 'react';
 import { useImportQueueStore } from '../stores/importQueueStore';
 import { getConfig, setConfig } from '../config/store';
+import { useConfigChanged } from './useLiveConfigReload';
 
 export function useNotebookWatcher() {
   const notebookWatchPath = useImportQueueStore((s) => s.notebookWatchPath);
@@ -42,20 +44,30 @@ export function useNotebookWatcher() {
 
   // Hydrate path AND active flag together on mount (Issue #100).
   // Re-arm only when both are truthy — never path-only or active-only.
-  useEffect(() => {
-    Promise.all([
-      getConfig<string>('folderWatch.notebookPath'),
-      getConfig<boolean>('folderWatch.notebookWatchActive'),
-    ])
-      .then(([savedPath, savedActive]) => {
-        if (savedPath) setNotebookWatchPath(savedPath);
-        if (savedPath && savedActive === true) setNotebookWatchActiveRaw(true);
-      })
-      .catch((err: unknown) => {
-        const message = err instanceof Error ? err.message : 'Unknown error';
-        console.error('[useNotebookWatcher] Failed to hydrate from config:', message);
-      });
-  }, [setNotebookWatchPath, setNotebookWatchActiveRaw]);
+  // After a live config change the saved flag also switches the watch off.
+  const hydrate = useCallback(
+    (live: boolean) => {
+      Promise.all([
+        getConfig<string>('folderWatch.notebookPath'),
+        getConfig<boolean>('folderWatch.notebookWatchActive'),
+      ])
+        .then(([savedPath, savedActive]) => {
+          if (savedPath) setNotebookWatchPath(savedPath);
+          const active = Boolean(savedPath) && savedActive === true;
+          if (active || live) setNotebookWatchActiveRaw(active);
+        })
+        .catch((err: unknown) => {
+          const message = err instanceof Error ? err.message : 'Unknown error';
+          console.error('[useNotebookWatcher] Failed to hydrate from config:', message);
+        });
+    },
+    [setNotebookWatchPath, setNotebookWatchActiveRaw],
+  );
+
+  useEffect(() => hydrate(false), [hydrate]);
+  useConfigChanged(['folderWatch.notebookPath', 'folderWatch.notebookWatchActive'], () =>
+    hydrate(true),
+  );
 
   // Start / stop watcher when active state or path changes
   useEffect(() => {
@@ -4,7 +4,8 @@
  * Covers the docker:downloadEvent start/complete path, the activity:event
  * model-load progress path, the server-ready aggregate completion, and the
  * legacy-card dedupe (a granular `model-load-*` event dismisses the coarse
- * `model-preload` toast while keeping its record).
+ * `model-preload` toast while keeping its record), and the hand-off of
+ * changed entries to the cross-session notification history.
  */
 
 import { renderHook } from '@testing-library/react';
@@ -206,4 +207,31 @@ describe('useNotificationBridge (GH-207)', () => {
     expect(aggregate?.status).toBe('complete');
     expect(aggregate?.title).toBe('Server ready');
   });
+
+  it('sends only changed entries, without transcripts, to the notification history', async () => {
+    vi.useFakeTimers();
+    const record = vi.fn().mockResolvedValue(undefined);
+    (window as unknown as { electronAPI: unknown }).electronAPI = {
+      notificationLog: { load: vi.fn().mockResolvedValue([]), persist: vi.fn() },
+      notificationHistory: { record },
+    };
+    try {
+      renderHook(() => useNotificationBridge());
+      const { notify } = useNotificationsStore.getState();
+
+      notify({ id: 'a', category: 'transcription', title: 'A', transcript: 'long text' });
+      notify({ id: 'b', category: 'import', title: 'B' });
+      await vi.advanceTimersByTimeAsync(1000);
+      expect(record).toHaveBeenCalledTimes(1);
+      expect(record.mock.calls[0][0].map((n: AppNotification) => n.id)).toEqual(['a', 'b']);
+      expect(record.mock.calls[0][0][0].transcript).toBeUndefined();
+
+      notify({ id: 'b', category: 'import', title: 'B', status: 'complete' });
+      await vi.advanceTimersByTimeAsync(1000);
+      expect(record).toHaveBeenCalledTimes(2);
+      expect(record.mock.calls[1][0]).toMatchObject([{ id: 'b', status: 'complete' }]);
+    } finally {
+      vi.useRealTimers();
+    }
+  });
 });
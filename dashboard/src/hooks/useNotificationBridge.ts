@@ -6,6 +6,8 @@
  * Responsibilities:
  *  1. Hydrate the store from the semi-persistent session file, then persist
  *     changes back (debounced) so a renderer reload keeps the session log.
+ *     Changed entries also go to the cross-session notification history
+ *     (electron/notificationHistory.ts).
  *  2. Subscribe to every notification-relevant IPC channel:
  *     docker:downloadEvent, activity:event, updates:installerStatus (+ a
  *     mount-time snapshot: that channel is broadcast-only with no replay),
@@ -73,11 +75,23 @@ export function useNotificationBridge(): void {
       })
       .catch(() => {});
 
+    // The store replaces an entry object on every change, so identity tells
+    // which entries the history has not seen in their current state.
+    const history = window.electronAPI?.notificationHistory;
+    const recorded = new WeakSet<AppNotification>();
+
     let timer: ReturnType<typeof setTimeout> | null = null;
     const unsubscribe = useNotificationsStore.subscribe(() => {
       if (timer) clearTimeout(timer);
       timer = setTimeout(() => {
-        void api.persist(useNotificationsStore.getState().notifications).catch(() => {});
+        const { notifications } = useNotificationsStore.getState();
+        void api.persist(notifications).catch(() => {});
+        const changed = notifications.filter((n) => !recorded.has(n));
+        if (!history || changed.length === 0) return;
+        changed.forEach((n) => recorded.add(n));
+        void history
+          .record(changed.map((n) => ({ ...n, transcript: undefined })))
+          .catch(() => {});
       }, PERSIST_DEBOUNCE_MS);
     });
 
@@ -0,0 +1,57 @@
+/**
+ * useNotificationHistory / useUnreadNotificationCount - the cross-session
+ * notification history kept by the main process (electron/notificationHistory.ts).
+ *
+ * Entries are written by useNotificationBridge; these hooks only read,
+ * mark read and dismiss. Both share one react-query key, so a dismissal in
+ * the Notifications view updates the sidebar badge at once. Outside
+ * Electron the history is empty.
+ */
+
+import { useCallback } from 'react';
+import { useQuery, useQueryClient } from '@tanstack/react-query';
+
+const HISTORY_KEY = ['notificationHistory'] as const;
+const UNREAD_POLL_MS = 10_000;
+const HISTORY_LIMIT = 200;
+
+export function useUnreadNotificationCount(): number {
+  const { data } = useQuery({
+    queryKey: [...HISTORY_KEY, 'unread'],
+    queryFn: async () => (await window.electronAPI?.notificationHistory?.unreadCount()) ?? 0,
+    refetchInterval: UNREAD_POLL_MS,
+  });
+  return data ?? 0;
+}
+
+export function useNotificationHistory() {
+  const queryClient = useQueryClient();
+  const { data } = useQuery({
+    queryKey: [...HISTORY_KEY, 'list'],
+    queryFn: async () =>
+      (await window.electronAPI?.notificationHistory?.list({ limit: HISTORY_LIMIT })) ?? [],
+  });
+
+  const refresh = useCallback(
+    () => queryClient.invalidateQueries({ queryKey: HISTORY_KEY }),
+    [queryClient],
+  );
+
+  const dismiss = useCallback(
+    async (entryId: string) => {
+      await window.electronAPI?.notificationHistory?.dismiss(entryId);
+      await refresh();
+    },
+    [refresh],
+  );
+
+  const markRead = useCallback(
+    async (entryIds?: string[]) => {
+      await window.electronAPI?.notificationHistory?.markRead(entryIds);
+      await refresh();
+    },
+    [refresh],
+  );
+
+  return { history: data ?? [], dismiss, markRead, refresh };
+}
@@ -0,0 +1,72 @@
+/**
+ * useOutline — the chapter outline for one recording.
+ *
+ * The server builds the outline the first time it is asked for, so the
+ * initial fetch can take a moment on long recordings. `rebuild` re-runs
+ * chaptering and re-throws so callers can toast.
+ */
+
+import { useCallback, useEffect, useState } from 'react';
+import { apiClient } from '../api/client';
+import type { Chapter } from '../api/types';
+
+export interface OutlineState {
+  chapters: Chapter[];
+  loading: boolean;
+  rebuild: (useLlm?: boolean) => Promise<number>;
+}
+
+/** Index of the chapter playing at `time`; -1 before the first one. */
+export function chapterIndexAt(chapters: Chapter[], time: number): number {
+  let index = -1;
+  for (let i = 0; i < chapters.length; i++) {
+    if (chapters[i].start_time <= time) index = i;
+    else break;
+  }
+  return index;
+}
+
+export function useOutline(recordingId: number | null): OutlineState {
+  const [chapters, setChapters] = useState<Chapter[]>([]);
+  const [loading, setLoading] = useState(false);
+
+  useEffect(() => {
+    if (recordingId === null || !Number.isFinite(recordingId) || recordingId <= 0) {
+      setChapters([]);
+      return;
+    }
+    let cancelled = false;
+    setLoading(true);
+    apiClient
+      .getOutline(recordingId)
+      .then((resp) => {
+        if (!cancelled) setChapters(resp.chapters ?? []);
+      })
+      .catch(() => {
+        if (!cancelled) setChapters([]);
+      })
+      .finally(() => {
+        if (!cancelled) setLoading(false);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [recordingId]);
+
+  const rebuild = useCallback(
+    async (useLlm?: boolean) => {
+      if (recordingId === null) return 0;
+      setLoading(true);
+      try {
+        const resp = await apiClient.rebuildOutline(recordingId, useLlm);
+        setChapters(resp.chapters);
+        return resp.chapters.length;
+      } finally {
+        setLoading(false);
+      }
+    },
+    [recordingId],
+  );
+
+  return { chapters, loading, rebuild };
+}
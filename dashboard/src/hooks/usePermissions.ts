@@ -0,0 +1,27 @@
+import { useQuery } from '@tanstack/react-query';
+import { apiClient } from '../api/client';
+import type { Capability, Permissions } from '../api/types';
+
+export interface PermissionsState {
+  permissions: Permissions | null;
+  /**
+   * False only when the server has said the token lacks `capability`. While
+   * loading, on older servers or on errors every feature stays visible and
+   * the server's own 403 is the final word.
+   */
+  can: (capability: Capability) => boolean;
+}
+
+export function usePermissions(): PermissionsState {
+  const { data } = useQuery({
+    queryKey: ['permissions'],
+    queryFn: async () => apiClient.getPermissions(),
+    staleTime: 60_000,
+    retry: false,
+  });
+  const permissions = data ?? null;
+  return {
+    permissions,
+    can: (capability) => permissions?.[capability] !== false,
+  };
+}
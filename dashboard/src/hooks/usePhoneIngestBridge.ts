@@ -0,0 +1,64 @@
+/**
+ * usePhoneIngestBridge — singleton bridge that forwards files uploaded from a
+ * paired phone (`electronAPI.phoneIngest.onFileReceived`) into the Notebook
+ * import queue.
+ *
+ * Goes through `handleFilesDetected` like Folder Watch, so phone uploads get
+ * the same offline / language guards (held and retried, never dropped) and
+ * the Notebook tab's diarization and language settings. Uploads the main
+ * process still holds as pending — received with no window open, or before
+ * a restart — are queued on mount, and each one is released with
+ * `markImported` once its job succeeds. Mount exactly once at the app root,
+ * next to useWatcherFilesBridge, or each upload would be queued once per
+ * subscriber.
+ */
+
+import { useEffect } from 'react';
+import { useImportQueueStore } from '../stores/importQueueStore';
+
+export function usePhoneIngestBridge(): void {
+  const handleFilesDetected = useImportQueueStore((s) => s.handleFilesDetected);
+
+  useEffect(() => {
+    const api = window.electronAPI?.phoneIngest;
+    if (!api) return;
+    let cancelled = false;
+    // Paths queued by this bridge and not yet released.
+    const queued = new Set<string>();
+
+    const enqueue = (file: PhoneIngestFile) => {
+      if (queued.has(file.path)) return;
+      queued.add(file.path);
+      handleFilesDetected({
+        type: 'notebook',
+        files: [file.path],
+        count: 1,
+        fileMeta: [{ path: file.path, createdAt: file.receivedAt }],
+        label: 'Phone',
+        source: 'phone',
+      });
+    };
+
+    const unsubscribeFiles = api.onFileReceived(enqueue);
+    void api
+      .listPending()
+      .then((files) => {
+        if (!cancelled) files.forEach(enqueue);
+      })
+      .catch(() => {});
+
+    const unsubscribeJobs = useImportQueueStore.subscribe((state) => {
+      for (const job of state.jobs) {
+        if (job.status !== 'success' || typeof job.file !== 'string') continue;
+        if (!queued.delete(job.file)) continue;
+        void api.markImported(job.file).catch(() => {});
+      }
+    });
+
+    return () => {
+      cancelled = true;
+      unsubscribeFiles();
+      unsubscribeJobs();
+    };
+  }, [handleFilesDetected]);
+}
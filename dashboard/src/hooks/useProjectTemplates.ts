@@ -0,0 +1,102 @@
+/**
+ * useProjectTemplates — reusable setups for recurring workflows
+ * (server api/routes/projects.py).
+ *
+ * A template lists the custom fields its recordings carry, pre-fills some
+ * of them, and pre-selects transcription options. Applying one happens
+ * client-side when files are queued: the fields travel as the upload's
+ * `metadata`, the options override the import tab's own choices.
+ */
+
+import { useMutation, useQuery, useQueryClient } from '@tanstack/react-query';
+import { apiClient } from '../api/client';
+import type {
+  ProjectTemplate,
+  ProjectTemplatePayload,
+  TranscriptionUploadOptions,
+} from '../api/types';
+
+const TEMPLATES_KEY = ['projectTemplates'];
+
+export interface ProjectTemplatesState {
+  templates: ProjectTemplate[];
+  loading: boolean;
+  error: string | null;
+  /** Create when `id` is null, otherwise update. */
+  save: (id: number | null, payload: ProjectTemplatePayload) => Promise<ProjectTemplate>;
+  remove: (id: number) => Promise<void>;
+}
+
+export function useProjectTemplates(): ProjectTemplatesState {
+  const queryClient = useQueryClient();
+  const { data, isLoading, error } = useQuery({
+    queryKey: TEMPLATES_KEY,
+    queryFn: () => apiClient.listProjectTemplates(),
+  });
+  const invalidate = () => void queryClient.invalidateQueries({ queryKey: TEMPLATES_KEY });
+
+  const saveMutation = useMutation({
+    mutationFn: ({ id, payload }: { id: number | null; payload: ProjectTemplatePayload }) =>
+      id === null
+        ? apiClient.createProjectTemplate(payload)
+        : apiClient.updateProjectTemplate(id, payload),
+    onSuccess: invalidate,
+  });
+  const removeMutation = useMutation({
+    mutationFn: (id: number) => apiClient.deleteProjectTemplate(id),
+    onSuccess: invalidate,
+  });
+
+  return {
+    templates: data ?? [],
+    loading: isLoading,
+    error: error instanceof Error ? error.message : error ? 'Failed to load templates' : null,
+    save: (id, payload) => saveMutation.mutateAsync({ id, payload }),
+    remove: (id) => removeMutation.mutateAsync(id),
+  };
+}
+
+/**
+ * The template's fields in its order, pre-filled from its defaults. Values
+ * already typed in `current` win, so switching templates keeps them.
+ */
+export function templateMetadata(
+  template: ProjectTemplate | null,
+  current: Record<string, string> = {},
+): Record<string, string> {
+  if (!template) return {};
+  return Object.fromEntries(
+    template.fields.map(({ key }) => [key, current[key] || template.defaults[key] || '']),
+  );
+}
+
+/** Fields with a value — what gets stored on the recording. */
+export function filledMetadata(metadata: Record<string, string>): Record<string, string> {
+  return Object.fromEntries(
+    Object.entries(metadata)
+      .map(([field, value]) => [field.trim(), value.trim()] as const)
+      .filter(([field, value]) => field && value),
+  );
+}
+
+/**
+ * Upload options with the template's choices laid over the import tab's.
+ * Diarization needs word timestamps, as in the tab's own toggles.
+ */
+export function applyTemplateOptions(
+  options: TranscriptionUploadOptions,
+  template: ProjectTemplate | null,
+): TranscriptionUploadOptions {
+  if (!template) return options;
+  const { language, enable_diarization, expected_speakers, profile_id } = template.options;
+  const merged = { ...options };
+  if (language) merged.language = language;
+  if (enable_diarization !== undefined) {
+    merged.enable_diarization = enable_diarization;
+    if (enable_diarization) merged.enable_word_timestamps = true;
+    else merged.parallel_diarization = undefined;
+  }
+  if (expected_speakers) merged.expected_speakers = expected_speakers;
+  if (profile_id != null) merged.profile_id = profile_id;
+  return merged;
+}
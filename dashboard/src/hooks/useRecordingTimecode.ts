@@ -0,0 +1,73 @@
+/**
+ * useRecordingTimecode — fetch + mutate per-recording SMPTE timecode settings.
+ *
+ * Same hand-rolled state pattern as `useRecordingAliases`. `timecode` is
+ * `null` when the recording has none; the viewer then falls back to the
+ * plain `m:ss` display and exports keep zero-based times.
+ */
+
+import { useCallback, useEffect, useState } from 'react';
+import { apiClient } from '../api/client';
+import type { TimecodeSettings } from '../api/types';
+
+export interface RecordingTimecodeState {
+  timecode: TimecodeSettings | null;
+  loading: boolean;
+  error: string | null;
+  /** Store settings (`null` clears). Resolves with the server-normalized value. */
+  setTimecode: (next: TimecodeSettings | null) => Promise<TimecodeSettings | null>;
+}
+
+export function useRecordingTimecode(recordingId: number | null): RecordingTimecodeState {
+  const [timecode, setTimecodeState] = useState<TimecodeSettings | null>(null);
+  const [loading, setLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (recordingId === null || !Number.isFinite(recordingId) || recordingId <= 0) {
+      setTimecodeState(null);
+      setError(null);
+      return;
+    }
+    let cancelled = false;
+    setLoading(true);
+    setError(null);
+    apiClient
+      .getRecordingTimecode(recordingId)
+      .then((resp) => {
+        if (!cancelled) setTimecodeState(resp.timecode ?? null);
+      })
+      .catch((err) => {
+        if (cancelled) return;
+        setError(err instanceof Error ? err.message : 'Failed to load timecode');
+        setTimecodeState(null);
+      })
+      .finally(() => {
+        if (!cancelled) setLoading(false);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [recordingId]);
+
+  const setTimecode = useCallback(
+    async (next: TimecodeSettings | null): Promise<TimecodeSettings | null> => {
+      if (recordingId === null) return null;
+      try {
+        const resp =
+          next === null
+            ? await apiClient.clearRecordingTimecode(recordingId)
+            : await apiClient.setRecordingTimecode(recordingId, next);
+        setTimecodeState(resp.timecode ?? null);
+        setError(null);
+        return resp.timecode ?? null;
+      } catch (err) {
+        setError(err instanceof Error ? err.message : 'Failed to update timecode');
+        throw err;
+      }
+    },
+    [recordingId],
+  );
+
+  return { timecode, loading, error, setTimecode };
+}
@@ -0,0 +1,75 @@
+/**
+ * useRedactions — sensitive ranges for one recording.
+ *
+ * Mutations re-fetch the list afterwards and re-throw so callers can toast.
+ * `scanPii` re-runs the server's PII scan and returns how many suggestions
+ * it made.
+ */
+
+import { useCallback, useEffect, useState } from 'react';
+import { apiClient } from '../api/client';
+import type { Redaction } from '../api/types';
+
+export interface RedactionsState {
+  ranges: Redaction[];
+  loading: boolean;
+  add: (startTime: number, endTime: number) => Promise<void>;
+  remove: (redactionIds: number[]) => Promise<void>;
+  scanPii: () => Promise<number>;
+  refresh: () => Promise<void>;
+}
+
+export function useRedactions(recordingId: number | null): RedactionsState {
+  const [ranges, setRanges] = useState<Redaction[]>([]);
+  const [loading, setLoading] = useState(false);
+
+  const refresh = useCallback(async () => {
+    if (recordingId === null || !Number.isFinite(recordingId) || recordingId <= 0) {
+      setRanges([]);
+      return;
+    }
+    setLoading(true);
+    try {
+      const resp = await apiClient.getRedactions(recordingId);
+      setRanges(resp.redactions ?? []);
+    } catch {
+      setRanges([]);
+    } finally {
+      setLoading(false);
+    }
+  }, [recordingId]);
+
+  useEffect(() => {
+    refresh();
+  }, [refresh]);
+
+  const add = useCallback(
+    async (startTime: number, endTime: number) => {
+      if (recordingId === null) return;
+      await apiClient.createRedaction(recordingId, { start_time: startTime, end_time: endTime });
+      await refresh();
+    },
+    [recordingId, refresh],
+  );
+
+  const remove = useCallback(
+    async (redactionIds: number[]) => {
+      if (recordingId === null) return;
+      try {
+        for (const id of redactionIds) await apiClient.deleteRedaction(recordingId, id);
+      } finally {
+        await refresh();
+      }
+    },
+    [recordingId, refresh],
+  );
+
+  const scanPii = useCallback(async () => {
+    if (recordingId === null) return 0;
+    const resp = await apiClient.scanPii(recordingId);
+    setRanges(resp.redactions);
+    return resp.redactions.filter((r) => r.source === 'pii').length;
+  }, [recordingId]);
+
+  return { ranges, loading, add, remove, scanPii, refresh };
+}
@@ -0,0 +1,78 @@
+/**
+ * useReviewTimeTracking — counts the time spent reviewing and editing an
+ * open recording (electron/timeTracking.ts), for transcribers who bill by
+ * the hour.
+ *
+ * With `timeTracking.enabled` a work session starts when the recording is
+ * opened and ends when it is closed. In between a heartbeat every
+ * HEARTBEAT_MS (electron/timeTrackingShared.ts) tells the main process
+ * whether audio is playing and the window has focus; the main process adds
+ * the system idle time and credits only the time actually worked. The project is read from the recording's
+ * `timeTracking.projectField` metadata field.
+ *
+ * Reads the `<audio>` element directly in the heartbeat, so the session is
+ * made once per open recording and never goes stale.
+ */
+
+import { useEffect, type RefObject } from 'react';
+import { getConfig } from '../config/store';
+import { HEARTBEAT_MS } from '../../electron/timeTrackingShared';
+
+export interface ReviewTimeOptions {
+  enabled: boolean;
+  recordingId: number | null;
+  title: string;
+  metadata?: Record<string, string>;
+  audioSeconds: number;
+}
+
+export function useReviewTimeTracking(
+  audioRef: RefObject<HTMLAudioElement | null>,
+  { enabled, recordingId, title, metadata, audioSeconds }: ReviewTimeOptions,
+): void {
+  const ready = enabled && recordingId !== null && audioSeconds > 0;
+  useEffect(() => {
+    const api = window.electronAPI?.timeTracking;
+    if (!ready || recordingId === null || !api) return;
+    let cancelled = false;
+    let sessionId: number | null = null;
+    let timer: number | undefined;
+
+    void Promise.all([
+      getConfig<boolean>('timeTracking.enabled'),
+      getConfig<string>('timeTracking.projectField'),
+    ]).then(async ([on, field]) => {
+      if (cancelled || on !== true) return;
+      const project = field ? (metadata?.[field] ?? '') : '';
+      const id = await api.begin({ recordingId, title, project, audioSeconds });
+      if (id === null) return;
+      if (cancelled) {
+        void api.end(id);
+        return;
+      }
+      sessionId = id;
+      timer = window.setInterval(() => {
+        const audio = audioRef.current;
+        void api.heartbeat(id, {
+          playing: !!audio && !audio.paused,
+          focused: document.hasFocus(),
+        });
+      }, HEARTBEAT_MS);
+    });
+
+    return () => {
+      cancelled = true;
+      window.clearInterval(timer);
+      if (sessionId === null) return;
+      const audio = audioRef.current;
+      const id = sessionId;
+      // Credit the stretch since the last heartbeat before closing.
+      void api
+        .heartbeat(id, { playing: !!audio && !audio.paused, focused: document.hasFocus() })
+        .finally(() => void api.end(id));
+    };
+    // Title and metadata are taken as they were when the recording opened;
+    // depending on them would split the session on every rename.
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [ready, recordingId, audioRef]);
+}
@@ -0,0 +1,33 @@
+import { useEffect, useState } from 'react';
+import { getConfig, setConfig } from '../config/store';
+
+/** Past this much use the app is not new any more, and the sample is not offered. */
+const NEW_USER_USAGE_MS = 30 * 60 * 1000;
+
+/**
+ * Offer the onboarding sample transcription (services/sampleJob.ts) once,
+ * the first time the server is ready for a new user.
+ */
+export function useSampleJobOffer(serverReady: boolean) {
+  const [showSampleJob, setShowSampleJob] = useState(false);
+
+  useEffect(() => {
+    if (!serverReady) return;
+    let cancelled = false;
+
+    async function check() {
+      if ((await getConfig<boolean>('app.sampleJobOffered')) ?? false) return;
+      const usageMs = (await getConfig<number>('app.cumulativeUsageMs')) ?? 0;
+      if (cancelled) return;
+      await setConfig('app.sampleJobOffered', true);
+      if (usageMs < NEW_USER_USAGE_MS) setShowSampleJob(true);
+    }
+
+    void check();
+    return () => {
+      cancelled = true;
+    };
+  }, [serverReady]);
+
+  return { showSampleJob, dismissSampleJob: () => setShowSampleJob(false) };
+}
@@ -13,6 +13,8 @@ export interface SearchOptions {
   startDate?: string;
   endDate?: string;
   limit?: number;
+  /** Only recordings whose custom fields match all of these. */
+  metadata?: Record<string, string>;
 }
 
 export interface SearchState {
@@ -0,0 +1,88 @@
+/**
+ * useSegmentReview — per-segment QA state (approved / flagged / unreviewed)
+ * for the transcript viewer's review mode.
+ *
+ * `states` is keyed by segment id. `setState` updates optimistically and
+ * rolls back (re-throwing) when the PUT fails, so keyboard review never
+ * waits on the network. `reloadKey` re-fetches when the segments change.
+ */
+
+import { useCallback, useEffect, useMemo, useState } from 'react';
+import { apiClient } from '../api/client';
+import type { SegmentReviewItem, SegmentReviewProgress, SegmentReviewState } from '../api/types';
+
+export interface SegmentReviewHookState {
+  states: Map<number, SegmentReviewState | null>;
+  progress: SegmentReviewProgress;
+  loading: boolean;
+  setState: (segmentIds: number[], state: SegmentReviewState | null) => Promise<void>;
+}
+
+function progressOf(items: SegmentReviewItem[]): SegmentReviewProgress {
+  const approved = items.filter((s) => s.state === 'approved').length;
+  const flagged = items.filter((s) => s.state === 'flagged').length;
+  const total = items.length;
+  return {
+    total,
+    approved,
+    flagged,
+    unreviewed: total - approved - flagged,
+    reviewed_fraction: total ? (approved + flagged) / total : 0,
+  };
+}
+
+export function useSegmentReview(
+  recordingId: number | null,
+  reloadKey?: unknown,
+): SegmentReviewHookState {
+  const [items, setItems] = useState<SegmentReviewItem[]>([]);
+  const [loading, setLoading] = useState(false);
+
+  useEffect(() => {
+    if (recordingId === null || !Number.isFinite(recordingId) || recordingId <= 0) {
+      setItems([]);
+      return;
+    }
+    let cancelled = false;
+    setLoading(true);
+    apiClient
+      .getSegmentReview(recordingId)
+      .then((resp) => {
+        if (!cancelled) setItems(resp.segments ?? []);
+      })
+      .catch(() => {
+        if (!cancelled) setItems([]);
+      })
+      .finally(() => {
+        if (!cancelled) setLoading(false);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [recordingId, reloadKey]);
+
+  const setState = useCallback(
+    async (segmentIds: number[], state: SegmentReviewState | null) => {
+      if (recordingId === null || segmentIds.length === 0) return;
+      const ids = new Set(segmentIds);
+      let previous: SegmentReviewItem[] = [];
+      setItems((current) => {
+        previous = current;
+        return current.map((s) => (ids.has(s.id) ? { ...s, state } : s));
+      });
+      try {
+        const resp = await apiClient.setSegmentReview(recordingId, segmentIds, state);
+        setItems(resp.segments ?? []);
+      } catch (err) {
+        setItems(previous);
+        throw err;
+      }
+    },
+    [recordingId],
+  );
+
+  const states = useMemo(() => new Map(items.map((s) => [s.id, s.state])), [items]);
+  const progress = useMemo(() => progressOf(items), [items]);
+
+  return { states, progress, loading, setState };
+}
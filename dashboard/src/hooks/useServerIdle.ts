@@ -0,0 +1,31 @@
+/**
+ * useServerIdle — state of the main-process idle manager
+ * (electron/idleManager.ts), which stops the local container after a quiet
+ * period when `server.idleStop.enabled` is on. Waking it before work is
+ * src/services/serverIdle.ts.
+ */
+
+import { useEffect, useState } from 'react';
+
+export function useServerIdle(): ServerIdleStatus | null {
+  const [status, setStatus] = useState<ServerIdleStatus | null>(null);
+
+  useEffect(() => {
+    const api = window.electronAPI?.serverIdle;
+    if (!api) return;
+    let cancelled = false;
+    api
+      .getStatus()
+      .then((current) => {
+        if (!cancelled) setStatus(current);
+      })
+      .catch(() => {});
+    const unsubscribe = api.onStatus(setStatus);
+    return () => {
+      cancelled = true;
+      unsubscribe();
+    };
+  }, []);
+
+  return status;
+}
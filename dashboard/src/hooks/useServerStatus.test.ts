@@ -138,4 +138,14 @@ describe('deriveStatus', () => {
     expect(info.gpuError).toBeNull();
     expect(info.serverLabel).toBe('Server ready');
   });
+
+  it('labels an unreachable server the idle manager put to sleep', () => {
+    const offline = makeResult({ reachable: false, status: null });
+
+    expect(deriveStatus(offline).serverLabel).toBe('Server offline');
+    expect(deriveStatus(offline, 'sleeping').serverLabel).toBe(
+      'Server sleeping — starts with the next job',
+    );
+    expect(deriveStatus(offline, 'waking').serverStatus).toBe('loading');
+  });
 });
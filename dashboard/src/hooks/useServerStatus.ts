@@ -1,5 +1,6 @@
 import { useQuery } from '@tanstack/react-query';
 import { apiClient, type ServerStatus } from '../api/client';
+import { useServerIdle } from './useServerIdle';
 
 export type ServerHealthState = 'active' | 'inactive' | 'warning' | 'error' | 'loading';
 
@@ -30,8 +31,16 @@ export interface ServerConnectionInfo {
   refresh: () => void;
 }
 
+/** Offline labels while the idle manager (electron/idleManager.ts) has the container down. */
+const IDLE_LABELS: Partial<Record<ServerIdleStatus['state'], string>> = {
+  stopping: 'Server going to sleep…',
+  sleeping: 'Server sleeping — starts with the next job',
+  waking: 'Waking server…',
+};
+
 export function deriveStatus(
   result: Awaited<ReturnType<typeof apiClient.checkConnection>> | undefined,
+  idleState: ServerIdleStatus['state'] = 'off',
 ): Omit<ServerConnectionInfo, 'refresh'> {
   if (!result) {
     return {
@@ -49,10 +58,10 @@ export function deriveStatus(
 
   if (!result.reachable) {
     return {
-      serverStatus: 'inactive',
+      serverStatus: idleState === 'waking' ? 'loading' : 'inactive',
       clientStatus: 'inactive',
       details: result.status,
-      serverLabel: 'Server offline',
+      serverLabel: IDLE_LABELS[idleState] ?? 'Server offline',
       reachable: false,
       ready: false,
       error: result.error,
@@ -117,9 +126,10 @@ export function useServerStatus(pollInterval = 10_000): ServerConnectionInfo {
     queryFn: () => apiClient.checkConnection(),
     refetchInterval: pollInterval,
   });
+  const idle = useServerIdle();
 
   return {
-    ...deriveStatus(data),
+    ...deriveStatus(data, idle?.state),
     refresh: () => void refetch(),
   };
 }
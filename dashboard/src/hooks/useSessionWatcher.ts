@@ -1,7 +1,8 @@
 /**
  * useSessionWatcher — manages the session folder-watch toggle.
  *
- * - Loads the persisted watch path AND active flag from config on mount (Issue #100).
+ * - Loads the persisted watch path AND active flag from config on mount (Issue #100),
+ *   and again whenever either key changes (useLiveConfigReload.ts).
  * - Starts/stops the watcher via IPC when the active toggle changes.
  * - Re-arms the watcher on launch only when both the saved path and active flag are truthy.
  * - Polls folder accessibility every 10s when watch is active (4.1).
@@ -14,6 +15,7 @@
 import { useState, useEffect, useCallback } from 'react';
 import { useImportQueueStore } from '../stores/importQueueStore';
 import { getConfig, setConfig } from '../config/store';
+import { useConfigChanged } from './useLiveConfigReload';
 
 export function useSessionWatcher() {
   const sessionWatchPath = useImportQueueStore((s) => s.sessionWatchPath);
@@ -40,20 +42,30 @@ export function useSessionWatcher() {
 
   // Hydrate path AND active flag together on mount (Issue #100).
   // Re-arm only when both are truthy — never path-only or active-only.
-  useEffect(() => {
-    Promise.all([
-      getConfig<string>('folderWatch.sessionPath'),
-      getConfig<boolean>('folderWatch.sessionWatchActive'),
-    ])
-      .then(([savedPath, savedActive]) => {
-        if (savedPath) setSessionWatchPath(savedPath);
-        if (savedPath && savedActive === true) setSessionWatchActiveRaw(true);
-      })
-      .catch((err: unknown) => {
-        const message = err instanceof Error ? err.message : 'Unknown error';
-        console.error('[useSessionWatcher] Failed to hydrate from config:', message);
-      });
-  }, [setSessionWatchPath, setSessionWatchActiveRaw]);
+  // After a live config change the saved flag also switches the watch off.
+  const hydrate = useCallback(
+    (live: boolean) => {
+      Promise.all([
+        getConfig<string>('folderWatch.sessionPath'),
+        getConfig<boolean>('folderWatch.sessionWatchActive'),
+      ])
+        .then(([savedPath, savedActive]) => {
+          if (savedPath) setSessionWatchPath(savedPath);
+          const active = Boolean(savedPath) && savedActive === true;
+          if (active || live) setSessionWatchActiveRaw(active);
+        })
+        .catch((err: unknown) => {
+          const message = err instanceof Error ? err.message : 'Unknown error';
+          console.error('[useSessionWatcher] Failed to hydrate from config:', message);
+        });
+    },
+    [setSessionWatchPath, setSessionWatchActiveRaw],
+  );
+
+  useEffect(() => hydrate(false), [hydrate]);
+  useConfigChanged(['folderWatch.sessionPath', 'folderWatch.sessionWatchActive'], () =>
+    hydrate(true),
+  );
 
   // Start / stop watcher when active state or path changes
   useEffect(() => {
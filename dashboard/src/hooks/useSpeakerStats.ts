@@ -0,0 +1,48 @@
+/**
+ * useSpeakerStats — per-speaker talk time, pace and interruptions for one
+ * recording (GET /api/notebook/recordings/:id/speaker-stats).
+ *
+ * `refreshKey` changes when the transcript or speaker names change, so the
+ * numbers and labels follow edits and renames without a manual reload.
+ */
+
+import { useEffect, useState } from 'react';
+import { apiClient } from '../api/client';
+import type { SpeakerStatsResponse } from '../api/types';
+
+/** "4:05" / "1:02:05" for a duration in seconds. */
+export function formatDuration(seconds: number): string {
+  const total = Math.max(0, Math.round(seconds));
+  const h = Math.floor(total / 3600);
+  const m = Math.floor((total % 3600) / 60);
+  const s = String(total % 60).padStart(2, '0');
+  return h > 0 ? `${h}:${String(m).padStart(2, '0')}:${s}` : `${m}:${s}`;
+}
+
+export function useSpeakerStats(
+  recordingId: number | null,
+  refreshKey?: unknown,
+): SpeakerStatsResponse | null {
+  const [stats, setStats] = useState<SpeakerStatsResponse | null>(null);
+
+  useEffect(() => {
+    if (recordingId === null || !Number.isFinite(recordingId) || recordingId <= 0) {
+      setStats(null);
+      return;
+    }
+    let cancelled = false;
+    apiClient
+      .getSpeakerStats(recordingId)
+      .then((resp) => {
+        if (!cancelled) setStats(resp);
+      })
+      .catch(() => {
+        if (!cancelled) setStats(null);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [recordingId, refreshKey]);
+
+  return stats;
+}
@@ -95,7 +95,12 @@ function resolveTrayState(
   }
 
   // Live mode states
-  if (liveStatus === 'listening' || liveStatus === 'starting' || liveStatus === 'connecting') {
+  if (
+    liveStatus === 'listening' ||
+    liveStatus === 'starting' ||
+    liveStatus === 'connecting' ||
+    liveStatus === 'reconnecting'
+  ) {
     return muted ? 'live-muted' : 'live-active';
   }
   if (liveStatus === 'processing') {
@@ -293,7 +293,7 @@ export class AudioCapture {
    * Poll enumerateDevices until a device whose label contains `substring`
    * appears.  Returns its deviceId.  Throws after `timeoutMs`.
    */
-  private static async waitForDevice(substring: string, timeoutMs = 3000): Promise<string> {
+  static async waitForDevice(substring: string, timeoutMs = 3000): Promise<string> {
     const start = Date.now();
     while (Date.now() - start < timeoutMs) {
       const devices = await navigator.mediaDevices.enumerateDevices();
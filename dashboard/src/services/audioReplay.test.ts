@@ -0,0 +1,35 @@
+import { describe, expect, it } from 'vitest';
+
+import { AudioReplayBuffer } from './audioReplay';
+
+const chunk = (samples: number) => new Int16Array(samples);
+
+describe('AudioReplayBuffer', () => {
+  it('numbers chunks and keeps them until acknowledged', () => {
+    const buffer = new AudioReplayBuffer();
+    expect([1, 2, 3].map(() => buffer.push(chunk(10)))).toEqual([1, 2, 3]);
+
+    buffer.ack(2);
+    expect(buffer.size).toBe(1);
+    expect(buffer.after(0).map((frame) => frame.seq)).toEqual([3]);
+  });
+
+  it('replays only what the server reports it is missing', () => {
+    const buffer = new AudioReplayBuffer();
+    for (let i = 0; i < 5; i++) buffer.push(chunk(10));
+    buffer.ack(1);
+
+    // The last ack was 1, but frames 2 and 3 reached the engine before the drop.
+    expect(buffer.after(3).map((frame) => frame.seq)).toEqual([4, 5]);
+  });
+
+  it('drops the oldest audio beyond its capacity and restarts numbering on clear', () => {
+    const buffer = new AudioReplayBuffer(25);
+    for (let i = 0; i < 4; i++) buffer.push(chunk(10));
+    expect(buffer.after(0).map((frame) => frame.seq)).toEqual([3, 4]);
+
+    buffer.clear();
+    expect(buffer.size).toBe(0);
+    expect(buffer.push(chunk(10))).toBe(1);
+  });
+});
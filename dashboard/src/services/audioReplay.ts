@@ -0,0 +1,60 @@
+/**
+ * Live Mode audio replay (server core/live_resume.py).
+ *
+ * Every captured chunk gets a `seq` and stays here until the server's
+ * `audio_ack` covers it. When the connection drops, capture keeps going
+ * into this buffer; after the session is resumed the chunks the engine
+ * never had are sent again, so nothing said during the outage is lost.
+ * The buffer holds a bounded amount of audio — an outage longer than that
+ * loses its oldest part rather than memory.
+ */
+
+export interface ReplayFrame {
+  seq: number;
+  pcm: Int16Array;
+}
+
+/** Two minutes at the live sample rate. */
+export const DEFAULT_REPLAY_SAMPLES = 16_000 * 120;
+
+export class AudioReplayBuffer {
+  private frames: ReplayFrame[] = [];
+  private samples = 0;
+  private nextSeq = 1;
+
+  constructor(private readonly maxSamples: number = DEFAULT_REPLAY_SAMPLES) {}
+
+  /** Keep a chunk until it is acknowledged; returns its seq. */
+  push(pcm: Int16Array): number {
+    const seq = this.nextSeq++;
+    this.frames.push({ seq, pcm });
+    this.samples += pcm.length;
+    while (this.samples > this.maxSamples && this.frames.length > 1) {
+      this.samples -= this.frames.shift()!.pcm.length;
+    }
+    return seq;
+  }
+
+  /** Drop every chunk up to and including `seq`. */
+  ack(seq: number): void {
+    while (this.frames.length > 0 && this.frames[0].seq <= seq) {
+      this.samples -= this.frames.shift()!.pcm.length;
+    }
+  }
+
+  /** Chunks the server does not have, given the last seq it reported. */
+  after(seq: number): ReplayFrame[] {
+    return this.frames.filter((frame) => frame.seq > seq);
+  }
+
+  /** Start over for a new session. */
+  clear(): void {
+    this.frames = [];
+    this.samples = 0;
+    this.nextSeq = 1;
+  }
+
+  get size(): number {
+    return this.frames.length;
+  }
+}
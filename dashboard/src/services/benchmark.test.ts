@@ -0,0 +1,119 @@
+import { describe, it, expect, vi } from 'vitest';
+
+import {
+  recommendTarget,
+  runBenchmark,
+  type BenchmarkDeps,
+  type BenchmarkResult,
+  type BenchmarkSample,
+} from './benchmark';
+
+const SAMPLE: BenchmarkSample = {
+  file: new File([new Uint8Array(8)], 'reference.wav', { type: 'audio/wav' }),
+  reference: 'the quick brown fox',
+};
+
+const result = (
+  profile: BenchmarkResult['profile'],
+  model: string,
+  realtimeFactor: number | null,
+  wer: number | null,
+): BenchmarkResult => ({
+  profile,
+  model,
+  realtimeFactor,
+  wer,
+  error: realtimeFactor === null ? 'failed' : null,
+});
+
+describe('recommendTarget', () => {
+  it('takes the fastest run that is about as accurate as the best', () => {
+    expect(
+      recommendTarget([
+        result('local', 'large-v3', 0.5, 0.05),
+        result('lan', 'large-v3', 0.1, 0.06),
+        result('lan', 'tiny', 0.02, 0.3),
+      ]),
+    ).toEqual({ profile: 'lan', model: 'large-v3' });
+  });
+
+  it('recommends nothing when every run failed', () => {
+    expect(recommendTarget([result('local', 'large-v3', null, null)])).toBeNull();
+  });
+});
+
+describe('runBenchmark', () => {
+  it('benchmarks each server and flattens its runs', async () => {
+    const onTarget = vi.fn();
+    const deps: BenchmarkDeps = {
+      benchmarkServer: vi.fn(async (profile, _sample, models) => ({
+        duration: 12,
+        runs: (models.length ? models : ['main']).map((model) => ({
+          model,
+          seconds: 1,
+          realtime_factor: profile === 'lan' ? 0.05 : 0.2,
+          wer: 0.1,
+          text: '',
+          error: null,
+        })),
+      })),
+      onTarget,
+      now: () => new Date('2026-10-15T10:00:00Z'),
+    };
+
+    const report = await runBenchmark(
+      SAMPLE,
+      [
+        { profile: 'local', models: [] },
+        { profile: 'lan', models: ['large-v3', 'small'] },
+      ],
+      deps,
+    );
+
+    expect(deps.benchmarkServer).toHaveBeenCalledWith('lan', SAMPLE, ['large-v3', 'small']);
+    expect(onTarget).toHaveBeenCalledTimes(2);
+    expect(report.results.map((r) => `${r.profile}:${r.model}`)).toEqual([
+      'local:main',
+      'lan:large-v3',
+      'lan:small',
+    ]);
+    expect(report).toMatchObject({
+      ranAt: '2026-10-15T10:00:00.000Z',
+      sampleName: 'reference.wav',
+      audioSeconds: 12,
+      recommended: { profile: 'lan', model: 'large-v3' },
+    });
+  });
+
+  it('records an unreachable or unconfigured server and carries on', async () => {
+    const deps: BenchmarkDeps = {
+      benchmarkServer: vi.fn(async (profile) => {
+        if (profile === 'tailscale') return null;
+        if (profile === 'lan') throw new Error('connection refused');
+        return {
+          duration: 12,
+          runs: [
+            { model: 'small', seconds: 2, realtime_factor: 0.2, wer: 0.1, text: '', error: null },
+          ],
+        };
+      }),
+    };
+
+    const report = await runBenchmark(
+      SAMPLE,
+      [
+        { profile: 'tailscale', models: [] },
+        { profile: 'lan', models: ['large-v3'] },
+        { profile: 'local', models: ['small'] },
+      ],
+      deps,
+    );
+
+    expect(report.results.map((r) => r.error)).toEqual([
+      'no host configured for this connection',
+      'connection refused',
+      null,
+    ]);
+    expect(report.recommended).toEqual({ profile: 'local', model: 'small' });
+  });
+});
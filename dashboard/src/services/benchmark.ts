@@ -0,0 +1,158 @@
+import { APIClient } from '../api/client';
+import type { ModelBenchmarkResponse } from '../api/types';
+import { setConfig, type ConnectionProfile } from '../config/store';
+
+/**
+ * Server and model benchmark: one reference clip with a known transcript is
+ * transcribed by the selected models on each selected connection profile,
+ * and every run is reported with its realtime factor and word error rate
+ * (POST /api/admin/benchmark, server/backend/core/model_benchmark.py).
+ *
+ * The clip is the user's own — a reference is only as good as its checked
+ * transcript, and a clip from their own recordings (microphones, accents,
+ * vocabulary) ranks the models for what they actually transcribe.
+ *
+ * The report is stored with a recommendation: the fastest run whose WER is
+ * within `WER_TOLERANCE` of the most accurate one. It becomes the dispatch
+ * default (`dispatch.profile` / `dispatch.model`) that new jobs start from.
+ */
+
+export interface BenchmarkSample {
+  file: File;
+  /** The clip's correct transcript. */
+  reference: string;
+  language?: string;
+}
+
+export interface BenchmarkTarget {
+  profile: ConnectionProfile;
+  /** Models to compare on that server; empty benchmarks its main model. */
+  models: string[];
+}
+
+export interface BenchmarkResult {
+  profile: ConnectionProfile;
+  /** As the server reports it — the main model by its full name. */
+  model: string;
+  realtimeFactor: number | null;
+  wer: number | null;
+  /** Why the run (or the whole server) failed; null on success. */
+  error: string | null;
+}
+
+export interface BenchmarkReport {
+  ranAt: string;
+  sampleName: string;
+  /** Length of the clip, from the first server that answered. */
+  audioSeconds: number | null;
+  results: BenchmarkResult[];
+  recommended: { profile: ConnectionProfile; model: string } | null;
+}
+
+export interface BenchmarkDeps {
+  /** Null when the profile has no host configured. */
+  benchmarkServer: (
+    profile: ConnectionProfile,
+    sample: BenchmarkSample,
+    models: string[],
+  ) => Promise<ModelBenchmarkResponse | null>;
+  /** Called before each server, for progress. */
+  onTarget?: (target: BenchmarkTarget, index: number, total: number) => void;
+  now?: () => Date;
+}
+
+/** How much worse than the most accurate run (as WER) the fastest may be. */
+export const WER_TOLERANCE = 0.02;
+
+export const PROFILE_LABELS: Record<ConnectionProfile, string> = {
+  local: 'Local',
+  tailscale: 'Tailscale',
+  lan: 'LAN',
+};
+
+/** The fastest run whose WER is within `WER_TOLERANCE` of the best; null if none succeeded. */
+export function recommendTarget(results: BenchmarkResult[]): BenchmarkReport['recommended'] {
+  const ok = results.filter(
+    (r): r is BenchmarkResult & { realtimeFactor: number; wer: number } =>
+      r.realtimeFactor !== null && r.wer !== null,
+  );
+  if (ok.length === 0) return null;
+  const bestWer = Math.min(...ok.map((r) => r.wer));
+  const best = ok
+    .filter((r) => r.wer <= bestWer + WER_TOLERANCE)
+    .reduce((a, b) => (b.realtimeFactor < a.realtimeFactor ? b : a));
+  return { profile: best.profile, model: best.model };
+}
+
+function describeError(err: unknown): string {
+  return err instanceof Error ? err.message : String(err);
+}
+
+/** Benchmark `sample` on each target in turn; one server failing does not stop the rest. */
+export async function runBenchmark(
+  sample: BenchmarkSample,
+  targets: BenchmarkTarget[],
+  deps: BenchmarkDeps = apiBenchmarkDeps(),
+): Promise<BenchmarkReport> {
+  const results: BenchmarkResult[] = [];
+  let audioSeconds: number | null = null;
+  for (const [index, target] of targets.entries()) {
+    deps.onTarget?.(target, index, targets.length);
+    const failed = (error: string) =>
+      results.push({
+        profile: target.profile,
+        model: target.models.join(', '),
+        realtimeFactor: null,
+        wer: null,
+        error,
+      });
+    try {
+      const response = await deps.benchmarkServer(target.profile, sample, target.models);
+      if (!response) {
+        failed('no host configured for this connection');
+        continue;
+      }
+      audioSeconds ??= response.duration;
+      for (const run of response.runs) {
+        results.push({
+          profile: target.profile,
+          model: run.model,
+          realtimeFactor: run.realtime_factor,
+          wer: run.wer,
+          error: run.error,
+        });
+      }
+    } catch (err) {
+      failed(describeError(err));
+    }
+  }
+  return {
+    ranAt: (deps.now?.() ?? new Date()).toISOString(),
+    sampleName: sample.file.name,
+    audioSeconds,
+    results,
+    recommended: recommendTarget(results),
+  };
+}
+
+/** Store the report; its recommendation becomes the dispatch default. */
+export async function saveBenchmarkReport(report: BenchmarkReport): Promise<void> {
+  await setConfig('dispatch.lastBenchmark', report);
+  if (report.recommended) {
+    await setConfig('dispatch.profile', report.recommended.profile);
+    await setConfig('dispatch.model', report.recommended.model);
+  }
+}
+
+/** The real servers, each through its own client so the app's connection stays put. */
+export function apiBenchmarkDeps(onTarget?: BenchmarkDeps['onTarget']): BenchmarkDeps {
+  return {
+    benchmarkServer: async (profile, sample, models) => {
+      const client = await APIClient.forProfile(profile);
+      return client
+        ? client.benchmarkModels(sample.file, sample.reference, models, sample.language)
+        : null;
+    },
+    onTarget,
+  };
+}
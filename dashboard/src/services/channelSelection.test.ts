@@ -0,0 +1,35 @@
+import { describe, expect, it } from 'vitest';
+import { audibleChannels, channelFilename, channelsLabel, meterPercent } from './channelSelection';
+
+describe('channelFilename', () => {
+  it('names the channels 1-based and in order', () => {
+    expect(channelsLabel([2])).toBe('ch3');
+    expect(channelsLabel([2, 0])).toBe('ch1+3');
+    expect(channelFilename('panel.wav', [2])).toBe('panel_ch3.wav');
+    expect(channelFilename('panel', [0, 1])).toBe('panel_ch1+2');
+  });
+});
+
+describe('meterPercent', () => {
+  it('maps the floor to empty and full scale to full', () => {
+    expect(meterPercent(-96)).toBe(0);
+    expect(meterPercent(-60)).toBe(0);
+    expect(meterPercent(-30)).toBe(50);
+    expect(meterPercent(0)).toBe(100);
+  });
+});
+
+describe('audibleChannels', () => {
+  it('leaves silent channels out of the preselection', () => {
+    const levels = [
+      { index: 0, rmsDb: -96 },
+      { index: 1, rmsDb: -22 },
+      { index: 2, rmsDb: -30 },
+    ];
+    expect(audibleChannels(levels)).toEqual([1, 2]);
+  });
+
+  it('keeps everything when every channel is silent', () => {
+    expect(audibleChannels([{ index: 0, rmsDb: -96 }, { index: 1, rmsDb: -96 }])).toEqual([0, 1]);
+  });
+});
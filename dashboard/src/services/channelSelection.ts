@@ -0,0 +1,38 @@
+/**
+ * Channel selection for local imports: transcribe only the lav mic on
+ * channel 3 of a panel recording instead of the whole mix. The main process
+ * reports per-channel preview levels (electron/channelProbe.ts) and isolates
+ * the chosen channels — averaged to mono when there are several — before the
+ * upload (electron/mediaClip.ts).
+ */
+
+/** Level shown as an empty meter; quieter channels are effectively silent. */
+export const METER_FLOOR_DB = -60;
+
+/** 0..100 meter width for a dBFS level. */
+export function meterPercent(db: number): number {
+  if (db <= METER_FLOOR_DB) return 0;
+  return Math.round(Math.min(1, 1 - db / METER_FLOOR_DB) * 100);
+}
+
+/** `[2]` → `ch3`, `[0, 2]` → `ch1+3` — 1-based, as channels are labelled. */
+export function channelsLabel(channels: number[]): string {
+  return `ch${[...channels]
+    .sort((a, b) => a - b)
+    .map((c) => c + 1)
+    .join('+')}`;
+}
+
+/** `panel.wav` → `panel_ch3.wav`, so a channel never overwrites the full mix's transcript. */
+export function channelFilename(filename: string, channels: number[]): string {
+  const dot = filename.lastIndexOf('.');
+  const stem = dot > 0 ? filename.slice(0, dot) : filename;
+  const ext = dot > 0 ? filename.slice(dot) : '';
+  return `${stem}_${channelsLabel(channels)}${ext}`;
+}
+
+/** The channels worth preselecting: all of them, unless some are silent. */
+export function audibleChannels(levels: { index: number; rmsDb: number }[]): number[] {
+  const audible = levels.filter((l) => l.rmsDb > METER_FLOOR_DB).map((l) => l.index);
+  return audible.length > 0 ? audible : levels.map((l) => l.index);
+}
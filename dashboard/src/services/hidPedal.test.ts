@@ -0,0 +1,32 @@
+import { describe, expect, it } from 'vitest';
+import { deviceKey, diffButtons, pressedMask, withBinding } from './hidPedal';
+
+const report = (...bytes: number[]) => new DataView(new Uint8Array(bytes).buffer);
+
+describe('hidPedal', () => {
+  it('reads the button bitmask from the report', () => {
+    expect(pressedMask(report(0b101))).toBe(5);
+    expect(pressedMask(report(0, 1))).toBe(256);
+    expect(pressedMask(report())).toBe(0);
+  });
+
+  it('detects press and release edges', () => {
+    expect(diffButtons(0, 0b010)).toEqual({ pressed: [1], released: [] });
+    expect(diffButtons(0b011, 0b110)).toEqual({ pressed: [2], released: [0] });
+  });
+
+  it('keys devices by vendor and product id', () => {
+    expect(deviceKey({ vendorId: 0x05f3, productId: 0xff })).toBe('05f3:00ff');
+  });
+
+  it('replaces and removes bindings per pedal', () => {
+    let bindings = withBinding([], '05f3:00ff', 1, 'play_while_held');
+    bindings = withBinding(bindings, '05f3:00ff', 0, 'rewind');
+    bindings = withBinding(bindings, '05f3:00ff', 1, 'play_pause');
+    expect(bindings).toEqual([
+      { deviceKey: '05f3:00ff', button: 0, action: 'rewind' },
+      { deviceKey: '05f3:00ff', button: 1, action: 'play_pause' },
+    ]);
+    expect(withBinding(bindings, '05f3:00ff', 0, null)).toHaveLength(1);
+  });
+});
@@ -0,0 +1,200 @@
+/**
+ * Foot pedal / HID controller support for transcript review.
+ *
+ * Pedals are read through Chromium's WebHID (hidapi underneath); the main
+ * process grants HID device access (electron/hidAccess.ts), so no native
+ * module ships with the app. Pedals report a button bitmask — the common
+ * 3-pedal units (Infinity IN-USB, Olympus RS, Philips ACC) send left=1,
+ * centre=2, right=4 in the first byte — so a binding is simply
+ * `(device, bit index) → action`, learned by pressing the pedal.
+ *
+ * Bindings persist in the client config under `hidPedal.bindings`.
+ */
+
+import { getConfig, setConfig } from '../config/store';
+
+export type HidPedalAction = 'play_pause' | 'play_while_held' | 'rewind' | 'forward';
+
+export const HID_PEDAL_ACTIONS: Record<HidPedalAction, string> = {
+  play_pause: 'Play / pause',
+  play_while_held: 'Play while held',
+  rewind: 'Rewind',
+  forward: 'Fast-forward',
+};
+
+export interface HidBinding {
+  /** `vendorId:productId` in hex — stable across reconnects, unlike the handle. */
+  deviceKey: string;
+  /** Bit index in the input report (0 = first pedal). */
+  button: number;
+  action: HidPedalAction;
+}
+
+export interface HidDeviceInfo {
+  key: string;
+  vendorId: number;
+  productId: number;
+  name: string;
+}
+
+/** Minimal WebHID surface — `lib.dom` does not ship these types yet. */
+export interface HidDeviceLike extends EventTarget {
+  vendorId: number;
+  productId: number;
+  productName: string;
+  opened: boolean;
+  open(): Promise<void>;
+  close(): Promise<void>;
+}
+
+export interface HidInputReportEventLike extends Event {
+  device: HidDeviceLike;
+  data: DataView;
+}
+
+interface HidLike extends EventTarget {
+  getDevices(): Promise<HidDeviceLike[]>;
+}
+
+export function getHid(): HidLike | null {
+  if (typeof navigator === 'undefined') return null;
+  return (navigator as Navigator & { hid?: HidLike }).hid ?? null;
+}
+
+export function deviceKey(device: { vendorId: number; productId: number }): string {
+  const hex = (n: number) => n.toString(16).padStart(4, '0');
+  return `${hex(device.vendorId)}:${hex(device.productId)}`;
+}
+
+/** HID devices the app may open, one entry per model (multi-interface units collapse). */
+export async function listHidDevices(): Promise<HidDeviceInfo[]> {
+  const hid = getHid();
+  if (!hid) return [];
+  const seen = new Map<string, HidDeviceInfo>();
+  for (const device of await hid.getDevices()) {
+    const key = deviceKey(device);
+    if (!seen.has(key)) {
+      seen.set(key, {
+        key,
+        vendorId: device.vendorId,
+        productId: device.productId,
+        name: device.productName || `HID device ${key}`,
+      });
+    }
+  }
+  return [...seen.values()];
+}
+
+/** Pressed-button bitmask from an input report (first four bytes, little-endian). */
+export function pressedMask(data: DataView): number {
+  let mask = 0;
+  for (let i = 0; i < Math.min(4, data.byteLength); i++) {
+    mask |= data.getUint8(i) << (8 * i);
+  }
+  return mask >>> 0;
+}
+
+/** Bit indices that went down / up between two reports. */
+export function diffButtons(
+  prev: number,
+  next: number,
+): { pressed: number[]; released: number[] } {
+  const pressed: number[] = [];
+  const released: number[] = [];
+  for (let bit = 0; bit < 32; bit++) {
+    const was = (prev >>> bit) & 1;
+    const is = (next >>> bit) & 1;
+    if (is && !was) pressed.push(bit);
+    if (was && !is) released.push(bit);
+  }
+  return { pressed, released };
+}
+
+/** Replace any binding for the same pedal; `action: null` removes it. */
+export function withBinding(
+  bindings: HidBinding[],
+  deviceKeyValue: string,
+  button: number,
+  action: HidPedalAction | null,
+): HidBinding[] {
+  const rest = bindings.filter((b) => b.deviceKey !== deviceKeyValue || b.button !== button);
+  if (!action) return rest;
+  return [...rest, { deviceKey: deviceKeyValue, button, action }].sort(
+    (a, b) => a.deviceKey.localeCompare(b.deviceKey) || a.button - b.button,
+  );
+}
+
+export async function loadHidBindings(): Promise<HidBinding[]> {
+  return (await getConfig<HidBinding[]>('hidPedal.bindings')) ?? [];
+}
+
+/** Bind (or, with `null`, unbind) one pedal button and persist the result. */
+export async function bindHidAction(
+  deviceKeyValue: string,
+  button: number,
+  action: HidPedalAction | null,
+): Promise<HidBinding[]> {
+  const next = withBinding(await loadHidBindings(), deviceKeyValue, button, action);
+  await setConfig('hidPedal.bindings', next);
+  return next;
+}
+
+/**
+ * Open every permitted device and route input reports to `onReport`
+ * (re-opening devices plugged in later). Returns a cleanup function.
+ */
+export function subscribeHidReports(
+  onReport: (device: HidDeviceLike, mask: number) => void,
+): () => void {
+  const hid = getHid();
+  if (!hid) return () => {};
+  const opened = new Set<HidDeviceLike>();
+  const onInput = (event: Event) => {
+    const e = event as HidInputReportEventLike;
+    onReport(e.device, pressedMask(e.data));
+  };
+  const attach = async (device: HidDeviceLike) => {
+    if (opened.has(device)) return;
+    opened.add(device);
+    try {
+      if (!device.opened) await device.open();
+      device.addEventListener('inputreport', onInput);
+    } catch {
+      // Claimed by another app or blocked usage page — not a pedal we can read.
+      opened.delete(device);
+    }
+  };
+  const onConnect = (event: Event) => {
+    void attach((event as Event & { device: HidDeviceLike }).device);
+  };
+  void hid.getDevices().then((devices) => devices.forEach((d) => void attach(d)));
+  hid.addEventListener('connect', onConnect);
+  return () => {
+    hid.removeEventListener('connect', onConnect);
+    for (const device of opened) {
+      device.removeEventListener('inputreport', onInput);
+    }
+    opened.clear();
+  };
+}
+
+/** Resolve with the first pedal pressed (for binding), or `null` after `timeoutMs`. */
+export function waitForPedalPress(
+  timeoutMs = 10_000,
+): Promise<{ deviceKey: string; button: number } | null> {
+  return new Promise((resolve) => {
+    const last = new Map<HidDeviceLike, number>();
+    const unsubscribe = subscribeHidReports((device, mask) => {
+      const { pressed } = diffButtons(last.get(device) ?? 0, mask);
+      last.set(device, mask);
+      if (pressed.length === 0) return;
+      finish({ deviceKey: deviceKey(device), button: pressed[0] });
+    });
+    const timer = setTimeout(() => finish(null), timeoutMs);
+    function finish(result: { deviceKey: string; button: number } | null) {
+      clearTimeout(timer);
+      unsubscribe();
+      resolve(result);
+    }
+  });
+}
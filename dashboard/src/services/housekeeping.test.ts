@@ -0,0 +1,135 @@
+import { describe, it, expect, vi } from 'vitest';
+
+import {
+  dueWindow,
+  parseClock,
+  runHousekeeping,
+  windowDate,
+  type HousekeepingActions,
+  type HousekeepingSettings,
+} from './housekeeping';
+
+const SETTINGS: HousekeepingSettings = {
+  enabled: true,
+  windowStart: '03:00',
+  windowEnd: '05:00',
+  pruneModelCache: true,
+  pruneUnusedDays: 30,
+  rotateLogs: true,
+  goldenCheck: false,
+  restartContainer: true,
+  lastRunDate: '',
+};
+
+const at = (iso: string) => new Date(`${iso}:00`);
+
+describe('housekeeping schedule', () => {
+  it('parses clock times', () => {
+    expect(parseClock('03:30')).toBe(210);
+    expect(parseClock('7:05')).toBe(425);
+    expect(parseClock('24:00')).toBeNull();
+    expect(parseClock('noon')).toBeNull();
+  });
+
+  it('dates a window by the day it opened, across midnight', () => {
+    expect(windowDate(at('2026-03-10T04:00'), '03:00', '05:00')).toBe('2026-03-10');
+    expect(windowDate(at('2026-03-10T05:00'), '03:00', '05:00')).toBeNull();
+    expect(windowDate(at('2026-03-10T23:45'), '23:30', '01:00')).toBe('2026-03-10');
+    expect(windowDate(at('2026-03-11T00:30'), '23:30', '01:00')).toBe('2026-03-10');
+    expect(windowDate(at('2026-03-11T12:00'), '23:30', '01:00')).toBeNull();
+    expect(windowDate(at('2026-03-11T12:00'), '12:00', '12:00')).toBeNull();
+  });
+
+  it('runs once per window and only when enabled', () => {
+    const now = at('2026-03-10T03:10');
+    expect(dueWindow(SETTINGS, now)).toBe('2026-03-10');
+    expect(dueWindow({ ...SETTINGS, lastRunDate: '2026-03-10' }, now)).toBeNull();
+    expect(dueWindow({ ...SETTINGS, enabled: false }, now)).toBeNull();
+    expect(
+      dueWindow(
+        { ...SETTINGS, pruneModelCache: false, rotateLogs: false, restartContainer: false },
+        now,
+      ),
+    ).toBeNull();
+  });
+});
+
+describe('runHousekeeping', () => {
+  it('restarts last and keeps going after a failure', async () => {
+    const calls: string[] = [];
+    const results = await runHousekeeping(SETTINGS, {
+      rotateLogs: vi.fn(async () => {
+        calls.push('rotate');
+        throw new Error('disk full');
+      }),
+      pruneModelCache: vi.fn(async (days: number) => {
+        calls.push(`prune ${days}`);
+        return { removed: [{ model: 'org/old' }], freed_bytes: 5 * 1024 * 1024 };
+      }),
+      goldenCheck: vi.fn(),
+      restartContainer: vi.fn(async () => calls.push('restart')),
+    });
+
+    expect(calls).toEqual(['rotate', 'prune 30', 'restart']);
+    expect(results.map((r) => r.outcome)).toEqual(['failed', 'done', 'done']);
+    expect(results[0].detail).toBe('disk full');
+    expect(results[1].detail).toBe('removed org/old (5 MB)');
+  });
+
+  it('skips the restart without a local container', async () => {
+    const results = await runHousekeeping(
+      { ...SETTINGS, rotateLogs: false, pruneModelCache: false },
+      {
+        rotateLogs: vi.fn(),
+        pruneModelCache: vi.fn(),
+        goldenCheck: vi.fn(),
+        restartContainer: null,
+      },
+    );
+
+    expect(results).toEqual([
+      { task: 'restartContainer', outcome: 'skipped', detail: 'no local container to restart' },
+    ]);
+  });
+
+  it('reports golden-set drift and fails on a regression', async () => {
+    const only = {
+      ...SETTINGS,
+      rotateLogs: false,
+      pruneModelCache: false,
+      goldenCheck: true,
+      restartContainer: false,
+    };
+    const actions = (report: Awaited<ReturnType<HousekeepingActions['goldenCheck']>>) => ({
+      rotateLogs: vi.fn(),
+      pruneModelCache: vi.fn(),
+      goldenCheck: vi.fn(async () => report),
+      restartContainer: null,
+    });
+    const runs = [{ error: null }, { error: 'audio gone' }];
+
+    const [steady] = await runHousekeeping(
+      only,
+      actions({ runs, mean_latest_wer: 0.082, drift: -0.004, regressed: false }),
+    );
+    const [worse] = await runHousekeeping(
+      only,
+      actions({ runs, mean_latest_wer: 0.12, drift: 0.035, regressed: true }),
+    );
+    const [empty] = await runHousekeeping(
+      only,
+      actions({ runs: [], mean_latest_wer: null, drift: null, regressed: false }),
+    );
+
+    expect(steady).toEqual({
+      task: 'goldenCheck',
+      outcome: 'done',
+      detail: 'WER 8.2%, -0.4 points since the baseline, 1 of 2 failed',
+    });
+    expect(worse.outcome).toBe('failed');
+    expect(worse.detail).toBe(
+      'accuracy dropped — WER 12.0%, +3.5 points since the baseline, 1 of 2 failed',
+    );
+    expect(empty).toMatchObject({ outcome: 'skipped', detail: 'no reference transcripts' });
+  });
+});
@@ -0,0 +1,162 @@
+import type { ClientConfig } from '../config/store';
+
+export type HousekeepingSettings = ClientConfig['housekeeping'];
+
+export type HousekeepingTask =
+  | 'rotateLogs'
+  | 'pruneModelCache'
+  | 'goldenCheck'
+  | 'restartContainer';
+
+export interface HousekeepingResult {
+  task: HousekeepingTask;
+  outcome: 'done' | 'skipped' | 'failed';
+  detail: string;
+}
+
+/**
+ * How each task is carried out. The server tasks go through the API client;
+ * `restartContainer` is null when there is no local container to restart
+ * (remote server, Metal runtime, container not running).
+ */
+export interface HousekeepingActions {
+  rotateLogs: () => Promise<{ rotated: string[] }>;
+  pruneModelCache: (
+    unusedDays: number,
+  ) => Promise<{ removed: { model: string }[]; freed_bytes: number }>;
+  goldenCheck: () => Promise<{
+    runs: { error: string | null }[];
+    mean_latest_wer: number | null;
+    drift: number | null;
+    regressed: boolean;
+  }>;
+  restartContainer: (() => Promise<unknown>) | null;
+}
+
+export const TASK_LABELS: Record<HousekeepingTask, string> = {
+  rotateLogs: 'Log rotation',
+  pruneModelCache: 'Model cache prune',
+  goldenCheck: 'Accuracy check',
+  restartContainer: 'Container restart',
+};
+
+/** Minutes after midnight for a "HH:MM" string, or null if malformed. */
+export function parseClock(value: string): number | null {
+  const match = /^(\d{1,2}):(\d{2})$/.exec(value.trim());
+  if (!match) return null;
+  const hours = Number(match[1]);
+  const minutes = Number(match[2]);
+  return hours < 24 && minutes < 60 ? hours * 60 + minutes : null;
+}
+
+const pad = (n: number) => String(n).padStart(2, '0');
+const dateKey = (d: Date) => `${d.getFullYear()}-${pad(d.getMonth() + 1)}-${pad(d.getDate())}`;
+
+/**
+ * The date the window containing `now` opened on (YYYY-MM-DD, local time),
+ * or null when `now` is outside the window. Windows may cross midnight
+ * ("23:30"–"01:00"); a window whose start equals its end is empty.
+ */
+export function windowDate(now: Date, start: string, end: string): string | null {
+  const from = parseClock(start);
+  const to = parseClock(end);
+  if (from === null || to === null || from === to) return null;
+  const minute = now.getHours() * 60 + now.getMinutes();
+  if (from < to) return minute >= from && minute < to ? dateKey(now) : null;
+  if (minute >= from) return dateKey(now);
+  if (minute < to) {
+    const opened = new Date(now);
+    opened.setDate(opened.getDate() - 1);
+    return dateKey(opened);
+  }
+  return null;
+}
+
+/** Run order: the restart goes last so the server tasks are not cut short. */
+const TASK_ORDER: readonly HousekeepingTask[] = [
+  'rotateLogs',
+  'pruneModelCache',
+  'goldenCheck',
+  'restartContainer',
+];
+
+export function enabledTasks(settings: HousekeepingSettings): HousekeepingTask[] {
+  return TASK_ORDER.filter((task) => settings[task]);
+}
+
+/** The window date to run for, or null when tonight's run is done or not due. */
+export function dueWindow(settings: HousekeepingSettings, now: Date): string | null {
+  if (!settings.enabled || enabledTasks(settings).length === 0) return null;
+  const opened = windowDate(now, settings.windowStart, settings.windowEnd);
+  return opened && opened !== settings.lastRunDate ? opened : null;
+}
+
+const megabytes = (bytes: number) => `${(bytes / 1024 / 1024).toFixed(0)} MB`;
+const percent = (wer: number) => `${(wer * 100).toFixed(1)}%`;
+
+/** "WER 8.2%, +1.5 points since the baseline"; a regression is reported as a failure. */
+function goldenResult(
+  report: Awaited<ReturnType<HousekeepingActions['goldenCheck']>>,
+): Omit<HousekeepingResult, 'task'> {
+  if (report.runs.length === 0) {
+    return { outcome: 'skipped', detail: 'no reference transcripts' };
+  }
+  const parts: string[] = [];
+  if (report.mean_latest_wer !== null) parts.push(`WER ${percent(report.mean_latest_wer)}`);
+  if (report.drift !== null) {
+    const points = (report.drift * 100).toFixed(1);
+    parts.push(`${report.drift > 0 ? '+' : ''}${points} points since the baseline`);
+  }
+  const errors = report.runs.filter((r) => r.error).length;
+  if (errors) parts.push(`${errors} of ${report.runs.length} failed`);
+  const detail = parts.join(', ') || 'baseline recorded';
+  return report.regressed
+    ? { outcome: 'failed', detail: `accuracy dropped — ${detail}` }
+    : { outcome: 'done', detail };
+}
+
+/**
+ * Run the enabled tasks one after another. The restart goes last so the
+ * server tasks are not cut short; a failed task does not stop the others.
+ */
+export async function runHousekeeping(
+  settings: HousekeepingSettings,
+  actions: HousekeepingActions,
+): Promise<HousekeepingResult[]> {
+  const results: HousekeepingResult[] = [];
+  for (const task of enabledTasks(settings)) {
+    try {
+      if (task === 'rotateLogs') {
+        const { rotated } = await actions.rotateLogs();
+        results.push({
+          task,
+          outcome: rotated.length ? 'done' : 'skipped',
+          detail: rotated.length ? 'server.log rotated' : 'no log file to rotate',
+        });
+      } else if (task === 'pruneModelCache') {
+        const { removed, freed_bytes } = await actions.pruneModelCache(settings.pruneUnusedDays);
+        results.push({
+          task,
+          outcome: 'done',
+          detail: removed.length
+            ? `removed ${removed.map((r) => r.model).join(', ')} (${megabytes(freed_bytes)})`
+            : `nothing unused; freed ${megabytes(freed_bytes)}`,
+        });
+      } else if (task === 'goldenCheck') {
+        results.push({ task, ...goldenResult(await actions.goldenCheck()) });
+      } else if (actions.restartContainer) {
+        await actions.restartContainer();
+        results.push({ task, outcome: 'done', detail: 'container restarted' });
+      } else {
+        results.push({ task, outcome: 'skipped', detail: 'no local container to restart' });
+      }
+    } catch (err) {
+      results.push({
+        task,
+        outcome: 'failed',
+        detail: err instanceof Error ? err.message : String(err),
+      });
+    }
+  }
+  return results;
+}
@@ -0,0 +1,171 @@
+import { describe, it, expect, vi, beforeEach, afterEach, type Mock } from 'vitest';
+
+import {
+  InterviewRecorder,
+  MIC_CHANNEL,
+  SYSTEM_CHANNEL,
+  interviewChannelLabels,
+  pickInterviewMimeType,
+} from './interviewRecorder';
+import { loopbackOwner } from './loopbackOwner';
+
+vi.mock('./loopbackOwner', () => ({
+  LOOPBACK_DEVICE_LABEL: 'TranscriptionSuite_Loopback',
+  loopbackOwner: {
+    acquire: vi.fn().mockResolvedValue({ label: 'TranscriptionSuite_Loopback', volumePct: 100 }),
+    release: vi.fn(),
+  },
+}));
+
+const acquireMock = loopbackOwner.acquire as unknown as Mock;
+const releaseMock = loopbackOwner.release as unknown as Mock;
+
+function makeStream(name: string) {
+  const track = { stop: vi.fn() };
+  const stream = {
+    name,
+    getTracks: () => [track],
+    getVideoTracks: () => [],
+  } as unknown as MediaStream;
+  return { stream, track };
+}
+
+let sources: { stream: MediaStream; connect: Mock }[];
+let merger: { connect: Mock };
+
+class FakeAudioContext {
+  createChannelMerger = vi.fn(() => merger);
+  createMediaStreamSource = vi.fn((stream: MediaStream) => {
+    const node = { stream, connect: vi.fn() };
+    sources.push(node);
+    return node;
+  });
+  createMediaStreamDestination = vi.fn(() => ({ stream: {}, channelCount: 1 }));
+  createAnalyser = vi.fn(() => ({ fftSize: 0, smoothingTimeConstant: 0 }));
+  close = vi.fn().mockResolvedValue(undefined);
+}
+
+class FakeMediaRecorder {
+  static isTypeSupported = vi.fn(() => true);
+  state = 'inactive';
+  mimeType = 'audio/webm;codecs=opus';
+  ondataavailable: ((ev: { data: Blob }) => void) | null = null;
+  onstop: (() => void) | null = null;
+  start = vi.fn(() => {
+    this.state = 'recording';
+  });
+  stop = vi.fn(() => {
+    this.state = 'inactive';
+    this.ondataavailable?.({ data: new Blob(['abc']) });
+    this.onstop?.();
+  });
+}
+
+let mic: ReturnType<typeof makeStream>;
+let loopback: ReturnType<typeof makeStream>;
+let getUserMediaMock: Mock;
+
+describe('interview channel helpers', () => {
+  it('fills blank names with the defaults, microphone first', () => {
+    expect(interviewChannelLabels(' Ana ', '')).toEqual(['Ana', 'Guest']);
+    expect(interviewChannelLabels('', 'Dr. Lee')).toEqual(['Me', 'Dr. Lee']);
+  });
+
+  it('picks the first container the recorder supports', () => {
+    expect(pickInterviewMimeType((t) => t.startsWith('audio/ogg'))).toBe('audio/ogg;codecs=opus');
+    expect(pickInterviewMimeType(() => false)).toBeUndefined();
+  });
+});
+
+describe('InterviewRecorder', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    acquireMock.mockResolvedValue({ label: 'TranscriptionSuite_Loopback', volumePct: 100 });
+    sources = [];
+    merger = { connect: vi.fn() };
+    mic = makeStream('mic');
+    loopback = makeStream('loopback');
+    getUserMediaMock = vi
+      .fn()
+      .mockResolvedValueOnce(mic.stream)
+      .mockResolvedValueOnce(loopback.stream);
+    Object.defineProperty(globalThis.navigator, 'mediaDevices', {
+      value: {
+        getUserMedia: getUserMediaMock,
+        enumerateDevices: vi.fn().mockResolvedValue([
+          {
+            kind: 'audioinput',
+            label: 'Remapped TranscriptionSuite_Loopback source',
+            deviceId: 'loopback-dev-1',
+          },
+        ]),
+        getDisplayMedia: vi.fn().mockResolvedValue(makeStream('display').stream),
+      },
+      configurable: true,
+    });
+    vi.stubGlobal('AudioContext', FakeAudioContext);
+    vi.stubGlobal('MediaRecorder', FakeMediaRecorder);
+  });
+
+  afterEach(() => {
+    vi.unstubAllGlobals();
+  });
+
+  it('puts the microphone on the left channel and system audio on the right', async () => {
+    const recorder = new InterviewRecorder();
+    await recorder.start({ micDeviceId: 'mic-1', monitorSinkName: 'sink-a' });
+
+    expect(getUserMediaMock.mock.calls[0][0].audio.deviceId).toEqual({ exact: 'mic-1' });
+    expect(getUserMediaMock.mock.calls[1][0].audio.deviceId).toEqual({ exact: 'loopback-dev-1' });
+    expect(sources[0].stream).toBe(mic.stream);
+    expect(sources[0].connect).toHaveBeenCalledWith(merger, 0, MIC_CHANNEL);
+    expect(sources[1].stream).toBe(loopback.stream);
+    expect(sources[1].connect).toHaveBeenCalledWith(merger, 0, SYSTEM_CHANNEL);
+    expect(recorder.isRecording).toBe(true);
+  });
+
+  it('stop() resolves the recording and releases every input once', async () => {
+    const recorder = new InterviewRecorder();
+    await recorder.start({ monitorSinkName: 'sink-a' });
+    const blob = await recorder.stop();
+
+    expect(blob.size).toBe(3);
+    expect(blob.type).toBe('audio/webm;codecs=opus');
+    expect(mic.track.stop).toHaveBeenCalled();
+    expect(loopback.track.stop).toHaveBeenCalled();
+    expect(releaseMock).toHaveBeenCalledTimes(1);
+    recorder.cancel();
+    expect(releaseMock).toHaveBeenCalledTimes(1);
+  });
+
+  it('cancel() during the loopback acquire aborts start and frees the microphone', async () => {
+    let resolveAcquire!: (v: { label: string; volumePct: number | null }) => void;
+    acquireMock.mockImplementationOnce(
+      () =>
+        new Promise((res) => {
+          resolveAcquire = res;
+        }),
+    );
+    const recorder = new InterviewRecorder();
+    const starting = recorder.start({ monitorSinkName: 'sink-a' });
+    const assertion = expect(starting).rejects.toMatchObject({ name: 'AbortError' });
+    await vi.waitFor(() => expect(acquireMock).toHaveBeenCalled());
+
+    recorder.cancel();
+    resolveAcquire({ label: 'TranscriptionSuite_Loopback', volumePct: 100 });
+    await assertion;
+
+    expect(mic.track.stop).toHaveBeenCalled();
+    expect(releaseMock).toHaveBeenCalledTimes(1);
+    expect(recorder.isRecording).toBe(false);
+  });
+
+  it('uses getDisplayMedia without a Linux sink and never touches loopbackOwner', async () => {
+    const recorder = new InterviewRecorder();
+    await recorder.start();
+    await recorder.stop();
+    expect(navigator.mediaDevices.getDisplayMedia).toHaveBeenCalled();
+    expect(acquireMock).not.toHaveBeenCalled();
+    expect(releaseMock).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,184 @@
+/**
+ * InterviewRecorder — records the microphone and system audio as the left and
+ * right channels of one stereo file, for call and interview recording.
+ *
+ * The file is uploaded with `multitrack` so the server transcribes each
+ * channel on its own and attributes every word to the channel's speaker —
+ * no diarization guesswork when both sides are already isolated. Channel 0
+ * is always the microphone and channel 1 the system audio; `channelLabels`
+ * follows the same order.
+ *
+ * System audio is acquired exactly like AudioCapture does it: the Linux
+ * loopback module through loopbackOwner (GH-230), getDisplayMedia elsewhere.
+ *
+ * Usage:
+ *   const recorder = new InterviewRecorder();
+ *   await recorder.start({ micDeviceId, monitorSinkName });
+ *   const blob = await recorder.stop();
+ */
+
+import { AudioCapture, AudioCaptureAbortedError } from './audioCapture';
+import { loopbackOwner } from './loopbackOwner';
+
+export const MIC_CHANNEL = 0;
+export const SYSTEM_CHANNEL = 1;
+
+export interface InterviewRecorderOptions {
+  /** Microphone device ID; the default input when unset. */
+  micDeviceId?: string;
+  /** Linux sink to capture; unset selects the getDisplayMedia path. */
+  monitorSinkName?: string;
+}
+
+/** Speaker names in channel order, falling back to the defaults when blank. */
+export function interviewChannelLabels(localName: string, remoteName: string): string[] {
+  return [localName.trim() || 'Me', remoteName.trim() || 'Guest'];
+}
+
+/** Preferred recording container — Opus keeps both channels at a small size. */
+export function pickInterviewMimeType(
+  isSupported: (type: string) => boolean = (t) => MediaRecorder.isTypeSupported(t),
+): string | undefined {
+  return ['audio/webm;codecs=opus', 'audio/ogg;codecs=opus', 'audio/webm'].find(isSupported);
+}
+
+export class InterviewRecorder {
+  private ctx: AudioContext | null = null;
+  private streams: MediaStream[] = [];
+  private recorder: MediaRecorder | null = null;
+  private chunks: Blob[] = [];
+  private analyserNode: AnalyserNode | null = null;
+  private holdsLoopback = false;
+  /** Bumped by every cancel(); start() re-checks it after each await (GH-230). */
+  private stopEpoch = 0;
+
+  /** Open both inputs and start recording. */
+  async start(options: InterviewRecorderOptions = {}): Promise<void> {
+    this.cancel();
+    const epoch = this.stopEpoch;
+
+    try {
+      const mic = await navigator.mediaDevices.getUserMedia({
+        audio: {
+          ...(options.micDeviceId ? { deviceId: { exact: options.micDeviceId } } : {}),
+          echoCancellation: true, // the far end plays through the speakers
+          noiseSuppression: false,
+          autoGainControl: false,
+          channelCount: 1,
+        },
+      });
+      this.streams.push(mic);
+      this.assertNotStopped(epoch);
+
+      this.streams.push(await this.openSystemAudio(options, epoch));
+      this.assertNotStopped(epoch);
+
+      this.ctx = new AudioContext();
+      // Each merger input is mono, so a stereo system mix is downmixed into
+      // its channel instead of spilling into the microphone's.
+      const merger = this.ctx.createChannelMerger(2);
+      this.ctx.createMediaStreamSource(mic).connect(merger, 0, MIC_CHANNEL);
+      this.ctx.createMediaStreamSource(this.streams[1]).connect(merger, 0, SYSTEM_CHANNEL);
+
+      const destination = this.ctx.createMediaStreamDestination();
+      destination.channelCount = 2;
+      merger.connect(destination);
+
+      this.analyserNode = this.ctx.createAnalyser();
+      this.analyserNode.fftSize = 2048;
+      this.analyserNode.smoothingTimeConstant = 0.8;
+      merger.connect(this.analyserNode);
+
+      const mimeType = pickInterviewMimeType();
+      this.chunks = [];
+      this.recorder = new MediaRecorder(destination.stream, mimeType ? { mimeType } : undefined);
+      this.recorder.ondataavailable = (ev) => {
+        if (ev.data.size > 0) this.chunks.push(ev.data);
+      };
+      // Flush every second so a crash mid-call loses at most a second.
+      this.recorder.start(1000);
+    } catch (err) {
+      this.cancel();
+      throw err;
+    }
+  }
+
+  /** Finish the recording and return the stereo file. */
+  stop(): Promise<Blob> {
+    const recorder = this.recorder;
+    if (!recorder || recorder.state === 'inactive') {
+      this.cancel();
+      return Promise.reject(new Error('Interview recording is not running'));
+    }
+    return new Promise((resolve) => {
+      recorder.onstop = () => {
+        const blob = new Blob(this.chunks, { type: recorder.mimeType || 'audio/webm' });
+        this.chunks = [];
+        this.cancel();
+        resolve(blob);
+      };
+      recorder.stop();
+    });
+  }
+
+  /** Stop everything and discard the recording. Safe to call repeatedly. */
+  cancel(): void {
+    this.stopEpoch++;
+    if (this.recorder) {
+      this.recorder.ondataavailable = null;
+      this.recorder.onstop = null;
+      if (this.recorder.state !== 'inactive') this.recorder.stop();
+      this.recorder = null;
+    }
+    this.chunks = [];
+    this.analyserNode = null;
+    this.streams.forEach((s) => s.getTracks().forEach((t) => t.stop()));
+    this.streams = [];
+    if (this.ctx) {
+      this.ctx.close().catch(() => {});
+      this.ctx = null;
+    }
+    if (this.holdsLoopback) {
+      this.holdsLoopback = false;
+      loopbackOwner.release();
+    }
+  }
+
+  /** Mixed level of both channels for the visualizer (available after start()). */
+  get analyser(): AnalyserNode | null {
+    return this.analyserNode;
+  }
+
+  get isRecording(): boolean {
+    return this.recorder?.state === 'recording';
+  }
+
+  private async openSystemAudio(
+    options: InterviewRecorderOptions,
+    epoch: number,
+  ): Promise<MediaStream> {
+    if (options.monitorSinkName) {
+      const { label } = await loopbackOwner.acquire(options.monitorSinkName);
+      this.holdsLoopback = true;
+      this.assertNotStopped(epoch);
+      const deviceId = await AudioCapture.waitForDevice(label, 7000);
+      this.assertNotStopped(epoch);
+      return navigator.mediaDevices.getUserMedia({
+        audio: {
+          deviceId: { exact: deviceId },
+          echoCancellation: false,
+          noiseSuppression: false,
+          autoGainControl: false,
+          channelCount: 1,
+        },
+      });
+    }
+    const display = await navigator.mediaDevices.getDisplayMedia({ audio: true, video: true });
+    display.getVideoTracks().forEach((t) => t.stop());
+    return display;
+  }
+
+  private assertNotStopped(epoch: number): void {
+    if (epoch !== this.stopEpoch) throw new AudioCaptureAbortedError();
+  }
+}
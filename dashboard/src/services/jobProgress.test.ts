@@ -1,5 +1,10 @@
 import { describe, expect, it } from 'vitest';
-import { describeJobProgress, formatClock } from './jobProgress';
+import {
+  describeDowngrade,
+  describeJobProgress,
+  describeServerQueueWait,
+  formatClock,
+} from './jobProgress';
 
 describe('formatClock', () => {
   it('formats mm:ss under an hour', () => {
@@ -82,3 +87,42 @@ describe('describeJobProgress (GH-211)', () => {
     expect(describeJobProgress(null, null, now)).toBe('Processing...');
   });
 });
+
+describe('describeServerQueueWait', () => {
+  const now = 1_000_000;
+
+  it('shows the place in line and the estimated wait', () => {
+    expect(describeServerQueueWait(2, now + 185, now)).toBe(
+      'Waiting for the server (2 ahead, starts in ~3:05)',
+    );
+  });
+
+  it('says next in line and omits an unknown estimate', () => {
+    expect(describeServerQueueWait(0, null, now)).toBe('Waiting for the server (next in line)');
+  });
+});
+
+describe('describeDowngrade', () => {
+  const downgrade = {
+    from_model: 'Systran/faster-whisper-large-v3',
+    to_model: 'Systran/faster-whisper-large-v3',
+    chunk_seconds: 300,
+    error: 'CUDA out of memory',
+    vram: { total_gb: 12 },
+    summary: '',
+  };
+
+  it('names the chunk length when the same model ran in pieces', () => {
+    expect(describeDowngrade(downgrade)).toBe('low VRAM: 5-min chunks');
+  });
+
+  it('names the smaller model without its organisation', () => {
+    expect(
+      describeDowngrade({
+        ...downgrade,
+        to_model: 'Systran/faster-whisper-medium',
+        chunk_seconds: null,
+      }),
+    ).toBe('low VRAM: faster-whisper-medium');
+  });
+});
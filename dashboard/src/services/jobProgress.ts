@@ -1,4 +1,4 @@
-import type { JobTrackerProgress } from '../api/types';
+import type { JobTrackerProgress, TranscriptionDowngrade } from '../api/types';
 
 /** Format seconds as mm:ss, or h:mm:ss over an hour. */
 export function formatClock(totalSeconds: number): string {
@@ -16,6 +16,7 @@ const PHASE_LABELS: Record<string, string> = {
   transcribing: 'Transcribing',
   diarizing: 'Identifying speakers',
   transcribing_diarizing: 'Transcribing + identifying speakers',
+  oom_retry: 'Out of GPU memory, retrying',
 };
 
 /**
@@ -65,3 +66,32 @@ export function describeJobProgress(
   }
   return `Processing...${elapsedPart}`;
 }
+
+/**
+ * Label for an import waiting behind other clients in the server queue.
+ * `position` is 0-based; `estimatedStartAt` is epoch seconds (null when the
+ * server cannot estimate yet).
+ */
+export function describeServerQueueWait(
+  position: number,
+  estimatedStartAt: number | null,
+  nowSeconds: number,
+): string {
+  const place = position === 0 ? 'next in line' : `${position} ahead`;
+  const eta =
+    estimatedStartAt !== null ? `, starts in ~${formatClock(estimatedStartAt - nowSeconds)}` : '';
+  return `Waiting for the server (${place}${eta})`;
+}
+
+/**
+ * Short note for a job the server finished after running out of GPU memory,
+ * e.g. "low VRAM: faster-whisper-medium, 5-min chunks".
+ */
+export function describeDowngrade(downgrade: TranscriptionDowngrade): string {
+  const parts: string[] = [];
+  if (downgrade.to_model !== downgrade.from_model) {
+    parts.push(downgrade.to_model.split('/').pop() ?? downgrade.to_model);
+  }
+  if (downgrade.chunk_seconds) parts.push(`${downgrade.chunk_seconds / 60}-min chunks`);
+  return `low VRAM: ${parts.join(', ')}`;
+}
@@ -0,0 +1,63 @@
+import { describe, expect, it } from 'vitest';
+
+import { LiveSegmentTracker } from './liveSegments';
+
+describe('LiveSegmentTracker', () => {
+  it('rebuilds the partial from kept prefixes and finalizes it', () => {
+    const tracker = new LiveSegmentTracker();
+
+    expect(tracker.apply({ seq: 1, op: 'insert', id: 1, text: 'good morn' })).toEqual({
+      kind: 'partial',
+      text: 'good morn',
+    });
+    expect(tracker.apply({ seq: 2, op: 'update', id: 1, keep: 4, text: ' morning' })).toEqual({
+      kind: 'partial',
+      text: 'good morning',
+    });
+    const final = tracker.apply({ seq: 3, op: 'finalize', id: 1, keep: 0, text: 'Good morning.' });
+    expect(final).toEqual({ kind: 'final', sentences: ['Good morning.'], partial: '' });
+  });
+
+  it('ignores duplicates and waits for a snapshot after a gap', () => {
+    const tracker = new LiveSegmentTracker();
+    tracker.apply({ seq: 1, op: 'finalize', id: 1, keep: 0, text: 'One.' });
+
+    expect(tracker.apply({ seq: 1, op: 'finalize', id: 1, keep: 0, text: 'One.' })).toEqual({
+      kind: 'none',
+    });
+    expect(tracker.apply({ seq: 4, op: 'insert', id: 3, text: 'Thr' })).toEqual({
+      kind: 'resync',
+      sinceId: 1,
+    });
+    expect(tracker.apply({ seq: 5, op: 'update', id: 3, keep: 3, text: 'ee' })).toEqual({
+      kind: 'none',
+    });
+
+    // The snapshot may repeat sentences the client already has.
+    const change = tracker.applySnapshot({
+      seq: 5,
+      segments: [
+        { id: 1, text: 'One.', final: true },
+        { id: 2, text: 'Two.', final: true },
+        { id: 3, text: 'Three', final: false },
+      ],
+    });
+    expect(change).toEqual({ kind: 'final', sentences: ['Two.'], partial: 'Three' });
+    expect(tracker.apply({ seq: 6, op: 'finalize', id: 3, keep: 5, text: '.' })).toEqual({
+      kind: 'final',
+      sentences: ['Three.'],
+      partial: '',
+    });
+  });
+
+  it('starts counting again after reset', () => {
+    const tracker = new LiveSegmentTracker();
+    tracker.apply({ seq: 1, op: 'insert', id: 1, text: 'a' });
+    tracker.reset();
+
+    expect(tracker.apply({ seq: 1, op: 'insert', id: 1, text: 'b' })).toEqual({
+      kind: 'partial',
+      text: 'b',
+    });
+  });
+});
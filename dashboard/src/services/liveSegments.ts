@@ -0,0 +1,96 @@
+/**
+ * Live transcript segment deltas (server core/live_segments.py).
+ *
+ * With `segment_deltas` in the start config the server sends one `segment`
+ * message per change instead of resending the growing partial: `insert`
+ * opens the in-progress segment, `update` rewrites its tail and `finalize`
+ * turns it into a sentence. Each delta has a `seq`; when one goes missing
+ * the tracker asks for a `segments` snapshot of everything after the last
+ * sentence it holds and ignores deltas until that snapshot arrives.
+ */
+
+export interface SegmentDelta {
+  seq: number;
+  op: 'insert' | 'update' | 'finalize';
+  id: number;
+  /** Characters of the previous text that stay (update/finalize only). */
+  keep?: number;
+  /** Text that follows the kept prefix. */
+  text: string;
+}
+
+export interface SegmentSnapshot {
+  seq: number;
+  segments: { id: number; text: string; final: boolean }[];
+}
+
+/** What a delta or snapshot means for the transcript. */
+export type SegmentChange =
+  | { kind: 'partial'; text: string }
+  | { kind: 'final'; sentences: string[]; partial: string }
+  | { kind: 'resync'; sinceId: number }
+  | { kind: 'none' };
+
+export class LiveSegmentTracker {
+  private lastSeq = 0;
+  private awaitingSnapshot = false;
+  private lastFinalId = 0;
+  private openId: number | null = null;
+  private openText = '';
+
+  /** Start over for a new server session (sequence numbers restart there). */
+  reset(): void {
+    this.lastSeq = 0;
+    this.awaitingSnapshot = false;
+    this.lastFinalId = 0;
+    this.openId = null;
+    this.openText = '';
+  }
+
+  /** After a resumed connection: ask for whatever went missing while it was down. */
+  resync(): SegmentChange {
+    this.awaitingSnapshot = true;
+    return { kind: 'resync', sinceId: this.lastFinalId };
+  }
+
+  apply(delta: SegmentDelta): SegmentChange {
+    if (this.awaitingSnapshot || delta.seq <= this.lastSeq) return { kind: 'none' };
+    if (delta.seq !== this.lastSeq + 1) {
+      this.awaitingSnapshot = true;
+      return { kind: 'resync', sinceId: this.lastFinalId };
+    }
+    this.lastSeq = delta.seq;
+
+    const base = delta.op !== 'insert' && delta.id === this.openId ? this.openText : '';
+    const text = base.slice(0, delta.keep ?? 0) + delta.text;
+    if (delta.op === 'finalize') {
+      this.lastFinalId = delta.id;
+      this.openId = null;
+      this.openText = '';
+      return { kind: 'final', sentences: [text], partial: '' };
+    }
+    this.openId = delta.id;
+    this.openText = text;
+    return { kind: 'partial', text };
+  }
+
+  /** Apply a snapshot: sentences the client missed, plus the current partial. */
+  applySnapshot(snapshot: SegmentSnapshot): SegmentChange {
+    this.awaitingSnapshot = false;
+    this.lastSeq = snapshot.seq;
+    const sentences: string[] = [];
+    this.openId = null;
+    this.openText = '';
+    for (const segment of snapshot.segments) {
+      if (segment.final) {
+        if (segment.id <= this.lastFinalId) continue;
+        sentences.push(segment.text);
+        this.lastFinalId = segment.id;
+      } else {
+        this.openId = segment.id;
+        this.openText = segment.text;
+      }
+    }
+    return { kind: 'final', sentences, partial: this.openText };
+  }
+}
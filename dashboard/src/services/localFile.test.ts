@@ -0,0 +1,44 @@
+// @vitest-environment node
+
+import { describe, expect, it, vi } from 'vitest';
+
+import { LocalFile, type LocalFileApi } from './localFile';
+
+/** A file on "disk" whose range reads return at most `maxRead` bytes. */
+function fakeDisk(content: string, maxRead = 3) {
+  const bytes = new TextEncoder().encode(content);
+  return {
+    statLocalFile: vi.fn(async () => ({ name: 'take.wav', size: bytes.length })),
+    readLocalFileRange: vi.fn(async (_path: string, offset: number, length: number) =>
+      bytes.slice(offset, offset + Math.min(length, maxRead)).buffer,
+    ),
+    readLocalFile: vi.fn(async () => ({ name: 'take.wav', buffer: bytes.buffer, mimeType: '' })),
+  } satisfies LocalFileApi;
+}
+
+describe('LocalFile', () => {
+  it('stats the file without reading it', async () => {
+    const api = fakeDisk('abcdefgh');
+    const file = await LocalFile.open('/watch/take.wav', api);
+
+    expect(file).toMatchObject({ name: 'take.wav', size: 8, type: '' });
+    expect(api.readLocalFileRange).not.toHaveBeenCalled();
+  });
+
+  it('reads a slice in as many ranges as it takes', async () => {
+    const api = fakeDisk('abcdefgh');
+    const file = await LocalFile.open('/watch/take.wav', api);
+
+    expect(await (await file.slice(1, 7)).text()).toBe('bcdefg');
+    expect(api.readLocalFileRange.mock.calls.map(([, offset]) => offset)).toEqual([1, 4]);
+    expect(await (await file.slice(6, 100)).text()).toBe('gh');
+  });
+
+  it('stops at the end of a file that shrank after opening', async () => {
+    const api = fakeDisk('abcdefgh');
+    const file = await LocalFile.open('/watch/take.wav', api);
+    api.readLocalFileRange.mockResolvedValue(new ArrayBuffer(0));
+
+    expect((await file.slice(0, 8)).size).toBe(0);
+  });
+});
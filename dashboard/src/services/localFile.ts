@@ -0,0 +1,67 @@
+/**
+ * Local files read range by range — auto-watch jobs are paths on disk.
+ *
+ * Reading a watched file used to copy all of it over IPC into the renderer
+ * before the upload started, which a multi-gigabyte video cannot survive.
+ * A {@link LocalFile} only stats the file up front; each upload chunk is
+ * then a positioned read in the main process handed straight to the request
+ * body, so memory stays at one chunk whatever the file size.
+ */
+
+import type { UploadBody } from './uploadPipeline';
+
+/** The preload file API (electron/preload.ts `app`) — swapped out in tests. */
+export interface LocalFileApi {
+  statLocalFile(filePath: string): Promise<{ name: string; size: number }>;
+  readLocalFileRange(filePath: string, offset: number, length: number): Promise<ArrayBuffer>;
+  readLocalFile(filePath: string): Promise<{ name: string; buffer: ArrayBuffer; mimeType: string }>;
+}
+
+function electronFileApi(): LocalFileApi {
+  const app = (window as any).electronAPI?.app;
+  if (!app?.statLocalFile) {
+    throw new Error('Auto-watch requires Electron — cannot read local file in browser');
+  }
+  return app;
+}
+
+export class LocalFile implements UploadBody {
+  private constructor(
+    readonly path: string,
+    readonly name: string,
+    readonly size: number,
+    private readonly api: LocalFileApi,
+  ) {}
+
+  /** Unset, as for the File this replaces; the extension tells formats apart. */
+  readonly type = '';
+
+  static async open(filePath: string, api: LocalFileApi = electronFileApi()): Promise<LocalFile> {
+    const { name, size } = await api.statLocalFile(filePath);
+    return new LocalFile(filePath, name, size, api);
+  }
+
+  /** Bytes `start`..`end` as a Blob. */
+  async slice(start: number, end: number): Promise<Blob> {
+    const stop = Math.min(end, this.size);
+    const parts: ArrayBuffer[] = [];
+    let offset = start;
+    while (offset < stop) {
+      const part = await this.api.readLocalFileRange(this.path, offset, stop - offset);
+      // The file shrank since it was opened.
+      if (part.byteLength === 0) break;
+      parts.push(part);
+      offset += part.byteLength;
+    }
+    return new Blob(parts);
+  }
+
+  /**
+   * The whole file in memory — only for servers without staged uploads,
+   * where a multipart request is the one way to send it.
+   */
+  async readAll(): Promise<File> {
+    const { buffer } = await this.api.readLocalFile(this.path);
+    return new File([buffer], this.name);
+  }
+}
@@ -0,0 +1,152 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+
+import {
+  ActivityGate,
+  MeetingSession,
+  parseMeetingUrl,
+  rmsLevel,
+  type MeetingCapture,
+  type MeetingRecording,
+  type MeetingSessionState,
+} from './meetings';
+
+vi.mock('./loopbackOwner', () => ({
+  loopbackOwner: { acquire: vi.fn(), release: vi.fn() },
+}));
+
+describe('parseMeetingUrl', () => {
+  it.each([
+    ['https://us02web.zoom.us/j/12345678901?pwd=abc', 'zoom', '123 4567 8901'],
+    ['https://teams.live.com/meet/9876543210', 'teams', '9876543210'],
+    ['https://teams.microsoft.com/l/meetup-join/19%3ameeting_x/0', 'teams', null],
+    ['https://meet.google.com/abc-defg-hij?authuser=0', 'meet', 'abc-defg-hij'],
+    ['https://acme.webex.com/meet/jane.doe', 'webex', 'jane.doe'],
+    ['https://meet.jit.si/Weekly%20Sync', 'jitsi', 'Weekly Sync'],
+    ['https://calls.example.org/room/42', 'other', null],
+  ])('recognises %s', (url, platform, meetingId) => {
+    expect(parseMeetingUrl(url)).toMatchObject({ platform, meetingId });
+  });
+
+  it('builds a title from the platform and meeting ID', () => {
+    expect(parseMeetingUrl(' https://zoom.us/j/1234567890 ').title).toBe(
+      'Zoom meeting 123 456 7890',
+    );
+    expect(parseMeetingUrl('https://teams.microsoft.com/l/meetup-join/x').title).toBe(
+      'Teams meeting',
+    );
+  });
+
+  it('rejects links that are not web URLs', () => {
+    expect(() => parseMeetingUrl('zoom meeting tomorrow')).toThrow('Not a valid meeting link');
+    expect(() => parseMeetingUrl('zoommtg://zoom.us/join?confno=1')).toThrow('https://');
+  });
+});
+
+describe('ActivityGate', () => {
+  it('starts after sustained activity and stops after sustained silence', () => {
+    const gate = new ActivityGate({ threshold: 0.1, startAfterMs: 1000, stopAfterMs: 5000 });
+
+    expect(gate.feed(0.5, 0)).toBeNull();
+    expect(gate.feed(0.0, 500)).toBeNull(); // a blip resets the run
+    expect(gate.feed(0.5, 600)).toBeNull();
+    expect(gate.feed(0.5, 1600)).toBe('start');
+    expect(gate.isActive).toBe(true);
+
+    expect(gate.feed(0.0, 2000)).toBeNull();
+    expect(gate.feed(0.5, 4000)).toBeNull(); // speech again: silence run restarts
+    expect(gate.feed(0.0, 5000)).toBeNull();
+    expect(gate.feed(0.0, 10000)).toBe('stop');
+    expect(gate.isActive).toBe(false);
+  });
+
+  it('measures RMS level', () => {
+    expect(rmsLevel(new Float32Array([0.5, -0.5, 0.5, -0.5]))).toBeCloseTo(0.5);
+    expect(rmsLevel(new Float32Array())).toBe(0);
+  });
+});
+
+describe('MeetingSession', () => {
+  let level: number;
+  let capture: MeetingCapture & { startRecording: ReturnType<typeof vi.fn> };
+  let states: MeetingSessionState[];
+  let recordings: MeetingRecording[];
+  let openUrl: ReturnType<typeof vi.fn>;
+
+  beforeEach(() => {
+    vi.useFakeTimers();
+    level = 0;
+    states = [];
+    recordings = [];
+    openUrl = vi.fn();
+    capture = {
+      analyser: null,
+      level: () => level,
+      startRecording: vi.fn(),
+      stopRecording: vi.fn(async () => new Blob(['audio'])),
+      close: vi.fn(),
+    };
+  });
+
+  afterEach(() => {
+    vi.useRealTimers();
+  });
+
+  function session() {
+    return new MeetingSession(
+      parseMeetingUrl('https://meet.google.com/abc-defg-hij'),
+      {
+        deviceId: 'cable',
+        gate: { threshold: 0.1, startAfterMs: 1000, stopAfterMs: 3000 },
+        pollMs: 250,
+        openUrl,
+        openCapture: async () => capture,
+        now: () => Date.now(),
+      },
+      {
+        onStateChange: (s) => states.push(s),
+        onRecording: (r) => {
+          recordings.push(r);
+        },
+      },
+    );
+  }
+
+  it('joins, records while the call is audible and files each part', async () => {
+    const s = session();
+    await s.start();
+    expect(openUrl).toHaveBeenCalledWith('https://meet.google.com/abc-defg-hij');
+
+    vi.advanceTimersByTime(5000); // silent waiting room
+    expect(capture.startRecording).not.toHaveBeenCalled();
+
+    level = 0.5;
+    vi.advanceTimersByTime(1500);
+    expect(s.currentState).toBe('recording');
+
+    level = 0;
+    await vi.advanceTimersByTimeAsync(3500);
+    expect(s.currentState).toBe('waiting');
+
+    level = 0.5;
+    vi.advanceTimersByTime(1500);
+    await s.end();
+
+    expect(states).toEqual(['waiting', 'recording', 'waiting', 'recording', 'ended']);
+    expect(recordings.map((r) => [r.meeting.title, r.part])).toEqual([
+      ['Google Meet meeting abc-defg-hij', 1],
+      ['Google Meet meeting abc-defg-hij', 2],
+    ]);
+    expect(capture.close).toHaveBeenCalled();
+  });
+
+  it('ends without a recording when the call never became audible', async () => {
+    const s = session();
+    await s.start();
+    vi.advanceTimersByTime(10_000);
+
+    await s.end();
+
+    expect(recordings).toEqual([]);
+    expect(capture.stopRecording).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,383 @@
+/**
+ * meetings — join-by-URL capture for conference calls.
+ *
+ * Given a meeting link, `MeetingSession` opens the link (the desktop client or
+ * browser joins the call), then listens on the selected input — a microphone,
+ * a virtual cable such as VB-Cable/BlackHole, or system audio — and records
+ * only while the call is actually audible:
+ *
+ *  - `ActivityGate` turns the input level into start/stop decisions: audio
+ *    above the threshold for `startAfterMs` starts a recording, silence for
+ *    `stopAfterMs` ends it. Waiting rooms and the minutes before everyone
+ *    turns up are never recorded, and a call that ends stops on its own.
+ *  - Each finished recording is handed to `onRecording` with the meeting
+ *    metadata from `parseMeetingUrl` (platform, meeting ID, title, URL) so the
+ *    transcript is filed under the meeting rather than a timestamp.
+ *
+ * The session keeps listening after a recording ends — a call that resumes
+ * after a long silence becomes a second part — until `end()` is called.
+ * Capture is injectable (`MeetingCaptureFactory`) so the orchestration can be
+ * driven without Web Audio; `openMeetingCapture` is the real implementation.
+ */
+
+import { AudioCapture, AudioCaptureAbortedError } from './audioCapture';
+import { pickInterviewMimeType } from './interviewRecorder';
+import { loopbackOwner } from './loopbackOwner';
+
+// ─── Meeting links ───────────────────────────────────────────────────────────
+
+export type MeetingPlatform = 'zoom' | 'teams' | 'meet' | 'webex' | 'jitsi' | 'other';
+
+export interface MeetingInfo {
+  url: string;
+  platform: MeetingPlatform;
+  /** Platform meeting ID or room name when the link carries one. */
+  meetingId: string | null;
+  /** Default recording title, e.g. "Zoom meeting 123 4567 8901". */
+  title: string;
+}
+
+export const MEETING_PLATFORM_LABELS: Record<MeetingPlatform, string> = {
+  zoom: 'Zoom',
+  teams: 'Teams',
+  meet: 'Google Meet',
+  webex: 'Webex',
+  jitsi: 'Jitsi',
+  other: 'Online',
+};
+
+function hostIs(host: string, domain: string): boolean {
+  return host === domain || host.endsWith(`.${domain}`);
+}
+
+/** Group a Zoom numeric ID the way Zoom displays it (3-4-4 or 3-3-4). */
+function formatZoomId(id: string): string {
+  if (id.length === 11) return `${id.slice(0, 3)} ${id.slice(3, 7)} ${id.slice(7)}`;
+  if (id.length === 10) return `${id.slice(0, 3)} ${id.slice(3, 6)} ${id.slice(6)}`;
+  return id;
+}
+
+/** Recognise the meeting platform and ID in a join link. Throws on non-web URLs. */
+export function parseMeetingUrl(raw: string): MeetingInfo {
+  let url: URL;
+  try {
+    url = new URL(raw.trim());
+  } catch {
+    throw new Error('Not a valid meeting link');
+  }
+  if (url.protocol !== 'https:' && url.protocol !== 'http:') {
+    throw new Error('Meeting links must start with https://');
+  }
+  const host = url.hostname.toLowerCase();
+  const segments = url.pathname.split('/').filter(Boolean);
+
+  let platform: MeetingPlatform = 'other';
+  let meetingId: string | null = null;
+  if (hostIs(host, 'zoom.us') || hostIs(host, 'zoomgov.com')) {
+    platform = 'zoom';
+    const id = segments.find((s) => /^\d{9,11}$/.test(s));
+    meetingId = id ? formatZoomId(id) : null;
+  } else if (hostIs(host, 'teams.microsoft.com') || hostIs(host, 'teams.live.com')) {
+    platform = 'teams';
+    meetingId = segments[0] === 'meet' && segments[1] ? segments[1] : null;
+  } else if (host === 'meet.google.com') {
+    platform = 'meet';
+    meetingId = segments.find((s) => /^[a-z]{3}-[a-z]{4}-[a-z]{3}$/.test(s)) ?? null;
+  } else if (hostIs(host, 'webex.com')) {
+    platform = 'webex';
+    meetingId = segments.at(-1) ?? null;
+  } else if (host === 'meet.jit.si' || host.startsWith('jitsi.')) {
+    platform = 'jitsi';
+    meetingId = segments[0] ? decodeURIComponent(segments[0]) : null;
+  }
+
+  const label = MEETING_PLATFORM_LABELS[platform];
+  return {
+    url: url.toString(),
+    platform,
+    meetingId,
+    title: meetingId ? `${label} meeting ${meetingId}` : `${label} meeting`,
+  };
+}
+
+// ─── Activity detection ──────────────────────────────────────────────────────
+
+export interface ActivityGateOptions {
+  /** RMS level (0–1) counted as activity. ~0.01 is quiet speech through a cable. */
+  threshold?: number;
+  /** Continuous activity needed before recording starts. */
+  startAfterMs?: number;
+  /** Continuous silence that ends the recording. */
+  stopAfterMs?: number;
+}
+
+export const DEFAULT_ACTIVITY_GATE: Required<ActivityGateOptions> = {
+  threshold: 0.01,
+  startAfterMs: 1500,
+  stopAfterMs: 2 * 60 * 1000,
+};
+
+/** Root-mean-square level of a block of samples in [-1, 1]. */
+export function rmsLevel(samples: Float32Array): number {
+  if (samples.length === 0) return 0;
+  let sum = 0;
+  for (const s of samples) sum += s * s;
+  return Math.sqrt(sum / samples.length);
+}
+
+/**
+ * Debounced audio-activity detector. Feed it levels with timestamps; it
+ * answers 'start' or 'stop' on the sample where the state flips.
+ */
+export class ActivityGate {
+  private readonly options: Required<ActivityGateOptions>;
+  private active = false;
+  /** Start of the current run of samples that disagree with `active`. */
+  private pendingSince: number | null = null;
+
+  constructor(options: ActivityGateOptions = {}) {
+    this.options = { ...DEFAULT_ACTIVITY_GATE, ...options };
+  }
+
+  get isActive(): boolean {
+    return this.active;
+  }
+
+  feed(level: number, nowMs: number): 'start' | 'stop' | null {
+    const loud = level >= this.options.threshold;
+    if (loud === this.active) {
+      this.pendingSince = null;
+      return null;
+    }
+    this.pendingSince ??= nowMs;
+    const wait = this.active ? this.options.stopAfterMs : this.options.startAfterMs;
+    if (nowMs - this.pendingSince < wait) return null;
+    this.active = loud;
+    this.pendingSince = null;
+    return loud ? 'start' : 'stop';
+  }
+
+  reset(): void {
+    this.active = false;
+    this.pendingSince = null;
+  }
+}
+
+// ─── Capture ─────────────────────────────────────────────────────────────────
+
+export interface MeetingCaptureOptions {
+  /** Input device ID — a microphone or a virtual cable. Ignored for system audio. */
+  deviceId?: string;
+  systemAudio?: boolean;
+  /** Linux sink to capture for system audio; unset selects getDisplayMedia. */
+  monitorSinkName?: string;
+}
+
+/** What MeetingSession needs from an open input. */
+export interface MeetingCapture {
+  readonly analyser: AnalyserNode | null;
+  /** Current RMS level, 0–1. */
+  level(): number;
+  startRecording(): void;
+  stopRecording(): Promise<Blob>;
+  close(): void;
+}
+
+export type MeetingCaptureFactory = (options: MeetingCaptureOptions) => Promise<MeetingCapture>;
+
+/** Open the input with Web Audio + MediaRecorder (the default factory). */
+export const openMeetingCapture: MeetingCaptureFactory = async (options) => {
+  let holdsLoopback = false;
+  let stream: MediaStream;
+  try {
+    if (options.systemAudio && options.monitorSinkName) {
+      const { label } = await loopbackOwner.acquire(options.monitorSinkName);
+      holdsLoopback = true;
+      const deviceId = await AudioCapture.waitForDevice(label, 7000);
+      stream = await navigator.mediaDevices.getUserMedia({
+        audio: { deviceId: { exact: deviceId }, echoCancellation: false, autoGainControl: false },
+      });
+    } else if (options.systemAudio) {
+      stream = await navigator.mediaDevices.getDisplayMedia({ audio: true, video: true });
+      stream.getVideoTracks().forEach((t) => t.stop());
+    } else {
+      stream = await navigator.mediaDevices.getUserMedia({
+        audio: {
+          ...(options.deviceId ? { deviceId: { exact: options.deviceId } } : {}),
+          // A call is already processed by the far end; keep the cable signal as is.
+          echoCancellation: false,
+          noiseSuppression: false,
+          autoGainControl: false,
+        },
+      });
+    }
+  } catch (err) {
+    if (holdsLoopback) loopbackOwner.release();
+    throw err;
+  }
+
+  const ctx = new AudioContext();
+  const analyser = ctx.createAnalyser();
+  analyser.fftSize = 2048;
+  analyser.smoothingTimeConstant = 0.8;
+  ctx.createMediaStreamSource(stream).connect(analyser);
+  const samples = new Float32Array(analyser.fftSize);
+  const mimeType = pickInterviewMimeType();
+  let recorder: MediaRecorder | null = null;
+  let chunks: Blob[] = [];
+
+  return {
+    analyser,
+    level: () => {
+      analyser.getFloatTimeDomainData(samples);
+      return rmsLevel(samples);
+    },
+    startRecording: () => {
+      chunks = [];
+      recorder = new MediaRecorder(stream, mimeType ? { mimeType } : undefined);
+      recorder.ondataavailable = (ev) => {
+        if (ev.data.size > 0) chunks.push(ev.data);
+      };
+      recorder.start(1000);
+    },
+    stopRecording: () =>
+      new Promise<Blob>((resolve, reject) => {
+        const active = recorder;
+        recorder = null;
+        if (!active || active.state === 'inactive') {
+          reject(new Error('Meeting recording is not running'));
+          return;
+        }
+        active.onstop = () => resolve(new Blob(chunks, { type: active.mimeType || 'audio/webm' }));
+        active.stop();
+      }),
+    close: () => {
+      if (recorder && recorder.state !== 'inactive') recorder.stop();
+      recorder = null;
+      stream.getTracks().forEach((t) => t.stop());
+      ctx.close().catch(() => {});
+      if (holdsLoopback) loopbackOwner.release();
+    },
+  };
+};
+
+// ─── Session ─────────────────────────────────────────────────────────────────
+
+export type MeetingSessionState = 'starting' | 'waiting' | 'recording' | 'ended';
+
+export interface MeetingRecording {
+  meeting: MeetingInfo;
+  blob: Blob;
+  startedAt: Date;
+  /** 1 for the first recording of the session, 2 after a resumed call, … */
+  part: number;
+}
+
+export interface MeetingSessionCallbacks {
+  onStateChange?: (state: MeetingSessionState) => void;
+  onRecording: (recording: MeetingRecording) => void | Promise<void>;
+  /** A recording could not be finished or delivered; the session keeps listening. */
+  onError?: (err: unknown) => void;
+}
+
+export interface MeetingSessionOptions extends MeetingCaptureOptions {
+  gate?: ActivityGateOptions;
+  /** Level sampling interval. */
+  pollMs?: number;
+  /** Opens the meeting link; skipped when unset. */
+  openUrl?: (url: string) => Promise<void> | void;
+  openCapture?: MeetingCaptureFactory;
+  now?: () => number;
+}
+
+export class MeetingSession {
+  private capture: MeetingCapture | null = null;
+  private timer: ReturnType<typeof setInterval> | null = null;
+  private readonly gate: ActivityGate;
+  private state: MeetingSessionState = 'starting';
+  private recordingStartedAt: Date | null = null;
+  private parts = 0;
+  private pending: Promise<void> = Promise.resolve();
+
+  constructor(
+    readonly meeting: MeetingInfo,
+    private readonly options: MeetingSessionOptions,
+    private readonly callbacks: MeetingSessionCallbacks,
+  ) {
+    this.gate = new ActivityGate(options.gate);
+  }
+
+  get currentState(): MeetingSessionState {
+    return this.state;
+  }
+
+  get analyser(): AnalyserNode | null {
+    return this.capture?.analyser ?? null;
+  }
+
+  /** Open the meeting link and start listening for activity. */
+  async start(): Promise<void> {
+    const open = this.options.openCapture ?? openMeetingCapture;
+    this.capture = await open(this.options);
+    if (this.state === 'ended') {
+      // end() landed while the input was opening.
+      this.capture.close();
+      this.capture = null;
+      throw new AudioCaptureAbortedError();
+    }
+    await this.options.openUrl?.(this.meeting.url);
+    this.setState('waiting');
+    this.timer = setInterval(() => this.poll(), this.options.pollMs ?? 250);
+  }
+
+  /** Stop listening; a recording in progress is finished and delivered. */
+  async end(): Promise<void> {
+    if (this.state === 'ended') return;
+    if (this.timer) clearInterval(this.timer);
+    this.timer = null;
+    if (this.state === 'recording') this.finishRecording();
+    await this.pending;
+    this.capture?.close();
+    this.capture = null;
+    this.setState('ended');
+  }
+
+  private now(): number {
+    return this.options.now?.() ?? Date.now();
+  }
+
+  private poll(): void {
+    if (!this.capture) return;
+    const decision = this.gate.feed(this.capture.level(), this.now());
+    if (decision === 'start') {
+      this.capture.startRecording();
+      this.recordingStartedAt = new Date(this.now());
+      this.setState('recording');
+    } else if (decision === 'stop') {
+      this.finishRecording();
+      this.setState('waiting');
+    }
+  }
+
+  private finishRecording(): void {
+    const capture = this.capture;
+    const startedAt = this.recordingStartedAt ?? new Date(this.now());
+    this.recordingStartedAt = null;
+    if (!capture) return;
+    const part = ++this.parts;
+    // Stop now, deliver in order: a slow upload of part 1 must not hold up
+    // the recorder that part 2 is about to reuse.
+    const stopped = capture.stopRecording();
+    this.pending = this.pending
+      .then(async () => {
+        const blob = await stopped;
+        await this.callbacks.onRecording({ meeting: this.meeting, blob, startedAt, part });
+      })
+      .catch((err) => this.callbacks.onError?.(err));
+  }
+
+  private setState(state: MeetingSessionState): void {
+    this.state = state;
+    this.callbacks.onStateChange?.(state);
+  }
+}
@@ -0,0 +1,132 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest';
+
+import {
+  NETWORK_TRACE_CAPACITY,
+  TRACE_BODY_LIMIT,
+  clearNetworkTrace,
+  formatNetworkTrace,
+  getNetworkTrace,
+  sanitizeBody,
+  sanitizeUrl,
+  setNetworkTraceEnabled,
+  traceSocketEvent,
+  tracedFetch,
+} from './networkTrace';
+
+beforeEach(() => {
+  clearNetworkTrace();
+  setNetworkTraceEnabled(true);
+});
+
+afterEach(() => {
+  setNetworkTraceEnabled(false);
+  vi.unstubAllGlobals();
+});
+
+describe('sanitizing', () => {
+  it('redacts secret query parameters and credentials in URLs', () => {
+    expect(sanitizeUrl('http://u:p@localhost:9786/ws?token=abc&lang=en')).toBe(
+      'http://localhost:9786/ws?token=%5Bredacted%5D&lang=en',
+    );
+    expect(sanitizeUrl('/relative?api_key=1')).toBe('/relative?api_key=1');
+  });
+
+  it('redacts secret JSON fields at any depth', () => {
+    const body = sanitizeBody(
+      JSON.stringify({ type: 'auth', data: { token: 'abc', hf_token: 'hf_x', user: 'me' } }),
+    );
+    expect(JSON.parse(body)).toEqual({
+      type: 'auth',
+      data: { token: '[redacted]', hf_token: '[redacted]', user: 'me' },
+    });
+  });
+
+  it('truncates long bodies', () => {
+    const body = sanitizeBody('x'.repeat(TRACE_BODY_LIMIT + 10));
+    expect(body).toBe(`${'x'.repeat(TRACE_BODY_LIMIT)}… (10 more characters)`);
+  });
+});
+
+describe('tracedFetch', () => {
+  it('records method, status, duration and the response body', async () => {
+    vi.stubGlobal(
+      'fetch',
+      vi.fn(
+        async () =>
+          new Response(JSON.stringify({ detail: 'boom' }), {
+            status: 500,
+            headers: { 'content-type': 'application/json' },
+          }),
+      ),
+    );
+    const res = await tracedFetch('http://localhost:9786/api/auth/login', {
+      method: 'post',
+      body: JSON.stringify({ token: 'secret' }),
+    });
+    expect(await res.json()).toEqual({ detail: 'boom' });
+    await vi.waitFor(() => expect(getNetworkTrace()[0].responseBody).toBeDefined());
+
+    const [entry] = getNetworkTrace();
+    expect(entry).toMatchObject({
+      kind: 'http',
+      method: 'POST',
+      status: 500,
+      requestBody: '{"token":"[redacted]"}',
+      responseBody: '{"detail":"boom"}',
+    });
+    expect(entry.durationMs).toBeGreaterThanOrEqual(0);
+  });
+
+  it('records network errors and rethrows them', async () => {
+    vi.stubGlobal(
+      'fetch',
+      vi.fn(async () => {
+        throw new TypeError('Failed to fetch');
+      }),
+    );
+    await expect(tracedFetch('http://localhost:9786/health')).rejects.toThrow('Failed to fetch');
+    expect(getNetworkTrace()[0]).toMatchObject({
+      method: 'GET',
+      error: 'TypeError: Failed to fetch',
+    });
+  });
+
+  it('summarizes binary responses and records nothing while disabled', async () => {
+    vi.stubGlobal(
+      'fetch',
+      vi.fn(
+        async () =>
+          new Response(new Uint8Array(4), {
+            headers: { 'content-type': 'audio/wav', 'content-length': '4' },
+          }),
+      ),
+    );
+    await tracedFetch('http://localhost:9786/api/notebook/recordings/1/audio');
+    expect(getNetworkTrace()[0].responseBody).toBe('<audio/wav, 4 bytes>');
+
+    clearNetworkTrace();
+    setNetworkTraceEnabled(false);
+    await tracedFetch('http://localhost:9786/health');
+    expect(getNetworkTrace()).toEqual([]);
+  });
+});
+
+describe('trace buffer', () => {
+  it('keeps only the newest entries', () => {
+    for (let i = 0; i < NETWORK_TRACE_CAPACITY + 5; i++) {
+      traceSocketEvent(`ws://localhost:9786/ws?n=${i}`, 'open');
+    }
+    const trace = getNetworkTrace();
+    expect(trace).toHaveLength(NETWORK_TRACE_CAPACITY);
+    expect(trace[0].url).toBe('ws://localhost:9786/ws?n=5');
+  });
+
+  it('formats socket frames as plain text', () => {
+    traceSocketEvent('ws://localhost:9786/ws', 'send', '{"type":"start"}');
+    traceSocketEvent('ws://localhost:9786/ws', 'receive', new ArrayBuffer(8));
+    const lines = formatNetworkTrace(getNetworkTrace()).split('\n');
+    expect(lines[0]).toMatch(/WS send {2}ws:\/\/localhost:9786\/ws$/);
+    expect(lines[1]).toBe('  > {"type":"start"}');
+    expect(lines[3]).toBe('  < <8 bytes>');
+  });
+});
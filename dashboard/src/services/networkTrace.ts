@@ -0,0 +1,224 @@
+/**
+ * Network trace — an opt-in record of the client's traffic with the server,
+ * for bug reports that would otherwise only say "fetch failed".
+ *
+ * While enabled (Settings → App → Network Trace), every API request made
+ * through `tracedFetch` and every WebSocket open/close/error and JSON frame
+ * lands in an in-memory ring buffer: method, URL, status, duration and the
+ * first couple of KB of each body. Credentials never do — token query
+ * parameters and token/password/key fields are replaced with "[redacted]",
+ * and request headers (Authorization) are not recorded at all. Audio frames,
+ * pings and binary bodies are summarized by size. Nothing is written to disk.
+ */
+
+export const NETWORK_TRACE_CAPACITY = 300;
+export const TRACE_BODY_LIMIT = 2048;
+
+const REDACTED = '[redacted]';
+const SECRET_KEY = /^(.*_)?(token|password|secret|api_?key|authorization|credentials?)$/i;
+
+export interface NetworkTraceEntry {
+  id: number;
+  kind: 'http' | 'ws';
+  /** Epoch ms when the request started or the socket event happened. */
+  at: number;
+  url: string;
+  /** HTTP method, or the socket event (open / send / receive / close / error). */
+  method: string;
+  status?: number;
+  durationMs?: number;
+  requestBody?: string;
+  responseBody?: string;
+  error?: string;
+}
+
+let enabled = false;
+let nextId = 1;
+let entries: NetworkTraceEntry[] = [];
+const listeners = new Set<() => void>();
+
+function notify(): void {
+  for (const listener of listeners) listener();
+}
+
+export function setNetworkTraceEnabled(on: boolean): void {
+  enabled = on;
+}
+
+export function isNetworkTraceEnabled(): boolean {
+  return enabled;
+}
+
+/** Oldest first. */
+export function getNetworkTrace(): NetworkTraceEntry[] {
+  return entries.slice();
+}
+
+export function clearNetworkTrace(): void {
+  entries = [];
+  notify();
+}
+
+/** Called after every change to the trace; returns the unsubscribe function. */
+export function subscribeNetworkTrace(listener: () => void): () => void {
+  listeners.add(listener);
+  return () => listeners.delete(listener);
+}
+
+function push(entry: Omit<NetworkTraceEntry, 'id'>): NetworkTraceEntry {
+  const full = { id: nextId++, ...entry };
+  entries.push(full);
+  if (entries.length > NETWORK_TRACE_CAPACITY) {
+    entries.splice(0, entries.length - NETWORK_TRACE_CAPACITY);
+  }
+  notify();
+  return full;
+}
+
+// ─── Sanitizing ─────────────────────────────────────────────────────────────
+
+export function sanitizeUrl(url: string): string {
+  try {
+    const parsed = new URL(url);
+    for (const key of [...parsed.searchParams.keys()]) {
+      if (SECRET_KEY.test(key)) parsed.searchParams.set(key, REDACTED);
+    }
+    if (parsed.username || parsed.password) {
+      parsed.username = '';
+      parsed.password = '';
+    }
+    return parsed.toString();
+  } catch {
+    return url;
+  }
+}
+
+function redact(value: unknown): unknown {
+  if (Array.isArray(value)) return value.map(redact);
+  if (value && typeof value === 'object') {
+    return Object.fromEntries(
+      Object.entries(value).map(([key, inner]) => [
+        key,
+        SECRET_KEY.test(key) && inner !== null && inner !== '' ? REDACTED : redact(inner),
+      ]),
+    );
+  }
+  return value;
+}
+
+/** Redact secrets in a JSON body and cut it to {@link TRACE_BODY_LIMIT} characters. */
+export function sanitizeBody(text: string): string {
+  let body = text;
+  try {
+    body = JSON.stringify(redact(JSON.parse(text)));
+  } catch {
+    // Not JSON — keep the text as is.
+  }
+  return body.length > TRACE_BODY_LIMIT
+    ? `${body.slice(0, TRACE_BODY_LIMIT)}… (${body.length - TRACE_BODY_LIMIT} more characters)`
+    : body;
+}
+
+function describeRequestBody(body: BodyInit | null | undefined): string | undefined {
+  if (body === undefined || body === null) return undefined;
+  if (typeof body === 'string') return sanitizeBody(body);
+  if (body instanceof FormData) {
+    const fields = [...body.entries()].map(([key, value]) =>
+      typeof value === 'string'
+        ? `${key}=${SECRET_KEY.test(key) ? REDACTED : value}`
+        : `${key}=<file ${value.name}, ${value.size} bytes>`,
+    );
+    return `multipart: ${fields.join(', ')}`;
+  }
+  if (body instanceof Blob) return `<${body.size} bytes>`;
+  return `<${body.constructor.name}>`;
+}
+
+const TEXT_TYPE = /json|^text\/(?!event-stream)|xml|javascript/;
+
+// ─── Recording ──────────────────────────────────────────────────────────────
+
+/**
+ * `fetch` that records the exchange while tracing is on. The response body
+ * is read from a clone, so callers see the untouched response; streams
+ * (server-sent events) and binary bodies are not read.
+ */
+export async function tracedFetch(url: string, init: RequestInit = {}): Promise<Response> {
+  if (!enabled) return fetch(url, init);
+  const started = Date.now();
+  const entry = push({
+    kind: 'http',
+    at: started,
+    url: sanitizeUrl(url),
+    method: (init.method ?? 'GET').toUpperCase(),
+    requestBody: describeRequestBody(init.body),
+  });
+  try {
+    const res = await fetch(url, init);
+    entry.status = res.status;
+    entry.durationMs = Date.now() - started;
+    const type = res.headers.get('content-type') ?? '';
+    if (TEXT_TYPE.test(type)) {
+      res
+        .clone()
+        .text()
+        .then((text) => {
+          entry.responseBody = sanitizeBody(text);
+          notify();
+        })
+        .catch(() => {});
+    } else if (type) {
+      const length = res.headers.get('content-length');
+      entry.responseBody = `<${type}${length ? `, ${length} bytes` : ''}>`;
+    }
+    notify();
+    return res;
+  } catch (err) {
+    entry.durationMs = Date.now() - started;
+    entry.error = err instanceof Error ? `${err.name}: ${err.message}` : String(err);
+    notify();
+    throw err;
+  }
+}
+
+/** Record a WebSocket event. Pass `data` for frames; binary ones are summarized by size. */
+export function traceSocketEvent(
+  url: string,
+  event: 'open' | 'send' | 'receive' | 'close' | 'error',
+  data?: string | ArrayBuffer | ArrayBufferView,
+): void {
+  if (!enabled) return;
+  let body: string | undefined;
+  if (typeof data === 'string') body = sanitizeBody(data);
+  else if (data !== undefined) body = `<${data.byteLength} bytes>`;
+  push({
+    kind: 'ws',
+    at: Date.now(),
+    url: sanitizeUrl(url),
+    method: event,
+    ...(event === 'send' ? { requestBody: body } : { responseBody: body }),
+  });
+}
+
+/** Plain-text dump of the trace for attaching to an issue. */
+export function formatNetworkTrace(trace: NetworkTraceEntry[]): string {
+  return trace
+    .map((e) => {
+      const head = [
+        new Date(e.at).toISOString(),
+        e.kind === 'ws' ? `WS ${e.method}` : e.method,
+        e.url,
+        e.status !== undefined ? String(e.status) : '',
+        e.durationMs !== undefined ? `${e.durationMs} ms` : '',
+        e.error ?? '',
+      ]
+        .filter(Boolean)
+        .join('  ');
+      const bodies = [
+        e.requestBody ? `  > ${e.requestBody}` : '',
+        e.responseBody ? `  < ${e.responseBody}` : '',
+      ].filter(Boolean);
+      return [head, ...bodies].join('\n');
+    })
+    .join('\n');
+}
@@ -0,0 +1,89 @@
+import { describe, expect, it } from 'vitest';
+
+import type { TranscriptionResponse } from '../api/types';
+import {
+  DEFAULT_OUTPUT_FILTER,
+  compileOutputFilter,
+  filterText,
+  filterTranscription,
+  formatReplacements,
+  parseReplacements,
+  type OutputFilter,
+} from './outputFilter';
+
+const on: OutputFilter = { ...DEFAULT_OUTPUT_FILTER, enabled: true };
+
+describe('filterText', () => {
+  it('masks whole words only, keeping their length', () => {
+    expect(filterText('What the fuck, this is bullshit. Class assessment.', on)).toBe(
+      'What the ****, this is ********. Class assessment.',
+    );
+  });
+
+  it('matches longer words for entries ending in *, in any case', () => {
+    expect(filterText('Damned Fucking day', on)).toBe('****** ******* day');
+  });
+
+  it('supports the other mask styles', () => {
+    expect(filterText('What the fuck.', { ...on, maskStyle: 'first-letter' })).toBe(
+      'What the f***.',
+    );
+    expect(filterText('What the fuck.', { ...on, maskStyle: 'bleep' })).toBe('What the [bleep].');
+    expect(filterText('Oh shit , that is fine.', { ...on, maskStyle: 'remove' })).toBe(
+      'Oh, that is fine.',
+    );
+  });
+
+  it('applies custom replacements before masking, matching capitalisation', () => {
+    const filter: OutputFilter = {
+      ...on,
+      builtInList: false,
+      words: ['darn'],
+      maskStyle: 'bleep',
+      replacements: [{ from: 'gonna', to: 'going to' }],
+    };
+
+    expect(filterText('Darn it, gonna go. Gonna stay.', filter)).toBe(
+      '[bleep] it, going to go. Going to stay.',
+    );
+  });
+
+  it('is a no-op when disabled or when there is nothing to do', () => {
+    expect(filterText('shit', DEFAULT_OUTPUT_FILTER)).toBe('shit');
+    expect(compileOutputFilter({ ...on, builtInList: false })).toBeNull();
+  });
+});
+
+describe('filterTranscription', () => {
+  it('filters the text, the segments and their words', () => {
+    const transcription: TranscriptionResponse = {
+      text: 'oh shit',
+      segments: [
+        { text: 'oh shit', start: 0, end: 1, words: [{ word: ' shit', start: 0.5, end: 1 }] },
+      ],
+      words: [{ word: ' shit', start: 0.5, end: 1 }],
+      language_probability: 1,
+      duration: 1,
+      num_speakers: 0,
+    };
+
+    const filtered = filterTranscription(transcription, on);
+
+    expect(filtered.text).toBe('oh ****');
+    expect(filtered.segments[0].text).toBe('oh ****');
+    expect(filtered.segments[0].words?.[0].word).toBe(' ****');
+    expect(filtered.words[0]).toEqual({ word: ' ****', start: 0.5, end: 1 });
+    expect(transcription.text).toBe('oh shit');
+  });
+});
+
+describe('parseReplacements', () => {
+  it('reads one `from => to` per line and skips malformed lines', () => {
+    const parsed = parseReplacements('gonna => going to\nnot a rule\n => nothing\nAcme =>  ');
+    expect(parsed).toEqual([
+      { from: 'gonna', to: 'going to' },
+      { from: 'Acme', to: '' },
+    ]);
+    expect(formatReplacements(parsed)).toBe('gonna => going to\nAcme => ');
+  });
+});
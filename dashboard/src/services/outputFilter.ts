@@ -0,0 +1,205 @@
+/**
+ * Output filter — profanity masking and custom word replacement applied to
+ * transcripts as they leave the app: written export files and copies to the
+ * clipboard. Stored transcripts are never changed, so switching the filter
+ * off brings the original wording back. Meant for broadcast captions and
+ * classroom handouts.
+ *
+ * Matching is on whole words, ignores case and is Unicode-aware; a list entry
+ * ending in `*` also matches longer words starting with it ("damn*" covers
+ * "damned"). Replacements run first, then masking.
+ */
+
+import type { TranscriptionResponse } from '../api/types';
+import { getConfig } from '../config/store';
+
+export type MaskStyle = 'asterisks' | 'first-letter' | 'bleep' | 'remove';
+
+export interface WordReplacement {
+  from: string;
+  to: string;
+}
+
+export interface OutputFilter {
+  enabled: boolean;
+  /** Mask the built-in list of common English profanity. */
+  builtInList: boolean;
+  /** Extra words or phrases to mask. */
+  words: string[];
+  maskStyle: MaskStyle;
+  replacements: WordReplacement[];
+}
+
+export const DEFAULT_OUTPUT_FILTER: OutputFilter = {
+  enabled: false,
+  builtInList: true,
+  words: [],
+  maskStyle: 'asterisks',
+  replacements: [],
+};
+
+export const BUILT_IN_WORDS: readonly string[] = [
+  'arse*',
+  'ass',
+  'asshole*',
+  'bastard*',
+  'bitch*',
+  'bollocks',
+  'bullshit*',
+  'cock',
+  'cocks',
+  'crap',
+  'cunt*',
+  'damn*',
+  'dick',
+  'dickhead*',
+  'fuck*',
+  'goddamn*',
+  'motherfuck*',
+  'piss*',
+  'prick*',
+  'shit*',
+  'slut*',
+  'twat*',
+  'wanker*',
+  'whore*',
+];
+
+const WORD_CHAR = '[\\p{L}\\p{N}_]';
+
+function escapeRegExp(value: string): string {
+  return value.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+}
+
+/** Whole-word pattern for one entry; a trailing `*` allows more letters. */
+function entryPattern(entry: string): string | null {
+  const trimmed = entry.trim();
+  const prefix = trimmed.endsWith('*');
+  const body = (prefix ? trimmed.slice(0, -1) : trimmed).trim();
+  if (!body) return null;
+  const words = body.split(/\s+/).map(escapeRegExp).join('\\s+');
+  return `${words}${prefix ? `${WORD_CHAR}*` : ''}`;
+}
+
+function wholeWords(patterns: string[]): RegExp {
+  return new RegExp(`(?<!${WORD_CHAR})(?:${patterns.join('|')})(?!${WORD_CHAR})`, 'giu');
+}
+
+function stars(text: string): string {
+  return text.replace(/\S/gu, '*');
+}
+
+export function maskWord(word: string, style: MaskStyle): string {
+  switch (style) {
+    case 'first-letter': {
+      const [first = '', ...rest] = [...word];
+      return first + stars(rest.join(''));
+    }
+    case 'bleep':
+      return '[bleep]';
+    case 'remove':
+      return '';
+    default:
+      return stars(word);
+  }
+}
+
+/** Keep "Dang" capitalised when the replacement for "damn" is written "dang". */
+function matchCase(original: string, replacement: string): string {
+  const first = original.charAt(0);
+  if (first && first === first.toUpperCase() && first !== first.toLowerCase()) {
+    return replacement.charAt(0).toUpperCase() + replacement.slice(1);
+  }
+  return replacement;
+}
+
+/** The filter as a text transform, or null when it would change nothing. */
+export function compileOutputFilter(filter: OutputFilter): ((text: string) => string) | null {
+  if (!filter.enabled) return null;
+
+  const replacements = filter.replacements
+    .map((r) => ({ pattern: entryPattern(r.from), to: r.to }))
+    .filter((r): r is { pattern: string; to: string } => r.pattern !== null)
+    .map((r) => ({ regex: wholeWords([r.pattern]), to: r.to }));
+
+  const masked = [...(filter.builtInList ? BUILT_IN_WORDS : []), ...filter.words]
+    .map(entryPattern)
+    .filter((p): p is string => p !== null);
+  const maskRegex = masked.length > 0 ? wholeWords(masked) : null;
+
+  if (replacements.length === 0 && !maskRegex) return null;
+
+  return (text: string) => {
+    let out = text;
+    for (const { regex, to } of replacements) {
+      out = out.replace(regex, (match) => matchCase(match, to));
+    }
+    if (maskRegex) {
+      out = out.replace(maskRegex, (match) => maskWord(match, filter.maskStyle));
+      if (filter.maskStyle === 'remove') {
+        out = out.replace(/[ \t]{2,}/g, ' ').replace(/[ \t]+([.,!?;:])/g, '$1');
+      }
+    }
+    return out;
+  };
+}
+
+export function filterText(text: string, filter: OutputFilter): string {
+  const apply = compileOutputFilter(filter);
+  return apply ? apply(text) : text;
+}
+
+/** Filter every text field a formatter reads: the full text, segments and words. */
+export function filterTranscription(
+  transcription: TranscriptionResponse,
+  filter: OutputFilter,
+): TranscriptionResponse {
+  const apply = compileOutputFilter(filter);
+  if (!apply) return transcription;
+  const filterWord = <T extends { word: string }>(w: T): T => ({ ...w, word: apply(w.word) });
+  return {
+    ...transcription,
+    text: apply(transcription.text),
+    segments: transcription.segments.map((seg) => ({
+      ...seg,
+      text: apply(seg.text),
+      ...(seg.words ? { words: seg.words.map(filterWord) } : {}),
+    })),
+    words: transcription.words.map(filterWord),
+  };
+}
+
+/** `from => to` per line, as the Settings textarea shows them. */
+export function parseReplacements(text: string): WordReplacement[] {
+  return text
+    .split('\n')
+    .map((line) => line.split('=>'))
+    .filter((parts) => parts.length === 2 && parts[0].trim() !== '')
+    .map(([from, to]) => ({ from: from.trim(), to: to.trim() }));
+}
+
+export function formatReplacements(replacements: WordReplacement[]): string {
+  return replacements.map((r) => `${r.from} => ${r.to}`).join('\n');
+}
+
+const MASK_STYLES: MaskStyle[] = ['asterisks', 'first-letter', 'bleep', 'remove'];
+
+/** Current filter settings (Settings → Client → Output Filter). */
+export async function loadOutputFilter(): Promise<OutputFilter> {
+  const [enabled, builtInList, words, maskStyle, replacements] = await Promise.all([
+    getConfig<boolean>('output.filter.enabled'),
+    getConfig<boolean>('output.filter.builtInList'),
+    getConfig<string[]>('output.filter.words'),
+    getConfig<MaskStyle>('output.filter.maskStyle'),
+    getConfig<WordReplacement[]>('output.filter.replacements'),
+  ]);
+  return {
+    enabled: enabled === true,
+    builtInList: builtInList !== false,
+    words: Array.isArray(words) ? words : [],
+    maskStyle: MASK_STYLES.includes(maskStyle as MaskStyle)
+      ? (maskStyle as MaskStyle)
+      : DEFAULT_OUTPUT_FILTER.maskStyle,
+    replacements: Array.isArray(replacements) ? replacements : [],
+  };
+}
@@ -0,0 +1,131 @@
+import type { TranscriptionResponse, TranscriptionSegment } from '../api/types';
+import type { ResolvedOutput } from './transcriptionFormatters';
+
+/**
+ * Renderer side of the WebAssembly plugins (electron/pluginHost.ts).
+ *
+ * `runPlugins` is the pipeline written transcripts go through: every
+ * enabled post-processor in turn, then every enabled exporter on the
+ * result, each adding one more output file. A plugin that fails is skipped
+ * and reported — the built-in outputs are always written.
+ */
+
+/** Mirrors PLUGIN_ABI_VERSION in electron/pluginHost.ts. */
+export const PLUGIN_ABI_VERSION = 1;
+
+export interface PluginDeps {
+  list: () => Promise<PluginInfo[]>;
+  run: (id: string, transcript: PluginTranscript) => Promise<PluginRunResult>;
+}
+
+export interface PluginPipelineResult {
+  transcription: TranscriptionResponse;
+  outputs: ResolvedOutput[];
+  /** One line per plugin that failed, e.g. `Markdown export: timed out`. */
+  errors: string[];
+}
+
+export function toPluginTranscript(
+  title: string,
+  transcription: TranscriptionResponse,
+): PluginTranscript {
+  return {
+    abi: PLUGIN_ABI_VERSION,
+    title,
+    language: transcription.language ?? null,
+    duration: transcription.duration,
+    segments: transcription.segments.map((s) => ({
+      start: s.start,
+      end: s.end,
+      text: s.text,
+      speaker: s.speaker ?? null,
+    })),
+  };
+}
+
+/**
+ * A post-processor's `{ "segments": [...] }` applied to the transcript.
+ * Word timings are dropped: they no longer line up with rewritten text.
+ */
+export function applyPostProcessed(
+  transcription: TranscriptionResponse,
+  output: Uint8Array,
+): TranscriptionResponse {
+  const parsed = JSON.parse(new TextDecoder().decode(output)) as { segments?: unknown };
+  if (!Array.isArray(parsed.segments)) throw new Error('returned no "segments" list');
+  const segments: TranscriptionSegment[] = parsed.segments.map((raw, i) => {
+    const s = raw as Record<string, unknown>;
+    if (typeof s.start !== 'number' || typeof s.end !== 'number' || typeof s.text !== 'string') {
+      throw new Error(`segment ${i} needs numeric "start"/"end" and a "text"`);
+    }
+    const segment: TranscriptionSegment = { start: s.start, end: s.end, text: s.text };
+    if (typeof s.speaker === 'string') segment.speaker = s.speaker;
+    return segment;
+  });
+  return {
+    ...transcription,
+    text: segments.map((s) => s.text.trim()).filter(Boolean).join(' '),
+    segments,
+    words: [],
+    num_speakers: new Set(segments.map((s) => s.speaker).filter(Boolean)).size,
+  };
+}
+
+function describeError(err: unknown): string {
+  return err instanceof Error ? err.message : String(err);
+}
+
+/**
+ * Run the enabled plugins over a transcript about to be written as
+ * `<stem>.*`. `exporterIds` narrows the exporters (e.g. to the one the user
+ * picked); post-processors always all run.
+ */
+export async function runPlugins(
+  stem: string,
+  transcription: TranscriptionResponse,
+  options: { exporterIds?: string[] } = {},
+  deps: PluginDeps | null = electronPluginDeps(),
+): Promise<PluginPipelineResult> {
+  const result: PluginPipelineResult = { transcription, outputs: [], errors: [] };
+  if (!deps) return result;
+  let plugins: PluginInfo[];
+  try {
+    plugins = (await deps.list()).filter((p) => p.enabled);
+  } catch (err) {
+    result.errors.push(`Plugin list: ${describeError(err)}`);
+    return result;
+  }
+
+  for (const plugin of plugins.filter((p) => p.kind === 'postprocessor')) {
+    try {
+      const run = await deps.run(plugin.id, toPluginTranscript(stem, result.transcription));
+      if (!run.ok) throw new Error(run.error);
+      result.transcription = applyPostProcessed(result.transcription, run.output);
+    } catch (err) {
+      result.errors.push(`${plugin.name}: ${describeError(err)}`);
+    }
+  }
+
+  const exporters = plugins.filter(
+    (p) => p.kind === 'exporter' && (!options.exporterIds || options.exporterIds.includes(p.id)),
+  );
+  for (const plugin of exporters) {
+    try {
+      const run = await deps.run(plugin.id, toPluginTranscript(stem, result.transcription));
+      if (!run.ok) throw new Error(run.error);
+      result.outputs.push({
+        outputFilename: `${stem}.${plugin.exporter?.extension ?? 'txt'}`,
+        content: new TextDecoder().decode(run.output),
+      });
+    } catch (err) {
+      result.errors.push(`${plugin.name}: ${describeError(err)}`);
+    }
+  }
+  return result;
+}
+
+/** The main-process plugin host; null outside Electron. */
+export function electronPluginDeps(): PluginDeps | null {
+  const plugins = window.electronAPI?.plugins;
+  return plugins ? { list: plugins.list, run: plugins.run } : null;
+}
@@ -0,0 +1,81 @@
+import { describe, expect, it, vi } from 'vitest';
+
+import { RETRO_SAMPLE_RATE, RetroRecorder, RollingPcmBuffer, encodeWav } from './retroRecorder';
+
+const ramp = (from: number, length: number) => Int16Array.from({ length }, (_, i) => from + i);
+
+describe('RollingPcmBuffer', () => {
+  it('keeps only the newest samples, trimming inside the oldest chunk', () => {
+    const buffer = new RollingPcmBuffer(5);
+    buffer.push(ramp(0, 3));
+    buffer.push(ramp(3, 3));
+    expect(buffer.samples).toBe(5);
+    expect(Array.from(buffer.snapshot())).toEqual([1, 2, 3, 4, 5]);
+
+    buffer.push(ramp(6, 4));
+    expect(Array.from(buffer.snapshot())).toEqual([5, 6, 7, 8, 9]);
+
+    buffer.clear();
+    expect(buffer.snapshot()).toHaveLength(0);
+  });
+});
+
+describe('encodeWav', () => {
+  it('writes a 16-bit mono header in front of the samples', async () => {
+    const blob = encodeWav(ramp(1, 4), 16000);
+    const view = new DataView(await blob.arrayBuffer());
+    const tag = (offset: number) =>
+      String.fromCharCode(...Array.from({ length: 4 }, (_, i) => view.getUint8(offset + i)));
+
+    expect(blob.size).toBe(44 + 8);
+    expect([tag(0), tag(8), tag(12), tag(36)]).toEqual(['RIFF', 'WAVE', 'fmt ', 'data']);
+    expect(view.getUint32(24, true)).toBe(16000);
+    expect(view.getUint32(40, true)).toBe(8);
+    expect(view.getInt16(44, true)).toBe(1);
+  });
+});
+
+describe('RetroRecorder', () => {
+  const fakeCapture = () => {
+    let onChunk: (pcm: Int16Array) => void = () => {};
+    const capture = { start: vi.fn(async () => {}), stop: vi.fn(), analyser: null };
+    const create = (cb: (pcm: Int16Array) => void) => {
+      onChunk = cb;
+      return capture;
+    };
+    return { capture, create, feed: (pcm: Int16Array) => onChunk(pcm) };
+  };
+
+  it('saves the buffered audio once and dates it from the first sample', async () => {
+    const { capture, create, feed } = fakeCapture();
+    const recorder = new RetroRecorder(create);
+    await recorder.start({ deviceId: 'mic', minutes: 1 });
+    expect(capture.start).toHaveBeenCalledWith(
+      expect.objectContaining({ deviceId: 'mic', targetSampleRateHz: RETRO_SAMPLE_RATE }),
+    );
+
+    feed(new Int16Array(RETRO_SAMPLE_RATE * 2));
+    expect(recorder.bufferedSeconds).toBe(2);
+
+    const saved = recorder.save(new Date('2026-03-01T10:00:02Z'));
+    expect(saved?.seconds).toBe(2);
+    expect(saved?.startedAt.toISOString()).toBe('2026-03-01T10:00:00.000Z');
+    expect(saved?.file.name).toBe('retro-2026-03-01-10-00-00.wav');
+    expect(recorder.save()).toBeNull();
+    expect(recorder.isArmed).toBe(true);
+  });
+
+  it('drops the buffer when disarmed or when the capture fails to start', async () => {
+    const { capture, create, feed } = fakeCapture();
+    const recorder = new RetroRecorder(create);
+    await recorder.start({ minutes: 1 });
+    feed(new Int16Array(100));
+    recorder.stop();
+    expect(capture.stop).toHaveBeenCalled();
+    expect(recorder.save()).toBeNull();
+
+    capture.start.mockRejectedValueOnce(new Error('no device'));
+    await expect(recorder.start({ minutes: 1 })).rejects.toThrow('no device');
+    expect(recorder.isArmed).toBe(false);
+  });
+});
@@ -0,0 +1,174 @@
+/**
+ * RetroRecorder — the recording safety net. While armed it keeps the last
+ * few minutes of the selected input in memory, so something important can
+ * still be saved after it was said: `save()` turns the buffer into a WAV
+ * file that goes through the normal import queue like any other recording.
+ *
+ * Audio is kept as 16 kHz mono PCM — about 1.9 MB per minute — and nothing
+ * touches the disk until the user saves. Disarming drops the buffer.
+ *
+ * Usage:
+ *   const recorder = new RetroRecorder();
+ *   await recorder.start({ deviceId, minutes: 5 });
+ *   const saved = recorder.save(); // { file, seconds, startedAt } | null
+ */
+
+import { AudioCapture, type AudioChunkCallback, type AudioCaptureOptions } from './audioCapture';
+
+export const RETRO_SAMPLE_RATE = 16_000;
+export const RETRO_MINUTE_OPTIONS = [1, 2, 5, 10, 15, 30] as const;
+
+/** PCM chunks covering at most `maxSamples`; the oldest audio falls off first. */
+export class RollingPcmBuffer {
+  private chunks: Int16Array[] = [];
+  private total = 0;
+
+  constructor(readonly maxSamples: number) {}
+
+  push(pcm: Int16Array): void {
+    if (pcm.length === 0) return;
+    this.chunks.push(pcm);
+    this.total += pcm.length;
+    while (this.total > this.maxSamples) {
+      const excess = this.total - this.maxSamples;
+      const oldest = this.chunks[0];
+      if (oldest.length <= excess) {
+        this.chunks.shift();
+        this.total -= oldest.length;
+      } else {
+        this.chunks[0] = oldest.subarray(excess);
+        this.total -= excess;
+      }
+    }
+  }
+
+  get samples(): number {
+    return this.total;
+  }
+
+  /** The buffered audio as one contiguous array. */
+  snapshot(): Int16Array {
+    const out = new Int16Array(this.total);
+    let offset = 0;
+    for (const chunk of this.chunks) {
+      out.set(chunk, offset);
+      offset += chunk.length;
+    }
+    return out;
+  }
+
+  clear(): void {
+    this.chunks = [];
+    this.total = 0;
+  }
+}
+
+/** 16-bit mono PCM in a RIFF/WAVE container. */
+export function encodeWav(pcm: Int16Array, sampleRate: number): Blob {
+  const header = new ArrayBuffer(44);
+  const view = new DataView(header);
+  const ascii = (offset: number, text: string) => {
+    for (let i = 0; i < text.length; i++) view.setUint8(offset + i, text.charCodeAt(i));
+  };
+  const dataBytes = pcm.length * 2;
+  ascii(0, 'RIFF');
+  view.setUint32(4, 36 + dataBytes, true);
+  ascii(8, 'WAVE');
+  ascii(12, 'fmt ');
+  view.setUint32(16, 16, true);
+  view.setUint16(20, 1, true); // PCM
+  view.setUint16(22, 1, true); // mono
+  view.setUint32(24, sampleRate, true);
+  view.setUint32(28, sampleRate * 2, true);
+  view.setUint16(32, 2, true);
+  view.setUint16(34, 16, true);
+  ascii(36, 'data');
+  view.setUint32(40, dataBytes, true);
+  // Copy so the Blob never aliases a buffer that is still being written.
+  const body = new Int16Array(pcm);
+  return new Blob([header, body.buffer], { type: 'audio/wav' });
+}
+
+export interface RetroRecorderOptions {
+  deviceId?: string;
+  systemAudio?: boolean;
+  /** Linux sink to capture; unset selects the getDisplayMedia path. */
+  monitorSinkName?: string;
+  /** How much audio to keep. */
+  minutes: number;
+}
+
+export interface RetroRecording {
+  file: File;
+  seconds: number;
+  /** Wall-clock time of the first buffered sample. */
+  startedAt: Date;
+}
+
+type CaptureLike = Pick<AudioCapture, 'start' | 'stop' | 'analyser'>;
+
+export class RetroRecorder {
+  private capture: CaptureLike | null = null;
+  private buffer: RollingPcmBuffer | null = null;
+
+  constructor(
+    private readonly createCapture: (onChunk: AudioChunkCallback) => CaptureLike = (onChunk) =>
+      new AudioCapture(onChunk),
+  ) {}
+
+  async start(options: RetroRecorderOptions): Promise<void> {
+    this.stop();
+    const buffer = new RollingPcmBuffer(Math.round(options.minutes * 60 * RETRO_SAMPLE_RATE));
+    const capture = this.createCapture((pcm) => buffer.push(pcm));
+    this.buffer = buffer;
+    this.capture = capture;
+    const captureOptions: AudioCaptureOptions = {
+      deviceId: options.deviceId,
+      systemAudio: options.systemAudio,
+      monitorSinkName: options.monitorSinkName,
+      targetSampleRateHz: RETRO_SAMPLE_RATE,
+    };
+    try {
+      await capture.start(captureOptions);
+    } catch (err) {
+      if (this.capture === capture) this.stop();
+      throw err;
+    }
+  }
+
+  /** Disarm and drop whatever was buffered. */
+  stop(): void {
+    this.capture?.stop();
+    this.capture = null;
+    this.buffer = null;
+  }
+
+  get isArmed(): boolean {
+    return this.capture !== null;
+  }
+
+  get analyser(): AnalyserNode | null {
+    return this.capture?.analyser ?? null;
+  }
+
+  get bufferedSeconds(): number {
+    return (this.buffer?.samples ?? 0) / RETRO_SAMPLE_RATE;
+  }
+
+  /**
+   * Flush the buffer into a WAV file; null when nothing is buffered. The
+   * buffer starts over, so saving twice never files the same audio twice.
+   */
+  save(now: Date = new Date()): RetroRecording | null {
+    if (!this.buffer || this.buffer.samples === 0) return null;
+    const pcm = this.buffer.snapshot();
+    this.buffer.clear();
+    const seconds = pcm.length / RETRO_SAMPLE_RATE;
+    const startedAt = new Date(now.getTime() - Math.round(seconds * 1000));
+    const stamp = startedAt.toISOString().slice(0, 19).replace(/[:T]/g, '-');
+    const file = new File([encodeWav(pcm, RETRO_SAMPLE_RATE)], `retro-${stamp}.wav`, {
+      type: 'audio/wav',
+    });
+    return { file, seconds, startedAt };
+  }
+}
@@ -0,0 +1,61 @@
+import { describe, it, expect, vi } from 'vitest';
+
+import type { AdminStatus, RecordingDetail } from '../api/types';
+import { runSampleTranscription, type SampleJobDeps } from './sampleJob';
+
+const tracker = (job_tracker: Record<string, unknown>) =>
+  ({ status: 'running', models: { job_tracker }, config: { server: {} } }) as AdminStatus;
+
+const RECORDING = {
+  id: 7,
+  duration_seconds: 30,
+  segments: [
+    { id: 1, text: ' Hello there. ', start: 0, end: 1 },
+    { id: 2, text: 'General Kenobi.', start: 1, end: 2 },
+  ],
+  words: [],
+} as unknown as RecordingDetail;
+
+function deps(result: Record<string, unknown>): SampleJobDeps {
+  let clock = 0;
+  const statuses = [
+    tracker({ is_busy: true, active_job_id: 'job-1', result: null }),
+    tracker({ is_busy: false, result: { job_id: 'job-1', ...result } }),
+  ];
+  return {
+    loadSample: async () => new File([new Uint8Array(2048)], 'sample.wav'),
+    upload: async () => ({ job_id: 'job-1' }),
+    getAdminStatus: async () => statuses.shift() ?? statuses[0],
+    getRecording: async () => RECORDING,
+    deleteRecording: vi.fn(async () => ({})),
+    sleep: async (ms) => {
+      clock += ms;
+    },
+    now: () => clock,
+  };
+}
+
+describe('runSampleTranscription', () => {
+  it('returns the transcript and deletes the sample recording', async () => {
+    const d = deps({ recording_id: 7 });
+    const stages: string[] = [];
+
+    const result = await runSampleTranscription(d, { onStage: (s) => stages.push(s) });
+
+    expect(result).toEqual({
+      text: 'Hello there. General Kenobi.',
+      segments: 2,
+      audioSeconds: 30,
+      elapsedMs: 4_000,
+    });
+    expect(stages).toEqual(['upload', 'transcribe', 'cleanup']);
+    expect(d.deleteRecording).toHaveBeenCalledWith(7);
+  });
+
+  it('still cleans up when the job reports an error', async () => {
+    const d = deps({ recording_id: 7, error: 'CUDA out of memory' });
+
+    await expect(runSampleTranscription(d)).rejects.toThrow('CUDA out of memory');
+    expect(d.deleteRecording).toHaveBeenCalledWith(7);
+  });
+});
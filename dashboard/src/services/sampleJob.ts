@@ -0,0 +1,86 @@
+import { apiClient } from '../api/client';
+import type { RecordingDetail } from '../api/types';
+import { apiSelfTestDeps, waitForJobResult, type SelfTestDeps } from './selfTest';
+
+/**
+ * Onboarding sample job: the first-run flow transcribes a bundled
+ * 30-second clip once the server is ready, so a new user sees a transcript
+ * straight away and every part of the stack — upload, model, job tracker,
+ * database — has worked once. The recording it creates is deleted again,
+ * whether the job succeeds or not, so nothing of it is left in the notebook.
+ */
+
+export type SampleJobStage = 'upload' | 'transcribe' | 'cleanup';
+
+export interface SampleJobResult {
+  text: string;
+  segments: number;
+  /** Seconds of audio transcribed. */
+  audioSeconds: number;
+  /** Upload to transcript, in ms. */
+  elapsedMs: number;
+}
+
+export type SampleJobDeps = Pick<
+  SelfTestDeps,
+  'upload' | 'getAdminStatus' | 'getRecording' | 'deleteRecording' | 'sleep' | 'now'
+> & { loadSample: () => Promise<File> };
+
+export const SAMPLE_JOB_TITLE = 'TranscriptionSuite sample';
+
+export interface SampleJobOptions {
+  onStage?: (stage: SampleJobStage) => void;
+  pollIntervalMs?: number;
+  timeoutMs?: number;
+}
+
+function transcriptText(recording: RecordingDetail): string {
+  return recording.segments
+    .map((segment) => segment.text.trim())
+    .filter(Boolean)
+    .join(' ');
+}
+
+/** Transcribe the sample and clean up after it; throws when any step fails. */
+export async function runSampleTranscription(
+  deps: SampleJobDeps,
+  { onStage, pollIntervalMs = 2_000, timeoutMs = 10 * 60_000 }: SampleJobOptions = {},
+): Promise<SampleJobResult> {
+  const began = deps.now();
+  onStage?.('upload');
+  const sample = await deps.loadSample();
+  const { job_id } = await deps.upload(sample);
+  onStage?.('transcribe');
+  const result = await waitForJobResult(deps, job_id, pollIntervalMs, timeoutMs);
+  const recordingId = result.recording_id;
+  try {
+    if (result.error) throw new Error(result.error);
+    if (recordingId == null) throw new Error('The job finished without a recording');
+    const recording = await deps.getRecording(recordingId);
+    return {
+      text: transcriptText(recording),
+      segments: recording.segments.length,
+      audioSeconds: recording.duration_seconds,
+      elapsedMs: deps.now() - began,
+    };
+  } finally {
+    if (recordingId != null) {
+      onStage?.('cleanup');
+      await deps.deleteRecording(recordingId).catch(() => {});
+    }
+  }
+}
+
+/** The real pipeline: the API client and the bundled 30-second clip. */
+export function apiSampleJobDeps(): SampleJobDeps {
+  return {
+    ...apiSelfTestDeps(),
+    loadSample: async () => {
+      const { default: url } = await import('../assets/onboarding-sample.wav?url');
+      const res = await fetch(url);
+      if (!res.ok) throw new Error(`Could not load the sample clip (HTTP ${res.status})`);
+      return new File([await res.blob()], 'onboarding-sample.wav', { type: 'audio/wav' });
+    },
+    upload: (file) => apiClient.uploadAndTranscribe(file, { title: SAMPLE_JOB_TITLE }),
+  };
+}
@@ -0,0 +1,48 @@
+import type { UnifiedImportJob } from '../stores/importQueueStore';
+
+/**
+ * Renderer side of the script hooks (electron/scriptHooks.ts): the payloads
+ * for the events the import queue knows about. on_export fires in the main
+ * process when a save-dialog target is written.
+ */
+
+function sourceName(job: UnifiedImportJob): string {
+  return typeof job.file === 'string' ? job.file : job.file.name;
+}
+
+/** Payloads for a finished import-queue job, in firing order. */
+export function jobHookPayloads(
+  job: UnifiedImportJob,
+): Array<{ event: HookEvent; payload: Record<string, unknown> }> {
+  const isSession = job.type.startsWith('session-');
+  const dir = job.outputPath ? job.outputPath.slice(0, job.outputPath.lastIndexOf('/')) : '';
+  const outputs = isSession
+    ? (job.outputFilename?.split(', ') ?? []).filter(Boolean).map((name) => `${dir}/${name}`)
+    : [];
+  const recordingId = isSession ? null : (job.result?.recording_id ?? null);
+  const events: Array<{ event: HookEvent; payload: Record<string, unknown> }> = [
+    {
+      event: 'on_job_complete',
+      payload: {
+        jobId: job.id,
+        kind: isSession ? 'session' : 'notebook',
+        source: sourceName(job),
+        outputs,
+        recordingId,
+      },
+    },
+  ];
+  if (recordingId !== null) {
+    events.push({ event: 'on_recording_saved', payload: { recordingId, source: sourceName(job) } });
+  }
+  return events;
+}
+
+/** Best-effort: a hook that cannot be fired never fails the job. */
+export function fireJobHooks(job: UnifiedImportJob): void {
+  const hooks = window.electronAPI?.hooks;
+  if (!hooks) return;
+  for (const { event, payload } of jobHookPayloads(job)) {
+    hooks.fire(event, payload).catch(() => {});
+  }
+}
@@ -0,0 +1,128 @@
+import { describe, it, expect, vi } from 'vitest';
+
+import { APIError } from '../api/client';
+import type { AdminStatus, RecordingDetail } from '../api/types';
+import { formatSelfTestReport, runSelfTest, type SelfTestDeps } from './selfTest';
+
+const tracker = (job_tracker: Record<string, unknown>) =>
+  ({ status: 'running', models: { job_tracker }, config: { server: {} } }) as AdminStatus;
+
+const RECORDING = {
+  id: 42,
+  segments: [{ id: 1, text: 'hello', start: 0, end: 1 }],
+  words: [{ word: 'hello', start: 0, end: 1 }],
+} as unknown as RecordingDetail;
+
+function deps(overrides: Partial<SelfTestDeps> = {}): SelfTestDeps {
+  let clock = 0;
+  const statuses = [
+    tracker({ is_busy: true, active_job_id: 'job-1', result: null }),
+    tracker({ is_busy: false, result: { job_id: 'job-1', recording_id: 42, message: 'ok' } }),
+  ];
+  return {
+    checkConnection: async () => ({
+      reachable: true,
+      ready: true,
+      status: { version: '1.3.8' },
+      error: null,
+    }),
+    loadSample: async () => new File([new Uint8Array(2048)], 'sample.wav'),
+    upload: async () => ({ job_id: 'job-1' }),
+    getAdminStatus: async () => statuses.shift() ?? statuses[0],
+    getRecording: async () => RECORDING,
+    exportSrt: async () => '1\n00:00:00,000 --> 00:00:01,000\nhello\n',
+    deleteRecording: vi.fn(async () => ({})),
+    sleep: async (ms) => {
+      clock += ms;
+    },
+    now: () => clock,
+    ...overrides,
+  };
+}
+
+describe('runSelfTest', () => {
+  it('passes every stage on a healthy server and cleans up', async () => {
+    const d = deps();
+    const report = await runSelfTest(d);
+    expect(report.passed).toBe(true);
+    expect(report.stages.map((s) => [s.stage, s.status])).toEqual([
+      ['connect', 'pass'],
+      ['upload', 'pass'],
+      ['transcribe', 'pass'],
+      ['fetch', 'pass'],
+      ['export', 'pass'],
+      ['cleanup', 'pass'],
+    ]);
+    expect(report.stages[3].detail).toBe('1 segments, 1 words');
+    expect(d.deleteRecording).toHaveBeenCalledWith(42);
+  });
+
+  it('skips everything after an unreachable server', async () => {
+    const report = await runSelfTest(
+      deps({
+        checkConnection: async () => ({
+          reachable: false,
+          ready: false,
+          status: null,
+          error: 'Connection refused — is the server running?',
+        }),
+      }),
+    );
+    expect(report.passed).toBe(false);
+    expect(report.stages[0]).toMatchObject({
+      status: 'fail',
+      detail: expect.stringMatching(/Connection refused/),
+    });
+    expect(report.stages.slice(1).every((s) => s.status === 'skipped')).toBe(true);
+  });
+
+  it('explains a busy server on upload', async () => {
+    const report = await runSelfTest(
+      deps({
+        upload: async () => {
+          throw new APIError(409, 'busy', '/api/notebook/transcribe/upload');
+        },
+      }),
+    );
+    expect(report.stages[1]).toMatchObject({
+      status: 'fail',
+      detail: 'the server is busy with another transcription',
+    });
+  });
+
+  it('still deletes the sample recording when a later stage fails', async () => {
+    const d = deps({ exportSrt: async () => '' });
+    const report = await runSelfTest(d);
+    expect(report.stages.find((s) => s.stage === 'export')).toMatchObject({
+      status: 'fail',
+      detail: 'the SRT export has no cues',
+    });
+    expect(report.stages.find((s) => s.stage === 'cleanup')?.status).toBe('pass');
+    expect(d.deleteRecording).toHaveBeenCalledWith(42);
+  });
+
+  it('reports a failed job and gives up on a job that never finishes', async () => {
+    const failedJob = await runSelfTest(
+      deps({
+        getAdminStatus: async () =>
+          tracker({ is_busy: false, result: { job_id: 'job-1', error: 'CUDA out of memory' } }),
+      }),
+    );
+    expect(failedJob.stages[2]).toMatchObject({ status: 'fail', detail: 'CUDA out of memory' });
+    expect(failedJob.stages[5].status).toBe('skipped');
+
+    const stuck = await runSelfTest(
+      deps({
+        getAdminStatus: async () => tracker({ is_busy: true, active_job_id: 'job-1' }),
+      }),
+      { pollIntervalMs: 1_000, timeoutMs: 5_000 },
+    );
+    expect(stuck.stages[2]).toMatchObject({ status: 'fail', detail: 'no result after 5 s' });
+  });
+
+  it('formats a plain-text report', async () => {
+    const text = formatSelfTestReport(await runSelfTest(deps()));
+    expect(text.split('\n')[0]).toBe('Self-test passed');
+    expect(text).toContain('PASS  Fetch result: 1 segments, 1 words');
+  });
+});
@@ -0,0 +1,200 @@
+import { APIError, apiClient } from '../api/client';
+import type { AdminStatus, JobTrackerResult, RecordingDetail } from '../api/types';
+import { jobTrackerFromAdminStatus } from '../api/types';
+
+/**
+ * End-to-end self-test: pushes a bundled 10-second sample through the whole
+ * pipeline — connect, upload, transcription, result fetch, export — and
+ * reports pass/fail per stage, so a failure can be pinned to one step
+ * instead of "transcription doesn't work".
+ *
+ * The sample goes through the notebook, the path that touches the most of
+ * the server (job tracker, database, exporters); the recording it creates is
+ * deleted again at the end. A failed stage skips the ones after it, except
+ * that cleanup still runs when a recording was created.
+ */
+
+export type SelfTestStage = 'connect' | 'upload' | 'transcribe' | 'fetch' | 'export' | 'cleanup';
+
+export interface SelfTestStageResult {
+  stage: SelfTestStage;
+  status: 'pass' | 'fail' | 'skipped';
+  detail: string;
+  durationMs: number;
+}
+
+export interface SelfTestReport {
+  passed: boolean;
+  startedAt: number;
+  stages: SelfTestStageResult[];
+}
+
+export interface SelfTestDeps {
+  checkConnection: () => Promise<{
+    reachable: boolean;
+    ready: boolean;
+    status: { version?: string } | null;
+    error: string | null;
+  }>;
+  loadSample: () => Promise<File>;
+  upload: (file: File) => Promise<{ job_id: string }>;
+  getAdminStatus: () => Promise<AdminStatus>;
+  getRecording: (id: number) => Promise<RecordingDetail>;
+  /** The exported SRT text. */
+  exportSrt: (id: number) => Promise<string>;
+  deleteRecording: (id: number) => Promise<unknown>;
+  sleep: (ms: number) => Promise<void>;
+  now: () => number;
+}
+
+export interface SelfTestOptions {
+  pollIntervalMs?: number;
+  /** Give up on the transcription after this long (model loading included). */
+  timeoutMs?: number;
+}
+
+export const STAGE_LABELS: Record<SelfTestStage, string> = {
+  connect: 'Connect to server',
+  upload: 'Upload sample',
+  transcribe: 'Transcribe',
+  fetch: 'Fetch result',
+  export: 'Export',
+  cleanup: 'Clean up',
+};
+
+export const SELF_TEST_TITLE = 'TranscriptionSuite self-test';
+
+class StageFailure extends Error {}
+
+function describeError(err: unknown): string {
+  if (err instanceof APIError) {
+    if (err.status === 409) return 'the server is busy with another transcription';
+    if (err.status === 401 || err.status === 403) return `not authorized (${err.status})`;
+    return `server error ${err.status} on ${err.path}`;
+  }
+  return err instanceof Error ? err.message : String(err);
+}
+
+/** Poll the job tracker until `jobId` has a result; also used by the onboarding sample. */
+export async function waitForJobResult(
+  deps: Pick<SelfTestDeps, 'getAdminStatus' | 'sleep' | 'now'>,
+  jobId: string,
+  pollIntervalMs: number,
+  timeoutMs: number,
+): Promise<JobTrackerResult> {
+  const deadline = deps.now() + timeoutMs;
+  while (deps.now() < deadline) {
+    await deps.sleep(pollIntervalMs);
+    const tracker = jobTrackerFromAdminStatus(await deps.getAdminStatus());
+    const result = tracker?.result;
+    if (result && result.job_id === jobId) return result;
+    if (!tracker?.is_busy) throw new StageFailure('the job disappeared - did the server restart?');
+  }
+  throw new StageFailure(`no result after ${Math.round(timeoutMs / 1000)} s`);
+}
+
+export async function runSelfTest(
+  deps: SelfTestDeps,
+  { pollIntervalMs = 2_000, timeoutMs = 10 * 60_000 }: SelfTestOptions = {},
+): Promise<SelfTestReport> {
+  const startedAt = deps.now();
+  const stages: SelfTestStageResult[] = [];
+  let recordingId: number | null = null;
+  let failed = false;
+
+  const run = async (stage: SelfTestStage, body: () => Promise<string>) => {
+    if (failed && !(stage === 'cleanup' && recordingId !== null)) {
+      stages.push({ stage, status: 'skipped', detail: 'skipped', durationMs: 0 });
+      return;
+    }
+    const began = deps.now();
+    try {
+      const detail = await body();
+      stages.push({ stage, status: 'pass', detail, durationMs: deps.now() - began });
+    } catch (err) {
+      failed = true;
+      stages.push({
+        stage,
+        status: 'fail',
+        detail: describeError(err),
+        durationMs: deps.now() - began,
+      });
+    }
+  };
+
+  let jobId = '';
+  let recording: RecordingDetail | null = null;
+
+  await run('connect', async () => {
+    const conn = await deps.checkConnection();
+    if (!conn.reachable) throw new StageFailure(conn.error ?? 'server unreachable');
+    if (!conn.ready) throw new StageFailure('server is up but its models are not loaded yet');
+    return `server ${conn.status?.version ?? 'reachable'}`;
+  });
+  await run('upload', async () => {
+    const sample = await deps.loadSample();
+    jobId = (await deps.upload(sample)).job_id;
+    return `${Math.round(sample.size / 1024)} KB accepted`;
+  });
+  await run('transcribe', async () => {
+    const result = await waitForJobResult(deps, jobId, pollIntervalMs, timeoutMs);
+    if (result.error) throw new StageFailure(result.error);
+    if (result.recording_id == null) throw new StageFailure('the job finished without a recording');
+    recordingId = result.recording_id;
+    return result.message ?? 'done';
+  });
+  await run('fetch', async () => {
+    recording = await deps.getRecording(recordingId as number);
+    const words = recording.words.length;
+    return `${recording.segments.length} segments, ${words} words`;
+  });
+  await run('export', async () => {
+    const srt = await deps.exportSrt(recordingId as number);
+    const cues = (srt.match(/-->/g) ?? []).length;
+    if (cues === 0 && (recording?.segments.length ?? 0) > 0) {
+      throw new StageFailure('the SRT export has no cues');
+    }
+    return `${cues} subtitle cues`;
+  });
+  await run('cleanup', async () => {
+    await deps.deleteRecording(recordingId as number);
+    return 'sample recording deleted';
+  });
+
+  return { passed: stages.every((s) => s.status === 'pass'), startedAt, stages };
+}
+
+/** Plain-text report for pasting into an issue. */
+export function formatSelfTestReport(report: SelfTestReport): string {
+  const lines = report.stages.map((s) => {
+    const mark = s.status === 'pass' ? 'PASS' : s.status === 'fail' ? 'FAIL' : 'SKIP';
+    const time = s.status === 'skipped' ? '' : ` (${(s.durationMs / 1000).toFixed(1)} s)`;
+    return `${mark}  ${STAGE_LABELS[s.stage]}: ${s.detail}${time}`;
+  });
+  return [`Self-test ${report.passed ? 'passed' : 'FAILED'}`, ...lines].join('\n');
+}
+
+/** The real pipeline: the API client and the bundled sample. */
+export function apiSelfTestDeps(): SelfTestDeps {
+  return {
+    checkConnection: () => apiClient.checkConnection(),
+    loadSample: async () => {
+      const { default: dataUrl } = await import('../assets/self-test-sample.wav?inline');
+      const bytes = Uint8Array.from(atob(dataUrl.split(',')[1]), (c) => c.charCodeAt(0));
+      return new File([bytes], 'self-test-sample.wav', { type: 'audio/wav' });
+    },
+    upload: (file) => apiClient.uploadAndTranscribe(file, { title: SELF_TEST_TITLE }),
+    getAdminStatus: () => apiClient.getAdminStatus(),
+    getRecording: (id) => apiClient.getRecording(id),
+    exportSrt: async (id) => {
+      const url = apiClient.getExportUrl(id, 'srt');
+      if (!url) throw new StageFailure('no server configured');
+      const res = await fetch(url);
+      if (!res.ok) throw new StageFailure(`export answered HTTP ${res.status}`);
+      return res.text();
+    },
+    deleteRecording: (id) => apiClient.deleteRecording(id),
+    sleep: (ms) => new Promise((resolve) => setTimeout(resolve, ms)),
+    now: () => Date.now(),
+  };
+}
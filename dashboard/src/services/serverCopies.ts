@@ -0,0 +1,71 @@
+import { apiClient, APIError } from '../api/client';
+import type { KeptMedia } from '../api/types';
+import { getConfig, setConfig } from '../config/store';
+
+/**
+ * Session imports the server kept a copy of ("Keep server copy"): the list
+ * the Import tab offers for download, and the download itself, which the
+ * main process checks against the upload's SHA-256 (electron/jobMedia.ts).
+ * Useful when the original was recorded on another device.
+ */
+
+export interface ServerCopy extends KeptMedia {
+  filename: string;
+  keptAt: number;
+}
+
+const SERVER_COPIES_KEY = 'sessionImport.serverCopies';
+/** Past this the oldest entries are forgotten here (not deleted on the server). */
+export const MAX_SERVER_COPIES = 200;
+
+function isServerCopy(value: unknown): value is ServerCopy {
+  const copy = value as ServerCopy | null;
+  return (
+    typeof copy?.job_id === 'string' &&
+    typeof copy.sha256 === 'string' &&
+    typeof copy.filename === 'string'
+  );
+}
+
+export async function listServerCopies(): Promise<ServerCopy[]> {
+  const stored = await getConfig<unknown>(SERVER_COPIES_KEY);
+  return Array.isArray(stored) ? stored.filter(isServerCopy) : [];
+}
+
+export async function rememberServerCopy(
+  filename: string,
+  kept: KeptMedia,
+  now = Date.now(),
+): Promise<ServerCopy[]> {
+  const others = (await listServerCopies()).filter((c) => c.job_id !== kept.job_id);
+  const copies = [{ ...kept, filename, keptAt: now }, ...others].slice(0, MAX_SERVER_COPIES);
+  await setConfig(SERVER_COPIES_KEY, copies);
+  return copies;
+}
+
+export async function forgetServerCopy(jobId: string): Promise<ServerCopy[]> {
+  const copies = (await listServerCopies()).filter((c) => c.job_id !== jobId);
+  await setConfig(SERVER_COPIES_KEY, copies);
+  return copies;
+}
+
+/** Delete the copy on the server and forget it; one the server no longer has is forgotten too. */
+export async function deleteServerCopy(jobId: string): Promise<ServerCopy[]> {
+  try {
+    await apiClient.deleteJobMedia(jobId);
+  } catch (err) {
+    if (!(err instanceof APIError && err.status === 404)) throw err;
+  }
+  return forgetServerCopy(jobId);
+}
+
+/** Ask where to save it, then download and verify; null when the dialog was cancelled. */
+export async function downloadServerCopy(copy: ServerCopy): Promise<JobMediaDownload | null> {
+  const api = window.electronAPI;
+  if (!api?.jobMedia || !api.fileIO) {
+    throw new Error('Downloading server copies needs the desktop app');
+  }
+  const destination = await api.fileIO.saveFile({ defaultPath: copy.filename });
+  if (!destination) return null;
+  return api.jobMedia.download(copy.job_id, destination, copy.sha256);
+}
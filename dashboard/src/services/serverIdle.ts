@@ -0,0 +1,66 @@
+/**
+ * Sleeping servers — renderer side of electron/idleManager.ts (local
+ * container stopped when idle) and electron/remotePower.ts (remote machine
+ * woken with Wake-on-LAN).
+ *
+ * ensureServerAwake() runs before anything that needs the server (a
+ * recording, a live session, an import job) and waits for a sleeping server
+ * to come back. Otherwise it only marks activity, and it is a no-op outside
+ * Electron. releaseServer() runs once the import queue drains.
+ */
+
+import { toast } from 'sonner';
+import { getConfig } from '../config/store';
+
+/** Wake a sleeping server and wait until it is ready; throws when it does not start. */
+export async function ensureServerAwake(): Promise<void> {
+  if (await getConfig<boolean>('connection.useRemote')) {
+    await wakeRemoteServer();
+    return;
+  }
+  const api = window.electronAPI?.serverIdle;
+  if (!api) return;
+  const status = await api.getStatus().catch(() => null);
+  if (status?.state !== 'sleeping' && status?.state !== 'waking') {
+    void api.noteActivity().catch(() => {});
+    return;
+  }
+  await withWakeToast(() => api.wake());
+}
+
+/** Suspend a remote server this session woke, when `power.sleepAfterJobs` is on. */
+export async function releaseServer(): Promise<void> {
+  const api = window.electronAPI?.power;
+  if (!api || !(await getConfig<boolean>('power.sleepAfterJobs'))) return;
+  try {
+    if (await api.sleepIfIdle()) toast.info('Jobs done — the server is going to sleep');
+  } catch (err) {
+    const message = err instanceof Error ? err.message : String(err);
+    toast.error(`Could not put the server to sleep: ${message}`);
+  }
+}
+
+async function wakeRemoteServer(): Promise<void> {
+  const api = window.electronAPI?.power;
+  if (!api || !(await getConfig<boolean>('power.autoWake'))) return;
+  await withWakeToast(() => api.wake());
+}
+
+/** Show progress only when waking takes a moment — an awake server answers the probe at once. */
+async function withWakeToast(wake: () => Promise<boolean>): Promise<void> {
+  let id: string | number | undefined;
+  const timer = setTimeout(() => {
+    id = toast.loading('Waking the server…');
+  }, 500);
+  try {
+    const woke = await wake();
+    clearTimeout(timer);
+    if (woke) toast.success('Server is back up', { id });
+    else if (id !== undefined) toast.dismiss(id);
+  } catch (err) {
+    clearTimeout(timer);
+    const message = err instanceof Error ? err.message : String(err);
+    toast.error(`Could not wake the server: ${message}`, { id });
+    throw err;
+  }
+}
@@ -0,0 +1,105 @@
+import { describe, expect, it } from 'vitest';
+
+import type { TranscriptionResponse } from '../api/types';
+import { normalizeText, normalizeTranscription } from './textNormalization';
+
+describe('normalizeText — English', () => {
+  it('writes amounts with their currency symbol', () => {
+    expect(normalizeText('It costs twenty three euros.', 'en')).toBe('It costs €23.');
+    expect(normalizeText('Five dollars and fifty cents, please.', 'en')).toBe('$5.50, please.');
+    expect(normalizeText('a thousand dollars', 'en')).toBe('$1000');
+  });
+
+  it('writes dates, with a year said either way', () => {
+    expect(normalizeText('We met on march fifth.', 'en')).toBe('We met on March 5.');
+    expect(normalizeText('on the fifth of march twenty twenty four', 'en')).toBe(
+      'on March 5, 2024',
+    );
+    expect(normalizeText('June twenty-third two thousand twenty five', 'en')).toBe(
+      'June 23, 2025',
+    );
+  });
+
+  it('writes percentages, decimals, measures and large numbers', () => {
+    expect(normalizeText('three point five percent', 'en')).toBe('3.5%');
+    expect(normalizeText('Two hundred and forty five kilometers.', 'en')).toBe('245 km.');
+    expect(normalizeText('Twenty three thousand four hundred fifty six people', 'en')).toBe(
+      '23,456 people',
+    );
+    expect(normalizeText('nineteen hundred and eighty four', 'en')).toBe('1984');
+    expect(normalizeText('She came twenty first out of one hundred twenty.', 'en')).toBe(
+      'She came 21st out of 120.',
+    );
+  });
+
+  it('leaves small numbers, digit strings and look-alike phrases as words', () => {
+    expect(normalizeText('One of them said one two three.', 'en')).toBe(
+      'One of them said one two three.',
+    );
+    expect(normalizeText('May one day you see it. I may five.', 'en')).toBe(
+      'May one day you see it. I may five.',
+    );
+    expect(normalizeText('He said a lot.', 'en')).toBe('He said a lot.');
+  });
+});
+
+describe('normalizeText — other languages', () => {
+  it('follows Spanish conventions', () => {
+    expect(normalizeText('Cuesta veintitrés euros con cincuenta céntimos.', 'es')).toBe(
+      'Cuesta 23,50 €.',
+    );
+    expect(normalizeText('El cinco de marzo de dos mil veinticuatro.', 'es')).toBe(
+      'El 5 de marzo de 2024.',
+    );
+    expect(normalizeText('Treinta y tres por ciento de doscientas personas.', 'es')).toBe(
+      '33 % de 200 personas.',
+    );
+  });
+
+  it('follows French conventions', () => {
+    expect(normalizeText('Le premier mars deux mille vingt-quatre.', 'fr')).toBe(
+      'Le 1er mars 2024.',
+    );
+    expect(normalizeText('Quatre-vingt-dix-sept pour cent, soixante et onze.', 'fr')).toBe(
+      '97 %, 71.',
+    );
+    expect(normalizeText('Trois virgule cinq kilos.', 'fr')).toBe('3,5 kg.');
+  });
+
+  it('passes languages without a rule set through', () => {
+    expect(normalizeText('zwanzig Euro', 'de')).toBe('zwanzig Euro');
+    expect(normalizeText('twenty three euros', null)).toBe('twenty three euros');
+  });
+});
+
+describe('normalizeTranscription', () => {
+  it('rewrites segments and drops the word timings of the changed ones', () => {
+    const transcription: TranscriptionResponse = {
+      text: 'twenty three euros hi',
+      language: 'en',
+      segments: [
+        {
+          text: 'twenty three euros',
+          start: 0,
+          end: 1,
+          words: [{ word: 'twenty', start: 0, end: 0.3 }],
+        },
+        { text: 'hi', start: 1, end: 2, words: [{ word: 'hi', start: 1, end: 2 }] },
+      ],
+      words: [
+        { word: 'twenty', start: 0, end: 0.3 },
+        { word: 'hi', start: 1, end: 2 },
+      ],
+      language_probability: 1,
+      duration: 2,
+      num_speakers: 0,
+    };
+
+    const normalized = normalizeTranscription(transcription);
+
+    expect(normalized.text).toBe('€23 hi');
+    expect(normalized.segments[0]).toEqual({ text: '€23', start: 0, end: 1 });
+    expect(normalized.segments[1]).toBe(transcription.segments[1]);
+    expect(normalized.words).toEqual([{ word: 'hi', start: 1, end: 2 }]);
+  });
+});
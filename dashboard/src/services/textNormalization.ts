@@ -0,0 +1,738 @@
+/**
+ * Inverse text normalization — the written form of what the model spelled
+ * out: "twenty three euros" → "€23", "march fifth" → "March 5",
+ * "three point five percent" → "3.5%". Rule-based, with one rule set per
+ * language (English, Spanish, French); transcripts in other languages pass
+ * through unchanged.
+ *
+ * It runs on transcripts as they are exported (optional, Settings → Client →
+ * Output), after transcription and before the output filter. Stored
+ * transcripts keep the spoken form.
+ *
+ * Conservative by design: a run of number words is only rewritten when it
+ * parses as one number, and numbers below ten stay words unless a unit,
+ * currency or date makes them a quantity ("one of them" is left alone).
+ */
+
+import type { TranscriptionResponse } from '../api/types';
+import { getConfig } from '../config/store';
+
+type Atom =
+  | { kind: 'unit' | 'tens' | 'compound' | 'hundreds' | 'scale' | 'ordinal'; value: number }
+  | { kind: 'hundred' | 'connector' };
+
+interface Currency {
+  words: string[][];
+  symbol: string;
+  prefix: boolean;
+}
+
+/** One language's rules; word keys are lowercase without accents. */
+interface RuleSet {
+  locale: string;
+  atoms: Record<string, Atom>;
+  /** French "soixante-douze": a tens word may be followed by 10–19. */
+  tensTakeTeens: boolean;
+  /** "mil" / "cent" alone is a number; English wants "a hundred" or "one hundred". */
+  bareScale: boolean;
+  /** Article read as "one" before a hundred or a scale word ("a thousand"). */
+  article?: string;
+  /** Years said in two halves: "twenty twenty four" is 2024. */
+  yearPairs: boolean;
+  decimalWord: string;
+  percent: string[][];
+  percentSpace: boolean;
+  measures: Record<string, string>;
+  currencies: Currency[];
+  subunits: string[];
+  subunitConnectors: string[];
+  /** "March fifth" / "the fifth of March", or "cinq mars" / "cinco de marzo". */
+  dateOrder: 'month-day' | 'day-month';
+  /** Accent-free month names to 0-based months, plus spelling variants. */
+  monthKeys: Record<string, number>;
+  /** Word between the day and the month ("de"), if the language has one. */
+  dayMonthWord?: string;
+  /** Word before the year ("de" in "5 de marzo de 2024"). */
+  yearWord?: string;
+  formatDate: (day: number, month: number, year: number | null) => string;
+  /** Written ordinal for a standalone ordinal, or null to leave it spelled out. */
+  formatOrdinal: (value: number) => string | null;
+}
+
+const unit = (value: number): Atom => ({ kind: 'unit', value });
+const tens = (value: number): Atom => ({ kind: 'tens', value });
+const compound = (value: number): Atom => ({ kind: 'compound', value });
+const hundreds = (value: number): Atom => ({ kind: 'hundreds', value });
+const scale = (value: number): Atom => ({ kind: 'scale', value });
+const ordinal = (value: number): Atom => ({ kind: 'ordinal', value });
+const HUNDRED: Atom = { kind: 'hundred' };
+const CONNECTOR: Atom = { kind: 'connector' };
+
+/** `words` (space-separated) mapped to `atom(start)`, `atom(start + step)`, … */
+function named(words: string, atom: (value: number) => Atom, start = 0, step = 1) {
+  return Object.fromEntries(
+    words
+      .trim()
+      .split(/\s+/)
+      .map((w, i) => [w, atom(start + i * step)]),
+  );
+}
+
+function monthKeys(months: string[], extra: Record<string, number> = {}): Record<string, number> {
+  return { ...Object.fromEntries(months.map((m, i) => [foldWord(m), i])), ...extra };
+}
+
+const EN_MONTHS = [
+  'January',
+  'February',
+  'March',
+  'April',
+  'May',
+  'June',
+  'July',
+  'August',
+  'September',
+  'October',
+  'November',
+  'December',
+];
+
+const EN: RuleSet = {
+  locale: 'en-US',
+  atoms: {
+    ...named(
+      `zero one two three four five six seven eight nine ten eleven twelve thirteen
+       fourteen fifteen sixteen seventeen eighteen nineteen`,
+      unit,
+    ),
+    ...named('twenty thirty forty fifty sixty seventy eighty ninety', tens, 20, 10),
+    ...named(
+      `first second third fourth fifth sixth seventh eighth ninth tenth eleventh twelfth
+       thirteenth fourteenth fifteenth sixteenth seventeenth eighteenth nineteenth`,
+      ordinal,
+      1,
+    ),
+    ...named(
+      'twentieth thirtieth fortieth fiftieth sixtieth seventieth eightieth ninetieth',
+      ordinal,
+      20,
+      10,
+    ),
+    hundred: HUNDRED,
+    thousand: scale(1e3),
+    million: scale(1e6),
+    billion: scale(1e9),
+    and: CONNECTOR,
+  },
+  tensTakeTeens: false,
+  bareScale: false,
+  article: 'a',
+  yearPairs: true,
+  decimalWord: 'point',
+  percent: [['percent'], ['per', 'cent']],
+  percentSpace: false,
+  measures: {
+    kilometers: 'km',
+    kilometres: 'km',
+    kilometer: 'km',
+    kilometre: 'km',
+    kilograms: 'kg',
+    kilogram: 'kg',
+    kilos: 'kg',
+    meters: 'm',
+    metres: 'm',
+    centimeters: 'cm',
+    centimetres: 'cm',
+    millimeters: 'mm',
+    millimetres: 'mm',
+  },
+  currencies: [
+    { words: [['dollars'], ['dollar']], symbol: '$', prefix: true },
+    { words: [['euros'], ['euro']], symbol: '€', prefix: true },
+    { words: [['yen']], symbol: '¥', prefix: true },
+  ],
+  subunits: ['cents', 'cent'],
+  subunitConnectors: ['and'],
+  dateOrder: 'month-day',
+  monthKeys: monthKeys(EN_MONTHS),
+  formatDate: (day, month, year) =>
+    `${EN_MONTHS[month]} ${day}${year !== null ? `, ${year}` : ''}`,
+  formatOrdinal: (value) => {
+    if (value < 10) return null;
+    const lastTwo = value % 100;
+    const suffix =
+      lastTwo >= 11 && lastTwo <= 13 ? 'th' : (['th', 'st', 'nd', 'rd'][value % 10] ?? 'th');
+    return `${value}${suffix}`;
+  },
+};
+
+const ES_MONTHS = [
+  'enero',
+  'febrero',
+  'marzo',
+  'abril',
+  'mayo',
+  'junio',
+  'julio',
+  'agosto',
+  'septiembre',
+  'octubre',
+  'noviembre',
+  'diciembre',
+];
+
+const ES_HUNDREDS = `doscientos trescientos cuatrocientos quinientos seiscientos setecientos
+  ochocientos novecientos`;
+
+const ES: RuleSet = {
+  locale: 'es-ES',
+  atoms: {
+    ...named(
+      `cero uno dos tres cuatro cinco seis siete ocho nueve diez once doce trece catorce
+       quince dieciseis diecisiete dieciocho diecinueve`,
+      unit,
+    ),
+    un: unit(1),
+    una: unit(1),
+    ...named('veinte treinta cuarenta cincuenta sesenta setenta ochenta noventa', tens, 20, 10),
+    ...named(
+      `veintiuno veintidos veintitres veinticuatro veinticinco veintiseis veintisiete
+       veintiocho veintinueve`,
+      compound,
+      21,
+    ),
+    veintiun: compound(21),
+    veintiuna: compound(21),
+    cien: hundreds(100),
+    ciento: hundreds(100),
+    ...named(ES_HUNDREDS, hundreds, 200, 100),
+    // Feminine forms: "doscientas personas".
+    ...named(ES_HUNDREDS.replace(/os\b/g, 'as'), hundreds, 200, 100),
+    mil: scale(1e3),
+    millon: scale(1e6),
+    millones: scale(1e6),
+    primero: ordinal(1),
+    primer: ordinal(1),
+    y: CONNECTOR,
+  },
+  tensTakeTeens: false,
+  bareScale: true,
+  yearPairs: false,
+  decimalWord: 'coma',
+  percent: [['por', 'ciento']],
+  percentSpace: true,
+  measures: {
+    kilometros: 'km',
+    kilogramos: 'kg',
+    kilos: 'kg',
+    metros: 'm',
+    centimetros: 'cm',
+    milimetros: 'mm',
+  },
+  currencies: [
+    { words: [['euros'], ['euro']], symbol: '€', prefix: false },
+    { words: [['dolares'], ['dolar']], symbol: '$', prefix: false },
+  ],
+  subunits: ['centimos', 'centimo', 'centavos', 'centavo'],
+  subunitConnectors: ['con', 'y'],
+  dateOrder: 'day-month',
+  monthKeys: monthKeys(ES_MONTHS, { setiembre: 8 }),
+  dayMonthWord: 'de',
+  yearWord: 'de',
+  formatDate: (day, month, year) =>
+    `${day} de ${ES_MONTHS[month]}${year !== null ? ` de ${year}` : ''}`,
+  formatOrdinal: () => null,
+};
+
+const FR_MONTHS = [
+  'janvier',
+  'février',
+  'mars',
+  'avril',
+  'mai',
+  'juin',
+  'juillet',
+  'août',
+  'septembre',
+  'octobre',
+  'novembre',
+  'décembre',
+];
+
+const FR: RuleSet = {
+  locale: 'fr-FR',
+  atoms: {
+    ...named(
+      `zero un deux trois quatre cinq six sept huit neuf dix onze douze treize quatorze
+       quinze seize dix-sept dix-huit dix-neuf`,
+      unit,
+    ),
+    une: unit(1),
+    ...named('vingt trente quarante cinquante soixante soixante-dix', tens, 20, 10),
+    'quatre-vingt': tens(80),
+    'quatre-vingts': tens(80),
+    'quatre-vingt-dix': tens(90),
+    cent: HUNDRED,
+    cents: HUNDRED,
+    mille: scale(1e3),
+    million: scale(1e6),
+    millions: scale(1e6),
+    milliard: scale(1e9),
+    milliards: scale(1e9),
+    premier: ordinal(1),
+    premiere: ordinal(1),
+    et: CONNECTOR,
+  },
+  tensTakeTeens: true,
+  bareScale: true,
+  yearPairs: false,
+  decimalWord: 'virgule',
+  percent: [['pour', 'cent']],
+  percentSpace: true,
+  measures: {
+    kilometres: 'km',
+    kilogrammes: 'kg',
+    kilos: 'kg',
+    metres: 'm',
+    centimetres: 'cm',
+    millimetres: 'mm',
+  },
+  currencies: [
+    { words: [['euros'], ['euro']], symbol: '€', prefix: false },
+    { words: [['dollars'], ['dollar']], symbol: '$', prefix: false },
+  ],
+  subunits: ['centimes', 'centime'],
+  subunitConnectors: ['et'],
+  dateOrder: 'day-month',
+  monthKeys: monthKeys(FR_MONTHS),
+  formatDate: (day, month, year) =>
+    `${day === 1 ? '1er' : day} ${FR_MONTHS[month]}${year !== null ? ` ${year}` : ''}`,
+  formatOrdinal: () => null,
+};
+
+const RULE_SETS: Record<string, RuleSet> = { en: EN, es: ES, fr: FR };
+
+/** Languages with a rule set, as transcript language codes. */
+export const NORMALIZATION_LANGUAGES = Object.keys(RULE_SETS);
+
+function foldWord(word: string): string {
+  return word
+    .toLowerCase()
+    .normalize('NFD')
+    .replace(/[\u0300-\u036f]/g, '')
+    .replace(/’/g, "'");
+}
+
+interface Chunk {
+  lead: string;
+  core: string;
+  trail: string;
+  /** Whitespace after the chunk. */
+  space: string;
+  key: string;
+}
+
+function splitChunks(text: string): { head: string; chunks: Chunk[] } {
+  const parts = text.split(/(\s+)/);
+  const head = parts[0] === '' && parts.length > 1 ? parts[1] : '';
+  const start = head ? 2 : 0;
+  const chunks: Chunk[] = [];
+  for (let i = start; i < parts.length; i += 2) {
+    if (parts[i] === '') continue;
+    const match = /^([^\p{L}\p{N}]*)(.*?[\p{L}\p{N}])?([^\p{L}\p{N}]*)$/su.exec(parts[i]);
+    const [lead, core, trail] = match ? [match[1], match[2] ?? '', match[3]] : ['', parts[i], ''];
+    chunks.push({ lead, core, trail, space: parts[i + 1] ?? '', key: foldWord(core) });
+  }
+  return { head, chunks };
+}
+
+/** A chunk's number atoms — hyphenated compounds split on the longest known parts. */
+function atomsOf(chunk: Chunk, rules: RuleSet): Atom[] | null {
+  const whole = rules.atoms[chunk.key];
+  if (whole) return [whole];
+  if (!chunk.key.includes('-')) return null;
+  const parts = chunk.key.split('-');
+  const atoms: Atom[] = [];
+  let i = 0;
+  while (i < parts.length) {
+    let j = parts.length;
+    while (j > i && !rules.atoms[parts.slice(i, j).join('-')]) j--;
+    if (j === i) return null;
+    atoms.push(rules.atoms[parts.slice(i, j).join('-')]);
+    i = j;
+  }
+  return atoms;
+}
+
+interface NumberRun {
+  value: number;
+  /** Exclusive chunk index. */
+  end: number;
+  ordinal: boolean;
+  /** The run has a hundred or a scale word, so it is no two-digit year half. */
+  large: boolean;
+}
+
+function sentenceStart(chunks: Chunk[], i: number): boolean {
+  return i === 0 || /[.!?]$/.test(chunks[i - 1].trail);
+}
+
+/** Chunks i and i+1 touch: no punctuation between them. */
+function joined(chunks: Chunk[], i: number): boolean {
+  return i + 1 < chunks.length && !chunks[i].trail && !chunks[i + 1].lead;
+}
+
+/**
+ * The longest run of number words starting at chunk `start` that reads as
+ * a single number: "one hundred and five" is 105, "one two" is not 3.
+ */
+function readNumber(chunks: Chunk[], start: number, rules: RuleSet): NumberRun | null {
+  let total = 0;
+  let group = 0;
+  // 0: group empty, 1: after hundreds, 2: after tens, 3: group closed by a unit.
+  let phase = 0;
+  let lastScale = Infinity;
+  let isOrdinal = false;
+  let large = false;
+  let numeric = false;
+  let afterConnector = false;
+  let best: NumberRun | null = null;
+
+  let first = start;
+  if (rules.article && chunks[start]?.key === rules.article && joined(chunks, start)) {
+    const kind = atomsOf(chunks[start + 1], rules)?.[0].kind;
+    if (kind !== 'hundred' && kind !== 'scale') return null;
+    group = 1;
+    phase = 3;
+    first = start + 1;
+  }
+
+  for (let c = first; c < chunks.length; c++) {
+    if (c > start && !joined(chunks, c - 1)) break;
+    const atoms = atomsOf(chunks[c], rules);
+    if (!atoms) break;
+    let fits = true;
+    for (const atom of atoms) {
+      if (isOrdinal) {
+        fits = false;
+        break;
+      }
+      const wasConnector = afterConnector;
+      afterConnector = false;
+      switch (atom.kind) {
+        case 'connector':
+          fits = !wasConnector && (phase === 1 || phase === 2 || (phase === 0 && total > 0));
+          afterConnector = fits;
+          break;
+        case 'unit':
+        case 'ordinal': {
+          const asTens = atom.kind === 'ordinal' && atom.value >= 20;
+          if (asTens) {
+            fits = phase <= 1;
+          } else {
+            fits =
+              phase <= 1 || (phase === 2 && (atom.value < 10 || rules.tensTakeTeens));
+          }
+          if (fits) {
+            group += atom.value;
+            phase = 3;
+            isOrdinal = atom.kind === 'ordinal';
+          }
+          break;
+        }
+        case 'tens':
+        case 'compound':
+          fits = phase <= 1;
+          if (fits) {
+            group += atom.value;
+            phase = atom.kind === 'tens' ? 2 : 3;
+          }
+          break;
+        case 'hundreds':
+          fits = phase === 0 && group === 0;
+          if (fits) {
+            group = atom.value;
+            phase = 1;
+            large = true;
+          }
+          break;
+        case 'hundred':
+          fits =
+            !wasConnector &&
+            (phase === 0 || (phase === 3 && group < 100)) &&
+            (rules.bareScale || total + group > 0);
+          if (fits) {
+            group = (group || 1) * 100;
+            phase = 1;
+            large = true;
+          }
+          break;
+        case 'scale':
+          fits = !wasConnector && atom.value < lastScale && (rules.bareScale || total + group > 0);
+          if (fits) {
+            total += (group || 1) * atom.value;
+            group = 0;
+            phase = 0;
+            lastScale = atom.value;
+            large = true;
+          }
+          break;
+      }
+      if (!fits) break;
+      if (atom.kind !== 'connector') numeric = true;
+    }
+    if (!fits) break;
+    if (numeric && !afterConnector) {
+      best = { value: total + group, end: c + 1, ordinal: isOrdinal, large };
+    }
+  }
+  return best;
+}
+
+/** End of `words` matched from chunk `start` (exclusive), or -1. */
+function matchWords(chunks: Chunk[], start: number, words: string[]): number {
+  for (let k = 0; k < words.length; k++) {
+    const c = start + k;
+    if (c >= chunks.length || chunks[c].key !== words[k]) return -1;
+    if (k > 0 && !joined(chunks, c - 1)) return -1;
+  }
+  return start + words.length;
+}
+
+function matchAny(chunks: Chunk[], start: number, options: string[][]): number {
+  for (const words of options) {
+    const end = matchWords(chunks, start, words);
+    if (end !== -1) return end;
+  }
+  return -1;
+}
+
+function formatNumber(value: number, rules: RuleSet, fractionDigits = 0): string {
+  return new Intl.NumberFormat(rules.locale, {
+    // Four-digit numbers are usually years ("in two thousand twenty" → 2020).
+    useGrouping: value >= 10_000,
+    minimumFractionDigits: fractionDigits,
+    maximumFractionDigits: Math.max(fractionDigits, 10),
+  }).format(value);
+}
+
+interface Rewrite {
+  text: string;
+  end: number;
+}
+
+/** "three point one four" → 3.14, extending `run` when the decimal word follows. */
+function readDecimal(
+  chunks: Chunk[],
+  run: NumberRun,
+  rules: RuleSet,
+): { value: number; digits: number; end: number } {
+  const plain = { value: run.value, digits: 0, end: run.end };
+  if (run.ordinal || !joined(chunks, run.end - 1)) return plain;
+  if (chunks[run.end]?.key !== rules.decimalWord) return plain;
+  let digits = '';
+  let c = run.end + 1;
+  while (c < chunks.length && joined(chunks, c - 1)) {
+    const atoms = atomsOf(chunks[c], rules);
+    if (!atoms || atoms.length !== 1 || atoms[0].kind !== 'unit' || atoms[0].value > 9) break;
+    digits += String(atoms[0].value);
+    c++;
+  }
+  if (!digits) return plain;
+  return { value: Number(`${run.value}.${digits}`), digits: digits.length, end: c };
+}
+
+function readDate(chunks: Chunk[], start: number, rules: RuleSet): Rewrite | null {
+  const dayAt = (c: number): NumberRun | null => {
+    const run = readNumber(chunks, c, rules);
+    return run && !run.large && run.value >= 1 && run.value <= 31 ? run : null;
+  };
+  const monthAt = (c: number): number | null => rules.monthKeys[chunks[c]?.key] ?? null;
+
+  let day: NumberRun | null = null;
+  let month: number | null = null;
+  let end = start;
+  if (rules.dateOrder === 'month-day') {
+    // "march (the) fifth", "(the) fifth of march"
+    month = monthAt(start);
+    if (month !== null && joined(chunks, start)) {
+      const dayStart = chunks[start + 1].key === 'the' && joined(chunks, start + 1) ? 2 : 1;
+      day = dayAt(start + dayStart);
+      // "may one day" is no date: a cardinal day needs a capitalised month
+      // that does not just start the sentence ("on March 5").
+      const titled = /^\p{Lu}/u.test(chunks[start].core) && !sentenceStart(chunks, start);
+      if (day && !day.ordinal && !titled) day = null;
+      end = day?.end ?? start;
+    } else {
+      const dayStart = chunks[start].key === 'the' && joined(chunks, start) ? start + 1 : start;
+      day = dayAt(dayStart);
+      if (day && day.ordinal && joined(chunks, day.end - 1) && chunks[day.end]?.key === 'of') {
+        month = joined(chunks, day.end) ? monthAt(day.end + 1) : null;
+        end = day.end + 2;
+      } else {
+        day = null;
+      }
+    }
+  } else {
+    // "cinco de marzo", "cinq mars"
+    day = dayAt(start);
+    if (day && joined(chunks, day.end - 1)) {
+      let at = day.end;
+      if (rules.dayMonthWord) {
+        if (chunks[at]?.key !== rules.dayMonthWord || !joined(chunks, at)) return null;
+        at++;
+      }
+      month = monthAt(at);
+      end = at + 1;
+    }
+  }
+  if (!day || month === null) return null;
+
+  const year = readYear(chunks, end, rules);
+  return { text: rules.formatDate(day.value, month, year?.value ?? null), end: year?.end ?? end };
+}
+
+/** A year after a date: "two thousand twenty four", or "twenty twenty four" in English. */
+function readYear(
+  chunks: Chunk[],
+  start: number,
+  rules: RuleSet,
+): { value: number; end: number } | null {
+  if (!joined(chunks, start - 1)) return null;
+  let at = start;
+  if (rules.yearWord) {
+    if (chunks[at]?.key !== rules.yearWord || !joined(chunks, at)) return null;
+    at++;
+  }
+  const first = readNumber(chunks, at, rules);
+  if (!first || first.ordinal) return null;
+  if (first.value >= 1000 && first.value <= 2999) return { value: first.value, end: first.end };
+  return readYearPair(chunks, first, rules);
+}
+
+/** "nineteen ninety nine" → 1999, given its first half. */
+function readYearPair(
+  chunks: Chunk[],
+  first: NumberRun,
+  rules: RuleSet,
+): { value: number; end: number } | null {
+  if (!rules.yearPairs || first.ordinal || first.large || first.value < 10) return null;
+  if (!joined(chunks, first.end - 1)) return null;
+  const second = readNumber(chunks, first.end, rules);
+  if (!second || second.ordinal || second.large || second.value < 10) return null;
+  return { value: first.value * 100 + second.value, end: second.end };
+}
+
+function readQuantity(chunks: Chunk[], start: number, rules: RuleSet): Rewrite | null {
+  const run = readNumber(chunks, start, rules);
+  if (!run) return null;
+
+  if (run.ordinal) {
+    const text = rules.formatOrdinal(run.value);
+    return text ? { text, end: run.end } : null;
+  }
+
+  const year = readYearPair(chunks, run, rules);
+  if (year) return { text: String(year.value), end: year.end };
+
+  const number = readDecimal(chunks, run, rules);
+  const written = formatNumber(number.value, rules);
+  const next = joined(chunks, number.end - 1) ? number.end : -1;
+
+  if (next !== -1) {
+    const percentEnd = matchAny(chunks, next, rules.percent);
+    if (percentEnd !== -1) {
+      return { text: `${written}${rules.percentSpace ? ' ' : ''}%`, end: percentEnd };
+    }
+    const measure = rules.measures[chunks[next].key];
+    if (measure) return { text: `${written} ${measure}`, end: next + 1 };
+    for (const currency of rules.currencies) {
+      const currencyEnd = matchAny(chunks, next, currency.words);
+      if (currencyEnd === -1) continue;
+      const cents = number.digits === 0 ? readCents(chunks, currencyEnd, rules) : null;
+      const amount = cents
+        ? formatNumber(number.value + cents.value / 100, rules, 2)
+        : formatNumber(number.value, rules, number.digits);
+      return {
+        text: currency.prefix ? `${currency.symbol}${amount}` : `${amount} ${currency.symbol}`,
+        end: cents?.end ?? currencyEnd,
+      };
+    }
+  }
+
+  if (number.digits === 0 && number.value < 10) return null;
+  return { text: written, end: number.end };
+}
+
+/** "… euros (and) fifty cents" after a currency word. */
+function readCents(
+  chunks: Chunk[],
+  start: number,
+  rules: RuleSet,
+): { value: number; end: number } | null {
+  if (!joined(chunks, start - 1)) return null;
+  let at = start;
+  if (rules.subunitConnectors.includes(chunks[at]?.key) && joined(chunks, at)) at++;
+  const run = readNumber(chunks, at, rules);
+  if (!run || run.ordinal || run.value < 1 || run.value > 99 || !joined(chunks, run.end - 1)) {
+    return null;
+  }
+  return rules.subunits.includes(chunks[run.end]?.key)
+    ? { value: run.value, end: run.end + 1 }
+    : null;
+}
+
+/** `text` with spelled-out numbers, dates and amounts written the way `language` writes them. */
+export function normalizeText(text: string, language: string | null | undefined): string {
+  const rules = RULE_SETS[(language ?? '').toLowerCase().split(/[-_]/)[0]];
+  if (!rules) return text;
+  const { head, chunks } = splitChunks(text);
+  let out = head;
+  let i = 0;
+  while (i < chunks.length) {
+    const rewrite = readDate(chunks, i, rules) ?? readQuantity(chunks, i, rules);
+    if (!rewrite) {
+      const chunk = chunks[i];
+      out += chunk.lead + chunk.core + chunk.trail + chunk.space;
+      i++;
+      continue;
+    }
+    const last = chunks[rewrite.end - 1];
+    out += chunks[i].lead + rewrite.text + last.trail + last.space;
+    i = rewrite.end;
+  }
+  return out;
+}
+
+/**
+ * Normalize a transcript's text and segments. Word timings of a segment
+ * whose text changed are dropped: they no longer line up with it.
+ */
+export function normalizeTranscription(
+  transcription: TranscriptionResponse,
+): TranscriptionResponse {
+  const language = transcription.language;
+  if (!language || !RULE_SETS[language.toLowerCase().split(/[-_]/)[0]]) return transcription;
+  let changed = false;
+  const segments = transcription.segments.map((seg) => {
+    const text = normalizeText(seg.text, language);
+    if (text === seg.text) return seg;
+    changed = true;
+    const rewritten = { ...seg, text };
+    delete rewritten.words;
+    return rewritten;
+  });
+  const text = normalizeText(transcription.text, language);
+  if (!changed && text === transcription.text) return transcription;
+  return {
+    ...transcription,
+    text,
+    segments,
+    words: changed ? segments.flatMap((s) => s.words ?? []) : transcription.words,
+  };
+}
+
+/** Whether exports write numbers, dates and amounts as digits (Settings → Client → Output). */
+export async function loadNormalizationEnabled(): Promise<boolean> {
+  return (await getConfig<boolean>('output.normalizeNumbers')) === true;
+}
@@ -0,0 +1,51 @@
+import { describe, expect, it } from 'vitest';
+import { offsetTranscription, parseRangeTime, rangeFilename, rangeLabel } from './timeRange';
+
+describe('parseRangeTime', () => {
+  it('reads seconds, m:ss and h:mm:ss', () => {
+    expect(parseRangeTime('45')).toBe(45_000);
+    expect(parseRangeTime('10:00')).toBe(600_000);
+    expect(parseRangeTime(' 1:02:03.5 ')).toBe(3_723_500);
+    expect(parseRangeTime('90:00')).toBe(5_400_000);
+  });
+
+  it('rejects malformed times', () => {
+    expect(parseRangeTime('')).toBeNull();
+    expect(parseRangeTime('1:75:00')).toBeNull();
+    expect(parseRangeTime('10:60')).toBeNull();
+    expect(parseRangeTime('ten')).toBeNull();
+  });
+});
+
+describe('rangeFilename', () => {
+  it('labels the output with the range', () => {
+    expect(rangeLabel(3_723_000)).toBe('1h02m03s');
+    expect(rangeFilename('talk.mp4', { startMs: 600_000, endMs: 1_200_000 })).toBe(
+      'talk_10m00s-20m00s.mp4',
+    );
+  });
+});
+
+describe('offsetTranscription', () => {
+  it('moves segments, nested words and words onto the recording', () => {
+    const word = { word: 'Hi', start: 0.5, end: 0.9 };
+    const shifted = offsetTranscription(
+      {
+        text: 'Hi',
+        segments: [{ text: 'Hi', start: 0.5, end: 0.9, words: [word] }],
+        words: [word],
+        language_probability: 1,
+        duration: 1,
+        num_speakers: 0,
+        partial: true,
+        completed_seconds: 1,
+      },
+      600,
+    );
+
+    expect(shifted.segments[0]).toMatchObject({ start: 600.5, end: 600.9 });
+    expect(shifted.segments[0].words?.[0]).toMatchObject({ start: 600.5, end: 600.9 });
+    expect(shifted.words[0].start).toBe(600.5);
+    expect(shifted.completed_seconds).toBe(601);
+  });
+});
@@ -0,0 +1,68 @@
+import type { TranscriptionResponse } from '../api/types';
+
+/**
+ * Time-range transcription: transcribe just the relevant ten minutes of a
+ * four-hour recording. The main process cuts the span out with ffmpeg
+ * (electron/mediaClip.ts) so only the clip is uploaded; the transcript that
+ * comes back is shifted by the range start, so its timestamps — and the
+ * subtitles written from it — line up with the full recording.
+ */
+
+export interface TimeRange {
+  startMs: number;
+  endMs: number;
+}
+
+/** `1:02:03`, `62:03`, `45` or `1:02:03.5` as milliseconds; null when malformed. */
+export function parseRangeTime(value: string): number | null {
+  const match = /^(?:(\d+):)??(?:(\d+):)?(\d+(?:\.\d+)?)$/.exec(value.trim());
+  if (!match) return null;
+  const [, h, m, s] = match;
+  const hasMinutes = m !== undefined;
+  // A leading minutes field may run past 59 ("90:00"); inner fields may not.
+  if (h !== undefined && Number(m) > 59) return null;
+  if (hasMinutes && Number(s) >= 60) return null;
+  const hours = Number(h ?? 0);
+  const minutes = Number(m ?? 0);
+  return Math.round(((hours * 60 + minutes) * 60 + Number(s)) * 1000);
+}
+
+/** `1h02m03s` / `10m00s` — for file names, so no colons. */
+export function rangeLabel(ms: number): string {
+  const total = Math.max(0, Math.floor(ms / 1000));
+  const h = Math.floor(total / 3600);
+  const m = String(Math.floor((total % 3600) / 60)).padStart(2, '0');
+  const s = String(total % 60).padStart(2, '0');
+  return h > 0 ? `${h}h${m}m${s}s` : `${m}m${s}s`;
+}
+
+/** `talk.mp4` → `talk_10m00s-20m00s.mp4`, so a range never overwrites the full transcript. */
+export function rangeFilename(filename: string, range: TimeRange): string {
+  const dot = filename.lastIndexOf('.');
+  const stem = dot > 0 ? filename.slice(0, dot) : filename;
+  const ext = dot > 0 ? filename.slice(dot) : '';
+  return `${stem}_${rangeLabel(range.startMs)}-${rangeLabel(range.endMs)}${ext}`;
+}
+
+function shiftTimed<T extends { start: number; end: number }>(item: T, offset: number): T {
+  return { ...item, start: item.start + offset, end: item.end + offset };
+}
+
+/** Move a clip's transcript onto the full recording's timeline. */
+export function offsetTranscription(
+  transcription: TranscriptionResponse,
+  offsetSeconds: number,
+): TranscriptionResponse {
+  if (offsetSeconds === 0) return transcription;
+  return {
+    ...transcription,
+    segments: transcription.segments.map((seg) => ({
+      ...shiftTimed(seg, offsetSeconds),
+      ...(seg.words ? { words: seg.words.map((w) => shiftTimed(w, offsetSeconds)) } : {}),
+    })),
+    words: transcription.words.map((w) => shiftTimed(w, offsetSeconds)),
+    ...(transcription.completed_seconds != null
+      ? { completed_seconds: transcription.completed_seconds + offsetSeconds }
+      : {}),
+  };
+}
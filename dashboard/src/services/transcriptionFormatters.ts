@@ -5,7 +5,7 @@
  * the database format used by server-side subtitle_export.py.
  */
 
-import type { TranscriptionResponse } from '../api/types';
+import type { TranscriptionResponse, TranscriptionSegment } from '../api/types';
 
 /**
  * Build a speaker normalization map (raw label → "Speaker N") by first appearance order.
@@ -187,6 +187,24 @@ export interface ResolvedOutput {
  * the Session import path. Falls back to .txt when the response carries no
  * usable segments (nothing to build subtitle cues from).
  */
+/**
+ * A stored notebook transcript in the shape the session formatters take —
+ * for writing out an existing recording's transcript instead of a new one.
+ */
+export function transcriptionFromSegments(
+  segments: TranscriptionSegment[],
+): TranscriptionResponse {
+  const speakers = new Set(segments.map((s) => s.speaker).filter(Boolean));
+  return {
+    text: segments.map((s) => s.text.trim()).filter(Boolean).join(' '),
+    segments,
+    words: segments.flatMap((s) => s.words ?? []),
+    language_probability: 0,
+    duration: segments.length ? segments[segments.length - 1].end : 0,
+    num_speakers: speakers.size,
+  };
+}
+
 export function resolveTranscriptionOutputs(
   filename: string,
   transcription: TranscriptionResponse,
@@ -0,0 +1,34 @@
+// @vitest-environment node
+
+import { describe, expect, it } from 'vitest';
+
+import { gzipBlob, isCompressibleAudio } from './uploadCompression';
+
+describe('isCompressibleAudio', () => {
+  it('picks uncompressed audio by MIME type or extension', () => {
+    expect(isCompressibleAudio(new File(['x'], 'take.bin', { type: 'audio/wav' }))).toBe(true);
+    expect(isCompressibleAudio(new File(['x'], 'Take 1.WAV'))).toBe(true);
+    expect(isCompressibleAudio(new File(['x'], 'capture.pcm'))).toBe(true);
+  });
+
+  it('leaves compressed formats alone', () => {
+    expect(isCompressibleAudio(new File(['x'], 'memo.m4a', { type: 'audio/mp4' }))).toBe(false);
+    expect(isCompressibleAudio(new File(['x'], 'song.flac'))).toBe(false);
+    // A declared compressed type wins over a misleading extension.
+    expect(isCompressibleAudio(new File(['x'], 'odd.wav', { type: 'audio/mpeg' }))).toBe(false);
+    expect(isCompressibleAudio(new Blob(['x']))).toBe(false);
+  });
+});
+
+describe('gzipBlob', () => {
+  it('round-trips and shrinks repetitive PCM', async () => {
+    const pcm = new Uint8Array(64 * 1024).map((_, i) => (i % 64 < 32 ? 0 : 255));
+    const compressed = await gzipBlob(new Blob([pcm]));
+
+    expect(compressed.size).toBeLessThan(pcm.length / 10);
+    const restored = await new Response(
+      compressed.stream().pipeThrough(new DecompressionStream('gzip')),
+    ).arrayBuffer();
+    expect(new Uint8Array(restored)).toEqual(pcm);
+  });
+});
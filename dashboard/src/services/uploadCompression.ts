@@ -0,0 +1,44 @@
+/**
+ * Upload compression — gzip for audio that is stored uncompressed.
+ *
+ * WAV, AIFF and raw PCM usually shrink by a third or more under gzip, which
+ * matters for remote servers over WAN. Already-compressed formats (MP3, M4A,
+ * Opus, FLAC) would only cost CPU, so they are sent as they are. The server
+ * decodes the body before any route sees it (server api/content_encoding.py)
+ * and lists the encodings it accepts in `/api/status` — never compress for a
+ * server that does not, or it would store the gzip bytes as the audio.
+ *
+ * Responses need nothing here: Chromium already sends `Accept-Encoding` and
+ * decompresses gzip JSON transparently.
+ */
+
+const UNCOMPRESSED_AUDIO_TYPES = new Set([
+  'audio/wav',
+  'audio/wave',
+  'audio/x-wav',
+  'audio/vnd.wave',
+  'audio/aiff',
+  'audio/x-aiff',
+  'audio/l16',
+  'audio/pcm',
+]);
+
+const UNCOMPRESSED_AUDIO_EXTENSIONS = /\.(wav|wave|aif|aiff|pcm|raw)$/i;
+
+/** Whether `file` is audio worth gzipping on the way to the server. */
+export function isCompressibleAudio(file: { type: string; name?: string }): boolean {
+  const type = file.type.split(';')[0].trim().toLowerCase();
+  if (UNCOMPRESSED_AUDIO_TYPES.has(type)) return true;
+  return !type.startsWith('audio/') && UNCOMPRESSED_AUDIO_EXTENSIONS.test(file.name ?? '');
+}
+
+/** Whether this runtime can gzip in a stream (every Electron and modern browser). */
+export function canCompressUploads(): boolean {
+  return typeof CompressionStream !== 'undefined';
+}
+
+/** Gzip `blob`, keeping the whole compressed result in memory. */
+export async function gzipBlob(blob: Blob): Promise<Blob> {
+  const stream = blob.stream().pipeThrough(new CompressionStream('gzip'));
+  return new Response(stream).blob();
+}
@@ -0,0 +1,192 @@
+import { describe, expect, it, vi } from 'vitest';
+
+import { APIError } from '../api/client';
+import {
+  BandwidthBudget,
+  UploadPipeline,
+  describeUploadProgress,
+  stageUpload,
+  type PipelineClock,
+  type StagingApi,
+} from './uploadPipeline';
+
+/** A clock whose sleeps advance time instantly and are recorded. */
+function fakeClock(): PipelineClock & { time: number; sleeps: number[] } {
+  const clock = {
+    time: 0,
+    sleeps: [] as number[],
+    now: () => clock.time,
+    sleep: async (ms: number) => {
+      clock.sleeps.push(ms);
+      clock.time += ms;
+    },
+  };
+  return clock;
+}
+
+/** An in-memory staging endpoint that keeps the bytes it was sent. */
+function fakeApi() {
+  const uploads = new Map<string, { size: number; data: string }>();
+  let counter = 0;
+  const api = {
+    uploads,
+    createStagedUpload: vi.fn(async (_filename: string, size: number) => {
+      const upload_id = `up-${++counter}`;
+      uploads.set(upload_id, { size, data: '' });
+      return { upload_id, received: 0, size };
+    }),
+    appendStagedUpload: vi.fn(async (uploadId: string, offset: number, chunk: Blob) => {
+      const upload = uploads.get(uploadId)!;
+      const path = `/api/transcribe/uploads/${uploadId}?offset=${offset}`;
+      if (offset !== upload.data.length) {
+        throw new APIError(409, JSON.stringify({ received: upload.data.length }), path);
+      }
+      upload.data += await chunk.text();
+      return { upload_id: uploadId, received: upload.data.length, size: upload.size };
+    }),
+    discardStagedUpload: vi.fn(async (uploadId: string) => {
+      uploads.delete(uploadId);
+    }),
+  } satisfies StagingApi & { uploads: unknown };
+  return api;
+}
+
+describe('BandwidthBudget', () => {
+  it('spaces chunks so all callers together stay under the rate', async () => {
+    const clock = fakeClock();
+    const budget = new BandwidthBudget(1000, clock);
+
+    await budget.take(500);
+    await budget.take(500);
+    await budget.take(1000);
+
+    expect(clock.sleeps).toEqual([500, 500]);
+    expect(clock.time).toBe(1000);
+  });
+
+  it('never waits when unlimited', async () => {
+    const clock = fakeClock();
+    const budget = new BandwidthBudget(0, clock);
+    await budget.take(10_000_000);
+    expect(clock.sleeps).toEqual([]);
+  });
+});
+
+describe('stageUpload', () => {
+  it('sends the file in chunks and reports progress', async () => {
+    const api = fakeApi();
+    const onProgress = vi.fn();
+
+    const ref = await stageUpload(new File(['abcdefgh'], 'memo.wav'), {
+      api,
+      chunkBytes: 3,
+      onProgress,
+    });
+
+    expect(api.uploads.get(ref.upload_id)?.data).toBe('abcdefgh');
+    expect(api.createStagedUpload).toHaveBeenCalledWith('memo.wav', 8);
+    expect(onProgress.mock.calls).toEqual([
+      [0, 8],
+      [3, 8],
+      [6, 8],
+      [8, 8],
+    ]);
+  });
+
+  it('awaits bodies that read each chunk when sliced', async () => {
+    const api = fakeApi();
+    const content = 'abcdefgh';
+    const body = {
+      name: 'watched.wav',
+      size: content.length,
+      type: '',
+      slice: async (start: number, end: number) => new Blob([content.slice(start, end)]),
+    };
+
+    const ref = await stageUpload(body, { api, chunkBytes: 5 });
+
+    expect(api.uploads.get(ref.upload_id)?.data).toBe('abcdefgh');
+  });
+
+  it('resumes from the server offset after a retried chunk had already arrived', async () => {
+    const api = fakeApi();
+    const clock = fakeClock();
+    const original = api.appendStagedUpload.getMockImplementation()!;
+    // The first chunk reaches the server but the response is lost.
+    api.appendStagedUpload.mockImplementationOnce(async (...args) => {
+      await original(...args);
+      throw new TypeError('Failed to fetch');
+    });
+
+    const ref = await stageUpload(new File(['abcdef'], 'a.wav'), { api, clock, chunkBytes: 3 });
+
+    expect(api.uploads.get(ref.upload_id)?.data).toBe('abcdef');
+    expect(clock.sleeps).toHaveLength(1);
+  });
+
+  it('discards the partial upload when the server rejects a chunk', async () => {
+    const api = fakeApi();
+    api.appendStagedUpload.mockRejectedValueOnce(new APIError(413, 'too big', '/x'));
+
+    await expect(stageUpload(new File(['abc'], 'a.wav'), { api })).rejects.toThrow('API 413');
+    expect(api.discardStagedUpload).toHaveBeenCalledWith('up-1');
+  });
+});
+
+describe('UploadPipeline', () => {
+  const item = (key: string, content = 'audio') => ({
+    key,
+    load: async () => new File([content], `${key}.wav`),
+  });
+
+  it('stages up to the stream limit ahead and hands each job its upload', async () => {
+    const api = fakeApi();
+    const pipeline = new UploadPipeline({ api, clock: fakeClock() });
+    pipeline.configure(2, 0);
+
+    pipeline.schedule([item('a'), item('b'), item('c')]);
+    expect(pipeline.has('c')).toBe(false);
+
+    expect(await pipeline.take('a')).toEqual({ upload_id: expect.any(String) });
+    pipeline.schedule([item('b'), item('c')]);
+    expect(pipeline.has('c')).toBe(true);
+    expect(await pipeline.take('missing')).toBeNull();
+  });
+
+  it('stops staging on servers without staged uploads', async () => {
+    const api = fakeApi();
+    api.createStagedUpload.mockRejectedValue(
+      new APIError(404, 'Not Found', '/api/transcribe/uploads'),
+    );
+    const pipeline = new UploadPipeline({ api });
+    pipeline.configure(3, 0);
+
+    pipeline.schedule([item('a')]);
+    expect(await pipeline.take('a')).toBeNull();
+    expect(pipeline.enabled).toBe(false);
+  });
+
+  it('discards the staged file of a cancelled job', async () => {
+    const api = fakeApi();
+    const pipeline = new UploadPipeline({ api });
+    pipeline.configure(2, 0);
+
+    pipeline.schedule([item('a')]);
+    pipeline.cancel('a');
+    await vi.waitFor(() => expect(api.uploads.size).toBe(0));
+    expect(pipeline.has('a')).toBe(false);
+  });
+
+  it('is off with a single stream', () => {
+    const pipeline = new UploadPipeline({ api: fakeApi() });
+    pipeline.configure(1, 0);
+    pipeline.schedule([item('a')]);
+    expect(pipeline.has('a')).toBe(false);
+  });
+});
+
+describe('describeUploadProgress', () => {
+  it('shows whole percentages', () => {
+    expect(describeUploadProgress({ sent: 1, total: 3 })).toBe('Uploading… 33%');
+  });
+});
@@ -0,0 +1,302 @@
+/**
+ * Upload pipeline — stages the upcoming files of an import batch on the
+ * server while the current one transcribes.
+ *
+ * The server runs one transcription at a time and used to receive each file
+ * only when its job started, so a batch alternated between uploading and
+ * transcribing. The pipeline instead sends the next few files ahead of time
+ * through `/api/transcribe/uploads`, several in parallel, in chunks that
+ * report per-file progress. All streams draw from one {@link BandwidthBudget}
+ * so a batch cannot saturate a slow link. A job then starts with its
+ * `upload_id` instead of the file.
+ *
+ * Staging is best-effort: when it fails — including on servers that predate
+ * staged uploads — the job falls back to a direct upload.
+ */
+
+import { apiClient, APIError } from '../api/client';
+import type { StagedUploadRef, StagedUploadStatus } from '../api/types';
+import { isCompressibleAudio } from './uploadCompression';
+
+/** Below the server's 16 MiB per-chunk limit; small enough for smooth progress. */
+export const DEFAULT_CHUNK_BYTES = 4 * 1024 * 1024;
+export const DEFAULT_PARALLEL_STREAMS = 3;
+const MAX_CHUNK_ATTEMPTS = 3;
+const RETRY_DELAY_MS = 1_000;
+
+export interface PipelineClock {
+  now(): number;
+  sleep(ms: number, signal?: AbortSignal): Promise<void>;
+}
+
+const realClock: PipelineClock = {
+  now: () => Date.now(),
+  sleep: (ms, signal) =>
+    new Promise((resolve, reject) => {
+      const timer = setTimeout(resolve, ms);
+      signal?.addEventListener(
+        'abort',
+        () => {
+          clearTimeout(timer);
+          reject(signal.reason);
+        },
+        { once: true },
+      );
+    }),
+};
+
+/**
+ * A bytes-per-second allowance shared by every upload stream. Each chunk
+ * reserves its share of the next free transfer time, so N streams together
+ * stay under the rate instead of each getting the full rate. 0 = unlimited.
+ */
+export class BandwidthBudget {
+  private cursor = 0;
+
+  constructor(
+    private bytesPerSecond = 0,
+    private readonly clock: PipelineClock = realClock,
+  ) {}
+
+  get rate(): number {
+    return this.bytesPerSecond;
+  }
+
+  setRate(bytesPerSecond: number): void {
+    this.bytesPerSecond = Math.max(0, bytesPerSecond);
+    this.cursor = 0;
+  }
+
+  /** Wait until `bytes` can be sent without going over the budget. */
+  async take(bytes: number, signal?: AbortSignal): Promise<void> {
+    if (this.bytesPerSecond <= 0) return;
+    const now = this.clock.now();
+    const start = Math.max(now, this.cursor);
+    this.cursor = start + (bytes / this.bytesPerSecond) * 1000;
+    if (start > now) await this.clock.sleep(start - now, signal);
+  }
+}
+
+/**
+ * What `stageUpload` reads from: a picked File, or a {@link LocalFile} that
+ * reads each chunk from disk when it is sliced (services/localFile.ts).
+ */
+export interface UploadBody {
+  readonly name: string;
+  readonly size: number;
+  readonly type: string;
+  slice(start: number, end: number): Blob | Promise<Blob>;
+}
+
+/** The subset of apiClient the pipeline uses — swapped out in tests. */
+export interface StagingApi {
+  createStagedUpload(filename: string, size: number): Promise<StagedUploadStatus>;
+  appendStagedUpload(
+    uploadId: string,
+    offset: number,
+    chunk: Blob,
+    signal?: AbortSignal,
+    compress?: boolean,
+  ): Promise<StagedUploadStatus>;
+  discardStagedUpload(uploadId: string): Promise<void>;
+}
+
+export interface StageOptions {
+  api?: StagingApi;
+  chunkBytes?: number;
+  budget?: BandwidthBudget;
+  clock?: PipelineClock;
+  onProgress?: (sent: number, total: number) => void;
+  signal?: AbortSignal;
+}
+
+/** Whether `err` means the server predates staged uploads. */
+export function isStagingUnsupported(err: unknown): boolean {
+  return err instanceof APIError && err.status === 404 && err.path.endsWith('/uploads');
+}
+
+/** The byte count a 409 offset-mismatch reply says the server has, if that is what `err` is. */
+function receivedFromMismatch(err: unknown): number | null {
+  if (!(err instanceof APIError) || err.status !== 409) return null;
+  try {
+    const received = JSON.parse(err.body)?.received;
+    return typeof received === 'number' ? received : null;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Send `file` to the server's staging area in chunks. A chunk that fails is
+ * retried; a retry the server already has is answered with a 409 carrying
+ * its byte count, and the upload continues from there. On failure the
+ * partial upload is discarded.
+ */
+export async function stageUpload(
+  file: UploadBody,
+  options: StageOptions = {},
+): Promise<StagedUploadRef> {
+  const api = options.api ?? apiClient;
+  const clock = options.clock ?? realClock;
+  const chunkBytes = options.chunkBytes ?? DEFAULT_CHUNK_BYTES;
+  const { budget, onProgress, signal } = options;
+
+  const { upload_id } = await api.createStagedUpload(file.name, file.size);
+  const compress = isCompressibleAudio(file);
+  let offset = 0;
+  try {
+    onProgress?.(0, file.size);
+    while (offset < file.size) {
+      signal?.throwIfAborted();
+      const chunk = await file.slice(offset, offset + chunkBytes);
+      await budget?.take(chunk.size, signal);
+      for (let attempt = 1; ; attempt++) {
+        try {
+          const status = await api.appendStagedUpload(upload_id, offset, chunk, signal, compress);
+          offset = status.received;
+          break;
+        } catch (err) {
+          const received = receivedFromMismatch(err);
+          if (received !== null) {
+            offset = received;
+            break;
+          }
+          const retryable = !(err instanceof APIError) || err.status >= 500;
+          if (signal?.aborted || !retryable || attempt >= MAX_CHUNK_ATTEMPTS) throw err;
+          await clock.sleep(RETRY_DELAY_MS * attempt, signal);
+        }
+      }
+      onProgress?.(offset, file.size);
+    }
+  } catch (err) {
+    void api.discardStagedUpload(upload_id).catch(() => {});
+    throw err;
+  }
+  return { upload_id };
+}
+
+export interface PipelineItem {
+  key: string;
+  /** Open the file only when its staging starts (auto-watch jobs are paths). */
+  load: () => Promise<UploadBody>;
+}
+
+interface Staging {
+  controller: AbortController;
+  result: Promise<StagedUploadRef | null>;
+}
+
+export interface UploadPipelineOptions {
+  api?: StagingApi;
+  clock?: PipelineClock;
+  chunkBytes?: number;
+  onProgress?: (key: string, sent: number, total: number) => void;
+}
+
+/**
+ * Keeps up to `parallelStreams` files of a batch staged or staging ahead of
+ * their jobs. `schedule` is called with the upcoming jobs in queue order;
+ * `take` hands a job its staged upload once the job starts.
+ */
+export class UploadPipeline {
+  readonly budget: BandwidthBudget;
+  private parallelStreams = DEFAULT_PARALLEL_STREAMS;
+  private supported = true;
+  private readonly stagings = new Map<string, Staging>();
+
+  constructor(private readonly options: UploadPipelineOptions = {}) {
+    this.budget = new BandwidthBudget(0, options.clock);
+  }
+
+  /** Apply `upload.parallelStreams` and `upload.bandwidthLimitMbps`; re-enables staging. */
+  configure(parallelStreams: number, bandwidthLimitMbps: number): void {
+    this.parallelStreams = Math.max(1, Math.floor(parallelStreams));
+    this.budget.setRate((bandwidthLimitMbps * 1_000_000) / 8);
+    this.supported = true;
+  }
+
+  /** False with one stream (plain direct uploads) or once the server refused staging. */
+  get enabled(): boolean {
+    return this.parallelStreams > 1 && this.supported;
+  }
+
+  has(key: string): boolean {
+    return this.stagings.has(key);
+  }
+
+  /**
+   * Start staging the first of `upcoming` that are not staged yet, up to the
+   * stream limit. Stagings for jobs no longer in `upcoming` (removed, failed)
+   * are cancelled.
+   */
+  schedule(upcoming: PipelineItem[]): void {
+    const keys = new Set(upcoming.map((item) => item.key));
+    for (const key of [...this.stagings.keys()]) {
+      if (!keys.has(key)) this.cancel(key);
+    }
+    if (!this.enabled) return;
+    for (const item of upcoming) {
+      if (this.stagings.size >= this.parallelStreams) return;
+      if (this.stagings.has(item.key)) continue;
+      const controller = new AbortController();
+      const result = item
+        .load()
+        .then((file) => {
+          if (file.size === 0) return null;
+          return stageUpload(file, {
+            api: this.options.api,
+            clock: this.options.clock,
+            chunkBytes: this.options.chunkBytes,
+            budget: this.budget,
+            signal: controller.signal,
+            onProgress: (sent, total) => this.options.onProgress?.(item.key, sent, total),
+          });
+        })
+        .catch((err) => {
+          if (isStagingUnsupported(err)) {
+            this.supported = false;
+          } else if (!controller.signal.aborted) {
+            console.warn(`Staging ${item.key} failed, uploading it directly:`, err);
+          }
+          return null;
+        });
+      this.stagings.set(item.key, { controller, result });
+    }
+  }
+
+  /**
+   * The staged upload for `key`, once it has finished, or null when there
+   * is none and the job should upload the file itself.
+   */
+  async take(key: string): Promise<StagedUploadRef | null> {
+    const staging = this.stagings.get(key);
+    if (!staging) return null;
+    try {
+      return await staging.result;
+    } finally {
+      this.stagings.delete(key);
+    }
+  }
+
+  /** Stop staging `key` and drop whatever the server already has. */
+  cancel(key: string): void {
+    const staging = this.stagings.get(key);
+    if (!staging) return;
+    this.stagings.delete(key);
+    staging.controller.abort();
+    const api = this.options.api ?? apiClient;
+    void staging.result
+      .then((ref) => ref && api.discardStagedUpload(ref.upload_id))
+      .catch(() => {});
+  }
+
+  cancelAll(): void {
+    for (const key of [...this.stagings.keys()]) this.cancel(key);
+  }
+}
+
+/** Status text for a job whose file is still on its way to the server. */
+export function describeUploadProgress(progress: { sent: number; total: number }): string {
+  const percent = progress.total > 0 ? Math.floor((progress.sent / progress.total) * 100) : 0;
+  return `Uploading… ${percent}%`;
+}
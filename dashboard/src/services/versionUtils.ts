@@ -35,10 +35,18 @@ export const VULKAN_WSL2_IMAGE_REPO = 'ghcr.io/homelab-00/transcriptionsuite-ser
  */
 export const VULKAN_LINUX_IMAGE_REPO = 'ghcr.io/homelab-00/transcriptionsuite-server-vulkan-linux';
 
+/**
+ * Separate GHCR repos for the AMD ROCm and Intel oneAPI (XPU) image variants
+ * (`rocm` / `xpu` runtime profiles, Linux only). Kept in sync with
+ * `dockerManager.ts`.
+ */
+export const ROCM_IMAGE_REPO = 'ghcr.io/homelab-00/transcriptionsuite-server-rocm';
+export const XPU_IMAGE_REPO = 'ghcr.io/homelab-00/transcriptionsuite-server-xpu';
+
 /**
  * Return the GHCR repo URL the dashboard should use for this session, based on
  * the user's `useLegacyGpu` setting (Issue #83) and the active runtime profile.
- * Vulkan-WSL2 and Linux Vulkan each get their own dedicated repo. Never mixes
+ * Vulkan-WSL2, Linux Vulkan, ROCm and XPU each get their own dedicated repo. Never mixes
  * repos within a single session — the dashboard uses exactly one repo at a
  * time. Kept in sync with the `resolveImageRepo` twin in
  * `dashboard/electron/dockerManager.ts`.
@@ -49,6 +57,8 @@ export function resolveImageRepo(
 ): string {
   if (runtimeProfile === 'vulkan-wsl2') return VULKAN_WSL2_IMAGE_REPO;
   if (runtimeProfile === 'vulkan') return VULKAN_LINUX_IMAGE_REPO;
+  if (runtimeProfile === 'rocm') return ROCM_IMAGE_REPO;
+  if (runtimeProfile === 'xpu') return XPU_IMAGE_REPO;
   return useLegacyGpu ? LEGACY_IMAGE_REPO : IMAGE_REPO;
 }
 
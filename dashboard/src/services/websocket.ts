@@ -9,6 +9,7 @@
 
 import { apiClient } from '../api/client';
 import { logClientEvent, type ClientLogType } from './clientDebugLog';
+import { traceSocketEvent } from './networkTrace';
 
 // ─── Types ────────────────────────────────────────────────────────────────────
 
@@ -46,7 +47,9 @@ const DEFAULT_RECONNECT: ReconnectConfig = {
 
 const NOISY_SERVER_MESSAGE_TYPES = new Set([
   'pong',
+  'audio_ack',
   'partial',
+  'segment',
   'vad_start',
   'vad_stop',
   'vad_recording_start',
@@ -97,12 +100,16 @@ const DEFAULT_AUDIO_SAMPLE_RATE = 16_000;
 /**
  * Frame raw PCM Int16 audio into the server's binary format:
  *   [uint32 LE metadata length] [JSON metadata] [raw PCM bytes]
+ * `seq` numbers the chunk for Live Mode acks and replay (services/audioReplay.ts).
  */
 export function frameAudioChunk(
   pcmInt16: Int16Array,
   sampleRate: number = DEFAULT_AUDIO_SAMPLE_RATE,
+  seq?: number,
 ): ArrayBuffer {
-  const metadata = JSON.stringify({ sample_rate: sampleRate });
+  const metadata = JSON.stringify(
+    seq === undefined ? { sample_rate: sampleRate } : { sample_rate: sampleRate, seq },
+  );
   const metaBytes = new TextEncoder().encode(metadata);
   const metaLen = metaBytes.byteLength;
 
@@ -202,6 +209,7 @@ export class TranscriptionSocket {
     this.ws.binaryType = 'arraybuffer';
 
     this.ws.onopen = () => {
+      traceSocketEvent(url, 'open');
       this.setState('authenticating');
       this.log('Socket connected', 'success');
       // Send auth message — token may be empty for localhost bypass
@@ -211,6 +219,7 @@ export class TranscriptionSocket {
 
     this.ws.onmessage = (ev: MessageEvent) => {
       if (typeof ev.data === 'string') {
+        traceSocketEvent(url, 'receive', ev.data);
         try {
           const msg: ServerMessage = JSON.parse(ev.data);
           this.handleMessage(msg);
@@ -222,12 +231,14 @@ export class TranscriptionSocket {
     };
 
     this.ws.onerror = () => {
+      traceSocketEvent(url, 'error');
       this.setState('error');
       this.log('WebSocket transport error', 'error');
       this.callbacks.onError?.('WebSocket connection error');
     };
 
     this.ws.onclose = (ev: CloseEvent) => {
+      traceSocketEvent(url, 'close', `code=${ev.code}${ev.reason ? ` reason=${ev.reason}` : ''}`);
       this.cleanup();
       this.setState('disconnected');
       this.log(
@@ -274,17 +285,19 @@ export class TranscriptionSocket {
   /** Send a typed JSON message. */
   sendJSON(msg: { type: string; data?: unknown }): void {
     if (this.ws?.readyState === WebSocket.OPEN) {
+      const frame = JSON.stringify(msg);
       if (msg.type !== 'ping') {
         this.log(`=> ${msg.type}`);
+        if (this.connectedUrl) traceSocketEvent(this.connectedUrl, 'send', frame);
       }
-      this.ws.send(JSON.stringify(msg));
+      this.ws.send(frame);
     }
   }
 
   /** Send a framed binary audio chunk. */
-  sendAudio(pcmInt16: Int16Array): void {
+  sendAudio(pcmInt16: Int16Array, seq?: number): void {
     if (this.ws?.readyState === WebSocket.OPEN) {
-      this.ws.send(frameAudioChunk(pcmInt16, this.audioSampleRateHz));
+      this.ws.send(frameAudioChunk(pcmInt16, this.audioSampleRateHz, seq));
     }
   }
 
@@ -356,6 +369,7 @@ export class TranscriptionSocket {
     this.ws.binaryType = 'arraybuffer';
 
     this.ws.onopen = () => {
+      traceSocketEvent(url, 'open');
       this.setState('authenticating');
       this.reconnectAttempt = 0; // reset on success
       this.log('Socket reconnected', 'success');
@@ -365,6 +379,7 @@ export class TranscriptionSocket {
 
     this.ws.onmessage = (ev: MessageEvent) => {
       if (typeof ev.data === 'string') {
+        traceSocketEvent(url, 'receive', ev.data);
         try {
           const msg: ServerMessage = JSON.parse(ev.data);
           this.handleMessage(msg);
@@ -375,12 +390,14 @@ export class TranscriptionSocket {
     };
 
     this.ws.onerror = () => {
+      traceSocketEvent(url, 'error');
       this.setState('error');
       this.log('Reconnect attempt failed', 'error');
       this.callbacks.onError?.('WebSocket connection error');
     };
 
     this.ws.onclose = (ev: CloseEvent) => {
+      traceSocketEvent(url, 'close', `code=${ev.code}${ev.reason ? ` reason=${ev.reason}` : ''}`);
       this.cleanup();
       this.setState('disconnected');
       this.log(
@@ -7,6 +7,29 @@ vi.mock('../api/client', () => ({
     uploadAndTranscribe: vi.fn(),
     getAdminStatus: vi.fn(),
     cancelTranscription: vi.fn().mockResolvedValue(undefined),
+    getRecordingTranscription: vi.fn(),
+    // An idle server with our entry at the front: jobs start straight away.
+    joinServerQueue: vi.fn().mockResolvedValue({ id: 'queue-1' }),
+    getServerQueue: vi.fn().mockResolvedValue({
+      active: null,
+      entries: [{ id: 'queue-1', position: 0, mine: true }],
+    }),
+    leaveServerQueue: vi.fn().mockResolvedValue({ success: true }),
+    // Servers without staged uploads: every job falls back to a direct upload.
+    createStagedUpload: vi.fn().mockRejectedValue(new Error('not supported')),
+    appendStagedUpload: vi.fn(),
+    discardStagedUpload: vi.fn().mockResolvedValue(undefined),
+    getBaseUrl: vi.fn(() => 'http://localhost:9786'),
+    atPort: vi.fn(),
+  },
+  APIError: class extends Error {
+    constructor(
+      public readonly status: number,
+      public readonly body: string,
+      public readonly path: string,
+    ) {
+      super(`API ${status} on ${path}`);
+    }
   },
 }));
 
@@ -35,6 +58,12 @@ vi.mock('sonner', () => ({
   },
 }));
 
+// Server copies ("Keep server copy") are remembered in the config store;
+// only the call matters here.
+vi.mock('../services/serverCopies', () => ({
+  rememberServerCopy: vi.fn().mockResolvedValue([]),
+}));
+
 // Mock the config store so resolveDuplicateChoice's policy read is deterministic
 // (GH-120). All other exports stay real — only getConfig is stubbed.
 vi.mock('../config/store', async (importOriginal) => {
@@ -49,7 +78,8 @@ Object.defineProperty(globalThis, 'window', {
 });
 
 import { toast } from 'sonner';
-import { apiClient } from '../api/client';
+import { apiClient, APIError } from '../api/client';
+import { rememberServerCopy } from '../services/serverCopies';
 import {
   useImportQueueStore,
   resolveDuplicateChoice,
@@ -94,6 +124,7 @@ function resetStore() {
     watcherServerConnected: true,
     watchLog: [],
     avgProcessingMs: 0,
+    heldDetections: [],
     // gh-102 #3 — default to a "ready" cache with a Whisper model so the
     // language-resolution branch in handleFilesDetected falls through (no
     // pause, no explicit-required) and pre-existing tests stay green. Tests
@@ -512,6 +543,25 @@ describe('importQueueStore', () => {
       expect(getState().jobs).toHaveLength(0);
     });
 
+    it('holds files detected while offline and queues them once on reconnect', () => {
+      useImportQueueStore.setState({ watcherServerConnected: false });
+      const detected = {
+        type: 'notebook' as const,
+        files: ['/phone/memo.m4a'],
+        count: 1,
+        fileMeta: [{ path: '/phone/memo.m4a', createdAt: '2026-04-26T12:00:00Z' }],
+        source: 'phone',
+      };
+      getState().handleFilesDetected(detected);
+      getState().handleFilesDetected(detected);
+      expect(getState().heldDetections).toHaveLength(1);
+
+      getState().setWatcherServerConnected(true);
+      expect(getState().jobs).toHaveLength(1);
+      expect(getState().jobs[0].options?.source).toBe('phone');
+      expect(getState().heldDetections).toHaveLength(0);
+    });
+
     // GH-212 — Folder Watch enqueues via addFiles, so auto jobs inherit the
     // plannedFormat stamp with no extra wiring.
     it('stamps plannedFormat on session-auto jobs from the current sessionConfig', () => {
@@ -616,6 +666,406 @@ describe('importQueueStore', () => {
         reason: 'token_missing',
       });
     });
+
+    it('writes a partial transcript and marks the job partial', async () => {
+      mockConfig({ 'sessionImport.outputFormat': 'txt' });
+      mockPollResult({
+        transcription: {
+          ...sessionTranscription,
+          duration: 600,
+          partial: true,
+          partial_reason: 'chunk 5 failed',
+          completed_seconds: 480,
+        },
+      });
+
+      await runQueue();
+
+      expect(writeText).toHaveBeenCalledWith('/out/memo.txt', 'Hello world.');
+      const job = getState().jobs[0];
+      expect(job.status).toBe('success');
+      expect(job.partialUntil).toBe(480);
+      expect(toast.warning).toHaveBeenCalledWith(expect.stringContaining('stopped at 8:00'));
+    });
+
+    it('asks the server to keep a copy and remembers it for download', async () => {
+      mockConfig({ 'sessionImport.outputFormat': 'txt', 'sessionImport.keepServerCopy': true });
+      const kept = { job_id: 'server-job-1-full-id', sha256: 'ab'.repeat(32), size: 5 };
+      vi.mocked(apiClient.importAndTranscribe).mockResolvedValue({
+        job_id: 'server-job-1',
+        kept_media: kept,
+      } as never);
+      mockPollResult({ transcription: sessionTranscription });
+
+      await runQueue();
+
+      expect(apiClient.importAndTranscribe).toHaveBeenLastCalledWith(
+        expect.anything(),
+        expect.objectContaining({ keep_media: true }),
+      );
+      expect(rememberServerCopy).toHaveBeenCalledWith('memo.m4a', kept);
+      expect(getState().jobs[0].status).toBe('success');
+    });
+  });
+
+  // ── Re-encoded / trimmed copies of notebook recordings ─────────────────
+
+  describe('fingerprint duplicates', () => {
+    const COPY: DedupMatch = {
+      recording_id: '7',
+      name: 'Talk',
+      created_at: '2026-05-07T00:00:00Z',
+      source: 'recording',
+      match: 'fingerprint',
+      offset_seconds: 12,
+      duration_seconds: 30,
+      similarity: 0.93,
+    };
+
+    let writeText: Mock;
+
+    beforeEach(() => {
+      writeText = vi.fn().mockResolvedValue(undefined);
+      (window as any).electronAPI = { fileIO: { writeText } };
+      vi.mocked(getConfig).mockReset();
+      vi.mocked(getConfig).mockImplementation(
+        (key: string) =>
+          Promise.resolve(key === 'sessionImport.outputFormat' ? 'txt' : undefined) as never,
+      );
+      vi.mocked(apiClient.getAdminStatus).mockReset();
+      vi.mocked(apiClient.cancelTranscription).mockClear();
+      useDedupChoiceStore.setState({
+        requestChoice: vi.fn((): Promise<DedupChoice> => Promise.resolve('use_existing')),
+      });
+    });
+
+    afterEach(() => {
+      delete (window as any).electronAPI;
+    });
+
+    it('session import writes the existing transcript re-timed onto the copy', async () => {
+      vi.mocked(apiClient.importAndTranscribe).mockResolvedValue({
+        job_id: 'server-job-1',
+        dedup_matches: [COPY],
+      } as never);
+      vi.mocked(apiClient.getRecordingTranscription).mockResolvedValue({
+        recording_id: 7,
+        segments: [{ text: 'Hello again.', start: 0, end: 1.5, words: [] }],
+      });
+
+      getState().updateSessionConfig({ outputDir: '/out' });
+      getState().addFiles([new File(['audio'], 'memo.m4a')], 'session-normal');
+      await vi.advanceTimersByTimeAsync(10_000);
+
+      expect(apiClient.cancelTranscription).toHaveBeenCalled();
+      expect(apiClient.getRecordingTranscription).toHaveBeenCalledWith(7, {
+        offsetSeconds: 12,
+        durationSeconds: 30,
+      });
+      expect(writeText).toHaveBeenCalledWith('/out/memo.txt', 'Hello again.');
+      expect(getState().jobs[0].status).toBe('success');
+    });
+
+    it('notebook import keeps the existing recording instead of transcribing again', async () => {
+      const onJobSuccess = vi.fn();
+      getState().updateNotebookCallbacks({ onJobSuccess });
+      vi.mocked(apiClient.uploadAndTranscribe).mockResolvedValue({
+        job_id: 'server-job-1',
+        dedup_matches: [COPY],
+      });
+
+      getState().addFiles([new File(['audio'], 'memo.m4a')], 'notebook-normal');
+      await vi.advanceTimersByTimeAsync(10_000);
+
+      expect(apiClient.cancelTranscription).toHaveBeenCalled();
+      expect(apiClient.getAdminStatus).not.toHaveBeenCalled();
+      const job = getState().jobs[0];
+      expect(job.status).toBe('success');
+      expect(job.result?.recording_id).toBe(7);
+      expect(onJobSuccess).toHaveBeenCalledWith(
+        expect.objectContaining({ id: job.id }),
+        expect.objectContaining({ recording_id: 7 }),
+      );
+    });
+  });
+
+  // ── Server queue — waiting behind other clients ─────────────────────────
+
+  describe('server queue', () => {
+    const waitingEntry = {
+      id: 'queue-1',
+      position: 1,
+      mine: true,
+      estimated_start_at: 1234,
+    };
+
+    beforeEach(() => {
+      (window as any).electronAPI = { fileIO: { writeText: vi.fn().mockResolvedValue(undefined) } };
+      vi.mocked(getConfig).mockResolvedValue(undefined as never);
+      vi.mocked(apiClient.importAndTranscribe).mockResolvedValue({
+        job_id: 'server-job-1',
+      } as never);
+      vi.mocked(apiClient.getAdminStatus).mockResolvedValue({
+        models: {
+          job_tracker: {
+            is_busy: false,
+            result: { job_id: 'server-job-1', transcription: { text: 'Hi.', segments: [] } },
+          },
+        },
+      } as never);
+    });
+
+    afterEach(() => {
+      delete (window as any).electronAPI;
+      vi.mocked(apiClient.getServerQueue).mockResolvedValue({
+        active: null,
+        entries: [{ id: 'queue-1', position: 0, mine: true }],
+      } as never);
+    });
+
+    it('waits for its turn, then starts the job with its queue entry', async () => {
+      vi.mocked(apiClient.getServerQueue)
+        .mockResolvedValueOnce({
+          active: { user: 'alice', mine: false },
+          entries: [{ id: null, position: 0, mine: false }, waitingEntry],
+        } as never)
+        .mockResolvedValueOnce({
+          active: null,
+          entries: [{ ...waitingEntry, position: 0 }],
+        } as never);
+
+      getState().updateSessionConfig({ outputDir: '/out' });
+      getState().addFiles([new File(['audio'], 'memo.m4a')], 'session-normal');
+      await vi.advanceTimersByTimeAsync(0);
+
+      expect(apiClient.joinServerQueue).toHaveBeenCalledWith('memo.m4a');
+      expect(getState().jobs[0].serverQueue).toEqual({ position: 1, estimatedStartAt: 1234 });
+      expect(apiClient.importAndTranscribe).not.toHaveBeenCalled();
+
+      await vi.advanceTimersByTimeAsync(10_000);
+
+      expect(apiClient.importAndTranscribe).toHaveBeenCalledWith(
+        expect.any(File),
+        expect.objectContaining({ queue_entry_id: 'queue-1' }),
+      );
+      expect(getState().jobs[0].serverQueue).toBeUndefined();
+    });
+
+    it('gives up its place when the upload fails', async () => {
+      vi.mocked(apiClient.importAndTranscribe).mockRejectedValueOnce(new Error('network down'));
+
+      getState().updateSessionConfig({ outputDir: '/out' });
+      getState().addFiles([new File(['audio'], 'memo.m4a')], 'session-normal');
+      await vi.advanceTimersByTimeAsync(1_000);
+
+      expect(apiClient.leaveServerQueue).toHaveBeenCalledWith('queue-1');
+      expect(getState().jobs[0].status).toBe('error');
+    });
+  });
+
+  // ── Server replicas — one server per GPU on the next ports ──────────────
+
+  describe('server replicas', () => {
+    const finished = (jobId: string) => ({
+      models: {
+        job_tracker: {
+          is_busy: false,
+          result: { job_id: jobId, transcription: { text: 'Hi.', segments: [] } },
+        },
+      },
+    });
+
+    afterEach(() => {
+      delete (window as any).electronAPI;
+      vi.mocked(getConfig).mockResolvedValue(undefined as never);
+    });
+
+    it('spreads pending jobs across the main server and its replicas', async () => {
+      (window as any).electronAPI = { fileIO: { writeText: vi.fn().mockResolvedValue(undefined) } };
+      const settings: Record<string, unknown> = {
+        'server.replicas': 2,
+        'server.runtimeProfile': 'gpu',
+      };
+      vi.mocked(getConfig).mockImplementation((async (key: string) => settings[key]) as never);
+      vi.mocked(apiClient.importAndTranscribe).mockReset();
+      vi.mocked(apiClient.importAndTranscribe).mockResolvedValue({ job_id: 'main-job' } as never);
+      vi.mocked(apiClient.getAdminStatus).mockResolvedValue(finished('main-job') as never);
+      const replica = {
+        healthCheck: vi.fn().mockResolvedValue({ status: 'ok' }),
+        importAndTranscribe: vi.fn().mockResolvedValue({ job_id: 'replica-job' }),
+        getAdminStatus: vi.fn().mockResolvedValue(finished('replica-job')),
+        joinServerQueue: vi.fn().mockResolvedValue({ id: 'queue-1' }),
+        getServerQueue: vi.fn().mockResolvedValue({
+          active: null,
+          entries: [{ id: 'queue-1', position: 0, mine: true }],
+        }),
+        leaveServerQueue: vi.fn().mockResolvedValue({ success: true }),
+        createStagedUpload: vi.fn().mockRejectedValue(new Error('not supported')),
+      };
+      vi.mocked(apiClient.atPort).mockReturnValue(replica as never);
+
+      getState().updateSessionConfig({ outputDir: '/out' });
+      getState().addFiles(
+        [new File(['a'], 'one.m4a'), new File(['b'], 'two.m4a')],
+        'session-normal',
+      );
+      await vi.advanceTimersByTimeAsync(10_000);
+
+      expect(apiClient.atPort).toHaveBeenCalledWith(9787);
+      expect(apiClient.importAndTranscribe).toHaveBeenCalledTimes(1);
+      expect(replica.importAndTranscribe).toHaveBeenCalledTimes(1);
+      expect(getState().jobs.map((j) => j.status)).toEqual(['success', 'success']);
+    });
+  });
+
+  // ── Staged uploads — files sent ahead while the current job transcribes ──
+
+  describe('staged uploads', () => {
+    beforeEach(() => {
+      (window as any).electronAPI = { fileIO: { writeText: vi.fn().mockResolvedValue(undefined) } };
+      vi.mocked(getConfig).mockResolvedValue(undefined as never);
+      vi.mocked(apiClient.createStagedUpload).mockImplementation(async (name, size) => ({
+        upload_id: `staged-${name}`,
+        received: 0,
+        size,
+      }));
+      vi.mocked(apiClient.appendStagedUpload).mockImplementation(async (id, offset, chunk) => ({
+        upload_id: id,
+        received: offset + chunk.size,
+        size: offset + chunk.size,
+      }));
+      vi.mocked(apiClient.importAndTranscribe).mockClear();
+      vi.mocked(apiClient.importAndTranscribe).mockResolvedValue({
+        job_id: 'server-job-1',
+      } as never);
+      vi.mocked(apiClient.getAdminStatus).mockResolvedValue({
+        models: {
+          job_tracker: {
+            is_busy: false,
+            result: { job_id: 'server-job-1', transcription: { text: 'Hi.', segments: [] } },
+          },
+        },
+      } as never);
+    });
+
+    afterEach(() => {
+      delete (window as any).electronAPI;
+      vi.mocked(apiClient.createStagedUpload).mockRejectedValue(new Error('not supported'));
+    });
+
+    it('stages the next files ahead and starts each job with its upload id', async () => {
+      getState().updateSessionConfig({ outputDir: '/out' });
+      getState().addFiles(
+        [new File(['one'], 'a.wav'), new File(['two'], 'b.wav'), new File(['three'], 'c.wav')],
+        'session-normal',
+      );
+      await vi.advanceTimersByTimeAsync(20_000);
+
+      expect(getState().jobs.map((j) => j.status)).toEqual(['success', 'success', 'success']);
+      expect(vi.mocked(apiClient.importAndTranscribe).mock.calls.map(([source]) => source)).toEqual([
+        { upload_id: 'staged-a.wav' },
+        { upload_id: 'staged-b.wav' },
+        { upload_id: 'staged-c.wav' },
+      ]);
+      // The last file was on its way before the first job started.
+      const staged = vi.mocked(apiClient.createStagedUpload).mock.invocationCallOrder;
+      const started = vi.mocked(apiClient.importAndTranscribe).mock.invocationCallOrder;
+      expect(staged[staged.length - 1]).toBeLessThan(started[0]);
+    });
+
+    it('drops the staged file of a removed job', async () => {
+      getState().updateSessionConfig({ outputDir: '/out' });
+      getState().addFiles(
+        [new File(['one'], 'a.wav'), new File(['two'], 'b.wav')],
+        'session-normal',
+      );
+      await vi.advanceTimersByTimeAsync(100);
+
+      getState().removeJob(getState().jobs[1].id);
+      await vi.advanceTimersByTimeAsync(10_000);
+
+      expect(apiClient.discardStagedUpload).toHaveBeenCalledWith('staged-b.wav');
+      expect(getState().jobs).toHaveLength(1);
+    });
+
+    describe('watched files on disk', () => {
+      const app = {
+        statLocalFile: vi.fn(async () => ({ name: 'big.wav', size: 5 })),
+        readLocalFileRange: vi.fn(async () => new TextEncoder().encode('audio').buffer),
+        readLocalFile: vi.fn(async () => ({
+          name: 'big.wav',
+          buffer: new TextEncoder().encode('audio').buffer,
+          mimeType: 'audio/wav',
+        })),
+      };
+
+      beforeEach(() => {
+        vi.clearAllMocks();
+        (window as any).electronAPI.app = app;
+        getState().updateSessionConfig({ outputDir: '/out' });
+      });
+
+      it('reads them chunk by chunk into a staged upload', async () => {
+        getState().addFiles(['/watch/big.wav'], 'session-auto');
+        await vi.advanceTimersByTimeAsync(20_000);
+
+        expect(getState().jobs[0].status).toBe('success');
+        expect(app.readLocalFileRange).toHaveBeenCalledWith('/watch/big.wav', 0, 5);
+        expect(app.readLocalFile).not.toHaveBeenCalled();
+        expect(vi.mocked(apiClient.importAndTranscribe).mock.calls[0][0]).toEqual({
+          upload_id: 'staged-big.wav',
+        });
+      });
+
+      it('uploads only the clip of a time range and removes it afterwards', async () => {
+        const clip = '/tmp/transcriptionsuite-clips/job.flac';
+        const mediaClip = {
+          create: vi.fn(async () => ({ path: clip, startMs: 600_000, endMs: 1_200_000 })),
+          remove: vi.fn().mockResolvedValue(undefined),
+        };
+        (window as any).electronAPI.mediaClip = mediaClip;
+
+        const range = { startMs: 600_000, endMs: 1_200_000 };
+        getState().transcribeLocalEdit('/rec/talk.mp4', { range });
+        await vi.advanceTimersByTimeAsync(20_000);
+
+        const job = getState().jobs[0];
+        expect(job.status).toBe('success');
+        expect(mediaClip.create).toHaveBeenCalledWith('/rec/talk.mp4', range, job.id);
+        expect(app.statLocalFile).toHaveBeenCalledWith(clip);
+        expect(job.outputFilename).toBe('talk_10m00s-20m00s.srt');
+        expect(mediaClip.remove).toHaveBeenCalledWith(clip);
+      });
+
+      it('uploads only the chosen channels and names the output after them', async () => {
+        const mediaClip = {
+          create: vi.fn(async () => ({ path: '/tmp/transcriptionsuite-clips/job.flac' })),
+          remove: vi.fn().mockResolvedValue(undefined),
+        };
+        (window as any).electronAPI.mediaClip = mediaClip;
+
+        getState().transcribeLocalEdit('/rec/panel.wav', { channels: [2] });
+        await vi.advanceTimersByTimeAsync(20_000);
+
+        const job = getState().jobs[0];
+        expect(job.status).toBe('success');
+        expect(mediaClip.create).toHaveBeenCalledWith('/rec/panel.wav', { channels: [2] }, job.id);
+        expect(job.outputFilename).toBe('panel_ch3.srt');
+      });
+
+      it('reads them whole only for servers without staged uploads', async () => {
+        vi.mocked(apiClient.createStagedUpload).mockRejectedValue(
+          new APIError(404, 'Not Found', '/api/transcribe/uploads'),
+        );
+        getState().addFiles(['/watch/big.wav'], 'session-auto');
+        await vi.advanceTimersByTimeAsync(20_000);
+
+        expect(getState().jobs[0].status).toBe('success');
+        expect(app.readLocalFile).toHaveBeenCalledWith('/watch/big.wav');
+        const [source] = vi.mocked(apiClient.importAndTranscribe).mock.calls[0];
+        expect((source as File).name).toBe('big.wav');
+      });
+    });
   });
 
   // ── handleFilesDetected — gh-102 #3 language resolution ────────────────
@@ -699,6 +1149,24 @@ describe('importQueueStore', () => {
       expect(toast.warning).toHaveBeenCalledWith('Folder Watch paused — languages still loading');
     });
 
+    it('queues files held while languages loaded once the cache is ready', () => {
+      getState().setLanguagesCache({ model: null, languages: [], loading: true });
+      getState().handleFilesDetected({
+        type: 'session',
+        files: ['/watch/early.wav'],
+        count: 1,
+        fileMeta: [],
+      });
+      expect(getState().jobs).toHaveLength(0);
+
+      getState().setLanguagesCache({
+        model: 'large-v3',
+        languages: [{ code: 'en', name: 'English' }],
+        loading: false,
+      });
+      expect(getState().jobs).toHaveLength(1);
+    });
+
     it('session: Whisper + Auto Detect → enqueues with language=undefined (auto-detect)', () => {
       getState().updateSessionConfig({ language: 'Auto Detect' });
       getState().setLanguagesCache({
@@ -8,20 +8,42 @@
 
 import { create } from 'zustand';
 import { toast } from 'sonner';
-import { apiClient } from '../api/client';
+import { apiClient, APIError, type APIClient } from '../api/client';
 import type {
   TranscriptionUploadOptions,
   FileImportJobResult,
   JobTrackerResult,
   UploadResponse,
   DedupMatch,
+  ServerQueue,
+  TranscriptionDowngrade,
+  TranscriptionResponse,
+  UploadSource,
 } from '../api/types';
 import {
   resolveTranscriptionOutputs,
+  transcriptionFromSegments,
   type SessionOutputFormat,
 } from '../services/transcriptionFormatters';
+import { runPlugins } from '../services/plugins';
+import { offsetTranscription, rangeFilename, type TimeRange } from '../services/timeRange';
+import { channelFilename } from '../services/channelSelection';
+import { filterTranscription, loadOutputFilter } from '../services/outputFilter';
+import { loadNormalizationEnabled, normalizeTranscription } from '../services/textNormalization';
+import { fireJobHooks } from '../services/scriptHooks';
+import { rememberServerCopy } from '../services/serverCopies';
 import { supportsAutoDetect } from '../services/modelCapabilities';
+import { formatClock } from '../services/jobProgress';
+import { LocalFile } from '../services/localFile';
+import {
+  DEFAULT_PARALLEL_STREAMS,
+  UploadPipeline,
+  isStagingUnsupported,
+  stageUpload,
+  type UploadBody,
+} from '../services/uploadPipeline';
 import { getConfig } from '../config/store';
+import { ensureServerAwake, releaseServer } from '../services/serverIdle';
 import { useDedupChoiceStore } from './dedupChoiceStore';
 import { useAriaAnnouncerStore } from './ariaAnnouncerStore';
 import type { DedupChoice } from '../../components/import/DedupPromptModal';
@@ -39,6 +61,12 @@ export type ImportJobType = 'session-normal' | 'session-auto' | 'notebook-normal
 
 export type UnifiedImportJobStatus = 'pending' | 'processing' | 'writing' | 'success' | 'error';
 
+/** The part of a local recording a session import covers; omitted fields mean all of it. */
+export interface LocalEdit {
+  range?: TimeRange;
+  channels?: number[];
+}
+
 export interface UnifiedImportJob {
   id: string;
   /** Browser File object (manual imports) or native file path string (auto-watch) */
@@ -54,8 +82,27 @@ export interface UnifiedImportJob {
   result?: UploadResponse;
   /** Diarization outcome from the server result (GH-209): shown when requested but not performed. */
   diarizationOutcome?: { requested: boolean; performed: boolean; reason: string | null };
+  /**
+   * Set when the server stopped partway through: the transcript that was
+   * saved or written covers only the first this-many seconds of audio.
+   */
+  partialUntil?: number;
+  /** Set when the server ran out of GPU memory and finished the job with a fallback. */
+  downgrade?: TranscriptionDowngrade;
+  /**
+   * Session jobs for part of a local recording: only this span is cut out
+   * (electron/mediaClip.ts) and uploaded, and the transcript is shifted back
+   * onto the recording's timeline.
+   */
+  range?: TimeRange;
+  /** Session jobs for some channels of a local recording (0-based), downmixed to mono. */
+  channels?: number[];
   /** Display-only: the output format chosen at enqueue time, e.g. ".srt" or ".txt + .srt" (GH-212) */
   plannedFormat?: string;
+  /** Set while the job waits behind other clients for the server's transcription slot */
+  serverQueue?: { position: number; estimatedStartAt: number | null };
+  /** Set while the file is being staged on the server ahead of its job */
+  uploadProgress?: { sent: number; total: number };
   error?: string;
 }
 
@@ -120,6 +167,18 @@ export interface WatcherState {
   avgProcessingMs: number;
 }
 
+/** Files reported by Folder Watch or a paired phone. */
+export interface DetectedFiles {
+  type: 'session' | 'notebook';
+  files: string[];
+  count: number;
+  fileMeta: Array<{ path: string; createdAt: string }>;
+  /** Toast/log label; defaults to "Session Watch" / "Notebook Watch". */
+  label?: string;
+  /** Recording source; defaults to the Notebook watch folder. */
+  source?: string;
+}
+
 // ─── Store interface ─────────────────────────────────────────────────────────
 
 interface ImportQueueState extends WatcherState {
@@ -131,6 +190,10 @@ interface ImportQueueState extends WatcherState {
   /** Languages cache pushed by useLanguages() consumers so handleFilesDetected
    *  can resolve a display name → code without calling a hook (gh-102 #3) */
   languagesCache: LanguagesCacheState;
+  /** Detections that could not be queued yet; retried when the server
+   *  reconnects, the languages load or the source language changes. Watchers
+   *  and phones report a file once, so dropping it would lose it. */
+  heldDetections: DetectedFiles[];
 
   // Actions
   addFiles: (
@@ -146,6 +209,12 @@ interface ImportQueueState extends WatcherState {
     type: ImportJobType,
     options?: TranscriptionUploadOptions,
   ) => void;
+  /** Transcribe a time range and/or some channels of the local recording at `path`. */
+  transcribeLocalEdit: (
+    path: string,
+    edit: LocalEdit,
+    options?: TranscriptionUploadOptions,
+  ) => void;
   pauseQueue: () => void;
   resumeQueue: () => void;
   removeJob: (id: string) => void;
@@ -164,12 +233,9 @@ interface ImportQueueState extends WatcherState {
   setSessionWatchActive: (active: boolean) => void;
   setNotebookWatchPath: (path: string) => void;
   setNotebookWatchActive: (active: boolean) => void;
-  handleFilesDetected: (payload: {
-    type: 'session' | 'notebook';
-    files: string[];
-    count: number;
-    fileMeta: Array<{ path: string; createdAt: string }>;
-  }) => void;
+  handleFilesDetected: (payload: DetectedFiles) => void;
+  /** Queue detections held back while offline or before languages loaded. */
+  retryHeldDetections: () => void;
   // 4.2 — server connectivity
   setWatcherServerConnected: (connected: boolean) => void;
   // 4.3 — activity log
@@ -205,8 +271,20 @@ export const selectNotebookJobs = (s: ImportQueueState) =>
 
 let _processing = false;
 let _abort = false;
+/** The servers taking jobs right now — the main one plus any replicas. */
+let _servers: APIClient[] = [];
 /** Per-job processing start timestamps — used for time estimates (4.5) */
 const _jobStartedAt: Record<string, number> = {};
+/** Stages upcoming jobs' files while the current one transcribes. */
+function setUploadProgress(key: string, progress: { sent: number; total: number } | undefined) {
+  useImportQueueStore.setState((s) => ({
+    jobs: s.jobs.map((j) => (j.id === key ? { ...j, uploadProgress: progress } : j)),
+  }));
+}
+
+const _uploads = new UploadPipeline({
+  onProgress: (key, sent, total) => setUploadProgress(key, { sent, total }),
+});
 let _jobIdCounter = 0;
 function nextJobId(type: ImportJobType): string {
   const prefix = type.startsWith('session') ? 'session' : 'notebook';
@@ -233,6 +311,103 @@ function filenameFromPath(filePath: string): string {
   return parts[parts.length - 1] || filePath;
 }
 
+/** Clips cut for range and channel jobs, by job id — deleted once the job is done. */
+const _clips = new Map<string, string>();
+
+/**
+ * The job's file. Auto-watch jobs are native paths, opened for chunked reads
+ * through Electron IPC rather than loaded whole.
+ */
+async function openJobFile(job: UnifiedImportJob): Promise<UploadBody> {
+  if (typeof job.file !== 'string') return job.file;
+  if (job.range || job.channels) {
+    const mediaClip = (window as any).electronAPI?.mediaClip;
+    if (!mediaClip) throw new Error('Transcribing part of a recording needs the desktop app');
+    const clip = await mediaClip.create(
+      job.file,
+      { ...job.range, ...(job.channels ? { channels: job.channels } : {}) },
+      job.id,
+    );
+    _clips.set(job.id, clip.path);
+    return LocalFile.open(clip.path);
+  }
+  return LocalFile.open(job.file);
+}
+
+/** Keep a detection for retryHeldDetections, without holding a path twice. */
+function holdDetection(payload: DetectedFiles): void {
+  useImportQueueStore.setState((s) => {
+    const held = new Set(s.heldDetections.flatMap((p) => p.files));
+    const files = payload.files.filter((f) => !held.has(f));
+    if (files.length === 0) return {};
+    const fileMeta = payload.fileMeta.filter((m) => files.includes(m.path));
+    return {
+      heldDetections: [...s.heldDetections, { ...payload, files, count: files.length, fileMeta }],
+    };
+  });
+}
+
+function removeJobClip(jobId: string): void {
+  const clipPath = _clips.get(jobId);
+  if (!clipPath) return;
+  _clips.delete(jobId);
+  void (window as any).electronAPI?.mediaClip?.remove(clipPath).catch(() => {});
+}
+
+/**
+ * Send a file on disk through staged uploads, one chunk in memory at a time.
+ * Only servers without staged uploads get it read whole into a File.
+ */
+async function sendLocalFile(
+  job: UnifiedImportJob,
+  file: LocalFile,
+  api: APIClient,
+): Promise<UploadSource> {
+  if (file.size > 0) {
+    try {
+      return await stageUpload(file, {
+        api,
+        budget: _uploads.budget,
+        onProgress: (sent, total) => setUploadProgress(job.id, { sent, total }),
+      });
+    } catch (err) {
+      if (!isStagingUnsupported(err)) throw err;
+    } finally {
+      setUploadProgress(job.id, undefined);
+    }
+  }
+  return file.readAll();
+}
+
+/**
+ * What to send when the job starts: its staged upload if the pipeline has
+ * one, otherwise `direct` (the file, opened up front when nothing was staged).
+ */
+async function uploadSource(
+  job: UnifiedImportJob,
+  store: typeof useImportQueueStore,
+  direct: UploadBody | null,
+  api: APIClient,
+): Promise<UploadSource> {
+  let file = direct;
+  if (!file) {
+    const staged = await _uploads.take(job.id);
+    setUploadProgress(job.id, undefined);
+    // The stream this job used is free for the next file in line.
+    stageUpcoming(store);
+    if (staged) return staged;
+    file = await openJobFile(job);
+  }
+  return file instanceof LocalFile ? sendLocalFile(job, file, api) : (file as File);
+}
+
+/** Start staging `current` and the pending jobs after it, as many as the stream limit allows. */
+function stageUpcoming(store: typeof useImportQueueStore, current?: UnifiedImportJob): void {
+  const pending = store.getState().jobs.filter((j) => j.status === 'pending');
+  const upcoming = current ? [current, ...pending] : pending;
+  _uploads.schedule(upcoming.map((job) => ({ key: job.id, load: () => openJobFile(job) })));
+}
+
 /** Human-readable planned-format label stamped on queued session jobs (GH-212). */
 export function describePlannedFormat(
   cfg: Pick<SessionConfig, 'outputFormat' | 'diarizedFormat'>,
@@ -268,11 +443,12 @@ export type DuplicatePolicy = 'ask' | 'create_new';
 const DEFAULT_DUPLICATE_POLICY: DuplicatePolicy = 'create_new';
 
 /**
- * Decide how to resolve a server-detected duplicate for a session import.
+ * Decide how to resolve a server-detected duplicate for an import.
  *
- * Manual imports (`session-normal`) always prompt the user via the interactive
- * DedupPromptModal. Folder Watch imports (`session-auto`) follow the configured
- * `folderWatch.duplicatePolicy` so a batch runs unattended (GH-120). Only the
+ * Manual imports (`session-normal`, `notebook-normal`) always prompt the user
+ * via the interactive DedupPromptModal. Folder Watch imports (`session-auto`,
+ * `notebook-auto`) follow the configured `folderWatch.duplicatePolicy` so a
+ * batch runs unattended (GH-120). Only the
  * explicit `'ask'` policy falls back to the modal; every other case — the
  * `'create_new'` default, an unknown/legacy stored value (e.g. a removed
  * `'skip'`), or an IPC read failure — creates a new entry, so a corrupt config
@@ -282,7 +458,7 @@ export async function resolveDuplicateChoice(
   jobType: ImportJobType,
   matches: DedupMatch[],
 ): Promise<DedupChoice> {
-  if (jobType === 'session-auto') {
+  if (jobType === 'session-auto' || jobType === 'notebook-auto') {
     const policy =
       (await getConfig<DuplicatePolicy>('folderWatch.duplicatePolicy').catch(() => undefined)) ??
       DEFAULT_DUPLICATE_POLICY;
@@ -292,18 +468,39 @@ export async function resolveDuplicateChoice(
   return useDedupChoiceStore.getState().requestChoice(matches);
 }
 
+/**
+ * The transcript of a notebook recording the upload duplicates. A
+ * fingerprint match (re-encoded or trimmed copy) is re-timed by the server
+ * so it lines up with this file rather than the original.
+ */
+async function existingTranscription(
+  match: DedupMatch,
+  api: APIClient,
+): Promise<TranscriptionResponse> {
+  const { segments } = await api.getRecordingTranscription(
+    Number(match.recording_id),
+    match.match === 'fingerprint'
+      ? { offsetSeconds: match.offset_seconds ?? 0, durationSeconds: match.duration_seconds }
+      : undefined,
+  );
+  return transcriptionFromSegments(segments);
+}
+
 const POLL_INTERVAL_MS = 5_000;
 const MAX_POLLS = (24 * 60 * 60 * 1000) / POLL_INTERVAL_MS; // 24 hours
 
 // ─── Polling ─────────────────────────────────────────────────────────────────
 
-async function pollForSessionResult(serverJobId: string): Promise<FileImportJobResult> {
+async function pollForSessionResult(
+  serverJobId: string,
+  api: APIClient,
+): Promise<FileImportJobResult> {
   for (let i = 0; i < MAX_POLLS; i++) {
     if (_abort) throw new Error('Import queue aborted');
     await new Promise((r) => setTimeout(r, POLL_INTERVAL_MS));
 
     try {
-      const status = await apiClient.getAdminStatus();
+      const status = await api.getAdminStatus();
       const jobTracker = (status?.models as any)?.job_tracker;
 
       if (jobTracker?.is_busy && jobTracker?.active_job_id === serverJobId) continue;
@@ -323,13 +520,16 @@ async function pollForSessionResult(serverJobId: string): Promise<FileImportJobR
   throw new Error('Transcription timed out after 24 hours');
 }
 
-async function pollForNotebookResult(serverJobId: string): Promise<JobTrackerResult> {
+async function pollForNotebookResult(
+  serverJobId: string,
+  api: APIClient,
+): Promise<JobTrackerResult> {
   for (let i = 0; i < MAX_POLLS; i++) {
     if (_abort) throw new Error('Import queue aborted');
     await new Promise((r) => setTimeout(r, POLL_INTERVAL_MS));
 
     try {
-      const status = await apiClient.getAdminStatus();
+      const status = await api.getAdminStatus();
       const jobTracker = (status?.models as any)?.job_tracker;
 
       if (jobTracker?.is_busy && jobTracker?.active_job_id === serverJobId) continue;
@@ -349,31 +549,104 @@ async function pollForNotebookResult(serverJobId: string): Promise<JobTrackerRes
   throw new Error('Transcription timed out after 24 hours');
 }
 
+// ─── Server queue ────────────────────────────────────────────────────────────
+
+const SERVER_QUEUE_POLL_MS = 3_000;
+
+/**
+ * Wait for our turn at the server's transcription slot, then run `start` with
+ * our queue entry. On a server shared by several people this keeps the job's
+ * place in line instead of failing with 409 while someone else transcribes.
+ * Servers without a queue (404 on join) run `start` straight away.
+ */
+async function withServerTurn<T>(
+  job: UnifiedImportJob,
+  store: typeof useImportQueueStore,
+  api: APIClient,
+  start: (queueEntryId: string | undefined) => Promise<T>,
+): Promise<T> {
+  const label = typeof job.file === 'string' ? filenameFromPath(job.file) : job.file.name;
+  const setWaiting = (serverQueue: UnifiedImportJob['serverQueue']) =>
+    store.setState((s) => ({
+      jobs: s.jobs.map((j) => (j.id === job.id ? { ...j, serverQueue } : j)),
+    }));
+
+  let entryId: string | undefined;
+  try {
+    entryId = (await api.joinServerQueue(label)).id;
+  } catch (err) {
+    if (!(err instanceof APIError && err.status === 404)) throw err;
+  }
+
+  try {
+    while (entryId) {
+      if (_abort) throw new Error('Import queue aborted');
+      let queue: ServerQueue;
+      try {
+        queue = await api.getServerQueue();
+      } catch (err) {
+        console.warn('Server queue poll error (will retry):', err);
+        await new Promise((r) => setTimeout(r, SERVER_QUEUE_POLL_MS));
+        continue;
+      }
+      const mine = queue.entries.find((e) => e.id === entryId);
+      if (!mine) {
+        // The entry expired (e.g. the machine slept) — take a new place at the back.
+        entryId = (await api.joinServerQueue(label)).id;
+        continue;
+      }
+      if (mine.position === 0 && !queue.active) break;
+      setWaiting({ position: mine.position, estimatedStartAt: mine.estimated_start_at });
+      await new Promise((r) => setTimeout(r, SERVER_QUEUE_POLL_MS));
+    }
+    setWaiting(undefined);
+    // Time estimates (4.5) measure processing, not the wait in line.
+    _jobStartedAt[job.id] = Date.now();
+    return await start(entryId);
+  } catch (err) {
+    setWaiting(undefined);
+    // A start that failed before the server took the job would leave our
+    // entry at the head, holding everyone up until it expires.
+    if (entryId) void api.leaveServerQueue(entryId).catch(() => {});
+    throw err;
+  }
+}
+
 // ─── Processing ──────────────────────────────────────────────────────────────
 
 async function processSessionJob(
   job: UnifiedImportJob,
   store: typeof useImportQueueStore,
+  api: APIClient,
 ): Promise<void> {
-  const file = job.file;
-  const isPath = typeof file === 'string';
-  const filename = isPath ? filenameFromPath(file) : file.name;
-
-  // For auto-watch jobs (file paths), read the file via Electron IPC
-  let fileObj: File;
-  if (isPath) {
-    const electronAPI = (window as any).electronAPI;
-    if (!electronAPI?.app?.readLocalFile) {
-      throw new Error('Auto-watch requires Electron — cannot read local file in browser');
-    }
-    const { buffer } = await electronAPI.app.readLocalFile(file as string);
-    fileObj = new File([buffer], filename);
-  } else {
-    fileObj = file;
+  try {
+    await runSessionJob(job, store, api);
+  } finally {
+    removeJobClip(job.id);
   }
+}
 
-  const importResponse = await apiClient.importAndTranscribe(fileObj, job.options);
-  const { job_id: serverJobId } = importResponse;
+async function runSessionJob(
+  job: UnifiedImportJob,
+  store: typeof useImportQueueStore,
+  api: APIClient,
+): Promise<void> {
+  const file = job.file;
+  const name = typeof file === 'string' ? filenameFromPath(file) : file.name;
+  const ranged = job.range ? rangeFilename(name, job.range) : name;
+  const filename = job.channels ? channelFilename(ranged, job.channels) : ranged;
+  const direct = _uploads.has(job.id) ? null : await openJobFile(job);
+  // Read at run time like the output format, so Folder Watch jobs follow it too.
+  const keepMedia = (await getConfig<boolean>('sessionImport.keepServerCopy')) ?? false;
+
+  const importResponse = await withServerTurn(job, store, api, async (queue_entry_id) =>
+    api.importAndTranscribe(await uploadSource(job, store, direct, api), {
+      ...job.options,
+      queue_entry_id,
+      keep_media: keepMedia || undefined,
+    }),
+  );
+  const { job_id: serverJobId, kept_media: keptMedia } = importResponse;
 
   // Issue #104, Story 2.4 + Sprint 2 Item 4 — full DedupPromptModal flow.
   // When the server reports prior matches, resolve the duplicate. Manual
@@ -389,10 +662,26 @@ async function processSessionJob(
       // server-side job and skip this queue entry. The cancel API is
       // best-effort — if the job already completed it's a harmless no-op.
       try {
-        await apiClient.cancelTranscription();
+        await api.cancelTranscription();
       } catch {
         // Swallow: skip-the-local-entry is the user-visible contract.
       }
+      // Nothing was imported, so there is no copy worth keeping either.
+      if (keptMedia) void api.deleteJobMedia(keptMedia.job_id).catch(() => {});
+      // A matching notebook recording has a transcript to write out in
+      // place of this one; a bare transcription_job match does not.
+      const recording = importResponse.dedup_matches.find((m) => m.source === 'recording');
+      if (choice === 'use_existing' && recording) {
+        const transcription = await existingTranscription(recording, api).catch(() => null);
+        if (transcription) {
+          await writeSessionOutputs(job, store, filename, transcription);
+          useAriaAnnouncerStore
+            .getState()
+            .announce(`Reused the transcript of ${recording.name}`, 'polite');
+          attachSessionTranscript(job, transcription.text);
+          return;
+        }
+      }
       useAriaAnnouncerStore.getState().announce(`Duplicate skipped: ${first.name}`, 'polite');
       // Mark this queue entry as success-with-no-output so the user sees
       // the queue advance rather than freeze on a "processing" state.
@@ -408,11 +697,39 @@ async function processSessionJob(
     toast.warning(`Duplicate of '${first.name}' detected — creating a new entry.`);
   }
 
-  const result = await pollForSessionResult(serverJobId);
+  // Remembered before the result arrives: the copy is kept even if this
+  // transcription fails, and can be downloaded from the Import tab.
+  if (keptMedia) await rememberServerCopy(filename, keptMedia).catch(() => {});
+
+  const result = await pollForSessionResult(serverJobId, api);
 
   if (result.error) throw new Error(result.error);
   if (!result.transcription) throw new Error('Server returned no transcription data');
 
+  await writeSessionOutputs(job, store, filename, result.transcription, result.diarization);
+
+  // Session completions have the transcript text in scope - attach it so the
+  // notification record carries a collapsible transcript (GH-202-safe: text is
+  // already local, no fetch needed). The dedup skip branch above returns
+  // early with no output and deliberately gets no transcript.
+  attachSessionTranscript(job, result.transcription.text);
+}
+
+/** The job finished, but not the way it was asked to — say how. */
+function notifyDowngrade(filename: string, downgrade: TranscriptionDowngrade): void {
+  toast.warning(`${filename}: ${downgrade.summary}`, {
+    description: 'Large files on this GPU may need a smaller model or shorter chunks.',
+  });
+}
+
+/** Write a session job's transcript files and mark the job done. */
+async function writeSessionOutputs(
+  job: UnifiedImportJob,
+  store: typeof useImportQueueStore,
+  filename: string,
+  result: TranscriptionResponse,
+  diarization?: FileImportJobResult['diarization'],
+): Promise<void> {
   // Read the format from the authoritative config store at WRITE time, not
   // from a value captured at enqueue time, so mid-queue setting changes are
   // respected (mirrors the Issue #67 convention it replaces). The
@@ -422,10 +739,27 @@ async function processSessionJob(
   const hideTimestamps = (await getConfig<boolean>('output.hideTimestamps')) ?? false;
   const outputFormat: SessionOutputFormat = storedFormat ?? (hideTimestamps ? 'txt' : 'subtitles');
   const { sessionConfig } = store.getState();
-  const outputs = resolveTranscriptionOutputs(filename, result.transcription, {
+  const timed = job.range ? offsetTranscription(result, job.range.startMs / 1000) : result;
+  const written = (await loadNormalizationEnabled()) ? normalizeTranscription(timed) : timed;
+  // Before the plugins, so exporter files are filtered like the built-in ones.
+  const transcription = filterTranscription(written, await loadOutputFilter());
+  // Enabled WebAssembly plugins: post-processors rewrite the transcript,
+  // exporters add files next to the built-in ones (services/plugins.ts).
+  const plugged = await runPlugins(filename.replace(/\.[^.]+$/, ''), transcription);
+  const outputs = resolveTranscriptionOutputs(filename, plugged.transcription, {
     outputFormat,
     subtitleFormat: sessionConfig.diarizedFormat ?? 'srt',
   });
+  // Ahead of the built-in files, so outputPath stays the app's own transcript.
+  const extra = plugged.outputs.filter((out) => {
+    if (!outputs.some((o) => o.outputFilename === out.outputFilename)) return true;
+    plugged.errors.push(`${out.outputFilename} is already written by the app`);
+    return false;
+  });
+  outputs.unshift(...extra);
+  if (plugged.errors.length > 0) {
+    toast.warning(`Plugins failed for ${filename}: ${plugged.errors.join('; ')}`);
+  }
 
   // Update status to 'writing'
   store.setState((s) => ({
@@ -448,6 +782,17 @@ async function processSessionJob(
   }
 
   const outputFilename = outputs.map((o) => o.outputFilename).join(', ');
+  // Whatever the server finished is kept rather than failing the job.
+  const partialUntil = transcription.partial
+    ? (transcription.completed_seconds ?? transcription.segments.at(-1)?.end ?? 0)
+    : undefined;
+  if (partialUntil !== undefined) {
+    toast.warning(
+      `${filename} stopped at ${formatClock(partialUntil)} — the finished part was saved`,
+    );
+  }
+  const downgrade = transcription.downgrade ?? undefined;
+  if (downgrade) notifyDowngrade(filename, downgrade);
 
   store.setState((s) => ({
     jobs: s.jobs.map((j) =>
@@ -457,49 +802,72 @@ async function processSessionJob(
             status: 'success' as const,
             outputPath,
             outputFilename,
-            ...(result.diarization ? { diarizationOutcome: result.diarization } : {}),
+            partialUntil,
+            downgrade,
+            ...(diarization ? { diarizationOutcome: diarization } : {}),
           }
         : j,
     ),
   }));
-
-  // Session completions have the transcript text in scope - attach it so the
-  // notification record carries a collapsible transcript (GH-202-safe: text is
-  // already local, no fetch needed). The dedup use_existing branch above
-  // returns early with no output and deliberately gets no transcript.
-  attachSessionTranscript(job, result.transcription.text);
 }
 
 async function processNotebookJob(
   job: UnifiedImportJob,
   store: typeof useImportQueueStore,
+  api: APIClient,
 ): Promise<void> {
-  const file = job.file;
-  const isPath = typeof file === 'string';
-
-  let fileObj: File;
-  if (isPath) {
-    const electronAPI = (window as any).electronAPI;
-    if (!electronAPI?.app?.readLocalFile) {
-      throw new Error('Auto-watch requires Electron — cannot read local file in browser');
+  const direct = _uploads.has(job.id) ? null : await openJobFile(job);
+
+  const accepted = await withServerTurn(job, store, api, async (queue_entry_id) =>
+    api.uploadAndTranscribe(await uploadSource(job, store, direct, api), {
+      ...job.options,
+      queue_entry_id,
+    }),
+  );
+
+  // The server recognised a re-encoded or trimmed copy of a notebook
+  // recording: "Use existing" keeps that recording instead of adding another.
+  const match = accepted.dedup_matches?.[0];
+  const choice = match
+    ? await resolveDuplicateChoice(job.type, accepted.dedup_matches!)
+    : 'create_new';
+  if (match && choice !== 'create_new') {
+    try {
+      await api.cancelTranscription();
+    } catch {
+      // Best-effort, as for session imports.
+    }
+    if (choice === 'cancel') {
+      store.setState((s) => ({
+        jobs: s.jobs.map((j) => (j.id === job.id ? { ...j, status: 'success' as const } : j)),
+      }));
+      return;
     }
-    const filename = filenameFromPath(file);
-    const { buffer } = await electronAPI.app.readLocalFile(file as string);
-    fileObj = new File([buffer], filename);
-  } else {
-    fileObj = file;
   }
 
-  const { job_id: serverJobId } = await apiClient.uploadAndTranscribe(fileObj, job.options);
-  const result = await pollForNotebookResult(serverJobId);
-
-  if (result.error) throw new Error(result.error);
-
-  const uploadResult: UploadResponse = {
-    recording_id: result.recording_id!,
-    message: result.message ?? 'Transcription complete',
-    diarization: result.diarization ?? { requested: false, performed: false, reason: null },
-  };
+  let uploadResult: UploadResponse;
+  if (match && choice === 'use_existing') {
+    uploadResult = {
+      recording_id: Number(match.recording_id),
+      message: `Same audio as '${match.name}' — kept the existing recording`,
+      diarization: { requested: false, performed: false, reason: null },
+    };
+  } else {
+    if (match) toast.warning(`Duplicate of '${match.name}' detected — creating a new entry.`);
+    const result = await pollForNotebookResult(accepted.job_id, api);
+    if (result.error) throw new Error(result.error);
+    uploadResult = {
+      recording_id: result.recording_id!,
+      message: result.message ?? 'Transcription complete',
+      diarization: result.diarization ?? { requested: false, performed: false, reason: null },
+      partial_until: result.partial ? (result.partial_until ?? 0) : null,
+      downgrade: result.downgrade ?? null,
+    };
+    if (result.downgrade) {
+      const name = typeof job.file === 'string' ? filenameFromPath(job.file) : job.file.name;
+      notifyDowngrade(name, result.downgrade);
+    }
+  }
 
   store.setState((s) => ({
     jobs: s.jobs.map((j) =>
@@ -508,7 +876,9 @@ async function processNotebookJob(
             ...j,
             status: 'success' as const,
             result: uploadResult,
-            diarizationOutcome: result.diarization ?? undefined,
+            diarizationOutcome: uploadResult.diarization,
+            partialUntil: uploadResult.partial_until ?? undefined,
+            downgrade: uploadResult.downgrade ?? undefined,
           }
         : j,
     ),
@@ -518,6 +888,34 @@ async function processNotebookJob(
   notebookCallbacks.onJobSuccess?.(job, uploadResult);
 }
 
+/**
+ * Clients for the server replicas running next to the local server
+ * (`server.replicas`, on the ports after the main one). Jobs are spread
+ * across them; a replica that does not answer is left out.
+ */
+async function replicaServers(): Promise<APIClient[]> {
+  try {
+    const count = (await getConfig<number>('server.replicas')) ?? 1;
+    if (count <= 1 || (await getConfig<boolean>('connection.useRemote'))) return [];
+    if ((await getConfig<string>('server.runtimeProfile')) !== 'gpu') return [];
+    const port = Number(new URL(apiClient.getBaseUrl()).port);
+    const replicas = Array.from({ length: Math.max(0, count - 1) }, (_, i) =>
+      apiClient.atPort(port + i + 1),
+    );
+    const up = await Promise.all(
+      replicas.map((api) =>
+        api
+          .healthCheck()
+          .then(() => true)
+          .catch(() => false),
+      ),
+    );
+    return replicas.filter((_, i) => up[i]);
+  } catch {
+    return [];
+  }
+}
+
 async function processQueue(): Promise<void> {
   if (_processing) return;
   _processing = true;
@@ -526,72 +924,111 @@ async function processQueue(): Promise<void> {
   const store = useImportQueueStore;
 
   try {
-    while (!_abort) {
-      const { jobs, isPaused } = store.getState();
-      if (isPaused) break;
+    try {
+      const streams = await getConfig<number>('upload.parallelStreams');
+      const mbps = await getConfig<number>('upload.bandwidthLimitMbps');
+      _uploads.configure(streams ?? DEFAULT_PARALLEL_STREAMS, mbps ?? 0);
+    } catch {
+      _uploads.configure(DEFAULT_PARALLEL_STREAMS, 0);
+    }
+
+    _servers = [apiClient, ...(await replicaServers())];
+    // Each server works through the shared queue. Files are only staged
+    // ahead for a single server: with replicas, the server a job lands on
+    // is not known until it starts.
+    const prestage = _servers.length === 1;
+    await Promise.all(_servers.map((api) => runJobs(store, api, prestage)));
+    // Drained: a remote server woken for these jobs may go back to sleep.
+    if (!_abort && !store.getState().jobs.some((j) => j.status === 'pending')) {
+      void releaseServer();
+    }
+  } finally {
+    if (_abort) _uploads.cancelAll();
+    _servers = [];
+    _processing = false;
+  }
+}
+
+/** Take pending jobs one at a time and run them on `api` until none are left. */
+async function runJobs(
+  store: typeof useImportQueueStore,
+  api: APIClient,
+  prestage: boolean,
+): Promise<void> {
+  while (!_abort) {
+    if (store.getState().isPaused) break;
+    // A container stopped for idleness comes back first; the jobs stay
+    // pending when it does not (the wake-up toast says why).
+    try {
+      await ensureServerAwake();
+    } catch {
+      break;
+    }
 
-      const nextJob = jobs.find((j) => j.status === 'pending');
-      if (!nextJob) break;
+    // Picked and marked in one go, so two servers never take the same job.
+    const { jobs, isPaused } = store.getState();
+    if (isPaused) break;
+    const nextJob = jobs.find((j) => j.status === 'pending');
+    if (!nextJob) break;
 
-      const jobId = nextJob.id;
-      const isSession = nextJob.type === 'session-normal' || nextJob.type === 'session-auto';
+    const jobId = nextJob.id;
+    const isSession = nextJob.type === 'session-normal' || nextJob.type === 'session-auto';
 
-      // Mark processing and record start time for time estimates (4.5)
-      _jobStartedAt[jobId] = Date.now();
+    // Mark processing and record start time for time estimates (4.5)
+    _jobStartedAt[jobId] = Date.now();
+    store.setState((s) => ({
+      jobs: s.jobs.map((j) =>
+        j.id === jobId ? { ...j, status: 'processing' as const, error: undefined } : j,
+      ),
+    }));
+    notifyJobProcessing(nextJob);
+    if (prestage) stageUpcoming(store, nextJob);
+
+    try {
+      if (isSession) {
+        await processSessionJob(nextJob, store, api);
+      } else {
+        await processNotebookJob(nextJob, store, api);
+      }
+
+      // Update exponential moving average on success (4.5)
+      const startedAt = _jobStartedAt[jobId];
+      if (startedAt) {
+        const duration = Date.now() - startedAt;
+        const prev = store.getState().avgProcessingMs;
+        const next = prev === 0 ? duration : Math.round(prev * 0.7 + duration * 0.3);
+        store.setState({ avgProcessingMs: next });
+      }
+
+      // Re-read the job so processor-set fields (session outputFilename,
+      // notebook result) are visible to the success notification.
+      const finishedJob = store.getState().jobs.find((j) => j.id === jobId);
+      notifyJobSuccess(finishedJob ?? nextJob);
+      fireJobHooks(finishedJob ?? nextJob);
+      if (finishedJob && !isSession && finishedJob.result?.recording_id !== undefined) {
+        attachNotebookTranscript(finishedJob, finishedJob.result.recording_id);
+      }
+    } catch (err) {
+      const errorMsg = err instanceof Error ? err.message : 'Import failed';
       store.setState((s) => ({
         jobs: s.jobs.map((j) =>
-          j.id === jobId ? { ...j, status: 'processing' as const, error: undefined } : j,
+          j.id === jobId ? { ...j, status: 'error' as const, error: errorMsg } : j,
         ),
       }));
-      notifyJobProcessing(nextJob);
 
-      try {
-        if (isSession) {
-          await processSessionJob(nextJob, store);
-        } else {
-          await processNotebookJob(nextJob, store);
-        }
-
-        // Update exponential moving average on success (4.5)
-        const startedAt = _jobStartedAt[jobId];
-        if (startedAt) {
-          const duration = Date.now() - startedAt;
-          const prev = store.getState().avgProcessingMs;
-          const next = prev === 0 ? duration : Math.round(prev * 0.7 + duration * 0.3);
-          store.setState({ avgProcessingMs: next });
-        }
-
-        // Re-read the job so processor-set fields (session outputFilename,
-        // notebook result) are visible to the success notification.
-        const finishedJob = store.getState().jobs.find((j) => j.id === jobId);
-        notifyJobSuccess(finishedJob ?? nextJob);
-        if (finishedJob && !isSession && finishedJob.result?.recording_id !== undefined) {
-          attachNotebookTranscript(finishedJob, finishedJob.result.recording_id);
-        }
-      } catch (err) {
-        const errorMsg = err instanceof Error ? err.message : 'Import failed';
-        store.setState((s) => ({
-          jobs: s.jobs.map((j) =>
-            j.id === jobId ? { ...j, status: 'error' as const, error: errorMsg } : j,
-          ),
-        }));
-
-        if (!isSession) {
-          const { notebookCallbacks } = store.getState();
-          notebookCallbacks.onJobError?.(nextJob, errorMsg);
-        }
-        notifyJobError(nextJob, errorMsg);
-      } finally {
-        delete _jobStartedAt[jobId];
+      if (!isSession) {
+        const { notebookCallbacks } = store.getState();
+        notebookCallbacks.onJobError?.(nextJob, errorMsg);
       }
+      notifyJobError(nextJob, errorMsg);
+    } finally {
+      delete _jobStartedAt[jobId];
+    }
 
-      if (_abort) break;
+    if (_abort) break;
 
-      // Small delay between jobs to let the server breathe
-      await new Promise((r) => setTimeout(r, 500));
-    }
-  } finally {
-    _processing = false;
+    // Small delay between jobs to let the server breathe
+    await new Promise((r) => setTimeout(r, 500));
   }
 }
 
@@ -635,6 +1072,7 @@ export const useImportQueueStore = create<ImportQueueState>()((set) => ({
     languages: [],
     loading: true,
   },
+  heldDetections: [],
 
   // ─── Queue Actions ───────────────────────────────────────────────────────
 
@@ -659,6 +1097,25 @@ export const useImportQueueStore = create<ImportQueueState>()((set) => ({
     setTimeout(() => processQueue(), 0);
   },
 
+  transcribeLocalEdit: (path, { range, channels }, options) => {
+    set((s) => ({
+      jobs: [
+        ...s.jobs,
+        {
+          id: nextJobId('session-normal'),
+          file: path,
+          type: 'session-normal' as const,
+          options: options ? { ...options } : undefined,
+          status: 'pending' as const,
+          ...(range ? { range: { ...range } } : {}),
+          ...(channels?.length ? { channels: [...channels] } : {}),
+          plannedFormat: describePlannedFormat(s.sessionConfig),
+        },
+      ],
+    }));
+    setTimeout(() => processQueue(), 0);
+  },
+
   addPriorityFiles: (files, type, options) => {
     const capturedOptions = options ? { ...options } : undefined;
     const newJobs: UnifiedImportJob[] = files.map((file) => ({
@@ -678,8 +1135,10 @@ export const useImportQueueStore = create<ImportQueueState>()((set) => ({
 
   pauseQueue: () => {
     set({ isPaused: true });
-    // Best-effort cancel the active server job
-    apiClient.cancelTranscription().catch(() => {});
+    // Best-effort cancel the active server jobs
+    for (const api of _servers.length > 0 ? _servers : [apiClient]) {
+      api.cancelTranscription().catch(() => {});
+    }
   },
 
   resumeQueue: () => {
@@ -688,6 +1147,11 @@ export const useImportQueueStore = create<ImportQueueState>()((set) => ({
   },
 
   removeJob: (id) => {
+    const job = useImportQueueStore.getState().jobs.find((j) => j.id === id);
+    if (job && job.status !== 'processing' && job.status !== 'writing') {
+      _uploads.cancel(id);
+      removeJobClip(id);
+    }
     set((s) => ({
       jobs: s.jobs.filter(
         (j) => j.id !== id || j.status === 'processing' || j.status === 'writing',
@@ -716,15 +1180,19 @@ export const useImportQueueStore = create<ImportQueueState>()((set) => ({
 
   clearAll: () => {
     _abort = true;
+    _uploads.cancelAll();
+    for (const id of [..._clips.keys()]) removeJobClip(id);
     set({ jobs: [] });
   },
 
   updateSessionConfig: (patch) => {
     set((s) => ({ sessionConfig: { ...s.sessionConfig, ...patch } }));
+    if ('language' in patch) useImportQueueStore.getState().retryHeldDetections();
   },
 
   updateNotebookConfig: (patch) => {
     set((s) => ({ notebookConfig: { ...s.notebookConfig, ...patch } }));
+    if ('language' in patch) useImportQueueStore.getState().retryHeldDetections();
   },
 
   updateNotebookCallbacks: (callbacks) => {
@@ -733,6 +1201,9 @@ export const useImportQueueStore = create<ImportQueueState>()((set) => ({
 
   setLanguagesCache: (cache) => {
     set({ languagesCache: cache });
+    if (!cache.loading && cache.model !== null) {
+      useImportQueueStore.getState().retryHeldDetections();
+    }
   },
 
   // ─── Watcher Actions ──────────────────────────────────────────────────────
@@ -758,15 +1229,16 @@ export const useImportQueueStore = create<ImportQueueState>()((set) => ({
     if (files.length === 0) return;
 
     const { watcherServerConnected } = useImportQueueStore.getState();
-    const label = type === 'session' ? 'Session Watch' : 'Notebook Watch';
+    const label = payload.label ?? (type === 'session' ? 'Session Watch' : 'Notebook Watch');
 
-    // 4.2 — pause file discovery when server is unreachable
+    // 4.2 — hold file discovery while the server is unreachable
     if (!watcherServerConnected) {
+      holdDetection(payload);
       toast.warning(
-        `${files.length} file${files.length === 1 ? '' : 's'} detected from ${label} but server is offline — files skipped`,
+        `${files.length} file${files.length === 1 ? '' : 's'} detected from ${label} but server is offline — queued when it reconnects`,
       );
       useImportQueueStore.getState().appendWatchLog({
-        message: `${files.length} file(s) detected but server offline — skipped`,
+        message: `${files.length} file(s) detected but server offline — held until it reconnects`,
         level: 'warn',
       });
       return;
@@ -793,6 +1265,7 @@ export const useImportQueueStore = create<ImportQueueState>()((set) => ({
     // fall through to the explicit-required guard below where Canary still
     // pauses (correct behavior) and Whisper proceeds with auto-detect.
     if (cache.loading || cache.model === null) {
+      holdDetection(payload);
       const msg = 'Folder Watch paused — languages still loading';
       toast.warning(msg);
       useImportQueueStore.getState().appendWatchLog({ message: msg, level: 'warn' });
@@ -807,6 +1280,7 @@ export const useImportQueueStore = create<ImportQueueState>()((set) => ({
     const requiresExplicit = cache.model !== null && !supportsAutoDetect(cache.model);
 
     if (requiresExplicit && resolvedCode === undefined) {
+      holdDetection(payload);
       const msg = 'Folder Watch paused — Source Language required for the active model';
       toast.warning(msg);
       useImportQueueStore.getState().appendWatchLog({ message: msg, level: 'warn' });
@@ -824,6 +1298,7 @@ export const useImportQueueStore = create<ImportQueueState>()((set) => ({
           enable_word_timestamps: enableWordTimestamps,
           parallel_diarization: enableDiarization ? parallelDiarization : undefined,
           language: resolvedCode,
+          source: payload.source ?? (state.notebookWatchPath || undefined),
         });
       }
     } else {
@@ -846,9 +1321,23 @@ export const useImportQueueStore = create<ImportQueueState>()((set) => ({
     });
   },
 
+  retryHeldDetections: () => {
+    const { heldDetections, watcherServerConnected, languagesCache } =
+      useImportQueueStore.getState();
+    if (heldDetections.length === 0 || !watcherServerConnected) return;
+    if (languagesCache.loading || languagesCache.model === null) return;
+    set({ heldDetections: [] });
+    // Anything still blocked (e.g. a source language is still required) is
+    // held again by handleFilesDetected.
+    for (const payload of heldDetections) {
+      useImportQueueStore.getState().handleFilesDetected(payload);
+    }
+  },
+
   // 4.2 — server connectivity
   setWatcherServerConnected: (connected) => {
     set({ watcherServerConnected: connected });
+    if (connected) useImportQueueStore.getState().retryHeldDetections();
   },
 
   // 4.3 — activity log
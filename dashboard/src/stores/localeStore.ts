@@ -0,0 +1,19 @@
+/**
+ * Active UI locale and its messages. Filled from the main process at boot
+ * and replaced in place on `locale:changed`, so switching language
+ * re-renders every `useT()` consumer without a reload.
+ */
+
+import { create } from 'zustand';
+
+interface LocaleState {
+  code: string;
+  messages: Record<string, string>;
+  setCatalog: (catalog: { code: string; messages: Record<string, string> }) => void;
+}
+
+export const useLocaleStore = create<LocaleState>((set) => ({
+  code: 'en',
+  messages: {},
+  setCatalog: ({ code, messages }) => set({ code, messages }),
+}));
@@ -15,8 +15,112 @@ type TrayState =
   | 'error'
   | 'disconnected';
 
+// Keep in sync with electron/shortcutRegistry.ts (canonical)
+type KeymapEntry = {
+  id: string;
+  label: string;
+  scope: 'global' | 'app';
+  accelerator: string;
+  defaultAccelerator: string;
+  conflicts: string[];
+};
+
+type KeymapResult =
+  | { ok: true; keymap: KeymapEntry[] }
+  | { ok: false; error: string; conflicts?: string[] };
+
+// Keep in sync with electron/actionRegistry.ts (canonical)
+type ActionEntry = {
+  id: string;
+  title: string;
+  category: string;
+  keywords: string[];
+  accelerator: string | null;
+  enabled: boolean;
+  disabledReason: string | null;
+};
+
+type InvokeActionResult = { ok: true } | { ok: false; error: string };
+
+// Keep in sync with electron/pluginHost.ts (canonical)
+interface PluginInfo {
+  id: string;
+  name: string;
+  version: string;
+  description?: string;
+  kind: 'postprocessor' | 'exporter';
+  abi: number;
+  wasm: string;
+  exporter?: { label: string; extension: string };
+  enabled: boolean;
+}
+
+interface PluginTranscript {
+  abi: number;
+  title: string;
+  language: string | null;
+  duration: number;
+  segments: Array<{ start: number; end: number; text: string; speaker: string | null }>;
+}
+
+type PluginRunResult = { ok: true; output: Uint8Array } | { ok: false; error: string };
+
+// Keep in sync with electron/scriptHooks.ts (canonical)
+type HookEvent = 'on_job_complete' | 'on_recording_saved' | 'on_export';
+
+interface HookScript {
+  id: string;
+  event: HookEvent;
+  command: string;
+  enabled: boolean;
+}
+
+interface HookRun {
+  scriptId: string;
+  event: HookEvent;
+  command: string;
+  startedAt: number;
+  durationMs: number;
+  exitCode: number | null;
+  timedOut: boolean;
+  error: string | null;
+  output: string;
+}
+
+// Keep in sync with electron/jobMedia.ts (canonical)
+interface JobMediaDownload {
+  path: string;
+  bytes: number;
+  sha256: string;
+}
+
+// Keep in sync with electron/mediaClip.ts (canonical)
+interface MediaEdit {
+  startMs?: number;
+  endMs?: number;
+  channels?: number[];
+}
+
+interface MediaClip extends MediaEdit {
+  path: string;
+}
+
+// Keep in sync with electron/channelProbe.ts (canonical)
+interface ChannelLevel {
+  index: number;
+  label: string;
+  rmsDb: number;
+  peakDb: number;
+}
+
+interface ChannelProbe {
+  channels: number;
+  layout: string | null;
+  levels: ChannelLevel[];
+}
+
 // Keep in sync with src/types/runtime.ts (canonical) and electron/preload.ts
-type RuntimeProfile = 'gpu' | 'cpu' | 'vulkan' | 'vulkan-wsl2' | 'metal';
+type RuntimeProfile = 'gpu' | 'cpu' | 'vulkan' | 'vulkan-wsl2' | 'rocm' | 'xpu' | 'metal';
 
 interface WslSupport {
   available: boolean;
@@ -50,6 +154,28 @@ interface StartContainerOptions {
   whispercppModel?: string;
 }
 
+/** Mirror of electron/whispercppTuning.ts. */
+interface WhispercppTuning {
+  threads: number;
+  flashAttention: boolean;
+  cores: 'all' | 'performance';
+}
+
+interface StoredWhispercppTuning extends WhispercppTuning {
+  benchmark?: {
+    measuredAt: string;
+    model: string | null;
+    runs: (WhispercppTuning & { seconds: number | null; error?: string })[];
+  };
+}
+
+interface WhispercppTuningState {
+  available: boolean;
+  machine: string;
+  topology: { logical: number; performance: number[]; efficiency: number[] };
+  tuning: StoredWhispercppTuning;
+}
+
 interface TrayMenuState {
   serverRunning?: boolean;
   isRecording?: boolean;
@@ -98,6 +224,9 @@ interface ElectronAPI {
     get: (key: string) => Promise<unknown>;
     set: (key: string, value: unknown) => Promise<void>;
     getAll: () => Promise<Record<string, unknown>>;
+    /** Keys written since the last call, however they were set (configBus.ts). */
+    onChanged: (callback: (keys: string[]) => void) => () => void;
+    onReloadFailed: (callback: (failure: ConfigReloadFailure) => void) => () => void;
   };
   app: {
     getVersion: () => Promise<string>;
@@ -107,6 +236,10 @@ interface ElectronAPI {
     openExternal: (url: string) => Promise<void>;
     openPath: (filePath: string) => Promise<string>;
     getConfigDir: () => Promise<string>;
+    /** Data directory when running in portable mode, else null. */
+    getPortableRoot: () => Promise<string | null>;
+    getDataPaths: () => Promise<Record<'config' | 'data' | 'cache' | 'logs', string>>;
+    openDataDir: (kind?: 'config' | 'data' | 'cache' | 'logs') => Promise<string>;
     getServerConfigDir: () => Promise<string>;
     removeConfigAndCache: () => Promise<void>;
     getClientLogPath: () => Promise<string>;
@@ -121,6 +254,8 @@ interface ElectronAPI {
     readLocalFile: (
       filePath: string,
     ) => Promise<{ name: string; buffer: ArrayBuffer; mimeType: string }>;
+    statLocalFile: (filePath: string) => Promise<{ name: string; size: number }>;
+    readLocalFileRange: (filePath: string, offset: number, length: number) => Promise<ArrayBuffer>;
   };
   docker: {
     available: () => Promise<boolean>;
@@ -132,6 +267,8 @@ interface ElectronAPI {
       gpu: boolean;
       toolkit: boolean;
       vulkan: boolean;
+      rocm: boolean;
+      xpu: boolean;
       wslSupport?: WslSupport;
     }>;
     hasVulkanWsl2SidecarImage: () => Promise<boolean>;
@@ -145,7 +282,7 @@ interface ElectronAPI {
     >;
     fetchRemoteTagDates: (tags: string[]) => Promise<Record<string, string | null>>;
     listVariantTags: () => Promise<
-      Record<'cuda' | 'cuda-legacy' | 'vulkan-wsl2' | 'vulkan-linux', string[]>
+      Record<'cuda' | 'cuda-legacy' | 'vulkan-wsl2' | 'vulkan-linux' | 'rocm' | 'xpu', string[]>
     >;
     pullImage: (tag: string) => Promise<string>;
     cancelPull: () => Promise<boolean>;
@@ -164,6 +301,7 @@ interface ElectronAPI {
     }>;
     startContainer: (options: StartContainerOptions) => Promise<string>;
     stopContainer: () => Promise<string>;
+    restartContainer: () => Promise<string>;
     removeContainer: () => Promise<string>;
     getVolumes: () => Promise<
       Array<{ name: string; label: string; driver: string; mountpoint: string; size?: string }>
@@ -178,6 +316,12 @@ interface ElectronAPI {
     switchWhisperServerModel: (
       model: string | null,
     ) => Promise<{ switched: boolean; model: string | null }>;
+    getWhispercppTuning: () => Promise<WhispercppTuningState>;
+    setWhispercppTuning: (tuning: WhispercppTuning) => Promise<StoredWhispercppTuning>;
+    benchmarkWhispercpp: () => Promise<StoredWhispercppTuning>;
+    onWhispercppBenchmarkProgress: (
+      callback: (progress: { done: number; total: number }) => void,
+    ) => () => void;
     removeVolume: (name: string) => Promise<string>;
     readComposeEnvValue: (key: string) => Promise<string | null>;
     volumeExists: (name: string) => Promise<boolean>;
@@ -258,10 +402,65 @@ interface ElectronAPI {
     onPortalChanged: (
       callback: (bindings: Array<{ id: string; trigger: string }>) => void,
     ) => () => void;
+    getKeymap: () => Promise<KeymapEntry[]>;
+    set: (action: string, accelerator: string | null) => Promise<KeymapResult>;
+    exportKeymap: () => Promise<string | null>;
+    importKeymap: () => Promise<KeymapResult | null>;
+    onKeymapChanged: (callback: (keymap: KeymapEntry[]) => void) => () => void;
+  };
+  plugins: {
+    list: () => Promise<PluginInfo[]>;
+    install: () => Promise<PluginInfo | null>;
+    remove: (id: string) => Promise<void>;
+    setEnabled: (id: string, enabled: boolean) => Promise<void>;
+    run: (id: string, transcript: PluginTranscript) => Promise<PluginRunResult>;
+    onChanged: (callback: (plugins: PluginInfo[]) => void) => () => void;
+  };
+  hooks: {
+    fire: (event: HookEvent, payload: Record<string, unknown>) => Promise<void>;
+    history: () => Promise<HookRun[]>;
+    test: (id: string) => Promise<HookRun>;
+    onRan: (callback: (run: HookRun) => void) => () => void;
+  };
+  jobMedia: {
+    download: (jobId: string, destination: string, sha256: string) => Promise<JobMediaDownload>;
+  };
+  mediaClip: {
+    selectSource: () => Promise<string | null>;
+    probeChannels: (source: string) => Promise<ChannelProbe>;
+    create: (source: string, edit: MediaEdit, key: string) => Promise<MediaClip>;
+    remove: (clipPath: string) => Promise<void>;
+  };
+  actions: {
+    list: () => Promise<ActionEntry[]>;
+    invoke: (id: string) => Promise<InvokeActionResult>;
+    onRun: (callback: (id: string) => void) => () => void;
+  };
+  locale: {
+    list: () => Promise<{
+      locales: Array<{ code: string; name: string; source: 'bundled' | 'user' }>;
+      userDir: string;
+    }>;
+    get: () => Promise<{ code: string; messages: Record<string, string> }>;
+    set: (code: string) => Promise<{ code: string; messages: Record<string, string> }>;
+    onChanged: (
+      callback: (catalog: { code: string; messages: Record<string, string> }) => void,
+    ) => () => void;
   };
   fileIO: {
     getDownloadsPath: () => Promise<string>;
     writeText: (filePath: string, content: string) => Promise<void>;
+    exportPdf: (
+      html: string,
+      filePath: string,
+      opts: {
+        header?: string;
+        footer?: string;
+        pageNumbers?: 'none' | 'page' | 'page-of-total';
+        pageSize?: 'A4' | 'Letter' | 'Legal';
+        landscape?: boolean;
+      },
+    ) => Promise<{ path: string; bytes: number }>;
     selectFolder: () => Promise<string | null>;
     /** Issue #104, Story 3.5 — native file-save dialog. */
     saveFile: (opts: {
@@ -275,17 +474,373 @@ interface ElectronAPI {
       body: string;
       silent?: boolean;
       timeoutMs?: number;
+      clickPayload?: unknown;
     }) => Promise<boolean>;
+    onClicked?: (callback: (payload: unknown) => void) => () => void;
   };
   notificationLog?: {
     load: () => Promise<unknown[]>;
     persist: (items: unknown[]) => Promise<void>;
   };
+  notificationHistory?: {
+    record: (items: unknown[]) => Promise<void>;
+    list: (options?: NotificationHistoryQuery) => Promise<NotificationHistoryRecord[]>;
+    unreadCount: () => Promise<number>;
+    markRead: (entryIds?: string[]) => Promise<void>;
+    dismiss: (entryId: string) => Promise<boolean>;
+  };
+  timeTracking?: {
+    begin: (info: WorkSessionInfo) => Promise<number | null>;
+    heartbeat: (
+      sessionId: number,
+      beat: { playing: boolean; focused: boolean },
+    ) => Promise<number | null>;
+    end: (sessionId: number) => Promise<void>;
+    projectTotals: (range?: TimeTrackingRange) => Promise<ProjectTimeTotals[]>;
+    exportCsv: (range?: TimeTrackingRange, perRecording?: boolean) => Promise<string | null>;
+  };
   mlx?: {
     onStatusChanged: (
       callback: (status: 'stopped' | 'starting' | 'running' | 'stopping' | 'error') => void,
     ) => () => void;
   };
+  nativeBackend?: {
+    getState: () => Promise<NativeBackendState>;
+    install: (opts: NativeBackendBootstrapOptions) => Promise<void>;
+    start: (
+      opts: NativeBackendBootstrapOptions & { port: number; hfToken?: string },
+    ) => Promise<void>;
+    stop: () => Promise<void>;
+    getLogs: (tail?: number) => Promise<string[]>;
+    onStatusChanged: (callback: (status: NativeBackendStatus) => void) => () => void;
+    onProgress: (callback: (progress: NativeBackendProgress) => void) => () => void;
+  };
+  startup?: {
+    getState: () => Promise<StartupState>;
+    attach: () => Promise<void>;
+    getReportText: () => Promise<string | null>;
+    onStateChanged: (callback: (state: StartupState) => void) => () => void;
+  };
+  wsl?: {
+    listDistros: () => Promise<WslDistro[]>;
+    probe: (distro: string) => Promise<WslDockerProbe>;
+  };
+  captions?: {
+    show: (position?: CaptionPosition) => Promise<void>;
+    hide: () => Promise<void>;
+    isVisible: () => Promise<boolean>;
+    update: (payload: CaptionPayload) => void;
+    onVisibilityChanged: (callback: (visible: boolean) => void) => () => void;
+  };
+  cloud?: {
+    listSources: () => Promise<CloudSourceState[]>;
+    saveSource: (
+      source: Omit<CloudSource, 'id'> & { id?: string },
+      secret?: CloudSourceSecret,
+    ) => Promise<CloudSourceState>;
+    removeSource: (id: string) => Promise<void>;
+    connect: (id: string) => Promise<void>;
+    browse: (id: string, folderId?: string) => Promise<CloudEntry[]>;
+    download: (id: string, entries: CloudEntry[]) => Promise<string[]>;
+    onDownloadProgress: (callback: (progress: CloudDownloadProgress) => void) => () => void;
+  };
+  phoneIngest?: {
+    getStatus: () => Promise<PhoneIngestStatus>;
+    setEnabled: (enabled: boolean, port?: number) => Promise<PhoneIngestStatus>;
+    resetPairing: () => Promise<PhoneIngestStatus>;
+    listPending: () => Promise<PhoneIngestFile[]>;
+    markImported: (path: string) => Promise<boolean>;
+    onFileReceived: (callback: (file: PhoneIngestFile) => void) => () => void;
+  };
+  cache?: {
+    getUsage: () => Promise<CacheUsage>;
+    clear: (category?: CacheCategory) => Promise<CacheUsage>;
+    setQuota: (category: CacheCategory, mb: number) => Promise<CacheUsage>;
+    setDirectory: (dir: string | null) => Promise<CacheUsage>;
+  };
+  followAlong?: {
+    getStatus: () => Promise<FollowAlongStatus>;
+    start: () => Promise<FollowAlongStatus>;
+    stop: () => Promise<void>;
+    update: (payload: FollowAlongPayload) => void;
+  };
+  connectionQuality?: {
+    getLatest: () => Promise<ConnectionQualityReport | null>;
+    checkNow: () => Promise<ConnectionQualityReport>;
+    onReport: (callback: (report: ConnectionQualityReport) => void) => () => void;
+  };
+  serverIdle?: {
+    getStatus: () => Promise<ServerIdleStatus>;
+    wake: () => Promise<boolean>;
+    noteActivity: () => Promise<void>;
+    onStatus: (callback: (status: ServerIdleStatus) => void) => () => void;
+  };
+  power?: {
+    getStatus: () => Promise<RemotePowerStatus>;
+    wake: (profile?: ConnectionQualityProfile) => Promise<boolean>;
+    sleepIfIdle: () => Promise<boolean>;
+    onStatus: (callback: (status: RemotePowerStatus) => void) => () => void;
+  };
+  settingsBundle?: {
+    exportBundle: () => Promise<string | null>;
+    importBundle: (remaps?: SettingsPathRemap[]) => Promise<SettingsImportResult | null>;
+  };
+}
+
+// Keep in sync with electron/preload.ts (Cloud* types)
+type CloudProvider = 's3' | 'gdrive' | 'dropbox';
+
+interface CloudSource {
+  id: string;
+  provider: CloudProvider;
+  label: string;
+  bucket?: string;
+  region?: string;
+  endpoint?: string;
+  prefix?: string;
+  accessKeyId?: string;
+  clientId?: string;
+}
+
+interface CloudSourceState extends CloudSource {
+  connected: boolean;
+}
+
+interface CloudSourceSecret {
+  secretAccessKey?: string;
+  clientSecret?: string;
+}
+
+interface CloudEntry {
+  id: string;
+  name: string;
+  isFolder: boolean;
+  size?: number;
+  modifiedAt?: string;
+}
+
+interface CloudDownloadProgress {
+  sourceId: string;
+  entryId: string;
+  name: string;
+  received: number;
+  total: number | null;
+  done: boolean;
+}
+
+// Keep in sync with electron/preload.ts (ConfigReloadFailure)
+interface ConfigReloadFailure {
+  subscriber: string;
+  message: string;
+}
+
+// Keep in sync with electron/preload.ts (PhoneIngest* types)
+interface PhoneIngestStatus {
+  running: boolean;
+  port: number | null;
+  url: string | null;
+  qrSvg: string | null;
+}
+
+interface PhoneIngestFile {
+  path: string;
+  name: string;
+  size: number;
+  receivedAt: string;
+}
+
+// Keep in sync with electron/preload.ts (Cache* types)
+type CacheCategory = 'cloud-import' | 'phone-ingest' | 'media' | 'waveforms';
+
+interface CacheCategoryUsage {
+  category: CacheCategory;
+  label: string;
+  bytes: number;
+  files: number;
+  quotaBytes: number;
+}
+
+interface CacheUsage {
+  root: string;
+  custom: boolean;
+  categories: CacheCategoryUsage[];
+}
+
+// Keep in sync with electron/preload.ts (FollowAlong* types)
+interface FollowAlongStatus {
+  running: boolean;
+  url: string | null;
+  qrSvg: string | null;
+  viewers: number;
+}
+
+interface FollowAlongPayload {
+  sentences: string[];
+  partial: string;
+}
+
+// Keep in sync with electron/preload.ts (ConnectionQuality* types)
+type ConnectionQualityProfile = 'local' | 'tailscale' | 'lan';
+
+interface ConnectionProfileQuality {
+  profile: ConnectionQualityProfile;
+  quality: 'good' | 'degraded' | 'offline';
+  latencyMs: number | null;
+  loss: number;
+}
+
+interface ConnectionQualityReport extends ConnectionProfileQuality {
+  checkedAt: number;
+  alternative: ConnectionProfileQuality | null;
+  suggestSwitchTo: ConnectionQualityProfile | null;
+}
+
+// Keep in sync with electron/preload.ts (ServerIdleStatus)
+interface ServerIdleStatus {
+  state: 'off' | 'active' | 'stopping' | 'sleeping' | 'waking';
+  idleMinutes: number;
+  lastActivityAt: number | null;
+  sleepingSince: number | null;
+  error: string | null;
+}
+
+// Keep in sync with electron/preload.ts (RemotePowerStatus)
+interface RemotePowerStatus {
+  state: 'unknown' | 'waking' | 'awake' | 'suspending' | 'suspended';
+  wokenByUs: boolean;
+  error: string | null;
+}
+
+// Keep in sync with electron/settingsBundle.ts
+interface SettingsPathRemap {
+  from: string;
+  to: string;
+}
+
+type SettingsImportResult =
+  | {
+      ok: true;
+      counts: Record<'settings' | 'profiles' | 'vocabularies' | 'rules', number>;
+      missingPaths: string[];
+      disabledHooks: number;
+      switchedOff: string[];
+      signer: string;
+      signedHere: boolean;
+    }
+  | { ok: false; error: string };
+
+// Keep in sync with electron/preload.ts (NotificationHistory* types)
+interface NotificationHistoryRecord {
+  entryId: string;
+  id: string;
+  category: string;
+  title: string;
+  detail?: string;
+  status: 'active' | 'complete' | 'error';
+  severity?: 'warning' | 'error';
+  error?: string;
+  createdAt: number;
+  completedAt?: number;
+  read: boolean;
+}
+
+interface NotificationHistoryQuery {
+  unreadOnly?: boolean;
+  limit?: number;
+  before?: number;
+}
+
+// Keep in sync with electron/preload.ts (time tracking types)
+interface WorkSessionInfo {
+  recordingId: number;
+  title: string;
+  project?: string;
+  audioSeconds: number;
+}
+
+interface TimeTrackingRange {
+  from?: number;
+  to?: number;
+}
+
+interface ProjectTimeTotals {
+  project: string;
+  recordings: number;
+  activeMs: number;
+  audioSeconds: number;
+}
+
+// Keep in sync with electron/preload.ts (Caption* types)
+type CaptionPosition = 'top' | 'bottom';
+
+interface CaptionPayload {
+  lines: string[];
+  partial: string;
+}
+
+// Keep in sync with electron/preload.ts (Wsl* types)
+interface WslDistro {
+  name: string;
+  state: string;
+  version: number;
+  isDefault: boolean;
+}
+
+interface WslDockerProbe {
+  available: boolean;
+  dockerVersion?: string;
+  reason?: string;
+}
+
+// Keep in sync with electron/preload.ts (Startup* types)
+type StartupStage = 'backend' | 'health' | 'model' | 'version';
+
+interface StartupStageState {
+  stage: StartupStage;
+  status: 'pending' | 'running' | 'done' | 'warning' | 'failed';
+  message: string;
+  startedAt: number | null;
+  finishedAt: number | null;
+}
+
+interface StartupReport {
+  source: string | null;
+  failedStage: StartupStage;
+  reason: string;
+  elapsedMs: number;
+  stages: StartupStageState[];
+  probes: string[];
+  diagnostics: string;
+  createdAt: string;
+}
+
+interface StartupState {
+  status: 'idle' | 'running' | 'ready' | 'failed';
+  source: string | null;
+  stages: StartupStageState[];
+  percent: number;
+  report: StartupReport | null;
+}
+
+// Keep in sync with electron/preload.ts (NativeBackend* types)
+type NativeBackendStatus = 'stopped' | 'installing' | 'starting' | 'running' | 'stopping' | 'error';
+
+interface NativeBackendProgress {
+  stage: 'locate' | 'source' | 'sync' | 'verify' | 'ready' | 'error';
+  message: string;
+  percent: number | null;
+}
+
+interface NativeBackendBootstrapOptions {
+  extras: string[];
+  pytorchVariant: 'cu129' | 'cpu';
+  force?: boolean;
+}
+
+interface NativeBackendState {
+  status: NativeBackendStatus;
+  progress: NativeBackendProgress | null;
+  installed: boolean;
 }
 
 interface ComponentUpdateStatus {
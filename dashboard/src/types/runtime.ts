@@ -10,8 +10,12 @@
  * auto-selected — only surfaced in Settings when detectWslGpuPassthrough()
  * confirms /dev/dxg passthrough. Requires the locally-built sidecar image
  * `transcriptionsuite/whisper-cpp-vulkan-wsl2:latest`.
+ *
+ * `rocm` and `xpu` run the regular PyTorch stack on an AMD (ROCm) or Intel
+ * (oneAPI) GPU on Linux, from their own image variants with the kernel
+ * device nodes mounted in. Offered when checkGpu() reports the vendor.
  */
-const RUNTIME_PROFILES = ['gpu', 'cpu', 'vulkan', 'vulkan-wsl2', 'metal'] as const;
+const RUNTIME_PROFILES = ['gpu', 'cpu', 'vulkan', 'vulkan-wsl2', 'rocm', 'xpu', 'metal'] as const;
 export type RuntimeProfile = (typeof RUNTIME_PROFILES)[number];
 
 export function isRuntimeProfile(value: unknown): value is RuntimeProfile {
@@ -0,0 +1,45 @@
+import { describe, expect, it } from 'vitest';
+import { searchActions, type PaletteAction } from '../commandPalette';
+
+const action = (
+  id: string,
+  title: string,
+  category: string,
+  keywords: string[] = [],
+  enabled = true,
+): PaletteAction => ({
+  id,
+  title,
+  category,
+  keywords,
+  accelerator: null,
+  enabled,
+  disabledReason: enabled ? null : 'unavailable',
+});
+
+const ACTIONS = [
+  action('recording.start', 'Start recording', 'Recording', ['dictate']),
+  action('recording.stop', 'Stop recording and transcribe', 'Recording', [], false),
+  action('live.start', 'Start live mode', 'Live Mode', ['realtime']),
+  action('files.transcribe', 'Transcribe a file…', 'Files', ['import']),
+];
+
+const ids = (query: string) => searchActions(ACTIONS, query).map((a) => a.id);
+
+describe('searchActions', () => {
+  it('lists everything for an empty query', () => {
+    expect(ids('  ')).toEqual(ACTIONS.map((a) => a.id));
+  });
+
+  it('needs every query word to start a word of the action', () => {
+    expect(ids('st rec')).toEqual(['recording.start', 'recording.stop']);
+    expect(ids('cording')).toEqual([]);
+  });
+
+  it('ranks title matches first, then enabled before disabled', () => {
+    expect(ids('transcribe')).toEqual(['files.transcribe', 'recording.stop']);
+    expect(ids('recording')).toEqual(['recording.start', 'recording.stop']);
+    expect(ids('dictate')).toEqual(['recording.start']);
+    expect(ids('start')).toEqual(['recording.start', 'live.start']);
+  });
+});
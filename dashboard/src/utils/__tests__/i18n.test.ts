@@ -0,0 +1,27 @@
+import { describe, expect, it } from 'vitest';
+import { FALLBACK_MESSAGES, formatDate, formatDuration, translate } from '../i18n';
+
+describe('i18n', () => {
+  it('falls back to English, then to the message id', () => {
+    expect(FALLBACK_MESSAGES['nav-session']).toBe('Session');
+    expect(translate({ 'nav-session': 'Συνεδρία' }, 'nav-session')).toBe('Συνεδρία');
+    expect(translate({}, 'nav-session')).toBe('Session');
+    expect(translate({}, 'no-such-message')).toBe('no-such-message');
+  });
+
+  it('fills placeables and leaves unknown ones visible', () => {
+    const messages = { greet: 'Hi { $name }, see {$folder} and { $other }' };
+    expect(translate(messages, 'greet', { name: 'Ana', folder: '/tmp' })).toBe(
+      'Hi Ana, see /tmp and { $other }',
+    );
+  });
+
+  it('formats dates and durations per locale', () => {
+    const date = '2026-05-08T10:00:00Z';
+    expect(formatDate(date, 'en-US', { dateStyle: 'long', timeZone: 'UTC' })).toBe('May 8, 2026');
+    expect(formatDate(date, 'en-GB', { dateStyle: 'long', timeZone: 'UTC' })).toBe('8 May 2026');
+    expect(formatDate('not a date', 'en')).toBe('');
+    expect(formatDuration(3725, 'en')).toBe('1 hr 2 min 5 sec');
+    expect(formatDuration(0, 'en')).toBe('0 sec');
+  });
+});
@@ -0,0 +1,38 @@
+import { describe, expect, it } from 'vitest';
+import { matchesAccelerator, toAppKeymap } from '../keymap';
+
+type Mods = Partial<Record<'ctrl' | 'alt' | 'shift' | 'meta', boolean>>;
+
+const key = (k: string, mods: Mods = {}) => ({
+  key: k,
+  ctrlKey: !!mods.ctrl,
+  altKey: !!mods.alt,
+  shiftKey: !!mods.shift,
+  metaKey: !!mods.meta,
+});
+
+describe('keymap', () => {
+  it('matches bare keys and exact modifier sets', () => {
+    expect(matchesAccelerator(key('k'), 'K')).toBe(true);
+    expect(matchesAccelerator(key('k', { ctrl: true }), 'K')).toBe(false);
+    expect(matchesAccelerator(key('J', { shift: true }), 'Shift+J')).toBe(true);
+    expect(matchesAccelerator(key('ArrowLeft', { alt: true }), 'Alt+Left')).toBe(true);
+    expect(matchesAccelerator(key(' '), 'Space')).toBe(true);
+    expect(matchesAccelerator(key('k'), undefined)).toBe(false);
+  });
+
+  it('resolves CommandOrControl per platform', () => {
+    expect(matchesAccelerator(key(',', { ctrl: true }), 'CommandOrControl+,')).toBe(true);
+    expect(matchesAccelerator(key(',', { meta: true }), 'CommandOrControl+,', true)).toBe(true);
+    expect(matchesAccelerator(key(',', { ctrl: true }), 'CommandOrControl+,', true)).toBe(false);
+  });
+
+  it('keeps only app-scope entries', () => {
+    expect(
+      toAppKeymap([
+        { id: 'start-recording', scope: 'global', accelerator: 'Alt+Ctrl+Z' },
+        { id: 'playback.toggle', scope: 'app', accelerator: 'P' },
+      ]),
+    ).toEqual({ 'playback.toggle': 'P' });
+  });
+});
@@ -0,0 +1,43 @@
+import { describe, expect, it } from 'vitest';
+import { describeDetection, previewSample, PREVIEW_BYTES } from '../languagePreview';
+
+const LANGUAGES = [
+  { code: 'auto', name: 'Auto Detect' },
+  { code: 'en', name: 'English' },
+  { code: 'fr', name: 'French' },
+];
+
+const RESULT = {
+  supported: true,
+  language: 'en',
+  probability: 0.734,
+  languages: [{ code: 'en', probability: 0.734 }],
+  window_languages: ['en', 'fr', null, 'en'],
+  multilingual: true,
+  sampled_seconds: 30,
+};
+
+describe('language preview', () => {
+  it('maps codes to picker names', () => {
+    expect(describeDetection(RESULT, LANGUAGES)).toEqual({
+      name: 'English',
+      percent: 73,
+      confident: true,
+      multilingual: true,
+      others: ['French'],
+    });
+  });
+
+  it('hides unsupported or empty results', () => {
+    expect(describeDetection(null, LANGUAGES)).toBeNull();
+    expect(describeDetection({ ...RESULT, supported: false }, LANGUAGES)).toBeNull();
+    expect(describeDetection({ ...RESULT, language: null }, LANGUAGES)).toBeNull();
+  });
+
+  it('only slices large files', () => {
+    const small = new Blob(['abc']);
+    expect(previewSample(small)).toBe(small);
+    const large = new Blob([new Uint8Array(PREVIEW_BYTES + 10)]);
+    expect(previewSample(large).size).toBe(PREVIEW_BYTES);
+  });
+});
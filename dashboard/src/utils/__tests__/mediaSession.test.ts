@@ -0,0 +1,26 @@
+import { describe, expect, it } from 'vitest';
+import { seekTarget, toPositionState } from '../mediaSession';
+
+describe('media session position', () => {
+  it('clamps the position into the track', () => {
+    expect(toPositionState(120, 30)).toEqual({ duration: 120, position: 30, playbackRate: 1 });
+    expect(toPositionState(120, 500, 1.5)).toEqual({
+      duration: 120,
+      position: 120,
+      playbackRate: 1.5,
+    });
+    expect(toPositionState(120, Number.NaN)?.position).toBe(0);
+  });
+
+  it('withholds state the OS would reject', () => {
+    expect(toPositionState(Number.NaN, 0)).toBeNull();
+    expect(toPositionState(Infinity, 0)).toBeNull();
+    expect(toPositionState(0, 0)).toBeNull();
+  });
+
+  it('clamps relative seeks', () => {
+    expect(seekTarget(5, 100, -10)).toBe(0);
+    expect(seekTarget(95, 100, 10)).toBe(100);
+    expect(seekTarget(5, Number.NaN, 10)).toBe(15);
+  });
+});
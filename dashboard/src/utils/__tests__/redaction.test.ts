@@ -0,0 +1,20 @@
+import { describe, expect, it } from 'vitest';
+import { isRedacted, overlappingRedactions } from '../redaction';
+
+const RANGES = [
+  { start_time: 2, end_time: 4 },
+  { start_time: 10, end_time: 11 },
+];
+
+describe('redaction helpers', () => {
+  it('uses the word midpoint', () => {
+    expect(isRedacted(1.5, 2.5, RANGES)).toBe(true);
+    expect(isRedacted(1.0, 2.4, RANGES)).toBe(false);
+    expect(isRedacted(5, 6, [])).toBe(false);
+  });
+
+  it('lists ranges touching a span', () => {
+    expect(overlappingRedactions(3, 10.5, RANGES)).toEqual(RANGES);
+    expect(overlappingRedactions(4, 10, RANGES)).toEqual([]);
+  });
+});
@@ -0,0 +1,23 @@
+import { describe, expect, it } from 'vitest';
+import { findReviewTarget } from '../segmentReview';
+
+const STATES = ['approved', null, 'flagged', 'approved', null] as const;
+
+describe('review navigation', () => {
+  it('finds the next unreviewed segment and wraps', () => {
+    expect(findReviewTarget(STATES, -1, 'unreviewed')).toBe(1);
+    expect(findReviewTarget(STATES, 1, 'unreviewed')).toBe(4);
+    expect(findReviewTarget(STATES, 4, 'unreviewed')).toBe(1);
+  });
+
+  it('walks backwards', () => {
+    expect(findReviewTarget(STATES, 4, 'unreviewed', -1)).toBe(1);
+    expect(findReviewTarget(STATES, -1, 'flagged', -1)).toBe(2);
+  });
+
+  it('returns null when nothing else matches', () => {
+    expect(findReviewTarget(STATES, 2, 'flagged')).toBeNull();
+    expect(findReviewTarget(['approved', 'approved'], 0, 'unreviewed')).toBeNull();
+    expect(findReviewTarget([], -1, 'unreviewed')).toBeNull();
+  });
+});
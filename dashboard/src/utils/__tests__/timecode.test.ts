@@ -0,0 +1,30 @@
+import { describe, expect, it } from 'vitest';
+import { formatTimecode, framesToTimecode, timecodeToFrames } from '../timecode';
+import type { TimecodeSettings } from '../../api/types';
+
+const PAL: TimecodeSettings = { start_timecode: '01:00:00:00', fps: 25, drop_frame: false };
+const DF: TimecodeSettings = { start_timecode: '00:00:00;00', fps: 29.97, drop_frame: true };
+
+describe('timecode labels', () => {
+  it('offsets non-drop-frame labels from the start timecode', () => {
+    expect(formatTimecode(0, PAL)).toBe('01:00:00:00');
+    expect(formatTimecode(1.5, PAL)).toBe('01:00:01:13');
+  });
+
+  it('skips drop-frame labels at the minute except tenth minutes', () => {
+    expect(framesToTimecode(1799, DF)).toBe('00:00:59;29');
+    expect(framesToTimecode(1800, DF)).toBe('00:01:00;02');
+    expect(framesToTimecode(17982, DF)).toBe('00:10:00;00');
+  });
+
+  it('round-trips drop-frame labels', () => {
+    for (let f = 0; f < 40000; f += 53) {
+      expect(timecodeToFrames(framesToTimecode(f, DF), DF)).toBe(f);
+    }
+  });
+
+  it('rejects malformed start labels', () => {
+    expect(timecodeToFrames('1 hour', PAL)).toBeNull();
+    expect(timecodeToFrames('00:00:00:25', PAL)).toBeNull();
+  });
+});
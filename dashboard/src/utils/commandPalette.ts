@@ -0,0 +1,37 @@
+/**
+ * Matching for the Ctrl+K command palette. The actions themselves come from
+ * the main-process registry (electron/actionRegistry.ts); this only decides
+ * which of them a query shows and in what order.
+ *
+ * Every word of the query has to start a word of the action's title,
+ * category or keywords ("st rec" finds "Start recording"). Title matches
+ * rank above keyword-only ones, and actions that can run rank above ones
+ * that can't; otherwise the registry's order is kept.
+ */
+
+export interface PaletteAction {
+  id: string;
+  title: string;
+  category: string;
+  keywords: string[];
+  accelerator: string | null;
+  enabled: boolean;
+  disabledReason: string | null;
+}
+
+const words = (text: string) => text.toLowerCase().split(/[^\p{L}\p{N}]+/u).filter(Boolean);
+
+export function searchActions<T extends PaletteAction>(actions: T[], query: string): T[] {
+  const terms = words(query);
+  if (terms.length === 0) return actions;
+  const ranked: Array<{ action: T; rank: number }> = [];
+  for (const action of actions) {
+    const title = words(action.title);
+    const all = [...title, ...words(action.category), ...action.keywords.flatMap(words)];
+    if (!terms.every((term) => all.some((word) => word.startsWith(term)))) continue;
+    const inTitle = terms.every((term) => title.some((word) => word.startsWith(term)));
+    ranked.push({ action, rank: (inTitle ? 0 : 2) + (action.enabled ? 0 : 1) });
+  }
+  // Array.prototype.sort is stable, so equal ranks keep registry order.
+  return ranked.sort((a, b) => a.rank - b.rank).map((r) => r.action);
+}
@@ -17,6 +17,8 @@ export interface SampleRecording {
   title: string;
   model: string;
   date: string;
+  lang: string;
+  ext: string;
 }
 
 export const DEFAULT_TEMPLATE = '{date} - {title}.txt';
@@ -29,6 +31,8 @@ export const PREVIEW_SAMPLE: SampleRecording = {
   // The preview always shows today's date as 2026-05-08 — chosen to match
   // the Story 3.1 AC1 example so the docs and the UI agree visually.
   date: '2026-05-08',
+  lang: 'en',
+  ext: 'txt',
 };
 
 /**
@@ -40,6 +44,8 @@ export const RESOLVERS: Record<string, (r: SampleRecording) => string> = {
   title: (r) => r.title,
   recording_id: (r) => r.id,
   model: (r) => r.model,
+  lang: (r) => r.lang,
+  ext: (r) => r.ext,
 };
 
 const PLACEHOLDER_RE = /\{([a-zA-Z_][a-zA-Z0-9_]*)\}/g;
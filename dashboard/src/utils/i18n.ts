@@ -0,0 +1,80 @@
+/**
+ * Message lookup and locale-aware formatting for the UI.
+ *
+ * Catalogs are loaded by the main process (electron/localeCatalog.ts) and
+ * pushed to `localeStore`; English is bundled here as the fallback for
+ * missing messages and for browser dev mode.
+ */
+
+import enSource from '../../public/locales/en.ftl?raw';
+
+/**
+ * Simple-message Fluent parser. Keep in sync with `parseFtl` in
+ * electron/localeCatalog.ts (the electron build cannot import src/).
+ */
+export function parseFtl(source: string): Record<string, string> {
+  const messages: Record<string, string> = {};
+  let current: string | null = null;
+  for (const raw of source.replace(/\r\n?/g, '\n').split('\n')) {
+    const line = raw.replace(/\s+$/, '');
+    if (!line || line.startsWith('#')) {
+      current = null;
+      continue;
+    }
+    if (/^\s/.test(line)) {
+      if (current && !line.trim().startsWith('.')) {
+        messages[current] = [messages[current], line.trim()].filter(Boolean).join(' ');
+      }
+      continue;
+    }
+    const match = /^([a-zA-Z][\w-]*)\s*=\s*(.*)$/.exec(line);
+    current = match ? match[1] : null;
+    if (current) messages[current] = match![2];
+  }
+  return messages;
+}
+
+export const FALLBACK_MESSAGES: Record<string, string> = parseFtl(enSource);
+
+export type MessageArgs = Record<string, string | number>;
+
+/** Look `id` up in `messages`, then English, then return the id itself; fill `{ $var }`. */
+export function translate(
+  messages: Record<string, string>,
+  id: string,
+  args?: MessageArgs,
+): string {
+  const template = messages[id] ?? FALLBACK_MESSAGES[id] ?? id;
+  if (!args) return template;
+  return template.replace(/\{\s*\$([\w-]+)\s*\}/g, (whole, name: string) =>
+    name in args ? String(args[name]) : whole,
+  );
+}
+
+/** Date in the UI locale, e.g. `8 May 2026` / `May 8, 2026`. */
+export function formatDate(
+  value: string | number | Date,
+  locale: string,
+  options: Intl.DateTimeFormatOptions = { dateStyle: 'medium' },
+): string {
+  const date = value instanceof Date ? value : new Date(value);
+  if (Number.isNaN(date.getTime())) return '';
+  return new Intl.DateTimeFormat(locale, options).format(date);
+}
+
+/** Duration in the UI locale's unit words, e.g. `1 hr, 5 min` / `1 ώρα, 5 λεπ.`. */
+export function formatDuration(seconds: number, locale: string): string {
+  const total = Math.max(0, Math.round(seconds));
+  const hours = Math.floor(total / 3600);
+  const minutes = Math.floor((total % 3600) / 60);
+  const secs = total % 60;
+  const unit = (value: number, name: 'hour' | 'minute' | 'second') =>
+    new Intl.NumberFormat(locale, { style: 'unit', unit: name, unitDisplay: 'short' }).format(
+      value,
+    );
+  const parts: string[] = [];
+  if (hours) parts.push(unit(hours, 'hour'));
+  if (minutes) parts.push(unit(minutes, 'minute'));
+  if (secs || parts.length === 0) parts.push(unit(secs, 'second'));
+  return new Intl.ListFormat(locale, { style: 'narrow', type: 'unit' }).format(parts);
+}
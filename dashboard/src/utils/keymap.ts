@@ -0,0 +1,81 @@
+/**
+ * Renderer side of the shortcut registry (electron/shortcutRegistry.ts).
+ *
+ * The main process owns the keymap; the renderer only fetches it and asks
+ * "does this keydown match action X?". `FALLBACK_APP_KEYMAP` mirrors the
+ * registry's app-scope defaults for browser dev mode, where there is no
+ * main process to ask.
+ */
+
+export type KeymapAction =
+  | 'playback.toggle'
+  | 'playback.back'
+  | 'playback.forward'
+  | 'settings.open'
+  | 'palette.open';
+
+export type AppKeymap = Partial<Record<KeymapAction, string>>;
+
+export const FALLBACK_APP_KEYMAP: AppKeymap = {
+  'playback.toggle': 'K',
+  'playback.back': 'J',
+  'playback.forward': 'L',
+  'settings.open': 'CommandOrControl+,',
+  'palette.open': 'CommandOrControl+K',
+};
+
+const KEY_ALIASES: Record<string, string> = {
+  ' ': 'space',
+  arrowleft: 'left',
+  arrowright: 'right',
+  arrowup: 'up',
+  arrowdown: 'down',
+  escape: 'esc',
+  '+': 'plus',
+};
+
+/** App-scope accelerators from a registry listing. */
+export function toAppKeymap(
+  entries: Array<{ id: string; scope: string; accelerator: string }>,
+): AppKeymap {
+  const keymap: AppKeymap = {};
+  for (const entry of entries) {
+    if (entry.scope === 'app') keymap[entry.id as KeymapAction] = entry.accelerator;
+  }
+  return keymap;
+}
+
+/**
+ * Whether a keydown is exactly `accelerator` — every listed modifier held
+ * and no other. `CommandOrControl` means ⌘ on macOS and Ctrl elsewhere.
+ */
+export function matchesAccelerator(
+  event: Pick<KeyboardEvent, 'key' | 'ctrlKey' | 'altKey' | 'shiftKey' | 'metaKey'>,
+  accelerator: string | undefined,
+  isMac = false,
+): boolean {
+  if (!accelerator) return false;
+  const want = { ctrl: false, alt: false, shift: false, meta: false };
+  let key = '';
+  for (const raw of accelerator.split(/\+(?!$)/)) {
+    const part = raw.trim().toLowerCase();
+    if (part === 'commandorcontrol' || part === 'cmdorctrl') {
+      if (isMac) want.meta = true;
+      else want.ctrl = true;
+    } else if (part === 'ctrl' || part === 'control') want.ctrl = true;
+    else if (part === 'alt' || part === 'option') want.alt = true;
+    else if (part === 'shift') want.shift = true;
+    else if (part === 'command' || part === 'cmd' || part === 'super' || part === 'meta') {
+      want.meta = true;
+    } else key = KEY_ALIASES[part] ?? part;
+  }
+  const pressed = event.key.toLowerCase();
+  return (
+    key !== '' &&
+    (KEY_ALIASES[pressed] ?? pressed) === key &&
+    event.ctrlKey === want.ctrl &&
+    event.altKey === want.alt &&
+    event.shiftKey === want.shift &&
+    event.metaKey === want.meta
+  );
+}
@@ -0,0 +1,48 @@
+/**
+ * Language preview helpers for the import dialog.
+ *
+ * Only the head of a queued file is uploaded for detection. Most containers
+ * decode from a prefix; ones that keep their index at the end (some MP4s)
+ * fail to decode and the preview is simply not shown.
+ */
+
+import type { LanguageDetectionResult } from '../api/types';
+
+/** Bytes sent for detection — comfortably more than 30 s of any common codec. */
+export const PREVIEW_BYTES = 8 * 1024 * 1024;
+/** Seconds of audio the server samples (its default). */
+export const PREVIEW_SECONDS = 30;
+/** Below this the preview is shown as a hint, not offered as a choice. */
+export const CONFIDENT_PREVIEW = 0.5;
+
+export function previewSample(file: Blob): Blob {
+  return file.size > PREVIEW_BYTES ? file.slice(0, PREVIEW_BYTES) : file;
+}
+
+export interface LanguagePreview {
+  /** Display name matching the language picker, or the raw code. */
+  name: string;
+  percent: number;
+  confident: boolean;
+  multilingual: boolean;
+  /** Other languages heard in the sample, by display name. */
+  others: string[];
+}
+
+export function describeDetection(
+  result: LanguageDetectionResult | null,
+  languages: Array<{ code: string; name: string }>,
+): LanguagePreview | null {
+  if (!result?.supported || !result.language) return null;
+  const nameOf = (code: string) => languages.find((l) => l.code === code)?.name ?? code;
+  const others = [
+    ...new Set(result.window_languages.filter((c): c is string => !!c && c !== result.language)),
+  ];
+  return {
+    name: nameOf(result.language),
+    percent: Math.round(result.probability * 100),
+    confident: result.probability >= CONFIDENT_PREVIEW,
+    multilingual: result.multilingual,
+    others: others.map(nameOf),
+  };
+}
@@ -0,0 +1,40 @@
+/**
+ * Pure helpers behind `useMediaSession` — the OS media-session bridge.
+ *
+ * Chromium maps `navigator.mediaSession` onto MPRIS on Linux and System Media
+ * Transport Controls on Windows / Now Playing on macOS, so media keys and the
+ * OS media overlay drive transcript playback without a native module.
+ */
+
+/** Seconds skipped by the OS "seek backward / forward" buttons when none is given. */
+export const DEFAULT_SEEK_OFFSET = 10;
+
+export interface PlaybackPosition {
+  duration: number;
+  position: number;
+  playbackRate: number;
+}
+
+/**
+ * Position state the OS accepts, or `null` while it would be rejected —
+ * `setPositionState` throws on a non-finite duration (stream still loading)
+ * or a position past the end.
+ */
+export function toPositionState(
+  duration: number,
+  position: number,
+  playbackRate = 1,
+): PlaybackPosition | null {
+  if (!Number.isFinite(duration) || duration <= 0) return null;
+  return {
+    duration,
+    position: Math.min(Math.max(0, Number.isFinite(position) ? position : 0), duration),
+    playbackRate: playbackRate > 0 ? playbackRate : 1,
+  };
+}
+
+/** Target time for a relative seek (`seekbackward` / `seekforward`), clamped to the track. */
+export function seekTarget(current: number, duration: number, delta: number): number {
+  const end = Number.isFinite(duration) && duration > 0 ? duration : Infinity;
+  return Math.min(Math.max(0, current + delta), end);
+}
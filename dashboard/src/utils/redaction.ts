@@ -0,0 +1,23 @@
+/**
+ * Redaction helpers — the same midpoint rule the server uses to decide
+ * which words an export masks (server/backend/core/redaction.py).
+ */
+
+import type { Redaction } from '../api/types';
+
+type Span = Pick<Redaction, 'start_time' | 'end_time'>;
+
+/** True when the midpoint of `start`–`end` falls inside any range. */
+export function isRedacted(start: number, end: number, ranges: ReadonlyArray<Span>): boolean {
+  const mid = (start + end) / 2;
+  return ranges.some((r) => r.start_time <= mid && mid <= r.end_time);
+}
+
+/** Ranges overlapping `start`–`end` — what "un-redact" removes. */
+export function overlappingRedactions<T extends Span>(
+  start: number,
+  end: number,
+  ranges: ReadonlyArray<T>,
+): T[] {
+  return ranges.filter((r) => r.start_time < end && r.end_time > start);
+}
@@ -0,0 +1,43 @@
+/**
+ * Review-mode navigation — pure helpers so the keyboard handler in
+ * AudioNoteModal stays a thin key → action table.
+ */
+
+import type { SegmentReviewState } from '../api/types';
+
+export type ReviewTarget = 'unreviewed' | 'flagged';
+
+/** Keyboard map shown in the review-mode tooltip; the handler uses the same keys. */
+export const REVIEW_SHORTCUTS: ReadonlyArray<readonly [string, string]> = [
+  ['A', 'Approve and go to next unreviewed'],
+  ['F', 'Flag and go to next unreviewed'],
+  ['U', 'Mark unreviewed'],
+  ['N / Shift+N', 'Next / previous unreviewed'],
+  ['M / Shift+M', 'Next / previous flagged'],
+  ['↓ / ↑', 'Next / previous segment'],
+];
+
+function matches(state: SegmentReviewState | null | undefined, target: ReviewTarget): boolean {
+  return target === 'flagged' ? state === 'flagged' : !state;
+}
+
+/**
+ * Index of the next segment (after `from`, wrapping) in `target` state, or
+ * null when there is none. `from = -1` starts from the top.
+ */
+export function findReviewTarget(
+  states: ReadonlyArray<SegmentReviewState | null | undefined>,
+  from: number,
+  target: ReviewTarget,
+  direction: 1 | -1 = 1,
+): number | null {
+  const n = states.length;
+  if (n === 0) return null;
+  const origin = from < 0 ? (direction === 1 ? -1 : 0) : from;
+  for (let step = 1; step <= n; step += 1) {
+    const i = (((origin + direction * step) % n) + n) % n;
+    if (i === from) continue;
+    if (matches(states[i], target)) return i;
+  }
+  return null;
+}
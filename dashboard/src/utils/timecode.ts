@@ -0,0 +1,65 @@
+/**
+ * SMPTE timecode labels for the transcript viewer.
+ *
+ * Mirror of the label half of `server/backend/core/timecode.py` so the
+ * viewer shows the same `HH:MM:SS:FF` values an editor gets from the
+ * SRT/ASS export. Drop-frame (29.97 only) uses `;` before the frame field
+ * and skips labels 00/01 at every minute except each tenth.
+ */
+
+import type { TimecodeSettings } from '../api/types';
+
+const RATES: Record<number, [number, number]> = {
+  23.976: [24000, 1001],
+  24: [24, 1],
+  25: [25, 1],
+  29.97: [30000, 1001],
+  30: [30, 1],
+};
+
+const TIMECODE_RE = /^(\d{1,2}):([0-5]\d):([0-5]\d)[:;.](\d{2})$/;
+
+function rate(fps: number): [number, number] {
+  return RATES[fps] ?? [Math.round(fps), 1];
+}
+
+/** Absolute frame count for a start label; `null` if it does not parse. */
+export function timecodeToFrames(timecode: string, settings: TimecodeSettings): number | null {
+  const match = TIMECODE_RE.exec(timecode.trim());
+  if (!match) return null;
+  const [hh, mm, ss, ff] = match.slice(1).map(Number);
+  const nominal = Math.round(settings.fps);
+  if (ff >= nominal) return null;
+  let total = ((hh * 60 + mm) * 60 + ss) * nominal + ff;
+  if (settings.drop_frame) {
+    const minutes = hh * 60 + mm;
+    total -= 2 * (minutes - Math.floor(minutes / 10));
+  }
+  return total;
+}
+
+export function framesToTimecode(frames: number, settings: TimecodeSettings): string {
+  const nominal = Math.round(settings.fps);
+  let f = Math.max(0, Math.floor(frames));
+  if (settings.drop_frame) {
+    const tens = Math.floor(f / 17982);
+    const rem = f % 17982;
+    f += 18 * tens;
+    if (rem >= 2) f += 2 * Math.floor((rem - 2) / 1798);
+  }
+  const ff = f % nominal;
+  const totalSecs = Math.floor(f / nominal);
+  const hh = Math.floor(totalSecs / 3600) % 24;
+  const mm = Math.floor((totalSecs % 3600) / 60);
+  const ss = totalSecs % 60;
+  const pad = (n: number) => n.toString().padStart(2, '0');
+  return `${pad(hh)}:${pad(mm)}:${pad(ss)}${settings.drop_frame ? ';' : ':'}${pad(ff)}`;
+}
+
+/** Label for `seconds` into the recording, counted from the start timecode. */
+export function formatTimecode(seconds: number, settings: TimecodeSettings): string {
+  const [num, den] = rate(settings.fps);
+  const offset = seconds * (num / den);
+  const start = timecodeToFrames(settings.start_timecode, settings) ?? 0;
+  return framesToTimecode(start + Math.max(0, Math.floor(offset + 0.5)), settings);
+}
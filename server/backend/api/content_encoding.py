@@ -0,0 +1,197 @@
+"""
+Content-encoding negotiation for remote clients on slow links.
+
+Requests: a body sent with ``Content-Encoding: gzip`` is decompressed as it
+streams in, so routes see the original bytes. The dashboard uses this for
+uncompressed audio (WAV/PCM), which typically shrinks by a third or more.
+The encodings accepted here are advertised in ``/api/status`` as
+``request_encodings`` — clients must not compress bodies for servers that
+do not list them, since an older server would store the compressed bytes.
+
+Responses: JSON bodies (transcripts, recording lists) are gzip-compressed
+for clients that send ``Accept-Encoding: gzip``. Audio, downloads and event
+streams pass through untouched; they are either compressed already or must
+reach the client as they are produced.
+"""
+
+from __future__ import annotations
+
+import gzip
+import json
+import zlib
+from collections.abc import Awaitable, Callable, MutableMapping
+from typing import Any
+
+from fastapi import HTTPException
+
+Scope = MutableMapping[str, Any]
+Message = MutableMapping[str, Any]
+Receive = Callable[[], Awaitable[Message]]
+Send = Callable[[Message], Awaitable[None]]
+ASGIApp = Callable[[Scope, Receive, Send], Awaitable[None]]
+
+SUPPORTED_REQUEST_ENCODINGS = ("gzip",)
+# Smaller responses gain less than the gzip header and CPU cost.
+MIN_COMPRESS_BYTES = 1024
+COMPRESS_LEVEL = 6
+# Upper bound on each decompressed piece handed to the route, so a small
+# body that expands enormously is never inflated in one go.
+DECODE_PIECE_BYTES = 1024 * 1024
+
+
+class CorruptBody(HTTPException):
+    """
+    The request body is not valid data for its Content-Encoding. Raised while
+    the route reads the body, so FastAPI answers it like any other 400.
+    """
+
+    def __init__(self, reason: str) -> None:
+        super().__init__(status_code=400, detail=f"Invalid gzip request body: {reason}")
+
+
+def _header(scope: Scope, name: bytes) -> str:
+    for key, value in scope.get("headers", []):
+        if key.lower() == name:
+            return value.decode("latin-1")
+    return ""
+
+
+def accepts_gzip(accept_encoding: str) -> bool:
+    """Whether an ``Accept-Encoding`` header allows gzip (honouring ``q=0``)."""
+    for part in accept_encoding.split(","):
+        coding, _, params = part.strip().partition(";")
+        if coding.strip().lower() not in ("gzip", "*"):
+            continue
+        q = params.strip().lower()
+        if q.startswith("q="):
+            try:
+                return float(q[2:]) > 0
+            except ValueError:
+                return False
+        return True
+    return False
+
+
+def _gunzip_receive(receive: Receive) -> Receive:
+    """Wrap ``receive`` so gzip request bodies arrive decompressed, piece by piece."""
+    decoder = zlib.decompressobj(wbits=31)
+    pending = b""
+    upstream_done = False
+
+    async def wrapped() -> Message:
+        nonlocal pending, upstream_done
+        while True:
+            if pending:
+                try:
+                    piece = decoder.decompress(pending, DECODE_PIECE_BYTES)
+                except zlib.error as e:
+                    raise CorruptBody(str(e)) from e
+                pending = decoder.unconsumed_tail
+                more = bool(pending) or not upstream_done
+                if not more and not decoder.eof:
+                    raise CorruptBody("truncated gzip body")
+                if piece or not more:
+                    return {"type": "http.request", "body": piece, "more_body": more}
+                continue
+            if upstream_done:
+                if not decoder.eof:
+                    raise CorruptBody("truncated gzip body")
+                return {"type": "http.request", "body": b"", "more_body": False}
+            message = await receive()
+            if message["type"] != "http.request":
+                return message
+            pending = message.get("body", b"")
+            upstream_done = not message.get("more_body", False)
+
+    return wrapped
+
+
+class ContentEncodingMiddleware:
+    """Decode compressed request bodies and gzip JSON responses (see module docstring)."""
+
+    def __init__(self, app: ASGIApp) -> None:
+        self.app = app
+
+    async def __call__(self, scope: Scope, receive: Receive, send: Send) -> None:
+        if scope["type"] != "http":
+            await self.app(scope, receive, send)
+            return
+
+        encoding = _header(scope, b"content-encoding").strip().lower()
+        if encoding and encoding != "identity":
+            if encoding not in SUPPORTED_REQUEST_ENCODINGS:
+                await _plain_response(send, 415, f"Unsupported Content-Encoding: {encoding}")
+                return
+            scope = dict(scope)
+            scope["headers"] = [
+                (k, v)
+                for k, v in scope["headers"]
+                if k.lower() not in (b"content-encoding", b"content-length")
+            ]
+            receive = _gunzip_receive(receive)
+
+        if accepts_gzip(_header(scope, b"accept-encoding")):
+            send = _GzipJsonResponder(send).send
+        await self.app(scope, receive, send)
+
+
+class _GzipJsonResponder:
+    """Buffers the response start and compresses single-message JSON bodies."""
+
+    def __init__(self, send: Send) -> None:
+        self._send = send
+        self._start: Message | None = None
+        self._passthrough = False
+
+    async def send(self, message: Message) -> None:
+        if self._passthrough:
+            await self._send(message)
+            return
+        if message["type"] == "http.response.start":
+            self._start = message
+            return
+        if message["type"] != "http.response.body" or self._start is None:
+            await self._send(message)
+            return
+
+        start, self._start = self._start, None
+        body = message.get("body", b"")
+        headers = [(k.lower(), v) for k, v in start.get("headers", [])]
+        content_type = dict(headers).get(b"content-type", b"").decode("latin-1").lower()
+        compressible = (
+            content_type.startswith("application/json")
+            and b"content-encoding" not in dict(headers)
+            and not message.get("more_body", False)
+            and len(body) >= MIN_COMPRESS_BYTES
+        )
+        if not compressible:
+            self._passthrough = True
+            await self._send(start)
+            await self._send(message)
+            return
+
+        compressed = gzip.compress(body, compresslevel=COMPRESS_LEVEL)
+        headers = [(k, v) for k, v in headers if k != b"content-length"]
+        headers += [
+            (b"content-encoding", b"gzip"),
+            (b"content-length", str(len(compressed)).encode()),
+            (b"vary", b"Accept-Encoding"),
+        ]
+        await self._send({**start, "headers": headers})
+        await self._send({"type": "http.response.body", "body": compressed, "more_body": False})
+        self._passthrough = True
+
+
+async def _plain_response(send: Send, status: int, detail: str) -> None:
+    body = json.dumps({"detail": detail}).encode()
+    await send(
+        {
+            "type": "http.response.start",
+            "status": status,
+            "headers": [
+                (b"content-type", b"application/json"),
+                (b"content-length", str(len(body)).encode()),
+            ],
+        }
+    )
+    await send({"type": "http.response.body", "body": body, "more_body": False})
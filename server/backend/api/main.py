@@ -42,15 +42,18 @@ import server.core.token_store as _ts_mod  # noqa: E402
 
 _log_time("token_store imported")
 
+from server.api.content_encoding import ContentEncodingMiddleware  # noqa: E402
 from server.api.routes import (  # noqa: E402
     admin,
     auth,
     health,
     live,
     llm,
+    mcp,
     notebook,
     openai_audio,
     profiles,
+    projects,
     search,
     transcription,
     websocket,
@@ -198,7 +201,16 @@ PUBLIC_PREFIXES = (
     "/redoc",
 )
 
-NOTEBOOK_QUERY_TOKEN_ROUTES = re.compile(r"^/api/notebook/recordings/\d+/(audio|export)$")
+# Writes still accepted while the library is read-only (see database/library_lock.py)
+READ_ONLY_ALLOWED_PREFIXES = (
+    "/api/auth/",
+    "/api/mcp",  # JSON-RPC over POST, but every tool only reads
+    "/api/admin/library/",
+)
+
+NOTEBOOK_QUERY_TOKEN_ROUTES = re.compile(
+    r"^/api/notebook/recordings/\d+/(audio|export|redacted-media)$"
+)
 
 
 def _find_backend_dependency_error(exc: BaseException) -> object | None:
@@ -374,6 +386,56 @@ class AuthenticationMiddleware(BaseHTTPMiddleware):
         )
 
 
+class LibraryReadOnlyMiddleware(BaseHTTPMiddleware):
+    """
+    Refuse writes with 423 while another machine owns the library.
+
+    The database connections are already read-only at the SQLite level; this
+    turns what would be a 500 from ``attempt to write a readonly database``
+    into a clear answer the dashboard can show.
+    """
+
+    async def dispatch(self, request: Request, call_next):
+        from server.database.library_lock import is_read_only, status
+
+        if (
+            request.method in ("POST", "PUT", "PATCH", "DELETE")
+            and is_read_only()
+            and not request.url.path.startswith(READ_ONLY_ALLOWED_PREFIXES)
+        ):
+            holder = status()["holder"] or {}
+            return JSONResponse(
+                status_code=423,
+                content={
+                    "detail": (
+                        f"The library is open on {holder.get('machine') or 'another machine'}; "
+                        "this server is read-only until it takes ownership"
+                    )
+                },
+            )
+        return await call_next(request)
+
+
+class AuditMiddleware(BaseHTTPMiddleware):
+    """
+    Record successful destructive actions and settings changes
+    (``AUDITED_ROUTES``) in the append-only audit log.
+
+    Runs inside the auth and read-only checks, so refused requests never
+    reach it; failed ones (4xx/5xx) are not recorded.
+    """
+
+    async def dispatch(self, request: Request, call_next):
+        from server.api.routes.utils import audit_event, audited_action
+
+        response = await call_next(request)
+        audited = audited_action(request.method, request.url.path)
+        if audited and response.status_code < 400:
+            action, target_type, target_id = audited
+            audit_event(request, action, target_type=target_type, target_id=target_id)
+        return response
+
+
 @asynccontextmanager
 async def lifespan(app: FastAPI) -> AsyncGenerator[None]:
     """Application lifespan handler for startup/shutdown."""
@@ -387,10 +449,14 @@ async def lifespan(app: FastAPI) -> AsyncGenerator[None]:
     emit_event("lifespan-start", "server", "Starting server...", phase="lifespan")
 
     _cleanup_task = None
+    _source_retention_task = None
+    _mail_task = None
     _orphan_sweep_task = None
     _deferred_export_sweep_task = None
     _webhook_worker = None
     _webhook_cleanup_task = None
+    _library_heartbeat_task = None
+    _maintenance_task = None
 
     config = get_config()
     _log_time("config loaded")
@@ -410,6 +476,29 @@ async def lifespan(app: FastAPI) -> AsyncGenerator[None]:
     # the runtime model fetch trusts it too — before any model is loaded.
     propagate_ca_trust()
 
+    # Claim the library before touching the database: if another machine's
+    # server owns it (synced drive), every connection below opens read-only.
+    from server.database import library_lock
+    from server.database.database import get_db_path as _get_db_path
+
+    storage_config = config.config.get("storage", {}) or {}
+    if storage_config.get("library_lock_enabled", True):
+        library_lock.acquire(
+            _get_db_path(),
+            machine=storage_config.get("library_lock_machine_name") or None,
+            stale_after=storage_config.get(
+                "library_lock_stale_after_seconds", library_lock.DEFAULT_STALE_AFTER_SECONDS
+            ),
+        )
+        _library_heartbeat_task = asyncio.create_task(
+            library_lock.periodic_heartbeat(
+                storage_config.get(
+                    "library_lock_heartbeat_seconds", library_lock.DEFAULT_HEARTBEAT_SECONDS
+                )
+            )
+        )
+        _log_time("library lock acquired")
+
     # Initialize database
     init_db()
     _log_time("database init_db() complete")
@@ -432,15 +521,17 @@ async def lifespan(app: FastAPI) -> AsyncGenerator[None]:
     _durability_config_early = config.config.get("durability", {})
     _orphan_timeout = _durability_config_early.get("orphan_job_timeout_minutes", 10)
 
-    # Recover orphaned jobs from a previous crash/restart (Wave 3)
-    await recover_orphaned_jobs(_orphan_timeout)
-    _log_time("orphan job recovery complete")
+    # Recover orphaned jobs from a previous crash/restart (Wave 3). When the
+    # library is read-only, "processing" rows belong to the owning machine.
+    if not library_lock.is_read_only():
+        await recover_orphaned_jobs(_orphan_timeout)
+        _log_time("orphan job recovery complete")
 
     # Schedule backup check in background (non-blocking)
     backup_config = config.config.get("backup", {})
     backup_enabled = backup_config.get("enabled", True)
 
-    if backup_enabled:
+    if backup_enabled and not library_lock.is_read_only():
         from server.database.backup import run_backup_if_needed
         from server.database.database import get_data_dir, get_db_path
 
@@ -486,6 +577,43 @@ async def lifespan(app: FastAPI) -> AsyncGenerator[None]:
     else:
         logger.info("Audio cleanup disabled (cleanup_enabled=false)")
 
+    # Source-audio retention for notebook recordings (off unless retention_days > 0)
+    _source_retention_days = storage_config.get("source_audio_retention_days", 0) or 0
+    if _source_retention_days > 0 and not library_lock.is_read_only():
+        from server.database.recording_audio_retention import (
+            periodic_recording_audio_retention,
+        )
+
+        _source_retention_action = storage_config.get("source_audio_retention_action", "delete")
+        _source_retention_task = asyncio.create_task(
+            periodic_recording_audio_retention(
+                _source_retention_days,
+                _source_retention_action,
+                storage_config.get("source_audio_archive_dir", "") or "",
+                storage_config.get("source_audio_retention_interval_hours", 24),
+            )
+        )
+        _log_time("source-audio retention scheduled (async, periodic)")
+        logger.info(
+            "Source-audio retention scheduled (%s after %d days)",
+            _source_retention_action,
+            _source_retention_days,
+        )
+
+    # Periodic integrity check + repair of the library database
+    _maintenance_interval_hours = storage_config.get("maintenance_interval_hours", 168) or 0
+    if _maintenance_interval_hours > 0 and not library_lock.is_read_only():
+        from server.database.maintenance import periodic_maintenance
+
+        _maintenance_task = asyncio.create_task(
+            periodic_maintenance(
+                _maintenance_interval_hours,
+                vacuum=bool(storage_config.get("maintenance_vacuum", False)),
+                max_backups=backup_config.get("max_backups", 3),
+            )
+        )
+        _log_time("database maintenance scheduled (async, periodic)")
+
     # Schedule periodic deferred-export sweeper (Issue #104, Story 6.8 / R-EL12).
     # The sweeper re-fires auto_export rows whose destination came back online
     # since they were marked 'deferred', and re-fires auto_summary rows in
@@ -697,6 +825,27 @@ async def lifespan(app: FastAPI) -> AsyncGenerator[None]:
         _orphan_sweep_interval,
     )
 
+    # Email-in transcription (core/mail.py) — needs the model manager's job slot
+    mail_config = config.config.get("mail", {}) or {}
+    if mail_config.get("enabled", False):
+        from server.core.mail import MailError, MailPoller, MailSettings, notebook_transcriber
+
+        try:
+            _mail_settings = MailSettings.from_config(mail_config)
+        except MailError as exc:
+            logger.error("Mail polling not started: %s", exc)
+        else:
+            _mail_task = asyncio.create_task(
+                MailPoller(_mail_settings, notebook_transcriber(manager, _mail_settings)).run()
+            )
+            _log_time("mail polling scheduled (async, periodic)")
+            logger.info(
+                "Mail polling scheduled (%s/%s, every %.0fs)",
+                _mail_settings.imap_host,
+                _mail_settings.mailbox,
+                _mail_settings.poll_interval_s,
+            )
+
     logger.info("Server startup complete")
     _log_time("lifespan startup complete")
     emit_event(
@@ -713,6 +862,13 @@ async def lifespan(app: FastAPI) -> AsyncGenerator[None]:
     # Shutdown
     logger.info("Server shutting down...")
 
+    if _library_heartbeat_task and not _library_heartbeat_task.done():
+        _library_heartbeat_task.cancel()
+        try:
+            await _library_heartbeat_task
+        except asyncio.CancelledError:
+            logger.debug("Library heartbeat task cancelled")
+
     # Cancel periodic audio cleanup task
     if _cleanup_task and not _cleanup_task.done():
         _cleanup_task.cancel()
@@ -721,6 +877,30 @@ async def lifespan(app: FastAPI) -> AsyncGenerator[None]:
         except asyncio.CancelledError:
             logger.debug("Audio cleanup task cancelled")
 
+    # Cancel periodic source-audio retention task
+    if _source_retention_task and not _source_retention_task.done():
+        _source_retention_task.cancel()
+        try:
+            await _source_retention_task
+        except asyncio.CancelledError:
+            logger.debug("Source-audio retention task cancelled")
+
+    # Cancel periodic database maintenance task
+    if _maintenance_task and not _maintenance_task.done():
+        _maintenance_task.cancel()
+        try:
+            await _maintenance_task
+        except asyncio.CancelledError:
+            logger.debug("Database maintenance task cancelled")
+
+    # Cancel mail polling
+    if _mail_task and not _mail_task.done():
+        _mail_task.cancel()
+        try:
+            await _mail_task
+        except asyncio.CancelledError:
+            logger.debug("Mail polling task cancelled")
+
     # Cancel periodic orphan sweep task
     if _orphan_sweep_task and not _orphan_sweep_task.done():
         _orphan_sweep_task.cancel()
@@ -777,6 +957,8 @@ async def lifespan(app: FastAPI) -> AsyncGenerator[None]:
             )
 
     cleanup_models()
+    # Last: session drains above may still have written to the library.
+    library_lock.release()
     logger.info("Shutdown complete")
 
 
@@ -812,6 +994,11 @@ def create_app(config_path: Path | None = None) -> FastAPI:
         allow_headers=["*"],
     )
 
+    # Inside the read-only and auth checks: only requests that ran are audited.
+    app.add_middleware(AuditMiddleware)
+
+    app.add_middleware(LibraryReadOnlyMiddleware)
+
     # Add origin validation middleware to enforce strict CORS policies
     app.add_middleware(OriginValidationMiddleware)
 
@@ -820,14 +1007,20 @@ def create_app(config_path: Path | None = None) -> FastAPI:
         app.add_middleware(AuthenticationMiddleware)
         logger.info("TLS mode enabled - authentication required for all routes")
 
+    # Outermost: gzip request bodies are decoded before anything reads them,
+    # and JSON responses are compressed after everything else is done.
+    app.add_middleware(ContentEncodingMiddleware)
+
     # Include API routers
     app.include_router(health.router, tags=["Health"])
     app.include_router(auth.router, prefix="/api/auth", tags=["Authentication"])
     app.include_router(transcription.router, prefix="/api/transcribe", tags=["Transcription"])
     app.include_router(notebook.router, prefix="/api/notebook", tags=["Audio Notebook"])
     app.include_router(profiles.router, prefix="/api/profiles", tags=["Profiles"])
+    app.include_router(projects.router, prefix="/api/projects", tags=["Projects"])
     app.include_router(search.router, prefix="/api/search", tags=["Search"])
     app.include_router(llm.router, prefix="/api/llm", tags=["LLM"])
+    app.include_router(mcp.router, prefix="/api/mcp", tags=["MCP"])
     app.include_router(admin.router, prefix="/api/admin", tags=["Admin"])
     app.include_router(openai_audio.router, prefix="/v1/audio", tags=["OpenAI Compatible"])
     app.include_router(websocket.router, tags=["WebSocket"])
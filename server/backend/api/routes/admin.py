@@ -5,23 +5,34 @@ Handles:
 - Server configuration
 - Log access
 - Model management
+- Library ownership (read-only mode on a shared library)
+- Per-user usage
+- Audit log of destructive actions
+- Model benchmark (realtime factor and WER on a reference clip)
+- Scheduled housekeeping (model cache prune, log rotation)
 """
 
 import asyncio
 import logging
 import os
+from pathlib import Path
 from typing import Any
 
 from fastapi import (
     APIRouter,
+    File,
+    Form,
     HTTPException,
     Query,
     Request,
+    UploadFile,
     WebSocket,
     WebSocketDisconnect,
 )
 from server.api.routes.utils import (
     authenticate_websocket_from_headers,
+    get_client_name,
+    receive_upload,
     require_admin,
 )
 from server.config import (
@@ -317,6 +328,247 @@ async def unload_models(request: Request) -> dict[str, str]:
         raise HTTPException(status_code=500, detail=str(e)) from e
 
 
+@router.get("/usage")
+async def get_usage(
+    request: Request,
+    days: int | None = Query(None, ge=1, le=3650, description="Only the last N days"),
+) -> dict[str, Any]:
+    """Transcription jobs per client and notebook recordings per owner (admin only)."""
+    if not require_admin(request):
+        raise HTTPException(status_code=403, detail="Admin access required")
+
+    from server.database.usage_report import usage_by_user
+
+    try:
+        return await asyncio.to_thread(usage_by_user, days)
+    except Exception as e:
+        logger.error(f"Failed to build usage report: {e}")
+        raise HTTPException(status_code=500, detail=str(e)) from e
+
+
+@router.get("/audit-log")
+async def get_audit_log(
+    request: Request,
+    start: str | None = Query(None, description="ISO date or datetime, inclusive"),
+    end: str | None = Query(None, description="ISO date (whole day) or datetime, inclusive"),
+    action: str | None = Query(None, description="Action, or prefix such as 'recording'"),
+    limit: int = Query(500, ge=1, le=5000, description="Newest events first"),
+) -> dict[str, Any]:
+    """Deletions, redactions, bulk edits and settings changes in a range (admin only)."""
+    if not require_admin(request):
+        raise HTTPException(status_code=403, detail="Admin access required")
+
+    from server.database import audit_repository
+
+    try:
+        events = await asyncio.to_thread(
+            audit_repository.get_audit_log, start, end, action=action, limit=limit
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=f"Invalid date range: {e}") from e
+    return {"events": events}
+
+
+@router.post("/benchmark")
+async def benchmark_models(
+    request: Request,
+    file: UploadFile = File(...),  # noqa: B008
+    reference: str = Form(..., description="Correct transcript of the clip"),
+    models: str = Form("", description="Comma-separated; empty benchmarks the main model"),
+    language: str | None = Form(None),
+) -> dict[str, Any]:
+    """Realtime factor and word error rate of models on a reference clip (admin only).
+
+    Holds the job slot for the whole run and returns 409 while a
+    transcription is running. Models other than the main one are swapped in
+    as for segment re-transcription; the main model reloads on the next job.
+    """
+    if not require_admin(request):
+        raise HTTPException(status_code=403, detail="Admin access required")
+
+    from server.core.audio_utils import get_audio_duration
+    from server.core.model_benchmark import (
+        MAX_MODELS,
+        BenchmarkSample,
+        normalize_words,
+        run_benchmark,
+    )
+
+    if not file.filename:
+        raise HTTPException(status_code=400, detail="No file provided")
+    if not normalize_words(reference):
+        raise HTTPException(status_code=400, detail="The reference transcript is empty")
+    model_manager = request.app.state.model_manager
+    names = list(dict.fromkeys(m.strip() for m in models.split(",") if m.strip()))
+    names = names or [model_manager.main_model_name]
+    if len(names) > MAX_MODELS:
+        raise HTTPException(
+            status_code=400, detail=f"At most {MAX_MODELS} models can be benchmarked at once"
+        )
+
+    def transcribe(model: str, path: Path) -> str:
+        if model_manager.is_same_model(model, model_manager.main_model_name):
+            engine = model_manager.ensure_transcription_loaded()
+        else:
+            engine = model_manager.alternate_transcription_engine(model)
+        result = engine.transcribe_file(str(path), language=language, word_timestamps=False)
+        return result.text
+
+    job_tracker = model_manager.job_tracker
+    success, job_id, active_user = job_tracker.try_start_job(get_client_name(request))
+    if not success:
+        raise HTTPException(
+            status_code=409,
+            detail=f"A transcription is already running for {active_user}",
+        )
+    tmp_path: Path | None = None
+    try:
+        tmp_path, _ = await receive_upload(request, file, None)
+        duration = await asyncio.to_thread(get_audio_duration, str(tmp_path))
+        sample = BenchmarkSample(audio_path=tmp_path, reference=reference, duration=duration)
+        runs = await asyncio.to_thread(run_benchmark, sample, names, transcribe=transcribe)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    finally:
+        job_tracker.end_job(job_id)
+        if tmp_path is not None:
+            tmp_path.unlink(missing_ok=True)
+    return {"duration": duration, "runs": [run.to_dict() for run in runs]}
+
+
+@router.post("/housekeeping/prune-model-cache")
+async def prune_model_cache(
+    request: Request,
+    unused_days: int = Query(30, ge=1, le=3650, description="Keep models used more recently"),
+    dry_run: bool = Query(False, description="Only report what would be removed"),
+) -> dict[str, Any]:
+    """Remove stale partial downloads and unused, unconfigured models (admin only)."""
+    if not require_admin(request):
+        raise HTTPException(status_code=403, detail="Admin access required")
+
+    from server.core.housekeeping import prune_model_cache as _prune
+
+    config = request.app.state.config
+    keep = {
+        resolve_main_transcriber_model(config),
+        resolve_live_transcriber_model(config),
+        config.get("diarization", "model", default=None),
+    }
+    try:
+        return await asyncio.to_thread(_prune, keep, unused_days, dry_run=dry_run)
+    except Exception as e:
+        logger.error(f"Failed to prune model cache: {e}")
+        raise HTTPException(status_code=500, detail=str(e)) from e
+
+
+@router.post("/housekeeping/rotate-logs")
+async def rotate_logs(request: Request) -> dict[str, Any]:
+    """Roll the server log over now (admin only)."""
+    if not require_admin(request):
+        raise HTTPException(status_code=403, detail="Admin access required")
+
+    from server.core.housekeeping import rotate_logs as _rotate
+
+    try:
+        return _rotate()
+    except Exception as e:
+        logger.error(f"Failed to rotate logs: {e}")
+        raise HTTPException(status_code=500, detail=str(e)) from e
+
+
+@router.get("/golden-set")
+async def get_golden_set() -> dict[str, Any]:
+    """Reference transcripts with their WER history and drift (core/golden_set.py)."""
+    from server.core.golden_set import drift_report
+    from server.database import golden_repository
+
+    references = await asyncio.to_thread(golden_repository.list_references)
+    runs = await asyncio.to_thread(golden_repository.list_runs)
+    return drift_report(references, runs)
+
+
+@router.post("/housekeeping/golden-check")
+async def golden_check(request: Request) -> dict[str, Any]:
+    """Re-transcribe the golden set with the main model and report drift (admin only).
+
+    Holds the job slot for the whole run and returns 409 while a
+    transcription is running.
+    """
+    if not require_admin(request):
+        raise HTTPException(status_code=403, detail="Admin access required")
+
+    from server.core.golden_set import drift_report, run_golden_check
+    from server.core.storage_encryption import call_with_plaintext
+    from server.database import golden_repository
+
+    references = await asyncio.to_thread(golden_repository.list_references)
+    model_manager = request.app.state.model_manager
+    runs: list[dict[str, Any]] = []
+    if references:
+
+        def transcribe(path: Path) -> str:
+            engine = model_manager.ensure_transcription_loaded()
+            return call_with_plaintext(
+                lambda readable: engine.transcribe_file(str(readable), word_timestamps=False).text,
+                path,
+            )
+
+        job_tracker = model_manager.job_tracker
+        success, job_id, active_user = job_tracker.try_start_job(get_client_name(request))
+        if not success:
+            raise HTTPException(
+                status_code=409,
+                detail=f"A transcription is already running for {active_user}",
+            )
+        try:
+            runs = await asyncio.to_thread(
+                run_golden_check,
+                references,
+                transcribe,
+                model=model_manager.main_model_name,
+                server_version=__version__,
+                record=golden_repository.add_run,
+            )
+        finally:
+            job_tracker.end_job(job_id)
+    history = await asyncio.to_thread(golden_repository.list_runs)
+    return {"runs": runs, **drift_report(references, history)}
+
+
+@router.get("/library/lock")
+async def get_library_lock() -> dict[str, Any]:
+    """Whether this server owns the library, and who does if it doesn't."""
+    from server.database import library_lock
+
+    return library_lock.status()
+
+
+@router.post("/library/take-ownership")
+async def take_library_ownership(request: Request) -> dict[str, Any]:
+    """Make this server the library's writer after the other machine is gone.
+
+    Only safe once the other machine has stopped (or will never sync again);
+    it drops to read-only on its next heartbeat if it is still running.
+    Background maintenance skipped at startup resumes on the next restart.
+    """
+    if not require_admin(request):
+        raise HTTPException(status_code=403, detail="Admin access required")
+
+    from server.database import library_lock
+    from server.database.database import init_db
+
+    if not library_lock.is_read_only():
+        return library_lock.status()
+    try:
+        await asyncio.to_thread(library_lock.take_ownership)
+        # Startup skipped migrations and the WAL pragma while read-only.
+        await asyncio.to_thread(init_db)
+    except (OSError, RuntimeError) as e:
+        logger.error(f"Failed to take library ownership: {e}")
+        raise HTTPException(status_code=500, detail=str(e)) from e
+    return library_lock.status()
+
+
 @router.get("/logs")
 async def get_logs(
     service: str | None = Query(None, description="Filter by service"),
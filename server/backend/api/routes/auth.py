@@ -3,17 +3,18 @@ Authentication API endpoints for TranscriptionSuite server.
 
 Handles:
 - Token-based login
-- Token management (admin only)
-- User information
+- Token management (admin only): create, rotate, revoke
+- Server users — the client names tokens are issued to (admin only)
+- User information and capabilities
 """
 
 import logging
 from typing import Any
 
 import server.core.token_store as _ts_mod
-from fastapi import APIRouter, HTTPException, Request
+from fastapi import APIRouter, HTTPException, Query, Request
 from pydantic import BaseModel
-from server.api.routes.utils import require_admin
+from server.api.routes.utils import get_client_name, get_permissions, require_admin
 
 logger = logging.getLogger(__name__)
 
@@ -76,6 +77,42 @@ async def login(request: LoginRequest) -> dict[str, Any]:
         }
 
 
+def _is_active(token: _ts_mod.StoredToken) -> bool:
+    return not token.is_revoked and not token.is_expired()
+
+
+def _leaves_no_admin(tokens: list[_ts_mod.StoredToken], revoking: set[str | None]) -> bool:
+    """True if revoking the token IDs in *revoking* would revoke the last active admin token."""
+    admins = {t.token_id for t in tokens if t.is_admin and _is_active(t)}
+    return bool(admins) and admins <= revoking
+
+
+def _token_payload(stored_token: _ts_mod.StoredToken, plaintext_token: str) -> dict[str, Any]:
+    return {
+        "token": plaintext_token,  # Only time this is shown!
+        "token_id": stored_token.token_id,
+        "client_name": stored_token.client_name,
+        "is_admin": stored_token.is_admin,
+        "created_at": stored_token.created_at,
+        "expires_at": stored_token.expires_at,
+    }
+
+
+@router.get("/permissions")
+async def get_caller_permissions(request: Request) -> dict[str, Any]:
+    """
+    What the caller's token may do, so clients can hide features it lacks.
+
+    The routes themselves still enforce every check; this is advisory.
+    """
+    permissions = get_permissions(request)
+    return {
+        "user": get_client_name(request),
+        "is_admin": all(permissions.values()),
+        "permissions": permissions,
+    }
+
+
 @router.get("/tokens")
 async def list_tokens(request: Request) -> dict[str, Any]:
     """
@@ -130,14 +167,7 @@ async def create_token(request: Request, body: CreateTokenRequest) -> dict[str,
         return {
             "success": True,
             "message": "Token created successfully",
-            "token": {
-                "token": plaintext_token,  # Only time this is shown!
-                "token_id": stored_token.token_id,
-                "client_name": stored_token.client_name,
-                "is_admin": stored_token.is_admin,
-                "created_at": stored_token.created_at,
-                "expires_at": stored_token.expires_at,
-            },
+            "token": _token_payload(stored_token, plaintext_token),
         }
 
     except Exception as e:
@@ -149,12 +179,17 @@ async def create_token(request: Request, body: CreateTokenRequest) -> dict[str,
 async def revoke_token(request: Request, token_id: str) -> dict[str, Any]:
     """
     Revoke a token by its ID (admin only).
+
+    Refuses (409) to revoke the last active admin token, which would lock
+    every admin out of the server.
     """
     if not require_admin(request):
         raise HTTPException(status_code=403, detail="Admin access required")
 
     try:
         token_store = _ts_mod.get_token_store()
+        if _leaves_no_admin(token_store.list_tokens(), {token_id}):
+            raise HTTPException(status_code=409, detail="Cannot revoke the last admin token")
         success = token_store.revoke_token_by_id(token_id)
 
         if not success:
@@ -167,3 +202,84 @@ async def revoke_token(request: Request, token_id: str) -> dict[str, Any]:
     except Exception as e:
         logger.error(f"Failed to revoke token: {e}")
         raise HTTPException(status_code=500, detail=str(e)) from e
+
+
+@router.post("/tokens/{token_id}/rotate")
+async def rotate_token(request: Request, token_id: str) -> dict[str, Any]:
+    """
+    Replace a token's secret (admin only).
+
+    The old token stops working immediately; the new one keeps its client
+    name, role and expiry date. Returns the new token (only shown once).
+    """
+    if not require_admin(request):
+        raise HTTPException(status_code=403, detail="Admin access required")
+
+    try:
+        rotated = _ts_mod.get_token_store().rotate_token_by_id(token_id)
+    except Exception as e:
+        logger.error(f"Failed to rotate token: {e}")
+        raise HTTPException(status_code=500, detail=str(e)) from e
+
+    if rotated is None:
+        raise HTTPException(status_code=404, detail="Active token not found")
+    return {"success": True, "token": _token_payload(*rotated)}
+
+
+@router.get("/users")
+async def list_users(request: Request) -> dict[str, Any]:
+    """
+    Server users — the client names tokens were issued to (admin only).
+    """
+    if not require_admin(request):
+        raise HTTPException(status_code=403, detail="Admin access required")
+
+    users: dict[str, dict[str, Any]] = {}
+    for t in _ts_mod.get_token_store().list_tokens():
+        user = users.setdefault(
+            t.client_name,
+            {
+                "name": t.client_name,
+                "is_admin": False,
+                "active_tokens": 0,
+                "tokens": 0,
+                "last_token_created_at": None,
+            },
+        )
+        user["tokens"] += 1
+        if _is_active(t):
+            user["active_tokens"] += 1
+            user["is_admin"] = user["is_admin"] or t.is_admin
+        if user["last_token_created_at"] is None or t.created_at > user["last_token_created_at"]:
+            user["last_token_created_at"] = t.created_at
+
+    return {"users": sorted(users.values(), key=lambda u: u["name"].lower())}
+
+
+@router.delete("/users/{client_name}")
+async def remove_user(
+    request: Request,
+    client_name: str,
+    confirm: str = Query(..., description="Must repeat the user name"),
+) -> dict[str, Any]:
+    """
+    Revoke every active token of a user (admin only).
+
+    ``confirm`` must repeat the user name, so a stray request cannot lock
+    someone out. Refuses (409) to remove the last active admin.
+    """
+    if not require_admin(request):
+        raise HTTPException(status_code=403, detail="Admin access required")
+    if confirm != client_name:
+        raise HTTPException(status_code=400, detail="confirm must repeat the user name")
+
+    token_store = _ts_mod.get_token_store()
+    tokens = token_store.list_tokens()
+    revoking = {t.token_id for t in tokens if t.client_name == client_name and _is_active(t)}
+    if not revoking:
+        raise HTTPException(status_code=404, detail="User has no active tokens")
+    if _leaves_no_admin(tokens, revoking):
+        raise HTTPException(status_code=409, detail="Cannot remove the last admin")
+
+    revoked = token_store.revoke_tokens_for_client(client_name)
+    return {"success": True, "revoked": revoked}
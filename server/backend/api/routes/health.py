@@ -6,7 +6,9 @@ from typing import Any
 
 from fastapi import APIRouter, Request
 from fastapi.responses import JSONResponse
+from server.api.content_encoding import SUPPORTED_REQUEST_ENCODINGS
 from server.api.routes.live import is_live_mode_active
+from server.database import library_lock
 
 from server import __version__
 
@@ -102,6 +104,12 @@ async def get_status(request: Request) -> dict[str, Any]:
     if gpu_available is not None:
         response["gpu_available"] = gpu_available
 
+    # Details (which machine holds it) live behind /api/admin/library/lock.
+    response["library_read_only"] = library_lock.is_read_only()
+
+    # Body encodings uploads may use (api/content_encoding.py).
+    response["request_encodings"] = list(SUPPORTED_REQUEST_ENCODINGS)
+
     gpu_error = getattr(request.app.state, "gpu_error", None)
     if gpu_error is not None:
         response["gpu_error"] = gpu_error.get("error", "Unknown GPU error")
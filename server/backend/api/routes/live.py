@@ -9,20 +9,39 @@ streams completed sentences as they are detected.
 Model Swapping: When Live Mode starts, the main transcription model
 is unloaded to free VRAM for the Live Mode model. When Live Mode
 stops, the main model is reloaded for normal transcription.
+
+Streaming mode: ``streaming_mode`` in the start config picks ``accurate``
+(finals only) or ``fast`` (greedy partials, quicker finals); a
+``set_streaming_mode`` message switches it while the session runs.
+
+Resumption: a session that loses its connection is parked rather than
+stopped, and a reconnecting client picks it up with its resume token
+(see server/backend/core/live_resume.py).
 """
 
 import asyncio
 import json
+import time
 from typing import Optional
 
 from fastapi import APIRouter, WebSocket, WebSocketDisconnect
 from server.api.routes.utils import authenticate_websocket_from_message
 from server.config import get_config, resolve_live_transcriber_model
+from server.core.accessibility import LiveAnnouncer, state_announcement
+from server.core.keyword_alerts import KeywordWatcher
 from server.core.live_engine import (
+    STREAMING_PROFILES,
     LiveModeConfig,
     LiveModeEngine,
     LiveModeState,
 )
+from server.core.live_resume import (
+    AudioSequence,
+    new_resume_token,
+    parse_audio_frame,
+    token_matches,
+)
+from server.core.live_segments import SegmentLog
 from server.core.model_manager import get_model_manager
 from server.core.stt.backends.factory import detect_backend_type
 from server.logging import get_logger
@@ -56,6 +75,26 @@ def is_live_mode_model_supported(model_name: str) -> bool:
     return detect_backend_type(name) in ("whisper", "whispercpp")
 
 
+def _resume_grace_seconds() -> float:
+    """How long a dropped session waits for its client (0 disables resumption)."""
+    grace = get_config().get("live_transcriber", "resume_grace_seconds", default=30)
+    try:
+        return max(0.0, float(grace))
+    except (TypeError, ValueError):
+        return 0.0
+
+
+def _make_announcer() -> LiveAnnouncer | None:
+    """Screen-reader announcer from ``live_transcriber`` config (None when disabled)."""
+    cfg = get_config()
+    if not cfg.get("live_transcriber", "announcements", default=True):
+        return None
+    return LiveAnnouncer(
+        min_interval=cfg.get("live_transcriber", "announce_interval", default=2.0),
+        max_chars=cfg.get("live_transcriber", "announce_max_chars", default=280),
+    )
+
+
 class LiveModeSession:
     """
     Manages a Live Mode WebSocket session.
@@ -79,6 +118,20 @@ class LiveModeSession:
         # Capture the event loop so engine callbacks (from background threads)
         # can safely enqueue messages via call_soon_threadsafe.
         self._loop = asyncio.get_running_loop()
+        self._announcer = _make_announcer()
+        # Watch keywords from the client's start config; `at` in keyword
+        # messages counts seconds from the engine start.
+        self._keywords = KeywordWatcher(())
+        self._started_at = time.monotonic()
+        # Set when the client asks for segment deltas instead of the
+        # `partial` / `sentence` stream (server/backend/core/live_segments.py).
+        self._segments: SegmentLog | None = None
+        # Resumption (server/backend/core/live_resume.py): the token a
+        # reconnecting client presents, the audio frames fed so far, and the
+        # timer that ends the session if nobody comes back while parked.
+        self._resume_token: str | None = None
+        self._audio = AudioSequence()
+        self._expiry: asyncio.Task | None = None
 
     async def send_message(self, msg_type: str, data: dict | None = None) -> None:
         """Send a JSON message to the client."""
@@ -116,6 +169,12 @@ class LiveModeSession:
     def _on_sentence(self, text: str) -> None:
         """Callback when a sentence is completed."""
         self._queue_message("sentence", {"text": text})
+        announcement = self._announcer.push(text) if self._announcer else None
+        if announcement:
+            self._queue_message("announce", announcement)
+        for hit in self._keywords.match(text):
+            at = round(time.monotonic() - self._started_at, 1)
+            self._queue_message("keyword", {**hit, "text": text, "at": at})
         # Fire outgoing webhook (thread-safe — this runs in engine's background thread)
         from server.core.webhook import dispatch_fire_and_forget
 
@@ -135,6 +194,9 @@ class LiveModeSession:
     def _on_state_change(self, state: LiveModeState) -> None:
         """Callback when engine state changes."""
         self._queue_message("state", {"state": state.name})
+        announcement = state_announcement(state.name) if self._announcer else None
+        if announcement:
+            self._queue_message("announce", announcement)
 
     async def start_engine(self, config_data: dict | None = None) -> bool:
         """
@@ -192,6 +254,13 @@ class LiveModeSession:
                     config.post_speech_silence_duration = float(
                         config_data["post_speech_silence_duration"]
                     )
+                if config_data.get("streaming_mode") in STREAMING_PROFILES:
+                    config.streaming_mode = config_data["streaming_mode"]
+            if (config_data or {}).get("segment_deltas"):
+                self._segments = SegmentLog()
+            watch = (config_data or {}).get("watch_keywords")
+            self._keywords = KeywordWatcher(watch if isinstance(watch, list) else ())
+            self._started_at = time.monotonic()
 
             if not config.model.strip():
                 await self.send_message(
@@ -367,6 +436,12 @@ class LiveModeSession:
                 self._running = True
                 _model_displaced = False  # Engine owns the model now
                 logger.info(f"Live Mode started for {self.client_name}")
+                grace = _resume_grace_seconds()
+                if grace > 0:
+                    self._resume_token = new_resume_token()
+                    await self.send_message(
+                        "session", {"token": self._resume_token, "resume_grace_seconds": grace}
+                    )
                 return True
             else:
                 await self.send_message("error", {"message": "Failed to start engine"})
@@ -479,6 +554,8 @@ class LiveModeSession:
         without a reload.  Otherwise the main model is fully reloaded.
         """
         self._running = False
+        # A stopped session cannot be resumed.
+        self._resume_token = None
         if self._engine:
             self._engine.stop()
             self._engine = None
@@ -505,6 +582,17 @@ class LiveModeSession:
 
         await self.send_message("state", {"state": "STOPPED"})
 
+    async def set_streaming_mode(self, mode: str) -> None:
+        """Switch a running session between ``fast`` and ``accurate`` streaming."""
+        if mode not in STREAMING_PROFILES:
+            await self.send_message("error", {"message": f"Unknown streaming mode: {mode}"})
+            return
+        if self._engine is None:
+            await self.send_message("error", {"message": "Live Mode is not running"})
+            return
+        self._engine.set_streaming_mode(mode)
+        await self.send_message("streaming_mode", {"mode": mode})
+
     async def get_history(self) -> list[str]:
         """Get transcription history."""
         if self._engine:
@@ -515,8 +603,89 @@ class LiveModeSession:
         """Clear transcription history."""
         if self._engine:
             self._engine.clear_history()
+        if self._segments:
+            self._segments.clear()
         await self.send_message("history_cleared", {})
 
+    async def send_segments(self, since_id: int = 0) -> None:
+        """Send the segments after ``since_id`` so a client can recover from a seq gap."""
+        if self._segments is None:
+            await self.send_message("error", {"message": "Segment deltas are not enabled"})
+            return
+        await self.send_message("segments", self._segments.snapshot(since_id))
+
+    async def _deliver(self, msg: dict) -> None:
+        """Send a queued engine message, as a segment delta when the client wants those."""
+        if self._segments is not None and msg["type"] in ("partial", "sentence"):
+            text = msg["data"].get("text", "")
+            if msg["type"] == "partial":
+                delta = self._segments.partial(text)
+            else:
+                delta = self._segments.finalize(text)
+            if delta:
+                await self.send_message("segment", delta)
+            return
+        await self.send_message(msg["type"], msg["data"])
+
+    async def receive_audio(self, frame: bytes) -> None:
+        """Feed one binary audio frame; replayed frames the engine has are skipped."""
+        if not (self._engine and self._engine.is_running):
+            return
+        parsed = parse_audio_frame(frame)
+        if parsed is None:
+            return
+        metadata, pcm_data = parsed
+        if self._audio.accept(metadata.get("seq")):
+            self._engine.feed_audio(pcm_data)
+        ack = self._audio.ack_due()
+        if ack is not None:
+            await self.send_message("audio_ack", {"seq": ack})
+
+    @property
+    def resumable(self) -> bool:
+        return self._resume_token is not None and bool(self._engine and self._engine.is_running)
+
+    @property
+    def parked(self) -> bool:
+        return self._expiry is not None
+
+    def park(self, grace: float) -> None:
+        """Keep the engine running without a client; cleanup follows after ``grace``."""
+        self._expiry = asyncio.create_task(self._expire(grace))
+        logger.info(f"Live Mode session for {self.client_name} parked for {grace:g}s")
+
+    async def _expire(self, grace: float) -> None:
+        await asyncio.sleep(grace)
+        async with _session_lock:
+            if _live_mode_state["active_session"] is not self:
+                return
+            _live_mode_state["active_session"] = None
+        self._expiry = None
+        logger.info(f"Live Mode session for {self.client_name} was not resumed; stopping")
+        await self.cleanup()
+
+    @property
+    def audio_seq(self) -> int:
+        """The last numbered audio frame the engine has had."""
+        return self._audio.last_seq
+
+    def try_resume(self, token: object, websocket: WebSocket) -> bool:
+        """Attach the session to ``websocket`` when ``token`` is its resume token."""
+        if not self.resumable or not token_matches(self._resume_token, token):
+            return False
+        if self._expiry is not None:
+            self._expiry.cancel()
+            self._expiry = None
+        self.websocket = websocket
+        return True
+
+    async def abandon(self) -> None:
+        """End a parked session its client will not come back to."""
+        if self._expiry is not None:
+            self._expiry.cancel()
+            self._expiry = None
+        await self.cleanup()
+
     async def cleanup(self) -> None:
         """Clean up session resources."""
         await self.stop_engine()
@@ -529,13 +698,18 @@ class LiveModeSession:
         while True:
             try:
                 msg = await asyncio.wait_for(self._message_queue.get(), timeout=0.1)
-                await self.send_message(msg["type"], msg["data"])
+                await self._deliver(msg)
             except TimeoutError:
                 # Check if we should exit - only exit when:
                 # 1. _running is False (engine stopped)
                 # 2. Queue is empty (no pending messages)
                 if not self._running and self._message_queue.empty():
                     break
+                # Sentences held back by the announce throttle go out once due.
+                if self._announcer and self._announcer.due_in() == 0:
+                    pending = self._announcer.flush()
+                    if pending:
+                        await self.send_message("announce", pending)
                 continue
             except Exception as e:
                 logger.error(f"Error processing message: {e}")
@@ -554,11 +728,23 @@ async def handle_client_message(session: LiveModeSession, message: dict) -> None
         # Stop Live Mode
         await session.stop_engine()
 
+    elif msg_type == "set_streaming_mode":
+        # Switch latency/accuracy trade-off mid-session
+        await session.set_streaming_mode(str(data.get("mode", "")))
+
     elif msg_type == "get_history":
         # Get transcription history
         history = await session.get_history()
         await session.send_message("history", {"sentences": history})
 
+    elif msg_type == "get_segments":
+        # Resend segments after a seq gap (segment-delta clients only)
+        try:
+            since_id = int(data.get("since_id", 0))
+        except (TypeError, ValueError):
+            since_id = 0
+        await session.send_segments(since_id)
+
     elif msg_type == "clear_history":
         # Clear history
         await session.clear_history()
@@ -572,6 +758,57 @@ async def handle_client_message(session: LiveModeSession, message: dict) -> None
         await session.send_message("error", {"message": f"Unknown message type: {msg_type}"})
 
 
+async def _resume_session(session: LiveModeSession, data: dict) -> LiveModeSession | None:
+    """Hand this connection to the session whose resume token ``data`` carries.
+
+    The session may still hold its old socket when the server has not noticed
+    the drop yet; that socket is closed so its loop lets go of the session.
+    """
+    async with _session_lock:
+        active = _live_mode_state["active_session"]
+        stale = active.websocket if active is not None else None
+        if (
+            active is None
+            or active is session
+            or active.client_name != session.client_name
+            or not active.try_resume(data.get("token"), session.websocket)
+        ):
+            active = None
+    if active is None:
+        await session.send_message(
+            "resume_failed", {"message": "The live session ended and cannot be resumed"}
+        )
+        return None
+    if stale is not None and stale is not active.websocket:
+        try:
+            await stale.close()
+        except Exception as close_error:
+            logger.debug("Failed to close the superseded Live Mode socket: %s", close_error)
+    logger.info(f"Live Mode session resumed for {active.client_name}")
+    await active.send_message("resumed", {"audio_seq": active.audio_seq})
+    return active
+
+
+async def _take_over(session: LiveModeSession) -> bool:
+    """Register ``session`` for a new start, ending a parked session it replaces."""
+    async with _session_lock:
+        previous = _live_mode_state["active_session"]
+        if previous is session:
+            return True
+        busy = previous is not None and not previous.parked
+        if not busy:
+            _live_mode_state["active_session"] = session
+    if busy:
+        await session.send_message(
+            "error", {"message": "Another Live Mode session is already active"}
+        )
+        return False
+    if previous is not None:
+        logger.info(f"Live Mode: {session.client_name} started over; ending the parked session")
+        await previous.abandon()
+    return True
+
+
 @router.websocket("/ws/live")
 async def live_mode_endpoint(websocket: WebSocket) -> None:
     """WebSocket endpoint for Live Mode transcription."""
@@ -597,9 +834,12 @@ async def live_mode_endpoint(websocket: WebSocket) -> None:
 
         client_name = auth.client_name
 
-        # Check if another session is active
+        # Check if another session is active. A resumable session of the
+        # same client does not block: this connection may be its client
+        # coming back (`resume`) or the user starting over (`start`).
         async with _session_lock:
-            if _live_mode_state["active_session"] is not None:
+            active = _live_mode_state["active_session"]
+            if active is not None and not (active.resumable and active.client_name == client_name):
                 await websocket.send_json(
                     {
                         "type": "error",
@@ -615,7 +855,8 @@ async def live_mode_endpoint(websocket: WebSocket) -> None:
                 websocket=websocket,
                 client_name=client_name,
             )
-            _live_mode_state["active_session"] = session
+            if active is None:
+                _live_mode_state["active_session"] = session
 
         # Send auth success
         await session.send_message("auth_ok", {"client_name": client_name})
@@ -634,26 +875,29 @@ async def live_mode_endpoint(websocket: WebSocket) -> None:
                     break
 
                 # Handle binary audio data
+                # Same format as the /ws endpoint:
+                # [4 bytes metadata length][metadata JSON][PCM Int16 data]
                 if "bytes" in message:
-                    audio_data = message["bytes"]
-                    if session and session._engine and session._engine.is_running:
-                        # Parse audio format (same as /ws endpoint):
-                        # [4 bytes metadata length][metadata JSON][PCM Int16 data]
-                        if len(audio_data) > 4:
-                            import struct
-
-                            metadata_len = struct.unpack("<I", audio_data[:4])[0]
-                            if len(audio_data) >= 4 + metadata_len:
-                                pcm_data = audio_data[4 + metadata_len :]
-                                session._engine.feed_audio(pcm_data)
+                    await session.receive_audio(message["bytes"])
                     continue
 
                 if "text" in message:
                     try:
                         msg_data = json.loads(message["text"])
-                        await handle_client_message(session, msg_data)
                     except json.JSONDecodeError as e:
                         logger.warning(f"Invalid JSON message: {e}")
+                        continue
+                    msg_type = msg_data.get("type")
+                    if msg_type == "resume":
+                        resumed = await _resume_session(session, msg_data.get("data") or {})
+                        if resumed is not None:
+                            session = resumed
+                            message_task.cancel()
+                            message_task = asyncio.create_task(session.process_messages())
+                        continue
+                    if msg_type == "start" and not await _take_over(session):
+                        continue
+                    await handle_client_message(session, msg_data)
         finally:
             # Cancel message processing task
             message_task.cancel()
@@ -677,10 +921,18 @@ async def live_mode_endpoint(websocket: WebSocket) -> None:
             logger.debug("Failed to close Live Mode websocket after error: %s", close_error)
 
     finally:
-        # Clean up session
+        # Clean up session — or park it, when the client can still resume
         if session:
-            await session.cleanup()
+            grace = _resume_grace_seconds()
             async with _session_lock:
-                if _live_mode_state["active_session"] is session:
+                registered = _live_mode_state["active_session"] is session
+                # Resumed on another connection meanwhile: that one owns it now.
+                moved = session.websocket is not websocket
+                park = registered and not moved and session.resumable and grace > 0
+                if registered and not moved and not park:
                     _live_mode_state["active_session"] = None
-            logger.info(f"Live Mode session ended for {session.client_name}")
+            if park:
+                session.park(grace)
+            elif not moved:
+                await session.cleanup()
+                logger.info(f"Live Mode session ended for {session.client_name}")
@@ -0,0 +1,274 @@
+"""
+Model Context Protocol endpoint for TranscriptionSuite server.
+
+Lets an AI assistant (Claude Desktop, an IDE agent, …) query the Audio
+Notebook library through four read-only tools: ``search_transcripts``,
+``list_recordings``, ``get_transcript`` and ``summarize_range``. Off until
+``mcp.enabled`` is set in config.yaml; it then sits behind the same token
+auth as the rest of the API.
+
+Transport is MCP's Streamable HTTP in its simplest form: every JSON-RPC
+POST gets a single JSON reply, and there is no server-initiated stream.
+Clients that only speak stdio use the bridge in
+``server.core.mcp_stdio``.
+"""
+
+import logging
+from typing import Any
+
+from fastapi import APIRouter, HTTPException, Request
+from fastapi.responses import JSONResponse, Response
+from server import __version__
+from server.core.mcp_protocol import (
+    PARSE_ERROR,
+    McpServer,
+    McpTool,
+    ToolError,
+    float_arg,
+    int_arg,
+    jsonrpc_error,
+    str_arg,
+)
+from server.database.database import (
+    get_all_recordings,
+    get_recording,
+    get_recordings_by_date_range,
+    get_segments,
+    get_words,
+)
+
+logger = logging.getLogger(__name__)
+
+router = APIRouter()
+
+# get_transcript output beyond this is cut, with a note to ask for a range.
+MAX_TRANSCRIPT_CHARS = 200_000
+
+INSTRUCTIONS = (
+    "Tools over the user's local transcription library. Find recordings with "
+    "search_transcripts or list_recordings, then read them with get_transcript. "
+    "Times are seconds from the start of a recording; transcript lines carry "
+    "[h:mm:ss] stamps you can pass back as start/end."
+)
+
+_DATE_SCHEMA = {"type": "string", "description": "YYYY-MM-DD", "pattern": r"^\d{4}-\d{2}-\d{2}$"}
+_RANGE_SCHEMA = {
+    "recording_id": {"type": "integer", "description": "From search_transcripts/list_recordings"},
+    "start": {"type": "number", "description": "Range start, seconds (default: beginning)"},
+    "end": {"type": "number", "description": "Range end, seconds (default: end)"},
+}
+
+
+def mcp_enabled() -> bool:
+    from server.config import get_config
+
+    return bool(get_config().get("mcp", "enabled", default=False))
+
+
+def _clock(seconds: float) -> str:
+    total = int(seconds)
+    return f"{total // 3600}:{total // 60 % 60:02d}:{total % 60:02d}"
+
+
+def _recording_summary(row: dict[str, Any]) -> dict[str, Any]:
+    return {
+        "recording_id": row.get("id"),
+        "title": row.get("title") or row.get("filename"),
+        "recorded_at": row.get("recorded_at"),
+        "duration_seconds": row.get("duration_seconds"),
+        "has_summary": bool(row.get("summary")),
+    }
+
+
+def _range_lines(arguments: dict[str, Any]) -> tuple[dict[str, Any], list[str]]:
+    """The recording and its transcript lines overlapping [start, end], with
+    redacted ranges masked as in exports."""
+    recording_id = int_arg(arguments, "recording_id")
+    if recording_id is None:
+        raise ToolError('"recording_id" is required')
+    recording = get_recording(recording_id)
+    if recording is None:
+        raise ToolError(f"No recording with id {recording_id}")
+    start = float_arg(arguments, "start") or 0.0
+    end = float_arg(arguments, "end")
+    if end is not None and end <= start:
+        raise ToolError('"end" must be after "start"')
+
+    from server.core.alias_substitution import apply_aliases
+    from server.core.redaction import redact_transcript
+    from server.database import alias_repository, redaction_repository
+
+    redacted, _ = redact_transcript(
+        get_segments(recording_id),
+        get_words(recording_id),
+        redaction_repository.list_redactions(recording_id),
+    )
+    segments = [
+        s for s in redacted if s["end_time"] > start and (end is None or s["start_time"] < end)
+    ]
+    lines = []
+    for seg in apply_aliases(segments, alias_repository.alias_map(recording_id)):
+        speaker = f"{seg['speaker']}: " if seg.get("speaker") else ""
+        lines.append(f"[{_clock(seg['start_time'])}] {speaker}{str(seg['text']).strip()}")
+    return recording, lines
+
+
+async def search_transcripts(arguments: dict[str, Any]) -> dict[str, Any]:
+    from server.api.routes.search import unified_search
+
+    query = str_arg(arguments, "query", required=True)
+    try:
+        found = await unified_search(
+            q=query,
+            fuzzy=False,
+            start_date=str_arg(arguments, "start_date"),
+            end_date=str_arg(arguments, "end_date"),
+            limit=int_arg(arguments, "limit", default=20, minimum=1, maximum=100),
+            metadata=[],
+        )
+    except HTTPException as exc:
+        raise ToolError(f"Search failed: {exc.detail}") from exc
+    matches = [
+        {
+            "recording_id": r["recording_id"],
+            "title": r.get("title") or r.get("filename"),
+            "recorded_at": r.get("recorded_at"),
+            "match_type": r["match_type"],
+            "start": r.get("start_time"),
+            "speaker": r.get("speaker"),
+            "context": r.get("context"),
+        }
+        for r in found["results"]
+    ]
+    return {"query": query, "matches": matches}
+
+
+async def list_recordings(arguments: dict[str, Any]) -> dict[str, Any]:
+    start_date = str_arg(arguments, "start_date")
+    end_date = str_arg(arguments, "end_date")
+    limit = int_arg(arguments, "limit", default=50, minimum=1, maximum=500)
+    if start_date or end_date:
+        rows = get_recordings_by_date_range(start_date or "0001-01-01", end_date or "9999-12-31")
+    else:
+        rows = get_all_recordings()
+    return {"recordings": [_recording_summary(r) for r in rows[:limit]], "total": len(rows)}
+
+
+async def get_transcript(arguments: dict[str, Any]) -> str:
+    recording, lines = _range_lines(arguments)
+    header = f"# {recording.get('title') or recording.get('filename')}\n"
+    header += f"Recorded {recording.get('recorded_at')}, "
+    header += f"{_clock(recording.get('duration_seconds') or 0)} long\n\n"
+    text = "\n".join(lines) or "(no speech in this range)"
+    if len(text) > MAX_TRANSCRIPT_CHARS:
+        cut = text.rfind("\n", 0, MAX_TRANSCRIPT_CHARS)
+        text = text[: cut if cut > 0 else MAX_TRANSCRIPT_CHARS]
+        text += "\n\n[Transcript truncated — ask for a later range with start/end.]"
+    return header + text
+
+
+async def summarize_range(arguments: dict[str, Any]) -> str:
+    from server.api.routes.llm import LLMRequest, get_llm_config, process_with_llm
+
+    recording, lines = _range_lines(arguments)
+    if not lines:
+        raise ToolError("There is no speech in that range to summarize")
+    if not get_llm_config()["enabled"]:
+        raise ToolError("The server's LLM integration is disabled (local_llm in config.yaml)")
+    try:
+        response = await process_with_llm(
+            LLMRequest(
+                transcription_text="\n".join(lines),
+                user_prompt=str_arg(arguments, "prompt"),
+            )
+        )
+    except HTTPException as exc:
+        raise ToolError(f"Summarizing failed: {exc.detail}") from exc
+    return response.response
+
+
+TOOLS = [
+    McpTool(
+        name="search_transcripts",
+        description=(
+            "Full-text search of every transcript, plus titles, summaries and custom "
+            "fields. Returns matching recordings with the time and context of each hit."
+        ),
+        input_schema={
+            "type": "object",
+            "properties": {
+                "query": {"type": "string", "description": "Words or phrase to find"},
+                "start_date": _DATE_SCHEMA,
+                "end_date": _DATE_SCHEMA,
+                "limit": {"type": "integer", "minimum": 1, "maximum": 100, "default": 20},
+            },
+            "required": ["query"],
+        },
+        handler=search_transcripts,
+    ),
+    McpTool(
+        name="list_recordings",
+        description="List recordings, newest first, optionally within a date range.",
+        input_schema={
+            "type": "object",
+            "properties": {
+                "start_date": _DATE_SCHEMA,
+                "end_date": _DATE_SCHEMA,
+                "limit": {"type": "integer", "minimum": 1, "maximum": 500, "default": 50},
+            },
+        },
+        handler=list_recordings,
+    ),
+    McpTool(
+        name="get_transcript",
+        description=(
+            "The transcript of one recording, or of a time range of it, as "
+            "[h:mm:ss] Speaker: text lines with the user's speaker names."
+        ),
+        input_schema={
+            "type": "object",
+            "properties": _RANGE_SCHEMA,
+            "required": ["recording_id"],
+        },
+        handler=get_transcript,
+    ),
+    McpTool(
+        name="summarize_range",
+        description=(
+            "Summarize a recording, or a time range of it, with the server's own "
+            "LLM. The summary is returned, not saved to the recording."
+        ),
+        input_schema={
+            "type": "object",
+            "properties": {
+                **_RANGE_SCHEMA,
+                "prompt": {"type": "string", "description": "Instructions for the summary"},
+            },
+            "required": ["recording_id"],
+        },
+        handler=summarize_range,
+    ),
+]
+
+mcp_server = McpServer(TOOLS, version=__version__, instructions=INSTRUCTIONS)
+
+
+@router.post("")
+async def mcp_message(request: Request) -> Response:
+    """One JSON-RPC message or batch in, the replies out (202 for notifications only)."""
+    if not mcp_enabled():
+        raise HTTPException(status_code=404, detail="MCP is disabled (mcp.enabled in config.yaml)")
+    try:
+        message = await request.json()
+    except ValueError:
+        return JSONResponse(jsonrpc_error(None, PARSE_ERROR, "Parse error"), status_code=400)
+    reply = await mcp_server.handle(message)
+    if reply is None:
+        return Response(status_code=202)
+    return JSONResponse(reply)
+
+
+@router.get("")
+async def mcp_stream() -> Response:
+    """No server-initiated stream: the spec's answer is 405."""
+    return Response(status_code=405, headers={"Allow": "POST"})
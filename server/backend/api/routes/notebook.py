@@ -8,11 +8,13 @@ Handles:
 """
 
 import asyncio
+import json
 import logging
 import os
 import re
 import shutil
 import tempfile
+from collections.abc import AsyncIterator, Iterator
 from datetime import datetime
 from pathlib import Path
 from typing import Annotated, Any
@@ -31,10 +33,39 @@ from fastapi import (
 )
 from fastapi.responses import FileResponse, Response, StreamingResponse
 from pydantic import BaseModel, field_validator
-from server.api.routes.utils import get_client_name, sanitize_for_log
+from server.api.routes.utils import (
+    check_upload_source,
+    get_client_name,
+    get_requester,
+    get_user_name,
+    parse_metadata_filters,
+    receive_upload,
+    require_admin,
+    sanitize_for_log,
+    save_upload,
+)
+from server.api.routes.transcription import DedupMatch, fingerprint_dedup_matches
 from server.config import get_config, resolve_parallel_diarization_default
+from server.core.audio_fingerprint import (
+    compute_fingerprint,
+    fingerprint_seconds,
+    pack_fingerprint,
+    shift_segments,
+)
+from server.core.diarization_confidence import LOW_CONFIDENCE_THRESHOLD
+from server.core.media_previews import get_media_previews, link_for_preview
+from server.core.storage_encryption import (
+    StorageEncryptionError,
+    call_with_plaintext,
+    encrypt_at_rest,
+    is_encrypted,
+    iter_plaintext,
+    plaintext_size,
+    read_plaintext,
+)
 from server.core.stt.backends.factory import detect_backend_type
 from server.core.subtitle_export import _to_float, build_subtitle_cues, render_ass, render_srt
+from server.database import metadata_repository
 from server.database.backup import DatabaseBackupManager
 
 # NOTE: audio_utils is imported lazily inside upload_and_transcribe() to avoid
@@ -50,9 +81,13 @@ from server.database.database import (
     get_time_slot_info,
     get_words,
     save_longform_to_database,
+    set_recording_partial,
+    set_segment_review_state,
     update_recording_corrected_transcript,
     update_recording_date,
+    update_recording_keep_audio,
     update_recording_summary,
+    update_recording_timecode_settings,
     update_recording_title,
 )
 
@@ -120,6 +155,19 @@ class RecordingResponse(BaseModel):
     auto_export_status: str | None = None
     auto_export_error: str | None = None
     auto_export_path: str | None = None
+    # Source-audio retention (migration 023): "keep forever" override, and
+    # when the policy deleted or archived the original audio (None = present).
+    keep_audio: bool = False
+    audio_retired_at: str | None = None
+    # Per-user namespace (migration 025): the X-TranscriptionSuite-User name
+    # of the client that created it; None = shared between all users.
+    owner: str | None = None
+    # User-defined fields (migration 027), e.g. {"Client": "Acme"}.
+    metadata: dict[str, str] = {}
+    # Interrupted transcription (migration 029): seconds of audio the saved
+    # transcript covers and why it stopped; None = complete.
+    partial_until: float | None = None
+    partial_reason: str | None = None
 
 
 class RecordingDetailResponse(RecordingResponse):
@@ -156,6 +204,12 @@ class TitleUpdate(BaseModel):
     title: str
 
 
+class KeepAudioUpdate(BaseModel):
+    """Request body for a recording's "keep audio forever" retention override."""
+
+    keep_audio: bool
+
+
 class DateUpdate(BaseModel):
     """Request body for updating a recording's recorded_at date."""
 
@@ -186,6 +240,208 @@ class DiarizationConfidenceResponse(BaseModel):
     turns: list[TurnConfidence]
 
 
+class LowConfidenceRegion(BaseModel):
+    """A run of consecutive words scoring under the review threshold."""
+
+    start: float
+    end: float
+    text: str
+    word_ids: list[int]
+    min_confidence: float
+    mean_confidence: float
+    segment_id: int | None = None
+
+
+class LowConfidenceResponse(BaseModel):
+    """Response shape for ``GET /recordings/{id}/low-confidence``.
+
+    ``coverage`` is the fraction of words with a score; 0.0 means the
+    backend reported none, so an empty ``regions`` list proves nothing.
+    """
+
+    recording_id: int
+    threshold: float
+    coverage: float
+    regions: list[LowConfidenceRegion]
+
+
+class SegmentReviewItem(BaseModel):
+    """QA review state of one segment; ``state`` None means unreviewed."""
+
+    id: int
+    segment_index: int
+    state: str | None = None
+    reviewed_at: str | None = None
+
+
+class SegmentReviewResponse(BaseModel):
+    """GET / PUT response shape for ``/recordings/{id}/segment-review``."""
+
+    recording_id: int
+    segments: list[SegmentReviewItem]
+    progress: dict[str, Any]
+
+
+class SegmentReviewUpdate(BaseModel):
+    """PUT body — set ``state`` on every listed segment (None / "unreviewed" clears)."""
+
+    segment_ids: list[int]
+    state: str | None = None
+
+
+class AnnotationCreate(BaseModel):
+    """POST body — a new note, or a reply when ``parent_id`` is set.
+
+    Anchor with ``segment_id`` (times default to the segment's span) and/or
+    ``start_time`` / ``end_time``. ``author`` defaults to the client name.
+    """
+
+    body: str
+    start_time: float | None = None
+    end_time: float | None = None
+    segment_id: int | None = None
+    parent_id: int | None = None
+    author: str | None = None
+
+
+class AnnotationUpdate(BaseModel):
+    """PATCH body — edit a note's text."""
+
+    body: str
+
+
+class AnnotationItem(BaseModel):
+    """One stored note; ``parent_id`` is the thread root for replies."""
+
+    id: int
+    recording_id: int
+    segment_id: int | None = None
+    parent_id: int | None = None
+    start_time: float
+    end_time: float | None = None
+    author: str
+    body: str
+    created_at: str
+    updated_at: str
+
+
+class AnnotationThread(AnnotationItem):
+    """A thread root with its replies, oldest first."""
+
+    replies: list[AnnotationItem] = []
+
+
+class AnnotationsResponse(BaseModel):
+    """GET response — threads ordered by start time."""
+
+    recording_id: int
+    threads: list[AnnotationThread]
+
+
+class RedactionCreate(BaseModel):
+    """POST body — redact ``word_ids`` (their combined span) or a time range."""
+
+    start_time: float | None = None
+    end_time: float | None = None
+    word_ids: list[int] | None = None
+    label: str | None = None
+
+
+class RedactionItem(BaseModel):
+    """One redacted range; ``text`` is the words it covered when marked."""
+
+    id: int
+    recording_id: int
+    start_time: float
+    end_time: float
+    label: str | None = None
+    source: str
+    text: str | None = None
+    created_at: str
+
+
+class RedactionsResponse(BaseModel):
+    """GET response — ranges ordered by start time."""
+
+    recording_id: int
+    redactions: list[RedactionItem]
+
+
+class ChapterItem(BaseModel):
+    """One chapter of the outline; ``source`` is 'auto' or 'llm' (title origin)."""
+
+    id: int
+    recording_id: int
+    start_time: float
+    end_time: float
+    title: str
+    source: str
+    created_at: str
+
+
+class OutlineResponse(BaseModel):
+    """GET/POST response — chapters ordered by start time; empty for short recordings."""
+
+    recording_id: int
+    chapters: list[ChapterItem]
+
+
+class OutlineRebuild(BaseModel):
+    """POST body — ``use_llm`` overrides ``chaptering.llm_titles`` for this run."""
+
+    use_llm: bool | None = None
+
+
+class SpeakerStatsItem(BaseModel):
+    speaker: str
+    label: str
+    talk_seconds: float
+    share: float
+    words: int
+    words_per_minute: float
+    turns: int
+    longest_monologue_seconds: float
+    longest_monologue_start: float
+    interruptions: int
+    interrupted: int
+
+
+class SpeakerStatsResponse(BaseModel):
+    """Per-speaker talk time, pace and interruptions (see core/speaker_stats.py)."""
+
+    recording_id: int
+    speakers: list[SpeakerStatsItem]
+    total_talk_seconds: float
+    duration_seconds: float
+
+
+class TranscriptDiffSide(BaseModel):
+    text: str
+    start: float | None = None
+    end: float | None = None
+
+
+class TranscriptDiffHunk(BaseModel):
+    op: str  # equal | replace | delete | insert
+    a: TranscriptDiffSide
+    b: TranscriptDiffSide
+
+
+class TranscriptDiffResponse(BaseModel):
+    """Word alignment of two transcripts, A as reference (see core/transcript_diff.py)."""
+
+    recording_a: int
+    recording_b: int
+    wer: float
+    words_a: int
+    words_b: int
+    matches: int
+    substitutions: int
+    deletions: int
+    insertions: int
+    hunks: list[TranscriptDiffHunk]
+
+
 class DiarizationReviewState(BaseModel):
     """ADR-009 lifecycle state for a recording (Story 5.6 / 5.7)."""
 
@@ -221,21 +477,58 @@ class AliasesResponse(BaseModel):
     aliases: list[AliasItem]
 
 
+class VoiceProfileItem(BaseModel):
+    """One enrolled speaker (embedding omitted)."""
+
+    id: int
+    name: str
+    sample_count: int
+    created_at: str
+    updated_at: str
+
+
+class VoiceProfilesResponse(BaseModel):
+    """``enabled`` is false when no speaker-embedding model is configured."""
+
+    enabled: bool
+    profiles: list[VoiceProfileItem]
+
+
+class VoiceProfileRename(BaseModel):
+    name: str
+
+
+class SpeakerIdentificationResponse(BaseModel):
+    """Aliases added by the match, plus the recording's full alias set."""
+
+    recording_id: int
+    matched: dict[str, str]
+    aliases: list[AliasItem]
+
+
 @router.get("/recordings", response_model=list[RecordingResponse])
 async def list_recordings(
     start_date: str | None = Query(None, description="Start date (YYYY-MM-DD)"),
     end_date: str | None = Query(None, description="End date (YYYY-MM-DD)"),
+    metadata: list[str] = Query(  # noqa: B008
+        [], description="field=value, repeatable; all must match (case-insensitive)"
+    ),
 ) -> list[dict[str, Any]]:
     """
-    List all recordings, optionally filtered by date range.
+    List all recordings, optionally filtered by date range and metadata fields.
     """
+    filters = parse_metadata_filters(metadata)
     try:
         if start_date and end_date:
             recordings = get_recordings_by_date_range(start_date, end_date)
         else:
             recordings = get_all_recordings()
 
-        return recordings
+        if filters:
+            matching = metadata_repository.filter_recording_ids(filters)
+            recordings = [r for r in recordings if r["id"] in matching]
+        fields = metadata_repository.get_metadata_for(r["id"] for r in recordings)
+        return [{**r, "metadata": fields.get(r["id"], {})} for r in recordings]
 
     except Exception as e:
         logger.error(f"Failed to list recordings: {e}")
@@ -286,6 +579,7 @@ async def get_recording_detail(recording_id: int) -> dict[str, Any]:
 
     return {
         **recording,
+        "metadata": metadata_repository.get_metadata(recording_id),
         "segments": segments,
         "words": words,
         "webhook_status": webhook_status,
@@ -339,6 +633,7 @@ async def remove_recording(
     except Exception as e:
         logger.warning(f"Orphan file cleanup needed for {audio_path}: {e}")
         artifact_failures.append(str(audio_path))
+    get_media_previews().discard(audio_path)
 
     # 3. (Story 3.7 AC3) Opt-in delete of on-disk transcript/summary
     # export artifacts. Best-effort — surface failures via artifact_failures
@@ -479,1240 +774,3401 @@ async def update_date_patch(
         raise HTTPException(status_code=500, detail="Failed to update date")
 
 
+@router.patch("/recordings/{recording_id}/keep-audio")
+async def update_keep_audio_patch(
+    recording_id: int,
+    body: KeepAudioUpdate,
+) -> dict[str, Any]:
+    """Exempt a recording from (or return it to) the source-audio retention policy."""
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+
+    if update_recording_keep_audio(recording_id, body.keep_audio):
+        return {"status": "updated", "id": recording_id, "keep_audio": body.keep_audio}
+    else:
+        raise HTTPException(status_code=500, detail="Failed to update keep_audio")
+
+
 # ---------------------------------------------------------------------------
-# Speaker aliases (Issue #104, Story 4.2)
+# Custom metadata fields (case number, client, interviewee…)
 # ---------------------------------------------------------------------------
-# Mounted on the notebook router rather than a top-level /api/recordings
-# router because notebook recordings are the only entity that owns
-# speaker labels in this codebase. URL-prefix override is documented in
-# `_bmad-output/implementation-artifacts/sprint-3-design.md` §1.
 
 
-@router.get(
-    "/recordings/{recording_id}/diarization-review",
-    response_model=DiarizationReviewState,
-)
-async def get_diarization_review_state(
-    recording_id: int,
-) -> DiarizationReviewState:
-    """Return the current ADR-009 lifecycle state (Issue #104, Story 5.7).
+class MetadataPayload(BaseModel):
+    """Full replacement of a recording's fields; blank values remove a field."""
 
-    Returns ``status: null`` (no row) when no review has been triggered
-    for this recording — the dashboard treats null as "no banner".
-    """
+    metadata: dict[str, str]
+
+
+class MetadataResponse(BaseModel):
+    recording_id: int
+    metadata: dict[str, str]
+
+
+class MetadataFieldItem(BaseModel):
+    field: str
+    # Recordings carrying the field, and its most common values.
+    count: int
+    values: list[str]
+
+
+@router.get("/metadata/fields", response_model=list[MetadataFieldItem])
+async def list_metadata_fields() -> list[dict[str, Any]]:
+    """Every field in use across the library, for filter controls."""
+    return metadata_repository.list_fields()
+
+
+@router.get("/recordings/{recording_id}/metadata", response_model=MetadataResponse)
+async def get_recording_metadata(recording_id: int) -> MetadataResponse:
     if not get_recording(recording_id):
         raise HTTPException(status_code=404, detail="Recording not found")
-    from server.database import diarization_review_repository as repo
-
-    row = repo.get_review(recording_id)
-    return DiarizationReviewState(
-        recording_id=recording_id,
-        status=row["status"] if row else None,
-        reviewed_turns_json=row["reviewed_turns_json"] if row else None,
+    return MetadataResponse(
+        recording_id=recording_id, metadata=metadata_repository.get_metadata(recording_id)
     )
 
 
-@router.post(
-    "/recordings/{recording_id}/diarization-review",
-    response_model=DiarizationReviewState,
-)
-async def submit_diarization_review(
+@router.put("/recordings/{recording_id}/metadata", response_model=MetadataResponse)
+async def update_recording_metadata(
     recording_id: int,
-    payload: DiarizationReviewSubmit,
-) -> DiarizationReviewState:
-    """Lifecycle trigger endpoint (Stories 5.7 / 5.9).
-
-    Actions:
-      - ``open``     — pending → in_review (banner CTA invokes this)
-      - ``complete`` — in_review → completed; persists ``reviewed_turns_json``;
-                       Sprint 4 Story 6.2 calls ``on_auto_summary_fired()``
-                       to flip to ``released``
-
-    Persist-Before-Deliver (NFR16): each lifecycle trigger commits before
-    returning, so the response reflects committed state.
-    """
+    payload: MetadataPayload,
+) -> MetadataResponse:
+    """Replace the recording's fields and echo back what was stored (trimmed)."""
     if not get_recording(recording_id):
         raise HTTPException(status_code=404, detail="Recording not found")
+    try:
+        stored = metadata_repository.set_metadata(recording_id, payload.metadata)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    return MetadataResponse(recording_id=recording_id, metadata=stored)
 
-    import json
 
-    from server.core.diarization_review_lifecycle import (
-        IllegalReviewTransitionError,
-        on_review_view_opened,
-        on_run_summary_now_clicked,
-    )
-    from server.database import diarization_review_repository as repo
+# ---------------------------------------------------------------------------
+# Timecode settings (video-editor workflows)
+# ---------------------------------------------------------------------------
 
-    try:
-        if payload.action == "open":
-            on_review_view_opened(recording_id)
-        elif payload.action == "complete":
-            # Lifecycle transition FIRST — illegal-transition failure
-            # must leave reviewed_turns_json untouched. Writing the JSON
-            # before the transition would produce orphan data when the
-            # transition raises (e.g. someone raced and the row is no
-            # longer in_review). After the transition succeeds, the
-            # JSON write commits separately but is functionally a follow-up
-            # decoration of an already-completed row.
-            on_run_summary_now_clicked(recording_id)
-            if payload.reviewed_turns is not None:
-                repo.update_reviewed_turns(
-                    recording_id,
-                    json.dumps(payload.reviewed_turns, ensure_ascii=False, sort_keys=True),
-                )
-        else:
-            raise HTTPException(
-                status_code=400,
-                detail=f"Invalid action: {payload.action!r}. Expected 'open' or 'complete'.",
-            )
-    except IllegalReviewTransitionError as exc:
-        raise HTTPException(status_code=409, detail=str(exc)) from exc
 
-    row = repo.get_review(recording_id)
-    return DiarizationReviewState(
+class TimecodePayload(BaseModel):
+    """Per-recording SMPTE settings — see ``server.core.timecode``."""
+
+    start_timecode: str = "00:00:00:00"
+    fps: float = 25.0
+    drop_frame: bool = False
+
+
+class TimecodeResponse(BaseModel):
+    recording_id: int
+    # ``None`` = no timecode; exports and the viewer use zero-based times.
+    timecode: TimecodePayload | None = None
+
+
+@router.get("/recordings/{recording_id}/timecode", response_model=TimecodeResponse)
+async def get_recording_timecode(recording_id: int) -> TimecodeResponse:
+    """Return the recording's timecode settings (``timecode: null`` when unset)."""
+    from server.core.timecode import parse_settings
+
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
+    settings = parse_settings(recording.get("timecode_settings"))
+    return TimecodeResponse(
         recording_id=recording_id,
-        status=row["status"] if row else None,
-        reviewed_turns_json=row["reviewed_turns_json"] if row else None,
+        timecode=TimecodePayload(**settings.to_dict()) if settings else None,
     )
 
 
-@router.get(
-    "/recordings/{recording_id}/diarization-confidence",
-    response_model=DiarizationConfidenceResponse,
-)
-async def get_diarization_confidence(
+@router.put("/recordings/{recording_id}/timecode", response_model=TimecodeResponse)
+async def update_recording_timecode(
     recording_id: int,
-) -> DiarizationConfidenceResponse:
-    """Per-turn diarization confidence (Issue #104, Story 5.4).
+    payload: TimecodePayload,
+) -> TimecodeResponse:
+    """Validate and store timecode settings; the start label is normalized
+    (``;`` separator for drop-frame) and echoed back."""
+    from server.core.timecode import TimecodeSettings, serialize_settings
 
-    Returns ``{recording_id, turns: [{turn_index, speaker_id, confidence}, ...]}``.
-    Older recordings without word-level confidence return ``turns: []`` —
-    the dashboard treats absent turns as "no chip rendering" (Story 5.5).
-    """
     if not get_recording(recording_id):
         raise HTTPException(status_code=404, detail="Recording not found")
-    from server.core.diarization_confidence import per_turn_confidence
-
-    segments = get_segments(recording_id)
-    words = get_words(recording_id)
-    return DiarizationConfidenceResponse(
+    try:
+        settings = TimecodeSettings(
+            start_timecode=payload.start_timecode,
+            fps=payload.fps,
+            drop_frame=payload.drop_frame,
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    if not update_recording_timecode_settings(recording_id, serialize_settings(settings)):
+        raise HTTPException(status_code=500, detail="Failed to update timecode settings")
+    return TimecodeResponse(
         recording_id=recording_id,
-        turns=[TurnConfidence(**t) for t in per_turn_confidence(segments, words)],
+        timecode=TimecodePayload(**settings.to_dict()),
     )
 
 
-@router.get(
-    "/recordings/{recording_id}/aliases",
-    response_model=AliasesResponse,
-)
-async def list_recording_aliases(recording_id: int) -> AliasesResponse:
-    """List speaker aliases for a recording.
+@router.delete("/recordings/{recording_id}/timecode", response_model=TimecodeResponse)
+async def clear_recording_timecode(recording_id: int) -> TimecodeResponse:
+    """Clear timecode settings — exports revert to zero-based clock times."""
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    if not update_recording_timecode_settings(recording_id, None):
+        raise HTTPException(status_code=500, detail="Failed to clear timecode settings")
+    return TimecodeResponse(recording_id=recording_id, timecode=None)
 
-    Returns an empty array when no aliases are stored — never 404 for
-    a missing alias set (Story 4.2 AC1). Returns 404 only when the
-    recording itself does not exist.
-    """
+
+# ---------------------------------------------------------------------------
+# Annotations — threaded review notes on segments / time ranges
+# ---------------------------------------------------------------------------
+
+
+def _annotation_body(body: str) -> str:
+    text = body.strip()
+    if not text:
+        raise HTTPException(status_code=400, detail="Annotation body must not be empty")
+    return text
+
+
+@router.get("/recordings/{recording_id}/annotations", response_model=AnnotationsResponse)
+async def list_recording_annotations(recording_id: int) -> AnnotationsResponse:
+    """All note threads for a recording; empty when none exist."""
     if not get_recording(recording_id):
         raise HTTPException(status_code=404, detail="Recording not found")
-    from server.database import alias_repository
+    from server.database import annotation_repository
 
-    return AliasesResponse(
+    threads = annotation_repository.build_threads(
+        annotation_repository.list_annotations(recording_id)
+    )
+    return AnnotationsResponse(
         recording_id=recording_id,
-        aliases=[AliasItem(**row) for row in alias_repository.list_aliases(recording_id)],
+        threads=[AnnotationThread(**t) for t in threads],
     )
 
 
-@router.put(
-    "/recordings/{recording_id}/aliases",
-    response_model=AliasesResponse,
+@router.post(
+    "/recordings/{recording_id}/annotations",
+    response_model=AnnotationItem,
+    status_code=201,
 )
-async def update_recording_aliases(
-    recording_id: int,
-    payload: AliasesPayload,
-) -> AliasesResponse:
-    """Full-replace upsert of recording aliases (Story 4.2 AC2).
+async def create_recording_annotation(
+    recording_id: int, payload: AnnotationCreate, request: Request
+) -> AnnotationItem:
+    """Attach a note (or reply) to a segment or time range."""
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    from server.database import annotation_repository
 
-    Each item in ``payload.aliases`` is upserted on
-    ``(recording_id, speaker_id)``. Pre-existing rows whose
-    ``speaker_id`` is NOT in the request body are deleted.
+    author = (payload.author or "").strip() or get_client_name(request)
+    try:
+        row = annotation_repository.create_annotation(
+            recording_id,
+            author=author,
+            body=_annotation_body(payload.body),
+            start_time=payload.start_time,
+            end_time=payload.end_time,
+            segment_id=payload.segment_id,
+            parent_id=payload.parent_id,
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    return AnnotationItem(**row)
 
-    The ``alias_name`` is preserved verbatim (R-EL3) — only surrounding
-    whitespace is stripped. Empty alias names (after strip) are
-    skipped, which has the effect of CLEARING the alias for that
-    speaker_id.
-    """
+
+@router.patch(
+    "/recordings/{recording_id}/annotations/{annotation_id}",
+    response_model=AnnotationItem,
+)
+async def update_recording_annotation(
+    recording_id: int, annotation_id: int, payload: AnnotationUpdate
+) -> AnnotationItem:
+    """Edit a note's text."""
+    from server.database import annotation_repository
+
+    row = annotation_repository.update_annotation(
+        recording_id, annotation_id, _annotation_body(payload.body)
+    )
+    if row is None:
+        raise HTTPException(status_code=404, detail="Annotation not found")
+    return AnnotationItem(**row)
+
+
+@router.delete("/recordings/{recording_id}/annotations/{annotation_id}")
+async def delete_recording_annotation(recording_id: int, annotation_id: int) -> dict[str, Any]:
+    """Delete a note; deleting a thread root removes its replies too."""
+    from server.database import annotation_repository
+
+    if not annotation_repository.delete_annotation(recording_id, annotation_id):
+        raise HTTPException(status_code=404, detail="Annotation not found")
+    return {"status": "deleted", "id": annotation_id}
+
+
+# ---------------------------------------------------------------------------
+# Redactions — sensitive ranges masked in text exports, muted in media
+# ---------------------------------------------------------------------------
+
+
+@router.get("/recordings/{recording_id}/redactions", response_model=RedactionsResponse)
+async def list_recording_redactions(recording_id: int) -> RedactionsResponse:
+    """Redacted ranges for a recording; empty when none are marked."""
     if not get_recording(recording_id):
         raise HTTPException(status_code=404, detail="Recording not found")
+    from server.database import redaction_repository
 
-    from server.core.auto_action_coordinator import (
-        notify_alias_mutation_finished,
-        notify_alias_mutation_started,
+    return RedactionsResponse(
+        recording_id=recording_id,
+        redactions=[RedactionItem(**r) for r in redaction_repository.list_redactions(recording_id)],
     )
-    from server.database import alias_repository
 
-    # Story 6.11 (cross-feature constraint #1) — F1 auto-summary must
-    # not race with F4 alias propagation. We bracket this PUT with the
-    # coordinator's race-guard so any in-flight auto-summary trigger
-    # waits for this mutation to complete.
-    notify_alias_mutation_started(recording_id)
-    try:
-        cleaned: list[dict[str, str]] = []
-        for entry in payload.aliases:
-            # Validate speaker_id — must be a non-empty token. Whitespace
-            # and NUL bytes are rejected because `speaker_id` is used as a
-            # join key against `segments.speaker` and a malformed value
-            # would silently produce a label miss.
-            speaker_id = entry.speaker_id.strip() if entry.speaker_id else ""
-            if not speaker_id or "\x00" in speaker_id:
-                raise HTTPException(
-                    status_code=400,
-                    detail=(
-                        f"Invalid speaker_id: {entry.speaker_id!r} — must be a "
-                        "non-empty string without NUL bytes."
-                    ),
-                )
-            trimmed = entry.alias_name.strip()
-            if not trimmed:
-                # Empty alias → drop the row (full-replace semantics handle it)
-                continue
-            cleaned.append({"speaker_id": speaker_id, "alias_name": trimmed})
 
-        alias_repository.replace_aliases(recording_id, cleaned)
+@router.post(
+    "/recordings/{recording_id}/redactions",
+    response_model=RedactionItem,
+    status_code=201,
+)
+async def create_recording_redaction(recording_id: int, payload: RedactionCreate) -> RedactionItem:
+    """Mark words or a time range as sensitive."""
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    from server.database import redaction_repository
 
-        return AliasesResponse(
-            recording_id=recording_id,
-            aliases=[AliasItem(**row) for row in alias_repository.list_aliases(recording_id)],
+    words = get_words(recording_id)
+    if payload.word_ids:
+        wanted = set(payload.word_ids)
+        covered = [w for w in words if w["id"] in wanted]
+        if len(covered) != len(wanted):
+            raise HTTPException(status_code=400, detail="Unknown word id for this recording")
+        start = min(float(w["start_time"]) for w in covered)
+        end = max(float(w["end_time"]) for w in covered)
+    elif payload.start_time is not None and payload.end_time is not None:
+        start, end = payload.start_time, payload.end_time
+        covered = [w for w in words if w["start_time"] < end and w["end_time"] > start]
+    else:
+        raise HTTPException(status_code=400, detail="Provide word_ids or start_time and end_time")
+    snapshot = " ".join(str(w.get("word") or "").strip() for w in covered) or None
+    try:
+        (new_id,) = redaction_repository.add_redactions(
+            recording_id,
+            [{"start_time": start, "end_time": end, "label": payload.label, "text": snapshot}],
         )
-    finally:
-        notify_alias_mutation_finished(recording_id)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    row = next(r for r in redaction_repository.list_redactions(recording_id) if r["id"] == new_id)
+    return RedactionItem(**row)
 
 
-@router.get("/recordings/{recording_id}/audio")
-async def get_audio_file(
+@router.delete("/recordings/{recording_id}/redactions/{redaction_id}")
+async def delete_recording_redaction(recording_id: int, redaction_id: int) -> dict[str, Any]:
+    """Un-redact one range."""
+    from server.database import redaction_repository
+
+    if not redaction_repository.delete_redaction(recording_id, redaction_id):
+        raise HTTPException(status_code=404, detail="Redaction not found")
+    return {"status": "deleted", "id": redaction_id}
+
+
+@router.post("/recordings/{recording_id}/pii-scan", response_model=RedactionsResponse)
+async def scan_recording_pii(recording_id: int) -> RedactionsResponse:
+    """Re-run the PII scan; its hits replace earlier ``pii`` suggestions."""
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    from server.core.pii_detection import run_pii_scan
+    from server.database import redaction_repository
+
+    await asyncio.to_thread(run_pii_scan, recording_id)
+    return RedactionsResponse(
+        recording_id=recording_id,
+        redactions=[RedactionItem(**r) for r in redaction_repository.list_redactions(recording_id)],
+    )
+
+
+@router.get("/recordings/{recording_id}/redacted-media")
+async def export_redacted_media(
     recording_id: int,
-    range: str | None = Header(None, alias="Range"),
+    mode: str = Query("bleep", description="'mute' (silence) or 'bleep' (1 kHz tone)"),
 ) -> Response:
-    """
-    Stream the audio file for a recording with HTTP Range request support.
+    """The recording's media with every redacted range muted or bleeped."""
+    from starlette.background import BackgroundTask
+
+    from server.core.redaction import AUDIO_MODES, render_redacted_media
+    from server.database import redaction_repository
 
-    Supports partial content requests (HTTP 206) for efficient seeking in large files.
-    """
     recording = get_recording(recording_id)
     if not recording:
         raise HTTPException(status_code=404, detail="Recording not found")
-
-    audio_path = Path(recording["filepath"])
-    if not audio_path.exists():
+    requested_mode = mode.strip().lower()
+    if requested_mode not in AUDIO_MODES:
+        raise HTTPException(status_code=400, detail="mode must be 'mute' or 'bleep'")
+    source = Path(recording["filepath"])
+    if not source.exists():
         raise HTTPException(status_code=404, detail="Audio file not found")
 
-    # Determine media type
-    suffix = audio_path.suffix.lower()
-    media_types = {
-        ".mp3": "audio/mpeg",
-        ".wav": "audio/wav",
-        ".ogg": "audio/ogg",
-        ".flac": "audio/flac",
-        ".m4a": "audio/mp4",
-    }
-    media_type = media_types.get(suffix, "audio/mpeg")
-
-    file_size = audio_path.stat().st_size
-
-    # Check for Range header
-    if range:
-        # Parse range header: "bytes=start-end"
-        range_match = re.match(r"bytes=(\d+)-(\d*)", range)
-        if range_match:
-            start = int(range_match.group(1))
-            end_str = range_match.group(2)
-            end = int(end_str) if end_str else file_size - 1
+    fd, tmp_name = tempfile.mkstemp(suffix=source.suffix or ".mp3")
+    os.close(fd)
+    tmp_path = Path(tmp_name)
+    try:
+        await asyncio.to_thread(
+            call_with_plaintext,
+            render_redacted_media,
+            source,
+            tmp_path,
+            redaction_repository.list_redactions(recording_id),
+            requested_mode,
+        )
+    except RuntimeError as e:
+        tmp_path.unlink(missing_ok=True)
+        raise HTTPException(status_code=500, detail=str(e)) from e
+    stem = Path(recording.get("filename") or f"recording_{recording_id}").stem
+    return FileResponse(
+        path=tmp_path,
+        filename=f"{stem}_redacted{source.suffix}",
+        background=BackgroundTask(tmp_path.unlink, missing_ok=True),
+    )
 
-            # Validate range
-            if start >= file_size:
-                raise HTTPException(
-                    status_code=416,
-                    detail="Range Not Satisfiable",
-                    headers={"Content-Range": f"bytes */{file_size}"},
-                )
 
-            end = min(end, file_size - 1)
-            content_length = end - start + 1
+# ---------------------------------------------------------------------------
+# Outline — chapters at long pauses and topic shifts (core/chaptering.py)
+# ---------------------------------------------------------------------------
 
-            async def stream_range():
-                async with aiofiles.open(audio_path, "rb") as f:
-                    await f.seek(start)
-                    remaining = content_length
-                    chunk_size = 64 * 1024  # 64KB chunks
-                    while remaining > 0:
-                        chunk = await f.read(min(chunk_size, remaining))
-                        if not chunk:
-                            break
-                        remaining -= len(chunk)
-                        yield chunk
 
-            return StreamingResponse(
-                stream_range(),
-                status_code=206,
-                media_type=media_type,
-                headers={
-                    "Content-Range": f"bytes {start}-{end}/{file_size}",
-                    "Accept-Ranges": "bytes",
-                    "Content-Length": str(content_length),
-                    "Content-Disposition": _content_disposition("inline", recording["filename"]),
-                },
-            )
+@router.get("/recordings/{recording_id}/outline", response_model=OutlineResponse)
+async def get_recording_outline(recording_id: int) -> OutlineResponse:
+    """The recording's chapters, built on first request."""
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    from server.core.chaptering import get_outline
 
-    # No Range header - return full file with Accept-Ranges header
-    return FileResponse(
-        path=audio_path,
-        media_type=media_type,
-        filename=recording["filename"],
-        headers={"Accept-Ranges": "bytes"},
+    return OutlineResponse(
+        recording_id=recording_id,
+        chapters=[ChapterItem(**c) for c in await get_outline(recording_id)],
     )
 
 
-@router.get("/recordings/{recording_id}/transcription")
-async def get_transcription(recording_id: int) -> dict[str, Any]:
-    """
-    Get the transcription for a recording (segments with words).
-    """
-    recording = get_recording(recording_id)
-    if not recording:
+@router.post("/recordings/{recording_id}/outline", response_model=OutlineResponse)
+async def rebuild_recording_outline(
+    recording_id: int, payload: OutlineRebuild | None = None
+) -> OutlineResponse:
+    """Re-run chaptering (after edits, or to switch between keyword and LLM titles)."""
+    if not get_recording(recording_id):
         raise HTTPException(status_code=404, detail="Recording not found")
+    from server.core.chaptering import build_outline
 
-    segments = get_segments(recording_id)
-    words = get_words(recording_id)
+    chapters = await build_outline(recording_id, use_llm=payload.use_llm if payload else None)
+    return OutlineResponse(
+        recording_id=recording_id,
+        chapters=[ChapterItem(**c) for c in chapters],
+    )
 
-    # Group words by segment_id
-    words_by_segment: dict[int, list[dict[str, Any]]] = {}
-    for word in words:
-        seg_id = word.get("segment_id")
-        if seg_id not in words_by_segment:
-            words_by_segment[seg_id] = []
-        words_by_segment[seg_id].append(
-            {
-                "word": word.get("word", ""),
-                "start": word.get("start_time", 0),
-                "end": word.get("end_time", 0),
-                "confidence": word.get("confidence"),
-            }
-        )
 
-    # Build segments with embedded words
-    result_segments = []
-    for seg in segments:
-        seg_id = seg.get("id")
-        result_segments.append(
-            {
-                "text": seg.get("text", ""),
-                "start": seg.get("start_time", 0),
-                "end": seg.get("end_time", 0),
-                "speaker": seg.get("speaker"),
-                "words": words_by_segment.get(seg_id, []),
-            }
-        )
+# ---------------------------------------------------------------------------
+# Speaker statistics — talk time, pace, monologues, interruptions
+# ---------------------------------------------------------------------------
 
-    return {
-        "recording_id": recording_id,
-        "segments": result_segments,
-    }
 
+def _speaker_stats(recording_id: int) -> dict[str, Any]:
+    from server.core.alias_substitution import build_speaker_label_map
+    from server.core.speaker_stats import compute_speaker_stats
+    from server.database import alias_repository
 
-class UploadResponse(BaseModel):
-    """Response model for file upload."""
+    segments = get_segments(recording_id)
+    label_map = build_speaker_label_map(segments, alias_repository.alias_map(recording_id))
+    return compute_speaker_stats(segments, label_map)
 
-    recording_id: int
-    message: str
-    diarization: dict[str, Any]
 
+@router.get("/recordings/{recording_id}/speaker-stats", response_model=SpeakerStatsResponse)
+async def get_recording_speaker_stats(recording_id: int) -> SpeakerStatsResponse:
+    """Per-speaker statistics; ``speakers`` is empty for undiarized recordings."""
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    stats = await asyncio.to_thread(_speaker_stats, recording_id)
+    return SpeakerStatsResponse(recording_id=recording_id, **stats)
 
-class AcceptedResponse(BaseModel):
-    """Response model for accepted transcription job (202)."""
 
-    job_id: str
+# ---------------------------------------------------------------------------
+# Transcript comparison — word diff and WER between two transcripts
+# ---------------------------------------------------------------------------
 
 
-def _run_transcription(
-    *,
-    model_manager: Any,
-    tmp_path: Path,
-    filename: str,
-    language: str | None,
-    translation_enabled: bool,
-    translation_target_language: str | None,
-    enable_diarization: bool,
-    enable_word_timestamps: bool,
-    file_created_at: str | None,
-    expected_speakers: int | None,
-    parallel_diarization: bool | None,
-    use_parallel_default: bool,
-    title: str | None,
-    job_id: str,
-    event_loop: Any = None,
-    audio_hash: str | None = None,
-    normalized_audio_hash: str | None = None,
-    profile_snapshot: dict[str, Any] | None = None,
-) -> None:
+@router.get(
+    "/recordings/{recording_id}/diff/{other_id}",
+    response_model=TranscriptDiffResponse,
+)
+async def get_transcript_diff(
+    recording_id: int,
+    other_id: int,
+    a_corrected: bool = Query(False, description="Use A's hand-corrected text"),
+    b_corrected: bool = Query(False, description="Use B's hand-corrected text"),
+) -> TranscriptDiffResponse:
+    """Side-by-side diff of two transcripts of the same audio.
+
+    Pass the same id twice with ``b_corrected=true`` to compare the machine
+    transcript with its corrected text.
     """
-    Run transcription in a background thread.
+    for rid in (recording_id, other_id):
+        if not get_recording(rid):
+            raise HTTPException(status_code=404, detail=f"Recording {rid} not found")
+    from server.core.transcript_diff import diff_transcripts
+
+    diff = await asyncio.to_thread(
+        diff_transcripts,
+        recording_id,
+        other_id,
+        a_corrected=a_corrected,
+        b_corrected=b_corrected,
+    )
+    return TranscriptDiffResponse(**diff)
 
-    This is a synchronous function intended to be called via asyncio.to_thread().
-    It performs the full transcription pipeline and stores the result (or error)
-    in model_manager.job_tracker so that clients can poll for completion.
-    """
-    # Lazy import to avoid loading torch at module import time
-    from server.core.audio_utils import AudioDecodeError, convert_to_mp3, load_audio
 
-    try:
-        # Progress callback to update job tracker with chunk progress
-        def on_progress(current: int, total: int) -> None:
-            model_manager.job_tracker.update_progress(current, total)
+# ---------------------------------------------------------------------------
+# Speaker aliases (Issue #104, Story 4.2)
+# ---------------------------------------------------------------------------
+# Mounted on the notebook router rather than a top-level /api/recordings
+# router because notebook recordings are the only entity that owns
+# speaker labels in this codebase. URL-prefix override is documented in
+# `_bmad-output/implementation-artifacts/sprint-3-design.md` §1.
 
-        # Get transcription engine, lazily reloading the model if a prior
-        # Live-Mode restore or sequential-diarization swap left it detached.
-        # See ModelManager.ensure_transcription_loaded() for the full rationale
-        # (Issue #76).
-        engine = model_manager.ensure_transcription_loaded()
 
-        # Resolve the diarization engine (funasr CAM++ single-pass vs pyannote
-        # two-pass) only when diarization is requested. For SenseVoice this honors
-        # the server-wide engine setting (config diarization.sensevoice_engine);
-        # every other integrated backend (WhisperX, VibeVoice) keeps its
-        # single-pass path unconditionally. This is a threaded worker with no
-        # request object, so the config default comes from the get_config()
-        # singleton rather than app.state.config.
-        backend = engine._backend
-        use_integrated_diarization = False
-        if enable_diarization:
-            from server.config import resolve_sensevoice_diarization_engine
-            from server.core.stt.backends.base import use_integrated_diarization_for
+@router.get(
+    "/recordings/{recording_id}/diarization-review",
+    response_model=DiarizationReviewState,
+)
+async def get_diarization_review_state(
+    recording_id: int,
+) -> DiarizationReviewState:
+    """Return the current ADR-009 lifecycle state (Issue #104, Story 5.7).
 
-            app_config = get_config()
-            sensevoice_engine_default = (
-                app_config.get("diarization", "sensevoice_engine", default="funasr")
-                if app_config is not None
-                else "funasr"
-            )
-            resolved_diar_engine = resolve_sensevoice_diarization_engine(
-                getattr(engine, "model_name", None),
-                None,
-                sensevoice_engine_default,
-                funasr_diar_available=getattr(backend, "_diarization_loaded", False),
-            )
-            use_integrated_diarization = use_integrated_diarization_for(
-                backend, resolved_diar_engine
-            )
+    Returns ``status: null`` (no row) when no review has been triggered
+    for this recording — the dashboard treats null as "no banner".
+    """
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    from server.database import diarization_review_repository as repo
 
-        # Run diarization if enabled
-        diarization_segments = None
-        diarization_outcome: dict[str, Any] = {
-            "requested": bool(enable_diarization),
-            "performed": False,
-            "reason": None,
-        }
+    row = repo.get_review(recording_id)
+    return DiarizationReviewState(
+        recording_id=recording_id,
+        status=row["status"] if row else None,
+        reviewed_turns_json=row["reviewed_turns_json"] if row else None,
+    )
 
-        if use_integrated_diarization:
-            # --- Integrated backend single-pass path (e.g. WhisperX, VibeVoice) ---
-            try:
-                backend_label = getattr(backend, "backend_name", "integrated")
-                logger.info(
-                    "Using %s single-pass diarization for: %s",
-                    backend_label,
-                    filename,
-                )
-                preferred_rate = int(
-                    getattr(backend, "preferred_input_sample_rate_hz", 16000) or 16000
-                )
-                audio_data, audio_sample_rate = load_audio(
-                    str(tmp_path), target_sample_rate=preferred_rate
-                )
 
-                diar_result = backend.transcribe_with_diarization(
-                    audio_data,
-                    audio_sample_rate=audio_sample_rate,
-                    language=language,
-                    task="translate" if translation_enabled else "transcribe",
-                    beam_size=engine.beam_size,
-                    num_speakers=expected_speakers,
-                    progress_callback=on_progress,
-                )
+@router.post(
+    "/recordings/{recording_id}/diarization-review",
+    response_model=DiarizationReviewState,
+)
+async def submit_diarization_review(
+    recording_id: int,
+    payload: DiarizationReviewSubmit,
+) -> DiarizationReviewState:
+    """Lifecycle trigger endpoint (Stories 5.7 / 5.9).
 
-                from server.core.stt.engine import TranscriptionResult
+    Actions:
+      - ``open``     — pending → in_review (banner CTA invokes this)
+      - ``complete`` — in_review → completed; persists ``reviewed_turns_json``;
+                       Sprint 4 Story 6.2 calls ``on_auto_summary_fired()``
+                       to flip to ``released``
 
-                result = TranscriptionResult(
-                    text=" ".join(seg.get("text", "") for seg in diar_result.segments).strip(),
-                    segments=diar_result.segments,
-                    words=diar_result.words,
-                    language=diar_result.language,
-                    language_probability=diar_result.language_probability,
-                    duration=len(audio_data) / audio_sample_rate,
-                    num_speakers=diar_result.num_speakers,
-                )
+    Persist-Before-Deliver (NFR16): each lifecycle trigger commits before
+    returning, so the response reflects committed state.
+    """
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
 
-                diarization_segments = diar_result.segments
-                diarization_outcome["performed"] = True
-                diarization_outcome["reason"] = "ready"
-                logger.info(
-                    "%s diarization complete: %s speakers found",
-                    backend_label,
-                    diar_result.num_speakers,
-                )
+    import json
 
-            except AudioDecodeError:
-                # A corrupt/undecodable file is not a diarization-token problem —
-                # re-raise the clean error rather than mislabeling it (FINDING #1).
-                raise
-            except ValueError as e:
-                logger.error(f"Diarization requires HuggingFace token: {e}")
-                logger.error("Set HUGGINGFACE_TOKEN env var when starting docker compose")
-                diarization_outcome["reason"] = model_manager.get_diarization_feature_status().get(
-                    "reason", "token_missing"
+    from server.core.diarization_review_lifecycle import (
+        IllegalReviewTransitionError,
+        on_review_view_opened,
+        on_run_summary_now_clicked,
+    )
+    from server.database import diarization_review_repository as repo
+
+    try:
+        if payload.action == "open":
+            on_review_view_opened(recording_id)
+        elif payload.action == "complete":
+            # Lifecycle transition FIRST — illegal-transition failure
+            # must leave reviewed_turns_json untouched. Writing the JSON
+            # before the transition would produce orphan data when the
+            # transition raises (e.g. someone raced and the row is no
+            # longer in_review). After the transition succeeds, the
+            # JSON write commits separately but is functionally a follow-up
+            # decoration of an already-completed row.
+            on_run_summary_now_clicked(recording_id)
+            if payload.reviewed_turns is not None:
+                repo.update_reviewed_turns(
+                    recording_id,
+                    json.dumps(payload.reviewed_turns, ensure_ascii=False, sort_keys=True),
                 )
-                # Fall back to transcription without diarization
-                use_integrated_diarization = False
-            except Exception as e:
-                logger.error("Integrated backend diarization failed (continuing without): %s", e)
-                diarization_outcome["reason"] = "unavailable"
-                # Fall back to transcription without diarization
-                use_integrated_diarization = False
+        else:
+            raise HTTPException(
+                status_code=400,
+                detail=f"Invalid action: {payload.action!r}. Expected 'open' or 'complete'.",
+            )
+    except IllegalReviewTransitionError as exc:
+        raise HTTPException(status_code=409, detail=str(exc)) from exc
 
-        if not use_integrated_diarization:
-            # --- Standard path (NeMo backends or WhisperX fallback) ---
-            # Force word timestamps if diarization is enabled
-            # (needed for proper text-to-speaker alignment, even if user doesn't want to save words)
-            need_word_timestamps = enable_word_timestamps or enable_diarization
+    row = repo.get_review(recording_id)
+    return DiarizationReviewState(
+        recording_id=recording_id,
+        status=row["status"] if row else None,
+        reviewed_turns_json=row["reviewed_turns_json"] if row else None,
+    )
 
-            if enable_diarization and not diarization_outcome["performed"]:
-                # Resolve parallel vs sequential diarization
-                use_parallel = (
-                    parallel_diarization
-                    if parallel_diarization is not None
-                    else use_parallel_default
-                )
 
-                if use_parallel:
-                    from server.core.parallel_diarize import transcribe_and_diarize
+@router.get(
+    "/recordings/{recording_id}/diarization-confidence",
+    response_model=DiarizationConfidenceResponse,
+)
+async def get_diarization_confidence(
+    recording_id: int,
+) -> DiarizationConfidenceResponse:
+    """Per-turn diarization confidence (Issue #104, Story 5.4).
 
-                    diarize_fn = transcribe_and_diarize
-                else:
-                    from server.core.parallel_diarize import transcribe_then_diarize
+    Returns ``{recording_id, turns: [{turn_index, speaker_id, confidence}, ...]}``.
+    Older recordings without word-level confidence return ``turns: []`` —
+    the dashboard treats absent turns as "no chip rendering" (Story 5.5).
+    """
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    from server.core.diarization_confidence import per_turn_confidence
 
-                    diarize_fn = transcribe_then_diarize
+    segments = get_segments(recording_id)
+    words = get_words(recording_id)
+    return DiarizationConfidenceResponse(
+        recording_id=recording_id,
+        turns=[TurnConfidence(**t) for t in per_turn_confidence(segments, words)],
+    )
 
-                result, diar_result = diarize_fn(
-                    engine=engine,
-                    model_manager=model_manager,
-                    file_path=str(tmp_path),
-                    language=language,
-                    task="translate" if translation_enabled else "transcribe",
-                    translation_target_language=(
-                        translation_target_language if translation_enabled else None
-                    ),
-                    word_timestamps=need_word_timestamps,
-                    expected_speakers=expected_speakers,
-                    progress_callback=on_progress,
-                )
 
-                if diar_result is not None:
-                    diarization_segments = [seg.to_dict() for seg in diar_result.segments]
-                    diarization_outcome["performed"] = True
-                    diarization_outcome["reason"] = "ready"
-                    logger.info(
-                        "Diarization complete: %s speakers found",
-                        diar_result.num_speakers,
-                    )
-                else:
-                    diarization_outcome["reason"] = (
-                        model_manager.get_diarization_feature_status().get("reason", "unavailable")
-                    )
-            else:
-                # Transcribe without diarization
-                logger.info(f"Transcribing uploaded file for notebook: {filename}")
-                result = engine.transcribe_file(
-                    str(tmp_path),
-                    language=language,
-                    task="translate" if translation_enabled else "transcribe",
-                    translation_target_language=(
-                        translation_target_language if translation_enabled else None
-                    ),
-                    word_timestamps=need_word_timestamps,
-                    progress_callback=on_progress,
-                    cancellation_check=model_manager.job_tracker.is_cancelled,
-                )
+@router.get(
+    "/recordings/{recording_id}/low-confidence",
+    response_model=LowConfidenceResponse,
+)
+async def get_low_confidence_regions(
+    recording_id: int,
+    threshold: float = Query(LOW_CONFIDENCE_THRESHOLD, ge=0.0, le=1.0),
+    bridge: int = Query(1, ge=0, le=5, description="Confident words allowed inside a region"),
+) -> LowConfidenceResponse:
+    """Word-level confidence regions so reviewers can jump between likely errors."""
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    from server.core.word_confidence import confidence_coverage, low_confidence_regions
+
+    words = get_words(recording_id)
+    return LowConfidenceResponse(
+        recording_id=recording_id,
+        threshold=threshold,
+        coverage=round(confidence_coverage(words), 4),
+        regions=[
+            LowConfidenceRegion(**r)
+            for r in low_confidence_regions(words, threshold, bridge_words=bridge)
+        ],
+    )
+
+
+def _segment_review_response(recording_id: int) -> SegmentReviewResponse:
+    from server.core.segment_review import review_progress
+
+    segments = get_segments(recording_id)
+    return SegmentReviewResponse(
+        recording_id=recording_id,
+        segments=[
+            SegmentReviewItem(
+                id=seg["id"],
+                segment_index=seg["segment_index"],
+                state=seg.get("review_state"),
+                reviewed_at=seg.get("reviewed_at"),
+            )
+            for seg in segments
+        ],
+        progress=review_progress(segments),
+    )
+
+
+@router.get(
+    "/recordings/{recording_id}/segment-review",
+    response_model=SegmentReviewResponse,
+)
+async def get_segment_review(recording_id: int) -> SegmentReviewResponse:
+    """Per-segment QA state plus progress counts for the viewer's review mode."""
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    return _segment_review_response(recording_id)
+
+
+@router.put(
+    "/recordings/{recording_id}/segment-review",
+    response_model=SegmentReviewResponse,
+)
+async def update_segment_review(
+    recording_id: int, payload: SegmentReviewUpdate
+) -> SegmentReviewResponse:
+    """Approve, flag or clear segments; returns the refreshed state and progress."""
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    from server.core.segment_review import normalize_review_state
+
+    try:
+        state = normalize_review_state(payload.state)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    set_segment_review_state(recording_id, payload.segment_ids, state)
+    return _segment_review_response(recording_id)
+
+
+@router.get(
+    "/recordings/{recording_id}/aliases",
+    response_model=AliasesResponse,
+)
+async def list_recording_aliases(recording_id: int) -> AliasesResponse:
+    """List speaker aliases for a recording.
+
+    Returns an empty array when no aliases are stored — never 404 for
+    a missing alias set (Story 4.2 AC1). Returns 404 only when the
+    recording itself does not exist.
+    """
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    from server.database import alias_repository
+
+    return AliasesResponse(
+        recording_id=recording_id,
+        aliases=[AliasItem(**row) for row in alias_repository.list_aliases(recording_id)],
+    )
+
+
+@router.put(
+    "/recordings/{recording_id}/aliases",
+    response_model=AliasesResponse,
+)
+async def update_recording_aliases(
+    recording_id: int,
+    payload: AliasesPayload,
+) -> AliasesResponse:
+    """Full-replace upsert of recording aliases (Story 4.2 AC2).
+
+    Each item in ``payload.aliases`` is upserted on
+    ``(recording_id, speaker_id)``. Pre-existing rows whose
+    ``speaker_id`` is NOT in the request body are deleted.
+
+    The ``alias_name`` is preserved verbatim (R-EL3) — only surrounding
+    whitespace is stripped. Empty alias names (after strip) are
+    skipped, which has the effect of CLEARING the alias for that
+    speaker_id.
+    """
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+
+    from server.core.auto_action_coordinator import (
+        notify_alias_mutation_finished,
+        notify_alias_mutation_started,
+    )
+    from server.database import alias_repository
+
+    # Story 6.11 (cross-feature constraint #1) — F1 auto-summary must
+    # not race with F4 alias propagation. We bracket this PUT with the
+    # coordinator's race-guard so any in-flight auto-summary trigger
+    # waits for this mutation to complete.
+    notify_alias_mutation_started(recording_id)
+    try:
+        cleaned: list[dict[str, str]] = []
+        for entry in payload.aliases:
+            # Validate speaker_id — must be a non-empty token. Whitespace
+            # and NUL bytes are rejected because `speaker_id` is used as a
+            # join key against `segments.speaker` and a malformed value
+            # would silently produce a label miss.
+            speaker_id = entry.speaker_id.strip() if entry.speaker_id else ""
+            if not speaker_id or "\x00" in speaker_id:
+                raise HTTPException(
+                    status_code=400,
+                    detail=(
+                        f"Invalid speaker_id: {entry.speaker_id!r} — must be a "
+                        "non-empty string without NUL bytes."
+                    ),
+                )
+            trimmed = entry.alias_name.strip()
+            if not trimmed:
+                # Empty alias → drop the row (full-replace semantics handle it)
+                continue
+            cleaned.append({"speaker_id": speaker_id, "alias_name": trimmed})
+
+        alias_repository.replace_aliases(recording_id, cleaned)
+
+        return AliasesResponse(
+            recording_id=recording_id,
+            aliases=[AliasItem(**row) for row in alias_repository.list_aliases(recording_id)],
+        )
+    finally:
+        notify_alias_mutation_finished(recording_id)
+
+
+@router.post(
+    "/recordings/{recording_id}/identify-speakers",
+    response_model=SpeakerIdentificationResponse,
+)
+async def identify_recording_speakers_route(recording_id: int) -> SpeakerIdentificationResponse:
+    """Match the recording's unnamed speakers against the voice profiles.
+
+    Runs automatically after every diarized transcription; this re-runs it,
+    e.g. after enrolling someone new. Matches are stored as aliases and
+    names the user set are never overwritten (see
+    ``core/speaker_identification.py``). 503 when no model is configured.
+    """
+    from server.core.auto_action_coordinator import (
+        notify_alias_mutation_finished,
+        notify_alias_mutation_started,
+    )
+    from server.core.speaker_identification import (
+        configured_embedder,
+        identify_recording_speakers,
+    )
+    from server.database import alias_repository
+
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    embedder = configured_embedder()
+    if embedder is None:
+        raise HTTPException(status_code=503, detail=_VOICE_PROFILES_DISABLED)
+
+    notify_alias_mutation_started(recording_id)
+    try:
+        matched = await asyncio.to_thread(
+            identify_recording_speakers, recording_id, embedder=embedder
+        )
+    finally:
+        notify_alias_mutation_finished(recording_id)
+    return SpeakerIdentificationResponse(
+        recording_id=recording_id,
+        matched=matched,
+        aliases=[AliasItem(**row) for row in alias_repository.list_aliases(recording_id)],
+    )
+
+
+# ---------------------------------------------------------------------------
+# Voice profiles — speaker enrollment (core/speaker_identification.py)
+# ---------------------------------------------------------------------------
+
+_VOICE_PROFILES_DISABLED = (
+    "Voice profiles need a speaker-embedding model: set speaker_identification.model_path "
+    "in config.yaml (requires onnxruntime)"
+)
+
+
+@router.get("/voice-profiles", response_model=VoiceProfilesResponse)
+async def list_voice_profiles() -> VoiceProfilesResponse:
+    """Enrolled speakers, ordered by name."""
+    from server.core.speaker_identification import configured_embedder
+    from server.database import voice_profile_repository
+
+    return VoiceProfilesResponse(
+        enabled=configured_embedder() is not None,
+        profiles=[VoiceProfileItem(**p) for p in voice_profile_repository.list_profiles()],
+    )
+
+
+@router.post("/voice-profiles", response_model=VoiceProfileItem, status_code=201)
+async def enroll_voice_sample(
+    file: Annotated[UploadFile, File(...)],
+    name: str = Form(...),
+) -> VoiceProfileItem:
+    """Enroll a voice sample for ``name`` (3-60 s of that person speaking alone).
+
+    Creates the profile, or refines an existing one with the same name
+    (case-insensitive) — a few samples in different conditions match best.
+    """
+    from server.core.audio_utils import AudioDecodeError, load_audio
+    from server.core.speaker_identification import (
+        MAX_ENROLL_SECONDS,
+        SAMPLE_RATE,
+        configured_embedder,
+        enrollment_embedding,
+    )
+    from server.database import voice_profile_repository
+
+    clean_name = name.strip()
+    if not clean_name or "\x00" in clean_name:
+        raise HTTPException(status_code=400, detail="A profile name is required")
+    embedder = configured_embedder()
+    if embedder is None:
+        raise HTTPException(status_code=503, detail=_VOICE_PROFILES_DISABLED)
+
+    suffix = Path(file.filename or "").suffix or ".wav"
+    with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
+        await save_upload(file, tmp)
+        tmp_path = Path(tmp.name)
+    try:
+        audio, _ = await asyncio.to_thread(
+            load_audio, str(tmp_path), SAMPLE_RATE, max_seconds=MAX_ENROLL_SECONDS
+        )
+        embedding = await asyncio.to_thread(enrollment_embedding, embedder, audio)
+        profile = voice_profile_repository.add_sample(clean_name, embedding)
+    except AudioDecodeError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    finally:
+        tmp_path.unlink(missing_ok=True)
+    return VoiceProfileItem(**profile)
+
+
+@router.patch("/voice-profiles/{profile_id}", response_model=VoiceProfileItem)
+async def rename_voice_profile(profile_id: int, body: VoiceProfileRename) -> VoiceProfileItem:
+    """Rename a profile. Aliases already written to recordings keep the old name."""
+    import sqlite3
+
+    from server.database import voice_profile_repository
+
+    clean_name = body.name.strip()
+    if not clean_name or "\x00" in clean_name:
+        raise HTTPException(status_code=400, detail="A profile name is required")
+    try:
+        found = voice_profile_repository.rename_profile(profile_id, clean_name)
+    except sqlite3.IntegrityError as e:
+        raise HTTPException(
+            status_code=409, detail=f"A voice profile named {clean_name!r} already exists"
+        ) from e
+    if not found:
+        raise HTTPException(status_code=404, detail="Voice profile not found")
+    profile = next(p for p in voice_profile_repository.list_profiles() if p["id"] == profile_id)
+    return VoiceProfileItem(**profile)
+
+
+@router.delete("/voice-profiles/{profile_id}")
+async def delete_voice_profile(profile_id: int) -> dict[str, Any]:
+    from server.database import voice_profile_repository
+
+    if not voice_profile_repository.delete_profile(profile_id):
+        raise HTTPException(status_code=404, detail="Voice profile not found")
+    return {"status": "deleted", "id": profile_id}
+
+
+async def _decrypted_stream(
+    path: Path, start: int = 0, end: int | None = None
+) -> Iterator[bytes]:
+    """Decrypt the first chunk up front so a missing or wrong key is a 503, not a cut stream."""
+    chunks = iter_plaintext(path, start, end)
+    try:
+        first = await asyncio.to_thread(next, chunks, b"")
+    except StorageEncryptionError as e:
+        raise HTTPException(status_code=503, detail=str(e)) from e
+
+    def stream() -> Iterator[bytes]:
+        yield first
+        yield from chunks
+
+    return stream()
+
+
+@router.get("/recordings/{recording_id}/audio")
+async def get_audio_file(
+    recording_id: int,
+    range: str | None = Header(None, alias="Range"),
+) -> Response:
+    """
+    Stream the audio file for a recording with HTTP Range request support.
+
+    Supports partial content requests (HTTP 206) for efficient seeking in large files.
+    """
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
+
+    audio_path = Path(recording["filepath"])
+    if not audio_path.exists():
+        if recording.get("audio_retired_at"):
+            raise HTTPException(
+                status_code=410, detail="The audio was removed by the retention policy"
+            )
+        raise HTTPException(status_code=404, detail="Audio file not found")
+
+    # Determine media type
+    suffix = audio_path.suffix.lower()
+    media_types = {
+        ".mp3": "audio/mpeg",
+        ".wav": "audio/wav",
+        ".ogg": "audio/ogg",
+        ".flac": "audio/flac",
+        ".m4a": "audio/mp4",
+    }
+    media_type = media_types.get(suffix, "audio/mpeg")
+
+    file_size = plaintext_size(audio_path)
+    encrypted = is_encrypted(audio_path)
+
+    # Check for Range header
+    if range:
+        # Parse range header: "bytes=start-end"
+        range_match = re.match(r"bytes=(\d+)-(\d*)", range)
+        if range_match:
+            start = int(range_match.group(1))
+            end_str = range_match.group(2)
+            end = int(end_str) if end_str else file_size - 1
+
+            # Validate range
+            if start >= file_size:
+                raise HTTPException(
+                    status_code=416,
+                    detail="Range Not Satisfiable",
+                    headers={"Content-Range": f"bytes */{file_size}"},
+                )
+
+            end = min(end, file_size - 1)
+            content_length = end - start + 1
+
+            if encrypted:
+                return StreamingResponse(
+                    await _decrypted_stream(audio_path, start, end),
+                    status_code=206,
+                    media_type=media_type,
+                    headers={
+                        "Content-Range": f"bytes {start}-{end}/{file_size}",
+                        "Accept-Ranges": "bytes",
+                        "Content-Length": str(content_length),
+                        "Content-Disposition": _content_disposition(
+                            "inline", recording["filename"]
+                        ),
+                    },
+                )
+
+            async def stream_range():
+                async with aiofiles.open(audio_path, "rb") as f:
+                    await f.seek(start)
+                    remaining = content_length
+                    chunk_size = 64 * 1024  # 64KB chunks
+                    while remaining > 0:
+                        chunk = await f.read(min(chunk_size, remaining))
+                        if not chunk:
+                            break
+                        remaining -= len(chunk)
+                        yield chunk
+
+            return StreamingResponse(
+                stream_range(),
+                status_code=206,
+                media_type=media_type,
+                headers={
+                    "Content-Range": f"bytes {start}-{end}/{file_size}",
+                    "Accept-Ranges": "bytes",
+                    "Content-Length": str(content_length),
+                    "Content-Disposition": _content_disposition("inline", recording["filename"]),
+                },
+            )
+
+    # No Range header - return full file with Accept-Ranges header
+    if encrypted:
+        return StreamingResponse(
+            await _decrypted_stream(audio_path),
+            media_type=media_type,
+            headers={
+                "Accept-Ranges": "bytes",
+                "Content-Length": str(file_size),
+                "Content-Disposition": _content_disposition("attachment", recording["filename"]),
+            },
+        )
+    return FileResponse(
+        path=audio_path,
+        media_type=media_type,
+        filename=recording["filename"],
+        headers={"Accept-Ranges": "bytes"},
+    )
+
+
+# Cards a library view asks for at once; more ids are ignored.
+MAX_MEDIA_INFO_IDS = 200
+
+
+def _media_info(recording: dict[str, Any]) -> dict[str, Any]:
+    audio_path = Path(recording["filepath"])
+    previews = get_media_previews()
+    meta = previews.get(audio_path)
+    if meta is not None:
+        if "error" in meta:
+            return {"status": "failed", "error": meta["error"]}
+        return {"status": "ready", **meta}
+    if not previews.is_pending(audio_path):
+        if not audio_path.exists():
+            return {"status": "unavailable"}
+        previews.submit(audio_path)
+    return {"status": "pending"}
+
+
+@router.get("/media-info")
+async def get_media_info(
+    ids: str = Query(..., description="Comma-separated recording ids"),
+) -> dict[str, dict[str, Any]]:
+    """
+    Media info for library cards, keyed by recording id: duration, container,
+    audio/video codecs and which preview image exists. ``status`` is
+    ``ready``, ``pending`` while the background workers extract it (queued
+    here for recordings that predate it), ``failed``, or ``unavailable`` when
+    the recording has no stored audio. Unknown ids are left out.
+    """
+    try:
+        wanted = [int(part) for part in ids.split(",") if part.strip()][:MAX_MEDIA_INFO_IDS]
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail="ids must be comma-separated integers") from e
+    result: dict[str, dict[str, Any]] = {}
+    for recording_id in wanted:
+        recording = get_recording(recording_id)
+        if recording:
+            result[str(recording_id)] = _media_info(recording)
+    return result
+
+
+@router.get("/recordings/{recording_id}/preview")
+async def get_recording_preview(recording_id: int) -> Response:
+    """The card preview image: a video frame or an audio spectrogram."""
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
+    preview = get_media_previews().preview(recording["filepath"])
+    if preview is None:
+        raise HTTPException(status_code=404, detail="No preview for this recording yet")
+    content, media_type = preview
+    return Response(
+        content=content,
+        media_type=media_type,
+        headers={"Cache-Control": "private, max-age=86400"},
+    )
+
+
+@router.get("/recordings/{recording_id}/transcription")
+async def get_transcription(
+    recording_id: int,
+    offset_seconds: float | None = Query(None),
+    duration_seconds: float | None = Query(None, gt=0),
+) -> dict[str, Any]:
+    """
+    Get the transcription for a recording (segments with words).
+
+    With ``offset_seconds`` (from a fingerprint dedup match) the transcript
+    is re-timed for the matched copy instead: times move back by the offset
+    and anything outside the copy's ``duration_seconds`` is left out.
+    """
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
+
+    segments = get_segments(recording_id)
+    words = get_words(recording_id)
+
+    # Group words by segment_id
+    words_by_segment: dict[int, list[dict[str, Any]]] = {}
+    for word in words:
+        seg_id = word.get("segment_id")
+        if seg_id not in words_by_segment:
+            words_by_segment[seg_id] = []
+        words_by_segment[seg_id].append(
+            {
+                "word": word.get("word", ""),
+                "start": word.get("start_time", 0),
+                "end": word.get("end_time", 0),
+                "confidence": word.get("confidence"),
+            }
+        )
+
+    # Build segments with embedded words
+    result_segments = []
+    for seg in segments:
+        seg_id = seg.get("id")
+        result_segments.append(
+            {
+                "id": seg_id,
+                "text": seg.get("text", ""),
+                "start": seg.get("start_time", 0),
+                "end": seg.get("end_time", 0),
+                "speaker": seg.get("speaker"),
+                "review_state": seg.get("review_state"),
+                "words": words_by_segment.get(seg_id, []),
+            }
+        )
+
+    if offset_seconds is not None:
+        result_segments = shift_segments(result_segments, offset_seconds, duration_seconds)
+
+    return {
+        "recording_id": recording_id,
+        "segments": result_segments,
+    }
+
+
+class UploadResponse(BaseModel):
+    """Response model for file upload."""
+
+    recording_id: int
+    message: str
+    diarization: dict[str, Any]
+
+
+class AcceptedResponse(BaseModel):
+    """Response model for accepted transcription job (202).
+
+    ``dedup_matches`` lists recordings that are a re-encoded or trimmed copy
+    of the upload (chromaprint fingerprint match); empty for new audio.
+    """
+
+    job_id: str
+    dedup_matches: list[DedupMatch] = []
+
+
+def _notebook_audio_dir() -> Path:
+    config = get_config()
+    _data_dir = os.environ.get("DATA_DIR", "/data")
+    return Path(config.get("audio_notebook", "audio_dir", default=f"{_data_dir}/audio"))
+
+
+def _store_notebook_audio(tmp_path: Path, filename: str | None) -> Path:
+    """Move an uploaded file into the notebook audio dir; returns the stored path."""
+    from server.core.audio_utils import convert_to_mp3
+
+    # Convert audio to MP3 and save to permanent storage
+    audio_dir = _notebook_audio_dir()
+    audio_dir.mkdir(parents=True, exist_ok=True)
+
+    # Keep original filename, convert to .mp3 extension
+    # Sanitize filename to prevent path traversal
+    raw_stem = Path(filename or "audio").stem
+    # Remove any path separators and sanitize to alphanumeric + safe chars
+    original_stem = "".join(c for c in raw_stem if c.isalnum() or c in "._- ")[:100]
+    if not original_stem:
+        original_stem = "audio"
+    dest_filename = f"{original_stem}.mp3"
+    dest_path = audio_dir / dest_filename
+
+    # Handle duplicates by adding -2, -3, etc. suffix
+    counter = 2
+    while dest_path.exists():
+        dest_filename = f"{original_stem}-{counter}.mp3"
+        dest_path = audio_dir / dest_filename
+        counter += 1
+
+    # Convert to MP3 for storage efficiency. If conversion fails (e.g. ffmpeg
+    # is not installed — a stock macOS install has none), fall back to storing
+    # the original audio verbatim so a COMPLETED transcript is never discarded
+    # (persist-before-deliver: the transcript is saved below regardless).
+    # See FINDING #2 (Apple-Silicon stress test).
+    try:
+        convert_to_mp3(str(tmp_path), str(dest_path))
+    except Exception as mp3_err:
+        logger.warning(
+            "MP3 conversion failed (%s); storing the original audio so the "
+            "completed transcript is preserved.",
+            mp3_err,
+        )
+        fallback_suffix = Path(filename or "audio").suffix.lower() or ".wav"
+        dest_filename = f"{original_stem}{fallback_suffix}"
+        dest_path = audio_dir / dest_filename
+        counter = 2
+        while dest_path.exists():
+            dest_filename = f"{original_stem}-{counter}{fallback_suffix}"
+            dest_path = audio_dir / dest_filename
+            counter += 1
+        shutil.copyfile(str(tmp_path), str(dest_path))
+
+    # Card metadata and preview come from the upload, which still has the
+    # video and original codecs the MP3 dropped.
+    get_media_previews().submit(dest_path, original=link_for_preview(tmp_path))
+    return dest_path
+
+
+def _run_transcription(
+    *,
+    model_manager: Any,
+    tmp_path: Path,
+    filename: str,
+    language: str | None,
+    translation_enabled: bool,
+    translation_target_language: str | None,
+    enable_diarization: bool,
+    enable_word_timestamps: bool,
+    file_created_at: str | None,
+    expected_speakers: int | None,
+    parallel_diarization: bool | None,
+    use_parallel_default: bool,
+    title: str | None,
+    job_id: str,
+    event_loop: Any = None,
+    audio_hash: str | None = None,
+    normalized_audio_hash: str | None = None,
+    fingerprint: list[int] | None = None,
+    metadata: dict[str, str] | None = None,
+    profile_snapshot: dict[str, Any] | None = None,
+    source: str | None = None,
+    multitrack: bool = False,
+    channel_labels: list[str] | None = None,
+    owner: str | None = None,
+) -> None:
+    """
+    Run transcription in a background thread.
+
+    This is a synchronous function intended to be called via asyncio.to_thread().
+    It performs the full transcription pipeline and stores the result (or error)
+    in model_manager.job_tracker so that clients can poll for completion.
+    """
+    # Lazy import to avoid loading torch at module import time
+    from server.core.audio_utils import AudioDecodeError, load_audio
+
+    try:
+        # Progress callback to update job tracker with chunk progress
+        def on_progress(current: int, total: int) -> None:
+            model_manager.job_tracker.update_progress(current, total)
+
+        # Get transcription engine, lazily reloading the model if a prior
+        # Live-Mode restore or sequential-diarization swap left it detached.
+        # See ModelManager.ensure_transcription_loaded() for the full rationale
+        # (Issue #76).
+        engine = model_manager.ensure_transcription_loaded()
+
+        # Resolve the diarization engine (funasr CAM++ single-pass vs pyannote
+        # two-pass) only when diarization is requested. For SenseVoice this honors
+        # the server-wide engine setting (config diarization.sensevoice_engine);
+        # every other integrated backend (WhisperX, VibeVoice) keeps its
+        # single-pass path unconditionally. This is a threaded worker with no
+        # request object, so the config default comes from the get_config()
+        # singleton rather than app.state.config.
+        backend = engine._backend
+        use_integrated_diarization = False
+        if enable_diarization and not multitrack:
+            from server.config import resolve_sensevoice_diarization_engine
+            from server.core.stt.backends.base import use_integrated_diarization_for
+
+            app_config = get_config()
+            sensevoice_engine_default = (
+                app_config.get("diarization", "sensevoice_engine", default="funasr")
+                if app_config is not None
+                else "funasr"
+            )
+            resolved_diar_engine = resolve_sensevoice_diarization_engine(
+                getattr(engine, "model_name", None),
+                None,
+                sensevoice_engine_default,
+                funasr_diar_available=getattr(backend, "_diarization_loaded", False),
+            )
+            use_integrated_diarization = use_integrated_diarization_for(
+                backend, resolved_diar_engine
+            )
+
+        # Run diarization if enabled
+        diarization_segments = None
+        diarization_outcome: dict[str, Any] = {
+            "requested": bool(enable_diarization),
+            "performed": False,
+            "reason": None,
+        }
+
+        if multitrack:
+            # --- Multitrack path: each channel is one speaker (interview mode) ---
+            from server.core.multitrack import transcribe_multitrack
+
+            logger.info(f"Transcribing multitrack upload for notebook: {filename}")
+            result = transcribe_multitrack(
+                engine,
+                str(tmp_path),
+                language=language,
+                task="translate" if translation_enabled else "transcribe",
+                translation_target_language=(
+                    translation_target_language if translation_enabled else None
+                ),
+                cancellation_check=model_manager.job_tracker.is_cancelled,
+                progress_callback=on_progress,
+                channel_labels=channel_labels,
+            )
+            # Channel-attributed segments take the place of diarization turns.
+            diarization_segments = result.segments or None
+            diarization_outcome = {"requested": False, "performed": False, "reason": "multitrack"}
+
+        if use_integrated_diarization:
+            # --- Integrated backend single-pass path (e.g. WhisperX, VibeVoice) ---
+            try:
+                backend_label = getattr(backend, "backend_name", "integrated")
+                logger.info(
+                    "Using %s single-pass diarization for: %s",
+                    backend_label,
+                    filename,
+                )
+                preferred_rate = int(
+                    getattr(backend, "preferred_input_sample_rate_hz", 16000) or 16000
+                )
+                audio_data, audio_sample_rate = load_audio(
+                    str(tmp_path), target_sample_rate=preferred_rate
+                )
+
+                diar_result = backend.transcribe_with_diarization(
+                    audio_data,
+                    audio_sample_rate=audio_sample_rate,
+                    language=language,
+                    task="translate" if translation_enabled else "transcribe",
+                    beam_size=engine.beam_size,
+                    num_speakers=expected_speakers,
+                    progress_callback=on_progress,
+                )
+
+                from server.core.stt.engine import TranscriptionResult
+
+                result = TranscriptionResult(
+                    text=" ".join(seg.get("text", "") for seg in diar_result.segments).strip(),
+                    segments=diar_result.segments,
+                    words=diar_result.words,
+                    language=diar_result.language,
+                    language_probability=diar_result.language_probability,
+                    duration=len(audio_data) / audio_sample_rate,
+                    num_speakers=diar_result.num_speakers,
+                )
+
+                diarization_segments = diar_result.segments
+                diarization_outcome["performed"] = True
+                diarization_outcome["reason"] = "ready"
+                logger.info(
+                    "%s diarization complete: %s speakers found",
+                    backend_label,
+                    diar_result.num_speakers,
+                )
+
+            except AudioDecodeError:
+                # A corrupt/undecodable file is not a diarization-token problem —
+                # re-raise the clean error rather than mislabeling it (FINDING #1).
+                raise
+            except ValueError as e:
+                logger.error(f"Diarization requires HuggingFace token: {e}")
+                logger.error("Set HUGGINGFACE_TOKEN env var when starting docker compose")
+                diarization_outcome["reason"] = model_manager.get_diarization_feature_status().get(
+                    "reason", "token_missing"
+                )
+                # Fall back to transcription without diarization
+                use_integrated_diarization = False
+            except Exception as e:
+                logger.error("Integrated backend diarization failed (continuing without): %s", e)
+                diarization_outcome["reason"] = "unavailable"
+                # Fall back to transcription without diarization
+                use_integrated_diarization = False
+
+        if not use_integrated_diarization and not multitrack:
+            # --- Standard path (NeMo backends or WhisperX fallback) ---
+            # Force word timestamps if diarization is enabled
+            # (needed for proper text-to-speaker alignment, even if user doesn't want to save words)
+            need_word_timestamps = enable_word_timestamps or enable_diarization
+
+            if enable_diarization and not diarization_outcome["performed"]:
+                # Resolve parallel vs sequential diarization
+                use_parallel = (
+                    parallel_diarization
+                    if parallel_diarization is not None
+                    else use_parallel_default
+                )
+
+                if use_parallel:
+                    from server.core.parallel_diarize import transcribe_and_diarize
+
+                    diarize_fn = transcribe_and_diarize
+                else:
+                    from server.core.parallel_diarize import transcribe_then_diarize
+
+                    diarize_fn = transcribe_then_diarize
+
+                result, diar_result = diarize_fn(
+                    engine=engine,
+                    model_manager=model_manager,
+                    file_path=str(tmp_path),
+                    language=language,
+                    task="translate" if translation_enabled else "transcribe",
+                    translation_target_language=(
+                        translation_target_language if translation_enabled else None
+                    ),
+                    word_timestamps=need_word_timestamps,
+                    expected_speakers=expected_speakers,
+                    progress_callback=on_progress,
+                )
+
+                if diar_result is not None:
+                    diarization_segments = [seg.to_dict() for seg in diar_result.segments]
+                    diarization_outcome["performed"] = True
+                    diarization_outcome["reason"] = "ready"
+                    logger.info(
+                        "Diarization complete: %s speakers found",
+                        diar_result.num_speakers,
+                    )
+                else:
+                    diarization_outcome["reason"] = (
+                        model_manager.get_diarization_feature_status().get("reason", "unavailable")
+                    )
+            else:
+                # Transcribe without diarization; out-of-memory retries in chunks
+                # or with a smaller model (core/oom_fallback.py).
+                from server.core.oom_fallback import fallback_settings, transcribe_with_fallback
+
+                logger.info(f"Transcribing uploaded file for notebook: {filename}")
+                result, engine = transcribe_with_fallback(
+                    model_manager,
+                    engine,
+                    str(tmp_path),
+                    settings=fallback_settings(model_manager.config),
+                    language=language,
+                    task="translate" if translation_enabled else "transcribe",
+                    translation_target_language=(
+                        translation_target_language if translation_enabled else None
+                    ),
+                    word_timestamps=need_word_timestamps,
+                    progress_callback=on_progress,
+                    cancellation_check=model_manager.job_tracker.is_cancelled,
+                )
+
+        # Determine recorded_at timestamp
+        recorded_at = None
+        if file_created_at:
+            try:
+                recorded_at = datetime.fromisoformat(file_created_at.replace("Z", "+00:00"))
+            except ValueError:
+                logger.warning(
+                    f"Invalid file_created_at format: {sanitize_for_log(file_created_at)}"
+                )
+
+        # Check for time slot overlap before saving
+        check_time = recorded_at or datetime.now()
+        overlap = check_time_slot_overlap(check_time, result.duration)
+        if overlap:
+            overlap_title = overlap.get("title") or overlap.get("filename", "Unknown")
+            raise ValueError(
+                f"Time slot conflict: overlaps with existing recording '{overlap_title}' "
+                f"(recorded at {overlap.get('recorded_at', 'unknown time')})"
+            )
+
+        dest_path = _store_notebook_audio(tmp_path, filename)
+
+        # Extract word timestamps from segments
+        # Diarization automatically enables word timestamps (they're needed for alignment anyway)
+        word_timestamps_list = None
+
+        # Extract words from segments if they were computed
+        if result.segments and "words" in result.segments[0]:
+            word_timestamps_list = []
+            for seg in result.segments:
+                if "words" in seg:
+                    word_timestamps_list.extend(seg["words"])
+
+        # When diarization was performed but no word timestamps are available
+        # (e.g. MLX Canary backend), fall back to segment-level speaker
+        # attribution so that the DB segments carry text instead of being empty.
+        if diarization_segments and not word_timestamps_list:
+            from server.core.speaker_merge import build_speaker_segments_nowords
+
+            diarization_segments = build_speaker_segments_nowords(
+                result.segments, diarization_segments
+            )
+
+        # Save to database
+        # Use provided title if given, otherwise database falls back to filename stem
+        partial_until: float | None = None
+        if getattr(result, "partial", False):
+            # A chunking backend failed partway through long audio; persist the
+            # completed transcript rather than lose it (GH #168 follow-up) and
+            # remember how far it reaches so it can be resumed.
+            completed = getattr(result, "completed_seconds", None)
+            if completed is None:
+                completed = max((float(s.get("end") or 0.0) for s in result.segments), default=0.0)
+            partial_until = round(float(completed), 3)
+            logger.warning(
+                "Saving a PARTIAL transcript for notebook recording (%.0fs of %.0fs): %s",
+                partial_until,
+                result.duration,
+                getattr(result, "partial_reason", None),
+            )
+        clean_title = title.strip() if title else None
+        transcription_backend = detect_backend_type(getattr(engine, "model_name", "") or "")
+        recording_id = save_longform_to_database(
+            audio_path=dest_path,
+            duration_seconds=result.duration,
+            transcription_text=result.text,
+            word_timestamps=word_timestamps_list,
+            diarization_segments=diarization_segments,
+            recorded_at=recorded_at,
+            title=clean_title or None,
+            transcription_backend=transcription_backend,
+            audio_hash=audio_hash,
+            normalized_audio_hash=normalized_audio_hash,
+            owner=owner,
+        )
+
+        if not recording_id:
+            raise RuntimeError("Failed to save recording to database")
+
+        if fingerprint:
+            from server.database.fingerprint_repository import save_fingerprint
+
+            try:
+                save_fingerprint(
+                    recording_id, pack_fingerprint(fingerprint), fingerprint_seconds(fingerprint)
+                )
+            except Exception as e:
+                # Only copy detection loses out; the recording is saved.
+                logger.warning(f"Failed to store fingerprint for recording {recording_id}: {e}")
+
+        if metadata:
+            try:
+                metadata_repository.set_metadata(recording_id, metadata)
+            except Exception as e:
+                # The fields can be filled in again from the recording view.
+                logger.warning(f"Failed to store metadata for recording {recording_id}: {e}")
+
+        if partial_until is not None:
+            set_recording_partial(
+                recording_id, partial_until, getattr(result, "partial_reason", None)
+            )
+
+        # Store successful result for client polling
+        model_manager.job_tracker.end_job(
+            job_id,
+            result={
+                "job_id": job_id[:8],
+                "recording_id": recording_id,
+                "message": f"Successfully transcribed and saved: {filename}",
+                "diarization": diarization_outcome,
+                "partial": partial_until is not None,
+                "partial_until": partial_until,
+                "downgrade": getattr(result, "downgrade", None),
+            },
+        )
+        logger.info(
+            f"Background transcription job {job_id[:8]} completed: recording_id={recording_id}"
+        )
+
+        # Issue #104, Story 5.6 — diarization-review lifecycle hook +
+        # Story 6.2 / 6.3 — auto-action coordinator dispatch.
+        # Both fire AFTER the transcript has been committed (Persist-Before-Deliver).
+        if event_loop is not None:
+            from server.core.auto_action_coordinator import trigger_auto_actions
+            from server.core.diarization_confidence import (
+                LOW_CONFIDENCE_THRESHOLD,
+                per_turn_confidence,
+            )
+            from server.core.diarization_review_lifecycle import on_transcription_complete
+            from server.database.database import get_segments, get_words
+
+            try:
+                segments_for_conf = get_segments(recording_id)
+                words_for_conf = get_words(recording_id)
+                turns = per_turn_confidence(segments_for_conf, words_for_conf)
+                has_low_conf = any(t["confidence"] < LOW_CONFIDENCE_THRESHOLD for t in turns)
+                on_transcription_complete(recording_id, has_low_conf)
+            except Exception:
+                # Lifecycle bookkeeping must never crash the transcription pipeline.
+                logger.exception(
+                    "diarization-review lifecycle hook failed for recording %d",
+                    recording_id,
+                )
+
+            # Fire-and-forget the auto-action coordinator on the main loop.
+            # Disabled toggles are a no-op inside the coordinator.
+            try:
+                asyncio.run_coroutine_threadsafe(
+                    trigger_auto_actions(recording_id, profile_snapshot),
+                    event_loop,
+                )
+            except Exception:
+                logger.exception(
+                    "trigger_auto_actions dispatch failed for recording %d",
+                    recording_id,
+                )
+
+            # Post-transcription rules (core/post_transcription_rules.py) —
+            # same fire-and-forget contract as the auto-action coordinator.
+            from server.core.post_transcription_rules import JobContext
+
+            try:
+                asyncio.run_coroutine_threadsafe(
+                    _run_post_transcription_rules(
+                        JobContext(
+                            recording_id=recording_id,
+                            source=source,
+                            filename=filename or "",
+                            language=result.language,
+                            duration=float(result.duration or 0.0),
+                        )
+                    ),
+                    event_loop,
+                )
+            except Exception:
+                logger.exception(
+                    "post-transcription rules dispatch failed for recording %d",
+                    recording_id,
+                )
+
+        # Fire outgoing webhook (background thread — use fire-and-forget)
+        if event_loop is not None:
+            from server.core.webhook import dispatch_fire_and_forget
+
+            dispatch_fire_and_forget(
+                event_loop,
+                "longform_complete",
+                {
+                    "source": "longform",
+                    "text": result.text,
+                    "filename": filename or "",
+                    "duration": result.duration,
+                    "language": result.language,
+                    "num_speakers": result.num_speakers,
+                },
+            )
+
+    except Exception as e:
+        logger.error(f"Background transcription job {job_id[:8]} failed: {e}", exc_info=True)
+        # Surface the BackendDependencyError remedy so the dashboard can render
+        # an actionable hint instead of just the bare error string (Issue #76).
+        from server.core.stt.backends.base import BackendDependencyError
+
+        dep_error: BackendDependencyError | None = None
+        if isinstance(e, BackendDependencyError):
+            dep_error = e
+        elif isinstance(e.__cause__, BackendDependencyError):
+            dep_error = e.__cause__
+        error_payload: dict[str, Any] = {
+            "job_id": job_id[:8],
+            "error": str(e),
+        }
+        if dep_error is not None:
+            error_payload["remedy"] = dep_error.remedy
+            error_payload["backend_type"] = dep_error.backend_type
+        # Store error result for client polling
+        model_manager.job_tracker.end_job(
+            job_id,
+            result=error_payload,
+        )
+
+    finally:
+        # Cleanup temp file
+        try:
+            tmp_path.unlink()
+        except Exception as e:
+            logger.warning(f"Failed to cleanup temp file {tmp_path}: {e}")
+
+
+@router.post("/transcribe/upload", response_model=AcceptedResponse, status_code=202)
+async def upload_and_transcribe(
+    request: Request,
+    file: Annotated[UploadFile | None, File()] = None,
+    language: str | None = Form(None),
+    translation_enabled: bool = Form(False),
+    translation_target_language: str | None = Form(None),
+    enable_diarization: bool = Form(False),
+    enable_word_timestamps: bool = Form(True),
+    file_created_at: str | None = Form(None),
+    expected_speakers: int | None = Form(None),
+    parallel_diarization: bool | None = Form(None),
+    title: str | None = Form(None),
+    profile_id: int | None = Form(None),
+    source: str | None = Form(None),
+    multitrack: bool = Form(False),
+    channel_labels: list[str] | None = Form(None),  # noqa: B008
+    queue_entry_id: str | None = Form(None),
+    upload_id: str | None = Form(None),
+    metadata: str | None = Form(None),
+) -> dict[str, Any]:
+    """
+    Upload an audio file and start transcription in the background.
+
+    Returns 202 Accepted immediately with a job_id. Clients should poll
+    GET /api/admin/status to check job_tracker.result for completion.
+
+    Parameters:
+    - expected_speakers: Exact number of speakers (2-10). Forces diarization to
+      identify exactly this many speakers. Useful for podcasts with known hosts
+      where occasional clips should be attributed to the main speakers.
+    - parallel_diarization: Override the server default for parallel vs sequential
+      diarization. When False, transcription completes before diarization starts
+      (lower VRAM usage). When None, uses the server config default.
+    - source: Folder Watch folder the file came from (unset for manual uploads);
+      matched by ``post_transcription_rules``.
+    - multitrack: Transcribe each channel separately and attribute its words to
+      one speaker instead of diarizing (interview mode records the microphone
+      and the system audio as the two channels of one file).
+    - channel_labels: Speaker name per channel, repeated once per channel in
+      file order; unnamed channels become "Speaker N".
+    - queue_entry_id: The caller's entry in ``/api/transcribe/queue`` once it
+      has reached the front.
+    - upload_id: A file staged with ``POST /api/transcribe/uploads``, sent
+      instead of ``file``.
+    - metadata: JSON object of custom fields stored on the recording, e.g.
+      a project template's pre-filled ``{"Client": "Acme"}``.
+
+    Returns 409 Conflict if another transcription job is already running.
+    """
+    check_upload_source(request, file, upload_id)
+
+    # Validate expected_speakers parameter
+    if expected_speakers is not None:
+        if expected_speakers < 1 or expected_speakers > 10:
+            raise HTTPException(
+                status_code=400,
+                detail="expected_speakers must be between 1 and 10",
+            )
+
+    recording_metadata = _parse_metadata_form(metadata)
+
+    # Get model manager and check if busy
+    model_manager = request.app.state.model_manager
+
+    # Try to acquire a job slot
+    success, job_id, active_user = model_manager.job_tracker.try_start_job(
+        get_requester(request), queue_entry_id
+    )
+    if not success:
+        raise HTTPException(
+            status_code=409,
+            detail=f"A transcription is already running for {active_user}",
+        )
+
+    # Save uploaded file to temp location (fast — just I/O)
+    try:
+        tmp_path, filename = await receive_upload(request, file, upload_id)
+    except HTTPException as e:
+        model_manager.job_tracker.end_job(job_id, {"job_id": job_id, "error": e.detail})
+        raise
+
+    # Compute audio_hash for dedup (Issue #104, Sprint 2 carve-out — Item 2).
+    # Hash the raw upload bytes — same approach as /api/transcribe/import. The
+    # hash is written atomically with the recordings row in
+    # save_longform_to_database; the dashboard can later call dedup-check to
+    # match notebook recordings against transcription_jobs and other notebook
+    # recordings.
+    # Sprint 2 Item 3 — also compute the normalized PCM hash for
+    # format-agnostic dedup. ffmpeg failure → NULL second hash, upload still
+    # proceeds.
+    from server.core.audio_utils import (
+        compute_normalized_pcm_hash as _norm_sha,
+    )
+    from server.core.audio_utils import sha256_streaming as _sha
+
+    try:
+        audio_hash: str | None = _sha(tmp_path)
+    except OSError as hash_err:
+        # Tempfile vanished or unreadable — release the job slot and surface
+        # the failure before any DB row is created (mirrors the request-path
+        # invariant: failed pre-conditions never produce orphan records).
+        try:
+            tmp_path.unlink(missing_ok=True)
+        except OSError:
+            # Best-effort cleanup; the OS reaps tempfiles on reboot and the
+            # caller is already raising 500 — leaking a temp byte stream
+            # here must not mask the real error returned to the client.
+            pass
+        model_manager.job_tracker.cancel_job()
+        raise HTTPException(
+            status_code=500,
+            detail="Failed to compute audio hash for upload",
+        ) from hash_err
+    normalized_audio_hash: str | None = _norm_sha(tmp_path)
+
+    # Re-encoded or trimmed copies of earlier recordings: the client may
+    # cancel this job and reuse the match's transcript instead.
+    fingerprint = await asyncio.to_thread(compute_fingerprint, tmp_path)
+    try:
+        dedup_matches = await asyncio.to_thread(fingerprint_dedup_matches, fingerprint)
+    except Exception as e:
+        logger.warning(f"Fingerprint dedup failed for job {job_id[:8]}: {e}")
+        dedup_matches = []
+
+    # Resolve parallel diarization default from config before entering background thread
+    config = request.app.state.config
+    use_parallel_default = resolve_parallel_diarization_default(config)
+
+    # Issue #104, Story 6.2 — snapshot the profile at upload time so the
+    # background thread (which may finish minutes later) sees the SAME
+    # toggles even if the user edits the profile during transcription.
+    profile_snapshot: dict[str, Any] | None = None
+    if profile_id is not None:
+        from server.database import profile_repository
+
+        profile_row = profile_repository.get_profile(profile_id)
+        if profile_row is not None:
+            profile_snapshot = {
+                "profile_id": profile_id,
+                "schema_version": profile_row.get("schema_version"),
+                "public_fields": profile_row.get("public_fields") or {},
+            }
+
+    # Capture event loop for webhook dispatch from background thread
+    loop = asyncio.get_running_loop()
+
+    # Launch background transcription task (runs on thread pool, doesn't block event loop)
+    loop.create_task(
+        asyncio.to_thread(
+            _run_transcription,
+            model_manager=model_manager,
+            tmp_path=tmp_path,
+            filename=filename,
+            language=language,
+            translation_enabled=translation_enabled,
+            translation_target_language=translation_target_language,
+            enable_diarization=enable_diarization,
+            enable_word_timestamps=enable_word_timestamps,
+            file_created_at=file_created_at,
+            expected_speakers=expected_speakers,
+            parallel_diarization=parallel_diarization,
+            use_parallel_default=use_parallel_default,
+            title=title,
+            job_id=job_id,
+            event_loop=loop,
+            audio_hash=audio_hash,
+            normalized_audio_hash=normalized_audio_hash,
+            fingerprint=fingerprint,
+            metadata=recording_metadata,
+            profile_snapshot=profile_snapshot,
+            source=source.strip() if source else None,
+            multitrack=multitrack,
+            channel_labels=channel_labels,
+            owner=get_user_name(request),
+        )
+    )
+
+    # Return immediately — client polls /api/admin/status for result
+    return {"job_id": job_id[:8], "dedup_matches": dedup_matches}
+
+
+def _parse_metadata_form(raw: str | None) -> dict[str, str]:
+    """The upload's ``metadata`` form field: a JSON object of field -> value."""
+    if not raw:
+        return {}
+    try:
+        parsed = json.loads(raw)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail="metadata must be a JSON object") from e
+    if not isinstance(parsed, dict):
+        raise HTTPException(status_code=400, detail="metadata must be a JSON object")
+    try:
+        return metadata_repository.normalize_metadata(parsed)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+
+
+class NotebookBusyError(RuntimeError):
+    """Another transcription holds the server's single job slot."""
+
+
+async def transcribe_file_into_notebook(
+    model_manager: Any,
+    path: Path,
+    *,
+    filename: str,
+    title: str | None = None,
+    source: str | None = None,
+    language: str | None = None,
+    enable_diarization: bool = False,
+    client_name: str = "server",
+) -> dict[str, Any]:
+    """Transcribe a server-side file into the notebook and wait for the result.
+
+    The in-process counterpart of ``POST /transcribe/upload`` for server-side
+    producers (e.g. :mod:`server.core.mail`). ``path`` is copied, never
+    consumed. Returns the job result — ``recording_id`` on success, ``error``
+    otherwise — and raises :class:`NotebookBusyError` when another job runs.
+    """
+    from server.core.audio_utils import compute_normalized_pcm_hash, sha256_streaming
+
+    success, job_id, active_user = model_manager.job_tracker.try_start_job(client_name)
+    if not success:
+        raise NotebookBusyError(f"A transcription is already running for {active_user}")
+
+    tmp_path: Path | None = None
+    try:
+        with tempfile.NamedTemporaryFile(delete=False, suffix=path.suffix or ".wav") as tmp:
+            tmp_path = Path(tmp.name)
+        shutil.copyfile(path, tmp_path)
+        audio_hash = sha256_streaming(tmp_path)
+    except OSError:
+        # Same invariant as the upload route: no job slot or temp file leaks
+        # when the input can't be read.
+        if tmp_path is not None:
+            tmp_path.unlink(missing_ok=True)
+        model_manager.job_tracker.end_job(job_id)
+        raise
+    normalized_audio_hash = compute_normalized_pcm_hash(tmp_path)
+    fingerprint = await asyncio.to_thread(compute_fingerprint, tmp_path)
+
+    await asyncio.to_thread(
+        _run_transcription,
+        model_manager=model_manager,
+        tmp_path=tmp_path,
+        filename=filename,
+        language=language,
+        translation_enabled=False,
+        translation_target_language=None,
+        enable_diarization=enable_diarization,
+        enable_word_timestamps=True,
+        file_created_at=None,
+        expected_speakers=None,
+        parallel_diarization=None,
+        use_parallel_default=resolve_parallel_diarization_default(get_config()),
+        title=title,
+        job_id=job_id,
+        event_loop=asyncio.get_running_loop(),
+        audio_hash=audio_hash,
+        normalized_audio_hash=normalized_audio_hash,
+        fingerprint=fingerprint,
+        source=source,
+    )
+    result = model_manager.job_tracker.get_status().get("result") or {}
+    if result.get("job_id") != job_id[:8]:
+        return {"job_id": job_id[:8], "error": "job result was superseded"}
+    return result
+
+
+# ---------------------------------------------------------------------------
+# Subtitle import (SRT / VTT / ASS + media)
+# ---------------------------------------------------------------------------
+
+MAX_SUBTITLE_BYTES = 10 * 1024 * 1024
+
+
+class SubtitleImportResponse(BaseModel):
+    recording_id: int
+    format: str
+    cues: int
+    words: int
+    alignment: str
+
+
+def _decode_subtitle_bytes(raw: bytes) -> str:
+    try:
+        return raw.decode("utf-8-sig")
+    except UnicodeDecodeError:
+        # Older SRTs are frequently Windows-1252; latin-1 never fails.
+        return raw.decode("latin-1")
+
+
+@router.post("/import/subtitles", response_model=SubtitleImportResponse, status_code=201)
+async def import_subtitles(
+    request: Request,
+    file: Annotated[UploadFile, File(...)],
+    subtitles: Annotated[UploadFile, File(...)],
+    align: bool = Form(False),
+    language: str | None = Form(None),
+    title: str | None = Form(None),
+    file_created_at: str | None = Form(None),
+) -> SubtitleImportResponse:
+    """Create a notebook recording from a media file plus existing captions.
+
+    Cue text is kept verbatim. With ``align`` the server force-aligns the
+    text against the audio (WhisperX wav2vec2) to fix drifting timings;
+    otherwise — or when no aligner is installed — word timings are spread
+    proportionally inside each cue. Returns 409 while a transcription job
+    holds the GPU and ``align`` is requested.
+    """
+    from server.core.audio_utils import (
+        compute_normalized_pcm_hash,
+        get_audio_duration,
+        sha256_streaming,
+    )
+    from server.core.forced_alignment import align_segments
+    from server.core.subtitle_import import detect_format, parse_subtitles
+
+    if not file.filename or not subtitles.filename:
+        raise HTTPException(status_code=400, detail="Both a media file and subtitles are required")
+
+    raw_subs = await subtitles.read()
+    if len(raw_subs) > MAX_SUBTITLE_BYTES:
+        raise HTTPException(status_code=413, detail="Subtitle file is too large")
+    content = _decode_subtitle_bytes(raw_subs)
+    fmt = detect_format(subtitles.filename, content)
+    try:
+        cues = parse_subtitles(content, fmt)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+
+    recorded_at = None
+    if file_created_at:
+        try:
+            recorded_at = datetime.fromisoformat(file_created_at.replace("Z", "+00:00"))
+        except ValueError:
+            logger.warning(f"Invalid file_created_at format: {sanitize_for_log(file_created_at)}")
+
+    job_tracker = request.app.state.model_manager.job_tracker
+    job_id: str | None = None
+    if align:
+        success, job_id, active_user = job_tracker.try_start_job(get_client_name(request))
+        if not success:
+            raise HTTPException(
+                status_code=409,
+                detail=f"A transcription is already running for {active_user}",
+            )
+
+    suffix = Path(file.filename).suffix or ".wav"
+    with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
+        await save_upload(file, tmp)
+        tmp_path = Path(tmp.name)
+
+    try:
+        audio_hash = sha256_streaming(tmp_path)
+        normalized_audio_hash = compute_normalized_pcm_hash(tmp_path)
+        try:
+            duration = get_audio_duration(str(tmp_path))
+        except OSError:  # ffprobe not installed
+            duration = 0.0
+        duration = duration or max(cue.end for cue in cues)
+        cue_segments = [
+            {"speaker": cue.speaker, "text": cue.text, "start": cue.start, "end": cue.end}
+            for cue in cues
+        ]
+        aligned = await asyncio.to_thread(
+            align_segments, tmp_path, cue_segments, language=language, use_model=align
+        )
+        dest_path = await asyncio.to_thread(_store_notebook_audio, tmp_path, file.filename)
+        recording_id = save_longform_to_database(
+            audio_path=dest_path,
+            duration_seconds=duration,
+            transcription_text=" ".join(seg["text"] for seg in aligned.segments),
+            word_timestamps=aligned.words,
+            diarization_segments=aligned.segments,
+            recorded_at=recorded_at,
+            title=(title or "").strip() or Path(file.filename).stem,
+            audio_hash=audio_hash,
+            normalized_audio_hash=normalized_audio_hash,
+            owner=get_user_name(request),
+        )
+    finally:
+        if job_id is not None:
+            job_tracker.end_job(job_id)
+        try:
+            tmp_path.unlink()
+        except OSError as e:
+            logger.warning(f"Failed to cleanup temp file {tmp_path}: {e}")
+
+    if not recording_id:
+        raise HTTPException(status_code=500, detail="Failed to save imported subtitles")
+
+    logger.info(
+        "Imported %d %s cues as recording %d (alignment=%s)",
+        len(cues),
+        fmt,
+        recording_id,
+        aligned.method,
+    )
+    return SubtitleImportResponse(
+        recording_id=recording_id,
+        format=fmt,
+        cues=len(aligned.segments),
+        words=len(aligned.words),
+        alignment=aligned.method,
+    )
+
+
+# ---------------------------------------------------------------------------
+# Legacy transcript import (Whisper JSON / Otter / SBV / Descript, media optional)
+# ---------------------------------------------------------------------------
+
+
+class TranscriptImportResponse(BaseModel):
+    recording_id: int
+    format: str
+    segments: int
+    words: int
+    alignment: str
+    has_audio: bool
+
+
+@router.post("/import/transcript", response_model=TranscriptImportResponse, status_code=201)
+async def import_transcript(
+    request: Request,
+    transcript: Annotated[UploadFile, File(...)],
+    file: Annotated[UploadFile | None, File()] = None,
+    format: str | None = Form(None),
+    align: bool = Form(False),
+    language: str | None = Form(None),
+    title: str | None = Form(None),
+    recorded_at: str | None = Form(None),
+) -> TranscriptImportResponse:
+    """Create a notebook recording from another tool's transcript export.
+
+    ``format`` is auto-detected unless given (see ``TRANSCRIPT_FORMATS``).
+    The media file is optional: without it the recording is text-only and
+    its audio endpoint answers 404. Word timings come from the transcript
+    when it has them (Whisper JSON), from the forced aligner with ``align``
+    and media, and are otherwise spread proportionally inside each segment.
+    """
+    from server.core.forced_alignment import align_segments
+    from server.core.transcript_import import TRANSCRIPT_FORMATS, load_transcript
+
+    if not transcript.filename:
+        raise HTTPException(status_code=400, detail="A transcript file is required")
+    if format and format not in TRANSCRIPT_FORMATS:
+        raise HTTPException(status_code=400, detail=f"Unsupported transcript format: {format}")
+    try:
+        imported = load_transcript(transcript.filename, await transcript.read(), format)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+
+    when = None
+    if recorded_at:
+        try:
+            when = datetime.fromisoformat(recorded_at.replace("Z", "+00:00"))
+        except ValueError:
+            logger.warning(f"Invalid recorded_at format: {sanitize_for_log(recorded_at)}")
+
+    media = file if file is not None and file.filename else None
+    use_model = align and media is not None
+    job_tracker = request.app.state.model_manager.job_tracker
+    job_id: str | None = None
+    if use_model:
+        success, job_id, active_user = job_tracker.try_start_job(get_client_name(request))
+        if not success:
+            raise HTTPException(
+                status_code=409,
+                detail=f"A transcription is already running for {active_user}",
+            )
+
+    tmp_path: Path | None = None
+    audio_hash = normalized_audio_hash = None
+    duration = imported.duration
+    try:
+        if media is not None:
+            from server.core.audio_utils import (
+                compute_normalized_pcm_hash,
+                get_audio_duration,
+                sha256_streaming,
+            )
+
+            suffix = Path(media.filename or "").suffix or ".wav"
+            with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
+                tmp.write(await media.read())
+                tmp_path = Path(tmp.name)
+            audio_hash = sha256_streaming(tmp_path)
+            normalized_audio_hash = compute_normalized_pcm_hash(tmp_path)
+            try:
+                duration = get_audio_duration(str(tmp_path)) or duration
+            except OSError:  # ffprobe not installed
+                pass
+
+        if imported.words and not use_model:
+            segments, words, method = imported.segments, imported.words, "source"
+        else:
+            aligned = await asyncio.to_thread(
+                align_segments,
+                tmp_path,
+                imported.segments,
+                language=language or imported.language,
+                use_model=use_model,
+            )
+            segments, words, method = aligned.segments, aligned.words, aligned.method
+
+        if media is not None and tmp_path is not None:
+            audio_path = await asyncio.to_thread(_store_notebook_audio, tmp_path, media.filename)
+        else:
+            # Text-only: a path no upload is ever stored under, so playback 404s.
+            audio_path = _notebook_audio_dir() / f"{Path(transcript.filename).stem}.transcript"
+        recording_id = save_longform_to_database(
+            audio_path=audio_path,
+            duration_seconds=duration,
+            transcription_text=" ".join(seg["text"] for seg in segments),
+            word_timestamps=words,
+            diarization_segments=segments,
+            recorded_at=when,
+            title=(title or "").strip() or Path(transcript.filename).stem,
+            audio_hash=audio_hash,
+            normalized_audio_hash=normalized_audio_hash,
+            owner=get_user_name(request),
+        )
+    finally:
+        if job_id is not None:
+            job_tracker.end_job(job_id)
+        if tmp_path is not None:
+            try:
+                tmp_path.unlink()
+            except OSError as e:
+                logger.warning(f"Failed to cleanup temp file {tmp_path}: {e}")
+
+    if not recording_id:
+        raise HTTPException(status_code=500, detail="Failed to save imported transcript")
+
+    logger.info(
+        "Imported %d %s segments as recording %d (words=%s, audio=%s)",
+        len(segments),
+        imported.format,
+        recording_id,
+        method,
+        media is not None,
+    )
+    return TranscriptImportResponse(
+        recording_id=recording_id,
+        format=imported.format,
+        segments=len(segments),
+        words=len(words),
+        alignment=method,
+        has_audio=media is not None,
+    )
+
+
+# ---------------------------------------------------------------------------
+# Project bundles (.tsproj) — a whole recording, lossless (core/project_bundle.py)
+# ---------------------------------------------------------------------------
+
+
+class ProjectImportResponse(BaseModel):
+    recording_id: int
+    segments: int
+    words: int
+    annotations: int
+    redactions: int
+    media: str
+    has_audio: bool
+
+
+@router.get("/recordings/{recording_id}/project")
+async def export_project_bundle(
+    recording_id: int,
+    embed_media: bool = Query(
+        True, description="embed the audio; otherwise keep a path + SHA-256 reference"
+    ),
+) -> Response:
+    """The recording as a ``.tsproj`` bundle: media, transcript, edits and notes."""
+    from starlette.background import BackgroundTask
+
+    from server import __version__
+    from server.core.project_bundle import PROJECT_EXTENSION, export_project
+
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
+
+    fd, tmp_name = tempfile.mkstemp(suffix=PROJECT_EXTENSION)
+    os.close(fd)
+    tmp_path = Path(tmp_name)
+    try:
+        await asyncio.to_thread(
+            export_project,
+            recording_id,
+            tmp_path,
+            embed_media=embed_media,
+            app_version=__version__,
+        )
+    except StorageEncryptionError as e:
+        tmp_path.unlink(missing_ok=True)
+        raise HTTPException(status_code=409, detail=str(e)) from e
+    except Exception:
+        tmp_path.unlink(missing_ok=True)
+        raise
+    stem = (recording.get("title") or f"recording_{recording_id}").replace(" ", "_")
+    return FileResponse(
+        path=tmp_path,
+        filename=f"{stem}{PROJECT_EXTENSION}",
+        media_type="application/zip",
+        background=BackgroundTask(tmp_path.unlink, missing_ok=True),
+    )
+
+
+@router.post("/import/project", response_model=ProjectImportResponse, status_code=201)
+async def import_project_bundle(
+    request: Request,
+    bundle: Annotated[UploadFile, File(...)],
+) -> ProjectImportResponse:
+    """Create a recording from a ``.tsproj`` bundle.
+
+    The bundle is verified member by member before anything is written. A
+    referenced (not embedded) media file is picked up when it is still at
+    its original path with the same hash; otherwise the recording is
+    text-only and its audio endpoint answers 404.
+    """
+    from server.core.project_bundle import (
+        PROJECT_EXTENSION,
+        ProjectBundleError,
+        read_project,
+        restore_media,
+        restore_project,
+        text_only_path,
+    )
+
+    fd, tmp_name = tempfile.mkstemp(suffix=PROJECT_EXTENSION)
+    tmp_path = Path(tmp_name)
+    audio_path: Path | None = None
+    has_audio = False
+    recording_id = 0
+    try:
+        with os.fdopen(fd, "wb") as out:
+            await asyncio.to_thread(shutil.copyfileobj, bundle.file, out)
+        try:
+            parsed = await asyncio.to_thread(read_project, tmp_path)
+        except ProjectBundleError as e:
+            raise HTTPException(status_code=400, detail=str(e)) from e
+
+        audio_dir = _notebook_audio_dir()
+        audio_path = await asyncio.to_thread(restore_media, tmp_path, parsed, audio_dir)
+        has_audio = audio_path is not None
+        if audio_path is None:
+            audio_path = text_only_path(audio_dir, parsed)
+        recording_id = await asyncio.to_thread(
+            restore_project, parsed.project, audio_path, owner=get_user_name(request)
+        )
+        if has_audio:
+            await asyncio.to_thread(encrypt_at_rest, audio_path)
+    finally:
+        tmp_path.unlink(missing_ok=True)
+        if has_audio and not recording_id and audio_path is not None:
+            audio_path.unlink(missing_ok=True)
+
+    project = parsed.project
+    logger.info(
+        "Imported project bundle as recording %d (media=%s, audio=%s)",
+        recording_id,
+        parsed.media.get("mode"),
+        has_audio,
+    )
+    return ProjectImportResponse(
+        recording_id=recording_id,
+        segments=len(project.get("segments") or []),
+        words=len(project.get("words") or []),
+        annotations=len(project.get("annotations") or []),
+        redactions=len(project.get("redactions") or []),
+        media=str(parsed.media.get("mode") or "none"),
+        has_audio=has_audio,
+    )
+
+
+@router.get("/calendar")
+async def get_calendar_data(
+    year: int = Query(..., description="Year"),
+    month: int = Query(..., description="Month (1-12)"),
+) -> dict[str, Any]:
+    """
+    Get recordings grouped by day for calendar view.
+    """
+    try:
+        # Get date range for the month
+        start_date = f"{year:04d}-{month:02d}-01"
+        if month == 12:
+            end_date = f"{year + 1:04d}-01-01"
+        else:
+            end_date = f"{year:04d}-{month + 1:02d}-01"
+
+        recordings = get_recordings_by_date_range(start_date, end_date)
+
+        # Group by day
+        days: dict[str, list[dict[str, Any]]] = {}
+        for rec in recordings:
+            recorded_at = rec.get("recorded_at", "")
+            if recorded_at:
+                day = recorded_at[:10]  # YYYY-MM-DD
+                if day not in days:
+                    days[day] = []
+                days[day].append(rec)
+
+        return {
+            "year": year,
+            "month": month,
+            "days": days,
+            "total_recordings": len(recordings),
+        }
+
+    except Exception as e:
+        logger.error(f"Failed to get calendar data: {e}")
+        raise HTTPException(status_code=500, detail=str(e)) from e
+
+
+@router.get("/timeslot")
+async def get_timeslot_info(
+    date: str = Query(..., description="Date in YYYY-MM-DD format"),
+    hour: int = Query(..., ge=0, le=23, description="Hour (0-23)"),
+) -> dict[str, Any]:
+    """
+    Get information about a specific time slot.
+
+    Returns:
+    - recordings: List of recordings in this slot
+    - next_available: ISO timestamp of next available start time (or null if full)
+    - total_duration: Total duration of recordings in seconds
+    - available_seconds: Remaining seconds available in the slot
+    - is_full: Whether the slot is completely full
+    """
+    try:
+        info = get_time_slot_info(date, hour)
+        return info
+
+    except Exception as e:
+        logger.error(f"Failed to get time slot info: {e}")
+        raise HTTPException(status_code=500, detail=str(e)) from e
+
+
+@router.get("/recordings/{recording_id}/export")
+async def export_recording(
+    recording_id: int,
+    format: str = Query(
+        "txt",
+        description=(
+            "Export format: 'txt', 'srt', 'ass', 'plaintext', 'accessible', 'html', "
+            "'edl', 'premiere_xml', 'resolve_csv', or 'audacity'"
+        ),
+    ),
+    speaker_style: str = Query("bold", description="html only: 'bold', 'caps', or 'plain'"),
+    timestamps: bool = Query(True, description="html only: include the timestamp column"),
+    font_size: int = Query(11, description="html only: body font size in points (8-18)"),
+    page_size: str = Query("A4", description="html only: 'A4', 'Letter', or 'Legal'"),
+    q: str | None = Query(
+        None,
+        description="marker formats only: one marker per keyword hit instead of per speaker turn",
+    ),
+    annotations: bool = Query(
+        False, description="txt / ass only: include review notes (see core/annotation_export.py)"
+    ),
+    redact: bool = Query(True, description="mask redacted ranges (see core/redaction.py)"),
+    caption_profile: str | None = Query(
+        None, description="srt / ass only: re-wrap cue lines to a caption profile"
+    ),
+    speaker_stats: bool = Query(
+        False, description="html / plaintext only: include per-speaker statistics"
+    ),
+) -> Response:
+    """
+    Export a recording's transcription.
+
+    Includes:
+    - Recording metadata (title, date, duration)
+    - Full transcription text
+    - Subtitle cue rendering from word-level timestamps (if present)
+    - Speaker labels (if diarization is present)
+
+    Formats:
+    - txt: Verbose human-readable text — full metadata header + transcript
+    - plaintext: FR9 streaming format — paragraph-per-speaker-turn, no
+      subtitle timestamps, no metadata header. Used by the Sprint 2
+      "Download transcript" button (Issue #104, Story 3.4).
+    - accessible: screen-reader text — speaker named on every turn, no
+      timestamps or markdown, duration in words (see core/accessibility.py)
+    - srt: SubRip subtitle format
+    - ass: Advanced SubStation Alpha subtitle format
+    - html: Print-ready document; the dashboard renders it to PDF and adds
+      running header/footer + page numbers (see core/print_export.py)
+    - edl / premiere_xml / resolve_csv: NLE timeline markers, one per
+      speaker turn or per ``q`` keyword hit (see core/marker_export.py)
+    - audacity: Audacity label track, one region label per segment
+      (see core/audacity_labels.py; re-import via POST .../labels)
+
+    ``annotations=true`` adds review notes to txt (an ANNOTATIONS section)
+    and ass (``Comment:`` events, which players ignore). SRT has no comment
+    syntax, so notes are never written there.
+
+    ``speaker_stats=true`` adds talk time, pace and interruptions per speaker
+    to html (a table above the transcript) and plaintext (a closing section);
+    see core/speaker_stats.py.
+
+    Redacted ranges are masked in every format unless ``redact=false``.
+    """
+    from server.core.marker_export import MARKER_FORMATS
+
+    requested_format = format.strip().lower()
+    supported = {
+        "txt",
+        "srt",
+        "ass",
+        "plaintext",
+        "accessible",
+        "html",
+        "audacity",
+        *MARKER_FORMATS,
+    }
+    if requested_format not in supported:
+        raise HTTPException(
+            status_code=400,
+            detail=(
+                "Unsupported export format. Supported formats: txt, plaintext, accessible, "
+                "srt, ass, html, edl, premiere_xml, resolve_csv, audacity."
+            ),
+        )
+
+    if requested_format == "audacity":
+        from server.core.alias_substitution import build_speaker_label_map
+        from server.core.audacity_labels import render_audacity_labels
+        from server.database import alias_repository
+
+        recording = get_recording(recording_id)
+        if not recording:
+            raise HTTPException(status_code=404, detail="Recording not found")
+        title = recording.get("title") or recording.get("filename") or "Recording"
+        segments, _ = _export_transcript(recording_id, redact)
+        label_map = build_speaker_label_map(segments, alias_repository.alias_map(recording_id))
+        return Response(
+            content=render_audacity_labels(segments, label_map=label_map),
+            media_type="text/plain; charset=utf-8",
+            headers={
+                "Content-Disposition": _content_disposition(
+                    "attachment", f"{title.replace(' ', '_')}_labels.txt"
+                ),
+            },
+        )
 
-        # Determine recorded_at timestamp
-        recorded_at = None
-        if file_created_at:
-            try:
-                recorded_at = datetime.fromisoformat(file_created_at.replace("Z", "+00:00"))
-            except ValueError:
-                logger.warning(
-                    f"Invalid file_created_at format: {sanitize_for_log(file_created_at)}"
-                )
+    if requested_format in MARKER_FORMATS:
+        return _export_markers(recording_id, requested_format, q, redact=redact)
 
-        # Check for time slot overlap before saving
-        check_time = recorded_at or datetime.now()
-        overlap = check_time_slot_overlap(check_time, result.duration)
-        if overlap:
-            overlap_title = overlap.get("title") or overlap.get("filename", "Unknown")
-            raise ValueError(
-                f"Time slot conflict: overlaps with existing recording '{overlap_title}' "
-                f"(recorded at {overlap.get('recorded_at', 'unknown time')})"
+    if requested_format == "html":
+        from server.core.alias_substitution import apply_aliases
+        from server.core.print_export import PrintOptions, render_print_html
+        from server.database import alias_repository
+
+        recording = get_recording(recording_id)
+        if not recording:
+            raise HTTPException(status_code=404, detail="Recording not found")
+        try:
+            print_options = PrintOptions(
+                speaker_style=speaker_style.strip().lower(),  # type: ignore[arg-type]
+                show_timestamps=timestamps,
+                font_size_pt=font_size,
+                page_size=page_size.strip(),  # type: ignore[arg-type]
             )
+        except ValueError as e:
+            raise HTTPException(status_code=400, detail=str(e)) from e
+        title = recording.get("title") or recording.get("filename") or "Recording"
+        content = render_print_html(
+            recording,
+            apply_aliases(
+                _export_transcript(recording_id, redact)[0],
+                alias_repository.alias_map(recording_id),
+            ),
+            print_options,
+            speaker_stats=_speaker_stats(recording_id) if speaker_stats else None,
+        )
+        return Response(
+            content=content,
+            media_type="text/html; charset=utf-8",
+            headers={
+                "Content-Disposition": _content_disposition(
+                    "inline", f"{title.replace(' ', '_')}.html"
+                ),
+            },
+        )
 
-        # Convert audio to MP3 and save to permanent storage
-        config = get_config()
-        _data_dir = os.environ.get("DATA_DIR", "/data")
-        audio_dir = Path(config.get("audio_notebook", "audio_dir", default=f"{_data_dir}/audio"))
-        audio_dir.mkdir(parents=True, exist_ok=True)
-
-        # Keep original filename, convert to .mp3 extension
-        # Sanitize filename to prevent path traversal
-        raw_stem = Path(filename or "audio").stem
-        # Remove any path separators and sanitize to alphanumeric + safe chars
-        original_stem = "".join(c for c in raw_stem if c.isalnum() or c in "._- ")[:100]
-        if not original_stem:
-            original_stem = "audio"
-        dest_filename = f"{original_stem}.mp3"
-        dest_path = audio_dir / dest_filename
+    # Story 3.4 — plaintext (and its screen-reader sibling, accessible) is a
+    # streaming response that bypasses the full materialization path used by
+    # txt/srt/ass. We branch early so we don't pay the cost of get_words() /
+    # cue building for plaintext.
+    if requested_format in ("plaintext", "accessible"):
+        recording = get_recording(recording_id)
+        if not recording:
+            raise HTTPException(status_code=404, detail="Recording not found")
+        from server.core.accessibility import stream_accessible
+        from server.core.alias_substitution import apply_aliases
+        from server.core.plaintext_export import stream_plaintext
+        from server.core.redaction import iter_export_segments
+        from server.database import alias_repository
 
-        # Handle duplicates by adding -2, -3, etc. suffix
-        counter = 2
-        while dest_path.exists():
-            dest_filename = f"{original_stem}-{counter}.mp3"
-            dest_path = audio_dir / dest_filename
-            counter += 1
+        title = recording.get("title") or recording.get("filename") or "Recording"
+        rendered_filename = (
+            f"{title.replace(' ', '_')}.txt" if title else f"recording_{recording_id}.txt"
+        )
+        render = stream_accessible if requested_format == "accessible" else stream_plaintext
+        # Story 5.1 — alias propagation. ``apply_aliases`` is a lazy
+        # generator over ``iter_segments``, so the bounded-RAM property
+        # of the streaming exporter is preserved (redacted recordings are
+        # materialized — see ``iter_export_segments``).
+        aliases = alias_repository.alias_map(recording_id)
+        body = render(
+            recording,
+            apply_aliases(iter_export_segments(recording_id, enabled=redact), aliases),
+        )
+        if speaker_stats and requested_format == "plaintext":
+            body = _with_speaker_stats(body, recording_id)
+        return StreamingResponse(
+            body,
+            media_type="text/plain; charset=utf-8",
+            headers={
+                "Content-Disposition": _content_disposition("attachment", rendered_filename),
+            },
+        )
 
-        # Convert to MP3 for storage efficiency. If conversion fails (e.g. ffmpeg
-        # is not installed — a stock macOS install has none), fall back to storing
-        # the original audio verbatim so a COMPLETED transcript is never discarded
-        # (persist-before-deliver: the transcript is saved below regardless).
-        # See FINDING #2 (Apple-Silicon stress test).
-        try:
-            convert_to_mp3(str(tmp_path), str(dest_path))
-        except Exception as mp3_err:
-            logger.warning(
-                "MP3 conversion failed (%s); storing the original audio so the "
-                "completed transcript is preserved.",
-                mp3_err,
+    try:
+        recording = get_recording(recording_id)
+        if not recording:
+            raise HTTPException(status_code=404, detail="Recording not found")
+
+        segments, words = _export_transcript(recording_id, redact)
+        note_threads: list[dict[str, Any]] = []
+        if annotations:
+            from server.database import annotation_repository
+
+            note_threads = annotation_repository.build_threads(
+                annotation_repository.list_annotations(recording_id)
             )
-            fallback_suffix = Path(filename or "audio").suffix.lower() or ".wav"
-            dest_filename = f"{original_stem}{fallback_suffix}"
-            dest_path = audio_dir / dest_filename
-            counter = 2
-            while dest_path.exists():
-                dest_filename = f"{original_stem}-{counter}{fallback_suffix}"
-                dest_path = audio_dir / dest_filename
-                counter += 1
-            shutil.copyfile(str(tmp_path), str(dest_path))
 
-        # Extract word timestamps from segments
-        # Diarization automatically enables word timestamps (they're needed for alignment anyway)
-        word_timestamps_list = None
+        # Parse recording date
+        recorded_at = recording.get("recorded_at", "")
+        try:
+            rec_dt = datetime.fromisoformat(recorded_at.replace("Z", "+00:00"))
+            date_str = rec_dt.strftime("%B %d, %Y at %I:%M %p")
+        except (ValueError, AttributeError):
+            date_str = recorded_at or ""
 
-        # Extract words from segments if they were computed
-        if result.segments and "words" in result.segments[0]:
-            word_timestamps_list = []
-            for seg in result.segments:
-                if "words" in seg:
-                    word_timestamps_list.extend(seg["words"])
+        # Format duration — coerce so a NULL duration_seconds doesn't crash arithmetic (Issue #98).
+        duration = _to_float(recording.get("duration_seconds"), default=0.0)
+        if duration < 60:
+            duration_str = f"{int(duration)} seconds"
+        elif duration < 3600:
+            mins = int(duration // 60)
+            secs = int(duration % 60)
+            duration_str = f"{mins} min {secs} sec"
+        else:
+            hours = int(duration // 3600)
+            mins = int((duration % 3600) // 60)
+            duration_str = f"{hours} hr {mins} min"
 
-        # When diarization was performed but no word timestamps are available
-        # (e.g. MLX Canary backend), fall back to segment-level speaker
-        # attribution so that the DB segments carry text instead of being empty.
-        if diarization_segments and not word_timestamps_list:
-            from server.core.speaker_merge import build_speaker_segments_nowords
+        title = recording.get("title") or recording.get("filename") or "Recording"
+        has_diarization = bool(recording.get("has_diarization"))
+        has_words = len(words) > 0
+        is_pure_note = (not has_diarization) and (not has_words)
 
-            diarization_segments = build_speaker_segments_nowords(
-                result.segments, diarization_segments
+        if is_pure_note and requested_format != "txt":
+            raise HTTPException(
+                status_code=400,
+                detail="This recording only supports TXT export. SRT/ASS require word timestamps or diarization.",
             )
 
-        # Save to database
-        # Use provided title if given, otherwise database falls back to filename stem
-        if getattr(result, "partial", False):
-            # A chunking backend failed partway through long audio; persist the
-            # completed transcript rather than lose it (GH #168 follow-up).
-            logger.warning(
-                "Saving a PARTIAL transcript for notebook recording: %s",
-                getattr(result, "partial_reason", None),
+        if (not is_pure_note) and requested_format == "txt":
+            raise HTTPException(
+                status_code=400,
+                detail="This recording supports subtitle export only. Use SRT or ASS.",
             )
-        clean_title = title.strip() if title else None
-        transcription_backend = detect_backend_type(getattr(engine, "model_name", "") or "")
-        recording_id = save_longform_to_database(
-            audio_path=dest_path,
-            duration_seconds=result.duration,
-            transcription_text=result.text,
-            word_timestamps=word_timestamps_list,
-            diarization_segments=diarization_segments,
-            recorded_at=recorded_at,
-            title=clean_title or None,
-            transcription_backend=transcription_backend,
-            audio_hash=audio_hash,
-            normalized_audio_hash=normalized_audio_hash,
-        )
 
-        if not recording_id:
-            raise RuntimeError("Failed to save recording to database")
+        if requested_format == "txt":
+            # Human-readable text export
+            lines = []
+            lines.append("=" * 60)
+            lines.append("TRANSCRIPTION EXPORT")
+            lines.append("=" * 60)
+            lines.append("")
+            lines.append(f"Title: {title}")
+            lines.append(f"Date: {date_str}")
+            lines.append(f"Duration: {duration_str}")
+            lines.append(f"Word Count: {recording.get('word_count') or 0}")
+            if has_diarization:
+                lines.append("Speaker Diarization: Yes")
+            lines.append("")
 
-        # Store successful result for client polling
-        model_manager.job_tracker.end_job(
-            job_id,
-            result={
-                "job_id": job_id[:8],
-                "recording_id": recording_id,
-                "message": f"Successfully transcribed and saved: {filename}",
-                "diarization": diarization_outcome,
-            },
-        )
-        logger.info(
-            f"Background transcription job {job_id[:8]} completed: recording_id={recording_id}"
-        )
+            if recording.get("summary"):
+                lines.append("-" * 40)
+                lines.append("SUMMARY")
+                lines.append("-" * 40)
+                lines.append(recording["summary"])
+                lines.append("")
 
-        # Issue #104, Story 5.6 — diarization-review lifecycle hook +
-        # Story 6.2 / 6.3 — auto-action coordinator dispatch.
-        # Both fire AFTER the transcript has been committed (Persist-Before-Deliver).
-        if event_loop is not None:
-            from server.core.auto_action_coordinator import trigger_auto_actions
-            from server.core.diarization_confidence import (
-                LOW_CONFIDENCE_THRESHOLD,
-                per_turn_confidence,
-            )
-            from server.core.diarization_review_lifecycle import on_transcription_complete
-            from server.database.database import get_segments, get_words
+            lines.append("-" * 40)
+            lines.append("TRANSCRIPTION")
+            lines.append("-" * 40)
+            lines.append("")
 
-            try:
-                segments_for_conf = get_segments(recording_id)
-                words_for_conf = get_words(recording_id)
-                turns = per_turn_confidence(segments_for_conf, words_for_conf)
-                has_low_conf = any(t["confidence"] < LOW_CONFIDENCE_THRESHOLD for t in turns)
-                on_transcription_complete(recording_id, has_low_conf)
-            except Exception:
-                # Lifecycle bookkeeping must never crash the transcription pipeline.
-                logger.exception(
-                    "diarization-review lifecycle hook failed for recording %d",
-                    recording_id,
-                )
+            if has_diarization and segments:
+                # Group by speaker with timestamps
+                current_speaker = None
+                for seg in segments:
+                    speaker = seg.get("speaker") or "Unknown"
+                    start = _to_float(seg.get("start_time"), default=0.0)
+                    text = str(seg.get("text") or "").strip()
+
+                    # Format timestamp
+                    mins = int(start // 60)
+                    secs = int(start % 60)
+                    timestamp = f"[{mins:02d}:{secs:02d}]"
+
+                    if speaker != current_speaker:
+                        lines.append("")
+                        lines.append(f"{speaker}:")
+                        current_speaker = speaker
+
+                    lines.append(f"  {timestamp} {text}")
+            else:
+                # Simple text output with timestamps
+                for seg in segments:
+                    start = _to_float(seg.get("start_time"), default=0.0)
+                    text = str(seg.get("text") or "").strip()
+                    mins = int(start // 60)
+                    secs = int(start % 60)
+                    lines.append(f"[{mins:02d}:{secs:02d}] {text}")
+
+            # Add word-level timestamps section if present
+            if words:
+                lines.append("")
+                lines.append("-" * 40)
+                lines.append("WORD-LEVEL TIMESTAMPS")
+                lines.append("-" * 40)
+                lines.append("")
+
+                word_lines = []
+                for w in words:
+                    word = str(w.get("word") or "")
+                    start = _to_float(w.get("start_time"), default=0.0)
+                    end = _to_float(w.get("end_time"), default=0.0)
+                    conf = w.get("confidence")
+                    conf_str = f" ({conf:.2f})" if isinstance(conf, (int, float)) else ""
+                    word_lines.append(f"{word} [{start:.2f}s-{end:.2f}s]{conf_str}")
+
+                # Group words into lines of ~80 chars
+                current_line = []
+                current_len = 0
+                for wl in word_lines:
+                    if current_len + len(wl) + 2 > 80 and current_line:
+                        lines.append("  ".join(current_line))
+                        current_line = [wl]
+                        current_len = len(wl)
+                    else:
+                        current_line.append(wl)
+                        current_len += len(wl) + 2
+                if current_line:
+                    lines.append("  ".join(current_line))
 
-            # Fire-and-forget the auto-action coordinator on the main loop.
-            # Disabled toggles are a no-op inside the coordinator.
-            try:
-                asyncio.run_coroutine_threadsafe(
-                    trigger_auto_actions(recording_id, profile_snapshot),
-                    event_loop,
-                )
-            except Exception:
-                logger.exception(
-                    "trigger_auto_actions dispatch failed for recording %d",
-                    recording_id,
-                )
+            if note_threads:
+                from server.core.annotation_export import annotation_text_lines
 
-        # Fire outgoing webhook (background thread — use fire-and-forget)
-        if event_loop is not None:
-            from server.core.webhook import dispatch_fire_and_forget
+                lines.append("")
+                lines.append("-" * 40)
+                lines.append("ANNOTATIONS")
+                lines.append("-" * 40)
+                lines.append("")
+                lines.extend(annotation_text_lines(note_threads))
 
-            dispatch_fire_and_forget(
-                event_loop,
-                "longform_complete",
-                {
-                    "source": "longform",
-                    "text": result.text,
-                    "filename": filename or "",
-                    "duration": result.duration,
-                    "language": result.language,
-                    "num_speakers": result.num_speakers,
-                },
+            lines.append("")
+            lines.append("=" * 60)
+            lines.append("End of Export")
+            lines.append("=" * 60)
+
+            content = "\n".join(lines)
+            filename = f"{title.replace(' ', '_')}_export.txt"
+            media_type = "text/plain; charset=utf-8"
+        else:
+            # Story 5.1 — alias propagation to subtitle exports.
+            from server.core.timecode import parse_settings
+            from server.database import alias_repository
+
+            cues = build_subtitle_cues(
+                segments=segments,
+                words=words,
+                has_diarization=has_diarization,
+                alias_overrides=alias_repository.alias_map(recording_id),
             )
+            if caption_profile:
+                from server.core.caption_lint import rewrap_cues
 
-    except Exception as e:
-        logger.error(f"Background transcription job {job_id[:8]} failed: {e}", exc_info=True)
-        # Surface the BackendDependencyError remedy so the dashboard can render
-        # an actionable hint instead of just the bare error string (Issue #76).
-        from server.core.stt.backends.base import BackendDependencyError
+                cues = rewrap_cues(cues, _caption_profile(caption_profile))
 
-        dep_error: BackendDependencyError | None = None
-        if isinstance(e, BackendDependencyError):
-            dep_error = e
-        elif isinstance(e.__cause__, BackendDependencyError):
-            dep_error = e.__cause__
-        error_payload: dict[str, Any] = {
-            "job_id": job_id[:8],
-            "error": str(e),
-        }
-        if dep_error is not None:
-            error_payload["remedy"] = dep_error.remedy
-            error_payload["backend_type"] = dep_error.backend_type
-        # Store error result for client polling
-        model_manager.job_tracker.end_job(
-            job_id,
-            result=error_payload,
-        )
+            timecode = parse_settings(recording.get("timecode_settings"))
+            if requested_format == "srt":
+                content = render_srt(cues, timecode=timecode)
+                filename = f"{title.replace(' ', '_')}_export.srt"
+                media_type = "application/x-subrip; charset=utf-8"
+            else:
+                from server.core.annotation_export import annotation_cues
 
-    finally:
-        # Cleanup temp file
-        try:
-            tmp_path.unlink()
-        except Exception as e:
-            logger.warning(f"Failed to cleanup temp file {tmp_path}: {e}")
+                content = render_ass(
+                    cues, title=title, timecode=timecode, comments=annotation_cues(note_threads)
+                )
+                filename = f"{title.replace(' ', '_')}_export.ass"
+                media_type = "text/x-ass; charset=utf-8"
+
+        return Response(
+            content=content,
+            media_type=media_type,
+            headers={
+                "Content-Disposition": _content_disposition("attachment", filename),
+            },
+        )
+    except HTTPException:
+        # Intentional 400/404 responses — pass through unchanged.
+        raise
+    except Exception as e:
+        # Issue #98: surface the real cause to the client instead of FastAPI's
+        # generic "Internal server error" envelope so users can report something
+        # actionable. Full traceback still lands in the server log.
+        logger.error(
+            "Export failed for recording %d (format=%s): %s",
+            int(recording_id),
+            _sanitize_for_log(requested_format),
+            _sanitize_for_log(e),
+            exc_info=True,
+        )
+        raise HTTPException(
+            status_code=500,
+            detail=f"Export failed: {type(e).__name__}: {e}",
+        ) from e
 
 
-@router.post("/transcribe/upload", response_model=AcceptedResponse, status_code=202)
-async def upload_and_transcribe(
-    request: Request,
-    file: Annotated[UploadFile, File(...)],
-    language: str | None = Form(None),
-    translation_enabled: bool = Form(False),
-    translation_target_language: str | None = Form(None),
-    enable_diarization: bool = Form(False),
-    enable_word_timestamps: bool = Form(True),
-    file_created_at: str | None = Form(None),
-    expected_speakers: int | None = Form(None),
-    parallel_diarization: bool | None = Form(None),
-    title: str | None = Form(None),
-    profile_id: int | None = Form(None),
+@router.get("/recordings/{recording_id}/caption-lint")
+async def lint_recording_captions(
+    recording_id: int,
+    profile: str | None = Query(None, description="netflix, bbc, cea608 or a custom profile"),
+    redact: bool = Query(True, description="lint the masked text the export would contain"),
 ) -> dict[str, Any]:
     """
-    Upload an audio file and start transcription in the background.
-
-    Returns 202 Accepted immediately with a job_id. Clients should poll
-    GET /api/admin/status to check job_tracker.result for completion.
+    Check the recording's subtitle cues against a broadcast caption profile.
 
-    Parameters:
-    - expected_speakers: Exact number of speakers (2-10). Forces diarization to
-      identify exactly this many speakers. Useful for podcasts with known hosts
-      where occasional clips should be attributed to the main speakers.
-    - parallel_diarization: Override the server default for parallel vs sequential
-      diarization. When False, transcription completes before diarization starts
-      (lower VRAM usage). When None, uses the server config default.
-
-    Returns 409 Conflict if another transcription job is already running.
+    ``remaining_after_fix`` is how many violations an export with
+    ``caption_profile`` would still have once lines are re-wrapped — the
+    rest (timing, reading speed) need a split or merge in the editor.
     """
-    if not file.filename:
-        raise HTTPException(status_code=400, detail="No file provided")
+    from dataclasses import asdict
 
-    # Validate expected_speakers parameter
-    if expected_speakers is not None:
-        if expected_speakers < 1 or expected_speakers > 10:
-            raise HTTPException(
-                status_code=400,
-                detail="expected_speakers must be between 1 and 10",
-            )
+    from server.core.caption_lint import lint_cues, rewrap_cues, summarize
+    from server.database import alias_repository
 
-    # Get model manager and check if busy
-    model_manager = request.app.state.model_manager
-    client_name = get_client_name(request)
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
+    caption_profile = _caption_profile(profile)
 
-    # Try to acquire a job slot
-    success, job_id, active_user = model_manager.job_tracker.try_start_job(client_name)
-    if not success:
+    segments, words = _export_transcript(recording_id, redact)
+    has_diarization = bool(recording.get("has_diarization"))
+    if not words and not has_diarization:
         raise HTTPException(
-            status_code=409,
-            detail=f"A transcription is already running for {active_user}",
+            status_code=400,
+            detail="Caption lint requires word timestamps or diarization.",
         )
 
-    # Save uploaded file to temp location (fast — just I/O)
-    suffix = Path(file.filename).suffix or ".wav"
-    with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
-        content = await file.read()
-        tmp.write(content)
-        tmp_path = Path(tmp.name)
-
-    # Compute audio_hash for dedup (Issue #104, Sprint 2 carve-out — Item 2).
-    # Hash the raw upload bytes — same approach as /api/transcribe/import. The
-    # hash is written atomically with the recordings row in
-    # save_longform_to_database; the dashboard can later call dedup-check to
-    # match notebook recordings against transcription_jobs and other notebook
-    # recordings.
-    # Sprint 2 Item 3 — also compute the normalized PCM hash for
-    # format-agnostic dedup. ffmpeg failure → NULL second hash, upload still
-    # proceeds.
-    from server.core.audio_utils import (
-        compute_normalized_pcm_hash as _norm_sha,
+    cues = build_subtitle_cues(
+        segments=segments,
+        words=words,
+        has_diarization=has_diarization,
+        alias_overrides=alias_repository.alias_map(recording_id),
     )
-    from server.core.audio_utils import sha256_streaming as _sha
+    violations = lint_cues(cues, caption_profile)
+    return {
+        "recording_id": recording_id,
+        "profile": asdict(caption_profile),
+        "cue_count": len(cues),
+        "violations": [v.to_dict() for v in violations],
+        "summary": summarize(violations),
+        "remaining_after_fix": len(lint_cues(rewrap_cues(cues, caption_profile), caption_profile)),
+    }
 
-    try:
-        audio_hash: str | None = _sha(tmp_path)
-    except OSError as hash_err:
-        # Tempfile vanished or unreadable — release the job slot and surface
-        # the failure before any DB row is created (mirrors the request-path
-        # invariant: failed pre-conditions never produce orphan records).
-        try:
-            tmp_path.unlink(missing_ok=True)
-        except OSError:
-            # Best-effort cleanup; the OS reaps tempfiles on reboot and the
-            # caller is already raising 500 — leaking a temp byte stream
-            # here must not mask the real error returned to the client.
-            pass
-        model_manager.job_tracker.cancel_job()
-        raise HTTPException(
-            status_code=500,
-            detail="Failed to compute audio hash for upload",
-        ) from hash_err
-    normalized_audio_hash: str | None = _norm_sha(tmp_path)
 
-    # Resolve parallel diarization default from config before entering background thread
-    config = request.app.state.config
-    use_parallel_default = resolve_parallel_diarization_default(config)
+_MARKER_MEDIA = {
+    "edl": ("text/plain; charset=utf-8", "edl"),
+    "premiere_xml": ("application/xml; charset=utf-8", "xml"),
+    "resolve_csv": ("text/csv; charset=utf-8", "csv"),
+}
 
-    # Issue #104, Story 6.2 — snapshot the profile at upload time so the
-    # background thread (which may finish minutes later) sees the SAME
-    # toggles even if the user edits the profile during transcription.
-    profile_snapshot: dict[str, Any] | None = None
-    if profile_id is not None:
-        from server.database import profile_repository
 
-        profile_row = profile_repository.get_profile(profile_id)
-        if profile_row is not None:
-            profile_snapshot = {
-                "profile_id": profile_id,
-                "schema_version": profile_row.get("schema_version"),
-                "public_fields": profile_row.get("public_fields") or {},
-            }
+def _export_transcript(
+    recording_id: int, redact: bool
+) -> tuple[list[dict[str, Any]], list[dict[str, Any]]]:
+    """Segments and words for an export, masked unless ``redact`` is off."""
+    segments, words = get_segments(recording_id), get_words(recording_id)
+    if not redact:
+        return segments, words
+    from server.core.redaction import redact_transcript
+    from server.database import redaction_repository
 
-    # Capture event loop for webhook dispatch from background thread
-    loop = asyncio.get_running_loop()
+    return redact_transcript(segments, words, redaction_repository.list_redactions(recording_id))
 
-    # Launch background transcription task (runs on thread pool, doesn't block event loop)
-    loop.create_task(
-        asyncio.to_thread(
-            _run_transcription,
-            model_manager=model_manager,
-            tmp_path=tmp_path,
-            filename=file.filename,
-            language=language,
-            translation_enabled=translation_enabled,
-            translation_target_language=translation_target_language,
-            enable_diarization=enable_diarization,
-            enable_word_timestamps=enable_word_timestamps,
-            file_created_at=file_created_at,
-            expected_speakers=expected_speakers,
-            parallel_diarization=parallel_diarization,
-            use_parallel_default=use_parallel_default,
-            title=title,
-            job_id=job_id,
-            event_loop=loop,
-            audio_hash=audio_hash,
-            normalized_audio_hash=normalized_audio_hash,
-            profile_snapshot=profile_snapshot,
-        )
-    )
 
-    # Return immediately — client polls /api/admin/status for result
-    return {"job_id": job_id[:8]}
+def _with_speaker_stats(chunks: Iterator[str], recording_id: int) -> Iterator[str]:
+    """Plaintext export followed by a speaker statistics section, if diarized."""
+    from server.core.speaker_stats import stats_text_lines
 
+    yield from chunks
+    lines = stats_text_lines(_speaker_stats(recording_id))
+    if lines:
+        yield "\n## Speaker statistics\n\n" + "\n".join(f"- {line}" for line in lines) + "\n"
 
-@router.get("/calendar")
-async def get_calendar_data(
-    year: int = Query(..., description="Year"),
-    month: int = Query(..., description="Month (1-12)"),
-) -> dict[str, Any]:
-    """
-    Get recordings grouped by day for calendar view.
-    """
+
+def _caption_profile(name: str | None) -> Any:
+    """Resolve a caption profile (built-in or ``caption_lint.profiles``); 400 if unknown."""
+    from server.core.caption_lint import get_profile
+
+    cfg = get_config()
     try:
-        # Get date range for the month
-        start_date = f"{year:04d}-{month:02d}-01"
-        if month == 12:
-            end_date = f"{year + 1:04d}-01-01"
-        else:
-            end_date = f"{year:04d}-{month + 1:02d}-01"
+        return get_profile(
+            name or cfg.get("caption_lint", "default_profile", default=None),
+            cfg.get("caption_lint", "profiles", default=None) or {},
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+
+
+def _export_markers(
+    recording_id: int, requested_format: str, query: str | None, *, redact: bool = True
+) -> Response:
+    """Render NLE timeline markers (EDL / Premiere XML / Resolve CSV)."""
+    from server.core.alias_substitution import build_speaker_label_map
+    from server.core.marker_export import (
+        markers_from_search,
+        markers_from_segments,
+        render_edl,
+        render_premiere_xml,
+        render_resolve_csv,
+    )
+    from server.core.timecode import parse_settings
+    from server.database import alias_repository
 
-        recordings = get_recordings_by_date_range(start_date, end_date)
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
 
-        # Group by day
-        days: dict[str, list[dict[str, Any]]] = {}
-        for rec in recordings:
-            recorded_at = rec.get("recorded_at", "")
-            if recorded_at:
-                day = recorded_at[:10]  # YYYY-MM-DD
-                if day not in days:
-                    days[day] = []
-                days[day].append(rec)
+    title = recording.get("title") or recording.get("filename") or "Recording"
+    segments, words = _export_transcript(recording_id, redact)
+    timecode = parse_settings(recording.get("timecode_settings"))
+    if query and query.strip():
+        markers = markers_from_search(segments, words, query.strip())
+    else:
+        label_map = build_speaker_label_map(segments, alias_repository.alias_map(recording_id))
+        markers = markers_from_segments(segments, label_map=label_map)
+
+    if requested_format == "edl":
+        content = render_edl(markers, title, timecode)
+    elif requested_format == "premiere_xml":
+        content = render_premiere_xml(
+            markers,
+            title,
+            duration_seconds=_to_float(recording.get("duration_seconds"), default=0.0),
+            timecode=timecode,
+        )
+    else:
+        content = render_resolve_csv(markers, timecode)
 
-        return {
-            "year": year,
-            "month": month,
-            "days": days,
-            "total_recordings": len(recordings),
-        }
+    media_type, ext = _MARKER_MEDIA[requested_format]
+    return Response(
+        content=content,
+        media_type=media_type,
+        headers={
+            "Content-Disposition": _content_disposition(
+                "attachment", f"{title.replace(' ', '_')}_markers.{ext}"
+            ),
+        },
+    )
 
-    except Exception as e:
-        logger.error(f"Failed to get calendar data: {e}")
-        raise HTTPException(status_code=500, detail=str(e)) from e
 
+class LabelImportRequest(BaseModel):
+    """Audacity label-track file contents (``start\tend\tlabel`` per line)."""
 
-@router.get("/timeslot")
-async def get_timeslot_info(
-    date: str = Query(..., description="Date in YYYY-MM-DD format"),
-    hour: int = Query(..., ge=0, le=23, description="Hour (0-23)"),
+    content: str
+    # True = return the planned segments without writing them.
+    dry_run: bool = False
+
+
+@router.post("/recordings/{recording_id}/labels")
+async def import_audacity_labels(
+    recording_id: int,
+    body: LabelImportRequest,
 ) -> dict[str, Any]:
-    """
-    Get information about a specific time slot.
+    """Re-segment a recording using an Audacity label track as boundaries.
 
-    Returns:
-    - recordings: List of recordings in this slot
-    - next_available: ISO timestamp of next available start time (or null if full)
-    - total_duration: Total duration of recordings in seconds
-    - available_seconds: Remaining seconds available in the slot
-    - is_full: Whether the slot is completely full
+    Words (or, without word timestamps, existing segment text) are re-parented
+    onto the new segments — no transcript content is dropped. Speaker per
+    segment is the majority speaker of the content it receives.
     """
+    from server.core.audacity_labels import parse_audacity_labels, plan_resegmentation
+    from server.database.database import replace_segments
+
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
     try:
-        info = get_time_slot_info(date, hour)
-        return info
+        labels = parse_audacity_labels(body.content)
+        planned = plan_resegmentation(
+            labels,
+            get_segments(recording_id),
+            get_words(recording_id),
+            duration=_to_float(recording.get("duration_seconds"), default=0.0),
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=f"Invalid label file: {e}") from e
+
+    segments = [
+        {
+            "text": p.text,
+            "start_time": p.start,
+            "end_time": p.end,
+            "speaker": p.speaker,
+            "word_ids": p.word_ids,
+        }
+        for p in planned
+    ]
+    if not body.dry_run:
+        replace_segments(recording_id, segments)
+        logger.info(
+            "Re-segmented recording %d from %d Audacity labels → %d segments",
+            recording_id,
+            len(labels),
+            len(segments),
+        )
+    return {
+        "recording_id": recording_id,
+        "dry_run": body.dry_run,
+        "label_count": len(labels),
+        "segment_count": len(segments),
+        "segments": [{k: v for k, v in seg.items() if k != "word_ids"} for seg in segments],
+    }
 
-    except Exception as e:
-        logger.error(f"Failed to get time slot info: {e}")
-        raise HTTPException(status_code=500, detail=str(e)) from e
 
+class RealignRequest(BaseModel):
+    """Corrected text to align; defaults to the saved corrected transcript."""
 
-@router.get("/recordings/{recording_id}/export")
-async def export_recording(
+    text: str | None = None
+    # False = carry timings over from unchanged words only (no model, instant).
+    use_model: bool = True
+    language: str | None = None
+
+
+@router.post("/recordings/{recording_id}/realign")
+async def realign_transcript(
+    request: Request,
     recording_id: int,
-    format: str = Query(
-        "txt",
-        description="Export format: 'txt', 'srt', 'ass', or 'plaintext'",
-    ),
-) -> Response:
+    body: RealignRequest,
+) -> dict[str, Any]:
+    """Apply corrected text to the timed transcript and re-time the edits.
+
+    Unchanged words keep their timestamps; edited stretches are re-timed by
+    the forced aligner (``use_model``) or interpolated in place. Only the
+    word timings are replaced, so click-to-seek works on the edited text;
+    the original segment text and the corrected-transcript overlay are
+    kept. Returns 400 when the text would leave a segment without words and
+    409 while a transcription job holds the GPU.
     """
-    Export a recording's transcription.
+    from server.core.forced_alignment import plan_realignment, refine_with_model
+    from server.database.database import rewrite_segment_words
 
-    Includes:
-    - Recording metadata (title, date, duration)
-    - Full transcription text
-    - Subtitle cue rendering from word-level timestamps (if present)
-    - Speaker labels (if diarization is present)
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
+    text = body.text if body.text is not None else recording.get("transcript_corrected")
+    if not text or not text.strip():
+        raise HTTPException(status_code=400, detail="No corrected text to align")
 
-    Formats:
-    - txt: Verbose human-readable text — full metadata header + transcript
-    - plaintext: FR9 streaming format — paragraph-per-speaker-turn, no
-      subtitle timestamps, no metadata header. Used by the Sprint 2
-      "Download transcript" button (Issue #104, Story 3.4).
-    - srt: SubRip subtitle format
-    - ass: Advanced SubStation Alpha subtitle format
-    """
-    requested_format = format.strip().lower()
-    if requested_format not in {"txt", "srt", "ass", "plaintext"}:
+    segments = get_segments(recording_id)
+    planned = plan_realignment(text, segments, get_words(recording_id))
+    if not planned:
+        raise HTTPException(status_code=400, detail="Corrected text matched no segments")
+    if len(planned) < len(segments):
         raise HTTPException(
             status_code=400,
-            detail="Unsupported export format. Supported formats: txt, plaintext, srt, ass.",
+            detail=(
+                f"The corrected text leaves {len(segments) - len(planned)} segment(s) "
+                "without words; realigning would drop them"
+            ),
         )
 
-    # Story 3.4 — plaintext is a streaming response that bypasses the
-    # full materialization path used by txt/srt/ass. We branch early so
-    # we don't pay the cost of get_words() / cue building for plaintext.
-    if requested_format == "plaintext":
-        recording = get_recording(recording_id)
-        if not recording:
-            raise HTTPException(status_code=404, detail="Recording not found")
-        from server.core.alias_substitution import apply_aliases
-        from server.core.plaintext_export import stream_plaintext
-        from server.database import alias_repository
-        from server.database.database import iter_segments
+    method = "interpolated"
+    audio_path = Path(recording.get("filepath") or "")
+    if body.use_model and audio_path.is_file():
+        job_tracker = request.app.state.model_manager.job_tracker
+        success, job_id, active_user = job_tracker.try_start_job(get_client_name(request))
+        if not success:
+            raise HTTPException(
+                status_code=409,
+                detail=f"A transcription is already running for {active_user}",
+            )
+        try:
+            method = await asyncio.to_thread(
+                call_with_plaintext, refine_with_model, audio_path, planned, language=body.language
+            )
+        finally:
+            job_tracker.end_job(job_id)
 
-        title = recording.get("title") or recording.get("filename") or "Recording"
-        rendered_filename = (
-            f"{title.replace(' ', '_')}.txt" if title else f"recording_{recording_id}.txt"
-        )
-        # Story 5.1 — alias propagation. ``apply_aliases`` is a lazy
-        # generator over ``iter_segments``, so the bounded-RAM property
-        # of the streaming exporter is preserved.
-        aliases = alias_repository.alias_map(recording_id)
-        return StreamingResponse(
-            stream_plaintext(
-                recording,
-                apply_aliases(iter_segments(recording_id), aliases),
-            ),
-            media_type="text/plain; charset=utf-8",
-            headers={
-                "Content-Disposition": _content_disposition("attachment", rendered_filename),
-            },
-        )
+    try:
+        word_count = rewrite_segment_words(recording_id, planned)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    logger.info(
+        "Realigned recording %d: %d segments, %d words (%s)",
+        recording_id,
+        len(planned),
+        word_count,
+        method,
+    )
+    return {
+        "recording_id": recording_id,
+        "method": method,
+        "segment_count": len(planned),
+        "word_count": word_count,
+    }
+
+
+class GoldenReferenceRequest(BaseModel):
+    """True marks the transcript as accuracy reference; False removes the mark."""
+
+    golden: bool
+
+
+@router.put("/recordings/{recording_id}/golden")
+async def set_golden_reference(recording_id: int, body: GoldenReferenceRequest) -> dict[str, Any]:
+    """Add the recording to (or drop it from) the golden set (core/golden_set.py).
+
+    The reference is the corrected transcript, or the segment text once the
+    corrections were realigned into it. Marking again takes the current
+    text and starts a new baseline.
+    """
+    from server.database import golden_repository
 
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
+    if not body.golden:
+        await asyncio.to_thread(golden_repository.unmark_reference, recording_id)
+        return {"recording_id": recording_id, "golden": False}
+    if not Path(recording.get("filepath") or "").is_file():
+        raise HTTPException(status_code=409, detail="Recording audio is no longer available")
+    reference = recording.get("transcript_corrected")
+    if not isinstance(reference, str) or not reference.strip():
+        reference = " ".join(str(seg["text"]).strip() for seg in get_segments(recording_id))
     try:
-        recording = get_recording(recording_id)
-        if not recording:
-            raise HTTPException(status_code=404, detail="Recording not found")
+        await asyncio.to_thread(golden_repository.mark_reference, recording_id, reference)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    return {"recording_id": recording_id, "golden": True}
 
-        segments = get_segments(recording_id)
-        words = get_words(recording_id)
 
-        # Parse recording date
-        recorded_at = recording.get("recorded_at", "")
-        try:
-            rec_dt = datetime.fromisoformat(recorded_at.replace("Z", "+00:00"))
-            date_str = rec_dt.strftime("%B %d, %Y at %I:%M %p")
-        except (ValueError, AttributeError):
-            date_str = recorded_at or ""
+class RetranscribeSegmentRequest(BaseModel):
+    """Model to redo one segment with; defaults to ``segment_retranscription.model``."""
 
-        # Format duration — coerce so a NULL duration_seconds doesn't crash arithmetic (Issue #98).
-        duration = _to_float(recording.get("duration_seconds"), default=0.0)
-        if duration < 60:
-            duration_str = f"{int(duration)} seconds"
-        elif duration < 3600:
-            mins = int(duration // 60)
-            secs = int(duration % 60)
-            duration_str = f"{mins} min {secs} sec"
-        else:
-            hours = int(duration // 3600)
-            mins = int((duration % 3600) // 60)
-            duration_str = f"{hours} hr {mins} min"
+    model: str | None = None
+    language: str | None = None
+    # True = return the candidate text without splicing it in.
+    dry_run: bool = False
 
-        title = recording.get("title") or recording.get("filename") or "Recording"
-        has_diarization = bool(recording.get("has_diarization"))
-        has_words = len(words) > 0
-        is_pure_note = (not has_diarization) and (not has_words)
 
-        if is_pure_note and requested_format != "txt":
-            raise HTTPException(
-                status_code=400,
-                detail="This recording only supports TXT export. SRT/ASS require word timestamps or diarization.",
-            )
+@router.post("/recordings/{recording_id}/segments/{segment_id}/retranscribe")
+async def retranscribe_segment_route(
+    request: Request,
+    recording_id: int,
+    segment_id: int,
+    body: RetranscribeSegmentRequest,
+) -> dict[str, Any]:
+    """Re-transcribe one segment's audio span with a (usually bigger) model.
 
-        if (not is_pure_note) and requested_format == "txt":
-            raise HTTPException(
-                status_code=400,
-                detail="This recording supports subtitle export only. Use SRT or ASS.",
+    Only that segment's text and words are replaced; speaker, bounds and
+    the other segments are kept (see ``core/segment_retranscription.py``).
+    A model other than the main one is swapped in and the main model
+    reloads on the next job. Returns 409 while a transcription job runs.
+    """
+    from server.core.audio_utils import AudioDecodeError
+    from server.core.segment_retranscription import (
+        DEFAULT_PADDING_SECONDS,
+        retranscribe_segment,
+    )
+    from server.core.stt.backends.base import BackendDependencyError
+    from server.database.database import replace_segment_words
+
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
+    segment = next((s for s in get_segments(recording_id) if s["id"] == segment_id), None)
+    if segment is None:
+        raise HTTPException(status_code=404, detail="Segment not found")
+    audio_path = Path(recording.get("filepath") or "")
+    if not audio_path.is_file():
+        raise HTTPException(status_code=409, detail="Recording audio is no longer available")
+
+    cfg = get_config()
+    model_manager = request.app.state.model_manager
+    model_name = (
+        body.model or cfg.get("segment_retranscription", "model", default="") or ""
+    ).strip()
+    use_main = not model_name or model_manager.is_same_model(
+        model_name, model_manager.main_model_name
+    )
+    padding = _to_float(
+        cfg.get("segment_retranscription", "padding_seconds", default=DEFAULT_PADDING_SECONDS),
+        default=DEFAULT_PADDING_SECONDS,
+    )
+
+    job_tracker = model_manager.job_tracker
+    success, job_id, active_user = job_tracker.try_start_job(get_client_name(request))
+    if not success:
+        raise HTTPException(
+            status_code=409,
+            detail=f"A transcription is already running for {active_user}",
+        )
+    try:
+        if use_main:
+            engine = await asyncio.to_thread(model_manager.ensure_transcription_loaded)
+        else:
+            engine = await asyncio.to_thread(
+                model_manager.alternate_transcription_engine, model_name
             )
+        duration = _to_float(recording.get("duration_seconds"), default=0.0) or None
+        result = await asyncio.to_thread(
+            call_with_plaintext,
+            lambda readable: retranscribe_segment(
+                engine,
+                readable,
+                segment,
+                language=body.language,
+                padding=padding,
+                duration=duration,
+            ),
+            audio_path,
+        )
+    except StorageEncryptionError as e:
+        raise HTTPException(status_code=503, detail=str(e)) from e
+    except BackendDependencyError as dep_err:
+        remedy_suffix = f". {dep_err.remedy}" if dep_err.remedy else ""
+        raise HTTPException(
+            status_code=503, detail=f"Backend dependency missing: {dep_err}{remedy_suffix}"
+        ) from dep_err
+    except AudioDecodeError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    finally:
+        job_tracker.end_job(job_id)
 
-        if requested_format == "txt":
-            # Human-readable text export
-            lines = []
-            lines.append("=" * 60)
-            lines.append("TRANSCRIPTION EXPORT")
-            lines.append("=" * 60)
-            lines.append("")
-            lines.append(f"Title: {title}")
-            lines.append(f"Date: {date_str}")
-            lines.append(f"Duration: {duration_str}")
-            lines.append(f"Word Count: {recording.get('word_count') or 0}")
-            if has_diarization:
-                lines.append("Speaker Diarization: Yes")
-            lines.append("")
+    if not result.text:
+        raise HTTPException(status_code=422, detail="The model heard no speech in this segment")
+    if not body.dry_run:
+        replace_segment_words(recording_id, segment_id, result.text, result.words)
+    return {"recording_id": recording_id, "dry_run": body.dry_run, **result.to_dict()}
 
-            if recording.get("summary"):
-                lines.append("-" * 40)
-                lines.append("SUMMARY")
-                lines.append("-" * 40)
-                lines.append(recording["summary"])
-                lines.append("")
 
-            lines.append("-" * 40)
-            lines.append("TRANSCRIPTION")
-            lines.append("-" * 40)
-            lines.append("")
+class ResumeTranscriptionRequest(BaseModel):
+    """Language for the rest of the audio; None = auto-detect."""
 
-            if has_diarization and segments:
-                # Group by speaker with timestamps
-                current_speaker = None
-                for seg in segments:
-                    speaker = seg.get("speaker") or "Unknown"
-                    start = _to_float(seg.get("start_time"), default=0.0)
-                    text = str(seg.get("text") or "").strip()
+    language: str | None = None
 
-                    # Format timestamp
-                    mins = int(start // 60)
-                    secs = int(start % 60)
-                    timestamp = f"[{mins:02d}:{secs:02d}]"
 
-                    if speaker != current_speaker:
-                        lines.append("")
-                        lines.append(f"{speaker}:")
-                        current_speaker = speaker
+@router.post("/recordings/{recording_id}/resume")
+async def resume_transcription_route(
+    request: Request, recording_id: int, body: ResumeTranscriptionRequest
+) -> dict[str, Any]:
+    """Transcribe the rest of a partial recording and append it.
 
-                    lines.append(f"  {timestamp} {text}")
-            else:
-                # Simple text output with timestamps
-                for seg in segments:
-                    start = _to_float(seg.get("start_time"), default=0.0)
-                    text = str(seg.get("text") or "").strip()
-                    mins = int(start // 60)
-                    secs = int(start % 60)
-                    lines.append(f"[{mins:02d}:{secs:02d}] {text}")
+    Starts at ``partial_until`` — where the interrupted job stopped — and
+    adds the new segments after the saved ones (see
+    ``core/partial_resume.py``). Returns 400 for a complete transcript and
+    409 while a transcription job runs or when the audio is gone.
+    """
+    from server.core.audio_utils import AudioDecodeError
+    from server.core.partial_resume import resume_offset, resume_transcription
+    from server.core.stt.backends.base import BackendDependencyError
+    from server.database.database import append_resumed_segments
+
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
+    if recording.get("partial_until") is None:
+        raise HTTPException(status_code=400, detail="The transcript is already complete")
+    audio_path = Path(recording.get("filepath") or "")
+    if not audio_path.is_file():
+        raise HTTPException(status_code=409, detail="Recording audio is no longer available")
+
+    offset = resume_offset(recording, get_segments(recording_id))
+    duration = _to_float(recording.get("duration_seconds"), default=0.0)
+    model_manager = request.app.state.model_manager
+    job_tracker = model_manager.job_tracker
+    success, job_id, active_user = job_tracker.try_start_job(get_client_name(request))
+    if not success:
+        raise HTTPException(
+            status_code=409,
+            detail=f"A transcription is already running for {active_user}",
+        )
+    try:
+        engine = await asyncio.to_thread(model_manager.ensure_transcription_loaded)
+        resumed = await asyncio.to_thread(
+            call_with_plaintext,
+            lambda readable: resume_transcription(
+                engine, readable, offset, duration=duration, language=body.language
+            ),
+            audio_path,
+        )
+    except StorageEncryptionError as e:
+        raise HTTPException(status_code=503, detail=str(e)) from e
+    except BackendDependencyError as dep_err:
+        remedy_suffix = f". {dep_err.remedy}" if dep_err.remedy else ""
+        raise HTTPException(
+            status_code=503, detail=f"Backend dependency missing: {dep_err}{remedy_suffix}"
+        ) from dep_err
+    except AudioDecodeError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    finally:
+        job_tracker.end_job(job_id)
+
+    append_resumed_segments(
+        recording_id,
+        resumed.segments,
+        partial_until=resumed.partial_until,
+        partial_reason=resumed.partial_reason,
+    )
+    return {"recording_id": recording_id, **resumed.to_dict()}
 
-            # Add word-level timestamps section if present
-            if words:
-                lines.append("")
-                lines.append("-" * 40)
-                lines.append("WORD-LEVEL TIMESTAMPS")
-                lines.append("-" * 40)
-                lines.append("")
 
-                word_lines = []
-                for w in words:
-                    word = str(w.get("word") or "")
-                    start = _to_float(w.get("start_time"), default=0.0)
-                    end = _to_float(w.get("end_time"), default=0.0)
-                    conf = w.get("confidence")
-                    conf_str = f" ({conf:.2f})" if isinstance(conf, (int, float)) else ""
-                    word_lines.append(f"{word} [{start:.2f}s-{end:.2f}s]{conf_str}")
+class SplitSegmentRequest(BaseModel):
+    """Split point in milliseconds; snapped to the nearest pause between words."""
 
-                # Group words into lines of ~80 chars
-                current_line = []
-                current_len = 0
-                for wl in word_lines:
-                    if current_len + len(wl) + 2 > 80 and current_line:
-                        lines.append("  ".join(current_line))
-                        current_line = [wl]
-                        current_len = len(wl)
-                    else:
-                        current_line.append(wl)
-                        current_len += len(wl) + 2
-                if current_line:
-                    lines.append("  ".join(current_line))
+    at_ms: int
 
-            lines.append("")
-            lines.append("=" * 60)
-            lines.append("End of Export")
-            lines.append("=" * 60)
 
-            content = "\n".join(lines)
-            filename = f"{title.replace(' ', '_')}_export.txt"
-            media_type = "text/plain; charset=utf-8"
-        else:
-            # Story 5.1 — alias propagation to subtitle exports.
-            from server.database import alias_repository
+class MergeSegmentsRequest(BaseModel):
+    """Two or more adjacent segments to fold into the first."""
 
-            cues = build_subtitle_cues(
-                segments=segments,
-                words=words,
-                has_diarization=has_diarization,
-                alias_overrides=alias_repository.alias_map(recording_id),
+    segment_ids: list[int]
+
+
+@router.post("/recordings/{recording_id}/segments/{segment_id}/split")
+async def split_segment_route(
+    recording_id: int, segment_id: int, body: SplitSegmentRequest
+) -> dict[str, Any]:
+    """Split a segment at the word gap closest to ``at_ms``.
+
+    Words are re-parented, never re-timed; the boundary lands on the
+    quietest point of the pause (see ``core/segment_editing.py``).
+    """
+    from server.core.segment_editing import plan_split, snap_to_audio
+    from server.database.database import split_segment
+
+    recording = get_recording(recording_id)
+    if not recording:
+        raise HTTPException(status_code=404, detail="Recording not found")
+    segment = next((s for s in get_segments(recording_id) if s["id"] == segment_id), None)
+    if segment is None:
+        raise HTTPException(status_code=404, detail="Segment not found")
+    words = [w for w in get_words(recording_id) if w.get("segment_id") == segment_id]
+    try:
+        plan = plan_split(segment, words, body.at_ms / 1000.0)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    audio_path = Path(recording.get("filepath") or "")
+    if audio_path.is_file():
+        try:
+            plan = await asyncio.to_thread(
+                call_with_plaintext, lambda readable: snap_to_audio(plan, readable), audio_path
             )
+        except StorageEncryptionError:
+            logger.debug("Split boundary: audio is encrypted and unreadable", exc_info=True)
+    new_id = split_segment(
+        recording_id,
+        segment_id,
+        plan.boundary,
+        plan.left_text,
+        plan.right_text,
+        plan.right_word_ids,
+    )
+    if new_id is None:
+        raise HTTPException(status_code=404, detail="Segment not found")
+    logger.info(
+        "Split segment %d of recording %d at %.3fs", segment_id, recording_id, plan.boundary
+    )
+    return {
+        "recording_id": recording_id,
+        "segment_ids": [segment_id, new_id],
+        "boundary": plan.boundary,
+    }
 
-            if requested_format == "srt":
-                content = render_srt(cues)
-                filename = f"{title.replace(' ', '_')}_export.srt"
-                media_type = "application/x-subrip; charset=utf-8"
-            else:
-                content = render_ass(cues, title=title)
-                filename = f"{title.replace(' ', '_')}_export.ass"
-                media_type = "text/x-ass; charset=utf-8"
 
-        return Response(
-            content=content,
-            media_type=media_type,
-            headers={
-                "Content-Disposition": _content_disposition("attachment", filename),
-            },
-        )
-    except HTTPException:
-        # Intentional 400/404 responses — pass through unchanged.
-        raise
-    except Exception as e:
-        # Issue #98: surface the real cause to the client instead of FastAPI's
-        # generic "Internal server error" envelope so users can report something
-        # actionable. Full traceback still lands in the server log.
-        logger.error(
-            "Export failed for recording %d (format=%s): %s",
-            int(recording_id),
-            _sanitize_for_log(requested_format),
-            _sanitize_for_log(e),
-            exc_info=True,
-        )
-        raise HTTPException(
-            status_code=500,
-            detail=f"Export failed: {type(e).__name__}: {e}",
-        ) from e
+@router.post("/recordings/{recording_id}/segments/merge")
+async def merge_segments_route(recording_id: int, body: MergeSegmentsRequest) -> dict[str, Any]:
+    """Merge adjacent segments into the first; words keep their timings."""
+    from server.core.segment_editing import plan_merge
+    from server.database.database import merge_segments
+
+    if not get_recording(recording_id):
+        raise HTTPException(status_code=404, detail="Recording not found")
+    try:
+        merged = plan_merge(get_segments(recording_id), body.segment_ids, get_words(recording_id))
+    except LookupError as e:
+        raise HTTPException(status_code=404, detail="Segment not found") from e
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    if not merge_segments(recording_id, merged):
+        raise HTTPException(status_code=404, detail="Segment not found")
+    logger.info(
+        "Merged %d segments of recording %d into %d",
+        len(merged["drop_ids"]) + 1,
+        recording_id,
+        merged["keep_id"],
+    )
+    return {
+        "recording_id": recording_id,
+        "segment_id": merged["keep_id"],
+        "merged_ids": merged["drop_ids"],
+        "start_time": merged["start_time"],
+        "end_time": merged["end_time"],
+    }
 
 
 # ──────────────────────────────────────────────────────────────────────────
@@ -1746,8 +4202,8 @@ async def reexport_recording(recording_id: int, body: ReexportRequest) -> Reexpo
     """
     from server.core.filename_template import render_and_sanitize
     from server.core.plaintext_export import stream_plaintext
+    from server.core.redaction import iter_export_segments
     from server.database import profile_repository
-    from server.database.database import iter_segments
 
     recording = get_recording(recording_id)
     if not recording:
@@ -1782,7 +4238,7 @@ async def reexport_recording(recording_id: int, body: ReexportRequest) -> Reexpo
     # Stream to disk so an 8-hour transcript doesn't OOM the server.
     try:
         with open(target_path, "w", encoding="utf-8") as f:
-            for chunk in stream_plaintext(recording, iter_segments(recording_id)):
+            for chunk in stream_plaintext(recording, iter_export_segments(recording_id)):
                 f.write(chunk)
     except OSError as exc:
         logger.error(
@@ -1803,6 +4259,371 @@ async def reexport_recording(recording_id: int, body: ReexportRequest) -> Reexpo
     )
 
 
+# ──────────────────────────────────────────────────────────────────────────
+# Output manager — template-named, per-type routed exports
+# ──────────────────────────────────────────────────────────────────────────
+
+
+class OutputRouteRequest(BaseModel):
+    """Body for ``POST /api/notebook/outputs/preview`` and ``.../outputs/export``.
+
+    Empty fields fall back to the ``output_manager`` config section.
+    """
+
+    recording_ids: list[int]
+    formats: list[str] = []
+    template: str | None = None
+    collision: str | None = None
+
+
+def _plan_routed_outputs(
+    body: OutputRouteRequest,
+) -> tuple[list[Any], dict[int, dict[str, Any]]]:
+    """Resolve settings and plan every target; 400 on bad settings, 404 on a missing recording."""
+    from server.core.output_manager import OutputSettings, plan_outputs
+
+    if not body.recording_ids:
+        raise HTTPException(status_code=400, detail="recording_ids must not be empty")
+    section = get_config().get("output_manager", default=None) or {}
+    formats = body.formats or list(section.get("formats") or ["txt"])
+    try:
+        settings = OutputSettings.from_config(
+            section, template=body.template, collision=body.collision
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+
+    recordings: dict[int, dict[str, Any]] = {}
+    for recording_id in dict.fromkeys(body.recording_ids):
+        recording = get_recording(recording_id)
+        if not recording:
+            raise HTTPException(status_code=404, detail=f"Recording {recording_id} not found")
+        recordings[recording_id] = recording
+    try:
+        plans = plan_outputs(recordings.values(), formats, settings)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    return plans, recordings
+
+
+async def _render_output(recording: dict[str, Any], fmt: str) -> bytes | Path:
+    """Content for one routed target, rendered by the regular export path."""
+    if fmt == "recording":
+        source = Path(str(recording.get("filepath") or ""))
+        if not source.is_file():
+            raise FileNotFoundError(f"audio file missing: {source}")
+        if is_encrypted(source):
+            try:
+                return await asyncio.to_thread(read_plaintext, source)
+            except StorageEncryptionError as e:
+                raise ValueError(str(e)) from e
+        return source
+    if fmt == "summary":
+        summary = recording.get("summary")
+        if not summary:
+            raise ValueError("recording has no summary")
+        return str(summary).encode("utf-8")
+    response = await export_recording(
+        int(recording["id"]),
+        format=fmt,
+        speaker_style="bold",
+        timestamps=True,
+        font_size=11,
+        page_size="A4",
+        q=None,
+        annotations=False,
+        redact=True,
+        caption_profile=None,
+        speaker_stats=False,
+    )
+    if isinstance(response, StreamingResponse):
+        chunks = [
+            chunk.encode("utf-8") if isinstance(chunk, str) else bytes(chunk)
+            async for chunk in response.body_iterator
+        ]
+        return b"".join(chunks)
+    return bytes(response.body)
+
+
+@router.post("/outputs/preview")
+async def preview_outputs(body: OutputRouteRequest) -> dict[str, Any]:
+    """
+    Show where each ``recording × format`` export would be written.
+
+    Nothing is written. ``action`` is ``write`` / ``overwrite`` / ``skip``
+    per the collision policy, or ``unrouted`` when the output type has no
+    folder configured (the dashboard falls back to a save dialog).
+    """
+    plans, _ = _plan_routed_outputs(body)
+    return {"outputs": [p.to_dict() for p in plans]}
+
+
+@router.post("/outputs/export")
+async def export_outputs(body: OutputRouteRequest) -> dict[str, Any]:
+    """
+    Render and write every routed export planned by ``/outputs/preview``.
+
+    Each target reports ``status``: ``written``, ``skipped``, ``unrouted``
+    or ``failed`` (with ``error``); one failure does not stop the batch.
+    """
+    from server.core.output_manager import write_output
+
+    plans, recordings = _plan_routed_outputs(body)
+    results: list[dict[str, Any]] = []
+    for plan in plans:
+        result = plan.to_dict()
+        if plan.action in ("skip", "unrouted"):
+            result["status"] = "skipped" if plan.action == "skip" else "unrouted"
+            results.append(result)
+            continue
+        try:
+            content = await _render_output(recordings[plan.recording_id], plan.format)
+            await asyncio.to_thread(write_output, plan.path, content)
+            result["status"] = "written"
+        except HTTPException as e:
+            result.update(status="failed", error=str(e.detail))
+        except (OSError, ValueError) as e:
+            logger.warning(
+                "Routed export of recording %d (%s) failed: %s",
+                plan.recording_id,
+                plan.format,
+                _sanitize_for_log(e),
+            )
+            result.update(status="failed", error=str(e))
+        results.append(result)
+    written = sum(1 for r in results if r["status"] == "written")
+    logger.info("Routed export wrote %d of %d outputs", written, len(results))
+    return {"outputs": results, "written": written}
+
+
+class LibraryExportRequest(BaseModel):
+    """Body for ``POST /api/notebook/outputs/library-export``.
+
+    Filter fields narrow the library (all empty = every recording). ``dest``
+    sends every output type to one folder; without it the configured
+    ``output_manager`` folders are used.
+    """
+
+    formats: list[str]
+    start_date: str | None = None
+    end_date: str | None = None
+    title: str | None = None
+    recording_ids: list[int] = []
+    dest: str | None = None
+    template: str | None = None
+    collision: str | None = None
+
+
+def _plan_library_export(
+    body: LibraryExportRequest,
+) -> tuple[list[Any], dict[int, dict[str, Any]]]:
+    """Select recordings and plan their targets; 400 on bad settings or no destination."""
+    from server.core.library_export import LibraryFilter, select_recordings
+    from server.core.output_manager import OutputSettings, plan_outputs
+
+    if not body.formats:
+        raise HTTPException(status_code=400, detail="formats must not be empty")
+    section = dict(get_config().get("output_manager", default=None) or {})
+    if body.dest and body.dest.strip():
+        section.update(root=body.dest.strip(), destinations={})
+    try:
+        settings = OutputSettings.from_config(
+            section, template=body.template, collision=body.collision
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+
+    flt = LibraryFilter(
+        start_date=body.start_date or None,
+        end_date=body.end_date or None,
+        title=body.title,
+        recording_ids=tuple(body.recording_ids),
+    )
+    selected = select_recordings(get_all_recordings(), flt)
+    try:
+        plans = plan_outputs(selected, body.formats, settings)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    if any(p.action == "unrouted" for p in plans):
+        raise HTTPException(
+            status_code=400,
+            detail="No destination: choose a folder or configure output_manager.root",
+        )
+    return plans, {int(r["id"]): r for r in selected}
+
+
+async def _render_library_output(recording: dict[str, Any], fmt: str) -> bytes | Path:
+    """``_render_output`` with "can't export this one" errors marked unsupported."""
+    from server.core.library_export import UnsupportedExport
+
+    if fmt == "summary" and not recording.get("summary"):
+        raise UnsupportedExport("recording has no summary")
+    try:
+        return await _render_output(recording, fmt)
+    except HTTPException as e:
+        if e.status_code in (400, 404):
+            raise UnsupportedExport(str(e.detail)) from e
+        raise ValueError(str(e.detail)) from e
+
+
+@router.post("/outputs/library-export")
+async def export_library(body: LibraryExportRequest) -> StreamingResponse:
+    """
+    Export every matching recording in each format, streaming progress (SSE).
+
+    Events are ``data: {json}`` lines: ``start`` (``total`` targets), one
+    ``progress`` per target (``status``: ``written`` / ``skipped`` /
+    ``unsupported`` / ``failed``) and a final ``done`` with counts and
+    ``cancelled``. 409 while another library export runs; stop one with
+    ``POST .../library-export/cancel`` or by closing the stream.
+    """
+    import json
+
+    from server.core.library_export import run_library_export, start_job
+    from server.core.output_manager import write_output
+
+    plans, recordings = _plan_library_export(body)
+    try:
+        job = start_job()
+    except RuntimeError as e:
+        raise HTTPException(status_code=409, detail=str(e)) from e
+
+    async def events() -> AsyncIterator[str]:
+        async for event in run_library_export(
+            job, plans, recordings, render=_render_library_output, write=write_output
+        ):
+            yield f"data: {json.dumps(event)}\n\n"
+
+    return StreamingResponse(
+        events(),
+        media_type="text/event-stream",
+        headers={"Cache-Control": "no-cache", "X-Accel-Buffering": "no"},
+    )
+
+
+@router.post("/outputs/library-export/cancel")
+async def cancel_library_export() -> dict[str, bool]:
+    """Stop the running library export after its current target; files written so far stay."""
+    from server.core.library_export import cancel_active
+
+    return {"cancelled": cancel_active()}
+
+
+# ──────────────────────────────────────────────────────────────────────────
+# Post-transcription rules (core/post_transcription_rules.py)
+# ──────────────────────────────────────────────────────────────────────────
+
+
+def _load_post_rules() -> list[Any]:
+    """Validated ``post_transcription_rules``; raises ``ValueError`` on a bad config."""
+    from server.core.post_transcription_rules import load_rules
+
+    return load_rules(get_config().get("post_transcription_rules", default=None))
+
+
+async def _run_post_transcription_rules(ctx: Any) -> None:
+    """Job-completion hook: run the matching rules' actions. Never raises."""
+    from server.core.output_manager import write_output
+    from server.core.post_transcription_rules import run_rules
+
+    try:
+        rules = _load_post_rules()
+        recording = get_recording(ctx.recording_id)
+        if not rules or not recording:
+            return
+        await run_rules(
+            rules,
+            ctx,
+            {**recording, "language": ctx.language},
+            render=_render_output,
+            write=write_output,
+            output_section=get_config().get("output_manager", default=None) or {},
+        )
+    except Exception:
+        logger.exception("post-transcription rules failed for recording %d", ctx.recording_id)
+
+
+class RuleTestRequest(BaseModel):
+    """Body for ``POST /api/notebook/rules/test``.
+
+    With ``recording_id`` the job's filename and duration default to that
+    recording's; without it a sample recording is planned against.
+    """
+
+    recording_id: int | None = None
+    source: str | None = None
+    filename: str | None = None
+    language: str | None = None
+    duration: float | None = None
+
+
+@router.get("/rules")
+async def list_post_rules() -> dict[str, Any]:
+    """The configured post-transcription rules; 400 if the config is invalid."""
+    try:
+        rules = _load_post_rules()
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    return {
+        "rules": [
+            {"name": r.name, "enabled": r.enabled, "when": r.when, "actions": list(r.actions)}
+            for r in rules
+        ]
+    }
+
+
+@router.post("/rules/test")
+async def dry_run_post_rules(body: RuleTestRequest) -> dict[str, Any]:
+    """
+    Dry-run the rules against a hypothetical completed job.
+
+    Reports, per rule, whether it matches (and which conditions failed)
+    plus the concrete actions it would run — export paths after collision
+    handling, webhook URLs. Nothing is written or sent.
+    """
+    from server.core.post_transcription_rules import JobContext, evaluate
+
+    try:
+        rules = _load_post_rules()
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+
+    if body.recording_id is not None:
+        recording = get_recording(body.recording_id)
+        if not recording:
+            raise HTTPException(status_code=404, detail="Recording not found")
+    else:
+        filename = body.filename or "sample.wav"
+        recording = {
+            "id": 0,
+            "title": Path(filename).stem,
+            "filename": filename,
+            "filepath": filename,
+            "recorded_at": datetime.now().isoformat(),
+        }
+    ctx = JobContext(
+        recording_id=int(recording["id"]),
+        source=body.source.strip() if body.source else None,
+        filename=body.filename or str(recording.get("filename") or ""),
+        language=body.language,
+        duration=(
+            body.duration
+            if body.duration is not None
+            else _to_float(recording.get("duration_seconds"), default=0.0)
+        ),
+    )
+    try:
+        report = evaluate(
+            rules,
+            ctx,
+            {**recording, "language": ctx.language},
+            get_config().get("output_manager", default=None) or {},
+        )
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    return {"rules": report, "matched": sum(1 for r in report if r["matched"])}
+
+
 # ---------------------------------------------------------------------------
 # Auto-action retry endpoint (Issue #104, Stories 6.9 / 6.10)
 # ---------------------------------------------------------------------------
@@ -1942,6 +4763,100 @@ async def retry_auto_action(
     )
 
 
+# ──────────────────────────────────────────────────────────────────────────
+# Direct share targets (email / Slack / Discord / WebDAV)
+# ──────────────────────────────────────────────────────────────────────────
+
+
+class ShareRequest(BaseModel):
+    """Body for ``POST /api/notebook/recordings/{id}/share``."""
+
+    target: str
+    format: str = "plaintext"
+
+
+class ShareCredentialsRequest(BaseModel):
+    """Body for ``PUT /api/notebook/share/targets/{name}/credentials``."""
+
+    credentials: dict[str, str]
+
+
+_SHARE_ERROR_STATUS = {
+    "unknown_target": 404,
+    "unknown_recording": 404,
+    "missing_credential": 400,
+    "invalid_config": 400,
+    "url_blocked": 400,
+    "delivery_failed": 502,
+}
+
+
+@router.get("/share/targets")
+async def list_share_targets() -> dict[str, Any]:
+    """List configured share targets: name, type and the names of credentials
+    not yet in the keychain — never the secrets themselves."""
+    from server.core.share import SHARE_TARGET_TYPES, configured_targets, missing_credentials
+
+    targets = []
+    for entry in configured_targets():
+        target = SHARE_TARGET_TYPES[entry["type"]](entry["name"], entry)
+        targets.append(
+            {
+                "name": target.name,
+                "type": target.type_name,
+                "missing_credentials": missing_credentials(target),
+            }
+        )
+    return {"targets": targets}
+
+
+@router.put("/share/targets/{name}/credentials")
+async def set_share_credentials(
+    name: str, body: ShareCredentialsRequest, request: Request
+) -> dict[str, Any]:
+    """Store a share target's credentials in the server keychain (admin only).
+
+    An empty value removes that entry. Returns the fields still missing.
+    """
+    from server.core.share import ShareError, set_credentials
+
+    if not require_admin(request):
+        raise HTTPException(status_code=403, detail="Admin access required")
+    try:
+        missing = set_credentials(name, body.credentials)
+    except ShareError as exc:
+        raise HTTPException(
+            status_code=_SHARE_ERROR_STATUS.get(exc.code, 400),
+            detail={"error": exc.code, "message": str(exc)},
+        ) from exc
+    return {"target": name, "missing_credentials": missing}
+
+
+@router.post("/recordings/{recording_id}/share")
+async def share_recording(recording_id: int, body: ShareRequest) -> dict[str, Any]:
+    """Send the recording's export to a configured share target.
+
+    Email targets without an SMTP relay return ``status='draft'`` plus the
+    unsent ``.eml`` text; the dashboard saves and opens it locally.
+    """
+    from server.core.share import ShareError
+    from server.core.share import share_recording as _share
+
+    try:
+        result = await _share(recording_id, body.target, body.format.strip().lower())
+    except ShareError as exc:
+        raise HTTPException(
+            status_code=_SHARE_ERROR_STATUS.get(exc.code, 400),
+            detail={"error": exc.code, "message": str(exc)},
+        ) from exc
+    return {
+        "target": result.target,
+        "type": result.target_type,
+        "status": result.status,
+        **result.detail,
+    }
+
+
 def _get_backup_manager() -> DatabaseBackupManager:
     """Get the backup manager instance with configured paths."""
     config = get_config()
@@ -2017,9 +4932,13 @@ async def restore_backup(body: RestoreRequest) -> dict[str, Any]:
     1. Creates a safety backup of the current database
     2. Verifies the backup file integrity
     3. Restores the database from the backup
+    4. Re-appends audit events recorded since the backup was taken
 
-    Warning: This will replace all current data with the backup data.
+    Warning: This will replace all current data with the backup data —
+    except the audit log, which only ever grows.
     """
+    from server.database import audit_repository
+
     try:
         manager = _get_backup_manager()
 
@@ -2039,9 +4958,11 @@ async def restore_backup(body: RestoreRequest) -> dict[str, Any]:
             raise HTTPException(status_code=400, detail="Backup file is invalid or corrupted")
 
         # Perform restore
+        audit_events = audit_repository.all_events()
         success = manager.restore_backup(backup_path)
 
         if success:
+            audit_repository.reappend(audit_events)
             return {
                 "success": True,
                 "message": f"Database restored from {body.filename}",
@@ -2055,3 +4976,53 @@ async def restore_backup(body: RestoreRequest) -> dict[str, Any]:
     except Exception as e:
         logger.error(f"Failed to restore backup: {e}")
         raise HTTPException(status_code=500, detail=str(e)) from e
+
+
+# ---------------------------------------------------------------------------
+# Database maintenance (integrity check, repair, FTS rebuild, vacuum)
+# ---------------------------------------------------------------------------
+
+
+class MaintenanceRequest(BaseModel):
+    repair: bool = True
+    vacuum: bool = True
+
+
+@router.get("/maintenance")
+async def get_maintenance_status() -> dict[str, Any]:
+    """Whether a maintenance run is active, and the report of the last one."""
+    from server.database.maintenance import is_running, last_report
+
+    return {"running": is_running(), "last_report": last_report()}
+
+
+@router.post("/maintenance")
+async def run_database_maintenance(body: MaintenanceRequest | None = None) -> StreamingResponse:
+    """Check and repair the database, streaming progress as server-sent events.
+
+    Events: ``start`` (steps), ``step`` (index/total), ``issue`` (step,
+    message, count, repaired) and a final ``done`` report. A repairing run
+    backs the database up first and turns report-only when the integrity
+    check fails; with ``repair=false`` nothing is written. Returns 409 while
+    a run is active.
+    """
+    import json
+
+    from server.database.maintenance import is_running, run_maintenance
+
+    body = body or MaintenanceRequest()
+    if is_running():
+        raise HTTPException(status_code=409, detail="Database maintenance is already running")
+
+    def events() -> Iterator[str]:
+        max_backups = get_config().get("backup", "max_backups", default=10)
+        for event in run_maintenance(
+            repair=body.repair, vacuum=body.vacuum, max_backups=max_backups
+        ):
+            yield f"data: {json.dumps(event)}\n\n"
+
+    return StreamingResponse(
+        events(),
+        media_type="text/event-stream",
+        headers={"Cache-Control": "no-cache", "X-Accel-Buffering": "no"},
+    )
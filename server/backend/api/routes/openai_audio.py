@@ -24,7 +24,7 @@ from typing import Any
 
 from fastapi import APIRouter, File, Form, HTTPException, Request, UploadFile
 from fastapi.responses import JSONResponse, PlainTextResponse
-from server.api.routes.utils import get_client_name
+from server.api.routes.utils import get_client_name, save_upload
 from server.config import resolve_main_transcriber_model, resolve_parallel_diarization_default
 from server.core.formatters import (
     format_diarized_json,
@@ -420,8 +420,7 @@ async def create_transcription(
     try:
         with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
             tmp_path = tmp.name
-            content = await file.read()
-            tmp.write(content)
+            await save_upload(file, tmp)
 
         result = await _run_transcription(
             request=request,
@@ -538,8 +537,7 @@ async def create_translation(
     try:
         with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
             tmp_path = tmp.name
-            content = await file.read()
-            tmp.write(content)
+            await save_upload(file, tmp)
 
         result = await _run_transcription(
             request=request,
@@ -96,6 +96,9 @@ class ProfilePublicFields(BaseModel):
     destination_folder: str = ""
     auto_summary_enabled: bool = False
     auto_export_enabled: bool = False
+    # Suggest redactions for names / phones / emails / addresses on completion
+    # (core/pii_detection.py). Runs before auto-export so the export is masked.
+    pii_scan_enabled: bool = False
     summary_model_id: str | None = None
     summary_prompt_template: str | None = None
     export_format: str = "plaintext"
@@ -0,0 +1,177 @@
+"""Project template CRUD endpoints.
+
+Mounted at ``/api/projects`` from ``server/api/main.py``. A project
+template bundles the metadata fields a recurring workflow records (case
+number, client, interviewee…), pre-filled values for some of them, and
+the transcription options to import with. The dashboard applies a
+template when queueing files; the server only stores it and validates
+its shape (``server/backend/database/project_template_repository.py``).
+
+Persist-Before-Deliver (NFR16): every handler relies on the repository's
+commit-before-return guarantee.
+"""
+
+from __future__ import annotations
+
+import logging
+from typing import Any
+
+from fastapi import APIRouter, HTTPException
+from pydantic import BaseModel, Field
+from server.database import project_template_repository
+from server.database.metadata_repository import MAX_FIELD_LENGTH, normalize_metadata
+
+logger = logging.getLogger(__name__)
+router = APIRouter()
+
+
+class ProjectField(BaseModel):
+    """A metadata field the template's recordings are asked for."""
+
+    key: str
+    label: str = ""
+
+
+class ProjectOptions(BaseModel):
+    """Transcription options pre-selected for files imported under the template."""
+
+    language: str | None = None
+    enable_diarization: bool | None = None
+    expected_speakers: int | None = Field(default=None, ge=1, le=10)
+    profile_id: int | None = None
+
+    model_config = {"extra": "allow"}  # forward-compat: unknown keys preserved
+
+
+class ProjectTemplateCreate(BaseModel):
+    name: str
+    description: str | None = None
+    fields: list[ProjectField] = Field(default_factory=list)
+    defaults: dict[str, str] = Field(default_factory=dict)
+    options: ProjectOptions = Field(default_factory=ProjectOptions)
+
+
+class ProjectTemplateUpdate(BaseModel):
+    name: str | None = None
+    description: str | None = None
+    fields: list[ProjectField] | None = None
+    defaults: dict[str, str] | None = None
+    options: ProjectOptions | None = None
+
+
+class ProjectTemplateResponse(BaseModel):
+    id: int
+    name: str
+    description: str | None
+    fields: list[ProjectField]
+    defaults: dict[str, str]
+    options: ProjectOptions
+    created_at: str
+    updated_at: str
+
+
+def _clean_name(name: str) -> str:
+    clean = name.strip()
+    if not clean:
+        raise HTTPException(status_code=400, detail={"error": "name_required"})
+    return clean
+
+
+def _clean_fields(fields: list[ProjectField]) -> list[dict[str, str]]:
+    """Trimmed fields with their label defaulting to the key; keys must be unique."""
+    clean: list[dict[str, str]] = []
+    seen: set[str] = set()
+    for field in fields:
+        key = field.key.strip()
+        if not key or len(key) > MAX_FIELD_LENGTH:
+            raise HTTPException(
+                status_code=400, detail={"error": "invalid_field", "field": field.key}
+            )
+        if key.casefold() in seen:
+            raise HTTPException(status_code=400, detail={"error": "duplicate_field", "field": key})
+        seen.add(key.casefold())
+        clean.append({"key": key, "label": field.label.strip() or key})
+    return clean
+
+
+def _clean_defaults(defaults: dict[str, str]) -> dict[str, str]:
+    try:
+        return normalize_metadata(defaults)
+    except ValueError as exc:
+        raise HTTPException(
+            status_code=400, detail={"error": "invalid_defaults", "reason": str(exc)}
+        ) from exc
+
+
+def _to_response(record: dict[str, Any]) -> ProjectTemplateResponse:
+    return ProjectTemplateResponse.model_validate(record)
+
+
+@router.get("", response_model=list[ProjectTemplateResponse])
+async def list_templates_endpoint() -> list[ProjectTemplateResponse]:
+    return [_to_response(t) for t in project_template_repository.list_templates()]
+
+
+@router.get("/{template_id}", response_model=ProjectTemplateResponse)
+async def get_template_endpoint(template_id: int) -> ProjectTemplateResponse:
+    template = project_template_repository.get_template(template_id)
+    if template is None:
+        raise HTTPException(status_code=404, detail={"error": "template_not_found"})
+    return _to_response(template)
+
+
+@router.post("", response_model=ProjectTemplateResponse, status_code=201)
+async def create_template_endpoint(body: ProjectTemplateCreate) -> ProjectTemplateResponse:
+    template_id = project_template_repository.create_template(
+        name=_clean_name(body.name),
+        description=body.description,
+        fields=_clean_fields(body.fields),
+        defaults=_clean_defaults(body.defaults),
+        options=body.options.model_dump(exclude_none=True),
+    )
+    template = project_template_repository.get_template(template_id)
+    if template is None:
+        raise HTTPException(
+            status_code=500,
+            detail={"error": "template_vanished_post_commit", "id": template_id},
+        )
+    return _to_response(template)
+
+
+@router.put("/{template_id}", response_model=ProjectTemplateResponse)
+async def update_template_endpoint(
+    template_id: int, body: ProjectTemplateUpdate
+) -> ProjectTemplateResponse:
+    # Only fields present in the payload are written; null on the JSON
+    # columns means "reset to empty".
+    sent = body.model_fields_set
+    update_kwargs: dict[str, Any] = {}
+    if "name" in sent and body.name is not None:
+        update_kwargs["name"] = _clean_name(body.name)
+    if "description" in sent:
+        update_kwargs["description"] = body.description
+    if "fields" in sent:
+        update_kwargs["fields"] = _clean_fields(body.fields or [])
+    if "defaults" in sent:
+        update_kwargs["defaults"] = _clean_defaults(body.defaults or {})
+    if "options" in sent:
+        update_kwargs["options"] = (
+            body.options.model_dump(exclude_none=True) if body.options is not None else {}
+        )
+
+    if update_kwargs:
+        updated = project_template_repository.update_template(template_id, **update_kwargs)
+    else:
+        updated = project_template_repository.get_template(template_id) is not None
+    if not updated:
+        raise HTTPException(status_code=404, detail={"error": "template_not_found"})
+    template = project_template_repository.get_template(template_id)
+    if template is None:
+        raise HTTPException(status_code=404, detail={"error": "template_not_found"})
+    return _to_response(template)
+
+
+@router.delete("/{template_id}", status_code=204)
+async def delete_template_endpoint(template_id: int) -> None:
+    if not project_template_repository.delete_template(template_id):
+        raise HTTPException(status_code=404, detail={"error": "template_not_found"})
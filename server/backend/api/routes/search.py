@@ -8,6 +8,8 @@ import logging
 from typing import Any
 
 from fastapi import APIRouter, HTTPException, Query
+from server.api.routes.utils import parse_metadata_filters
+from server.database import metadata_repository
 from server.database.database import (
     search_recording_metadata,
     search_recordings,
@@ -80,12 +82,18 @@ async def unified_search(
     start_date: str | None = Query(None, description="Start date (YYYY-MM-DD)"),
     end_date: str | None = Query(None, description="End date (YYYY-MM-DD)"),
     limit: int = Query(50, ge=1, le=200, description="Maximum results"),
+    metadata: list[str] = Query(  # noqa: B008
+        [], description="field=value, repeatable; only recordings matching all"
+    ),
 ) -> dict[str, Any]:
     """
     Unified search endpoint.
 
     Can search words, recordings, or both based on the type parameter.
+    ``metadata`` filters narrow every kind of match to recordings whose
+    custom fields match.
     """
+    filters = parse_metadata_filters(metadata)
     try:
         clean_query = q.strip()
 
@@ -144,6 +152,32 @@ async def unified_search(
                 }
             )
 
+        # 3) Custom metadata values (client, case number…)
+        field_rows = metadata_repository.search_metadata(
+            clean_query, start_date=start_date, end_date=end_date, limit=limit
+        )
+        for row in field_rows:
+            results.append(
+                {
+                    "id": None,
+                    "recording_id": row.get("recording_id"),
+                    "segment_id": None,
+                    "word": clean_query,
+                    "start_time": 0.0,
+                    "end_time": 0.0,
+                    "filename": row.get("filename"),
+                    "title": row.get("title"),
+                    "recorded_at": row.get("recorded_at"),
+                    "speaker": None,
+                    "context": f"{row.get('field')}: {row.get('value')}",
+                    "match_type": "metadata",
+                }
+            )
+
+        if filters:
+            matching = metadata_repository.filter_recording_ids(filters)
+            results = [r for r in results if r.get("recording_id") in matching]
+
         # Keep stable ordering: most recent recordings first, then by start time
         results_sorted = sorted(
             results,
@@ -6,12 +6,15 @@ Handles:
 - Real-time audio streaming (WebSocket)
 - Transcription status and results
 - File import (background transcription without notebook storage)
+- The queue of clients waiting for the transcription slot
 """
 
 import asyncio
 import functools
 import json as _json
 import logging
+import re
+import shutil
 import tempfile
 from pathlib import Path
 from typing import Any, Literal
@@ -26,14 +29,35 @@ from fastapi import (
     Response,
     UploadFile,
 )
-from fastapi.responses import JSONResponse
+from fastapi.responses import JSONResponse, StreamingResponse
 from pydantic import BaseModel
-from server.api.routes.utils import get_client_name
+from server.api.routes.utils import (
+    check_upload_source,
+    get_client_name,
+    get_permissions,
+    get_requester,
+    receive_upload,
+    save_upload,
+)
 from server.config import resolve_main_transcriber_model, resolve_parallel_diarization_default
+from server.core.audio_fingerprint import compute_fingerprint
+from server.core.job_queue import QueueEntryNotFound, QueueFull
 from server.core.json_utils import sanitize_for_json
 from server.core.model_manager import TranscriptionCancelledError
+from server.core.storage_encryption import (
+    call_with_plaintext,
+    encrypt_at_rest,
+    iter_plaintext,
+    plaintext_size,
+)
 from server.core.stt.backends.base import BackendDependencyError
-from server.database.dedup_query import find_duplicates_anywhere
+from server.core.upload_staging import (
+    StagedUploadError,
+    StagedUploadNotFound,
+    StagedUploadOffsetMismatch,
+    get_upload_staging,
+)
+from server.database.dedup_query import find_duplicates_anywhere, find_fingerprint_matches
 from server.database.job_repository import (
     create_job,
     mark_delivered,
@@ -259,8 +283,7 @@ async def transcribe_audio(
         # Save uploaded file to temp location
         suffix = Path(file.filename).suffix or ".wav"
         with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
-            content = await file.read()
-            tmp.write(content)
+            await save_upload(file, tmp)
             tmp_path = tmp.name
 
         # Issue #104, Story 2.2 — compute SHA-256 of the saved upload for
@@ -528,12 +551,18 @@ async def transcribe_audio(
                         exc_info=True,
                     )
         else:
-            # Transcribe without diarization
+            # Transcribe without diarization; out-of-memory retries in chunks or
+            # with a smaller model (core/oom_fallback.py).
+            from server.core.oom_fallback import fallback_settings, transcribe_with_fallback
+
             logger.info("Transcribing uploaded file")
-            result = await asyncio.to_thread(
+            result, engine = await asyncio.to_thread(
                 functools.partial(
-                    engine.transcribe_file,
+                    transcribe_with_fallback,
+                    model_manager,
+                    engine,
                     tmp_path,
+                    settings=fallback_settings(model_manager.config),
                     language=language,
                     task="translate" if translation_enabled else "transcribe",
                     translation_target_language=(
@@ -692,8 +721,7 @@ async def transcribe_quick(
     # Save uploaded file to temp location
     suffix = Path(file.filename).suffix or ".wav"
     with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
-        content = await file.read()
-        tmp.write(content)
+        await save_upload(file, tmp)
         tmp_path = tmp.name
 
     try:
@@ -797,6 +825,136 @@ async def cancel_transcription(request: Request) -> dict[str, Any]:
         }
 
 
+class QueueJoinRequest(BaseModel):
+    """A client asking for a place in the queue."""
+
+    label: str = ""
+    duration_seconds: float | None = None
+
+
+class QueueOrderRequest(BaseModel):
+    """Every queue entry the caller holds, in the order it wants them run."""
+
+    entry_ids: list[str]
+
+
+@router.get("/queue")
+async def get_queue(request: Request) -> dict[str, Any]:
+    """
+    The running job and the clients waiting for the transcription slot.
+
+    Other clients' entries show who is waiting but not what (callers with
+    ``can_view_all_jobs`` also see their labels); only the caller's own
+    entries carry their id. Polling this endpoint keeps the caller's entries
+    alive — entries that stop being polled expire.
+    """
+    model_manager = request.app.state.model_manager
+    view_all = get_permissions(request)["can_view_all_jobs"]
+    return model_manager.job_tracker.get_queue(get_requester(request), view_all)
+
+
+@router.post("/queue", status_code=201)
+async def join_queue(body: QueueJoinRequest, request: Request) -> dict[str, Any]:
+    """
+    Take a place in the queue. Once the entry reaches the front and the slot
+    is free, start the job with ``queue_entry_id`` set to the returned id —
+    while anyone is queued, jobs without the head entry's id get 409.
+    """
+    model_manager = request.app.state.model_manager
+    try:
+        entry_id = model_manager.job_tracker.join_queue(
+            get_requester(request), body.label, body.duration_seconds
+        )
+    except QueueFull as e:
+        raise HTTPException(status_code=429, detail=str(e)) from e
+    return {"id": entry_id}
+
+
+@router.delete("/queue/{entry_id}")
+async def leave_queue(entry_id: str, request: Request) -> dict[str, Any]:
+    """Give up one of the caller's places in the queue."""
+    model_manager = request.app.state.model_manager
+    try:
+        model_manager.job_tracker.leave_queue(entry_id, get_requester(request))
+    except QueueEntryNotFound as e:
+        raise HTTPException(status_code=404, detail="Queue entry not found") from e
+    return {"success": True}
+
+
+@router.put("/queue/order")
+async def reorder_queue(body: QueueOrderRequest, request: Request) -> dict[str, Any]:
+    """
+    Reorder the caller's own entries. They trade places among the positions
+    the caller already holds, so other clients' jobs keep their place.
+    """
+    model_manager = request.app.state.model_manager
+    requester = get_requester(request)
+    try:
+        model_manager.job_tracker.reorder_queue(requester, body.entry_ids)
+    except ValueError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    return model_manager.job_tracker.get_queue(requester)
+
+
+# ─── Staged uploads (send files ahead of their turn at the slot) ────────────
+
+MAX_CHUNK_BYTES = 16 * 1024 * 1024
+
+
+class StagedUploadRequest(BaseModel):
+    filename: str
+    size: int
+
+
+@router.post("/uploads", status_code=201)
+async def create_staged_upload(body: StagedUploadRequest, request: Request) -> dict[str, Any]:
+    """
+    Start staging a file. Send its bytes with ``PUT /uploads/{id}``, then
+    pass ``upload_id`` instead of ``file`` to ``/import`` or the notebook
+    upload. Staging does not take the transcription slot.
+    """
+    try:
+        upload = get_upload_staging().create(get_requester(request), body.filename, body.size)
+    except StagedUploadError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    return {"upload_id": upload.id, "received": 0, "size": upload.size}
+
+
+@router.put("/uploads/{upload_id}")
+async def append_staged_upload(upload_id: str, offset: int, request: Request) -> Response:
+    """
+    Append the raw request body at ``offset``. A wrong offset gets 409 with
+    the bytes received so far, so the client can resume from there.
+    """
+    data = await request.body()
+    if len(data) > MAX_CHUNK_BYTES:
+        raise HTTPException(
+            status_code=413, detail=f"Chunks are limited to {MAX_CHUNK_BYTES} bytes"
+        )
+    staging = get_upload_staging()
+    requester = get_requester(request)
+    try:
+        received = staging.append(upload_id, requester, offset, data)
+    except StagedUploadNotFound as e:
+        raise HTTPException(status_code=404, detail="Staged upload not found") from e
+    except StagedUploadOffsetMismatch as e:
+        return JSONResponse(status_code=409, content={"detail": str(e), "received": e.received})
+    except StagedUploadError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    size = staging.status(upload_id, requester).size
+    return JSONResponse(content={"upload_id": upload_id, "received": received, "size": size})
+
+
+@router.delete("/uploads/{upload_id}")
+async def discard_staged_upload(upload_id: str, request: Request) -> dict[str, Any]:
+    """Drop a staged file the caller no longer needs."""
+    try:
+        get_upload_staging().discard(upload_id, get_requester(request))
+    except StagedUploadNotFound as e:
+        raise HTTPException(status_code=404, detail="Staged upload not found") from e
+    return {"success": True}
+
+
 # ─── File Import (background transcription, no notebook/DB storage) ─────────
 
 
@@ -815,12 +973,55 @@ class DedupMatch(BaseModel):
 
     Default value preserves wire compatibility for the pre-Item-2 fixture
     data where every match was implicitly a transcription_job.
+
+    ``match="fingerprint"`` marks a recording that is the same audio but not
+    the same samples — a re-encode or a trimmed copy (core/audio_fingerprint.py).
+    Those carry ``offset_seconds`` (match time = upload time + offset),
+    ``duration_seconds`` of the upload and ``similarity``, so the client can
+    reuse the match's transcript shifted onto the upload.
     """
 
     recording_id: str
     name: str
     created_at: str
     source: Literal["transcription_job", "recording"] = "transcription_job"
+    match: Literal["hash", "fingerprint"] = "hash"
+    offset_seconds: float | None = None
+    duration_seconds: float | None = None
+    similarity: float | None = None
+
+
+def fingerprint_dedup_matches(
+    fingerprint: list[int] | None, exclude_ids: set[str] | None = None
+) -> list[DedupMatch]:
+    """Recordings that are a re-encoded or trimmed copy of the fingerprinted upload."""
+    if not fingerprint:
+        return []
+    return [
+        DedupMatch(
+            recording_id=m["id"],
+            name=m["name"],
+            created_at=m["created_at"],
+            source="recording",
+            match="fingerprint",
+            offset_seconds=m["offset_seconds"],
+            duration_seconds=m["duration_seconds"],
+            similarity=m["similarity"],
+        )
+        for m in find_fingerprint_matches(fingerprint, limit=10, exclude_ids=exclude_ids)
+    ]
+
+
+class KeptMedia(BaseModel):
+    """The server copy of an import made with ``keep_media``.
+
+    ``job_id`` is the full id ``GET /jobs/{job_id}/media`` takes, and
+    ``sha256`` the digest of the uploaded bytes the download must match.
+    """
+
+    job_id: str
+    sha256: str
+    size: int
 
 
 class ImportAcceptedResponse(BaseModel):
@@ -830,10 +1031,38 @@ class ImportAcceptedResponse(BaseModel):
     ``audio_hash`` matches this upload. Empty list when no prior match
     (J1 happy-path silently proceeds — AC2.4.AC3). Default-empty keeps the
     response shape backwards compatible for clients that ignore the field.
+
+    ``kept_media`` is set only when ``keep_media`` was asked for and the
+    server copy was stored.
     """
 
     job_id: str
     dedup_matches: list[DedupMatch] = []
+    kept_media: KeptMedia | None = None
+
+
+def _keep_import_media(job_id: str, tmp_path: Path, filename: str) -> Path:
+    """Copy an import's upload into ``durability.recordings_dir`` for later download.
+
+    The copy is the job's ``audio_path``, so it can also be retried, and is
+    encrypted at rest like the session recordings kept there.
+    """
+    from server.config import get_config
+    from server.database.job_repository import set_audio_path
+
+    recordings_dir = Path(
+        get_config().get("durability", "recordings_dir", default="/data/recordings")
+        or "/data/recordings"
+    )
+    recordings_dir.mkdir(parents=True, exist_ok=True)
+    suffix = Path(filename).suffix.lower()
+    if not re.fullmatch(r"\.[a-z0-9]{1,8}", suffix):
+        suffix = ""
+    kept = recordings_dir / f"{job_id}{suffix}"
+    shutil.copyfile(tmp_path, kept)
+    set_audio_path(job_id, str(kept))
+    encrypt_at_rest(kept)
+    return kept
 
 
 def _run_file_import(
@@ -1103,11 +1332,17 @@ def _run_file_import(
                         model_manager.get_diarization_feature_status().get("reason", "unavailable")
                     )
             else:
-                # Transcribe without diarization
+                # Transcribe without diarization; out-of-memory retries in chunks
+                # or with a smaller model (core/oom_fallback.py).
+                from server.core.oom_fallback import fallback_settings, transcribe_with_fallback
+
                 logger.info("File import: transcribing uploaded file: %s", filename)
                 tracker.set_phase("transcribing")
-                result = engine.transcribe_file(
+                result, engine = transcribe_with_fallback(
+                    model_manager,
+                    engine,
                     str(tmp_path),
+                    settings=fallback_settings(model_manager.config),
                     language=language,
                     task="translate" if translation_enabled else "transcribe",
                     translation_target_language=(
@@ -1190,7 +1425,7 @@ def _run_file_import(
 @router.post("/import", response_model=ImportAcceptedResponse, status_code=202)
 async def import_and_transcribe(
     request: Request,
-    file: UploadFile = File(...),  # noqa: B008
+    file: UploadFile | None = File(None),  # noqa: B008
     language: str | None = Form(None),
     translation_enabled: bool = Form(False),
     translation_target_language: str | None = Form(None),
@@ -1200,6 +1435,9 @@ async def import_and_transcribe(
     parallel_diarization: bool | None = Form(None),
     diarization_engine: str | None = Form(None),
     multitrack: bool = Form(False),
+    queue_entry_id: str | None = Form(None),
+    upload_id: str | None = Form(None),
+    keep_media: bool = Form(False),
 ) -> dict[str, Any]:
     """
     Import an audio file and transcribe it in the background.
@@ -1211,12 +1449,17 @@ async def import_and_transcribe(
     Returns 202 Accepted immediately with a job_id. Clients should poll
     GET /api/admin/status to check job_tracker.result for completion.
 
+    ``upload_id`` names a file staged with ``POST /uploads`` and replaces
+    ``file``.
+
+    With ``keep_media`` the uploaded file stays on the server after the job,
+    for the client to fetch again from ``GET /jobs/{job_id}/media``; the
+    response's ``kept_media`` says where and what digest to expect.
+
     Returns 409 Conflict if another transcription job is already running.
     """
     _assert_main_model_selected(request)
-
-    if not file.filename:
-        raise HTTPException(status_code=400, detail="No file provided")
+    check_upload_source(request, file, upload_id)
 
     # Validate expected_speakers parameter
     if expected_speakers is not None:
@@ -1231,7 +1474,9 @@ async def import_and_transcribe(
     client_name = get_client_name(request)
 
     # Try to acquire a job slot
-    success, job_id, active_user = model_manager.job_tracker.try_start_job(client_name)
+    success, job_id, active_user = model_manager.job_tracker.try_start_job(
+        get_requester(request), queue_entry_id
+    )
     if not success:
         raise HTTPException(
             status_code=409,
@@ -1239,11 +1484,11 @@ async def import_and_transcribe(
         )
 
     # Save uploaded file to temp location (fast — just I/O)
-    suffix = Path(file.filename).suffix or ".wav"
-    with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
-        content = await file.read()
-        tmp.write(content)
-        tmp_path = Path(tmp.name)
+    try:
+        tmp_path, filename = await receive_upload(request, file, upload_id)
+    except HTTPException as e:
+        model_manager.job_tracker.end_job(job_id, {"job_id": job_id, "error": e.detail})
+        raise
 
     # Issue #104, Story 2.2 — durability row for the /import flow exists
     # purely so the dedup-check endpoint can find re-imports of the same
@@ -1304,6 +1549,28 @@ async def import_and_transcribe(
             job_id[:8],
             _e,
         )
+    # Re-encoded or trimmed copies of notebook recordings — those have a
+    # transcript the dashboard can reuse, shifted by the reported offset.
+    try:
+        fingerprint = await asyncio.to_thread(compute_fingerprint, tmp_path)
+        dedup_matches += await asyncio.to_thread(
+            fingerprint_dedup_matches,
+            fingerprint,
+            {m.recording_id for m in dedup_matches if m.source == "recording"},
+        )
+    except Exception as _e:
+        logger.warning("Fingerprint dedup failed for import job %s: %s", job_id[:8], _e)
+
+    # Without a digest the copy could not be verified on download, so it is
+    # only kept when hashing succeeded.
+    kept_media: KeptMedia | None = None
+    if keep_media and audio_hash:
+        try:
+            size = tmp_path.stat().st_size
+            await asyncio.to_thread(_keep_import_media, job_id, tmp_path, filename)
+            kept_media = KeptMedia(job_id=job_id, sha256=audio_hash, size=size)
+        except Exception as _e:
+            logger.warning("Failed to keep the server copy of import job %s: %s", job_id[:8], _e)
 
     # Resolve parallel diarization default from config before entering background thread
     config = request.app.state.config
@@ -1318,7 +1585,7 @@ async def import_and_transcribe(
             _run_file_import,
             model_manager=model_manager,
             tmp_path=tmp_path,
-            filename=file.filename,
+            filename=filename,
             language=language,
             translation_enabled=translation_enabled,
             translation_target_language=translation_target_language,
@@ -1341,7 +1608,7 @@ async def import_and_transcribe(
     # dedup_matches carries any prior jobs with the same audio_hash so the
     # dashboard can show the dedup prompt (Story 2.4) without a follow-up
     # round-trip. Empty list = J1 happy path (no duplicate found).
-    return {"job_id": job_id[:8], "dedup_matches": dedup_matches}
+    return {"job_id": job_id[:8], "dedup_matches": dedup_matches, "kept_media": kept_media}
 
 
 class DedupCheckRequest(BaseModel):
@@ -1554,8 +1821,10 @@ async def _run_retry(job_id: str, audio_path: str, job: dict[str, Any], app_stat
         # detached (Issue #76).
         engine = await asyncio.to_thread(model_manager.ensure_transcription_loaded)
 
+        # Recordings kept for retry may be encrypted at rest.
         result = await asyncio.to_thread(
-            engine.transcribe_file,
+            call_with_plaintext,
+            lambda readable, **kwargs: engine.transcribe_file(str(readable), **kwargs),
             audio_path,
             language=job.get("language"),
             task=job.get("task", "transcribe"),
@@ -1631,6 +1900,63 @@ async def _run_retry(job_id: str, audio_path: str, job: dict[str, Any], app_stat
             model_manager.job_tracker.end_job(tracker_job_id)
 
 
+def _owned_job(job_id: str, request: Request) -> dict[str, Any]:
+    from ...database.job_repository import get_job
+
+    job = get_job(job_id)
+    if job is None:
+        raise HTTPException(status_code=404, detail="Job not found")
+    client_name = get_client_name(request)
+    if job.get("client_name") is not None and job["client_name"] != client_name:
+        raise HTTPException(status_code=403, detail="Access denied")
+    return job
+
+
+@router.get("/jobs/{job_id}/media", response_model=None)
+async def download_job_media(job_id: str, request: Request) -> StreamingResponse:
+    """Stream the audio a job kept on the server (an import's ``keep_media`` copy).
+
+    ``X-Content-SHA256`` carries the digest recorded when the audio arrived;
+    clients compare it with what they received before trusting the file.
+
+    Returns:
+        200: The audio, decrypted when stored encrypted.
+        403: Job belongs to a different client.
+        404: Job not found.
+        410: No audio was kept for this job, or it has been deleted.
+    """
+    job = _owned_job(job_id, request)
+    audio_path = job.get("audio_path")
+    if not audio_path or not Path(audio_path).exists():
+        raise HTTPException(status_code=410, detail="No server copy is kept for this job")
+    path = Path(audio_path)
+    headers = {
+        "Content-Length": str(await asyncio.to_thread(plaintext_size, path)),
+        "Content-Disposition": f'attachment; filename="{job_id[:8]}{path.suffix}"',
+    }
+    if job.get("audio_hash"):
+        headers["X-Content-SHA256"] = job["audio_hash"]
+    return StreamingResponse(
+        iter_plaintext(path), media_type="application/octet-stream", headers=headers
+    )
+
+
+@router.delete("/jobs/{job_id}/media", response_model=None)
+async def delete_job_media(job_id: str, request: Request) -> JSONResponse:
+    """Delete a job's kept audio; the job row stays. ``deleted`` is false when none was kept."""
+    job = _owned_job(job_id, request)
+    # An import transcribes its own temp copy, and its row is never moved out
+    # of 'processing'; other sources read audio_path while they run.
+    if job["status"] == "processing" and job.get("source") != "file_import":
+        raise HTTPException(status_code=409, detail="Job is still processing")
+    audio_path = job.get("audio_path")
+    deleted = False
+    if audio_path and Path(audio_path).exists():
+        await asyncio.to_thread(Path(audio_path).unlink, missing_ok=True)
+        deleted = True
+    return JSONResponse(content={"job_id": job_id, "deleted": deleted})
+
+
 def _sorted_languages(langs: dict[str, str]) -> dict[str, str]:
     """Return *langs* sorted: English first, then alphabetical by name."""
     items = sorted(langs.items(), key=lambda kv: (kv[1] != "English", kv[1]))
@@ -1873,3 +2199,67 @@ async def get_supported_languages(request: Request) -> dict[str, Any]:
         "backend_type": backend_type,
         "supports_translation": supports_translation,
     }
+
+
+@router.post("/detect-language")
+async def detect_language(
+    request: Request,
+    file: UploadFile = File(...),  # noqa: B008
+    seconds: float | None = Form(None),
+) -> dict[str, Any]:
+    """Detect the spoken language from the first ``seconds`` of an upload.
+
+    A preview for the job options dialog — clients may send just the head
+    of the file. Returns language probabilities, the per-window top language
+    and ``multilingual`` when confident windows disagree (see
+    ``core/language_detection.py``). ``supported`` is false for backends
+    without language detection.
+
+    Returns 409 while a transcription job is running (the preview shares
+    the loaded model and is never worth queueing behind a job).
+    """
+    from server.core.audio_utils import AudioDecodeError
+    from server.core.language_detection import DEFAULT_SECONDS, MAX_SECONDS
+
+    _assert_main_model_selected(request)
+    if not file.filename:
+        raise HTTPException(status_code=400, detail="No file provided")
+    sample_seconds = DEFAULT_SECONDS if seconds is None else seconds
+    if not 1.0 <= sample_seconds <= MAX_SECONDS:
+        raise HTTPException(
+            status_code=400, detail=f"seconds must be between 1 and {MAX_SECONDS:g}"
+        )
+
+    model_manager = request.app.state.model_manager
+    busy, active_user = model_manager.job_tracker.is_busy()
+    if busy:
+        raise HTTPException(
+            status_code=409, detail=f"A transcription is already running for {active_user}"
+        )
+    try:
+        await asyncio.to_thread(model_manager.ensure_transcription_loaded)
+    except BackendDependencyError as dep_err:
+        remedy_suffix = f". {dep_err.remedy}" if dep_err.remedy else ""
+        raise HTTPException(
+            status_code=503, detail=f"Backend dependency missing: {dep_err}{remedy_suffix}"
+        ) from dep_err
+
+    suffix = Path(file.filename).suffix or ".wav"
+    with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
+        await save_upload(file, tmp)
+        tmp_path = tmp.name
+    try:
+        return await asyncio.to_thread(
+            functools.partial(
+                model_manager.transcription_engine.detect_language_file,
+                tmp_path,
+                seconds=sample_seconds,
+            )
+        )
+    except AudioDecodeError as e:
+        raise HTTPException(status_code=400, detail=str(e)) from e
+    finally:
+        try:
+            Path(tmp_path).unlink()
+        except OSError:
+            logger.warning("Failed to cleanup temp file %s", tmp_path, exc_info=True)
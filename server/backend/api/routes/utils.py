@@ -3,16 +3,26 @@ Shared utilities for API routes.
 """
 
 import asyncio
+import io
 import ipaddress
 import logging
 import os
+import re
+import shutil
+import tempfile
 from dataclasses import dataclass
 from http.cookies import SimpleCookie
 from pathlib import Path
-from typing import Any
+from typing import Any, BinaryIO
+from urllib.parse import unquote
 
 import server.core.token_store as _ts_mod
-from fastapi import Request, WebSocket
+from fastapi import HTTPException, Request, UploadFile, WebSocket
+from server.core.upload_staging import (
+    StagedUploadError,
+    StagedUploadNotFound,
+    get_upload_staging,
+)
 from starlette.websockets import WebSocketState
 
 logger = logging.getLogger(__name__)
@@ -315,6 +325,133 @@ def get_client_name(request: Request) -> str:
     return "Unknown Client"
 
 
+# Optional per-person identity sent by the dashboard (percent-encoded UTF-8),
+# for households that share one server — and often one token.
+USER_HEADER = "X-TranscriptionSuite-User"
+USER_NAME_MAX_LENGTH = 64
+
+
+def get_user_name(request: Request) -> str | None:
+    """
+    The person behind the request, as named by the client's settings.
+
+    Self-declared and unauthenticated: it namespaces queue entries and labels
+    new recordings so each person can filter to their own items, but it is
+    not an access-control boundary — anyone holding the token can send any
+    name. Returns None when the header is missing or blank.
+    """
+    raw = request.headers.get(USER_HEADER)
+    if not raw:
+        return None
+    name = "".join(c for c in unquote(raw) if c.isprintable()).strip()
+    return name[:USER_NAME_MAX_LENGTH] or None
+
+
+def get_requester(request: Request) -> str:
+    """
+    Identity for the shared transcription slot and its queue.
+
+    ``get_client_name`` narrowed by ``get_user_name``, so two people using
+    the same token (or the localhost bypass) hold separate queue entries.
+    Result ownership keeps using ``get_client_name`` alone.
+    """
+    client_name = get_client_name(request)
+    user_name = get_user_name(request)
+    return f"{client_name}/{user_name}" if user_name else client_name
+
+
+# Destructive actions and settings changes recorded in the audit log by
+# ``AuditMiddleware`` (api/main.py) once they succeed. The ``id`` group, if
+# any, is stored as the event's target_id.
+_RECORDING = r"/api/notebook/recordings/(?P<id>\d+)"
+AUDITED_ROUTES: tuple[tuple[str, re.Pattern[str], str, str | None], ...] = tuple(
+    (method, re.compile(pattern + "$"), action, target_type)
+    for method, pattern, action, target_type in (
+        ("DELETE", _RECORDING, "recording.delete", "recording"),
+        ("PATCH", _RECORDING + "/transcript", "transcript.edit", "recording"),
+        ("POST", _RECORDING + "/realign", "transcript.realign", "recording"),
+        ("POST", _RECORDING + "/labels", "transcript.import_labels", "recording"),
+        ("POST", _RECORDING + r"/segments/\d+/retranscribe", "segment.retranscribe", "recording"),
+        ("POST", _RECORDING + r"/segments/\d+/split", "segment.split", "recording"),
+        ("POST", _RECORDING + "/segments/merge", "segment.merge", "recording"),
+        ("POST", _RECORDING + "/diarization-review", "speakers.review", "recording"),
+        ("PUT", _RECORDING + "/segment-review", "speakers.reassign", "recording"),
+        ("PUT", _RECORDING + "/aliases", "speakers.rename", "recording"),
+        ("PUT", _RECORDING + "/metadata", "metadata.edit", "recording"),
+        ("DELETE", _RECORDING + r"/annotations/\d+", "annotation.delete", "recording"),
+        ("POST", _RECORDING + "/redactions", "redaction.create", "recording"),
+        ("DELETE", _RECORDING + r"/redactions/\d+", "redaction.delete", "recording"),
+        ("POST", _RECORDING + "/pii-scan", "redaction.pii_scan", "recording"),
+        (
+            "DELETE",
+            r"/api/notebook/voice-profiles/(?P<id>\d+)",
+            "voice_profile.delete",
+            "voice_profile",
+        ),
+        ("POST", "/api/notebook/restore", "database.restore", None),
+        ("POST", "/api/notebook/maintenance", "database.maintenance", None),
+        ("PATCH", "/api/admin/diarization", "settings.diarization", None),
+        ("POST", "/api/admin/library/take-ownership", "library.take_ownership", None),
+        ("POST", "/api/auth/tokens", "token.create", "token"),
+        ("DELETE", r"/api/auth/tokens/(?P<id>[^/]+)", "token.revoke", "token"),
+        ("POST", r"/api/auth/tokens/(?P<id>[^/]+)/rotate", "token.rotate", "token"),
+        ("DELETE", r"/api/auth/users/(?P<id>[^/]+)", "user.delete", "user"),
+        ("POST", "/api/profiles", "profile.create", "profile"),
+        ("PUT", r"/api/profiles/(?P<id>\d+)", "profile.update", "profile"),
+        ("DELETE", r"/api/profiles/(?P<id>\d+)", "profile.delete", "profile"),
+        ("POST", "/api/projects", "project_template.create", "project_template"),
+        ("PUT", r"/api/projects/(?P<id>\d+)", "project_template.update", "project_template"),
+        ("DELETE", r"/api/projects/(?P<id>\d+)", "project_template.delete", "project_template"),
+    )
+)
+
+
+def audited_action(method: str, path: str) -> tuple[str, str | None, str | None] | None:
+    """``(action, target_type, target_id)`` when the request is audited, else None."""
+    for route_method, pattern, action, target_type in AUDITED_ROUTES:
+        if method != route_method:
+            continue
+        match = pattern.match(path)
+        if match:
+            target_id = match.groupdict().get("id")
+            return action, target_type, unquote(target_id) if target_id else None
+    return None
+
+
+def audit_event(
+    request: Request,
+    action: str,
+    *,
+    target_type: str | None = None,
+    target_id: str | int | None = None,
+    details: dict[str, Any] | None = None,
+) -> None:
+    """
+    Append a destructive action or settings change to the audit log
+    (``database/audit_repository.py``), attributed to ``get_requester`` and
+    the initiating ``METHOD /path?query`` (auth tokens stripped). Call it
+    once the action has succeeded. A failed write is logged, not raised:
+    the action has already happened and its response must still go out.
+    """
+    from server.database import audit_repository
+
+    query = "&".join(
+        f"{key}={value}" for key, value in request.query_params.multi_items() if key != "token"
+    )
+    command = f"{request.method} {request.url.path}" + (f"?{query}" if query else "")
+    try:
+        audit_repository.record_event(
+            action,
+            command=command,
+            actor=get_requester(request),
+            target_type=target_type,
+            target_id=target_id,
+            details=details,
+        )
+    except Exception as e:
+        logger.error("Failed to record audit event %s: %s", action, e)
+
+
 def get_authenticated_token(request: Request):
     """
     Get the authenticated token from the request.
@@ -345,6 +482,24 @@ def require_admin(request: Request) -> bool:
     return stored_token is not None and stored_token.is_admin
 
 
+# Capabilities reported by GET /api/auth/permissions. All of them are admin-
+# only today; clients hide the matching features instead of waiting for 403s.
+ADMIN_CAPABILITIES = (
+    "can_manage_models",  # POST /api/admin/models/load, /models/unload
+    "can_manage_tokens",  # /api/auth/tokens
+    "can_change_server_settings",  # PATCH /api/admin/diarization, /webhook/test
+    "can_manage_library",  # POST /api/admin/library/take-ownership
+    "can_view_all_jobs",  # GET /api/admin/status, every label in GET /api/transcribe/queue
+    "can_view_audit_log",  # GET /api/admin/audit-log
+)
+
+
+def get_permissions(request: Request) -> dict[str, bool]:
+    """Capability flags for the caller, keyed by ``ADMIN_CAPABILITIES``."""
+    is_admin = require_admin(request)
+    return {capability: is_admin for capability in ADMIN_CAPABILITIES}
+
+
 def sanitize_for_log(value: str, max_length: int = 200) -> str:
     """
     Sanitize user input before logging to prevent log injection attacks.
@@ -373,3 +528,102 @@ def sanitize_for_log(value: str, max_length: int = 200) -> str:
         return sanitized[:max_length] + "..."
 
     return sanitized
+
+
+def parse_metadata_filters(filters: list[str]) -> dict[str, str]:
+    """``["Client=Acme", …]`` query values as ``{"Client": "Acme"}``."""
+    parsed: dict[str, str] = {}
+    for item in filters:
+        field, sep, value = item.partition("=")
+        if not sep or not field.strip() or not value.strip():
+            raise HTTPException(
+                status_code=400,
+                detail=f"Metadata filter must look like field=value, got {item!r}",
+            )
+        parsed[field.strip()] = value.strip()
+    return parsed
+
+
+def check_upload_source(request: Request, file: UploadFile | None, upload_id: str | None) -> None:
+    """
+    Reject a transcription request without audio before it takes the job
+    slot: either a multipart ``file`` or the id of a complete staged upload.
+    """
+    if upload_id is None:
+        if file is None or not file.filename:
+            raise HTTPException(status_code=400, detail="No file provided")
+        return
+    try:
+        upload = get_upload_staging().status(upload_id, get_requester(request))
+    except StagedUploadNotFound as e:
+        raise HTTPException(status_code=404, detail="Staged upload not found") from e
+    if not upload.complete:
+        raise HTTPException(
+            status_code=409,
+            detail=f"Staged upload incomplete: {upload.received} of {upload.size} bytes",
+        )
+
+
+# Copy step for multipart uploads too large for a single kernel call.
+UPLOAD_COPY_BYTES = 8 * 1024 * 1024
+
+
+def _copy_spooled_upload(src: BinaryIO, dest: BinaryIO) -> None:
+    src.seek(0)
+    # Starlette spools uploads into a SpooledTemporaryFile: a BytesIO while
+    # small, a real temp file once it rolls over. Neither is read into a
+    # fresh bytes object here.
+    inner = getattr(src, "_file", src)
+    if isinstance(inner, io.BytesIO):
+        with inner.getbuffer() as view:
+            dest.write(view)
+        return
+    dest.flush()
+    try:
+        src_fd, dest_fd = inner.fileno(), dest.fileno()
+        while os.copy_file_range(src_fd, dest_fd, UPLOAD_COPY_BYTES):
+            pass
+    except (AttributeError, OSError, io.UnsupportedOperation):
+        # No copy_file_range (macOS, Windows) or not across these files.
+        src.seek(0)
+        dest.seek(0)
+        dest.truncate()
+        shutil.copyfileobj(src, dest, UPLOAD_COPY_BYTES)
+    else:
+        dest.seek(0, os.SEEK_END)
+
+
+async def save_upload(file: UploadFile, dest: BinaryIO) -> None:
+    """
+    Write a multipart upload into ``dest`` with flat memory: in-memory spools
+    are written from a view of their buffer, on-disk spools are copied
+    kernel-side, so even a multi-gigabyte video never sits in RAM whole.
+    """
+    await asyncio.to_thread(_copy_spooled_upload, file.file, dest)
+
+
+async def receive_upload(
+    request: Request, file: UploadFile | None, upload_id: str | None
+) -> tuple[Path, str]:
+    """
+    The request's audio as a temp file the caller owns, plus its original
+    filename — written from the multipart ``file`` or moved out of the
+    staging area. Call ``check_upload_source`` first.
+    """
+    if upload_id is not None:
+        staging = get_upload_staging()
+        requester = get_requester(request)
+        try:
+            suffix = Path(staging.status(upload_id, requester).filename).suffix or ".wav"
+            with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
+                tmp_path = Path(tmp.name)
+            filename = staging.claim(upload_id, requester, tmp_path)
+        except (StagedUploadNotFound, StagedUploadError) as e:
+            raise HTTPException(status_code=409, detail=f"Staged upload unavailable: {e}") from e
+        return tmp_path, filename
+
+    assert file is not None and file.filename
+    suffix = Path(file.filename).suffix or ".wav"
+    with tempfile.NamedTemporaryFile(delete=False, suffix=suffix) as tmp:
+        await save_upload(file, tmp)
+        return Path(tmp.name), file.filename
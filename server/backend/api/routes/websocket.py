@@ -650,6 +650,11 @@ class TranscriptionSession:
                     self.temp_file.unlink()
                 except Exception as e:
                     logger.warning(f"Failed to delete temp file: {e}")
+            elif self.temp_file and self.temp_file.exists():
+                # Kept for retry — encrypt it now that transcription is done.
+                from server.core.storage_encryption import encrypt_at_rest
+
+                await asyncio.to_thread(encrypt_at_rest, self.temp_file)
             self.temp_file = None
             self.audio_chunks = []
 
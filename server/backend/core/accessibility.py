@@ -0,0 +1,166 @@
+"""Screen-reader support: throttled live announcements and an accessible export.
+
+Live Mode emits a ``sentence`` message per detected sentence — several a
+second in fast speech. Forwarded one by one to an ARIA live region, each
+new write interrupts the screen reader mid-phrase. ``LiveAnnouncer``
+batches sentences into one ``announce`` event at most every
+``min_interval`` seconds, so the reader finishes a phrase before the next
+arrives; state changes get their own short announcements.
+
+``stream_accessible`` renders the ``accessible`` export: plain text with no
+inline timestamps or markdown (screen readers read ``**`` aloud), one
+paragraph per speaker turn, each prefixed with the speaker's name.
+"""
+
+from __future__ import annotations
+
+import threading
+import time
+from collections.abc import Callable, Iterable, Iterator
+from typing import Any
+
+DEFAULT_MIN_INTERVAL = 2.0
+DEFAULT_MAX_CHARS = 280
+
+# Live engine state → (announcement, politeness). Unlisted states are silent.
+_STATE_ANNOUNCEMENTS: dict[str, tuple[str, str]] = {
+    "LISTENING": ("Live transcription listening", "polite"),
+    "STOPPED": ("Live transcription stopped", "polite"),
+    "ERROR": ("Live transcription error", "assertive"),
+}
+
+
+class LiveAnnouncer:
+    """Batch live sentences into rate-limited ``announce`` payloads.
+
+    ``push`` is called from the engine thread and ``flush`` from the event
+    loop, hence the lock.
+    """
+
+    def __init__(
+        self,
+        min_interval: float = DEFAULT_MIN_INTERVAL,
+        max_chars: int = DEFAULT_MAX_CHARS,
+        clock: Callable[[], float] = time.monotonic,
+    ) -> None:
+        self.min_interval = max(0.0, float(min_interval))
+        self.max_chars = max(40, int(max_chars))
+        self._clock = clock
+        self._pending: list[str] = []
+        self._last_emit: float | None = None
+        self._lock = threading.Lock()
+
+    def push(self, text: str) -> dict[str, Any] | None:
+        """Buffer a sentence; return an announcement if one is due now."""
+        text = " ".join(text.split())
+        if not text:
+            return None
+        with self._lock:
+            self._pending.append(text)
+            if self._due_in_locked() > 0:
+                return None
+            return self._emit_locked()
+
+    def due_in(self) -> float:
+        """Seconds until buffered text may be announced (0 when due or empty)."""
+        with self._lock:
+            return self._due_in_locked() if self._pending else 0.0
+
+    def flush(self) -> dict[str, Any] | None:
+        """Announce whatever is buffered, regardless of the interval."""
+        with self._lock:
+            return self._emit_locked() if self._pending else None
+
+    def _due_in_locked(self) -> float:
+        if self._last_emit is None:
+            return 0.0
+        return max(0.0, self._last_emit + self.min_interval - self._clock())
+
+    def _emit_locked(self) -> dict[str, Any]:
+        count = len(self._pending)
+        text = " ".join(self._pending)
+        self._pending = []
+        self._last_emit = self._clock()
+        if len(text) > self.max_chars:
+            # Keep the newest words — a listener who fell behind wants "now".
+            cut = text[-self.max_chars :]
+            text = cut[cut.find(" ") + 1 :] if " " in cut else cut
+        return {"text": text, "politeness": "polite", "sentences": count}
+
+
+def state_announcement(state: str) -> dict[str, Any] | None:
+    """The ``announce`` payload for a live engine state change, if any."""
+    entry = _STATE_ANNOUNCEMENTS.get(state.upper())
+    if entry is None:
+        return None
+    return {"text": entry[0], "politeness": entry[1], "sentences": 0}
+
+
+def stream_accessible(
+    recording: dict[str, Any],
+    segments: Iterable[dict[str, Any]],
+) -> Iterator[str]:
+    """Yield the screen-reader export, lazily like ``stream_plaintext``.
+
+    The speaker is named at the start of every turn ("Alice: …") rather
+    than only on change of a visual layout, since a listener navigating by
+    paragraph lands mid-transcript.
+    """
+    title = recording.get("title") or recording.get("filename") or "Recording"
+    yield f"{title}.\n"
+    details = _describe(recording)
+    if details:
+        yield f"{details}\n"
+    yield "\n"
+
+    current: object = object()
+    parts: list[str] = []
+    for seg in segments:
+        raw = seg.get("speaker")
+        speaker = str(raw).strip() if raw not in (None, "") else None
+        text = " ".join(str(seg.get("text") or "").split())
+        if not text:
+            continue
+        if speaker != current:
+            if parts:
+                yield " ".join(parts) + "\n\n"
+                parts = []
+            current = speaker
+            if speaker is not None:
+                parts.append(f"{speaker}:")
+        parts.append(text)
+    if parts:
+        yield " ".join(parts) + "\n"
+
+
+def _describe(recording: dict[str, Any]) -> str:
+    """'Recorded 8 May 2026. Duration 1 hour 5 minutes.' — words, not digits with colons."""
+    bits: list[str] = []
+    recorded = str(recording.get("recorded_at") or "")[:10]
+    if len(recorded) == 10:
+        try:
+            day = time.strptime(recorded, "%Y-%m-%d")
+            bits.append(f"Recorded {day.tm_mday} {time.strftime('%B %Y', day)}.")
+        except ValueError:
+            pass
+    duration = recording.get("duration_seconds") or recording.get("duration")
+    if duration:
+        bits.append(f"Duration {spoken_duration(float(duration))}.")
+    return " ".join(bits)
+
+
+def spoken_duration(seconds: float) -> str:
+    """``3725`` → ``1 hour 2 minutes`` (seconds only under a minute)."""
+    total = int(round(seconds))
+    hours, rest = divmod(total, 3600)
+    minutes, secs = divmod(rest, 60)
+
+    def unit(n: int, word: str) -> str:
+        return f"{n} {word}{'' if n == 1 else 's'}"
+
+    if not hours and not minutes:
+        return unit(secs, "second")
+    parts = [unit(hours, "hour")] if hours else []
+    if minutes:
+        parts.append(unit(minutes, "minute"))
+    return " ".join(parts)
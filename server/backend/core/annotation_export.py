@@ -0,0 +1,63 @@
+"""Render review annotations into exports.
+
+Exports only include notes when asked (``annotations=true`` on the export
+route): they are reviewer-facing remarks, not part of the transcript.
+Input is ``annotation_repository.build_threads`` output.
+"""
+
+from __future__ import annotations
+
+from typing import Any
+
+from server.core.subtitle_export import SubtitleCue
+
+# Point notes (no end_time) get a short span so subtitle editors can show them.
+POINT_NOTE_SECONDS = 2.0
+
+
+def _clock(seconds: float) -> str:
+    total = int(seconds)
+    hours, rest = divmod(total, 3600)
+    mins, secs = divmod(rest, 60)
+    return f"{hours}:{mins:02d}:{secs:02d}" if hours else f"{mins:02d}:{secs:02d}"
+
+
+def _date(iso: str | None) -> str:
+    return (iso or "")[:10]
+
+
+def annotation_text_lines(threads: list[dict[str, Any]]) -> list[str]:
+    """Plain-text block: one line per note, replies indented under their root."""
+    lines: list[str] = []
+    for thread in threads:
+        start = float(thread["start_time"])
+        end = thread.get("end_time")
+        span = f"[{_clock(start)}]" if end is None else f"[{_clock(start)}-{_clock(float(end))}]"
+        lines.append(
+            f"{span} {thread['author']} ({_date(thread.get('created_at'))}): {thread['body']}"
+        )
+        for reply in thread.get("replies", []):
+            lines.append(
+                f"    ↳ {reply['author']} ({_date(reply.get('created_at'))}): {reply['body']}"
+            )
+    return lines
+
+
+def annotation_cues(threads: list[dict[str, Any]]) -> list[SubtitleCue]:
+    """One cue per thread — root text followed by ``author: reply`` lines."""
+    cues: list[SubtitleCue] = []
+    for thread in threads:
+        start = float(thread["start_time"])
+        end = thread.get("end_time")
+        text = "\n".join(
+            [thread["body"], *(f"{r['author']}: {r['body']}" for r in thread.get("replies", []))]
+        )
+        cues.append(
+            SubtitleCue(
+                start=start,
+                end=float(end) if end is not None else start + POINT_NOTE_SECONDS,
+                text=text,
+                speaker=thread["author"],
+            )
+        )
+    return cues
@@ -0,0 +1,171 @@
+"""Audacity label-track export and import.
+
+Audacity's label format is one label per line, tab-separated::
+
+    <start seconds>\t<end seconds>\t<label text>
+
+(``start == end`` is a point label). Spectral-selection labels add a second
+line starting with ``\\`` carrying the frequency range; we skip those.
+
+Export writes one region label per segment. Import treats the labels as new
+**segment boundaries**: users tidy cut points in Audacity and bring them
+back. Re-segmentation never drops transcript content — every existing word
+(or, for word-less recordings, every existing segment's text) is reassigned
+to the new segment whose span contains its midpoint, falling back to the
+nearest one. Label text is used only for a region that receives no content.
+"""
+
+from __future__ import annotations
+
+from collections import Counter
+from collections.abc import Iterable
+from dataclasses import dataclass, field
+from typing import Any
+
+MAX_LABELS = 20_000
+
+
+@dataclass(slots=True)
+class AudacityLabel:
+    start: float
+    end: float
+    text: str = ""
+
+
+@dataclass(slots=True)
+class PlannedSegment:
+    start: float
+    end: float
+    text: str
+    speaker: str | None = None
+    word_ids: list[int] = field(default_factory=list)
+
+
+def render_audacity_labels(
+    segments: Iterable[dict[str, Any]],
+    *,
+    label_map: dict[str, str] | None = None,
+) -> str:
+    """Render segments as an Audacity label track (``Speaker: text`` labels)."""
+    labels = label_map or {}
+    lines: list[str] = []
+    for seg in segments:
+        text = " ".join(str(seg.get("text") or "").split())
+        if not text:
+            continue
+        start = float(seg.get("start_time") or 0.0)
+        end = max(start, float(seg.get("end_time") or start))
+        raw = str(seg.get("speaker") or "").strip()
+        speaker = labels.get(raw, raw)
+        label = f"{speaker}: {text}" if speaker else text
+        lines.append(f"{start:.6f}\t{end:.6f}\t{label.replace(chr(9), ' ')}")
+    return "\n".join(lines) + ("\n" if lines else "")
+
+
+def parse_audacity_labels(content: str) -> list[AudacityLabel]:
+    """Parse a label file; raises ``ValueError`` naming the first bad line."""
+    labels: list[AudacityLabel] = []
+    for lineno, raw_line in enumerate(content.splitlines(), start=1):
+        line = raw_line.strip("\r\n")
+        if not line.strip() or line.lstrip().startswith("\\"):
+            continue
+        parts = line.split("\t", 2)
+        if len(parts) < 2:
+            raise ValueError(f"line {lineno}: expected '<start>\\t<end>\\t<label>'")
+        try:
+            start, end = float(parts[0]), float(parts[1])
+        except ValueError as e:
+            raise ValueError(f"line {lineno}: start/end must be numbers") from e
+        if start < 0 or end < start:
+            raise ValueError(f"line {lineno}: end must not precede start")
+        labels.append(AudacityLabel(start, end, parts[2].strip() if len(parts) > 2 else ""))
+        if len(labels) > MAX_LABELS:
+            raise ValueError(f"too many labels (max {MAX_LABELS})")
+    labels.sort(key=lambda label: (label.start, label.end))
+    return labels
+
+
+def _spans(labels: list[AudacityLabel], duration: float) -> list[AudacityLabel]:
+    """Region labels are used as-is; point labels cut the timeline."""
+    regions = [label for label in labels if label.end > label.start]
+    if regions:
+        return regions
+    cuts = sorted({label.start for label in labels if 0 < label.start < duration})
+    edges = [0.0, *cuts, max(duration, cuts[-1] if cuts else 0.0)]
+    text_at = {label.start: label.text for label in labels}
+    return [
+        AudacityLabel(edges[i], edges[i + 1], text_at.get(edges[i], ""))
+        for i in range(len(edges) - 1)
+        if edges[i + 1] > edges[i]
+    ]
+
+
+def _target_index(spans: list[AudacityLabel], start: float, end: float) -> int:
+    mid = (start + end) / 2
+    for i, span in enumerate(spans):
+        if span.start <= mid < span.end or (i == len(spans) - 1 and mid == span.end):
+            return i
+    # Outside every label: nearest span by edge distance.
+    return min(
+        range(len(spans)),
+        key=lambda i: min(abs(mid - spans[i].start), abs(mid - spans[i].end)),
+    )
+
+
+def plan_resegmentation(
+    labels: list[AudacityLabel],
+    segments: list[dict[str, Any]],
+    words: list[dict[str, Any]],
+    *,
+    duration: float = 0.0,
+) -> list[PlannedSegment]:
+    """Map the recording's content onto new segments bounded by ``labels``.
+
+    Speaker per new segment is the most common speaker among the content it
+    received (ties → earliest). Spans that receive nothing are dropped
+    unless they carry label text, which then becomes the segment text.
+    """
+    if not labels:
+        raise ValueError("label file contains no labels")
+    last_end = max(
+        [duration, *(float(s.get("end_time") or 0.0) for s in segments)],
+        default=duration,
+    )
+    spans = _spans(labels, last_end)
+    if not spans:
+        raise ValueError("labels do not define any segment span")
+
+    speaker_by_segment = {seg.get("id"): seg.get("speaker") for seg in segments}
+    buckets: list[list[tuple[str, str | None, int | None]]] = [[] for _ in spans]
+
+    if words:
+        for word in sorted(words, key=lambda w: float(w.get("start_time") or 0.0)):
+            start = float(word.get("start_time") or 0.0)
+            end = float(word.get("end_time") or start)
+            text = str(word.get("word") or "").strip()
+            speaker = speaker_by_segment.get(word.get("segment_id"))
+            buckets[_target_index(spans, start, end)].append((text, speaker, word.get("id")))
+    else:
+        for seg in segments:
+            start = float(seg.get("start_time") or 0.0)
+            end = float(seg.get("end_time") or start)
+            text = str(seg.get("text") or "").strip()
+            buckets[_target_index(spans, start, end)].append((text, seg.get("speaker"), None))
+
+    planned: list[PlannedSegment] = []
+    for span, bucket in zip(spans, buckets, strict=True):
+        texts = [text for text, _, _ in bucket if text]
+        if not bucket and not span.text:
+            continue
+        speakers = Counter(speaker for _, speaker, _ in bucket if speaker)
+        speaker = speakers.most_common(1)[0][0] if speakers else None
+        planned.append(
+            PlannedSegment(
+                start=span.start,
+                end=span.end,
+                text=" ".join(texts) if texts else span.text,
+                speaker=speaker,
+                word_ids=[wid for _, _, wid in bucket if wid is not None],
+            )
+        )
+    return planned
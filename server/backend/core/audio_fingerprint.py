@@ -0,0 +1,169 @@
+"""
+Chromaprint fingerprints for recognizing re-encoded and trimmed copies.
+
+The raw and normalized audio hashes (migrations 012/013) only catch copies
+whose decoded samples are identical. A re-export at another bitrate, or a
+copy with the intro cut off, hashes differently but sounds the same. A
+chromaprint fingerprint (``fpcalc -raw``) is a sequence of 32-bit
+sub-fingerprints, one per ~0.124 s of audio, that survives re-encoding
+with only a few flipped bits — so two fingerprints can be slid against
+each other to find both whether they are the same audio and by how much
+one is offset into the other.
+
+Notebook uploads store their fingerprint next to the recording
+(migration 026); new uploads are compared against them and matches are
+offered back with the offset, so the existing transcript can be reused
+shifted onto the new file. ``fpcalc`` (chromaprint tools) is optional:
+without it uploads simply carry no fingerprint.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+import shutil
+import subprocess
+from array import array
+from collections import Counter, defaultdict
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+# Chromaprint's default algorithm: a 4096-sample frame every 1365 samples
+# of 11025 Hz audio.
+ITEM_SECONDS = 4096 / 3 / 11025
+# fpcalc stops after this much audio; longer recordings are compared by
+# their first four hours.
+MAX_FINGERPRINT_SECONDS = 4 * 60 * 60
+FPCALC_TIMEOUT_SECONDS = 600
+
+# Fraction of matching bits over the overlap. Re-encodes land well above
+# 0.85; unrelated audio hovers around 0.5.
+MIN_SIMILARITY = 0.8
+MIN_OVERLAP_SECONDS = 10.0
+
+# Offsets are voted on by the top 20 bits of each item — the low bits are
+# the ones lossy codecs flip. Values seen more often than this in one
+# fingerprint (silence, a held tone) say nothing about alignment.
+_KEY_SHIFT = 12
+_MAX_KEY_POSITIONS = 32
+# Best-voted offsets checked bit by bit.
+_CANDIDATE_OFFSETS = 3
+
+
+@dataclass(frozen=True)
+class FingerprintMatch:
+    """Where ``query`` sits in ``reference``: reference time = query time + offset."""
+
+    offset_seconds: float
+    similarity: float
+    overlap_seconds: float
+
+
+def compute_fingerprint(path: str | Path) -> list[int] | None:
+    """The raw chromaprint of ``path``, or None when fpcalc is missing or fails."""
+    if not shutil.which("fpcalc"):
+        logger.debug("fpcalc not in PATH — no audio fingerprint for %s", path)
+        return None
+    try:
+        result = subprocess.run(
+            ["fpcalc", "-raw", "-json", "-length", str(MAX_FINGERPRINT_SECONDS), str(path)],
+            capture_output=True,
+            text=True,
+            check=True,
+            timeout=FPCALC_TIMEOUT_SECONDS,
+        )
+        items = json.loads(result.stdout)["fingerprint"]
+    except (OSError, subprocess.SubprocessError, ValueError, KeyError) as e:
+        logger.warning("Audio fingerprinting failed for %s: %s", path, e)
+        return None
+    # Older fpcalc builds print the items signed.
+    return [int(v) & 0xFFFFFFFF for v in items] or None
+
+
+def pack_fingerprint(fingerprint: list[int]) -> bytes:
+    packed = array("I", fingerprint)
+    return packed.tobytes()
+
+
+def unpack_fingerprint(blob: bytes) -> list[int]:
+    packed = array("I")
+    packed.frombytes(blob)
+    return packed.tolist()
+
+
+def fingerprint_seconds(fingerprint: list[int]) -> float:
+    return len(fingerprint) * ITEM_SECONDS
+
+
+def _similarity(query: list[int], reference: list[int], shift: int) -> tuple[float, int]:
+    start = max(0, -shift)
+    stop = min(len(query), len(reference) - shift)
+    count = stop - start
+    if count <= 0:
+        return 0.0, 0
+    errors = sum((query[i] ^ reference[i + shift]).bit_count() for i in range(start, stop))
+    return 1.0 - errors / (32 * count), count
+
+
+def align(query: list[int], reference: list[int]) -> FingerprintMatch | None:
+    """
+    The offset at which ``query`` best lines up with ``reference``, if the
+    overlap is long and similar enough to be the same audio.
+    """
+    min_items = int(MIN_OVERLAP_SECONDS / ITEM_SECONDS)
+    if len(query) < min_items or len(reference) < min_items:
+        return None
+
+    positions: defaultdict[int, list[int]] = defaultdict(list)
+    for j, value in enumerate(reference):
+        positions[value >> _KEY_SHIFT].append(j)
+    votes: Counter[int] = Counter()
+    for i, value in enumerate(query):
+        hits = positions.get(value >> _KEY_SHIFT)
+        if hits and len(hits) <= _MAX_KEY_POSITIONS:
+            votes.update(j - i for j in hits)
+
+    best: FingerprintMatch | None = None
+    for shift, _ in votes.most_common(_CANDIDATE_OFFSETS):
+        similarity, overlap = _similarity(query, reference, shift)
+        if overlap < min_items or similarity < MIN_SIMILARITY:
+            continue
+        if best is None or similarity > best.similarity:
+            best = FingerprintMatch(
+                offset_seconds=round(shift * ITEM_SECONDS, 3),
+                similarity=round(similarity, 4),
+                overlap_seconds=round(overlap * ITEM_SECONDS, 3),
+            )
+    return best
+
+
+def shift_segments(
+    segments: list[dict[str, Any]], offset: float, duration: float | None = None
+) -> list[dict[str, Any]]:
+    """
+    Segments re-timed onto a copy whose time ``t`` is ``t + offset`` here
+    (a fingerprint match's offset): what falls outside the copy's
+    ``0..duration`` is dropped and edges are clipped.
+    """
+    end_limit = duration if duration is not None else float("inf")
+
+    def clip(t: float) -> float:
+        return round(min(max(t - offset, 0.0), end_limit), 3)
+
+    shifted = []
+    for seg in segments:
+        start, end = seg["start"] - offset, seg["end"] - offset
+        if end <= 0 or start >= end_limit:
+            continue
+        words = [
+            {**w, "start": clip(w["start"]), "end": clip(w["end"])}
+            for w in seg["words"]
+            if w["end"] - offset > 0 and w["start"] - offset < end_limit
+        ]
+        shifted.append(
+            {**seg, "start": clip(seg["start"]), "end": clip(seg["end"]), "words": words}
+        )
+    return shifted
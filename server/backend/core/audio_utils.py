@@ -594,9 +594,22 @@ def load_audio_legacy(
             os.unlink(temp_wav)
 
 
+def _trim(
+    loaded: tuple[np.ndarray, int], max_seconds: float | None, start_seconds: float = 0.0
+) -> tuple[np.ndarray, int]:
+    audio, rate = loaded
+    if start_seconds:
+        audio = audio[int(start_seconds * rate) :]
+    if max_seconds:
+        audio = audio[: int(max_seconds * rate)]
+    return audio, rate
+
+
 def load_audio(
     file_path: str,
     target_sample_rate: int = 16000,
+    max_seconds: float | None = None,
+    start_seconds: float = 0.0,
 ) -> tuple[np.ndarray, int]:
     """
     Load an audio file and return as numpy array.
@@ -608,6 +621,9 @@ def load_audio(
     Args:
         file_path: Path to audio file (any format: WAV, MP3, M4A, OGG, etc.)
         target_sample_rate: Target sample rate for resampling (default 16000 for Whisper)
+        max_seconds: Return only the first N seconds (None = whole file). The
+            ffmpeg backend stops decoding there; legacy decodes, then trims.
+        start_seconds: Skip this much audio first (with ``max_seconds``, a clip).
 
     Returns:
         Tuple of (audio_data as float32 array, sample_rate)
@@ -627,15 +643,26 @@ def load_audio(
             try:
                 from server.core.ffmpeg_utils import load_audio_ffmpeg
 
-                return load_audio_ffmpeg(file_path, target_sample_rate)
+                return load_audio_ffmpeg(
+                    file_path,
+                    target_sample_rate,
+                    max_seconds=max_seconds,
+                    start_seconds=start_seconds,
+                )
             except ImportError as e:
                 logger.warning(f"ffmpeg-python not available, falling back to legacy: {e}")
-                return load_audio_legacy(file_path, target_sample_rate)
+                return _trim(
+                    load_audio_legacy(file_path, target_sample_rate), max_seconds, start_seconds
+                )
             except Exception as e:
                 logger.warning(f"FFmpeg loading failed, falling back to legacy: {e}")
-                return load_audio_legacy(file_path, target_sample_rate)
+                return _trim(
+                    load_audio_legacy(file_path, target_sample_rate), max_seconds, start_seconds
+                )
         else:
-            return load_audio_legacy(file_path, target_sample_rate)
+            return _trim(
+                load_audio_legacy(file_path, target_sample_rate), max_seconds, start_seconds
+            )
     except AudioDecodeError:
         # Already the clean, path-free error — never re-wrap it.
         raise
@@ -111,7 +111,12 @@ async def trigger_auto_actions(
     The snapshot is also persisted onto the recording row so retries +
     the deferred-export sweeper can resume with the SAME profile context
     that fired the original auto-action (no profile drift).
+
+    Speaker identification runs first and regardless of the profile —
+    voice profiles are global — so summary and export already see the
+    matched names.
     """
+    await _run_speaker_identification(recording_id)
     if not profile_snapshot:
         return
     public = profile_snapshot.get("public_fields") or {}
@@ -137,6 +142,11 @@ async def trigger_auto_actions(
                 recording_id,
             )
 
+    if public.get("pii_scan_enabled"):
+        # Sequential, not a sibling task: auto-export must see the
+        # suggested redactions so the exported file is already masked.
+        await _run_pii_scan(recording_id)
+
     tasks: list[asyncio.Task] = []
     if public.get("auto_summary_enabled"):
         tasks.append(asyncio.create_task(_run_auto_summary(recording_id, public)))
@@ -151,6 +161,36 @@ async def trigger_auto_actions(
         await asyncio.gather(*tasks, return_exceptions=True)
 
 
+# ──────────────────────────────────────────────────────────────────────────
+# PII scan — pre-populates redactions (core/pii_detection.py)
+# ──────────────────────────────────────────────────────────────────────────
+
+
+async def _run_pii_scan(recording_id: int) -> None:
+    """Best-effort: a failed scan leaves redactions as they were."""
+    from server.core.pii_detection import run_pii_scan
+
+    try:
+        await asyncio.to_thread(run_pii_scan, recording_id)
+    except Exception:  # noqa: BLE001 — never blocks summary / export
+        logger.exception("pii_scan failed for recording %d", recording_id)
+
+
+# ──────────────────────────────────────────────────────────────────────────
+# Speaker identification — aliases from voice profiles (core/speaker_identification.py)
+# ──────────────────────────────────────────────────────────────────────────
+
+
+async def _run_speaker_identification(recording_id: int) -> None:
+    """Best-effort: a failed match leaves the speakers unnamed."""
+    try:
+        from server.core.speaker_identification import identify_recording_speakers
+
+        await asyncio.to_thread(identify_recording_speakers, recording_id)
+    except Exception:  # noqa: BLE001 — never blocks summary / export
+        logger.exception("speaker identification failed for recording %d", recording_id)
+
+
 # ──────────────────────────────────────────────────────────────────────────
 # Auto-summary (Story 6.2)
 # ──────────────────────────────────────────────────────────────────────────
@@ -367,14 +407,15 @@ def _write_atomic(target: Path, content: str) -> None:
 
 
 def _write_transcript_atomic(base: Path, recording_id: int) -> None:
-    """Build alias-aware plaintext via existing exporter, write atomically."""
+    """Build alias-aware, redacted plaintext via existing exporter, write atomically."""
     from server.core.alias_substitution import apply_aliases
     from server.core.plaintext_export import stream_plaintext
+    from server.core.redaction import redacted_transcript
     from server.database.alias_repository import list_aliases
-    from server.database.database import get_recording, get_segments
+    from server.database.database import get_recording
 
     recording = get_recording(recording_id) or {}
-    segments = get_segments(recording_id)
+    segments, _ = redacted_transcript(recording_id)
     aliases = {a["speaker_id"]: a["alias_name"] for a in list_aliases(recording_id)}
     text = "".join(stream_plaintext(recording, apply_aliases(segments, aliases)))
     _write_atomic(base, text)
@@ -0,0 +1,259 @@
+"""Caption quality linter for subtitle exports.
+
+Checks the cues ``build_subtitle_cues`` produces against a broadcast
+caption profile — characters per line, lines per cue, minimum / maximum
+duration, reading speed (characters per second, spaces included) and the
+gap between consecutive cues — and reports each violation with the cue it
+belongs to, so the editor can jump straight to it.
+
+``rewrap_cues`` is the auto-fix pass: it re-breaks each cue's text into
+the fewest lines that fit the profile, balancing line lengths and
+preferring breaks after punctuation. It never changes timings or words;
+a cue that cannot fit in ``max_lines`` keeps a plain greedy wrap and is
+left for the editor to split (``core/segment_editing.py``).
+
+Built-in profiles can be overridden or extended under
+``caption_lint.profiles`` in config.yaml.
+"""
+
+from __future__ import annotations
+
+import functools
+import math
+from dataclasses import asdict, dataclass, replace
+from typing import Any
+
+from server.core.subtitle_export import SubtitleCue, _wrap_text
+
+BREAK_AFTER = (",", ".", "?", "!", ";", ":")
+
+
+@dataclass(frozen=True)
+class CaptionProfile:
+    """Limits for one caption standard; durations in seconds."""
+
+    name: str
+    max_chars_per_line: int
+    max_lines: int
+    min_duration: float
+    max_duration: float
+    max_cps: float
+    min_gap: float
+
+
+BUILTIN_PROFILES: dict[str, CaptionProfile] = {
+    # Netflix Timed Text Style Guide (English): 42 chars, 20 cps, 5/6 s minimum,
+    # 2 frames between cues at 24 fps.
+    "netflix": CaptionProfile("netflix", 42, 2, 0.833, 7.0, 20.0, 0.083),
+    # BBC subtitle guidelines: ~37 chars on a 16:9 line, 160-180 wpm.
+    "bbc": CaptionProfile("bbc", 37, 2, 1.0, 7.0, 17.0, 0.04),
+    # CEA-608 broadcast captions: 32-column grid, up to 4 rows.
+    "cea608": CaptionProfile("cea608", 32, 4, 1.0, 6.0, 20.0, 0.0),
+}
+DEFAULT_PROFILE = "netflix"
+
+
+@dataclass(frozen=True)
+class CaptionViolation:
+    """One rule broken by one cue (``line`` is 1-based, for per-line rules)."""
+
+    rule: str
+    severity: str
+    cue_index: int
+    start: float
+    end: float
+    message: str
+    value: float
+    limit: float
+    line: int | None = None
+
+    def to_dict(self) -> dict[str, Any]:
+        return asdict(self)
+
+
+def get_profile(name: str | None, overrides: dict[str, Any] | None = None) -> CaptionProfile:
+    """Resolve a profile by name; ``overrides`` come from ``caption_lint.profiles``.
+
+    An override for a built-in name replaces only the keys it sets; a new
+    name must set every limit. Raises ``ValueError`` for unknown names or
+    bad values.
+    """
+    key = (name or DEFAULT_PROFILE).strip().lower()
+    custom = (overrides or {}).get(key)
+    base = BUILTIN_PROFILES.get(key)
+    if custom is None:
+        if base is None:
+            known = ", ".join(sorted({*BUILTIN_PROFILES, *(overrides or {})}))
+            raise ValueError(f"Unknown caption profile '{key}'. Known: {known}")
+        return base
+    if not isinstance(custom, dict):
+        raise ValueError(f"caption profile '{key}' must be a mapping")
+    fields = {k: v for k, v in custom.items() if k != "name"}
+    try:
+        if base is not None:
+            profile = replace(base, **fields)
+        else:
+            profile = CaptionProfile(name=key, **fields)
+    except TypeError as e:
+        raise ValueError(f"caption profile '{key}': {e}") from e
+    if profile.max_chars_per_line < 1 or profile.max_lines < 1:
+        raise ValueError(f"caption profile '{key}' needs at least 1 char and 1 line")
+    return profile
+
+
+def _chars(text: str) -> int:
+    return len(" ".join(line.strip() for line in text.splitlines()))
+
+
+def lint_cues(cues: list[SubtitleCue], profile: CaptionProfile) -> list[CaptionViolation]:
+    """Every violation of ``profile`` in ``cues``, in cue order."""
+    found: list[CaptionViolation] = []
+
+    def add(
+        rule: str, severity: str, i: int, message: str, value: float, limit: float, **kw: Any
+    ) -> None:
+        cue = cues[i]
+        found.append(
+            CaptionViolation(
+                rule=rule,
+                severity=severity,
+                cue_index=i,
+                start=round(cue.start, 3),
+                end=round(cue.end, 3),
+                message=message,
+                value=round(value, 3),
+                limit=limit,
+                **kw,
+            )
+        )
+
+    for i, cue in enumerate(cues):
+        lines = cue.text.splitlines() or [""]
+        for n, line in enumerate(lines, start=1):
+            if len(line) > profile.max_chars_per_line:
+                add(
+                    "line_length",
+                    "error",
+                    i,
+                    f"Line {n} has {len(line)} characters (max {profile.max_chars_per_line})",
+                    len(line),
+                    profile.max_chars_per_line,
+                    line=n,
+                )
+        if len(lines) > profile.max_lines:
+            add(
+                "line_count",
+                "error",
+                i,
+                f"{len(lines)} lines (max {profile.max_lines})",
+                len(lines),
+                profile.max_lines,
+            )
+        duration = cue.end - cue.start
+        if duration < profile.min_duration:
+            add(
+                "min_duration",
+                "warning",
+                i,
+                f"On screen {duration:.2f}s (min {profile.min_duration:g}s)",
+                duration,
+                profile.min_duration,
+            )
+        if duration > profile.max_duration:
+            add(
+                "max_duration",
+                "warning",
+                i,
+                f"On screen {duration:.2f}s (max {profile.max_duration:g}s)",
+                duration,
+                profile.max_duration,
+            )
+        if duration > 0:
+            cps = _chars(cue.text) / duration
+            if cps > profile.max_cps:
+                add(
+                    "reading_speed",
+                    "warning",
+                    i,
+                    f"{cps:.1f} characters/second (max {profile.max_cps:g})",
+                    cps,
+                    profile.max_cps,
+                )
+        if i + 1 < len(cues):
+            gap = cues[i + 1].start - cue.end
+            if gap < 0:
+                add("overlap", "error", i, "Overlaps the next cue", gap, 0.0)
+            elif 0 < gap < profile.min_gap:  # back-to-back cues (0 ms) are fine
+                add(
+                    "min_gap",
+                    "warning",
+                    i,
+                    f"{gap * 1000:.0f} ms before the next cue "
+                    f"(min {profile.min_gap * 1000:.0f} ms)",
+                    gap,
+                    profile.min_gap,
+                )
+    return found
+
+
+def _balanced_lines(tokens: list[str], count: int, max_chars: int) -> list[str] | None:
+    """Best split of ``tokens`` into ``count`` lines that all fit, or None."""
+    total = len(" ".join(tokens))
+    target = total / count
+
+    @functools.lru_cache(maxsize=None)
+    def best(start: int, remaining: int) -> tuple[float, tuple[int, ...]] | None:
+        if remaining == 1:
+            line = " ".join(tokens[start:])
+            if len(line) > max_chars:
+                return None
+            return ((target - len(line)) ** 2, ())
+        result: tuple[float, tuple[int, ...]] | None = None
+        for end in range(start + 1, len(tokens) - remaining + 2):
+            line = " ".join(tokens[start:end])
+            if len(line) > max_chars:
+                break
+            rest = best(end, remaining - 1)
+            if rest is None:
+                continue
+            cost = (target - len(line)) ** 2 + rest[0]
+            if tokens[end - 1].endswith(BREAK_AFTER):
+                cost -= target  # a break at a clause boundary reads far better
+            if result is None or cost < result[0]:
+                result = (cost, (end, *rest[1]))
+        return result
+
+    plan = best(0, count)
+    if plan is None:
+        return None
+    bounds = [0, *plan[1], len(tokens)]
+    return [" ".join(tokens[a:b]) for a, b in zip(bounds, bounds[1:], strict=False)]
+
+
+def rewrap_text(text: str, profile: CaptionProfile) -> str:
+    """Re-break ``text`` into the fewest balanced lines that fit ``profile``."""
+    tokens = text.split()
+    if not tokens:
+        return ""
+    single = " ".join(tokens)
+    if len(single) <= profile.max_chars_per_line:
+        return single
+    fewest = max(2, math.ceil(len(single) / profile.max_chars_per_line))
+    for count in range(fewest, min(profile.max_lines, len(tokens)) + 1):
+        lines = _balanced_lines(tokens, count, profile.max_chars_per_line)
+        if lines is not None:
+            return "\n".join(lines)
+    return _wrap_text(single, profile.max_chars_per_line)
+
+
+def rewrap_cues(cues: list[SubtitleCue], profile: CaptionProfile) -> list[SubtitleCue]:
+    """Auto-fix pass: re-wrap every cue's lines; timings are untouched."""
+    return [replace(cue, text=rewrap_text(cue.text, profile)) for cue in cues]
+
+
+def summarize(violations: list[CaptionViolation]) -> dict[str, int]:
+    """Violation count per rule."""
+    counts: dict[str, int] = {}
+    for v in violations:
+        counts[v.rule] = counts.get(v.rule, 0) + 1
+    return counts
@@ -0,0 +1,256 @@
+"""Chaptering: split a long transcript into a navigable outline.
+
+A chapter boundary is placed before a segment when it follows a long pause,
+or when the vocabulary of the minute before it barely overlaps the minute
+after it (cosine similarity of stopword-filtered word counts — a topic
+shift). Candidates are taken strongest first as long as every chapter stays
+at least ``min_chapter_seconds`` long.
+
+Titles are the chapter's most distinctive words (frequent in the chapter,
+rare in the others). With ``chaptering.llm_titles`` on, the configured LLM
+titles the same chapters instead; if it fails or answers with the wrong
+number of lines the keyword titles are kept.
+
+The outline is stored in the ``chapters`` table and rebuilt on demand.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+import math
+import re
+from collections import Counter
+from collections.abc import Mapping, Sequence
+from dataclasses import asdict, dataclass
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+WINDOW_SECONDS = 60.0
+# A window with fewer content words than this says nothing about the topic.
+MIN_WINDOW_TERMS = 8
+TITLE_TERMS = 3
+LLM_EXCERPT_CHARS = 800
+
+_TERM = re.compile(r"[^\W\d_]{3,}")
+_LIST_MARKER = re.compile(r"^\s*(?:\d+[.):]|[-*•])\s*")
+_STOPWORDS = frozenset(
+    """
+    about above after again against all also and any are aren because been before
+    being below between both but can cannot could did didn does doesn doing don down
+    during each few for from further had hadn has hasn have haven having her here hers
+    herself him himself his how into isn its itself just let more most mustn myself
+    nor not now off once only other ought our ours ourselves out over own same shan
+    she should shouldn some such than that the their theirs them themselves then there
+    these they this those through too under until very was wasn were weren what when
+    where which while who whom why will with won would wouldn you your yours yourself
+    yourselves
+
+    actually alright anyway basically going gonna got gotta kind know like maybe mean
+    okay really right say said see something sort stuff sure thing things think thought
+    well want yeah yes get gets one two lot lots way ll ve re
+    """.split()
+)
+
+
+@dataclass(frozen=True)
+class Chapter:
+    start_time: float
+    end_time: float
+    title: str
+    source: str = "auto"
+
+
+def _terms(text: str) -> list[str]:
+    return [t for t in _TERM.findall(text.lower()) if t not in _STOPWORDS]
+
+
+def _cosine(a: Counter[str], b: Counter[str]) -> float:
+    dot = sum(count * b[term] for term, count in a.items())
+    norm = math.sqrt(sum(v * v for v in a.values())) * math.sqrt(sum(v * v for v in b.values()))
+    return dot / norm if norm else 0.0
+
+
+def _keyword_title(
+    terms: Counter[str], doc_freq: Counter[str], n_chapters: int, fallback: str
+) -> str:
+    ranked = sorted(
+        terms,
+        key=lambda t: -terms[t] * math.log(1 + n_chapters / doc_freq[t]),
+    )
+    picked = [t for t in ranked if terms[t] > 1][:TITLE_TERMS] or ranked[:TITLE_TERMS]
+    if not picked:
+        words = fallback.split()
+        return " ".join(words[:8]) + ("…" if len(words) > 8 else "") or "Untitled"
+    title = ", ".join(picked)
+    return title[0].upper() + title[1:]
+
+
+def segment_into_chapters(
+    segments: Sequence[Mapping[str, Any]],
+    *,
+    pause_seconds: float = 8.0,
+    topic_similarity: float = 0.08,
+    min_chapter_seconds: float = 120.0,
+    max_chapters: int = 30,
+    min_duration: float = 0.0,
+) -> list[Chapter]:
+    """Chapters covering ``segments``; empty when fewer than two would result."""
+    segs = sorted(
+        (s for s in segments if str(s.get("text") or "").strip()),
+        key=lambda s: float(s["start_time"]),
+    )
+    if len(segs) < 2:
+        return []
+    first_start = float(segs[0]["start_time"])
+    last_end = max(float(s["end_time"]) for s in segs)
+    if last_end - first_start < max(min_duration, 2 * min_chapter_seconds):
+        return []
+
+    seg_terms = [_terms(str(s["text"])) for s in segs]
+    candidates: list[tuple[float, int]] = []
+    for i in range(1, len(segs)):
+        at = float(segs[i]["start_time"])
+        pause = at - float(segs[i - 1]["end_time"])
+        before: Counter[str] = Counter()
+        after: Counter[str] = Counter()
+        for j in range(i - 1, -1, -1):
+            if float(segs[j]["start_time"]) < at - WINDOW_SECONDS:
+                break
+            before.update(seg_terms[j])
+        for j in range(i, len(segs)):
+            if float(segs[j]["start_time"]) >= at + WINDOW_SECONDS:
+                break
+            after.update(seg_terms[j])
+        enough = min(before.total(), after.total()) >= MIN_WINDOW_TERMS
+        similarity = _cosine(before, after) if enough else None
+        is_pause = pause >= pause_seconds
+        is_shift = similarity is not None and similarity < topic_similarity
+        if is_pause or is_shift:
+            strength = (pause / pause_seconds if is_pause else 0.0) + (
+                1.0 - similarity if similarity is not None else 0.0
+            )
+            candidates.append((strength, i))
+
+    edges = [first_start, last_end]
+    cuts: list[int] = []
+    for _strength, i in sorted(candidates, key=lambda c: (-c[0], c[1])):
+        if len(cuts) >= max_chapters - 1:
+            break
+        at = float(segs[i]["start_time"])
+        if all(abs(at - edge) >= min_chapter_seconds for edge in edges):
+            edges.append(at)
+            cuts.append(i)
+    if not cuts:
+        return []
+
+    bounds = [0, *sorted(cuts), len(segs)]
+    spans = [segs[a:b] for a, b in zip(bounds, bounds[1:], strict=False)]
+    span_terms = [
+        Counter(t for k in range(a, b) for t in seg_terms[k])
+        for a, b in zip(bounds, bounds[1:], strict=False)
+    ]
+    doc_freq: Counter[str] = Counter(t for terms in span_terms for t in terms)
+    chapters = []
+    for n, (span, terms) in enumerate(zip(spans, span_terms, strict=True)):
+        end = float(spans[n + 1][0]["start_time"]) if n + 1 < len(spans) else last_end
+        chapters.append(
+            Chapter(
+                start_time=float(span[0]["start_time"]),
+                end_time=end,
+                title=_keyword_title(terms, doc_freq, len(spans), str(span[0]["text"]).strip()),
+            )
+        )
+    return chapters
+
+
+def parse_llm_titles(response: str, expected: int) -> list[str] | None:
+    """One title per non-empty line, list markers and quotes stripped.
+
+    None when the count does not match, so the caller keeps its own titles.
+    """
+    titles = [
+        _LIST_MARKER.sub("", line).strip().strip("\"'").strip()
+        for line in response.splitlines()
+        if line.strip()
+    ]
+    titles = [t for t in titles if t]
+    return titles if len(titles) == expected else None
+
+
+async def llm_titles(
+    chapters: Sequence[Chapter], segments: Sequence[Mapping[str, Any]]
+) -> list[str] | None:
+    """Ask the configured LLM for one title per chapter; None on any failure."""
+    from server.api.routes.llm import LLMRequest, process_with_llm
+
+    excerpts = []
+    for n, chapter in enumerate(chapters, start=1):
+        text = " ".join(
+            str(s.get("text") or "").strip()
+            for s in segments
+            if chapter.start_time <= float(s["start_time"]) < chapter.end_time
+        )
+        excerpts.append(f"Chapter {n}:\n{text[:LLM_EXCERPT_CHARS]}")
+    request = LLMRequest(
+        transcription_text="\n\n".join(excerpts),
+        system_prompt=(
+            "You title the chapters of a transcript. Answer with exactly one line per "
+            "chapter, in order: a title of at most 8 words in the transcript's language. "
+            "No numbering, no quotes, no other text."
+        ),
+        user_prompt=f"Give titles for these {len(chapters)} chapters.",
+        max_tokens=32 * len(chapters),
+    )
+    try:
+        response = await process_with_llm(request)
+    except Exception as exc:  # noqa: BLE001 — keyword titles are the fallback
+        logger.warning("LLM chapter titling failed: %s", getattr(exc, "detail", exc))
+        return None
+    titles = parse_llm_titles(response.response, len(chapters))
+    if titles is None:
+        logger.warning("LLM chapter titling returned the wrong number of titles")
+    return titles
+
+
+async def build_outline(recording_id: int, *, use_llm: bool | None = None) -> list[dict[str, Any]]:
+    """Segment the recording, title the chapters and store them as its outline."""
+    from server.config import get_config
+    from server.database import chapter_repository
+    from server.database.database import get_segments
+
+    cfg = get_config()
+    segments = await asyncio.to_thread(get_segments, recording_id)
+    chapters = segment_into_chapters(
+        segments,
+        pause_seconds=float(cfg.get("chaptering", "pause_seconds", default=8.0)),
+        topic_similarity=float(cfg.get("chaptering", "topic_similarity", default=0.08)),
+        min_chapter_seconds=float(cfg.get("chaptering", "min_chapter_seconds", default=120)),
+        max_chapters=int(cfg.get("chaptering", "max_chapters", default=30)),
+        min_duration=float(cfg.get("chaptering", "min_duration", default=600)),
+    )
+    if use_llm is None:
+        use_llm = bool(cfg.get("chaptering", "llm_titles", default=False))
+    if chapters and use_llm:
+        titles = await llm_titles(chapters, segments)
+        if titles:
+            chapters = [
+                Chapter(c.start_time, c.end_time, title, "llm")
+                for c, title in zip(chapters, titles, strict=True)
+            ]
+    await asyncio.to_thread(
+        chapter_repository.replace_chapters,
+        recording_id,
+        [asdict(c) for c in chapters],
+    )
+    logger.info("Outline for recording %d: %d chapter(s)", recording_id, len(chapters))
+    return await asyncio.to_thread(chapter_repository.list_chapters, recording_id)
+
+
+async def get_outline(recording_id: int) -> list[dict[str, Any]]:
+    """The stored outline, built on first request for recordings without one."""
+    from server.database import chapter_repository
+
+    chapters = await asyncio.to_thread(chapter_repository.list_chapters, recording_id)
+    return chapters or await build_outline(recording_id)
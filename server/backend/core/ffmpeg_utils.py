@@ -32,6 +32,8 @@ def load_audio_ffmpeg(
     file_path: str,
     target_sample_rate: int = 16000,
     target_channels: int = 1,
+    max_seconds: float | None = None,
+    start_seconds: float = 0.0,
 ) -> tuple[np.ndarray, int]:
     """
     Load audio file using FFmpeg with integrated resampling and channel conversion.
@@ -48,6 +50,8 @@ def load_audio_ffmpeg(
         file_path: Path to audio file (any format: WAV, MP3, M4A, OGG, etc.)
         target_sample_rate: Target sample rate in Hz (default 16000 for Whisper)
         target_channels: Number of output channels (default 1 for mono)
+        max_seconds: Decode only the first N seconds (None = whole file)
+        start_seconds: Seek this far into the file before decoding
 
     Returns:
         Tuple of (audio_data as float32 array, sample_rate)
@@ -65,8 +69,11 @@ def load_audio_ffmpeg(
         # - High-quality resampling with SoX resampler
         # - Convert to mono (if requested)
         # - Output: float32 PCM via pipe
+        input_kwargs: dict[str, float] = {"t": max_seconds} if max_seconds else {}
+        if start_seconds:
+            input_kwargs["ss"] = start_seconds
         stream = (
-            ffmpeg.input(file_path)
+            ffmpeg.input(file_path, **input_kwargs)
             .audio.filter(
                 "aresample",
                 target_sample_rate,
@@ -64,6 +64,10 @@ PLACEHOLDER_RESOLVERS: dict[str, Callable[[dict[str, Any]], str]] = {
     "title": lambda r: str(r.get("title") or r.get("filename") or "Recording"),
     "recording_id": lambda r: str(r.get("id") or r.get("recording_id") or ""),
     "model": lambda r: str(r.get("model_id") or r.get("model") or "model"),
+    # Output manager (core/output_manager.py) fills these per export target;
+    # a profile template without them renders exactly as before.
+    "lang": lambda r: str(r.get("language") or "und"),
+    "ext": lambda r: str(r.get("ext") or "txt"),
 }
 
 
@@ -0,0 +1,335 @@
+"""Forced alignment of known text against audio.
+
+Given segments whose *text* is trusted but whose timings may be rough
+(imported subtitles, hand-edited transcripts), produce word-level timings
+that follow the audio.
+
+Two strategies:
+
+* ``wav2vec2`` — WhisperX's phoneme aligner (``whisperx.load_align_model`` /
+  ``whisperx.align``), the same model the WhisperX backend uses after
+  transcription. Only available when WhisperX is installed.
+* ``proportional`` — no model: each segment's span is divided between its
+  words by character length. Timings stay inside the original cue, which is
+  already good enough for click-to-seek and word-level exports.
+
+``align_segments`` never raises for a model failure — it logs and falls back
+to the proportional strategy so an import is never lost to a missing model.
+
+``plan_realignment`` handles the edited-transcript case: the corrected text
+is diffed against the recording's existing words, unchanged words keep their
+timings (and ASR confidence), and only the edited stretches are re-timed —
+by the model when available, otherwise by interpolating inside the span the
+replaced words used to occupy.
+"""
+
+from __future__ import annotations
+
+import difflib
+import importlib
+import logging
+import re
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+ALIGNMENT_METHODS: tuple[str, ...] = ("wav2vec2", "proportional", "interpolated")
+
+
+@dataclass(slots=True)
+class AlignmentResult:
+    """Aligned segments plus flat words; each word carries its ``segment_index``."""
+
+    segments: list[dict[str, Any]]
+    words: list[dict[str, Any]] = field(default_factory=list)
+    method: str = "proportional"
+
+
+def proportional_words(segments: list[dict[str, Any]]) -> list[dict[str, Any]]:
+    """Spread each segment's words across its span, weighted by character count."""
+    words: list[dict[str, Any]] = []
+    for index, seg in enumerate(segments):
+        tokens = str(seg.get("text") or "").split()
+        if not tokens:
+            continue
+        start = float(seg.get("start") or 0.0)
+        end = max(start, float(seg.get("end") or start))
+        # +1 per token stands in for the inter-word gap so short words still get time.
+        weights = [len(token) + 1 for token in tokens]
+        total = sum(weights)
+        cursor = start
+        for token, weight in zip(tokens, weights, strict=True):
+            span = (end - start) * weight / total
+            words.append(
+                {
+                    "word": token,
+                    "start": round(cursor, 3),
+                    "end": round(cursor + span, 3),
+                    "confidence": None,
+                    "segment_index": index,
+                }
+            )
+            cursor += span
+    return words
+
+
+def _fill_missing_times(aligned: list[dict[str, Any]], start: float, end: float) -> None:
+    """wav2vec2 leaves tokens it cannot score (digits, symbols) untimed; interpolate them."""
+    previous_end = start
+    for index, word in enumerate(aligned):
+        if "start" in word and "end" in word:
+            previous_end = float(word["end"])
+            continue
+        next_start = next(
+            (float(w["start"]) for w in aligned[index + 1 :] if "start" in w),
+            end,
+        )
+        word["start"] = previous_end
+        word["end"] = max(previous_end, next_start)
+        previous_end = word["end"]
+
+
+def _wav2vec2_align(
+    audio_path: Path,
+    segments: list[dict[str, Any]],
+    language: str,
+    device: str,
+) -> AlignmentResult:
+    whisperx = importlib.import_module("whisperx")
+    audio = whisperx.load_audio(str(audio_path))
+    model, metadata = whisperx.load_align_model(language_code=language, device=device)
+    try:
+        result = whisperx.align(
+            [{"start": s["start"], "end": s["end"], "text": s["text"]} for s in segments],
+            model,
+            metadata,
+            audio,
+            device,
+            return_char_alignments=False,
+        )
+    finally:
+        del model
+
+    aligned_segments: list[dict[str, Any]] = []
+    words: list[dict[str, Any]] = []
+    # whisperx.align returns one output segment per input segment, in order.
+    outputs = result.get("segments", [])
+    for index, (source, out) in enumerate(zip(segments, outputs, strict=False)):
+        seg_start = float(out.get("start", source["start"]))
+        seg_end = float(out.get("end", source["end"]))
+        seg_words = [dict(w) for w in out.get("words", []) if str(w.get("word", "")).strip()]
+        _fill_missing_times(seg_words, seg_start, seg_end)
+        aligned_segments.append({**source, "start": seg_start, "end": seg_end})
+        words.extend(
+            {
+                "word": str(w["word"]).strip(),
+                "start": round(float(w["start"]), 3),
+                "end": round(float(w["end"]), 3),
+                "confidence": w.get("score"),
+                "segment_index": index,
+            }
+            for w in seg_words
+        )
+    if len(aligned_segments) != len(segments):
+        raise RuntimeError("aligner returned a different number of segments")
+    return AlignmentResult(segments=aligned_segments, words=words, method="wav2vec2")
+
+
+def _default_device() -> str:
+    from server.core.audio_utils import check_cuda_available
+
+    return "cuda" if check_cuda_available() else "cpu"
+
+
+def align_segments(
+    audio_path: Path | None,
+    segments: list[dict[str, Any]],
+    *,
+    language: str | None = None,
+    device: str | None = None,
+    use_model: bool = True,
+) -> AlignmentResult:
+    """Align ``segments`` (dicts with ``start``/``end``/``text``) to ``audio_path``.
+
+    ``use_model=False`` (or no audio file) skips straight to the proportional
+    strategy. The returned ``method`` says which strategy produced the words.
+    """
+    segments = [dict(seg) for seg in segments if str(seg.get("text") or "").strip()]
+    if use_model and audio_path is not None and segments:
+        try:
+            return _wav2vec2_align(
+                Path(audio_path), segments, language or "en", device or _default_device()
+            )
+        except ImportError:
+            logger.info("WhisperX not installed; using proportional word timings")
+        except Exception as e:
+            logger.warning("Forced alignment failed (%s); using proportional word timings", e)
+    return AlignmentResult(segments=segments, words=proportional_words(segments))
+
+
+_NORMALISE_RE = re.compile(r"[^\w']+", re.UNICODE)
+
+
+def _norm(token: str) -> str:
+    return _NORMALISE_RE.sub("", token.lower())
+
+
+def _spread(tokens: list[str], start: float, end: float) -> list[dict[str, Any]]:
+    return proportional_words([{"start": start, "end": max(start, end), "text": " ".join(tokens)}])
+
+
+def transfer_timings(
+    old_words: list[dict[str, Any]],
+    tokens: list[str],
+    *,
+    span: tuple[float, float] = (0.0, 0.0),
+) -> list[dict[str, Any]]:
+    """Carry timings from ``old_words`` onto the edited ``tokens``.
+
+    Matching words (case/punctuation-insensitive) keep their exact times,
+    confidence and ``segment_id``. Replaced and inserted words are spread over
+    the gap they occupy; their confidence is ``None`` since no model scored
+    them. ``span`` bounds insertions at the very start or end.
+    """
+    if not old_words:
+        return _spread(tokens, *span) if tokens else []
+    old_keys = [_norm(str(w.get("word") or "")) for w in old_words]
+    matcher = difflib.SequenceMatcher(None, old_keys, [_norm(t) for t in tokens], autojunk=False)
+    out: list[dict[str, Any]] = []
+    for op, i1, i2, j1, j2 in matcher.get_opcodes():
+        if op == "equal":
+            for old, token in zip(old_words[i1:i2], tokens[j1:j2], strict=True):
+                out.append(
+                    {
+                        "word": token,
+                        "start": float(old["start_time"]),
+                        "end": float(old["end_time"]),
+                        "confidence": old.get("confidence"),
+                        "segment_id": old.get("segment_id"),
+                    }
+                )
+            continue
+        if op == "delete":
+            continue
+        if op == "replace":
+            start = float(old_words[i1]["start_time"])
+            end = float(old_words[i2 - 1]["end_time"])
+            sources = old_words[i1:i2]
+        else:  # insert between old_words[i1 - 1] and old_words[i1]
+            start = float(old_words[i1 - 1]["end_time"]) if i1 > 0 else span[0]
+            end = float(old_words[i1]["start_time"]) if i1 < len(old_words) else span[1]
+            sources = [old_words[max(0, min(i1 - 1, len(old_words) - 1))]]
+        spread = _spread(tokens[j1:j2], start, max(start, end))
+        for k, word in enumerate(spread):
+            source = sources[min(len(sources) - 1, k * len(sources) // len(spread))]
+            word.pop("segment_index", None)
+            word["segment_id"] = source.get("segment_id")
+            out.append(word)
+    return out
+
+
+def _pseudo_words(segments: list[dict[str, Any]]) -> list[dict[str, Any]]:
+    """Proportional stand-in words for recordings stored without word timings."""
+    spans = [
+        {"start": s.get("start_time"), "end": s.get("end_time"), "text": s.get("text")}
+        for s in segments
+    ]
+    return [
+        {
+            "word": w["word"],
+            "start_time": w["start"],
+            "end_time": w["end"],
+            "segment_id": segments[w["segment_index"]]["id"],
+        }
+        for w in proportional_words(spans)
+    ]
+
+
+def plan_realignment(
+    corrected_text: str,
+    segments: list[dict[str, Any]],
+    words: list[dict[str, Any]],
+) -> list[dict[str, Any]]:
+    """Map corrected text onto the recording's segments with carried-over timings.
+
+    When the text has one non-empty line per segment (the editor's layout),
+    line *i* replaces segment *i* and speakers stay exactly where they were.
+    Otherwise the whole text is diffed against all words and each new word
+    lands in the segment of the word it replaced. Returns segment dicts with
+    ``id``/``speaker``/``start``/``end``/``text``/``words``; segments left
+    without words are omitted.
+    """
+    ordered = sorted(words, key=lambda w: float(w.get("start_time") or 0.0))
+    by_segment: dict[Any, list[dict[str, Any]]] = {}
+    for word in ordered:
+        by_segment.setdefault(word.get("segment_id"), []).append(word)
+    lines = [line.split() for line in corrected_text.splitlines() if line.strip()]
+
+    new_words: dict[Any, list[dict[str, Any]]]
+    if len(lines) == len(segments):
+        new_words = {}
+        for seg, tokens in zip(segments, lines, strict=True):
+            span = (float(seg.get("start_time") or 0.0), float(seg.get("end_time") or 0.0))
+            carried = transfer_timings(by_segment.get(seg["id"], []), tokens, span=span)
+            for word in carried:
+                word["segment_id"] = seg["id"]
+            new_words[seg["id"]] = carried
+    else:
+        tokens = corrected_text.split()
+        span = (
+            min((float(s.get("start_time") or 0.0) for s in segments), default=0.0),
+            max((float(s.get("end_time") or 0.0) for s in segments), default=0.0),
+        )
+        if not ordered:
+            ordered = _pseudo_words(segments)
+        new_words = {}
+        for word in transfer_timings(ordered, tokens, span=span):
+            new_words.setdefault(word["segment_id"], []).append(word)
+
+    planned: list[dict[str, Any]] = []
+    for seg in segments:
+        seg_words = new_words.get(seg["id"], [])
+        if not seg_words:
+            continue
+        planned.append(
+            {
+                "id": seg["id"],
+                "speaker": seg.get("speaker"),
+                "start": min(float(seg.get("start_time") or 0.0), seg_words[0]["start"]),
+                "end": max(float(seg.get("end_time") or 0.0), seg_words[-1]["end"]),
+                "text": " ".join(w["word"] for w in seg_words),
+                "words": seg_words,
+            }
+        )
+    return planned
+
+
+def refine_with_model(
+    audio_path: Path,
+    planned: list[dict[str, Any]],
+    *,
+    language: str | None = None,
+) -> str:
+    """Re-time ``planned`` words in place with the wav2vec2 aligner.
+
+    Only timings change — confidence and text are kept. A segment whose
+    aligned word count differs from the plan keeps its carried timings.
+    Returns the method that produced the final timings.
+    """
+    result = align_segments(audio_path, planned, language=language)
+    if result.method != "wav2vec2":
+        return "interpolated"
+    aligned: dict[int, list[dict[str, Any]]] = {}
+    for word in result.words:
+        aligned.setdefault(word["segment_index"], []).append(word)
+    for index, seg in enumerate(planned):
+        timed = aligned.get(index, [])
+        if len(timed) != len(seg["words"]):
+            continue
+        for word, new in zip(seg["words"], timed, strict=True):
+            word["start"], word["end"] = new["start"], new["end"]
+        seg["start"] = min(seg["start"], timed[0]["start"])
+        seg["end"] = max(seg["end"], timed[-1]["end"])
+    return "wav2vec2"
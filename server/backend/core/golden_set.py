@@ -0,0 +1,126 @@
+"""Golden-set accuracy tracking: catch transcription regressions after updates.
+
+Users mark recordings whose transcript they corrected by hand as reference
+(``database/golden_repository.py``); the corrected text is snapshotted then.
+The ``golden-check`` housekeeping action, which clients schedule like the
+other nightly tasks, re-transcribes each reference's audio with the current
+main model and stores the word error rate against the snapshot (same WER as
+``core/model_benchmark.py``).
+
+The report compares each recording's latest WER with its first one — the
+baseline — so a model swap or image update that makes transcripts worse
+shows up as positive drift. Drift above ``threshold`` (absolute WER, 0.02 =
+two points) flags a regression.
+"""
+
+from __future__ import annotations
+
+import logging
+from collections.abc import Callable, Iterable, Sequence
+from pathlib import Path
+from typing import Any
+
+from server.core.model_benchmark import normalize_words, word_error_rate
+
+logger = logging.getLogger(__name__)
+
+REGRESSION_THRESHOLD = 0.02
+# Runs per recording included in the report's history.
+HISTORY_RUNS = 20
+
+
+def _mean(values: Sequence[float]) -> float | None:
+    return round(sum(values) / len(values), 4) if values else None
+
+
+def drift_report(
+    references: Iterable[dict[str, Any]],
+    runs: Iterable[dict[str, Any]],
+    *,
+    threshold: float = REGRESSION_THRESHOLD,
+) -> dict[str, Any]:
+    """Per-reference baseline, latest WER and drift, plus the means across them.
+
+    ``runs`` must be oldest first. Failed runs (``wer`` None) appear in the
+    history but never count as baseline or latest.
+    """
+    by_recording: dict[int, list[dict[str, Any]]] = {}
+    for run in runs:
+        by_recording.setdefault(int(run["recording_id"]), []).append(run)
+
+    recordings: list[dict[str, Any]] = []
+    for ref in references:
+        history = by_recording.get(int(ref["recording_id"]), [])
+        scored = [float(r["wer"]) for r in history if r.get("wer") is not None]
+        baseline = scored[0] if scored else None
+        latest = scored[-1] if scored else None
+        drift = round(latest - baseline, 4) if len(scored) > 1 else None
+        last = history[-1] if history else {}
+        recordings.append(
+            {
+                "recording_id": int(ref["recording_id"]),
+                "title": ref.get("title") or ref.get("filename") or "",
+                "marked_at": ref["marked_at"],
+                "reference_words": len(normalize_words(ref["reference"])),
+                "runs": len(history),
+                "baseline_wer": baseline,
+                "latest_wer": latest,
+                "drift": drift,
+                "regressed": drift is not None and drift > threshold,
+                "last_run_at": last.get("run_at"),
+                "last_error": last.get("error"),
+                "history": [
+                    {k: r[k] for k in ("run_at", "model", "server_version", "wer", "error")}
+                    for r in history[-HISTORY_RUNS:]
+                ],
+            }
+        )
+
+    compared = [r for r in recordings if r["drift"] is not None]
+    baseline_mean = _mean([r["baseline_wer"] for r in compared])
+    latest_mean = _mean([r["latest_wer"] for r in compared])
+    return {
+        "threshold": threshold,
+        "recordings": recordings,
+        "mean_baseline_wer": baseline_mean,
+        "mean_latest_wer": latest_mean,
+        "drift": (
+            round(latest_mean - baseline_mean, 4)
+            if baseline_mean is not None and latest_mean is not None
+            else None
+        ),
+        "regressed": any(r["regressed"] for r in recordings),
+    }
+
+
+def run_golden_check(
+    references: Iterable[dict[str, Any]],
+    transcribe: Callable[[Path], str],
+    *,
+    model: str,
+    server_version: str,
+    record: Callable[..., Any],
+) -> list[dict[str, Any]]:
+    """Re-transcribe every reference and ``record`` one run each.
+
+    ``transcribe(path)`` returns the hypothesis text; ``record`` takes the
+    ``golden_repository.add_run`` arguments. Missing audio or a failing
+    transcription is stored as an error run and the next reference still runs.
+    """
+    results: list[dict[str, Any]] = []
+    for ref in references:
+        recording_id = int(ref["recording_id"])
+        path = Path(ref.get("filepath") or "")
+        wer: float | None = None
+        error: str | None = None
+        if not path.is_file():
+            error = "Recording audio is no longer available"
+        else:
+            try:
+                wer = round(word_error_rate(ref["reference"], transcribe(path)), 4)
+            except Exception as e:
+                logger.warning("Golden-set run failed for recording %d: %s", recording_id, e)
+                error = str(e) or type(e).__name__
+        record(recording_id, model=model, server_version=server_version, wer=wer, error=error)
+        results.append({"recording_id": recording_id, "wer": wer, "error": error})
+    return results
@@ -0,0 +1,147 @@
+"""
+Server housekeeping actions that clients schedule (typically nightly).
+
+- ``prune_model_cache`` — free disk space in the Hugging Face hub cache:
+  partial downloads left behind by interrupted pulls, and whole models that
+  no config key names and nobody has touched for ``unused_days``
+- ``rotate_logs`` — roll ``server.log`` over now instead of waiting for it
+  to reach ``logging.max_size_mb``
+
+The schedule lives in the client; each request runs one action and reports
+what it did. Restarting the container is the client's job (it owns Docker).
+"""
+
+from __future__ import annotations
+
+import logging
+import logging.handlers
+import os
+import shutil
+import time
+from collections.abc import Iterable
+from pathlib import Path
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+# Partial downloads younger than this may still be in progress.
+INCOMPLETE_GRACE_SECONDS = 24 * 3600
+
+# Orgs whose repos are never pruned: pyannote pipelines pull helper models
+# (segmentation, embedding) that no config key names.
+PROTECTED_ORGS = frozenset({"pyannote"})
+
+# Mirrors _MODELSCOPE_TO_HF_REPO in core/stt/backends/sensevoice_backend.py —
+# keep the two in sync.
+_HF_REPO_ALIASES = {
+    "iic/SenseVoiceSmall": "FunAudioLLM/SenseVoiceSmall",
+}
+
+
+def hub_cache_dir() -> Path:
+    hf_home = os.environ.get("HF_HOME") or os.path.join(
+        os.path.expanduser("~"), ".cache", "huggingface"
+    )
+    return Path(hf_home) / "hub"
+
+
+def _cache_dir_name(model_id: str) -> str:
+    repo_id = _HF_REPO_ALIASES.get(model_id.strip(), model_id.strip())
+    return "models--" + repo_id.replace("/", "--")
+
+
+def _tree_stats(path: Path) -> tuple[int, float]:
+    """Total size and the newest access/modification time under *path*."""
+    size, last_used = 0, 0.0
+    for root, _dirs, files in os.walk(path):
+        for name in files:
+            try:
+                st = (Path(root) / name).stat()
+            except OSError:
+                continue  # dangling snapshot symlink
+            size += st.st_size
+            last_used = max(last_used, st.st_atime, st.st_mtime)
+    return size, last_used
+
+
+def prune_model_cache(
+    keep: Iterable[str],
+    unused_days: int,
+    *,
+    dry_run: bool = False,
+    cache_dir: Path | None = None,
+    now: float | None = None,
+) -> dict[str, Any]:
+    """Delete stale partial downloads and unused, unconfigured models.
+
+    *keep* lists the model IDs the server is configured to use; they are
+    never removed, whatever their age. Returns what was (or, with
+    *dry_run*, would be) removed and how many bytes that frees.
+    """
+    cache_dir = cache_dir or hub_cache_dir()
+    now = time.time() if now is None else now
+    keep_dirs = {_cache_dir_name(model_id) for model_id in keep if model_id}
+    removed: list[dict[str, Any]] = []
+    partial_bytes = 0
+
+    if not cache_dir.is_dir():
+        return {"removed": [], "partial_bytes": 0, "freed_bytes": 0, "dry_run": dry_run}
+
+    for repo in sorted(cache_dir.iterdir()):
+        if not repo.is_dir() or not repo.name.startswith("models--"):
+            continue
+
+        for blob in (repo / "blobs").glob("*.incomplete"):
+            try:
+                st = blob.stat()
+            except OSError:
+                continue
+            if now - st.st_mtime < INCOMPLETE_GRACE_SECONDS:
+                continue
+            partial_bytes += st.st_size
+            if not dry_run:
+                blob.unlink(missing_ok=True)
+
+        org = repo.name.removeprefix("models--").split("--", 1)[0]
+        if repo.name in keep_dirs or org in PROTECTED_ORGS:
+            continue
+        size, last_used = _tree_stats(repo)
+        if now - last_used < unused_days * 86400:
+            continue
+        removed.append(
+            {
+                "model": repo.name.removeprefix("models--").replace("--", "/"),
+                "bytes": size,
+                "last_used_at": last_used,
+            }
+        )
+        if not dry_run:
+            shutil.rmtree(repo, ignore_errors=True)
+
+    freed = partial_bytes + sum(entry["bytes"] for entry in removed)
+    if removed or partial_bytes:
+        logger.info(
+            "Model cache prune%s: %d model(s), %d bytes",
+            " (dry run)" if dry_run else "",
+            len(removed),
+            freed,
+        )
+    return {
+        "removed": removed,
+        "partial_bytes": partial_bytes,
+        "freed_bytes": freed,
+        "dry_run": dry_run,
+    }
+
+
+def rotate_logs(root: logging.Logger | None = None) -> dict[str, Any]:
+    """Roll every rotating file handler on the root logger over now."""
+    root = root or logging.getLogger()
+    rotated: list[str] = []
+    for handler in root.handlers:
+        if isinstance(handler, logging.handlers.RotatingFileHandler):
+            handler.doRollover()
+            rotated.append(handler.baseFilename)
+    if rotated:
+        logger.info("Log files rotated on request")
+    return {"rotated": rotated}
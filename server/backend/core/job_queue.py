@@ -0,0 +1,120 @@
+"""
+Waiting list in front of the single transcription slot.
+
+The server runs one transcription at a time (``TranscriptionJobTracker``).
+On a homelab server shared by several people, clients that find the slot
+busy can join this list instead of retrying blindly; the head of the list
+gets the slot next. Each client may reorder its own entries — doing so only
+shuffles them among the places they already hold, so nobody else moves.
+
+Entries are kept alive by the owning client polling ``GET /queue``. An entry
+that is not seen for ``ENTRY_TTL_SECONDS`` is dropped; the head gets
+``HEAD_TTL_SECONDS`` because its owner may be busy uploading the file.
+
+Not thread-safe on its own: ``TranscriptionJobTracker`` calls it under its
+lock so queue checks and slot acquisition are one atomic step.
+"""
+
+from __future__ import annotations
+
+import time
+import uuid
+from dataclasses import dataclass, field
+
+ENTRY_TTL_SECONDS = 120
+HEAD_TTL_SECONDS = 600
+MAX_ENTRIES_PER_USER = 50
+LABEL_MAX_LENGTH = 200
+
+
+class QueueEntryNotFound(LookupError):
+    """The entry does not exist (or expired), or belongs to someone else."""
+
+
+class QueueFull(Exception):
+    """The user already holds ``MAX_ENTRIES_PER_USER`` entries."""
+
+
+@dataclass
+class QueueEntry:
+    id: str
+    user: str
+    label: str
+    duration_seconds: float | None
+    enqueued_at: float
+    last_seen: float = field(default=0.0)
+
+
+class JobQueue:
+    def __init__(self) -> None:
+        self._entries: list[QueueEntry] = []
+
+    def __len__(self) -> int:
+        return len(self._entries)
+
+    def prune(self, now: float | None = None) -> None:
+        """Drop entries whose owner stopped polling."""
+        now = time.time() if now is None else now
+        self._entries = [
+            entry
+            for index, entry in enumerate(self._entries)
+            if now - entry.last_seen < (HEAD_TTL_SECONDS if index == 0 else ENTRY_TTL_SECONDS)
+        ]
+
+    def head(self) -> QueueEntry | None:
+        return self._entries[0] if self._entries else None
+
+    def entries(self) -> list[QueueEntry]:
+        return list(self._entries)
+
+    def join(self, user: str, label: str, duration_seconds: float | None) -> QueueEntry:
+        if sum(1 for e in self._entries if e.user == user) >= MAX_ENTRIES_PER_USER:
+            raise QueueFull(f"At most {MAX_ENTRIES_PER_USER} queued jobs per client")
+        now = time.time()
+        entry = QueueEntry(
+            id=str(uuid.uuid4()),
+            user=user,
+            label=label.strip()[:LABEL_MAX_LENGTH],
+            duration_seconds=(
+                duration_seconds if duration_seconds and duration_seconds > 0 else None
+            ),
+            enqueued_at=now,
+            last_seen=now,
+        )
+        self._entries.append(entry)
+        return entry
+
+    def leave(self, entry_id: str, user: str) -> None:
+        self._entries.remove(self._own(entry_id, user))
+
+    def pop(self, entry_id: str) -> bool:
+        """Remove *entry_id* when its job starts; False if it had expired."""
+        for entry in self._entries:
+            if entry.id == entry_id:
+                self._entries.remove(entry)
+                return True
+        return False
+
+    def touch(self, user: str, now: float | None = None) -> None:
+        now = time.time() if now is None else now
+        for entry in self._entries:
+            if entry.user == user:
+                entry.last_seen = now
+
+    def reorder(self, user: str, entry_ids: list[str]) -> None:
+        """Put *user*'s entries in the order of *entry_ids*, in the slots they hold.
+
+        *entry_ids* must name every entry the user has queued, exactly once.
+        """
+        own_slots = [i for i, e in enumerate(self._entries) if e.user == user]
+        own = {self._entries[i].id: self._entries[i] for i in own_slots}
+        if len(entry_ids) != len(own) or set(entry_ids) != set(own):
+            raise ValueError("entry_ids must list each of your queued jobs exactly once")
+        for slot, entry_id in zip(own_slots, entry_ids, strict=True):
+            self._entries[slot] = own[entry_id]
+
+    def _own(self, entry_id: str, user: str) -> QueueEntry:
+        for entry in self._entries:
+            if entry.id == entry_id and entry.user == user:
+                return entry
+        raise QueueEntryNotFound(entry_id)
@@ -0,0 +1,102 @@
+"""Keyword alerts: match watch keywords against live sentences as they finish.
+
+Each watch entry is a plain keyword or phrase ("action item", a name, a
+ticker symbol), matched as whole words, or a regular expression written as
+``/pattern/``. Matching ignores case and diacritics: both the sentence and
+the keyword are compared in a folded form (NFKD, combining marks dropped,
+casefolded), and hits are reported with the original wording.
+
+Live Mode sends one ``keyword`` message per hit so the dashboard can raise
+a desktop notification that jumps back to the sentence.
+"""
+
+from __future__ import annotations
+
+import logging
+import re
+import unicodedata
+from collections.abc import Iterable
+from dataclasses import dataclass
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+MAX_KEYWORDS = 50
+MAX_KEYWORD_CHARS = 200
+
+
+def _strip_marks(text: str) -> str:
+    return "".join(c for c in unicodedata.normalize("NFKD", text) if not unicodedata.combining(c))
+
+
+def fold_with_offsets(text: str) -> tuple[str, list[int]]:
+    """Folded ``text`` plus, per folded character, the index it came from."""
+    folded: list[str] = []
+    offsets: list[int] = []
+    for i, ch in enumerate(text):
+        for out in _strip_marks(ch).casefold():
+            folded.append(out)
+            offsets.append(i)
+    return "".join(folded), offsets
+
+
+@dataclass(frozen=True)
+class _Watch:
+    keyword: str
+    pattern: re.Pattern[str]
+
+
+def _compile(keyword: str) -> re.Pattern[str]:
+    if len(keyword) > 2 and keyword.startswith("/") and keyword.endswith("/"):
+        # Regex: fold diacritics only — casefolding would turn \S into \s.
+        return re.compile(_strip_marks(keyword[1:-1]), re.IGNORECASE)
+    words = _strip_marks(keyword).casefold().split()
+    return re.compile(r"(?<!\w)" + r"\s+".join(map(re.escape, words)) + r"(?!\w)")
+
+
+class KeywordWatcher:
+    """Compiled watch list; invalid or empty entries are skipped with a warning."""
+
+    def __init__(self, keywords: Iterable[Any]) -> None:
+        self._watches: list[_Watch] = []
+        seen: set[str] = set()
+        for raw in keywords:
+            keyword = str(raw or "").strip()[:MAX_KEYWORD_CHARS]
+            if not keyword or keyword in seen:
+                continue
+            if len(self._watches) >= MAX_KEYWORDS:
+                logger.warning("Keyword alerts: more than %d keywords, rest ignored", MAX_KEYWORDS)
+                break
+            try:
+                pattern = _compile(keyword)
+            except re.error as exc:
+                logger.warning("Keyword alerts: skipping invalid pattern %r (%s)", keyword, exc)
+                continue
+            seen.add(keyword)
+            self._watches.append(_Watch(keyword, pattern))
+
+    def __bool__(self) -> bool:
+        return bool(self._watches)
+
+    @property
+    def keywords(self) -> list[str]:
+        return [w.keyword for w in self._watches]
+
+    def match(self, text: str) -> list[dict[str, Any]]:
+        """First hit of each keyword in ``text``, in keyword order.
+
+        Each hit is ``{"keyword", "match"}`` where ``match`` is the matched
+        span in the sentence's original spelling.
+        """
+        if not self._watches or not text:
+            return []
+        folded, offsets = fold_with_offsets(text)
+        hits = []
+        for watch in self._watches:
+            found = watch.pattern.search(folded)
+            if not found or found.end() == found.start():
+                continue
+            start = offsets[found.start()]
+            end = offsets[found.end() - 1] + 1
+            hits.append({"keyword": watch.keyword, "match": text[start:end]})
+        return hits
@@ -0,0 +1,120 @@
+"""Language preview: detect a file's spoken language from its first seconds.
+
+The job dialog calls this before queueing so it can pre-select the
+language and warn about multilingual files. Only the head of the file is
+decoded (``load_audio(max_seconds=...)``); it is split into fixed windows
+and each window is detected separately, so a change of language inside
+the sample shows up as disagreeing windows.
+
+Detection needs a backend that exposes per-language probabilities — the
+Whisper family does (``STTBackend.detect_language``). Others report
+``supported=False`` and the dialog keeps its current selection.
+"""
+
+from __future__ import annotations
+
+import logging
+from dataclasses import dataclass, field
+from typing import Any
+
+import numpy as np
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_SECONDS = 30.0
+MAX_SECONDS = 120.0
+WINDOW_SECONDS = 10.0
+# A window only counts towards "multilingual" when its top language is this sure.
+CONFIDENT_PROBABILITY = 0.6
+# Windows quieter than this (RMS) are skipped — silence detects as noise.
+SILENCE_RMS = 0.005
+TOP_LANGUAGES = 5
+
+
+@dataclass
+class LanguageDetection:
+    """Aggregate result; ``languages`` is sorted, most likely first."""
+
+    supported: bool
+    language: str | None = None
+    probability: float = 0.0
+    languages: list[tuple[str, float]] = field(default_factory=list)
+    window_languages: list[str | None] = field(default_factory=list)
+    multilingual: bool = False
+    sampled_seconds: float = 0.0
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "supported": self.supported,
+            "language": self.language,
+            "probability": round(self.probability, 3),
+            "languages": [
+                {"code": code, "probability": round(p, 3)} for code, p in self.languages
+            ],
+            "window_languages": self.window_languages,
+            "multilingual": self.multilingual,
+            "sampled_seconds": round(self.sampled_seconds, 2),
+        }
+
+
+def faster_whisper_language_probabilities(model: Any, audio: np.ndarray) -> list[tuple[str, float]]:
+    """All-language probabilities from a faster-whisper ``WhisperModel``."""
+    _language, _probability, all_probs = model.detect_language(audio=audio)
+    return sorted(((str(c), float(p)) for c, p in all_probs), key=lambda cp: -cp[1])
+
+
+def _windows(audio: np.ndarray, sample_rate: int) -> list[np.ndarray]:
+    size = int(WINDOW_SECONDS * sample_rate)
+    chunks = [audio[i : i + size] for i in range(0, len(audio), size)] if size else [audio]
+    # A short tail would be detected on mostly padding; fold it into the previous window.
+    if len(chunks) > 1 and len(chunks[-1]) < size // 2:
+        chunks[-2] = np.concatenate([chunks[-2], chunks.pop()])
+    return chunks
+
+
+def summarize_windows(
+    windows: list[list[tuple[str, float]] | None], sampled_seconds: float
+) -> LanguageDetection:
+    """Average per-window probabilities; flag confident windows that disagree.
+
+    ``None`` marks a skipped (silent) window.
+    """
+    totals: dict[str, float] = {}
+    counted = 0
+    tops: list[str | None] = []
+    for probs in windows:
+        if not probs:
+            tops.append(None)
+            continue
+        counted += 1
+        for code, p in probs:
+            totals[code] = totals.get(code, 0.0) + p
+        code, p = probs[0]
+        tops.append(code if p >= CONFIDENT_PROBABILITY else None)
+    if not counted:
+        return LanguageDetection(supported=True, sampled_seconds=sampled_seconds)
+    ranked = sorted(((c, t / counted) for c, t in totals.items()), key=lambda cp: -cp[1])
+    confident = {c for c in tops if c}
+    return LanguageDetection(
+        supported=True,
+        language=ranked[0][0],
+        probability=ranked[0][1],
+        languages=ranked[:TOP_LANGUAGES],
+        window_languages=tops,
+        multilingual=len(confident) > 1,
+        sampled_seconds=sampled_seconds,
+    )
+
+
+def detect_language_audio(backend: Any, audio: np.ndarray, sample_rate: int) -> LanguageDetection:
+    """Run ``backend.detect_language`` per window of ``audio``."""
+    sampled = len(audio) / sample_rate if sample_rate else 0.0
+    if backend is None or not backend.supports_language_detection():
+        return LanguageDetection(supported=False, sampled_seconds=sampled)
+    results: list[list[tuple[str, float]] | None] = []
+    for chunk in _windows(audio, sample_rate):
+        if not len(chunk) or float(np.sqrt(np.mean(np.square(chunk)))) < SILENCE_RMS:
+            results.append(None)
+            continue
+        results.append(backend.detect_language(chunk, audio_sample_rate=sample_rate) or None)
+    return summarize_windows(results, sampled)
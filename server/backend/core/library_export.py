@@ -0,0 +1,160 @@
+"""Bulk export of the whole library (or a filtered slice of it).
+
+Built on the output manager: every ``recording × format`` target is planned
+with ``plan_outputs`` (template naming, per-type folders, collision policy)
+and then rendered and written one at a time, yielding a progress event
+after each so the route can stream them to the dashboard.
+
+Only one library export runs at a time. It stops early when cancelled
+(``cancel_active``) or when the stream's consumer goes away; targets
+already written stay on disk.
+
+A format a recording cannot produce (TXT for a diarized recording, SRT for
+a pure note, ``summary`` without a summary) is reported as ``unsupported``
+rather than ``failed``, so "dump everything to SRT" reads cleanly.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+from collections.abc import AsyncIterator, Awaitable, Callable, Iterable
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+
+class UnsupportedExport(ValueError):
+    """The recording cannot be exported in the requested format."""
+
+
+@dataclass(frozen=True)
+class LibraryFilter:
+    """Which recordings to export; empty fields match everything."""
+
+    start_date: str | None = None  # YYYY-MM-DD, inclusive, on recorded_at
+    end_date: str | None = None
+    title: str | None = None  # case-insensitive substring of title or filename
+    recording_ids: tuple[int, ...] = ()
+
+    def matches(self, recording: dict[str, Any]) -> bool:
+        if self.recording_ids and int(recording.get("id") or 0) not in self.recording_ids:
+            return False
+        day = str(recording.get("recorded_at") or "")[:10]
+        if self.start_date and day < self.start_date:
+            return False
+        if self.end_date and day > self.end_date:
+            return False
+        needle = (self.title or "").strip().casefold()
+        if needle:
+            haystack = f"{recording.get('title') or ''} {recording.get('filename') or ''}"
+            if needle not in haystack.casefold():
+                return False
+        return True
+
+
+def select_recordings(
+    recordings: Iterable[dict[str, Any]], flt: LibraryFilter
+) -> list[dict[str, Any]]:
+    """Matching recordings, oldest first so numbering suffixes follow recording order."""
+    return sorted(
+        (r for r in recordings if flt.matches(r)),
+        key=lambda r: (str(r.get("recorded_at") or ""), int(r.get("id") or 0)),
+    )
+
+
+class LibraryExportJob:
+    def __init__(self) -> None:
+        self._cancel = asyncio.Event()
+
+    def cancel(self) -> None:
+        self._cancel.set()
+
+    @property
+    def cancelled(self) -> bool:
+        return self._cancel.is_set()
+
+
+_active: LibraryExportJob | None = None
+
+
+def start_job() -> LibraryExportJob:
+    """Claim the single library-export slot; ``RuntimeError`` if one is running."""
+    global _active
+    if _active is not None:
+        raise RuntimeError("A library export is already running")
+    _active = LibraryExportJob()
+    return _active
+
+
+def finish_job(job: LibraryExportJob) -> None:
+    global _active
+    if _active is job:
+        _active = None
+
+
+def cancel_active() -> bool:
+    """Ask the running export to stop after its current target."""
+    if _active is None:
+        return False
+    _active.cancel()
+    return True
+
+
+async def run_library_export(
+    job: LibraryExportJob,
+    plans: list[Any],
+    recordings: dict[int, dict[str, Any]],
+    *,
+    render: Callable[[dict[str, Any], str], Awaitable[bytes | Path]],
+    write: Callable[[str, bytes | Path], None],
+) -> AsyncIterator[dict[str, Any]]:
+    """Write every planned target, yielding ``start`` / ``progress`` / ``done`` events.
+
+    ``progress`` carries the plan fields plus ``status`` (``written``,
+    ``skipped``, ``unsupported`` or ``failed`` with ``error``) and the
+    running ``done`` / ``total`` counts. ``done`` summarises the counts and
+    whether the run was ``cancelled``.
+    """
+    total = len(plans)
+    counts = {"written": 0, "skipped": 0, "unsupported": 0, "failed": 0}
+    done = 0
+    try:
+        yield {"type": "start", "total": total, "recordings": len(recordings)}
+        for plan in plans:
+            if job.cancelled:
+                break
+            event = {"type": "progress", **plan.to_dict()}
+            if plan.action == "skip":
+                event["status"] = "skipped"
+            elif plan.path is None:
+                event.update(status="failed", error=f"no folder for {plan.output_type} outputs")
+            else:
+                try:
+                    content = await render(recordings[plan.recording_id], plan.format)
+                    await asyncio.to_thread(write, plan.path, content)
+                    event["status"] = "written"
+                except UnsupportedExport as e:
+                    event.update(status="unsupported", error=str(e))
+                except (OSError, ValueError) as e:
+                    logger.warning(
+                        "Library export of recording %d (%s) failed: %s",
+                        plan.recording_id,
+                        plan.format,
+                        e,
+                    )
+                    event.update(status="failed", error=str(e))
+            counts[event["status"]] += 1
+            done += 1
+            yield {**event, "done": done, "total": total}
+    finally:
+        finish_job(job)
+    logger.info(
+        "Library export %s: %d written of %d targets",
+        "cancelled" if job.cancelled else "finished",
+        counts["written"],
+        total,
+    )
+    yield {"type": "done", "done": done, "total": total, "cancelled": job.cancelled, **counts}
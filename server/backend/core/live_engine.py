@@ -7,6 +7,12 @@ engine. Each completed sentence triggers a callback.
 
 Unlike the main transcription (which processes complete recordings), Live Mode
 operates continuously and delivers sentences as they are detected via VAD.
+
+Two streaming modes trade latency for accuracy and can be switched while a
+session runs: ``accurate`` (note-taking) waits for the full silence grace
+period and decodes with beam search; ``fast`` (captioning) sends a greedy
+partial hypothesis every half second of new speech and closes sentences
+sooner, greedy as well.
 """
 
 import logging
@@ -25,6 +31,27 @@ logger = logging.getLogger(__name__)
 SAMPLE_RATE = 16000
 
 
+@dataclass(frozen=True)
+class StreamingProfile:
+    """How a streaming mode trades latency for accuracy."""
+
+    # Seconds of new speech between partial hypotheses; None sends finals only.
+    partial_interval: float | None
+    # Beam size for finals; None keeps ``LiveModeConfig.beam_size``.
+    beam_size: int | None
+    # Upper bound on the silence that ends a sentence; None keeps the configured value.
+    max_post_speech_silence: float | None
+
+
+STREAMING_PROFILES: dict[str, StreamingProfile] = {
+    "accurate": StreamingProfile(
+        partial_interval=None, beam_size=None, max_post_speech_silence=None
+    ),
+    "fast": StreamingProfile(partial_interval=0.5, beam_size=1, max_post_speech_silence=0.4),
+}
+DEFAULT_STREAMING_MODE = "accurate"
+
+
 class LiveModeState(Enum):
     """State of the Live Mode engine."""
 
@@ -63,6 +90,7 @@ class LiveModeConfig:
     # Performance
     beam_size: int = 5
     batch_size: int = 16
+    streaming_mode: str = DEFAULT_STREAMING_MODE
 
 
 class LiveModeEngine:
@@ -112,6 +140,10 @@ class LiveModeEngine:
         # Audio queue for feeding from WebSocket
         self._audio_queue: queue.Queue[bytes] = queue.Queue()
 
+        # Partial hypotheses (fast mode): seconds of speech at the last one, and its text
+        self._partial_at = 0.0
+        self._last_partial = ""
+
     @property
     def state(self) -> LiveModeState:
         """Get current state."""
@@ -122,6 +154,30 @@ class LiveModeEngine:
         """Check if Live Mode is running."""
         return self._state in (LiveModeState.LISTENING, LiveModeState.PROCESSING)
 
+    @property
+    def streaming_mode(self) -> str:
+        return self.config.streaming_mode
+
+    def set_streaming_mode(self, mode: str) -> None:
+        """Switch streaming mode; applies from the next sentence, mid-session too."""
+        if mode not in STREAMING_PROFILES:
+            raise ValueError(f"Unknown streaming mode: {mode}")
+        self.config.streaming_mode = mode
+        self._apply_streaming_mode()
+        logger.info(f"Live Mode streaming mode: {mode}")
+
+    def _apply_streaming_mode(self) -> None:
+        """Push the mode's decode and silence settings into a running recorder."""
+        recorder = self._recorder
+        if recorder is None:
+            return
+        profile = STREAMING_PROFILES[self.config.streaming_mode]
+        recorder.beam_size = profile.beam_size or self.config.beam_size
+        silence = self.config.post_speech_silence_duration
+        if profile.max_post_speech_silence is not None:
+            silence = min(silence, profile.max_post_speech_silence)
+        recorder.post_speech_silence_duration = silence
+
     @property
     def sentence_history(self) -> list[str]:
         """Get history of transcribed sentences."""
@@ -199,6 +255,7 @@ class LiveModeEngine:
                 shared_backend=self._shared_backend,
             )
 
+            self._apply_streaming_mode()
             self._set_state(LiveModeState.LISTENING)
             logger.info("Live Mode started")
 
@@ -230,6 +287,37 @@ class LiveModeEngine:
                 self._set_state(LiveModeState.STOPPED)
             logger.info("Live Mode stopped")
 
+    def _emit_partial(self) -> None:
+        """Send a partial hypothesis when fast mode is on and enough new speech arrived."""
+        interval = STREAMING_PROFILES[self.config.streaming_mode].partial_interval
+        recorder = self._recorder
+        if interval is None or recorder is None or not recorder.is_recording:
+            self._partial_at = 0.0
+            self._last_partial = ""
+            return
+        recorded = recorder.recorded_seconds
+        if recorded < self._partial_at + interval:
+            return
+        text = recorder.transcribe_partial()
+        if text is None:
+            return
+        self._partial_at = recorded
+        if text and text != self._last_partial and self._on_realtime_update:
+            self._last_partial = text
+            try:
+                self._on_realtime_update(text)
+            except Exception as e:
+                logger.error(f"Partial callback error: {e}")
+
+    def _partial_loop(self) -> None:
+        """Poll for partial hypotheses (runs in separate thread)."""
+        while not self._stop_event.wait(0.05):
+            try:
+                if self.is_running:
+                    self._emit_partial()
+            except Exception as e:
+                logger.debug(f"Live Mode partial hypothesis failed: {e}")
+
     def _audio_feeder_loop(self) -> None:
         """Feed audio from queue to recorder (runs in separate thread)."""
         while not self._stop_event.is_set():
@@ -305,6 +393,12 @@ class LiveModeEngine:
         )
         self._feeder_thread.start()
 
+        # Partial hypotheses thread (idle unless the streaming mode wants them)
+        self._partial_thread = threading.Thread(
+            target=self._partial_loop, daemon=True, name="LiveModePartials"
+        )
+        self._partial_thread.start()
+
         return True
 
     def stop(self) -> None:
@@ -331,6 +425,9 @@ class LiveModeEngine:
         if hasattr(self, "_feeder_thread") and self._feeder_thread.is_alive():
             self._feeder_thread.join(timeout=2.0)
 
+        if hasattr(self, "_partial_thread") and self._partial_thread.is_alive():
+            self._partial_thread.join(timeout=2.0)
+
         self._loop_thread = None
         self._recorder = None
 
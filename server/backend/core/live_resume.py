@@ -0,0 +1,98 @@
+"""Session resumption for Live Mode.
+
+A network blip used to end a live session: the socket closed, the engine
+stopped and every word spoken until the user noticed was gone. Now the
+session outlives its connection for a grace period
+(``live_transcriber.resume_grace_seconds``):
+
+- Once the engine runs, the server sends a ``session`` message with a
+  resume token. The token, not the client name, proves that a new
+  connection is the same session.
+- The client numbers its audio frames (``seq`` in the frame metadata) and
+  keeps every frame until the server's ``audio_ack`` covers it.
+- After a drop the client reconnects, authenticates and sends ``resume``
+  with its token. The server reattaches the parked session and answers
+  ``resumed`` with the last frame the engine has; the client replays the
+  frames after it. Frames the engine already has are dropped here, so a
+  replay that overlaps what arrived before the drop is harmless.
+
+Transcript messages produced while the client was away wait in the
+session's queue; a segment-delta client also asks for ``get_segments``,
+which covers anything lost in the socket as it closed.
+"""
+
+from __future__ import annotations
+
+import hmac
+import json
+import secrets
+import struct
+import time
+from collections.abc import Callable
+from typing import Any
+
+# Seconds between ``audio_ack`` messages while audio flows.
+ACK_INTERVAL = 0.5
+
+
+def new_resume_token() -> str:
+    return secrets.token_urlsafe(24)
+
+
+def token_matches(expected: str | None, presented: object) -> bool:
+    """Constant-time token comparison; a missing token never matches."""
+    if not expected or not isinstance(presented, str) or not presented:
+        return False
+    return hmac.compare_digest(expected.encode(), presented.encode())
+
+
+def parse_audio_frame(frame: bytes) -> tuple[dict[str, Any], bytes] | None:
+    """Split ``[uint32 LE metadata length][metadata JSON][PCM]``; None when malformed."""
+    if len(frame) <= 4:
+        return None
+    metadata_len = struct.unpack("<I", frame[:4])[0]
+    if len(frame) < 4 + metadata_len:
+        return None
+    try:
+        metadata = json.loads(frame[4 : 4 + metadata_len] or b"{}")
+    except (UnicodeDecodeError, json.JSONDecodeError):
+        metadata = {}
+    if not isinstance(metadata, dict):
+        metadata = {}
+    return metadata, frame[4 + metadata_len :]
+
+
+class AudioSequence:
+    """Tracks which numbered audio frames the engine has had.
+
+    Frames without a ``seq`` (older clients) are always accepted and never
+    acknowledged.
+    """
+
+    def __init__(
+        self, ack_interval: float = ACK_INTERVAL, clock: Callable[[], float] = time.monotonic
+    ) -> None:
+        self.last_seq = 0
+        self._acked = 0
+        self._acked_at = 0.0
+        self._ack_interval = ack_interval
+        self._clock = clock
+
+    def accept(self, seq: object) -> bool:
+        """Whether to feed a frame: False for one the engine already has."""
+        if not isinstance(seq, int) or isinstance(seq, bool):
+            return True
+        if seq <= self.last_seq:
+            return False
+        self.last_seq = seq
+        return True
+
+    def ack_due(self) -> int | None:
+        """The seq to acknowledge now, or None when nothing new or too soon."""
+        if self.last_seq == self._acked:
+            return None
+        now = self._clock()
+        if now - self._acked_at < self._ack_interval:
+            return None
+        self._acked, self._acked_at = self.last_seq, now
+        return self.last_seq
@@ -0,0 +1,96 @@
+"""Segment deltas for the Live Mode transcript.
+
+Instead of resending the growing partial text and every finished sentence,
+a client that opts in (``segment_deltas`` in its start config) receives one
+``segment`` message per change to the transcript:
+
+- ``insert`` opens a new segment with its first partial text
+- ``update`` replaces the open segment's text
+- ``finalize`` fixes a segment's text for good; the next partial opens a new one
+
+``update`` and ``finalize`` carry ``keep`` (how many characters of the
+previous text stay) and ``text`` (what follows them), so a partial that only
+grows costs the new words rather than the whole utterance.
+
+Every delta has a ``seq`` one higher than the last. A client that sees a
+jump asks for ``get_segments`` with the last finalized id it holds and gets
+back the segments after it plus the current ``seq`` to continue from.
+"""
+
+from __future__ import annotations
+
+from collections import deque
+from typing import Any
+
+# Finalized segments kept for gap recovery. An hour of speech is well under
+# this; clients that fall further behind keep what they already have.
+MAX_SEGMENTS = 2000
+
+
+def _common_prefix(a: str, b: str) -> int:
+    n = min(len(a), len(b))
+    i = 0
+    while i < n and a[i] == b[i]:
+        i += 1
+    return i
+
+
+class SegmentLog:
+    """Turns partial and sentence callbacks into numbered segment deltas.
+
+    Not thread-safe: the Live Mode session drives it from the event loop as
+    it sends queued engine messages, which also keeps ``seq`` in send order.
+    """
+
+    def __init__(self, max_segments: int = MAX_SEGMENTS) -> None:
+        self.seq = 0
+        self._next_id = 1
+        self._finalized: deque[dict[str, Any]] = deque(maxlen=max_segments)
+        self._open: dict[str, Any] | None = None
+
+    def _delta(self, op: str, segment: dict[str, Any], **fields: Any) -> dict[str, Any]:
+        self.seq += 1
+        return {"seq": self.seq, "op": op, "id": segment["id"], **fields}
+
+    def _change(self, op: str, segment: dict[str, Any], text: str) -> dict[str, Any]:
+        keep = _common_prefix(segment["text"], text)
+        segment["text"] = text
+        return self._delta(op, segment, keep=keep, text=text[keep:])
+
+    def partial(self, text: str) -> dict[str, Any] | None:
+        """Delta for a new partial text, or None when nothing changed."""
+        if self._open is None:
+            if not text:
+                return None
+            self._open = {"id": self._next_id, "text": text}
+            self._next_id += 1
+            return self._delta("insert", self._open, text=text)
+        if text == self._open["text"]:
+            return None
+        return self._change("update", self._open, text)
+
+    def finalize(self, text: str) -> dict[str, Any]:
+        """Delta fixing the open segment (or a new one) to a finished sentence."""
+        if self._open is None:
+            self._open = {"id": self._next_id, "text": ""}
+            self._next_id += 1
+        segment, self._open = self._open, None
+        delta = self._change("finalize", segment, text)
+        self._finalized.append(segment)
+        return delta
+
+    def snapshot(self, since_id: int = 0) -> dict[str, Any]:
+        """Segments after ``since_id`` (finalized, then the open one) and the current seq."""
+        segments = [
+            {"id": s["id"], "text": s["text"], "final": True}
+            for s in self._finalized
+            if s["id"] > since_id
+        ]
+        if self._open is not None:
+            segments.append({"id": self._open["id"], "text": self._open["text"], "final": False})
+        return {"seq": self.seq, "segments": segments}
+
+    def clear(self) -> None:
+        """Forget all segments; ``seq`` and ids keep counting."""
+        self._finalized.clear()
+        self._open = None
@@ -0,0 +1,443 @@
+"""Email-in transcription: poll an IMAP mailbox for audio attachments.
+
+A popular homelab pattern — forward voicemails or dictation memos to a
+dedicated address and get the transcript back. When ``mail.enabled`` is set,
+:class:`MailPoller` checks ``mail.mailbox`` every ``poll_interval_s`` for
+unread messages, transcribes each audio attachment into the Audio Notebook
+(``source="mail"``, so ``post_transcription_rules`` can match it) and then:
+
+  - replies to the sender with the transcript attached when ``reply`` is on
+    and an SMTP relay is configured (``reply_format``: plaintext / srt / ass,
+    rendered exactly like a share);
+  - marks the message read, or moves it to ``processed_mailbox`` when set.
+
+Messages from senders outside ``allowed_senders`` (when the list is set) or
+without audio attachments are marked read and left in place — the mailbox is
+expected to be dedicated to this. A message whose transcription fails, or
+with an attachment over ``max_attachment_mb``, is marked read and flagged so
+it stands out in a mail client — never moved — and is not retried; the other
+attachments of an oversized message are still transcribed.
+
+Passwords never live in ``config.yaml``: the IMAP password is read from the
+OS keychain under ``mail.imap_password`` and the SMTP password (only when
+``smtp_username`` is set) under ``mail.smtp_password``, mirroring
+:mod:`server.core.share`.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import imaplib
+import logging
+import re
+import smtplib
+import tempfile
+from collections.abc import Awaitable, Callable
+from dataclasses import dataclass, field
+from email import message_from_bytes, policy
+from email.message import EmailMessage
+from email.utils import formatdate, make_msgid, parseaddr
+from pathlib import Path
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+MAIL_SOURCE = "mail"
+MAIL_TIMEOUT_S = 60.0
+BUSY_RETRY_S = 15.0
+IMAP_SECURITY_MODES = ("ssl", "starttls", "none")
+
+# Voicemail systems favour AMR / 3GP / WAV; phones send M4A / AAC / OPUS.
+MAIL_AUDIO_EXTENSIONS = frozenset(
+    {
+        ".3gp",
+        ".aac",
+        ".amr",
+        ".flac",
+        ".m4a",
+        ".mp3",
+        ".mp4",
+        ".oga",
+        ".ogg",
+        ".opus",
+        ".wav",
+        ".webm",
+    }
+)
+
+# Recording id for one attachment; raises when transcription failed.
+Transcriber = Callable[[Path, str, str], Awaitable[int]]
+
+
+class MailError(RuntimeError):
+    """Raised for configuration problems (missing credential, bad mode)."""
+
+
+@dataclass(slots=True)
+class MailSettings:
+    """The ``mail`` config section."""
+
+    enabled: bool = False
+    imap_host: str = ""
+    imap_port: int = 993
+    imap_security: str = "ssl"
+    username: str = ""
+    mailbox: str = "INBOX"
+    processed_mailbox: str = ""
+    poll_interval_s: float = 300.0
+    allowed_senders: list[str] = field(default_factory=list)
+    max_attachment_mb: float = 200.0
+    language: str | None = None
+    enable_diarization: bool = False
+    reply: bool = True
+    reply_format: str = "plaintext"
+    smtp_host: str = ""
+    smtp_port: int = 587
+    smtp_username: str = ""
+    starttls: bool = True
+    from_address: str = ""
+
+    @classmethod
+    def from_config(cls, raw: dict[str, Any] | None) -> MailSettings:
+        raw = raw or {}
+        defaults = cls()
+        settings = cls(
+            enabled=bool(raw.get("enabled", False)),
+            imap_host=str(raw.get("imap_host") or "").strip(),
+            imap_port=int(raw.get("imap_port") or defaults.imap_port),
+            imap_security=str(raw.get("imap_security") or "ssl").lower(),
+            username=str(raw.get("username") or "").strip(),
+            mailbox=str(raw.get("mailbox") or "INBOX"),
+            processed_mailbox=str(raw.get("processed_mailbox") or ""),
+            poll_interval_s=float(raw.get("poll_interval_s", defaults.poll_interval_s)),
+            allowed_senders=[
+                str(s).strip().lower() for s in raw.get("allowed_senders") or [] if str(s).strip()
+            ],
+            max_attachment_mb=float(raw.get("max_attachment_mb") or defaults.max_attachment_mb),
+            language=raw.get("language") or None,
+            enable_diarization=bool(raw.get("enable_diarization", False)),
+            reply=bool(raw.get("reply", True)),
+            reply_format=str(raw.get("reply_format") or "plaintext"),
+            smtp_host=str(raw.get("smtp_host") or "").strip(),
+            smtp_port=int(raw.get("smtp_port") or defaults.smtp_port),
+            smtp_username=str(raw.get("smtp_username") or "").strip(),
+            starttls=bool(raw.get("starttls", True)),
+            from_address=str(raw.get("from") or "").strip(),
+        )
+        if settings.imap_security not in IMAP_SECURITY_MODES:
+            raise MailError(f"mail.imap_security must be one of {', '.join(IMAP_SECURITY_MODES)}")
+        return settings
+
+
+@dataclass(slots=True)
+class MailAttachment:
+    filename: str
+    data: bytes
+
+
+@dataclass(slots=True)
+class IncomingMail:
+    uid: str
+    sender: str
+    reply_to: str
+    subject: str
+    message_id: str
+    attachments: list[MailAttachment] = field(default_factory=list)
+    # Audio attachments over ``max_attachment_mb``, which were not kept.
+    oversized: list[str] = field(default_factory=list)
+
+
+def _secret(field_name: str) -> str:
+    from server.utils import keychain
+
+    value = keychain.get(f"mail.{field_name}")
+    if not value:
+        raise MailError(f"No '{field_name}' stored in the keychain (key: mail.{field_name})")
+    return value
+
+
+def sender_allowed(address: str, allowed: list[str]) -> bool:
+    """An empty list allows everyone; ``@example.com`` allows a whole domain."""
+    if not allowed:
+        return True
+    address = address.lower()
+    return any(
+        address.endswith(entry) if entry.startswith("@") else address == entry for entry in allowed
+    )
+
+
+def _safe_filename(name: str) -> str:
+    cleaned = re.sub(r"[^\w.\- ]", "_", Path(name).name).strip()
+    return cleaned or "attachment"
+
+
+def parse_message(uid: str, raw: bytes, max_bytes: int | None = None) -> IncomingMail:
+    """Decode one RFC 822 message, keeping only its audio attachments."""
+    msg = message_from_bytes(raw, policy=policy.default)
+    sender = parseaddr(str(msg.get("From") or ""))[1]
+    reply_to = parseaddr(str(msg.get("Reply-To") or ""))[1] or sender
+    mail = IncomingMail(
+        uid=uid,
+        sender=sender,
+        reply_to=reply_to,
+        subject=str(msg.get("Subject") or "").strip(),
+        message_id=str(msg.get("Message-ID") or "").strip(),
+    )
+    for part in msg.walk():
+        if part.is_multipart():
+            continue
+        filename = part.get_filename()
+        is_audio = part.get_content_maintype() == "audio"
+        if not filename and not is_audio:
+            continue
+        name = _safe_filename(filename or f"voicemail.{part.get_content_subtype()}")
+        if not is_audio and Path(name).suffix.lower() not in MAIL_AUDIO_EXTENSIONS:
+            continue
+        data = part.get_payload(decode=True) or b""
+        if not data:
+            continue
+        if max_bytes is not None and len(data) > max_bytes:
+            logger.warning("Mail %s: skipping %s (%d bytes over the limit)", uid, name, len(data))
+            mail.oversized.append(name)
+            continue
+        mail.attachments.append(MailAttachment(filename=name, data=data))
+    return mail
+
+
+def build_reply(mail: IncomingMail, contents: list[Any], settings: MailSettings) -> EmailMessage:
+    """Reply in-thread to ``mail`` with one attachment per transcribed file.
+
+    ``contents`` are :class:`server.core.share.ShareContent` items.
+    """
+    msg = EmailMessage()
+    subject = mail.subject or "Transcript"
+    msg["Subject"] = subject if subject.lower().startswith("re:") else f"Re: {subject}"
+    msg["From"] = settings.from_address or settings.username
+    msg["To"] = mail.reply_to
+    msg["Date"] = formatdate(localtime=True)
+    msg["Message-ID"] = make_msgid(domain="transcriptionsuite.local")
+    if mail.message_id:
+        msg["In-Reply-To"] = mail.message_id
+        msg["References"] = mail.message_id
+    msg["Auto-Submitted"] = "auto-replied"
+    msg.set_content(
+        "\n\n".join(f"{c.title}\n\n{c.summary}".strip() for c in contents) or "(no speech)"
+    )
+    for content in contents:
+        maintype, _, subtype = content.attachment_media_type.split(";")[0].partition("/")
+        msg.add_attachment(
+            content.attachment_bytes,
+            maintype=maintype,
+            subtype=subtype,
+            filename=content.attachment_name,
+        )
+    return msg
+
+
+class MailPoller:
+    """Poll the configured mailbox and transcribe what arrives.
+
+    ``imap_factory`` / ``smtp_factory`` default to :mod:`imaplib` /
+    :mod:`smtplib` and are injectable for tests.
+    """
+
+    def __init__(
+        self,
+        settings: MailSettings,
+        transcribe: Transcriber,
+        *,
+        imap_factory: Callable[[MailSettings], Any] | None = None,
+        smtp_factory: Callable[[MailSettings], Any] | None = None,
+    ) -> None:
+        self.settings = settings
+        self._transcribe = transcribe
+        self._imap_factory = imap_factory or self._open_imap
+        self._smtp_factory = smtp_factory or self._open_smtp
+
+    # -- IMAP ------------------------------------------------------------
+
+    @staticmethod
+    def _open_imap(settings: MailSettings) -> imaplib.IMAP4:
+        if settings.imap_security == "ssl":
+            return imaplib.IMAP4_SSL(settings.imap_host, settings.imap_port, timeout=MAIL_TIMEOUT_S)
+        client = imaplib.IMAP4(settings.imap_host, settings.imap_port, timeout=MAIL_TIMEOUT_S)
+        if settings.imap_security == "starttls":
+            client.starttls()
+        return client
+
+    def _connect(self) -> Any:
+        client = self._imap_factory(self.settings)
+        client.login(self.settings.username, _secret("imap_password"))
+        status, _ = client.select(self.settings.mailbox)
+        if status != "OK":
+            client.logout()
+            raise MailError(f"Cannot open mailbox '{self.settings.mailbox}'")
+        return client
+
+    def fetch_unseen(self) -> list[IncomingMail]:
+        """Unread messages, fetched with BODY.PEEK so they stay unread."""
+        max_bytes = int(self.settings.max_attachment_mb * 1024 * 1024)
+        client = self._connect()
+        try:
+            _, data = client.uid("SEARCH", None, "UNSEEN")
+            uids = (data[0] or b"").split() if data else []
+            messages = []
+            for uid in uids:
+                _, parts = client.uid("FETCH", uid, "(BODY.PEEK[])")
+                raw = next((p[1] for p in parts if isinstance(p, tuple)), None)
+                if raw:
+                    messages.append(parse_message(uid.decode(), raw, max_bytes))
+            return messages
+        finally:
+            client.logout()
+
+    def finish(self, uid: str, *, failed: bool = False) -> None:
+        """Mark a message read (flagged on failure) or move it when configured."""
+        client = self._connect()
+        try:
+            flags = "(\\Seen \\Flagged)" if failed else "(\\Seen)"
+            client.uid("STORE", uid, "+FLAGS", flags)
+            if self.settings.processed_mailbox and not failed:
+                status, _ = client.uid("COPY", uid, self.settings.processed_mailbox)
+                if status == "OK":
+                    client.uid("STORE", uid, "+FLAGS", "(\\Deleted)")
+                    client.expunge()
+                else:
+                    logger.warning(
+                        "Mail %s: could not move to '%s'", uid, self.settings.processed_mailbox
+                    )
+        finally:
+            client.logout()
+
+    # -- SMTP ------------------------------------------------------------
+
+    @staticmethod
+    def _open_smtp(settings: MailSettings) -> smtplib.SMTP:
+        return smtplib.SMTP(settings.smtp_host, settings.smtp_port, timeout=MAIL_TIMEOUT_S)
+
+    def send_reply(self, msg: EmailMessage) -> None:
+        password = _secret("smtp_password") if self.settings.smtp_username else ""
+        with self._smtp_factory(self.settings) as smtp:
+            if self.settings.starttls:
+                smtp.starttls()
+            if self.settings.smtp_username:
+                smtp.login(self.settings.smtp_username, password)
+            smtp.send_message(msg)
+
+    # -- Processing --------------------------------------------------------
+
+    async def _transcribe_mail(self, mail: IncomingMail) -> list[int]:
+        title_base = mail.subject or f"Mail from {mail.sender}"
+        recording_ids = []
+        with tempfile.TemporaryDirectory(prefix="mail-in-") as tmp_dir:
+            for index, attachment in enumerate(mail.attachments, start=1):
+                path = Path(tmp_dir) / attachment.filename
+                path.write_bytes(attachment.data)
+                title = (
+                    title_base
+                    if len(mail.attachments) == 1
+                    else f"{title_base} ({index}/{len(mail.attachments)})"
+                )
+                recording_ids.append(await self._transcribe(path, attachment.filename, title))
+        return recording_ids
+
+    async def _reply(self, mail: IncomingMail, recording_ids: list[int]) -> None:
+        if not self.settings.reply:
+            return
+        if not self.settings.smtp_host:
+            logger.info("Mail %s: transcribed, but no smtp_host is set — not replying", mail.uid)
+            return
+        from server.core.share import build_share_content
+
+        contents = [
+            await asyncio.to_thread(build_share_content, rid, self.settings.reply_format)
+            for rid in recording_ids
+        ]
+        await asyncio.to_thread(self.send_reply, build_reply(mail, contents, self.settings))
+
+    async def poll_once(self) -> int:
+        """Process every unread message; returns how many were transcribed."""
+        messages = await asyncio.to_thread(self.fetch_unseen)
+        processed = 0
+        for mail in messages:
+            if not sender_allowed(mail.sender, self.settings.allowed_senders):
+                logger.info("Mail %s: sender %s not allowed — skipped", mail.uid, mail.sender)
+                await asyncio.to_thread(self.finish, mail.uid)
+                continue
+            if mail.oversized:
+                logger.warning(
+                    "Mail %s: %s over max_attachment_mb — message flagged",
+                    mail.uid,
+                    ", ".join(mail.oversized),
+                )
+            if not mail.attachments:
+                if not mail.oversized:
+                    logger.info("Mail %s: no audio attachments — skipped", mail.uid)
+                await asyncio.to_thread(self.finish, mail.uid, failed=bool(mail.oversized))
+                continue
+            try:
+                recording_ids = await self._transcribe_mail(mail)
+            except asyncio.CancelledError:
+                raise
+            except Exception:
+                logger.exception("Mail %s: transcription failed — message flagged", mail.uid)
+                await asyncio.to_thread(self.finish, mail.uid, failed=True)
+                continue
+            logger.info("Mail %s: saved recording(s) %s", mail.uid, recording_ids)
+            try:
+                await self._reply(mail, recording_ids)
+            except Exception:
+                # The transcripts are already in the notebook; a failed reply
+                # must not make the next poll transcribe them again.
+                logger.exception("Mail %s: reply failed", mail.uid)
+            await asyncio.to_thread(self.finish, mail.uid, failed=bool(mail.oversized))
+            processed += 1
+        return processed
+
+    async def run(self) -> None:
+        """Poll now and then every ``poll_interval_s``; cancel to stop.
+
+        With ``poll_interval_s`` <= 0, polls once and returns.
+        """
+        while True:
+            try:
+                await self.poll_once()
+            except asyncio.CancelledError:
+                logger.info("Mail polling cancelled (shutdown)")
+                return
+            except Exception:
+                logger.exception("Mail poll failed — will retry next interval")
+            if self.settings.poll_interval_s <= 0:
+                return
+            try:
+                await asyncio.sleep(self.settings.poll_interval_s)
+            except asyncio.CancelledError:
+                logger.info("Mail polling cancelled (shutdown)")
+                return
+
+
+def notebook_transcriber(model_manager: Any, settings: MailSettings) -> Transcriber:
+    """Transcribe attachments into the notebook, waiting for the job slot."""
+    from server.api.routes.notebook import NotebookBusyError, transcribe_file_into_notebook
+
+    async def transcribe(path: Path, filename: str, title: str) -> int:
+        while True:
+            try:
+                result = await transcribe_file_into_notebook(
+                    model_manager,
+                    path,
+                    filename=filename,
+                    title=title,
+                    source=MAIL_SOURCE,
+                    language=settings.language,
+                    enable_diarization=settings.enable_diarization,
+                    client_name=MAIL_SOURCE,
+                )
+            except NotebookBusyError:
+                await asyncio.sleep(BUSY_RETRY_S)
+                continue
+            if "recording_id" not in result:
+                raise RuntimeError(result.get("error") or "transcription failed")
+            return int(result["recording_id"])
+
+    return transcribe
@@ -0,0 +1,248 @@
+"""Timeline-marker exports for video editors (EDL / Premiere XML / Resolve CSV).
+
+Editors cutting interviews want to jump to every speaker turn — or every
+mention of a keyword — inside their NLE timeline. This module turns a
+recording into a list of ``Marker`` objects and renders them in three
+interchange formats:
+
+* ``edl``          — CMX3600 EDL with Resolve-style ``|C: |M: |D:`` marker
+                     comments (DaVinci Resolve "Import Timeline Markers from
+                     EDL"; also readable by Premiere / Avid as plain events).
+* ``premiere_xml`` — Final Cut Pro 7 XML (``xmeml``) sequence markers, which
+                     Premiere imports via File → Import.
+* ``resolve_csv``  — Resolve's marker-list CSV layout (Name / Notes / Color /
+                     Start TC / End TC / Duration), handy for spreadsheets.
+
+Marker times are labelled with the recording's timecode settings
+(``server.core.timecode``) so they line up with a timeline that starts at
+``01:00:00:00``. Without settings we default to 25 fps from zero.
+"""
+
+from __future__ import annotations
+
+import csv
+import io
+import re
+from collections.abc import Iterable
+from dataclasses import dataclass
+from typing import Any
+from xml.sax.saxutils import escape
+
+from server.core.timecode import (
+    TimecodeSettings,
+    format_timecode,
+    frames_to_timecode,
+    seconds_to_frames,
+)
+
+MARKER_FORMATS: tuple[str, ...] = ("edl", "premiere_xml", "resolve_csv")
+MARKER_NAME_CHARS = 60
+RESOLVE_COLORS: tuple[str, ...] = ("Blue", "Cyan", "Green", "Yellow", "Red", "Pink", "Purple")
+SEARCH_HIT_COLOR = "Red"
+
+_WORD_RE = re.compile(r"[\w'-]+", re.UNICODE)
+
+
+@dataclass(slots=True)
+class Marker:
+    start: float
+    end: float
+    name: str
+    comment: str = ""
+    color: str = "Blue"
+
+
+def _truncate(text: str, limit: int = MARKER_NAME_CHARS) -> str:
+    text = " ".join(text.split())
+    return text if len(text) <= limit else text[: limit - 1].rstrip() + "…"
+
+
+def markers_from_segments(
+    segments: Iterable[dict[str, Any]],
+    *,
+    label_map: dict[str, str] | None = None,
+) -> list[Marker]:
+    """One marker per speaker turn (or per segment without diarization).
+
+    Consecutive segments from the same speaker merge into a single marker;
+    each speaker gets a stable colour so turns are scannable on the timeline.
+    ``label_map`` maps raw speaker ids to display labels (aliases).
+    """
+    labels = label_map or {}
+    colors: dict[str, str] = {}
+    markers: list[Marker] = []
+    previous: str | None = None
+    for seg in segments:
+        text = str(seg.get("text") or "").strip()
+        if not text:
+            continue
+        raw = str(seg.get("speaker") or "").strip()
+        start = float(seg.get("start_time") or 0.0)
+        end = float(seg.get("end_time") or start)
+        if raw and raw == previous and markers:
+            markers[-1].end = max(markers[-1].end, end)
+            markers[-1].comment = _truncate(f"{markers[-1].comment} {text}", 240)
+            continue
+        previous = raw or None
+        speaker = labels.get(raw, raw)
+        if raw and raw not in colors:
+            colors[raw] = RESOLVE_COLORS[len(colors) % len(RESOLVE_COLORS)]
+        markers.append(
+            Marker(
+                start=start,
+                end=end,
+                name=_truncate(speaker or text),
+                comment=_truncate(text, 240),
+                color=colors.get(raw, "Blue"),
+            )
+        )
+    return markers
+
+
+def markers_from_search(
+    segments: list[dict[str, Any]],
+    words: list[dict[str, Any]],
+    query: str,
+) -> list[Marker]:
+    """One marker per occurrence of ``query`` (case-insensitive).
+
+    Word timestamps give frame-accurate hits; recordings without words fall
+    back to one marker per matching segment. Multi-word queries match
+    consecutive words.
+    """
+    needle = [t.lower() for t in _WORD_RE.findall(query)]
+    if not needle:
+        return []
+    segment_text = {seg.get("id"): str(seg.get("text") or "").strip() for seg in segments}
+    markers: list[Marker] = []
+
+    if words:
+        tokens = [
+            ("".join(_WORD_RE.findall(str(w.get("word") or ""))).lower(), w) for w in words
+        ]
+        for i in range(len(tokens) - len(needle) + 1):
+            if all(tokens[i + k][0] == needle[k] for k in range(len(needle))):
+                first = tokens[i][1]
+                last = tokens[i + len(needle) - 1][1]
+                context = segment_text.get(first.get("segment_id"), "")
+                markers.append(
+                    Marker(
+                        start=float(first.get("start_time") or 0.0),
+                        end=float(last.get("end_time") or first.get("start_time") or 0.0),
+                        name=_truncate(query),
+                        comment=_truncate(context, 240),
+                        color=SEARCH_HIT_COLOR,
+                    )
+                )
+        return markers
+
+    phrase = " ".join(needle)
+    for seg in segments:
+        text = str(seg.get("text") or "")
+        if phrase in " ".join(_WORD_RE.findall(text.lower())):
+            start = float(seg.get("start_time") or 0.0)
+            markers.append(
+                Marker(
+                    start=start,
+                    end=float(seg.get("end_time") or start),
+                    name=_truncate(query),
+                    comment=_truncate(text, 240),
+                    color=SEARCH_HIT_COLOR,
+                )
+            )
+    return markers
+
+
+def _duration_frames(marker: Marker, tc: TimecodeSettings) -> int:
+    return max(1, seconds_to_frames(marker.end, tc) - seconds_to_frames(marker.start, tc))
+
+
+def _edl_safe(text: str) -> str:
+    # EDL is line-oriented and ``|`` delimits Resolve's marker fields.
+    return " ".join(text.replace("|", "/").split())
+
+
+def render_edl(markers: list[Marker], title: str, timecode: TimecodeSettings | None = None) -> str:
+    """CMX3600 EDL — one single-frame event per marker, carrying the marker comment."""
+    tc = timecode or TimecodeSettings()
+    lines = [
+        f"TITLE: {_edl_safe(title) or 'Markers'}",
+        f"FCM: {'DROP FRAME' if tc.drop_frame else 'NON-DROP FRAME'}",
+        "",
+    ]
+    for index, marker in enumerate(markers, start=1):
+        frame = tc.start_frames + seconds_to_frames(marker.start, tc)
+        rec_in = frames_to_timecode(frame, tc)
+        rec_out = frames_to_timecode(frame + 1, tc)
+        lines.append(f"{index:03d}  001      V     C        {rec_in} {rec_out} {rec_in} {rec_out}")
+        lines.append(
+            f" |C:ResolveColor{marker.color} |M:{_edl_safe(marker.name)} "
+            f"|D:{_duration_frames(marker, tc)}"
+        )
+        if marker.comment:
+            lines.append(f"* COMMENT: {_edl_safe(marker.comment)}")
+        lines.append("")
+    return "\r\n".join(lines)
+
+
+def render_premiere_xml(
+    markers: list[Marker],
+    title: str,
+    *,
+    duration_seconds: float = 0.0,
+    timecode: TimecodeSettings | None = None,
+) -> str:
+    """FCP7 ``xmeml`` sequence carrying markers (Premiere File → Import)."""
+    tc = timecode or TimecodeSettings()
+    ntsc = "TRUE" if tc.nominal_fps != tc.fps else "FALSE"
+    rate = f"<rate><timebase>{tc.nominal_fps}</timebase><ntsc>{ntsc}</ntsc></rate>"
+    last_end = max([duration_seconds, *(m.end for m in markers)] or [0.0])
+    parts = [
+        '<?xml version="1.0" encoding="UTF-8"?>',
+        "<!DOCTYPE xmeml>",
+        '<xmeml version="4">',
+        "<sequence>",
+        f"<name>{escape(title)}</name>",
+        f"<duration>{seconds_to_frames(last_end, tc)}</duration>",
+        rate,
+        "<timecode>",
+        rate,
+        f"<string>{tc.start_timecode}</string>",
+        f"<frame>{tc.start_frames}</frame>",
+        f"<displayformat>{'DF' if tc.drop_frame else 'NDF'}</displayformat>",
+        "</timecode>",
+    ]
+    for marker in markers:
+        parts.extend(
+            [
+                "<marker>",
+                f"<name>{escape(marker.name)}</name>",
+                f"<comment>{escape(marker.comment)}</comment>",
+                f"<in>{seconds_to_frames(marker.start, tc)}</in>",
+                f"<out>{seconds_to_frames(marker.end, tc)}</out>",
+                "</marker>",
+            ]
+        )
+    parts.extend(["</sequence>", "</xmeml>"])
+    return "\n".join(parts)
+
+
+def render_resolve_csv(markers: list[Marker], timecode: TimecodeSettings | None = None) -> str:
+    """Resolve marker-list CSV (one row per marker)."""
+    tc = timecode or TimecodeSettings()
+    out = io.StringIO()
+    writer = csv.writer(out, lineterminator="\r\n")
+    writer.writerow(["Name", "Notes", "Color", "Start TC", "End TC", "Duration"])
+    for marker in markers:
+        frames = _duration_frames(marker, tc)
+        writer.writerow(
+            [
+                marker.name,
+                marker.comment,
+                marker.color,
+                format_timecode(marker.start, tc),
+                format_timecode(marker.end, tc),
+                frames,
+            ]
+        )
+    return out.getvalue()
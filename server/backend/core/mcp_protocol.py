@@ -0,0 +1,202 @@
+"""Model Context Protocol (MCP) server core.
+
+Transport-agnostic JSON-RPC 2.0 handling for the subset of MCP a tool
+server needs: ``initialize``, ``ping``, ``tools/list`` and ``tools/call``.
+The HTTP transport lives in ``server.api.routes.mcp`` and the stdio bridge
+in ``server.core.mcp_stdio``; both hand parsed messages to
+:meth:`McpServer.handle`.
+
+Tool failures are reported the way MCP asks — as a result with
+``isError: true`` the model can read and react to — while malformed
+requests, unknown methods and unknown tools are JSON-RPC errors.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+from collections.abc import Awaitable, Callable
+from dataclasses import dataclass
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+# Newest first; the first one is offered when a client asks for a version
+# this server does not know.
+SUPPORTED_PROTOCOL_VERSIONS = ("2025-06-18", "2025-03-26", "2024-11-05")
+SERVER_NAME = "transcriptionsuite"
+
+PARSE_ERROR = -32700
+INVALID_REQUEST = -32600
+METHOD_NOT_FOUND = -32601
+INVALID_PARAMS = -32602
+INTERNAL_ERROR = -32603
+
+
+class ToolError(Exception):
+    """A tool could not do what was asked; the message is shown to the model."""
+
+
+@dataclass(frozen=True)
+class McpTool:
+    name: str
+    description: str
+    input_schema: dict[str, Any]
+    handler: Callable[[dict[str, Any]], Awaitable[Any]]
+    """Returns text, or anything JSON-serialisable (sent as structured content too)."""
+
+
+def jsonrpc_error(request_id: Any, code: int, message: str) -> dict[str, Any]:
+    return {"jsonrpc": "2.0", "id": request_id, "error": {"code": code, "message": message}}
+
+
+def _tool_result(value: Any) -> dict[str, Any]:
+    if isinstance(value, str):
+        return {"content": [{"type": "text", "text": value}], "isError": False}
+    result: dict[str, Any] = {
+        "content": [{"type": "text", "text": json.dumps(value, indent=2, default=str)}],
+        "isError": False,
+    }
+    if isinstance(value, dict):
+        result["structuredContent"] = value
+    return result
+
+
+class McpServer:
+    """Dispatches MCP messages to a fixed set of tools."""
+
+    def __init__(
+        self, tools: list[McpTool], *, version: str, instructions: str | None = None
+    ) -> None:
+        self._tools = {tool.name: tool for tool in tools}
+        self._version = version
+        self._instructions = instructions
+
+    async def handle(self, message: Any) -> dict[str, Any] | list[dict[str, Any]] | None:
+        """Answer one message or a batch; None when nothing needs a reply (notifications)."""
+        if isinstance(message, list):
+            if not message:
+                return jsonrpc_error(None, INVALID_REQUEST, "Empty batch")
+            replies = [reply for m in message if (reply := await self._handle_one(m)) is not None]
+            return replies or None
+        return await self._handle_one(message)
+
+    async def _handle_one(self, message: Any) -> dict[str, Any] | None:
+        if (
+            not isinstance(message, dict)
+            or message.get("jsonrpc") != "2.0"
+            or not isinstance(message.get("method"), str)
+        ):
+            # Responses from the client (we never send requests) are ignored.
+            if isinstance(message, dict) and ("result" in message or "error" in message):
+                return None
+            request_id = message.get("id") if isinstance(message, dict) else None
+            return jsonrpc_error(request_id, INVALID_REQUEST, "Invalid JSON-RPC request")
+
+        method: str = message["method"]
+        params = message.get("params") or {}
+        if "id" not in message:
+            # Notifications (notifications/initialized, cancelled, …) need no reply.
+            return None
+        request_id = message["id"]
+        if not isinstance(params, dict):
+            return jsonrpc_error(request_id, INVALID_PARAMS, "params must be an object")
+
+        if method == "initialize":
+            result: dict[str, Any] = self._initialize(params)
+        elif method == "ping":
+            result = {}
+        elif method == "tools/list":
+            result = {"tools": [self._describe(tool) for tool in self._tools.values()]}
+        elif method == "tools/call":
+            name = params.get("name")
+            tool = self._tools.get(name) if isinstance(name, str) else None
+            if tool is None:
+                return jsonrpc_error(request_id, INVALID_PARAMS, f"Unknown tool: {name}")
+            arguments = params.get("arguments") or {}
+            if not isinstance(arguments, dict):
+                return jsonrpc_error(request_id, INVALID_PARAMS, "arguments must be an object")
+            result = await self._call(tool, arguments)
+        else:
+            return jsonrpc_error(request_id, METHOD_NOT_FOUND, f"Method not found: {method}")
+        return {"jsonrpc": "2.0", "id": request_id, "result": result}
+
+    def _initialize(self, params: dict[str, Any]) -> dict[str, Any]:
+        requested = params.get("protocolVersion")
+        version = (
+            requested
+            if requested in SUPPORTED_PROTOCOL_VERSIONS
+            else SUPPORTED_PROTOCOL_VERSIONS[0]
+        )
+        result: dict[str, Any] = {
+            "protocolVersion": version,
+            "capabilities": {"tools": {"listChanged": False}},
+            "serverInfo": {"name": SERVER_NAME, "version": self._version},
+        }
+        if self._instructions:
+            result["instructions"] = self._instructions
+        return result
+
+    @staticmethod
+    def _describe(tool: McpTool) -> dict[str, Any]:
+        return {
+            "name": tool.name,
+            "description": tool.description,
+            "inputSchema": tool.input_schema,
+        }
+
+    @staticmethod
+    async def _call(tool: McpTool, arguments: dict[str, Any]) -> dict[str, Any]:
+        try:
+            return _tool_result(await tool.handler(arguments))
+        except ToolError as exc:
+            return {"content": [{"type": "text", "text": str(exc)}], "isError": True}
+        except Exception:
+            logger.exception("MCP tool %s failed", tool.name)
+            message = f"{tool.name} failed; see the server log."
+            return {"content": [{"type": "text", "text": message}], "isError": True}
+
+
+# ── Argument helpers for tool handlers ─────────────────────────────────────
+
+
+def int_arg(
+    arguments: dict[str, Any],
+    key: str,
+    *,
+    default: int | None = None,
+    minimum: int | None = None,
+    maximum: int | None = None,
+) -> int | None:
+    """An integer argument, clamped to [minimum, maximum]; ToolError when not a number."""
+    value = arguments.get(key, default)
+    if value is None:
+        return None
+    if isinstance(value, bool) or not isinstance(value, (int, float)):
+        raise ToolError(f'"{key}" must be a number')
+    result = int(value)
+    if minimum is not None:
+        result = max(minimum, result)
+    if maximum is not None:
+        result = min(maximum, result)
+    return result
+
+
+def float_arg(arguments: dict[str, Any], key: str) -> float | None:
+    value = arguments.get(key)
+    if value is None:
+        return None
+    if isinstance(value, bool) or not isinstance(value, (int, float)):
+        raise ToolError(f'"{key}" must be a number of seconds')
+    return float(value)
+
+
+def str_arg(arguments: dict[str, Any], key: str, *, required: bool = False) -> str | None:
+    value = arguments.get(key)
+    if value is None or (isinstance(value, str) and not value.strip()):
+        if required:
+            raise ToolError(f'"{key}" is required')
+        return None
+    if not isinstance(value, str):
+        raise ToolError(f'"{key}" must be a string')
+    return value.strip()
@@ -0,0 +1,112 @@
+"""stdio bridge to the server's MCP endpoint.
+
+MCP clients that launch their tool servers as subprocesses (Claude Desktop
+and most IDE agents) speak newline-delimited JSON-RPC over stdin/stdout.
+This relays each message to ``POST /api/mcp`` and writes the reply back,
+so the library stays behind the server's own auth and nothing but the
+bridge runs in the client. Standard library only, so it also runs with a
+plain ``python3`` outside the server's environment::
+
+    docker exec -i transcriptionsuite-container transcription-mcp
+    python3 -m server.core.mcp_stdio --url https://host:9786 --token <token>
+
+The token can also come from ``TRANSCRIPTIONSUITE_TOKEN``. Logs go to
+stderr; stdout carries protocol messages only.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import os
+import ssl
+import sys
+import urllib.error
+import urllib.request
+from typing import Any
+
+DEFAULT_URL = "http://127.0.0.1:9786"
+TIMEOUT_S = 600.0  # summarize_range waits on the LLM
+PARSE_ERROR = -32700
+INTERNAL_ERROR = -32603
+
+
+def _error(request_id: Any, code: int, message: str) -> dict[str, Any]:
+    return {"jsonrpc": "2.0", "id": request_id, "error": {"code": code, "message": message}}
+
+
+def _request_ids(message: Any) -> list[Any]:
+    """Ids of the requests in a message or batch (notifications have none)."""
+    items = message if isinstance(message, list) else [message]
+    return [m["id"] for m in items if isinstance(m, dict) and "id" in m and "method" in m]
+
+
+class Bridge:
+    def __init__(self, url: str, token: str | None, *, insecure: bool = False) -> None:
+        self.endpoint = url.rstrip("/") + "/api/mcp"
+        self.token = token
+        self.context: ssl.SSLContext | None = None
+        if insecure:
+            self.context = ssl.create_default_context()
+            self.context.check_hostname = False
+            self.context.verify_mode = ssl.CERT_NONE
+
+    def relay(self, line: str) -> list[dict[str, Any]] | dict[str, Any] | None:
+        """Forward one line; the reply to print, or None when there is none."""
+        try:
+            message = json.loads(line)
+        except ValueError:
+            return _error(None, PARSE_ERROR, "Parse error")
+        headers = {"Content-Type": "application/json", "Accept": "application/json"}
+        if self.token:
+            headers["Authorization"] = f"Bearer {self.token}"
+        request = urllib.request.Request(
+            self.endpoint, data=line.encode("utf-8"), headers=headers, method="POST"
+        )
+        try:
+            with urllib.request.urlopen(request, timeout=TIMEOUT_S, context=self.context) as resp:
+                body = resp.read()
+                return json.loads(body) if body else None
+        except urllib.error.HTTPError as exc:
+            problem = self._describe_http_error(exc)
+        except (urllib.error.URLError, OSError, ValueError) as exc:
+            problem = f"Cannot reach the TranscriptionSuite server at {self.endpoint}: {exc}"
+        print(f"[mcp-bridge] {problem}", file=sys.stderr)
+        errors = [_error(i, INTERNAL_ERROR, problem) for i in _request_ids(message)]
+        if not errors:
+            return None
+        return errors if isinstance(message, list) else errors[0]
+
+    @staticmethod
+    def _describe_http_error(exc: urllib.error.HTTPError) -> str:
+        if exc.code == 401:
+            return "The server needs a token (--token or TRANSCRIPTIONSUITE_TOKEN)"
+        if exc.code == 404:
+            return "MCP is disabled on the server (mcp.enabled in config.yaml)"
+        return f"The server answered HTTP {exc.code}"
+
+
+def main(argv: list[str] | None = None) -> int:
+    parser = argparse.ArgumentParser(description=__doc__.split("\n\n")[0])
+    parser.add_argument("--url", default=os.environ.get("TRANSCRIPTIONSUITE_URL", DEFAULT_URL))
+    parser.add_argument("--token", default=os.environ.get("TRANSCRIPTIONSUITE_TOKEN"))
+    parser.add_argument(
+        "--insecure",
+        action="store_true",
+        help="skip TLS certificate checks (self-signed certificates on a LAN)",
+    )
+    args = parser.parse_args(argv)
+
+    bridge = Bridge(args.url, args.token, insecure=args.insecure)
+    for line in sys.stdin:
+        if not line.strip():
+            continue
+        reply = bridge.relay(line)
+        if reply is not None:
+            sys.stdout.write(json.dumps(reply) + "\n")
+            sys.stdout.flush()
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
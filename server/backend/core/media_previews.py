@@ -0,0 +1,292 @@
+"""
+Media info and preview images for the notebook library cards.
+
+Each stored recording gets a small cache entry: duration, container and
+codec details from ffprobe, plus a preview image — a frame from the middle
+of a video, or a spectrogram of audio. A pool of worker threads fills the
+entries in the background, so imports and the library view never wait on
+ffmpeg; the dashboard polls until an entry is ready.
+
+Notebook audio is stored as MP3, so a video's picture and original codecs
+survive only in the upload. An import therefore hands over a hard link of
+the upload (``submit(..., original=...)``), which the worker deletes when it
+is done; without one — and for recordings imported before this existed —
+the stored audio is used.
+
+Entries live under ``<data dir>/previews/<key>/`` where the key is derived
+from the stored audio path. Preview images are encrypted at rest like the
+audio they were made from.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+import logging
+import os
+import shutil
+import subprocess
+import threading
+from concurrent.futures import Future, ThreadPoolExecutor
+from pathlib import Path
+from typing import Any
+
+from server.core.storage_encryption import call_with_plaintext, encrypt_at_rest, read_plaintext
+
+logger = logging.getLogger(__name__)
+
+MAX_WORKERS = 2
+PREVIEW_WIDTH = 320
+SPECTROGRAM_SIZE = "320x96"
+PROBE_TIMEOUT_SECONDS = 30
+# A spectrogram decodes the whole file; hours of audio take a while.
+RENDER_TIMEOUT_SECONDS = 300
+
+_META = "meta.json"
+_PREVIEWS = {
+    "thumbnail": ("preview.jpg", "image/jpeg"),
+    "spectrogram": ("preview.png", "image/png"),
+}
+
+
+def _number(value: Any) -> float | None:
+    try:
+        return float(value)
+    except (TypeError, ValueError):
+        return None
+
+
+def _frame_rate(value: Any) -> float | None:
+    num, _, den = str(value or "").partition("/")
+    rate = _number(num)
+    if rate is None:
+        return None
+    divisor = _number(den) if den else 1.0
+    return round(rate / divisor, 3) if divisor else None
+
+
+def summarize_probe(probe: dict[str, Any]) -> dict[str, Any]:
+    """The card-sized summary of ``ffprobe -show_format -show_streams`` JSON."""
+    fmt = probe.get("format", {})
+    streams = probe.get("streams", [])
+    audio = next((s for s in streams if s.get("codec_type") == "audio"), None)
+    # Cover art is a video stream too; it is not what the recording is.
+    video = next(
+        (
+            s
+            for s in streams
+            if s.get("codec_type") == "video"
+            and not s.get("disposition", {}).get("attached_pic")
+        ),
+        None,
+    )
+    summary: dict[str, Any] = {
+        "duration_seconds": _number(fmt.get("duration")),
+        "container": (fmt.get("format_name") or "").split(",")[0] or None,
+        "size_bytes": int(_number(fmt.get("size")) or 0) or None,
+        "bit_rate": int(_number(fmt.get("bit_rate")) or 0) or None,
+        "audio": None,
+        "video": None,
+    }
+    if audio:
+        summary["audio"] = {
+            "codec": audio.get("codec_name"),
+            "sample_rate": int(_number(audio.get("sample_rate")) or 0) or None,
+            "channels": audio.get("channels"),
+        }
+    if video:
+        summary["video"] = {
+            "codec": video.get("codec_name"),
+            "width": video.get("width"),
+            "height": video.get("height"),
+            "frame_rate": _frame_rate(video.get("avg_frame_rate")),
+        }
+    return summary
+
+
+def probe_media(path: Path) -> dict[str, Any]:
+    result = subprocess.run(
+        [
+            "ffprobe",
+            "-v",
+            "error",
+            "-print_format",
+            "json",
+            "-show_format",
+            "-show_streams",
+            str(path),
+        ],
+        capture_output=True,
+        text=True,
+        check=True,
+        timeout=PROBE_TIMEOUT_SECONDS,
+    )
+    return summarize_probe(json.loads(result.stdout))
+
+
+def render_thumbnail(source: Path, dest: Path, at_seconds: float) -> None:
+    subprocess.run(
+        [
+            "ffmpeg",
+            "-v",
+            "error",
+            "-y",
+            "-ss",
+            f"{at_seconds:.3f}",
+            "-i",
+            str(source),
+            "-frames:v",
+            "1",
+            "-vf",
+            f"scale={PREVIEW_WIDTH}:-2",
+            "-q:v",
+            "4",
+            str(dest),
+        ],
+        capture_output=True,
+        check=True,
+        timeout=RENDER_TIMEOUT_SECONDS,
+    )
+
+
+def render_spectrogram(source: Path, dest: Path) -> None:
+    subprocess.run(
+        [
+            "ffmpeg",
+            "-v",
+            "error",
+            "-y",
+            "-i",
+            str(source),
+            "-lavfi",
+            f"showspectrumpic=s={SPECTROGRAM_SIZE}:legend=0:scale=log",
+            "-frames:v",
+            "1",
+            str(dest),
+        ],
+        capture_output=True,
+        check=True,
+        timeout=RENDER_TIMEOUT_SECONDS,
+    )
+
+
+def _extract(source: Path, entry: Path) -> dict[str, Any]:
+    """Probe ``source`` and render its preview into ``entry``; returns the metadata."""
+    meta = probe_media(source)
+    meta["preview"] = None
+    try:
+        if meta["video"]:
+            dest = entry / _PREVIEWS["thumbnail"][0]
+            render_thumbnail(source, dest, (meta["duration_seconds"] or 0) / 2)
+            meta["preview"] = "thumbnail"
+        elif meta["audio"]:
+            dest = entry / _PREVIEWS["spectrogram"][0]
+            render_spectrogram(source, dest)
+            meta["preview"] = "spectrogram"
+    except (OSError, subprocess.SubprocessError) as e:
+        # Metadata alone still makes a useful card.
+        logger.warning("Preview render failed for %s: %s", source, e)
+    if meta["preview"]:
+        encrypt_at_rest(entry / _PREVIEWS[meta["preview"]][0])
+    return meta
+
+
+class MediaPreviews:
+    """Cache of per-recording media info and preview images, filled by a worker pool."""
+
+    def __init__(self, directory: Path, workers: int = MAX_WORKERS) -> None:
+        self._dir = directory
+        self._pool = ThreadPoolExecutor(max_workers=workers, thread_name_prefix="media-preview")
+        self._pending: dict[str, Future[None]] = {}
+        self._lock = threading.Lock()
+
+    def _entry(self, media_path: Path | str) -> Path:
+        key = hashlib.sha256(str(Path(media_path)).encode()).hexdigest()[:24]
+        return self._dir / key
+
+    def get(self, media_path: Path | str) -> dict[str, Any] | None:
+        """The cached metadata (``{"error": ...}`` when extraction failed), or None."""
+        try:
+            return json.loads((self._entry(media_path) / _META).read_text())
+        except (OSError, ValueError):
+            return None
+
+    def is_pending(self, media_path: Path | str) -> bool:
+        with self._lock:
+            return self._entry(media_path).name in self._pending
+
+    def preview(self, media_path: Path | str) -> tuple[bytes, str] | None:
+        """The preview image and its media type, if the entry has one."""
+        meta = self.get(media_path)
+        if not meta or meta.get("preview") not in _PREVIEWS:
+            return None
+        name, media_type = _PREVIEWS[meta["preview"]]
+        try:
+            return read_plaintext(self._entry(media_path) / name), media_type
+        except OSError:
+            return None
+
+    def submit(self, media_path: Path | str, original: Path | None = None) -> Future[None] | None:
+        """
+        Queue extraction for a stored recording unless it is cached or queued.
+        ``original`` is a file the worker owns and deletes: a link to the upload.
+        """
+        entry = self._entry(media_path)
+        with self._lock:
+            if entry.name in self._pending or (entry / _META).exists():
+                if original is not None:
+                    original.unlink(missing_ok=True)
+                return None
+            future = self._pool.submit(self._run, Path(media_path), entry, original)
+            self._pending[entry.name] = future
+            return future
+
+    def discard(self, media_path: Path | str) -> None:
+        """Drop a recording's entry (the recording was deleted)."""
+        shutil.rmtree(self._entry(media_path), ignore_errors=True)
+
+    def _run(self, media_path: Path, entry: Path, original: Path | None) -> None:
+        try:
+            entry.mkdir(parents=True, exist_ok=True)
+            try:
+                if original is not None:
+                    meta = _extract(original, entry)
+                else:
+                    meta = call_with_plaintext(_extract, media_path, entry)
+            except Exception as e:
+                logger.warning("Media info extraction failed for %s: %s", media_path, e)
+                meta = {"error": str(e) or type(e).__name__}
+            tmp = entry / f"{_META}.tmp"
+            tmp.write_text(json.dumps(meta))
+            tmp.replace(entry / _META)
+        finally:
+            if original is not None:
+                original.unlink(missing_ok=True)
+            with self._lock:
+                self._pending.pop(entry.name, None)
+
+
+def link_for_preview(upload: Path) -> Path | None:
+    """
+    A hard link to ``upload`` next to it, for ``MediaPreviews.submit`` to own
+    after the caller deletes the upload. None when the filesystem refuses.
+    """
+    link = upload.with_name(f"{upload.name}.preview{upload.suffix}")
+    try:
+        os.link(upload, link)
+    except OSError:
+        return None
+    return link
+
+
+_previews: MediaPreviews | None = None
+
+
+def get_media_previews() -> MediaPreviews:
+    """Get or create the global preview cache."""
+    global _previews
+    if _previews is None:
+        from server.database.database import get_data_dir
+
+        _previews = MediaPreviews(get_data_dir() / "previews")
+    return _previews
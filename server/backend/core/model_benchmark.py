@@ -0,0 +1,140 @@
+"""
+Model benchmark: speed and accuracy of transcription models on one clip.
+
+The client uploads a reference clip together with its correct transcript
+and names the models to compare. Each model transcribes the clip once
+untimed (so loading and first-inference warm-up do not count against it)
+and once timed, and is reported with:
+
+- realtime factor — processing seconds per second of audio; lower is
+  faster, 1.0 keeps up with real time
+- word error rate — word substitutions, insertions and deletions needed to
+  turn the hypothesis into the reference, over the reference's word count
+
+Comparing servers is the client's side: it runs the same clip against each
+connection profile and keeps the results to pick where, and with which
+model, jobs go by default (dashboard/src/services/benchmark.ts).
+"""
+
+from __future__ import annotations
+
+import logging
+import re
+import time
+from collections.abc import Callable, Sequence
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+MAX_MODELS = 8
+
+# Letters and digits, keeping in-word apostrophes ("don't") together.
+_WORD_RE = re.compile(r"\w+(?:'\w+)*")
+
+
+def normalize_words(text: str) -> list[str]:
+    """Case-folded words without punctuation, the unit WER is counted in."""
+    return _WORD_RE.findall(text.casefold().replace("’", "'"))
+
+
+def word_error_rate(reference: str, hypothesis: str) -> float:
+    """Word-level edit distance between the texts over the reference length.
+
+    Can exceed 1.0 when the hypothesis adds many words. An empty reference
+    scores 0.0 against an empty hypothesis and 1.0 against anything else.
+    """
+    ref = normalize_words(reference)
+    hyp = normalize_words(hypothesis)
+    if not ref:
+        return 0.0 if not hyp else 1.0
+    previous = list(range(len(hyp) + 1))
+    for i, ref_word in enumerate(ref, start=1):
+        current = [i]
+        for j, hyp_word in enumerate(hyp, start=1):
+            current.append(
+                min(
+                    previous[j] + 1,  # deletion
+                    current[j - 1] + 1,  # insertion
+                    previous[j - 1] + (ref_word != hyp_word),  # substitution
+                )
+            )
+        previous = current
+    return previous[-1] / len(ref)
+
+
+@dataclass
+class BenchmarkSample:
+    """The clip, its correct transcript and its length in seconds."""
+
+    audio_path: Path
+    reference: str
+    duration: float
+
+
+@dataclass
+class BenchmarkRun:
+    """One model's result; ``error`` is set (and the figures None) when it failed."""
+
+    model: str
+    seconds: float | None = None
+    realtime_factor: float | None = None
+    wer: float | None = None
+    text: str = ""
+    error: str | None = None
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "model": self.model,
+            "seconds": self.seconds,
+            "realtime_factor": self.realtime_factor,
+            "wer": self.wer,
+            "text": self.text,
+            "error": self.error,
+        }
+
+
+def run_benchmark(
+    sample: BenchmarkSample,
+    models: Sequence[str],
+    *,
+    transcribe: Callable[[str, Path], str],
+    clock: Callable[[], float] = time.perf_counter,
+) -> list[BenchmarkRun]:
+    """Transcribe ``sample`` with each model in turn.
+
+    ``transcribe(model, path)`` returns the transcript text and is
+    responsible for loading the model. A model that fails is reported with
+    its error and the next one still runs.
+    """
+    if sample.duration <= 0:
+        raise ValueError("the reference clip has no duration")
+    runs: list[BenchmarkRun] = []
+    for model in models:
+        try:
+            transcribe(model, sample.audio_path)  # load + warm up, untimed
+            started = clock()
+            text = transcribe(model, sample.audio_path)
+            seconds = clock() - started
+        except Exception as e:
+            logger.warning("Benchmark of %s failed: %s", model, e)
+            runs.append(BenchmarkRun(model=model, error=str(e) or type(e).__name__))
+            continue
+        run = BenchmarkRun(
+            model=model,
+            seconds=round(seconds, 3),
+            realtime_factor=round(seconds / sample.duration, 4),
+            wer=round(word_error_rate(sample.reference, text), 4),
+            text=text,
+        )
+        logger.info(
+            "Benchmark %s: %.2fs for %.1fs of audio (RTF %.3f), WER %.3f",
+            model,
+            seconds,
+            sample.duration,
+            run.realtime_factor,
+            run.wer,
+        )
+        runs.append(run)
+    return runs
@@ -22,6 +22,7 @@ from collections.abc import Callable
 from typing import TYPE_CHECKING, Any
 
 from server.config import resolve_main_transcriber_model
+from server.core.job_queue import JobQueue
 
 # Type-only imports for hints (no runtime cost)
 if TYPE_CHECKING:
@@ -59,6 +60,9 @@ class TranscriptionJobTracker:
     Ensures only one transcription job runs at a time across the entire server.
     Thread-safe for concurrent access from multiple request handlers.
     Supports cancellation of running jobs.
+
+    Clients waiting for the slot can join a queue (``server.core.job_queue``);
+    while anyone is queued, only the head entry's job may start.
     """
 
     def __init__(self):
@@ -68,24 +72,39 @@ class TranscriptionJobTracker:
         self._progress: dict[str, Any] | None = None
         self._started_at: float | None = None
         self._result: dict[str, Any] | None = None
+        self._queue = JobQueue()
+        # Moving averages over finished jobs, used for queue start estimates
+        self._avg_job_seconds: float | None = None
+        self._seconds_per_audio_second: float | None = None
         self._lock = threading.Lock()
 
-    def try_start_job(self, user: str) -> tuple[bool, str | None, str | None]:
+    def try_start_job(
+        self, user: str, queue_entry_id: str | None = None
+    ) -> tuple[bool, str | None, str | None]:
         """
         Attempt to start a new transcription job.
 
         Args:
             user: Client name/identifier for the user starting the job
+            queue_entry_id: The caller's queue entry, when it waited in the queue
 
         Returns:
             Tuple of (success, job_id_if_success, active_user_if_busy)
             - If success: (True, job_id, None)
-            - If busy: (False, None, active_user)
+            - If busy: (False, None, active_user) — or the user at the head
+              of the queue when the slot is free but someone else is next
         """
         with self._lock:
             if self._active_job_id is not None:
                 return (False, None, self._active_user)
 
+            self._queue.prune()
+            head = self._queue.head()
+            if head is not None and head.id != queue_entry_id:
+                return (False, None, head.user)
+            if head is not None:
+                self._queue.pop(head.id)
+
             job_id = str(uuid.uuid4())
             self._active_job_id = job_id
             self._active_user = user
@@ -111,6 +130,8 @@ class TranscriptionJobTracker:
         with self._lock:
             if self._active_job_id == job_id:
                 logger.info(f"Ended transcription job {job_id[:8]} for user '{self._active_user}'")
+                if not (result or {}).get("error") and not self._cancelled:
+                    self._record_duration()
                 self._active_job_id = None
                 self._active_user = None
                 self._cancelled = False
@@ -202,8 +223,101 @@ class TranscriptionJobTracker:
                 "progress": self._progress,
                 "started_at": self._started_at,
                 "result": self._result,
+                "queue_length": len(self._queue),
             }
 
+    # ── Queue ──────────────────────────────────────────────────────────────
+
+    def join_queue(self, user: str, label: str, duration_seconds: float | None = None) -> str:
+        """Queue *user* for the slot; returns the entry id to start the job with."""
+        with self._lock:
+            self._queue.prune()
+            entry = self._queue.join(user, label, duration_seconds)
+            logger.info(f"Queued job {entry.id[:8]} for user '{user}' at #{len(self._queue)}")
+            return entry.id
+
+    def leave_queue(self, entry_id: str, user: str) -> None:
+        """Remove one of *user*'s entries (``QueueEntryNotFound`` otherwise)."""
+        with self._lock:
+            self._queue.leave(entry_id, user)
+
+    def reorder_queue(self, user: str, entry_ids: list[str]) -> None:
+        """Reorder *user*'s own entries among the places they hold."""
+        with self._lock:
+            self._queue.prune()
+            self._queue.reorder(user, entry_ids)
+
+    def get_queue(self, user: str, view_all: bool = False) -> dict[str, Any]:
+        """Snapshot of the running job and the queue as seen by *user*.
+
+        Other users' labels are hidden unless *view_all* (admins). Also
+        keeps *user*'s entries alive. Start estimates come from the
+        running job's progress and the averages over finished jobs; they are
+        None until the server has finished a job to learn from.
+        """
+        now = time.time()
+        with self._lock:
+            self._queue.touch(user, now)
+            self._queue.prune(now)
+            wait = self._remaining_active_seconds(now)
+            active = None
+            if self._active_job_id is not None:
+                active = {
+                    "user": self._active_user,
+                    "mine": self._active_user == user,
+                    "started_at": self._started_at,
+                    "progress": self._progress,
+                    "estimated_end_at": None if wait is None else now + wait,
+                }
+            entries = []
+            for position, entry in enumerate(self._queue.entries()):
+                entries.append(
+                    {
+                        "id": entry.id if entry.user == user else None,
+                        "position": position,
+                        "user": entry.user,
+                        "mine": entry.user == user,
+                        "label": entry.label if view_all or entry.user == user else None,
+                        "duration_seconds": entry.duration_seconds,
+                        "enqueued_at": entry.enqueued_at,
+                        "estimated_start_at": None if wait is None else now + wait,
+                    }
+                )
+                entry_seconds = self._estimate_job_seconds(entry.duration_seconds)
+                wait = None if wait is None or entry_seconds is None else wait + entry_seconds
+            return {"now": now, "active": active, "entries": entries}
+
+    def _estimate_job_seconds(self, duration_seconds: float | None) -> float | None:
+        if duration_seconds and self._seconds_per_audio_second is not None:
+            return duration_seconds * self._seconds_per_audio_second
+        return self._avg_job_seconds
+
+    def _remaining_active_seconds(self, now: float) -> float | None:
+        """Seconds until the running job frees the slot (0 when idle)."""
+        if self._active_job_id is None or self._started_at is None:
+            return 0.0
+        elapsed = now - self._started_at
+        progress = self._progress or {}
+        current, total = progress.get("current") or 0, progress.get("total") or 0
+        if 0 < current < total:
+            return elapsed * (total - current) / current
+        estimate = self._estimate_job_seconds(total or None)
+        return None if estimate is None else max(estimate - elapsed, 0.0)
+
+    def _record_duration(self) -> None:
+        """Fold the ending job into the averages (caller holds the lock)."""
+        if self._started_at is None:
+            return
+        elapsed = time.time() - self._started_at
+
+        def ema(prev: float | None, value: float) -> float:
+            return value if prev is None else prev * 0.7 + value * 0.3
+
+        self._avg_job_seconds = ema(self._avg_job_seconds, elapsed)
+        total = (self._progress or {}).get("total") or 0
+        if total > 0:
+            self._seconds_per_audio_second = ema(self._seconds_per_audio_second, elapsed / total)
+
 
 class ModelManager:
     """
@@ -230,6 +344,9 @@ class ModelManager:
 
         self.config = config
         self._transcription_engine: AudioToTextRecorder | None = None
+        # A second Whisper model borrowed for per-segment re-transcription;
+        # only ever loaded while the main model is unloaded.
+        self._alternate_engine: AudioToTextRecorder | None = None
         # Serializes ensure_transcription_loaded() so concurrent routes can't
         # both trigger load_transcription_model() on the same detached state
         # and double-allocate GPU memory (Issue #76).
@@ -718,8 +835,14 @@ class ModelManager:
             self._transcription_engine = self._create_transcription_engine()
         return self._transcription_engine
 
-    def _create_transcription_engine(self) -> "AudioToTextRecorder":
-        """Create the unified transcription engine from config."""
+    def _create_transcription_engine(
+        self, model_override: str | None = None
+    ) -> "AudioToTextRecorder":
+        """Create the unified transcription engine from config.
+
+        ``model_override`` swaps the model but keeps every other main
+        transcriber setting (used by :meth:`alternate_transcription_engine`).
+        """
         # Wait for background NeMo import to complete if it's running
         if self._nemo_import_thread is not None and self._nemo_import_thread.is_alive():
             logger.info("Waiting for background NeMo import to complete...")
@@ -731,7 +854,7 @@ class ModelManager:
 
         main_cfg = self.config.get("main_transcriber", {})
         trans_opts = self.config.get("longform_recording", {})
-        resolved_main_model = resolve_main_transcriber_model(self.config)
+        resolved_main_model = model_override or resolve_main_transcriber_model(self.config)
         if not resolved_main_model.strip():
             raise RuntimeError(
                 "Main transcriber model is disabled; select a model before recording."
@@ -741,7 +864,7 @@ class ModelManager:
         effective_batch_size = self._scale_batch_size(configured_batch_size)
 
         return AudioToTextRecorder(
-            instance_name="file_transcriber",
+            instance_name="segment_retranscriber" if model_override else "file_transcriber",
             model=resolved_main_model,
             device=main_cfg.get("device", "cuda"),
             compute_type=main_cfg.get("compute_type", "default"),
@@ -771,6 +894,7 @@ class ModelManager:
             if progress_callback:
                 progress_callback(msg)
 
+        self.release_alternate_engine()
         engine = self.transcription_engine
         if not engine.is_loaded():
             report("Loading transcription model...")
@@ -833,6 +957,48 @@ class ModelManager:
                 )
             return engine
 
+    def alternate_transcription_engine(self, model_name: str) -> "AudioToTextRecorder":
+        """Return a loaded engine for ``model_name`` (e.g. a bigger Whisper).
+
+        Keeps the one-model-at-a-time rule: the main model is unloaded first
+        and reloads lazily on the next job (``load_transcription_model``
+        releases the alternate). The alternate stays loaded between calls so
+        re-transcribing several segments in a row loads it once. Callers must
+        hold a job slot.
+        """
+        from server.core.audio_utils import clear_gpu_cache
+
+        with self._transcription_load_lock:
+            current = self._alternate_engine
+            if current is not None and self.is_same_model(current.model_name, model_name):
+                if current.is_loaded():
+                    return current
+            self.release_alternate_engine()
+            self.unload_transcription_model()
+            clear_gpu_cache()
+            engine = self._create_transcription_engine(model_override=model_name)
+            from server.core.download_progress import track_model_download
+
+            logger.info("Loading alternate transcription model %s", model_name)
+            with track_model_download(engine.model_name):
+                engine.load_model()
+            self._alternate_engine = engine
+            return engine
+
+    def release_alternate_engine(self) -> None:
+        """Unload the alternate engine, if any, to make room for the main model."""
+        from server.core.audio_utils import clear_gpu_cache
+
+        engine, self._alternate_engine = self._alternate_engine, None
+        if engine is None:
+            return
+        try:
+            engine.unload_model()
+        except Exception:
+            logger.debug("Alternate engine unload failed (non-critical)", exc_info=True)
+        clear_gpu_cache()
+        logger.info("Alternate transcription model %s unloaded", engine.model_name)
+
     @property
     def diarization_engine(self) -> "DiarizationEngine | Any":
         """Get or create the diarization engine (PyAnnote or Sortformer)."""
@@ -1010,6 +1176,7 @@ class ModelManager:
         from server.core.audio_utils import clear_gpu_cache
 
         logger.info("Unloading all models...")
+        self.release_alternate_engine()
         self.unload_transcription_model()
         self.unload_diarization_model()
         self.release_all_realtime_engines()
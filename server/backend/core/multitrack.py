@@ -8,6 +8,10 @@ Channel-based speaker separation is complementary to diarization — when the
 recording hardware already isolates speakers on separate channels (podcasts,
 film sound, court recordings, panels, TTRPGs), splitting channels is more
 reliable than algorithmic diarization.
+
+Channels can be named (``channel_labels``) so a two-channel call recording —
+microphone on the left, the far end on the right — comes back attributed to
+"Me" and "Guest" rather than numbered speakers.
 """
 
 from __future__ import annotations
@@ -17,7 +21,7 @@ import logging
 import re
 import subprocess
 import tempfile
-from collections.abc import Callable
+from collections.abc import Callable, Sequence
 from pathlib import Path
 from typing import TYPE_CHECKING, Any
 
@@ -257,14 +261,34 @@ def split_channels(
 # ---------------------------------------------------------------------------
 
 
+def channel_speaker_labels(
+    active_channels: Sequence[int],
+    channel_labels: Sequence[str] | None = None,
+) -> list[str]:
+    """Speaker label for each active channel.
+
+    ``channel_labels`` is indexed by the channel's position in the source
+    file, so a silent channel dropped by the filter never shifts the names
+    of the others. Unnamed channels fall back to ``"Speaker N"`` (1-based
+    over the active tracks).
+    """
+    names = [str(label).strip() for label in channel_labels or []]
+    labels: list[str] = []
+    for track_num, channel in enumerate(active_channels, 1):
+        name = names[channel] if channel < len(names) else ""
+        labels.append(name or f"Speaker {track_num}")
+    return labels
+
+
 def merge_track_results(
     track_results: list[TranscriptionResult],
+    labels: Sequence[str] | None = None,
 ) -> TranscriptionResult:
     """Merge per-track transcription results into a unified transcript.
 
-    Each track is assigned a speaker label ``"Speaker N"`` (1-based).  All
-    words are interleaved by start time and grouped into contiguous speaker
-    segments.
+    Each track is assigned ``labels[i]`` as its speaker, or ``"Speaker N"``
+    (1-based) when no label is given.  All words are interleaved by start
+    time and grouped into contiguous speaker segments.
     """
     from server.core.speaker_merge import build_speaker_segments
     from server.core.stt.engine import TranscriptionResult as TR
@@ -275,7 +299,7 @@ def merge_track_results(
     lang_prob: float = 0.0
 
     for speaker_num, track in enumerate(track_results, 1):
-        label = f"Speaker {speaker_num}"
+        label = labels[speaker_num - 1] if labels else f"Speaker {speaker_num}"
         for word in track.words:
             all_words.append({**word, "speaker": label})
         total_duration = max(total_duration, track.duration)
@@ -328,11 +352,14 @@ def transcribe_multitrack(
     silence_threshold_db: float = DEFAULT_SILENCE_THRESHOLD_DB,
     cancellation_check: Callable[[], bool] | None = None,
     progress_callback: Callable[[int, int], None] | None = None,
+    channel_labels: Sequence[str] | None = None,
 ) -> TranscriptionResult:
     """Full multitrack pipeline: probe → filter → split → transcribe → merge.
 
     Raises ``ValueError`` when no channels pass the silence threshold.
     Falls through to standard single-file transcription for mono files.
+    ``channel_labels`` names the speaker on each source channel (see
+    ``channel_speaker_labels``).
     """
     logger.info("Multitrack: probing channels in %s", file_path)
     probe = probe_channels(file_path, cancellation_check=cancellation_check)
@@ -418,7 +445,9 @@ def transcribe_multitrack(
             )
             track_results.append(result)
 
-        merged = merge_track_results(track_results)
+        merged = merge_track_results(
+            track_results, channel_speaker_labels(active, channel_labels)
+        )
         logger.info(
             "Multitrack: merged %d tracks → %d speakers, %d segments, %d words",
             total_tracks,
@@ -0,0 +1,75 @@
+"""Local ONNX models for the optional transcript analysers.
+
+PII detection, punctuation restoration and speaker identification each run a
+user-supplied ONNX model on the CPU. :func:`load_model` is how they all load
+one: a missing path, missing packages or a broken model turn the feature off
+with a warning instead of failing the job. :class:`TokenClassifier` is the
+shared part of the two text models — a ``model.onnx`` + ``tokenizer.json`` +
+``config.json`` (with ``id2label``) directory, as exported from Hugging Face
+token-classification models. onnxruntime and tokenizers ship with the whisper
+extra.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+from collections.abc import Callable, Sequence
+from pathlib import Path
+from typing import Any, TypeVar
+
+logger = logging.getLogger(__name__)
+
+M = TypeVar("M")
+
+
+class TokenClassifier:
+    """ONNX token-classification model: one ``id2label`` label per token."""
+
+    def __init__(self, model_dir: Path) -> None:
+        import onnxruntime
+        from tokenizers import Tokenizer
+
+        config = json.loads((model_dir / "config.json").read_text(encoding="utf-8"))
+        self.labels = {int(k): str(v) for k, v in config["id2label"].items()}
+        self._tokenizer = Tokenizer.from_file(str(model_dir / "tokenizer.json"))
+        self._tokenizer.enable_truncation(max_length=512)
+        self._session = onnxruntime.InferenceSession(
+            str(model_dir / "model.onnx"), providers=["CPUExecutionProvider"]
+        )
+        self._inputs = {i.name for i in self._session.get_inputs()}
+
+    def classify(self, text: str | Sequence[str]) -> tuple[Any, list[str]]:
+        """The tokenizer encoding of ``text`` (a string or pre-split words) and
+        the predicted label of each of its tokens ("O" for unknown ids)."""
+        import numpy as np
+
+        if isinstance(text, str):
+            enc = self._tokenizer.encode(text)
+        else:
+            enc = self._tokenizer.encode(list(text), is_pretokenized=True)
+        feeds = {
+            "input_ids": np.array([enc.ids], dtype=np.int64),
+            "attention_mask": np.array([enc.attention_mask], dtype=np.int64),
+        }
+        if "token_type_ids" in self._inputs:
+            feeds["token_type_ids"] = np.array([enc.type_ids], dtype=np.int64)
+        logits = self._session.run(None, {k: v for k, v in feeds.items() if k in self._inputs})
+        return enc, [self.labels.get(int(i), "O") for i in logits[0][0].argmax(-1)]
+
+
+def load_model(factory: Callable[[Path], M], model_path: str, off: str) -> M | None:
+    """``factory(path)`` for the configured ``model_path``, or None when it is
+    unset or can't load; ``off`` starts the warning, e.g. "PII NER disabled"."""
+    if not model_path:
+        return None
+    path = Path(model_path).expanduser()
+    try:
+        return factory(path)
+    except ImportError as exc:
+        logger.warning("%s: %s is not installed", off, exc.name or "onnxruntime")
+    except (OSError, KeyError, ValueError) as exc:
+        logger.warning("%s: could not load model from %s (%s)", off, path, exc)
+    except Exception as exc:  # noqa: BLE001 — onnxruntime raises its own error types
+        logger.warning("%s: onnxruntime failed on %s (%s)", off, path, exc)
+    return None
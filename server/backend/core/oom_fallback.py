@@ -0,0 +1,282 @@
+"""Automatic retries for file jobs that run out of GPU memory.
+
+Large models on 12 GB cards used to fail long files outright: the model
+fits, the activations for a long file do not. When a file job fails with
+an out-of-memory error the server now clears the GPU cache and tries, in
+order (``main_transcriber.oom_fallback``):
+
+1. the same model on ``chunk_seconds`` pieces of the file, one at a time
+2. each smaller model from ``models`` that comes after the current one,
+   again in pieces — only when the current model is on that list, so a
+   model family is never swapped for another one
+
+The first attempt that succeeds wins. Its result carries a ``downgrade``
+record (what failed, what ran instead, the VRAM figures at the failure)
+that the job history and the dashboard show. The main model reloads on
+the next job as usual (``ModelManager.alternate_transcription_engine``).
+When every attempt runs out of memory too, the original error is raised.
+"""
+
+from __future__ import annotations
+
+import dataclasses
+import logging
+from collections.abc import Callable, Sequence
+from dataclasses import dataclass, field
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_CHUNK_SECONDS = 300.0
+DEFAULT_MODELS = (
+    "Systran/faster-whisper-large-v3",
+    "deepdml/faster-whisper-large-v3-turbo-ct2",
+    "Systran/faster-whisper-medium",
+    "Systran/faster-whisper-small",
+)
+
+_OOM_MARKERS = (
+    "out of memory",
+    "cublas_status_alloc_failed",
+    "cudnn_status_alloc_failed",
+    "failed to allocate memory",
+)
+
+
+@dataclass(slots=True)
+class OomFallbackSettings:
+    enabled: bool = True
+    # 0 disables the chunked retry (smaller models then run on the whole file).
+    chunk_seconds: float = DEFAULT_CHUNK_SECONDS
+    models: tuple[str, ...] = DEFAULT_MODELS
+
+    @classmethod
+    def from_config(cls, section: dict[str, Any] | None) -> OomFallbackSettings:
+        section = section or {}
+        models = section.get("models")
+        try:
+            chunk = max(0.0, float(section.get("chunk_seconds", DEFAULT_CHUNK_SECONDS)))
+        except (TypeError, ValueError):
+            chunk = DEFAULT_CHUNK_SECONDS
+        return cls(
+            enabled=bool(section.get("enabled", True)),
+            chunk_seconds=chunk,
+            models=(
+                tuple(str(m) for m in models if str(m).strip())
+                if isinstance(models, list)
+                else DEFAULT_MODELS
+            ),
+        )
+
+
+def fallback_settings(config: dict[str, Any]) -> OomFallbackSettings:
+    """Settings from a full server config (``main_transcriber.oom_fallback``)."""
+    section = (config.get("main_transcriber") or {}).get("oom_fallback")
+    return OomFallbackSettings.from_config(section)
+
+
+@dataclass(slots=True)
+class Downgrade:
+    """How an out-of-memory job was finished instead."""
+
+    from_model: str
+    to_model: str
+    chunk_seconds: float | None
+    error: str
+    vram: dict[str, Any] = field(default_factory=dict)
+
+    @property
+    def summary(self) -> str:
+        parts: list[str] = []
+        if self.to_model != self.from_model:
+            parts.append(f"with {self.to_model} instead of {self.from_model}")
+        if self.chunk_seconds:
+            parts.append(f"in {self.chunk_seconds / 60:g}-minute chunks")
+        return "Out of GPU memory — transcribed " + " ".join(parts)
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "from_model": self.from_model,
+            "to_model": self.to_model,
+            "chunk_seconds": self.chunk_seconds,
+            "error": self.error,
+            "vram": self.vram,
+            "summary": self.summary,
+        }
+
+
+def is_out_of_memory(exc: BaseException) -> bool:
+    """True for CUDA/cuBLAS allocation failures, including wrapped ones."""
+    seen: set[int] = set()
+    current: BaseException | None = exc
+    while current is not None and id(current) not in seen:
+        seen.add(id(current))
+        if type(current).__name__ == "OutOfMemoryError":
+            return True
+        message = str(current).lower()
+        if any(marker in message for marker in _OOM_MARKERS):
+            return True
+        current = current.__cause__ or current.__context__
+    return False
+
+
+def smaller_models(
+    current: str, ladder: Sequence[str], same_model: Callable[[str, str], bool]
+) -> list[str]:
+    """The models after ``current`` on ``ladder``; none when it is not on it."""
+    for index, name in enumerate(ladder):
+        if same_model(current, name):
+            return list(ladder[index + 1 :])
+    return []
+
+
+def _shifted(item: dict[str, Any], offset: float) -> dict[str, Any]:
+    moved = dict(item)
+    for key in ("start", "end"):
+        if moved.get(key) is not None:
+            moved[key] = round(float(moved[key]) + offset, 3)
+    if isinstance(moved.get("words"), list):
+        moved["words"] = [_shifted(w, offset) for w in moved["words"]]
+    return moved
+
+
+def transcribe_in_chunks(
+    engine: Any,
+    file_path: str,
+    *,
+    duration: float,
+    chunk_seconds: float,
+    language: str | None = None,
+    task: str | None = None,
+    translation_target_language: str | None = None,
+    word_timestamps: bool = True,
+    cancellation_check: Callable[[], bool] | None = None,
+    progress_callback: Callable[[int, int], None] | None = None,
+) -> Any:
+    """Transcribe ``file_path`` piece by piece and join the pieces on one timeline.
+
+    A piece that stops early ends the run with a partial result, the same
+    way a chunking backend reports it.
+    """
+    merged: Any = None
+    segments: list[dict[str, Any]] = []
+    words: list[dict[str, Any]] = []
+    texts: list[str] = []
+    offset = 0.0
+    while offset < duration:
+        span = min(chunk_seconds, duration - offset)
+        result = engine.transcribe_file_span(
+            file_path,
+            start_seconds=offset,
+            duration_seconds=span,
+            language=language,
+            task=task,
+            translation_target_language=translation_target_language,
+            word_timestamps=word_timestamps,
+            cancellation_check=cancellation_check,
+        )
+        merged = merged or result
+        segments.extend(_shifted(s, offset) for s in result.segments)
+        words.extend(_shifted(w, offset) for w in result.words)
+        if result.text.strip():
+            texts.append(result.text.strip())
+        if getattr(result, "partial", False):
+            completed = offset + float(getattr(result, "completed_seconds", None) or 0.0)
+            return dataclasses.replace(
+                merged,
+                text=" ".join(texts),
+                segments=segments,
+                words=words,
+                duration=duration,
+                partial=True,
+                partial_reason=result.partial_reason,
+                completed_seconds=round(completed, 3),
+            )
+        offset += span
+        if progress_callback is not None:
+            progress_callback(int(offset), int(duration))
+    if merged is None:
+        raise ValueError("The file has no audio to transcribe")
+    return dataclasses.replace(
+        merged, text=" ".join(texts), segments=segments, words=words, duration=duration
+    )
+
+
+def transcribe_with_fallback(
+    model_manager: Any,
+    engine: Any,
+    file_path: str,
+    *,
+    settings: OomFallbackSettings,
+    duration: Callable[[str], float] | None = None,
+    vram: Callable[[], dict[str, Any]] | None = None,
+    clear_cache: Callable[[], None] | None = None,
+    **kwargs: Any,
+) -> tuple[Any, Any]:
+    """``engine.transcribe_file(file_path, **kwargs)``, retried on out-of-memory.
+
+    Returns ``(result, engine_used)``; ``result.downgrade`` is set when a
+    retry produced it. ``duration``/``vram``/``clear_cache`` default to the
+    audio_utils helpers and exist for tests.
+    """
+    try:
+        return engine.transcribe_file(file_path, **kwargs), engine
+    except Exception as exc:
+        if not settings.enabled or not is_out_of_memory(exc):
+            raise
+        original = exc
+
+    if duration is None or vram is None or clear_cache is None:
+        from server.core import audio_utils
+
+        device = getattr(model_manager, "_gpu_device_index", 0)
+        duration = duration or audio_utils.get_audio_duration
+        vram = vram or (lambda: audio_utils.get_gpu_memory_info(device))
+        clear_cache = clear_cache or audio_utils.clear_gpu_cache
+    snapshot = vram()
+    clear_cache()
+    from_model = str(getattr(engine, "model_name", "") or "")
+    logger.warning(
+        "Out of GPU memory transcribing with %s (VRAM %s); retrying", from_model, snapshot
+    )
+    total = duration(file_path)
+    chunk = settings.chunk_seconds if 0 < settings.chunk_seconds < total else None
+    span_kwargs = {k: v for k, v in kwargs.items() if k != "progress_callback"}
+
+    attempts: list[str | None] = [None] if chunk else []
+    attempts += smaller_models(from_model, settings.models, model_manager.is_same_model)
+    model_manager.job_tracker.set_phase("oom_retry")
+    for model in attempts:
+        try:
+            current = (
+                engine if model is None else model_manager.alternate_transcription_engine(model)
+            )
+            if chunk:
+                result = transcribe_in_chunks(
+                    current,
+                    file_path,
+                    duration=total,
+                    chunk_seconds=chunk,
+                    progress_callback=kwargs.get("progress_callback"),
+                    **span_kwargs,
+                )
+            else:
+                result = current.transcribe_file(file_path, **kwargs)
+        except Exception as exc:
+            if not is_out_of_memory(exc):
+                raise
+            logger.warning("Still out of GPU memory with %s", model or f"{from_model} in chunks")
+            clear_cache()
+            continue
+        downgrade = Downgrade(
+            from_model=from_model,
+            to_model=model or from_model,
+            chunk_seconds=chunk,
+            error=str(original),
+            vram=snapshot,
+        )
+        logger.warning("%s", downgrade.summary)
+        result.downgrade = downgrade.to_dict()
+        model_manager.job_tracker.set_phase("transcribing")
+        return result, current
+    raise original
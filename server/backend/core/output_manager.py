@@ -0,0 +1,206 @@
+"""Output manager: template-named exports routed to per-type folders.
+
+Every export target gets a filename rendered from one template (default
+``{date}_{title}_{lang}.{ext}``, see ``core/filename_template.py``) and a
+folder picked by its output type — transcripts, subtitles, documents,
+markers, summaries or the recording's own audio. A type without its own
+folder falls back to ``output_manager.root``.
+
+When a planned path is already taken — on disk, or by an earlier target in
+the same batch — the collision policy decides:
+
+* ``suffix`` — append `` (2)``, `` (3)`` … before the extension
+* ``overwrite`` — replace the existing file
+* ``skip`` — leave it alone and report the target as skipped
+
+``plan_outputs`` never touches the disk beyond ``exists`` checks, so the
+same call backs both the preview and the actual write.
+"""
+
+from __future__ import annotations
+
+import os
+import shutil
+import tempfile
+from collections.abc import Callable, Iterable
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
+from typing import Any
+
+from server.core.filename_template import find_unknown_placeholders, render_and_sanitize
+
+DEFAULT_TEMPLATE = "{date}_{title}_{lang}.{ext}"
+COLLISION_POLICIES = ("suffix", "overwrite", "skip")
+
+# export format → (output type, file extension)
+OUTPUT_FORMATS: dict[str, tuple[str, str]] = {
+    "txt": ("transcript", "txt"),
+    "plaintext": ("transcript", "txt"),
+    "accessible": ("transcript", "txt"),
+    "srt": ("subtitles", "srt"),
+    "ass": ("subtitles", "ass"),
+    "html": ("document", "html"),
+    "edl": ("markers", "edl"),
+    "premiere_xml": ("markers", "xml"),
+    "resolve_csv": ("markers", "csv"),
+    "audacity": ("markers", "txt"),
+    "summary": ("summary", "txt"),
+    "recording": ("recording", ""),  # extension comes from the source audio
+}
+OUTPUT_TYPES = tuple(dict.fromkeys(kind for kind, _ in OUTPUT_FORMATS.values()))
+
+
+@dataclass(frozen=True)
+class OutputSettings:
+    """The ``output_manager`` config section, validated."""
+
+    template: str = DEFAULT_TEMPLATE
+    root: str = ""
+    destinations: dict[str, str] = field(default_factory=dict)
+    collision: str = "suffix"
+
+    @classmethod
+    def from_config(cls, section: dict[str, Any] | None, **overrides: Any) -> OutputSettings:
+        """Build from config; non-empty ``overrides`` (per request) win. Raises ``ValueError``."""
+        section = dict(section or {})
+        section.update({k: v for k, v in overrides.items() if v})
+        destinations = {
+            str(k): str(v).strip()
+            for k, v in (section.get("destinations") or {}).items()
+            if v and str(v).strip()
+        }
+        unknown_types = sorted(set(destinations) - set(OUTPUT_TYPES))
+        if unknown_types:
+            raise ValueError(
+                f"Unknown output type(s): {', '.join(unknown_types)}. "
+                f"Known: {', '.join(OUTPUT_TYPES)}"
+            )
+        settings = cls(
+            template=str(section.get("template") or DEFAULT_TEMPLATE),
+            root=str(section.get("root") or "").strip(),
+            destinations=destinations,
+            collision=str(section.get("collision") or "suffix").strip().lower(),
+        )
+        unknown = find_unknown_placeholders(settings.template)
+        if unknown:
+            raise ValueError(f"Unknown template placeholder(s): {', '.join(unknown)}")
+        if settings.collision not in COLLISION_POLICIES:
+            raise ValueError(f"collision must be one of: {', '.join(COLLISION_POLICIES)}")
+        return settings
+
+    def folder_for(self, output_type: str) -> Path | None:
+        """Destination folder for an output type; a relative folder sits under ``root``."""
+        folder = self.destinations.get(output_type, "")
+        if folder and (Path(folder).is_absolute() or not self.root):
+            return Path(folder).expanduser()
+        if self.root:
+            return Path(self.root).expanduser() / folder
+        return None
+
+
+@dataclass
+class PlannedOutput:
+    """Where one export of one recording goes, and what the collision policy made of it."""
+
+    recording_id: int
+    format: str
+    output_type: str
+    filename: str
+    path: str | None
+    action: str  # "write" | "overwrite" | "skip" | "unrouted"
+    renamed: bool = False
+
+    def to_dict(self) -> dict[str, Any]:
+        return asdict(self)
+
+
+def output_type_for(fmt: str) -> tuple[str, str]:
+    """``(output type, extension)`` for an export format; ``ValueError`` if unknown."""
+    try:
+        return OUTPUT_FORMATS[fmt]
+    except KeyError:
+        raise ValueError(
+            f"Unsupported output format '{fmt}'. Supported: {', '.join(OUTPUT_FORMATS)}"
+        ) from None
+
+
+def _with_suffix(path: Path, n: int) -> Path:
+    stem, dot, ext = path.name.rpartition(".")
+    if not dot:
+        stem, ext = path.name, ""
+    return path.with_name(f"{stem} ({n}).{ext}" if ext else f"{stem} ({n})")
+
+
+def plan_outputs(
+    recordings: Iterable[dict[str, Any]],
+    formats: Iterable[str],
+    settings: OutputSettings,
+    *,
+    exists: Callable[[Path], bool] = Path.exists,
+) -> list[PlannedOutput]:
+    """Plan every ``recording × format`` target in order, resolving collisions.
+
+    A target whose type has no folder (and no ``root``) is ``unrouted`` —
+    the caller falls back to a save dialog for it.
+    """
+    fmts = [f.strip().lower() for f in formats]
+    for fmt in fmts:
+        output_type_for(fmt)
+    taken: set[str] = set()
+    plans: list[PlannedOutput] = []
+    for recording in recordings:
+        for fmt in fmts:
+            output_type, ext = output_type_for(fmt)
+            if fmt == "recording":
+                ext = Path(str(recording.get("filepath") or "")).suffix.lstrip(".") or "wav"
+            filename = render_and_sanitize(settings.template, {**recording, "ext": ext})
+            plan = PlannedOutput(
+                recording_id=int(recording.get("id") or 0),
+                format=fmt,
+                output_type=output_type,
+                filename=filename,
+                path=None,
+                action="unrouted",
+            )
+            plans.append(plan)
+            folder = settings.folder_for(output_type)
+            if folder is None:
+                continue
+            target = folder / filename
+            clash = str(target) in taken or exists(target)
+            if clash and settings.collision == "skip":
+                plan.path, plan.action = str(target), "skip"
+                continue
+            if clash and settings.collision == "suffix":
+                n = 2
+                while str(_with_suffix(target, n)) in taken or exists(_with_suffix(target, n)):
+                    n += 1
+                target = _with_suffix(target, n)
+                plan.renamed = True
+            plan.filename = target.name
+            plan.path = str(target)
+            plan.action = "overwrite" if clash and settings.collision == "overwrite" else "write"
+            taken.add(str(target))
+    return plans
+
+
+def write_output(path: str | Path, content: bytes | Path) -> None:
+    """Atomically write ``content`` — bytes, or a file to copy — creating folders.
+
+    The data lands in a temp sibling first and is moved into place with
+    ``os.replace``, so a half-written export is never visible.
+    """
+    target = Path(path)
+    target.parent.mkdir(parents=True, exist_ok=True)
+    fd, tmp_path = tempfile.mkstemp(prefix=target.name + ".", suffix=".tmp", dir=target.parent)
+    try:
+        with os.fdopen(fd, "wb") as f:
+            if isinstance(content, Path):
+                with content.open("rb") as src:
+                    shutil.copyfileobj(src, f)
+            else:
+                f.write(content)
+        os.replace(tmp_path, target)
+    except Exception:
+        Path(tmp_path).unlink(missing_ok=True)
+        raise
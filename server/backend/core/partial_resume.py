@@ -0,0 +1,119 @@
+"""Resume a partial transcript from where the interrupted job stopped.
+
+A chunking backend that fails partway through long audio raises
+``PartialTranscriptionError``; the notebook keeps the completed segments
+and records how far they reach (``recordings.partial_until``). Resuming
+transcribes only the rest of the audio with
+``AudioToTextRecorder.transcribe_file_span``, moves the clip-relative
+timings onto the recording timeline and appends the new segments after
+the saved ones. Speakers are left unset — diarization ran, if at all, on
+the first part only.
+
+A resume can itself stop early; the recording then stays partial with a
+later offset and can be resumed again.
+"""
+
+from __future__ import annotations
+
+import logging
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+# Less audio than this left after the offset is not worth a model run.
+MIN_REMAINING_SECONDS = 0.5
+
+
+@dataclass
+class ResumedTranscript:
+    """Segments transcribed from ``offset`` on, on the recording timeline."""
+
+    offset: float
+    segments: list[dict[str, Any]] = field(default_factory=list)
+    # Set when the resumed run stopped early too; None = the transcript is now complete.
+    partial_until: float | None = None
+    partial_reason: str | None = None
+
+    @property
+    def partial(self) -> bool:
+        return self.partial_until is not None
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "offset": self.offset,
+            "segment_count": len(self.segments),
+            "word_count": sum(len(s["words"]) for s in self.segments),
+            "partial": self.partial,
+            "partial_until": self.partial_until,
+            "partial_reason": self.partial_reason,
+        }
+
+
+def resume_offset(recording: dict[str, Any], segments: list[dict[str, Any]]) -> float:
+    """Where a resume starts: the stored offset, else the end of the last segment."""
+    until = recording.get("partial_until")
+    if until is not None:
+        return max(0.0, float(until))
+    return max((float(s.get("end_time") or 0.0) for s in segments), default=0.0)
+
+
+def shift_segments(result_segments: list[dict[str, Any]], offset: float) -> list[dict[str, Any]]:
+    """Move clip-relative segments and words onto the recording timeline."""
+    shifted: list[dict[str, Any]] = []
+    for seg in result_segments:
+        text = str(seg.get("text") or "").strip()
+        if not text:
+            continue
+        words = [
+            {
+                "word": str(w.get("word") or "").strip(),
+                "start": round(offset + float(w.get("start") or 0.0), 3),
+                "end": round(offset + float(w.get("end") or 0.0), 3),
+                "confidence": w.get("probability", w.get("confidence")),
+            }
+            for w in seg.get("words") or []
+            if str(w.get("word") or "").strip()
+        ]
+        shifted.append(
+            {
+                "text": text,
+                "start": round(offset + float(seg.get("start") or 0.0), 3),
+                "end": round(offset + float(seg.get("end") or 0.0), 3),
+                "words": words,
+            }
+        )
+    return shifted
+
+
+def resume_transcription(
+    engine: Any,
+    audio_path: Path,
+    offset: float,
+    *,
+    duration: float,
+    language: str | None = None,
+) -> ResumedTranscript:
+    """Transcribe ``audio_path`` from ``offset`` to ``duration`` with ``engine``."""
+    remaining = duration - offset
+    if remaining < MIN_REMAINING_SECONDS:
+        raise ValueError("Nothing left to transcribe after the saved part")
+    result = engine.transcribe_file_span(
+        str(audio_path),
+        start_seconds=offset,
+        duration_seconds=remaining,
+        language=language,
+    )
+    resumed = ResumedTranscript(offset=offset, segments=shift_segments(result.segments, offset))
+    if getattr(result, "partial", False):
+        completed = getattr(result, "completed_seconds", None) or 0.0
+        resumed.partial_until = round(offset + float(completed), 3)
+        resumed.partial_reason = getattr(result, "partial_reason", None)
+    logger.info(
+        "Resumed transcript from %.2fs: %d segments%s",
+        offset,
+        len(resumed.segments),
+        f", stopped again at {resumed.partial_until:.2f}s" if resumed.partial else "",
+    )
+    return resumed
@@ -0,0 +1,201 @@
+"""PII detection: suggest redactions for names, phone numbers, emails and addresses.
+
+Runs locally on a finished transcript. Regexes find emails, phone numbers
+and street addresses. When ``pii_detection.ner_model_dir`` points at an
+ONNX token-classification model (``model.onnx`` + ``tokenizer.json`` +
+``config.json`` with ``id2label``), person and place entities come from it
+too. onnxruntime and tokenizers ship with the whisper extra; without them
+(or without a model) the scan is regex-only.
+
+Hits are stored as ``source='pii'`` redactions (see ``core/redaction.py``).
+A re-scan replaces the previous ``pii`` ranges and never touches manual ones.
+"""
+
+from __future__ import annotations
+
+import functools
+import logging
+import re
+from collections.abc import Iterable
+from dataclasses import dataclass
+from typing import Any
+
+from server.core.onnx_models import TokenClassifier, load_model
+
+logger = logging.getLogger(__name__)
+
+PII_KINDS: tuple[str, ...] = ("name", "phone", "email", "address")
+
+_EMAIL = re.compile(r"[\w.+-]+@[\w-]+(?:\.[\w-]+)+")
+# ASR output spells addresses out: "jane dot doe at example dot com".
+_SPOKEN_EMAIL = re.compile(
+    r"\b[\w]+(?: dot [\w]+)* at [\w-]+(?: dot [\w-]+)* dot (?:com|org|net|edu|gov|io|co|de|uk)\b",
+    re.IGNORECASE,
+)
+_PHONE = re.compile(r"(?<![\w+])\+?\(?\d[\d\s().-]{5,}\d(?!\w)")
+_MIN_PHONE_DIGITS = 7
+_STREET = (
+    r"(?i:street|st|avenue|ave|road|rd|boulevard|blvd|lane|ln|drive|dr|court|ct"
+    r"|way|place|pl|terrace|close|crescent|square|sq|highway|hwy)"
+)
+_ADDRESS = re.compile(rf"\b\d{{1,5}}\s+(?:[A-Z][\w'-]*\s+){{1,3}}{_STREET}\b\.?")
+
+# NER label suffix → PII kind (CoNLL-style B-/I- tags).
+_NER_KINDS = {"PER": "name", "PERSON": "name", "LOC": "address", "GPE": "address"}
+
+
+@dataclass(frozen=True)
+class PiiHit:
+    """One sensitive span, in transcript time."""
+
+    kind: str
+    start_time: float
+    end_time: float
+    text: str
+
+
+def find_pattern_spans(text: str, kinds: Iterable[str] = PII_KINDS) -> list[tuple[str, int, int]]:
+    """``(kind, char_start, char_end)`` for every regex hit in ``text``."""
+    wanted = set(kinds)
+    spans: list[tuple[str, int, int]] = []
+    if "email" in wanted:
+        for pattern in (_EMAIL, _SPOKEN_EMAIL):
+            spans.extend(("email", m.start(), m.end()) for m in pattern.finditer(text))
+    if "phone" in wanted:
+        for m in _PHONE.finditer(text):
+            if sum(c.isdigit() for c in m.group()) >= _MIN_PHONE_DIGITS:
+                spans.append(("phone", m.start(), m.end()))
+    if "address" in wanted:
+        spans.extend(("address", m.start(), m.end()) for m in _ADDRESS.finditer(text))
+    return spans
+
+
+class NerModel(TokenClassifier):
+    """ONNX token-classification model (e.g. a BERT NER export)."""
+
+    def entity_spans(self, text: str) -> list[tuple[str, int, int]]:
+        """``(kind, char_start, char_end)`` for person / place entities."""
+        enc, labels = self.classify(text)
+
+        spans: list[tuple[str, int, int]] = []
+        for label, (start, end) in zip(labels, enc.offsets, strict=True):
+            if start == end:
+                continue  # special token
+            tag, _, entity = label.partition("-")
+            kind = _NER_KINDS.get(entity.upper())
+            if kind is None:
+                continue
+            if spans and tag == "I" and spans[-1][0] == kind and start - spans[-1][2] <= 1:
+                spans[-1] = (kind, spans[-1][1], end)
+            elif spans and spans[-1][0] == kind and start == spans[-1][2]:
+                spans[-1] = (kind, spans[-1][1], end)  # word-piece continuation
+            else:
+                spans.append((kind, start, end))
+        return spans
+
+
+@functools.lru_cache(maxsize=1)
+def load_ner_model(model_dir: str) -> NerModel | None:
+    """The configured NER model, or None (regex-only) when it can't load."""
+    return load_model(NerModel, model_dir, "PII NER disabled")
+
+
+def _segment_tokens(
+    segment: dict[str, Any], words: list[dict[str, Any]]
+) -> list[tuple[str, float, float]]:
+    if words:
+        return [
+            (str(w.get("word") or "").strip(), float(w["start_time"]), float(w["end_time"]))
+            for w in words
+            if str(w.get("word") or "").strip()
+        ]
+    from server.core.forced_alignment import proportional_words
+
+    estimated = proportional_words(
+        [
+            {
+                "start": float(segment.get("start_time") or 0.0),
+                "end": float(segment.get("end_time") or 0.0),
+                "text": segment.get("text"),
+            }
+        ]
+    )
+    return [(w["word"], w["start"], w["end"]) for w in estimated]
+
+
+def _spans_to_hits(
+    tokens: list[tuple[str, float, float]], spans: list[tuple[str, int, int]]
+) -> list[PiiHit]:
+    offsets: list[tuple[int, int]] = []
+    cursor = 0
+    for token, _, _ in tokens:
+        offsets.append((cursor, cursor + len(token)))
+        cursor += len(token) + 1
+    hits: list[PiiHit] = []
+    for kind, start, end in spans:
+        covered = [i for i, (a, b) in enumerate(offsets) if a < end and b > start]
+        if not covered:
+            continue
+        first, last = covered[0], covered[-1]
+        hits.append(
+            PiiHit(
+                kind=kind,
+                start_time=tokens[first][1],
+                end_time=tokens[last][2],
+                text=" ".join(t[0] for t in tokens[first : last + 1]),
+            )
+        )
+    return hits
+
+
+def scan_transcript(
+    segments: list[dict[str, Any]],
+    words: list[dict[str, Any]],
+    *,
+    kinds: Iterable[str] = PII_KINDS,
+    ner: NerModel | None = None,
+) -> list[PiiHit]:
+    """PII hits across a transcript's segments, ordered by start time.
+
+    Detection runs on the segment text rebuilt from its words, so each hit
+    maps back to word timings (estimated for segments without words).
+    """
+    wanted = tuple(k for k in kinds if k in PII_KINDS)
+    by_segment: dict[Any, list[dict[str, Any]]] = {}
+    for word in sorted(words, key=lambda w: float(w.get("start_time") or 0.0)):
+        by_segment.setdefault(word.get("segment_id"), []).append(word)
+
+    hits: list[PiiHit] = []
+    for seg in segments:
+        tokens = _segment_tokens(seg, by_segment.get(seg.get("id"), []))
+        if not tokens:
+            continue
+        text = " ".join(t[0] for t in tokens)
+        spans = find_pattern_spans(text, wanted)
+        if ner is not None:
+            spans.extend(s for s in ner.entity_spans(text) if s[0] in wanted)
+        hits.extend(h for h in _spans_to_hits(tokens, spans) if h.end_time > h.start_time)
+    return sorted(set(hits), key=lambda h: (h.start_time, h.end_time, h.kind))
+
+
+def run_pii_scan(recording_id: int) -> int:
+    """Scan a recording and replace its ``pii`` redactions; returns the hit count."""
+    from server.config import get_config
+    from server.database import redaction_repository
+    from server.database.database import get_segments, get_words
+
+    cfg = get_config()
+    kinds = cfg.get("pii_detection", "kinds", default=list(PII_KINDS)) or list(PII_KINDS)
+    ner = load_ner_model(str(cfg.get("pii_detection", "ner_model_dir", default="") or ""))
+    segments, words = get_segments(recording_id), get_words(recording_id)
+    hits = scan_transcript(segments, words, kinds=kinds, ner=ner)
+    redaction_repository.replace_redactions(
+        recording_id,
+        "pii",
+        [
+            {"start_time": h.start_time, "end_time": h.end_time, "label": h.kind, "text": h.text}
+            for h in hits
+        ],
+    )
+    logger.info("PII scan for recording %d: %d hit(s)", recording_id, len(hits))
+    return len(hits)
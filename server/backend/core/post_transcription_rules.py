@@ -0,0 +1,271 @@
+"""Post-transcription rules: "when a job from watch folder X completes → do Y".
+
+Rules live in ``post_transcription_rules`` in config.yaml. Each has a
+``when`` block — every condition set must hold — and a list of actions
+run in order once a notebook transcription has been committed:
+
+* ``export`` — write an export (any ``core/output_manager.py`` format) to
+  ``folder``, named by ``template`` (default ``output_manager.template``)
+* ``webhook`` — queue a ``transcription.completed`` delivery to ``url``
+  through the durable webhook worker (same path as profile webhooks)
+
+Conditions: ``watch_folder`` (the job came from that Folder Watch folder
+or a sub-folder; ``manual`` matches non-watch uploads), ``filename`` (glob,
+case-insensitive), ``language`` and ``min_duration`` / ``max_duration``
+in seconds.
+
+``evaluate`` is pure — it backs the dry-run tester as well as the real
+run, so what the tester shows is exactly what would happen.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import fnmatch
+import logging
+from collections.abc import Awaitable, Callable
+from dataclasses import dataclass, field
+from pathlib import PurePath
+from typing import Any
+
+from server.core.output_manager import OutputSettings, output_type_for, plan_outputs
+
+logger = logging.getLogger(__name__)
+
+ACTION_TYPES = ("export", "webhook")
+_CONDITIONS = ("watch_folder", "filename", "language", "min_duration", "max_duration")
+
+
+@dataclass(frozen=True)
+class JobContext:
+    """What a rule can match on, taken from the completed job."""
+
+    recording_id: int
+    source: str | None = None  # Folder Watch folder; None for manual uploads
+    filename: str = ""
+    language: str | None = None
+    duration: float = 0.0
+
+
+@dataclass(frozen=True)
+class Rule:
+    """One validated rule; ``actions`` are the raw config mappings."""
+
+    name: str
+    when: dict[str, Any] = field(default_factory=dict)
+    actions: tuple[dict[str, Any], ...] = ()
+    enabled: bool = True
+
+    def mismatches(self, ctx: JobContext) -> list[str]:
+        """Conditions ``ctx`` fails, as readable reasons; empty means the rule matches."""
+        reasons: list[str] = []
+        folder = self.when.get("watch_folder")
+        if folder is not None and not _in_watch_folder(ctx.source, str(folder)):
+            reasons.append(f"source {ctx.source or 'manual'!r} is not {folder!r}")
+        pattern = self.when.get("filename")
+        if pattern and not fnmatch.fnmatch(ctx.filename.lower(), str(pattern).lower()):
+            reasons.append(f"filename {ctx.filename!r} does not match {pattern!r}")
+        language = self.when.get("language")
+        if language and (ctx.language or "").lower() != str(language).lower():
+            reasons.append(f"language {ctx.language or 'unknown'!r} is not {language!r}")
+        low = self.when.get("min_duration")
+        if low is not None and ctx.duration < float(low):
+            reasons.append(f"duration {ctx.duration:.0f}s is under {float(low):g}s")
+        high = self.when.get("max_duration")
+        if high is not None and ctx.duration > float(high):
+            reasons.append(f"duration {ctx.duration:.0f}s is over {float(high):g}s")
+        return reasons
+
+
+def _in_watch_folder(source: str | None, folder: str) -> bool:
+    if folder.strip().lower() == "manual":
+        return not source
+    if not source:
+        return False
+    src, root = PurePath(source), PurePath(folder.rstrip("/\\") or folder)
+    return src == root or root in src.parents
+
+
+def load_rules(raw: Any) -> list[Rule]:
+    """Validate the ``post_transcription_rules`` config list. Raises ``ValueError``."""
+    if not raw:
+        return []
+    if not isinstance(raw, list):
+        raise ValueError("post_transcription_rules must be a list")
+    rules: list[Rule] = []
+    for i, item in enumerate(raw):
+        label = f"rule {i + 1}"
+        if not isinstance(item, dict):
+            raise ValueError(f"{label} must be a mapping")
+        name = str(item.get("name") or label)
+        when = item.get("when") or {}
+        if not isinstance(when, dict):
+            raise ValueError(f"{name}: 'when' must be a mapping")
+        unknown = sorted(set(when) - set(_CONDITIONS))
+        if unknown:
+            raise ValueError(f"{name}: unknown condition(s) {', '.join(unknown)}")
+        actions = item.get("actions") or []
+        if not isinstance(actions, list) or not actions:
+            raise ValueError(f"{name}: needs at least one action")
+        for action in actions:
+            _validate_action(name, action)
+        rules.append(
+            Rule(
+                name=name,
+                when=dict(when),
+                actions=tuple(dict(a) for a in actions),
+                enabled=bool(item.get("enabled", True)),
+            )
+        )
+    return rules
+
+
+def _validate_action(name: str, action: Any) -> None:
+    if not isinstance(action, dict) or action.get("type") not in ACTION_TYPES:
+        raise ValueError(f"{name}: each action needs a type ({', '.join(ACTION_TYPES)})")
+    if action["type"] == "export":
+        if not action.get("folder"):
+            raise ValueError(f"{name}: export action needs a folder")
+        try:
+            output_type_for(str(action.get("format") or "txt").strip().lower())
+            OutputSettings.from_config(
+                {"template": action.get("template"), "collision": action.get("collision")}
+            )
+        except ValueError as e:
+            raise ValueError(f"{name}: {e}") from e
+    elif not action.get("url"):
+        raise ValueError(f"{name}: webhook action needs a url")
+
+
+def evaluate(
+    rules: list[Rule],
+    ctx: JobContext,
+    recording: dict[str, Any],
+    output_section: dict[str, Any] | None = None,
+) -> list[dict[str, Any]]:
+    """Per rule: whether it matches, why not, and the concrete actions it would run.
+
+    Export actions are planned through the output manager, so the reported
+    ``path`` already has the rule's collision policy applied.
+    """
+    report: list[dict[str, Any]] = []
+    for rule in rules:
+        reasons = ["rule is disabled"] if not rule.enabled else rule.mismatches(ctx)
+        entry: dict[str, Any] = {
+            "rule": rule.name,
+            "matched": not reasons,
+            "reasons": reasons,
+            "actions": [],
+        }
+        if not reasons:
+            entry["actions"] = [_plan_action(a, recording, output_section) for a in rule.actions]
+        report.append(entry)
+    return report
+
+
+def _plan_action(
+    action: dict[str, Any], recording: dict[str, Any], output_section: dict[str, Any] | None
+) -> dict[str, Any]:
+    if action["type"] == "webhook":
+        return {
+            "type": "webhook",
+            "url": str(action["url"]),
+            "include_transcript": bool(action.get("include_transcript")),
+        }
+    fmt = str(action.get("format") or "txt").strip().lower()
+    defaults = output_section or {}
+    settings = OutputSettings.from_config(
+        {
+            "template": action.get("template") or defaults.get("template"),
+            "collision": action.get("collision") or defaults.get("collision"),
+            "root": str(action["folder"]),
+        }
+    )
+    plan = plan_outputs([recording], [fmt], settings)[0]
+    return {"type": "export", "format": fmt, "path": plan.path, "action": plan.action}
+
+
+Renderer = Callable[[dict[str, Any], str], Awaitable[Any]]
+Writer = Callable[[str, Any], None]
+WebhookQueue = Callable[[int, dict[str, Any]], Awaitable[None]]
+
+
+async def queue_webhook(recording_id: int, planned: dict[str, Any]) -> None:
+    """Insert a pending delivery for the worker — Persist-Before-Deliver, as
+    ``auto_action_coordinator._run_webhook_dispatch`` does for profile webhooks.
+    """
+    from server.core.webhook_payload import build_payload
+    from server.database import webhook_deliveries_repository as wdr
+    from server.database.database import get_recording
+    from server.services.webhook_worker import get_worker
+
+    recording = get_recording(recording_id) or {}
+    transcript_text: str | None = None
+    if planned.get("include_transcript"):
+        from server.core.alias_substitution import apply_aliases
+        from server.core.plaintext_export import stream_plaintext
+        from server.core.redaction import redacted_transcript
+        from server.database.alias_repository import alias_map
+
+        segments, _ = redacted_transcript(recording_id)
+        transcript_text = "".join(
+            stream_plaintext(recording, apply_aliases(segments, alias_map(recording_id)))
+        )
+    payload = build_payload(
+        recording_id=recording_id,
+        profile_id=recording.get("profile_id"),
+        summary_present=bool(recording.get("summary")),
+        transcript_text=transcript_text,
+    )
+    payload["__webhook_url__"] = planned["url"]
+    await asyncio.to_thread(wdr.create_pending, recording_id, recording.get("profile_id"), payload)
+    try:
+        get_worker().notify_new_delivery()
+    except Exception:  # noqa: BLE001 — the row is durable; the wake is a nicety
+        logger.debug("webhook notify_new_delivery failed (worker may not be running)")
+
+
+async def run_rules(
+    rules: list[Rule],
+    ctx: JobContext,
+    recording: dict[str, Any],
+    *,
+    render: Renderer,
+    write: Writer,
+    webhook: WebhookQueue = queue_webhook,
+    output_section: dict[str, Any] | None = None,
+) -> list[dict[str, Any]]:
+    """Run every matching rule's actions; a failed action is logged and skipped.
+
+    The route layer injects ``render`` (the regular export path) and
+    ``write`` so this module stays free of HTTP plumbing.
+    """
+    report = evaluate(rules, ctx, recording, output_section)
+    for entry in report:
+        for planned in entry["actions"]:
+            try:
+                if planned["type"] == "webhook":
+                    await webhook(ctx.recording_id, planned)
+                    planned["status"] = "queued"
+                elif planned["action"] == "skip":
+                    planned["status"] = "skipped"
+                else:
+                    content = await render(recording, planned["format"])
+                    await asyncio.to_thread(write, planned["path"], content)
+                    planned["status"] = "written"
+            except Exception as exc:  # noqa: BLE001 — one action never blocks the next
+                planned["status"] = "failed"
+                planned["error"] = str(exc)
+                logger.warning(
+                    "post-transcription rule %r: %s action failed for recording %d: %s",
+                    entry["rule"],
+                    planned["type"],
+                    ctx.recording_id,
+                    exc,
+                )
+    ran = sum(len(e["actions"]) for e in report if e["matched"])
+    if ran:
+        logger.info(
+            "post-transcription rules ran %d action(s) for recording %d", ran, ctx.recording_id
+        )
+    return report
@@ -0,0 +1,185 @@
+"""Print-ready HTML exporter — the source document for PDF export.
+
+The dashboard renders this HTML to PDF through Electron's headless Chromium
+(``webContents.printToPDF``), which gives us correct Unicode shaping (Greek,
+Cyrillic, CJK) and real pagination without shipping a PDF library in the
+server image. Running headers, footers and page numbers are not part of this
+document: the dashboard adds them in the Chromium print pipeline (see
+``dashboard/electron/pdfExport.ts``) so they repeat on every page.
+
+This module only owns the body: a title block, optional metadata lines, an
+optional speaker statistics table (``core/speaker_stats.py``) and a
+transcript table with an optional timestamp column and one of three speaker
+formatting styles. Page-break rules keep a speaker turn together where
+possible and never split a single row across pages.
+"""
+
+from __future__ import annotations
+
+import html
+from collections.abc import Iterable, Mapping
+from dataclasses import dataclass
+from typing import Any, Literal
+
+SpeakerStyle = Literal["bold", "caps", "plain"]
+PageSize = Literal["A4", "Letter", "Legal"]
+
+SPEAKER_STYLES: tuple[str, ...] = ("bold", "caps", "plain")
+PAGE_SIZES: tuple[str, ...] = ("A4", "Letter", "Legal")
+MIN_FONT_PT = 8
+MAX_FONT_PT = 18
+
+
+@dataclass(slots=True)
+class PrintOptions:
+    speaker_style: SpeakerStyle = "bold"
+    show_timestamps: bool = True
+    show_metadata: bool = True
+    font_size_pt: int = 11
+    page_size: PageSize = "A4"
+
+    def __post_init__(self) -> None:
+        if self.speaker_style not in SPEAKER_STYLES:
+            raise ValueError(f"speaker_style must be one of {', '.join(SPEAKER_STYLES)}")
+        if self.page_size not in PAGE_SIZES:
+            raise ValueError(f"page_size must be one of {', '.join(PAGE_SIZES)}")
+        self.font_size_pt = max(MIN_FONT_PT, min(MAX_FONT_PT, int(self.font_size_pt)))
+
+
+def format_print_timestamp(seconds: float) -> str:
+    """``H:MM:SS`` for recordings over an hour, ``MM:SS`` otherwise."""
+    total = max(0, int(seconds))
+    hours, rem = divmod(total, 3600)
+    minutes, secs = divmod(rem, 60)
+    if hours:
+        return f"{hours}:{minutes:02d}:{secs:02d}"
+    return f"{minutes:02d}:{secs:02d}"
+
+
+def _speaker_html(speaker: str, style: SpeakerStyle) -> str:
+    escaped = html.escape(speaker)
+    if style == "bold":
+        return f"<strong>{escaped}</strong>"
+    if style == "caps":
+        return f'<span class="caps">{escaped}</span>'
+    return escaped
+
+
+_CSS = """
+@page {{ size: {page_size}; margin: 22mm 18mm 20mm 18mm; }}
+html {{ font-family: "Noto Serif", "DejaVu Serif", Georgia, serif; font-size: {font}pt; color: #111; }}
+body {{ margin: 0; }}
+h1 {{ font-size: 1.6em; margin: 0 0 0.3em 0; }}
+.meta {{ color: #555; font-size: 0.9em; margin: 0 0 1.2em 0; }}
+.meta div {{ margin: 0.1em 0; }}
+.summary {{ border-left: 3px solid #999; padding: 0.2em 0 0.2em 0.8em; margin: 0 0 1.2em 0; }}
+table {{ width: 100%; border-collapse: collapse; }}
+tr {{ break-inside: avoid; page-break-inside: avoid; }}
+td {{ vertical-align: top; padding: 0.25em 0.4em; line-height: 1.45; }}
+td.ts {{ width: 5.2em; color: #666; font-family: "DejaVu Sans Mono", monospace; font-size: 0.85em; white-space: nowrap; }}
+td.spk {{ width: 9em; }}
+tr.turn td {{ padding-top: 0.7em; }}
+.caps {{ text-transform: uppercase; letter-spacing: 0.04em; font-size: 0.9em; }}
+h2 {{ font-size: 1.1em; margin: 0 0 0.4em 0; }}
+table.stats {{ margin: 0 0 1.4em 0; font-size: 0.9em; }}
+table.stats th {{ text-align: left; font-weight: 600; padding: 0.2em 0.4em; border-bottom: 1px solid #999; }}
+table.stats td {{ padding: 0.15em 0.4em; }}
+table.stats td.n {{ text-align: right; font-variant-numeric: tabular-nums; }}
+"""
+
+
+def _stats_table(stats: Mapping[str, Any]) -> str:
+    head = (
+        "<tr><th>Speaker</th><th>Talk time</th><th>Share</th><th>WPM</th>"
+        "<th>Turns</th><th>Longest monologue</th><th>Interruptions</th></tr>"
+    )
+    rows = [
+        "<tr>"
+        f"<td>{html.escape(str(s['label']))}</td>"
+        f'<td class="n">{format_print_timestamp(s["talk_seconds"])}</td>'
+        f'<td class="n">{s["share"] * 100:.0f}%</td>'
+        f'<td class="n">{s["words_per_minute"]:.0f}</td>'
+        f'<td class="n">{s["turns"]}</td>'
+        f'<td class="n">{format_print_timestamp(s["longest_monologue_seconds"])}</td>'
+        f'<td class="n">{s["interruptions"]}</td>'
+        "</tr>"
+        for s in stats.get("speakers", [])
+    ]
+    return f'<h2>Speaker statistics</h2><table class="stats">{head}{"".join(rows)}</table>'
+
+
+def render_print_html(
+    recording: dict[str, Any],
+    segments: Iterable[dict[str, Any]],
+    options: PrintOptions | None = None,
+    speaker_stats: Mapping[str, Any] | None = None,
+) -> str:
+    """Render a complete, self-contained HTML document for printing.
+
+    ``segments`` should already be alias-substituted (see
+    ``server.core.alias_substitution.apply_aliases``); speaker values are
+    printed verbatim. ``speaker_stats`` is ``compute_speaker_stats`` output,
+    printed above the transcript when it has any speakers.
+    """
+    opts = options or PrintOptions()
+    title = recording.get("title") or recording.get("filename") or "Recording"
+
+    rows: list[str] = []
+    previous_speaker: object = object()
+    has_speakers = False
+    for seg in segments:
+        text = str(seg.get("text") or "").strip()
+        if not text:
+            continue
+        raw_speaker = seg.get("speaker")
+        speaker = str(raw_speaker).strip() if raw_speaker not in (None, "") else ""
+        has_speakers = has_speakers or bool(speaker)
+        new_turn = speaker != previous_speaker
+        previous_speaker = speaker
+
+        cells: list[str] = []
+        if opts.show_timestamps:
+            try:
+                start = float(seg.get("start_time", seg.get("start", 0.0)) or 0.0)
+            except (TypeError, ValueError):
+                start = 0.0
+            cells.append(f'<td class="ts">{format_print_timestamp(start)}</td>')
+        speaker_cell = _speaker_html(speaker, opts.speaker_style) if speaker and new_turn else ""
+        cells.append(f'<td class="spk">{speaker_cell}</td>')
+        cells.append(f'<td class="txt">{html.escape(text)}</td>')
+        css_class = ' class="turn"' if new_turn and rows else ""
+        rows.append(f"<tr{css_class}>{''.join(cells)}</tr>")
+
+    if not has_speakers:
+        # Drop the empty speaker column entirely when diarization never ran.
+        rows = [r.replace('<td class="spk"></td>', "") for r in rows]
+
+    meta: list[str] = []
+    if opts.show_metadata:
+        recorded_at = recording.get("recorded_at")
+        if recorded_at:
+            meta.append(f"<div>Recorded: {html.escape(str(recorded_at))}</div>")
+        duration = recording.get("duration_seconds")
+        if isinstance(duration, (int, float)) and duration > 0:
+            meta.append(f"<div>Duration: {format_print_timestamp(duration)}</div>")
+
+    summary = str(recording.get("summary") or "").strip()
+    css = _CSS.format(page_size=opts.page_size, font=opts.font_size_pt)
+
+    parts = [
+        "<!DOCTYPE html>",
+        '<html lang="und"><head><meta charset="utf-8">',
+        f"<title>{html.escape(title)}</title>",
+        f"<style>{css}</style>",
+        "</head><body>",
+        f"<h1>{html.escape(title)}</h1>",
+    ]
+    if meta:
+        parts.append(f'<div class="meta">{"".join(meta)}</div>')
+    if summary and opts.show_metadata:
+        parts.append(f'<div class="summary">{html.escape(summary)}</div>')
+    if speaker_stats and speaker_stats.get("speakers"):
+        parts.append(_stats_table(speaker_stats))
+    parts.append(f"<table>{''.join(rows)}</table>")
+    parts.append("</body></html>")
+    return "\n".join(parts)
@@ -0,0 +1,320 @@
+"""Project bundles (``.tsproj``) — one notebook recording, complete, in one file.
+
+A subtitle or transcript export keeps the words and loses the work done on
+them. A project bundle keeps everything, so a recording can move to another
+machine or go into an archive and come back exactly as it was:
+
+- ``manifest.json`` — format, version, creation time, the media entry and a
+  SHA-256 for every other member
+- ``project.json`` — the recording row (title, dates, summary, corrected
+  transcript, timecode settings, …), segments with their review state,
+  words, speaker aliases, annotation threads, redactions, chapters,
+  metadata fields and the diarization review
+- ``media/<name>`` — the audio, decrypted and byte-for-byte as stored, when
+  exported with ``embed_media``
+
+Without embedded media the manifest keeps a reference instead: the original
+path and the audio's SHA-256. On import the file at that path is used only
+when its hash still matches; otherwise the recording comes back text-only.
+
+Import always creates a new recording. Row ids are renumbered and every
+cross-reference (words → segment, notes → segment and thread root) follows;
+columns the bundle has but this schema lacks are dropped, so a bundle from
+a newer server still imports.
+"""
+
+from __future__ import annotations
+
+import hashlib
+import json
+import logging
+import shutil
+import sqlite3
+import zipfile
+from dataclasses import dataclass
+from datetime import UTC, datetime
+from pathlib import Path
+from typing import Any
+
+from server.core.storage_encryption import StorageEncryptionError, iter_plaintext
+from server.database.database import get_connection
+
+logger = logging.getLogger(__name__)
+
+PROJECT_FORMAT = "transcriptionsuite-project"
+PROJECT_VERSION = 1
+PROJECT_EXTENSION = ".tsproj"
+
+_MANIFEST = "manifest.json"
+_PROJECT = "project.json"
+_MEDIA_DIR = "media/"
+_HASH_BLOCK = 1024 * 1024
+
+# Recording columns that describe this machine's copy rather than the project.
+_LOCAL_RECORDING_COLUMNS = frozenset(
+    {"id", "filename", "filepath", "imported_at", "owner", "audio_retired_at"}
+)
+_LOCAL_RECORDING_PREFIXES = ("auto_",)
+
+# Tables keyed by recording, in insert order: rows pointing at another
+# table's ids come after it.
+_CHILD_TABLES = (
+    "segments",
+    "words",
+    "recording_speaker_aliases",
+    "annotations",
+    "redactions",
+    "chapters",
+    "recording_metadata",
+    "recording_diarization_review",
+)
+
+
+class ProjectBundleError(ValueError):
+    """The file is not a readable project bundle."""
+
+
+@dataclass(slots=True)
+class ProjectBundle:
+    manifest: dict[str, Any]
+    project: dict[str, Any]
+
+    @property
+    def media(self) -> dict[str, Any]:
+        return self.manifest.get("media") or {"mode": "none"}
+
+
+def _is_local_column(column: str) -> bool:
+    return column in _LOCAL_RECORDING_COLUMNS or column.startswith(_LOCAL_RECORDING_PREFIXES)
+
+
+def _rows(conn: sqlite3.Connection, table: str, recording_id: int) -> list[dict[str, Any]]:
+    try:
+        cursor = conn.execute(
+            f"SELECT * FROM {table} WHERE recording_id = ? ORDER BY rowid",  # noqa: S608
+            (recording_id,),
+        )
+    except sqlite3.OperationalError as exc:
+        if "no such table" in str(exc):
+            return []
+        raise
+    rows = [dict(row) for row in cursor.fetchall()]
+    for row in rows:
+        row.pop("recording_id", None)
+    return rows
+
+
+def collect_project(recording_id: int) -> dict[str, Any] | None:
+    """Everything stored for ``recording_id``; ``None`` when it does not exist."""
+    with get_connection() as conn:
+        row = conn.execute("SELECT * FROM recordings WHERE id = ?", (recording_id,)).fetchone()
+        if row is None:
+            return None
+        recording = {k: v for k, v in dict(row).items() if not _is_local_column(k)}
+        recording["filename"] = row["filename"]
+        project: dict[str, Any] = {"recording": recording}
+        for table in _CHILD_TABLES:
+            project[table] = _rows(conn, table, recording_id)
+    return project
+
+
+def _sha256_plaintext(path: Path) -> str:
+    digest = hashlib.sha256()
+    for block in iter_plaintext(path):
+        digest.update(block)
+    return digest.hexdigest()
+
+
+def export_project(
+    recording_id: int, dest: Path, *, embed_media: bool = True, app_version: str = ""
+) -> dict[str, Any]:
+    """Write ``recording_id`` to ``dest`` as a ``.tsproj``; returns the manifest.
+
+    Raises ``LookupError`` when the recording does not exist.
+    """
+    with get_connection() as conn:
+        row = conn.execute("SELECT * FROM recordings WHERE id = ?", (recording_id,)).fetchone()
+    project = collect_project(recording_id)
+    if row is None or project is None:
+        raise LookupError(f"Recording {recording_id} not found")
+
+    stored = dict(row)
+    audio = Path(str(stored["filepath"] or ""))
+    has_audio = not stored.get("audio_retired_at") and audio.is_file()
+    project_bytes = json.dumps(project, ensure_ascii=False, indent=2).encode("utf-8")
+    manifest: dict[str, Any] = {
+        "format": PROJECT_FORMAT,
+        "version": PROJECT_VERSION,
+        "created_at": datetime.now(UTC).isoformat(),
+        "app_version": app_version,
+        "files": {_PROJECT: hashlib.sha256(project_bytes).hexdigest()},
+        "media": {"mode": "none"},
+    }
+
+    with zipfile.ZipFile(dest, "w", compression=zipfile.ZIP_DEFLATED) as zf:
+        zf.writestr(_PROJECT, project_bytes)
+        if has_audio and embed_media:
+            member = f"{_MEDIA_DIR}{audio.name}"
+            digest = hashlib.sha256()
+            # Audio is already compressed; storing it keeps export fast.
+            with zf.open(zipfile.ZipInfo(member), "w", force_zip64=True) as out:
+                for block in iter_plaintext(audio):
+                    digest.update(block)
+                    out.write(block)
+            manifest["files"][member] = digest.hexdigest()
+            manifest["media"] = {"mode": "embedded", "name": audio.name, "member": member}
+        elif has_audio:
+            manifest["media"] = {
+                "mode": "reference",
+                "name": audio.name,
+                "path": str(audio),
+                "sha256": _sha256_plaintext(audio),
+            }
+        zf.writestr(_MANIFEST, json.dumps(manifest, indent=2))
+    return manifest
+
+
+def read_project(src: Path) -> ProjectBundle:
+    """Open and verify a bundle: every member must match the manifest's hash."""
+    try:
+        with zipfile.ZipFile(src) as zf:
+            try:
+                manifest = json.loads(zf.read(_MANIFEST))
+            except KeyError as exc:
+                raise ProjectBundleError("Not a project bundle: no manifest") from exc
+            if not isinstance(manifest, dict) or manifest.get("format") != PROJECT_FORMAT:
+                raise ProjectBundleError("Not a project bundle")
+            if manifest.get("version") != PROJECT_VERSION:
+                raise ProjectBundleError(
+                    f"Unsupported project bundle version {manifest.get('version')}"
+                )
+            files = manifest.get("files") or {}
+            if _PROJECT not in files:
+                raise ProjectBundleError("The bundle has no project data")
+            for member, expected in files.items():
+                digest = hashlib.sha256()
+                try:
+                    with zf.open(member) as fh:
+                        while block := fh.read(_HASH_BLOCK):
+                            digest.update(block)
+                except KeyError as exc:
+                    raise ProjectBundleError(f"The bundle is missing {member}") from exc
+                if digest.hexdigest() != expected:
+                    raise ProjectBundleError(f"{member} is damaged (checksum mismatch)")
+            project = json.loads(zf.read(_PROJECT))
+    except zipfile.BadZipFile as exc:
+        raise ProjectBundleError("Not a project bundle: not a zip archive") from exc
+    except json.JSONDecodeError as exc:
+        raise ProjectBundleError("The bundle's JSON is not readable") from exc
+    if not isinstance(project, dict) or not isinstance(project.get("recording"), dict):
+        raise ProjectBundleError("The bundle has no recording")
+    return ProjectBundle(manifest=manifest, project=project)
+
+
+def _unique_path(directory: Path, name: str) -> Path:
+    stem = "".join(c for c in Path(name).stem if c.isalnum() or c in "._- ")[:100] or "audio"
+    suffix = Path(name).suffix
+    candidate = directory / f"{stem}{suffix}"
+    counter = 2
+    while candidate.exists():
+        candidate = directory / f"{stem}-{counter}{suffix}"
+        counter += 1
+    return candidate
+
+
+def restore_media(src: Path, bundle: ProjectBundle, audio_dir: Path) -> Path | None:
+    """Put the bundle's audio into ``audio_dir``; ``None`` when there is none to use.
+
+    Embedded media is extracted. A reference is copied (decrypted) from its
+    original path, but only while the file there still has the exported hash.
+    """
+    media = bundle.media
+    if media.get("mode") == "embedded":
+        audio_dir.mkdir(parents=True, exist_ok=True)
+        dest = _unique_path(audio_dir, str(media.get("name") or "audio"))
+        with zipfile.ZipFile(src) as zf, zf.open(media["member"]) as fh, open(dest, "wb") as out:
+            shutil.copyfileobj(fh, out, _HASH_BLOCK)
+        return dest
+    if media.get("mode") == "reference":
+        source = Path(str(media.get("path") or ""))
+        try:
+            if not source.is_file() or _sha256_plaintext(source) != media.get("sha256"):
+                return None
+        except StorageEncryptionError:
+            return None
+        audio_dir.mkdir(parents=True, exist_ok=True)
+        dest = _unique_path(audio_dir, source.name)
+        with open(dest, "wb") as out:
+            for block in iter_plaintext(source):
+                out.write(block)
+        return dest
+    return None
+
+
+def text_only_path(audio_dir: Path, bundle: ProjectBundle) -> Path:
+    """A free ``.transcript`` path for a recording without audio, so playback 404s."""
+    name = str(bundle.project["recording"].get("filename") or "project")
+    stem = "".join(c for c in Path(name).stem if c.isalnum() or c in "._- ")[:100] or "project"
+    with get_connection() as conn:
+        candidate, counter = audio_dir / f"{stem}.transcript", 2
+        while conn.execute(
+            "SELECT 1 FROM recordings WHERE filepath = ?", (str(candidate),)
+        ).fetchone():
+            candidate = audio_dir / f"{stem}-{counter}.transcript"
+            counter += 1
+    return candidate
+
+
+def _columns(conn: sqlite3.Connection, table: str) -> set[str]:
+    return {row[1] for row in conn.execute(f"PRAGMA table_info({table})")}
+
+
+def _insert(conn: sqlite3.Connection, table: str, row: dict[str, Any]) -> int:
+    names = list(row)
+    cursor = conn.execute(
+        f"INSERT INTO {table} ({', '.join(names)}) "  # noqa: S608
+        f"VALUES ({', '.join('?' * len(names))})",
+        [row[name] for name in names],
+    )
+    return cursor.lastrowid or 0
+
+
+def restore_project(project: dict[str, Any], audio_path: Path, *, owner: str | None = None) -> int:
+    """Insert ``project`` as a new recording stored at ``audio_path``; returns its id.
+
+    One transaction: a bundle either imports completely or not at all.
+    """
+    with get_connection() as conn:
+        try:
+            columns = _columns(conn, "recordings")
+            recording = {
+                k: v
+                for k, v in project["recording"].items()
+                if k in columns and not _is_local_column(k)
+            }
+            recording.update(filename=audio_path.name, filepath=str(audio_path), owner=owner)
+            recording_id = _insert(conn, "recordings", recording)
+
+            ids: dict[str, dict[int, int]] = {"segments": {}, "annotations": {}}
+            refs = {"segment_id": "segments", "parent_id": "annotations"}
+            for table in _CHILD_TABLES:
+                rows = project.get(table) or []
+                columns = _columns(conn, table)
+                if rows and not columns:
+                    logger.warning("Skipping %d %s rows: no such table here", len(rows), table)
+                    continue
+                for raw in rows:
+                    old_id = raw.get("id")
+                    row = {k: v for k, v in raw.items() if k in columns and k != "id"}
+                    for column, target in refs.items():
+                        if row.get(column) is not None:
+                            row[column] = ids[target].get(int(row[column]))
+                    row["recording_id"] = recording_id
+                    new_id = _insert(conn, table, row)
+                    if table in ids and old_id is not None:
+                        ids[table][int(old_id)] = new_id
+            conn.commit()
+        except Exception:
+            conn.rollback()
+            raise
+    return recording_id
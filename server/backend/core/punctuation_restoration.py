@@ -0,0 +1,171 @@
+"""Punctuation and casing restoration for engines that return bare lowercase text.
+
+Some backends (e.g. English-only Parakeet/Canary exports, CTC models) emit
+"so we met on monday and it went well" where Whisper would write "So we met
+on Monday, and it went well." When ``punctuation_restoration.enabled`` is
+set, ``AudioToTextRecorder.transcribe_audio`` passes finished segments
+through a local ONNX token-classification model so every engine's output
+reads the same. In ``auto`` mode only transcripts that carry no punctuation
+and no capitals are touched; ``always`` re-punctuates everything.
+
+Models are chosen per language (``punctuation_restoration.models``, with a
+``default`` entry for the rest). Each is a directory with ``model.onnx``,
+``tokenizer.json`` and ``config.json`` whose ``id2label`` either names the
+mark (``PERIOD``, ``COMMA``, ``QUESTION``, ...) or pairs mark and case as two
+characters — ``.U`` = "end with a period, capitalise", ``OO`` = "leave as
+is" — the layout of common BERT punctuation exports. onnxruntime and
+tokenizers ship with the whisper extra; without them (or without a model)
+transcripts are left unchanged.
+
+Only the text changes: segments and words keep their timings, and a word
+gets its mark and capital in place so word timestamps still line up.
+"""
+
+from __future__ import annotations
+
+import functools
+from collections.abc import Mapping, Sequence
+from typing import Any, Protocol
+
+from server.core.onnx_models import TokenClassifier, load_model
+
+MODES = ("auto", "always")
+
+# Words per model call; keeps sub-word tokens under the 512-token limit.
+WINDOW_WORDS = 150
+
+_NAMED_MARKS = {
+    "O": "",
+    "PERIOD": ".",
+    "COMMA": ",",
+    "QUESTION": "?",
+    "EXCLAMATION": "!",
+    "COLON": ":",
+    "SEMICOLON": ";",
+}
+_MARKS = ".,?!:;"
+_SENTENCE_END = ".?!"
+# Any of these in a transcript means the engine punctuates on its own.
+_NATIVE_MARKS = set(".,?!;:。？！，、")
+
+
+class PunctuationPredictor(Protocol):
+    def predict(self, words: Sequence[str]) -> list[tuple[str, bool]]:
+        """``(mark, capitalise)`` for each word; ``mark`` is "" for none."""
+        ...
+
+
+def parse_label(label: str) -> tuple[str, bool]:
+    """``(mark, capitalise)`` for one ``id2label`` entry; unknown labels change nothing."""
+    named = _NAMED_MARKS.get(label.upper())
+    if named is not None:
+        return named, False
+    if len(label) == 2 and label[1] in "UO" and (label[0] in _MARKS or label[0] == "O"):
+        return ("" if label[0] == "O" else label[0]), label[1] == "U"
+    return "", False
+
+
+class PunctuationModel(TokenClassifier):
+    """ONNX token-classification model predicting a mark (and case) per word."""
+
+    def predict(self, words: Sequence[str]) -> list[tuple[str, bool]]:
+        enc, labels = self.classify(words)
+
+        # The mark belongs after a word's last sub-token, the case to its first.
+        out: list[tuple[str, bool]] = [("", False)] * len(words)
+        seen: set[int] = set()
+        for label, word_id in zip(labels, enc.word_ids, strict=True):
+            if word_id is None:
+                continue  # special token
+            mark, upper = parse_label(label)
+            first = word_id not in seen
+            seen.add(word_id)
+            out[word_id] = (mark, out[word_id][1] or (upper and first))
+        return out
+
+
+@functools.lru_cache(maxsize=4)
+def load_punctuation_model(model_dir: str) -> PunctuationModel | None:
+    """The model in ``model_dir``, or None (no restoration) when it can't load."""
+    return load_model(PunctuationModel, model_dir, "Punctuation restoration off")
+
+
+def model_dir_for(models: Mapping[str, Any], language: str | None) -> str:
+    """Configured model directory for ``language`` ("en-US" → "en"), else ``default``."""
+    base = (language or "").lower().replace("_", "-").split("-")[0]
+    return str(models.get(base) or models.get("default") or "")
+
+
+def lacks_punctuation(texts: Sequence[str]) -> bool:
+    """True when the text has letters but neither punctuation nor capitals."""
+    joined = " ".join(texts)
+    if not any(c.isalpha() for c in joined):
+        return False
+    return not any(c in _NATIVE_MARKS or c.isupper() for c in joined)
+
+
+def _apply(token: str, mark: str, upper: bool) -> str:
+    """``token`` with its capital and mark; leading spaces (" word") are kept."""
+    core = token.lstrip()
+    lead = token[: len(token) - len(core)]
+    if not core:
+        return token
+    if upper:
+        core = core[0].upper() + core[1:]
+    if mark and core[-1] not in _MARKS:
+        core += mark
+    return lead + core
+
+
+def restore_segments(segments: list[dict[str, Any]], model: PunctuationPredictor) -> None:
+    """Punctuate and case ``segments`` in place, reading across segment boundaries.
+
+    Each segment's text is split on whitespace; its ``words`` (when their
+    count matches) receive the same marks so word-level output agrees.
+    """
+    tokens: list[tuple[int, str]] = []
+    for s, seg in enumerate(segments):
+        tokens.extend((s, word) for word in str(seg.get("text") or "").split())
+    if not tokens:
+        return
+
+    labels: list[tuple[str, bool]] = []
+    for i in range(0, len(tokens), WINDOW_WORDS):
+        labels.extend(model.predict([w for _, w in tokens[i : i + WINDOW_WORDS]]))
+
+    # Sentences start with a capital whatever the model thinks.
+    sentence_start = True
+    per_segment: dict[int, list[tuple[str, bool]]] = {}
+    for (s, _), (mark, upper) in zip(tokens, labels, strict=True):
+        per_segment.setdefault(s, []).append((mark, upper or sentence_start))
+        sentence_start = bool(mark) and mark in _SENTENCE_END
+    if not sentence_start:
+        s = tokens[-1][0]
+        per_segment[s][-1] = (".", per_segment[s][-1][1])
+
+    for s, marks in per_segment.items():
+        seg = segments[s]
+        words = str(seg["text"]).split()
+        seg["text"] = " ".join(_apply(w, m, u) for w, (m, u) in zip(words, marks, strict=True))
+        seg_words = seg.get("words")
+        if seg_words and len(seg_words) == len(marks):
+            for word, (mark, upper) in zip(seg_words, marks, strict=True):
+                word["word"] = _apply(str(word.get("word") or ""), mark, upper)
+
+
+def restore_punctuation(segments: list[dict[str, Any]], language: str | None) -> bool:
+    """Apply the configured restoration to engine output; True when text changed."""
+    from server.config import get_config
+
+    cfg = get_config()
+    if not cfg.get("punctuation_restoration", "enabled", default=False):
+        return False
+    mode = cfg.get("punctuation_restoration", "mode", default="auto")
+    if mode != "always" and not lacks_punctuation([str(s.get("text") or "") for s in segments]):
+        return False
+    models = cfg.get("punctuation_restoration", "models", default={}) or {}
+    model = load_punctuation_model(model_dir_for(models, language))
+    if model is None:
+        return False
+    restore_segments(segments, model)
+    return True
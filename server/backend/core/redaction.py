@@ -0,0 +1,208 @@
+"""Redaction: mask sensitive ranges in text exports, mute or bleep them in audio.
+
+A redaction is a time range. A word is redacted when its midpoint falls
+inside a range; a run of consecutive redacted words collapses into a single
+``MASK`` so neither the words nor their count leak. Segments without stored
+word timings are split with ``proportional_words`` so their text can be
+masked the same way.
+
+Audio uses one ffmpeg pass: ``mute`` zeroes the ranges, ``bleep`` also mixes
+a 1 kHz tone into them. Video streams are copied untouched.
+"""
+
+from __future__ import annotations
+
+import logging
+import shutil
+import subprocess
+from collections.abc import Iterable, Iterator, Mapping
+from pathlib import Path
+from typing import Any, Literal
+
+logger = logging.getLogger(__name__)
+
+MASK = "█████"
+AUDIO_MODES: tuple[str, ...] = ("mute", "bleep")
+BLEEP_HZ = 1000
+BLEEP_VOLUME = 0.3
+AudioMode = Literal["mute", "bleep"]
+
+
+def merge_ranges(ranges: Iterable[Mapping[str, Any]]) -> list[tuple[float, float]]:
+    """Sorted, non-overlapping ``(start, end)`` pairs from redaction rows."""
+    spans = sorted(
+        (float(r["start_time"]), float(r["end_time"]))
+        for r in ranges
+        if float(r["end_time"]) > float(r["start_time"])
+    )
+    merged: list[tuple[float, float]] = []
+    for start, end in spans:
+        if merged and start <= merged[-1][1]:
+            merged[-1] = (merged[-1][0], max(merged[-1][1], end))
+        else:
+            merged.append((start, end))
+    return merged
+
+
+def _covered(start: float, end: float, spans: list[tuple[float, float]]) -> bool:
+    mid = (start + end) / 2
+    return any(a <= mid <= b for a, b in spans)
+
+
+def _mask_tokens(tokens: list[tuple[str, float, float]], spans: list[tuple[float, float]]) -> str:
+    out: list[str] = []
+    for token, start, end in tokens:
+        if _covered(start, end, spans):
+            if not out or out[-1] != MASK:
+                out.append(MASK)
+        else:
+            out.append(token)
+    return " ".join(out)
+
+
+def redact_words(
+    words: list[dict[str, Any]], spans: list[tuple[float, float]]
+) -> list[dict[str, Any]]:
+    """Copies of ``words`` with redacted runs collapsed into one masked word."""
+    out: list[dict[str, Any]] = []
+    previous_masked = False
+    for word in sorted(words, key=lambda w: float(w.get("start_time") or 0.0)):
+        start, end = float(word.get("start_time") or 0.0), float(word.get("end_time") or 0.0)
+        if not _covered(start, end, spans):
+            out.append(dict(word))
+            previous_masked = False
+            continue
+        if previous_masked and out[-1].get("segment_id") == word.get("segment_id"):
+            out[-1]["end_time"] = end
+            continue
+        out.append({**word, "word": MASK, "confidence": None})
+        previous_masked = True
+    return out
+
+
+def redact_transcript(
+    segments: list[dict[str, Any]],
+    words: list[dict[str, Any]],
+    ranges: Iterable[Mapping[str, Any]],
+) -> tuple[list[dict[str, Any]], list[dict[str, Any]]]:
+    """Return ``(segments, words)`` with every redacted range masked.
+
+    Inputs are database rows (``start_time`` / ``end_time``); nothing is
+    modified in place. No ranges → the inputs are returned unchanged.
+    """
+    spans = merge_ranges(ranges)
+    if not spans:
+        return segments, words
+    from server.core.forced_alignment import proportional_words
+
+    by_segment: dict[Any, list[dict[str, Any]]] = {}
+    for word in sorted(words, key=lambda w: float(w.get("start_time") or 0.0)):
+        by_segment.setdefault(word.get("segment_id"), []).append(word)
+
+    out_segments: list[dict[str, Any]] = []
+    for seg in segments:
+        seg_start = float(seg.get("start_time") or 0.0)
+        seg_end = float(seg.get("end_time") or 0.0)
+        if not any(a < seg_end and b > seg_start for a, b in spans):
+            out_segments.append(dict(seg))
+            continue
+        seg_words = by_segment.get(seg.get("id"))
+        if seg_words:
+            tokens = [
+                (str(w.get("word") or "").strip(), float(w["start_time"]), float(w["end_time"]))
+                for w in seg_words
+            ]
+        else:
+            estimated = proportional_words(
+                [{"start": seg_start, "end": seg_end, "text": seg.get("text")}]
+            )
+            tokens = [(w["word"], w["start"], w["end"]) for w in estimated]
+        out_segments.append({**seg, "text": _mask_tokens(tokens, spans)})
+    return out_segments, redact_words(words, spans)
+
+
+def redacted_transcript(
+    recording_id: int, *, enabled: bool = True
+) -> tuple[list[dict[str, Any]], list[dict[str, Any]]]:
+    """Load a recording's segments and words with its redactions applied."""
+    from server.database import redaction_repository
+    from server.database.database import get_segments, get_words
+
+    segments, words = get_segments(recording_id), get_words(recording_id)
+    if not enabled:
+        return segments, words
+    return redact_transcript(segments, words, redaction_repository.list_redactions(recording_id))
+
+
+def iter_export_segments(recording_id: int, *, enabled: bool = True) -> Iterator[dict[str, Any]]:
+    """Segments for streaming exports.
+
+    Masking needs word timings, so a recording with redactions is
+    materialized; one without keeps ``iter_segments``' bounded-RAM stream.
+    """
+    from server.database import redaction_repository
+    from server.database.database import iter_segments
+
+    if enabled and redaction_repository.list_redactions(recording_id):
+        return iter(redacted_transcript(recording_id)[0])
+    return iter_segments(recording_id)
+
+
+def _enable_expr(spans: list[tuple[float, float]]) -> str:
+    return "+".join(f"between(t,{a:.3f},{b:.3f})" for a, b in spans)
+
+
+def build_audio_filter(spans: list[tuple[float, float]], mode: AudioMode = "mute") -> str:
+    """ffmpeg ``-filter_complex`` graph producing ``[out]`` from input ``0:a``."""
+    if mode not in AUDIO_MODES:
+        raise ValueError(f"audio redaction mode must be one of {', '.join(AUDIO_MODES)}")
+    if not spans:
+        return "[0:a]anull[out]"
+    enable = _enable_expr(spans)
+    muted = f"[0:a]volume=0:enable='{enable}'"
+    if mode == "mute":
+        return f"{muted}[out]"
+    return (
+        f"{muted}[muted];"
+        f"sine=frequency={BLEEP_HZ}:sample_rate=48000,"
+        f"volume={BLEEP_VOLUME},volume=0:enable='not({enable})'[tone];"
+        "[muted][tone]amix=inputs=2:duration=first:dropout_transition=0:normalize=0[out]"
+    )
+
+
+def render_redacted_media(
+    source: Path,
+    destination: Path,
+    ranges: Iterable[Mapping[str, Any]],
+    mode: AudioMode = "mute",
+) -> Path:
+    """Write ``source`` to ``destination`` with redacted ranges muted / bleeped.
+
+    The container follows ``destination``'s suffix. Raises RuntimeError when
+    ffmpeg is missing or fails (stderr stays in the server log).
+    """
+    if not shutil.which("ffmpeg"):
+        raise RuntimeError("ffmpeg is not installed or not in PATH")
+    graph = build_audio_filter(merge_ranges(ranges), mode)
+    cmd = [
+        "ffmpeg",
+        "-y",
+        "-i",
+        str(source),
+        "-filter_complex",
+        graph,
+        "-map",
+        "0:v?",
+        "-map",
+        "[out]",
+        "-c:v",
+        "copy",
+        str(destination),
+    ]
+    try:
+        subprocess.run(cmd, capture_output=True, check=True, timeout=1800)
+    except (subprocess.CalledProcessError, subprocess.TimeoutExpired) as exc:
+        stderr = getattr(exc, "stderr", b"") or b""
+        logger.warning("Redacted media render failed: %s", stderr[-2000:])
+        raise RuntimeError("ffmpeg failed to render the redacted media") from exc
+    return destination
@@ -0,0 +1,144 @@
+"""Per-segment re-transcription: redo one segment with a different model.
+
+The segment's span (plus a little padding, so words at the edges are not
+clipped mid-phoneme) is decoded straight from the recording's audio with
+``AudioToTextRecorder.transcribe_file_span`` and transcribed with the
+requested model — typically a bigger Whisper than the one the recording
+was made with. Word timings come back relative to the clip; they are moved
+onto the recording timeline and only words whose midpoint falls inside the
+original segment are kept, so the padding never pulls in words that belong
+to the neighbours.
+
+The splice replaces that segment's text and words only; speaker, bounds and
+all other segments stay as they were.
+"""
+
+from __future__ import annotations
+
+import logging
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_PADDING_SECONDS = 0.3
+
+
+@dataclass
+class SegmentRetranscription:
+    """The re-transcribed text and words for one segment, on the recording timeline."""
+
+    segment_id: int
+    text: str
+    words: list[dict[str, Any]] = field(default_factory=list)
+    previous_text: str = ""
+    model: str = ""
+    language: str | None = None
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "segment_id": self.segment_id,
+            "text": self.text,
+            "previous_text": self.previous_text,
+            "words": self.words,
+            "model": self.model,
+            "language": self.language,
+        }
+
+
+def clip_bounds(
+    segment: dict[str, Any], *, padding: float, duration: float | None = None
+) -> tuple[float, float]:
+    """Padded ``(start, end)`` of a segment, clamped to the recording."""
+    start = max(0.0, float(segment.get("start_time") or 0.0) - padding)
+    end = float(segment.get("end_time") or 0.0) + padding
+    if duration:
+        end = min(end, duration)
+    if end <= start:
+        raise ValueError("segment has no duration")
+    return start, end
+
+
+def splice_words(
+    segment: dict[str, Any],
+    result_segments: list[dict[str, Any]],
+    clip_start: float,
+) -> tuple[str, list[dict[str, Any]]]:
+    """Text and words from a clip transcription that belong to ``segment``.
+
+    Backends without word timestamps only return segments; their text is
+    kept when the segment overlaps the original span, and words are spread
+    proportionally across it.
+    """
+    seg_start = float(segment.get("start_time") or 0.0)
+    seg_end = float(segment.get("end_time") or 0.0)
+    words: list[dict[str, Any]] = []
+    texts: list[str] = []
+    for result in result_segments:
+        r_start = clip_start + float(result.get("start") or 0.0)
+        r_end = clip_start + float(result.get("end") or 0.0)
+        if not result.get("words"):
+            if r_end > seg_start and r_start < seg_end:
+                texts.append(str(result.get("text") or "").strip())
+            continue
+        for word in result["words"]:
+            token = str(word.get("word") or "").strip()
+            start = clip_start + float(word.get("start") or 0.0)
+            end = clip_start + float(word.get("end") or 0.0)
+            if not token or not seg_start <= (start + end) / 2 <= seg_end:
+                continue
+            words.append(
+                {
+                    "word": token,
+                    # Clamp to the segment so the padding never leaks into its bounds.
+                    "start": round(max(start, seg_start), 3),
+                    "end": round(min(max(end, start), seg_end), 3),
+                    "confidence": word.get("probability", word.get("confidence")),
+                }
+            )
+    if words:
+        return " ".join(w["word"] for w in words), words
+    text = " ".join(t for t in texts if t)
+    if not text:
+        return "", []
+    from server.core.forced_alignment import proportional_words
+
+    spread = proportional_words([{"start": seg_start, "end": seg_end, "text": text}])
+    return text, [{"word": w["word"], "start": w["start"], "end": w["end"]} for w in spread]
+
+
+def retranscribe_segment(
+    engine: Any,
+    audio_path: Path,
+    segment: dict[str, Any],
+    *,
+    language: str | None = None,
+    padding: float = DEFAULT_PADDING_SECONDS,
+    duration: float | None = None,
+) -> SegmentRetranscription:
+    """Transcribe ``segment``'s span of ``audio_path`` with ``engine``."""
+    clip_start, clip_end = clip_bounds(segment, padding=padding, duration=duration)
+    result = engine.transcribe_file_span(
+        str(audio_path),
+        start_seconds=clip_start,
+        duration_seconds=clip_end - clip_start,
+        language=language,
+    )
+    text, words = splice_words(segment, result.segments, clip_start)
+    logger.info(
+        "Re-transcribed segment %s (%.2f–%.2fs) with %s: %d words",
+        segment.get("id"),
+        clip_start,
+        clip_end,
+        engine.model_name,
+        len(words),
+    )
+    return SegmentRetranscription(
+        segment_id=int(segment["id"]),
+        text=text,
+        words=words,
+        previous_text=str(segment.get("text") or ""),
+        model=engine.model_name,
+        language=result.language,
+    )
@@ -0,0 +1,49 @@
+"""Per-segment QA review state for transcript review mode.
+
+Each segment is *unreviewed* (``review_state`` NULL), ``approved`` or
+``flagged``. The viewer toggles states from the keyboard and jumps between
+unreviewed / flagged segments; this module owns the vocabulary and the
+progress figures shown in the review header.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Iterable, Mapping
+from typing import Any
+
+REVIEW_STATES: tuple[str, ...] = ("approved", "flagged")
+
+
+def normalize_review_state(value: str | None) -> str | None:
+    """Return a stored state for ``value``; ``None``/"" / "unreviewed" clear it.
+
+    Raises ValueError for anything else.
+    """
+    if value is None:
+        return None
+    state = value.strip().lower()
+    if state in ("", "unreviewed"):
+        return None
+    if state not in REVIEW_STATES:
+        raise ValueError(
+            f"review state must be one of {', '.join(REVIEW_STATES)} or unreviewed; got {value!r}"
+        )
+    return state
+
+
+def review_progress(segments: Iterable[Mapping[str, Any]]) -> dict[str, Any]:
+    """Counts per state plus ``reviewed_fraction`` (approved + flagged over total)."""
+    total = approved = flagged = 0
+    for seg in segments:
+        total += 1
+        state = seg.get("review_state")
+        approved += state == "approved"
+        flagged += state == "flagged"
+    reviewed = approved + flagged
+    return {
+        "total": total,
+        "approved": approved,
+        "flagged": flagged,
+        "unreviewed": total - reviewed,
+        "reviewed_fraction": round(reviewed / total, 4) if total else 0.0,
+    }
@@ -0,0 +1,419 @@
+"""Direct share targets for finished transcripts.
+
+A share target is a named, user-configured destination under the
+``share.targets`` list in ``config.yaml``. Three target types ship:
+
+  - ``email``   — compose a message with the transcript attached. Sent via
+    SMTP when ``smtp_host`` is set; otherwise the unsent ``.eml`` draft is
+    returned in the API response for the caller to save.
+  - ``slack`` / ``discord`` — POST a short summary to an incoming webhook.
+  - ``webdav``  — PUT the rendered export to a WebDAV collection (Nextcloud,
+    ownCloud, any RFC 4918 server).
+
+Secrets never live in ``config.yaml``. Each target reads its credentials from
+the OS keychain under ``share.<target name>.<field>`` (e.g.
+``share.team-slack.webhook_url``, ``share.nextcloud.password``) via
+:mod:`server.utils.keychain`, so the same NFR8 "no plaintext secrets on disk"
+guarantee the profile webhook tokens get applies here too.
+
+The dashboard's Share dialog asks for any credential a target is still
+missing (``missing_credentials``) and stores it through
+``set_credentials``; values are written to the server's keychain and never
+read back out over the API.
+
+Adding a target type is a one-line change to ``SHARE_TARGET_TYPES``.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+import smtplib
+from dataclasses import dataclass, field
+from email.message import EmailMessage
+from email.utils import formatdate, make_msgid
+from typing import Any
+from urllib.parse import quote
+
+from server.core.webhook_url_validation import WebhookUrlValidationError, validate_webhook_url
+
+logger = logging.getLogger(__name__)
+
+HTTP_TIMEOUT_S = 30.0
+SUMMARY_PREVIEW_CHARS = 600
+SHARE_EXPORT_FORMATS = ("plaintext", "srt", "ass")
+
+_EXPORT_MEDIA_TYPES = {
+    "plaintext": ("text/plain; charset=utf-8", "txt"),
+    "srt": ("application/x-subrip; charset=utf-8", "srt"),
+    "ass": ("text/x-ass; charset=utf-8", "ass"),
+}
+
+
+class ShareError(RuntimeError):
+    """Raised when a share attempt cannot be completed.
+
+    ``code`` is a short machine-readable token the route maps to an HTTP
+    status (``unknown_target`` → 404, ``missing_credential`` /
+    ``invalid_config`` / ``url_blocked`` → 400, ``delivery_failed`` → 502).
+    """
+
+    def __init__(self, code: str, message: str) -> None:
+        self.code = code
+        super().__init__(message)
+
+
+@dataclass(slots=True)
+class ShareContent:
+    """Everything a target may need about the recording being shared."""
+
+    title: str
+    summary: str
+    attachment_name: str
+    attachment_bytes: bytes
+    attachment_media_type: str
+    extra: dict[str, Any] = field(default_factory=dict)
+
+
+@dataclass(slots=True)
+class ShareResult:
+    target: str
+    target_type: str
+    status: str  # "sent" | "uploaded" | "draft"
+    detail: dict[str, Any] = field(default_factory=dict)
+
+
+def _get_httpx():
+    """Import httpx lazily (mirrors ``server.core.webhook``)."""
+    import httpx
+
+    return httpx
+
+
+def _secret(target_name: str, field_name: str) -> str:
+    """Fetch a required credential from the keychain or raise."""
+    from server.utils import keychain
+
+    value = keychain.get(f"share.{target_name}.{field_name}")
+    if not value:
+        raise ShareError(
+            "missing_credential",
+            f"Share target '{target_name}' has no '{field_name}' stored in the keychain "
+            f"(key: share.{target_name}.{field_name})",
+        )
+    return value
+
+
+def _check_url(url: str, *, allow_private: bool) -> None:
+    try:
+        validate_webhook_url(url)
+    except WebhookUrlValidationError as exc:
+        # LAN-hosted Nextcloud is the common homelab case; it must be opted
+        # into per target rather than silently allowed for every target.
+        if allow_private and exc.code == "private_ip_blocked":
+            return
+        raise ShareError("url_blocked", f"Share URL rejected ({exc.code})") from exc
+
+
+class ShareTarget:
+    """Base class for share targets. Subclasses implement :meth:`send`."""
+
+    type_name = ""
+    # Keychain fields (``share.<name>.<field>``) this target type reads.
+    credential_fields: tuple[str, ...] = ()
+
+    def __init__(self, name: str, settings: dict[str, Any]) -> None:
+        self.name = name
+        self.settings = settings
+
+    def required_credentials(self) -> tuple[str, ...]:
+        """The credential fields :meth:`send` needs with the current settings."""
+        return self.credential_fields
+
+    async def send(self, content: ShareContent) -> ShareResult:
+        raise NotImplementedError
+
+
+class EmailShareTarget(ShareTarget):
+    """Compose a message with the transcript attached."""
+
+    type_name = "email"
+    credential_fields = ("smtp_password",)
+
+    def required_credentials(self) -> tuple[str, ...]:
+        smtp_host = str(self.settings.get("smtp_host") or "").strip()
+        username = str(self.settings.get("smtp_username") or "").strip()
+        return self.credential_fields if smtp_host and username else ()
+
+    def build_message(self, content: ShareContent) -> EmailMessage:
+        to_addr = str(self.settings.get("to") or "").strip()
+        from_addr = str(self.settings.get("from") or to_addr).strip()
+        msg = EmailMessage()
+        msg["Subject"] = f"{self.settings.get('subject_prefix', 'Transcript:')} {content.title}"
+        if from_addr:
+            msg["From"] = from_addr
+        if to_addr:
+            msg["To"] = to_addr
+        msg["Date"] = formatdate(localtime=True)
+        msg["Message-ID"] = make_msgid(domain="transcriptionsuite.local")
+        msg.set_content(content.summary or content.title)
+        maintype, _, subtype = content.attachment_media_type.split(";")[0].partition("/")
+        msg.add_attachment(
+            content.attachment_bytes,
+            maintype=maintype,
+            subtype=subtype,
+            filename=content.attachment_name,
+        )
+        return msg
+
+    async def send(self, content: ShareContent) -> ShareResult:
+        msg = self.build_message(content)
+        smtp_host = str(self.settings.get("smtp_host") or "").strip()
+        if not smtp_host:
+            # No SMTP relay: return an unsent draft. ``X-Unsent`` makes
+            # Outlook / Thunderbird open it in compose mode.
+            msg["X-Unsent"] = "1"
+            return ShareResult(
+                target=self.name,
+                target_type=self.type_name,
+                status="draft",
+                detail={"eml": msg.as_string(), "filename": f"{content.attachment_name}.eml"},
+            )
+
+        if not msg["To"]:
+            raise ShareError("invalid_config", f"Share target '{self.name}' has no 'to' address")
+        port = int(self.settings.get("smtp_port") or 587)
+        username = str(self.settings.get("smtp_username") or "").strip()
+        password = _secret(self.name, "smtp_password") if username else ""
+
+        def _deliver() -> None:
+            with smtplib.SMTP(smtp_host, port, timeout=HTTP_TIMEOUT_S) as smtp:
+                if self.settings.get("starttls", True):
+                    smtp.starttls()
+                if username:
+                    smtp.login(username, password)
+                smtp.send_message(msg)
+
+        try:
+            await asyncio.to_thread(_deliver)
+        except (OSError, smtplib.SMTPException) as exc:
+            raise ShareError("delivery_failed", f"SMTP delivery failed: {exc}") from exc
+        return ShareResult(target=self.name, target_type=self.type_name, status="sent")
+
+
+class _ChatWebhookShareTarget(ShareTarget):
+    """Shared POST logic for Slack / Discord incoming webhooks."""
+
+    credential_fields = ("webhook_url",)
+
+    def build_payload(self, content: ShareContent) -> dict[str, Any]:
+        raise NotImplementedError
+
+    async def send(self, content: ShareContent) -> ShareResult:
+        url = _secret(self.name, "webhook_url")
+        _check_url(url, allow_private=False)
+        httpx = _get_httpx()
+        try:
+            async with httpx.AsyncClient(timeout=HTTP_TIMEOUT_S) as client:
+                response = await client.post(url, json=self.build_payload(content))
+        except Exception as exc:
+            raise ShareError("delivery_failed", f"Webhook POST failed: {exc}") from exc
+        if response.status_code >= 400:
+            raise ShareError("delivery_failed", f"Webhook returned HTTP {response.status_code}")
+        return ShareResult(
+            target=self.name,
+            target_type=self.type_name,
+            status="sent",
+            detail={"status_code": response.status_code},
+        )
+
+
+class SlackShareTarget(_ChatWebhookShareTarget):
+    type_name = "slack"
+
+    def build_payload(self, content: ShareContent) -> dict[str, Any]:
+        return {"text": f"*{content.title}*\n{content.summary}"}
+
+
+class DiscordShareTarget(_ChatWebhookShareTarget):
+    type_name = "discord"
+
+    def build_payload(self, content: ShareContent) -> dict[str, Any]:
+        # Discord rejects message content over 2000 characters.
+        return {"content": f"**{content.title}**\n{content.summary}"[:2000]}
+
+
+class WebDavShareTarget(ShareTarget):
+    """Upload the rendered export to a WebDAV collection (Nextcloud et al.)."""
+
+    type_name = "webdav"
+    credential_fields = ("password",)
+
+    def upload_url(self, filename: str) -> str:
+        base = str(self.settings.get("url") or "").strip()
+        if not base:
+            raise ShareError("invalid_config", f"Share target '{self.name}' has no 'url'")
+        remote_dir = str(self.settings.get("remote_dir") or "").strip("/")
+        parts = [base.rstrip("/")]
+        if remote_dir:
+            parts.append("/".join(quote(p, safe="") for p in remote_dir.split("/") if p))
+        parts.append(quote(filename, safe=""))
+        return "/".join(parts)
+
+    async def send(self, content: ShareContent) -> ShareResult:
+        url = self.upload_url(content.attachment_name)
+        _check_url(url, allow_private=bool(self.settings.get("allow_private_network", False)))
+        username = str(self.settings.get("username") or "").strip()
+        password = _secret(self.name, "password")
+        httpx = _get_httpx()
+        try:
+            async with httpx.AsyncClient(timeout=HTTP_TIMEOUT_S) as client:
+                response = await client.put(
+                    url,
+                    content=content.attachment_bytes,
+                    headers={"Content-Type": content.attachment_media_type},
+                    auth=(username, password),
+                )
+        except Exception as exc:
+            raise ShareError("delivery_failed", f"WebDAV upload failed: {exc}") from exc
+        if response.status_code >= 400:
+            raise ShareError("delivery_failed", f"WebDAV returned HTTP {response.status_code}")
+        return ShareResult(
+            target=self.name,
+            target_type=self.type_name,
+            status="uploaded",
+            detail={"url": url, "status_code": response.status_code},
+        )
+
+
+SHARE_TARGET_TYPES: dict[str, type[ShareTarget]] = {
+    "email": EmailShareTarget,
+    "slack": SlackShareTarget,
+    "discord": DiscordShareTarget,
+    "webdav": WebDavShareTarget,
+}
+
+
+def configured_targets() -> list[dict[str, Any]]:
+    """Return the ``share.targets`` list from config (invalid entries dropped)."""
+    from server.config import get_config
+
+    raw = get_config().get("share", "targets", default=[]) or []
+    targets: list[dict[str, Any]] = []
+    for entry in raw:
+        if not isinstance(entry, dict):
+            continue
+        name = str(entry.get("name") or "").strip()
+        if not name or entry.get("type") not in SHARE_TARGET_TYPES:
+            logger.warning("Ignoring malformed share target entry: %r", entry.get("name"))
+            continue
+        targets.append(entry)
+    return targets
+
+
+def get_target(name: str) -> ShareTarget:
+    """Instantiate the configured target called ``name``."""
+    for entry in configured_targets():
+        if entry["name"] == name:
+            return SHARE_TARGET_TYPES[entry["type"]](name, entry)
+    raise ShareError("unknown_target", f"No share target named '{name}'")
+
+
+def missing_credentials(target: ShareTarget) -> list[str]:
+    """Fields ``target`` needs that have no keychain entry yet."""
+    from server.utils import keychain
+
+    return [
+        f for f in target.required_credentials() if not keychain.get(f"share.{target.name}.{f}")
+    ]
+
+
+def set_credentials(target_name: str, values: dict[str, str]) -> list[str]:
+    """Store ``values`` (field → secret) in the keychain for ``target_name``.
+
+    An empty value removes the entry. Returns the fields still missing.
+    """
+    from server.utils import keychain
+
+    target = get_target(target_name)
+    unknown = sorted(set(values) - set(target.credential_fields))
+    if unknown:
+        raise ShareError(
+            "invalid_config",
+            f"Share target '{target_name}' has no credential {', '.join(unknown)}",
+        )
+    for field_name, value in values.items():
+        key = f"share.{target.name}.{field_name}"
+        if value:
+            keychain.set(key, value)
+        else:
+            keychain.delete(key)
+    logger.info("Updated credentials %s for share target '%s'", sorted(values), target.name)
+    return missing_credentials(target)
+
+
+def build_share_content(recording_id: int, export_format: str = "plaintext") -> ShareContent:
+    """Render ``recording_id`` for sharing (alias-substituted and redacted, like exports)."""
+    from server.core.alias_substitution import apply_aliases
+    from server.core.plaintext_export import stream_plaintext
+    from server.core.redaction import redacted_transcript
+    from server.core.subtitle_export import build_subtitle_cues, render_ass, render_srt
+    from server.core.timecode import parse_settings
+    from server.database import alias_repository
+    from server.database.database import get_recording
+
+    if export_format not in SHARE_EXPORT_FORMATS:
+        raise ShareError("invalid_config", f"Unsupported share format '{export_format}'")
+    recording = get_recording(recording_id)
+    if not recording:
+        raise ShareError("unknown_recording", "Recording not found")
+
+    title = recording.get("title") or recording.get("filename") or "Recording"
+    segments, words = redacted_transcript(recording_id)
+    aliases = alias_repository.alias_map(recording_id)
+    if export_format == "plaintext":
+        body = "".join(stream_plaintext(recording, apply_aliases(iter(segments), aliases)))
+    else:
+        cues = build_subtitle_cues(
+            segments=segments,
+            words=words,
+            has_diarization=bool(recording.get("has_diarization")),
+            alias_overrides=aliases,
+        )
+        timecode = parse_settings(recording.get("timecode_settings"))
+        body = (
+            render_srt(cues, timecode=timecode)
+            if export_format == "srt"
+            else render_ass(cues, title=title, timecode=timecode)
+        )
+
+    summary = (recording.get("summary") or "").strip()
+    if not summary:
+        flat = " ".join(str(s.get("text") or "").strip() for s in segments).strip()
+        summary = flat[:SUMMARY_PREVIEW_CHARS] + ("…" if len(flat) > SUMMARY_PREVIEW_CHARS else "")
+
+    media_type, ext = _EXPORT_MEDIA_TYPES[export_format]
+    return ShareContent(
+        title=title,
+        summary=summary,
+        attachment_name=f"{title.replace(' ', '_')}.{ext}",
+        attachment_bytes=body.encode("utf-8"),
+        attachment_media_type=media_type,
+    )
+
+
+async def share_recording(
+    recording_id: int, target_name: str, export_format: str = "plaintext"
+) -> ShareResult:
+    """Resolve ``target_name``, render the recording, and send it."""
+    target = get_target(target_name)
+    content = build_share_content(recording_id, export_format)
+    result = await target.send(content)
+    logger.info(
+        "Shared recording %d via %s target '%s' (%s)",
+        recording_id,
+        target.type_name,
+        target.name,
+        result.status,
+    )
+    return result
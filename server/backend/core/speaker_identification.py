@@ -0,0 +1,278 @@
+"""Speaker identification: match diarized speakers to enrolled voice profiles.
+
+Enrollment records a few seconds of one person's voice, turns it into a
+speaker embedding with a local ONNX model and stores it as a voice profile
+(``database/voice_profile_repository.py``); more samples refine the mean.
+
+After a diarized transcription is saved, each speaker's longest turns are
+embedded and compared with every profile by cosine similarity. Matches are
+assigned one-to-one, best score first, and only above
+``speaker_identification.threshold``; each match is written as that
+recording's speaker alias, so "SPEAKER_01" shows as "Bob" everywhere aliases
+are read. Speakers the user already named are left alone.
+
+The model is a WeSpeaker-style ONNX export (``speaker_identification.model_path``)
+that takes Kaldi 80-bin log-mel filterbanks shaped ``[batch, frames, 80]``
+and returns one embedding per batch row — e.g. ``voxceleb_resnet34_LM.onnx``.
+onnxruntime ships with the whisper extra; without it (or without a model)
+enrollment and identification are disabled.
+"""
+
+from __future__ import annotations
+
+import functools
+import logging
+from collections.abc import Iterable, Mapping, Sequence
+from pathlib import Path
+from typing import Any
+
+import numpy as np
+
+from server.core.onnx_models import load_model
+
+logger = logging.getLogger(__name__)
+
+SAMPLE_RATE = 16000
+NUM_MEL_BINS = 80
+
+DEFAULT_THRESHOLD = 0.5
+DEFAULT_MAX_SECONDS_PER_SPEAKER = 30.0
+
+# Shorter turns are mostly backchannel ("yeah", "mm") and embed poorly.
+MIN_TURN_SECONDS = 1.0
+MIN_ENROLL_SECONDS = 3.0
+MAX_ENROLL_SECONDS = 60.0
+
+
+def _mel_banks(num_bins: int, n_fft: int, sample_rate: int) -> np.ndarray:
+    """Triangular mel filters, ``(num_bins, n_fft // 2 + 1)``, laid out as in Kaldi."""
+
+    def mel(freq: np.ndarray | float) -> np.ndarray:
+        return 1127.0 * np.log1p(np.asarray(freq) / 700.0)
+
+    low, high = mel(20.0), mel(sample_rate / 2)
+    edges = low + (high - low) * np.arange(num_bins + 2) / (num_bins + 1)
+    bin_mels = mel(np.arange(n_fft // 2 + 1) * sample_rate / n_fft)
+    banks = np.zeros((num_bins, n_fft // 2 + 1))
+    for i in range(num_bins):
+        left, center, right = edges[i], edges[i + 1], edges[i + 2]
+        up = (bin_mels - left) / (center - left)
+        down = (right - bin_mels) / (right - center)
+        banks[i] = np.maximum(0.0, np.minimum(up, down))
+    return banks
+
+
+def fbank(audio: np.ndarray, sample_rate: int = SAMPLE_RATE) -> np.ndarray:
+    """Kaldi-compatible log-mel filterbank: 25 ms Povey frames, 10 ms hop.
+
+    Returns ``(frames, NUM_MEL_BINS)`` float32; empty when the audio is
+    shorter than one frame.
+    """
+    frame_len = int(sample_rate * 0.025)
+    hop = int(sample_rate * 0.010)
+    # Kaldi features are computed on int16-scaled samples.
+    signal = np.asarray(audio, dtype=np.float64) * 32768.0
+    if len(signal) < frame_len:
+        return np.zeros((0, NUM_MEL_BINS), dtype=np.float32)
+    n_frames = 1 + (len(signal) - frame_len) // hop
+    index = np.arange(frame_len)[None, :] + hop * np.arange(n_frames)[:, None]
+    frames = signal[index]
+    frames -= frames.mean(axis=1, keepdims=True)
+    frames[:, 1:] -= 0.97 * frames[:, :-1].copy()
+    frames[:, 0] *= 1.0 - 0.97
+    povey = np.power(0.5 - 0.5 * np.cos(2 * np.pi * np.arange(frame_len) / (frame_len - 1)), 0.85)
+    n_fft = 1 << (frame_len - 1).bit_length()
+    power = np.abs(np.fft.rfft(frames * povey, n=n_fft)) ** 2
+    energies = power @ _mel_banks(NUM_MEL_BINS, n_fft, sample_rate).T
+    return np.log(np.maximum(energies, np.finfo(np.float32).eps)).astype(np.float32)
+
+
+def normalize(vector: Sequence[float] | np.ndarray) -> np.ndarray:
+    arr = np.asarray(vector, dtype=np.float32).reshape(-1)
+    norm = float(np.linalg.norm(arr))
+    return arr / norm if norm > 0 else arr
+
+
+class SpeakerEmbedder:
+    """ONNX speaker-embedding model (WeSpeaker export)."""
+
+    def __init__(self, model_path: Path) -> None:
+        import onnxruntime
+
+        self._session = onnxruntime.InferenceSession(
+            str(model_path), providers=["CPUExecutionProvider"]
+        )
+        self._input = self._session.get_inputs()[0].name
+
+    def embed(self, audio: np.ndarray) -> np.ndarray:
+        """L2-normalised embedding of 16 kHz mono audio."""
+        feats = fbank(audio)
+        if len(feats) == 0:
+            raise ValueError("audio is too short to embed")
+        feats -= feats.mean(axis=0)  # per-utterance mean normalisation, as in training
+        (output, *_) = self._session.run(None, {self._input: feats[None, :, :]})
+        return normalize(output[0])
+
+
+@functools.lru_cache(maxsize=1)
+def load_speaker_embedder(model_path: str) -> SpeakerEmbedder | None:
+    """The configured embedding model, or None (identification off) when it can't load."""
+    return load_model(SpeakerEmbedder, model_path, "Speaker identification disabled")
+
+
+def configured_embedder() -> SpeakerEmbedder | None:
+    from server.config import get_config
+
+    path = get_config().get("speaker_identification", "model_path", default="") or ""
+    return load_speaker_embedder(str(path))
+
+
+def enrollment_embedding(embedder: SpeakerEmbedder, audio: np.ndarray) -> list[float]:
+    """Embedding of one enrollment sample; long samples are cut to the first minute."""
+    seconds = len(audio) / SAMPLE_RATE
+    if seconds < MIN_ENROLL_SECONDS:
+        raise ValueError(
+            f"Sample is {seconds:.1f}s long — record at least {MIN_ENROLL_SECONDS:.0f}s of speech"
+        )
+    clip = audio[: int(MAX_ENROLL_SECONDS * SAMPLE_RATE)]
+    return [float(v) for v in embedder.embed(clip)]
+
+
+def speaker_clips(
+    segments: Iterable[Mapping[str, Any]],
+    max_seconds: float = DEFAULT_MAX_SECONDS_PER_SPEAKER,
+) -> dict[str, list[tuple[float, float]]]:
+    """Longest turns per speaker, up to ``max_seconds`` of audio each.
+
+    Turns shorter than ``MIN_TURN_SECONDS`` are skipped; the last turn that
+    fits is trimmed to the remaining budget. Spans are in recording time.
+    """
+    turns: dict[str, list[tuple[float, float]]] = {}
+    for seg in segments:
+        speaker = seg.get("speaker")
+        start = float(seg.get("start_time") or 0.0)
+        end = float(seg.get("end_time") or 0.0)
+        if speaker and end - start >= MIN_TURN_SECONDS:
+            turns.setdefault(str(speaker), []).append((start, end))
+
+    clips: dict[str, list[tuple[float, float]]] = {}
+    for speaker, spans in turns.items():
+        budget = max_seconds
+        chosen: list[tuple[float, float]] = []
+        for start, end in sorted(spans, key=lambda s: s[1] - s[0], reverse=True):
+            if budget < MIN_TURN_SECONDS:
+                break
+            end = min(end, start + budget)
+            chosen.append((start, end))
+            budget -= end - start
+        clips[speaker] = sorted(chosen)
+    return clips
+
+
+def match_speakers(
+    speaker_embeddings: Mapping[str, Sequence[float] | np.ndarray],
+    profiles: Iterable[Mapping[str, Any]],
+    threshold: float = DEFAULT_THRESHOLD,
+) -> dict[str, tuple[str, float]]:
+    """One-to-one ``{speaker: (profile name, similarity)}``, best pairs first.
+
+    A profile names at most one speaker per recording and vice versa, so two
+    similar-sounding people never collapse onto the same name.
+    """
+    candidates: list[tuple[float, str, str]] = []
+    for profile in profiles:
+        reference = normalize(profile["embedding"])
+        for speaker, embedding in speaker_embeddings.items():
+            vector = normalize(embedding)
+            if vector.shape != reference.shape:
+                continue
+            score = float(np.dot(vector, reference))
+            if score >= threshold:
+                candidates.append((score, speaker, str(profile["name"])))
+
+    matches: dict[str, tuple[str, float]] = {}
+    used: set[str] = set()
+    for score, speaker, name in sorted(candidates, reverse=True):
+        if speaker in matches or name in used:
+            continue
+        matches[speaker] = (name, score)
+        used.add(name)
+    return matches
+
+
+def identify_recording_speakers(
+    recording_id: int, *, embedder: SpeakerEmbedder | None = None
+) -> dict[str, str]:
+    """Name a recording's unnamed speakers from the enrolled profiles.
+
+    Returns the aliases added (``{speaker_id: name}``); empty when nothing
+    matched, no profiles are enrolled or no model is configured.
+    """
+    from server.config import get_config
+    from server.core.audio_utils import load_audio
+    from server.core.storage_encryption import plaintext_path
+    from server.database import alias_repository, voice_profile_repository
+    from server.database.database import get_recording, get_segments
+
+    embedder = embedder or configured_embedder()
+    if embedder is None:
+        return {}
+    existing = alias_repository.alias_map(recording_id)
+    taken = set(existing.values())
+    profiles = [
+        p
+        for p in voice_profile_repository.list_profiles(with_embeddings=True)
+        if p["name"] not in taken
+    ]
+    if not profiles:
+        return {}
+    recording = get_recording(recording_id)
+    audio_path = Path((recording or {}).get("filepath") or "")
+    if not audio_path.is_file():
+        return {}
+
+    cfg = get_config()
+    threshold = float(
+        cfg.get("speaker_identification", "threshold", default=DEFAULT_THRESHOLD)
+        or DEFAULT_THRESHOLD
+    )
+    max_seconds = float(
+        cfg.get(
+            "speaker_identification",
+            "max_seconds_per_speaker",
+            default=DEFAULT_MAX_SECONDS_PER_SPEAKER,
+        )
+        or DEFAULT_MAX_SECONDS_PER_SPEAKER
+    )
+    segments = [s for s in get_segments(recording_id) if s.get("speaker") not in existing]
+
+    embeddings: dict[str, np.ndarray] = {}
+    with plaintext_path(audio_path) as readable:
+        for speaker, spans in speaker_clips(segments, max_seconds).items():
+            vectors, weights = [], []
+            for start, end in spans:
+                audio, _ = load_audio(
+                    str(readable), SAMPLE_RATE, max_seconds=end - start, start_seconds=start
+                )
+                if len(audio) < MIN_TURN_SECONDS * SAMPLE_RATE:
+                    continue
+                vectors.append(embedder.embed(audio))
+                weights.append(end - start)
+            if vectors:
+                embeddings[speaker] = normalize(np.average(vectors, axis=0, weights=weights))
+
+    matches = match_speakers(embeddings, profiles, threshold)
+    added = {speaker: name for speaker, (name, _score) in matches.items()}
+    if added:
+        merged = {**existing, **added}
+        alias_repository.replace_aliases(
+            recording_id,
+            [{"speaker_id": speaker, "alias_name": name} for speaker, name in merged.items()],
+        )
+    logger.info(
+        "Speaker identification for recording %d: %d of %d speaker(s) matched",
+        recording_id,
+        len(added),
+        len(embeddings),
+    )
+    return added
@@ -0,0 +1,153 @@
+"""Per-speaker conversation statistics from diarized segments.
+
+For every speaker: talk time and its share of all speech, words and words
+per minute, number of turns, the longest monologue, and how often they cut
+in on someone else (and were cut off themselves).
+
+Definitions, all computed from segment timing alone:
+
+- A **turn** is a run of consecutive segments by the same speaker; the
+  **longest monologue** is the longest turn, start of its first segment to
+  end of its last.
+- An **interruption** is a speaker change where the new speaker starts
+  before the previous turn has ended (overlapping speech), or within
+  ``cutoff_gap`` seconds of it while the previous speaker was mid-sentence
+  (their text doesn't end in terminal punctuation).
+
+Segments without a speaker are skipped, so an undiarized recording yields
+no speakers. Labels come from ``alias_substitution.build_speaker_label_map``
+so the numbers line up with the names shown in the viewer and exports.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Iterable, Mapping
+from dataclasses import asdict, dataclass
+from typing import Any
+
+DEFAULT_CUTOFF_GAP = 0.3
+_TERMINAL_PUNCTUATION = (".", "?", "!", "…", "。", "？", "！")
+
+
+@dataclass(slots=True)
+class SpeakerStats:
+    speaker: str
+    label: str
+    talk_seconds: float = 0.0
+    share: float = 0.0
+    words: int = 0
+    words_per_minute: float = 0.0
+    turns: int = 0
+    longest_monologue_seconds: float = 0.0
+    longest_monologue_start: float = 0.0
+    interruptions: int = 0
+    interrupted: int = 0
+
+
+def _num(value: Any) -> float:
+    try:
+        return float(value or 0.0)
+    except (TypeError, ValueError):
+        return 0.0
+
+
+def _ends_sentence(text: str) -> bool:
+    return text.rstrip().rstrip("\"'”’)»").endswith(_TERMINAL_PUNCTUATION)
+
+
+def compute_speaker_stats(
+    segments: Iterable[Mapping[str, Any]],
+    label_map: Mapping[str, str] | None = None,
+    cutoff_gap: float = DEFAULT_CUTOFF_GAP,
+) -> dict[str, Any]:
+    """Stats for every speaker in ``segments`` (ordered by start time).
+
+    Returns ``{"speakers": [...], "total_talk_seconds", "duration_seconds"}``
+    with speakers sorted by talk time, longest first.
+    """
+    rows = sorted(
+        (
+            (_num(s.get("start_time")), _num(s.get("end_time")), str(s.get("speaker")), s)
+            for s in segments
+            if s.get("speaker")
+        ),
+        key=lambda r: r[0],
+    )
+    labels = dict(label_map or {})
+    stats: dict[str, SpeakerStats] = {}
+    turn_speaker: str | None = None
+    turn_start = turn_end = 0.0
+    turn_text = ""
+
+    def close_turn() -> None:
+        if turn_speaker is None:
+            return
+        entry = stats[turn_speaker]
+        length = turn_end - turn_start
+        if length > entry.longest_monologue_seconds:
+            entry.longest_monologue_seconds = length
+            entry.longest_monologue_start = turn_start
+
+    for start, end, speaker, seg in rows:
+        end = max(start, end)
+        text = str(seg.get("text") or "").strip()
+        entry = stats.get(speaker)
+        if entry is None:
+            entry = stats[speaker] = SpeakerStats(
+                speaker=speaker, label=labels.get(speaker) or speaker
+            )
+        entry.talk_seconds += end - start
+        entry.words += len(text.split())
+
+        if speaker == turn_speaker:
+            turn_end = max(turn_end, end)
+            turn_text = text or turn_text
+            continue
+
+        if turn_speaker is not None:
+            cut_in = start < turn_end or (
+                start - turn_end <= cutoff_gap and turn_text and not _ends_sentence(turn_text)
+            )
+            if cut_in:
+                entry.interruptions += 1
+                stats[turn_speaker].interrupted += 1
+        close_turn()
+        entry.turns += 1
+        turn_speaker, turn_start, turn_end, turn_text = speaker, start, end, text
+    close_turn()
+
+    total = sum(s.talk_seconds for s in stats.values())
+    for entry in stats.values():
+        entry.share = round(entry.talk_seconds / total, 4) if total > 0 else 0.0
+        minutes = entry.talk_seconds / 60
+        entry.words_per_minute = round(entry.words / minutes, 1) if minutes > 0 else 0.0
+        entry.talk_seconds = round(entry.talk_seconds, 2)
+        entry.longest_monologue_seconds = round(entry.longest_monologue_seconds, 2)
+        entry.longest_monologue_start = round(entry.longest_monologue_start, 2)
+
+    ordered = sorted(stats.values(), key=lambda s: s.talk_seconds, reverse=True)
+    return {
+        "speakers": [asdict(s) for s in ordered],
+        "total_talk_seconds": round(total, 2),
+        "duration_seconds": round(max((r[1] for r in rows), default=0.0), 2),
+    }
+
+
+def _clock(seconds: float) -> str:
+    total = max(0, int(round(seconds)))
+    hours, rem = divmod(total, 3600)
+    minutes, secs = divmod(rem, 60)
+    return f"{hours}:{minutes:02d}:{secs:02d}" if hours else f"{minutes}:{secs:02d}"
+
+
+def stats_text_lines(stats: Mapping[str, Any]) -> list[str]:
+    """Plain-text table of ``compute_speaker_stats`` output, one line per speaker."""
+    lines = []
+    for s in stats.get("speakers", []):
+        lines.append(
+            f"{s['label']}: {_clock(s['talk_seconds'])} talk time ({s['share'] * 100:.0f}%), "
+            f"{s['words_per_minute']:.0f} wpm, {s['turns']} turns, "
+            f"longest monologue {_clock(s['longest_monologue_seconds'])}, "
+            f"{s['interruptions']} interruptions, interrupted {s['interrupted']} times"
+        )
+    return lines
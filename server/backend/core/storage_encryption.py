@@ -0,0 +1,365 @@
+"""At-rest encryption for stored audio.
+
+When ``storage_encryption.enabled`` is on, notebook audio and the session
+recordings kept for retry (``durability.recordings_dir``) are encrypted in
+place once written. Each file gets its own random AES-256-GCM data key,
+wrapped with the master key and stored in the file header — a leaked data
+key exposes one file, never the rest.
+
+The master key (base64, 32 bytes) is looked up in order: ``STORAGE_MASTER_KEY``
+— the dashboard keeps its key in the OS keychain and passes it to the
+container on start — then ``storage_encryption.key_file``, then the server's
+own keychain entry. New files are encrypted with the first key found;
+reading tries every configured key, so files written while one source was
+missing still open once it is back. The keychain key is generated on first
+use only when no other source is configured or has ever been used: a server
+started once without its usual key stores new audio unencrypted rather than
+under a key nobody expects. Without a key nothing is encrypted, and
+already-encrypted files can't be played until it is back.
+
+Files keep their name and extension. Readers never open stored audio
+directly: ``plaintext_path`` hands ffmpeg-based code a temporary decrypted
+copy, and ``iter_plaintext`` streams byte ranges for playback. Both pass
+unencrypted files through untouched, so enabling encryption never strands
+older recordings.
+
+Layout: ``MAGIC | key nonce (12) | wrapped data key (48) | nonce prefix (8)``
+followed by 64 KiB plaintext chunks, each sealed separately (+16 byte tag)
+with the chunk index and a last-chunk flag as associated data, so chunks
+can't be reordered or the file silently truncated.
+"""
+
+from __future__ import annotations
+
+import base64
+import binascii
+import contextlib
+import logging
+import os
+import struct
+import tempfile
+from collections.abc import Callable, Iterator
+from pathlib import Path
+from types import ModuleType
+from typing import Any, TypeVar
+
+from cryptography.exceptions import InvalidTag
+from cryptography.hazmat.primitives.ciphers.aead import AESGCM
+
+logger = logging.getLogger(__name__)
+
+MAGIC = b"TSENC01\n"
+KEY_BYTES = 32
+NONCE_BYTES = 12
+TAG_BYTES = 16
+NONCE_PREFIX_BYTES = 8
+WRAPPED_KEY_BYTES = KEY_BYTES + TAG_BYTES
+HEADER_BYTES = len(MAGIC) + NONCE_BYTES + WRAPPED_KEY_BYTES + NONCE_PREFIX_BYTES
+CHUNK_BYTES = 64 * 1024
+SEALED_CHUNK_BYTES = CHUNK_BYTES + TAG_BYTES
+
+MASTER_KEY_ENV = "STORAGE_MASTER_KEY"
+KEYCHAIN_KEY = "storage_encryption.master_key"
+# Set once a key from STORAGE_MASTER_KEY or key_file has encrypted a file.
+EXTERNAL_KEY_MARKER = "storage_encryption.external_key_used"
+
+T = TypeVar("T")
+
+
+class StorageEncryptionError(RuntimeError):
+    """An encrypted file can't be read: no master key, wrong key, or tampering."""
+
+
+def parse_master_key(raw: str) -> bytes:
+    """Decode a base64 master key; raises ValueError unless it is 32 bytes."""
+    try:
+        key = base64.b64decode(raw.strip(), validate=True)
+    except (binascii.Error, ValueError) as exc:
+        raise ValueError("master key is not valid base64") from exc
+    if len(key) != KEY_BYTES:
+        raise ValueError(f"master key must be {KEY_BYTES} bytes, got {len(key)}")
+    return key
+
+
+def _master_keys() -> list[tuple[str, bytes]]:
+    """``(source, key)`` for every configured key that parses, in precedence order."""
+    from server.config import get_config
+
+    raw_keys: list[tuple[str, str]] = []
+    raw = os.environ.get(MASTER_KEY_ENV, "").strip()
+    if raw:
+        raw_keys.append((MASTER_KEY_ENV, raw))
+    key_file = get_config().get("storage_encryption", "key_file", default="") or ""
+    if key_file:
+        try:
+            raw = Path(key_file).expanduser().read_text(encoding="utf-8").strip()
+        except OSError as exc:
+            logger.warning("Storage encryption: could not read key file %s (%s)", key_file, exc)
+            raw = ""
+        if raw:
+            raw_keys.append((str(key_file), raw))
+    keychain = _keychain()
+    raw = (keychain.get(KEYCHAIN_KEY) if keychain else None) or ""
+    if raw:
+        raw_keys.append(("keychain", raw))
+
+    keys: list[tuple[str, bytes]] = []
+    for source, value in raw_keys:
+        try:
+            key = parse_master_key(value)
+        except ValueError as exc:
+            logger.warning("Storage encryption: ignoring key from %s — %s", source, exc)
+            continue
+        if all(key != known for _, known in keys):
+            keys.append((source, key))
+    return keys
+
+
+def master_keys() -> list[bytes]:
+    """Every configured master key, for reading; empty when none is set."""
+    return [key for _, key in _master_keys()]
+
+
+def master_key(*, create: bool = False) -> bytes | None:
+    """The key new files are encrypted with, or None when none is available.
+
+    With ``create`` (encrypting, never reading) a missing key is generated in
+    the server keychain — unless a key file is configured or an external key
+    was used before, which means the usual key is only missing for now.
+    """
+    keys = _master_keys()
+    if keys:
+        source, key = keys[0]
+        if create and source != "keychain":
+            _remember_external_key()
+        return key
+    if not create:
+        return None
+    from server.config import get_config
+
+    keychain = _keychain()
+    if get_config().get("storage_encryption", "key_file", default="") or (
+        keychain is not None and keychain.get(EXTERNAL_KEY_MARKER)
+    ):
+        logger.warning(
+            "Storage encryption: the configured master key (%s or storage_encryption.key_file) "
+            "is missing; not generating a new one",
+            MASTER_KEY_ENV,
+        )
+        return None
+    return _create_keychain_key()
+
+
+def _keychain() -> ModuleType | None:
+    """The server keychain module, or None where ``keyring`` isn't installed."""
+    try:
+        from server.utils import keychain
+    except ImportError:
+        return None
+    return keychain
+
+
+def _remember_external_key() -> None:
+    keychain = _keychain()
+    if keychain is None or keychain.get(EXTERNAL_KEY_MARKER):
+        return
+    try:
+        keychain.set(EXTERNAL_KEY_MARKER, "1")
+    except keychain.KeychainUnavailableError as exc:
+        logger.debug("Storage encryption: could not record the external key (%s)", exc)
+
+
+def _create_keychain_key() -> bytes | None:
+    keychain = _keychain()
+    if keychain is None:
+        return None
+    key = os.urandom(KEY_BYTES)
+    try:
+        keychain.set(KEYCHAIN_KEY, base64.b64encode(key).decode("ascii"))
+    except keychain.KeychainUnavailableError as exc:
+        logger.warning("Storage encryption: could not store a new master key — %s", exc)
+        return None
+    logger.info("Storage encryption: generated a master key in the server keychain")
+    return key
+
+
+def encryption_enabled() -> bool:
+    from server.config import get_config
+
+    return bool(get_config().get("storage_encryption", "enabled", default=False))
+
+
+def is_encrypted(path: Path | str) -> bool:
+    try:
+        with open(path, "rb") as fh:
+            return fh.read(len(MAGIC)) == MAGIC
+    except OSError:
+        return False
+
+
+def _chunk_nonce(prefix: bytes, index: int) -> bytes:
+    return prefix + struct.pack(">I", index)
+
+
+def _chunk_aad(index: int, last: bool) -> bytes:
+    return MAGIC + struct.pack(">I?", index, last)
+
+
+def encrypt_file(path: Path | str, key: bytes) -> None:
+    """Encrypt ``path`` in place (atomic rename; the plaintext never lingers)."""
+    path = Path(path)
+    data_key = os.urandom(KEY_BYTES)
+    key_nonce = os.urandom(NONCE_BYTES)
+    prefix = os.urandom(NONCE_PREFIX_BYTES)
+    wrapped = AESGCM(key).encrypt(key_nonce, data_key, MAGIC)
+    cipher = AESGCM(data_key)
+
+    tmp_path = path.with_name(f".{path.name}.enc-tmp")
+    try:
+        with open(path, "rb") as src, open(tmp_path, "wb") as dst:
+            dst.write(MAGIC + key_nonce + wrapped + prefix)
+            index = 0
+            chunk = src.read(CHUNK_BYTES)
+            while True:
+                following = src.read(CHUNK_BYTES)
+                last = not following
+                nonce = _chunk_nonce(prefix, index)
+                dst.write(cipher.encrypt(nonce, chunk, _chunk_aad(index, last)))
+                if last:
+                    break
+                chunk = following
+                index += 1
+        os.replace(tmp_path, path)
+    except BaseException:
+        tmp_path.unlink(missing_ok=True)
+        raise
+
+
+def encrypt_at_rest(path: Path | str) -> bool:
+    """Encrypt a freshly stored file when encryption is on; True if it was.
+
+    Failures are logged and leave the plaintext in place — a recording is
+    never lost because it could not be encrypted.
+    """
+    if not encryption_enabled():
+        return False
+    key = master_key(create=True)
+    if key is None:
+        logger.warning(
+            "Storage encryption is enabled but no master key is available (%s, "
+            "storage_encryption.key_file or the keychain); storing %s unencrypted",
+            MASTER_KEY_ENV,
+            Path(path).name,
+        )
+        return False
+    if is_encrypted(path):
+        return True
+    try:
+        encrypt_file(path, key)
+    except Exception as exc:
+        logger.error("Storage encryption failed for %s: %s", path, exc)
+        return False
+    return True
+
+
+def _data_key(header: bytes, keys: list[bytes]) -> tuple[AESGCM, bytes]:
+    """Unwrap a file's data key with whichever of ``keys`` sealed it; returns
+    its cipher and chunk-nonce prefix."""
+    if not keys:
+        raise StorageEncryptionError(
+            "This recording is encrypted and no storage master key is configured"
+        )
+    offset = len(MAGIC)
+    key_nonce = header[offset : offset + NONCE_BYTES]
+    wrapped = header[offset + NONCE_BYTES : offset + NONCE_BYTES + WRAPPED_KEY_BYTES]
+    prefix = header[HEADER_BYTES - NONCE_PREFIX_BYTES : HEADER_BYTES]
+    for key in keys:
+        try:
+            return AESGCM(AESGCM(key).decrypt(key_nonce, wrapped, MAGIC)), prefix
+        except InvalidTag:
+            continue
+    raise StorageEncryptionError("This recording was encrypted with a different master key")
+
+
+def plaintext_size(path: Path | str) -> int:
+    """Size of the decrypted content (the file size for unencrypted files)."""
+    size = Path(path).stat().st_size
+    if not is_encrypted(path):
+        return size
+    body = size - HEADER_BYTES
+    chunks = -(-body // SEALED_CHUNK_BYTES)
+    return body - chunks * TAG_BYTES
+
+
+def iter_plaintext(
+    path: Path | str,
+    start: int = 0,
+    end: int | None = None,
+    *,
+    key: bytes | None = None,
+) -> Iterator[bytes]:
+    """Yield decrypted bytes ``start``..``end`` (inclusive) of ``path``.
+
+    Only the chunks covering the range are read, so seeking in a long
+    recording stays cheap. Unencrypted files are streamed as-is.
+    """
+    path = Path(path)
+    total = plaintext_size(path)
+    last_byte = total - 1 if end is None else min(end, total - 1)
+    if start > last_byte:
+        return
+    if not is_encrypted(path):
+        with open(path, "rb") as fh:
+            fh.seek(start)
+            remaining = last_byte - start + 1
+            while remaining > 0:
+                block = fh.read(min(CHUNK_BYTES, remaining))
+                if not block:
+                    break
+                remaining -= len(block)
+                yield block
+        return
+
+    chunk_count = -(-(path.stat().st_size - HEADER_BYTES) // SEALED_CHUNK_BYTES)
+    with open(path, "rb") as fh:
+        cipher, prefix = _data_key(fh.read(HEADER_BYTES), [key] if key else master_keys())
+        first, final = start // CHUNK_BYTES, last_byte // CHUNK_BYTES
+        fh.seek(HEADER_BYTES + first * SEALED_CHUNK_BYTES)
+        for index in range(first, final + 1):
+            sealed = fh.read(SEALED_CHUNK_BYTES)
+            aad = _chunk_aad(index, index == chunk_count - 1)
+            try:
+                plain = cipher.decrypt(_chunk_nonce(prefix, index), sealed, aad)
+            except InvalidTag as exc:
+                raise StorageEncryptionError(f"{path.name} is corrupt or truncated") from exc
+            lo = start - index * CHUNK_BYTES if index == first else 0
+            hi = last_byte - index * CHUNK_BYTES + 1 if index == final else len(plain)
+            yield plain[lo:hi]
+
+
+def read_plaintext(path: Path | str) -> bytes:
+    return b"".join(iter_plaintext(path))
+
+
+@contextlib.contextmanager
+def plaintext_path(path: Path | str) -> Iterator[Path]:
+    """A readable plaintext path for ``path``; decrypted copies are deleted on exit."""
+    path = Path(path)
+    if not is_encrypted(path):
+        yield path
+        return
+    fd, tmp_name = tempfile.mkstemp(suffix=path.suffix)
+    tmp_path = Path(tmp_name)
+    try:
+        with os.fdopen(fd, "wb") as dst:
+            for block in iter_plaintext(path):
+                dst.write(block)
+        yield tmp_path
+    finally:
+        tmp_path.unlink(missing_ok=True)
+
+
+def call_with_plaintext(fn: Callable[..., T], path: Path | str, *args: Any, **kwargs: Any) -> T:
+    """``fn(readable_path, *args, **kwargs)`` on a plaintext view of ``path``."""
+    with plaintext_path(path) as readable:
+        return fn(readable, *args, **kwargs)
@@ -178,6 +178,21 @@ class STTBackend(abc.ABC):
         """
         return False
 
+    def supports_language_detection(self) -> bool:
+        """Return True if ``detect_language`` yields per-language probabilities."""
+        return False
+
+    def detect_language(
+        self, audio: np.ndarray, *, audio_sample_rate: int = 16000
+    ) -> list[tuple[str, float]]:
+        """Return ``(language_code, probability)`` pairs, most likely first.
+
+        Only called when ``supports_language_detection()`` is True; the
+        language preview (``core/language_detection.py``) passes short
+        windows of a file's head.
+        """
+        raise NotImplementedError
+
     @property
     def preferred_input_sample_rate_hz(self) -> int:
         """Preferred audio sample rate for this backend's input pipeline."""
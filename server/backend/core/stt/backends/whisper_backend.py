@@ -172,6 +172,20 @@ class WhisperBackend(STTBackend):
     def supports_translation(self) -> bool:
         return True
 
+    def supports_language_detection(self) -> bool:
+        return True
+
+    def detect_language(
+        self, audio: np.ndarray, *, audio_sample_rate: int = SAMPLE_RATE
+    ) -> list[tuple[str, float]]:
+        from server.core.language_detection import faster_whisper_language_probabilities
+
+        if self._model is None:
+            raise RuntimeError("Whisper model is not loaded")
+        # BatchedInferencePipeline wraps the WhisperModel that owns detection.
+        model = getattr(self._model, "model", self._model)
+        return faster_whisper_language_probabilities(model, audio)
+
     @property
     def backend_name(self) -> str:
         return "whisper"
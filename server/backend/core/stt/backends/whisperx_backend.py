@@ -417,6 +417,19 @@ class WhisperXBackend(STTBackend):
     def supports_translation(self) -> bool:
         return True
 
+    def supports_language_detection(self) -> bool:
+        return True
+
+    def detect_language(
+        self, audio: np.ndarray, *, audio_sample_rate: int = SAMPLE_RATE
+    ) -> list[tuple[str, float]]:
+        from server.core.language_detection import faster_whisper_language_probabilities
+
+        if self._model is None:
+            raise RuntimeError("WhisperX model is not loaded")
+        # The WhisperX pipeline's ``model`` is a faster-whisper WhisperModel.
+        return faster_whisper_language_probabilities(self._model.model, audio)
+
     @property
     def backend_name(self) -> str:
         return "whisperx"
@@ -84,6 +84,11 @@ class TranscriptionResult:
     # that the transcript is incomplete). GH #168 follow-up.
     partial: bool = False
     partial_reason: str | None = None
+    # Seconds of audio the partial transcript covers — where a resume picks up.
+    completed_seconds: float | None = None
+    # Set when the job ran out of GPU memory and was retried in chunks or
+    # with a smaller model (``core/oom_fallback.Downgrade.to_dict``).
+    downgrade: dict[str, Any] | None = None
 
     def to_dict(self) -> dict[str, Any]:
         """Convert to dictionary for API responses."""
@@ -98,6 +103,8 @@ class TranscriptionResult:
             "total_words": len(self.words),
             "partial": self.partial,
             "partial_reason": self.partial_reason,
+            "completed_seconds": self.completed_seconds,
+            "downgrade": self.downgrade,
             "metadata": {"num_segments": len(self.segments)},
         }
 
@@ -667,6 +674,40 @@ class AudioToTextRecorder:
         result = self.transcribe()
         return result.text
 
+    @property
+    def recorded_seconds(self) -> float:
+        """Length of the recording in progress, in seconds."""
+        return sum(len(frame) for frame in list(self.frames)) / 2 / SAMPLE_RATE
+
+    def transcribe_partial(self, beam_size: int = 1) -> str | None:
+        """Quick transcript of the recording in progress, for a live partial hypothesis.
+
+        Greedy by default and without word timestamps. Returns ``None`` instead
+        of waiting while a final transcription holds the backend — a partial is
+        never worth delaying a final — or when nothing is being recorded.
+        """
+        if not self.is_recording or self._backend is None:
+            return None
+        audio_bytes = b"".join(list(self.frames))
+        if not audio_bytes or not self.transcription_lock.acquire(blocking=False):
+            return None
+        try:
+            audio = np.frombuffer(audio_bytes, dtype=np.int16).astype(np.float32)
+            segments, _info = self._backend.transcribe(
+                audio / INT16_MAX_ABS_VALUE,
+                language=self.language if self.language else None,
+                task=self.task,
+                beam_size=beam_size,
+                initial_prompt=self.initial_prompt,
+                suppress_tokens=self.suppress_tokens,
+                vad_filter=False,
+                word_timestamps=False,
+                translation_target_language=self.translation_target_language,
+            )
+            return " ".join(segment.text.strip() for segment in segments).strip()
+        finally:
+            self.transcription_lock.release()
+
     def _perform_transcription(
         self,
         audio: np.ndarray | None = None,
@@ -845,6 +886,64 @@ class AudioToTextRecorder:
             progress_callback=progress_callback,
         )
 
+    def detect_language_file(self, file_path: str, *, seconds: float) -> dict[str, Any]:
+        """Detect the spoken language from the first ``seconds`` of a file.
+
+        See ``core/language_detection.py``. Returns ``supported=False`` when
+        the active backend has no language detection.
+        """
+        from server.core.audio_utils import load_audio
+        from server.core.language_detection import detect_language_audio
+
+        backend = self._backend
+        preferred_rate = int(
+            getattr(backend, "preferred_input_sample_rate_hz", SAMPLE_RATE) or SAMPLE_RATE
+        )
+        audio_data, sample_rate = load_audio(
+            file_path, target_sample_rate=preferred_rate, max_seconds=seconds
+        )
+        return detect_language_audio(backend, audio_data, sample_rate).to_dict()
+
+    def transcribe_file_span(
+        self,
+        file_path: str,
+        *,
+        start_seconds: float,
+        duration_seconds: float,
+        language: str | None = None,
+        task: str | None = None,
+        translation_target_language: str | None = None,
+        word_timestamps: bool = True,
+        cancellation_check: Callable[[], bool] | None = None,
+    ) -> TranscriptionResult:
+        """Transcribe ``duration_seconds`` of a file starting at ``start_seconds``.
+
+        Used by per-segment re-transcription and chunked out-of-memory retries
+        (``core/oom_fallback.py``). No VAD preprocessing — removing silence
+        would shift the clip-relative timings the caller maps back.
+        """
+        from server.core.audio_utils import load_audio
+
+        backend = self._backend
+        preferred_rate = int(
+            getattr(backend, "preferred_input_sample_rate_hz", SAMPLE_RATE) or SAMPLE_RATE
+        )
+        audio_data, sample_rate = load_audio(
+            file_path,
+            target_sample_rate=preferred_rate,
+            start_seconds=start_seconds,
+            max_seconds=duration_seconds,
+        )
+        return self.transcribe_audio(
+            audio_data,
+            sample_rate=sample_rate,
+            language=language,
+            task=task,
+            translation_target_language=translation_target_language,
+            word_timestamps=word_timestamps,
+            cancellation_check=cancellation_check,
+        )
+
     def transcribe_audio(
         self,
         audio_data: np.ndarray,
@@ -928,6 +1027,7 @@ class AudioToTextRecorder:
                 # Transcribe via backend
                 partial = False
                 partial_reason: str | None = None
+                completed_seconds: float | None = None
                 try:
                     backend_segments, backend_info = self._backend.transcribe(
                         audio_data,
@@ -950,6 +1050,7 @@ class AudioToTextRecorder:
                     # whole job being lost (GH #168 follow-up).
                     partial = True
                     partial_reason = str(partial_exc)
+                    completed_seconds = partial_exc.completed_seconds
                     backend_segments = partial_exc.segments
                     backend_info = partial_exc.info
                     logger.warning(
@@ -962,7 +1063,6 @@ class AudioToTextRecorder:
                 # Collect results
                 all_segments = []
                 all_words = []
-                full_text_parts = []
 
                 for segment in backend_segments:
                     # Check for cancellation between segments. Skip this once we
@@ -997,9 +1097,15 @@ class AudioToTextRecorder:
                         all_words.extend(seg_dict["words"])
 
                     all_segments.append(seg_dict)
-                    full_text_parts.append(segment.text.strip())
 
-                full_text = " ".join(full_text_parts)
+                # Engines that return bare lowercase text get punctuation and
+                # capitals from the local model, when one is configured. Word
+                # dicts are shared with the segments, so both are updated.
+                from server.core.punctuation_restoration import restore_punctuation
+
+                restore_punctuation(all_segments, backend_info.language or lang)
+
+                full_text = " ".join(seg["text"] for seg in all_segments)
                 full_text = self._preprocess_output(full_text)
 
                 elapsed = time.time() - start_time
@@ -1017,6 +1123,7 @@ class AudioToTextRecorder:
                     duration=len(audio_data) / sample_rate,
                     partial=partial,
                     partial_reason=partial_reason,
+                    completed_seconds=completed_seconds,
                 )
 
             except Exception as e:
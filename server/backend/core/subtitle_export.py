@@ -9,6 +9,8 @@ from collections.abc import Iterable
 from dataclasses import dataclass
 from typing import Any
 
+from server.core.timecode import TimecodeSettings, apply_timecode
+
 MAX_CUE_DURATION = 5.0
 MIN_CUE_DURATION = 0.7
 WORD_GAP_SPLIT = 0.8
@@ -120,19 +122,35 @@ def build_subtitle_cues(
     )
 
 
-def render_srt(cues: list[SubtitleCue]) -> str:
-    """Render subtitle cues into SRT format."""
+def render_srt(cues: list[SubtitleCue], *, timecode: TimecodeSettings | None = None) -> str:
+    """Render subtitle cues into SRT format.
+
+    ``timecode`` shifts every cue by the recording's start timecode and
+    snaps it to a frame boundary (see ``server.core.timecode``).
+    """
     lines: list[str] = []
     for index, cue in enumerate(cues, start=1):
+        start = apply_timecode(cue.start, timecode)
+        end = apply_timecode(cue.end, timecode)
         lines.append(str(index))
-        lines.append(f"{_format_srt_timestamp(cue.start)} --> {_format_srt_timestamp(cue.end)}")
+        lines.append(f"{_format_srt_timestamp(start)} --> {_format_srt_timestamp(end)}")
         lines.append(cue.text)
         lines.append("")
     return "\n".join(lines)
 
 
-def render_ass(cues: list[SubtitleCue], title: str) -> str:
-    """Render subtitle cues into ASS format."""
+def render_ass(
+    cues: list[SubtitleCue],
+    title: str,
+    *,
+    timecode: TimecodeSettings | None = None,
+    comments: Iterable[SubtitleCue] = (),
+) -> str:
+    """Render subtitle cues into ASS format (``timecode`` as for ``render_srt``).
+
+    ``comments`` become ``Comment:`` events (``speaker`` → Name field):
+    editors such as Aegisub show them, players ignore them.
+    """
     safe_title = _collapse_whitespace(title).replace("\n", " ").strip() or "Export"
 
     lines = [
@@ -154,10 +172,18 @@ def render_ass(cues: list[SubtitleCue], title: str) -> str:
         text = _escape_ass_text(cue.text)
         lines.append(
             "Dialogue: 0,"
-            f"{_format_ass_timestamp(cue.start)},"
-            f"{_format_ass_timestamp(cue.end)},"
+            f"{_format_ass_timestamp(apply_timecode(cue.start, timecode))},"
+            f"{_format_ass_timestamp(apply_timecode(cue.end, timecode))},"
             f"Default,,0,0,0,,{text}"
         )
+    for note in comments:
+        name = _collapse_whitespace(note.speaker or "").replace(",", " ").strip()
+        lines.append(
+            "Comment: 0,"
+            f"{_format_ass_timestamp(apply_timecode(note.start, timecode))},"
+            f"{_format_ass_timestamp(apply_timecode(note.end, timecode))},"
+            f"Default,{name},0,0,0,,{_escape_ass_text(note.text)}"
+        )
 
     return "\n".join(lines)
 
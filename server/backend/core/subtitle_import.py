@@ -0,0 +1,182 @@
+"""Subtitle import (SRT / WebVTT / ASS) for the Audio Notebook.
+
+Users often already have captions for a file — from a broadcaster, a
+previous tool, or an earlier export of ours. Importing them alongside the
+media creates a normal notebook recording (segments + words) so the editor,
+search and every exporter apply, without re-running transcription.
+
+Parsing is deliberately forgiving: BOMs, CRLF, missing SRT indices, VTT cue
+settings and ASS override tags are all tolerated. Speakers are taken from
+the ASS ``Name`` field, VTT ``<v Name>`` voice spans, or a short
+``Name: text`` prefix (the layout our own SRT/ASS export writes), so an
+exported-then-reimported recording keeps its speaker turns.
+"""
+
+from __future__ import annotations
+
+import re
+from pathlib import Path
+
+from server.core.subtitle_export import SubtitleCue
+
+SUBTITLE_FORMATS: tuple[str, ...] = ("srt", "vtt", "ass")
+MAX_CUES = 50_000
+
+_SRT_TIME = r"(\d{1,2}):(\d{2}):(\d{2})[,.](\d{1,3})"
+_SRT_TIMING_RE = re.compile(rf"^\s*{_SRT_TIME}\s*-->\s*{_SRT_TIME}")
+_VTT_TIME = r"(?:(\d{1,2}):)?(\d{2}):(\d{2})\.(\d{1,3})"
+_VTT_TIMING_RE = re.compile(rf"^\s*{_VTT_TIME}\s*-->\s*{_VTT_TIME}")
+_ASS_TIME_RE = re.compile(r"^\s*(\d+):(\d{2}):(\d{2})[.:](\d{1,3})\s*$")
+_VTT_VOICE_RE = re.compile(r"<v(?:\.[\w.-]+)?\s+([^>]+)>")
+_TAG_RE = re.compile(r"<[^>]+>")
+_ASS_OVERRIDE_RE = re.compile(r"\{[^}]*\}")
+_SPEAKER_PREFIX_RE = re.compile(r"^([A-Z][\w.'-]*(?: [\w.'-]+){0,2}):\s+(\S.*)$", re.DOTALL)
+
+
+def detect_format(filename: str, content: str) -> str:
+    """Pick the parser from the extension, falling back to content sniffing."""
+    suffix = Path(filename or "").suffix.lower().lstrip(".")
+    if suffix in SUBTITLE_FORMATS:
+        return suffix
+    if suffix == "ssa":
+        return "ass"
+    head = content.lstrip("\ufeff").lstrip()[:200]
+    if head.startswith("WEBVTT"):
+        return "vtt"
+    if head.startswith("[Script Info]") or "[Events]" in content[:4000]:
+        return "ass"
+    return "srt"
+
+
+def parse_subtitles(content: str, fmt: str) -> list[SubtitleCue]:
+    """Parse ``content`` as ``fmt``; raises ``ValueError`` on unusable input."""
+    parsers = {"srt": parse_srt, "vtt": parse_vtt, "ass": parse_ass}
+    if fmt not in parsers:
+        raise ValueError(f"Unsupported subtitle format: {fmt}")
+    cues = parsers[fmt](content)
+    if not cues:
+        raise ValueError(f"No cues found in {fmt.upper()} file")
+    if len(cues) > MAX_CUES:
+        raise ValueError(f"Too many cues (max {MAX_CUES})")
+    cues.sort(key=lambda cue: (cue.start, cue.end))
+    return cues
+
+
+def _seconds(hours: str | None, minutes: str, secs: str, fraction: str) -> float:
+    # ``fraction`` is milliseconds in SRT/VTT but centiseconds in ASS; scale by width.
+    return (
+        int(hours or 0) * 3600
+        + int(minutes) * 60
+        + int(secs)
+        + int(fraction) / (10 ** len(fraction))
+    )
+
+
+def _blocks(content: str) -> list[list[str]]:
+    text = content.lstrip("\ufeff").replace("\r\n", "\n").replace("\r", "\n")
+    return [block.split("\n") for block in re.split(r"\n\s*\n", text) if block.strip()]
+
+
+def _clean_text(lines: list[str]) -> str:
+    return " ".join(" ".join(_TAG_RE.sub("", line) for line in lines).split())
+
+
+def split_speaker(text: str) -> tuple[str | None, str]:
+    match = _SPEAKER_PREFIX_RE.match(text)
+    if match:
+        return match.group(1), match.group(2).strip()
+    return None, text
+
+
+def _cue(start: float, end: float, text: str, speaker: str | None) -> SubtitleCue | None:
+    if not text:
+        return None
+    if speaker is None:
+        speaker, text = split_speaker(text)
+    return SubtitleCue(start=start, end=max(start, end), text=text, speaker=speaker)
+
+
+def parse_srt(content: str) -> list[SubtitleCue]:
+    cues: list[SubtitleCue] = []
+    for block in _blocks(content):
+        timing_index = next(
+            (i for i, line in enumerate(block[:2]) if _SRT_TIMING_RE.match(line)), None
+        )
+        if timing_index is None:
+            continue
+        match = _SRT_TIMING_RE.match(block[timing_index])
+        assert match is not None
+        groups = match.groups()
+        cue = _cue(
+            _seconds(*groups[:4]),
+            _seconds(*groups[4:]),
+            _clean_text(block[timing_index + 1 :]),
+            None,
+        )
+        if cue:
+            cues.append(cue)
+    return cues
+
+
+def parse_vtt(content: str) -> list[SubtitleCue]:
+    blocks = _blocks(content)
+    if not blocks or not blocks[0][0].strip().startswith("WEBVTT"):
+        raise ValueError("WebVTT file must start with 'WEBVTT'")
+    cues: list[SubtitleCue] = []
+    for block in blocks[1:]:
+        if block[0].startswith(("NOTE", "STYLE", "REGION")):
+            continue
+        timing_index = next(
+            (i for i, line in enumerate(block[:2]) if _VTT_TIMING_RE.match(line)), None
+        )
+        if timing_index is None:
+            continue
+        match = _VTT_TIMING_RE.match(block[timing_index])
+        assert match is not None
+        groups = match.groups()
+        body = block[timing_index + 1 :]
+        voices = [m.group(1).strip() for m in map(_VTT_VOICE_RE.search, body) if m]
+        voice = voices[0] if voices else None
+        cue = _cue(_seconds(*groups[:4]), _seconds(*groups[4:]), _clean_text(body), voice)
+        if cue:
+            cues.append(cue)
+    return cues
+
+
+def parse_ass(content: str) -> list[SubtitleCue]:
+    text = content.lstrip("\ufeff").replace("\r\n", "\n").replace("\r", "\n")
+    fields: list[str] | None = None
+    in_events = False
+    cues: list[SubtitleCue] = []
+    for lineno, line in enumerate(text.split("\n"), start=1):
+        stripped = line.strip()
+        if stripped.startswith("["):
+            in_events = stripped.lower() == "[events]"
+            continue
+        if not in_events:
+            continue
+        key, _, value = stripped.partition(":")
+        if key == "Format":
+            fields = [name.strip().lower() for name in value.split(",")]
+        elif key == "Dialogue":
+            if fields is None:
+                raise ValueError(f"line {lineno}: Dialogue before Format in [Events]")
+            parts = value.split(",", len(fields) - 1)
+            if len(parts) != len(fields):
+                raise ValueError(f"line {lineno}: malformed Dialogue line")
+            row = dict(zip(fields, (part.strip() for part in parts), strict=True))
+            start_match = _ASS_TIME_RE.match(row.get("start", ""))
+            end_match = _ASS_TIME_RE.match(row.get("end", ""))
+            if not start_match or not end_match:
+                raise ValueError(f"line {lineno}: invalid Start/End time")
+            body = _ASS_OVERRIDE_RE.sub("", row.get("text", ""))
+            body = body.replace("\\N", " ").replace("\\n", " ").replace("\\h", " ")
+            cue = _cue(
+                _seconds(*start_match.groups()),
+                _seconds(*end_match.groups()),
+                " ".join(body.split()),
+                row.get("name") or None,
+            )
+            if cue:
+                cues.append(cue)
+    return cues
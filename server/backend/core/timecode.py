@@ -0,0 +1,175 @@
+"""SMPTE timecode helpers for video-editor workflows.
+
+A recording can carry per-transcript timecode settings (migration 018,
+``recordings.timecode_settings``): a start timecode, a frame rate and the
+drop-frame flag. When present they are applied by the subtitle exporters
+(``render_srt`` / ``render_ass``) and the dashboard viewer so editors can
+paste timings directly into an NLE whose timeline does not start at zero
+(the broadcast convention is ``01:00:00:00``).
+
+Two separate concerns are handled here:
+
+* **Labels** — ``HH:MM:SS:FF`` (``;`` before the frame field for drop-frame).
+  Drop-frame skips frame labels 00 and 01 at the start of every minute
+  except each tenth minute, which keeps 29.97 fps labels aligned with the
+  wall clock.
+* **Subtitle times** — SRT/ASS carry clock times, not frames. We shift each
+  cue by the start timecode's *nominal* value (``01:00:00:00`` → 3600 s, the
+  value NLEs expect on import) and snap it to the nearest frame boundary so
+  cuts land on a frame.
+"""
+
+from __future__ import annotations
+
+import json
+import math
+import re
+from dataclasses import asdict, dataclass
+from fractions import Fraction
+from typing import Any
+
+# Exact rational rates — 23.976 and 29.97 are really 24000/1001 and 30000/1001.
+SUPPORTED_FPS: dict[float, Fraction] = {
+    23.976: Fraction(24000, 1001),
+    24.0: Fraction(24),
+    25.0: Fraction(25),
+    29.97: Fraction(30000, 1001),
+    30.0: Fraction(30),
+}
+DROP_FRAME_FPS: frozenset[float] = frozenset({29.97})
+
+_TIMECODE_RE = re.compile(r"^(\d{1,2}):([0-5]\d):([0-5]\d)[:;.](\d{2})$")
+
+
+@dataclass(slots=True, frozen=True)
+class TimecodeSettings:
+    start_timecode: str = "00:00:00:00"
+    fps: float = 25.0
+    drop_frame: bool = False
+
+    def __post_init__(self) -> None:
+        fps = _canonical_fps(self.fps)
+        object.__setattr__(self, "fps", fps)
+        if self.drop_frame and fps not in DROP_FRAME_FPS:
+            raise ValueError("drop_frame is only valid at 29.97 fps")
+        # Validates and normalises the separator (``;`` for drop-frame).
+        frames = timecode_to_frames(self.start_timecode, self)
+        object.__setattr__(self, "start_timecode", frames_to_timecode(frames, self))
+
+    @property
+    def nominal_fps(self) -> int:
+        """Frame-label base: 24, 25 or 30."""
+        return round(self.fps)
+
+    @property
+    def rate(self) -> Fraction:
+        return SUPPORTED_FPS[self.fps]
+
+    @property
+    def start_frames(self) -> int:
+        return timecode_to_frames(self.start_timecode, self)
+
+    @property
+    def offset_seconds(self) -> float:
+        """Nominal seconds of the start label — what an NLE reads ``01:00:00:00`` as."""
+        match = _TIMECODE_RE.match(self.start_timecode)
+        assert match is not None  # validated in __post_init__
+        hours, minutes, secs, frames = (int(g) for g in match.groups())
+        return hours * 3600 + minutes * 60 + secs + frames / self.nominal_fps
+
+    def to_dict(self) -> dict[str, Any]:
+        return asdict(self)
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> TimecodeSettings:
+        return cls(
+            start_timecode=str(data.get("start_timecode") or "00:00:00:00"),
+            fps=float(data.get("fps") or 25.0),
+            drop_frame=bool(data.get("drop_frame", False)),
+        )
+
+
+def _canonical_fps(value: float) -> float:
+    for fps in SUPPORTED_FPS:
+        if abs(float(value) - fps) < 0.01:
+            return fps
+    supported = ", ".join(f"{fps:g}" for fps in SUPPORTED_FPS)
+    raise ValueError(f"fps must be one of {supported}")
+
+
+def parse_settings(raw: str | None) -> TimecodeSettings | None:
+    """Decode the ``recordings.timecode_settings`` column.
+
+    NULL, blank or unreadable JSON means "no timecode" — exports fall back
+    to zero-based clock times rather than failing.
+    """
+    if not raw:
+        return None
+    try:
+        data = json.loads(raw)
+        if not isinstance(data, dict):
+            return None
+        return TimecodeSettings.from_dict(data)
+    except (ValueError, TypeError):
+        return None
+
+
+def serialize_settings(settings: TimecodeSettings) -> str:
+    return json.dumps(settings.to_dict(), sort_keys=True)
+
+
+def timecode_to_frames(timecode: str, settings: TimecodeSettings) -> int:
+    """Parse ``HH:MM:SS:FF`` (or ``;``-separated) into an absolute frame count."""
+    match = _TIMECODE_RE.match(timecode.strip())
+    if not match:
+        raise ValueError(f"Invalid timecode {timecode!r} — expected HH:MM:SS:FF")
+    hours, minutes, secs, frames = (int(g) for g in match.groups())
+    nominal = round(settings.fps)
+    if frames >= nominal:
+        raise ValueError(f"Frame field {frames:02d} out of range for {settings.fps:g} fps")
+    total = ((hours * 60 + minutes) * 60 + secs) * nominal + frames
+    if settings.drop_frame:
+        total_minutes = hours * 60 + minutes
+        if secs == 0 and frames < 2 and minutes % 10 != 0:
+            raise ValueError(f"{timecode!r} is not a valid drop-frame label")
+        total -= 2 * (total_minutes - total_minutes // 10)
+    return total
+
+
+def frames_to_timecode(frames: int, settings: TimecodeSettings) -> str:
+    """Format an absolute frame count as a SMPTE label."""
+    nominal = round(settings.fps)
+    frames = max(0, int(frames))
+    if settings.drop_frame:
+        # 29.97 DF: 17982 real frames per 10 minutes, 1798 per dropped minute.
+        tens, rem = divmod(frames, 17982)
+        frames += 18 * tens
+        if rem >= 2:
+            frames += 2 * ((rem - 2) // 1798)
+    ff = frames % nominal
+    total_secs = frames // nominal
+    hh, rem = divmod(total_secs, 3600)
+    mm, ss = divmod(rem, 60)
+    sep = ";" if settings.drop_frame else ":"
+    # Timecode wraps at 24 h like a VTR counter.
+    return f"{hh % 24:02d}:{mm:02d}:{ss:02d}{sep}{ff:02d}"
+
+
+def seconds_to_frames(seconds: float, settings: TimecodeSettings) -> int:
+    """Nearest frame, rounding halves up (``round`` would bank to even)."""
+    exact = Fraction(seconds).limit_denominator(1_000_000) * settings.rate
+    return max(0, math.floor(exact + Fraction(1, 2)))
+
+
+def format_timecode(seconds: float, settings: TimecodeSettings) -> str:
+    """Label for ``seconds`` into the recording, counted from the start timecode."""
+    frames = settings.start_frames + seconds_to_frames(seconds, settings)
+    return frames_to_timecode(frames, settings)
+
+
+def apply_timecode(seconds: float, settings: TimecodeSettings | None) -> float:
+    """Shift a cue time by the start offset and snap it to a frame boundary."""
+    if settings is None:
+        return seconds
+    snapped = float(seconds_to_frames(seconds, settings) / settings.rate)
+    return settings.offset_seconds + snapped
@@ -325,6 +325,56 @@ class TokenStore:
         logger.warning(f"Cannot revoke token: ID {token_id} not found")
         return False
 
+    def rotate_token_by_id(self, token_id: str) -> tuple[StoredToken, str] | None:
+        """
+        Replace an active token's secret.
+
+        The old token is revoked and a new one is issued for the same client,
+        role and expiry date, in a single store write.
+
+        Returns:
+            Tuple of (new StoredToken, plaintext token), or None if no active
+            token has that ID
+        """
+        data = self._read_store()
+
+        for token_data in data["tokens"]:
+            if token_data.get("token_id") != token_id:
+                continue
+            old = StoredToken.from_dict(token_data)
+            if old.is_revoked or old.is_expired():
+                break
+            new_token, plaintext_token = StoredToken.create(old.client_name, old.is_admin)
+            new_token.expires_at = old.expires_at
+            token_data["is_revoked"] = True
+            data["tokens"].append(new_token.to_dict())
+            self._write_store(data)
+            logger.info(f"Token rotated for client: {old.client_name}")
+            return new_token, plaintext_token
+
+        logger.warning(f"Cannot rotate token: no active token with ID {token_id}")
+        return None
+
+    def revoke_tokens_for_client(self, client_name: str) -> int:
+        """
+        Revoke every active token issued to a client name.
+
+        Returns:
+            Number of tokens revoked
+        """
+        data = self._read_store()
+        revoked = 0
+
+        for token_data in data["tokens"]:
+            if token_data["client_name"] == client_name and not token_data.get("is_revoked"):
+                token_data["is_revoked"] = True
+                revoked += 1
+
+        if revoked:
+            self._write_store(data)
+            logger.info(f"Revoked {revoked} token(s) for client: {client_name}")
+        return revoked
+
     def list_tokens(self) -> list[StoredToken]:
         """
         List all tokens.
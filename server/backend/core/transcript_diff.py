@@ -0,0 +1,119 @@
+"""Transcript diff: word-level alignment and WER between two transcripts.
+
+Compares two notebook recordings of the same audio — a small against a
+large model, or the machine transcript against its hand-corrected text
+(``transcript_corrected``, migration 017) — for the side-by-side
+comparison view.
+
+Words are compared case-folded and without punctuation (the same unit as
+``core/model_benchmark.py``), but hunks carry the words as written. The
+alignment comes from ``difflib`` so long recordings stay fast; substitutions,
+deletions and insertions are counted from it, with transcript A as the
+reference. Each hunk has the time range its words cover on both sides
+(None for corrected text, which has no word timings).
+"""
+
+from __future__ import annotations
+
+import difflib
+from collections.abc import Sequence
+from dataclasses import dataclass
+from typing import Any
+
+from server.core.model_benchmark import normalize_words
+
+
+@dataclass(frozen=True)
+class DiffToken:
+    """One written word and its timing, when known."""
+
+    text: str
+    start: float | None = None
+    end: float | None = None
+
+    @property
+    def key(self) -> str:
+        return " ".join(normalize_words(self.text))
+
+
+def _side(tokens: Sequence[DiffToken]) -> dict[str, Any]:
+    starts = [t.start for t in tokens if t.start is not None]
+    ends = [t.end for t in tokens if t.end is not None]
+    return {
+        "text": " ".join(t.text for t in tokens),
+        "start": min(starts) if starts else None,
+        "end": max(ends) if ends else None,
+    }
+
+
+def diff_words(a: Sequence[DiffToken], b: Sequence[DiffToken]) -> dict[str, Any]:
+    """Align ``b`` against reference ``a``: counts, WER and hunks in order.
+
+    Hunk ``op`` is ``equal``, ``replace``, ``delete`` (only in A) or
+    ``insert`` (only in B). Tokens that are pure punctuation are skipped.
+    """
+    a = [t for t in a if t.key]
+    b = [t for t in b if t.key]
+    matcher = difflib.SequenceMatcher(None, [t.key for t in a], [t.key for t in b], autojunk=False)
+
+    counts = {"matches": 0, "substitutions": 0, "deletions": 0, "insertions": 0}
+    hunks: list[dict[str, Any]] = []
+    for op, i1, i2, j1, j2 in matcher.get_opcodes():
+        n_a, n_b = i2 - i1, j2 - j1
+        if op == "equal":
+            counts["matches"] += n_a
+        else:
+            counts["substitutions"] += min(n_a, n_b)
+            counts["deletions"] += max(n_a - n_b, 0)
+            counts["insertions"] += max(n_b - n_a, 0)
+        hunks.append({"op": op, "a": _side(a[i1:i2]), "b": _side(b[j1:j2])})
+
+    errors = counts["substitutions"] + counts["deletions"] + counts["insertions"]
+    if a:
+        wer = errors / len(a)
+    else:
+        wer = 0.0 if not b else 1.0
+    return {
+        "wer": round(wer, 4),
+        "words_a": len(a),
+        "words_b": len(b),
+        **counts,
+        "hunks": hunks,
+    }
+
+
+def recording_tokens(recording_id: int, *, corrected: bool = False) -> list[DiffToken]:
+    """A recording's transcript as tokens; ``corrected`` prefers the hand-corrected text.
+
+    Falls back to the word timings, then to the segment text (untimed),
+    for recordings transcribed without word timestamps.
+    """
+    from server.database.database import get_recording, get_segments, get_words
+
+    if corrected:
+        recording = get_recording(recording_id) or {}
+        text = recording.get("transcript_corrected")
+        if isinstance(text, str):
+            return [DiffToken(w) for w in text.split()]
+    words = get_words(recording_id)
+    if words:
+        return [
+            DiffToken(
+                str(w.get("word") or "").strip(),
+                float(w["start_time"]),
+                float(w["end_time"]),
+            )
+            for w in words
+        ]
+    return [DiffToken(w) for seg in get_segments(recording_id) for w in str(seg["text"]).split()]
+
+
+def diff_transcripts(
+    id_a: int, id_b: int, *, a_corrected: bool = False, b_corrected: bool = False
+) -> dict[str, Any]:
+    """Compare two recordings' transcripts (or one against its corrected text)."""
+    result = diff_words(
+        recording_tokens(id_a, corrected=a_corrected),
+        recording_tokens(id_b, corrected=b_corrected),
+    )
+    return {"recording_a": id_a, "recording_b": id_b, **result}
@@ -0,0 +1,304 @@
+"""Import transcripts made by other tools into the Audio Notebook.
+
+People switching over usually have years of history elsewhere. This module
+turns the common export formats into plain segments (``start`` / ``end`` /
+``text`` / ``speaker``) that ``save_longform_to_database`` stores like any
+other recording:
+
+- ``whisper_json`` — openai-whisper / WhisperX / faster-whisper JSON
+  (``{"segments": [...], "language": ...}`` or a bare segment list). Word
+  timings are kept when every segment has them.
+- ``otter`` — Otter.ai TXT export: a ``Speaker Name  0:15`` header line,
+  then the paragraph. The trailing "Transcribed by otter.ai" line is dropped.
+- ``sbv`` — YouTube ``.sbv`` captions (``0:00:01.000,0:00:03.500`` timing
+  line, then the text).
+- ``descript`` — Descript's plain-text export with timestamps:
+  ``[00:01:02] Speaker: text`` paragraphs.
+- ``srt`` / ``vtt`` / ``ass`` — delegated to ``subtitle_import``.
+
+Otter and Descript only stamp the start of a paragraph, so each paragraph
+ends where the next begins; the last one gets a reading-speed estimate.
+"""
+
+from __future__ import annotations
+
+import json
+import re
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any
+
+from server.core.subtitle_import import (
+    MAX_CUES,
+    SUBTITLE_FORMATS,
+    parse_subtitles,
+    split_speaker,
+)
+from server.core.subtitle_import import detect_format as detect_subtitle_format
+
+TRANSCRIPT_FORMATS: tuple[str, ...] = (
+    "whisper_json",
+    "otter",
+    "sbv",
+    "descript",
+    *SUBTITLE_FORMATS,
+)
+MAX_TRANSCRIPT_BYTES = 20 * 1024 * 1024
+# Speaking rate used to give an open-ended last paragraph a plausible length.
+_WORDS_PER_SECOND = 2.5
+
+_CLOCK = r"(?:(\d{1,2}):)?(\d{1,2}):(\d{2})(?:[.,](\d{1,3}))?"
+_SBV_TIMING_RE = re.compile(rf"^\s*{_CLOCK}\s*,\s*{_CLOCK}\s*$")
+_OTTER_HEADER_RE = re.compile(rf"^(?:(\S.{{0,39}}?)\s+)?{_CLOCK}\s*$")
+_DESCRIPT_LINE_RE = re.compile(rf"^\s*\[{_CLOCK}\]\s*(.*)$")
+_OTTER_FOOTER_RE = re.compile(r"^transcribed by\b.*otter\.ai", re.IGNORECASE)
+
+
+@dataclass(slots=True)
+class ImportedTranscript:
+    """Parsed transcript; ``words`` is ``None`` when the source had no word timings."""
+
+    format: str
+    segments: list[dict[str, Any]]
+    words: list[dict[str, Any]] | None = None
+    language: str | None = None
+    duration: float = 0.0
+
+
+def _clock(groups: tuple[str | None, ...]) -> float:
+    hours, minutes, secs, fraction = groups
+    value = int(hours or 0) * 3600 + int(minutes) * 60 + int(secs)
+    if fraction:
+        value += int(fraction) / (10 ** len(fraction))
+    return float(value)
+
+
+def _first_line(content: str) -> str:
+    return next((line.strip() for line in content.splitlines() if line.strip()), "")
+
+
+def detect_transcript_format(filename: str, content: str) -> str:
+    """Pick the importer from the extension, falling back to content sniffing."""
+    suffix = Path(filename or "").suffix.lower().lstrip(".")
+    if suffix == "json":
+        return "whisper_json"
+    if suffix == "sbv":
+        return "sbv"
+    if suffix in (*SUBTITLE_FORMATS, "ssa"):
+        return detect_subtitle_format(filename, content)
+
+    text = content.lstrip("\ufeff")
+    first = _first_line(text)
+    if first.startswith(("{", "[{")) or first == "[":
+        return "whisper_json"
+    if first.startswith("WEBVTT") or first.startswith("[Script Info]"):
+        return detect_subtitle_format("", text)
+    if _SBV_TIMING_RE.match(first):
+        return "sbv"
+    if _DESCRIPT_LINE_RE.match(first):
+        return "descript"
+    if _OTTER_HEADER_RE.match(first):
+        return "otter"
+    if "-->" in text[:2000]:
+        return "srt"
+    raise ValueError("Could not recognise the transcript format")
+
+
+def _lines(content: str) -> list[str]:
+    return content.lstrip("\ufeff").replace("\r\n", "\n").replace("\r", "\n").split("\n")
+
+
+def _segment(start: float, end: float, text: str, speaker: str | None) -> dict[str, Any]:
+    return {"start": start, "end": max(start, end), "text": text, "speaker": speaker}
+
+
+def _close_open_ends(segments: list[dict[str, Any]]) -> None:
+    """Paragraphs only carry a start time: end each where the next one starts."""
+    for current, following in zip(segments, segments[1:], strict=False):
+        current["end"] = max(current["start"], following["start"])
+    if segments:
+        last = segments[-1]
+        last["end"] = last["start"] + max(1.0, len(last["text"].split()) / _WORDS_PER_SECOND)
+
+
+def parse_whisper_json(content: str) -> ImportedTranscript:
+    try:
+        data = json.loads(content)
+    except json.JSONDecodeError as e:
+        raise ValueError(f"Invalid JSON: {e.msg} (line {e.lineno})") from e
+    raw_segments = data.get("segments") if isinstance(data, dict) else data
+    if not isinstance(raw_segments, list):
+        raise ValueError("Whisper JSON must contain a 'segments' list")
+
+    segments: list[dict[str, Any]] = []
+    words: list[dict[str, Any]] = []
+    every_segment_has_words = True
+    for raw in raw_segments:
+        if not isinstance(raw, dict):
+            continue
+        text = " ".join(str(raw.get("text") or "").split())
+        if not text:
+            continue
+        try:
+            start, end = float(raw.get("start") or 0.0), float(raw.get("end") or 0.0)
+        except (TypeError, ValueError) as e:
+            raise ValueError(f"segment {len(segments) + 1}: invalid start/end") from e
+        speaker = raw.get("speaker")
+        segments.append(_segment(start, end, text, str(speaker) if speaker else None))
+        raw_words = [w for w in raw.get("words") or [] if isinstance(w, dict)]
+        timed = [w for w in raw_words if w.get("start") is not None and w.get("end") is not None]
+        if not timed:
+            every_segment_has_words = False
+            continue
+        for w in timed:
+            confidence = w.get("probability", w.get("score"))
+            words.append(
+                {
+                    "word": str(w.get("word") or "").strip(),
+                    "start": float(w["start"]),
+                    "end": float(w["end"]),
+                    "confidence": float(confidence) if confidence is not None else None,
+                    "segment_index": len(segments) - 1,
+                }
+            )
+
+    language = data.get("language") if isinstance(data, dict) else None
+    return ImportedTranscript(
+        format="whisper_json",
+        segments=segments,
+        words=[w for w in words if w["word"]] if every_segment_has_words and words else None,
+        language=str(language) if language else None,
+    )
+
+
+def parse_otter(content: str) -> ImportedTranscript:
+    segments: list[dict[str, Any]] = []
+    header: tuple[float, str | None] | None = None
+    body: list[str] = []
+
+    def flush() -> None:
+        text = " ".join(" ".join(body).split())
+        if header is not None and text:
+            segments.append(_segment(header[0], header[0], text, header[1]))
+
+    previous_blank = True
+    for line in _lines(content):
+        stripped = line.strip()
+        if _OTTER_FOOTER_RE.match(stripped):
+            continue
+        # Headers open a paragraph; "see you at 10:30" mid-paragraph is text.
+        match = _OTTER_HEADER_RE.match(stripped) if previous_blank else None
+        previous_blank = not stripped
+        if match:
+            flush()
+            speaker = match.group(1)
+            header = (_clock(match.groups()[1:]), speaker.strip() if speaker else None)
+            body = []
+        elif stripped:
+            body.append(stripped)
+    flush()
+    _close_open_ends(segments)
+    return ImportedTranscript(format="otter", segments=segments)
+
+
+def parse_sbv(content: str) -> ImportedTranscript:
+    segments: list[dict[str, Any]] = []
+    timing: tuple[float, float] | None = None
+    body: list[str] = []
+
+    def flush() -> None:
+        text = " ".join(" ".join(body).split())
+        if timing is not None and text:
+            speaker, text = split_speaker(text)
+            segments.append(_segment(timing[0], timing[1], text, speaker))
+
+    for line in _lines(content):
+        match = _SBV_TIMING_RE.match(line)
+        if match:
+            flush()
+            groups = match.groups()
+            timing = (_clock(groups[:4]), _clock(groups[4:]))
+            body = []
+        elif line.strip():
+            body.append(line.strip())
+    flush()
+    return ImportedTranscript(format="sbv", segments=segments)
+
+
+def parse_descript(content: str) -> ImportedTranscript:
+    segments: list[dict[str, Any]] = []
+    start: float | None = None
+    body: list[str] = []
+
+    def flush() -> None:
+        text = " ".join(" ".join(body).split())
+        if start is not None and text:
+            speaker, text = split_speaker(text)
+            segments.append(_segment(start, start, text, speaker))
+
+    for line in _lines(content):
+        match = _DESCRIPT_LINE_RE.match(line)
+        if match:
+            flush()
+            start = _clock(match.groups()[:4])
+            body = [match.group(5)]
+        elif line.strip():
+            body.append(line.strip())
+    flush()
+    _close_open_ends(segments)
+    return ImportedTranscript(format="descript", segments=segments)
+
+
+def parse_transcript(content: str, fmt: str) -> ImportedTranscript:
+    """Parse ``content`` as ``fmt``; raises ``ValueError`` on unusable input."""
+    parsers = {
+        "whisper_json": parse_whisper_json,
+        "otter": parse_otter,
+        "sbv": parse_sbv,
+        "descript": parse_descript,
+    }
+    if fmt in SUBTITLE_FORMATS:
+        cues = parse_subtitles(content, fmt)
+        transcript = ImportedTranscript(
+            format=fmt,
+            segments=[_segment(c.start, c.end, c.text, c.speaker) for c in cues],
+        )
+    elif fmt in parsers:
+        transcript = parsers[fmt](content)
+    else:
+        raise ValueError(f"Unsupported transcript format: {fmt}")
+    if not transcript.segments:
+        raise ValueError(f"No transcript text found in {fmt} file")
+    if len(transcript.segments) > MAX_CUES:
+        raise ValueError(f"Too many segments (max {MAX_CUES})")
+    order = sorted(range(len(transcript.segments)), key=lambda i: transcript.segments[i]["start"])
+    if order != list(range(len(order))):
+        # Keep words pointing at their segment once the segments are re-ordered.
+        transcript.segments = [transcript.segments[i] for i in order]
+        remap = {old: new for new, old in enumerate(order)}
+        for word in transcript.words or []:
+            word["segment_index"] = remap[word["segment_index"]]
+    transcript.duration = max(seg["end"] for seg in transcript.segments)
+    return transcript
+
+
+def decode_transcript_bytes(raw: bytes) -> str:
+    try:
+        return raw.decode("utf-8-sig")
+    except UnicodeDecodeError:
+        # Older exports are frequently Windows-1252; latin-1 never fails.
+        return raw.decode("latin-1")
+
+
+def load_transcript(filename: str, raw: bytes, fmt: str | None = None) -> ImportedTranscript:
+    """Decode, detect (unless ``fmt`` is given) and parse an uploaded transcript."""
+    if len(raw) > MAX_TRANSCRIPT_BYTES:
+        raise ValueError("Transcript file is too large")
+    content = decode_transcript_bytes(raw)
+    return parse_transcript(content, fmt or detect_transcript_format(filename, content))
+
+
+def import_transcript_file(path: str | Path, fmt: str | None = None) -> ImportedTranscript:
+    """Parse the transcript at ``path``, auto-detecting its format."""
+    path = Path(path)
+    return load_transcript(path.name, path.read_bytes(), fmt)
@@ -0,0 +1,164 @@
+"""
+Staged uploads: files a client sends ahead of their transcription.
+
+The server runs one transcription at a time, and the upload endpoints
+(``/api/transcribe/import``, ``/api/notebook/transcribe/upload``) take the
+job slot as they receive the file, so a client working through a batch used
+to upload, wait for the transcription, then upload the next file. A client
+can instead stage the next files — several in parallel — while the current
+one transcribes, and start each job with its ``upload_id``.
+
+Files arrive in chunks appended at the current offset, which lets the client
+show per-file progress and pace all its streams against one bandwidth
+budget. A chunk at the wrong offset is rejected with the byte count the
+server has, so the client can resume from there. Staged files belong to the
+requester that created them; ones untouched for ``STAGED_TTL_SECONDS`` are
+deleted the next time anyone stages a file.
+"""
+
+from __future__ import annotations
+
+import shutil
+import tempfile
+import threading
+import time
+import uuid
+from dataclasses import dataclass
+from pathlib import Path
+
+STAGED_TTL_SECONDS = 6 * 60 * 60
+MAX_STAGED_PER_USER = 16
+MAX_STAGED_BYTES = 20 * 1024**3
+
+
+class StagedUploadNotFound(LookupError):
+    """The upload does not exist (or expired), or belongs to someone else."""
+
+
+class StagedUploadError(ValueError):
+    """The request does not fit the upload: bad size, overflow, or incomplete."""
+
+
+class StagedUploadOffsetMismatch(StagedUploadError):
+    """A chunk was sent for an offset other than the bytes received so far."""
+
+    def __init__(self, received: int) -> None:
+        super().__init__(f"Expected offset {received}")
+        self.received = received
+
+
+@dataclass
+class StagedUpload:
+    id: str
+    owner: str
+    filename: str
+    size: int
+    path: Path
+    received: int = 0
+    touched_at: float = 0.0
+
+    @property
+    def complete(self) -> bool:
+        return self.received == self.size
+
+
+class UploadStaging:
+    def __init__(self, directory: Path | None = None) -> None:
+        self._dir = directory or Path(tempfile.gettempdir()) / "transcriptionsuite-staged"
+        self._uploads: dict[str, StagedUpload] = {}
+        self._lock = threading.Lock()
+
+    def _get(self, upload_id: str, owner: str) -> StagedUpload:
+        upload = self._uploads.get(upload_id)
+        if upload is None or upload.owner != owner:
+            raise StagedUploadNotFound(upload_id)
+        return upload
+
+    def prune(self, now: float | None = None) -> int:
+        """Delete uploads nobody touched for ``STAGED_TTL_SECONDS``."""
+        now = time.time() if now is None else now
+        with self._lock:
+            stale = [u for u in self._uploads.values() if now - u.touched_at > STAGED_TTL_SECONDS]
+            for upload in stale:
+                del self._uploads[upload.id]
+        for upload in stale:
+            upload.path.unlink(missing_ok=True)
+        return len(stale)
+
+    def create(
+        self, owner: str, filename: str, size: int, now: float | None = None
+    ) -> StagedUpload:
+        if size <= 0 or size > MAX_STAGED_BYTES:
+            raise StagedUploadError(f"size must be between 1 and {MAX_STAGED_BYTES} bytes")
+        now = time.time() if now is None else now
+        self.prune(now)
+        with self._lock:
+            if sum(u.owner == owner for u in self._uploads.values()) >= MAX_STAGED_PER_USER:
+                raise StagedUploadError(f"At most {MAX_STAGED_PER_USER} staged uploads per user")
+            upload_id = uuid.uuid4().hex
+            suffix = Path(filename).suffix or ".wav"
+            self._dir.mkdir(parents=True, exist_ok=True)
+            path = self._dir / f"{upload_id}{suffix}"
+            path.touch()
+            upload = StagedUpload(
+                id=upload_id,
+                owner=owner,
+                filename=Path(filename).name or f"upload{suffix}",
+                size=size,
+                path=path,
+                touched_at=now,
+            )
+            self._uploads[upload_id] = upload
+            return upload
+
+    def append(
+        self, upload_id: str, owner: str, offset: int, data: bytes, now: float | None = None
+    ) -> int:
+        """Append a chunk at ``offset``; returns the bytes received so far."""
+        with self._lock:
+            upload = self._get(upload_id, owner)
+            if offset != upload.received:
+                raise StagedUploadOffsetMismatch(upload.received)
+            if upload.received + len(data) > upload.size:
+                raise StagedUploadError("Chunk runs past the declared size")
+            with upload.path.open("ab") as f:
+                f.write(data)
+            upload.received += len(data)
+            upload.touched_at = time.time() if now is None else now
+            return upload.received
+
+    def status(self, upload_id: str, owner: str) -> StagedUpload:
+        with self._lock:
+            return self._get(upload_id, owner)
+
+    def claim(self, upload_id: str, owner: str, destination: Path) -> str:
+        """
+        Move a complete upload to ``destination`` and forget it; returns the
+        original filename. The caller owns the file from here on.
+        """
+        with self._lock:
+            upload = self._get(upload_id, owner)
+            if not upload.complete:
+                raise StagedUploadError(
+                    f"Upload incomplete: {upload.received} of {upload.size} bytes"
+                )
+            del self._uploads[upload_id]
+        shutil.move(upload.path, destination)
+        return upload.filename
+
+    def discard(self, upload_id: str, owner: str) -> None:
+        with self._lock:
+            upload = self._get(upload_id, owner)
+            del self._uploads[upload_id]
+        upload.path.unlink(missing_ok=True)
+
+
+_staging: UploadStaging | None = None
+
+
+def get_upload_staging() -> UploadStaging:
+    """Get or create the global staging area."""
+    global _staging
+    if _staging is None:
+        _staging = UploadStaging()
+    return _staging
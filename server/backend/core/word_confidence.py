@@ -0,0 +1,85 @@
+"""Low-confidence word regions for transcript review.
+
+``words.confidence`` holds the STT backend's per-word probability (0–1;
+NULL for backends that do not report one and for words typed in by hand).
+Reviewers care about *stretches* of uncertain words, not single tokens, so
+consecutive words under the threshold are merged into regions the viewer
+can highlight and step through.
+
+A single confident word between two low ones does not split a region
+(``bridge_words``) — "the" between two misheard names is still the same
+problem spot. Regions are ordered by start time.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Iterable, Mapping
+from typing import Any
+
+from server.core.diarization_confidence import LOW_CONFIDENCE_THRESHOLD
+
+DEFAULT_BRIDGE_WORDS = 1
+
+
+def _score(word: Mapping[str, Any]) -> float | None:
+    value = word.get("confidence")
+    return float(value) if isinstance(value, (int, float)) else None
+
+
+def low_confidence_regions(
+    words: Iterable[Mapping[str, Any]],
+    threshold: float = LOW_CONFIDENCE_THRESHOLD,
+    *,
+    bridge_words: int = DEFAULT_BRIDGE_WORDS,
+) -> list[dict[str, Any]]:
+    """Group words scoring below ``threshold`` into review regions.
+
+    Each region is ``{start, end, text, word_ids, min_confidence,
+    mean_confidence, segment_id}``; ``segment_id`` is the segment of the
+    region's first word. Words without a score never start or extend a
+    region.
+    """
+    ordered = sorted(words, key=lambda w: float(w.get("start_time") or 0.0))
+    regions: list[dict[str, Any]] = []
+    current: list[Mapping[str, Any]] = []
+    pending: list[Mapping[str, Any]] = []
+
+    def flush() -> None:
+        if not current:
+            return
+        scores = [s for s in (_score(w) for w in current) if s is not None and s < threshold]
+        regions.append(
+            {
+                "start": float(current[0].get("start_time") or 0.0),
+                "end": float(current[-1].get("end_time") or 0.0),
+                "text": " ".join(str(w.get("word") or "").strip() for w in current),
+                "word_ids": [w.get("id") for w in current],
+                "min_confidence": round(min(scores), 4),
+                "mean_confidence": round(sum(scores) / len(scores), 4),
+                "segment_id": current[0].get("segment_id"),
+            }
+        )
+        current.clear()
+
+    for word in ordered:
+        score = _score(word)
+        if score is not None and score < threshold:
+            current.extend(pending)
+            pending.clear()
+            current.append(word)
+        elif current and score is not None and len(pending) < bridge_words:
+            pending.append(word)
+        else:
+            flush()
+            pending.clear()
+    flush()
+    return regions
+
+
+def confidence_coverage(words: Iterable[Mapping[str, Any]]) -> float:
+    """Fraction of words carrying a score — 0.0 means the backend reported none."""
+    total = scored = 0
+    for word in words:
+        total += 1
+        scored += _score(word) is not None
+    return scored / total if total else 0.0
@@ -0,0 +1,163 @@
+"""Annotation repository — threaded review notes on a transcript.
+
+CRUD over the ``annotations`` table created by migration 020. A note is
+anchored to a time range and optionally a segment; replies carry the id of
+their thread root in ``parent_id`` (threads are one level deep — replying to
+a reply attaches to the same root).
+
+All writes commit before returning (NFR16).
+"""
+
+from __future__ import annotations
+
+import logging
+import sqlite3
+from datetime import UTC, datetime
+from typing import Any
+
+from server.database.database import get_connection
+
+logger = logging.getLogger(__name__)
+
+_COLUMNS = (
+    "id, recording_id, segment_id, parent_id, start_time, end_time, "
+    "author, body, created_at, updated_at"
+)
+
+
+def _now_iso() -> str:
+    return datetime.now(UTC).isoformat()
+
+
+def list_annotations(recording_id: int) -> list[dict[str, Any]]:
+    """All notes for ``recording_id``, roots and replies, ordered by time then id.
+
+    Returns an empty list when the table is missing (fixtures that do not
+    run migration 020), mirroring ``alias_repository.list_aliases``.
+    """
+    try:
+        with get_connection() as conn:
+            rows = conn.execute(
+                f"SELECT {_COLUMNS} FROM annotations WHERE recording_id = ? "
+                "ORDER BY start_time, id",
+                (recording_id,),
+            ).fetchall()
+    except sqlite3.OperationalError as exc:
+        if "no such table" in str(exc).lower():
+            logger.debug("annotations table missing — returning empty list")
+            return []
+        raise
+    return [dict(row) for row in rows]
+
+
+def get_annotation(recording_id: int, annotation_id: int) -> dict[str, Any] | None:
+    """One note by id, scoped to the recording; None when missing."""
+    with get_connection() as conn:
+        row = conn.execute(
+            f"SELECT {_COLUMNS} FROM annotations WHERE id = ? AND recording_id = ?",
+            (annotation_id, recording_id),
+        ).fetchone()
+    return dict(row) if row else None
+
+
+def create_annotation(
+    recording_id: int,
+    *,
+    author: str,
+    body: str,
+    start_time: float | None = None,
+    end_time: float | None = None,
+    segment_id: int | None = None,
+    parent_id: int | None = None,
+) -> dict[str, Any]:
+    """Insert a note and return the stored row.
+
+    A reply inherits its root's anchor (time range and segment). A
+    segment-anchored note without explicit times takes the segment's span.
+    Raises ValueError when the parent or segment does not belong to the
+    recording, or when a root note has no anchor at all.
+    """
+    now = _now_iso()
+    with get_connection() as conn:
+        if parent_id is not None:
+            parent = conn.execute(
+                "SELECT id, parent_id, segment_id, start_time, end_time FROM annotations "
+                "WHERE id = ? AND recording_id = ?",
+                (parent_id, recording_id),
+            ).fetchone()
+            if parent is None:
+                raise ValueError(f"annotation {parent_id} not found on this recording")
+            parent_id = parent["parent_id"] or parent["id"]
+            segment_id = parent["segment_id"]
+            start_time, end_time = parent["start_time"], parent["end_time"]
+        elif segment_id is not None:
+            seg = conn.execute(
+                "SELECT start_time, end_time FROM segments WHERE id = ? AND recording_id = ?",
+                (segment_id, recording_id),
+            ).fetchone()
+            if seg is None:
+                raise ValueError(f"segment {segment_id} not found on this recording")
+            if start_time is None:
+                start_time, end_time = seg["start_time"], seg["end_time"]
+        if start_time is None:
+            raise ValueError("a note needs a segment_id or a start_time")
+        if end_time is not None and end_time < start_time:
+            raise ValueError("end_time must not be before start_time")
+        cursor = conn.execute(
+            """
+            INSERT INTO annotations
+                (recording_id, segment_id, parent_id, start_time, end_time,
+                 author, body, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            """,
+            (recording_id, segment_id, parent_id, start_time, end_time, author, body, now, now),
+        )
+        conn.commit()
+        new_id = cursor.lastrowid
+    created = get_annotation(recording_id, int(new_id))
+    assert created is not None
+    return created
+
+
+def update_annotation(recording_id: int, annotation_id: int, body: str) -> dict[str, Any] | None:
+    """Replace a note's body; returns the updated row or None when missing."""
+    with get_connection() as conn:
+        cursor = conn.execute(
+            "UPDATE annotations SET body = ?, updated_at = ? WHERE id = ? AND recording_id = ?",
+            (body, _now_iso(), annotation_id, recording_id),
+        )
+        conn.commit()
+        if cursor.rowcount == 0:
+            return None
+    return get_annotation(recording_id, annotation_id)
+
+
+def delete_annotation(recording_id: int, annotation_id: int) -> bool:
+    """Delete a note; deleting a thread root also deletes its replies."""
+    with get_connection() as conn:
+        conn.execute(
+            "DELETE FROM annotations WHERE parent_id = ? AND recording_id = ?",
+            (annotation_id, recording_id),
+        )
+        cursor = conn.execute(
+            "DELETE FROM annotations WHERE id = ? AND recording_id = ?",
+            (annotation_id, recording_id),
+        )
+        conn.commit()
+        return cursor.rowcount > 0
+
+
+def build_threads(rows: list[dict[str, Any]]) -> list[dict[str, Any]]:
+    """Nest replies under their root: each root gains ``replies`` (oldest first)."""
+    roots: dict[int, dict[str, Any]] = {}
+    replies: list[dict[str, Any]] = []
+    for row in rows:
+        if row.get("parent_id") is None:
+            roots[row["id"]] = {**row, "replies": []}
+        else:
+            replies.append(row)
+    for reply in sorted(replies, key=lambda r: (r["created_at"], r["id"])):
+        root = roots.get(reply["parent_id"])
+        if root is not None:
+            root["replies"].append(reply)
+    return list(roots.values())
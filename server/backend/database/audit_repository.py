@@ -0,0 +1,198 @@
+"""Audit repository — append-only trail of destructive actions.
+
+Storage over the ``audit_log`` table created by migration 028. Routes
+record deletions, redactions, bulk transcript edits and settings changes
+through ``api.routes.utils.audit_event`` after the action succeeds; each
+row carries the server-side timestamp, who asked (``get_requester``) and
+the initiating request. Triggers in the schema reject UPDATE and DELETE,
+so this module only ever inserts and reads.
+
+All writes commit before returning (NFR16).
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+import sqlite3
+from datetime import UTC, date, datetime, timedelta
+from typing import Any
+
+from server.database.database import get_connection
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_LIMIT = 500
+MAX_LIMIT = 5000
+
+
+def _is_missing_table(exc: sqlite3.OperationalError) -> bool:
+    return "no such table" in str(exc).lower()
+
+
+def _row_to_dict(row: Any) -> dict[str, Any]:
+    return {
+        "id": row["id"],
+        "occurred_at": row["occurred_at"],
+        "action": row["action"],
+        "target_type": row["target_type"],
+        "target_id": row["target_id"],
+        "actor": row["actor"],
+        "command": row["command"],
+        "details": json.loads(row["details_json"] or "{}"),
+    }
+
+
+def _is_bare_date(value: str) -> bool:
+    try:
+        date.fromisoformat(value)
+    except ValueError:
+        return False
+    return True
+
+
+def _bound(value: str, *, end: bool) -> str:
+    """
+    A range bound comparable with ``occurred_at``. Datetimes are used as
+    given (converted to UTC when they carry an offset); a bare date as an
+    end bound is the start of the next day.
+    """
+    if not _is_bare_date(value):
+        moment = datetime.fromisoformat(value)
+        if moment.tzinfo is None:
+            moment = moment.replace(tzinfo=UTC)
+        return moment.astimezone(UTC).isoformat()
+    day = date.fromisoformat(value)
+    if end:
+        day += timedelta(days=1)
+    return datetime(day.year, day.month, day.day, tzinfo=UTC).isoformat()
+
+
+def record_event(
+    action: str,
+    *,
+    command: str,
+    actor: str | None = None,
+    target_type: str | None = None,
+    target_id: str | int | None = None,
+    details: dict[str, Any] | None = None,
+) -> int | None:
+    """Append one event and commit. Returns its id, or None before migration 028."""
+    try:
+        with get_connection() as conn:
+            cur = conn.execute(
+                """
+                INSERT INTO audit_log
+                    (occurred_at, action, target_type, target_id, actor, command, details_json)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                """,
+                (
+                    datetime.now(UTC).isoformat(),
+                    action,
+                    target_type,
+                    None if target_id is None else str(target_id),
+                    actor,
+                    command,
+                    json.dumps(details or {}, default=str),
+                ),
+            )
+            conn.commit()
+            return cur.lastrowid
+    except sqlite3.OperationalError as exc:
+        if _is_missing_table(exc):
+            logger.warning("audit_log table missing; %s not recorded", action)
+            return None
+        raise
+
+
+def get_audit_log(
+    start: str | None = None,
+    end: str | None = None,
+    *,
+    action: str | None = None,
+    limit: int = DEFAULT_LIMIT,
+) -> list[dict[str, Any]]:
+    """
+    Events in ``[start, end]``, newest first. Bounds are ISO dates or
+    datetimes (ValueError otherwise); ``end`` as a bare date includes that
+    day. ``action`` matches exactly or as a prefix (``recording`` matches
+    ``recording.delete``).
+    """
+    clauses: list[str] = []
+    params: list[Any] = []
+    if start:
+        clauses.append("occurred_at >= ?")
+        params.append(_bound(start, end=False))
+    if end:
+        clauses.append("occurred_at < ?" if _is_bare_date(end) else "occurred_at <= ?")
+        params.append(_bound(end, end=True))
+    if action:
+        clauses.append("(action = ? OR action LIKE ? ESCAPE '\\')")
+        escaped = action.replace("\\", "\\\\").replace("%", "\\%").replace("_", "\\_")
+        params.extend([action, f"{escaped}.%"])
+    where = f"WHERE {' AND '.join(clauses)}" if clauses else ""
+    params.append(max(1, min(limit, MAX_LIMIT)))
+    try:
+        with get_connection() as conn:
+            rows = conn.execute(
+                f"SELECT * FROM audit_log {where} ORDER BY id DESC LIMIT ?",
+                params,
+            ).fetchall()
+    except sqlite3.OperationalError as exc:
+        if _is_missing_table(exc):
+            return []
+        raise
+    return [_row_to_dict(row) for row in rows]
+
+
+def all_events() -> list[dict[str, Any]]:
+    """Every event, oldest first — kept aside across a backup restore."""
+    try:
+        with get_connection() as conn:
+            rows = conn.execute("SELECT * FROM audit_log ORDER BY id").fetchall()
+    except sqlite3.OperationalError as exc:
+        if _is_missing_table(exc):
+            return []
+        raise
+    return [_row_to_dict(row) for row in rows]
+
+
+def reappend(events: list[dict[str, Any]]) -> int:
+    """
+    Re-insert the events a restored database is missing, i.e. those newer
+    than its last row, keeping their ids and timestamps. A restore must not
+    erase the trail of what happened since the backup was taken. Returns
+    how many were appended.
+    """
+    try:
+        with get_connection() as conn:
+            last_id = conn.execute("SELECT COALESCE(MAX(id), 0) FROM audit_log").fetchone()[0]
+            missing = [e for e in events if e["id"] > last_id]
+            conn.executemany(
+                """
+                INSERT INTO audit_log
+                    (id, occurred_at, action, target_type, target_id, actor, command,
+                     details_json)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                """,
+                [
+                    (
+                        e["id"],
+                        e["occurred_at"],
+                        e["action"],
+                        e["target_type"],
+                        e["target_id"],
+                        e["actor"],
+                        e["command"],
+                        json.dumps(e["details"]),
+                    )
+                    for e in missing
+                ],
+            )
+            conn.commit()
+    except sqlite3.OperationalError as exc:
+        if _is_missing_table(exc):
+            logger.warning("Restored database has no audit_log; %d events dropped", len(events))
+            return 0
+        raise
+    return len(missing)
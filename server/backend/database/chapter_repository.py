@@ -0,0 +1,89 @@
+"""Chapter repository — the stored outline of a recording.
+
+CRUD over the ``chapters`` table created by migration 024. Segmentation and
+titling live in ``server/backend/core/chaptering.py``; this module provides
+only the data primitives.
+
+All writes commit before returning (NFR16).
+"""
+
+from __future__ import annotations
+
+import logging
+import sqlite3
+from datetime import UTC, datetime
+from typing import Any
+
+from server.database.database import get_connection
+
+logger = logging.getLogger(__name__)
+
+CHAPTER_SOURCES: tuple[str, ...] = ("auto", "llm")
+
+
+def list_chapters(recording_id: int) -> list[dict[str, Any]]:
+    """Chapters ordered by start time; empty when the table is missing."""
+    try:
+        with get_connection() as conn:
+            rows = conn.execute(
+                """
+                SELECT id, recording_id, start_time, end_time, title, source, created_at
+                FROM chapters
+                WHERE recording_id = ?
+                ORDER BY start_time, id
+                """,
+                (recording_id,),
+            ).fetchall()
+    except sqlite3.OperationalError as exc:
+        if "no such table" in str(exc).lower():
+            logger.debug("chapters table missing — returning empty list")
+            return []
+        raise
+    return [dict(row) for row in rows]
+
+
+def replace_chapters(recording_id: int, chapters: list[dict[str, Any]]) -> list[int]:
+    """Swap the recording's outline for ``chapters`` atomically; returns the new ids.
+
+    Each entry has ``start_time``, ``end_time``, ``title`` and optional
+    ``source``. Raises ValueError for an empty or inverted range, a blank
+    title or an unknown source.
+    """
+    now = datetime.now(UTC).isoformat()
+    rows = []
+    for c in chapters:
+        start, end = float(c["start_time"]), float(c["end_time"])
+        if start < 0 or end <= start:
+            raise ValueError(f"invalid chapter range {start}–{end}")
+        title = str(c.get("title") or "").strip()
+        if not title:
+            raise ValueError("chapter title must not be empty")
+        source = c.get("source") or "auto"
+        if source not in CHAPTER_SOURCES:
+            raise ValueError(f"unknown chapter source {source!r}")
+        rows.append((recording_id, start, end, title, source, now))
+    with get_connection() as conn:
+        conn.execute("DELETE FROM chapters WHERE recording_id = ?", (recording_id,))
+        ids = [
+            int(
+                conn.execute(
+                    """
+                    INSERT INTO chapters
+                        (recording_id, start_time, end_time, title, source, created_at)
+                    VALUES (?, ?, ?, ?, ?, ?)
+                    """,
+                    row,
+                ).lastrowid
+            )
+            for row in rows
+        ]
+        conn.commit()
+    return ids
+
+
+def clear_chapters(recording_id: int) -> int:
+    """Delete the recording's outline; returns the number of chapters removed."""
+    with get_connection() as conn:
+        cursor = conn.execute("DELETE FROM chapters WHERE recording_id = ?", (recording_id,))
+        conn.commit()
+        return cursor.rowcount
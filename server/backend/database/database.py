@@ -23,6 +23,8 @@ from typing import Any
 
 import numpy as np
 
+from server.database.library_lock import is_read_only
+
 logger = logging.getLogger(__name__)
 
 # Path to migrations directory
@@ -75,6 +77,13 @@ def get_audio_dir() -> Path:
     return audio_dir
 
 
+def _connect(db_path: Path, **kwargs: Any) -> sqlite3.Connection:
+    """Open the notebook DB — with ``mode=ro`` while another machine owns the library."""
+    if is_read_only():
+        return sqlite3.connect(f"{db_path.resolve().as_uri()}?mode=ro", uri=True, **kwargs)
+    return sqlite3.connect(db_path, **kwargs)
+
+
 @contextmanager
 def get_connection() -> Generator[sqlite3.Connection]:
     """Get a database connection with context manager.
@@ -84,7 +93,7 @@ def get_connection() -> Generator[sqlite3.Connection]:
     - 5 second busy timeout for retry on SQLITE_BUSY
     - Multi-thread support enabled
     """
-    conn = sqlite3.connect(
+    conn = _connect(
         get_db_path(),
         timeout=30.0,  # Wait up to 30s for locks
         check_same_thread=False,  # Allow multi-thread access
@@ -159,6 +168,10 @@ def _assert_schema_sanity(conn: sqlite3.Connection) -> None:
             "summary_model",
             "transcript_corrected",
             "transcription_backend",
+            "timecode_settings",
+            "keep_audio",
+            "audio_retired_at",
+            "owner",
         },
         "segments": {
             "id",
@@ -168,6 +181,8 @@ def _assert_schema_sanity(conn: sqlite3.Connection) -> None:
             "text",
             "start_time",
             "end_time",
+            "review_state",
+            "reviewed_at",
         },
         "words": {
             "id",
@@ -240,6 +255,13 @@ def init_db() -> None:
     """
     logger.info(f"Initializing database at {get_db_path()}")
 
+    if is_read_only():
+        # Another machine owns the library: migrations and the WAL pragma both write.
+        with get_connection() as conn:
+            _assert_schema_sanity(conn)
+        logger.info("Database opened read-only (library is owned by another machine)")
+        return
+
     # Migrations are required. Do not silently continue on failure.
     if not run_migrations():
         raise RuntimeError(
@@ -283,6 +305,13 @@ class Recording:
         # Non-destructive hand-corrected transcript (NULL = use original segments)
         self.transcript_corrected = data.get("transcript_corrected")
         self.transcription_backend = data.get("transcription_backend")
+        # JSON SMPTE settings (NULL = no timecode) — see core/timecode.py
+        self.timecode_settings = data.get("timecode_settings")
+        # Source-audio retention (migration 023) — see recording_audio_retention.py
+        self.keep_audio = bool(data.get("keep_audio", 0))
+        self.audio_retired_at = data.get("audio_retired_at")
+        # Per-user namespace on shared servers (migration 025); NULL = shared
+        self.owner = data.get("owner")
 
     def to_dict(self) -> dict[str, Any]:
         return {
@@ -299,6 +328,10 @@ class Recording:
             "summary_model": self.summary_model,
             "transcript_corrected": self.transcript_corrected,
             "transcription_backend": self.transcription_backend,
+            "timecode_settings": self.timecode_settings,
+            "keep_audio": self.keep_audio,
+            "audio_retired_at": self.audio_retired_at,
+            "owner": self.owner,
         }
 
 
@@ -408,6 +441,18 @@ def update_recording_corrected_transcript(
         return cursor.rowcount > 0
 
 
+def update_recording_timecode_settings(recording_id: int, settings_json: str | None) -> bool:
+    """Set or clear (NULL) a recording's serialized timecode settings."""
+    with get_connection() as conn:
+        cursor = conn.cursor()
+        cursor.execute(
+            "UPDATE recordings SET timecode_settings = ? WHERE id = ?",
+            (settings_json, recording_id),
+        )
+        conn.commit()
+        return cursor.rowcount > 0
+
+
 def update_recording_title(recording_id: int, title: str) -> bool:
     """Update the title for a recording."""
     with get_connection() as conn:
@@ -420,6 +465,65 @@ def update_recording_title(recording_id: int, title: str) -> bool:
         return cursor.rowcount > 0
 
 
+def update_recording_keep_audio(recording_id: int, keep: bool) -> bool:
+    """Set a recording's "keep audio forever" retention override."""
+    with get_connection() as conn:
+        cursor = conn.cursor()
+        cursor.execute(
+            "UPDATE recordings SET keep_audio = ? WHERE id = ?",
+            (int(keep), recording_id),
+        )
+        conn.commit()
+        return cursor.rowcount > 0
+
+
+def get_recordings_for_audio_retention(
+    max_age_days: int, after_id: int = 0, limit: int = 100
+) -> list[dict]:
+    """Recordings whose original audio the retention policy may retire.
+
+    Imported more than ``max_age_days`` ago, not marked "keep forever", not
+    a partial transcript waiting to be resumed from that audio, not a
+    golden-set reference (re-scored against its audio), and not retired
+    already. ``imported_at`` is SQLite ``CURRENT_TIMESTAMP``
+    (UTC, space separator), so the cutoff is computed by SQLite as well.
+    Pages by id: pass the last id of the previous page as ``after_id``, so
+    rows that could not be retired do not come back in the same run.
+    """
+    with get_connection() as conn:
+        cursor = conn.execute(
+            """
+            SELECT * FROM recordings
+            WHERE keep_audio = 0
+              AND audio_retired_at IS NULL
+              AND partial_until IS NULL
+              AND id NOT IN (SELECT recording_id FROM golden_references)
+              AND imported_at < datetime('now', ?)
+              AND id > ?
+            ORDER BY id ASC
+            LIMIT ?
+            """,
+            (f"-{int(max_age_days)} days", int(after_id), limit),
+        )
+        return [dict(row) for row in cursor.fetchall()]
+
+
+def mark_recording_audio_retired(recording_id: int, filepath: str | None = None) -> bool:
+    """Record that the audio was deleted, or archived to ``filepath``."""
+    with get_connection() as conn:
+        cursor = conn.cursor()
+        cursor.execute(
+            """
+            UPDATE recordings
+            SET audio_retired_at = ?, filepath = COALESCE(?, filepath)
+            WHERE id = ?
+            """,
+            (datetime.now().isoformat(), filepath, recording_id),
+        )
+        conn.commit()
+        return cursor.rowcount > 0
+
+
 # =============================================================================
 # Segment and Word operations
 # =============================================================================
@@ -507,6 +611,335 @@ def update_recording_word_count(recording_id: int) -> None:
         conn.commit()
 
 
+def replace_segments(recording_id: int, segments: list[dict[str, Any]]) -> int:
+    """Atomically swap a recording's segments for ``segments``.
+
+    Each entry carries ``text``, ``start_time``, ``end_time``, ``speaker`` and
+    ``word_ids`` — the existing words to re-parent (in order) onto the new
+    segment. Words are moved BEFORE the old segments are deleted so the
+    ``ON DELETE CASCADE`` on ``words.segment_id`` never fires for them.
+    Returns the number of segments written.
+    """
+    with get_connection() as conn:
+        cursor = conn.cursor()
+        old_ids = [
+            row[0]
+            for row in cursor.execute(
+                "SELECT id FROM segments WHERE recording_id = ?", (recording_id,)
+            ).fetchall()
+        ]
+        for index, seg in enumerate(segments):
+            cursor.execute(
+                """
+                INSERT INTO segments (recording_id, segment_index, speaker, text, start_time, end_time)
+                VALUES (?, ?, ?, ?, ?, ?)
+                """,
+                (
+                    recording_id,
+                    index,
+                    seg.get("speaker"),
+                    seg["text"],
+                    seg["start_time"],
+                    seg["end_time"],
+                ),
+            )
+            new_id = cursor.lastrowid
+            cursor.executemany(
+                "UPDATE words SET segment_id = ?, word_index = ? WHERE id = ? AND recording_id = ?",
+                [(new_id, i, wid, recording_id) for i, wid in enumerate(seg.get("word_ids", []))],
+            )
+        if old_ids:
+            placeholders = ",".join("?" * len(old_ids))
+            cursor.execute(f"DELETE FROM segments WHERE id IN ({placeholders})", old_ids)
+        conn.commit()
+        return len(segments)
+
+
+def rewrite_segment_words(recording_id: int, segments: list[dict[str, Any]]) -> int:
+    """Apply a forced re-alignment: replace the recording's word timings.
+
+    ``segments`` are ``plan_realignment`` entries (``id`` and ``words``) and
+    must cover every segment of the recording; a plan that would leave one
+    out raises ValueError before anything changes. Only the words are
+    rewritten — segment text (the original ASR output), bounds and the
+    corrected-transcript overlay are kept. Returns the number of words
+    written.
+    """
+    with get_connection() as conn:
+        cursor = conn.cursor()
+        cursor.execute("SELECT id FROM segments WHERE recording_id = ?", (recording_id,))
+        missing = {row[0] for row in cursor.fetchall()} - {seg["id"] for seg in segments}
+        if missing:
+            raise ValueError(f"The alignment would drop {len(missing)} segment(s)")
+        cursor.execute("DELETE FROM words WHERE recording_id = ?", (recording_id,))
+        written = 0
+        for seg in segments:
+            cursor.executemany(
+                """
+                INSERT INTO words (recording_id, segment_id, word_index, word, start_time, end_time, confidence)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                """,
+                [
+                    (
+                        recording_id,
+                        seg["id"],
+                        i,
+                        w["word"],
+                        w["start"],
+                        w["end"],
+                        w.get("confidence"),
+                    )
+                    for i, w in enumerate(seg["words"])
+                ],
+            )
+            written += len(seg["words"])
+        cursor.execute(
+            "UPDATE recordings SET word_count = ? WHERE id = ?", (written, recording_id)
+        )
+        conn.commit()
+        return written
+
+
+def replace_segment_words(
+    recording_id: int, segment_id: int, text: str, words: list[dict[str, Any]]
+) -> bool:
+    """Swap one segment's text and words (per-segment re-transcription).
+
+    ``words`` carry ``word``/``start``/``end`` and an optional score. Other
+    segments, the speaker and the segment bounds are untouched; the
+    recording's word count is recomputed. Returns False when the segment
+    does not belong to the recording.
+    """
+    with get_connection() as conn:
+        cursor = conn.cursor()
+        cursor.execute(
+            "UPDATE segments SET text = ? WHERE id = ? AND recording_id = ?",
+            (text, segment_id, recording_id),
+        )
+        if cursor.rowcount == 0:
+            return False
+        cursor.execute(
+            "DELETE FROM words WHERE recording_id = ? AND segment_id = ?",
+            (recording_id, segment_id),
+        )
+        cursor.executemany(
+            """
+            INSERT INTO words (recording_id, segment_id, word_index, word, start_time, end_time, confidence)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            """,
+            [
+                (recording_id, segment_id, i, w["word"], w["start"], w["end"], _word_confidence(w))
+                for i, w in enumerate(words)
+            ],
+        )
+        cursor.execute(
+            """
+            UPDATE recordings
+            SET word_count = (SELECT COUNT(*) FROM words WHERE recording_id = ?)
+            WHERE id = ?
+            """,
+            (recording_id, recording_id),
+        )
+        conn.commit()
+        return True
+
+
+def set_recording_partial(
+    recording_id: int, partial_until: float | None, reason: str | None = None
+) -> bool:
+    """Mark a transcript as covering only the first ``partial_until`` seconds.
+
+    ``None`` marks it complete again.
+    """
+    with get_connection() as conn:
+        cursor = conn.cursor()
+        cursor.execute(
+            "UPDATE recordings SET partial_until = ?, partial_reason = ? WHERE id = ?",
+            (partial_until, reason if partial_until is not None else None, recording_id),
+        )
+        conn.commit()
+        return cursor.rowcount > 0
+
+
+def append_resumed_segments(
+    recording_id: int,
+    segments: list[dict[str, Any]],
+    *,
+    partial_until: float | None,
+    partial_reason: str | None = None,
+) -> int:
+    """Append the segments a resumed transcription produced (``core/partial_resume.py``).
+
+    ``segments`` carry ``text``/``start``/``end`` and ``words`` on the
+    recording timeline; they go after the existing ones without a speaker.
+    The word count and the partial marker are updated in the same
+    transaction. Returns the number of words written.
+    """
+    with get_connection() as conn:
+        cursor = conn.cursor()
+        cursor.execute(
+            "SELECT COALESCE(MAX(segment_index), -1) FROM segments WHERE recording_id = ?",
+            (recording_id,),
+        )
+        next_index = int(cursor.fetchone()[0]) + 1
+        written = 0
+        for offset, seg in enumerate(segments):
+            cursor.execute(
+                """
+                INSERT INTO segments
+                (recording_id, segment_index, text, start_time, end_time, speaker)
+                VALUES (?, ?, ?, ?, ?, NULL)
+                """,
+                (recording_id, next_index + offset, seg["text"], seg["start"], seg["end"]),
+            )
+            segment_id = cursor.lastrowid
+            cursor.executemany(
+                """
+                INSERT INTO words (recording_id, segment_id, word_index, word, start_time, end_time, confidence)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                """,
+                [
+                    (
+                        recording_id,
+                        segment_id,
+                        i,
+                        w["word"],
+                        w["start"],
+                        w["end"],
+                        _word_confidence(w),
+                    )
+                    for i, w in enumerate(seg["words"])
+                ],
+            )
+            written += len(seg["words"])
+        cursor.execute(
+            """
+            UPDATE recordings
+            SET word_count = (SELECT COUNT(*) FROM words WHERE recording_id = ?),
+                partial_until = ?,
+                partial_reason = ?
+            WHERE id = ?
+            """,
+            (
+                recording_id,
+                partial_until,
+                partial_reason if partial_until is not None else None,
+                recording_id,
+            ),
+        )
+        conn.commit()
+        return written
+
+
+def split_segment(
+    recording_id: int,
+    segment_id: int,
+    boundary: float,
+    left_text: str,
+    right_text: str,
+    right_word_ids: list[int],
+) -> int | None:
+    """Cut a segment at ``boundary`` (``core/segment_editing.plan_split``).
+
+    The segment keeps its id and ends at ``boundary``; a new segment with
+    the same speaker starts there and receives ``right_word_ids``. Later
+    segments shift down one index. Both pieces lose their review state.
+    Returns the new segment's id, or None when the segment is unknown.
+    """
+    with get_connection() as conn:
+        cursor = conn.cursor()
+        row = cursor.execute(
+            "SELECT * FROM segments WHERE id = ? AND recording_id = ?",
+            (segment_id, recording_id),
+        ).fetchone()
+        if row is None:
+            return None
+        cursor.execute(
+            "UPDATE segments SET segment_index = segment_index + 1 "
+            "WHERE recording_id = ? AND segment_index > ?",
+            (recording_id, row["segment_index"]),
+        )
+        cursor.execute(
+            """
+            UPDATE segments SET text = ?, end_time = ?, review_state = NULL, reviewed_at = NULL
+            WHERE id = ?
+            """,
+            (left_text, boundary, segment_id),
+        )
+        cursor.execute(
+            """
+            INSERT INTO segments (recording_id, segment_index, speaker, text, start_time, end_time)
+            VALUES (?, ?, ?, ?, ?, ?)
+            """,
+            (
+                recording_id,
+                row["segment_index"] + 1,
+                row["speaker"],
+                right_text,
+                boundary,
+                row["end_time"],
+            ),
+        )
+        new_id = cursor.lastrowid
+        cursor.executemany(
+            "UPDATE words SET segment_id = ?, word_index = ? WHERE id = ? AND recording_id = ?",
+            [(new_id, i, wid, recording_id) for i, wid in enumerate(right_word_ids)],
+        )
+        conn.commit()
+        return new_id
+
+
+def merge_segments(recording_id: int, merged: dict[str, Any]) -> bool:
+    """Fold adjacent segments into one (``core/segment_editing.plan_merge``).
+
+    Words of the dropped segments are re-parented onto ``keep_id`` before
+    those segments are deleted, then every segment is re-indexed so
+    ``segment_index`` stays dense. Returns False when ``keep_id`` is unknown.
+    """
+    with get_connection() as conn:
+        cursor = conn.cursor()
+        cursor.execute(
+            """
+            UPDATE segments
+            SET text = ?, start_time = ?, end_time = ?, review_state = NULL, reviewed_at = NULL
+            WHERE id = ? AND recording_id = ?
+            """,
+            (
+                merged["text"],
+                merged["start_time"],
+                merged["end_time"],
+                merged["keep_id"],
+                recording_id,
+            ),
+        )
+        if cursor.rowcount == 0:
+            return False
+        cursor.executemany(
+            "UPDATE words SET segment_id = ?, word_index = ? WHERE id = ? AND recording_id = ?",
+            [
+                (merged["keep_id"], i, wid, recording_id)
+                for i, wid in enumerate(merged["word_ids"])
+            ],
+        )
+        drop = list(merged["drop_ids"])
+        if drop:
+            placeholders = ",".join("?" * len(drop))
+            cursor.execute(
+                f"DELETE FROM segments WHERE recording_id = ? AND id IN ({placeholders})",
+                (recording_id, *drop),
+            )
+        ordered = cursor.execute(
+            "SELECT id FROM segments WHERE recording_id = ? ORDER BY segment_index",
+            (recording_id,),
+        ).fetchall()
+        cursor.executemany(
+            "UPDATE segments SET segment_index = ? WHERE id = ?",
+            [(i, r[0]) for i, r in enumerate(ordered)],
+        )
+        conn.commit()
+        return True
+
+
 def get_segments(recording_id: int) -> list[dict[str, Any]]:
     """Get all segments for a recording."""
     with get_connection() as conn:
@@ -536,6 +969,32 @@ def iter_segments(recording_id: int) -> Iterator[dict[str, Any]]:
             yield dict(row)
 
 
+def set_segment_review_state(
+    recording_id: int, segment_ids: list[int], state: str | None
+) -> int:
+    """Set (or clear with ``None``) the QA review state of segments.
+
+    Only segments belonging to ``recording_id`` are touched. Returns the
+    number of segments updated.
+    """
+    if not segment_ids:
+        return 0
+    placeholders = ",".join("?" * len(segment_ids))
+    with get_connection() as conn:
+        cursor = conn.cursor()
+        cursor.execute(
+            f"""
+            UPDATE segments
+            SET review_state = ?,
+                reviewed_at = CASE WHEN ? IS NULL THEN NULL ELSE CURRENT_TIMESTAMP END
+            WHERE recording_id = ? AND id IN ({placeholders})
+            """,
+            (state, state, recording_id, *segment_ids),
+        )
+        conn.commit()
+        return cursor.rowcount
+
+
 def get_words(recording_id: int) -> list[dict[str, Any]]:
     """Get all words for a recording."""
     with get_connection() as conn:
@@ -1429,6 +1888,22 @@ def convert_audio_to_mp3(
         return None
 
 
+def _word_confidence(word: dict[str, Any]) -> float | None:
+    """Word score as stored in ``words.confidence``.
+
+    STT backends report it as ``probability``; aligned/imported words already
+    use ``confidence``; a null ``confidence`` falls back to ``probability``.
+    Missing or non-numeric values stay NULL.
+    """
+    value = word.get("confidence")
+    if value is None:
+        value = word.get("probability")
+    try:
+        return None if value is None else max(0.0, min(1.0, float(value)))
+    except (TypeError, ValueError):
+        return None
+
+
 def _insert_single_segment_with_words(
     cursor: sqlite3.Cursor,
     recording_id: int,
@@ -1462,7 +1937,7 @@ def _insert_single_segment_with_words(
                 "word": w.get("word", ""),
                 "start_time": w.get("start", 0.0),
                 "end_time": w.get("end", 0.0),
-                "confidence": w.get("confidence"),
+                "confidence": _word_confidence(w),
             }
             for i, w in enumerate(word_timestamps)
         ]
@@ -1609,7 +2084,7 @@ def _insert_diarization_segments_with_words(
                             )
                             or 0.0
                         ),
-                        "confidence": w.get("confidence"),
+                        "confidence": _word_confidence(w),
                     }
                     for i, w in enumerate(segment_words_sorted)
                 ]
@@ -1635,6 +2110,7 @@ def save_longform_to_database(
     transcription_backend: str | None = None,
     audio_hash: str | None = None,
     normalized_audio_hash: str | None = None,
+    owner: str | None = None,
 ) -> int | None:
     """
     Save a longform recording to the database atomically.
@@ -1662,6 +2138,8 @@ def save_longform_to_database(
             content across format re-encodes. NULL when ffmpeg normalization
             failed for this upload — the row still participates in raw-hash
             dedup, just not in format-agnostic dedup.
+        owner: Optional user name of the creating client (migration 025).
+            None leaves the recording shared between all users.
 
     Returns:
         Recording ID on success, None on error
@@ -1678,7 +2156,7 @@ def save_longform_to_database(
         recorded_at = recorded_at or datetime.now()
         has_diarization = bool(diarization_segments and len(diarization_segments) > 0)
 
-        conn = sqlite3.connect(
+        conn = _connect(
             db_path,
             timeout=30.0,
             check_same_thread=False,
@@ -1704,9 +2182,10 @@ def save_longform_to_database(
                     has_diarization,
                     transcription_backend,
                     audio_hash,
-                    normalized_audio_hash
+                    normalized_audio_hash,
+                    owner
                 )
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 """,
                 (
                     audio_path.name,
@@ -1718,6 +2197,7 @@ def save_longform_to_database(
                     transcription_backend,
                     audio_hash,
                     normalized_audio_hash,
+                    owner,
                 ),
             )
             recording_id: int = cursor.lastrowid or 0
@@ -1767,6 +2247,12 @@ def save_longform_to_database(
             # Commit entire transaction atomically
             conn.commit()
             logger.info(f"Recording saved to database with ID: {recording_id}")
+
+            # Every notebook recording lands here, so this is the one place
+            # stored audio gets encrypted (a no-op unless enabled).
+            from server.core.storage_encryption import encrypt_at_rest
+
+            encrypt_at_rest(audio_path)
             return recording_id
 
         except Exception:
@@ -1822,7 +2308,7 @@ def find_recordings_by_audio_hash(
     where_clause = " OR ".join(where_parts)
     params.append(limit)
 
-    conn = sqlite3.connect(db_path, timeout=30.0, check_same_thread=False)
+    conn = _connect(db_path, timeout=30.0, check_same_thread=False)
     try:
         conn.row_factory = sqlite3.Row
         cursor = conn.execute(
@@ -19,7 +19,9 @@ from __future__ import annotations
 
 from typing import Any, Literal
 
+from server.core.audio_fingerprint import align, fingerprint_seconds, unpack_fingerprint
 from server.database.database import find_recordings_by_audio_hash
+from server.database.fingerprint_repository import iter_fingerprinted_recordings
 from server.database.job_repository import find_by_audio_hash
 
 DedupSource = Literal["transcription_job", "recording"]
@@ -91,6 +93,54 @@ def find_duplicates_anywhere(
     return merged[:limit]
 
 
+def find_fingerprint_matches(
+    fingerprint: list[int],
+    limit: int = 10,
+    exclude_ids: set[str] | None = None,
+) -> list[dict[str, Any]]:
+    """Return recordings whose chromaprint fingerprint lines up with ``fingerprint``.
+
+    Catches what the hashes cannot: a re-encode at another bitrate, or a
+    copy trimmed at either end. Same shape as :func:`find_duplicates_anywhere`
+    results (always ``source="recording"``) plus where the new audio sits in
+    the match::
+
+        {
+            ...,
+            "offset_seconds": float,   # match time = new-file time + offset
+            "duration_seconds": float, # length of the new audio
+            "similarity": float,       # 0..1, fraction of matching bits
+        }
+
+    Best similarity first. ``exclude_ids`` drops recordings the caller has
+    already reported (e.g. exact hash matches). Compares against every
+    fingerprinted recording, so call it off the event loop.
+    """
+    if not fingerprint:
+        return []
+    matches: list[dict[str, Any]] = []
+    for row in iter_fingerprinted_recordings():
+        if exclude_ids and str(row["id"]) in exclude_ids:
+            continue
+        found = align(fingerprint, unpack_fingerprint(row.pop("fingerprint")))
+        if found is None:
+            continue
+        matches.append(
+            {
+                "source": "recording",
+                "id": str(row["id"]),
+                "name": _recording_display_name(row),
+                "created_at": row.get("imported_at") or row.get("recorded_at") or "",
+                "raw": row,
+                "offset_seconds": found.offset_seconds,
+                "duration_seconds": round(fingerprint_seconds(fingerprint), 3),
+                "similarity": found.similarity,
+            }
+        )
+    matches.sort(key=lambda m: m["similarity"], reverse=True)
+    return matches[:limit]
+
+
 def _job_display_name(row: dict[str, Any]) -> str:
     """Best-effort display name for a transcription_jobs row.
 
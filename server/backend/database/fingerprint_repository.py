@@ -0,0 +1,61 @@
+"""Fingerprint repository — chromaprint fingerprints of notebook recordings.
+
+Storage over the ``recording_fingerprints`` table created by migration 026.
+Fingerprinting and alignment live in ``server/backend/core/audio_fingerprint.py``;
+the cross-recording search is ``find_fingerprint_matches`` in
+``server/backend/database/dedup_query.py``.
+
+All writes commit before returning (NFR16).
+"""
+
+from __future__ import annotations
+
+import logging
+import sqlite3
+from collections.abc import Iterator
+from datetime import UTC, datetime
+from typing import Any
+
+from server.database.database import get_connection
+
+logger = logging.getLogger(__name__)
+
+
+def save_fingerprint(recording_id: int, fingerprint: bytes, duration_seconds: float) -> None:
+    """Store (or replace) the recording's packed fingerprint."""
+    with get_connection() as conn:
+        conn.execute(
+            """
+            INSERT OR REPLACE INTO recording_fingerprints
+                (recording_id, fingerprint, duration_seconds, created_at)
+            VALUES (?, ?, ?, ?)
+            """,
+            (recording_id, fingerprint, duration_seconds, datetime.now(UTC).isoformat()),
+        )
+        conn.commit()
+
+
+def iter_fingerprinted_recordings() -> Iterator[dict[str, Any]]:
+    """
+    Every fingerprinted recording with the columns a dedup match needs,
+    streamed row by row (fingerprints of long recordings are large).
+    Empty when the table is missing.
+    """
+    try:
+        with get_connection() as conn:
+            cursor = conn.execute(
+                """
+                SELECT r.id, r.filename, r.title, r.imported_at, r.recorded_at,
+                       f.fingerprint, f.duration_seconds
+                FROM recording_fingerprints f
+                JOIN recordings r ON r.id = f.recording_id
+                ORDER BY r.id
+                """
+            )
+            for row in cursor:
+                yield dict(row)
+    except sqlite3.OperationalError as exc:
+        if "no such table" in str(exc).lower():
+            logger.debug("recording_fingerprints table missing — nothing to match")
+            return
+        raise
@@ -0,0 +1,121 @@
+"""Golden-set repository — reference transcripts and their accuracy runs.
+
+CRUD over the ``golden_references`` and ``golden_runs`` tables created by
+migration 030. Scoring and drift live in ``server/backend/core/golden_set.py``;
+this module provides only the data primitives.
+
+All writes commit before returning (NFR16).
+"""
+
+from __future__ import annotations
+
+import logging
+import sqlite3
+from datetime import UTC, datetime
+from typing import Any
+
+from server.database.database import get_connection
+
+logger = logging.getLogger(__name__)
+
+
+def list_references() -> list[dict[str, Any]]:
+    """Marked recordings with their title and audio path, oldest mark first."""
+    try:
+        with get_connection() as conn:
+            rows = conn.execute(
+                """
+                SELECT g.recording_id, g.reference, g.marked_at,
+                       r.title, r.filename, r.filepath
+                FROM golden_references g
+                JOIN recordings r ON r.id = g.recording_id
+                ORDER BY g.marked_at, g.recording_id
+                """
+            ).fetchall()
+    except sqlite3.OperationalError as exc:
+        if "no such table" in str(exc).lower():
+            logger.debug("golden_references table missing — returning empty list")
+            return []
+        raise
+    return [dict(row) for row in rows]
+
+
+def is_reference(recording_id: int) -> bool:
+    with get_connection() as conn:
+        row = conn.execute(
+            "SELECT 1 FROM golden_references WHERE recording_id = ?", (recording_id,)
+        ).fetchone()
+    return row is not None
+
+
+def mark_reference(recording_id: int, reference: str) -> None:
+    """Mark (or re-mark, replacing the reference text) a recording as reference.
+
+    Runs from an earlier reference text are dropped: they were scored
+    against different words and would show as false drift.
+    """
+    if not reference.strip():
+        raise ValueError("the reference transcript is empty")
+    now = datetime.now(UTC).isoformat()
+    with get_connection() as conn:
+        conn.execute("DELETE FROM golden_runs WHERE recording_id = ?", (recording_id,))
+        conn.execute(
+            """
+            INSERT OR REPLACE INTO golden_references (recording_id, reference, marked_at)
+            VALUES (?, ?, ?)
+            """,
+            (recording_id, reference, now),
+        )
+        conn.commit()
+
+
+def unmark_reference(recording_id: int) -> bool:
+    """Remove the mark and its run history; False when it was not marked."""
+    with get_connection() as conn:
+        conn.execute("DELETE FROM golden_runs WHERE recording_id = ?", (recording_id,))
+        cursor = conn.execute(
+            "DELETE FROM golden_references WHERE recording_id = ?", (recording_id,)
+        )
+        conn.commit()
+        return cursor.rowcount > 0
+
+
+def add_run(
+    recording_id: int,
+    *,
+    model: str,
+    server_version: str,
+    wer: float | None,
+    error: str | None = None,
+) -> int:
+    """Record one re-transcription; returns the new row id."""
+    now = datetime.now(UTC).isoformat()
+    with get_connection() as conn:
+        cursor = conn.execute(
+            """
+            INSERT INTO golden_runs (recording_id, run_at, model, server_version, wer, error)
+            VALUES (?, ?, ?, ?, ?, ?)
+            """,
+            (recording_id, now, model, server_version, wer, error),
+        )
+        conn.commit()
+        return int(cursor.lastrowid)
+
+
+def list_runs() -> list[dict[str, Any]]:
+    """Every run, oldest first; empty when the table is missing."""
+    try:
+        with get_connection() as conn:
+            rows = conn.execute(
+                """
+                SELECT id, recording_id, run_at, model, server_version, wer, error
+                FROM golden_runs
+                ORDER BY run_at, id
+                """
+            ).fetchall()
+    except sqlite3.OperationalError as exc:
+        if "no such table" in str(exc).lower():
+            logger.debug("golden_runs table missing — returning empty list")
+            return []
+        raise
+    return [dict(row) for row in rows]
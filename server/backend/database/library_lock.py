@@ -0,0 +1,233 @@
+"""Single-writer ownership of the notebook library.
+
+A library kept on a synced drive (Dropbox, Syncthing, a NAS share) can be
+opened by two machines at once. SQLite's own locks do not travel through a
+sync client, so two writers silently fork the database and the sync tool
+later picks one copy — or corrupts both.
+
+The server that owns the library keeps an owner file next to the database
+(``notebook.db.owner``: machine, pid, heartbeat) and refreshes it every
+``heartbeat_seconds``. The file syncs like everything else, so another
+machine starting against the same library sees a fresh heartbeat from a
+different owner and opens it **read-only**: every SQLite connection is
+opened with ``mode=ro`` and the API answers 423 to anything that would
+write. A heartbeat older than ``stale_after_seconds`` — the owner crashed
+or the other machine is off — is not honoured.
+
+``take_ownership`` is the recovery path: it rewrites the owner file and
+switches this server back to read-write. The previous owner notices on its
+next heartbeat that the file names someone else and drops to read-only
+itself, so the two never write at the same time for longer than one
+heartbeat (plus sync latency).
+"""
+
+from __future__ import annotations
+
+import asyncio
+import json
+import logging
+import os
+import socket
+import time
+import uuid
+from dataclasses import asdict, dataclass, replace
+from pathlib import Path
+from typing import Any
+
+logger = logging.getLogger(__name__)
+
+OWNER_SUFFIX = ".owner"
+DEFAULT_STALE_AFTER_SECONDS = 300
+DEFAULT_HEARTBEAT_SECONDS = 60
+
+
+@dataclass(frozen=True, slots=True)
+class LibraryOwner:
+    owner_id: str
+    machine: str
+    pid: int
+    heartbeat_at: float
+
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> LibraryOwner:
+        return cls(
+            owner_id=str(data["owner_id"]),
+            machine=str(data.get("machine") or ""),
+            pid=int(data.get("pid") or 0),
+            heartbeat_at=float(data.get("heartbeat_at") or 0.0),
+        )
+
+
+@dataclass(slots=True)
+class _LockState:
+    owner_path: Path | None = None
+    me: LibraryOwner | None = None
+    read_only: bool = False
+    holder: LibraryOwner | None = None
+    stale_after: float = DEFAULT_STALE_AFTER_SECONDS
+
+
+_state = _LockState()
+
+
+def owner_path_for(db_path: Path) -> Path:
+    return db_path.with_name(db_path.name + OWNER_SUFFIX)
+
+
+def read_owner(path: Path) -> LibraryOwner | None:
+    """The recorded owner, or ``None`` when the file is missing or unreadable."""
+    try:
+        return LibraryOwner.from_dict(json.loads(path.read_text(encoding="utf-8")))
+    except FileNotFoundError:
+        return None
+    except (OSError, ValueError, KeyError, TypeError) as e:
+        # A half-synced or conflicted copy; treat it as unowned rather than lock forever.
+        logger.warning("Ignoring unreadable library owner file %s: %s", path, e)
+        return None
+
+
+def _write_owner(path: Path, owner: LibraryOwner) -> None:
+    tmp = path.with_name(path.name + ".tmp")
+    tmp.write_text(json.dumps(asdict(owner)), encoding="utf-8")
+    os.replace(tmp, path)
+
+
+def _pid_alive(pid: int) -> bool:
+    if pid <= 0:
+        return False
+    try:
+        os.kill(pid, 0)
+    except ProcessLookupError:
+        return False
+    except PermissionError:
+        return True
+    except OSError:
+        return False
+    return True
+
+
+def held_by_other(
+    holder: LibraryOwner | None, me: LibraryOwner, now: float, stale_after: float
+) -> bool:
+    """Whether ``holder`` still owns the library from ``me``'s point of view."""
+    if holder is None or holder.owner_id == me.owner_id:
+        return False
+    if now - holder.heartbeat_at > stale_after:
+        return False
+    if holder.machine == me.machine:
+        # Same machine: only a live *other* process counts. Our own pid means a
+        # previous run of this server (containers restart as pid 1) that crashed.
+        return holder.pid != me.pid and _pid_alive(holder.pid)
+    return True
+
+
+def acquire(
+    db_path: Path,
+    *,
+    machine: str | None = None,
+    stale_after: float = DEFAULT_STALE_AFTER_SECONDS,
+) -> bool:
+    """Claim the library at ``db_path``; returns ``False`` (read-only) if it is held."""
+    me = LibraryOwner(
+        owner_id=uuid.uuid4().hex,
+        machine=machine or socket.gethostname(),
+        pid=os.getpid(),
+        heartbeat_at=time.time(),
+    )
+    path = owner_path_for(db_path)
+    holder = read_owner(path)
+    _state.owner_path, _state.me, _state.stale_after = path, me, stale_after
+    if held_by_other(holder, me, me.heartbeat_at, stale_after):
+        assert holder is not None
+        _state.read_only, _state.holder = True, holder
+        logger.warning(
+            "Library is in use on %s (pid %d, last seen %.0fs ago) — opening read-only",
+            holder.machine,
+            holder.pid,
+            me.heartbeat_at - holder.heartbeat_at,
+        )
+        return False
+    _write_owner(path, me)
+    _state.read_only, _state.holder = False, None
+    return True
+
+
+def heartbeat() -> None:
+    """Refresh our claim, or step down if another server has taken ownership."""
+    if _state.owner_path is None or _state.me is None:
+        return
+    holder = read_owner(_state.owner_path)
+    if _state.read_only:
+        # Keep "last seen" current for the dashboard; recovery stays explicit.
+        _state.holder = holder or _state.holder
+        return
+    if holder is not None and holder.owner_id != _state.me.owner_id:
+        _state.read_only, _state.holder = True, holder
+        logger.warning(
+            "Library ownership was taken by %s (pid %d) — switching to read-only",
+            holder.machine,
+            holder.pid,
+        )
+        return
+    _state.me = replace(_state.me, heartbeat_at=time.time())
+    _write_owner(_state.owner_path, _state.me)
+
+
+def take_ownership() -> None:
+    """Force this server to own the library (after the other machine is gone)."""
+    if _state.owner_path is None or _state.me is None:
+        raise RuntimeError("Library lock was never acquired")
+    previous = _state.holder
+    _state.me = replace(_state.me, heartbeat_at=time.time())
+    _write_owner(_state.owner_path, _state.me)
+    _state.read_only, _state.holder = False, None
+    logger.warning(
+        "Took ownership of the library%s",
+        f" from {previous.machine} (pid {previous.pid})" if previous else "",
+    )
+
+
+def release() -> None:
+    """Drop our owner file on clean shutdown so the next machine starts read-write."""
+    if _state.read_only or _state.owner_path is None or _state.me is None:
+        return
+    holder = read_owner(_state.owner_path)
+    if holder is not None and holder.owner_id == _state.me.owner_id:
+        try:
+            _state.owner_path.unlink()
+        except OSError as e:
+            logger.warning("Could not remove library owner file: %s", e)
+
+
+def is_read_only() -> bool:
+    return _state.read_only
+
+
+def status() -> dict[str, Any]:
+    holder = _state.holder
+    return {
+        "read_only": _state.read_only,
+        "machine": _state.me.machine if _state.me else None,
+        "holder": (
+            {
+                "machine": holder.machine,
+                "pid": holder.pid,
+                "last_seen_seconds": round(max(0.0, time.time() - holder.heartbeat_at)),
+            }
+            if holder
+            else None
+        ),
+    }
+
+
+async def periodic_heartbeat(interval_seconds: float = DEFAULT_HEARTBEAT_SECONDS) -> None:
+    """Keep the owner file fresh until cancelled on shutdown."""
+    while True:
+        try:
+            await asyncio.sleep(interval_seconds)
+        except asyncio.CancelledError:
+            return
+        try:
+            await asyncio.to_thread(heartbeat)
+        except OSError:
+            logger.exception("Library heartbeat failed — will retry next interval")
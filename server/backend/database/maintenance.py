@@ -0,0 +1,273 @@
+"""Integrity checks and repairs for the notebook database.
+
+``run_maintenance`` walks a fixed list of steps and yields an event per
+step and per problem found, so the route can stream progress to the
+dashboard and the periodic task can log a summary:
+
+- ``integrity`` — ``PRAGMA integrity_check``. Page-level corruption cannot
+  be repaired in place, and repairing on top of it can delete rows that are
+  only unreadable: when this step finds anything, the rest of the run is
+  report-only and the fix is to restore a backup.
+- ``foreign_keys`` — ``PRAGMA foreign_key_check``. Rows whose parent is gone
+  are deleted when their foreign key is ``ON DELETE CASCADE`` (exactly what
+  SQLite would have done had the parent been deleted with FKs enabled);
+  other orphans are only reported.
+- ``consistency`` — words filed under a different recording than their
+  segment, words/segments that end before they start, and stale
+  ``recordings.word_count`` values.
+- ``fts`` — the ``words_fts`` search index is checked and rebuilt from the
+  ``words`` table.
+- ``vacuum`` — ``VACUUM`` plus a WAL checkpoint to return free pages to the
+  disk; skipped with ``vacuum=False`` since it rewrites the whole file.
+
+With ``repair=False`` nothing is written: every problem is reported with
+``repaired: False``. A repairing run first takes a backup through
+``DatabaseBackupManager`` and falls back to report-only if it can't. Only
+one run happens at a time.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+import sqlite3
+import threading
+import time
+from collections.abc import Iterator
+from pathlib import Path
+from typing import Any
+
+from server.database.backup import DEFAULT_MAX_BACKUPS, DatabaseBackupManager
+from server.database.database import get_connection, get_db_path
+
+logger = logging.getLogger(__name__)
+
+MAINTENANCE_STEPS: tuple[str, ...] = ("integrity", "foreign_keys", "consistency", "fts", "vacuum")
+_MAX_REPORTED_INTEGRITY_ERRORS = 20
+
+_run_lock = threading.Lock()
+_last_report: dict[str, Any] | None = None
+
+
+class MaintenanceBusy(RuntimeError):
+    """Another maintenance run is in progress."""
+
+
+def last_report() -> dict[str, Any] | None:
+    return _last_report
+
+
+def is_running() -> bool:
+    return _run_lock.locked()
+
+
+def _issue(step: str, message: str, count: int = 1, repaired: bool = False) -> dict[str, Any]:
+    return {
+        "type": "issue",
+        "step": step,
+        "message": message,
+        "count": count,
+        "repaired": repaired,
+    }
+
+
+def _check_integrity(conn: sqlite3.Connection, repair: bool) -> Iterator[dict[str, Any]]:
+    rows = [r[0] for r in conn.execute("PRAGMA integrity_check").fetchall()]
+    if rows != ["ok"]:
+        for message in rows[:_MAX_REPORTED_INTEGRITY_ERRORS]:
+            yield _issue("integrity", message)
+        hidden = len(rows) - _MAX_REPORTED_INTEGRITY_ERRORS
+        if hidden > 0:
+            yield _issue("integrity", f"{hidden} more integrity error(s)", count=hidden)
+
+
+def _check_foreign_keys(conn: sqlite3.Connection, repair: bool) -> Iterator[dict[str, Any]]:
+    violations = conn.execute("PRAGMA foreign_key_check").fetchall()
+    by_key: dict[tuple[str, str, int], list[int]] = {}
+    for table, rowid, parent, fkid in violations:
+        by_key.setdefault((table, parent, fkid), []).append(rowid)
+    for (table, parent, fkid), rowids in sorted(by_key.items()):
+        on_delete = next(
+            (fk[6] for fk in conn.execute(f'PRAGMA foreign_key_list("{table}")') if fk[0] == fkid),
+            "",
+        )
+        cascade = str(on_delete).upper() == "CASCADE"
+        if repair and cascade:
+            conn.executemany(f'DELETE FROM "{table}" WHERE rowid = ?', [(r,) for r in rowids])
+        yield _issue(
+            "foreign_keys",
+            f"{len(rowids)} {table} row(s) point at missing {parent}"
+            + ("" if cascade else " (not removed: no ON DELETE CASCADE)"),
+            count=len(rowids),
+            repaired=repair and cascade,
+        )
+
+
+def _check_consistency(conn: sqlite3.Connection, repair: bool) -> Iterator[dict[str, Any]]:
+    checks = [
+        (
+            "word(s) filed under a different recording than their segment",
+            """SELECT COUNT(*) FROM words w JOIN segments s ON s.id = w.segment_id
+               WHERE w.recording_id != s.recording_id""",
+            """UPDATE words SET recording_id =
+                   (SELECT s.recording_id FROM segments s WHERE s.id = words.segment_id)
+               WHERE recording_id != (SELECT s.recording_id FROM segments s
+                                      WHERE s.id = words.segment_id)""",
+        ),
+        (
+            "segment(s) ending before they start",
+            "SELECT COUNT(*) FROM segments WHERE end_time < start_time",
+            "UPDATE segments SET end_time = start_time WHERE end_time < start_time",
+        ),
+        (
+            "word(s) ending before they start",
+            "SELECT COUNT(*) FROM words WHERE end_time < start_time",
+            "UPDATE words SET end_time = start_time WHERE end_time < start_time",
+        ),
+        (
+            "recording(s) with a stale word count",
+            """SELECT COUNT(*) FROM recordings r WHERE COALESCE(r.word_count, 0) !=
+                   (SELECT COUNT(*) FROM words w WHERE w.recording_id = r.id)""",
+            """UPDATE recordings SET word_count =
+                   (SELECT COUNT(*) FROM words w WHERE w.recording_id = recordings.id)
+               WHERE COALESCE(word_count, 0) !=
+                   (SELECT COUNT(*) FROM words w WHERE w.recording_id = recordings.id)""",
+        ),
+    ]
+    for message, count_sql, repair_sql in checks:
+        count = conn.execute(count_sql).fetchone()[0]
+        if count:
+            if repair:
+                conn.execute(repair_sql)
+            yield _issue("consistency", f"{count} {message}", count=count, repaired=repair)
+
+
+def _check_fts(conn: sqlite3.Connection, repair: bool) -> Iterator[dict[str, Any]]:
+    try:
+        conn.execute("INSERT INTO words_fts(words_fts) VALUES('integrity-check')")
+    except sqlite3.DatabaseError as e:
+        if repair:
+            conn.execute("INSERT INTO words_fts(words_fts) VALUES('rebuild')")
+        yield _issue("fts", f"Search index out of sync with words: {e}", repaired=repair)
+        return
+    if repair:
+        # Cheap next to the rest and clears drift the check cannot see.
+        conn.execute("INSERT INTO words_fts(words_fts) VALUES('rebuild')")
+        conn.execute("INSERT INTO words_fts(words_fts) VALUES('optimize')")
+
+
+_CHECKS = {
+    "integrity": _check_integrity,
+    "foreign_keys": _check_foreign_keys,
+    "consistency": _check_consistency,
+    "fts": _check_fts,
+}
+
+
+def _db_size() -> int:
+    path = get_db_path()
+    wal = path.with_name(path.name + "-wal")
+    return sum(p.stat().st_size for p in (path, wal) if p.exists())
+
+
+def _backup(max_backups: int) -> Path | None:
+    db_path = get_db_path()
+    manager = DatabaseBackupManager(db_path, db_path.parent / "backups", max_backups=max_backups)
+    return manager.create_backup()
+
+
+def run_maintenance(
+    *, repair: bool = True, vacuum: bool = True, max_backups: int = DEFAULT_MAX_BACKUPS
+) -> Iterator[dict[str, Any]]:
+    """Run every step, yielding ``start`` / ``step`` / ``issue`` / ``done`` events.
+
+    ``max_backups`` is the rotation limit for the backup a repairing run
+    takes first. Raises ``MaintenanceBusy`` on the first iteration if a run
+    is active.
+    """
+    global _last_report
+    if not _run_lock.acquire(blocking=False):
+        raise MaintenanceBusy("Database maintenance is already running")
+    # VACUUM rewrites the file, so a report-only run leaves it out.
+    steps = [s for s in MAINTENANCE_STEPS if s != "vacuum" or (vacuum and repair)]
+    issues: list[dict[str, Any]] = []
+    started = time.monotonic()
+    try:
+        size_before = _db_size()
+        backup = None
+        if repair:
+            backup = _backup(max_backups)
+            if backup is None:
+                logger.warning("Database maintenance: backup failed, running report-only")
+                repair = False
+        yield {
+            "type": "start",
+            "steps": steps,
+            "repair": repair,
+            "backup": str(backup) if backup else None,
+        }
+        for index, step in enumerate(steps):
+            yield {"type": "step", "step": step, "index": index, "total": len(steps)}
+            with get_connection() as conn:
+                if step == "vacuum":
+                    if not repair:
+                        continue
+                    conn.execute("VACUUM")
+                    conn.execute("PRAGMA wal_checkpoint(TRUNCATE)")
+                    conn.execute("PRAGMA optimize")
+                    continue
+                try:
+                    for event in _CHECKS[step](conn, repair):
+                        issues.append(event)
+                        yield event
+                    conn.commit()
+                except sqlite3.DatabaseError as e:
+                    conn.rollback()
+                    event = _issue(step, f"Check could not run: {e}")
+                    issues.append(event)
+                    yield event
+            if step == "integrity" and repair and any(i["step"] == "integrity" for i in issues):
+                logger.warning("Database integrity check failed: repairs skipped, restore a backup")
+                repair = False
+        report = {
+            "type": "done",
+            "finished_at": time.time(),
+            "duration_seconds": round(time.monotonic() - started, 2),
+            "issues": sum(i["count"] for i in issues),
+            "repaired": sum(i["count"] for i in issues if i["repaired"]),
+            "healthy": all(i["repaired"] for i in issues),
+            "backup": str(backup) if backup else None,
+            "size_before": size_before,
+            "size_after": _db_size(),
+            "details": issues,
+        }
+        _last_report = report
+        logger.info(
+            "Database maintenance: %d issue(s), %d repaired, %.1fs",
+            report["issues"],
+            report["repaired"],
+            report["duration_seconds"],
+        )
+        yield report
+    finally:
+        _run_lock.release()
+
+
+async def periodic_maintenance(
+    interval_hours: float, vacuum: bool = False, max_backups: int = DEFAULT_MAX_BACKUPS
+) -> None:
+    """Check and repair the library every ``interval_hours`` until cancelled."""
+    while True:
+        try:
+            await asyncio.sleep(interval_hours * 3600)
+        except asyncio.CancelledError:
+            logger.info("Periodic database maintenance cancelled (shutdown)")
+            return
+        try:
+            await asyncio.to_thread(
+                lambda: list(run_maintenance(repair=True, vacuum=vacuum, max_backups=max_backups))
+            )
+        except MaintenanceBusy:
+            logger.info("Periodic database maintenance skipped: a run is already active")
+        except Exception:
+            logger.exception("Periodic database maintenance failed — will retry next interval")
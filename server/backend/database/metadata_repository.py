@@ -0,0 +1,176 @@
+"""Metadata repository — user-defined fields on notebook recordings.
+
+Storage over the ``recording_metadata`` table created by migration 027:
+one ``(recording_id, field, value)`` row per field, so the library can be
+filtered and searched by field without parsing JSON. Which fields a
+recording is asked for comes from its project template
+(``project_template_repository``); any other key is accepted too.
+
+Fields and values are trimmed; a blank value removes the field. Values
+match case-insensitively in filters and search.
+
+All writes commit before returning (NFR16).
+"""
+
+from __future__ import annotations
+
+import logging
+import sqlite3
+from collections.abc import Iterable, Mapping
+from typing import Any
+
+from server.database.database import get_connection
+
+logger = logging.getLogger(__name__)
+
+MAX_FIELD_LENGTH = 64
+MAX_VALUE_LENGTH = 500
+# Distinct values listed per field for the filter dropdowns.
+MAX_SUGGESTED_VALUES = 50
+
+
+def _is_missing_table(exc: sqlite3.OperationalError) -> bool:
+    return "no such table" in str(exc).lower()
+
+
+def normalize_metadata(metadata: Mapping[str, Any]) -> dict[str, str]:
+    """
+    Trimmed ``field -> value`` with blank fields and values dropped.
+    Raises ValueError for a field or value over the length limits.
+    """
+    clean: dict[str, str] = {}
+    for raw_field, raw_value in metadata.items():
+        field = str(raw_field).strip()
+        value = "" if raw_value is None else str(raw_value).strip()
+        if not field or not value:
+            continue
+        if len(field) > MAX_FIELD_LENGTH:
+            raise ValueError(f"Field name longer than {MAX_FIELD_LENGTH} characters: {field!r}")
+        if len(value) > MAX_VALUE_LENGTH:
+            raise ValueError(f"Value of {field!r} longer than {MAX_VALUE_LENGTH} characters")
+        clean[field] = value
+    return clean
+
+
+def get_metadata(recording_id: int) -> dict[str, str]:
+    """The recording's fields; empty when it has none or the table is missing."""
+    return get_metadata_for([recording_id]).get(recording_id, {})
+
+
+def get_metadata_for(recording_ids: Iterable[int]) -> dict[int, dict[str, str]]:
+    """Fields of several recordings at once, keyed by recording id."""
+    ids = sorted(set(recording_ids))
+    if not ids:
+        return {}
+    result: dict[int, dict[str, str]] = {}
+    try:
+        with get_connection() as conn:
+            # Chunked to stay under SQLite's bound-parameter limit.
+            for start in range(0, len(ids), 500):
+                chunk = ids[start : start + 500]
+                placeholders = ",".join("?" * len(chunk))
+                rows = conn.execute(
+                    f"SELECT recording_id, field, value FROM recording_metadata "  # noqa: S608
+                    f"WHERE recording_id IN ({placeholders}) ORDER BY field COLLATE NOCASE",
+                    chunk,
+                ).fetchall()
+                for row in rows:
+                    result.setdefault(row["recording_id"], {})[row["field"]] = row["value"]
+    except sqlite3.OperationalError as exc:
+        if _is_missing_table(exc):
+            return {}
+        raise
+    return result
+
+
+def set_metadata(recording_id: int, metadata: Mapping[str, Any]) -> dict[str, str]:
+    """Replace the recording's fields. Returns what was stored."""
+    clean = normalize_metadata(metadata)
+    with get_connection() as conn:
+        conn.execute("DELETE FROM recording_metadata WHERE recording_id = ?", (recording_id,))
+        conn.executemany(
+            "INSERT INTO recording_metadata (recording_id, field, value) VALUES (?, ?, ?)",
+            [(recording_id, field, value) for field, value in clean.items()],
+        )
+        conn.commit()
+    return clean
+
+
+def list_fields() -> list[dict[str, Any]]:
+    """
+    Every field in use, with how many recordings carry it and its most
+    common values — what the library's filter controls offer.
+    """
+    try:
+        with get_connection() as conn:
+            rows = conn.execute(
+                """
+                SELECT field, value, COUNT(*) AS n
+                FROM recording_metadata
+                GROUP BY field, value
+                ORDER BY field COLLATE NOCASE, n DESC, value COLLATE NOCASE
+                """
+            ).fetchall()
+    except sqlite3.OperationalError as exc:
+        if _is_missing_table(exc):
+            return []
+        raise
+    fields: dict[str, dict[str, Any]] = {}
+    for row in rows:
+        entry = fields.setdefault(row["field"], {"field": row["field"], "count": 0, "values": []})
+        entry["count"] += row["n"]
+        if len(entry["values"]) < MAX_SUGGESTED_VALUES:
+            entry["values"].append(row["value"])
+    return list(fields.values())
+
+
+def filter_recording_ids(filters: Mapping[str, str]) -> set[int]:
+    """Recordings whose every ``field`` equals its ``value`` (case-insensitive)."""
+    clauses = []
+    params: list[str] = []
+    for field, value in filters.items():
+        clauses.append(
+            "SELECT recording_id FROM recording_metadata "
+            "WHERE field = ? COLLATE NOCASE AND value = ? COLLATE NOCASE"
+        )
+        params.extend([field, value])
+    if not clauses:
+        return set()
+    try:
+        with get_connection() as conn:
+            rows = conn.execute(" INTERSECT ".join(clauses), params).fetchall()
+    except sqlite3.OperationalError as exc:
+        if _is_missing_table(exc):
+            return set()
+        raise
+    return {row["recording_id"] for row in rows}
+
+
+def search_metadata(
+    query: str,
+    start_date: str | None = None,
+    end_date: str | None = None,
+    limit: int = 50,
+) -> list[dict[str, Any]]:
+    """Recordings with a field value containing ``query``, one row per matching field."""
+    try:
+        with get_connection() as conn:
+            rows = conn.execute(
+                """
+                SELECT r.id AS recording_id, r.filename, r.title, r.recorded_at,
+                       m.field, m.value
+                FROM recording_metadata m
+                JOIN recordings r ON r.id = m.recording_id
+                WHERE m.value LIKE ?
+                  AND (? IS NULL OR date(r.recorded_at) >= date(?))
+                  AND (? IS NULL OR date(r.recorded_at) <= date(?))
+                ORDER BY r.recorded_at DESC, m.field COLLATE NOCASE
+                LIMIT ?
+                """,
+                (f"%{query}%", start_date, start_date, end_date, end_date, limit),
+            ).fetchall()
+    except sqlite3.OperationalError as exc:
+        if _is_missing_table(exc):
+            return []
+        raise
+    return [dict(row) for row in rows]
@@ -0,0 +1,49 @@
+"""Add timecode_settings column to recordings (video-editor timecode).
+
+Per-transcript SMPTE settings used by the subtitle exporters and the
+dashboard viewer (see ``server/backend/core/timecode.py``). Stored as a
+small JSON object so the shape can grow without another migration:
+
+    NULL  — no timecode; exports use zero-based clock times (previous behavior)
+    TEXT  — {"start_timecode": "01:00:00:00", "fps": 25.0, "drop_frame": false}
+
+Purely additive and nullable (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "018"
+down_revision: str | None = "017"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Add the nullable timecode_settings column."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    # Existing rows default to NULL ("no timecode") — see NFR21.
+    conn.execute(text("ALTER TABLE recordings ADD COLUMN timecode_settings TEXT"))
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
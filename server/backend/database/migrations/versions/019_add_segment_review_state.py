@@ -0,0 +1,51 @@
+"""Add review_state / reviewed_at columns to segments (transcript QA).
+
+Per-segment state for the viewer's review mode (see
+``server/backend/core/segment_review.py``):
+
+    NULL        — unreviewed (every existing segment)
+    'approved'  — checked and correct
+    'flagged'   — needs another look
+
+``reviewed_at`` records when the state was last changed. Purely additive and
+nullable (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "019"
+down_revision: str | None = "018"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Add the nullable review_state and reviewed_at columns."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    # Existing rows default to NULL ("unreviewed") — see NFR21.
+    conn.execute(text("ALTER TABLE segments ADD COLUMN review_state TEXT"))
+    conn.execute(text("ALTER TABLE segments ADD COLUMN reviewed_at TEXT"))
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
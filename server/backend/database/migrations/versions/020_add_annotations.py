@@ -0,0 +1,77 @@
+"""Add annotations table (threaded review notes on a transcript).
+
+One row per note. A note is anchored to a time range (``start_time`` /
+``end_time``; ``end_time`` NULL = a point in time) and optionally to a
+segment. Replies point at their thread root through ``parent_id``.
+
+Foreign keys:
+  - recording_id ON DELETE CASCADE — notes go with the recording.
+  - segment_id ON DELETE SET NULL — re-segmenting a transcript (label
+    import, realign) must not lose notes; the time range still places them.
+  - parent_id ON DELETE CASCADE — deleting a thread root removes its replies.
+
+Purely additive (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "020"
+down_revision: str | None = "019"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Create the annotations table + its recording index."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    conn.execute(
+        text(
+            """
+            CREATE TABLE IF NOT EXISTS annotations (
+                id            INTEGER PRIMARY KEY AUTOINCREMENT,
+                recording_id  INTEGER NOT NULL
+                                REFERENCES recordings(id) ON DELETE CASCADE,
+                segment_id    INTEGER
+                                REFERENCES segments(id) ON DELETE SET NULL,
+                parent_id     INTEGER
+                                REFERENCES annotations(id) ON DELETE CASCADE,
+                start_time    REAL NOT NULL,
+                end_time      REAL,
+                author        TEXT NOT NULL,
+                body          TEXT NOT NULL,
+                created_at    TEXT NOT NULL,
+                updated_at    TEXT NOT NULL
+            )
+            """
+        )
+    )
+    conn.execute(
+        text(
+            "CREATE INDEX IF NOT EXISTS idx_annotations_recording "
+            "ON annotations(recording_id, start_time)"
+        )
+    )
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
@@ -0,0 +1,71 @@
+"""Add redactions table (sensitive time ranges masked in exports).
+
+One row per redacted range. Text exports replace the words inside a range
+with a mask; audio exports mute or bleep it (see
+``server/backend/core/redaction.py``).
+
+    source — 'manual' (marked in the viewer) or 'pii' (detector suggestion)
+    label  — optional category, e.g. 'email', 'phone', 'name'
+    text   — the words covered when the range was marked (UI display only)
+
+recording_id ON DELETE CASCADE. Purely additive (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "021"
+down_revision: str | None = "020"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Create the redactions table + its recording index."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    conn.execute(
+        text(
+            """
+            CREATE TABLE IF NOT EXISTS redactions (
+                id            INTEGER PRIMARY KEY AUTOINCREMENT,
+                recording_id  INTEGER NOT NULL
+                                REFERENCES recordings(id) ON DELETE CASCADE,
+                start_time    REAL NOT NULL,
+                end_time      REAL NOT NULL,
+                label         TEXT,
+                source        TEXT NOT NULL DEFAULT 'manual',
+                text          TEXT,
+                created_at    TEXT NOT NULL
+            )
+            """
+        )
+    )
+    conn.execute(
+        text(
+            "CREATE INDEX IF NOT EXISTS idx_redactions_recording "
+            "ON redactions(recording_id, start_time)"
+        )
+    )
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
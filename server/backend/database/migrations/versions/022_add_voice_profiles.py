@@ -0,0 +1,60 @@
+"""Add voice_profiles table (enrolled speakers for automatic identification).
+
+One row per enrolled person. ``embedding`` is the L2-normalised mean of the
+speaker embeddings of every enrolled sample, stored as little-endian
+float32 bytes; ``sample_count`` weights the running mean when another
+sample is added (see ``server/backend/core/speaker_identification.py``).
+
+Names are unique case-insensitively — enrolling "bob" again adds a sample
+to "Bob". Purely additive (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "022"
+down_revision: str | None = "021"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Create the voice_profiles table."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    conn.execute(
+        text(
+            """
+            CREATE TABLE IF NOT EXISTS voice_profiles (
+                id            INTEGER PRIMARY KEY AUTOINCREMENT,
+                name          TEXT NOT NULL UNIQUE COLLATE NOCASE,
+                embedding     BLOB NOT NULL,
+                sample_count  INTEGER NOT NULL DEFAULT 1,
+                created_at    TEXT NOT NULL,
+                updated_at    TEXT NOT NULL
+            )
+            """
+        )
+    )
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
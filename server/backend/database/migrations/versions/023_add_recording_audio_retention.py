@@ -0,0 +1,54 @@
+"""Add keep_audio / audio_retired_at columns to recordings (source-audio retention).
+
+Backs the optional retention policy in
+``server/backend/database/recording_audio_retention.py``, which deletes or
+archives a recording's original audio once it is old enough while keeping
+its transcript:
+
+    keep_audio        INTEGER NOT NULL DEFAULT 0 — 1 = "keep forever", the
+                      policy never touches this recording's audio
+    audio_retired_at  TEXT NULL — when the policy deleted or archived the
+                      audio; NULL = the original file is still in place
+
+Purely additive (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "023"
+down_revision: str | None = "022"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Add the retention opt-out flag and the retirement timestamp."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    # Existing rows default to "not kept" and "not retired" — the policy is
+    # off by default, so nothing changes until it is enabled.
+    conn.execute(text("ALTER TABLE recordings ADD COLUMN keep_audio INTEGER NOT NULL DEFAULT 0"))
+    conn.execute(text("ALTER TABLE recordings ADD COLUMN audio_retired_at TEXT"))
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
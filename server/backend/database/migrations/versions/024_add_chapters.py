@@ -0,0 +1,68 @@
+"""Add chapters table (navigable transcript outline).
+
+One row per chapter, built by ``server/backend/core/chaptering.py`` from
+long pauses and topic shifts. A regenerated outline replaces every row of
+the recording.
+
+    source — 'auto' (heuristic title) or 'llm' (title from the LLM)
+
+recording_id ON DELETE CASCADE. Purely additive (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "024"
+down_revision: str | None = "023"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Create the chapters table + its recording index."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    conn.execute(
+        text(
+            """
+            CREATE TABLE IF NOT EXISTS chapters (
+                id            INTEGER PRIMARY KEY AUTOINCREMENT,
+                recording_id  INTEGER NOT NULL
+                                REFERENCES recordings(id) ON DELETE CASCADE,
+                start_time    REAL NOT NULL,
+                end_time      REAL NOT NULL,
+                title         TEXT NOT NULL,
+                source        TEXT NOT NULL DEFAULT 'auto',
+                created_at    TEXT NOT NULL
+            )
+            """
+        )
+    )
+    conn.execute(
+        text(
+            "CREATE INDEX IF NOT EXISTS idx_chapters_recording "
+            "ON chapters(recording_id, start_time)"
+        )
+    )
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
@@ -0,0 +1,53 @@
+"""Add owner column to recordings (per-user namespaces on shared servers).
+
+When several people share one server, each dashboard can name its user in
+the ``X-TranscriptionSuite-User`` header (see ``api/routes/utils.py``).
+Recordings created by such a request remember that name so clients can show
+only their own items:
+
+    owner  TEXT NULL — the user name the creating client sent; NULL =
+           shared / created before owners existed (live-mode recordings,
+           clients without a user name)
+
+A label for filtering, not an access-control boundary. Purely additive
+(NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "025"
+down_revision: str | None = "024"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Add the owner column and its index."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    # Existing rows stay unowned, i.e. visible to every user.
+    conn.execute(text("ALTER TABLE recordings ADD COLUMN owner TEXT"))
+    conn.execute(text("CREATE INDEX IF NOT EXISTS idx_recordings_owner ON recordings(owner)"))
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
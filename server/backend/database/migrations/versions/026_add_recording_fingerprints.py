@@ -0,0 +1,64 @@
+"""Add recording_fingerprints table (re-encoded / trimmed copy detection).
+
+One row per notebook recording whose upload could be fingerprinted with
+chromaprint (``server/backend/core/audio_fingerprint.py``):
+
+    fingerprint       BLOB — the raw sub-fingerprints, packed uint32
+    duration_seconds  REAL — audio covered by the fingerprint
+
+Unlike the audio hashes this is not an equality key, so there is no index
+on it; matching slides each stored fingerprint against the new upload's.
+Recordings imported before this migration have no row and are never
+matched.
+
+recording_id ON DELETE CASCADE. Purely additive (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "026"
+down_revision: str | None = "025"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Create the recording_fingerprints table."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    conn.execute(
+        text(
+            """
+            CREATE TABLE IF NOT EXISTS recording_fingerprints (
+                recording_id      INTEGER PRIMARY KEY
+                                    REFERENCES recordings(id) ON DELETE CASCADE,
+                fingerprint       BLOB NOT NULL,
+                duration_seconds  REAL NOT NULL,
+                created_at        TEXT NOT NULL
+            )
+            """
+        )
+    )
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
@@ -0,0 +1,95 @@
+"""Add recording_metadata and project_templates tables.
+
+``recording_metadata`` holds user-defined fields on a recording (case
+number, client, interviewee, location…) as one row per field:
+
+    field  TEXT — the field's key, as typed by the user
+    value  TEXT — its value; blank values are deleted, not stored
+
+The (field, value) index backs the library filter and metadata search.
+recording_id ON DELETE CASCADE.
+
+``project_templates`` holds reusable setups for recurring workflows:
+
+    fields_json    TEXT — JSON list of {"key", "label"} the template asks for
+    defaults_json  TEXT — JSON object of pre-filled field values
+    options_json   TEXT — JSON object of transcription options
+                          (language, diarization, expected speakers, profile)
+
+Purely additive (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "027"
+down_revision: str | None = "026"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Create recording_metadata + project_templates and their indexes."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    conn.execute(
+        text(
+            """
+            CREATE TABLE IF NOT EXISTS recording_metadata (
+                recording_id  INTEGER NOT NULL
+                                REFERENCES recordings(id) ON DELETE CASCADE,
+                field         TEXT NOT NULL,
+                value         TEXT NOT NULL,
+                PRIMARY KEY (recording_id, field)
+            )
+            """
+        )
+    )
+    conn.execute(
+        text(
+            "CREATE INDEX IF NOT EXISTS idx_recording_metadata_field_value "
+            "ON recording_metadata(field, value)"
+        )
+    )
+
+    conn.execute(
+        text(
+            """
+            CREATE TABLE IF NOT EXISTS project_templates (
+                id             INTEGER PRIMARY KEY AUTOINCREMENT,
+                name           TEXT NOT NULL,
+                description    TEXT,
+                fields_json    TEXT NOT NULL DEFAULT '[]',
+                defaults_json  TEXT NOT NULL DEFAULT '{}',
+                options_json   TEXT NOT NULL DEFAULT '{}',
+                created_at     TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at     TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+            """
+        )
+    )
+    conn.execute(
+        text("CREATE INDEX IF NOT EXISTS idx_project_templates_name ON project_templates(name)")
+    )
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
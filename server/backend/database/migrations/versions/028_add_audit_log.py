@@ -0,0 +1,85 @@
+"""Add the append-only audit_log table.
+
+One row per destructive action or settings change (recording deletion,
+redaction, bulk transcript edit, token or profile change, restore…):
+
+    occurred_at   TEXT — UTC ISO-8601 timestamp, set by the server
+    action        TEXT — dotted name, e.g. ``recording.delete``
+    target_type   TEXT — what was acted on (``recording``, ``token``…)
+    target_id     TEXT — its id, as text; NULL for server-wide settings
+    actor         TEXT — ``get_requester`` identity of the caller
+    command       TEXT — the initiating request, ``METHOD /path?query``
+    details_json  TEXT — JSON object with action-specific context
+
+Two triggers abort any UPDATE or DELETE on the table, so rows can only be
+appended — including by the maintenance tooling and ad-hoc SQL.
+
+Purely additive (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "028"
+down_revision: str | None = "027"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Create audit_log, its time index and the append-only triggers."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    conn.execute(
+        text(
+            """
+            CREATE TABLE IF NOT EXISTS audit_log (
+                id            INTEGER PRIMARY KEY AUTOINCREMENT,
+                occurred_at   TEXT NOT NULL,
+                action        TEXT NOT NULL,
+                target_type   TEXT,
+                target_id     TEXT,
+                actor         TEXT,
+                command       TEXT NOT NULL,
+                details_json  TEXT NOT NULL DEFAULT '{}'
+            )
+            """
+        )
+    )
+    conn.execute(
+        text("CREATE INDEX IF NOT EXISTS idx_audit_log_occurred_at ON audit_log(occurred_at)")
+    )
+    for event in ("UPDATE", "DELETE"):
+        conn.execute(
+            text(
+                f"""
+                CREATE TRIGGER IF NOT EXISTS audit_log_no_{event.lower()}
+                BEFORE {event} ON audit_log
+                BEGIN
+                    SELECT RAISE(ABORT, 'audit_log is append-only');
+                END
+                """
+            )
+        )
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
@@ -0,0 +1,51 @@
+"""Add partial_until / partial_reason columns to recordings (interrupted jobs).
+
+A transcription that fails partway through long audio keeps the segments
+it finished instead of losing them; these columns mark such a recording
+so it can be resumed (``POST /api/notebook/recordings/{id}/resume``):
+
+    partial_until   REAL NULL — seconds of audio the saved transcript
+                    covers; NULL = the transcript is complete
+    partial_reason  TEXT NULL — why the transcription stopped early
+
+Purely additive (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "029"
+down_revision: str | None = "028"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Add the partial-transcript offset and reason."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    # Existing rows are complete transcripts.
+    conn.execute(text("ALTER TABLE recordings ADD COLUMN partial_until REAL"))
+    conn.execute(text("ALTER TABLE recordings ADD COLUMN partial_reason TEXT"))
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
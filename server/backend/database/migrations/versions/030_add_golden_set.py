@@ -0,0 +1,83 @@
+"""Add golden_references / golden_runs tables (accuracy tracking).
+
+Recordings with a hand-corrected transcript can be marked as reference;
+their audio is re-transcribed on a schedule and the word error rate
+against the reference is kept per run, so a model or image update that
+makes transcripts worse shows up as drift (``core/golden_set.py``).
+
+    golden_references  reference — the corrected text when the recording
+                       was marked; later edits don't move the baseline
+    golden_runs        wer NULL + error set when the run failed
+
+recording_id ON DELETE CASCADE. Purely additive (NFR21); forward-only (NFR22).
+"""
+
+from collections.abc import Sequence
+
+from alembic import op
+from sqlalchemy import text
+
+# revision identifiers, used by Alembic.
+revision: str = "030"
+down_revision: str | None = "029"
+branch_labels: str | Sequence[str] | None = None
+depends_on: str | Sequence[str] | None = None
+
+
+def _revision_metadata() -> tuple[
+    str,
+    str | None,
+    str | Sequence[str] | None,
+    str | Sequence[str] | None,
+]:
+    """Reference Alembic metadata globals for static analyzers."""
+    return revision, down_revision, branch_labels, depends_on
+
+
+def upgrade() -> None:
+    """Create the reference and run tables."""
+    _revision_metadata()
+    conn = op.get_bind()
+
+    conn.execute(
+        text(
+            """
+            CREATE TABLE IF NOT EXISTS golden_references (
+                recording_id  INTEGER PRIMARY KEY
+                                REFERENCES recordings(id) ON DELETE CASCADE,
+                reference     TEXT NOT NULL,
+                marked_at     TEXT NOT NULL
+            )
+            """
+        )
+    )
+    conn.execute(
+        text(
+            """
+            CREATE TABLE IF NOT EXISTS golden_runs (
+                id              INTEGER PRIMARY KEY AUTOINCREMENT,
+                recording_id    INTEGER NOT NULL
+                                  REFERENCES recordings(id) ON DELETE CASCADE,
+                run_at          TEXT NOT NULL,
+                model           TEXT NOT NULL,
+                server_version  TEXT NOT NULL,
+                wer             REAL,
+                error           TEXT
+            )
+            """
+        )
+    )
+    conn.execute(
+        text(
+            "CREATE INDEX IF NOT EXISTS idx_golden_runs_recording "
+            "ON golden_runs(recording_id, run_at)"
+        )
+    )
+
+
+def downgrade() -> None:
+    """Forward-only — see NFR22."""
+    _revision_metadata()
+    raise RuntimeError(
+        "forward-only migration — see NFR22; restore from backup if a roll-back is required."
+    )
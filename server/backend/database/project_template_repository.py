@@ -0,0 +1,146 @@
+"""Project template repository — reusable setups for recurring workflows.
+
+Parameterised SQL CRUD over the ``project_templates`` table created by
+migration 027. A template names the metadata fields its recordings carry
+(``fields``), pre-fills some of them (``defaults``) and pre-selects the
+transcription options (``options``) the dashboard uses when a file is
+imported under it. The recording itself stores only the resulting
+metadata (``metadata_repository``), so editing or deleting a template
+never rewrites the library.
+
+Persist-Before-Deliver (NFR16): every write commits before returning.
+Last-write-wins on concurrent edits, as for profiles.
+"""
+
+from __future__ import annotations
+
+import json
+import logging
+from datetime import UTC, datetime
+from typing import Any
+
+from server.database.database import get_connection
+
+logger = logging.getLogger(__name__)
+
+
+def _now_iso() -> str:
+    return datetime.now(UTC).isoformat()
+
+
+def _row_to_dict(row: Any) -> dict[str, Any]:
+    return {
+        "id": row["id"],
+        "name": row["name"],
+        "description": row["description"],
+        "fields": json.loads(row["fields_json"] or "[]"),
+        "defaults": json.loads(row["defaults_json"] or "{}"),
+        "options": json.loads(row["options_json"] or "{}"),
+        "created_at": row["created_at"],
+        "updated_at": row["updated_at"],
+    }
+
+
+def list_templates() -> list[dict[str, Any]]:
+    with get_connection() as conn:
+        rows = conn.execute(
+            "SELECT * FROM project_templates ORDER BY name COLLATE NOCASE"
+        ).fetchall()
+    return [_row_to_dict(row) for row in rows]
+
+
+def get_template(template_id: int) -> dict[str, Any] | None:
+    with get_connection() as conn:
+        row = conn.execute(
+            "SELECT * FROM project_templates WHERE id = ?",
+            (template_id,),
+        ).fetchone()
+    return _row_to_dict(row) if row is not None else None
+
+
+def create_template(
+    *,
+    name: str,
+    description: str | None,
+    fields: list[dict[str, Any]],
+    defaults: dict[str, str],
+    options: dict[str, Any],
+) -> int:
+    """Insert and commit. Returns new row id."""
+    now = _now_iso()
+    with get_connection() as conn:
+        cur = conn.execute(
+            """
+            INSERT INTO project_templates
+                (name, description, fields_json, defaults_json, options_json,
+                 created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            """,
+            (
+                name,
+                description,
+                json.dumps(fields),
+                json.dumps(defaults, sort_keys=True),
+                json.dumps(options, sort_keys=True),
+                now,
+                now,
+            ),
+        )
+        conn.commit()
+        return int(cur.lastrowid or 0)
+
+
+# Distinguishes "argument omitted" from ``None`` (clearing description).
+_UNSET: Any = object()
+
+
+def update_template(
+    template_id: int,
+    *,
+    name: str = _UNSET,
+    description: str | None = _UNSET,
+    fields: list[dict[str, Any]] = _UNSET,
+    defaults: dict[str, str] = _UNSET,
+    options: dict[str, Any] = _UNSET,
+) -> bool:
+    """Update the fields that were passed. Returns False when no row matched."""
+    sets: list[str] = []
+    params: list[Any] = []
+    if name is not _UNSET:
+        sets.append("name = ?")
+        params.append(name)
+    if description is not _UNSET:
+        sets.append("description = ?")
+        params.append(description)
+    if fields is not _UNSET:
+        sets.append("fields_json = ?")
+        params.append(json.dumps(fields))
+    if defaults is not _UNSET:
+        sets.append("defaults_json = ?")
+        params.append(json.dumps(defaults, sort_keys=True))
+    if options is not _UNSET:
+        sets.append("options_json = ?")
+        params.append(json.dumps(options, sort_keys=True))
+
+    if not sets:
+        return False
+
+    sets.append("updated_at = ?")
+    params.append(_now_iso())
+    params.append(template_id)
+
+    with get_connection() as conn:
+        cur = conn.execute(
+            f"UPDATE project_templates SET {', '.join(sets)} WHERE id = ?",  # noqa: S608
+            tuple(params),
+        )
+        conn.commit()
+        return cur.rowcount > 0
+
+
+def delete_template(template_id: int) -> bool:
+    """Delete a template row. Returns True if a row was removed."""
+    with get_connection() as conn:
+        cur = conn.execute("DELETE FROM project_templates WHERE id = ?", (template_id,))
+        conn.commit()
+        return cur.rowcount > 0
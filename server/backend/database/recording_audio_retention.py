@@ -0,0 +1,130 @@
+"""
+Source-audio retention for Audio Notebook recordings.
+
+Once a recording was imported more than ``storage.source_audio_retention_days``
+ago, its original audio is deleted or moved to the archive directory. The
+recording row, segments, words, summary and edits are always kept — only the
+audio file goes, and ``audio_retired_at`` records when.
+
+Recordings marked "keep audio forever" (``keep_audio``) are never touched,
+nor are partial transcripts (``partial_until``) or golden-set references —
+resuming or re-scoring one needs the audio.
+Mirrors :func:`server.database.audio_cleanup.periodic_cleanup`, which does the
+same for the raw job audio kept for retries.
+"""
+
+import asyncio
+import logging
+import shutil
+from pathlib import Path
+
+logger = logging.getLogger(__name__)
+
+RETENTION_ACTIONS = ("delete", "archive")
+
+
+async def periodic_recording_audio_retention(
+    max_age_days: int,
+    action: str = "delete",
+    archive_dir: str = "",
+    interval_hours: int = 24,
+) -> None:
+    """Run retire_old_recording_audio now and then every *interval_hours*.
+
+    If *interval_hours* <= 0, runs once and returns. Designed to be launched
+    via ``asyncio.create_task`` and cancelled on shutdown via ``task.cancel()``.
+    """
+    try:
+        await asyncio.to_thread(retire_old_recording_audio, max_age_days, action, archive_dir)
+    except Exception:
+        logger.exception("Initial source-audio retention failed — periodic retries will continue")
+
+    if interval_hours <= 0:
+        logger.info("Periodic source-audio retention disabled (interval_hours=%d)", interval_hours)
+        return
+
+    interval_seconds = interval_hours * 3600
+    logger.info(
+        "Periodic source-audio retention armed (every %dh, %s after %dd)",
+        interval_hours,
+        action,
+        max_age_days,
+    )
+
+    while True:
+        try:
+            await asyncio.sleep(interval_seconds)
+        except asyncio.CancelledError:
+            logger.info("Periodic source-audio retention cancelled (shutdown)")
+            return
+        try:
+            await asyncio.to_thread(retire_old_recording_audio, max_age_days, action, archive_dir)
+        except Exception:
+            logger.exception("Periodic source-audio retention failed — will retry next interval")
+
+
+def _archive(audio_path: Path, archive_dir: Path) -> Path:
+    """Move ``audio_path`` into ``archive_dir`` without overwriting anything."""
+    archive_dir.mkdir(parents=True, exist_ok=True)
+    target = archive_dir / audio_path.name
+    counter = 2
+    while target.exists():
+        target = archive_dir / f"{audio_path.stem}-{counter}{audio_path.suffix}"
+        counter += 1
+    shutil.move(str(audio_path), str(target))
+    return target
+
+
+def retire_old_recording_audio(
+    max_age_days: int, action: str = "delete", archive_dir: str = ""
+) -> int:
+    """Delete or archive the audio of recordings older than *max_age_days*.
+
+    Returns the number of recordings retired. Pass 0 days to skip (keep
+    forever). A file that can't be removed or moved is left in place and
+    retried on the next run; a file already missing is marked retired.
+    """
+    if max_age_days <= 0:
+        logger.debug("Source-audio retention skipped (retention_days=%d)", max_age_days)
+        return 0
+    if action not in RETENTION_ACTIONS:
+        logger.error("Source-audio retention: unknown action %r — nothing retired", action)
+        return 0
+    if action == "archive" and not archive_dir:
+        logger.error("Source-audio retention: action 'archive' needs an archive dir")
+        return 0
+
+    from .database import get_recordings_for_audio_retention, mark_recording_audio_retired
+
+    retired = 0
+    after_id = 0
+    while True:
+        recordings = get_recordings_for_audio_retention(max_age_days, after_id)
+        if not recordings:
+            break
+        after_id = int(recordings[-1]["id"])
+        for recording in recordings:
+            audio_path = Path(recording.get("filepath") or "")
+            new_path: str | None = None
+            try:
+                if audio_path.is_file():
+                    if action == "archive":
+                        new_path = str(_archive(audio_path, Path(archive_dir)))
+                    else:
+                        audio_path.unlink()
+            except OSError as exc:
+                logger.warning(
+                    "Source-audio retention: could not %s %s: %s", action, audio_path, exc
+                )
+                continue
+            mark_recording_audio_retired(int(recording["id"]), new_path)
+            retired += 1
+
+    if retired:
+        logger.info(
+            "Source-audio retention: %d recording(s) %s (older than %d days)",
+            retired,
+            "archived" if action == "archive" else "deleted",
+            max_age_days,
+        )
+    return retired
@@ -0,0 +1,127 @@
+"""Redaction repository — sensitive time ranges per recording.
+
+CRUD over the ``redactions`` table created by migration 021. Masking and
+audio muting live in ``server/backend/core/redaction.py``; this module
+provides only the data primitives.
+
+All writes commit before returning (NFR16).
+"""
+
+from __future__ import annotations
+
+import logging
+import sqlite3
+from datetime import UTC, datetime
+from typing import Any
+
+from server.database.database import get_connection
+
+logger = logging.getLogger(__name__)
+
+REDACTION_SOURCES: tuple[str, ...] = ("manual", "pii")
+
+
+def _now_iso() -> str:
+    return datetime.now(UTC).isoformat()
+
+
+def list_redactions(recording_id: int) -> list[dict[str, Any]]:
+    """Redacted ranges ordered by start time; empty when the table is missing."""
+    try:
+        with get_connection() as conn:
+            rows = conn.execute(
+                """
+                SELECT id, recording_id, start_time, end_time, label, source, text, created_at
+                FROM redactions
+                WHERE recording_id = ?
+                ORDER BY start_time, id
+                """,
+                (recording_id,),
+            ).fetchall()
+    except sqlite3.OperationalError as exc:
+        if "no such table" in str(exc).lower():
+            logger.debug("redactions table missing — returning empty list")
+            return []
+        raise
+    return [dict(row) for row in rows]
+
+
+def _validated_rows(recording_id: int, ranges: list[dict[str, Any]]) -> list[tuple[Any, ...]]:
+    now = _now_iso()
+    rows = []
+    for r in ranges:
+        start, end = float(r["start_time"]), float(r["end_time"])
+        if start < 0 or end <= start:
+            raise ValueError(f"invalid redaction range {start}–{end}")
+        source = r.get("source") or "manual"
+        if source not in REDACTION_SOURCES:
+            raise ValueError(f"unknown redaction source {source!r}")
+        rows.append((recording_id, start, end, r.get("label"), source, r.get("text"), now))
+    return rows
+
+
+def _insert(conn: sqlite3.Connection, rows: list[tuple[Any, ...]]) -> list[int]:
+    ids: list[int] = []
+    for row in rows:
+        cursor = conn.execute(
+            """
+            INSERT INTO redactions
+                (recording_id, start_time, end_time, label, source, text, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            """,
+            row,
+        )
+        ids.append(int(cursor.lastrowid))
+    return ids
+
+
+def add_redactions(recording_id: int, ranges: list[dict[str, Any]]) -> list[int]:
+    """Insert ranges in one transaction; returns the new ids.
+
+    Each entry has ``start_time`` / ``end_time`` and optional ``label``,
+    ``source`` and ``text``. Raises ValueError for an empty or inverted
+    range or an unknown source.
+    """
+    rows = _validated_rows(recording_id, ranges)
+    with get_connection() as conn:
+        ids = _insert(conn, rows)
+        conn.commit()
+    return ids
+
+
+def replace_redactions(recording_id: int, source: str, ranges: list[dict[str, Any]]) -> list[int]:
+    """Swap every ``source`` range for ``ranges`` atomically (PII re-scans)."""
+    rows = _validated_rows(recording_id, [{**r, "source": source} for r in ranges])
+    with get_connection() as conn:
+        conn.execute(
+            "DELETE FROM redactions WHERE recording_id = ? AND source = ?",
+            (recording_id, source),
+        )
+        ids = _insert(conn, rows)
+        conn.commit()
+    return ids
+
+
+def delete_redaction(recording_id: int, redaction_id: int) -> bool:
+    """Delete one range, scoped to the recording."""
+    with get_connection() as conn:
+        cursor = conn.execute(
+            "DELETE FROM redactions WHERE id = ? AND recording_id = ?",
+            (redaction_id, recording_id),
+        )
+        conn.commit()
+        return cursor.rowcount > 0
+
+
+def clear_redactions(recording_id: int, source: str | None = None) -> int:
+    """Delete every range (or only those from ``source``); returns the count."""
+    with get_connection() as conn:
+        if source is None:
+            cursor = conn.execute("DELETE FROM redactions WHERE recording_id = ?", (recording_id,))
+        else:
+            cursor = conn.execute(
+                "DELETE FROM redactions WHERE recording_id = ? AND source = ?",
+                (recording_id, source),
+            )
+        conn.commit()
+        return cursor.rowcount
@@ -0,0 +1,57 @@
+"""Per-user usage for the admin panel.
+
+Two views, because the server knows users in two ways:
+
+  * ``clients`` — transcription jobs grouped by the token's client name
+    (``transcription_jobs.client_name``; HTTP imports and WebSocket jobs)
+  * ``owners`` — notebook recordings grouped by the self-declared user name
+    (``recordings.owner``, migration 025); unowned recordings are left out
+
+``days`` limits both to recent activity; None covers everything.
+"""
+
+from __future__ import annotations
+
+from datetime import UTC, datetime, timedelta
+from typing import Any
+
+from server.database.database import get_connection
+
+
+def usage_by_user(days: int | None = None) -> dict[str, Any]:
+    since = (datetime.now(UTC) - timedelta(days=days)).isoformat() if days else None
+    with get_connection() as conn:
+        clients = conn.execute(
+            """
+            SELECT COALESCE(client_name, 'Unknown Client') AS client_name,
+                   COUNT(*) AS jobs,
+                   SUM(status = 'failed') AS failed_jobs,
+                   COALESCE(SUM(CASE WHEN status = 'completed' THEN duration_seconds END), 0)
+                       AS audio_seconds,
+                   MAX(created_at) AS last_job_at
+            FROM transcription_jobs
+            WHERE ? IS NULL OR datetime(created_at) >= datetime(?)
+            GROUP BY 1
+            ORDER BY jobs DESC
+            """,
+            (since, since),
+        ).fetchall()
+        owners = conn.execute(
+            """
+            SELECT owner,
+                   COUNT(*) AS recordings,
+                   COALESCE(SUM(duration_seconds), 0) AS recording_seconds,
+                   MAX(recorded_at) AS last_recorded_at
+            FROM recordings
+            WHERE owner IS NOT NULL
+              AND (? IS NULL OR datetime(recorded_at) >= datetime(?))
+            GROUP BY owner
+            ORDER BY recordings DESC
+            """,
+            (since, since),
+        ).fetchall()
+    return {
+        "since": since,
+        "clients": [dict(row) for row in clients],
+        "owners": [dict(row) for row in owners],
+    }
@@ -0,0 +1,141 @@
+"""Voice-profile repository — enrolled speakers for automatic identification.
+
+CRUD over the ``voice_profiles`` table created by migration 022. Embedding
+and matching live in ``server/backend/core/speaker_identification.py``; this
+module provides only the data primitives. Embeddings are stored as
+little-endian float32 bytes and returned as plain ``list[float]``.
+
+All writes commit before returning (NFR16).
+"""
+
+from __future__ import annotations
+
+import logging
+import math
+import sqlite3
+import struct
+from collections.abc import Sequence
+from datetime import UTC, datetime
+from typing import Any
+
+from server.database.database import get_connection
+
+logger = logging.getLogger(__name__)
+
+
+def _now_iso() -> str:
+    return datetime.now(UTC).isoformat()
+
+
+def _pack(embedding: Sequence[float]) -> bytes:
+    return struct.pack(f"<{len(embedding)}f", *embedding)
+
+
+def _unpack(blob: bytes) -> list[float]:
+    return list(struct.unpack(f"<{len(blob) // 4}f", blob))
+
+
+def _normalized(vector: Sequence[float]) -> list[float]:
+    norm = math.sqrt(sum(v * v for v in vector))
+    if norm == 0.0:
+        raise ValueError("speaker embedding is all zeros")
+    return [v / norm for v in vector]
+
+
+def list_profiles(*, with_embeddings: bool = False) -> list[dict[str, Any]]:
+    """Profiles ordered by name; empty when the table is missing."""
+    try:
+        with get_connection() as conn:
+            rows = conn.execute(
+                """
+                SELECT id, name, embedding, sample_count, created_at, updated_at
+                FROM voice_profiles
+                ORDER BY name COLLATE NOCASE
+                """
+            ).fetchall()
+    except sqlite3.OperationalError as exc:
+        if "no such table" in str(exc).lower():
+            logger.debug("voice_profiles table missing — returning empty list")
+            return []
+        raise
+    profiles = []
+    for row in rows:
+        profile = dict(row)
+        blob = profile.pop("embedding")
+        if with_embeddings:
+            profile["embedding"] = _unpack(blob)
+        profiles.append(profile)
+    return profiles
+
+
+def add_sample(name: str, embedding: Sequence[float]) -> dict[str, Any]:
+    """Enroll one sample for ``name``, creating the profile if needed.
+
+    An existing profile (matched case-insensitively) folds the new sample
+    into its running mean, weighted by the samples it already holds.
+    Returns the profile row without its embedding.
+    """
+    sample = _normalized(embedding)
+    now = _now_iso()
+    with get_connection() as conn:
+        row = conn.execute(
+            "SELECT id, embedding, sample_count FROM voice_profiles WHERE name = ?",
+            (name,),
+        ).fetchone()
+        if row is None:
+            cursor = conn.execute(
+                """
+                INSERT INTO voice_profiles (name, embedding, sample_count, created_at, updated_at)
+                VALUES (?, ?, 1, ?, ?)
+                """,
+                (name, _pack(sample), now, now),
+            )
+            profile_id = cursor.lastrowid
+        else:
+            current = _unpack(row["embedding"])
+            if len(current) != len(sample):
+                raise ValueError(
+                    f"embedding size {len(sample)} does not match the enrolled "
+                    f"{len(current)} — was the speaker model changed?"
+                )
+            count = int(row["sample_count"])
+            merged = _normalized([(c * count + s) / (count + 1) for c, s in zip(current, sample)])
+            conn.execute(
+                """
+                UPDATE voice_profiles
+                SET embedding = ?, sample_count = ?, updated_at = ?
+                WHERE id = ?
+                """,
+                (_pack(merged), count + 1, now, row["id"]),
+            )
+            profile_id = row["id"]
+        conn.commit()
+        result = conn.execute(
+            """
+            SELECT id, name, sample_count, created_at, updated_at
+            FROM voice_profiles WHERE id = ?
+            """,
+            (profile_id,),
+        ).fetchone()
+    return dict(result)
+
+
+def rename_profile(profile_id: int, name: str) -> bool:
+    """Rename a profile; False when it does not exist.
+
+    Raises ``sqlite3.IntegrityError`` when another profile has that name.
+    """
+    with get_connection() as conn:
+        cursor = conn.execute(
+            "UPDATE voice_profiles SET name = ?, updated_at = ? WHERE id = ?",
+            (name, _now_iso(), profile_id),
+        )
+        conn.commit()
+    return cursor.rowcount > 0
+
+
+def delete_profile(profile_id: int) -> bool:
+    with get_connection() as conn:
+        cursor = conn.execute("DELETE FROM voice_profiles WHERE id = ?", (profile_id,))
+        conn.commit()
+    return cursor.rowcount > 0
@@ -0,0 +1,87 @@
+"""Screen-reader support: live announcement throttling and the accessible export."""
+
+from __future__ import annotations
+
+from server.core.accessibility import (
+    LiveAnnouncer,
+    spoken_duration,
+    state_announcement,
+    stream_accessible,
+)
+
+
+class _Clock:
+    def __init__(self) -> None:
+        self.now = 100.0
+
+    def __call__(self) -> float:
+        return self.now
+
+
+def test_announcer_batches_sentences_within_the_interval() -> None:
+    clock = _Clock()
+    announcer = LiveAnnouncer(min_interval=2.0, clock=clock)
+    assert announcer.push("Hello there.") == {
+        "text": "Hello there.",
+        "politeness": "polite",
+        "sentences": 1,
+    }
+    assert announcer.push("How are you?") is None
+    clock.now += 0.5
+    assert announcer.push("  Fine,   thanks. ") is None
+    assert announcer.due_in() == 1.5
+    clock.now += 1.5
+    assert announcer.due_in() == 0
+    assert announcer.flush() == {
+        "text": "How are you? Fine, thanks.",
+        "politeness": "polite",
+        "sentences": 2,
+    }
+    assert announcer.flush() is None
+    assert announcer.push("   ") is None
+
+
+def test_announcer_keeps_the_newest_words_when_over_budget() -> None:
+    announcer = LiveAnnouncer(min_interval=0, max_chars=40, clock=_Clock())
+    announcement = announcer.push("one two three four five six seven eight nine ten eleven twelve")
+    assert announcement is not None
+    assert announcement["text"] == "six seven eight nine ten eleven twelve"
+
+
+def test_state_announcements() -> None:
+    assert state_announcement("ERROR") == {
+        "text": "Live transcription error",
+        "politeness": "assertive",
+        "sentences": 0,
+    }
+    assert state_announcement("PROCESSING") is None
+
+
+def test_accessible_export_names_speakers_without_markup_or_timestamps() -> None:
+    recording = {
+        "title": "Standup",
+        "recorded_at": "2026-05-08T10:00:00Z",
+        "duration_seconds": 3725,
+    }
+    segments = [
+        {"speaker": "Alice", "text": "Morning.", "start": 0.0},
+        {"speaker": "Alice", "text": "Quick one today.", "start": 1.0},
+        {"speaker": "Bob", "text": "  Sounds   good. ", "start": 3.0},
+        {"speaker": None, "text": "[music]"},
+        {"speaker": "Bob", "text": ""},
+    ]
+    out = "".join(stream_accessible(recording, segments))
+    assert out == (
+        "Standup.\n"
+        "Recorded 8 May 2026. Duration 1 hour 2 minutes.\n\n"
+        "Alice: Morning. Quick one today.\n\n"
+        "Bob: Sounds good.\n\n"
+        "[music]\n"
+    )
+    assert "**" not in out and "-->" not in out
+
+
+def test_spoken_duration() -> None:
+    assert spoken_duration(42) == "42 seconds"
+    assert spoken_duration(61) == "1 minute"
+    assert spoken_duration(7200) == "2 hours"
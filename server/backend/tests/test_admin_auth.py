@@ -144,3 +144,69 @@ def test_models_load_stream_requires_admin_websocket(test_client_tls, admin_toke
             assert "invalid or expired token" not in lower
         else:
             assert msg["type"] in {"progress", "complete"}
+
+
+def test_permissions_reflect_token_role(test_client_tls, admin_token, user_token):
+    """/api/auth/permissions reports admin capabilities per token."""
+    response = test_client_tls.get(
+        "/api/auth/permissions", headers={"Authorization": f"Bearer {admin_token}"}
+    )
+    assert response.status_code == 200
+    body = response.json()
+    assert body["is_admin"] is True
+    assert body["permissions"]["can_manage_models"] is True
+
+    response = test_client_tls.get(
+        "/api/auth/permissions", headers={"Authorization": f"Bearer {user_token}"}
+    )
+    assert response.status_code == 200
+    body = response.json()
+    assert body["is_admin"] is False
+    assert not any(body["permissions"].values())
+
+
+def test_rotate_token_issues_new_secret(test_client_tls, admin_token):
+    """Rotating a token revokes it and returns a replacement for the same client."""
+    headers = {"Authorization": f"Bearer {admin_token}"}
+    created = test_client_tls.post(
+        "/api/auth/tokens", headers=headers, json={"client_name": "pytest-rotate"}
+    ).json()["token"]
+
+    response = test_client_tls.post(
+        f"/api/auth/tokens/{created['token_id']}/rotate", headers=headers
+    )
+    assert response.status_code == 200
+    rotated = response.json()["token"]
+    assert rotated["client_name"] == "pytest-rotate"
+    assert rotated["token"] != created["token"]
+
+    response = test_client_tls.get(
+        "/api/auth/permissions", headers={"Authorization": f"Bearer {created['token']}"}
+    )
+    assert response.status_code == 401
+
+
+def test_remove_user_requires_matching_confirmation(test_client_tls, admin_token):
+    """DELETE /api/auth/users/{name} only acts when confirm repeats the name."""
+    headers = {"Authorization": f"Bearer {admin_token}"}
+    test_client_tls.post("/api/auth/tokens", headers=headers, json={"client_name": "pytest-kid"})
+
+    response = test_client_tls.delete("/api/auth/users/pytest-kid?confirm=other", headers=headers)
+    assert response.status_code == 400
+
+    response = test_client_tls.delete(
+        "/api/auth/users/pytest-kid?confirm=pytest-kid", headers=headers
+    )
+    assert response.status_code == 200
+    assert response.json()["revoked"] == 1
+
+    users = test_client_tls.get("/api/auth/users", headers=headers).json()["users"]
+    kid = next(u for u in users if u["name"] == "pytest-kid")
+    assert kid["active_tokens"] == 0
+
+
+def test_usage_requires_admin(test_client_tls, user_token):
+    response = test_client_tls.get(
+        "/api/admin/usage", headers={"Authorization": f"Bearer {user_token}"}
+    )
+    assert response.status_code == 403
@@ -0,0 +1,108 @@
+"""Threaded review annotations: repository, threading and export rendering."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.core.annotation_export import annotation_cues, annotation_text_lines
+from server.core.subtitle_export import SubtitleCue, render_ass
+from server.database import annotation_repository as repo
+
+_SCHEMA_SQL = """
+CREATE TABLE recordings (id INTEGER PRIMARY KEY, filename TEXT);
+CREATE TABLE segments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_index INTEGER NOT NULL,
+    text TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL
+);
+CREATE TABLE annotations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL REFERENCES recordings(id) ON DELETE CASCADE,
+    segment_id INTEGER REFERENCES segments(id) ON DELETE SET NULL,
+    parent_id INTEGER REFERENCES annotations(id) ON DELETE CASCADE,
+    start_time REAL NOT NULL,
+    end_time REAL,
+    author TEXT NOT NULL,
+    body TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute("INSERT INTO recordings VALUES (1, 'a.mp3'), (2, 'b.mp3')")
+    conn.execute("INSERT INTO segments VALUES (5, 1, 0, 'Hello', 3.0, 6.5)")
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_segment_note_takes_the_segment_span(isolated_db) -> None:
+    note = repo.create_annotation(1, author="ana", body="Check name", segment_id=5)
+    assert (note["start_time"], note["end_time"], note["segment_id"]) == (3.0, 6.5, 5)
+    with pytest.raises(ValueError, match="segment 5"):
+        repo.create_annotation(2, author="ana", body="x", segment_id=5)
+    with pytest.raises(ValueError, match="start_time"):
+        repo.create_annotation(1, author="ana", body="x")
+
+
+def test_replies_attach_to_the_thread_root(isolated_db) -> None:
+    root = repo.create_annotation(1, author="ana", body="Is this right?", start_time=10.0)
+    reply = repo.create_annotation(1, author="ben", body="Yes", parent_id=root["id"])
+    nested = repo.create_annotation(1, author="ana", body="Thanks", parent_id=reply["id"])
+    assert nested["parent_id"] == root["id"]
+    assert nested["start_time"] == 10.0
+
+    threads = repo.build_threads(repo.list_annotations(1))
+    assert [t["id"] for t in threads] == [root["id"]]
+    assert [r["body"] for r in threads[0]["replies"]] == ["Yes", "Thanks"]
+
+    assert repo.delete_annotation(1, root["id"]) is True
+    assert repo.list_annotations(1) == []
+
+
+def test_update_is_scoped_to_the_recording(isolated_db) -> None:
+    note = repo.create_annotation(1, author="ana", body="typo", start_time=1.0)
+    assert repo.update_annotation(2, note["id"], "nope") is None
+    assert repo.update_annotation(1, note["id"], "fixed")["body"] == "fixed"
+
+
+_THREADS = [
+    {
+        "start_time": 65.0,
+        "end_time": None,
+        "author": "ana",
+        "body": "Check the name",
+        "created_at": "2026-10-01T09:00:00+00:00",
+        "replies": [{"author": "ben", "body": "It's Zoë", "created_at": "2026-10-02T10:00:00"}],
+    }
+]
+
+
+def test_text_export_lists_notes_with_replies() -> None:
+    assert annotation_text_lines(_THREADS) == [
+        "[01:05] ana (2026-10-01): Check the name",
+        "    ↳ ben (2026-10-02): It's Zoë",
+    ]
+
+
+def test_ass_export_writes_comment_events() -> None:
+    content = render_ass(
+        [SubtitleCue(start=0.0, end=1.0, text="Hi")], "T", comments=annotation_cues(_THREADS)
+    )
+    expected = "Comment: 0,0:01:05.00,0:01:07.00,Default,ana,0,0,0,,Check the name\\Nben: It's Zoë"
+    assert expected in content.splitlines()
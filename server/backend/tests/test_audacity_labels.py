@@ -0,0 +1,153 @@
+"""Audacity label-track export/import and label-driven re-segmentation."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.core.audacity_labels import (
+    parse_audacity_labels,
+    plan_resegmentation,
+    render_audacity_labels,
+)
+
+_SEGMENTS = [
+    {"id": 10, "speaker": "SPEAKER_00", "text": "Hello there.", "start_time": 0.0},
+    {"id": 11, "speaker": "SPEAKER_01", "text": "Hi, how are you?", "start_time": 1.2},
+]
+_SEGMENTS[0]["end_time"] = 1.0
+_SEGMENTS[1]["end_time"] = 2.6
+_WORDS = [
+    {"id": 1, "segment_id": 10, "word": "Hello", "start_time": 0.0, "end_time": 0.4},
+    {"id": 2, "segment_id": 10, "word": "there.", "start_time": 0.5, "end_time": 1.0},
+    {"id": 3, "segment_id": 11, "word": "Hi,", "start_time": 1.2, "end_time": 1.4},
+    {"id": 4, "segment_id": 11, "word": "how", "start_time": 1.6, "end_time": 1.8},
+    {"id": 5, "segment_id": 11, "word": "are", "start_time": 1.9, "end_time": 2.1},
+    {"id": 6, "segment_id": 11, "word": "you?", "start_time": 2.2, "end_time": 2.6},
+]
+
+
+def test_export_writes_tab_separated_region_labels() -> None:
+    out = render_audacity_labels(_SEGMENTS, label_map={"SPEAKER_00": "Elena"})
+    assert out.splitlines() == [
+        "0.000000\t1.000000\tElena: Hello there.",
+        "1.200000\t2.600000\tSPEAKER_01: Hi, how are you?",
+    ]
+
+
+def test_parse_skips_spectral_lines_and_sorts() -> None:
+    labels = parse_audacity_labels("2.0\t3.0\tB\r\n\\\t100.0\t2000.0\n0.5\t0.5\tpoint\n")
+    assert [(lab.start, lab.end, lab.text) for lab in labels] == [
+        (0.5, 0.5, "point"),
+        (2.0, 3.0, "B"),
+    ]
+
+
+@pytest.mark.parametrize("content", ["garbage", "1.0\tx\tlabel", "3.0\t1.0\tbackwards"])
+def test_parse_rejects_malformed_lines(content: str) -> None:
+    with pytest.raises(ValueError, match="line 1"):
+        parse_audacity_labels(content)
+
+
+def test_region_labels_become_boundaries_without_losing_words() -> None:
+    labels = parse_audacity_labels("0.0\t1.5\tA\n1.5\t2.6\tB\n")
+    planned = plan_resegmentation(labels, _SEGMENTS, _WORDS)
+    assert [p.text for p in planned] == ["Hello there. Hi,", "how are you?"]
+    assert [p.speaker for p in planned] == ["SPEAKER_00", "SPEAKER_01"]
+    assert sorted(w for p in planned for w in p.word_ids) == [1, 2, 3, 4, 5, 6]
+
+
+def test_point_labels_cut_the_timeline() -> None:
+    labels = parse_audacity_labels("1.1\t1.1\tcut\n")
+    planned = plan_resegmentation(labels, _SEGMENTS, [], duration=3.0)
+    assert [(p.start, p.end, p.text) for p in planned] == [
+        (0.0, 1.1, "Hello there."),
+        (1.1, 3.0, "Hi, how are you?"),
+    ]
+
+
+def test_empty_label_file_is_rejected() -> None:
+    with pytest.raises(ValueError):
+        plan_resegmentation([], _SEGMENTS, _WORDS)
+
+
+_SCHEMA_SQL = """
+CREATE TABLE recordings (id INTEGER PRIMARY KEY, filename TEXT, filepath TEXT);
+CREATE TABLE segments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_index INTEGER NOT NULL,
+    speaker TEXT,
+    text TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    FOREIGN KEY (recording_id) REFERENCES recordings(id) ON DELETE CASCADE
+);
+CREATE TABLE words (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_id INTEGER NOT NULL,
+    word_index INTEGER NOT NULL,
+    word TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    confidence REAL,
+    FOREIGN KEY (segment_id) REFERENCES segments(id) ON DELETE CASCADE
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute("INSERT INTO recordings VALUES (1, 'a.mp3', '/a.mp3')")
+    for seg in _SEGMENTS:
+        conn.execute(
+            "INSERT INTO segments VALUES (?, 1, ?, ?, ?, ?, ?)",
+            (
+                seg["id"],
+                seg["id"] - 10,
+                seg["speaker"],
+                seg["text"],
+                seg["start_time"],
+                seg["end_time"],
+            ),
+        )
+    for i, w in enumerate(_WORDS):
+        conn.execute(
+            "INSERT INTO words VALUES (?, 1, ?, ?, ?, ?, ?, NULL)",
+            (w["id"], w["segment_id"], i, w["word"], w["start_time"], w["end_time"]),
+        )
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_replace_segments_reparents_words_before_deleting(isolated_db) -> None:
+    labels = parse_audacity_labels("0.0\t1.5\tA\n1.5\t2.6\tB\n")
+    planned = plan_resegmentation(labels, db.get_segments(1), db.get_words(1))
+    db.replace_segments(
+        1,
+        [
+            {
+                "text": p.text,
+                "start_time": p.start,
+                "end_time": p.end,
+                "speaker": p.speaker,
+                "word_ids": p.word_ids,
+            }
+            for p in planned
+        ],
+    )
+    segments = db.get_segments(1)
+    assert [s["text"] for s in segments] == ["Hello there. Hi,", "how are you?"]
+    words = db.get_words(1)
+    assert len(words) == 6
+    assert {w["segment_id"] for w in words} == {s["id"] for s in segments}
@@ -0,0 +1,152 @@
+"""Chromaprint copy detection: alignment, transcript shifting and library matching."""
+
+from __future__ import annotations
+
+import random
+import sqlite3
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.core import audio_fingerprint as fp
+from server.database import fingerprint_repository as repo
+from server.database.dedup_query import find_fingerprint_matches
+
+# ~60 s of sub-fingerprints.
+_ITEMS = 485
+
+
+def _fingerprint(seed: int, n: int = _ITEMS) -> list[int]:
+    rng = random.Random(seed)
+    return [rng.getrandbits(32) for _ in range(n)]
+
+
+def _reencode(items: list[int], seed: int = 7) -> list[int]:
+    """Flip one of the low bits in most items, as a lossy re-encode would."""
+    rng = random.Random(seed)
+    return [v ^ (1 << rng.randrange(8)) if rng.random() < 0.7 else v for v in items]
+
+
+def test_reencoded_copy_aligns_at_zero() -> None:
+    original = _fingerprint(1)
+
+    match = fp.align(_reencode(original), original)
+
+    assert match is not None
+    assert match.offset_seconds == 0
+    assert 0.95 < match.similarity < 1
+    assert match.overlap_seconds == pytest.approx(_ITEMS * fp.ITEM_SECONDS, abs=0.01)
+
+
+def test_trimmed_copy_reports_where_it_starts() -> None:
+    original = _fingerprint(2)
+    trimmed = _reencode(original[100:400])
+
+    match = fp.align(trimmed, original)
+
+    assert match is not None
+    assert match.offset_seconds == pytest.approx(100 * fp.ITEM_SECONDS, abs=0.001)
+    assert match.overlap_seconds == pytest.approx(300 * fp.ITEM_SECONDS, abs=0.01)
+
+
+def test_copy_with_extra_lead_in_has_a_negative_offset() -> None:
+    original = _fingerprint(3)
+    padded = _fingerprint(4, 50) + original
+
+    match = fp.align(padded, original)
+
+    assert match is not None
+    assert match.offset_seconds == pytest.approx(-50 * fp.ITEM_SECONDS, abs=0.001)
+
+
+def test_unrelated_or_short_audio_does_not_match() -> None:
+    assert fp.align(_fingerprint(5), _fingerprint(6)) is None
+    original = _fingerprint(7)
+    assert fp.align(original[:20], original) is None
+
+
+def test_pack_round_trips() -> None:
+    items = [0, 1, 0xFFFFFFFF, 123456789]
+    assert fp.unpack_fingerprint(fp.pack_fingerprint(items)) == items
+
+
+def test_compute_fingerprint_without_fpcalc_is_none(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setattr(fp.shutil, "which", lambda name: None)
+    assert fp.compute_fingerprint("/nonexistent.mp3") is None
+
+
+def test_shift_segments_moves_clips_and_drops_outside_the_copy() -> None:
+    segments = [
+        {"id": 1, "text": "Intro.", "start": 0.0, "end": 4.0, "words": []},
+        {
+            "id": 2,
+            "text": "Hello there.",
+            "start": 9.0,
+            "end": 12.0,
+            "words": [
+                {"word": "Hello", "start": 9.0, "end": 10.5},
+                {"word": "there.", "start": 10.5, "end": 12.0},
+            ],
+        },
+        {"id": 3, "text": "Outro.", "start": 30.0, "end": 32.0, "words": []},
+    ]
+
+    shifted = fp.shift_segments(segments, offset=10.0, duration=15.0)
+
+    assert [s["id"] for s in shifted] == [2]
+    assert (shifted[0]["start"], shifted[0]["end"]) == (0.0, 2.0)
+    assert [(w["word"], w["start"], w["end"]) for w in shifted[0]["words"]] == [
+        ("Hello", 0.0, 0.5),
+        ("there.", 0.5, 2.0),
+    ]
+
+
+_SCHEMA_SQL = """
+CREATE TABLE recordings (
+    id INTEGER PRIMARY KEY,
+    filename TEXT,
+    title TEXT,
+    imported_at TEXT,
+    recorded_at TEXT
+);
+CREATE TABLE recording_fingerprints (
+    recording_id INTEGER PRIMARY KEY REFERENCES recordings(id) ON DELETE CASCADE,
+    fingerprint BLOB NOT NULL,
+    duration_seconds REAL NOT NULL,
+    created_at TEXT NOT NULL
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute(
+        "INSERT INTO recordings VALUES "
+        "(1, 'talk.wav', 'Talk', '2025-01-15T12:00:00Z', NULL), "
+        "(2, 'other.wav', NULL, '2025-01-16T12:00:00Z', NULL)"
+    )
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_library_search_finds_the_trimmed_source(isolated_db) -> None:
+    talk = _fingerprint(8)
+    repo.save_fingerprint(1, fp.pack_fingerprint(talk), fp.fingerprint_seconds(talk))
+    other = _fingerprint(9)
+    repo.save_fingerprint(2, fp.pack_fingerprint(other), fp.fingerprint_seconds(other))
+
+    matches = find_fingerprint_matches(_reencode(talk[80:]))
+
+    assert [(m["id"], m["name"], m["source"]) for m in matches] == [("1", "Talk", "recording")]
+    assert matches[0]["offset_seconds"] == pytest.approx(80 * fp.ITEM_SECONDS, abs=0.001)
+    assert matches[0]["duration_seconds"] == pytest.approx(
+        (_ITEMS - 80) * fp.ITEM_SECONDS, abs=0.001
+    )
+    assert find_fingerprint_matches(_reencode(talk[80:]), exclude_ids={"1"}) == []
@@ -0,0 +1,144 @@
+"""Audit log of destructive actions: recording, range queries, append-only."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.api.routes.utils import audited_action
+from server.database import audit_repository as audit
+
+_SCHEMA_SQL = """
+CREATE TABLE audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    occurred_at TEXT NOT NULL,
+    action TEXT NOT NULL,
+    target_type TEXT,
+    target_id TEXT,
+    actor TEXT,
+    command TEXT NOT NULL,
+    details_json TEXT NOT NULL DEFAULT '{}'
+);
+CREATE TRIGGER audit_log_no_update BEFORE UPDATE ON audit_log
+BEGIN SELECT RAISE(ABORT, 'audit_log is append-only'); END;
+CREATE TRIGGER audit_log_no_delete BEFORE DELETE ON audit_log
+BEGIN SELECT RAISE(ABORT, 'audit_log is append-only'); END;
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute(
+        "INSERT INTO audit_log (occurred_at, action, target_type, target_id, command) VALUES "
+        "('2025-03-01T09:00:00+00:00', 'recording.delete', 'recording', '4', 'DELETE /a'), "
+        "('2025-03-31T23:30:00+00:00', 'redaction.create', 'recording', '5', 'POST /b'), "
+        "('2025-04-01T08:00:00+00:00', 'token.revoke', 'token', 'x1', 'DELETE /c')"
+    )
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+    return db_path
+
+
+def test_record_event_appends_with_actor_and_command(isolated_db) -> None:
+    event_id = audit.record_event(
+        "recording.delete",
+        command="DELETE /api/notebook/recordings/7?delete_artifacts=true",
+        actor="laptop/alice",
+        target_type="recording",
+        target_id=7,
+        details={"status": 200},
+    )
+
+    [event] = audit.get_audit_log(limit=1)
+    assert event["id"] == event_id
+    assert event["target_id"] == "7"
+    assert event["actor"] == "laptop/alice"
+    assert event["command"].endswith("?delete_artifacts=true")
+    assert event["details"] == {"status": 200}
+    assert event["occurred_at"] > "2025-04-01"
+
+
+def test_range_is_inclusive_and_a_bare_end_date_covers_the_day(isolated_db) -> None:
+    march = audit.get_audit_log("2025-03-01", "2025-03-31")
+    assert [e["action"] for e in march] == ["redaction.create", "recording.delete"]
+
+    assert len(audit.get_audit_log("2025-03-02", "2025-04-01T08:00:00Z")) == 2
+    assert audit.get_audit_log(end="2025-02-28") == []
+    with pytest.raises(ValueError):
+        audit.get_audit_log("last week")
+
+
+def test_action_filter_matches_exactly_or_by_prefix(isolated_db) -> None:
+    assert [e["target_id"] for e in audit.get_audit_log(action="token")] == ["x1"]
+    assert [e["target_id"] for e in audit.get_audit_log(action="recording.delete")] == ["4"]
+    assert audit.get_audit_log(action="record") == []
+
+
+def test_rows_cannot_be_updated_or_deleted(isolated_db) -> None:
+    conn = sqlite3.connect(str(isolated_db))
+    try:
+        with pytest.raises(sqlite3.IntegrityError, match="append-only"):
+            conn.execute("UPDATE audit_log SET actor = 'someone else'")
+        with pytest.raises(sqlite3.IntegrityError, match="append-only"):
+            conn.execute("DELETE FROM audit_log")
+    finally:
+        conn.close()
+    assert len(audit.get_audit_log()) == 3
+
+
+def test_reappend_restores_events_newer_than_the_backup(isolated_db, tmp_path) -> None:
+    backup = tmp_path / "backup.db"
+    with sqlite3.connect(str(isolated_db)) as src, sqlite3.connect(str(backup)) as dest:
+        src.backup(dest)
+    audit.record_event("database.restore", command="POST /api/notebook/restore")
+    events = audit.all_events()
+
+    isolated_db.unlink()
+    backup.rename(isolated_db)
+    assert len(audit.get_audit_log()) == 3
+
+    assert audit.reappend(events) == 1
+    assert audit.reappend(events) == 0
+    assert [e["id"] for e in audit.all_events()] == [e["id"] for e in events]
+
+
+def test_missing_table_reads_empty(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    sqlite3.connect(str(db_path)).close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+    assert audit.get_audit_log() == []
+    assert audit.record_event("settings.diarization", command="PATCH /x") is None
+
+
+def test_audited_routes_resolve_action_and_target() -> None:
+    assert audited_action("DELETE", "/api/notebook/recordings/12") == (
+        "recording.delete",
+        "recording",
+        "12",
+    )
+    assert audited_action("POST", "/api/notebook/recordings/3/segments/9/split") == (
+        "segment.split",
+        "recording",
+        "3",
+    )
+    assert audited_action("DELETE", "/api/auth/users/Zo%C3%AB") == ("user.delete", "user", "Zoë")
+    assert audited_action("PATCH", "/api/admin/diarization") == (
+        "settings.diarization",
+        None,
+        None,
+    )
+    assert audited_action("GET", "/api/notebook/recordings/12") is None
+    assert audited_action("PATCH", "/api/notebook/recordings/12/title") is None
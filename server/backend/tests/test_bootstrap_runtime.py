@@ -1886,6 +1886,35 @@ def test_main_still_trusts_baked_for_non_cpu_mismatch(
     assert resolved == "cu126"
 
 
+def test_main_resolves_baked_rocm_and_xpu_variants(
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """The AMD/Intel images bake rocm6.4 / xpu; an unset runtime env must not demote them."""
+    module = _load_bootstrap_module()
+    for variant in ("rocm6.4", "xpu"):
+        variant_dir = tmp_path / variant
+        variant_dir.mkdir()
+        resolved = _invoke_main_resolving_variant(
+            module, monkeypatch, variant_dir, baked_variant=variant, env_variant=variant
+        )
+        assert resolved == variant
+
+
+def test_run_dependency_sync_gpu_vendor_variants_target_their_index(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    """ROCm and XPU use the same name-reuse URL swap as cu126/cpu."""
+    module = _load_bootstrap_module()
+    for variant in ("rocm6.4", "xpu"):
+        cmd = _capture_run_dependency_sync_cmd(monkeypatch, module, pytorch_variant=variant)
+
+        assert "--frozen" not in cmd
+        idx = cmd.index("--index")
+        assert cmd[idx + 1] == f"pytorch-cu129=https://download.pytorch.org/whl/{variant}"
+        assert cmd[cmd.index("--index-strategy") + 1] == "unsafe-best-match"
+
+
 # ---------------------------------------------------------------------------
 # GH #125 (reopen) — VibeVoice git-clone regression + git/CA TLS propagation
 # ---------------------------------------------------------------------------
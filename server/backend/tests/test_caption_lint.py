@@ -0,0 +1,66 @@
+"""Caption linter: per-rule violations, profile overrides and the re-wrap fix."""
+
+from __future__ import annotations
+
+import pytest
+from server.core.caption_lint import get_profile, lint_cues, rewrap_cues, rewrap_text, summarize
+from server.core.subtitle_export import SubtitleCue
+
+NETFLIX = get_profile("netflix")
+_LONG = "We shipped the release on Friday, and nobody noticed the bug until Monday."
+
+
+def test_each_rule_reports_its_cue() -> None:
+    cues = [
+        SubtitleCue(0.0, 0.5, "Hi."),
+        SubtitleCue(0.55, 2.0, _LONG),
+        SubtitleCue(1.9, 10.0, "one\ntwo\nthree"),
+    ]
+    violations = lint_cues(cues, NETFLIX)
+    assert summarize(violations) == {
+        "min_duration": 1,
+        "min_gap": 1,
+        "line_length": 1,
+        "reading_speed": 1,
+        "overlap": 1,
+        "line_count": 1,
+        "max_duration": 1,
+    }
+    line_rule = next(v for v in violations if v.rule == "line_length")
+    assert (line_rule.cue_index, line_rule.line, line_rule.severity) == (1, 1, "error")
+    assert line_rule.to_dict()["limit"] == 42
+
+
+def test_rewrap_balances_and_prefers_punctuation() -> None:
+    assert rewrap_text(_LONG, NETFLIX) == (
+        "We shipped the release on Friday,\nand nobody noticed the bug until Monday."
+    )
+    assert rewrap_text("short\nline", NETFLIX) == "short line"
+    fixed = rewrap_cues([SubtitleCue(0.0, 6.0, _LONG)], NETFLIX)
+    assert [v.rule for v in lint_cues(fixed, NETFLIX)] == []
+    assert (fixed[0].start, fixed[0].end) == (0.0, 6.0)
+
+
+def test_text_too_long_for_the_profile_is_left_for_the_editor() -> None:
+    wrapped = rewrap_text(_LONG * 2, NETFLIX)
+    assert len(wrapped.splitlines()) > NETFLIX.max_lines
+    assert all(len(line) <= 42 for line in wrapped.splitlines())
+
+
+def test_profile_overrides() -> None:
+    assert get_profile("netflix", {"netflix": {"max_cps": 17}}).max_cps == 17
+    custom = {
+        "kids": {
+            "max_chars_per_line": 30,
+            "max_lines": 2,
+            "min_duration": 1.5,
+            "max_duration": 6.0,
+            "max_cps": 13,
+            "min_gap": 0.1,
+        }
+    }
+    assert get_profile("KIDS", custom).max_chars_per_line == 30
+    with pytest.raises(ValueError, match="Unknown caption profile"):
+        get_profile("teletext")
+    with pytest.raises(ValueError, match="caption profile 'odd'"):
+        get_profile("odd", {"odd": {"max_lines": 2}})
@@ -0,0 +1,122 @@
+"""Chaptering: pause / topic-shift boundaries, titles and the outline repository."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.core.chaptering import parse_llm_titles, segment_into_chapters
+from server.database import chapter_repository as repo
+
+_GARDEN = "the tomatoes need compost and the garden beds need watering before the frost"
+_BUDGET = "the budget forecast shows invoices and payroll costs rising this quarter"
+
+
+def _talk(text: str, start: float, count: int, step: float = 10.0) -> list[dict]:
+    return [
+        {"text": text, "start_time": start + k * step, "end_time": start + k * step + step}
+        for k in range(count)
+    ]
+
+
+def test_topic_shift_starts_a_chapter_titled_by_its_keywords() -> None:
+    segments = _talk(_GARDEN, 0.0, 30) + _talk(_BUDGET, 300.0, 30)
+    chapters = segment_into_chapters(segments, min_chapter_seconds=60)
+    assert [(c.start_time, c.end_time) for c in chapters] == [(0.0, 300.0), (300.0, 600.0)]
+    assert [c.title for c in chapters] == ["Need, tomatoes, compost", "Budget, forecast, shows"]
+    assert all(c.source == "auto" for c in chapters)
+
+
+def test_long_pause_starts_a_chapter_even_on_the_same_topic() -> None:
+    segments = _talk(_GARDEN, 0.0, 20) + _talk(_GARDEN, 230.0, 20)
+    chapters = segment_into_chapters(segments, pause_seconds=8.0, min_chapter_seconds=60)
+    assert [c.start_time for c in chapters] == [0.0, 230.0]
+
+
+def test_boundaries_respect_min_chapter_length_and_duration() -> None:
+    segments = _talk(_GARDEN, 0.0, 3) + _talk(_BUDGET, 40.0, 30)
+    assert segment_into_chapters(segments, min_chapter_seconds=60) == []
+    long = _talk(_GARDEN, 0.0, 30) + _talk(_BUDGET, 300.0, 30)
+    assert segment_into_chapters(long, min_chapter_seconds=60, min_duration=900) == []
+    assert segment_into_chapters([], min_chapter_seconds=60) == []
+
+
+def test_llm_titles_are_parsed_line_by_line() -> None:
+    assert parse_llm_titles('1. "Garden prep"\n\n2) Budget review\n', 2) == [
+        "Garden prep",
+        "Budget review",
+    ]
+    assert parse_llm_titles("Sure! Here are the titles:\nGarden\nBudget", 2) is None
+
+
+_SCHEMA_SQL = """
+CREATE TABLE recordings (id INTEGER PRIMARY KEY, filename TEXT);
+CREATE TABLE chapters (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL REFERENCES recordings(id) ON DELETE CASCADE,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    title TEXT NOT NULL,
+    source TEXT NOT NULL DEFAULT 'auto',
+    created_at TEXT NOT NULL
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute("INSERT INTO recordings VALUES (1, 'a.mp3'), (2, 'b.mp3')")
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_replace_swaps_the_whole_outline(isolated_db) -> None:
+    repo.replace_chapters(
+        1,
+        [
+            {"start_time": 300.0, "end_time": 600.0, "title": "Budget", "source": "llm"},
+            {"start_time": 0.0, "end_time": 300.0, "title": "Garden"},
+        ],
+    )
+    repo.replace_chapters(2, [{"start_time": 0.0, "end_time": 60.0, "title": "Other"}])
+    assert [(c["title"], c["source"]) for c in repo.list_chapters(1)] == [
+        ("Garden", "auto"),
+        ("Budget", "llm"),
+    ]
+    repo.replace_chapters(1, [{"start_time": 0.0, "end_time": 600.0, "title": "All"}])
+    assert [c["title"] for c in repo.list_chapters(1)] == ["All"]
+    assert repo.clear_chapters(1) == 1
+    assert repo.list_chapters(1) == []
+    assert [c["title"] for c in repo.list_chapters(2)] == ["Other"]
+
+
+def test_replace_rejects_bad_chapters_without_touching_the_outline(isolated_db) -> None:
+    repo.replace_chapters(1, [{"start_time": 0.0, "end_time": 60.0, "title": "Kept"}])
+    with pytest.raises(ValueError, match="invalid chapter range"):
+        repo.replace_chapters(1, [{"start_time": 5.0, "end_time": 5.0, "title": "x"}])
+    with pytest.raises(ValueError, match="title"):
+        repo.replace_chapters(1, [{"start_time": 0.0, "end_time": 5.0, "title": "  "}])
+    with pytest.raises(ValueError, match="unknown chapter source"):
+        repo.replace_chapters(
+            1, [{"start_time": 0.0, "end_time": 5.0, "title": "x", "source": "ai"}]
+        )
+    assert [c["title"] for c in repo.list_chapters(1)] == ["Kept"]
+
+
+def test_missing_table_reads_as_empty_outline(tmp_path: Path, monkeypatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    sqlite3.connect(str(db_path)).close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+    assert repo.list_chapters(1) == []
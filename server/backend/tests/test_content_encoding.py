@@ -0,0 +1,146 @@
+"""Tests for ContentEncodingMiddleware — gzip request bodies and JSON responses.
+
+Covers:
+- gzip request bodies reach the route decompressed, in bounded pieces
+- corrupt or truncated bodies fail with 400; unknown encodings with 415
+- JSON responses are compressed only when the client accepts gzip
+- small, non-JSON and streamed responses pass through untouched
+"""
+
+from __future__ import annotations
+
+import asyncio
+import gzip
+import json
+
+import pytest
+from fastapi import HTTPException
+from server.api import content_encoding
+from server.api.content_encoding import ContentEncodingMiddleware, accepts_gzip
+
+
+def _scope(headers: dict[str, str]) -> dict:
+    return {
+        "type": "http",
+        "headers": [(k.lower().encode(), v.encode()) for k, v in headers.items()],
+    }
+
+
+def _run(app, headers: dict[str, str], chunks: list[bytes]) -> list[dict]:
+    """Call the middleware-wrapped ``app`` with a body split into ``chunks``."""
+    messages = [
+        {"type": "http.request", "body": c, "more_body": i < len(chunks) - 1}
+        for i, c in enumerate(chunks)
+    ]
+    sent: list[dict] = []
+
+    async def receive() -> dict:
+        return messages.pop(0)
+
+    async def send(message: dict) -> None:
+        sent.append(message)
+
+    asyncio.run(ContentEncodingMiddleware(app)(_scope(headers), receive, send))
+    return sent
+
+
+def _echo_app(received: list[bytes], seen_headers: list[dict] | None = None):
+    """Reads the whole body, then answers with an empty 204."""
+
+    async def app(scope, receive, send) -> None:
+        if seen_headers is not None:
+            seen_headers.append(dict(scope["headers"]))
+        while True:
+            message = await receive()
+            received.append(message["body"])
+            if not message["more_body"]:
+                break
+        await send({"type": "http.response.start", "status": 204, "headers": []})
+        await send({"type": "http.response.body", "body": b""})
+
+    return app
+
+
+def _json_app(payload: bytes, content_type: bytes = b"application/json", more: bool = False):
+    async def app(scope, receive, send) -> None:
+        headers = [(b"content-type", content_type), (b"content-length", b"%d" % len(payload))]
+        await send({"type": "http.response.start", "status": 200, "headers": headers})
+        await send({"type": "http.response.body", "body": payload, "more_body": more})
+        if more:
+            await send({"type": "http.response.body", "body": b"", "more_body": False})
+
+    return app
+
+
+def test_gzip_request_body_is_decoded_in_bounded_pieces(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setattr(content_encoding, "DECODE_PIECE_BYTES", 1000)
+    original = bytes(range(256)) * 40
+    compressed = gzip.compress(original)
+    received: list[bytes] = []
+    seen: list[dict] = []
+
+    _run(
+        _echo_app(received, seen),
+        {"Content-Encoding": "gzip", "Content-Length": str(len(compressed))},
+        [compressed[:100], compressed[100:]],
+    )
+
+    assert b"".join(received) == original
+    assert max(len(piece) for piece in received) <= 1000
+    assert b"content-encoding" not in seen[0]
+    assert b"content-length" not in seen[0]
+
+
+def test_corrupt_and_truncated_bodies_are_rejected() -> None:
+    compressed = gzip.compress(b"x" * 5000)
+
+    with pytest.raises(HTTPException) as exc:
+        _run(_echo_app([]), {"Content-Encoding": "gzip"}, [b"not gzip at all"])
+    assert exc.value.status_code == 400
+
+    with pytest.raises(HTTPException, match="truncated"):
+        _run(_echo_app([]), {"Content-Encoding": "gzip"}, [compressed[:-10]])
+
+
+def test_unknown_encoding_is_refused() -> None:
+    received: list[bytes] = []
+    sent = _run(_echo_app(received), {"Content-Encoding": "br"}, [b"data"])
+
+    assert sent[0]["status"] == 415
+    assert "br" in json.loads(sent[1]["body"])["detail"]
+    assert received == []
+
+
+def test_json_responses_are_gzipped_for_clients_that_accept_it() -> None:
+    payload = json.dumps({"segments": ["hello world"] * 200}).encode()
+
+    sent = _run(_json_app(payload), {"Accept-Encoding": "gzip, deflate, br"}, [b""])
+    headers = dict(sent[0]["headers"])
+    assert headers[b"content-encoding"] == b"gzip"
+    assert int(headers[b"content-length"]) == len(sent[1]["body"])
+    assert gzip.decompress(sent[1]["body"]) == payload
+
+    sent = _run(_json_app(payload), {"Accept-Encoding": "gzip;q=0"}, [b""])
+    assert sent[1]["body"] == payload
+
+
+@pytest.mark.parametrize(
+    ("payload", "content_type", "more"),
+    [
+        (b'{"ok": true}', b"application/json", False),
+        (b"\x00" * 5000, b"audio/wav", False),
+        (b'{"a": "' + b"x" * 5000 + b'"}', b"application/json", True),
+    ],
+)
+def test_other_responses_pass_through(payload: bytes, content_type: bytes, more: bool) -> None:
+    sent = _run(_json_app(payload, content_type, more), {"Accept-Encoding": "gzip"}, [b""])
+
+    assert b"content-encoding" not in dict(sent[0]["headers"])
+    assert sent[1]["body"] == payload
+
+
+def test_accepts_gzip() -> None:
+    assert accepts_gzip("gzip, deflate")
+    assert accepts_gzip("*")
+    assert not accepts_gzip("identity")
+    assert not accepts_gzip("gzip;q=0, br")
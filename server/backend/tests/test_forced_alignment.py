@@ -0,0 +1,143 @@
+"""Re-alignment of an edited transcript onto the recording's word timings."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.core.forced_alignment import plan_realignment, transfer_timings
+
+_SEGMENTS = [
+    {"id": 10, "speaker": "SPEAKER_00", "text": "Hello there.", "start_time": 0.0},
+    {"id": 11, "speaker": "SPEAKER_01", "text": "Hi, how are you?", "start_time": 1.2},
+]
+_SEGMENTS[0]["end_time"] = 1.0
+_SEGMENTS[1]["end_time"] = 2.6
+_WORDS = [
+    {"id": i, "segment_id": seg, "word": word, "start_time": s, "end_time": e, "confidence": c}
+    for i, seg, word, s, e, c in (
+        (1, 10, "Hello", 0.0, 0.4, 0.9),
+        (2, 10, "there.", 0.5, 1.0, 0.8),
+        (3, 11, "Hi,", 1.2, 1.4, 0.7),
+        (4, 11, "how", 1.6, 1.8, 0.6),
+        (5, 11, "are", 1.9, 2.1, 0.5),
+        (6, 11, "you?", 2.2, 2.6, 0.4),
+    )
+]
+
+
+def test_unchanged_words_keep_timings_and_confidence() -> None:
+    out = transfer_timings(_WORDS[:2], ["hello", "there!"])
+    assert [(w["word"], w["start"], w["end"], w["confidence"]) for w in out] == [
+        ("hello", 0.0, 0.4, 0.9),
+        ("there!", 0.5, 1.0, 0.8),
+    ]
+
+
+def test_replaced_words_are_spread_over_the_old_span() -> None:
+    out = transfer_timings(_WORDS[2:], ["Hi,", "who", "is", "you?"])
+    assert [w["word"] for w in out] == ["Hi,", "who", "is", "you?"]
+    middle = out[1:3]
+    assert middle[0]["start"] == 1.6
+    assert middle[-1]["end"] == 2.1
+    assert all(w["confidence"] is None for w in middle)
+    assert out[-1]["start"] == 2.2
+
+
+def test_insert_at_end_uses_the_span_bound() -> None:
+    out = transfer_timings(_WORDS[:2], ["Hello", "there.", "friend"], span=(0.0, 1.5))
+    assert (out[-1]["start"], out[-1]["end"]) == (1.0, 1.5)
+
+
+def test_one_line_per_segment_preserves_speakers() -> None:
+    planned = plan_realignment("Hello there.\nHi, who are you?", _SEGMENTS, _WORDS)
+    assert [(p["id"], p["speaker"], p["text"]) for p in planned] == [
+        (10, "SPEAKER_00", "Hello there."),
+        (11, "SPEAKER_01", "Hi, who are you?"),
+    ]
+    assert planned[1]["words"][1]["start"] == 1.6
+
+
+def test_reflowed_text_follows_the_replaced_words() -> None:
+    text = "Hello there. Hi, how are you?\n\nExtra line.\nMore"
+    planned = plan_realignment(text, _SEGMENTS, _WORDS)
+    assert [p["id"] for p in planned] == [10, 11]
+    assert planned[1]["text"].endswith("you? Extra line. More")
+
+
+def test_wordless_recording_gets_interpolated_words() -> None:
+    planned = plan_realignment("Hello.\nHi!", _SEGMENTS, [])
+    assert [p["text"] for p in planned] == ["Hello.", "Hi!"]
+    assert planned[1]["words"][0]["start"] == 1.2
+
+
+_SCHEMA_SQL = """
+CREATE TABLE recordings (
+    id INTEGER PRIMARY KEY, filename TEXT, filepath TEXT,
+    word_count INTEGER, transcript_corrected TEXT
+);
+CREATE TABLE segments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_index INTEGER NOT NULL,
+    speaker TEXT,
+    text TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL
+);
+CREATE TABLE words (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_id INTEGER NOT NULL,
+    word_index INTEGER NOT NULL,
+    word TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    confidence REAL
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute("INSERT INTO recordings VALUES (1, 'a.mp3', '/a.mp3', 6, 'edited')")
+    for index, seg in enumerate(_SEGMENTS):
+        conn.execute(
+            "INSERT INTO segments VALUES (?, 1, ?, ?, ?, ?, ?)",
+            (seg["id"], index, seg["speaker"], seg["text"], seg["start_time"], seg["end_time"]),
+        )
+    for i, w in enumerate(_WORDS):
+        conn.execute(
+            "INSERT INTO words VALUES (?, 1, ?, ?, ?, ?, ?, ?)",
+            (w["id"], w["segment_id"], i, w["word"], w["start_time"], w["end_time"], None),
+        )
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_rewrite_replaces_only_the_words(isolated_db) -> None:
+    text = "Hello there.\nHi, who are you?"
+    planned = plan_realignment(text, db.get_segments(1), db.get_words(1))
+    assert db.rewrite_segment_words(1, planned) == 6
+    assert [w["word"] for w in db.get_words(1)][3] == "who"
+    assert [s["text"] for s in db.get_segments(1)] == ["Hello there.", "Hi, how are you?"]
+    recording = db.get_recording(1)
+    assert recording["word_count"] == 6
+    assert recording["transcript_corrected"] == "edited"
+
+
+def test_rewrite_refuses_a_plan_that_drops_segments(isolated_db) -> None:
+    planned = plan_realignment("Hello there.", db.get_segments(1)[:1], db.get_words(1))
+    with pytest.raises(ValueError, match="drop 1 segment"):
+        db.rewrite_segment_words(1, planned)
+    assert len(db.get_segments(1)) == 2
+    assert len(db.get_words(1)) == 6
@@ -0,0 +1,76 @@
+"""Golden set: WER drift per reference and the check runner."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import Any
+
+from server.core.golden_set import drift_report, run_golden_check
+
+
+def _ref(recording_id: int, **extra: Any) -> dict[str, Any]:
+    return {
+        "recording_id": recording_id,
+        "reference": "the quick brown fox jumps over the lazy dog",
+        "marked_at": "2026-01-01T00:00:00+00:00",
+        "title": f"Talk {recording_id}",
+        **extra,
+    }
+
+
+def _run(recording_id: int, wer: float | None, error: str | None = None) -> dict[str, Any]:
+    return {
+        "recording_id": recording_id,
+        "run_at": "2026-01-02T03:00:00+00:00",
+        "model": "large-v3",
+        "server_version": "1.0.0",
+        "wer": wer,
+        "error": error,
+    }
+
+
+def test_drift_compares_latest_with_first_scored_run() -> None:
+    report = drift_report(
+        [_ref(1), _ref(2)],
+        [_run(1, 0.05), _run(2, 0.10), _run(1, None, "boom"), _run(1, 0.09), _run(2, 0.10)],
+    )
+
+    first, second = report["recordings"]
+    assert (first["baseline_wer"], first["latest_wer"], first["drift"]) == (0.05, 0.09, 0.04)
+    assert first["regressed"] and first["runs"] == 3 and first["last_error"] is None
+    assert first["reference_words"] == 9
+    assert (second["drift"], second["regressed"]) == (0.0, False)
+    assert (report["mean_baseline_wer"], report["mean_latest_wer"]) == (0.075, 0.095)
+    assert report["drift"] == 0.02
+    assert report["regressed"] is True
+
+
+def test_a_single_run_has_no_drift_yet() -> None:
+    report = drift_report([_ref(1)], [_run(1, 0.2)])
+
+    assert report["recordings"][0]["drift"] is None
+    assert report["drift"] is None
+    assert report["regressed"] is False
+
+
+def test_check_scores_each_reference_and_records_failures(tmp_path: Path) -> None:
+    audio = tmp_path / "talk.flac"
+    audio.write_bytes(b"fLaC")
+    recorded: list[tuple[int, dict[str, Any]]] = []
+
+    runs = run_golden_check(
+        [_ref(1, filepath=str(audio)), _ref(2, filepath=str(tmp_path / "gone.flac"))],
+        lambda path: "the quick brown fox jumps over the dog",
+        model="large-v3",
+        server_version="1.0.0",
+        record=lambda rid, **kw: recorded.append((rid, kw)),
+    )
+
+    assert runs == [
+        {"recording_id": 1, "wer": 0.1111, "error": None},
+        {"recording_id": 2, "wer": None, "error": "Recording audio is no longer available"},
+    ]
+    assert recorded[0] == (
+        1,
+        {"model": "large-v3", "server_version": "1.0.0", "wer": 0.1111, "error": None},
+    )
@@ -0,0 +1,86 @@
+"""Scheduled housekeeping actions (core/housekeeping.py)."""
+
+from __future__ import annotations
+
+import logging
+import logging.handlers
+import os
+from pathlib import Path
+
+from server.core.housekeeping import INCOMPLETE_GRACE_SECONDS, prune_model_cache, rotate_logs
+
+NOW = 2_000_000_000.0
+DAY = 86400
+
+
+def _repo(cache: Path, model_id: str, age_days: float) -> Path:
+    repo = cache / ("models--" + model_id.replace("/", "--"))
+    blob = repo / "blobs" / "abc123"
+    blob.parent.mkdir(parents=True)
+    blob.write_bytes(b"x" * 100)
+    stamp = NOW - age_days * DAY
+    os.utime(blob, (stamp, stamp))
+    return repo
+
+
+def test_prunes_only_unused_unconfigured_models(tmp_path: Path) -> None:
+    stale = _repo(tmp_path, "org/old-model", age_days=90)
+    fresh = _repo(tmp_path, "org/recent-model", age_days=2)
+    configured = _repo(tmp_path, "org/main-model", age_days=90)
+    diarization_helper = _repo(tmp_path, "pyannote/segmentation-3.0", age_days=90)
+
+    result = prune_model_cache(["org/main-model"], 30, cache_dir=tmp_path, now=NOW)
+
+    assert [entry["model"] for entry in result["removed"]] == ["org/old-model"]
+    assert result["freed_bytes"] == 100
+    assert not stale.exists()
+    assert fresh.exists() and configured.exists() and diarization_helper.exists()
+
+
+def test_configured_modelscope_id_keeps_its_hf_cache(tmp_path: Path) -> None:
+    repo = _repo(tmp_path, "FunAudioLLM/SenseVoiceSmall", age_days=90)
+
+    result = prune_model_cache(["iic/SenseVoiceSmall"], 30, cache_dir=tmp_path, now=NOW)
+
+    assert result["removed"] == []
+    assert repo.exists()
+
+
+def test_dry_run_and_stale_partial_downloads(tmp_path: Path) -> None:
+    repo = _repo(tmp_path, "org/main-model", age_days=1)
+    old_partial = repo / "blobs" / "def.incomplete"
+    old_partial.write_bytes(b"y" * 10)
+    stamp = NOW - INCOMPLETE_GRACE_SECONDS - 1
+    os.utime(old_partial, (stamp, stamp))
+    new_partial = repo / "blobs" / "ghi.incomplete"
+    new_partial.write_bytes(b"z" * 10)
+    os.utime(new_partial, (NOW, NOW))
+
+    preview = prune_model_cache(["org/main-model"], 30, dry_run=True, cache_dir=tmp_path, now=NOW)
+    assert preview["partial_bytes"] == 10
+    assert old_partial.exists()
+
+    prune_model_cache(["org/main-model"], 30, cache_dir=tmp_path, now=NOW)
+    assert not old_partial.exists()
+    assert new_partial.exists()
+
+
+def test_missing_cache_dir_is_a_no_op(tmp_path: Path) -> None:
+    result = prune_model_cache([], 30, cache_dir=tmp_path / "absent", now=NOW)
+    assert result["freed_bytes"] == 0
+
+
+def test_rotate_logs_rolls_rotating_handlers(tmp_path: Path) -> None:
+    log_path = tmp_path / "server.log"
+    handler = logging.handlers.RotatingFileHandler(log_path, backupCount=2)
+    root = logging.getLogger("housekeeping-test")
+    root.addHandler(handler)
+    try:
+        log_path.write_text("before\n")
+        result = rotate_logs(root)
+    finally:
+        root.removeHandler(handler)
+        handler.close()
+
+    assert result == {"rotated": [str(log_path)]}
+    assert (tmp_path / "server.log.1").read_text() == "before\n"
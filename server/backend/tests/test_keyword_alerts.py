@@ -0,0 +1,33 @@
+"""Keyword alerts: folding, whole-word phrases, regex entries and bad input."""
+
+from __future__ import annotations
+
+from server.core.keyword_alerts import KeywordWatcher, fold_with_offsets
+
+
+def test_fold_maps_back_to_original_characters() -> None:
+    folded, offsets = fold_with_offsets("Zoë Straße")
+    assert folded == "zoe strasse"
+    assert offsets[2] == 2 and offsets[-1] == 9 and offsets[-2] == 8
+
+
+def test_phrases_match_whole_words_ignoring_case_and_diacritics() -> None:
+    watcher = KeywordWatcher(["action item", "Zoe", "art"])
+    hits = watcher.match("OK, ACTION   Item for Zoë: restart the party.")
+    assert hits == [
+        {"keyword": "action item", "match": "ACTION   Item"},
+        {"keyword": "Zoe", "match": "Zoë"},
+    ]
+    assert watcher.match("nothing to see") == []
+
+
+def test_slash_delimited_entries_are_regular_expressions() -> None:
+    watcher = KeywordWatcher([r"/\$[a-z]{2,5}\b/", r"/\d+ percent/"])
+    hits = watcher.match("Watching $AAPL after a 12 percent drop")
+    assert [h["match"] for h in hits] == ["$AAPL", "12 percent"]
+
+
+def test_invalid_blank_and_duplicate_entries_are_skipped() -> None:
+    watcher = KeywordWatcher(["/(unclosed/", "", "   ", None, "budget", "budget"])
+    assert watcher.keywords == ["budget"]
+    assert not KeywordWatcher([])
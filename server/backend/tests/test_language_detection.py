@@ -0,0 +1,73 @@
+"""Language preview: windowing, silence skipping and multilingual flagging."""
+
+from __future__ import annotations
+
+import numpy as np
+from server.core.language_detection import (
+    detect_language_audio,
+    faster_whisper_language_probabilities,
+    summarize_windows,
+)
+
+RATE = 16000
+
+
+class _Backend:
+    """Answers each window from a script; records what it was asked."""
+
+    def __init__(self, answers: list[list[tuple[str, float]]], supported: bool = True) -> None:
+        self._answers = list(answers)
+        self._supported = supported
+        self.calls: list[int] = []
+
+    def supports_language_detection(self) -> bool:
+        return self._supported
+
+    def detect_language(self, audio, *, audio_sample_rate: int = RATE):
+        self.calls.append(len(audio))
+        return self._answers.pop(0)
+
+
+def _tone(seconds: float) -> np.ndarray:
+    return np.full(int(seconds * RATE), 0.1, dtype=np.float32)
+
+
+def test_single_language_sample() -> None:
+    backend = _Backend([[("de", 0.9), ("en", 0.1)], [("de", 0.8), ("nl", 0.2)]])
+    result = detect_language_audio(backend, _tone(20.0), RATE)
+    assert (result.language, result.multilingual) == ("de", False)
+    assert round(result.probability, 2) == 0.85
+    assert result.window_languages == ["de", "de"]
+    assert result.to_dict()["sampled_seconds"] == 20.0
+
+
+def test_confident_windows_that_disagree_flag_multilingual() -> None:
+    result = summarize_windows([[("en", 0.9)], [("fr", 0.95)], [("en", 0.4)]], 30.0)
+    assert result.multilingual is True
+    assert result.window_languages == ["en", "fr", None]
+
+
+def test_silent_windows_are_skipped_and_short_tail_is_folded() -> None:
+    audio = np.concatenate([np.zeros(10 * RATE, dtype=np.float32), _tone(13.0)])
+    backend = _Backend([[("es", 0.99)]])
+    result = detect_language_audio(backend, audio, RATE)
+    assert backend.calls == [13 * RATE]
+    assert result.window_languages == [None, "es"]
+
+
+def test_backend_without_detection_is_unsupported() -> None:
+    result = detect_language_audio(_Backend([], supported=False), _tone(5.0), RATE)
+    assert result.supported is False
+    assert result.language is None
+
+
+def test_faster_whisper_probabilities_are_sorted() -> None:
+    class _Model:
+        def detect_language(self, audio):
+            return "en", 0.7, [("fr", 0.2), ("en", 0.7), ("de", 0.1)]
+
+    assert faster_whisper_language_probabilities(_Model(), _tone(1.0)) == [
+        ("en", 0.7),
+        ("fr", 0.2),
+        ("de", 0.1),
+    ]
@@ -0,0 +1,117 @@
+"""Library export: filtering, progress events, unsupported formats and cancellation."""
+
+from __future__ import annotations
+
+import asyncio
+from pathlib import Path
+
+import pytest
+from server.core import library_export as lib
+from server.core.output_manager import OutputSettings, plan_outputs
+
+_LIBRARY = [
+    {"id": 1, "title": "Standup", "filename": "a.wav", "recorded_at": "2026-03-01T09:00:00"},
+    {"id": 2, "title": "Budget review", "filename": "b.wav", "recorded_at": "2026-03-05T09:00:00"},
+    {"id": 3, "title": "Standup", "filename": "c.wav", "recorded_at": "2026-02-20T09:00:00"},
+]
+
+
+def _run(plans, recordings, render, written, job=None, stop_after=None):
+    job = job or lib.start_job()
+
+    def write(path, content):
+        written[path] = content
+
+    async def collect():
+        events = []
+        async for event in lib.run_library_export(
+            job, plans, recordings, render=render, write=write
+        ):
+            events.append(event)
+            if stop_after is not None and event.get("done") == stop_after:
+                lib.cancel_active()
+        return events
+
+    return asyncio.run(collect())
+
+
+def _plan(recordings, formats, tmp_path: Path):
+    settings = OutputSettings.from_config({"root": str(tmp_path), "template": "{title}.{ext}"})
+    return plan_outputs(recordings, formats, settings), {r["id"]: r for r in recordings}
+
+
+def test_filter_by_date_title_and_ids_oldest_first() -> None:
+    march = lib.LibraryFilter(start_date="2026-03-01", end_date="2026-03-31")
+    assert [r["id"] for r in lib.select_recordings(_LIBRARY, march)] == [1, 2]
+    standups = lib.LibraryFilter(title="standUP")
+    assert [r["id"] for r in lib.select_recordings(_LIBRARY, standups)] == [3, 1]
+    assert [r["id"] for r in lib.select_recordings(_LIBRARY, lib.LibraryFilter())] == [3, 1, 2]
+    only = lib.LibraryFilter(recording_ids=(2,))
+    assert [r["id"] for r in lib.select_recordings(_LIBRARY, only)] == [2]
+
+
+def test_progress_events_and_template_named_files(tmp_path: Path) -> None:
+    library = lib.select_recordings(_LIBRARY, lib.LibraryFilter())
+    plans, recordings = _plan(library, ["srt"], tmp_path)
+
+    async def render(recording, fmt):
+        if recording["id"] == 2:
+            raise lib.UnsupportedExport("needs word timestamps")
+        return f"{recording['id']}.{fmt}".encode()
+
+    written: dict = {}
+    events = _run(plans, recordings, render, written)
+    assert events[0] == {"type": "start", "total": 3, "recordings": 3}
+    assert [e["status"] for e in events[1:-1]] == ["written", "written", "unsupported"]
+    assert [e["done"] for e in events[1:-1]] == [1, 2, 3]
+    # Same title twice → the collision policy numbers the second file.
+    assert sorted(Path(p).name for p in written) == ["Standup (2).srt", "Standup.srt"]
+    assert events[-1] == {
+        "type": "done",
+        "done": 3,
+        "total": 3,
+        "cancelled": False,
+        "written": 2,
+        "skipped": 0,
+        "unsupported": 1,
+        "failed": 0,
+    }
+
+
+def test_cancel_stops_after_the_current_target_and_frees_the_slot(tmp_path: Path) -> None:
+    plans, recordings = _plan(_LIBRARY, ["txt", "srt"], tmp_path)
+
+    async def render(recording, fmt):
+        return b"x"
+
+    written: dict = {}
+    events = _run(plans, recordings, render, written, stop_after=2)
+    assert len(written) == 2
+    assert events[-1]["cancelled"] is True and events[-1]["done"] == 2
+    assert lib.cancel_active() is False
+    lib.finish_job(lib.start_job())
+
+
+def test_only_one_library_export_at_a_time() -> None:
+    job = lib.start_job()
+    try:
+        with pytest.raises(RuntimeError):
+            lib.start_job()
+    finally:
+        lib.finish_job(job)
+    lib.finish_job(lib.start_job())
+
+
+def test_write_errors_are_reported_and_the_batch_continues(tmp_path: Path) -> None:
+    plans, recordings = _plan(_LIBRARY[:2], ["txt"], tmp_path)
+
+    async def render(recording, fmt):
+        if recording["id"] == 1:
+            raise OSError("disk full")
+        return b"ok"
+
+    events = _run(plans, recordings, render, {})
+    assert [(e["status"], e.get("error")) for e in events[1:-1]] == [
+        ("failed", "disk full"),
+        ("written", None),
+    ]
@@ -0,0 +1,99 @@
+"""Single-writer library ownership (server.database.library_lock)."""
+
+from __future__ import annotations
+
+import json
+import os
+import sqlite3
+import time
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.database import library_lock
+
+
+@pytest.fixture()
+def lock_state(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setattr(library_lock, "_state", library_lock._LockState())
+
+
+def _write_foreign_owner(db_path: Path, *, machine: str, age: float, pid: int = 4242) -> None:
+    library_lock.owner_path_for(db_path).write_text(
+        json.dumps(
+            {"owner_id": "other", "machine": machine, "pid": pid, "heartbeat_at": time.time() - age}
+        )
+    )
+
+
+def test_acquire_claims_an_unowned_library(tmp_path: Path, lock_state: None) -> None:
+    db_path = tmp_path / "notebook.db"
+
+    assert library_lock.acquire(db_path, machine="laptop")
+
+    owner = library_lock.read_owner(library_lock.owner_path_for(db_path))
+    assert owner is not None and owner.machine == "laptop" and owner.pid == os.getpid()
+    assert not library_lock.is_read_only()
+    library_lock.release()
+    assert not library_lock.owner_path_for(db_path).exists()
+
+
+def test_fresh_foreign_owner_opens_read_only_and_stale_one_does_not(
+    tmp_path: Path, lock_state: None
+) -> None:
+    db_path = tmp_path / "notebook.db"
+    _write_foreign_owner(db_path, machine="desktop", age=30)
+
+    assert not library_lock.acquire(db_path, machine="laptop", stale_after=300)
+    assert library_lock.status()["holder"]["machine"] == "desktop"
+
+    _write_foreign_owner(db_path, machine="desktop", age=600)
+    assert library_lock.acquire(db_path, machine="laptop", stale_after=300)
+
+
+def test_same_machine_crashed_run_with_our_pid_is_not_honoured(
+    tmp_path: Path, lock_state: None
+) -> None:
+    db_path = tmp_path / "notebook.db"
+    _write_foreign_owner(db_path, machine="box", age=5, pid=os.getpid())
+
+    assert library_lock.acquire(db_path, machine="box")
+
+
+def test_take_ownership_and_previous_owner_steps_down(
+    tmp_path: Path, lock_state: None
+) -> None:
+    db_path = tmp_path / "notebook.db"
+    _write_foreign_owner(db_path, machine="desktop", age=10)
+    assert not library_lock.acquire(db_path, machine="laptop")
+
+    library_lock.take_ownership()
+
+    assert not library_lock.is_read_only()
+    owner = library_lock.read_owner(library_lock.owner_path_for(db_path))
+    assert owner is not None and owner.machine == "laptop"
+
+    # The desktop comes back and takes the library over again: we step down.
+    _write_foreign_owner(db_path, machine="desktop", age=0)
+    library_lock.heartbeat()
+    assert library_lock.is_read_only()
+
+
+def test_read_only_connections_refuse_writes(
+    tmp_path: Path, monkeypatch: pytest.MonkeyPatch, lock_state: None
+) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True, exist_ok=True)
+    monkeypatch.setattr(db, "_data_dir", None)
+    monkeypatch.setattr(db, "_db_path", None)
+    db.set_data_directory(data_dir)
+    db.init_db()
+
+    _write_foreign_owner(db.get_db_path(), machine="desktop", age=0)
+    assert not library_lock.acquire(db.get_db_path(), machine="laptop")
+    db.init_db()  # read-only startup: schema check only
+
+    with db.get_connection() as conn:
+        assert conn.execute("SELECT COUNT(*) FROM recordings").fetchone()[0] == 0
+        with pytest.raises(sqlite3.OperationalError, match="readonly"):
+            conn.execute("DELETE FROM recordings")
@@ -256,3 +256,64 @@ class TestStateChangeCallback:
         engine._process_sentence("Hello world.")
 
         assert received == ["Hello world."]
+
+
+# ── Streaming modes ───────────────────────────────────────────────────────
+
+
+class _FakeRecorder:
+    def __init__(self) -> None:
+        self.is_recording = True
+        self.recorded_seconds = 0.0
+        self.beam_size = 5
+        self.post_speech_silence_duration = 1.0
+        self.partials = 0
+
+    def transcribe_partial(self) -> str:
+        self.partials += 1
+        return f"hello {self.partials}"
+
+
+class TestStreamingMode:
+    def test_accurate_is_the_default(self):
+        assert LiveModeConfig().streaming_mode == "accurate"
+
+    def test_switching_updates_the_running_recorder(self):
+        engine = LiveModeEngine(config=LiveModeConfig(beam_size=5))
+        recorder = engine._recorder = _FakeRecorder()
+
+        engine.set_streaming_mode("fast")
+        assert recorder.beam_size == 1
+        assert recorder.post_speech_silence_duration == 0.4
+
+        engine.set_streaming_mode("accurate")
+        assert recorder.beam_size == 5
+        assert recorder.post_speech_silence_duration == 1.0
+
+    def test_unknown_mode_is_rejected(self):
+        with pytest.raises(ValueError, match="Unknown streaming mode"):
+            LiveModeEngine().set_streaming_mode("turbo")
+
+    def test_fast_mode_sends_a_partial_per_interval_of_new_speech(self):
+        received: list[str] = []
+        engine = LiveModeEngine(
+            config=LiveModeConfig(streaming_mode="fast"),
+            on_realtime_update=received.append,
+        )
+        recorder = engine._recorder = _FakeRecorder()
+
+        for seconds in (0.3, 0.6, 0.8, 1.2):
+            recorder.recorded_seconds = seconds
+            engine._emit_partial()
+
+        assert received == ["hello 1", "hello 2"]
+
+    def test_accurate_mode_sends_no_partials(self):
+        received: list[str] = []
+        engine = LiveModeEngine(on_realtime_update=received.append)
+        engine._recorder = _FakeRecorder()
+        engine._recorder.recorded_seconds = 5.0
+
+        engine._emit_partial()
+
+        assert received == []
@@ -0,0 +1,61 @@
+"""Live Mode resumption: audio frame numbering, acks and resume tokens."""
+
+from __future__ import annotations
+
+import json
+import struct
+
+from server.core.live_resume import (
+    AudioSequence,
+    new_resume_token,
+    parse_audio_frame,
+    token_matches,
+)
+
+
+def _frame(metadata: dict, pcm: bytes = b"\x01\x00\x02\x00") -> bytes:
+    meta = json.dumps(metadata).encode()
+    return struct.pack("<I", len(meta)) + meta + pcm
+
+
+def test_frames_split_into_metadata_and_pcm() -> None:
+    assert parse_audio_frame(_frame({"sample_rate": 16000, "seq": 4})) == (
+        {"sample_rate": 16000, "seq": 4},
+        b"\x01\x00\x02\x00",
+    )
+    assert parse_audio_frame(b"\x00\x00") is None
+    assert parse_audio_frame(struct.pack("<I", 50) + b"{}") is None
+    assert parse_audio_frame(struct.pack("<I", 3) + b"{{{pcm") == ({}, b"pcm")
+
+
+def test_replayed_frames_are_fed_once() -> None:
+    seq = AudioSequence()
+    assert [seq.accept(n) for n in (1, 2, 3)] == [True, True, True]
+    # The client replays from its last ack (1); the engine already has 2 and 3.
+    assert [seq.accept(n) for n in (2, 3, 4)] == [False, False, True]
+    assert seq.last_seq == 4
+    # Frames from clients that do not number them always go through.
+    assert seq.accept(None) and seq.accept(True)
+
+
+def test_acks_are_throttled_and_only_sent_for_new_frames() -> None:
+    now = [100.0]
+    seq = AudioSequence(ack_interval=0.5, clock=lambda: now[0])
+    assert seq.ack_due() is None
+    seq.accept(1)
+    assert seq.ack_due() == 1
+    seq.accept(2)
+    assert seq.ack_due() is None
+    now[0] += 0.5
+    assert seq.ack_due() == 2
+    now[0] += 5
+    assert seq.ack_due() is None
+
+
+def test_resume_tokens_must_match_exactly() -> None:
+    token = new_resume_token()
+    assert token != new_resume_token()
+    assert token_matches(token, token)
+    assert not token_matches(token, token[:-1])
+    assert not token_matches(token, None)
+    assert not token_matches(None, "")
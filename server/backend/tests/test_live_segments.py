@@ -0,0 +1,73 @@
+"""Live Mode segment deltas: insert/update/finalize, seq numbering and snapshots."""
+
+from __future__ import annotations
+
+from server.core.live_segments import SegmentLog
+
+
+def _apply(text: str, delta: dict) -> str:
+    return text[: delta.get("keep", 0)] + delta["text"]
+
+
+def test_partials_open_and_grow_a_segment_with_suffix_updates() -> None:
+    log = SegmentLog()
+
+    first = log.partial("Hello")
+    grown = log.partial("Hello there")
+    revised = log.partial("Hello, there")
+
+    assert first == {"seq": 1, "op": "insert", "id": 1, "text": "Hello"}
+    assert grown == {"seq": 2, "op": "update", "id": 1, "keep": 5, "text": " there"}
+    assert revised == {"seq": 3, "op": "update", "id": 1, "keep": 5, "text": ", there"}
+    assert _apply(_apply("Hello", grown), revised) == "Hello, there"
+    assert log.partial("Hello, there") is None
+    assert log.seq == 3
+
+
+def test_finalize_closes_the_segment_and_the_next_partial_opens_another() -> None:
+    log = SegmentLog()
+    log.partial("good morn")
+
+    final = log.finalize("Good morning.")
+    assert final == {"seq": 2, "op": "finalize", "id": 1, "keep": 0, "text": "Good morning."}
+
+    assert log.partial("") is None
+    assert log.partial("Next") == {"seq": 3, "op": "insert", "id": 2, "text": "Next"}
+
+
+def test_sentence_without_partials_is_inserted_final() -> None:
+    log = SegmentLog()
+
+    assert log.finalize("Just this.") == {
+        "seq": 1,
+        "op": "finalize",
+        "id": 1,
+        "keep": 0,
+        "text": "Just this.",
+    }
+
+
+def test_snapshot_lists_segments_after_the_clients_last_final() -> None:
+    log = SegmentLog(max_segments=2)
+    for text in ("One.", "Two.", "Three."):
+        log.finalize(text)
+    log.partial("Fo")
+
+    assert log.snapshot(since_id=2) == {
+        "seq": 4,
+        "segments": [
+            {"id": 3, "text": "Three.", "final": True},
+            {"id": 4, "text": "Fo", "final": False},
+        ],
+    }
+    # Segments beyond the cap are gone; the client keeps its own copy.
+    assert [s["id"] for s in log.snapshot()["segments"]] == [2, 3, 4]
+
+
+def test_clear_drops_segments_but_keeps_counting() -> None:
+    log = SegmentLog()
+    log.finalize("Old.")
+    log.clear()
+
+    assert log.snapshot() == {"seq": 1, "segments": []}
+    assert log.partial("New") == {"seq": 2, "op": "insert", "id": 2, "text": "New"}
@@ -0,0 +1,286 @@
+"""Tests for email-in transcription (server.core.mail)."""
+
+from __future__ import annotations
+
+from email import message_from_bytes, policy
+from email.message import EmailMessage
+from pathlib import Path
+from unittest.mock import AsyncMock, patch
+
+import pytest
+from server.core import mail as mail_module
+from server.core.mail import (
+    MailError,
+    MailPoller,
+    MailSettings,
+    notebook_transcriber,
+    parse_message,
+    sender_allowed,
+)
+from server.core.share import ShareContent
+from server.utils import keychain
+
+
+def _mail(
+    sender: str = "Voicemail <vm@example.com>",
+    subject: str = "New voicemail",
+    attachments: list[tuple[str, str, bytes]] | None = None,
+) -> bytes:
+    msg = EmailMessage()
+    msg["From"] = sender
+    msg["To"] = "transcribe@example.com"
+    msg["Subject"] = subject
+    msg["Message-ID"] = "<abc@example.com>"
+    msg.set_content("See attached.")
+    for filename, mime, data in attachments or []:
+        maintype, _, subtype = mime.partition("/")
+        msg.add_attachment(data, maintype=maintype, subtype=subtype, filename=filename)
+    return msg.as_bytes()
+
+
+class FakeImap:
+    """Just enough IMAP4 for the poller: UID SEARCH / FETCH / STORE / COPY."""
+
+    def __init__(self, messages: dict[str, bytes]) -> None:
+        self.messages = messages
+        self.flags: dict[str, set[str]] = {uid: set() for uid in messages}
+        self.copied: list[tuple[str, str]] = []
+
+    def login(self, user: str, password: str) -> None:
+        assert password == "imap-secret"
+
+    def select(self, mailbox: str):
+        return "OK", [b"1"]
+
+    def logout(self) -> None:
+        pass
+
+    def expunge(self) -> None:
+        for uid in [u for u, f in self.flags.items() if "\\Deleted" in f]:
+            del self.messages[uid]
+            del self.flags[uid]
+
+    def uid(self, command: str, *args):
+        if command == "SEARCH":
+            unseen = [u for u in self.messages if "\\Seen" not in self.flags[u]]
+            return "OK", [" ".join(unseen).encode()]
+        uid = args[0].decode() if isinstance(args[0], bytes) else args[0]
+        if command == "FETCH":
+            return "OK", [(f"{uid} (BODY[] {{0}}".encode(), self.messages[uid]), b")"]
+        if command == "STORE":
+            self.flags[uid].update(args[2].strip("()").split())
+            return "OK", []
+        if command == "COPY":
+            self.copied.append((uid, args[1]))
+            return "OK", []
+        raise AssertionError(command)
+
+
+class FakeSmtp:
+    def __init__(self) -> None:
+        self.sent: list[EmailMessage] = []
+
+    def __enter__(self):
+        return self
+
+    def __exit__(self, *exc) -> None:
+        pass
+
+    def starttls(self) -> None:
+        pass
+
+    def login(self, user: str, password: str) -> None:
+        pass
+
+    def send_message(self, msg: EmailMessage) -> None:
+        self.sent.append(msg)
+
+
+def _content(recording_id: int, fmt: str) -> ShareContent:
+    return ShareContent(
+        title=f"Recording {recording_id}",
+        summary="Please call me back.",
+        attachment_name=f"Recording_{recording_id}.txt",
+        attachment_bytes=b"transcript",
+        attachment_media_type="text/plain; charset=utf-8",
+    )
+
+
+def _poller(imap: FakeImap, smtp: FakeSmtp, calls: list, **overrides) -> MailPoller:
+    settings = MailSettings.from_config(
+        {
+            "enabled": True,
+            "imap_host": "imap.example.com",
+            "username": "transcribe@example.com",
+            "smtp_host": "smtp.example.com",
+            **overrides,
+        }
+    )
+
+    async def transcribe(path: Path, filename: str, title: str) -> int:
+        calls.append((filename, title, path.read_bytes()))
+        if filename.startswith("broken"):
+            raise RuntimeError("decode failed")
+        return 40 + len(calls)
+
+    return MailPoller(
+        settings, transcribe, imap_factory=lambda _s: imap, smtp_factory=lambda _s: smtp
+    )
+
+
+@pytest.fixture()
+def imap_password(fake_keyring):
+    keychain.set("mail.imap_password", "imap-secret")
+    return fake_keyring
+
+
+def test_parse_message_keeps_only_audio_attachments() -> None:
+    raw = _mail(
+        attachments=[
+            ("message.wav", "audio/wav", b"RIFF"),
+            ("notes.pdf", "application/pdf", b"%PDF"),
+            ("../../memo.amr", "application/octet-stream", b"#!AMR"),
+            ("huge.mp3", "audio/mpeg", b"x" * 2048),
+        ]
+    )
+
+    mail = parse_message("7", raw, max_bytes=1024)
+
+    assert mail.sender == "vm@example.com"
+    assert [(a.filename, a.data) for a in mail.attachments] == [
+        ("message.wav", b"RIFF"),
+        ("memo.amr", b"#!AMR"),
+    ]
+    assert mail.oversized == ["huge.mp3"]
+
+
+@pytest.mark.parametrize(
+    ("address", "allowed", "expected"),
+    [
+        ("a@example.com", [], True),
+        ("A@Example.com", ["a@example.com"], True),
+        ("b@example.com", ["a@example.com"], False),
+        ("b@example.com", ["@example.com"], True),
+        ("b@notexample.com", ["@example.com"], False),
+    ],
+)
+def test_sender_allowed(address: str, allowed: list[str], expected: bool) -> None:
+    assert sender_allowed(address, allowed) is expected
+
+
+def test_invalid_security_mode_is_rejected() -> None:
+    with pytest.raises(MailError, match="imap_security"):
+        MailSettings.from_config({"imap_security": "tls1.0"})
+
+
+async def test_transcribes_replies_and_moves_the_message(imap_password) -> None:
+    imap = FakeImap({"1": _mail(attachments=[("message.wav", "audio/wav", b"RIFF")])})
+    smtp, calls = FakeSmtp(), []
+    poller = _poller(imap, smtp, calls, processed_mailbox="Done")
+
+    with patch("server.core.share.build_share_content", side_effect=_content):
+        assert await poller.poll_once() == 1
+
+    assert calls == [("message.wav", "New voicemail", b"RIFF")]
+    assert imap.copied == [("1", "Done")]
+    assert imap.messages == {}
+    reply = message_from_bytes(smtp.sent[0].as_bytes(), policy=policy.default)
+    assert reply["To"] == "vm@example.com"
+    assert reply["Subject"] == "Re: New voicemail"
+    assert reply["In-Reply-To"] == "<abc@example.com>"
+    assert [p.get_filename() for p in reply.iter_attachments()] == ["Recording_41.txt"]
+
+
+async def test_skips_strangers_and_mail_without_audio(imap_password) -> None:
+    imap = FakeImap(
+        {
+            "1": _mail(sender="spam@else.where", attachments=[("a.mp3", "audio/mpeg", b"ID3")]),
+            "2": _mail(sender="vm@example.com"),
+        }
+    )
+    smtp, calls = FakeSmtp(), []
+    poller = _poller(imap, smtp, calls, allowed_senders=["@example.com"])
+
+    assert await poller.poll_once() == 0
+
+    assert calls == []
+    assert imap.flags == {"1": {"\\Seen"}, "2": {"\\Seen"}}
+    assert smtp.sent == []
+
+
+async def test_failed_transcription_is_flagged_not_retried(imap_password) -> None:
+    imap = FakeImap({"1": _mail(attachments=[("broken.wav", "audio/wav", b"??")])})
+    smtp, calls = FakeSmtp(), []
+    poller = _poller(imap, smtp, calls, processed_mailbox="Done")
+
+    assert await poller.poll_once() == 0
+    assert imap.flags["1"] == {"\\Seen", "\\Flagged"}
+    assert imap.copied == []
+
+    assert await poller.poll_once() == 0
+    assert len(calls) == 1
+
+
+async def test_oversized_attachments_flag_the_message(imap_password) -> None:
+    imap = FakeImap(
+        {
+            "1": _mail(attachments=[("huge.wav", "audio/wav", b"x" * 2048)]),
+            "2": _mail(
+                attachments=[("a.m4a", "audio/mp4", b"one"), ("huge.m4a", "audio/mp4", b"x" * 2048)]
+            ),
+        }
+    )
+    smtp, calls = FakeSmtp(), []
+    poller = _poller(
+        imap, smtp, calls, processed_mailbox="Done", smtp_host="", max_attachment_mb=1 / 1024
+    )
+
+    assert await poller.poll_once() == 1
+
+    assert [filename for filename, _, _ in calls] == ["a.m4a"]
+    assert imap.flags == {"1": {"\\Seen", "\\Flagged"}, "2": {"\\Seen", "\\Flagged"}}
+    assert imap.copied == []
+
+
+async def test_no_smtp_host_files_without_replying(imap_password) -> None:
+    imap = FakeImap(
+        {
+            "1": _mail(
+                attachments=[("a.m4a", "audio/mp4", b"one"), ("b.m4a", "audio/mp4", b"two")]
+            )
+        }
+    )
+    smtp, calls = FakeSmtp(), []
+    poller = _poller(imap, smtp, calls, smtp_host="")
+
+    assert await poller.poll_once() == 1
+
+    assert [title for _, title, _ in calls] == ["New voicemail (1/2)", "New voicemail (2/2)"]
+    assert smtp.sent == []
+    assert imap.flags["1"] == {"\\Seen"}
+
+
+async def test_missing_imap_password_fails_loudly(fake_keyring) -> None:
+    poller = _poller(FakeImap({}), FakeSmtp(), [])
+
+    with pytest.raises(MailError, match="mail.imap_password"):
+        await poller.poll_once()
+
+
+async def test_notebook_transcriber_waits_for_the_job_slot(
+    monkeypatch: pytest.MonkeyPatch, tmp_path: Path
+) -> None:
+    from server.api.routes.notebook import NotebookBusyError
+
+    monkeypatch.setattr(mail_module, "BUSY_RETRY_S", 0)
+    audio = tmp_path / "vm.wav"
+    audio.write_bytes(b"RIFF")
+    run = AsyncMock(side_effect=[NotebookBusyError("busy"), {"job_id": "j", "recording_id": 9}])
+
+    with patch("server.api.routes.notebook.transcribe_file_into_notebook", run):
+        transcribe = notebook_transcriber(object(), MailSettings(language="de"))
+        assert await transcribe(audio, "vm.wav", "Voicemail") == 9
+
+    assert run.await_count == 2
+    assert run.await_args.kwargs["source"] == "mail"
+    assert run.await_args.kwargs["language"] == "de"
@@ -0,0 +1,130 @@
+"""Database integrity checks and repair (server.database.maintenance)."""
+
+from __future__ import annotations
+
+import sqlite3
+from collections.abc import Iterator
+from pathlib import Path
+from typing import Any
+
+import pytest
+import server.database.database as db
+from server.database import maintenance
+from server.database.database import get_recording, save_longform_to_database
+
+
+@pytest.fixture()
+def fresh_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True, exist_ok=True)
+    monkeypatch.setenv("DATA_DIR", str(data_dir))
+    monkeypatch.setattr(db, "_data_dir", None)
+    monkeypatch.setattr(db, "_db_path", None)
+    monkeypatch.setattr(maintenance, "_last_report", None)
+    db.set_data_directory(data_dir)
+    db.init_db()
+    return db.get_db_path()
+
+
+def _recording(tmp_path: Path) -> int:
+    audio = tmp_path / "talk.mp3"
+    audio.write_bytes(b"\x00" * 16)
+    rec_id = save_longform_to_database(
+        audio_path=audio,
+        duration_seconds=2.0,
+        transcription_text="hello world",
+        word_timestamps=[
+            {"word": "hello", "start": 0.0, "end": 0.5},
+            {"word": "world", "start": 0.6, "end": 1.0},
+        ],
+    )
+    assert rec_id
+    return rec_id
+
+
+def _damage(db_path: Path, rec_id: int) -> None:
+    with sqlite3.connect(db_path) as conn:
+        conn.execute("UPDATE recordings SET word_count = 99 WHERE id = ?", (rec_id,))
+        conn.execute("UPDATE segments SET end_time = -1 WHERE recording_id = ?", (rec_id,))
+
+
+def test_healthy_library_reports_no_issues(fresh_db: Path, tmp_path: Path) -> None:
+    _recording(tmp_path)
+
+    events = list(maintenance.run_maintenance())
+
+    assert [e["step"] for e in events if e["type"] == "step"] == list(
+        maintenance.MAINTENANCE_STEPS
+    )
+    done = events[-1]
+    assert done["type"] == "done" and done["healthy"] and done["issues"] == 0
+    assert maintenance.last_report() == done
+
+
+def test_report_only_run_leaves_problems_in_place(fresh_db: Path, tmp_path: Path) -> None:
+    rec_id = _recording(tmp_path)
+    _damage(fresh_db, rec_id)
+
+    events = list(maintenance.run_maintenance(repair=False))
+
+    issues = [e for e in events if e["type"] == "issue"]
+    assert {i["step"] for i in issues} == {"consistency"}
+    assert not any(i["repaired"] for i in issues)
+    assert "vacuum" not in events[0]["steps"]
+    assert not events[-1]["healthy"]
+    assert get_recording(rec_id)["word_count"] == 99
+
+
+def test_repair_fixes_inconsistencies_and_orphans(fresh_db: Path, tmp_path: Path) -> None:
+    rec_id = _recording(tmp_path)
+    _damage(fresh_db, rec_id)
+    with sqlite3.connect(fresh_db) as conn:
+        # Written with foreign keys off, as an interrupted delete would leave it.
+        conn.execute(
+            "INSERT INTO segments (recording_id, segment_index, text, start_time, end_time)"
+            " VALUES (9999, 0, 'orphan', 0, 1)"
+        )
+
+    done = list(maintenance.run_maintenance(vacuum=False))[-1]
+
+    assert done["healthy"] and done["repaired"] == done["issues"] >= 3
+    assert get_recording(rec_id)["word_count"] == 2
+    with sqlite3.connect(fresh_db) as conn:
+        orphans = conn.execute("SELECT COUNT(*) FROM segments WHERE recording_id = 9999")
+        assert orphans.fetchone()[0] == 0
+        backwards = conn.execute("SELECT COUNT(*) FROM segments WHERE end_time < start_time")
+        assert backwards.fetchone()[0] == 0
+    assert list(maintenance.run_maintenance())[-1]["issues"] == 0
+    assert done["backup"] and Path(done["backup"]).exists()
+
+
+def test_failed_integrity_check_only_reports(
+    fresh_db: Path, tmp_path: Path, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    rec_id = _recording(tmp_path)
+    _damage(fresh_db, rec_id)
+
+    def corrupt(conn: sqlite3.Connection, repair: bool) -> Iterator[dict[str, Any]]:
+        yield maintenance._issue("integrity", "Page 7: btreeInitPage() returns error code 11")
+
+    monkeypatch.setitem(maintenance._CHECKS, "integrity", corrupt)
+
+    events = list(maintenance.run_maintenance())
+
+    issues = [e for e in events if e["type"] == "issue"]
+    assert {i["step"] for i in issues} == {"integrity", "consistency"}
+    assert not any(i["repaired"] for i in issues)
+    assert not events[-1]["healthy"]
+    assert get_recording(rec_id)["word_count"] == 99
+
+
+def test_only_one_run_at_a_time(fresh_db: Path) -> None:
+    running = maintenance.run_maintenance()
+    next(running)
+
+    assert maintenance.is_running()
+    with pytest.raises(maintenance.MaintenanceBusy):
+        next(maintenance.run_maintenance())
+
+    running.close()
+    assert not maintenance.is_running()
@@ -0,0 +1,82 @@
+"""NLE marker exports (CMX3600 EDL, Premiere xmeml, Resolve CSV)."""
+
+from __future__ import annotations
+
+import csv
+import io
+import xml.etree.ElementTree as ET
+
+from server.core.marker_export import (
+    markers_from_search,
+    markers_from_segments,
+    render_edl,
+    render_premiere_xml,
+    render_resolve_csv,
+)
+from server.core.timecode import TimecodeSettings
+
+_SEGMENTS = [
+    {"id": 1, "speaker": "SPEAKER_00", "text": "Welcome to the budget review."},
+    {"id": 2, "speaker": "SPEAKER_00", "text": "Let's start."},
+    {"id": 3, "speaker": "SPEAKER_01", "text": "The budget is tight."},
+]
+for _seg, (_start, _end) in zip(_SEGMENTS, [(0.0, 2.0), (2.0, 3.0), (3.5, 5.0)], strict=True):
+    _seg.update(start_time=_start, end_time=_end)
+_WORDS = [
+    {"segment_id": 1, "word": "Welcome", "start_time": 0.0, "end_time": 0.4},
+    {"segment_id": 1, "word": "budget", "start_time": 1.0, "end_time": 1.4},
+    {"segment_id": 1, "word": "review.", "start_time": 1.4, "end_time": 2.0},
+    {"segment_id": 3, "word": "The", "start_time": 3.5, "end_time": 3.7},
+    {"segment_id": 3, "word": "Budget", "start_time": 3.7, "end_time": 4.1},
+    {"segment_id": 3, "word": "is", "start_time": 4.1, "end_time": 4.3},
+]
+
+
+def test_segment_markers_merge_speaker_turns_and_use_aliases() -> None:
+    markers = markers_from_segments(_SEGMENTS, label_map={"SPEAKER_00": "Elena"})
+    assert [m.name for m in markers] == ["Elena", "SPEAKER_01"]
+    assert markers[0].end == 3.0
+    assert markers[0].color != markers[1].color
+
+
+def test_search_markers_use_word_timestamps() -> None:
+    markers = markers_from_search(_SEGMENTS, _WORDS, "budget")
+    assert [m.start for m in markers] == [1.0, 3.7]
+    assert markers[1].comment == "The budget is tight."
+    assert [m.start for m in markers_from_search(_SEGMENTS, _WORDS, "budget review")] == [1.0]
+
+
+def test_search_falls_back_to_segments_without_words() -> None:
+    markers = markers_from_search(_SEGMENTS, [], "Budget")
+    assert [m.start for m in markers] == [0.0, 3.5]
+    assert markers_from_search(_SEGMENTS, [], "  ") == []
+
+
+def test_edl_events_use_timecode_and_resolve_marker_comments() -> None:
+    tc = TimecodeSettings(start_timecode="01:00:00:00", fps=25)
+    edl = render_edl(markers_from_segments(_SEGMENTS), "Review | 1", tc)
+    lines = edl.split("\r\n")
+    assert lines[0] == "TITLE: Review / 1"
+    assert lines[1] == "FCM: NON-DROP FRAME"
+    assert "01:00:03:13 01:00:03:14 01:00:03:13 01:00:03:14" in lines[7]
+    assert lines[4].startswith(" |C:ResolveColorBlue |M:SPEAKER_00 |D:75")
+
+
+def test_premiere_xml_is_well_formed_with_frame_markers() -> None:
+    tc = TimecodeSettings(start_timecode="00:59:50:00", fps=29.97, drop_frame=True)
+    markers = markers_from_search(_SEGMENTS, _WORDS, "budget")
+    xml = render_premiere_xml(markers, "A & B", timecode=tc)
+    root = ET.fromstring(xml.split("\n", 2)[2])
+    seq = root.find("sequence")
+    assert seq is not None
+    assert seq.findtext("name") == "A & B"
+    assert seq.findtext("rate/ntsc") == "TRUE"
+    assert seq.findtext("timecode/displayformat") == "DF"
+    assert [m.findtext("in") for m in seq.findall("marker")] == ["30", "111"]
+
+
+def test_resolve_csv_round_trips_through_csv_reader() -> None:
+    rows = list(csv.reader(io.StringIO(render_resolve_csv(markers_from_segments(_SEGMENTS)))))
+    assert rows[0] == ["Name", "Notes", "Color", "Start TC", "End TC", "Duration"]
+    assert rows[2][:3] == ["SPEAKER_01", "The budget is tight.", "Cyan"]
+    assert rows[2][3:5] == ["00:00:03:13", "00:00:05:00"]
@@ -0,0 +1,147 @@
+"""Tests for the MCP server core (server.core.mcp_protocol)."""
+
+from __future__ import annotations
+
+from typing import Any
+
+import pytest
+from server.core.mcp_protocol import (
+    INVALID_PARAMS,
+    INVALID_REQUEST,
+    METHOD_NOT_FOUND,
+    McpServer,
+    McpTool,
+    ToolError,
+    int_arg,
+    str_arg,
+)
+
+
+async def _echo(arguments: dict[str, Any]) -> Any:
+    if arguments.get("fail") == "tool":
+        raise ToolError("No recording with id 7")
+    if arguments.get("fail") == "crash":
+        raise RuntimeError("boom")
+    return arguments.get("value", "plain text")
+
+
+def _server() -> McpServer:
+    tool = McpTool(
+        name="echo",
+        description="Returns its value",
+        input_schema={"type": "object", "properties": {"value": {}}},
+        handler=_echo,
+    )
+    return McpServer([tool], version="1.2.3", instructions="Be brief.")
+
+
+def _initialize(version: str) -> dict[str, Any]:
+    return {
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {"protocolVersion": version},
+    }
+
+
+def _call(arguments: dict[str, Any], request_id: int = 1) -> dict[str, Any]:
+    return {
+        "jsonrpc": "2.0",
+        "id": request_id,
+        "method": "tools/call",
+        "params": {"name": "echo", "arguments": arguments},
+    }
+
+
+async def test_initialize_negotiates_a_supported_version() -> None:
+    server = _server()
+    reply = await server.handle(_initialize("2025-03-26"))
+    assert reply["result"]["protocolVersion"] == "2025-03-26"
+    assert reply["result"]["serverInfo"] == {"name": "transcriptionsuite", "version": "1.2.3"}
+    assert reply["result"]["capabilities"] == {"tools": {"listChanged": False}}
+    assert reply["result"]["instructions"] == "Be brief."
+
+    newer = await server.handle(_initialize("2099-01-01"))
+    assert newer["result"]["protocolVersion"] == "2025-06-18"
+
+
+async def test_tools_list_describes_each_tool() -> None:
+    reply = await _server().handle({"jsonrpc": "2.0", "id": "a", "method": "tools/list"})
+    assert reply == {
+        "jsonrpc": "2.0",
+        "id": "a",
+        "result": {
+            "tools": [
+                {
+                    "name": "echo",
+                    "description": "Returns its value",
+                    "inputSchema": {"type": "object", "properties": {"value": {}}},
+                }
+            ]
+        },
+    }
+
+
+async def test_tool_results_are_text_plus_structured_content() -> None:
+    server = _server()
+
+    text = await server.handle(_call({}))
+    assert text["result"] == {"content": [{"type": "text", "text": "plain text"}], "isError": False}
+
+    structured = await server.handle(_call({"value": {"count": 2}}))
+    assert structured["result"]["structuredContent"] == {"count": 2}
+    assert '"count": 2' in structured["result"]["content"][0]["text"]
+
+
+async def test_tool_failures_are_results_the_model_can_read() -> None:
+    server = _server()
+
+    failed = await server.handle(_call({"fail": "tool"}))
+    assert failed["result"] == {
+        "content": [{"type": "text", "text": "No recording with id 7"}],
+        "isError": True,
+    }
+
+    crashed = await server.handle(_call({"fail": "crash"}))
+    assert crashed["result"]["isError"] is True
+    assert "boom" not in crashed["result"]["content"][0]["text"]
+
+
+async def test_protocol_errors() -> None:
+    server = _server()
+
+    unknown_method = await server.handle({"jsonrpc": "2.0", "id": 1, "method": "resources/list"})
+    assert unknown_method["error"]["code"] == METHOD_NOT_FOUND
+
+    unknown_tool = await server.handle(
+        {"jsonrpc": "2.0", "id": 2, "method": "tools/call", "params": {"name": "nope"}}
+    )
+    assert unknown_tool["error"]["code"] == INVALID_PARAMS
+
+    invalid = await server.handle({"id": 3, "method": "ping"})
+    assert invalid == {
+        "jsonrpc": "2.0",
+        "id": 3,
+        "error": {"code": INVALID_REQUEST, "message": "Invalid JSON-RPC request"},
+    }
+
+
+async def test_notifications_get_no_reply_and_batches_collect_replies() -> None:
+    server = _server()
+    initialized = {"jsonrpc": "2.0", "method": "notifications/initialized"}
+
+    assert await server.handle(initialized) is None
+    assert await server.handle([initialized]) is None
+
+    replies = await server.handle([initialized, {"jsonrpc": "2.0", "id": 9, "method": "ping"}])
+    assert replies == [{"jsonrpc": "2.0", "id": 9, "result": {}}]
+
+
+def test_argument_helpers() -> None:
+    assert int_arg({"limit": 500}, "limit", default=20, maximum=100) == 100
+    assert int_arg({}, "limit", default=20) == 20
+    with pytest.raises(ToolError, match='"limit" must be a number'):
+        int_arg({"limit": "many"}, "limit")
+    assert str_arg({"query": "  budget "}, "query") == "budget"
+    with pytest.raises(ToolError, match='"query" is required'):
+        str_arg({"query": " "}, "query", required=True)
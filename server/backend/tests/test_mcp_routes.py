@@ -0,0 +1,200 @@
+"""Tests for the MCP endpoint and its library tools (/api/mcp)."""
+
+from collections.abc import Iterator
+from contextlib import contextmanager
+from unittest.mock import AsyncMock, patch
+
+import pytest
+from fastapi import FastAPI
+from fastapi.testclient import TestClient
+from server.api.routes import mcp
+
+_ROUTE_MOD = "server.api.routes.mcp"
+
+_RECORDING = {
+    "id": 7,
+    "filename": "standup.wav",
+    "title": "Standup",
+    "recorded_at": "2026-03-02T09:00:00",
+    "duration_seconds": 95.0,
+    "summary": None,
+}
+_SEGMENTS = [
+    {"speaker": "SPEAKER_00", "text": "Morning all.", "start_time": 0.0, "end_time": 2.0},
+    {
+        "speaker": "SPEAKER_01",
+        "text": "Budget is due Friday.",
+        "start_time": 62.0,
+        "end_time": 65.0,
+    },
+]
+
+
+@pytest.fixture()
+def client() -> TestClient:
+    app = FastAPI()
+    app.include_router(mcp.router, prefix="/api/mcp")
+    return TestClient(app)
+
+
+def _call(name: str, arguments: dict) -> dict:
+    return {
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {"name": name, "arguments": arguments},
+    }
+
+
+@contextmanager
+def _library(redactions: list[dict] | None = None) -> Iterator[None]:
+    with (
+        patch(f"{_ROUTE_MOD}.mcp_enabled", return_value=True),
+        patch(f"{_ROUTE_MOD}.get_recording", return_value=_RECORDING),
+        patch(f"{_ROUTE_MOD}.get_segments", return_value=_SEGMENTS),
+        patch(f"{_ROUTE_MOD}.get_words", return_value=[]),
+        patch("server.database.alias_repository.alias_map", return_value={"SPEAKER_01": "Dana"}),
+        patch(
+            "server.database.redaction_repository.list_redactions",
+            return_value=redactions or [],
+        ),
+    ):
+        yield
+
+
+def test_disabled_by_default(client):
+    with patch(f"{_ROUTE_MOD}.mcp_enabled", return_value=False):
+        response = client.post(
+            "/api/mcp", json={"jsonrpc": "2.0", "id": 1, "method": "ping"}
+        )
+
+    assert response.status_code == 404
+
+
+def test_initialize_and_list_tools(client):
+    with patch(f"{_ROUTE_MOD}.mcp_enabled", return_value=True):
+        init = client.post(
+            "/api/mcp",
+            json={"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}},
+        )
+        initialized = client.post(
+            "/api/mcp", json={"jsonrpc": "2.0", "method": "notifications/initialized"}
+        )
+        tools = client.post(
+            "/api/mcp", json={"jsonrpc": "2.0", "id": 2, "method": "tools/list"}
+        )
+
+    assert init.json()["result"]["serverInfo"]["name"] == "transcriptionsuite"
+    assert initialized.status_code == 202
+    assert [t["name"] for t in tools.json()["result"]["tools"]] == [
+        "search_transcripts",
+        "list_recordings",
+        "get_transcript",
+        "summarize_range",
+    ]
+
+
+def test_malformed_json_is_a_parse_error(client):
+    with patch(f"{_ROUTE_MOD}.mcp_enabled", return_value=True):
+        response = client.post(
+            "/api/mcp", content=b"{not json", headers={"Content-Type": "application/json"}
+        )
+
+    assert response.status_code == 400
+    assert response.json()["error"]["code"] == -32700
+
+
+def test_get_transcript_range_uses_speaker_names(client):
+    with _library():
+        response = client.post(
+            "/api/mcp", json=_call("get_transcript", {"recording_id": 7, "start": 60})
+        )
+
+    result = response.json()["result"]
+    assert result["isError"] is False
+    text = result["content"][0]["text"]
+    assert text.startswith("# Standup\n")
+    assert "[0:01:02] Dana: Budget is due Friday." in text
+    assert "Morning all." not in text
+
+
+def test_get_transcript_masks_redacted_ranges(client):
+    with _library(redactions=[{"start_time": 62.0, "end_time": 65.0}]):
+        response = client.post(
+            "/api/mcp", json=_call("get_transcript", {"recording_id": 7, "start": 60})
+        )
+
+    text = response.json()["result"]["content"][0]["text"]
+    assert "Budget" not in text
+    assert "[0:01:02] Dana: █████" in text
+
+
+def test_get_transcript_unknown_recording_is_a_tool_error(client):
+    with (
+        patch(f"{_ROUTE_MOD}.mcp_enabled", return_value=True),
+        patch(f"{_ROUTE_MOD}.get_recording", return_value=None),
+    ):
+        response = client.post(
+            "/api/mcp", json=_call("get_transcript", {"recording_id": 99})
+        )
+
+    assert response.json()["result"] == {
+        "content": [{"type": "text", "text": "No recording with id 99"}],
+        "isError": True,
+    }
+
+
+def test_search_transcripts_returns_matches(client):
+    rows = [
+        {
+            "recording_id": 7,
+            "title": "Standup",
+            "filename": "standup.wav",
+            "recorded_at": "2026-03-02T09:00:00",
+            "match_type": "word",
+            "start_time": 62.0,
+            "speaker": "SPEAKER_01",
+            "context": "Budget is due Friday.",
+        }
+    ]
+    with (
+        patch(f"{_ROUTE_MOD}.mcp_enabled", return_value=True),
+        patch("server.api.routes.search.search_words_by_date_range", return_value=rows),
+        patch("server.api.routes.search.search_recording_metadata", return_value=[]),
+        patch("server.database.metadata_repository.search_metadata", return_value=[]),
+    ):
+        response = client.post(
+            "/api/mcp", json=_call("search_transcripts", {"query": "budget"})
+        )
+
+    matches = response.json()["result"]["structuredContent"]["matches"]
+    assert matches == [
+        {
+            "recording_id": 7,
+            "title": "Standup",
+            "recorded_at": "2026-03-02T09:00:00",
+            "match_type": "word",
+            "start": 62.0,
+            "speaker": "SPEAKER_01",
+            "context": "Budget is due Friday.",
+        }
+    ]
+
+
+def test_summarize_range_sends_only_the_range_to_the_llm(client):
+    from server.api.routes.llm import LLMResponse
+
+    process = AsyncMock(return_value=LLMResponse(response="Budget due Friday.", model="m"))
+    with (
+        _library(),
+        patch("server.api.routes.llm.get_llm_config", return_value={"enabled": True}),
+        patch("server.api.routes.llm.process_with_llm", process),
+    ):
+        response = client.post(
+            "/api/mcp",
+            json=_call("summarize_range", {"recording_id": 7, "start": 60, "end": 90}),
+        )
+
+    assert response.json()["result"]["content"][0]["text"] == "Budget due Friday."
+    request = process.await_args.args[0]
+    assert request.transcription_text == "[0:01:02] Dana: Budget is due Friday."
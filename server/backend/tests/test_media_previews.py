@@ -0,0 +1,118 @@
+"""Library card media info: ffprobe summaries and the background preview cache."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from server.core import media_previews
+from server.core.media_previews import MediaPreviews, link_for_preview, summarize_probe
+
+
+def test_summarize_probe_reports_codecs_and_skips_cover_art() -> None:
+    probe = {
+        "format": {
+            "format_name": "mov,mp4,m4a,3gp,3g2,mj2",
+            "duration": "61.250000",
+            "size": "1048576",
+            "bit_rate": "136954",
+        },
+        "streams": [
+            {"codec_type": "video", "codec_name": "mjpeg", "disposition": {"attached_pic": 1}},
+            {
+                "codec_type": "video",
+                "codec_name": "h264",
+                "width": 1920,
+                "height": 1080,
+                "avg_frame_rate": "30000/1001",
+            },
+            {"codec_type": "audio", "codec_name": "aac", "sample_rate": "48000", "channels": 2},
+        ],
+    }
+
+    assert summarize_probe(probe) == {
+        "duration_seconds": 61.25,
+        "container": "mov",
+        "size_bytes": 1048576,
+        "bit_rate": 136954,
+        "audio": {"codec": "aac", "sample_rate": 48000, "channels": 2},
+        "video": {"codec": "h264", "width": 1920, "height": 1080, "frame_rate": 29.97},
+    }
+
+
+def test_summarize_probe_tolerates_missing_fields() -> None:
+    summary = summarize_probe({"streams": [{"codec_type": "video", "avg_frame_rate": "0/0"}]})
+
+    assert summary["duration_seconds"] is None
+    assert summary["container"] is None
+    assert summary["audio"] is None
+    assert summary["video"]["frame_rate"] is None
+
+
+@pytest.fixture
+def previews(tmp_path: Path) -> MediaPreviews:
+    return MediaPreviews(tmp_path / "previews", workers=1)
+
+
+def _fake_extract(sources: list[Path]):
+    def extract(source: Path, entry: Path) -> dict:
+        sources.append(source)
+        (entry / "preview.png").write_bytes(b"png")
+        return {"duration_seconds": 3.0, "audio": {"codec": "mp3"}, "preview": "spectrogram"}
+
+    return extract
+
+
+def test_submit_extracts_from_the_upload_link_and_deletes_it(
+    tmp_path: Path, previews: MediaPreviews, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    sources: list[Path] = []
+    monkeypatch.setattr(media_previews, "_extract", _fake_extract(sources))
+    stored = tmp_path / "recording.mp3"
+    stored.write_bytes(b"mp3")
+    upload = tmp_path / "upload.mp4"
+    upload.write_bytes(b"video")
+    link = link_for_preview(upload)
+    assert link is not None
+
+    previews.submit(stored, original=link).result()
+
+    assert sources == [link]
+    assert not link.exists()
+    assert upload.exists()
+    assert previews.get(stored)["audio"] == {"codec": "mp3"}
+    assert previews.preview(stored) == (b"png", "image/png")
+    assert not previews.is_pending(stored)
+    # Already cached: nothing is queued again.
+    assert previews.submit(stored) is None
+
+
+def test_failed_extraction_is_cached_as_an_error(
+    tmp_path: Path, previews: MediaPreviews, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    def broken(source: Path, entry: Path) -> dict:
+        raise RuntimeError("no streams")
+
+    monkeypatch.setattr(media_previews, "_extract", broken)
+    stored = tmp_path / "recording.mp3"
+    stored.write_bytes(b"mp3")
+
+    previews.submit(stored).result()
+
+    assert previews.get(stored) == {"error": "no streams"}
+    assert previews.preview(stored) is None
+
+
+def test_discard_drops_the_entry(
+    tmp_path: Path, previews: MediaPreviews, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    monkeypatch.setattr(media_previews, "_extract", _fake_extract([]))
+    stored = tmp_path / "recording.mp3"
+    stored.write_bytes(b"mp3")
+    previews.submit(stored).result()
+
+    previews.discard(stored)
+
+    assert previews.get(stored) is None
+    assert previews.preview(stored) is None
@@ -0,0 +1,71 @@
+"""Model benchmark: word error rate and the per-model timing runs."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from server.core.model_benchmark import (
+    BenchmarkSample,
+    normalize_words,
+    run_benchmark,
+    word_error_rate,
+)
+
+_REFERENCE = "The quick brown fox jumps over the lazy dog."
+
+
+def test_words_ignore_case_and_punctuation() -> None:
+    assert normalize_words("Don’t STOP, now!") == ["don't", "stop", "now"]
+
+
+def test_word_error_rate_counts_edits_over_reference_words() -> None:
+    assert word_error_rate(_REFERENCE, "the quick brown fox jumps over the lazy dog") == 0.0
+    # one substitution, one deletion
+    assert word_error_rate(_REFERENCE, "the quick brown box jumps over lazy dog") == 2 / 9
+    # insertions can push it past 1.0
+    assert word_error_rate("hello", "well hello there friend") == 3.0
+    assert word_error_rate("", "") == 0.0
+    assert word_error_rate("", "noise") == 1.0
+
+
+def test_each_model_is_warmed_up_then_timed() -> None:
+    calls: list[str] = []
+    ticks = iter([10.0, 12.0, 20.0, 20.5])
+
+    def transcribe(model: str, path: Path) -> str:
+        calls.append(model)
+        return _REFERENCE if model == "large" else "the quick brown fox"
+
+    sample = BenchmarkSample(audio_path=Path("clip.wav"), reference=_REFERENCE, duration=8.0)
+    runs = run_benchmark(
+        sample, ["large", "tiny"], transcribe=transcribe, clock=lambda: next(ticks)
+    )
+
+    assert calls == ["large", "large", "tiny", "tiny"]
+    assert [(r.model, r.seconds, r.realtime_factor) for r in runs] == [
+        ("large", 2.0, 0.25),
+        ("tiny", 0.5, 0.0625),
+    ]
+    assert runs[0].wer == 0.0
+    assert runs[1].wer == round(5 / 9, 4)
+
+
+def test_a_failing_model_does_not_stop_the_others() -> None:
+    def transcribe(model: str, path: Path) -> str:
+        if model == "broken":
+            raise RuntimeError("model not found")
+        return _REFERENCE
+
+    sample = BenchmarkSample(audio_path=Path("clip.wav"), reference=_REFERENCE, duration=8.0)
+    runs = run_benchmark(sample, ["broken", "large"], transcribe=transcribe)
+
+    assert runs[0].to_dict()["error"] == "model not found"
+    assert runs[0].realtime_factor is None
+    assert runs[1].error is None and runs[1].wer == 0.0
+
+
+def test_a_clip_without_duration_is_rejected() -> None:
+    sample = BenchmarkSample(audio_path=Path("clip.wav"), reference=_REFERENCE, duration=0.0)
+    with pytest.raises(ValueError, match="no duration"):
+        run_benchmark(sample, ["large"], transcribe=lambda model, path: "")
@@ -65,6 +65,7 @@ _ensure_stubs()
 from server.core.multitrack import (  # noqa: E402
     MAX_CHANNELS,
     _parse_mean_volume,
+    channel_speaker_labels,
     filter_silent_channels,
     merge_track_results,
     probe_channels,
@@ -727,6 +728,39 @@ class TestTranscribeMultitrack:
         assert result.words[1]["speaker"] == "Speaker 2"
         assert engine.transcribe_file.call_count == 2
 
+    def test_channel_labels_follow_source_channels(self) -> None:
+        """Interview mode: names stay on their channel when another is silent."""
+        engine = MagicMock()
+        engine.transcribe_file.side_effect = [
+            _make_result([{"word": "Question", "start": 0.0, "end": 0.5}]),
+            _make_result([{"word": "Answer", "start": 1.0, "end": 1.5}]),
+        ]
+
+        with (
+            patch(
+                "server.core.multitrack.probe_channels",
+                return_value={"num_channels": 3, "channel_levels_db": [-20.0, -91.0, -25.0]},
+            ),
+            patch(
+                "server.core.multitrack.split_channels",
+                return_value=["/tmp/ch0.wav", "/tmp/ch2.wav"],
+            ),
+            patch("server.core.multitrack.Path"),
+        ):
+            result = transcribe_multitrack(
+                engine, "/fake/call.wav", channel_labels=["Me", "Nobody", "Guest"]
+            )
+
+        assert [w["speaker"] for w in result.words] == ["Me", "Guest"]
+        assert [s["speaker"] for s in result.segments] == ["Me", "Guest"]
+
+
+class TestChannelSpeakerLabels:
+    def test_unnamed_channels_fall_back_to_track_numbers(self) -> None:
+        assert channel_speaker_labels([0, 2]) == ["Speaker 1", "Speaker 2"]
+        assert channel_speaker_labels([0, 1], ["  Me ", ""]) == ["Me", "Speaker 2"]
+        assert channel_speaker_labels([0, 3], ["Me"]) == ["Me", "Speaker 2"]
+
 
 class TestTranscribeMultitrackProgressScaling:
     """The caller-supplied progress_callback must observe a monotone 0 → N*total
@@ -0,0 +1,49 @@
+"""Shared ONNX model loading: every failure turns the feature off with a warning."""
+
+from __future__ import annotations
+
+import logging
+from collections.abc import Callable
+from pathlib import Path
+
+import pytest
+
+from server.core.onnx_models import load_model
+
+
+def _raise(exc: Exception) -> Callable[[Path], object]:
+    def factory(path: Path) -> object:
+        raise exc
+
+    return factory
+
+
+def test_unset_path_loads_nothing() -> None:
+    calls: list[Path] = []
+    assert load_model(calls.append, "", "Feature off") is None
+    assert calls == []
+
+
+def test_loads_from_the_expanded_path() -> None:
+    assert load_model(lambda path: path, "~/models/ner", "Feature off") == (
+        Path.home() / "models" / "ner"
+    )
+
+
+@pytest.mark.parametrize(
+    ("exc", "message"),
+    [
+        (
+            ImportError("no tokenizers", name="tokenizers"),
+            "Feature off: tokenizers is not installed",
+        ),
+        (FileNotFoundError("config.json"), "Feature off: could not load model from"),
+        (RuntimeError("bad graph"), "Feature off: onnxruntime failed on"),
+    ],
+)
+def test_failures_turn_the_feature_off(
+    exc: Exception, message: str, caplog: pytest.LogCaptureFixture
+) -> None:
+    with caplog.at_level(logging.WARNING, logger="server.core.onnx_models"):
+        assert load_model(_raise(exc), "/models/ner", "Feature off") is None
+    assert message in caplog.text
@@ -0,0 +1,168 @@
+"""Out-of-memory retries for file jobs: detection, chunking and model downgrade."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from types import SimpleNamespace
+from typing import Any
+
+import pytest
+from server.core.oom_fallback import (
+    DEFAULT_MODELS,
+    OomFallbackSettings,
+    is_out_of_memory,
+    smaller_models,
+    transcribe_in_chunks,
+    transcribe_with_fallback,
+)
+
+LARGE = "Systran/faster-whisper-large-v3"
+MEDIUM = "Systran/faster-whisper-medium"
+
+
+@dataclass
+class _Result:
+    text: str
+    segments: list[dict[str, Any]] = field(default_factory=list)
+    words: list[dict[str, Any]] = field(default_factory=list)
+    duration: float = 0.0
+    partial: bool = False
+    partial_reason: str | None = None
+    completed_seconds: float | None = None
+    downgrade: dict[str, Any] | None = None
+
+
+class _Engine:
+    def __init__(self, model_name: str, *, whole_fails: bool = True, span_fails: bool = False):
+        self.model_name = model_name
+        self.whole_fails = whole_fails
+        self.span_fails = span_fails
+        self.spans: list[tuple[float, float]] = []
+
+    def transcribe_file(self, file_path: str, **kwargs: Any) -> _Result:
+        if self.whole_fails:
+            raise RuntimeError("CUDA out of memory. Tried to allocate 2.00 GiB")
+        return _Result(text=f"whole:{self.model_name}", duration=600.0)
+
+    def transcribe_file_span(
+        self, file_path: str, *, start_seconds: float, duration_seconds: float, **kwargs: Any
+    ) -> _Result:
+        if self.span_fails:
+            raise RuntimeError("CUBLAS_STATUS_ALLOC_FAILED")
+        self.spans.append((start_seconds, duration_seconds))
+        segment = {"start": 1.0, "end": 2.0, "text": "hi", "words": [{"start": 1.0, "end": 1.5}]}
+        return _Result(text="hi", segments=[segment], words=[{"start": 1.0, "end": 1.5}])
+
+
+def _manager(engines: dict[str, _Engine]) -> Any:
+    phases: list[str] = []
+    return SimpleNamespace(
+        is_same_model=lambda a, b: a.lower() == b.lower(),
+        alternate_transcription_engine=lambda name: engines[name],
+        job_tracker=SimpleNamespace(set_phase=phases.append),
+        phases=phases,
+    )
+
+
+def _run(manager: Any, engine: _Engine, settings: OomFallbackSettings, seconds: float = 600.0):
+    return transcribe_with_fallback(
+        manager,
+        engine,
+        "/tmp/in.wav",
+        settings=settings,
+        duration=lambda _path: seconds,
+        vram=lambda: {"total_gb": 12.0, "free_gb": 0.4},
+        clear_cache=lambda: None,
+        language="en",
+    )
+
+
+def test_out_of_memory_is_recognised_through_wrapping() -> None:
+    class OutOfMemoryError(RuntimeError):
+        pass
+
+    assert is_out_of_memory(OutOfMemoryError("boom"))
+    assert is_out_of_memory(RuntimeError("CUDA error: out of memory"))
+    try:
+        try:
+            raise RuntimeError("cuDNN error: CUDNN_STATUS_ALLOC_FAILED")
+        except RuntimeError as inner:
+            raise ValueError("Transcription failed") from inner
+    except ValueError as wrapped:
+        assert is_out_of_memory(wrapped)
+    assert not is_out_of_memory(RuntimeError("device not ready"))
+
+
+def test_smaller_models_only_follow_the_current_one_on_the_ladder() -> None:
+    same = lambda a, b: a == b  # noqa: E731
+    assert smaller_models(MEDIUM, DEFAULT_MODELS, same) == ["Systran/faster-whisper-small"]
+    assert smaller_models("nvidia/parakeet-tdt-0.6b-v3", DEFAULT_MODELS, same) == []
+
+
+def test_chunks_are_joined_on_one_timeline() -> None:
+    engine = _Engine(LARGE)
+    result = transcribe_in_chunks(engine, "/tmp/in.wav", duration=250.0, chunk_seconds=100.0)
+
+    assert engine.spans == [(0.0, 100.0), (100.0, 100.0), (200.0, 50.0)]
+    assert [s["start"] for s in result.segments] == [1.0, 101.0, 201.0]
+    assert result.segments[2]["words"][0]["end"] == 201.5
+    assert [w["start"] for w in result.words] == [1.0, 101.0, 201.0]
+    assert result.text == "hi hi hi"
+    assert result.duration == 250.0
+
+
+def test_first_retry_keeps_the_model_and_chunks_the_file() -> None:
+    engine = _Engine(LARGE)
+    manager = _manager({})
+    result, used = _run(manager, engine, OomFallbackSettings(chunk_seconds=300))
+
+    assert used is engine
+    assert len(engine.spans) == 2
+    assert result.downgrade["to_model"] == LARGE
+    assert result.downgrade["chunk_seconds"] == 300
+    assert result.downgrade["vram"] == {"total_gb": 12.0, "free_gb": 0.4}
+    assert "5-minute chunks" in result.downgrade["summary"]
+    assert manager.phases == ["oom_retry", "transcribing"]
+
+
+def test_smaller_models_are_tried_in_order_after_chunking_fails() -> None:
+    engines = {
+        "deepdml/faster-whisper-large-v3-turbo-ct2": _Engine("turbo", span_fails=True),
+        MEDIUM: _Engine(MEDIUM),
+    }
+    large = _Engine(LARGE, span_fails=True)
+    result, used = _run(_manager(engines), large, OomFallbackSettings(chunk_seconds=300))
+
+    assert used is engines[MEDIUM]
+    assert result.downgrade["from_model"] == LARGE
+    assert result.downgrade["to_model"] == MEDIUM
+    assert "instead of" in result.downgrade["summary"]
+
+
+def test_short_files_skip_chunking_and_unknown_models_give_up() -> None:
+    medium = _Engine(MEDIUM, whole_fails=False)
+    settings = OomFallbackSettings(models=(LARGE, MEDIUM))
+    result, used = _run(_manager({MEDIUM: medium}), _Engine(LARGE), settings, seconds=60.0)
+    assert used is medium
+    assert result.downgrade["chunk_seconds"] is None
+
+    with pytest.raises(RuntimeError, match="out of memory"):
+        _run(_manager({}), _Engine("nvidia/parakeet-tdt-0.6b-v3"), OomFallbackSettings(), 60.0)
+
+
+def test_other_errors_and_disabled_settings_are_not_retried() -> None:
+    class Broken(_Engine):
+        def transcribe_file(self, file_path: str, **kwargs: Any) -> _Result:
+            raise RuntimeError("corrupt file")
+
+    with pytest.raises(RuntimeError, match="corrupt file"):
+        _run(_manager({}), Broken(LARGE), OomFallbackSettings())
+    with pytest.raises(RuntimeError, match="out of memory"):
+        _run(_manager({}), _Engine(LARGE), OomFallbackSettings(enabled=False))
+
+
+def test_settings_fall_back_to_defaults_on_bad_values() -> None:
+    settings = OomFallbackSettings.from_config({"chunk_seconds": "soon", "models": "medium"})
+    assert settings.chunk_seconds == 300.0
+    assert settings.models == DEFAULT_MODELS
+    assert OomFallbackSettings.from_config({"models": [MEDIUM, ""]}).models == (MEDIUM,)
@@ -0,0 +1,62 @@
+"""Output manager: template naming, per-type routing and collision policies."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from server.core.output_manager import OutputSettings, plan_outputs, write_output
+
+_REC = {"id": 7, "title": "Team sync", "recorded_at": "2026-05-08T10:00:00Z", "language": "en"}
+
+
+def test_template_and_per_type_folders(tmp_path: Path) -> None:
+    settings = OutputSettings.from_config(
+        {"root": str(tmp_path), "destinations": {"subtitles": "subs"}}
+    )
+    plans = plan_outputs([{**_REC, "filepath": "/x/a.flac"}], ["txt", "srt", "recording"], settings)
+    assert [(p.action, p.path) for p in plans] == [
+        ("write", str(tmp_path / "2026-05-08_Team sync_en.txt")),
+        ("write", str(tmp_path / "subs" / "2026-05-08_Team sync_en.srt")),
+        ("write", str(tmp_path / "2026-05-08_Team sync_en.flac")),
+    ]
+    assert OutputSettings.from_config({}).folder_for("transcript") is None
+    assert plan_outputs([_REC], ["txt"], OutputSettings())[0].action == "unrouted"
+
+
+def test_collisions_on_disk_and_within_batch(tmp_path: Path) -> None:
+    (tmp_path / "Team sync.txt").write_text("old")
+    twins = [_REC, {**_REC, "id": 8}]
+
+    suffix = OutputSettings.from_config({"root": str(tmp_path), "template": "{title}.{ext}"})
+    assert [p.filename for p in plan_outputs(twins, ["txt"], suffix)] == [
+        "Team sync (2).txt",
+        "Team sync (3).txt",
+    ]
+    skip = OutputSettings.from_config(
+        {"root": str(tmp_path), "collision": "skip"}, template="{title}.{ext}"
+    )
+    assert [p.action for p in plan_outputs(twins, ["txt"], skip)] == ["skip", "skip"]
+    overwrite = OutputSettings.from_config(
+        {"root": str(tmp_path), "template": "{title}.{ext}", "collision": "overwrite"}
+    )
+    assert [p.action for p in plan_outputs(twins, ["txt"], overwrite)] == ["overwrite", "overwrite"]
+
+
+def test_invalid_settings_are_rejected() -> None:
+    with pytest.raises(ValueError, match="placeholder"):
+        OutputSettings.from_config({"template": "{speaker}.{ext}"})
+    with pytest.raises(ValueError, match="collision"):
+        OutputSettings.from_config({"collision": "ask"})
+    with pytest.raises(ValueError, match="output type"):
+        OutputSettings.from_config({"destinations": {"video": "/tmp"}})
+    with pytest.raises(ValueError, match="Unsupported output format"):
+        plan_outputs([_REC], ["docx"], OutputSettings())
+
+
+def test_write_output_creates_folders_and_copies(tmp_path: Path) -> None:
+    target = tmp_path / "nested" / "out.txt"
+    write_output(target, b"hello")
+    write_output(tmp_path / "copy.txt", target)
+    assert (tmp_path / "copy.txt").read_text() == "hello"
+    assert sorted(p.name for p in (tmp_path / "nested").iterdir()) == ["out.txt"]
@@ -0,0 +1,158 @@
+"""Resuming partial transcripts: offsets, timeline shift and the segment append."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+import server.database.database as db
+from server.core.partial_resume import resume_offset, resume_transcription, shift_segments
+
+
+def test_resume_offset_prefers_the_stored_offset() -> None:
+    segments = [{"end_time": 10.0}, {"end_time": 38.5}]
+    assert resume_offset({"partial_until": 40.0}, segments) == 40.0
+    assert resume_offset({"partial_until": None}, segments) == 38.5
+    assert resume_offset({}, []) == 0.0
+
+
+def test_shift_segments_moves_words_onto_the_recording() -> None:
+    shifted = shift_segments(
+        [
+            {
+                "text": " and then",
+                "start": 0.2,
+                "end": 1.0,
+                "words": [
+                    {"word": " and", "start": 0.2, "end": 0.5, "probability": 0.9},
+                    {"word": " then", "start": 0.6, "end": 1.0, "probability": 0.8},
+                ],
+            },
+            {"text": "  ", "start": 1.0, "end": 1.2},
+        ],
+        offset=60.0,
+    )
+    assert shifted == [
+        {
+            "text": "and then",
+            "start": 60.2,
+            "end": 61.0,
+            "words": [
+                {"word": "and", "start": 60.2, "end": 60.5, "confidence": 0.9},
+                {"word": "then", "start": 60.6, "end": 61.0, "confidence": 0.8},
+            ],
+        }
+    ]
+
+
+def _engine(**result):
+    calls: list[dict] = []
+
+    class _Engine:
+        def transcribe_file_span(self, path, **kwargs):
+            calls.append(kwargs)
+            return SimpleNamespace(
+                segments=[{"text": "rest", "start": 0.0, "end": 2.0, "words": []}], **result
+            )
+
+    return _Engine(), calls
+
+
+def test_resume_transcribes_only_the_rest() -> None:
+    engine, calls = _engine(partial=False)
+    resumed = resume_transcription(engine, Path("/a.wav"), 40.0, duration=100.0)
+    assert calls == [{"start_seconds": 40.0, "duration_seconds": 60.0, "language": None}]
+    assert resumed.segments[0]["start"] == 40.0
+    assert resumed.to_dict()["partial"] is False
+
+
+def test_resume_that_stops_again_moves_the_offset() -> None:
+    engine, _ = _engine(partial=True, completed_seconds=30.0, partial_reason="chunk 3 failed")
+    resumed = resume_transcription(engine, Path("/a.wav"), 40.0, duration=100.0)
+    assert (resumed.partial_until, resumed.partial_reason) == (70.0, "chunk 3 failed")
+
+
+def test_resume_with_nothing_left_is_rejected() -> None:
+    engine, calls = _engine(partial=False)
+    with pytest.raises(ValueError, match="Nothing left"):
+        resume_transcription(engine, Path("/a.wav"), 99.8, duration=100.0)
+    assert calls == []
+
+
+_SCHEMA_SQL = """
+CREATE TABLE recordings (
+    id INTEGER PRIMARY KEY,
+    filename TEXT,
+    word_count INTEGER,
+    partial_until REAL,
+    partial_reason TEXT
+);
+CREATE TABLE segments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_index INTEGER NOT NULL,
+    speaker TEXT,
+    text TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL
+);
+CREATE TABLE words (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_id INTEGER NOT NULL,
+    word_index INTEGER NOT NULL,
+    word TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    confidence REAL
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute("INSERT INTO recordings VALUES (1, 'a.mp3', 2, 40.0, 'chunk 2 failed')")
+    conn.execute("INSERT INTO segments VALUES (1, 1, 0, 'A', 'hi there', 0.0, 39.0)")
+    conn.executemany(
+        "INSERT INTO words VALUES (NULL, 1, 1, ?, ?, ?, ?, NULL)",
+        [(0, "hi", 0.0, 0.4), (1, "there", 0.5, 1.0)],
+    )
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_append_resumed_segments_completes_the_transcript(isolated_db) -> None:
+    segments = [
+        {
+            "text": "and bye",
+            "start": 40.0,
+            "end": 41.0,
+            "words": [
+                {"word": "and", "start": 40.0, "end": 40.4, "confidence": 0.9},
+                {"word": "bye", "start": 40.5, "end": 41.0, "confidence": None},
+            ],
+        }
+    ]
+    assert db.append_resumed_segments(1, segments, partial_until=None) == 2
+    added = db.get_segments(1)[-1]
+    assert (added["segment_index"], added["speaker"], added["text"]) == (1, None, "and bye")
+    recording = db.get_recording(1)
+    assert (recording["word_count"], recording["partial_until"]) == (4, None)
+    assert recording["partial_reason"] is None
+
+
+def test_set_recording_partial_round_trip(isolated_db) -> None:
+    assert db.set_recording_partial(1, 55.5, "cancelled") is True
+    assert db.get_recording(1)["partial_reason"] == "cancelled"
+    assert db.set_recording_partial(1, None, "ignored") is True
+    assert db.get_recording(1)["partial_reason"] is None
+    assert db.set_recording_partial(99, 1.0) is False
@@ -0,0 +1,61 @@
+"""PII scan: regex patterns, NER span merging and mapping hits onto word timings."""
+
+from __future__ import annotations
+
+from server.core.pii_detection import PiiHit, find_pattern_spans, load_ner_model, scan_transcript
+
+
+def _kinds(text: str) -> list[tuple[str, str]]:
+    return [(kind, text[a:b]) for kind, a, b in find_pattern_spans(text)]
+
+
+def test_patterns_find_emails_phones_and_addresses() -> None:
+    assert _kinds("mail jane.doe@example.com today") == [("email", "jane.doe@example.com")]
+    assert _kinds("it is jane dot doe at example dot com ok") == [
+        ("email", "jane dot doe at example dot com")
+    ]
+    assert _kinds("call +1 (555) 010-4477 now") == [("phone", "+1 (555) 010-4477")]
+    assert _kinds("I live at 221 Baker Street now") == [("address", "221 Baker Street")]
+
+
+def test_short_numbers_are_not_phones() -> None:
+    assert _kinds("in 2024 we sold 3 500 units") == []
+
+
+class _StubNer:
+    """Tags every capitalised token as a name, like a PER-only model would."""
+
+    def entity_spans(self, text: str) -> list[tuple[str, int, int]]:
+        spans, pos = [], 0
+        for token in text.split(" "):
+            if token[:1].isupper():
+                spans.append(("name", pos, pos + len(token)))
+            pos += len(token) + 1
+        return spans
+
+
+_SEGMENTS = [
+    {"id": 1, "text": "ask Ana at 555 010 4477", "start_time": 0.0, "end_time": 4.0},
+    {"id": 2, "text": "bye now", "start_time": 4.0, "end_time": 6.0},
+]
+_WORDS = [
+    {"segment_id": 1, "word": "ask", "start_time": 0.0, "end_time": 0.4},
+    {"segment_id": 1, "word": "Ana", "start_time": 0.5, "end_time": 0.9},
+    {"segment_id": 1, "word": "at", "start_time": 1.0, "end_time": 1.2},
+    {"segment_id": 1, "word": "555", "start_time": 1.5, "end_time": 2.0},
+    {"segment_id": 1, "word": "010", "start_time": 2.1, "end_time": 2.6},
+    {"segment_id": 1, "word": "4477", "start_time": 2.7, "end_time": 3.5},
+]
+
+
+def test_hits_map_to_word_timings() -> None:
+    hits = scan_transcript(_SEGMENTS, _WORDS, ner=_StubNer())
+    assert hits == [
+        PiiHit(kind="name", start_time=0.5, end_time=0.9, text="Ana"),
+        PiiHit(kind="phone", start_time=1.5, end_time=3.5, text="555 010 4477"),
+    ]
+
+
+def test_kinds_filter_and_regex_only_default() -> None:
+    assert [h.kind for h in scan_transcript(_SEGMENTS, _WORDS, kinds=["name"])] == []
+    assert load_ner_model("") is None
@@ -0,0 +1,95 @@
+"""Post-transcription rules: matching, dry-run planning and the run loop."""
+
+from __future__ import annotations
+
+import asyncio
+from pathlib import Path
+from typing import Any
+
+import pytest
+from server.core.post_transcription_rules import JobContext, evaluate, load_rules, run_rules
+
+_REC = {"id": 3, "title": "Episode 12", "recorded_at": "2026-05-08T10:00:00Z"}
+
+
+def _rules(folder: Path) -> list[Any]:
+    return load_rules(
+        [
+            {
+                "name": "podcast",
+                "when": {"watch_folder": "/watch/podcasts", "filename": "*.MP3"},
+                "actions": [
+                    {"type": "export", "format": "srt", "folder": str(folder)},
+                    {"type": "webhook", "url": "https://example.com/hook"},
+                ],
+            },
+            {
+                "name": "manual only",
+                "when": {"watch_folder": "manual", "min_duration": 60},
+                "actions": [{"type": "export", "folder": str(folder)}],
+            },
+        ]
+    )
+
+
+def test_conditions_and_dry_run_plan(tmp_path: Path) -> None:
+    ctx = JobContext(recording_id=3, source="/watch/podcasts/2026", filename="ep12.mp3")
+    report = evaluate(_rules(tmp_path), ctx, _REC, {"template": "{title}.{ext}"})
+    podcast, manual = report
+    assert podcast["matched"] and podcast["actions"] == [
+        {
+            "type": "export",
+            "format": "srt",
+            "path": str(tmp_path / "Episode 12.srt"),
+            "action": "write",
+        },
+        {"type": "webhook", "url": "https://example.com/hook", "include_transcript": False},
+    ]
+    assert not manual["matched"]
+    assert manual["reasons"] == [
+        "source '/watch/podcasts/2026' is not 'manual'",
+        "duration 0s is under 60s",
+    ]
+    other = JobContext(recording_id=3, source="/watch/podcasts-old", filename="ep12.wav")
+    assert len(evaluate(_rules(tmp_path), other, _REC)[0]["reasons"]) == 2
+
+
+def test_run_writes_exports_queues_webhooks_and_survives_failures(tmp_path: Path) -> None:
+    written: dict[str, Any] = {}
+    hooks: list[str] = []
+
+    async def render(recording: dict[str, Any], fmt: str) -> bytes:
+        if fmt == "txt":
+            raise OSError("disk full")
+        return b"1\n00:00:00,000 --> 00:00:01,000\nhi\n"
+
+    async def webhook(recording_id: int, planned: dict[str, Any]) -> None:
+        hooks.append(planned["url"])
+
+    rules = _rules(tmp_path)
+    podcast = JobContext(recording_id=3, source="/watch/podcasts", filename="a.mp3")
+    report = asyncio.run(
+        run_rules(rules, podcast, _REC, render=render, write=written.__setitem__, webhook=webhook)
+    )
+    assert [a["status"] for a in report[0]["actions"]] == ["written", "queued"]
+    assert hooks == ["https://example.com/hook"]
+    assert len(written) == 1
+
+    manual = JobContext(recording_id=3, filename="a.wav", duration=90)
+    report = asyncio.run(
+        run_rules(rules, manual, _REC, render=render, write=written.__setitem__, webhook=webhook)
+    )
+    assert report[1]["actions"][0]["status"] == "failed"
+    assert report[1]["actions"][0]["error"] == "disk full"
+
+
+def test_invalid_rules_are_rejected() -> None:
+    assert load_rules(None) == []
+    with pytest.raises(ValueError, match="needs at least one action"):
+        load_rules([{"name": "empty"}])
+    with pytest.raises(ValueError, match="unknown condition"):
+        load_rules([{"when": {"speaker": "A"}, "actions": [{"type": "webhook", "url": "x"}]}])
+    with pytest.raises(ValueError, match="export action needs a folder"):
+        load_rules([{"actions": [{"type": "export", "format": "srt"}]}])
+    with pytest.raises(ValueError, match="Unsupported output format"):
+        load_rules([{"actions": [{"type": "export", "format": "docx", "folder": "/tmp"}]}])
@@ -0,0 +1,84 @@
+"""Print-ready HTML exporter tests (PDF export source document)."""
+
+from __future__ import annotations
+
+import pytest
+from server.core.print_export import PrintOptions, format_print_timestamp, render_print_html
+
+_REC = {
+    "title": "Deposition <Smith>",
+    "recorded_at": "2026-03-01T10:00:00Z",
+    "duration_seconds": 3725.0,
+    "summary": "Witness statement.",
+}
+
+
+def _segs() -> list[dict]:
+    return [
+        {"speaker": "Counsel", "text": "State your name.", "start_time": 1.0},
+        {"speaker": "Witness", "text": "John Smith.", "start_time": 3.5},
+        {"speaker": "Witness", "text": "I live in Athens.", "start_time": 6.0},
+    ]
+
+
+def test_timestamp_format_switches_to_hours() -> None:
+    assert format_print_timestamp(65.9) == "01:05"
+    assert format_print_timestamp(3725) == "1:02:05"
+    assert format_print_timestamp(-3) == "00:00"
+
+
+def test_title_and_text_are_html_escaped() -> None:
+    out = render_print_html(_REC, _segs())
+    assert "<h1>Deposition &lt;Smith&gt;</h1>" in out
+    assert "<Smith>" not in out
+
+
+def test_speaker_label_only_on_turn_change() -> None:
+    out = render_print_html(_REC, _segs())
+    assert out.count("<strong>Witness</strong>") == 1
+    assert out.count("<strong>Counsel</strong>") == 1
+
+
+def test_caps_and_plain_speaker_styles() -> None:
+    caps = render_print_html(_REC, _segs(), PrintOptions(speaker_style="caps"))
+    assert '<span class="caps">Counsel</span>' in caps
+    plain = render_print_html(_REC, _segs(), PrintOptions(speaker_style="plain"))
+    assert "<strong>" not in plain and "Counsel" in plain
+
+
+def test_timestamp_column_can_be_disabled() -> None:
+    with_ts = render_print_html(_REC, _segs())
+    without_ts = render_print_html(_REC, _segs(), PrintOptions(show_timestamps=False))
+    assert '<td class="ts">00:03</td>' in with_ts
+    assert 'class="ts"' not in without_ts.split("<table>")[1]
+
+
+def test_speaker_column_dropped_without_diarization() -> None:
+    segs = [{"text": "Just one voice.", "start_time": 0.0}]
+    out = render_print_html({"title": "Memo"}, segs)
+    assert 'class="spk"' not in out.split("<table>")[1]
+
+
+def test_page_size_and_font_are_applied() -> None:
+    out = render_print_html(_REC, _segs(), PrintOptions(page_size="Letter", font_size_pt=40))
+    assert "size: Letter" in out
+    # Font size is clamped into the readable range.
+    assert "font-size: 18pt" in out
+
+
+def test_rows_never_split_across_pages() -> None:
+    out = render_print_html(_REC, _segs())
+    assert "break-inside: avoid" in out
+
+
+def test_invalid_options_raise() -> None:
+    with pytest.raises(ValueError):
+        PrintOptions(speaker_style="italic")  # type: ignore[arg-type]
+    with pytest.raises(ValueError):
+        PrintOptions(page_size="A3")  # type: ignore[arg-type]
+
+
+def test_metadata_and_summary_can_be_hidden() -> None:
+    out = render_print_html(_REC, _segs(), PrintOptions(show_metadata=False))
+    assert "Witness statement." not in out
+    assert "Recorded:" not in out
@@ -129,14 +129,14 @@ def test_migration_idempotent(fresh_db: Path) -> None:
         # alembic_version table should still hold a single head revision
         rows = conn.execute("SELECT version_num FROM alembic_version").fetchall()
     assert len(rows) == 1
-    # Head advances with each sprint; accept any current-or-later revision.
-    # 009 = Stories 1.2/1.3, 010 = Story 1.9, 011 = Story 2.1,
-    # 012 = Sprint 2 Item 2, 013 = Sprint 2 Item 3,
-    # 014 = Sprint 3 Story 4.1 (recording_speaker_aliases),
-    # 015 = Sprint 4 Stories 6.2/6.3 (recordings auto-action status),
-    # 016 = Sprint 5 Story 7.1 (webhook_deliveries),
-    # 017 = recordings.transcript_corrected (in-place transcript editing).
-    assert rows[0][0] in {"009", "010", "011", "012", "013", "014", "015", "016", "017"}
+    # Head advances with each migration; compare against whatever it is now.
+    assert rows[0][0] == _alembic_head()
+
+
+def _alembic_head() -> str:
+    from alembic.script import ScriptDirectory
+
+    return ScriptDirectory(str(db.MIGRATIONS_DIR)).get_current_head()
 
 
 def _read_all(db_path: Path, table: str) -> list[dict]:
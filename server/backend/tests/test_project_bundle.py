@@ -0,0 +1,186 @@
+"""Project bundles (.tsproj): lossless round trip, media modes and verification."""
+
+from __future__ import annotations
+
+import json
+import sqlite3
+import zipfile
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.core import project_bundle as pb
+
+_SCHEMA_SQL = """
+CREATE TABLE recordings (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    filename TEXT NOT NULL,
+    filepath TEXT NOT NULL UNIQUE,
+    title TEXT,
+    duration_seconds REAL NOT NULL,
+    recorded_at TEXT NOT NULL,
+    imported_at TEXT,
+    summary TEXT,
+    transcript_corrected TEXT,
+    owner TEXT,
+    auto_summary_status TEXT
+);
+CREATE TABLE segments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_index INTEGER NOT NULL,
+    speaker TEXT,
+    text TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    review_state TEXT
+);
+CREATE TABLE words (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_id INTEGER NOT NULL,
+    word_index INTEGER NOT NULL,
+    word TEXT NOT NULL,
+    start_time REAL,
+    end_time REAL,
+    confidence REAL
+);
+CREATE TABLE recording_speaker_aliases (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    speaker_id TEXT NOT NULL,
+    alias_name TEXT NOT NULL
+);
+CREATE TABLE annotations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_id INTEGER,
+    parent_id INTEGER,
+    start_time REAL NOT NULL,
+    end_time REAL,
+    author TEXT NOT NULL,
+    body TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+CREATE TABLE redactions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    label TEXT,
+    source TEXT NOT NULL DEFAULT 'manual',
+    text TEXT,
+    created_at TEXT NOT NULL
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    audio = tmp_path / "talk.mp3"
+    audio.write_bytes(b"ID3 not really audio" * 100)
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute(
+        "INSERT INTO recordings (id, filename, filepath, title, duration_seconds, recorded_at, "
+        "summary, transcript_corrected, owner, auto_summary_status) "
+        "VALUES (7, 'talk.mp3', ?, 'Talk', 12.5, '2026-03-01T10:00:00', 'Short', "
+        "'Hello world.', 'ana', 'failed')",
+        (str(audio),),
+    )
+    conn.executemany(
+        "INSERT INTO segments VALUES (?, 7, ?, ?, ?, ?, ?, ?)",
+        [
+            (40, 0, "SPEAKER_00", "Hello", 0.0, 1.0, "approved"),
+            (41, 1, "SPEAKER_01", "world", 1.0, 2.0, None),
+        ],
+    )
+    conn.executemany(
+        "INSERT INTO words VALUES (NULL, 7, ?, 0, ?, ?, ?, 0.9)",
+        [(40, "Hello", 0.0, 1.0), (41, "world", 1.0, 2.0)],
+    )
+    conn.execute("INSERT INTO recording_speaker_aliases VALUES (NULL, 7, 'SPEAKER_00', 'Elena')")
+    conn.execute(
+        "INSERT INTO annotations VALUES (90, 7, 41, NULL, 1.0, 2.0, 'ana', 'Check', 't', 't')"
+    )
+    conn.execute(
+        "INSERT INTO annotations VALUES (91, 7, 41, 90, 1.0, 2.0, 'ben', 'Fine', 't', 't')"
+    )
+    conn.execute(
+        "INSERT INTO redactions VALUES (NULL, 7, 1.0, 2.0, 'name', 'manual', 'world', 't')"
+    )
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+    return audio
+
+
+def _strip_ids(project: dict) -> dict:
+    """The project with row ids left out, for comparing an original and its import."""
+    out = json.loads(json.dumps(project))
+    out["recording"].pop("filename")
+    for rows in (v for k, v in out.items() if k != "recording"):
+        for row in rows:
+            for key in ("id", "segment_id", "parent_id"):
+                row.pop(key, None)
+    return out
+
+
+def test_round_trip_with_embedded_media_is_lossless(isolated_db: Path, tmp_path: Path) -> None:
+    bundle_path = tmp_path / "talk.tsproj"
+    manifest = pb.export_project(7, bundle_path, embed_media=True)
+    assert manifest["media"]["mode"] == "embedded"
+
+    bundle = pb.read_project(bundle_path)
+    audio = pb.restore_media(bundle_path, bundle, tmp_path / "imported")
+    assert audio is not None and audio.read_bytes() == isolated_db.read_bytes()
+    new_id = pb.restore_project(bundle.project, audio, owner="ben")
+
+    original, copy = pb.collect_project(7), pb.collect_project(new_id)
+    assert _strip_ids(copy) == _strip_ids(original)
+    assert copy["recording"]["transcript_corrected"] == "Hello world."
+    assert "auto_summary_status" not in copy["recording"]
+
+    segment_ids = [s["id"] for s in copy["segments"]]
+    assert {w["segment_id"] for w in copy["words"]} == set(segment_ids)
+    root, reply = copy["annotations"]
+    assert root["segment_id"] == segment_ids[1]
+    assert reply["parent_id"] == root["id"]
+
+
+def test_reference_media_is_used_only_while_the_hash_matches(
+    isolated_db: Path, tmp_path: Path
+) -> None:
+    bundle_path = tmp_path / "talk.tsproj"
+    assert pb.export_project(7, bundle_path, embed_media=False)["media"]["mode"] == "reference"
+    with zipfile.ZipFile(bundle_path) as zf:
+        assert not any(name.startswith("media/") for name in zf.namelist())
+
+    bundle = pb.read_project(bundle_path)
+    assert pb.restore_media(bundle_path, bundle, tmp_path / "imported") is not None
+    isolated_db.write_bytes(b"re-encoded")
+    assert pb.restore_media(bundle_path, bundle, tmp_path / "imported") is None
+
+
+def test_a_changed_member_is_rejected(isolated_db: Path, tmp_path: Path) -> None:
+    bundle_path = tmp_path / "talk.tsproj"
+    pb.export_project(7, bundle_path)
+    tampered = tmp_path / "tampered.tsproj"
+    with zipfile.ZipFile(bundle_path) as src, zipfile.ZipFile(tampered, "w") as dst:
+        for item in src.infolist():
+            data = src.read(item)
+            if item.filename == "project.json":
+                data = data.replace(b"Hello", b"Howdy")
+            dst.writestr(item, data)
+    with pytest.raises(pb.ProjectBundleError, match="checksum"):
+        pb.read_project(tampered)
+
+    not_a_bundle = tmp_path / "notes.tsproj"
+    not_a_bundle.write_text("plain text")
+    with pytest.raises(pb.ProjectBundleError, match="zip"):
+        pb.read_project(not_a_bundle)
@@ -0,0 +1,69 @@
+"""Punctuation restoration: label parsing, detection and applying marks to segments and words."""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+
+from server.core.punctuation_restoration import (
+    lacks_punctuation,
+    model_dir_for,
+    parse_label,
+    restore_segments,
+)
+
+
+class _StubModel:
+    """Ends a sentence after "well", puts a comma after "monday", capitalises "monday"."""
+
+    def predict(self, words: Sequence[str]) -> list[tuple[str, bool]]:
+        marks = {"well": (".", False), "monday": (",", True)}
+        return [marks.get(w.lower(), ("", False)) for w in words]
+
+
+def test_labels_name_the_mark_or_pair_mark_and_case() -> None:
+    assert parse_label("PERIOD") == (".", False)
+    assert parse_label("question") == ("?", False)
+    assert parse_label(".U") == (".", True)
+    assert parse_label("OU") == ("", True)
+    assert parse_label("OO") == ("", False)
+    assert parse_label("B-MISC") == ("", False)
+
+
+def test_only_bare_lowercase_text_lacks_punctuation() -> None:
+    assert lacks_punctuation(["so we met on monday", "and it went well"])
+    assert not lacks_punctuation(["So we met on Monday"])
+    assert not lacks_punctuation(["so we met, on monday"])
+    assert not lacks_punctuation(["我们周一见面。"])
+    assert not lacks_punctuation(["", "123"])
+
+
+def test_model_is_chosen_by_base_language_then_default() -> None:
+    models = {"en": "/m/en", "default": "/m/multi"}
+    assert model_dir_for(models, "en-US") == "/m/en"
+    assert model_dir_for(models, "fr") == "/m/multi"
+    assert model_dir_for({"en": "/m/en"}, None) == ""
+
+
+def test_marks_span_segments_and_reach_the_words() -> None:
+    words = [
+        {"word": " so", "start": 0.0, "end": 0.2},
+        {"word": " we", "start": 0.2, "end": 0.4},
+        {"word": " met", "start": 0.4, "end": 0.6},
+        {"word": " on", "start": 0.6, "end": 0.8},
+        {"word": " monday", "start": 0.8, "end": 1.2},
+    ]
+    segments = [
+        {"text": "so we met on monday", "start": 0.0, "end": 1.2, "words": words},
+        {"text": "and it went well", "start": 1.2, "end": 2.0},
+        {"text": "see you", "start": 2.0, "end": 2.5},
+    ]
+
+    restore_segments(segments, _StubModel())
+
+    assert [s["text"] for s in segments] == [
+        "So we met on Monday,",
+        "and it went well.",
+        "See you.",
+    ]
+    assert [w["word"] for w in words] == [" So", " we", " met", " on", " Monday,"]
+    assert words[4]["start"] == 0.8
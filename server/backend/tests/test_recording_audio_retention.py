@@ -0,0 +1,169 @@
+"""Source-audio retention for notebook recordings
+(server.database.recording_audio_retention)."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.database import golden_repository
+from server.database.database import (
+    get_recording,
+    get_segments,
+    save_longform_to_database,
+    set_recording_partial,
+    update_recording_keep_audio,
+)
+from server.database.recording_audio_retention import (
+    periodic_recording_audio_retention,
+    retire_old_recording_audio,
+)
+
+
+@pytest.fixture()
+def fresh_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True, exist_ok=True)
+    monkeypatch.setenv("DATA_DIR", str(data_dir))
+    monkeypatch.setattr(db, "_data_dir", None)
+    monkeypatch.setattr(db, "_db_path", None)
+    db.set_data_directory(data_dir)
+    db.init_db()
+    return db.get_db_path()
+
+
+def _recording(tmp_path: Path, name: str, age_days: int) -> tuple[int, Path]:
+    audio = tmp_path / name
+    audio.write_bytes(b"\x00" * 16)
+    rec_id = save_longform_to_database(
+        audio_path=audio, duration_seconds=1.0, transcription_text=f"text of {name}"
+    )
+    assert rec_id
+    with sqlite3.connect(db.get_db_path()) as conn:
+        conn.execute(
+            "UPDATE recordings SET imported_at = datetime('now', ?) WHERE id = ?",
+            (f"-{age_days} days", rec_id),
+        )
+    return rec_id, audio
+
+
+def test_deletes_expired_audio_and_keeps_the_transcript(fresh_db: Path, tmp_path: Path) -> None:
+    old_id, old_audio = _recording(tmp_path, "old.mp3", age_days=40)
+    new_id, new_audio = _recording(tmp_path, "new.mp3", age_days=2)
+
+    assert retire_old_recording_audio(30) == 1
+
+    assert not old_audio.exists()
+    assert new_audio.exists()
+    old = get_recording(old_id)
+    assert old["audio_retired_at"]
+    assert old["filepath"] == str(old_audio)
+    assert get_segments(old_id)[0]["text"] == "text of old.mp3"
+    assert get_recording(new_id)["audio_retired_at"] is None
+    assert retire_old_recording_audio(30) == 0
+
+
+def test_keep_forever_is_never_touched(fresh_db: Path, tmp_path: Path) -> None:
+    rec_id, audio = _recording(tmp_path, "keep.mp3", age_days=400)
+    assert update_recording_keep_audio(rec_id, True)
+
+    assert retire_old_recording_audio(30) == 0
+    assert audio.exists()
+
+    update_recording_keep_audio(rec_id, False)
+    assert retire_old_recording_audio(30) == 1
+
+
+def test_partial_transcript_keeps_its_audio(fresh_db: Path, tmp_path: Path) -> None:
+    rec_id, audio = _recording(tmp_path, "cut.mp3", age_days=400)
+    assert set_recording_partial(rec_id, 55.5, "cancelled")
+
+    assert retire_old_recording_audio(30) == 0
+    assert audio.exists()
+    assert get_recording(rec_id)["audio_retired_at"] is None
+
+    set_recording_partial(rec_id, None)
+    assert retire_old_recording_audio(30) == 1
+
+
+def test_golden_reference_keeps_its_audio(fresh_db: Path, tmp_path: Path) -> None:
+    rec_id, audio = _recording(tmp_path, "golden.mp3", age_days=400)
+    golden_repository.mark_reference(rec_id, "text of golden.mp3")
+
+    assert retire_old_recording_audio(30) == 0
+    assert audio.exists()
+
+    golden_repository.unmark_reference(rec_id)
+    assert retire_old_recording_audio(30) == 1
+
+
+def test_archive_moves_audio_without_overwriting(fresh_db: Path, tmp_path: Path) -> None:
+    archive = tmp_path / "archive"
+    archive.mkdir()
+    (archive / "talk.mp3").write_bytes(b"earlier")
+    rec_id, audio = _recording(tmp_path, "talk.mp3", age_days=40)
+
+    assert retire_old_recording_audio(30, "archive", str(archive)) == 1
+
+    moved = Path(get_recording(rec_id)["filepath"])
+    assert not audio.exists()
+    assert moved == archive / "talk-2.mp3"
+    assert moved.read_bytes() == b"\x00" * 16
+    assert (archive / "talk.mp3").read_bytes() == b"earlier"
+
+
+def test_missing_audio_is_marked_retired(fresh_db: Path, tmp_path: Path) -> None:
+    rec_id, audio = _recording(tmp_path, "gone.mp3", age_days=40)
+    audio.unlink()
+
+    assert retire_old_recording_audio(30) == 1
+    assert get_recording(rec_id)["audio_retired_at"]
+
+
+def test_failed_rows_do_not_block_later_ones(
+    fresh_db: Path, tmp_path: Path, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    stuck = [_recording(tmp_path, f"stuck-{i}.mp3", age_days=40)[1] for i in range(3)]
+    rec_id, audio = _recording(tmp_path, "old.mp3", age_days=40)
+    real_get = db.get_recordings_for_audio_retention
+    monkeypatch.setattr(
+        db,
+        "get_recordings_for_audio_retention",
+        lambda days, after_id=0: real_get(days, after_id, limit=2),
+    )
+    real_unlink = Path.unlink
+
+    def unlink(self: Path, missing_ok: bool = False) -> None:
+        if self.name.startswith("stuck-"):
+            raise PermissionError("in use")
+        real_unlink(self, missing_ok)
+
+    monkeypatch.setattr(Path, "unlink", unlink)
+
+    assert retire_old_recording_audio(30) == 1
+    assert not audio.exists()
+    assert get_recording(rec_id)["audio_retired_at"]
+    assert all(path.exists() for path in stuck)
+
+
+@pytest.mark.parametrize(
+    ("days", "action", "archive_dir"),
+    [(0, "delete", ""), (30, "shred", ""), (30, "archive", "")],
+)
+def test_disabled_or_misconfigured_policy_is_a_no_op(
+    fresh_db: Path, tmp_path: Path, days: int, action: str, archive_dir: str
+) -> None:
+    _, audio = _recording(tmp_path, "old.mp3", age_days=40)
+
+    assert retire_old_recording_audio(days, action, archive_dir) == 0
+    assert audio.exists()
+
+
+async def test_periodic_one_shot_runs_once(fresh_db: Path, tmp_path: Path) -> None:
+    _, audio = _recording(tmp_path, "old.mp3", age_days=40)
+
+    await periodic_recording_audio_retention(30, interval_hours=0)
+
+    assert not audio.exists()
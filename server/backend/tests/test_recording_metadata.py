@@ -0,0 +1,124 @@
+"""Custom metadata fields and project templates: storage, filters and search."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.database import metadata_repository as meta
+from server.database import project_template_repository as templates
+
+_SCHEMA_SQL = """
+CREATE TABLE recordings (
+    id INTEGER PRIMARY KEY,
+    filename TEXT,
+    title TEXT,
+    recorded_at TEXT
+);
+CREATE TABLE recording_metadata (
+    recording_id INTEGER NOT NULL REFERENCES recordings(id) ON DELETE CASCADE,
+    field TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (recording_id, field)
+);
+CREATE TABLE project_templates (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    description TEXT,
+    fields_json TEXT NOT NULL DEFAULT '[]',
+    defaults_json TEXT NOT NULL DEFAULT '{}',
+    options_json TEXT NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute(
+        "INSERT INTO recordings VALUES "
+        "(1, 'a.wav', 'Intake', '2025-03-01T09:00:00'), "
+        "(2, 'b.wav', 'Follow-up', '2025-03-08T09:00:00'), "
+        "(3, 'c.wav', NULL, '2025-04-01T09:00:00')"
+    )
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_set_metadata_trims_and_replaces(isolated_db) -> None:
+    stored = meta.set_metadata(1, {" Client ": " Acme ", "Location": "", "Case": "C-12"})
+
+    assert stored == {"Client": "Acme", "Case": "C-12"}
+    assert meta.get_metadata(1) == {"Case": "C-12", "Client": "Acme"}
+
+    meta.set_metadata(1, {"Client": "Globex"})
+    assert meta.get_metadata(1) == {"Client": "Globex"}
+    assert meta.get_metadata(2) == {}
+
+
+def test_oversized_values_are_rejected() -> None:
+    with pytest.raises(ValueError):
+        meta.normalize_metadata({"Notes": "x" * (meta.MAX_VALUE_LENGTH + 1)})
+
+
+def test_filters_match_every_field_case_insensitively(isolated_db) -> None:
+    meta.set_metadata(1, {"Client": "Acme", "Interviewee": "Dana"})
+    meta.set_metadata(2, {"Client": "Acme", "Interviewee": "Lee"})
+    meta.set_metadata(3, {"Client": "Globex"})
+
+    assert meta.filter_recording_ids({"client": "ACME"}) == {1, 2}
+    assert meta.filter_recording_ids({"Client": "Acme", "Interviewee": "lee"}) == {2}
+    assert meta.filter_recording_ids({"Client": "Initech"}) == set()
+
+
+def test_fields_list_counts_and_common_values(isolated_db) -> None:
+    meta.set_metadata(1, {"Client": "Acme"})
+    meta.set_metadata(2, {"Client": "Acme", "Location": "Berlin"})
+    meta.set_metadata(3, {"Client": "Globex"})
+
+    assert meta.list_fields() == [
+        {"field": "Client", "count": 3, "values": ["Acme", "Globex"]},
+        {"field": "Location", "count": 1, "values": ["Berlin"]},
+    ]
+
+
+def test_search_finds_values_within_the_date_range(isolated_db) -> None:
+    meta.set_metadata(1, {"Case": "2025-CV-001"})
+    meta.set_metadata(3, {"Case": "2025-CV-002"})
+
+    rows = meta.search_metadata("cv-00", start_date="2025-03-01", end_date="2025-03-31")
+
+    assert [(r["recording_id"], r["field"], r["value"]) for r in rows] == [
+        (1, "Case", "2025-CV-001")
+    ]
+
+
+def test_template_round_trip_and_partial_update(isolated_db) -> None:
+    template_id = templates.create_template(
+        name="Client interviews",
+        description=None,
+        fields=[{"key": "Client", "label": "Client"}, {"key": "Case", "label": "Case number"}],
+        defaults={"Client": "Acme"},
+        options={"language": "en", "enable_diarization": True},
+    )
+
+    assert templates.update_template(template_id, defaults={"Client": "Globex"})
+    template = templates.get_template(template_id)
+
+    assert template is not None
+    assert template["fields"][1] == {"key": "Case", "label": "Case number"}
+    assert template["defaults"] == {"Client": "Globex"}
+    assert template["options"] == {"enable_diarization": True, "language": "en"}
+    assert [t["name"] for t in templates.list_templates()] == ["Client interviews"]
+    assert templates.delete_template(template_id)
+    assert templates.get_template(template_id) is None
@@ -0,0 +1,41 @@
+"""Recording owners for per-user namespaces on shared servers (migration 025)."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.database.database import get_all_recordings, get_recording, save_longform_to_database
+
+
+@pytest.fixture()
+def fresh_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True, exist_ok=True)
+    monkeypatch.setenv("DATA_DIR", str(data_dir))
+    monkeypatch.setattr(db, "_data_dir", None)
+    monkeypatch.setattr(db, "_db_path", None)
+    db.set_data_directory(data_dir)
+    db.init_db()
+    return db.get_db_path()
+
+
+def _save(tmp_path: Path, name: str, owner: str | None = None) -> int:
+    audio = tmp_path / name
+    audio.write_bytes(b"\x00")
+    rec_id = save_longform_to_database(
+        audio_path=audio, duration_seconds=1.0, transcription_text=name, owner=owner
+    )
+    assert rec_id
+    return rec_id
+
+
+def test_owner_is_stored_and_listed(fresh_db: Path, tmp_path: Path) -> None:
+    alice_id = _save(tmp_path, "alice.mp3", owner="alice")
+    shared_id = _save(tmp_path, "shared.mp3")
+
+    assert get_recording(alice_id)["owner"] == "alice"
+    assert get_recording(shared_id)["owner"] is None
+    owners = {rec["id"]: rec["owner"] for rec in get_all_recordings()}
+    assert owners == {alice_id: "alice", shared_id: None}
@@ -0,0 +1,122 @@
+"""Redaction: range merging, transcript masking, ffmpeg graphs and the repository."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.core.redaction import MASK, build_audio_filter, merge_ranges, redact_transcript
+from server.database import redaction_repository as repo
+
+_SEGMENTS = [
+    {"id": 1, "text": "call me at five five five", "start_time": 0.0, "end_time": 5.0},
+    {"id": 2, "text": "thanks bye", "start_time": 5.0, "end_time": 6.0},
+]
+_WORDS = [
+    {"segment_id": 1, "word": "call", "start_time": 0.0, "end_time": 0.5},
+    {"segment_id": 1, "word": "me", "start_time": 0.5, "end_time": 1.0},
+    {"segment_id": 1, "word": "at", "start_time": 1.0, "end_time": 1.5},
+    {"segment_id": 1, "word": "five", "start_time": 2.0, "end_time": 2.5},
+    {"segment_id": 1, "word": "five", "start_time": 3.0, "end_time": 3.5},
+    {"segment_id": 1, "word": "five", "start_time": 4.0, "end_time": 4.5},
+]
+
+
+def test_merge_ranges_sorts_and_joins_overlaps() -> None:
+    ranges = [
+        {"start_time": 4.0, "end_time": 5.0},
+        {"start_time": 1.0, "end_time": 2.0},
+        {"start_time": 1.5, "end_time": 3.0},
+        {"start_time": 6.0, "end_time": 6.0},
+    ]
+    assert merge_ranges(ranges) == [(1.0, 3.0), (4.0, 5.0)]
+
+
+def test_redacted_run_collapses_into_one_mask() -> None:
+    segments, words = redact_transcript(_SEGMENTS, _WORDS, [{"start_time": 1.9, "end_time": 4.6}])
+    assert segments[0]["text"] == f"call me at {MASK}"
+    assert segments[1]["text"] == "thanks bye"
+    masked = [w for w in words if w["word"] == MASK]
+    assert len(masked) == 1
+    assert (masked[0]["start_time"], masked[0]["end_time"]) == (2.0, 4.5)
+    assert _SEGMENTS[0]["text"] == "call me at five five five"
+
+
+def test_segment_without_words_is_masked_proportionally() -> None:
+    segments, _ = redact_transcript(_SEGMENTS, [], [{"start_time": 5.5, "end_time": 6.0}])
+    assert segments[1]["text"] == f"thanks {MASK}"
+
+
+def test_audio_filter_graphs() -> None:
+    assert build_audio_filter([], "mute") == "[0:a]anull[out]"
+    mute = build_audio_filter([(1.0, 2.5)], "mute")
+    assert mute == "[0:a]volume=0:enable='between(t,1.000,2.500)'[out]"
+    assert "sine=frequency=1000" in build_audio_filter([(1.0, 2.5)], "bleep")
+    with pytest.raises(ValueError, match="mute, bleep"):
+        build_audio_filter([(1.0, 2.0)], "beep")  # type: ignore[arg-type]
+
+
+_SCHEMA_SQL = """
+CREATE TABLE recordings (id INTEGER PRIMARY KEY, filename TEXT);
+CREATE TABLE redactions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL REFERENCES recordings(id) ON DELETE CASCADE,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    label TEXT,
+    source TEXT NOT NULL DEFAULT 'manual',
+    text TEXT,
+    created_at TEXT NOT NULL
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute("INSERT INTO recordings VALUES (1, 'a.mp3'), (2, 'b.mp3')")
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_repository_round_trip(isolated_db) -> None:
+    ids = repo.add_redactions(
+        1,
+        [
+            {"start_time": 9.0, "end_time": 10.0, "source": "pii", "text": "555-0100"},
+            {"start_time": 2.0, "end_time": 3.0, "label": "name"},
+        ],
+    )
+    rows = repo.list_redactions(1)
+    assert [r["start_time"] for r in rows] == [2.0, 9.0]
+    assert [r["source"] for r in rows] == ["manual", "pii"]
+    assert repo.delete_redaction(2, ids[0]) is False
+    assert repo.clear_redactions(1, source="pii") == 1
+    assert [r["id"] for r in repo.list_redactions(1)] == [ids[1]]
+
+
+def test_repository_rejects_bad_ranges(isolated_db) -> None:
+    with pytest.raises(ValueError, match="invalid redaction range"):
+        repo.add_redactions(1, [{"start_time": 3.0, "end_time": 3.0}])
+    with pytest.raises(ValueError, match="unknown redaction source"):
+        repo.add_redactions(1, [{"start_time": 1.0, "end_time": 2.0, "source": "ai"}])
+    assert repo.list_redactions(1) == []
+
+
+def test_replace_swaps_only_that_source(isolated_db) -> None:
+    (manual,) = repo.add_redactions(1, [{"start_time": 1.0, "end_time": 2.0}])
+    repo.replace_redactions(1, "pii", [{"start_time": 5.0, "end_time": 6.0, "label": "phone"}])
+    repo.replace_redactions(1, "pii", [{"start_time": 7.0, "end_time": 8.0, "label": "email"}])
+    rows = repo.list_redactions(1)
+    assert [(r["id"] == manual, r["source"], r["start_time"]) for r in rows] == [
+        (True, "manual", 1.0),
+        (False, "pii", 7.0),
+    ]
@@ -21,6 +21,8 @@ for _mod_name in ("fastapi", "starlette", "starlette.websockets"):
         if _mod_name == "fastapi":
             _stub.Request = type("Request", (), {})  # type: ignore[attr-defined]
             _stub.WebSocket = type("WebSocket", (), {})  # type: ignore[attr-defined]
+            _stub.HTTPException = type("HTTPException", (Exception,), {})  # type: ignore[attr-defined]
+            _stub.UploadFile = type("UploadFile", (), {})  # type: ignore[attr-defined]
         if _mod_name == "starlette.websockets":
             _stub.WebSocketState = type("WebSocketState", (), {"CONNECTED": 1})  # type: ignore[attr-defined]
         sys.modules[_mod_name] = _stub
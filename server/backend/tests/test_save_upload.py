@@ -0,0 +1,34 @@
+"""save_upload — multipart uploads copied to disk without buffering them whole."""
+
+from __future__ import annotations
+
+import asyncio
+import tempfile
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+from server.api.routes import utils
+from server.api.routes.utils import save_upload
+
+
+def _upload(data: bytes, max_size: int) -> SimpleNamespace:
+    """An UploadFile stand-in spooled the way Starlette spools request files."""
+    spool = tempfile.SpooledTemporaryFile(max_size=max_size)
+    spool.write(data)
+    return SimpleNamespace(file=spool, filename="clip.wav")
+
+
+@pytest.mark.parametrize("max_size", [1024 * 1024, 16], ids=["in-memory", "on-disk"])
+def test_upload_is_copied_whole(
+    max_size: int, tmp_path: Path, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    monkeypatch.setattr(utils, "UPLOAD_COPY_BYTES", 1000)
+    data = bytes(range(256)) * 50
+    upload = _upload(data, max_size)
+
+    with open(tmp_path / "out.wav", "wb") as dest:
+        asyncio.run(save_upload(upload, dest))
+        dest.write(b"!")
+
+    assert (tmp_path / "out.wav").read_bytes() == data + b"!"
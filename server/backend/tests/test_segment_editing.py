@@ -0,0 +1,135 @@
+"""Segment split/merge: gap snapping, merge validation and the DB rewrites."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.core.segment_editing import plan_merge, plan_split
+
+_SEGMENT = {"id": 1, "text": "one two three four", "start_time": 0.0, "end_time": 4.0}
+_WORDS = [
+    {"id": 1, "segment_id": 1, "word": "one", "start_time": 0.0, "end_time": 0.8},
+    {"id": 2, "segment_id": 1, "word": "two", "start_time": 1.0, "end_time": 1.6},
+    {"id": 3, "segment_id": 1, "word": "three", "start_time": 2.4, "end_time": 3.0},
+    {"id": 4, "segment_id": 1, "word": "four", "start_time": 3.1, "end_time": 4.0},
+]
+
+
+def test_split_snaps_to_the_closest_gap() -> None:
+    # 2.1 s is inside "two"…"three"'s pause; 2.8 s is mid-word and snaps to the nearest gap.
+    plan = plan_split(_SEGMENT, _WORDS, 2.1)
+    assert (plan.boundary, plan.gap) == (2.0, (1.6, 2.4))
+    assert (plan.left_text, plan.right_text, plan.right_word_ids) == (
+        "one two",
+        "three four",
+        [3, 4],
+    )
+    assert plan_split(_SEGMENT, _WORDS, 2.8).right_word_ids == [4]
+
+
+def test_split_without_words_uses_estimates() -> None:
+    plan = plan_split({**_SEGMENT, "text": "alpha beta"}, [], 2.0)
+    assert (plan.left_text, plan.right_text, plan.right_word_ids) == ("alpha", "beta", [])
+    with pytest.raises(ValueError, match="at least two words"):
+        plan_split({**_SEGMENT, "text": "alone"}, [], 1.0)
+
+
+_SEGMENTS = [
+    {"id": 10, "text": "a", "start_time": 0.0, "end_time": 1.0},
+    {"id": 11, "text": "b", "start_time": 1.0, "end_time": 2.0},
+    {"id": 12, "text": "c", "start_time": 2.0, "end_time": 3.0},
+]
+
+
+def test_merge_requires_adjacent_segments() -> None:
+    merged = plan_merge(_SEGMENTS, [11, 10], [])
+    assert (merged["keep_id"], merged["drop_ids"], merged["text"]) == (10, [11], "a b")
+    with pytest.raises(ValueError, match="adjacent"):
+        plan_merge(_SEGMENTS, [10, 12], [])
+    with pytest.raises(ValueError, match="at least two"):
+        plan_merge(_SEGMENTS, [10], [])
+    with pytest.raises(LookupError):
+        plan_merge(_SEGMENTS, [10, 99], [])
+
+
+_SCHEMA_SQL = """
+CREATE TABLE recordings (id INTEGER PRIMARY KEY, filename TEXT);
+CREATE TABLE segments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_index INTEGER NOT NULL,
+    speaker TEXT,
+    text TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    review_state TEXT,
+    reviewed_at TEXT
+);
+CREATE TABLE words (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_id INTEGER NOT NULL,
+    word_index INTEGER NOT NULL,
+    word TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    confidence REAL
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute("INSERT INTO recordings VALUES (1, 'a.mp3')")
+    conn.execute(
+        "INSERT INTO segments VALUES (1, 1, 0, 'A', ?, 0.0, 4.0, 'approved', 'x')",
+        (_SEGMENT["text"],),
+    )
+    conn.execute("INSERT INTO segments VALUES (2, 1, 1, 'B', 'five', 4.0, 5.0, NULL, NULL)")
+    for i, w in enumerate(_WORDS):
+        conn.execute(
+            "INSERT INTO words VALUES (?, 1, 1, ?, ?, ?, ?, NULL)",
+            (w["id"], i, w["word"], w["start_time"], w["end_time"]),
+        )
+    conn.execute("INSERT INTO words VALUES (5, 1, 2, 0, 'five', 4.1, 4.9, NULL)")
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_split_then_merge_round_trip(isolated_db) -> None:
+    plan = plan_split(db.get_segments(1)[0], db.get_words(1)[:4], 2.0)
+    new_id = db.split_segment(1, 1, plan.boundary, plan.left_text, plan.right_text, [3, 4])
+    segments = db.get_segments(1)
+    assert [(s["id"], s["segment_index"], s["text"]) for s in segments] == [
+        (1, 0, "one two"),
+        (new_id, 1, "three four"),
+        (2, 2, "five"),
+    ]
+    assert (segments[0]["end_time"], segments[1]["start_time"]) == (2.0, 2.0)
+    assert segments[0]["review_state"] is None
+    assert segments[1]["speaker"] == "A"
+
+    merged = plan_merge(segments, [new_id, 2], db.get_words(1))
+    assert db.merge_segments(1, merged) is True
+    segments = db.get_segments(1)
+    assert [(s["id"], s["segment_index"], s["text"]) for s in segments] == [
+        (1, 0, "one two"),
+        (new_id, 1, "three four five"),
+    ]
+    assert segments[1]["end_time"] == 5.0
+    assert [w["word"] for w in db.get_words(1) if w["segment_id"] == new_id] == [
+        "three",
+        "four",
+        "five",
+    ]
+    assert db.split_segment(1, 99, 1.0, "", "", []) is None
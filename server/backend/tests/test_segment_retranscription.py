@@ -0,0 +1,133 @@
+"""Per-segment re-transcription: clip bounds, splicing and the segment rewrite."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+from types import SimpleNamespace
+
+import pytest
+import server.database.database as db
+from server.core.segment_retranscription import clip_bounds, retranscribe_segment, splice_words
+
+_SEGMENT = {"id": 7, "text": "the quick brown fax", "start_time": 4.0, "end_time": 6.0}
+
+
+def test_clip_bounds_pad_and_clamp() -> None:
+    assert clip_bounds(_SEGMENT, padding=0.5) == (3.5, 6.5)
+    assert clip_bounds({"start_time": 0.1, "end_time": 2.0}, padding=0.5, duration=2.2) == (
+        0.0,
+        2.2,
+    )
+    with pytest.raises(ValueError, match="no duration"):
+        clip_bounds({"start_time": 3.0, "end_time": 3.0}, padding=0.0)
+
+
+def test_padding_words_are_dropped_and_times_shifted() -> None:
+    result = [
+        {
+            "text": "and the quick brown fox jumps",
+            "start": 0.0,
+            "end": 2.8,
+            "words": [
+                {"word": " and", "start": 0.0, "end": 0.25, "probability": 0.9},
+                {"word": " the", "start": 0.4, "end": 0.6, "probability": 0.9},
+                {"word": " quick", "start": 0.7, "end": 1.1, "probability": 0.8},
+                {"word": " brown", "start": 1.2, "end": 1.6, "probability": 0.95},
+                {"word": " fox", "start": 1.7, "end": 2.6, "probability": 0.99},
+                {"word": " jumps", "start": 2.6, "end": 2.8, "probability": 0.7},
+            ],
+        }
+    ]
+    text, words = splice_words(_SEGMENT, result, clip_start=3.5)
+    assert text == "the quick brown fox"
+    assert [(w["start"], w["end"]) for w in words][-1] == (5.2, 6.0)
+    assert words[0]["start"] == 4.0
+    assert words[-1]["confidence"] == 0.99
+
+
+def test_segments_without_words_are_spread_over_the_span() -> None:
+    result = [{"text": " the quick fox", "start": 0.3, "end": 2.4}]
+    text, words = splice_words(_SEGMENT, result, clip_start=3.5)
+    assert text == "the quick fox"
+    assert (words[0]["start"], words[-1]["end"]) == (4.0, 6.0)
+
+
+def test_retranscribe_uses_the_padded_clip() -> None:
+    calls: list[dict] = []
+
+    class _Engine:
+        model_name = "Systran/faster-whisper-large-v3"
+
+        def transcribe_file_span(self, path, **kwargs):
+            calls.append(kwargs)
+            return SimpleNamespace(
+                language="en",
+                segments=[{"start": 0.0, "end": 2.0, "text": " fox", "words": []}],
+            )
+
+    result = retranscribe_segment(_Engine(), Path("/a.wav"), _SEGMENT, padding=0.25)
+    assert calls == [{"start_seconds": 3.75, "duration_seconds": 2.5, "language": None}]
+    assert (result.text, result.previous_text) == ("fox", "the quick brown fax")
+    assert result.to_dict()["model"] == "Systran/faster-whisper-large-v3"
+
+
+_SCHEMA_SQL = """
+CREATE TABLE recordings (id INTEGER PRIMARY KEY, filename TEXT, word_count INTEGER);
+CREATE TABLE segments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_index INTEGER NOT NULL,
+    speaker TEXT,
+    text TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL
+);
+CREATE TABLE words (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_id INTEGER NOT NULL,
+    word_index INTEGER NOT NULL,
+    word TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    confidence REAL
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.execute("INSERT INTO recordings VALUES (1, 'a.mp3', 3)")
+    conn.execute("INSERT INTO segments VALUES (1, 1, 0, 'A', 'hi there', 0.0, 1.0)")
+    conn.execute("INSERT INTO segments VALUES (2, 1, 1, 'B', 'fax', 1.0, 2.0)")
+    conn.executemany(
+        "INSERT INTO words VALUES (NULL, 1, ?, ?, ?, ?, ?, NULL)",
+        [(1, 0, "hi", 0.0, 0.4), (1, 1, "there", 0.5, 1.0), (2, 0, "fax", 1.0, 2.0)],
+    )
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_replace_segment_words_only_touches_that_segment(isolated_db) -> None:
+    words = [
+        {"word": "the", "start": 1.0, "end": 1.3, "confidence": 0.9},
+        {"word": "fox", "start": 1.4, "end": 2.0, "probability": 1.4},
+    ]
+    assert db.replace_segment_words(1, 2, "the fox", words) is True
+    assert [s["text"] for s in db.get_segments(1)] == ["hi there", "the fox"]
+    assert [(w["word"], w["confidence"]) for w in db.get_words(1)] == [
+        ("hi", None),
+        ("there", None),
+        ("the", 0.9),
+        ("fox", 1.0),
+    ]
+    assert db.get_recording(1)["word_count"] == 4
+    assert db.replace_segment_words(1, 99, "x", []) is False
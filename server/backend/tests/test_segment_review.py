@@ -0,0 +1,83 @@
+"""Per-segment QA review state (review mode)."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.core.segment_review import normalize_review_state, review_progress
+
+
+def test_normalize_accepts_states_and_clears() -> None:
+    assert normalize_review_state("Approved") == "approved"
+    assert normalize_review_state(" flagged ") == "flagged"
+    assert normalize_review_state("unreviewed") is None
+    assert normalize_review_state("") is None
+    assert normalize_review_state(None) is None
+    with pytest.raises(ValueError, match="review state"):
+        normalize_review_state("rejected")
+
+
+def test_progress_counts_each_state() -> None:
+    segments = [{"review_state": s} for s in ("approved", "flagged", None, "approved")]
+    assert review_progress(segments) == {
+        "total": 4,
+        "approved": 2,
+        "flagged": 1,
+        "unreviewed": 1,
+        "reviewed_fraction": 0.75,
+    }
+    assert review_progress([])["reviewed_fraction"] == 0.0
+
+
+_SCHEMA_SQL = """
+CREATE TABLE segments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recording_id INTEGER NOT NULL,
+    segment_index INTEGER NOT NULL,
+    speaker TEXT,
+    text TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    review_state TEXT,
+    reviewed_at TEXT
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    for seg_id, recording_id, index in ((1, 1, 0), (2, 1, 1), (3, 2, 0)):
+        conn.execute(
+            "INSERT INTO segments (id, recording_id, segment_index, text, start_time, end_time)"
+            " VALUES (?, ?, ?, 'x', 0, 1)",
+            (seg_id, recording_id, index),
+        )
+    conn.commit()
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_set_state_is_scoped_to_the_recording(isolated_db) -> None:
+    # Segment 3 belongs to recording 2 and must not be touched.
+    assert db.set_segment_review_state(1, [1, 3], "flagged") == 1
+    states = {s["id"]: (s["review_state"], s["reviewed_at"]) for s in db.get_segments(1)}
+    assert states[1][0] == "flagged"
+    assert states[1][1] is not None
+    assert states[2] == (None, None)
+    assert db.get_segments(2)[0]["review_state"] is None
+
+
+def test_clearing_state_also_clears_timestamp(isolated_db) -> None:
+    db.set_segment_review_state(1, [1, 2], "approved")
+    assert db.set_segment_review_state(1, [2], None) == 1
+    assert [(s["review_state"], s["reviewed_at"]) for s in db.get_segments(1)][1] == (None, None)
+    assert db.set_segment_review_state(1, [], "approved") == 0
@@ -0,0 +1,218 @@
+"""Tests for direct share targets (server.core.share)."""
+
+from __future__ import annotations
+
+from email import message_from_string
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+from server.core import share
+from server.core.share import ShareContent, ShareError
+from server.utils import keychain
+
+
+def _content() -> ShareContent:
+    return ShareContent(
+        title="Board Meeting",
+        summary="Quarterly numbers were reviewed.",
+        attachment_name="Board_Meeting.txt",
+        attachment_bytes=b"# Board Meeting\n\n**Alice:** Hello.\n",
+        attachment_media_type="text/plain; charset=utf-8",
+    )
+
+
+def _patch_targets(targets: list[dict]):
+    cfg = MagicMock()
+    cfg.get = MagicMock(
+        side_effect=lambda *keys, default=None: targets if keys == ("share", "targets") else default
+    )
+    return patch("server.config.get_config", return_value=cfg)
+
+
+def _mock_httpx(status_code: int = 200) -> tuple[MagicMock, AsyncMock]:
+    response = MagicMock(status_code=status_code)
+    client = AsyncMock()
+    client.post = AsyncMock(return_value=response)
+    client.put = AsyncMock(return_value=response)
+    client.__aenter__ = AsyncMock(return_value=client)
+    client.__aexit__ = AsyncMock(return_value=False)
+    httpx = MagicMock()
+    httpx.AsyncClient = MagicMock(return_value=client)
+    return httpx, client
+
+
+# ──────────────────────────────────────────────────────────────────────────
+# Target resolution
+# ──────────────────────────────────────────────────────────────────────────
+
+
+def test_configured_targets_drops_malformed_entries() -> None:
+    with _patch_targets(
+        [
+            {"name": "ok", "type": "slack"},
+            {"name": "", "type": "slack"},
+            {"name": "bad-type", "type": "fax"},
+            "not-a-dict",
+        ]
+    ):
+        names = [t["name"] for t in share.configured_targets()]
+    assert names == ["ok"]
+
+
+def test_get_target_unknown_name_raises() -> None:
+    with _patch_targets([]), pytest.raises(ShareError) as exc:
+        share.get_target("nope")
+    assert exc.value.code == "unknown_target"
+
+
+def test_get_target_instantiates_registered_type() -> None:
+    with _patch_targets([{"name": "cloud", "type": "webdav", "url": "https://x"}]):
+        target = share.get_target("cloud")
+    assert isinstance(target, share.WebDavShareTarget)
+
+
+# ──────────────────────────────────────────────────────────────────────────
+# Credentials
+# ──────────────────────────────────────────────────────────────────────────
+
+
+def test_email_needs_a_password_only_for_smtp_login() -> None:
+    draft = share.EmailShareTarget("me", {"to": "me@example.com"})
+    relay = share.EmailShareTarget("me", {"smtp_host": "smtp.example.com", "smtp_username": "me"})
+    assert draft.required_credentials() == ()
+    assert relay.required_credentials() == ("smtp_password",)
+
+
+def test_set_credentials_stores_and_clears_keychain_entries(fake_keyring) -> None:
+    with _patch_targets([{"name": "cloud", "type": "webdav", "url": "https://x"}]):
+        assert share.missing_credentials(share.get_target("cloud")) == ["password"]
+        assert share.set_credentials("cloud", {"password": "s3cret"}) == []
+        assert keychain.get("share.cloud.password") == "s3cret"
+        assert share.set_credentials("cloud", {"password": ""}) == ["password"]
+    assert keychain.get("share.cloud.password") is None
+
+
+def test_set_credentials_rejects_fields_the_target_does_not_read(fake_keyring) -> None:
+    with (
+        _patch_targets([{"name": "team", "type": "slack"}]),
+        pytest.raises(ShareError) as exc,
+    ):
+        share.set_credentials("team", {"password": "x"})
+    assert exc.value.code == "invalid_config"
+    assert keychain.get("share.team.password") is None
+
+
+# ──────────────────────────────────────────────────────────────────────────
+# Email
+# ──────────────────────────────────────────────────────────────────────────
+
+
+async def test_email_without_smtp_returns_draft_with_attachment() -> None:
+    target = share.EmailShareTarget("me", {"name": "me", "to": "me@example.com"})
+    result = await target.send(_content())
+
+    assert result.status == "draft"
+    msg = message_from_string(result.detail["eml"])
+    assert msg["To"] == "me@example.com"
+    assert msg["X-Unsent"] == "1"
+    attachments = [p for p in msg.walk() if p.get_filename()]
+    assert [a.get_filename() for a in attachments] == ["Board_Meeting.txt"]
+
+
+async def test_email_smtp_login_requires_keychain_password(fake_keyring) -> None:
+    target = share.EmailShareTarget(
+        "me",
+        {"to": "me@example.com", "smtp_host": "smtp.example.com", "smtp_username": "me"},
+    )
+    with pytest.raises(ShareError) as exc:
+        await target.send(_content())
+    assert exc.value.code == "missing_credential"
+
+
+# ──────────────────────────────────────────────────────────────────────────
+# Slack / Discord
+# ──────────────────────────────────────────────────────────────────────────
+
+
+async def test_slack_posts_summary_to_keychain_url(fake_keyring) -> None:
+    keychain.set("share.team.webhook_url", "https://hooks.slack.com/services/T/B/X")
+    httpx, client = _mock_httpx()
+    with (
+        patch.object(share, "_get_httpx", return_value=httpx),
+        patch.object(share, "_check_url"),
+    ):
+        result = await share.SlackShareTarget("team", {}).send(_content())
+
+    assert result.status == "sent"
+    url = client.post.call_args.args[0]
+    payload = client.post.call_args.kwargs["json"]
+    assert url == "https://hooks.slack.com/services/T/B/X"
+    assert "Board Meeting" in payload["text"]
+    assert "Quarterly numbers" in payload["text"]
+
+
+async def test_discord_payload_is_capped_at_2000_chars() -> None:
+    content = _content()
+    content.summary = "x" * 5000
+    payload = share.DiscordShareTarget("d", {}).build_payload(content)
+    assert len(payload["content"]) == 2000
+
+
+async def test_chat_webhook_http_error_raises(fake_keyring) -> None:
+    keychain.set("share.team.webhook_url", "https://hooks.slack.com/services/T/B/X")
+    httpx, _ = _mock_httpx(status_code=500)
+    with (
+        patch.object(share, "_get_httpx", return_value=httpx),
+        patch.object(share, "_check_url"),
+        pytest.raises(ShareError) as exc,
+    ):
+        await share.SlackShareTarget("team", {}).send(_content())
+    assert exc.value.code == "delivery_failed"
+
+
+async def test_slack_missing_webhook_url_is_reported(fake_keyring) -> None:
+    with pytest.raises(ShareError) as exc:
+        await share.SlackShareTarget("team", {}).send(_content())
+    assert exc.value.code == "missing_credential"
+
+
+# ──────────────────────────────────────────────────────────────────────────
+# WebDAV
+# ──────────────────────────────────────────────────────────────────────────
+
+
+def test_webdav_upload_url_quotes_path_segments() -> None:
+    target = share.WebDavShareTarget(
+        "nc",
+        {"url": "https://cloud.example.com/remote.php/dav/files/alice/", "remote_dir": "My Notes"},
+    )
+    assert target.upload_url("Board Meeting.txt") == (
+        "https://cloud.example.com/remote.php/dav/files/alice/My%20Notes/Board%20Meeting.txt"
+    )
+
+
+async def test_webdav_puts_attachment_with_basic_auth(fake_keyring) -> None:
+    keychain.set("share.nc.password", "s3cret")
+    httpx, client = _mock_httpx(status_code=201)
+    target = share.WebDavShareTarget(
+        "nc", {"url": "https://cloud.example.com/dav", "username": "alice"}
+    )
+    with (
+        patch.object(share, "_get_httpx", return_value=httpx),
+        patch.object(share, "_check_url"),
+    ):
+        result = await target.send(_content())
+
+    assert result.status == "uploaded"
+    kwargs = client.put.call_args.kwargs
+    assert kwargs["auth"] == ("alice", "s3cret")
+    assert kwargs["content"].startswith(b"# Board Meeting")
+
+
+def test_private_network_allowed_only_when_opted_in(private_ip_resolver) -> None:
+    private_ip_resolver.add("nextcloud.lan.example", "192.168.1.20")
+    url = "https://nextcloud.lan.example/dav/file.txt"
+    with pytest.raises(ShareError) as exc:
+        share._check_url(url, allow_private=False)
+    assert exc.value.code == "url_blocked"
+    share._check_url(url, allow_private=True)
@@ -0,0 +1,133 @@
+"""Voice profiles: fbank features, turn selection, matching and the repository."""
+
+from __future__ import annotations
+
+import sqlite3
+from pathlib import Path
+
+import numpy as np
+import pytest
+import server.database.database as db
+from server.core.speaker_identification import (
+    MIN_ENROLL_SECONDS,
+    NUM_MEL_BINS,
+    SAMPLE_RATE,
+    enrollment_embedding,
+    fbank,
+    match_speakers,
+    speaker_clips,
+)
+from server.database import voice_profile_repository as repo
+
+
+def test_fbank_frames_and_bins() -> None:
+    rng = np.random.default_rng(0)
+    feats = fbank(rng.standard_normal(SAMPLE_RATE).astype(np.float32) * 0.1)
+    # 1 s at 25 ms frames / 10 ms hop → 98 frames.
+    assert feats.shape == (98, NUM_MEL_BINS)
+    assert feats.dtype == np.float32
+    assert np.isfinite(feats).all()
+    assert fbank(np.zeros(100, dtype=np.float32)).shape == (0, NUM_MEL_BINS)
+
+
+def test_fbank_puts_a_tone_in_the_matching_mel_bin() -> None:
+    t = np.arange(SAMPLE_RATE) / SAMPLE_RATE
+    low = fbank(0.5 * np.sin(2 * np.pi * 300 * t)).mean(axis=0)
+    high = fbank(0.5 * np.sin(2 * np.pi * 3000 * t)).mean(axis=0)
+    assert int(np.argmax(low)) < int(np.argmax(high))
+
+
+def test_speaker_clips_prefers_longest_turns_within_budget() -> None:
+    segments = [
+        {"speaker": "SPEAKER_00", "start_time": 0.0, "end_time": 4.0},
+        {"speaker": "SPEAKER_01", "start_time": 4.0, "end_time": 4.5},  # too short
+        {"speaker": "SPEAKER_00", "start_time": 10.0, "end_time": 20.0},
+        {"speaker": "SPEAKER_01", "start_time": 20.0, "end_time": 23.0},
+        {"speaker": None, "start_time": 30.0, "end_time": 40.0},
+    ]
+    clips = speaker_clips(segments, max_seconds=12.0)
+    assert clips == {
+        "SPEAKER_00": [(0.0, 2.0), (10.0, 20.0)],
+        "SPEAKER_01": [(20.0, 23.0)],
+    }
+
+
+def test_match_is_one_to_one_and_thresholded() -> None:
+    profiles = [
+        {"name": "Alice", "embedding": [1.0, 0.0, 0.0]},
+        {"name": "Bob", "embedding": [0.0, 1.0, 0.0]},
+    ]
+    speakers = {
+        "SPEAKER_00": [0.9, 0.1, 0.0],
+        "SPEAKER_01": [0.8, 0.3, 0.0],  # closer to Alice too, but Alice is taken
+        "SPEAKER_02": [0.0, 0.0, 1.0],
+    }
+    matches = match_speakers(speakers, profiles, threshold=0.3)
+    assert {k: v[0] for k, v in matches.items()} == {"SPEAKER_00": "Alice", "SPEAKER_01": "Bob"}
+    assert match_speakers(speakers, profiles, threshold=0.999) == {}
+
+
+def test_enrollment_rejects_short_samples() -> None:
+    class _Embedder:
+        def embed(self, audio: np.ndarray) -> np.ndarray:
+            return np.array([0.6, 0.8], dtype=np.float32)
+
+    short = np.zeros(int((MIN_ENROLL_SECONDS - 1) * SAMPLE_RATE), dtype=np.float32)
+    with pytest.raises(ValueError, match="at least"):
+        enrollment_embedding(_Embedder(), short)  # type: ignore[arg-type]
+    enough = np.zeros(int(MIN_ENROLL_SECONDS * SAMPLE_RATE), dtype=np.float32)
+    assert enrollment_embedding(_Embedder(), enough) == pytest.approx([0.6, 0.8])
+
+
+_SCHEMA_SQL = """
+CREATE TABLE voice_profiles (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    embedding BLOB NOT NULL,
+    sample_count INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+"""
+
+
+@pytest.fixture
+def isolated_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True)
+    db_path = data_dir / "database" / "notebook.db"
+    conn = sqlite3.connect(str(db_path))
+    conn.executescript(_SCHEMA_SQL)
+    conn.close()
+    monkeypatch.setattr(db, "_data_dir", data_dir)
+    monkeypatch.setattr(db, "_db_path", db_path)
+
+
+def test_samples_fold_into_a_running_mean(isolated_db) -> None:
+    first = repo.add_sample("Bob", [3.0, 4.0])
+    assert first["sample_count"] == 1
+    second = repo.add_sample("bob", [0.0, 1.0])
+    assert second["id"] == first["id"]
+    assert second["name"] == "Bob"
+    assert second["sample_count"] == 2
+
+    (profile,) = repo.list_profiles(with_embeddings=True)
+    # mean of (0.6, 0.8) and (0, 1), renormalised
+    expected = np.array([0.3, 0.9]) / np.linalg.norm([0.3, 0.9])
+    assert profile["embedding"] == pytest.approx(expected.tolist(), abs=1e-6)
+    assert "embedding" not in repo.list_profiles()[0]
+
+    with pytest.raises(ValueError, match="does not match"):
+        repo.add_sample("Bob", [1.0, 0.0, 0.0])
+
+
+def test_rename_and_delete(isolated_db) -> None:
+    alice = repo.add_sample("Alice", [1.0, 0.0])
+    repo.add_sample("Bob", [0.0, 1.0])
+    with pytest.raises(sqlite3.IntegrityError):
+        repo.rename_profile(alice["id"], "BOB")
+    assert repo.rename_profile(alice["id"], "Alicia") is True
+    assert [p["name"] for p in repo.list_profiles()] == ["Alicia", "Bob"]
+    assert repo.delete_profile(alice["id"]) is True
+    assert repo.delete_profile(alice["id"]) is False
+    assert repo.rename_profile(alice["id"], "Ghost") is False
@@ -0,0 +1,59 @@
+"""Speaker statistics: talk time, pace, monologues and interruptions."""
+
+from __future__ import annotations
+
+from server.core.print_export import render_print_html
+from server.core.speaker_stats import compute_speaker_stats, stats_text_lines
+
+
+def _seg(speaker: str | None, start: float, end: float, text: str) -> dict:
+    return {"speaker": speaker, "start_time": start, "end_time": end, "text": text}
+
+
+_MEETING = [
+    _seg("SPEAKER_00", 0.0, 30.0, " ".join(["word"] * 60) + "."),
+    _seg("SPEAKER_00", 31.0, 60.0, " ".join(["word"] * 40) + "."),
+    _seg("SPEAKER_01", 61.0, 70.0, "So what I was going to say is that"),
+    _seg("SPEAKER_00", 70.1, 80.0, "Sorry, go on."),
+    _seg("SPEAKER_01", 79.0, 90.0, "Thanks, the numbers look fine."),
+    _seg(None, 90.0, 95.0, "[music]"),
+]
+
+
+def test_talk_time_share_and_pace_per_speaker() -> None:
+    stats = compute_speaker_stats(_MEETING, {"SPEAKER_00": "Ana", "SPEAKER_01": "Speaker 2"})
+    first, second = stats["speakers"]
+    assert (first["label"], first["talk_seconds"], first["words"]) == ("Ana", 68.9, 103)
+    assert first["words_per_minute"] == round(103 / (68.9 / 60), 1)
+    assert (second["label"], second["talk_seconds"], second["turns"]) == ("Speaker 2", 20.0, 2)
+    assert stats["total_talk_seconds"] == 88.9
+    assert round(first["share"] + second["share"], 3) == 1.0
+
+
+def test_longest_monologue_spans_consecutive_segments() -> None:
+    ana = compute_speaker_stats(_MEETING)["speakers"][0]
+    assert (ana["longest_monologue_seconds"], ana["longest_monologue_start"]) == (60.0, 0.0)
+    assert ana["turns"] == 2
+
+
+def test_cut_off_mid_sentence_and_overlap_count_as_interruptions() -> None:
+    ana, other = compute_speaker_stats(_MEETING)["speakers"]
+    # Ana cut in 0.1 s after an unfinished sentence; the reply overlapped her.
+    assert (ana["interruptions"], ana["interrupted"]) == (1, 1)
+    assert (other["interruptions"], other["interrupted"]) == (1, 1)
+    # A normal hand-over after a finished sentence is not an interruption.
+    calm = compute_speaker_stats([_seg("A", 0, 5, "Done."), _seg("B", 5.1, 9, "Okay")])
+    assert all(s["interruptions"] == 0 for s in calm["speakers"])
+
+
+def test_undiarized_recording_has_no_speakers() -> None:
+    stats = compute_speaker_stats([_seg(None, 0, 5, "hello")])
+    assert stats["speakers"] == [] and stats_text_lines(stats) == []
+
+
+def test_stats_render_in_text_and_print_exports() -> None:
+    stats = compute_speaker_stats(_MEETING, {"SPEAKER_00": "Ana & Co"})
+    assert stats_text_lines(stats)[0].startswith("Ana & Co: 1:09 talk time (78%), 90 wpm")
+    doc = render_print_html({"title": "T"}, _MEETING, speaker_stats=stats)
+    assert "<h2>Speaker statistics</h2>" in doc and "Ana &amp; Co" in doc
+    assert "Speaker statistics" not in render_print_html({"title": "T"}, _MEETING)
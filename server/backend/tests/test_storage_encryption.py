@@ -0,0 +1,194 @@
+"""Tests for at-rest audio encryption (server.core.storage_encryption)."""
+
+from __future__ import annotations
+
+import base64
+import os
+from unittest.mock import MagicMock, patch
+
+import pytest
+from server.core import storage_encryption as enc
+from server.core.storage_encryption import StorageEncryptionError
+from server.utils import keychain
+
+KEY = bytes(range(32))
+KEY_B64 = base64.b64encode(KEY).decode()
+
+
+def _patch_config(enabled: bool = True, key_file: str = ""):
+    values = {
+        ("storage_encryption", "enabled"): enabled,
+        ("storage_encryption", "key_file"): key_file,
+    }
+    cfg = MagicMock()
+    cfg.get = MagicMock(side_effect=lambda *keys, default=None: values.get(keys, default))
+    return patch("server.config.get_config", return_value=cfg)
+
+
+def _payload(size: int) -> bytes:
+    return bytes((i * 7 + i // 251) % 256 for i in range(size))
+
+
+def _write(tmp_path, data: bytes, name: str = "rec.wav"):
+    path = tmp_path / name
+    path.write_bytes(data)
+    return path
+
+
+@pytest.mark.parametrize("size", [0, 1, enc.CHUNK_BYTES, enc.CHUNK_BYTES * 2 + 5])
+def test_round_trip(tmp_path, size):
+    data = _payload(size)
+    path = _write(tmp_path, data)
+    enc.encrypt_file(path, KEY)
+
+    assert enc.is_encrypted(path)
+    assert size < 16 or data[:16] not in path.read_bytes()
+    assert enc.plaintext_size(path) == size
+    assert b"".join(enc.iter_plaintext(path, key=KEY)) == data
+    assert not list(tmp_path.glob(".*.enc-tmp"))
+
+
+def test_ranged_reads_cross_chunk_boundaries(tmp_path):
+    data = _payload(enc.CHUNK_BYTES * 3 + 100)
+    path = _write(tmp_path, data)
+    enc.encrypt_file(path, KEY)
+
+    for start, end in [
+        (0, 0),
+        (enc.CHUNK_BYTES - 10, enc.CHUNK_BYTES + 10),
+        (enc.CHUNK_BYTES, enc.CHUNK_BYTES * 2 - 1),
+        (5, len(data) + 1000),
+    ]:
+        got = b"".join(enc.iter_plaintext(path, start, end, key=KEY))
+        assert got == data[start : end + 1]
+    assert list(enc.iter_plaintext(path, len(data), key=KEY)) == []
+
+
+def test_wrong_or_missing_key_raises(tmp_path, fake_keyring):
+    path = _write(tmp_path, _payload(1000))
+    enc.encrypt_file(path, KEY)
+
+    with pytest.raises(StorageEncryptionError, match="different master key"):
+        b"".join(enc.iter_plaintext(path, key=bytes(32)))
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: ""}), _patch_config():
+        with pytest.raises(StorageEncryptionError, match="no storage master key"):
+            enc.read_plaintext(path)
+
+
+def test_truncation_is_detected(tmp_path):
+    path = _write(tmp_path, _payload(enc.CHUNK_BYTES * 2 + 10))
+    enc.encrypt_file(path, KEY)
+    sealed = path.read_bytes()
+    path.write_bytes(sealed[: enc.HEADER_BYTES + enc.SEALED_CHUNK_BYTES])
+
+    with pytest.raises(StorageEncryptionError, match="corrupt or truncated"):
+        b"".join(enc.iter_plaintext(path, key=KEY))
+
+
+def test_plaintext_files_pass_through(tmp_path):
+    data = _payload(5000)
+    path = _write(tmp_path, data)
+
+    assert not enc.is_encrypted(path)
+    assert enc.plaintext_size(path) == len(data)
+    assert b"".join(enc.iter_plaintext(path, 100, 199)) == data[100:200]
+    with enc.plaintext_path(path) as readable:
+        assert readable == path
+
+
+def test_plaintext_path_decrypts_to_a_temporary_copy(tmp_path):
+    data = _payload(enc.CHUNK_BYTES + 1)
+    path = _write(tmp_path, data, "clip.mp3")
+    enc.encrypt_file(path, KEY)
+
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: KEY_B64}):
+        with enc.plaintext_path(path) as readable:
+            assert readable != path
+            assert readable.suffix == ".mp3"
+            assert readable.read_bytes() == data
+    assert not readable.exists()
+
+
+def test_encrypt_at_rest_keeps_plaintext_when_disabled_or_keyless(tmp_path):
+    data = _payload(100)
+    path = _write(tmp_path, data)
+
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: KEY_B64}), _patch_config(enabled=False):
+        assert enc.encrypt_at_rest(path) is False
+    with (
+        patch.dict(os.environ, {enc.MASTER_KEY_ENV: ""}),
+        _patch_config(),
+        patch.object(keychain, "get", return_value=None),
+        patch.object(keychain, "set", side_effect=keychain.KeychainUnavailableError("none")),
+    ):
+        assert enc.encrypt_at_rest(path) is False
+    assert path.read_bytes() == data
+
+
+def test_encrypt_at_rest_generates_a_keychain_key_once(tmp_path, fake_keyring):
+    first = _write(tmp_path, _payload(100), "a.wav")
+    second = _write(tmp_path, _payload(100), "b.wav")
+
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: ""}), _patch_config():
+        assert enc.encrypt_at_rest(first) is True
+        stored = keychain.get(enc.KEYCHAIN_KEY)
+        assert enc.encrypt_at_rest(second) is True
+        assert keychain.get(enc.KEYCHAIN_KEY) == stored
+        assert enc.read_plaintext(first) == _payload(100)
+        assert enc.read_plaintext(second) == _payload(100)
+
+
+def test_key_sources_take_precedence_over_the_keychain(tmp_path, fake_keyring):
+    key_file = tmp_path / "master.key"
+    key_file.write_text(KEY_B64 + "\n")
+    first = _write(tmp_path, _payload(100), "a.wav")
+    second = _write(tmp_path, _payload(100), "b.wav")
+
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: KEY_B64}), _patch_config():
+        assert enc.encrypt_at_rest(first) is True
+        assert enc.encrypt_at_rest(first) is True  # already encrypted: untouched
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: ""}), _patch_config(key_file=str(key_file)):
+        assert enc.encrypt_at_rest(second) is True
+    assert keychain.get(enc.KEYCHAIN_KEY) is None
+    assert b"".join(enc.iter_plaintext(first, key=KEY)) == _payload(100)
+    assert b"".join(enc.iter_plaintext(second, key=KEY)) == _payload(100)
+
+
+def test_files_stay_readable_when_the_key_source_changes(tmp_path, fake_keyring):
+    first = _write(tmp_path, _payload(100), "a.wav")
+    second = _write(tmp_path, _payload(100), "b.wav")
+
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: ""}), _patch_config():
+        assert enc.encrypt_at_rest(first) is True  # generates the keychain key
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: KEY_B64}), _patch_config():
+        assert enc.encrypt_at_rest(second) is True  # the dashboard's key wins
+        assert enc.read_plaintext(first) == _payload(100)
+        assert enc.read_plaintext(second) == _payload(100)
+    with pytest.raises(StorageEncryptionError, match="different master key"):
+        b"".join(enc.iter_plaintext(first, key=KEY))
+
+
+def test_no_new_key_while_the_usual_one_is_missing(tmp_path, fake_keyring):
+    first = _write(tmp_path, _payload(100), "a.wav")
+    second = _write(tmp_path, _payload(100), "b.wav")
+
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: KEY_B64}), _patch_config():
+        assert enc.encrypt_at_rest(first) is True
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: ""}), _patch_config():
+        assert enc.encrypt_at_rest(second) is False
+    assert keychain.get(enc.KEYCHAIN_KEY) is None
+    assert second.read_bytes() == _payload(100)
+
+    missing = str(tmp_path / "absent.key")
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: ""}), _patch_config(key_file=missing):
+        assert enc.master_key(create=True) is None
+    with patch.dict(os.environ, {enc.MASTER_KEY_ENV: KEY_B64}), _patch_config():
+        assert enc.read_plaintext(first) == _payload(100)
+
+
+def test_parse_master_key_rejects_bad_input():
+    assert enc.parse_master_key(f"  {KEY_B64}\n") == KEY
+    with pytest.raises(ValueError, match="base64"):
+        enc.parse_master_key("not base64!!")
+    with pytest.raises(ValueError, match="32 bytes"):
+        enc.parse_master_key(base64.b64encode(b"short").decode())
@@ -412,6 +412,8 @@ class TestTranscriptionResult:
             "total_words",
             "partial",
             "partial_reason",
+            "completed_seconds",
+            "downgrade",
             "metadata",
         }
         assert set(d.keys()) == expected_keys
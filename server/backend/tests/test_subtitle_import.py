@@ -0,0 +1,103 @@
+"""Subtitle (SRT / VTT / ASS) import parsing and the alignment fallback."""
+
+from __future__ import annotations
+
+import pytest
+from server.core.forced_alignment import align_segments, proportional_words
+from server.core.subtitle_export import SubtitleCue, render_ass, render_srt
+from server.core.subtitle_import import detect_format, parse_subtitles
+
+_SRT = (
+    "\ufeff1\r\n00:00:01,000 --> 00:00:02,500\r\n<i>Hello</i> there.\r\n\r\n"
+    "2\r\n00:00:03,000 --> 00:00:04,000\r\nSpeaker 2: General\r\nKenobi.\r\n"
+)
+
+
+def test_srt_parses_times_strips_tags_and_splits_speaker_prefix() -> None:
+    cues = parse_subtitles(_SRT, "srt")
+    assert [(c.start, c.end, c.text, c.speaker) for c in cues] == [
+        (1.0, 2.5, "Hello there.", None),
+        (3.0, 4.0, "General Kenobi.", "Speaker 2"),
+    ]
+
+
+def test_srt_without_indices_is_accepted() -> None:
+    cues = parse_subtitles("00:00:00,500 --> 00:00:01,000\nHi\n", "srt")
+    assert [(c.start, c.text) for c in cues] == [(0.5, "Hi")]
+
+
+def test_vtt_voice_spans_cue_settings_and_notes() -> None:
+    content = (
+        "WEBVTT - sample\n\nNOTE this is ignored\n\n"
+        "intro\n00:01.250 --> 00:02.000 align:start\n<v Elena>Good morning</v>\n\n"
+        "01:00:00.000 --> 01:00:01.000\nplain\n"
+    )
+    cues = parse_subtitles(content, "vtt")
+    assert [(c.start, c.end, c.text, c.speaker) for c in cues] == [
+        (1.25, 2.0, "Good morning", "Elena"),
+        (3600.0, 3601.0, "plain", None),
+    ]
+
+
+def test_vtt_requires_header() -> None:
+    with pytest.raises(ValueError, match="WEBVTT"):
+        parse_subtitles("00:01.000 --> 00:02.000\nhi\n", "vtt")
+
+
+def test_ass_uses_format_line_name_field_and_strips_overrides() -> None:
+    content = (
+        "[Script Info]\nTitle: x\n\n[Events]\n"
+        "Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n"
+        "Dialogue: 0,0:00:01.50,0:00:03.00,Default,Alex,0,0,0,,{\\an8}Line one\\Nline, two\n"
+    )
+    cues = parse_subtitles(content, "ass")
+    assert [(c.start, c.end, c.text, c.speaker) for c in cues] == [
+        (1.5, 3.0, "Line one line, two", "Alex"),
+    ]
+
+
+def test_malformed_ass_dialogue_names_the_line() -> None:
+    with pytest.raises(ValueError, match="line 2"):
+        parse_subtitles("[Events]\nDialogue: 0,0:00:01.00,0:00:02.00,x\n", "ass")
+
+
+def test_empty_file_is_rejected() -> None:
+    with pytest.raises(ValueError, match="No cues"):
+        parse_subtitles("garbage\n", "srt")
+
+
+def test_detect_format_prefers_extension_then_sniffs() -> None:
+    assert detect_format("a.VTT", "") == "vtt"
+    assert detect_format("a.ssa", "") == "ass"
+    assert detect_format("upload", "WEBVTT\n\n") == "vtt"
+    assert detect_format("upload", "[Script Info]\n") == "ass"
+    assert detect_format("upload", "1\n00:00:00,000 --> 00:00:01,000\n") == "srt"
+
+
+def test_our_own_exports_round_trip_with_speakers() -> None:
+    original = [
+        SubtitleCue(0.0, 1.5, "Speaker 1: Hello there.", "Speaker 1"),
+        SubtitleCue(2.0, 3.25, "Speaker 2: Hi.", "Speaker 2"),
+    ]
+    for fmt, rendered in (("srt", render_srt(original)), ("ass", render_ass(original, "t"))):
+        cues = parse_subtitles(rendered, fmt)
+        assert [(c.start, c.end, c.text, c.speaker) for c in cues] == [
+            (0.0, 1.5, "Hello there.", "Speaker 1"),
+            (2.0, 3.25, "Hi.", "Speaker 2"),
+        ], fmt
+
+
+def test_proportional_words_stay_inside_the_cue() -> None:
+    words = proportional_words([{"start": 1.0, "end": 2.0, "text": "a longerword"}])
+    assert [w["word"] for w in words] == ["a", "longerword"]
+    assert words[0]["start"] == 1.0
+    assert words[-1]["end"] == 2.0
+    assert words[0]["end"] - words[0]["start"] < words[1]["end"] - words[1]["start"]
+
+
+def test_align_without_model_falls_back_to_proportional() -> None:
+    segments = [{"start": 0.0, "end": 1.0, "text": "one two", "speaker": "A"}, {"text": " "}]
+    result = align_segments(None, segments)
+    assert result.method == "proportional"
+    assert [s["speaker"] for s in result.segments] == ["A"]
+    assert len(result.words) == 2
@@ -0,0 +1,79 @@
+"""SMPTE timecode helpers and their application in subtitle exports."""
+
+from __future__ import annotations
+
+import pytest
+from server.core.subtitle_export import SubtitleCue, render_ass, render_srt
+from server.core.timecode import (
+    TimecodeSettings,
+    apply_timecode,
+    format_timecode,
+    frames_to_timecode,
+    parse_settings,
+    serialize_settings,
+    timecode_to_frames,
+)
+
+
+def test_non_drop_frame_labels() -> None:
+    tc = TimecodeSettings(start_timecode="01:00:00:00", fps=25)
+    assert format_timecode(0.0, tc) == "01:00:00:00"
+    assert format_timecode(1.5, tc) == "01:00:01:13"
+    assert timecode_to_frames("00:00:02:10", tc) == 60
+
+
+def test_drop_frame_skips_labels_except_tenth_minutes() -> None:
+    tc = TimecodeSettings(fps=29.97, drop_frame=True)
+    assert frames_to_timecode(1799, tc) == "00:00:59;29"
+    assert frames_to_timecode(1800, tc) == "00:01:00;02"
+    assert frames_to_timecode(17982, tc) == "00:10:00;00"
+
+
+def test_drop_frame_round_trips() -> None:
+    tc = TimecodeSettings(fps=29.97, drop_frame=True)
+    for frames in range(0, 60_000, 37):
+        assert timecode_to_frames(frames_to_timecode(frames, tc), tc) == frames
+
+
+def test_start_label_is_normalized() -> None:
+    assert TimecodeSettings("01:00:00.00", fps=29.97, drop_frame=True).start_timecode == (
+        "01:00:00;00"
+    )
+    assert TimecodeSettings("00:00:10;05", fps=25).start_timecode == "00:00:10:05"
+
+
+@pytest.mark.parametrize(
+    "kwargs",
+    [
+        {"fps": 50},
+        {"fps": 25, "drop_frame": True},
+        {"start_timecode": "00:00:00:25", "fps": 25},
+        {"start_timecode": "00:01:00;00", "fps": 29.97, "drop_frame": True},
+        {"start_timecode": "1 hour"},
+    ],
+)
+def test_invalid_settings_raise(kwargs: dict) -> None:
+    with pytest.raises(ValueError):
+        TimecodeSettings(**kwargs)
+
+
+def test_apply_timecode_offsets_and_snaps_to_frames() -> None:
+    tc = TimecodeSettings(start_timecode="01:00:00:00", fps=25)
+    assert apply_timecode(1.01, tc) == pytest.approx(3601.0)
+    assert apply_timecode(1.03, None) == 1.03
+
+
+def test_settings_json_round_trip_and_bad_input() -> None:
+    tc = TimecodeSettings(start_timecode="00:59:50:00", fps=23.976)
+    assert parse_settings(serialize_settings(tc)) == tc
+    assert parse_settings(None) is None
+    assert parse_settings("not json") is None
+    assert parse_settings('{"fps": 12}') is None
+
+
+def test_srt_and_ass_use_timecode() -> None:
+    cues = [SubtitleCue(start=0.5, end=2.0, text="Hello")]
+    tc = TimecodeSettings(start_timecode="01:00:00:00", fps=25)
+    assert "01:00:00,520 --> 01:00:02,000" in render_srt(cues, timecode=tc)
+    assert "00:00:00,500 --> 00:00:02,000" in render_srt(cues)
+    assert "Dialogue: 0,1:00:00.52,1:00:02.00," in render_ass(cues, "T", timecode=tc)
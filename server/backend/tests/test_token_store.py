@@ -5,7 +5,7 @@ Covers:
 - ``StoredToken.create`` factories (admin / regular / custom expiry)
 - ``StoredToken.is_expired`` edge cases
 - ``StoredToken`` serialisation round-trip
-- ``TokenStore`` CRUD: generate → validate → revoke → list
+- ``TokenStore`` CRUD: generate → validate → revoke → rotate → list
 - Expiry enforcement at validation time
 - Role checking (``is_admin``)
 - File persistence across ``TokenStore`` reloads
@@ -225,6 +225,37 @@ class TestTokenStoreCRUD:
         # 1 initial admin + 2 generated
         assert len(tokens) == 3
 
+    def test_rotate_replaces_secret_and_keeps_identity(self, store: TokenStore):
+        stored, old_plaintext = store.generate_token("rotating", expiry_days=7)
+
+        rotated = store.rotate_token_by_id(stored.token_id)
+
+        assert rotated is not None
+        new_token, new_plaintext = rotated
+        assert store.validate_token(old_plaintext) is None
+        assert store.validate_token(new_plaintext).client_name == "rotating"
+        assert new_token.token_id != stored.token_id
+        assert new_token.expires_at == stored.expires_at
+        assert new_token.is_admin is False
+
+    def test_rotate_revoked_or_unknown_token_returns_none(self, store: TokenStore):
+        stored, _ = store.generate_token("gone")
+        store.revoke_token_by_id(stored.token_id)
+
+        assert store.rotate_token_by_id(stored.token_id) is None
+        assert store.rotate_token_by_id("does-not-exist") is None
+
+    def test_revoke_tokens_for_client(self, store: TokenStore):
+        _, first = store.generate_token("family-laptop")
+        _, second = store.generate_token("family-laptop")
+        _, other = store.generate_token("someone-else")
+
+        assert store.revoke_tokens_for_client("family-laptop") == 2
+        assert store.revoke_tokens_for_client("family-laptop") == 0
+        assert store.validate_token(first) is None
+        assert store.validate_token(second) is None
+        assert store.validate_token(other) is not None
+
 
 # ── File persistence ─────────────────────────────────────────────────────
 
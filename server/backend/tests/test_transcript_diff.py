@@ -0,0 +1,46 @@
+"""Transcript diff: word alignment, error counts and hunk timings."""
+
+from __future__ import annotations
+
+from server.core.transcript_diff import DiffToken, diff_words
+
+
+def _timed(text: str) -> list[DiffToken]:
+    return [DiffToken(w, float(i), i + 0.5) for i, w in enumerate(text.split())]
+
+
+def test_identical_text_ignoring_case_and_punctuation_has_no_errors() -> None:
+    untimed = [DiffToken(w) for w in "so we met on monday".split()]
+    diff = diff_words(_timed("So we met on Monday."), untimed)
+
+    assert diff["wer"] == 0.0
+    assert diff["matches"] == 5
+    assert [h["op"] for h in diff["hunks"]] == ["equal"]
+    assert diff["hunks"][0]["a"] == {"text": "So we met on Monday.", "start": 0.0, "end": 4.5}
+    assert diff["hunks"][0]["b"]["start"] is None
+
+
+def test_counts_substitutions_deletions_and_insertions() -> None:
+    diff = diff_words(
+        _timed("the quick brown fox jumps over the dog"),
+        _timed("the quick brown box jumps the lazy dog"),
+    )
+
+    assert (diff["substitutions"], diff["deletions"], diff["insertions"]) == (1, 1, 1)
+    assert diff["wer"] == 0.375
+    assert [(h["op"], h["a"]["text"], h["b"]["text"]) for h in diff["hunks"]] == [
+        ("equal", "the quick brown", "the quick brown"),
+        ("replace", "fox", "box"),
+        ("equal", "jumps", "jumps"),
+        ("delete", "over", ""),
+        ("equal", "the", "the"),
+        ("insert", "", "lazy"),
+        ("equal", "dog", "dog"),
+    ]
+    assert diff["hunks"][3]["a"]["start"] == 5.0
+
+
+def test_punctuation_tokens_are_skipped_and_empty_sides_score() -> None:
+    assert diff_words([DiffToken("—"), DiffToken("hi")], [DiffToken("hi")])["wer"] == 0.0
+    assert diff_words([], [])["wer"] == 0.0
+    assert diff_words([], [DiffToken("extra")])["wer"] == 1.0
@@ -0,0 +1,104 @@
+"""Legacy transcript import: format detection and the per-tool parsers."""
+
+from __future__ import annotations
+
+import json
+
+import pytest
+from server.core.transcript_import import (
+    detect_transcript_format,
+    import_transcript_file,
+    load_transcript,
+    parse_transcript,
+)
+
+_OTTER = (
+    "Jane Doe  0:00\nWelcome back, everyone.\n\n"
+    "Speaker 2  0:04\nThanks. Let's meet at\n10:30 tomorrow.\n\n"
+    "Jane Doe  1:02:05\nGreat.\n\n"
+    "Transcribed by https://otter.ai\n"
+)
+
+
+def test_detects_formats_by_extension_then_content() -> None:
+    assert detect_transcript_format("call.json", "") == "whisper_json"
+    assert detect_transcript_format("talk.sbv", "") == "sbv"
+    assert detect_transcript_format("a.srt", "") == "srt"
+    assert detect_transcript_format("notes.txt", _OTTER) == "otter"
+    assert detect_transcript_format("notes.txt", "[00:00:05] Alice: Hi") == "descript"
+    assert detect_transcript_format("x.txt", "0:00:01.000,0:00:02.000\nhi\n") == "sbv"
+    assert detect_transcript_format("x.txt", '{"segments": []}') == "whisper_json"
+    assert detect_transcript_format("x.txt", "WEBVTT\n\n00:01.000 --> 00:02.000\nhi\n") == "vtt"
+    with pytest.raises(ValueError, match="recognise"):
+        detect_transcript_format("x.txt", "just some prose")
+
+
+def test_whisper_json_keeps_word_timings_and_language() -> None:
+    content = json.dumps(
+        {
+            "language": "en",
+            "segments": [
+                {
+                    "start": 0.0,
+                    "end": 1.5,
+                    "text": " Hello there.",
+                    "speaker": "SPEAKER_00",
+                    "words": [
+                        {"word": " Hello", "start": 0.0, "end": 0.6, "probability": 0.9},
+                        {"word": " there.", "start": 0.7, "end": 1.5, "probability": 0.8},
+                    ],
+                },
+            ],
+        }
+    )
+    transcript = parse_transcript(content, "whisper_json")
+    assert transcript.language == "en"
+    assert transcript.segments == [
+        {"start": 0.0, "end": 1.5, "text": "Hello there.", "speaker": "SPEAKER_00"}
+    ]
+    assert [(w["word"], w["confidence"], w["segment_index"]) for w in transcript.words or []] == [
+        ("Hello", 0.9, 0),
+        ("there.", 0.8, 0),
+    ]
+
+
+def test_whisper_json_without_words_on_every_segment_drops_words() -> None:
+    content = json.dumps(
+        [
+            {"start": 0, "end": 1, "text": "a", "words": [{"word": "a", "start": 0, "end": 1}]},
+            {"start": 1, "end": 2, "text": "b"},
+        ]
+    )
+    transcript = parse_transcript(content, "whisper_json")
+    assert transcript.words is None
+    assert transcript.duration == 2.0
+
+
+def test_otter_paragraphs_end_at_the_next_header_and_footer_is_dropped() -> None:
+    transcript = parse_transcript(_OTTER, "otter")
+    assert [(s["start"], s["end"], s["speaker"], s["text"]) for s in transcript.segments] == [
+        (0.0, 4.0, "Jane Doe", "Welcome back, everyone."),
+        (4.0, 3725.0, "Speaker 2", "Thanks. Let's meet at 10:30 tomorrow."),
+        (3725.0, 3726.0, "Jane Doe", "Great."),
+    ]
+
+
+def test_sbv_and_descript_split_speaker_prefixes() -> None:
+    sbv = parse_transcript("0:00:01.500,0:00:03.000\nAlex: Hi\nthere\n\n", "sbv")
+    assert sbv.segments == [{"start": 1.5, "end": 3.0, "text": "Hi there", "speaker": "Alex"}]
+
+    descript = parse_transcript(
+        "[00:00:02] Alice: First point\ncontinues here.\n\n[00:00:09] Bob: Agreed.\n", "descript"
+    )
+    assert [(s["start"], s["end"], s["speaker"], s["text"]) for s in descript.segments] == [
+        (2.0, 9.0, "Alice", "First point continues here."),
+        (9.0, 10.0, "Bob", "Agreed."),
+    ]
+
+
+def test_load_and_import_file_decode_and_reject_empty(tmp_path) -> None:
+    path = tmp_path / "legacy.sbv"
+    path.write_bytes("0:00:00.000,0:00:01.000\ncafé\n".encode("latin-1"))
+    assert import_transcript_file(path).segments[0]["text"] == "café"
+    with pytest.raises(ValueError, match="No transcript text"):
+        load_transcript("empty.json", b'{"segments": []}')
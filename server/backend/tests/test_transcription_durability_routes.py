@@ -459,6 +459,117 @@ class TestDismissTranscriptionResult:
         assert resp.status_code == 200
 
 
+# ── Kept import media: /api/transcribe/jobs/{job_id}/media ──────────────────
+
+
+async def _body(response) -> bytes:
+    return b"".join([chunk async for chunk in response.body_iterator])
+
+
+class TestJobMedia:
+    def test_keep_import_media_copies_into_recordings_dir(self, monkeypatch, tmp_path):
+        upload = tmp_path / "upload.tmp"
+        upload.write_bytes(b"RIFF-audio")
+        recordings = tmp_path / "recordings"
+        config = SimpleNamespace(get=lambda *_a, **_kw: str(recordings))
+        monkeypatch.setattr("server.config.get_config", lambda: config)
+        recorded = []
+        monkeypatch.setattr(
+            "server.database.job_repository.set_audio_path",
+            lambda job_id, path: recorded.append((job_id, path)),
+        )
+        monkeypatch.setattr(transcription, "encrypt_at_rest", lambda _p: False)
+
+        kept = transcription._keep_import_media("job-full-id", upload, "Interview.M4A")
+
+        assert kept == recordings / "job-full-id.m4a"
+        assert kept.read_bytes() == b"RIFF-audio"
+        assert upload.exists()  # the import worker still owns and removes the temp file
+        assert recorded == [("job-full-id", str(kept))]
+
+    def test_download_streams_audio_with_its_digest(self, repo, monkeypatch, tmp_path):
+        audio = tmp_path / "job-full-id.wav"
+        audio.write_bytes(b"RIFF-audio")
+        monkeypatch.setattr(
+            repo,
+            "get_job",
+            lambda _: {
+                "status": "processing",
+                "client_name": "test-client",
+                "audio_path": str(audio),
+                "audio_hash": "ab" * 32,
+            },
+        )
+
+        resp = asyncio.run(transcription.download_job_media("job-full-id", _request()))
+
+        assert resp.status_code == 200
+        assert resp.headers["x-content-sha256"] == "ab" * 32
+        assert resp.headers["content-length"] == "10"
+        assert 'filename="job-full.wav"' in resp.headers["content-disposition"]
+        assert asyncio.run(_body(resp)) == b"RIFF-audio"
+
+    def test_download_410_when_nothing_was_kept(self, repo, monkeypatch, tmp_path):
+        monkeypatch.setattr(
+            repo,
+            "get_job",
+            lambda _: {
+                "status": "completed",
+                "client_name": "test-client",
+                "audio_path": str(tmp_path / "gone.wav"),
+            },
+        )
+
+        with pytest.raises(HTTPException) as exc:
+            asyncio.run(transcription.download_job_media("job-gone", _request()))
+        assert exc.value.status_code == 410
+
+    def test_download_403_for_different_client(self, repo, monkeypatch):
+        monkeypatch.setattr(repo, "get_job", lambda _: {"client_name": "other-client"})
+
+        with pytest.raises(HTTPException) as exc:
+            asyncio.run(transcription.download_job_media("job-other", _request()))
+        assert exc.value.status_code == 403
+
+    def test_delete_removes_kept_audio_of_an_import(self, repo, monkeypatch, tmp_path):
+        audio = tmp_path / "job-import.wav"
+        audio.write_bytes(b"RIFF")
+        monkeypatch.setattr(
+            repo,
+            "get_job",
+            lambda _: {
+                "status": "processing",
+                "source": "file_import",
+                "client_name": "test-client",
+                "audio_path": str(audio),
+            },
+        )
+
+        resp = asyncio.run(transcription.delete_job_media("job-import", _request()))
+
+        assert json.loads(resp.body) == {"job_id": "job-import", "deleted": True}
+        assert not audio.exists()
+
+    def test_delete_409_while_a_session_job_reads_its_audio(self, repo, monkeypatch, tmp_path):
+        audio = tmp_path / "job-live.wav"
+        audio.write_bytes(b"RIFF")
+        monkeypatch.setattr(
+            repo,
+            "get_job",
+            lambda _: {
+                "status": "processing",
+                "source": "websocket",
+                "client_name": "test-client",
+                "audio_path": str(audio),
+            },
+        )
+
+        with pytest.raises(HTTPException) as exc:
+            asyncio.run(transcription.delete_job_media("job-live", _request()))
+        assert exc.value.status_code == 409
+        assert audio.exists()
+
+
 class TestTranscribeDecodeErrorRouting:
     """FINDING #1 (route boundary): a decode failure must surface as a clean HTTP
     400 whose detail contains NO server temp path — not a path-leaking 500."""
@@ -8,6 +8,7 @@ Covers:
 - ``update_progress`` / ``clear_progress``
 - ``get_status`` dict structure
 - Result storage and clearing across jobs
+- Queue: head-only starts, per-user reordering, expiry and start estimates
 - Thread safety under concurrent access
 """
 
@@ -15,6 +16,8 @@ from __future__ import annotations
 
 import threading
 
+import pytest
+from server.core import job_queue
 from server.core.model_manager import TranscriptionJobTracker
 
 # ── try_start_job ─────────────────────────────────────────────────────────
@@ -295,6 +298,7 @@ class TestGetStatus:
             "progress": None,
             "started_at": None,
             "result": None,
+            "queue_length": 0,
         }
 
     def test_active_status_truncates_job_id(self):
@@ -329,6 +333,111 @@ class TestGetStatus:
         assert status["result"] is None
 
 
+# ── Queue ─────────────────────────────────────────────────────────────────
+
+
+class TestQueue:
+    def test_only_the_head_entry_may_start_while_queued(self):
+        tracker = TranscriptionJobTracker()
+        _, running, _ = tracker.try_start_job("alice")
+        bob = tracker.join_queue("bob", "interview.mp3")
+        carol = tracker.join_queue("carol", "lecture.wav")
+        tracker.end_job(running)
+
+        assert tracker.try_start_job("dave") == (False, None, "bob")
+        assert tracker.try_start_job("carol", carol) == (False, None, "bob")
+        success, _, _ = tracker.try_start_job("bob", bob)
+
+        assert success is True
+        assert tracker.get_status()["queue_length"] == 1
+
+    def test_queue_hides_other_clients_labels(self):
+        tracker = TranscriptionJobTracker()
+        tracker.join_queue("bob", "interview.mp3")
+        mine = tracker.join_queue("alice", "notes.m4a")
+
+        entries = tracker.get_queue("alice")["entries"]
+
+        assert [(e["user"], e["mine"], e["label"], e["id"]) for e in entries] == [
+            ("bob", False, None, None),
+            ("alice", True, "notes.m4a", mine),
+        ]
+
+    def test_queue_shows_every_label_to_viewers_of_all_jobs(self):
+        tracker = TranscriptionJobTracker()
+        tracker.join_queue("bob", "interview.mp3")
+
+        (entry,) = tracker.get_queue("admin", view_all=True)["entries"]
+
+        assert (entry["label"], entry["id"], entry["mine"]) == ("interview.mp3", None, False)
+
+    def test_reorder_keeps_other_users_in_place(self):
+        tracker = TranscriptionJobTracker()
+        a1 = tracker.join_queue("alice", "one")
+        tracker.join_queue("bob", "theirs")
+        a2 = tracker.join_queue("alice", "two")
+
+        tracker.reorder_queue("alice", [a2, a1])
+
+        entries = tracker.get_queue("alice")["entries"]
+        assert [e["label"] or e["user"] for e in entries] == ["two", "bob", "one"]
+
+    def test_reorder_must_name_every_own_entry(self):
+        tracker = TranscriptionJobTracker()
+        a1 = tracker.join_queue("alice", "one")
+        tracker.join_queue("alice", "two")
+        bob = tracker.join_queue("bob", "theirs")
+
+        with pytest.raises(ValueError):
+            tracker.reorder_queue("alice", [a1])
+        with pytest.raises(ValueError):
+            tracker.reorder_queue("alice", [a1, bob])
+
+    def test_cannot_leave_someone_elses_entry(self):
+        tracker = TranscriptionJobTracker()
+        bob = tracker.join_queue("bob", "theirs")
+
+        with pytest.raises(job_queue.QueueEntryNotFound):
+            tracker.leave_queue(bob, "alice")
+        tracker.leave_queue(bob, "bob")
+
+        assert tracker.get_status()["queue_length"] == 0
+
+    def test_entries_that_stop_polling_expire(self, monkeypatch):
+        tracker = TranscriptionJobTracker()
+        tracker.join_queue("bob", "gone")
+        tracker.join_queue("carol", "waiting")
+        later = job_queue.time.time() + job_queue.ENTRY_TTL_SECONDS + 1
+        monkeypatch.setattr(job_queue.time, "time", lambda: later)
+
+        tracker.get_queue("carol")
+
+        # bob is the head and gets the longer grace; carol keeps polling.
+        assert [e["user"] for e in tracker.get_queue("carol")["entries"]] == ["bob", "carol"]
+        later += job_queue.HEAD_TTL_SECONDS
+        tracker.get_queue("carol")
+        assert [e["user"] for e in tracker.get_queue("carol")["entries"]] == ["carol"]
+
+    def test_start_estimates_follow_finished_jobs(self):
+        tracker = TranscriptionJobTracker()
+        _, job_id, _ = tracker.try_start_job("alice")
+        tracker.join_queue("bob", "first")
+        tracker.join_queue("bob", "second")
+
+        snapshot = tracker.get_queue("bob")
+        assert snapshot["active"]["user"] == "alice"
+        assert snapshot["active"]["mine"] is False
+        # Nothing has finished yet, so there is nothing to estimate from.
+        assert [e["estimated_start_at"] for e in snapshot["entries"]] == [None, None]
+
+        tracker.end_job(job_id)
+        snapshot = tracker.get_queue("bob")
+        first, second = snapshot["entries"]
+        assert snapshot["active"] is None
+        assert first["estimated_start_at"] == snapshot["now"]
+        assert second["estimated_start_at"] >= first["estimated_start_at"]
+
+
 # ── Thread safety ─────────────────────────────────────────────────────────
 
 
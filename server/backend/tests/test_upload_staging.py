@@ -0,0 +1,95 @@
+"""Tests for UploadStaging — files staged ahead of their transcription.
+
+Covers:
+- chunked appends at the current offset, and resuming after a mismatch
+- claiming a complete upload moves it out of the staging area
+- incomplete, foreign and unknown uploads are refused
+- idle uploads expire; each user has a cap on staged uploads
+"""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+from server.core import upload_staging
+from server.core.upload_staging import (
+    StagedUploadError,
+    StagedUploadNotFound,
+    StagedUploadOffsetMismatch,
+    UploadStaging,
+)
+
+
+@pytest.fixture()
+def staging(tmp_path: Path) -> UploadStaging:
+    return UploadStaging(tmp_path / "staged")
+
+
+def test_chunks_append_in_order_and_claim_moves_the_file(
+    staging: UploadStaging, tmp_path: Path
+) -> None:
+    upload = staging.create("alice", "interview.m4a", 6, now=0)
+    assert upload.path.suffix == ".m4a"
+
+    assert staging.append(upload.id, "alice", 0, b"abc", now=1) == 3
+    assert staging.append(upload.id, "alice", 3, b"def", now=2) == 6
+    assert staging.status(upload.id, "alice").complete
+
+    destination = tmp_path / "claimed.m4a"
+    assert staging.claim(upload.id, "alice", destination) == "interview.m4a"
+    assert destination.read_bytes() == b"abcdef"
+    assert not upload.path.exists()
+    with pytest.raises(StagedUploadNotFound):
+        staging.status(upload.id, "alice")
+
+
+def test_wrong_offset_reports_what_was_received(staging: UploadStaging) -> None:
+    upload = staging.create("alice", "a.wav", 10)
+    staging.append(upload.id, "alice", 0, b"12345")
+
+    with pytest.raises(StagedUploadOffsetMismatch) as exc:
+        staging.append(upload.id, "alice", 0, b"12345")
+    assert exc.value.received == 5
+
+    with pytest.raises(StagedUploadError, match="past the declared size"):
+        staging.append(upload.id, "alice", 5, b"123456")
+
+
+def test_incomplete_foreign_and_unknown_uploads_are_refused(
+    staging: UploadStaging, tmp_path: Path
+) -> None:
+    upload = staging.create("alice", "a.wav", 10)
+    staging.append(upload.id, "alice", 0, b"123")
+
+    with pytest.raises(StagedUploadError, match="incomplete: 3 of 10"):
+        staging.claim(upload.id, "alice", tmp_path / "out.wav")
+    with pytest.raises(StagedUploadNotFound):
+        staging.append(upload.id, "bob", 3, b"456")
+    with pytest.raises(StagedUploadNotFound):
+        staging.discard("missing", "alice")
+    with pytest.raises(StagedUploadError):
+        staging.create("alice", "empty.wav", 0)
+
+    staging.discard(upload.id, "alice")
+    assert not upload.path.exists()
+
+
+def test_idle_uploads_expire(staging: UploadStaging) -> None:
+    old = staging.create("alice", "old.wav", 4, now=0)
+    active = staging.create("alice", "active.wav", 4, now=0)
+    staging.append(active.id, "alice", 0, b"ab", now=upload_staging.STAGED_TTL_SECONDS)
+
+    assert staging.prune(now=upload_staging.STAGED_TTL_SECONDS + 1) == 1
+    assert not old.path.exists()
+    assert staging.status(active.id, "alice").received == 2
+
+
+def test_per_user_cap(staging: UploadStaging, monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setattr(upload_staging, "MAX_STAGED_PER_USER", 2)
+    staging.create("alice", "1.wav", 1)
+    staging.create("alice", "2.wav", 1)
+
+    with pytest.raises(StagedUploadError, match="At most 2"):
+        staging.create("alice", "3.wav", 1)
+    staging.create("bob", "1.wav", 1)
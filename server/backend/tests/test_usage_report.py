@@ -0,0 +1,64 @@
+"""Per-user usage report for the admin panel (database/usage_report.py)."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.database.database import save_longform_to_database
+from server.database.job_repository import create_job, mark_failed, save_result
+from server.database.usage_report import usage_by_user
+
+
+@pytest.fixture()
+def fresh_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True, exist_ok=True)
+    monkeypatch.setenv("DATA_DIR", str(data_dir))
+    monkeypatch.setattr(db, "_data_dir", None)
+    monkeypatch.setattr(db, "_db_path", None)
+    db.set_data_directory(data_dir)
+    db.init_db()
+    return db.get_db_path()
+
+
+def _job(job_id: str, client: str) -> None:
+    create_job(job_id, "file", client, None, "transcribe", None)
+
+
+def test_jobs_are_grouped_by_client(fresh_db: Path) -> None:
+    _job("a1", "alice-laptop")
+    save_result("a1", "hi", "{}", "en", 30.0)
+    _job("a2", "alice-laptop")
+    mark_failed("a2", "boom")
+    _job("b1", "bob-phone")
+    save_result("b1", "yo", "{}", "en", 12.5)
+
+    clients = {row["client_name"]: row for row in usage_by_user()["clients"]}
+
+    assert clients["alice-laptop"]["jobs"] == 2
+    assert clients["alice-laptop"]["failed_jobs"] == 1
+    assert clients["alice-laptop"]["audio_seconds"] == 30.0
+    assert clients["bob-phone"]["audio_seconds"] == 12.5
+
+
+def test_recordings_are_grouped_by_owner(fresh_db: Path, tmp_path: Path) -> None:
+    for name, owner in [("a.mp3", "alice"), ("b.mp3", "alice"), ("c.mp3", None)]:
+        audio = tmp_path / name
+        audio.write_bytes(b"\x00")
+        save_longform_to_database(
+            audio_path=audio, duration_seconds=60.0, transcription_text=name, owner=owner
+        )
+
+    report = usage_by_user(days=30)
+
+    assert report["since"] is not None
+    assert report["owners"] == [
+        {
+            "owner": "alice",
+            "recordings": 2,
+            "recording_seconds": 120.0,
+            "last_recorded_at": report["owners"][0]["last_recorded_at"],
+        }
+    ]
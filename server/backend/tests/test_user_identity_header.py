@@ -0,0 +1,42 @@
+"""Tests for the per-user identity header on shared servers.
+
+``get_user_name`` reads the self-declared ``X-TranscriptionSuite-User``
+header; ``get_requester`` narrows ``get_client_name`` with it so people who
+share a token still hold separate places in the transcription queue.
+"""
+
+from server.api.routes import utils
+
+
+class _FakeClient:
+    def __init__(self, host: str) -> None:
+        self.host = host
+
+
+class _FakeRequest:
+    def __init__(self, headers: dict | None = None) -> None:
+        self.client = _FakeClient("127.0.0.1")
+        self.headers = headers or {}
+        self.cookies = {}
+
+
+def test_missing_or_blank_header_has_no_user():
+    assert utils.get_user_name(_FakeRequest()) is None
+    assert utils.get_user_name(_FakeRequest({utils.USER_HEADER: "   "})) is None
+
+
+def test_user_name_is_percent_decoded_and_cleaned():
+    req = _FakeRequest({utils.USER_HEADER: "%20Zo%C3%AB%0A "})
+    assert utils.get_user_name(req) == "Zoë"
+
+    long_name = _FakeRequest({utils.USER_HEADER: "x" * 100})
+    assert utils.get_user_name(long_name) == "x" * utils.USER_NAME_MAX_LENGTH
+
+
+def test_requester_combines_client_and_user(monkeypatch):
+    monkeypatch.setattr(utils, "TLS_MODE", False)
+    assert utils.get_requester(_FakeRequest()) == "localhost-user"
+    req = _FakeRequest({utils.USER_HEADER: "alice"})
+    assert utils.get_requester(req) == "localhost-user/alice"
+    # Result ownership (GH #202) is unaffected by the header.
+    assert utils.get_client_name(req) == "localhost-user"
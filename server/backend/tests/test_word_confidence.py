@@ -0,0 +1,91 @@
+"""Low-confidence word regions and word-score persistence."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+import server.database.database as db
+from server.core.word_confidence import confidence_coverage, low_confidence_regions
+from server.database.database import get_words, save_longform_to_database
+
+
+@pytest.fixture()
+def fresh_db(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    data_dir = tmp_path / "data"
+    (data_dir / "database").mkdir(parents=True, exist_ok=True)
+    monkeypatch.setenv("DATA_DIR", str(data_dir))
+    monkeypatch.setattr(db, "_data_dir", None)
+    monkeypatch.setattr(db, "_db_path", None)
+    db.set_data_directory(data_dir)
+    db.init_db()
+    return db.get_db_path()
+
+
+def _words(*scores: float | None) -> list[dict]:
+    return [
+        {
+            "id": i + 1,
+            "segment_id": 7,
+            "word": f"w{i + 1}",
+            "start_time": float(i),
+            "end_time": i + 0.5,
+            "confidence": score,
+        }
+        for i, score in enumerate(scores)
+    ]
+
+
+def test_consecutive_low_words_merge_into_one_region() -> None:
+    regions = low_confidence_regions(_words(0.9, 0.3, 0.5, 0.95), 0.6)
+    assert len(regions) == 1
+    region = regions[0]
+    assert (region["start"], region["end"], region["text"]) == (1.0, 2.5, "w2 w3")
+    assert region["word_ids"] == [2, 3]
+    assert region["min_confidence"] == 0.3
+    assert region["mean_confidence"] == 0.4
+    assert region["segment_id"] == 7
+
+
+def test_one_confident_word_bridges_but_two_split() -> None:
+    assert len(low_confidence_regions(_words(0.2, 0.9, 0.2), 0.6)) == 1
+    assert low_confidence_regions(_words(0.2, 0.9, 0.2), 0.6)[0]["word_ids"] == [1, 2, 3]
+    assert len(low_confidence_regions(_words(0.2, 0.9, 0.9, 0.2), 0.6)) == 2
+    assert len(low_confidence_regions(_words(0.2, 0.9, 0.2), 0.6, bridge_words=0)) == 2
+
+
+def test_trailing_bridge_word_is_not_included() -> None:
+    regions = low_confidence_regions(_words(0.2, 0.9), 0.6)
+    assert regions[0]["word_ids"] == [1]
+
+
+def test_unscored_words_never_form_regions() -> None:
+    words = _words(None, 0.1, None, 0.1)
+    assert [r["word_ids"] for r in low_confidence_regions(words, 0.6)] == [[2], [4]]
+    assert confidence_coverage(words) == 0.5
+    assert confidence_coverage([]) == 0.0
+
+
+def test_backend_probability_is_persisted_as_confidence(fresh_db: Path, tmp_path: Path) -> None:
+    audio = tmp_path / "talk.mp3"
+    audio.write_bytes(b"\x00" * 16)
+    scores = [
+        {"probability": 0.42},
+        {"confidence": 0.7, "probability": 0.1},
+        {"confidence": None, "probability": 0.3},
+        {"probability": 1.3},
+        {"probability": "n/a"},
+        {},
+    ]
+    rec_id = save_longform_to_database(
+        audio_path=audio,
+        duration_seconds=6.0,
+        transcription_text="a b c d e f",
+        word_timestamps=[
+            {"word": w, "start": float(i), "end": i + 0.5, **score}
+            for i, (w, score) in enumerate(zip("abcdef", scores, strict=True))
+        ],
+    )
+    assert rec_id
+
+    assert [w["confidence"] for w in get_words(rec_id)] == [0.42, 0.7, 0.3, 1.0, None, None]
@@ -442,7 +442,8 @@ def run_dependency_sync(
 ) -> None:
     """Run dependency sync into the runtime virtual environment.
 
-    Variant handling (Issue #83; cpu variant added in GH #125, same URL-swap):
+    Variant handling (Issue #83; cpu variant added in GH #125, rocm6.4 and xpu
+    for the AMD/Intel GPU images, all using the same URL-swap):
         cu129 (default) — frozen sync against the lock-pinned PyTorch index.
         cu126 (legacy)  — drops --frozen and overrides the URL of the named
                           index `pytorch-cu129` with the cu126 wheel URL via
@@ -479,10 +480,11 @@ def run_dependency_sync(
     ]
     # Non-default variants swap the URL of the *named* index `pytorch-cu129`
     # (which [tool.uv.sources] pins torch/torchaudio to): cu126 for legacy GPUs
-    # (Pascal/Maxwell, sm_50..sm_90) and cpu for CPU-only hosts (GH #125 — no
-    # multi-GB CUDA wheels). --frozen is dropped because uv.lock pins cu129 wheel
-    # hashes; --index-strategy unsafe-best-match lets non-torch packages fall
-    # back to PyPI (Issue #115).
+    # (Pascal/Maxwell, sm_50..sm_90), cpu for CPU-only hosts (GH #125 — no
+    # multi-GB CUDA wheels), and rocm6.4 / xpu for the AMD and Intel GPU images.
+    # --frozen is dropped because uv.lock pins cu129 wheel hashes;
+    # --index-strategy unsafe-best-match lets non-torch packages fall back to
+    # PyPI (Issue #115).
     #
     # GH #125 (reopen): dropping --frozen makes uv re-resolve the *universal* lock,
     # which includes the vibevoice_asr extra's git source — so uv would `git clone`
@@ -494,6 +496,8 @@ def run_dependency_sync(
     variant_index_urls = {
         "cu126": "https://download.pytorch.org/whl/cu126",
         "cpu": "https://download.pytorch.org/whl/cpu",
+        "rocm6.4": "https://download.pytorch.org/whl/rocm6.4",
+        "xpu": "https://download.pytorch.org/whl/xpu",
     }
     index_url = variant_index_urls.get(pytorch_variant)
     if index_url is not None:
@@ -1684,7 +1688,7 @@ def main() -> int:
     raw_variant = (os.environ.get("PYTORCH_VARIANT") or "").strip().lower()
     if raw_variant in {"", "cu129"}:
         pytorch_variant = "cu129"
-    elif raw_variant in {"cu126", "cpu"}:
+    elif raw_variant in {"cu126", "cpu", "rocm6.4", "xpu"}:
         pytorch_variant = raw_variant
     else:
         log(f"Unknown PYTORCH_VARIANT={raw_variant!r}; falling back to cu129")